@@ -31,24 +31,131 @@ facet::define_attr_grammar! {
         /// Usage: `#[facet(fg::bytes)]`
         Bytes,
 
-        /// Mark a type as branded (a newtype with distinct identity).
+        /// Mark a newtype struct as branded: emitted as a nominal type that can't be
+        /// mixed up with its inner type, or with other branded types wrapping it.
         ///
         /// Usage: `#[facet(fg::branded)]`
         Branded,
 
-        /// Mark a field or type as public in the generated code.
+        /// Mark a field or type as public in the generated code, even when the generator's
+        /// default visibility is internal.
         ///
         /// Usage: `#[facet(fg::public)]`
         Public,
 
-        /// Mark a field as read-only in the generated code.
+        /// Mark a field as read-only in the generated code. On a type, marks all of its
+        /// fields as read-only.
         ///
         /// Usage: `#[facet(fg::readonly)]`
         Readonly,
 
-        /// Specify a serialization proxy type name.
+        /// Specify the type that a type or field is serialized as, in Rust type syntax. Only
+        /// built-in types (primitives, `String`, `Uuid`, tuples, arrays and standard
+        /// collections) can be named.
         ///
         /// Usage: `#[facet(fg::serialized_as = "String")]`
         SerializedAs(&'static str),
+
+        /// Leave a type, field or enum variant out of the generated code for the listed
+        /// languages.
+        ///
+        /// Usage: `#[facet(fg::skip(swift, kotlin))]`
+        Skip(Languages),
+
+        /// Generate a type, field or enum variant only for the listed languages.
+        ///
+        /// Usage: `#[facet(fg::only(typescript))]`
+        Only(Languages),
+
+        /// Replace the type of a field in the generated code for one language.
+        ///
+        /// Usage: `#[facet(fg::override(lang = "swift", ty = "CGFloat"))]`
+        Override(Override),
+
+        /// Add protocols or interfaces, as a comma-separated list, to a type's declaration in
+        /// one language.
+        ///
+        /// Usage: `#[facet(fg::conforms(lang = "swift", to = "Sendable, Identifiable"))]`
+        Conforms(Conforms),
+
+        /// Add annotations (attributes or decorators), as a comma-separated list, before a type's
+        /// declaration in one language.
+        ///
+        /// Usage: `#[facet(fg::annotate(lang = "kotlin", with = "@Parcelize"))]`
+        Annotate(Annotate),
+
+        /// Mark a type, field or enum variant as deprecated in the generated code, with an
+        /// optional version and note.
+        ///
+        /// Usage: `#[facet(fg::deprecated(since = "0.3.0", note = "Use `Contact` instead"))]`
+        Deprecated(Deprecated),
+
+        /// Pin the index that identifies an enum variant in the bincode encoding, so that
+        /// inserting or removing other variants doesn't change it. Variants without an index
+        /// follow the previous one.
+        ///
+        /// Usage: `#[facet(fg::index = 3)]`
+        Index(usize),
+    }
+
+    /// A replacement type for a field, named by `fg::override`.
+    pub struct Override {
+        /// The language the override applies to: `cpp`, `csharp`, `dart`, `go`, `java`,
+        /// `kotlin`, `python`, `swift` or `typescript`.
+        pub lang: &'static str,
+        /// The type expression emitted for the field.
+        pub ty: &'static str,
+        /// A statement that serializes the field, with `{value}` standing for it.
+        pub serialize: Option<&'static str>,
+        /// An expression that deserializes the field.
+        pub deserialize: Option<&'static str>,
+    }
+
+    /// The protocols or interfaces named by `fg::conforms`.
+    pub struct Conforms {
+        /// The language they apply to: `cpp`, `csharp`, `dart`, `go`, `java`, `kotlin`,
+        /// `python`, `swift` or `typescript`.
+        pub lang: &'static str,
+        /// A comma-separated list of protocols or interfaces.
+        pub to: &'static str,
+    }
+
+    /// The annotations named by `fg::annotate`.
+    pub struct Annotate {
+        /// The language they apply to: `cpp`, `csharp`, `dart`, `go`, `java`, `kotlin`,
+        /// `python`, `swift` or `typescript`.
+        pub lang: &'static str,
+        /// A comma-separated list of annotations, each written as is on its own line.
+        pub with: &'static str,
+    }
+
+    /// The version and note of `fg::deprecated`.
+    pub struct Deprecated {
+        /// The version that deprecated it.
+        pub since: Option<&'static str>,
+        /// What to use instead, or why it is deprecated.
+        pub note: Option<&'static str>,
+    }
+
+    /// The target languages listed by `fg::skip` and `fg::only`.
+    pub struct Languages {
+        /// C++
+        pub cpp: bool,
+        /// C#
+        pub csharp: bool,
+        /// Dart
+        pub dart: bool,
+        /// Go
+        pub go: bool,
+        /// Java
+        pub java: bool,
+        /// Kotlin
+        pub kotlin: bool,
+        /// Python
+        pub python: bool,
+        /// Swift
+        pub swift: bool,
+        /// TypeScript
+        pub typescript: bool,
     }
 }
@@ -0,0 +1,319 @@
+//! The `facet-generate.toml` configuration file.
+//!
+//! ```toml
+//! registry = "schema.json"
+//! indent = 4
+//!
+//! [typescript]
+//! package = "shared-types"
+//! output = "generated/typescript"
+//! plugins = ["json"]
+//! indent = 2
+//!
+//! [swift]
+//! package = "SharedTypes"
+//! output = "generated/swift"
+//! plugins = ["bincode"]
+//! visibility = "internal"
+//!
+//! [[swift.external_packages]]
+//! namespace = "serde"
+//! url = "https://github.com/example/serde-swift"
+//! version = "1.0.0"
+//! ```
+//!
+//! Relative paths are resolved against the directory of the configuration file.
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context as _, Result, bail};
+use facet_generate::{
+    generation::{ExternalPackage, PackageLocation, indent::IndentConfig},
+    reflection::format::Visibility,
+};
+use serde::Deserialize;
+
+/// The contents of a configuration file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The schema file to generate code from.
+    pub registry: Option<PathBuf>,
+    /// The indentation of every target that doesn't set its own.
+    #[serde(default)]
+    pub indent: Indent,
+    pub swift: Option<TargetConfig>,
+    pub kotlin: Option<TargetConfig>,
+    pub typescript: Option<TargetConfig>,
+    pub csharp: Option<TargetConfig>,
+    pub dart: Option<TargetConfig>,
+    pub python: Option<TargetConfig>,
+    pub go: Option<TargetConfig>,
+    pub java: Option<TargetConfig>,
+    pub cpp: Option<TargetConfig>,
+}
+
+/// The settings of one target language.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TargetConfig {
+    /// The name of the generated package (a Swift package, Kotlin package, npm package, C#
+    /// namespace, Dart package, Python package, Go module path, Java package or C++ namespace).
+    pub package: String,
+    /// The directory the package is written to.
+    pub output: PathBuf,
+    /// The serialization plugins to generate code for.
+    #[serde(default)]
+    pub plugins: Vec<Plugin>,
+    /// Overrides the configuration file's indentation. Go packages are indented with tabs, as
+    /// `gofmt` writes them, unless this is set.
+    pub indent: Option<Indent>,
+    /// The visibility of types and fields that aren't marked `#[facet(fg::public)]`.
+    pub visibility: Option<VisibilityConfig>,
+    /// Packages that provide the types of other namespaces.
+    #[serde(default)]
+    pub external_packages: Vec<ExternalPackageConfig>,
+}
+
+/// A serialization plugin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Plugin {
+    Bincode,
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VisibilityConfig {
+    Public,
+    Internal,
+}
+
+impl From<VisibilityConfig> for Visibility {
+    fn from(visibility: VisibilityConfig) -> Self {
+        match visibility {
+            VisibilityConfig::Public => Self::Public,
+            VisibilityConfig::Internal => Self::Internal,
+        }
+    }
+}
+
+/// An indentation level: a number of spaces, or `"tab"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "IndentRepr")]
+pub enum Indent {
+    Spaces(usize),
+    Tab,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IndentRepr {
+    Spaces(usize),
+    Name(String),
+}
+
+impl TryFrom<IndentRepr> for Indent {
+    type Error = String;
+
+    fn try_from(indent: IndentRepr) -> Result<Self, Self::Error> {
+        match indent {
+            IndentRepr::Spaces(spaces) => Ok(Self::Spaces(spaces)),
+            IndentRepr::Name(name) if name == "tab" => Ok(Self::Tab),
+            IndentRepr::Name(name) => Err(format!(
+                r#"invalid indent "{name}": expected a number of spaces or "tab""#
+            )),
+        }
+    }
+}
+
+impl Default for Indent {
+    fn default() -> Self {
+        Self::Spaces(4)
+    }
+}
+
+impl From<Indent> for IndentConfig {
+    fn from(indent: Indent) -> Self {
+        match indent {
+            Indent::Spaces(spaces) => Self::Space(spaces),
+            Indent::Tab => Self::Tab,
+        }
+    }
+}
+
+/// A package that provides the types of a namespace, located by either `path` or `url`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExternalPackageConfig {
+    /// The namespace as specified in `#[facet(fg::namespace = "...")]`.
+    pub namespace: String,
+    /// A local path or, for Kotlin, a dot-separated package name.
+    pub path: Option<String>,
+    /// The URL of a remote package.
+    pub url: Option<String>,
+    /// The module to import from the package.
+    pub module: Option<String>,
+    /// The version of a published package.
+    pub version: Option<String>,
+}
+
+impl TryFrom<&ExternalPackageConfig> for ExternalPackage {
+    type Error = anyhow::Error;
+
+    fn try_from(package: &ExternalPackageConfig) -> Result<Self> {
+        let location = match (&package.path, &package.url) {
+            (Some(path), None) => PackageLocation::Path(path.clone()),
+            (None, Some(url)) => PackageLocation::Url(url.clone()),
+            _ => bail!(
+                "external package for namespace `{}` needs exactly one of `path` and `url`",
+                package.namespace
+            ),
+        };
+        Ok(Self {
+            for_namespace: package.namespace.clone(),
+            location,
+            module_name: package.module.clone(),
+            version: package.version.clone(),
+        })
+    }
+}
+
+/// A target language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+pub enum Target {
+    Swift,
+    Kotlin,
+    #[value(name = "typescript")]
+    TypeScript,
+    #[value(name = "csharp")]
+    CSharp,
+    Dart,
+    Python,
+    Go,
+    Java,
+    Cpp,
+}
+
+impl Target {
+    pub const ALL: [Self; 9] = [
+        Self::Swift,
+        Self::Kotlin,
+        Self::TypeScript,
+        Self::CSharp,
+        Self::Dart,
+        Self::Python,
+        Self::Go,
+        Self::Java,
+        Self::Cpp,
+    ];
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Swift => "swift",
+            Self::Kotlin => "kotlin",
+            Self::TypeScript => "typescript",
+            Self::CSharp => "csharp",
+            Self::Dart => "dart",
+            Self::Python => "python",
+            Self::Go => "go",
+            Self::Java => "java",
+            Self::Cpp => "cpp",
+        })
+    }
+}
+
+impl Config {
+    /// Reads a configuration file, resolving its relative paths against its directory.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let mut config =
+            Self::parse(&text).with_context(|| format!("invalid config {}", path.display()))?;
+        let base = path.parent().unwrap_or_else(|| Path::new(""));
+        config.resolve(base);
+        Ok(config)
+    }
+
+    /// Parses and validates the contents of a configuration file.
+    pub fn parse(text: &str) -> Result<Self> {
+        let config: Self = toml::from_str(text)?;
+        for target in Target::ALL {
+            let Some(settings) = config.target(target) else {
+                continue;
+            };
+            if matches!(
+                target,
+                Target::TypeScript
+                    | Target::Dart
+                    | Target::Python
+                    | Target::Go
+                    | Target::Java
+                    | Target::Cpp
+            ) && settings.visibility.is_some()
+            {
+                bail!("`visibility` isn't supported for {target}");
+            }
+            if target == Target::Cpp && settings.plugins.contains(&Plugin::Json) {
+                bail!("the `json` plugin isn't supported for {target}");
+            }
+            for package in &settings.external_packages {
+                ExternalPackage::try_from(package)?;
+            }
+        }
+        Ok(config)
+    }
+
+    /// The settings of `target`, if it is configured.
+    pub const fn target(&self, target: Target) -> Option<&TargetConfig> {
+        match target {
+            Target::Swift => self.swift.as_ref(),
+            Target::Kotlin => self.kotlin.as_ref(),
+            Target::TypeScript => self.typescript.as_ref(),
+            Target::CSharp => self.csharp.as_ref(),
+            Target::Dart => self.dart.as_ref(),
+            Target::Python => self.python.as_ref(),
+            Target::Go => self.go.as_ref(),
+            Target::Java => self.java.as_ref(),
+            Target::Cpp => self.cpp.as_ref(),
+        }
+    }
+
+    /// The configured targets, in the order of [`Target::ALL`].
+    pub fn targets(&self) -> impl Iterator<Item = (Target, &TargetConfig)> {
+        Target::ALL
+            .into_iter()
+            .filter_map(|target| self.target(target).map(|settings| (target, settings)))
+    }
+
+    fn resolve(&mut self, base: &Path) {
+        if let Some(registry) = &mut self.registry {
+            *registry = base.join(&*registry);
+        }
+        for settings in [
+            &mut self.swift,
+            &mut self.kotlin,
+            &mut self.typescript,
+            &mut self.csharp,
+            &mut self.dart,
+            &mut self.python,
+            &mut self.go,
+            &mut self.java,
+            &mut self.cpp,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            settings.output = base.join(&settings.output);
+        }
+    }
+}
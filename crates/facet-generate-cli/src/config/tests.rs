@@ -0,0 +1,178 @@
+use std::path::{Path, PathBuf};
+
+use facet_generate::generation::{ExternalPackage, PackageLocation};
+
+use super::{Config, Indent, Plugin, Target, VisibilityConfig};
+
+#[test]
+fn parses_targets() {
+    let config = Config::parse(
+        r#"
+registry = "schema.json"
+indent = "tab"
+
+[typescript]
+package = "shared-types"
+output = "generated/typescript"
+plugins = ["json"]
+indent = 2
+
+[swift]
+package = "SharedTypes"
+output = "generated/swift"
+plugins = ["bincode", "json"]
+visibility = "internal"
+
+[[swift.external_packages]]
+namespace = "serde"
+url = "https://github.com/example/serde-swift"
+version = "1.0.0"
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(config.registry, Some(PathBuf::from("schema.json")));
+    assert_eq!(config.indent, Indent::Tab);
+    assert_eq!(
+        config
+            .targets()
+            .map(|(target, _)| target)
+            .collect::<Vec<_>>(),
+        [Target::Swift, Target::TypeScript]
+    );
+
+    let typescript = config.target(Target::TypeScript).unwrap();
+    assert_eq!(typescript.plugins, [Plugin::Json]);
+    assert_eq!(typescript.indent, Some(Indent::Spaces(2)));
+
+    let swift = config.target(Target::Swift).unwrap();
+    assert_eq!(swift.visibility, Some(VisibilityConfig::Internal));
+    assert_eq!(
+        ExternalPackage::try_from(&swift.external_packages[0]).unwrap(),
+        ExternalPackage {
+            for_namespace: "serde".to_string(),
+            location: PackageLocation::Url("https://github.com/example/serde-swift".to_string()),
+            module_name: None,
+            version: Some("1.0.0".to_string()),
+        }
+    );
+}
+
+#[test]
+fn resolves_paths_against_config_directory() {
+    let mut config = Config::parse(
+        r#"
+registry = "schema.json"
+
+[kotlin]
+package = "com.example"
+output = "kotlin"
+"#,
+    )
+    .unwrap();
+    config.resolve(Path::new("project"));
+
+    assert_eq!(config.registry, Some(PathBuf::from("project/schema.json")));
+    assert_eq!(
+        config.target(Target::Kotlin).unwrap().output,
+        PathBuf::from("project/kotlin")
+    );
+}
+
+#[test]
+fn rejects_invalid_settings() {
+    let error = |text| Config::parse(text).unwrap_err().to_string();
+
+    assert!(error("indent = \"wide\"").contains(r#"invalid indent "wide""#));
+    assert_eq!(
+        error(
+            r#"
+[typescript]
+package = "types"
+output = "ts"
+visibility = "internal"
+"#
+        ),
+        "`visibility` isn't supported for typescript"
+    );
+    assert_eq!(
+        error(
+            r#"
+[dart]
+package = "types"
+output = "dart"
+visibility = "public"
+"#
+        ),
+        "`visibility` isn't supported for dart"
+    );
+    assert_eq!(
+        error(
+            r#"
+[python]
+package = "types"
+output = "python"
+visibility = "public"
+"#
+        ),
+        "`visibility` isn't supported for python"
+    );
+    assert_eq!(
+        error(
+            r#"
+[go]
+package = "example.com/types"
+output = "go"
+visibility = "public"
+"#
+        ),
+        "`visibility` isn't supported for go"
+    );
+    assert_eq!(
+        error(
+            r#"
+[java]
+package = "com.example.types"
+output = "java"
+visibility = "public"
+"#
+        ),
+        "`visibility` isn't supported for java"
+    );
+    assert_eq!(
+        error(
+            r#"
+[cpp]
+package = "types"
+output = "cpp"
+visibility = "public"
+"#
+        ),
+        "`visibility` isn't supported for cpp"
+    );
+    assert_eq!(
+        error(
+            r#"
+[cpp]
+package = "types"
+output = "cpp"
+plugins = ["bincode", "json"]
+"#
+        ),
+        "the `json` plugin isn't supported for cpp"
+    );
+    assert_eq!(
+        error(
+            r#"
+[csharp]
+package = "Types"
+output = "cs"
+
+[[csharp.external_packages]]
+namespace = "other"
+"#
+        ),
+        "external package for namespace `other` needs exactly one of `path` and `url`"
+    );
+    assert!(error("[swift]\npackage = \"Types\"").contains("missing field `output`"));
+}
@@ -0,0 +1,290 @@
+//! Runs the installers of the configured targets, and compares or prints their output.
+
+use std::{
+    fs, io,
+    path::{Component, Path, PathBuf},
+};
+
+use anyhow::{Context as _, Result, bail};
+use facet_generate::{
+    Registry,
+    generation::{
+        ExternalPackage, bincode::BincodePlugin, cpp, csharp, dart, go, indent::IndentConfig, java,
+        json::JsonPlugin, kotlin, python, swift, typescript,
+    },
+    reflection::format::Visibility,
+};
+
+use crate::config::{Indent, Plugin, Target, TargetConfig};
+
+/// The file listing the files that [`Job::generate`] wrote to an output directory, so that they
+/// can be removed once they are no longer generated.
+const MANIFEST: &str = ".facet-generate-files";
+
+/// A file of an output directory that doesn't match the generated package.
+#[derive(Debug, PartialEq, Eq)]
+pub enum StaleFile {
+    /// A generated file that is missing or has other content.
+    OutOfDate(PathBuf),
+    /// A file that an earlier run generated, but that isn't generated anymore.
+    Leftover(PathBuf),
+}
+
+/// The settings of one target, ready to be passed to its installer.
+pub struct Job<'a> {
+    pub target: Target,
+    pub settings: &'a TargetConfig,
+    pub indent: Indent,
+}
+
+impl Job<'_> {
+    /// Writes the package of this target to `dir`.
+    pub fn install(&self, registry: &Registry, dir: &Path) -> Result<()> {
+        let package = self.settings.package.as_str();
+        let packages = self
+            .settings
+            .external_packages
+            .iter()
+            .map(ExternalPackage::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        let default_indent = match self.target {
+            Target::Go => Indent::Tab,
+            _ => self.indent,
+        };
+        let indent = IndentConfig::from(self.settings.indent.unwrap_or(default_indent));
+        let visibility = self
+            .settings
+            .visibility
+            .map_or(Visibility::Public, Visibility::from);
+        let plugins = &self.settings.plugins;
+
+        // The installers have no common trait, so each is configured the same way in turn.
+        macro_rules! install {
+            ($installer:expr) => {{
+                let mut installer = $installer.external_packages(&packages).indent(indent);
+                for plugin in plugins {
+                    installer = match plugin {
+                        Plugin::Bincode => installer.plugin(BincodePlugin),
+                        Plugin::Json => installer.plugin(JsonPlugin),
+                    };
+                }
+                installer.generate(registry)
+            }};
+        }
+
+        match self.target {
+            Target::Swift => {
+                install!(swift::Installer::new(package, dir).default_visibility(visibility))
+            }
+            Target::Kotlin => {
+                install!(kotlin::Installer::new(package, dir).default_visibility(visibility))
+            }
+            Target::TypeScript => install!(typescript::Installer::new(package, dir)),
+            Target::CSharp => {
+                install!(csharp::Installer::new(package, dir).default_visibility(visibility))
+            }
+            Target::Dart => install!(dart::Installer::new(package, dir)),
+            Target::Python => install!(python::Installer::new(package, dir)),
+            Target::Go => install!(go::Installer::new(package, dir)),
+            Target::Java => install!(java::Installer::new(package, dir)),
+            Target::Cpp => {
+                // C++ has no JSON plugin, which `Config::parse` rejects.
+                let mut installer = cpp::Installer::new(package, dir)
+                    .external_packages(&packages)
+                    .indent(indent);
+                if plugins.contains(&Plugin::Bincode) {
+                    installer = installer.plugin(BincodePlugin);
+                }
+                installer.generate(registry)
+            }
+        }
+        .with_context(|| format!("failed to generate {}", self.target))
+    }
+
+    /// Writes the package of this target to its output directory, removing the files that an
+    /// earlier run wrote there but that are no longer generated.
+    pub fn generate(&self, registry: &Registry) -> Result<()> {
+        let fresh = tempfile::tempdir()?;
+        self.install(registry, fresh.path())?;
+
+        let output = &self.settings.output;
+        let generated = relative_files(fresh.path())?;
+        for relative in &generated {
+            let file = output.join(relative);
+            if let Some(parent) = file.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(fresh.path().join(relative), &file)
+                .with_context(|| format!("failed to write {}", file.display()))?;
+        }
+        for relative in self.manifest()? {
+            if !generated.contains(&relative) {
+                remove_file(output, &relative)?;
+            }
+        }
+        let manifest = generated
+            .iter()
+            .map(|relative| manifest_entry(relative) + "\n")
+            .collect::<String>();
+        fs::write(output.join(MANIFEST), manifest)?;
+        Ok(())
+    }
+
+    /// The files of the output directory that differ from what would be generated, are missing
+    /// from it, or were written by an earlier run but are no longer generated.
+    pub fn stale_files(&self, registry: &Registry) -> Result<Vec<StaleFile>> {
+        let fresh = tempfile::tempdir()?;
+        self.install(registry, fresh.path())?;
+
+        let output = &self.settings.output;
+        let generated = relative_files(fresh.path())?;
+        let mut stale = vec![];
+        for relative in &generated {
+            let existing = output.join(relative);
+            if fs::read(&existing).ok() != Some(fs::read(fresh.path().join(relative))?) {
+                stale.push(StaleFile::OutOfDate(existing));
+            }
+        }
+        for relative in self.manifest()? {
+            let existing = output.join(&relative);
+            if !generated.contains(&relative) && existing.exists() {
+                stale.push(StaleFile::Leftover(existing));
+            }
+        }
+        Ok(stale)
+    }
+
+    /// The files, relative to the output directory, that the last run of
+    /// [`generate`](Self::generate) wrote there.
+    fn manifest(&self) -> Result<Vec<PathBuf>> {
+        let path = self.settings.output.join(MANIFEST);
+        let manifest = match fs::read_to_string(&path) {
+            Ok(manifest) => manifest,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(error) => {
+                return Err(error).with_context(|| format!("failed to read {}", path.display()));
+            }
+        };
+        // Entries that could name a file outside of the output directory are ignored.
+        Ok(manifest
+            .lines()
+            .map(|line| line.split('/').collect::<PathBuf>())
+            .filter(|relative| {
+                !relative.as_os_str().is_empty()
+                    && relative
+                        .components()
+                        .all(|component| matches!(component, Component::Normal(_)))
+            })
+            .collect())
+    }
+
+    /// The generated source of `module`, which is either the package itself or a namespace.
+    pub fn module_source(&self, registry: &Registry, module: &str) -> Result<String> {
+        let fresh = tempfile::tempdir()?;
+        self.install(registry, fresh.path())?;
+
+        let extension = match self.target {
+            Target::Swift => "swift",
+            Target::Kotlin => "kt",
+            Target::TypeScript => "ts",
+            Target::CSharp => "cs",
+            Target::Dart => "dart",
+            Target::Python => "py",
+            Target::Go => "go",
+            Target::Java => "java",
+            Target::Cpp => "hpp",
+        };
+        // Installers name a module's file after the last segment of its (dotted, for Go
+        // slashed, or for C++ `::`-separated) name, in the case conventions of their language.
+        let name = normalize(module.rsplit(['.', '/', ':']).next().unwrap_or(module));
+        if self.target == Target::Java {
+            // Java writes each type to a file of its own, in the directory of its package.
+            let sources = files(fresh.path())?
+                .into_iter()
+                .filter(|file| {
+                    file.extension().is_some_and(|ext| ext == extension)
+                        && file
+                            .parent()
+                            .and_then(Path::file_name)
+                            .is_some_and(|dir| normalize(&dir.to_string_lossy()) == name)
+                })
+                .map(fs::read_to_string)
+                .collect::<io::Result<Vec<_>>>()?;
+            if sources.is_empty() {
+                bail!("no module `{module}` generated for {}", self.target);
+            }
+            return Ok(sources.join("\n"));
+        }
+        let found = files(fresh.path())?.into_iter().find(|file| {
+            file.extension().is_some_and(|ext| ext == extension)
+                && file
+                    .file_stem()
+                    .is_some_and(|stem| normalize(&stem.to_string_lossy()) == name)
+        });
+        let Some(file) = found else {
+            bail!("no module `{module}` generated for {}", self.target);
+        };
+        Ok(fs::read_to_string(file)?)
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// The manifest entry of a file: its path relative to the output directory, separated by `/`.
+fn manifest_entry(relative: &Path) -> String {
+    relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Removes the file at `relative` in `output`, if it is there, and the directories that this
+/// leaves empty.
+fn remove_file(output: &Path, relative: &Path) -> Result<()> {
+    let file = output.join(relative);
+    match fs::remove_file(&file) {
+        Ok(()) => {}
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(error) => {
+            return Err(error).with_context(|| format!("failed to remove {}", file.display()));
+        }
+    }
+    for dir in relative.ancestors().skip(1) {
+        if dir.as_os_str().is_empty() || fs::remove_dir(output.join(dir)).is_err() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Every file under `dir`, relative to it and sorted.
+fn relative_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    files(dir)?
+        .into_iter()
+        .map(|file| Ok(file.strip_prefix(dir)?.to_path_buf()))
+        .collect()
+}
+
+/// Every file under `dir`, sorted.
+fn files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
@@ -0,0 +1,212 @@
+//! `facet-generate` — generates Swift, Kotlin, TypeScript, C#, Dart, Python, Go, Java and C++
+//! packages from a schema file
+//! written by [`facet_generate::schema::save`], as described by a `facet-generate.toml`
+//! configuration file, and checks schema files for breaking changes.
+
+mod config;
+mod generate;
+
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use anyhow::{Context as _, Result, bail};
+use clap::{Parser, Subcommand, ValueEnum};
+use facet_generate::{
+    Registry,
+    compat::{self, Encoding},
+    reflection::format::Namespace,
+    schema,
+};
+
+use crate::{
+    config::{Config, Target},
+    generate::{Job, StaleFile},
+};
+
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// The configuration file.
+    #[arg(short, long, global = true, default_value = "facet-generate.toml")]
+    config: PathBuf,
+
+    /// The schema file, instead of the one named by the configuration file.
+    #[arg(short, long, global = true)]
+    registry: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate the packages of the configured targets.
+    Generate {
+        /// Only generate these targets.
+        #[arg(short, long)]
+        target: Vec<Target>,
+    },
+    /// List the types in the schema, by namespace.
+    List,
+    /// Print the generated source of a module: the package itself or one of its namespaces.
+    Print { target: Target, module: String },
+    /// Check that the generated packages are up to date, failing if they aren't.
+    Check {
+        /// Only check these targets.
+        #[arg(short, long)]
+        target: Vec<Target>,
+    },
+    /// Compare two schema files, failing if the new one breaks clients of the old one.
+    Compat {
+        /// The schema that existing clients were built with.
+        old: PathBuf,
+        /// The current schema.
+        new: PathBuf,
+        /// Only fail on changes that break these encodings.
+        #[arg(short, long)]
+        encoding: Vec<EncodingArg>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum EncodingArg {
+    Bincode,
+    Json,
+}
+
+impl From<EncodingArg> for Encoding {
+    fn from(encoding: EncodingArg) -> Self {
+        match encoding {
+            EncodingArg::Bincode => Self::Bincode,
+            EncodingArg::Json => Self::Json,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("error: {error:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<ExitCode> {
+    if matches!(cli.command, Command::List)
+        && let Some(path) = &cli.registry
+    {
+        list(&load(path)?);
+        return Ok(ExitCode::SUCCESS);
+    }
+    if let Command::Compat { old, new, encoding } = &cli.command {
+        return compat(old, new, encoding);
+    }
+
+    let config = Config::load(&cli.config)?;
+    let Some(path) = cli.registry.as_ref().or(config.registry.as_ref()) else {
+        bail!("no schema file: set `registry` in the config file or pass --registry");
+    };
+    let registry = load(path)?;
+
+    match cli.command {
+        Command::Generate { target } => {
+            for job in jobs(&config, &target)? {
+                job.generate(&registry)?;
+                println!(
+                    "generated {} in {}",
+                    job.target,
+                    job.settings.output.display()
+                );
+            }
+        }
+        Command::List => list(&registry),
+        Command::Compat { .. } => unreachable!("handled without a configuration file"),
+        Command::Print { target, module } => {
+            let job = jobs(&config, &[target])?.remove(0);
+            print!("{}", job.module_source(&registry, &module)?);
+        }
+        Command::Check { target } => {
+            let mut up_to_date = true;
+            for job in jobs(&config, &target)? {
+                for file in job.stale_files(&registry)? {
+                    match file {
+                        StaleFile::OutOfDate(file) => {
+                            println!("{}: {} is out of date", job.target, file.display());
+                        }
+                        StaleFile::Leftover(file) => {
+                            println!("{}: {} is no longer generated", job.target, file.display());
+                        }
+                    }
+                    up_to_date = false;
+                }
+            }
+            if !up_to_date {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn load(path: &Path) -> Result<Registry> {
+    schema::load(path).with_context(|| format!("failed to load {}", path.display()))
+}
+
+/// The configured targets, or those of `only` if it isn't empty.
+fn jobs<'a>(config: &'a Config, only: &[Target]) -> Result<Vec<Job<'a>>> {
+    for target in only {
+        if config.target(*target).is_none() {
+            bail!("{target} isn't configured");
+        }
+    }
+    let jobs = config
+        .targets()
+        .filter(|(target, _)| only.is_empty() || only.contains(target))
+        .map(|(target, settings)| Job {
+            target,
+            settings,
+            indent: config.indent,
+        })
+        .collect::<Vec<_>>();
+    if jobs.is_empty() {
+        bail!("no targets configured");
+    }
+    Ok(jobs)
+}
+
+/// Prints the changes between two schema files, and fails if any of them breaks one of
+/// `encodings` (or any encoding, if it's empty).
+fn compat(old: &Path, new: &Path, encodings: &[EncodingArg]) -> Result<ExitCode> {
+    let report = compat::check(&load(old)?, &load(new)?);
+    print!("{report}");
+    let encodings = if encodings.is_empty() {
+        Encoding::ALL.to_vec()
+    } else {
+        encodings.iter().copied().map(Encoding::from).collect()
+    };
+    if encodings
+        .into_iter()
+        .any(|encoding| report.is_breaking(encoding))
+    {
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn list(registry: &Registry) {
+    let mut namespace = None;
+    for name in registry.keys() {
+        if namespace != Some(&name.namespace) {
+            namespace = Some(&name.namespace);
+            match &name.namespace {
+                Namespace::Root => println!("(root)"),
+                Namespace::Named(namespace) => println!("{namespace}"),
+            }
+        }
+        println!("  {}", name.name);
+    }
+}
@@ -0,0 +1,231 @@
+use std::{
+    fs,
+    path::Path,
+    process::{Command, Output},
+};
+
+use facet::Facet;
+use facet_generate::{reflection::RegistryBuilder, schema};
+
+#[derive(Facet)]
+#[facet(facet_generate::namespace = "events")]
+struct Event {
+    id: u32,
+    name: String,
+}
+
+#[derive(Facet)]
+struct Feed {
+    events: Vec<Event>,
+}
+
+/// A project with a schema file and a configuration for TypeScript, Kotlin, Java and C++.
+fn project() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let registry = RegistryBuilder::new()
+        .add_type::<Feed>()
+        .unwrap()
+        .build()
+        .unwrap();
+    schema::save(&registry, dir.path().join("schema.yaml")).unwrap();
+    fs::write(
+        dir.path().join("facet-generate.toml"),
+        r#"
+registry = "schema.yaml"
+
+[typescript]
+package = "feed"
+output = "generated/typescript"
+plugins = ["json"]
+indent = 2
+
+[kotlin]
+package = "com.example.feed"
+output = "generated/kotlin"
+
+[java]
+package = "com.example.feed"
+output = "generated/java"
+
+[cpp]
+package = "feed"
+output = "generated/cpp"
+plugins = ["bincode"]
+"#,
+    )
+    .unwrap();
+    dir
+}
+
+fn run(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_facet-generate"))
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn lists_types() {
+    let dir = project();
+    let output = run(dir.path(), &["list"]);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "(root)\n  Feed\nevents\n  Event\n");
+
+    let output = run(dir.path(), &["list", "--registry", "schema.yaml"]);
+    assert_eq!(stdout(&output), "(root)\n  Feed\nevents\n  Event\n");
+}
+
+#[test]
+fn generates_and_checks() {
+    let dir = project();
+
+    let output = run(dir.path(), &["check"]);
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("typescript: "));
+
+    let output = run(dir.path(), &["generate"]);
+    assert!(output.status.success(), "{output:?}");
+    let module = dir.path().join("generated/typescript/events.ts");
+    assert!(
+        fs::read_to_string(&module)
+            .unwrap()
+            .contains("\n  constructor (public id: uint32")
+    );
+    assert!(dir.path().join("generated/kotlin").is_dir());
+
+    let output = run(dir.path(), &["check"]);
+    assert!(output.status.success(), "{output:?}");
+
+    fs::write(&module, "// edited\n").unwrap();
+    let output = run(dir.path(), &["check", "--target", "kotlin"]);
+    assert!(output.status.success(), "{output:?}");
+    let output = run(dir.path(), &["check", "--target", "typescript"]);
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("events.ts is out of date"));
+}
+
+#[test]
+fn removes_files_that_are_no_longer_generated() {
+    #[derive(Facet)]
+    struct Summary {
+        count: u32,
+    }
+
+    let dir = project();
+    let output = run(dir.path(), &["generate", "--target", "typescript"]);
+    assert!(output.status.success(), "{output:?}");
+    let output_dir = dir.path().join("generated/typescript");
+    let events = output_dir.join("events.ts");
+    assert!(events.is_file());
+
+    // Files that `generate` didn't write are never reported or removed.
+    fs::create_dir_all(output_dir.join("node_modules/serde")).unwrap();
+    fs::write(output_dir.join("node_modules/serde/index.js"), "").unwrap();
+    fs::create_dir_all(output_dir.join("build")).unwrap();
+    fs::write(output_dir.join("build/events.js"), "").unwrap();
+    let output = run(dir.path(), &["check", "--target", "typescript"]);
+    assert!(output.status.success(), "{output:?}");
+
+    // The `events` namespace is gone from the new schema.
+    let registry = RegistryBuilder::new()
+        .add_type::<Summary>()
+        .unwrap()
+        .build()
+        .unwrap();
+    schema::save(&registry, dir.path().join("schema.yaml")).unwrap();
+    let output = run(dir.path(), &["check", "--target", "typescript"]);
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains(&format!(
+        "typescript: {} is no longer generated\n",
+        Path::new("generated/typescript/events.ts").display()
+    )));
+
+    let output = run(dir.path(), &["generate", "--target", "typescript"]);
+    assert!(output.status.success(), "{output:?}");
+    assert!(!events.exists());
+    assert!(output_dir.join("node_modules/serde/index.js").is_file());
+    assert!(output_dir.join("build/events.js").is_file());
+    let output = run(dir.path(), &["check", "--target", "typescript"]);
+    assert!(output.status.success(), "{output:?}");
+}
+
+#[test]
+fn prints_a_module() {
+    let dir = project();
+    let output = run(dir.path(), &["print", "typescript", "events"]);
+    assert!(output.status.success(), "{output:?}");
+    assert!(stdout(&output).contains("export class Event"));
+    assert!(!dir.path().join("generated").exists());
+
+    let output = run(dir.path(), &["print", "kotlin", "com.example.feed"]);
+    assert!(stdout(&output).contains("data class Feed("));
+
+    let output = run(dir.path(), &["print", "java", "events"]);
+    assert!(stdout(&output).contains("public record Event(int id, String name)"));
+
+    let output = run(dir.path(), &["print", "cpp", "events"]);
+    assert!(stdout(&output).contains("namespace feed::events {"));
+
+    let output = run(dir.path(), &["print", "typescript", "missing"]);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "error: no module `missing` generated for typescript\n"
+    );
+}
+
+#[test]
+fn rejects_unconfigured_targets() {
+    let dir = project();
+    let output = run(dir.path(), &["generate", "--target", "swift"]);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "error: swift isn't configured\n"
+    );
+}
+
+#[test]
+fn checks_compatibility() {
+    #[derive(Facet)]
+    #[facet(facet_generate::namespace = "events")]
+    struct Event {
+        name: String,
+        id: u32,
+    }
+
+    #[derive(Facet)]
+    struct Feed {
+        events: Vec<Event>,
+    }
+
+    let dir = project();
+    let registry = RegistryBuilder::new()
+        .add_type::<Feed>()
+        .unwrap()
+        .build()
+        .unwrap();
+    schema::save(&registry, dir.path().join("next.json")).unwrap();
+
+    let output = run(dir.path(), &["compat", "schema.yaml", "schema.yaml"]);
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(stdout(&output), "no changes\n");
+
+    let output = run(dir.path(), &["compat", "schema.yaml", "next.json"]);
+    assert!(!output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "breaks bincode: events.Event.id: field moved from position 0 to 1\n\
+         breaks bincode: events.Event.name: field moved from position 1 to 0\n"
+    );
+    let output = run(
+        dir.path(),
+        &["compat", "schema.yaml", "next.json", "--encoding", "json"],
+    );
+    assert!(output.status.success(), "{output:?}");
+}
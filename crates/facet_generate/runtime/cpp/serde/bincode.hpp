@@ -0,0 +1,364 @@
+// The bincode encoding of the serde runtime.
+//
+// `BincodeSerializer` and `BincodeDeserializer` write and read the format of
+// Rust's `bincode` crate with its default (legacy) configuration: little
+// endian fixed-size integers, and 64-bit lengths.
+#pragma once
+
+#include <cstring>
+#include <limits>
+
+#include "serde.hpp"
+
+namespace serde {
+
+/// Writes values in bincode.
+class BincodeSerializer {
+public:
+    BincodeSerializer() = default;
+
+    /// A serializer that rejects values nested in more than
+    /// `max_container_depth` structs, enums and durations.
+    explicit BincodeSerializer(std::size_t max_container_depth)
+        : container_depth_budget_(max_container_depth) {}
+
+    void serialize_unit(const std::monostate &) {}
+
+    void serialize_bool(bool value) { serialize_u8(value ? 1 : 0); }
+
+    void serialize_u8(std::uint8_t value) { bytes_.push_back(value); }
+    void serialize_u16(std::uint16_t value) { write_le(value); }
+    void serialize_u32(std::uint32_t value) { write_le(value); }
+    void serialize_u64(std::uint64_t value) { write_le(value); }
+
+    void serialize_u128(const uint128_t &value) {
+        serialize_u64(value.low);
+        serialize_u64(value.high);
+    }
+
+    void serialize_i8(std::int8_t value) { serialize_u8(static_cast<std::uint8_t>(value)); }
+    void serialize_i16(std::int16_t value) { serialize_u16(static_cast<std::uint16_t>(value)); }
+    void serialize_i32(std::int32_t value) { serialize_u32(static_cast<std::uint32_t>(value)); }
+    void serialize_i64(std::int64_t value) { serialize_u64(static_cast<std::uint64_t>(value)); }
+
+    void serialize_i128(const int128_t &value) {
+        serialize_u64(value.low);
+        serialize_i64(value.high);
+    }
+
+    void serialize_f32(float value) {
+        std::uint32_t bits;
+        std::memcpy(&bits, &value, sizeof(bits));
+        serialize_u32(bits);
+    }
+
+    void serialize_f64(double value) {
+        std::uint64_t bits;
+        std::memcpy(&bits, &value, sizeof(bits));
+        serialize_u64(bits);
+    }
+
+    /// Writes a `char` as its UTF-8 encoding, without a length.
+    void serialize_char(char32_t value) {
+        auto code = static_cast<std::uint32_t>(value);
+        if (code > 0x10FFFF || (code >= 0xD800 && code <= 0xDFFF)) {
+            throw serialization_error("Invalid Unicode scalar value");
+        }
+        if (code < 0x80) {
+            serialize_u8(static_cast<std::uint8_t>(code));
+        } else if (code < 0x800) {
+            serialize_u8(static_cast<std::uint8_t>(0xC0 | (code >> 6)));
+            serialize_u8(static_cast<std::uint8_t>(0x80 | (code & 0x3F)));
+        } else if (code < 0x10000) {
+            serialize_u8(static_cast<std::uint8_t>(0xE0 | (code >> 12)));
+            serialize_u8(static_cast<std::uint8_t>(0x80 | ((code >> 6) & 0x3F)));
+            serialize_u8(static_cast<std::uint8_t>(0x80 | (code & 0x3F)));
+        } else {
+            serialize_u8(static_cast<std::uint8_t>(0xF0 | (code >> 18)));
+            serialize_u8(static_cast<std::uint8_t>(0x80 | ((code >> 12) & 0x3F)));
+            serialize_u8(static_cast<std::uint8_t>(0x80 | ((code >> 6) & 0x3F)));
+            serialize_u8(static_cast<std::uint8_t>(0x80 | (code & 0x3F)));
+        }
+    }
+
+    /// Writes a string's length, then its (UTF-8) bytes.
+    void serialize_str(const std::string &value) {
+        serialize_len(value.size());
+        bytes_.insert(bytes_.end(), value.begin(), value.end());
+    }
+
+    /// Writes a UUID as a byte string of its 16 bytes.
+    void serialize_uuid(const uuid &value) {
+        serialize_len(value.bytes.size());
+        bytes_.insert(bytes_.end(), value.bytes.begin(), value.bytes.end());
+    }
+
+    void serialize_duration(const duration &value) {
+        increase_container_depth();
+        serialize_u64(value.secs);
+        serialize_u32(value.nanos);
+        decrease_container_depth();
+    }
+
+    void serialize_len(std::size_t value) { serialize_u64(static_cast<std::uint64_t>(value)); }
+
+    void serialize_variant_index(std::uint32_t value) { serialize_u32(value); }
+
+    void serialize_option_tag(bool value) { serialize_bool(value); }
+
+    void increase_container_depth() {
+        if (container_depth_budget_ == 0) {
+            throw serialization_error("Exceeded maximum container depth");
+        }
+        --container_depth_budget_;
+    }
+
+    void decrease_container_depth() { ++container_depth_budget_; }
+
+    /// The number of bytes written so far.
+    std::size_t get_buffer_offset() const { return bytes_.size(); }
+
+    /// The bytes written so far.
+    const std::vector<std::uint8_t> &bytes() const & { return bytes_; }
+
+    /// The bytes written, moved out of the serializer.
+    std::vector<std::uint8_t> bytes() && { return std::move(bytes_); }
+
+private:
+    template <typename U>
+    void write_le(U value) {
+        for (std::size_t i = 0; i < sizeof(U); ++i) {
+            bytes_.push_back(static_cast<std::uint8_t>(value >> (8 * i)));
+        }
+    }
+
+    std::vector<std::uint8_t> bytes_;
+    std::size_t container_depth_budget_ = std::numeric_limits<std::size_t>::max();
+};
+
+/// Reads values in bincode from a buffer, which must outlive it.
+class BincodeDeserializer {
+public:
+    BincodeDeserializer(const std::uint8_t *data, std::size_t size) : data_(data), size_(size) {}
+
+    explicit BincodeDeserializer(const std::vector<std::uint8_t> &input)
+        : BincodeDeserializer(input.data(), input.size()) {}
+
+    /// A deserializer that rejects values nested in more than
+    /// `max_container_depth` structs, enums and durations.
+    BincodeDeserializer(const std::uint8_t *data, std::size_t size,
+                        std::size_t max_container_depth)
+        : data_(data), size_(size), container_depth_budget_(max_container_depth) {}
+
+    std::monostate deserialize_unit() { return {}; }
+
+    bool deserialize_bool() {
+        switch (deserialize_u8()) {
+        case 0:
+            return false;
+        case 1:
+            return true;
+        default:
+            throw deserialization_error("Incorrect boolean value");
+        }
+    }
+
+    std::uint8_t deserialize_u8() { return *read(1); }
+    std::uint16_t deserialize_u16() { return read_le<std::uint16_t>(); }
+    std::uint32_t deserialize_u32() { return read_le<std::uint32_t>(); }
+    std::uint64_t deserialize_u64() { return read_le<std::uint64_t>(); }
+
+    uint128_t deserialize_u128() {
+        uint128_t value;
+        value.low = deserialize_u64();
+        value.high = deserialize_u64();
+        return value;
+    }
+
+    std::int8_t deserialize_i8() { return static_cast<std::int8_t>(deserialize_u8()); }
+    std::int16_t deserialize_i16() { return static_cast<std::int16_t>(deserialize_u16()); }
+    std::int32_t deserialize_i32() { return static_cast<std::int32_t>(deserialize_u32()); }
+    std::int64_t deserialize_i64() { return static_cast<std::int64_t>(deserialize_u64()); }
+
+    int128_t deserialize_i128() {
+        int128_t value;
+        value.low = deserialize_u64();
+        value.high = deserialize_i64();
+        return value;
+    }
+
+    float deserialize_f32() {
+        std::uint32_t bits = deserialize_u32();
+        float value;
+        std::memcpy(&value, &bits, sizeof(value));
+        return value;
+    }
+
+    double deserialize_f64() {
+        std::uint64_t bits = deserialize_u64();
+        double value;
+        std::memcpy(&value, &bits, sizeof(value));
+        return value;
+    }
+
+    /// Reads a `char` from its UTF-8 encoding.
+    char32_t deserialize_char() {
+        if (offset_ >= size_) {
+            throw deserialization_error("Input is too short");
+        }
+        std::uint8_t first = data_[offset_];
+        std::size_t len = first < 0x80 ? 1 : first < 0xE0 ? 2 : first < 0xF0 ? 3 : 4;
+        const std::uint8_t *bytes = read(len);
+        std::size_t i = 0;
+        char32_t value = decode_utf8(bytes, len, i);
+        if (i != len) {
+            throw deserialization_error("Invalid UTF-8 character");
+        }
+        return value;
+    }
+
+    /// Reads a string's length, then its bytes, which must be UTF-8.
+    std::string deserialize_str() {
+        std::size_t len = deserialize_len();
+        const std::uint8_t *bytes = read(len);
+        for (std::size_t i = 0; i < len;) {
+            decode_utf8(bytes, len, i);
+        }
+        return std::string(reinterpret_cast<const char *>(bytes), len);
+    }
+
+    /// Reads a UUID from a byte string of its 16 bytes.
+    uuid deserialize_uuid() {
+        uuid value;
+        if (deserialize_len() != value.bytes.size()) {
+            throw deserialization_error("Incorrect length of UUID");
+        }
+        std::memcpy(value.bytes.data(), read(value.bytes.size()), value.bytes.size());
+        return value;
+    }
+
+    duration deserialize_duration() {
+        increase_container_depth();
+        duration value;
+        value.secs = deserialize_u64();
+        value.nanos = deserialize_u32();
+        decrease_container_depth();
+        return value;
+    }
+
+    std::size_t deserialize_len() {
+        std::uint64_t value = deserialize_u64();
+        if (value > std::numeric_limits<std::size_t>::max()) {
+            throw deserialization_error("Incorrect length value");
+        }
+        return static_cast<std::size_t>(value);
+    }
+
+    std::uint32_t deserialize_variant_index() { return deserialize_u32(); }
+
+    bool deserialize_option_tag() { return deserialize_bool(); }
+
+    void increase_container_depth() {
+        if (container_depth_budget_ == 0) {
+            throw deserialization_error("Exceeded maximum container depth");
+        }
+        --container_depth_budget_;
+    }
+
+    void decrease_container_depth() { ++container_depth_budget_; }
+
+    /// The number of bytes read so far.
+    std::size_t get_buffer_offset() const { return offset_; }
+
+private:
+    /// The next `len` bytes of the input, which it then skips.
+    const std::uint8_t *read(std::size_t len) {
+        if (size_ - offset_ < len) {
+            throw deserialization_error("Input is too short");
+        }
+        const std::uint8_t *bytes = data_ + offset_;
+        offset_ += len;
+        return bytes;
+    }
+
+    template <typename U>
+    U read_le() {
+        const std::uint8_t *bytes = read(sizeof(U));
+        U value = 0;
+        for (std::size_t i = 0; i < sizeof(U); ++i) {
+            value |= static_cast<U>(static_cast<U>(bytes[i]) << (8 * i));
+        }
+        return value;
+    }
+
+    /// Decodes the UTF-8 character at `bytes[i]`, and moves `i` past it.
+    static char32_t decode_utf8(const std::uint8_t *bytes, std::size_t len, std::size_t &i) {
+        std::uint8_t first = bytes[i];
+        std::size_t count;
+        std::uint32_t code;
+        if (first < 0x80) {
+            count = 0;
+            code = first;
+        } else if (first >= 0xC2 && first < 0xE0) {
+            count = 1;
+            code = first & 0x1F;
+        } else if (first >= 0xE0 && first < 0xF0) {
+            count = 2;
+            code = first & 0x0F;
+        } else if (first >= 0xF0 && first < 0xF5) {
+            count = 3;
+            code = first & 0x07;
+        } else {
+            throw deserialization_error("Invalid UTF-8 character");
+        }
+        if (len - i - 1 < count) {
+            throw deserialization_error("Invalid UTF-8 character");
+        }
+        for (std::size_t k = 1; k <= count; ++k) {
+            std::uint8_t next = bytes[i + k];
+            if ((next & 0xC0) != 0x80) {
+                throw deserialization_error("Invalid UTF-8 character");
+            }
+            code = (code << 6) | (next & 0x3F);
+        }
+        static constexpr std::uint32_t minimum[] = {0, 0x80, 0x800, 0x10000};
+        if (code < minimum[count] || code > 0x10FFFF || (code >= 0xD800 && code <= 0xDFFF)) {
+            throw deserialization_error("Invalid UTF-8 character");
+        }
+        i += count + 1;
+        return static_cast<char32_t>(code);
+    }
+
+    const std::uint8_t *data_;
+    std::size_t size_;
+    std::size_t offset_ = 0;
+    std::size_t container_depth_budget_ = std::numeric_limits<std::size_t>::max();
+};
+
+/// The bincode encoding of `value`.
+template <typename T>
+std::vector<std::uint8_t> bincode_serialize(const T &value) {
+    BincodeSerializer serializer;
+    Serializable<T>::serialize(value, serializer);
+    return std::move(serializer).bytes();
+}
+
+/// Reads a value of `T` from the whole of a bincode buffer, e.g. one passed
+/// across a C ABI.
+template <typename T>
+T bincode_deserialize(const std::uint8_t *data, std::size_t size) {
+    BincodeDeserializer deserializer(data, size);
+    T value = Deserializable<T>::deserialize(deserializer);
+    if (deserializer.get_buffer_offset() < size) {
+        throw deserialization_error("Some input bytes were not read");
+    }
+    return value;
+}
+
+/// Reads a value of `T` from the whole of `input`.
+template <typename T>
+T bincode_deserialize(const std::vector<std::uint8_t> &input) {
+    return bincode_deserialize<T>(input.data(), input.size());
+}
+
+} // namespace serde
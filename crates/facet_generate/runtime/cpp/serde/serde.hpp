@@ -0,0 +1,393 @@
+// The serde runtime of generated C++ types.
+//
+// Declares the types that some of Rust's formats map to (128-bit integers,
+// UUIDs, durations and the `value_ptr` of recursive types), and the
+// `Serializable` / `Deserializable` traits that write and read a value of any
+// generated or standard type with the serializer of an encoding, such as the
+// one in `bincode.hpp`.
+#pragma once
+
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <map>
+#include <memory>
+#include <optional>
+#include <set>
+#include <stdexcept>
+#include <string>
+#include <tuple>
+#include <utility>
+#include <variant>
+#include <vector>
+
+namespace serde {
+
+/// Thrown when a value can't be serialized.
+class serialization_error : public std::runtime_error {
+public:
+    using std::runtime_error::runtime_error;
+};
+
+/// Thrown when the input can't be deserialized.
+class deserialization_error : public std::runtime_error {
+public:
+    using std::runtime_error::runtime_error;
+};
+
+/// A Rust `u128`, as its high and low 64 bits.
+struct uint128_t {
+    std::uint64_t high = 0;
+    std::uint64_t low = 0;
+
+    friend bool operator==(const uint128_t &lhs, const uint128_t &rhs) {
+        return std::tie(lhs.high, lhs.low) == std::tie(rhs.high, rhs.low);
+    }
+    friend bool operator!=(const uint128_t &lhs, const uint128_t &rhs) {
+        return !(lhs == rhs);
+    }
+    friend bool operator<(const uint128_t &lhs, const uint128_t &rhs) {
+        return std::tie(lhs.high, lhs.low) < std::tie(rhs.high, rhs.low);
+    }
+};
+
+/// A Rust `i128`, as its (signed) high and low 64 bits.
+struct int128_t {
+    std::int64_t high = 0;
+    std::uint64_t low = 0;
+
+    friend bool operator==(const int128_t &lhs, const int128_t &rhs) {
+        return std::tie(lhs.high, lhs.low) == std::tie(rhs.high, rhs.low);
+    }
+    friend bool operator!=(const int128_t &lhs, const int128_t &rhs) {
+        return !(lhs == rhs);
+    }
+    friend bool operator<(const int128_t &lhs, const int128_t &rhs) {
+        return std::tie(lhs.high, lhs.low) < std::tie(rhs.high, rhs.low);
+    }
+};
+
+/// A UUID, as its 16 bytes in the order of its text form.
+struct uuid {
+    std::array<std::uint8_t, 16> bytes{};
+
+    friend bool operator==(const uuid &lhs, const uuid &rhs) {
+        return lhs.bytes == rhs.bytes;
+    }
+    friend bool operator!=(const uuid &lhs, const uuid &rhs) {
+        return !(lhs == rhs);
+    }
+    friend bool operator<(const uuid &lhs, const uuid &rhs) {
+        return lhs.bytes < rhs.bytes;
+    }
+};
+
+/// A Rust `std::time::Duration`: whole seconds, and the nanoseconds of the
+/// last second.
+struct duration {
+    std::uint64_t secs = 0;
+    std::uint32_t nanos = 0;
+
+    friend bool operator==(const duration &lhs, const duration &rhs) {
+        return std::tie(lhs.secs, lhs.nanos) == std::tie(rhs.secs, rhs.nanos);
+    }
+    friend bool operator!=(const duration &lhs, const duration &rhs) {
+        return !(lhs == rhs);
+    }
+    friend bool operator<(const duration &lhs, const duration &rhs) {
+        return std::tie(lhs.secs, lhs.nanos) < std::tie(rhs.secs, rhs.nanos);
+    }
+};
+
+/// An owning pointer with the value semantics of the `T` it points to: it is
+/// copied by copying its value, and compared by comparing it.
+///
+/// Generated types hold the types that they are (mutually) recursive with in
+/// a `value_ptr`, as Rust holds them in a `Box`, since a type can't contain a
+/// type that is still being declared. It converts implicitly from a `T`.
+template <typename T>
+class value_ptr {
+public:
+    value_ptr() = default;
+    value_ptr(T value) : ptr_(std::make_unique<T>(std::move(value))) {}
+    value_ptr(const value_ptr &other)
+        : ptr_(other.ptr_ ? std::make_unique<T>(*other.ptr_) : nullptr) {}
+    value_ptr(value_ptr &&) noexcept = default;
+    ~value_ptr() = default;
+
+    value_ptr &operator=(const value_ptr &other) {
+        if (this != &other) {
+            ptr_ = other.ptr_ ? std::make_unique<T>(*other.ptr_) : nullptr;
+        }
+        return *this;
+    }
+    value_ptr &operator=(value_ptr &&) noexcept = default;
+
+    T &operator*() { return *ptr_; }
+    const T &operator*() const { return *ptr_; }
+    T *operator->() { return ptr_.get(); }
+    const T *operator->() const { return ptr_.get(); }
+    T *get() { return ptr_.get(); }
+    const T *get() const { return ptr_.get(); }
+    explicit operator bool() const noexcept { return ptr_ != nullptr; }
+
+    friend bool operator==(const value_ptr &lhs, const value_ptr &rhs) {
+        if (!lhs || !rhs) {
+            return !lhs && !rhs;
+        }
+        return *lhs == *rhs;
+    }
+    friend bool operator!=(const value_ptr &lhs, const value_ptr &rhs) {
+        return !(lhs == rhs);
+    }
+    friend bool operator<(const value_ptr &lhs, const value_ptr &rhs) {
+        if (!lhs || !rhs) {
+            return !lhs && rhs;
+        }
+        return *lhs < *rhs;
+    }
+
+private:
+    std::unique_ptr<T> ptr_;
+};
+
+/// Writes a value of `T` with a serializer. Generated types serialize
+/// themselves with their `serialize` member function; the specializations
+/// below handle the types that Rust's formats map to.
+template <typename T>
+struct Serializable {
+    template <typename Serializer>
+    static void serialize(const T &value, Serializer &serializer) {
+        value.serialize(serializer);
+    }
+};
+
+/// Reads a value of `T` with a deserializer. Generated types deserialize
+/// themselves with their static `deserialize` member function; the
+/// specializations below handle the types that Rust's formats map to.
+template <typename T>
+struct Deserializable {
+    template <typename Deserializer>
+    static T deserialize(Deserializer &deserializer) {
+        return T::deserialize(deserializer);
+    }
+};
+
+/// Writes `value` with `serializer`.
+template <typename T, typename Serializer>
+void serialize(const T &value, Serializer &serializer) {
+    Serializable<T>::serialize(value, serializer);
+}
+
+/// Reads a value of `T` with `deserializer`.
+template <typename T, typename Deserializer>
+T deserialize(Deserializer &deserializer) {
+    return Deserializable<T>::deserialize(deserializer);
+}
+
+// Primitives, which the serializer of each encoding writes in its own way.
+#define SERDE_PRIMITIVE(TYPE, METHOD)                                        \
+    template <>                                                              \
+    struct Serializable<TYPE> {                                              \
+        template <typename Serializer>                                       \
+        static void serialize(const TYPE &value, Serializer &serializer) {   \
+            serializer.serialize_##METHOD(value);                            \
+        }                                                                    \
+    };                                                                       \
+    template <>                                                              \
+    struct Deserializable<TYPE> {                                            \
+        template <typename Deserializer>                                     \
+        static TYPE deserialize(Deserializer &deserializer) {                \
+            return deserializer.deserialize_##METHOD();                      \
+        }                                                                    \
+    };
+
+SERDE_PRIMITIVE(std::monostate, unit)
+SERDE_PRIMITIVE(bool, bool)
+SERDE_PRIMITIVE(std::int8_t, i8)
+SERDE_PRIMITIVE(std::int16_t, i16)
+SERDE_PRIMITIVE(std::int32_t, i32)
+SERDE_PRIMITIVE(std::int64_t, i64)
+SERDE_PRIMITIVE(int128_t, i128)
+SERDE_PRIMITIVE(std::uint8_t, u8)
+SERDE_PRIMITIVE(std::uint16_t, u16)
+SERDE_PRIMITIVE(std::uint32_t, u32)
+SERDE_PRIMITIVE(std::uint64_t, u64)
+SERDE_PRIMITIVE(uint128_t, u128)
+SERDE_PRIMITIVE(float, f32)
+SERDE_PRIMITIVE(double, f64)
+SERDE_PRIMITIVE(char32_t, char)
+SERDE_PRIMITIVE(std::string, str)
+SERDE_PRIMITIVE(uuid, uuid)
+SERDE_PRIMITIVE(duration, duration)
+
+#undef SERDE_PRIMITIVE
+
+/// An option: a tag, then the value if there is one.
+template <typename T>
+struct Serializable<std::optional<T>> {
+    template <typename Serializer>
+    static void serialize(const std::optional<T> &value, Serializer &serializer) {
+        serializer.serialize_option_tag(value.has_value());
+        if (value) {
+            Serializable<T>::serialize(*value, serializer);
+        }
+    }
+};
+
+template <typename T>
+struct Deserializable<std::optional<T>> {
+    template <typename Deserializer>
+    static std::optional<T> deserialize(Deserializer &deserializer) {
+        if (!deserializer.deserialize_option_tag()) {
+            return std::nullopt;
+        }
+        return Deserializable<T>::deserialize(deserializer);
+    }
+};
+
+/// A sequence: its length, then its elements.
+template <typename T, typename Allocator>
+struct Serializable<std::vector<T, Allocator>> {
+    template <typename Serializer>
+    static void serialize(const std::vector<T, Allocator> &value, Serializer &serializer) {
+        serializer.serialize_len(value.size());
+        for (const auto &element : value) {
+            Serializable<T>::serialize(element, serializer);
+        }
+    }
+};
+
+template <typename T, typename Allocator>
+struct Deserializable<std::vector<T, Allocator>> {
+    template <typename Deserializer>
+    static std::vector<T, Allocator> deserialize(Deserializer &deserializer) {
+        std::size_t len = deserializer.deserialize_len();
+        std::vector<T, Allocator> value;
+        for (std::size_t i = 0; i < len; ++i) {
+            value.push_back(Deserializable<T>::deserialize(deserializer));
+        }
+        return value;
+    }
+};
+
+/// A set: its length, then its elements.
+template <typename T, typename Compare, typename Allocator>
+struct Serializable<std::set<T, Compare, Allocator>> {
+    template <typename Serializer>
+    static void serialize(const std::set<T, Compare, Allocator> &value, Serializer &serializer) {
+        serializer.serialize_len(value.size());
+        for (const auto &element : value) {
+            Serializable<T>::serialize(element, serializer);
+        }
+    }
+};
+
+template <typename T, typename Compare, typename Allocator>
+struct Deserializable<std::set<T, Compare, Allocator>> {
+    template <typename Deserializer>
+    static std::set<T, Compare, Allocator> deserialize(Deserializer &deserializer) {
+        std::size_t len = deserializer.deserialize_len();
+        std::set<T, Compare, Allocator> value;
+        for (std::size_t i = 0; i < len; ++i) {
+            value.insert(Deserializable<T>::deserialize(deserializer));
+        }
+        return value;
+    }
+};
+
+/// A map: its length, then each of its keys followed by its value.
+template <typename K, typename V, typename Compare, typename Allocator>
+struct Serializable<std::map<K, V, Compare, Allocator>> {
+    template <typename Serializer>
+    static void serialize(const std::map<K, V, Compare, Allocator> &value,
+                          Serializer &serializer) {
+        serializer.serialize_len(value.size());
+        for (const auto &[key, item] : value) {
+            Serializable<K>::serialize(key, serializer);
+            Serializable<V>::serialize(item, serializer);
+        }
+    }
+};
+
+template <typename K, typename V, typename Compare, typename Allocator>
+struct Deserializable<std::map<K, V, Compare, Allocator>> {
+    template <typename Deserializer>
+    static std::map<K, V, Compare, Allocator> deserialize(Deserializer &deserializer) {
+        std::size_t len = deserializer.deserialize_len();
+        std::map<K, V, Compare, Allocator> value;
+        for (std::size_t i = 0; i < len; ++i) {
+            K key = Deserializable<K>::deserialize(deserializer);
+            V item = Deserializable<V>::deserialize(deserializer);
+            value.emplace(std::move(key), std::move(item));
+        }
+        return value;
+    }
+};
+
+/// A fixed-size array: its elements, without a length.
+template <typename T, std::size_t N>
+struct Serializable<std::array<T, N>> {
+    template <typename Serializer>
+    static void serialize(const std::array<T, N> &value, Serializer &serializer) {
+        for (const auto &element : value) {
+            Serializable<T>::serialize(element, serializer);
+        }
+    }
+};
+
+template <typename T, std::size_t N>
+struct Deserializable<std::array<T, N>> {
+    template <typename Deserializer>
+    static std::array<T, N> deserialize(Deserializer &deserializer) {
+        std::array<T, N> value{};
+        for (auto &element : value) {
+            element = Deserializable<T>::deserialize(deserializer);
+        }
+        return value;
+    }
+};
+
+/// A tuple: its elements, in order.
+template <typename... Ts>
+struct Serializable<std::tuple<Ts...>> {
+    template <typename Serializer>
+    static void serialize(const std::tuple<Ts...> &value, Serializer &serializer) {
+        std::apply(
+            [&serializer](const Ts &...elements) {
+                (Serializable<Ts>::serialize(elements, serializer), ...);
+            },
+            value);
+    }
+};
+
+template <typename... Ts>
+struct Deserializable<std::tuple<Ts...>> {
+    template <typename Deserializer>
+    static std::tuple<Ts...> deserialize(Deserializer &deserializer) {
+        // The elements of a braced initializer are evaluated in order.
+        return std::tuple<Ts...>{Deserializable<Ts>::deserialize(deserializer)...};
+    }
+};
+
+/// A recursive value, which is written as the value itself.
+template <typename T>
+struct Serializable<value_ptr<T>> {
+    template <typename Serializer>
+    static void serialize(const value_ptr<T> &value, Serializer &serializer) {
+        if (!value) {
+            throw serialization_error("Cannot serialize an empty value_ptr");
+        }
+        Serializable<T>::serialize(*value, serializer);
+    }
+};
+
+template <typename T>
+struct Deserializable<value_ptr<T>> {
+    template <typename Deserializer>
+    static value_ptr<T> deserialize(Deserializer &deserializer) {
+        return value_ptr<T>(Deserializable<T>::deserialize(deserializer));
+    }
+};
+
+} // namespace serde
@@ -0,0 +1,9 @@
+// Copyright (c) Facebook, Inc. and its affiliates
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+/// The bincode format, used by the generated `bincodeSerialize` and
+/// `bincodeDeserialize` methods.
+library;
+
+export 'bincode_deserializer.dart';
+export 'bincode_serializer.dart';
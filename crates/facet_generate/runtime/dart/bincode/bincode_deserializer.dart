@@ -0,0 +1,32 @@
+// Copyright (c) Facebook, Inc. and its affiliates
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+import 'dart:typed_data';
+
+import '../serde/serde.dart';
+
+/// Reads values in the bincode format: lengths as `u64` and variant indices
+/// as `u32`.
+class BincodeDeserializer extends BinaryDeserializer {
+  BincodeDeserializer(Uint8List input) : super(input, maxContainerDepth);
+
+  /// Bincode doesn't limit the nesting of containers.
+  static const int maxContainerDepth = 0x7fffffff;
+
+  @override
+  int deserializeLen() {
+    final value = deserializeU64();
+    if (value < 0 || value > 0x7fffffff) {
+      throw const DeserializationError('Incorrect length value');
+    }
+    return value;
+  }
+
+  @override
+  int deserializeVariantIndex() => deserializeU32();
+
+  @override
+  void checkThatKeySlicesAreIncreasing((int, int) key1, (int, int) key2) {
+    // Not required by the format.
+  }
+}
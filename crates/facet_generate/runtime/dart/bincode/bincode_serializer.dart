@@ -0,0 +1,28 @@
+// Copyright (c) Facebook, Inc. and its affiliates
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+import '../serde/serde.dart';
+
+/// Writes values in the bincode format: lengths as `u64` and variant indices
+/// as `u32`.
+class BincodeSerializer extends BinarySerializer {
+  BincodeSerializer() : super(maxContainerDepth);
+
+  /// Bincode doesn't limit the nesting of containers.
+  static const int maxContainerDepth = 0x7fffffff;
+
+  @override
+  void serializeLen(int value) {
+    serializeU64(value);
+  }
+
+  @override
+  void serializeVariantIndex(int value) {
+    serializeU32(value);
+  }
+
+  @override
+  void sortMapEntries(List<int> offsets) {
+    // Not required by the format.
+  }
+}
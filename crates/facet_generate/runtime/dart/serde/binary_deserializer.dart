@@ -0,0 +1,148 @@
+// Copyright (c) Facebook, Inc. and its affiliates
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+import 'dart:convert';
+import 'dart:typed_data';
+
+import 'deserializer.dart';
+import 'errors.dart';
+
+/// A [Deserializer] that reads little-endian values from a byte buffer.
+///
+/// Formats built on it decide how lengths and variant indices are read.
+abstract class BinaryDeserializer extends Deserializer {
+  BinaryDeserializer(Uint8List input, this._containerDepthBudget)
+    // copies the input to prevent outside mutation of the buffer
+    : _buffer = ByteData.sublistView(Uint8List.fromList(input));
+
+  final ByteData _buffer;
+  int _offset = 0;
+  int _containerDepthBudget;
+
+  int _advance(int length) {
+    final remaining = _buffer.lengthInBytes - _offset;
+    if (length > remaining) {
+      throw DeserializationError(
+        'Unexpected end of input: tried to read $length byte(s) at offset '
+        '$_offset, but only $remaining remain',
+      );
+    }
+    final offset = _offset;
+    _offset += length;
+    return offset;
+  }
+
+  @override
+  void increaseContainerDepth() {
+    if (_containerDepthBudget == 0) {
+      throw const DeserializationError('Exceeded maximum container depth');
+    }
+    _containerDepthBudget -= 1;
+  }
+
+  @override
+  void decreaseContainerDepth() {
+    _containerDepthBudget += 1;
+  }
+
+  @override
+  String deserializeStr() {
+    try {
+      return utf8.decode(deserializeBytes());
+    } on FormatException catch (error) {
+      throw DeserializationError('Invalid UTF-8 string: ${error.message}');
+    }
+  }
+
+  @override
+  Uint8List deserializeBytes() {
+    final length = deserializeLen();
+    final offset = _advance(length);
+    return Uint8List.fromList(
+      _buffer.buffer.asUint8List(_buffer.offsetInBytes + offset, length),
+    );
+  }
+
+  @override
+  bool deserializeBool() {
+    final value = deserializeU8();
+    if (value > 1) {
+      throw DeserializationError('Invalid bool: $value');
+    }
+    return value == 1;
+  }
+
+  @override
+  () deserializeUnit() => ();
+
+  /// Reads the UTF-8 encoding of a single character.
+  @override
+  String deserializeChar() {
+    final first = deserializeU8();
+    final length = first < 0x80
+        ? 1
+        : first < 0xe0
+        ? 2
+        : first < 0xf0
+        ? 3
+        : 4;
+    final offset = _advance(length - 1) - 1;
+    try {
+      return utf8.decode(
+        _buffer.buffer.asUint8List(_buffer.offsetInBytes + offset, length),
+      );
+    } on FormatException catch (error) {
+      throw DeserializationError('Invalid UTF-8 character: ${error.message}');
+    }
+  }
+
+  @override
+  double deserializeF32() => _buffer.getFloat32(_advance(4), Endian.little);
+
+  @override
+  double deserializeF64() => _buffer.getFloat64(_advance(8), Endian.little);
+
+  @override
+  int deserializeU8() => _buffer.getUint8(_advance(1));
+
+  @override
+  int deserializeU16() => _buffer.getUint16(_advance(2), Endian.little);
+
+  @override
+  int deserializeU32() => _buffer.getUint32(_advance(4), Endian.little);
+
+  @override
+  int deserializeU64() => _buffer.getUint64(_advance(8), Endian.little);
+
+  @override
+  BigInt deserializeU128() {
+    final low = BigInt.from(deserializeU64()).toUnsigned(64);
+    final high = BigInt.from(deserializeU64()).toUnsigned(64);
+    return low | (high << 64);
+  }
+
+  @override
+  int deserializeI8() => _buffer.getInt8(_advance(1));
+
+  @override
+  int deserializeI16() => _buffer.getInt16(_advance(2), Endian.little);
+
+  @override
+  int deserializeI32() => _buffer.getInt32(_advance(4), Endian.little);
+
+  @override
+  int deserializeI64() => _buffer.getInt64(_advance(8), Endian.little);
+
+  @override
+  BigInt deserializeI128() {
+    final low = BigInt.from(deserializeU64()).toUnsigned(64);
+    final high = BigInt.from(deserializeU64()).toUnsigned(64);
+    return (low | (high << 64)).toSigned(128);
+  }
+
+  @override
+  bool deserializeOptionTag() => deserializeBool();
+
+  @override
+  int getBufferOffset() => _offset;
+}
@@ -0,0 +1,191 @@
+// Copyright (c) Facebook, Inc. and its affiliates
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+import 'dart:convert';
+import 'dart:typed_data';
+
+import 'errors.dart';
+import 'serializer.dart';
+
+/// A [Serializer] that writes little-endian values into a growable buffer.
+///
+/// Formats built on it decide how lengths and variant indices are written.
+abstract class BinarySerializer extends Serializer {
+  BinarySerializer(this._containerDepthBudget);
+
+  static final BigInt _mask64 = (BigInt.one << 64) - BigInt.one;
+
+  ByteData _buffer = ByteData(64);
+  int _offset = 0;
+  int _containerDepthBudget;
+
+  void _reserve(int length) {
+    if (_offset + length <= _buffer.lengthInBytes) {
+      return;
+    }
+    var capacity = _buffer.lengthInBytes * 2;
+    while (capacity < _offset + length) {
+      capacity *= 2;
+    }
+    final buffer = ByteData(capacity);
+    buffer.buffer.asUint8List().setRange(
+      0,
+      _offset,
+      _buffer.buffer.asUint8List(),
+    );
+    _buffer = buffer;
+  }
+
+  void _write(List<int> bytes) {
+    _reserve(bytes.length);
+    _buffer.buffer.asUint8List().setRange(
+      _offset,
+      _offset + bytes.length,
+      bytes,
+    );
+    _offset += bytes.length;
+  }
+
+  @override
+  void increaseContainerDepth() {
+    if (_containerDepthBudget == 0) {
+      throw const SerializationError('Exceeded maximum container depth');
+    }
+    _containerDepthBudget -= 1;
+  }
+
+  @override
+  void decreaseContainerDepth() {
+    _containerDepthBudget += 1;
+  }
+
+  @override
+  void serializeStr(String value) {
+    serializeBytes(Uint8List.fromList(utf8.encode(value)));
+  }
+
+  @override
+  void serializeBytes(Uint8List value) {
+    serializeLen(value.length);
+    _write(value);
+  }
+
+  @override
+  void serializeBool(bool value) {
+    serializeU8(value ? 1 : 0);
+  }
+
+  @override
+  void serializeUnit(() value) {}
+
+  /// Writes the UTF-8 encoding of the single character `value`.
+  @override
+  void serializeChar(String value) {
+    if (value.runes.length != 1) {
+      throw SerializationError('Expected a single character, got "$value"');
+    }
+    _write(utf8.encode(value));
+  }
+
+  @override
+  void serializeF32(double value) {
+    _reserve(4);
+    _buffer.setFloat32(_offset, value, Endian.little);
+    _offset += 4;
+  }
+
+  @override
+  void serializeF64(double value) {
+    _reserve(8);
+    _buffer.setFloat64(_offset, value, Endian.little);
+    _offset += 8;
+  }
+
+  @override
+  void serializeU8(int value) {
+    _reserve(1);
+    _buffer.setUint8(_offset, value);
+    _offset += 1;
+  }
+
+  @override
+  void serializeU16(int value) {
+    _reserve(2);
+    _buffer.setUint16(_offset, value, Endian.little);
+    _offset += 2;
+  }
+
+  @override
+  void serializeU32(int value) {
+    _reserve(4);
+    _buffer.setUint32(_offset, value, Endian.little);
+    _offset += 4;
+  }
+
+  @override
+  void serializeU64(int value) {
+    _reserve(8);
+    _buffer.setUint64(_offset, value, Endian.little);
+    _offset += 8;
+  }
+
+  @override
+  void serializeU128(BigInt value) {
+    if (value.isNegative || value.bitLength > 128) {
+      throw SerializationError('Value out of range for u128: $value');
+    }
+    serializeU64((value & _mask64).toUnsigned(64).toInt());
+    serializeU64((value >> 64).toUnsigned(64).toInt());
+  }
+
+  @override
+  void serializeI8(int value) {
+    _reserve(1);
+    _buffer.setInt8(_offset, value);
+    _offset += 1;
+  }
+
+  @override
+  void serializeI16(int value) {
+    _reserve(2);
+    _buffer.setInt16(_offset, value, Endian.little);
+    _offset += 2;
+  }
+
+  @override
+  void serializeI32(int value) {
+    _reserve(4);
+    _buffer.setInt32(_offset, value, Endian.little);
+    _offset += 4;
+  }
+
+  @override
+  void serializeI64(int value) {
+    _reserve(8);
+    _buffer.setInt64(_offset, value, Endian.little);
+    _offset += 8;
+  }
+
+  /// Writes the two's complement of `value` as two 64-bit limbs, low first.
+  @override
+  void serializeI128(BigInt value) {
+    if (value.bitLength > 127) {
+      throw SerializationError('Value out of range for i128: $value');
+    }
+    final bits = value.toUnsigned(128);
+    serializeU64((bits & _mask64).toUnsigned(64).toInt());
+    serializeU64((bits >> 64).toUnsigned(64).toInt());
+  }
+
+  @override
+  void serializeOptionTag(bool value) {
+    serializeBool(value);
+  }
+
+  @override
+  int getBufferOffset() => _offset;
+
+  @override
+  Uint8List getBytes() =>
+      Uint8List.fromList(_buffer.buffer.asUint8List(0, _offset));
+}
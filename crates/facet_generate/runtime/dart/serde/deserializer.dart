@@ -0,0 +1,134 @@
+// Copyright (c) Facebook, Inc. and its affiliates
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+import 'dart:typed_data';
+
+import 'errors.dart';
+
+/// Reads values in a binary format.
+///
+/// The generated `deserialize` constructors call the primitive methods for
+/// their fields, and the container helpers with a callback for the elements.
+abstract class Deserializer {
+  String deserializeStr();
+
+  Uint8List deserializeBytes();
+
+  bool deserializeBool();
+
+  () deserializeUnit();
+
+  String deserializeChar();
+
+  double deserializeF32();
+
+  double deserializeF64();
+
+  int deserializeU8();
+
+  int deserializeU16();
+
+  int deserializeU32();
+
+  /// Reads the bits of a `u64`, so that values above 2^63 - 1 are returned
+  /// as the negative `int` with the same bits.
+  int deserializeU64();
+
+  BigInt deserializeU128();
+
+  int deserializeI8();
+
+  int deserializeI16();
+
+  int deserializeI32();
+
+  int deserializeI64();
+
+  BigInt deserializeI128();
+
+  int deserializeLen();
+
+  int deserializeVariantIndex();
+
+  bool deserializeOptionTag();
+
+  void increaseContainerDepth();
+
+  void decreaseContainerDepth();
+
+  int getBufferOffset();
+
+  void checkThatKeySlicesAreIncreasing((int, int) key1, (int, int) key2);
+
+  T? deserializeOption<T>(T Function() deserializeValue) {
+    return deserializeOptionTag() ? deserializeValue() : null;
+  }
+
+  List<T> deserializeSeq<T>(T Function() deserializeElement) {
+    final length = deserializeLen();
+    return [for (var i = 0; i < length; i++) deserializeElement()];
+  }
+
+  Set<T> deserializeSet<T>(T Function() deserializeElement) {
+    final length = deserializeLen();
+    return {for (var i = 0; i < length; i++) deserializeElement()};
+  }
+
+  Map<K, V> deserializeMap<K, V>(
+    K Function() deserializeKey,
+    V Function() deserializeValue,
+  ) {
+    final length = deserializeLen();
+    final map = <K, V>{};
+    int? previousStart;
+    int? previousEnd;
+    for (var i = 0; i < length; i++) {
+      final start = getBufferOffset();
+      final key = deserializeKey();
+      final end = getBufferOffset();
+      if (previousStart != null && previousEnd != null) {
+        checkThatKeySlicesAreIncreasing((previousStart, previousEnd), (start, end));
+      }
+      previousStart = start;
+      previousEnd = end;
+      map[key] = deserializeValue();
+    }
+    return map;
+  }
+
+  /// Reads the `size` elements of a fixed-size array, which has no length.
+  List<T> deserializeTupleArray<T>(int size, T Function() deserializeElement) {
+    return [for (var i = 0; i < size; i++) deserializeElement()];
+  }
+
+  /// Reads the 16 bytes of a UUID into its hyphenated string form.
+  String deserializeUuid() {
+    final bytes = deserializeBytes();
+    if (bytes.length != 16) {
+      throw DeserializationError('UUID must be 16 bytes, got ${bytes.length}');
+    }
+    final hex = bytes.map((byte) => byte.toRadixString(16).padLeft(2, '0')).join();
+    return '${hex.substring(0, 8)}-${hex.substring(8, 12)}-'
+        '${hex.substring(12, 16)}-${hex.substring(16, 20)}-${hex.substring(20)}';
+  }
+
+  /// Reads a timestamp written as an RFC 3339 string.
+  DateTime deserializeTimestamp() {
+    final text = deserializeStr();
+    final timestamp = DateTime.tryParse(text);
+    if (timestamp == null) {
+      throw DeserializationError('Invalid RFC 3339 timestamp: $text');
+    }
+    return timestamp;
+  }
+
+  /// Reads a duration written as its whole seconds and the remaining
+  /// nanoseconds.
+  Duration deserializeDuration() {
+    increaseContainerDepth();
+    final secs = deserializeU64();
+    final nanos = deserializeU32();
+    decreaseContainerDepth();
+    return Duration(seconds: secs, microseconds: nanos ~/ 1000);
+  }
+}
@@ -0,0 +1,22 @@
+// Copyright (c) Facebook, Inc. and its affiliates
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+/// Thrown when a value can't be serialized.
+class SerializationError implements Exception {
+  const SerializationError(this.message);
+
+  final String message;
+
+  @override
+  String toString() => 'SerializationError: $message';
+}
+
+/// Thrown when the input can't be deserialized.
+class DeserializationError implements Exception {
+  const DeserializationError(this.message);
+
+  final String message;
+
+  @override
+  String toString() => 'DeserializationError: $message';
+}
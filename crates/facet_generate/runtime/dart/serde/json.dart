@@ -0,0 +1,69 @@
+// Copyright (c) Facebook, Inc. and its affiliates
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+import 'dart:typed_data';
+
+import 'errors.dart';
+
+// Helpers for the generated `toJson` methods and `fromJson` constructors,
+// which read and write the shapes that `serde_json` gives Rust types.
+
+/// Converts `value` with `toJson`, unless it is `null`.
+Object? optionToJson<T>(T? value, Object? Function(T value) toJson) =>
+    value == null ? null : toJson(value);
+
+/// Reads `json` with `fromJson`, unless it is `null`.
+T? optionFromJson<T>(Object? json, T Function(Object? json) fromJson) =>
+    json == null ? null : fromJson(json);
+
+/// Reads a tuple, written as an array of `length` elements.
+R tupleFromJson<R>(
+  Object? json,
+  int length,
+  R Function(List<Object?> elements) fromJson,
+) {
+  if (json is! List<Object?> || json.length != length) {
+    throw DeserializationError('Expected an array of $length elements');
+  }
+  return fromJson(json);
+}
+
+/// Writes a 128-bit integer as a number when it fits in an `int`, and as a
+/// string otherwise.
+Object bigIntToJson(BigInt value) =>
+    value.isValidInt ? value.toInt() : value.toString();
+
+/// Reads a 128-bit integer written as a number or a string.
+BigInt bigIntFromJson(Object? json) => switch (json) {
+  int() => BigInt.from(json),
+  String() => BigInt.parse(json),
+  _ => throw DeserializationError('Expected an integer, got $json'),
+};
+
+/// Reads bytes, written as an array of numbers.
+Uint8List bytesFromJson(Object? json) =>
+    Uint8List.fromList((json as List<Object?>).cast<int>());
+
+/// Writes a duration as `{"secs": …, "nanos": …}`, the shape `serde` gives
+/// `std::time::Duration`.
+Object durationToJson(Duration value) => {
+  'secs': value.inSeconds,
+  'nanos': value.inMicroseconds.remainder(1000000) * 1000,
+};
+
+/// Reads a duration written as `{"secs": …, "nanos": …}`.
+Duration durationFromJson(Object? json) {
+  final map = json as Map<String, Object?>;
+  return Duration(
+    seconds: map['secs'] as int,
+    microseconds: (map['nanos'] as int) ~/ 1000,
+  );
+}
+
+/// Splits an externally tagged enum into its tag and content: a unit variant
+/// is written as its name, and any other as `{"Name": content}`.
+(String, Object?) externalTag(Object? json) => switch (json) {
+  String() => (json, null),
+  Map<String, Object?>(length: 1) => (json.keys.single, json.values.single),
+  _ => throw DeserializationError('Expected an enum variant, got $json'),
+};
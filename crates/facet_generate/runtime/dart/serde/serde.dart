@@ -0,0 +1,12 @@
+// Copyright (c) Facebook, Inc. and its affiliates
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+/// Serialization runtime for the generated `serialize` and `toJson` methods.
+library;
+
+export 'binary_deserializer.dart';
+export 'binary_serializer.dart';
+export 'deserializer.dart';
+export 'errors.dart';
+export 'json.dart';
+export 'serializer.dart';
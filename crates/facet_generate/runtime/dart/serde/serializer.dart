@@ -0,0 +1,145 @@
+// Copyright (c) Facebook, Inc. and its affiliates
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+import 'dart:typed_data';
+
+import 'errors.dart';
+
+/// Writes values in a binary format.
+///
+/// The generated `serialize` methods call the primitive methods for their
+/// fields, and the container helpers with a callback for the elements.
+abstract class Serializer {
+  void serializeStr(String value);
+
+  void serializeBytes(Uint8List value);
+
+  void serializeBool(bool value);
+
+  void serializeUnit(() value);
+
+  void serializeChar(String value);
+
+  void serializeF32(double value);
+
+  void serializeF64(double value);
+
+  void serializeU8(int value);
+
+  void serializeU16(int value);
+
+  void serializeU32(int value);
+
+  /// Writes the bits of `value`, so that values above 2^63 - 1 can be passed
+  /// as the negative `int` with the same bits.
+  void serializeU64(int value);
+
+  void serializeU128(BigInt value);
+
+  void serializeI8(int value);
+
+  void serializeI16(int value);
+
+  void serializeI32(int value);
+
+  void serializeI64(int value);
+
+  void serializeI128(BigInt value);
+
+  void serializeLen(int value);
+
+  void serializeVariantIndex(int value);
+
+  void serializeOptionTag(bool value);
+
+  void increaseContainerDepth();
+
+  void decreaseContainerDepth();
+
+  int getBufferOffset();
+
+  void sortMapEntries(List<int> offsets);
+
+  Uint8List getBytes();
+
+  void serializeOption<T>(T? value, void Function(T value) serializeValue) {
+    if (value == null) {
+      serializeOptionTag(false);
+    } else {
+      serializeOptionTag(true);
+      serializeValue(value);
+    }
+  }
+
+  void serializeSeq<T>(List<T> value, void Function(T element) serializeElement) {
+    serializeLen(value.length);
+    value.forEach(serializeElement);
+  }
+
+  void serializeSet<T>(Set<T> value, void Function(T element) serializeElement) {
+    serializeLen(value.length);
+    value.forEach(serializeElement);
+  }
+
+  void serializeMap<K, V>(
+    Map<K, V> value,
+    void Function(K key) serializeKey,
+    void Function(V value) serializeValue,
+  ) {
+    serializeLen(value.length);
+    final offsets = <int>[];
+    value.forEach((key, value) {
+      offsets.add(getBufferOffset());
+      serializeKey(key);
+      serializeValue(value);
+    });
+    sortMapEntries(offsets);
+  }
+
+  /// Writes the `size` elements of a fixed-size array, without a length.
+  void serializeTupleArray<T>(
+    List<T> value,
+    int size,
+    void Function(T element) serializeElement,
+  ) {
+    if (value.length != size) {
+      throw SerializationError(
+        'Expected an array of $size elements, got ${value.length}',
+      );
+    }
+    value.forEach(serializeElement);
+  }
+
+  /// Writes a UUID in its hyphenated string form as its 16 bytes.
+  void serializeUuid(String value) {
+    final hex = value.replaceAll('-', '');
+    if (hex.length != 32) {
+      throw SerializationError('Invalid UUID: $value');
+    }
+    final bytes = Uint8List(16);
+    for (var i = 0; i < 16; i++) {
+      final byte = int.tryParse(hex.substring(i * 2, i * 2 + 2), radix: 16);
+      if (byte == null) {
+        throw SerializationError('Invalid UUID: $value');
+      }
+      bytes[i] = byte;
+    }
+    serializeBytes(bytes);
+  }
+
+  /// Writes a timestamp as an RFC 3339 string in UTC.
+  void serializeTimestamp(DateTime value) {
+    serializeStr(value.toUtc().toIso8601String());
+  }
+
+  /// Writes a duration as its whole seconds and the remaining nanoseconds.
+  void serializeDuration(Duration value) {
+    if (value.isNegative) {
+      throw SerializationError('Duration must not be negative: $value');
+    }
+    increaseContainerDepth();
+    serializeU64(value.inSeconds);
+    serializeU32(value.inMicroseconds.remainder(1000000) * 1000);
+    decreaseContainerDepth();
+  }
+}
@@ -0,0 +1,481 @@
+// Package bincode implements the bincode format for the Serialize methods
+// and Deserialize functions generated by facet-generate: integers are
+// little-endian, lengths are written as u64 and variant indices as u32.
+package bincode
+
+import (
+	"bytes"
+	"encoding/binary"
+	"math"
+	"slices"
+	"strconv"
+	"time"
+	"unicode/utf8"
+
+	"github.com/redbadger/facet-generate/runtime/go/serde"
+)
+
+// Bincode doesn't limit the nesting of containers.
+const maxContainerDepth = math.MaxInt32
+
+var (
+	_ serde.Serializer   = (*Serializer)(nil)
+	_ serde.Deserializer = (*Deserializer)(nil)
+)
+
+// Serializer writes values in the bincode format.
+type Serializer struct {
+	buffer               []byte
+	containerDepthBudget uint64
+}
+
+// NewSerializer returns a Serializer with an empty buffer.
+func NewSerializer() *Serializer {
+	return &Serializer{containerDepthBudget: maxContainerDepth}
+}
+
+func (s *Serializer) IncreaseContainerDepth() error {
+	if s.containerDepthBudget == 0 {
+		return serde.NewSerializationError("exceeded maximum container depth")
+	}
+	s.containerDepthBudget--
+	return nil
+}
+
+func (s *Serializer) DecreaseContainerDepth() {
+	s.containerDepthBudget++
+}
+
+func (s *Serializer) SerializeStr(value string) error {
+	if !utf8.ValidString(value) {
+		return serde.NewSerializationError("invalid UTF-8 string")
+	}
+	return s.SerializeBytes([]byte(value))
+}
+
+func (s *Serializer) SerializeBytes(value serde.Bytes) error {
+	if err := s.SerializeLen(uint64(len(value))); err != nil {
+		return err
+	}
+	s.buffer = append(s.buffer, value...)
+	return nil
+}
+
+func (s *Serializer) SerializeBool(value bool) error {
+	if value {
+		return s.SerializeU8(1)
+	}
+	return s.SerializeU8(0)
+}
+
+func (s *Serializer) SerializeUnit(value serde.Unit) error {
+	return nil
+}
+
+// SerializeChar writes the UTF-8 encoding of a single character.
+func (s *Serializer) SerializeChar(value string) error {
+	if utf8.RuneCountInString(value) != 1 || !utf8.ValidString(value) {
+		return serde.NewSerializationError("expected a single character, got " + strconv.Quote(value))
+	}
+	s.buffer = append(s.buffer, value...)
+	return nil
+}
+
+func (s *Serializer) SerializeF32(value float32) error {
+	return s.SerializeU32(math.Float32bits(value))
+}
+
+func (s *Serializer) SerializeF64(value float64) error {
+	return s.SerializeU64(math.Float64bits(value))
+}
+
+func (s *Serializer) SerializeU8(value uint8) error {
+	s.buffer = append(s.buffer, value)
+	return nil
+}
+
+func (s *Serializer) SerializeU16(value uint16) error {
+	s.buffer = binary.LittleEndian.AppendUint16(s.buffer, value)
+	return nil
+}
+
+func (s *Serializer) SerializeU32(value uint32) error {
+	s.buffer = binary.LittleEndian.AppendUint32(s.buffer, value)
+	return nil
+}
+
+func (s *Serializer) SerializeU64(value uint64) error {
+	s.buffer = binary.LittleEndian.AppendUint64(s.buffer, value)
+	return nil
+}
+
+func (s *Serializer) SerializeU128(value serde.Uint128) error {
+	s.buffer = binary.LittleEndian.AppendUint64(s.buffer, value.Low)
+	s.buffer = binary.LittleEndian.AppendUint64(s.buffer, value.High)
+	return nil
+}
+
+func (s *Serializer) SerializeI8(value int8) error {
+	return s.SerializeU8(uint8(value))
+}
+
+func (s *Serializer) SerializeI16(value int16) error {
+	return s.SerializeU16(uint16(value))
+}
+
+func (s *Serializer) SerializeI32(value int32) error {
+	return s.SerializeU32(uint32(value))
+}
+
+func (s *Serializer) SerializeI64(value int64) error {
+	return s.SerializeU64(uint64(value))
+}
+
+func (s *Serializer) SerializeI128(value serde.Int128) error {
+	s.buffer = binary.LittleEndian.AppendUint64(s.buffer, value.Low)
+	s.buffer = binary.LittleEndian.AppendUint64(s.buffer, uint64(value.High))
+	return nil
+}
+
+// SerializeUUID writes a UUID as its 16 bytes.
+func (s *Serializer) SerializeUUID(value serde.UUID) error {
+	return s.SerializeBytes(value[:])
+}
+
+// SerializeDate writes a date as an ISO 8601 string.
+func (s *Serializer) SerializeDate(value string) error {
+	return s.SerializeStr(value)
+}
+
+// SerializeTime writes a time as an ISO 8601 string.
+func (s *Serializer) SerializeTime(value string) error {
+	return s.SerializeStr(value)
+}
+
+// SerializeTimestamp writes a timestamp as an RFC 3339 string in UTC.
+func (s *Serializer) SerializeTimestamp(value time.Time) error {
+	return s.SerializeStr(value.UTC().Format(time.RFC3339Nano))
+}
+
+// SerializeDuration writes a duration as its whole seconds and the remaining
+// nanoseconds.
+func (s *Serializer) SerializeDuration(value serde.Duration) error {
+	if err := s.IncreaseContainerDepth(); err != nil {
+		return err
+	}
+	if err := s.SerializeU64(value.Secs); err != nil {
+		return err
+	}
+	if err := s.SerializeU32(value.Nanos); err != nil {
+		return err
+	}
+	s.DecreaseContainerDepth()
+	return nil
+}
+
+func (s *Serializer) SerializeLen(value uint64) error {
+	return s.SerializeU64(value)
+}
+
+func (s *Serializer) SerializeVariantIndex(value uint32) error {
+	return s.SerializeU32(value)
+}
+
+func (s *Serializer) SerializeOptionTag(value bool) error {
+	return s.SerializeBool(value)
+}
+
+func (s *Serializer) GetBufferOffset() uint64 {
+	return uint64(len(s.buffer))
+}
+
+// SortMapEntries orders the entries of a map by their bytes, which orders
+// them by their keys' bytes, as the encoding of a key is never a prefix of
+// another's.
+func (s *Serializer) SortMapEntries(offsets []uint64) {
+	if len(offsets) < 2 {
+		return
+	}
+	start := offsets[0]
+	entries := make([][]byte, len(offsets))
+	for i, offset := range offsets {
+		end := uint64(len(s.buffer))
+		if i+1 < len(offsets) {
+			end = offsets[i+1]
+		}
+		entries[i] = slices.Clone(s.buffer[offset:end])
+	}
+	slices.SortFunc(entries, bytes.Compare)
+	s.buffer = s.buffer[:start]
+	for _, entry := range entries {
+		s.buffer = append(s.buffer, entry...)
+	}
+}
+
+// GetBytes returns the bytes written so far.
+func (s *Serializer) GetBytes() []byte {
+	return s.buffer
+}
+
+// Deserializer reads values in the bincode format.
+type Deserializer struct {
+	input                []byte
+	offset               uint64
+	containerDepthBudget uint64
+}
+
+// NewDeserializer returns a Deserializer reading input.
+func NewDeserializer(input []byte) *Deserializer {
+	return &Deserializer{input: input, containerDepthBudget: maxContainerDepth}
+}
+
+func (d *Deserializer) read(length uint64) ([]byte, error) {
+	remaining := uint64(len(d.input)) - d.offset
+	if length > remaining {
+		return nil, serde.NewDeserializationError("unexpected end of input: tried to read " +
+			strconv.FormatUint(length, 10) + " byte(s) at offset " + strconv.FormatUint(d.offset, 10) +
+			", but only " + strconv.FormatUint(remaining, 10) + " remain")
+	}
+	value := d.input[d.offset : d.offset+length]
+	d.offset += length
+	return value, nil
+}
+
+func (d *Deserializer) IncreaseContainerDepth() error {
+	if d.containerDepthBudget == 0 {
+		return serde.NewDeserializationError("exceeded maximum container depth")
+	}
+	d.containerDepthBudget--
+	return nil
+}
+
+func (d *Deserializer) DecreaseContainerDepth() {
+	d.containerDepthBudget++
+}
+
+func (d *Deserializer) DeserializeStr() (string, error) {
+	value, err := d.DeserializeBytes()
+	if err != nil {
+		return "", err
+	}
+	if !utf8.Valid(value) {
+		return "", serde.NewDeserializationError("invalid UTF-8 string")
+	}
+	return string(value), nil
+}
+
+func (d *Deserializer) DeserializeBytes() (serde.Bytes, error) {
+	length, err := d.DeserializeLen()
+	if err != nil {
+		return nil, err
+	}
+	value, err := d.read(length)
+	if err != nil {
+		return nil, err
+	}
+	return slices.Clone(value), nil
+}
+
+func (d *Deserializer) DeserializeBool() (bool, error) {
+	value, err := d.DeserializeU8()
+	if err != nil {
+		return false, err
+	}
+	if value > 1 {
+		return false, serde.NewDeserializationError("invalid bool: " + strconv.Itoa(int(value)))
+	}
+	return value == 1, nil
+}
+
+func (d *Deserializer) DeserializeUnit() (serde.Unit, error) {
+	return serde.Unit{}, nil
+}
+
+// DeserializeChar reads the UTF-8 encoding of a single character.
+func (d *Deserializer) DeserializeChar() (string, error) {
+	if d.offset >= uint64(len(d.input)) {
+		_, err := d.read(1)
+		return "", err
+	}
+	lead := d.input[d.offset]
+	length := uint64(1)
+	switch {
+	case lead >= 0xf0:
+		length = 4
+	case lead >= 0xe0:
+		length = 3
+	case lead >= 0x80:
+		length = 2
+	}
+	value, err := d.read(length)
+	if err != nil {
+		return "", err
+	}
+	if !utf8.FullRune(value) || !utf8.Valid(value) {
+		return "", serde.NewDeserializationError("invalid UTF-8 character")
+	}
+	return string(value), nil
+}
+
+func (d *Deserializer) DeserializeF32() (float32, error) {
+	value, err := d.DeserializeU32()
+	return math.Float32frombits(value), err
+}
+
+func (d *Deserializer) DeserializeF64() (float64, error) {
+	value, err := d.DeserializeU64()
+	return math.Float64frombits(value), err
+}
+
+func (d *Deserializer) DeserializeU8() (uint8, error) {
+	value, err := d.read(1)
+	if err != nil {
+		return 0, err
+	}
+	return value[0], nil
+}
+
+func (d *Deserializer) DeserializeU16() (uint16, error) {
+	value, err := d.read(2)
+	if err != nil {
+		return 0, err
+	}
+	return binary.LittleEndian.Uint16(value), nil
+}
+
+func (d *Deserializer) DeserializeU32() (uint32, error) {
+	value, err := d.read(4)
+	if err != nil {
+		return 0, err
+	}
+	return binary.LittleEndian.Uint32(value), nil
+}
+
+func (d *Deserializer) DeserializeU64() (uint64, error) {
+	value, err := d.read(8)
+	if err != nil {
+		return 0, err
+	}
+	return binary.LittleEndian.Uint64(value), nil
+}
+
+func (d *Deserializer) DeserializeU128() (serde.Uint128, error) {
+	value, err := d.read(16)
+	if err != nil {
+		return serde.Uint128{}, err
+	}
+	return serde.Uint128{
+		High: binary.LittleEndian.Uint64(value[8:]),
+		Low:  binary.LittleEndian.Uint64(value[:8]),
+	}, nil
+}
+
+func (d *Deserializer) DeserializeI8() (int8, error) {
+	value, err := d.DeserializeU8()
+	return int8(value), err
+}
+
+func (d *Deserializer) DeserializeI16() (int16, error) {
+	value, err := d.DeserializeU16()
+	return int16(value), err
+}
+
+func (d *Deserializer) DeserializeI32() (int32, error) {
+	value, err := d.DeserializeU32()
+	return int32(value), err
+}
+
+func (d *Deserializer) DeserializeI64() (int64, error) {
+	value, err := d.DeserializeU64()
+	return int64(value), err
+}
+
+func (d *Deserializer) DeserializeI128() (serde.Int128, error) {
+	value, err := d.read(16)
+	if err != nil {
+		return serde.Int128{}, err
+	}
+	return serde.Int128{
+		High: int64(binary.LittleEndian.Uint64(value[8:])),
+		Low:  binary.LittleEndian.Uint64(value[:8]),
+	}, nil
+}
+
+// DeserializeUUID reads a UUID written as its 16 bytes.
+func (d *Deserializer) DeserializeUUID() (serde.UUID, error) {
+	var value serde.UUID
+	data, err := d.DeserializeBytes()
+	if err != nil {
+		return value, err
+	}
+	if len(data) != len(value) {
+		return value, serde.NewDeserializationError("expected 16 bytes of a UUID, got " + strconv.Itoa(len(data)))
+	}
+	copy(value[:], data)
+	return value, nil
+}
+
+// DeserializeDate reads a date written as an ISO 8601 string.
+func (d *Deserializer) DeserializeDate() (string, error) {
+	return d.DeserializeStr()
+}
+
+// DeserializeTime reads a time written as an ISO 8601 string.
+func (d *Deserializer) DeserializeTime() (string, error) {
+	return d.DeserializeStr()
+}
+
+// DeserializeTimestamp reads a timestamp written as an RFC 3339 string.
+func (d *Deserializer) DeserializeTimestamp() (time.Time, error) {
+	text, err := d.DeserializeStr()
+	if err != nil {
+		return time.Time{}, err
+	}
+	value, err := time.Parse(time.RFC3339Nano, text)
+	if err != nil {
+		return time.Time{}, serde.NewDeserializationError("invalid RFC 3339 timestamp: " + strconv.Quote(text))
+	}
+	return value, nil
+}
+
+// DeserializeDuration reads a duration written as its whole seconds and the
+// remaining nanoseconds.
+func (d *Deserializer) DeserializeDuration() (serde.Duration, error) {
+	var value serde.Duration
+	var err error
+	if err = d.IncreaseContainerDepth(); err != nil {
+		return value, err
+	}
+	if value.Secs, err = d.DeserializeU64(); err != nil {
+		return value, err
+	}
+	if value.Nanos, err = d.DeserializeU32(); err != nil {
+		return value, err
+	}
+	d.DecreaseContainerDepth()
+	return value, nil
+}
+
+func (d *Deserializer) DeserializeLen() (uint64, error) {
+	value, err := d.DeserializeU64()
+	if err != nil {
+		return 0, err
+	}
+	if value > math.MaxInt32 {
+		return 0, serde.NewDeserializationError("incorrect length value")
+	}
+	return value, nil
+}
+
+func (d *Deserializer) DeserializeVariantIndex() (uint32, error) {
+	return d.DeserializeU32()
+}
+
+func (d *Deserializer) DeserializeOptionTag() (bool, error) {
+	return d.DeserializeBool()
+}
+
+// GetBufferOffset returns the number of bytes read so far.
+func (d *Deserializer) GetBufferOffset() uint64 {
+	return d.offset
+}
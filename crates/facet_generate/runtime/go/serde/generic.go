@@ -0,0 +1,116 @@
+package serde
+
+// SerializeOption writes an optional value: a tag, then the value if there
+// is one.
+func SerializeOption[T any](serializer Serializer, value *T, serializeValue func(Serializer, T) error) error {
+	if value == nil {
+		return serializer.SerializeOptionTag(false)
+	}
+	if err := serializer.SerializeOptionTag(true); err != nil {
+		return err
+	}
+	return serializeValue(serializer, *value)
+}
+
+// SerializeSeq writes the length of a sequence, then its elements.
+func SerializeSeq[T any](serializer Serializer, value []T, serializeElement func(Serializer, T) error) error {
+	if err := serializer.SerializeLen(uint64(len(value))); err != nil {
+		return err
+	}
+	return SerializeArray(serializer, value, serializeElement)
+}
+
+// SerializeArray writes the elements of a fixed-size array, without a
+// length.
+func SerializeArray[T any](serializer Serializer, value []T, serializeElement func(Serializer, T) error) error {
+	for _, element := range value {
+		if err := serializeElement(serializer, element); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// SerializeMap writes the length of a map, then its entries, ordered by the
+// serializer so that the output doesn't depend on Go's map iteration order.
+func SerializeMap[K comparable, V any](serializer Serializer, value map[K]V, serializeKey func(Serializer, K) error, serializeValue func(Serializer, V) error) error {
+	if err := serializer.SerializeLen(uint64(len(value))); err != nil {
+		return err
+	}
+	offsets := make([]uint64, 0, len(value))
+	for key, item := range value {
+		offsets = append(offsets, serializer.GetBufferOffset())
+		if err := serializeKey(serializer, key); err != nil {
+			return err
+		}
+		if err := serializeValue(serializer, item); err != nil {
+			return err
+		}
+	}
+	serializer.SortMapEntries(offsets)
+	return nil
+}
+
+// DeserializeOption reads an optional value: a tag, then the value if there
+// is one.
+func DeserializeOption[T any](deserializer Deserializer, deserializeValue func(Deserializer) (T, error)) (*T, error) {
+	tag, err := deserializer.DeserializeOptionTag()
+	if err != nil || !tag {
+		return nil, err
+	}
+	value, err := deserializeValue(deserializer)
+	if err != nil {
+		return nil, err
+	}
+	return &value, nil
+}
+
+// DeserializeSeq reads the length of a sequence, then its elements.
+func DeserializeSeq[T any](deserializer Deserializer, deserializeElement func(Deserializer) (T, error)) ([]T, error) {
+	length, err := deserializer.DeserializeLen()
+	if err != nil {
+		return nil, err
+	}
+	value := make([]T, 0, min(length, 1024))
+	for i := uint64(0); i < length; i++ {
+		element, err := deserializeElement(deserializer)
+		if err != nil {
+			return nil, err
+		}
+		value = append(value, element)
+	}
+	return value, nil
+}
+
+// DeserializeArray reads the elements of a fixed-size array into value.
+func DeserializeArray[T any](deserializer Deserializer, value []T, deserializeElement func(Deserializer) (T, error)) error {
+	for i := range value {
+		element, err := deserializeElement(deserializer)
+		if err != nil {
+			return err
+		}
+		value[i] = element
+	}
+	return nil
+}
+
+// DeserializeMap reads the length of a map, then its entries.
+func DeserializeMap[K comparable, V any](deserializer Deserializer, deserializeKey func(Deserializer) (K, error), deserializeValue func(Deserializer) (V, error)) (map[K]V, error) {
+	length, err := deserializer.DeserializeLen()
+	if err != nil {
+		return nil, err
+	}
+	value := make(map[K]V, min(length, 1024))
+	for i := uint64(0); i < length; i++ {
+		key, err := deserializeKey(deserializer)
+		if err != nil {
+			return nil, err
+		}
+		item, err := deserializeValue(deserializer)
+		if err != nil {
+			return nil, err
+		}
+		value[key] = item
+	}
+	return value, nil
+}
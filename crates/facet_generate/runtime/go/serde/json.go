@@ -0,0 +1,232 @@
+package serde
+
+import (
+	"bytes"
+	"encoding/json"
+	"reflect"
+	"strconv"
+	"sync"
+)
+
+// unionReaders holds the functions that read the interfaces of enums whose
+// variants carry data, by the interface's type.
+var unionReaders sync.Map
+
+// RegisterUnion makes FromJSON read the interface T of an enum whose variants
+// carry data with fromJSON, since encoding/json can't read an interface.
+func RegisterUnion[T any](fromJSON func([]byte) (T, error)) {
+	unionReaders.Store(reflect.TypeOf((*T)(nil)).Elem(), fromJSON)
+}
+
+// FromJSON reads a value of type T with encoding/json, or with the function
+// registered for T by RegisterUnion.
+func FromJSON[T any](data []byte) (T, error) {
+	if fromJSON, ok := unionReaders.Load(reflect.TypeOf((*T)(nil)).Elem()); ok {
+		return fromJSON.(func([]byte) (T, error))(data)
+	}
+	var value T
+	err := json.Unmarshal(data, &value)
+	return value, err
+}
+
+// IsNull reports whether data is the JSON value `null`.
+func IsNull(data []byte) bool {
+	return bytes.Equal(bytes.TrimSpace(data), []byte("null"))
+}
+
+// UnitFromJSON checks that data is `null`, the JSON value of Rust's `()`.
+func UnitFromJSON(data []byte) error {
+	if !IsNull(data) {
+		return NewDeserializationError("expected null, got " + string(data))
+	}
+	return nil
+}
+
+// ObjectFromJSON reads the fields of a JSON object.
+func ObjectFromJSON(data []byte) (map[string]json.RawMessage, error) {
+	if IsNull(data) {
+		return nil, NewDeserializationError("expected an object, got null")
+	}
+	var fields map[string]json.RawMessage
+	if err := json.Unmarshal(data, &fields); err != nil {
+		return nil, err
+	}
+	return fields, nil
+}
+
+// ElementsFromJSON reads the elements of a JSON array of length elements.
+func ElementsFromJSON(data []byte, length int) ([]json.RawMessage, error) {
+	var elements []json.RawMessage
+	if err := json.Unmarshal(data, &elements); err != nil {
+		return nil, err
+	}
+	if len(elements) != length {
+		return nil, NewDeserializationError("expected an array of " + strconv.Itoa(length) + " elements, got " + strconv.Itoa(len(elements)))
+	}
+	return elements, nil
+}
+
+// OptionFromJSON reads an optional value, which is absent if data is `null`.
+func OptionFromJSON[T any](data []byte, fromJSON func([]byte) (T, error)) (*T, error) {
+	if IsNull(data) {
+		return nil, nil
+	}
+	value, err := fromJSON(data)
+	if err != nil {
+		return nil, err
+	}
+	return &value, nil
+}
+
+// SeqFromJSON reads the elements of a JSON array.
+func SeqFromJSON[T any](data []byte, fromJSON func([]byte) (T, error)) ([]T, error) {
+	var elements []json.RawMessage
+	if err := json.Unmarshal(data, &elements); err != nil {
+		return nil, err
+	}
+	value := make([]T, 0, len(elements))
+	for _, element := range elements {
+		item, err := fromJSON(element)
+		if err != nil {
+			return nil, err
+		}
+		value = append(value, item)
+	}
+	return value, nil
+}
+
+// ArrayFromJSON reads the elements of a fixed-size array into value.
+func ArrayFromJSON[T any](data []byte, value []T, fromJSON func([]byte) (T, error)) error {
+	elements, err := ElementsFromJSON(data, len(value))
+	if err != nil {
+		return err
+	}
+	for i, element := range elements {
+		if value[i], err = fromJSON(element); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// MapFromJSON reads the entries of a JSON object, whose keys encoding/json
+// reads as K.
+func MapFromJSON[K comparable, V any](data []byte, fromJSON func([]byte) (V, error)) (map[K]V, error) {
+	var entries map[K]json.RawMessage
+	if err := json.Unmarshal(data, &entries); err != nil {
+		return nil, err
+	}
+	value := make(map[K]V, len(entries))
+	for key, entry := range entries {
+		item, err := fromJSON(entry)
+		if err != nil {
+			return nil, err
+		}
+		value[key] = item
+	}
+	return value, nil
+}
+
+// Field is a field of an Object.
+type Field struct {
+	Key   string
+	Value any
+}
+
+// Object is a JSON object whose fields are written in order, as serde_json
+// writes the fields of a struct.
+type Object []Field
+
+// MarshalJSON writes the fields in order.
+func (object Object) MarshalJSON() ([]byte, error) {
+	data := []byte{'{'}
+	for i, field := range object {
+		if i > 0 {
+			data = append(data, ',')
+		}
+		key, err := json.Marshal(field.Key)
+		if err != nil {
+			return nil, err
+		}
+		value, err := json.Marshal(field.Value)
+		if err != nil {
+			return nil, err
+		}
+		data = append(data, key...)
+		data = append(data, ':')
+		data = append(data, value...)
+	}
+	return append(data, '}'), nil
+}
+
+// InternallyTagged writes value, which must be a JSON object, with the tag
+// field naming its variant before its own fields.
+func InternallyTagged(tag string, name string, value any) ([]byte, error) {
+	content, err := json.Marshal(value)
+	if err != nil {
+		return nil, err
+	}
+	content = bytes.TrimSpace(content)
+	if len(content) < 2 || content[0] != '{' {
+		return nil, NewSerializationError("internally tagged variant " + name + " must be an object")
+	}
+	data, err := json.Marshal(Object{{Key: tag, Value: name}})
+	if err != nil {
+		return nil, err
+	}
+	rest := bytes.TrimSpace(content[1:])
+	if rest[0] == '}' {
+		return data, nil
+	}
+	data = append(data[:len(data)-1], ',')
+	return append(data, rest...), nil
+}
+
+// ExternalTag splits an externally tagged enum into its tag and content: a
+// unit variant is written as its name, and any other as {"Name": content}.
+func ExternalTag(data []byte) (string, []byte, error) {
+	var name string
+	if err := json.Unmarshal(data, &name); err == nil {
+		return name, nil, nil
+	}
+	fields, err := ObjectFromJSON(data)
+	if err != nil {
+		return "", nil, err
+	}
+	if len(fields) != 1 {
+		return "", nil, NewDeserializationError("expected an object with a single field, got " + string(data))
+	}
+	for name, content := range fields {
+		return name, content, nil
+	}
+	panic("unreachable")
+}
+
+// InternalTag reads the tag field of an internally tagged enum, whose other
+// fields are the variant's.
+func InternalTag(data []byte, tag string) (string, error) {
+	fields, err := ObjectFromJSON(data)
+	if err != nil {
+		return "", err
+	}
+	field, ok := fields[tag]
+	if !ok {
+		return "", MissingFieldError(tag)
+	}
+	var name string
+	if err := json.Unmarshal(field, &name); err != nil {
+		return "", err
+	}
+	return name, nil
+}
+
+// AdjacentTag reads the tag and content fields of an adjacently tagged enum.
+// Unit variants have no content.
+func AdjacentTag(data []byte, tag string, content string) (string, []byte, error) {
+	name, err := InternalTag(data, tag)
+	if err != nil {
+		return "", nil, err
+	}
+	fields, _ := ObjectFromJSON(data)
+	return name, fields[content], nil
+}
@@ -0,0 +1,139 @@
+// Package serde is the runtime of the Go types generated by facet-generate.
+//
+// It declares the Serializer and Deserializer interfaces that binary formats
+// such as bincode implement, the types that stand in for Rust types that Go
+// doesn't have (Unit, Bytes, Uint128, UUID, tuples, …), and the helpers that
+// the generated Serialize methods, Deserialize functions and JSON methods
+// call.
+package serde
+
+import (
+	"fmt"
+	"time"
+)
+
+// Serializer writes values in a binary format.
+type Serializer interface {
+	SerializeStr(value string) error
+	SerializeBytes(value Bytes) error
+	SerializeBool(value bool) error
+	SerializeUnit(value Unit) error
+	SerializeChar(value string) error
+	SerializeF32(value float32) error
+	SerializeF64(value float64) error
+	SerializeU8(value uint8) error
+	SerializeU16(value uint16) error
+	SerializeU32(value uint32) error
+	SerializeU64(value uint64) error
+	SerializeU128(value Uint128) error
+	SerializeI8(value int8) error
+	SerializeI16(value int16) error
+	SerializeI32(value int32) error
+	SerializeI64(value int64) error
+	SerializeI128(value Int128) error
+	SerializeUUID(value UUID) error
+	SerializeDate(value string) error
+	SerializeTime(value string) error
+	SerializeTimestamp(value time.Time) error
+	SerializeDuration(value Duration) error
+	SerializeLen(value uint64) error
+	SerializeVariantIndex(value uint32) error
+	SerializeOptionTag(value bool) error
+	IncreaseContainerDepth() error
+	DecreaseContainerDepth()
+	GetBufferOffset() uint64
+	// SortMapEntries orders the entries of a map that start at offsets,
+	// which were written in Go's random map iteration order.
+	SortMapEntries(offsets []uint64)
+	GetBytes() []byte
+}
+
+// Deserializer reads values in a binary format.
+type Deserializer interface {
+	DeserializeStr() (string, error)
+	DeserializeBytes() (Bytes, error)
+	DeserializeBool() (bool, error)
+	DeserializeUnit() (Unit, error)
+	DeserializeChar() (string, error)
+	DeserializeF32() (float32, error)
+	DeserializeF64() (float64, error)
+	DeserializeU8() (uint8, error)
+	DeserializeU16() (uint16, error)
+	DeserializeU32() (uint32, error)
+	DeserializeU64() (uint64, error)
+	DeserializeU128() (Uint128, error)
+	DeserializeI8() (int8, error)
+	DeserializeI16() (int16, error)
+	DeserializeI32() (int32, error)
+	DeserializeI64() (int64, error)
+	DeserializeI128() (Int128, error)
+	DeserializeUUID() (UUID, error)
+	DeserializeDate() (string, error)
+	DeserializeTime() (string, error)
+	DeserializeTimestamp() (time.Time, error)
+	DeserializeDuration() (Duration, error)
+	DeserializeLen() (uint64, error)
+	DeserializeVariantIndex() (uint32, error)
+	DeserializeOptionTag() (bool, error)
+	IncreaseContainerDepth() error
+	DecreaseContainerDepth()
+	GetBufferOffset() uint64
+}
+
+// SerializationError is returned when a value can't be written.
+type SerializationError struct {
+	Message string
+}
+
+func (err *SerializationError) Error() string {
+	return err.Message
+}
+
+// DeserializationError is returned when the input can't be read as the
+// expected type.
+type DeserializationError struct {
+	Message string
+}
+
+func (err *DeserializationError) Error() string {
+	return err.Message
+}
+
+// NewSerializationError returns a SerializationError with message.
+func NewSerializationError(message string) error {
+	return &SerializationError{Message: message}
+}
+
+// NewDeserializationError returns a DeserializationError with message.
+func NewDeserializationError(message string) error {
+	return &DeserializationError{Message: message}
+}
+
+// UnknownVariantError is returned when the variant index of an enum isn't
+// one of its variants'.
+func UnknownVariantError(typeName string, index uint32) error {
+	return NewDeserializationError(fmt.Sprintf("unknown variant index for %s: %d", typeName, index))
+}
+
+// UnknownVariantNameError is returned when the JSON tag of an enum isn't one
+// of its variants' names.
+func UnknownVariantNameError(typeName string, name string) error {
+	return NewDeserializationError(fmt.Sprintf("unknown variant for %s: %q", typeName, name))
+}
+
+// NoMatchingVariantError is returned when no variant of an untagged enum can
+// be read from the JSON value.
+func NoMatchingVariantError(typeName string) error {
+	return NewDeserializationError(fmt.Sprintf("no variant of %s matches the JSON value", typeName))
+}
+
+// UnknownValueError is returned when the value of an enum whose variants are
+// all unit variants isn't one of its constants.
+func UnknownValueError(typeName string, value string) error {
+	return NewSerializationError(fmt.Sprintf("unknown value of %s: %q", typeName, value))
+}
+
+// MissingFieldError is returned when a JSON object lacks a required field.
+func MissingFieldError(name string) error {
+	return NewDeserializationError(fmt.Sprintf("missing field %q", name))
+}
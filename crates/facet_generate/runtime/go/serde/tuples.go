@@ -0,0 +1,411 @@
+package serde
+
+import "encoding/json"
+
+// Go has no tuples, so Rust tuples of two to six elements are these generic
+// structs, which are written as arrays in JSON.
+
+// Tuple2 is a tuple of 2 elements.
+type Tuple2[A, B any] struct {
+	Field0 A
+	Field1 B
+}
+
+// MarshalJSON writes the tuple as an array.
+func (value Tuple2[A, B]) MarshalJSON() ([]byte, error) {
+	return json.Marshal([]any{value.Field0, value.Field1})
+}
+
+// UnmarshalJSON reads an array of 2 elements.
+func (value *Tuple2[A, B]) UnmarshalJSON(data []byte) error {
+	tuple, err := Tuple2FromJSON(data, FromJSON[A], FromJSON[B])
+	if err != nil {
+		return err
+	}
+	*value = tuple
+	return nil
+}
+
+// SerializeTuple2 writes the elements of a tuple.
+func SerializeTuple2[A, B any](serializer Serializer, value Tuple2[A, B], serializeA func(Serializer, A) error, serializeB func(Serializer, B) error) error {
+	if err := serializeA(serializer, value.Field0); err != nil {
+		return err
+	}
+	if err := serializeB(serializer, value.Field1); err != nil {
+		return err
+	}
+	return nil
+}
+
+// DeserializeTuple2 reads the elements of a tuple.
+func DeserializeTuple2[A, B any](deserializer Deserializer, deserializeA func(Deserializer) (A, error), deserializeB func(Deserializer) (B, error)) (Tuple2[A, B], error) {
+	var value Tuple2[A, B]
+	var err error
+	if value.Field0, err = deserializeA(deserializer); err != nil {
+		return value, err
+	}
+	if value.Field1, err = deserializeB(deserializer); err != nil {
+		return value, err
+	}
+	return value, nil
+}
+
+// Tuple2FromJSON reads a tuple from a JSON array of 2 elements.
+func Tuple2FromJSON[A, B any](data []byte, fromA func([]byte) (A, error), fromB func([]byte) (B, error)) (Tuple2[A, B], error) {
+	var value Tuple2[A, B]
+	elements, err := ElementsFromJSON(data, 2)
+	if err != nil {
+		return value, err
+	}
+	if value.Field0, err = fromA(elements[0]); err != nil {
+		return value, err
+	}
+	if value.Field1, err = fromB(elements[1]); err != nil {
+		return value, err
+	}
+	return value, nil
+}
+
+// Tuple3 is a tuple of 3 elements.
+type Tuple3[A, B, C any] struct {
+	Field0 A
+	Field1 B
+	Field2 C
+}
+
+// MarshalJSON writes the tuple as an array.
+func (value Tuple3[A, B, C]) MarshalJSON() ([]byte, error) {
+	return json.Marshal([]any{value.Field0, value.Field1, value.Field2})
+}
+
+// UnmarshalJSON reads an array of 3 elements.
+func (value *Tuple3[A, B, C]) UnmarshalJSON(data []byte) error {
+	tuple, err := Tuple3FromJSON(data, FromJSON[A], FromJSON[B], FromJSON[C])
+	if err != nil {
+		return err
+	}
+	*value = tuple
+	return nil
+}
+
+// SerializeTuple3 writes the elements of a tuple.
+func SerializeTuple3[A, B, C any](serializer Serializer, value Tuple3[A, B, C], serializeA func(Serializer, A) error, serializeB func(Serializer, B) error, serializeC func(Serializer, C) error) error {
+	if err := serializeA(serializer, value.Field0); err != nil {
+		return err
+	}
+	if err := serializeB(serializer, value.Field1); err != nil {
+		return err
+	}
+	if err := serializeC(serializer, value.Field2); err != nil {
+		return err
+	}
+	return nil
+}
+
+// DeserializeTuple3 reads the elements of a tuple.
+func DeserializeTuple3[A, B, C any](deserializer Deserializer, deserializeA func(Deserializer) (A, error), deserializeB func(Deserializer) (B, error), deserializeC func(Deserializer) (C, error)) (Tuple3[A, B, C], error) {
+	var value Tuple3[A, B, C]
+	var err error
+	if value.Field0, err = deserializeA(deserializer); err != nil {
+		return value, err
+	}
+	if value.Field1, err = deserializeB(deserializer); err != nil {
+		return value, err
+	}
+	if value.Field2, err = deserializeC(deserializer); err != nil {
+		return value, err
+	}
+	return value, nil
+}
+
+// Tuple3FromJSON reads a tuple from a JSON array of 3 elements.
+func Tuple3FromJSON[A, B, C any](data []byte, fromA func([]byte) (A, error), fromB func([]byte) (B, error), fromC func([]byte) (C, error)) (Tuple3[A, B, C], error) {
+	var value Tuple3[A, B, C]
+	elements, err := ElementsFromJSON(data, 3)
+	if err != nil {
+		return value, err
+	}
+	if value.Field0, err = fromA(elements[0]); err != nil {
+		return value, err
+	}
+	if value.Field1, err = fromB(elements[1]); err != nil {
+		return value, err
+	}
+	if value.Field2, err = fromC(elements[2]); err != nil {
+		return value, err
+	}
+	return value, nil
+}
+
+// Tuple4 is a tuple of 4 elements.
+type Tuple4[A, B, C, D any] struct {
+	Field0 A
+	Field1 B
+	Field2 C
+	Field3 D
+}
+
+// MarshalJSON writes the tuple as an array.
+func (value Tuple4[A, B, C, D]) MarshalJSON() ([]byte, error) {
+	return json.Marshal([]any{value.Field0, value.Field1, value.Field2, value.Field3})
+}
+
+// UnmarshalJSON reads an array of 4 elements.
+func (value *Tuple4[A, B, C, D]) UnmarshalJSON(data []byte) error {
+	tuple, err := Tuple4FromJSON(data, FromJSON[A], FromJSON[B], FromJSON[C], FromJSON[D])
+	if err != nil {
+		return err
+	}
+	*value = tuple
+	return nil
+}
+
+// SerializeTuple4 writes the elements of a tuple.
+func SerializeTuple4[A, B, C, D any](serializer Serializer, value Tuple4[A, B, C, D], serializeA func(Serializer, A) error, serializeB func(Serializer, B) error, serializeC func(Serializer, C) error, serializeD func(Serializer, D) error) error {
+	if err := serializeA(serializer, value.Field0); err != nil {
+		return err
+	}
+	if err := serializeB(serializer, value.Field1); err != nil {
+		return err
+	}
+	if err := serializeC(serializer, value.Field2); err != nil {
+		return err
+	}
+	if err := serializeD(serializer, value.Field3); err != nil {
+		return err
+	}
+	return nil
+}
+
+// DeserializeTuple4 reads the elements of a tuple.
+func DeserializeTuple4[A, B, C, D any](deserializer Deserializer, deserializeA func(Deserializer) (A, error), deserializeB func(Deserializer) (B, error), deserializeC func(Deserializer) (C, error), deserializeD func(Deserializer) (D, error)) (Tuple4[A, B, C, D], error) {
+	var value Tuple4[A, B, C, D]
+	var err error
+	if value.Field0, err = deserializeA(deserializer); err != nil {
+		return value, err
+	}
+	if value.Field1, err = deserializeB(deserializer); err != nil {
+		return value, err
+	}
+	if value.Field2, err = deserializeC(deserializer); err != nil {
+		return value, err
+	}
+	if value.Field3, err = deserializeD(deserializer); err != nil {
+		return value, err
+	}
+	return value, nil
+}
+
+// Tuple4FromJSON reads a tuple from a JSON array of 4 elements.
+func Tuple4FromJSON[A, B, C, D any](data []byte, fromA func([]byte) (A, error), fromB func([]byte) (B, error), fromC func([]byte) (C, error), fromD func([]byte) (D, error)) (Tuple4[A, B, C, D], error) {
+	var value Tuple4[A, B, C, D]
+	elements, err := ElementsFromJSON(data, 4)
+	if err != nil {
+		return value, err
+	}
+	if value.Field0, err = fromA(elements[0]); err != nil {
+		return value, err
+	}
+	if value.Field1, err = fromB(elements[1]); err != nil {
+		return value, err
+	}
+	if value.Field2, err = fromC(elements[2]); err != nil {
+		return value, err
+	}
+	if value.Field3, err = fromD(elements[3]); err != nil {
+		return value, err
+	}
+	return value, nil
+}
+
+// Tuple5 is a tuple of 5 elements.
+type Tuple5[A, B, C, D, E any] struct {
+	Field0 A
+	Field1 B
+	Field2 C
+	Field3 D
+	Field4 E
+}
+
+// MarshalJSON writes the tuple as an array.
+func (value Tuple5[A, B, C, D, E]) MarshalJSON() ([]byte, error) {
+	return json.Marshal([]any{value.Field0, value.Field1, value.Field2, value.Field3, value.Field4})
+}
+
+// UnmarshalJSON reads an array of 5 elements.
+func (value *Tuple5[A, B, C, D, E]) UnmarshalJSON(data []byte) error {
+	tuple, err := Tuple5FromJSON(data, FromJSON[A], FromJSON[B], FromJSON[C], FromJSON[D], FromJSON[E])
+	if err != nil {
+		return err
+	}
+	*value = tuple
+	return nil
+}
+
+// SerializeTuple5 writes the elements of a tuple.
+func SerializeTuple5[A, B, C, D, E any](serializer Serializer, value Tuple5[A, B, C, D, E], serializeA func(Serializer, A) error, serializeB func(Serializer, B) error, serializeC func(Serializer, C) error, serializeD func(Serializer, D) error, serializeE func(Serializer, E) error) error {
+	if err := serializeA(serializer, value.Field0); err != nil {
+		return err
+	}
+	if err := serializeB(serializer, value.Field1); err != nil {
+		return err
+	}
+	if err := serializeC(serializer, value.Field2); err != nil {
+		return err
+	}
+	if err := serializeD(serializer, value.Field3); err != nil {
+		return err
+	}
+	if err := serializeE(serializer, value.Field4); err != nil {
+		return err
+	}
+	return nil
+}
+
+// DeserializeTuple5 reads the elements of a tuple.
+func DeserializeTuple5[A, B, C, D, E any](deserializer Deserializer, deserializeA func(Deserializer) (A, error), deserializeB func(Deserializer) (B, error), deserializeC func(Deserializer) (C, error), deserializeD func(Deserializer) (D, error), deserializeE func(Deserializer) (E, error)) (Tuple5[A, B, C, D, E], error) {
+	var value Tuple5[A, B, C, D, E]
+	var err error
+	if value.Field0, err = deserializeA(deserializer); err != nil {
+		return value, err
+	}
+	if value.Field1, err = deserializeB(deserializer); err != nil {
+		return value, err
+	}
+	if value.Field2, err = deserializeC(deserializer); err != nil {
+		return value, err
+	}
+	if value.Field3, err = deserializeD(deserializer); err != nil {
+		return value, err
+	}
+	if value.Field4, err = deserializeE(deserializer); err != nil {
+		return value, err
+	}
+	return value, nil
+}
+
+// Tuple5FromJSON reads a tuple from a JSON array of 5 elements.
+func Tuple5FromJSON[A, B, C, D, E any](data []byte, fromA func([]byte) (A, error), fromB func([]byte) (B, error), fromC func([]byte) (C, error), fromD func([]byte) (D, error), fromE func([]byte) (E, error)) (Tuple5[A, B, C, D, E], error) {
+	var value Tuple5[A, B, C, D, E]
+	elements, err := ElementsFromJSON(data, 5)
+	if err != nil {
+		return value, err
+	}
+	if value.Field0, err = fromA(elements[0]); err != nil {
+		return value, err
+	}
+	if value.Field1, err = fromB(elements[1]); err != nil {
+		return value, err
+	}
+	if value.Field2, err = fromC(elements[2]); err != nil {
+		return value, err
+	}
+	if value.Field3, err = fromD(elements[3]); err != nil {
+		return value, err
+	}
+	if value.Field4, err = fromE(elements[4]); err != nil {
+		return value, err
+	}
+	return value, nil
+}
+
+// Tuple6 is a tuple of 6 elements.
+type Tuple6[A, B, C, D, E, F any] struct {
+	Field0 A
+	Field1 B
+	Field2 C
+	Field3 D
+	Field4 E
+	Field5 F
+}
+
+// MarshalJSON writes the tuple as an array.
+func (value Tuple6[A, B, C, D, E, F]) MarshalJSON() ([]byte, error) {
+	return json.Marshal([]any{value.Field0, value.Field1, value.Field2, value.Field3, value.Field4, value.Field5})
+}
+
+// UnmarshalJSON reads an array of 6 elements.
+func (value *Tuple6[A, B, C, D, E, F]) UnmarshalJSON(data []byte) error {
+	tuple, err := Tuple6FromJSON(data, FromJSON[A], FromJSON[B], FromJSON[C], FromJSON[D], FromJSON[E], FromJSON[F])
+	if err != nil {
+		return err
+	}
+	*value = tuple
+	return nil
+}
+
+// SerializeTuple6 writes the elements of a tuple.
+func SerializeTuple6[A, B, C, D, E, F any](serializer Serializer, value Tuple6[A, B, C, D, E, F], serializeA func(Serializer, A) error, serializeB func(Serializer, B) error, serializeC func(Serializer, C) error, serializeD func(Serializer, D) error, serializeE func(Serializer, E) error, serializeF func(Serializer, F) error) error {
+	if err := serializeA(serializer, value.Field0); err != nil {
+		return err
+	}
+	if err := serializeB(serializer, value.Field1); err != nil {
+		return err
+	}
+	if err := serializeC(serializer, value.Field2); err != nil {
+		return err
+	}
+	if err := serializeD(serializer, value.Field3); err != nil {
+		return err
+	}
+	if err := serializeE(serializer, value.Field4); err != nil {
+		return err
+	}
+	if err := serializeF(serializer, value.Field5); err != nil {
+		return err
+	}
+	return nil
+}
+
+// DeserializeTuple6 reads the elements of a tuple.
+func DeserializeTuple6[A, B, C, D, E, F any](deserializer Deserializer, deserializeA func(Deserializer) (A, error), deserializeB func(Deserializer) (B, error), deserializeC func(Deserializer) (C, error), deserializeD func(Deserializer) (D, error), deserializeE func(Deserializer) (E, error), deserializeF func(Deserializer) (F, error)) (Tuple6[A, B, C, D, E, F], error) {
+	var value Tuple6[A, B, C, D, E, F]
+	var err error
+	if value.Field0, err = deserializeA(deserializer); err != nil {
+		return value, err
+	}
+	if value.Field1, err = deserializeB(deserializer); err != nil {
+		return value, err
+	}
+	if value.Field2, err = deserializeC(deserializer); err != nil {
+		return value, err
+	}
+	if value.Field3, err = deserializeD(deserializer); err != nil {
+		return value, err
+	}
+	if value.Field4, err = deserializeE(deserializer); err != nil {
+		return value, err
+	}
+	if value.Field5, err = deserializeF(deserializer); err != nil {
+		return value, err
+	}
+	return value, nil
+}
+
+// Tuple6FromJSON reads a tuple from a JSON array of 6 elements.
+func Tuple6FromJSON[A, B, C, D, E, F any](data []byte, fromA func([]byte) (A, error), fromB func([]byte) (B, error), fromC func([]byte) (C, error), fromD func([]byte) (D, error), fromE func([]byte) (E, error), fromF func([]byte) (F, error)) (Tuple6[A, B, C, D, E, F], error) {
+	var value Tuple6[A, B, C, D, E, F]
+	elements, err := ElementsFromJSON(data, 6)
+	if err != nil {
+		return value, err
+	}
+	if value.Field0, err = fromA(elements[0]); err != nil {
+		return value, err
+	}
+	if value.Field1, err = fromB(elements[1]); err != nil {
+		return value, err
+	}
+	if value.Field2, err = fromC(elements[2]); err != nil {
+		return value, err
+	}
+	if value.Field3, err = fromD(elements[3]); err != nil {
+		return value, err
+	}
+	if value.Field4, err = fromE(elements[4]); err != nil {
+		return value, err
+	}
+	if value.Field5, err = fromF(elements[5]); err != nil {
+		return value, err
+	}
+	return value, nil
+}
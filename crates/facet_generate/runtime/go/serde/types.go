@@ -0,0 +1,172 @@
+package serde
+
+import (
+	"encoding/hex"
+	"encoding/json"
+	"math/big"
+	"strconv"
+)
+
+// Unit is the Go type of Rust's `()`, which is written as `null` in JSON.
+type Unit struct{}
+
+// MarshalJSON writes `null`.
+func (Unit) MarshalJSON() ([]byte, error) {
+	return []byte("null"), nil
+}
+
+// UnmarshalJSON reads `null`.
+func (*Unit) UnmarshalJSON(data []byte) error {
+	return UnitFromJSON(data)
+}
+
+// Bytes is a byte string, which serde_json writes as an array of numbers
+// rather than the base64 string that encoding/json gives []byte.
+type Bytes []byte
+
+// MarshalJSON writes the bytes as an array of numbers.
+func (value Bytes) MarshalJSON() ([]byte, error) {
+	data := []byte{'['}
+	for i, b := range value {
+		if i > 0 {
+			data = append(data, ',')
+		}
+		data = strconv.AppendUint(data, uint64(b), 10)
+	}
+	return append(data, ']'), nil
+}
+
+// UnmarshalJSON reads an array of numbers.
+func (value *Bytes) UnmarshalJSON(data []byte) error {
+	var numbers []uint16
+	if err := json.Unmarshal(data, &numbers); err != nil {
+		return err
+	}
+	bytes := make(Bytes, len(numbers))
+	for i, number := range numbers {
+		if number > 0xff {
+			return NewDeserializationError("byte out of range: " + strconv.Itoa(int(number)))
+		}
+		bytes[i] = byte(number)
+	}
+	*value = bytes
+	return nil
+}
+
+// Uint128 is an unsigned 128-bit integer: High<<64 | Low.
+type Uint128 struct {
+	High uint64
+	Low  uint64
+}
+
+// Big returns the value as a big.Int.
+func (value Uint128) Big() *big.Int {
+	result := new(big.Int).Lsh(new(big.Int).SetUint64(value.High), 64)
+	return result.Or(result, new(big.Int).SetUint64(value.Low))
+}
+
+// MarshalJSON writes the value as a number.
+func (value Uint128) MarshalJSON() ([]byte, error) {
+	return []byte(value.Big().String()), nil
+}
+
+// UnmarshalJSON reads a number.
+func (value *Uint128) UnmarshalJSON(data []byte) error {
+	number, ok := new(big.Int).SetString(string(data), 10)
+	if !ok || number.Sign() < 0 || number.BitLen() > 128 {
+		return NewDeserializationError("invalid u128: " + string(data))
+	}
+	mask := new(big.Int).SetUint64(^uint64(0))
+	value.Low = new(big.Int).And(number, mask).Uint64()
+	value.High = new(big.Int).Rsh(number, 64).Uint64()
+	return nil
+}
+
+// Int128 is a signed 128-bit integer: High<<64 + Low.
+type Int128 struct {
+	High int64
+	Low  uint64
+}
+
+// Big returns the value as a big.Int.
+func (value Int128) Big() *big.Int {
+	result := new(big.Int).Lsh(big.NewInt(value.High), 64)
+	return result.Add(result, new(big.Int).SetUint64(value.Low))
+}
+
+// MarshalJSON writes the value as a number.
+func (value Int128) MarshalJSON() ([]byte, error) {
+	return []byte(value.Big().String()), nil
+}
+
+// UnmarshalJSON reads a number.
+func (value *Int128) UnmarshalJSON(data []byte) error {
+	number, ok := new(big.Int).SetString(string(data), 10)
+	if !ok {
+		return NewDeserializationError("invalid i128: " + string(data))
+	}
+	high := new(big.Int).Rsh(number, 64)
+	if !high.IsInt64() {
+		return NewDeserializationError("i128 out of range: " + string(data))
+	}
+	low := new(big.Int).Sub(number, new(big.Int).Lsh(high, 64))
+	value.High = high.Int64()
+	value.Low = low.Uint64()
+	return nil
+}
+
+// UUID is a universally unique identifier, written as its 16 bytes in binary
+// formats and as its hyphenated hexadecimal form in JSON.
+type UUID [16]byte
+
+// String returns the hyphenated hexadecimal form of the UUID.
+func (value UUID) String() string {
+	text, _ := value.MarshalText()
+	return string(text)
+}
+
+// MarshalText writes the hyphenated hexadecimal form of the UUID.
+func (value UUID) MarshalText() ([]byte, error) {
+	text := make([]byte, 36)
+	hex.Encode(text[0:8], value[0:4])
+	text[8] = '-'
+	hex.Encode(text[9:13], value[4:6])
+	text[13] = '-'
+	hex.Encode(text[14:18], value[6:8])
+	text[18] = '-'
+	hex.Encode(text[19:23], value[8:10])
+	text[23] = '-'
+	hex.Encode(text[24:], value[10:])
+	return text, nil
+}
+
+// UnmarshalText reads the hyphenated hexadecimal form of a UUID.
+func (value *UUID) UnmarshalText(text []byte) error {
+	if len(text) != 36 || text[8] != '-' || text[13] != '-' || text[18] != '-' || text[23] != '-' {
+		return NewDeserializationError("invalid UUID: " + string(text))
+	}
+	digits := make([]byte, 0, 32)
+	digits = append(digits, text[0:8]...)
+	digits = append(digits, text[9:13]...)
+	digits = append(digits, text[14:18]...)
+	digits = append(digits, text[19:23]...)
+	digits = append(digits, text[24:]...)
+	if _, err := hex.Decode(value[:], digits); err != nil {
+		return NewDeserializationError("invalid UUID: " + string(text))
+	}
+	return nil
+}
+
+// ParseUUID reads the hyphenated hexadecimal form of a UUID.
+func ParseUUID(text string) (UUID, error) {
+	var value UUID
+	err := value.UnmarshalText([]byte(text))
+	return value, err
+}
+
+// Duration is a span of time, in the shape serde gives Rust's
+// std::time::Duration.
+type Duration struct {
+	Secs  uint64 `json:"secs"`
+	Nanos uint32 `json:"nanos"`
+}
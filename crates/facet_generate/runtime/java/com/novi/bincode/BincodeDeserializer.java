@@ -0,0 +1,43 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+package com.novi.bincode;
+
+import com.novi.serde.BinaryDeserializer;
+import com.novi.serde.DeserializationError;
+import com.novi.serde.Slice;
+
+public class BincodeDeserializer extends BinaryDeserializer {
+    public BincodeDeserializer(byte[] input) {
+        super(input, Long.MAX_VALUE);
+    }
+
+    @Override
+    public float deserialize_f32() throws DeserializationError {
+        return Float.intBitsToFloat(getInt());
+    }
+
+    @Override
+    public double deserialize_f64() throws DeserializationError {
+        return Double.longBitsToDouble(getLong());
+    }
+
+    @Override
+    public long deserialize_len() throws DeserializationError {
+        long value = getLong();
+        if (value < 0 || value > Integer.MAX_VALUE) {
+            throw new DeserializationError("Incorrect length value");
+        }
+        return value;
+    }
+
+    @Override
+    public int deserialize_variant_index() throws DeserializationError {
+        return getInt();
+    }
+
+    @Override
+    public void check_that_key_slices_are_increasing(Slice key1, Slice key2) {
+        // Not required by the format.
+    }
+}
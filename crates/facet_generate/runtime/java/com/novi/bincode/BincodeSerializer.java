@@ -0,0 +1,38 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+package com.novi.bincode;
+
+import com.novi.serde.BinarySerializer;
+import com.novi.serde.SerializationError;
+
+public class BincodeSerializer extends BinarySerializer {
+    public BincodeSerializer() {
+        super(Long.MAX_VALUE);
+    }
+
+    @Override
+    public void serialize_f32(float value) throws SerializationError {
+        serialize_i32(Float.floatToRawIntBits(value));
+    }
+
+    @Override
+    public void serialize_f64(double value) throws SerializationError {
+        serialize_i64(Double.doubleToRawLongBits(value));
+    }
+
+    @Override
+    public void serialize_len(long value) throws SerializationError {
+        serialize_u64(value);
+    }
+
+    @Override
+    public void serialize_variant_index(int value) throws SerializationError {
+        serialize_u32(value);
+    }
+
+    @Override
+    public void sort_map_entries(int[] offsets) {
+        // Not required by the format.
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+package com.novi.serde;
+
+import java.math.BigInteger;
+import java.nio.ByteBuffer;
+import java.nio.charset.CharacterCodingException;
+import java.nio.charset.CodingErrorAction;
+import java.nio.charset.StandardCharsets;
+
+public abstract class BinaryDeserializer implements Deserializer {
+    private static final String INPUT_NOT_LARGE_ENOUGH = "Input is not large enough";
+
+    protected final byte[] input;
+    private int position = 0;
+    private long containerDepthBudget;
+
+    public BinaryDeserializer(byte[] input, long maxContainerDepth) {
+        this.input = input;
+        this.containerDepthBudget = maxContainerDepth;
+    }
+
+    @Override
+    public void increase_container_depth() throws DeserializationError {
+        if (containerDepthBudget == 0) {
+            throw new DeserializationError("Exceeded maximum container depth");
+        }
+        containerDepthBudget -= 1;
+    }
+
+    @Override
+    public void decrease_container_depth() {
+        containerDepthBudget += 1;
+    }
+
+    @Override
+    public String deserialize_str() throws DeserializationError {
+        long len = deserialize_len();
+        if (len < 0 || len > Integer.MAX_VALUE) {
+            throw new DeserializationError("Incorrect length value for Java string");
+        }
+        return decodeUtf8(readBytes((int) len));
+    }
+
+    @Override
+    public Bytes deserialize_bytes() throws DeserializationError {
+        long len = deserialize_len();
+        if (len < 0 || len > Integer.MAX_VALUE) {
+            throw new DeserializationError("Incorrect length value for Java array");
+        }
+        return new Bytes(readBytes((int) len));
+    }
+
+    @Override
+    public boolean deserialize_bool() throws DeserializationError {
+        byte value = getByte();
+        switch (value) {
+            case 0:
+                return false;
+            case 1:
+                return true;
+            default:
+                throw new DeserializationError("Incorrect boolean value");
+        }
+    }
+
+    @Override
+    public Unit deserialize_unit() throws DeserializationError {
+        return new Unit();
+    }
+
+    /** Reads the UTF-8 encoding of a single character. */
+    @Override
+    public String deserialize_char() throws DeserializationError {
+        requireAvailable(1);
+        int lead = input[position] & 0xff;
+        int length = lead < 0x80 ? 1 : lead < 0xe0 ? 2 : lead < 0xf0 ? 3 : 4;
+        return decodeUtf8(readBytes(length));
+    }
+
+    @Override
+    public byte deserialize_u8() throws DeserializationError {
+        return getByte();
+    }
+
+    @Override
+    public short deserialize_u16() throws DeserializationError {
+        int b0 = getByte() & 0xff;
+        int b1 = getByte() & 0xff;
+        return (short) (b0 | (b1 << 8));
+    }
+
+    @Override
+    public int deserialize_u32() throws DeserializationError {
+        return getInt();
+    }
+
+    @Override
+    public long deserialize_u64() throws DeserializationError {
+        return getLong();
+    }
+
+    @Override
+    public BigInteger deserialize_u128() throws DeserializationError {
+        BigInteger low = unsigned(deserialize_u64());
+        BigInteger high = unsigned(deserialize_u64());
+        return high.shiftLeft(64).or(low);
+    }
+
+    @Override
+    public byte deserialize_i8() throws DeserializationError {
+        return getByte();
+    }
+
+    @Override
+    public short deserialize_i16() throws DeserializationError {
+        return deserialize_u16();
+    }
+
+    @Override
+    public int deserialize_i32() throws DeserializationError {
+        return getInt();
+    }
+
+    @Override
+    public long deserialize_i64() throws DeserializationError {
+        return getLong();
+    }
+
+    @Override
+    public BigInteger deserialize_i128() throws DeserializationError {
+        BigInteger low = unsigned(deserialize_u64());
+        BigInteger high = BigInteger.valueOf(deserialize_i64());
+        return high.shiftLeft(64).or(low);
+    }
+
+    @Override
+    public boolean deserialize_option_tag() throws DeserializationError {
+        return deserialize_bool();
+    }
+
+    @Override
+    public int get_buffer_offset() {
+        return position;
+    }
+
+    protected int getInt() throws DeserializationError {
+        int b0 = getByte() & 0xff;
+        int b1 = getByte() & 0xff;
+        int b2 = getByte() & 0xff;
+        int b3 = getByte() & 0xff;
+        return b0 | (b1 << 8) | (b2 << 16) | (b3 << 24);
+    }
+
+    protected long getLong() throws DeserializationError {
+        long value = 0;
+        for (int shift = 0; shift < 64; shift += 8) {
+            value |= (getByte() & 0xffL) << shift;
+        }
+        return value;
+    }
+
+    protected byte getByte() throws DeserializationError {
+        requireAvailable(1);
+        byte value = input[position];
+        position += 1;
+        return value;
+    }
+
+    private byte[] readBytes(int count) throws DeserializationError {
+        requireAvailable(count);
+        byte[] slice = java.util.Arrays.copyOfRange(input, position, position + count);
+        position += count;
+        return slice;
+    }
+
+    private void requireAvailable(int count) throws DeserializationError {
+        if (count > input.length - position) {
+            throw new DeserializationError(INPUT_NOT_LARGE_ENOUGH);
+        }
+    }
+
+    private static String decodeUtf8(byte[] content) throws DeserializationError {
+        try {
+            return StandardCharsets.UTF_8
+                    .newDecoder()
+                    .onMalformedInput(CodingErrorAction.REPORT)
+                    .onUnmappableCharacter(CodingErrorAction.REPORT)
+                    .decode(ByteBuffer.wrap(content))
+                    .toString();
+        } catch (CharacterCodingException e) {
+            throw new DeserializationError("Incorrect UTF8 string");
+        }
+    }
+
+    private static BigInteger unsigned(long value) {
+        BigInteger result = BigInteger.valueOf(value & Long.MAX_VALUE);
+        return value < 0 ? result.setBit(63) : result;
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+package com.novi.serde;
+
+import java.io.ByteArrayOutputStream;
+import java.math.BigInteger;
+import java.nio.charset.StandardCharsets;
+
+public abstract class BinarySerializer implements Serializer {
+    private static final BigInteger MASK_64 = BigInteger.ONE.shiftLeft(64).subtract(BigInteger.ONE);
+
+    protected final ByteArrayOutputStream output = new ByteArrayOutputStream();
+    private long containerDepthBudget;
+
+    public BinarySerializer(long maxContainerDepth) {
+        this.containerDepthBudget = maxContainerDepth;
+    }
+
+    @Override
+    public void increase_container_depth() throws SerializationError {
+        if (containerDepthBudget == 0) {
+            throw new SerializationError("Exceeded maximum container depth");
+        }
+        containerDepthBudget -= 1;
+    }
+
+    @Override
+    public void decrease_container_depth() {
+        containerDepthBudget += 1;
+    }
+
+    @Override
+    public void serialize_str(String value) throws SerializationError {
+        serialize_bytes(new Bytes(value.getBytes(StandardCharsets.UTF_8)));
+    }
+
+    @Override
+    public void serialize_bytes(Bytes value) throws SerializationError {
+        byte[] content = value.content();
+        serialize_len(content.length);
+        output.write(content, 0, content.length);
+    }
+
+    @Override
+    public void serialize_bool(boolean value) throws SerializationError {
+        output.write(value ? 1 : 0);
+    }
+
+    @Override
+    public void serialize_unit(Unit value) throws SerializationError {
+        // Nothing to serialize.
+    }
+
+    /** Writes the UTF-8 encoding of a single character. */
+    @Override
+    public void serialize_char(String value) throws SerializationError {
+        if (value.codePointCount(0, value.length()) != 1) {
+            throw new SerializationError("Expected a single character, got \"" + value + "\"");
+        }
+        byte[] bytes = value.getBytes(StandardCharsets.UTF_8);
+        output.write(bytes, 0, bytes.length);
+    }
+
+    @Override
+    public void serialize_u8(byte value) throws SerializationError {
+        output.write(value);
+    }
+
+    @Override
+    public void serialize_u16(short value) throws SerializationError {
+        output.write(value);
+        output.write(value >>> 8);
+    }
+
+    @Override
+    public void serialize_u32(int value) throws SerializationError {
+        output.write(value);
+        output.write(value >>> 8);
+        output.write(value >>> 16);
+        output.write(value >>> 24);
+    }
+
+    @Override
+    public void serialize_u64(long value) throws SerializationError {
+        for (int i = 0; i < 8; i++) {
+            output.write((int) (value >>> (i * 8)));
+        }
+    }
+
+    @Override
+    public void serialize_u128(BigInteger value) throws SerializationError {
+        if (value.signum() < 0 || value.bitLength() > 128) {
+            throw new SerializationError("Invalid value for an unsigned 128-bit integer");
+        }
+        serialize_u64(value.and(MASK_64).longValue());
+        serialize_u64(value.shiftRight(64).longValue());
+    }
+
+    @Override
+    public void serialize_i8(byte value) throws SerializationError {
+        serialize_u8(value);
+    }
+
+    @Override
+    public void serialize_i16(short value) throws SerializationError {
+        serialize_u16(value);
+    }
+
+    @Override
+    public void serialize_i32(int value) throws SerializationError {
+        serialize_u32(value);
+    }
+
+    @Override
+    public void serialize_i64(long value) throws SerializationError {
+        serialize_u64(value);
+    }
+
+    @Override
+    public void serialize_i128(BigInteger value) throws SerializationError {
+        if (value.bitLength() > 127) {
+            throw new SerializationError("Invalid value for a signed 128-bit integer");
+        }
+        serialize_u64(value.and(MASK_64).longValue());
+        serialize_i64(value.shiftRight(64).longValue());
+    }
+
+    @Override
+    public void serialize_option_tag(boolean value) throws SerializationError {
+        output.write(value ? 1 : 0);
+    }
+
+    @Override
+    public int get_buffer_offset() {
+        return output.size();
+    }
+
+    @Override
+    public byte[] get_bytes() {
+        return output.toByteArray();
+    }
+}
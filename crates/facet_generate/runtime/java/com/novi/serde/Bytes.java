@@ -0,0 +1,48 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+package com.novi.serde;
+
+/**
+ * An immutable byte string, compared by its content rather than by reference like a
+ * {@code byte[]}.
+ */
+public final class Bytes {
+    private final byte[] content;
+
+    public Bytes(byte[] content) {
+        this.content = content.clone();
+    }
+
+    public static Bytes empty() {
+        return new Bytes(new byte[0]);
+    }
+
+    public static Bytes valueOf(byte[] content) {
+        return new Bytes(content);
+    }
+
+    /** A copy of the bytes. */
+    public byte[] content() {
+        return content.clone();
+    }
+
+    public int length() {
+        return content.length;
+    }
+
+    @Override
+    public boolean equals(Object other) {
+        return other instanceof Bytes && java.util.Arrays.equals(content, ((Bytes) other).content);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Arrays.hashCode(content);
+    }
+
+    @Override
+    public String toString() {
+        return java.util.Arrays.toString(content);
+    }
+}
@@ -0,0 +1,10 @@
+package com.novi.serde;
+
+/**
+ * Reads a value of type {@code T}: how the generated code passes the deserialization of the
+ * elements of a collection, or of a generic type's type arguments.
+ */
+@FunctionalInterface
+public interface Deserialize<T> {
+    T deserialize(Deserializer deserializer) throws DeserializationError;
+}
@@ -0,0 +1,161 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+package com.novi.serde;
+
+/**
+ * Reads values in a binary format such as bincode.
+ *
+ * <p>Unsigned integers are returned in the signed primitive of the same width, whose bits they
+ * share, and 128-bit integers as {@link java.math.BigInteger}s.
+ *
+ * <p>The default methods read the values of the standard library types that the generated code
+ * uses in terms of the primitive methods.
+ */
+public interface Deserializer {
+    String deserialize_str() throws DeserializationError;
+
+    Bytes deserialize_bytes() throws DeserializationError;
+
+    boolean deserialize_bool() throws DeserializationError;
+
+    Unit deserialize_unit() throws DeserializationError;
+
+    String deserialize_char() throws DeserializationError;
+
+    float deserialize_f32() throws DeserializationError;
+
+    double deserialize_f64() throws DeserializationError;
+
+    byte deserialize_u8() throws DeserializationError;
+
+    short deserialize_u16() throws DeserializationError;
+
+    int deserialize_u32() throws DeserializationError;
+
+    long deserialize_u64() throws DeserializationError;
+
+    java.math.BigInteger deserialize_u128() throws DeserializationError;
+
+    byte deserialize_i8() throws DeserializationError;
+
+    short deserialize_i16() throws DeserializationError;
+
+    int deserialize_i32() throws DeserializationError;
+
+    long deserialize_i64() throws DeserializationError;
+
+    java.math.BigInteger deserialize_i128() throws DeserializationError;
+
+    long deserialize_len() throws DeserializationError;
+
+    int deserialize_variant_index() throws DeserializationError;
+
+    boolean deserialize_option_tag() throws DeserializationError;
+
+    void increase_container_depth() throws DeserializationError;
+
+    void decrease_container_depth();
+
+    int get_buffer_offset();
+
+    void check_that_key_slices_are_increasing(Slice key1, Slice key2) throws DeserializationError;
+
+    /** Reads an optional value: a tag, then the value if there is one. */
+    default <T> java.util.Optional<T> deserialize_option(Deserialize<T> deserializeValue)
+            throws DeserializationError {
+        if (deserialize_option_tag()) {
+            return java.util.Optional.of(deserializeValue.deserialize(this));
+        }
+        return java.util.Optional.empty();
+    }
+
+    /** Reads a sequence: its length, then its elements. */
+    default <T> java.util.List<T> deserialize_seq(Deserialize<T> deserializeElement)
+            throws DeserializationError {
+        long length = deserialize_len();
+        java.util.List<T> list = new java.util.ArrayList<>();
+        for (long i = 0; i < length; i++) {
+            list.add(deserializeElement.deserialize(this));
+        }
+        return list;
+    }
+
+    /** Reads a set, written as a sequence. */
+    default <T> java.util.Set<T> deserialize_set(Deserialize<T> deserializeElement)
+            throws DeserializationError {
+        return new java.util.LinkedHashSet<>(deserialize_seq(deserializeElement));
+    }
+
+    /** Reads a map: its length, then each key followed by its value. */
+    default <K, V> java.util.Map<K, V> deserialize_map(
+            Deserialize<K> deserializeKey, Deserialize<V> deserializeValue)
+            throws DeserializationError {
+        long length = deserialize_len();
+        java.util.Map<K, V> map = new java.util.LinkedHashMap<>();
+        Slice previousKey = null;
+        for (long i = 0; i < length; i++) {
+            int start = get_buffer_offset();
+            K key = deserializeKey.deserialize(this);
+            Slice keySlice = new Slice(start, get_buffer_offset());
+            if (previousKey != null) {
+                check_that_key_slices_are_increasing(previousKey, keySlice);
+            }
+            previousKey = keySlice;
+            map.put(key, deserializeValue.deserialize(this));
+        }
+        return map;
+    }
+
+    /** Reads a fixed-size array of {@code size} elements, written without a length. */
+    default <T> java.util.List<T> deserialize_tuple_array(
+            int size, Deserialize<T> deserializeElement) throws DeserializationError {
+        java.util.List<T> list = new java.util.ArrayList<>(size);
+        for (int i = 0; i < size; i++) {
+            list.add(deserializeElement.deserialize(this));
+        }
+        return list;
+    }
+
+    /** Reads a UUID written as its 16 bytes. */
+    default java.util.UUID deserialize_uuid() throws DeserializationError {
+        byte[] bytes = deserialize_bytes().content();
+        if (bytes.length != 16) {
+            throw new DeserializationError("UUID must be 16 bytes, got " + bytes.length);
+        }
+        long msb = 0;
+        long lsb = 0;
+        for (int i = 0; i < 8; i++) {
+            msb = (msb << 8) | (bytes[i] & 0xff);
+            lsb = (lsb << 8) | (bytes[8 + i] & 0xff);
+        }
+        return new java.util.UUID(msb, lsb);
+    }
+
+    /** Reads a timestamp written as an RFC 3339 string. */
+    default java.time.Instant deserialize_timestamp() throws DeserializationError {
+        return Json.parseTimestamp(deserialize_str());
+    }
+
+    /** Reads a date written as an ISO 8601 string. */
+    default java.time.LocalDate deserialize_date() throws DeserializationError {
+        return Json.parseDate(deserialize_str());
+    }
+
+    /** Reads a time written as an ISO 8601 string. */
+    default java.time.LocalTime deserialize_time() throws DeserializationError {
+        return Json.parseTime(deserialize_str());
+    }
+
+    /** Reads a duration written as its whole seconds and the remaining nanoseconds. */
+    default java.time.Duration deserialize_duration() throws DeserializationError {
+        increase_container_depth();
+        long seconds = deserialize_u64();
+        int nanos = deserialize_u32();
+        decrease_container_depth();
+        if (seconds < 0 || nanos < 0 || nanos >= 1_000_000_000) {
+            throw new DeserializationError("Invalid duration");
+        }
+        return java.time.Duration.ofSeconds(seconds, nanos);
+    }
+}
@@ -0,0 +1,7 @@
+package com.novi.serde;
+
+/** Reads a value of type {@code T} from a JSON value that {@link Json} parsed. */
+@FunctionalInterface
+public interface FromJson<T> {
+    T fromJson(Object json) throws DeserializationError;
+}
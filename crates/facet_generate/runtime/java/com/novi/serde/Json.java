@@ -0,0 +1,780 @@
+package com.novi.serde;
+
+import java.math.BigDecimal;
+import java.math.BigInteger;
+import java.time.Duration;
+import java.time.Instant;
+import java.time.LocalDate;
+import java.time.LocalTime;
+import java.time.format.DateTimeParseException;
+import java.util.ArrayList;
+import java.util.Collection;
+import java.util.LinkedHashMap;
+import java.util.LinkedHashSet;
+import java.util.List;
+import java.util.Map;
+import java.util.Optional;
+import java.util.Set;
+import java.util.UUID;
+
+/**
+ * Reads and writes JSON, and the helpers of the generated {@code toJson} and {@code fromJson}
+ * methods, which convert to and from the shapes that {@code serde_json} gives the Rust types.
+ *
+ * <p>JSON values are plain Java objects: {@code null}, {@link Boolean}, {@link String}, {@link
+ * Number} ({@link Long} or {@link BigInteger} for integers, {@link Double} for other numbers
+ * when parsed), {@link List} for arrays and {@link Map} with {@link String} keys for objects.
+ *
+ * <p>The readers check the type of the value they're given, so that a mismatch throws a {@link
+ * DeserializationError} rather than building a wrong value.
+ */
+public final class Json {
+    private static final BigInteger MAX_U64 = BigInteger.ONE.shiftLeft(64).subtract(BigInteger.ONE);
+    private static final BigInteger MAX_U128 =
+            BigInteger.ONE.shiftLeft(128).subtract(BigInteger.ONE);
+    private static final BigInteger MIN_I128 = BigInteger.ONE.shiftLeft(127).negate();
+    private static final BigInteger MAX_I128 =
+            BigInteger.ONE.shiftLeft(127).subtract(BigInteger.ONE);
+
+    private Json() {}
+
+    // ---------------------------------------------------------------------------------------
+    // Text
+    // ---------------------------------------------------------------------------------------
+
+    /** Parses JSON text into a JSON value. */
+    public static Object parse(String text) throws DeserializationError {
+        Parser parser = new Parser(text);
+        parser.skipWhitespace();
+        Object value = parser.value();
+        parser.skipWhitespace();
+        if (parser.position < text.length()) {
+            throw parser.error("Trailing characters");
+        }
+        return value;
+    }
+
+    /** Writes a JSON value as compact JSON text, like {@code serde_json::to_string}. */
+    public static String stringify(Object value) {
+        StringBuilder out = new StringBuilder();
+        write(out, value);
+        return out.toString();
+    }
+
+    private static void write(StringBuilder out, Object value) {
+        if (value == null) {
+            out.append("null");
+        } else if (value instanceof Boolean) {
+            out.append(value);
+        } else if (value instanceof String) {
+            writeString(out, (String) value);
+        } else if (value instanceof Float) {
+            float number = (Float) value;
+            out.append(Float.isFinite(number) ? formatNumber(Float.toString(number)) : "null");
+        } else if (value instanceof Double) {
+            double number = (Double) value;
+            out.append(Double.isFinite(number) ? formatNumber(Double.toString(number)) : "null");
+        } else if (value instanceof Number) {
+            out.append(value);
+        } else if (value instanceof Map) {
+            out.append('{');
+            boolean first = true;
+            for (Map.Entry<?, ?> entry : ((Map<?, ?>) value).entrySet()) {
+                if (!first) {
+                    out.append(',');
+                }
+                first = false;
+                writeString(out, String.valueOf(entry.getKey()));
+                out.append(':');
+                write(out, entry.getValue());
+            }
+            out.append('}');
+        } else if (value instanceof Collection) {
+            out.append('[');
+            boolean first = true;
+            for (Object element : (Collection<?>) value) {
+                if (!first) {
+                    out.append(',');
+                }
+                first = false;
+                write(out, element);
+            }
+            out.append(']');
+        } else {
+            throw new IllegalArgumentException("Not a JSON value: " + value.getClass());
+        }
+    }
+
+    /**
+     * Formats a floating-point number from its shortest Java representation the way {@code
+     * serde_json} does: in plain notation with a fractional part between 1e-5 and 1e16, and in
+     * scientific notation like {@code 1e20} otherwise.
+     */
+    private static String formatNumber(String shortest) {
+        BigDecimal decimal = new BigDecimal(shortest);
+        BigDecimal magnitude = decimal.abs();
+        if (decimal.signum() == 0
+                || (magnitude.compareTo(new BigDecimal("1e-5")) >= 0
+                        && magnitude.compareTo(new BigDecimal("1e16")) < 0)) {
+            String plain = decimal.toPlainString();
+            if (decimal.signum() == 0 && shortest.startsWith("-")) {
+                plain = "-" + plain;
+            }
+            return plain.contains(".") ? plain : plain + ".0";
+        }
+        String scientific = shortest.replace("E", "e");
+        return scientific.replace(".0e", "e");
+    }
+
+    private static void writeString(StringBuilder out, String value) {
+        out.append('"');
+        for (int i = 0; i < value.length(); i++) {
+            char c = value.charAt(i);
+            switch (c) {
+                case '"':
+                    out.append("\\\"");
+                    break;
+                case '\\':
+                    out.append("\\\\");
+                    break;
+                case '\n':
+                    out.append("\\n");
+                    break;
+                case '\r':
+                    out.append("\\r");
+                    break;
+                case '\t':
+                    out.append("\\t");
+                    break;
+                case '\b':
+                    out.append("\\b");
+                    break;
+                case '\f':
+                    out.append("\\f");
+                    break;
+                default:
+                    if (c < 0x20) {
+                        out.append(String.format("\\u%04x", (int) c));
+                    } else {
+                        out.append(c);
+                    }
+            }
+        }
+        out.append('"');
+    }
+
+    private static final class Parser {
+        private final String text;
+        private int position = 0;
+
+        Parser(String text) {
+            this.text = text;
+        }
+
+        DeserializationError error(String message) {
+            return new DeserializationError(message + " at position " + position);
+        }
+
+        void skipWhitespace() {
+            while (position < text.length()) {
+                char c = text.charAt(position);
+                if (c != ' ' && c != '\t' && c != '\n' && c != '\r') {
+                    break;
+                }
+                position++;
+            }
+        }
+
+        char peek() throws DeserializationError {
+            if (position >= text.length()) {
+                throw error("Unexpected end of input");
+            }
+            return text.charAt(position);
+        }
+
+        void expect(String literal) throws DeserializationError {
+            if (!text.startsWith(literal, position)) {
+                throw error("Expected " + literal);
+            }
+            position += literal.length();
+        }
+
+        Object value() throws DeserializationError {
+            char c = peek();
+            switch (c) {
+                case 'n':
+                    expect("null");
+                    return null;
+                case 't':
+                    expect("true");
+                    return true;
+                case 'f':
+                    expect("false");
+                    return false;
+                case '"':
+                    return string();
+                case '[':
+                    return array();
+                case '{':
+                    return object();
+                default:
+                    if (c == '-' || (c >= '0' && c <= '9')) {
+                        return number();
+                    }
+                    throw error("Unexpected character '" + c + "'");
+            }
+        }
+
+        List<Object> array() throws DeserializationError {
+            position++;
+            List<Object> list = new ArrayList<>();
+            skipWhitespace();
+            if (peek() == ']') {
+                position++;
+                return list;
+            }
+            while (true) {
+                skipWhitespace();
+                list.add(value());
+                skipWhitespace();
+                char c = peek();
+                position++;
+                if (c == ']') {
+                    return list;
+                }
+                if (c != ',') {
+                    throw error("Expected ',' or ']'");
+                }
+            }
+        }
+
+        Map<String, Object> object() throws DeserializationError {
+            position++;
+            Map<String, Object> map = new LinkedHashMap<>();
+            skipWhitespace();
+            if (peek() == '}') {
+                position++;
+                return map;
+            }
+            while (true) {
+                skipWhitespace();
+                if (peek() != '"') {
+                    throw error("Expected a key");
+                }
+                String key = string();
+                skipWhitespace();
+                expect(":");
+                skipWhitespace();
+                map.put(key, value());
+                skipWhitespace();
+                char c = peek();
+                position++;
+                if (c == '}') {
+                    return map;
+                }
+                if (c != ',') {
+                    throw error("Expected ',' or '}'");
+                }
+            }
+        }
+
+        String string() throws DeserializationError {
+            position++;
+            StringBuilder out = new StringBuilder();
+            while (true) {
+                char c = peek();
+                position++;
+                if (c == '"') {
+                    return out.toString();
+                }
+                if (c < 0x20) {
+                    throw error("Control character in string");
+                }
+                if (c != '\\') {
+                    out.append(c);
+                    continue;
+                }
+                char escape = peek();
+                position++;
+                switch (escape) {
+                    case '"':
+                    case '\\':
+                    case '/':
+                        out.append(escape);
+                        break;
+                    case 'b':
+                        out.append('\b');
+                        break;
+                    case 'f':
+                        out.append('\f');
+                        break;
+                    case 'n':
+                        out.append('\n');
+                        break;
+                    case 'r':
+                        out.append('\r');
+                        break;
+                    case 't':
+                        out.append('\t');
+                        break;
+                    case 'u':
+                        if (position + 4 > text.length()) {
+                            throw error("Invalid unicode escape");
+                        }
+                        try {
+                            out.append((char) Integer.parseInt(text.substring(position, position + 4), 16));
+                        } catch (NumberFormatException e) {
+                            throw error("Invalid unicode escape");
+                        }
+                        position += 4;
+                        break;
+                    default:
+                        throw error("Invalid escape");
+                }
+            }
+        }
+
+        Object number() throws DeserializationError {
+            int start = position;
+            boolean integral = true;
+            while (position < text.length()) {
+                char c = text.charAt(position);
+                if (c == '.' || c == 'e' || c == 'E') {
+                    integral = false;
+                } else if (!(c == '-' || c == '+' || (c >= '0' && c <= '9'))) {
+                    break;
+                }
+                position++;
+            }
+            String literal = text.substring(start, position);
+            try {
+                if (integral) {
+                    BigInteger value = new BigInteger(literal);
+                    return value.bitLength() < 64 ? (Object) value.longValue() : value;
+                }
+                return Double.parseDouble(literal);
+            } catch (NumberFormatException e) {
+                throw error("Invalid number " + literal);
+            }
+        }
+    }
+
+    // ---------------------------------------------------------------------------------------
+    // Readers
+    // ---------------------------------------------------------------------------------------
+
+    private static DeserializationError mismatch(String expected, Object json) {
+        return new DeserializationError("Expected " + expected + ", got " + stringify(json));
+    }
+
+    public static Map<String, Object> objectFromJson(Object json) throws DeserializationError {
+        if (!(json instanceof Map)) {
+            throw mismatch("an object", json);
+        }
+        @SuppressWarnings("unchecked")
+        Map<String, Object> object = (Map<String, Object>) json;
+        return object;
+    }
+
+    public static List<Object> arrayFromJson(Object json) throws DeserializationError {
+        if (!(json instanceof List)) {
+            throw mismatch("an array", json);
+        }
+        @SuppressWarnings("unchecked")
+        List<Object> array = (List<Object>) json;
+        return array;
+    }
+
+    /** Reads a tuple, written as an array of {@code length} elements. */
+    public static List<Object> tupleFromJson(Object json, int length) throws DeserializationError {
+        List<Object> array = arrayFromJson(json);
+        if (array.size() != length) {
+            throw mismatch("an array of " + length + " elements", json);
+        }
+        return array;
+    }
+
+    /** The value of a field that {@code object} must have. */
+    public static Object field(Map<String, Object> object, String key) throws DeserializationError {
+        if (!object.containsKey(key)) {
+            throw new DeserializationError("Missing field " + stringify(key));
+        }
+        return object.get(key);
+    }
+
+    public static Unit unitFromJson(Object json) throws DeserializationError {
+        if (json != null) {
+            throw mismatch("null", json);
+        }
+        return new Unit();
+    }
+
+    public static boolean boolFromJson(Object json) throws DeserializationError {
+        if (!(json instanceof Boolean)) {
+            throw mismatch("a bool", json);
+        }
+        return (Boolean) json;
+    }
+
+    public static String strFromJson(Object json) throws DeserializationError {
+        if (!(json instanceof String)) {
+            throw mismatch("a string", json);
+        }
+        return (String) json;
+    }
+
+    public static String charFromJson(Object json) throws DeserializationError {
+        if (!(json instanceof String)
+                || ((String) json).codePointCount(0, ((String) json).length()) != 1) {
+            throw mismatch("a single character", json);
+        }
+        return (String) json;
+    }
+
+    private static BigInteger integer(Object json, BigInteger min, BigInteger max)
+            throws DeserializationError {
+        BigInteger value;
+        if (json instanceof Long || json instanceof Integer || json instanceof Short
+                || json instanceof Byte) {
+            value = BigInteger.valueOf(((Number) json).longValue());
+        } else if (json instanceof BigInteger) {
+            value = (BigInteger) json;
+        } else {
+            throw mismatch("an integer", json);
+        }
+        if (value.compareTo(min) < 0 || value.compareTo(max) > 0) {
+            throw new DeserializationError("Integer out of range: " + value);
+        }
+        return value;
+    }
+
+    private static BigInteger bound(long value) {
+        return BigInteger.valueOf(value);
+    }
+
+    public static byte i8FromJson(Object json) throws DeserializationError {
+        return integer(json, bound(Byte.MIN_VALUE), bound(Byte.MAX_VALUE)).byteValue();
+    }
+
+    public static short i16FromJson(Object json) throws DeserializationError {
+        return integer(json, bound(Short.MIN_VALUE), bound(Short.MAX_VALUE)).shortValue();
+    }
+
+    public static int i32FromJson(Object json) throws DeserializationError {
+        return integer(json, bound(Integer.MIN_VALUE), bound(Integer.MAX_VALUE)).intValue();
+    }
+
+    public static long i64FromJson(Object json) throws DeserializationError {
+        return integer(json, bound(Long.MIN_VALUE), bound(Long.MAX_VALUE)).longValue();
+    }
+
+    public static BigInteger i128FromJson(Object json) throws DeserializationError {
+        return integer(json, MIN_I128, MAX_I128);
+    }
+
+    public static byte u8FromJson(Object json) throws DeserializationError {
+        return integer(json, BigInteger.ZERO, bound(0xff)).byteValue();
+    }
+
+    public static short u16FromJson(Object json) throws DeserializationError {
+        return integer(json, BigInteger.ZERO, bound(0xffff)).shortValue();
+    }
+
+    public static int u32FromJson(Object json) throws DeserializationError {
+        return integer(json, BigInteger.ZERO, bound(0xffffffffL)).intValue();
+    }
+
+    public static long u64FromJson(Object json) throws DeserializationError {
+        return integer(json, BigInteger.ZERO, MAX_U64).longValue();
+    }
+
+    public static BigInteger u128FromJson(Object json) throws DeserializationError {
+        return integer(json, BigInteger.ZERO, MAX_U128);
+    }
+
+    public static float f32FromJson(Object json) throws DeserializationError {
+        return (float) f64FromJson(json);
+    }
+
+    public static double f64FromJson(Object json) throws DeserializationError {
+        if (!(json instanceof Number)) {
+            throw mismatch("a number", json);
+        }
+        return ((Number) json).doubleValue();
+    }
+
+    /** Reads bytes, written as an array of numbers. */
+    public static Bytes bytesFromJson(Object json) throws DeserializationError {
+        List<Object> array = arrayFromJson(json);
+        byte[] bytes = new byte[array.size()];
+        for (int i = 0; i < bytes.length; i++) {
+            bytes[i] = u8FromJson(array.get(i));
+        }
+        return new Bytes(bytes);
+    }
+
+    public static UUID uuidFromJson(Object json) throws DeserializationError {
+        String text = strFromJson(json);
+        try {
+            return UUID.fromString(text);
+        } catch (IllegalArgumentException e) {
+            throw new DeserializationError("Invalid UUID: " + text);
+        }
+    }
+
+    public static Instant timestampFromJson(Object json) throws DeserializationError {
+        return parseTimestamp(strFromJson(json));
+    }
+
+    public static LocalDate dateFromJson(Object json) throws DeserializationError {
+        return parseDate(strFromJson(json));
+    }
+
+    public static LocalTime timeFromJson(Object json) throws DeserializationError {
+        return parseTime(strFromJson(json));
+    }
+
+    /** Reads a duration written as {@code {"secs": ..., "nanos": ...}}. */
+    public static Duration durationFromJson(Object json) throws DeserializationError {
+        Map<String, Object> object = objectFromJson(json);
+        long secs = u64FromJson(object.get("secs"));
+        int nanos = u32FromJson(object.get("nanos"));
+        if (secs < 0 || nanos < 0 || nanos >= 1_000_000_000) {
+            throw new DeserializationError("Invalid duration: " + stringify(json));
+        }
+        return Duration.ofSeconds(secs, nanos);
+    }
+
+    /** Reads {@code json} with {@code fromJson}, unless it is {@code null}. */
+    public static <T> Optional<T> optionFromJson(Object json, FromJson<T> fromJson)
+            throws DeserializationError {
+        return json == null ? Optional.empty() : Optional.of(fromJson.fromJson(json));
+    }
+
+    public static <T> List<T> seqFromJson(Object json, FromJson<T> fromElement)
+            throws DeserializationError {
+        List<T> list = new ArrayList<>();
+        for (Object element : arrayFromJson(json)) {
+            list.add(fromElement.fromJson(element));
+        }
+        return list;
+    }
+
+    public static <T> Set<T> setFromJson(Object json, FromJson<T> fromElement)
+            throws DeserializationError {
+        return new LinkedHashSet<>(seqFromJson(json, fromElement));
+    }
+
+    /** Reads an array of {@code size} elements. */
+    public static <T> List<T> tupleArrayFromJson(Object json, int size, FromJson<T> fromElement)
+            throws DeserializationError {
+        return seqFromJson(tupleFromJson(json, size), fromElement);
+    }
+
+    /**
+     * Reads a map, written as an object: {@code fromKey} reads each key from its string, or from
+     * the number or bool that {@link #numberFromKey} or {@link #boolFromKey} parse it as.
+     */
+    public static <K, V> Map<K, V> mapFromJson(
+            Object json, FromJson<K> fromKey, FromJson<V> fromValue) throws DeserializationError {
+        Map<K, V> map = new LinkedHashMap<>();
+        for (Map.Entry<String, Object> entry : objectFromJson(json).entrySet()) {
+            map.put(fromKey.fromJson(entry.getKey()), fromValue.fromJson(entry.getValue()));
+        }
+        return map;
+    }
+
+    /** Parses a map key written as a number, e.g. {@code "1"}. */
+    public static Object numberFromKey(Object key) throws DeserializationError {
+        String text = strFromJson(key);
+        Object value = parse(text);
+        if (!(value instanceof Number)) {
+            throw mismatch("a number", key);
+        }
+        return value;
+    }
+
+    /** Parses a map key written as a bool, i.e. {@code "true"} or {@code "false"}. */
+    public static boolean boolFromKey(Object key) throws DeserializationError {
+        String text = strFromJson(key);
+        if (text.equals("true") || text.equals("false")) {
+            return text.equals("true");
+        }
+        throw mismatch("a bool", key);
+    }
+
+    /**
+     * Splits an externally tagged enum into its tag and content: a unit variant is written as
+     * its name, and any other as {@code {"Name": content}}.
+     */
+    public static Map.Entry<String, Object> externalTag(Object json) throws DeserializationError {
+        if (json instanceof String) {
+            return new java.util.AbstractMap.SimpleImmutableEntry<>((String) json, null);
+        }
+        if (json instanceof Map && ((Map<?, ?>) json).size() == 1) {
+            return objectFromJson(json).entrySet().iterator().next();
+        }
+        throw mismatch("an enum variant", json);
+    }
+
+    // ---------------------------------------------------------------------------------------
+    // Writers
+    // ---------------------------------------------------------------------------------------
+
+    /** Builds a JSON object from its keys and values, which alternate, in order. */
+    public static Map<String, Object> object(Object... keysAndValues) {
+        Map<String, Object> object = new LinkedHashMap<>();
+        for (int i = 0; i + 1 < keysAndValues.length; i += 2) {
+            object.put((String) keysAndValues[i], keysAndValues[i + 1]);
+        }
+        return object;
+    }
+
+    /** Builds a JSON array of its elements. */
+    public static List<Object> array(Object... elements) {
+        List<Object> array = new ArrayList<>(elements.length);
+        for (Object element : elements) {
+            array.add(element);
+        }
+        return array;
+    }
+
+    /**
+     * Adds the tag of an internally tagged enum's variant to its content, which must be an
+     * object: {@code {"tag": "Name", ...content}}.
+     */
+    public static Map<String, Object> withTag(String tag, String name, Object content) {
+        Map<String, Object> object = new LinkedHashMap<>();
+        object.put(tag, name);
+        if (content instanceof Map) {
+            for (Map.Entry<?, ?> entry : ((Map<?, ?>) content).entrySet()) {
+                object.put((String) entry.getKey(), entry.getValue());
+            }
+        }
+        return object;
+    }
+
+    public static Object u8ToJson(byte value) {
+        return Byte.toUnsignedInt(value);
+    }
+
+    public static Object u16ToJson(short value) {
+        return Short.toUnsignedInt(value);
+    }
+
+    public static Object u32ToJson(int value) {
+        return Integer.toUnsignedLong(value);
+    }
+
+    public static Object u64ToJson(long value) {
+        return value >= 0 ? (Object) value : new BigInteger(Long.toUnsignedString(value));
+    }
+
+    /** Writes bytes as an array of numbers. */
+    public static Object bytesToJson(Bytes value) {
+        List<Object> array = new ArrayList<>();
+        for (byte b : value.content()) {
+            array.add(Byte.toUnsignedInt(b));
+        }
+        return array;
+    }
+
+    /** Writes a timestamp as an RFC 3339 string in UTC. */
+    public static Object timestampToJson(Instant value) {
+        return value.toString();
+    }
+
+    public static Object dateToJson(LocalDate value) {
+        return value.toString();
+    }
+
+    public static Object timeToJson(LocalTime value) {
+        return formatTime(value);
+    }
+
+    /**
+     * Writes a duration as {@code {"secs": ..., "nanos": ...}}, the shape {@code serde} gives {@code
+     * std::time::Duration}.
+     */
+    public static Object durationToJson(Duration value) {
+        Map<String, Object> object = new LinkedHashMap<>();
+        object.put("secs", value.getSeconds());
+        object.put("nanos", value.getNano());
+        return object;
+    }
+
+    /** Converts {@code value} with {@code toJson}, unless it is empty. */
+    public static <T> Object optionToJson(Optional<T> value, ToJson<T> toJson) {
+        return value.isPresent() ? toJson.toJson(value.get()) : null;
+    }
+
+    public static <T> Object seqToJson(Collection<T> value, ToJson<T> toElement) {
+        List<Object> array = new ArrayList<>();
+        for (T element : value) {
+            array.add(toElement.toJson(element));
+        }
+        return array;
+    }
+
+    /**
+     * Writes a map as an object, whose keys are the strings of the JSON values that {@code
+     * toKey} converts the keys to.
+     */
+    public static <K, V> Object mapToJson(Map<K, V> value, ToJson<K> toKey, ToJson<V> toValue) {
+        Map<String, Object> object = new LinkedHashMap<>();
+        for (Map.Entry<K, V> entry : value.entrySet()) {
+            object.put(String.valueOf(toKey.toJson(entry.getKey())), toValue.toJson(entry.getValue()));
+        }
+        return object;
+    }
+
+    // ---------------------------------------------------------------------------------------
+    // Dates and times
+    // ---------------------------------------------------------------------------------------
+
+    /** Parses an RFC 3339 timestamp, in any offset. */
+    public static Instant parseTimestamp(String text) throws DeserializationError {
+        try {
+            return java.time.OffsetDateTime.parse(text).toInstant();
+        } catch (DateTimeParseException e) {
+            throw new DeserializationError("Invalid RFC 3339 timestamp: " + text);
+        }
+    }
+
+    public static LocalDate parseDate(String text) throws DeserializationError {
+        try {
+            return LocalDate.parse(text);
+        } catch (DateTimeParseException e) {
+            throw new DeserializationError("Invalid ISO 8601 date: " + text);
+        }
+    }
+
+    public static LocalTime parseTime(String text) throws DeserializationError {
+        try {
+            return LocalTime.parse(text);
+        } catch (DateTimeParseException e) {
+            throw new DeserializationError("Invalid ISO 8601 time: " + text);
+        }
+    }
+
+    /**
+     * Formats a time like {@code chrono} does: with its seconds, and a fraction of 3, 6 or 9
+     * digits if it has one.
+     */
+    public static String formatTime(LocalTime value) {
+        String text =
+                String.format(
+                        "%02d:%02d:%02d", value.getHour(), value.getMinute(), value.getSecond());
+        int nanos = value.getNano();
+        if (nanos == 0) {
+            return text;
+        }
+        if (nanos % 1_000_000 == 0) {
+            return text + String.format(".%03d", nanos / 1_000_000);
+        }
+        if (nanos % 1_000 == 0) {
+            return text + String.format(".%06d", nanos / 1_000);
+        }
+        return text + String.format(".%09d", nanos);
+    }
+}
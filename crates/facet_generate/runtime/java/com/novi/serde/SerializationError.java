@@ -0,0 +1,12 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+package com.novi.serde;
+
+public class SerializationError extends Exception {
+    private static final long serialVersionUID = 1L;
+
+    public SerializationError(String message) {
+        super(message);
+    }
+}
@@ -0,0 +1,10 @@
+package com.novi.serde;
+
+/**
+ * Writes a value of type {@code T}: how the generated code passes the serialization of the
+ * elements of a collection, or of a generic type's type arguments.
+ */
+@FunctionalInterface
+public interface Serialize<T> {
+    void serialize(Serializer serializer, T value) throws SerializationError;
+}
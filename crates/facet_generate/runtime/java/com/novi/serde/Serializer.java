@@ -0,0 +1,152 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+package com.novi.serde;
+
+/**
+ * Writes values in a binary format such as bincode.
+ *
+ * <p>Unsigned integers are passed in the signed primitive of the same width, whose bits they
+ * share: a {@code u32} of {@code 4294967295} is the {@code int} {@code -1}. 128-bit integers are
+ * {@link java.math.BigInteger}s.
+ *
+ * <p>The default methods write the values of the standard library types that the generated code
+ * uses in terms of the primitive methods.
+ */
+public interface Serializer {
+    void serialize_str(String value) throws SerializationError;
+
+    void serialize_bytes(Bytes value) throws SerializationError;
+
+    void serialize_bool(boolean value) throws SerializationError;
+
+    void serialize_unit(Unit value) throws SerializationError;
+
+    void serialize_char(String value) throws SerializationError;
+
+    void serialize_f32(float value) throws SerializationError;
+
+    void serialize_f64(double value) throws SerializationError;
+
+    void serialize_u8(byte value) throws SerializationError;
+
+    void serialize_u16(short value) throws SerializationError;
+
+    void serialize_u32(int value) throws SerializationError;
+
+    void serialize_u64(long value) throws SerializationError;
+
+    void serialize_u128(java.math.BigInteger value) throws SerializationError;
+
+    void serialize_i8(byte value) throws SerializationError;
+
+    void serialize_i16(short value) throws SerializationError;
+
+    void serialize_i32(int value) throws SerializationError;
+
+    void serialize_i64(long value) throws SerializationError;
+
+    void serialize_i128(java.math.BigInteger value) throws SerializationError;
+
+    void serialize_len(long value) throws SerializationError;
+
+    void serialize_variant_index(int value) throws SerializationError;
+
+    void serialize_option_tag(boolean value) throws SerializationError;
+
+    void increase_container_depth() throws SerializationError;
+
+    void decrease_container_depth();
+
+    int get_buffer_offset();
+
+    void sort_map_entries(int[] offsets);
+
+    byte[] get_bytes();
+
+    /** Writes an optional value: a tag, then the value if there is one. */
+    default <T> void serialize_option(java.util.Optional<T> value, Serialize<T> serializeValue)
+            throws SerializationError {
+        if (value.isPresent()) {
+            serialize_option_tag(true);
+            serializeValue.serialize(this, value.get());
+        } else {
+            serialize_option_tag(false);
+        }
+    }
+
+    /** Writes a sequence: its length, then its elements. */
+    default <T> void serialize_seq(java.util.Collection<T> value, Serialize<T> serializeElement)
+            throws SerializationError {
+        serialize_len(value.size());
+        for (T element : value) {
+            serializeElement.serialize(this, element);
+        }
+    }
+
+    /** Writes a map: its length, then each key followed by its value. */
+    default <K, V> void serialize_map(
+            java.util.Map<K, V> value, Serialize<K> serializeKey, Serialize<V> serializeValue)
+            throws SerializationError {
+        serialize_len(value.size());
+        int[] offsets = new int[value.size()];
+        int count = 0;
+        for (java.util.Map.Entry<K, V> entry : value.entrySet()) {
+            offsets[count++] = get_buffer_offset();
+            serializeKey.serialize(this, entry.getKey());
+            serializeValue.serialize(this, entry.getValue());
+        }
+        sort_map_entries(offsets);
+    }
+
+    /** Writes a fixed-size array: its elements, without a length. */
+    default <T> void serialize_tuple_array(
+            java.util.List<T> value, int size, Serialize<T> serializeElement)
+            throws SerializationError {
+        if (value.size() != size) {
+            throw new SerializationError(
+                    "Expected an array of " + size + " elements, got " + value.size());
+        }
+        for (T element : value) {
+            serializeElement.serialize(this, element);
+        }
+    }
+
+    /** Writes a UUID as its 16 bytes. */
+    default void serialize_uuid(java.util.UUID value) throws SerializationError {
+        byte[] bytes = new byte[16];
+        long msb = value.getMostSignificantBits();
+        long lsb = value.getLeastSignificantBits();
+        for (int i = 0; i < 8; i++) {
+            bytes[i] = (byte) (msb >>> (56 - i * 8));
+            bytes[8 + i] = (byte) (lsb >>> (56 - i * 8));
+        }
+        serialize_bytes(new Bytes(bytes));
+    }
+
+    /** Writes a timestamp as an RFC 3339 string in UTC. */
+    default void serialize_timestamp(java.time.Instant value) throws SerializationError {
+        serialize_str(value.toString());
+    }
+
+    /** Writes a date as an ISO 8601 string. */
+    default void serialize_date(java.time.LocalDate value) throws SerializationError {
+        serialize_str(value.toString());
+    }
+
+    /** Writes a time as an ISO 8601 string. */
+    default void serialize_time(java.time.LocalTime value) throws SerializationError {
+        serialize_str(Json.formatTime(value));
+    }
+
+    /** Writes a duration as its whole seconds and the remaining nanoseconds. */
+    default void serialize_duration(java.time.Duration value) throws SerializationError {
+        if (value.isNegative()) {
+            throw new SerializationError("Duration must not be negative: " + value);
+        }
+        increase_container_depth();
+        serialize_u64(value.getSeconds());
+        serialize_u32(value.getNano());
+        decrease_container_depth();
+    }
+}
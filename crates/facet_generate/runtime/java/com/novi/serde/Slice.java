@@ -0,0 +1,11 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+package com.novi.serde;
+
+public record Slice(int start, int end) {
+    public static int compare_bytes(byte[] content, Slice slice1, Slice slice2) {
+        return java.util.Arrays.compareUnsigned(
+                content, slice1.start, slice1.end, content, slice2.start, slice2.end);
+    }
+}
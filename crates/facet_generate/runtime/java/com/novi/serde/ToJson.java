@@ -0,0 +1,7 @@
+package com.novi.serde;
+
+/** Converts a value of type {@code T} to the JSON value that {@link Json} writes. */
+@FunctionalInterface
+public interface ToJson<T> {
+    Object toJson(T value);
+}
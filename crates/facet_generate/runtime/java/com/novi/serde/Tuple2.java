@@ -0,0 +1,40 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+package com.novi.serde;
+
+public record Tuple2<T0, T1>(T0 field0, T1 field1) {
+    /** Writes the elements in order, each with its serializer. */
+    public void serialize(
+            Serializer serializer, Serialize<T0> serializeT0, Serialize<T1> serializeT1)
+            throws SerializationError {
+        serializeT0.serialize(serializer, field0);
+        serializeT1.serialize(serializer, field1);
+    }
+
+    /** Reads the elements in order, each with its deserializer. */
+    public static <T0, T1> Tuple2<T0, T1> deserialize(
+            Deserializer deserializer, Deserialize<T0> deserializeT0, Deserialize<T1> deserializeT1)
+            throws DeserializationError {
+        T0 field0 = deserializeT0.deserialize(deserializer);
+        T1 field1 = deserializeT1.deserialize(deserializer);
+        return new Tuple2<>(field0, field1);
+    }
+
+    /** Converts the tuple to a JSON array of its elements. */
+    public Object toJson(ToJson<T0> toJsonT0, ToJson<T1> toJsonT1) {
+        return Json.array(
+                toJsonT0.toJson(field0),
+                toJsonT1.toJson(field1));
+    }
+
+    /** Reads the tuple from a JSON array of 2 elements. */
+    public static <T0, T1> Tuple2<T0, T1> fromJson(
+            Object json, FromJson<T0> fromJsonT0, FromJson<T1> fromJsonT1)
+            throws DeserializationError {
+        java.util.List<Object> elements = Json.tupleFromJson(json, 2);
+        return new Tuple2<>(
+                fromJsonT0.fromJson(elements.get(0)),
+                fromJsonT1.fromJson(elements.get(1)));
+    }
+}
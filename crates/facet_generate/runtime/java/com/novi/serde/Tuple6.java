@@ -0,0 +1,80 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+package com.novi.serde;
+
+public record Tuple6<T0, T1, T2, T3, T4, T5>(T0 field0, T1 field1, T2 field2, T3 field3, T4 field4, T5 field5) {
+    /** Writes the elements in order, each with its serializer. */
+    public void serialize(
+            Serializer serializer,
+            Serialize<T0> serializeT0,
+            Serialize<T1> serializeT1,
+            Serialize<T2> serializeT2,
+            Serialize<T3> serializeT3,
+            Serialize<T4> serializeT4,
+            Serialize<T5> serializeT5)
+            throws SerializationError {
+        serializeT0.serialize(serializer, field0);
+        serializeT1.serialize(serializer, field1);
+        serializeT2.serialize(serializer, field2);
+        serializeT3.serialize(serializer, field3);
+        serializeT4.serialize(serializer, field4);
+        serializeT5.serialize(serializer, field5);
+    }
+
+    /** Reads the elements in order, each with its deserializer. */
+    public static <T0, T1, T2, T3, T4, T5> Tuple6<T0, T1, T2, T3, T4, T5> deserialize(
+            Deserializer deserializer,
+            Deserialize<T0> deserializeT0,
+            Deserialize<T1> deserializeT1,
+            Deserialize<T2> deserializeT2,
+            Deserialize<T3> deserializeT3,
+            Deserialize<T4> deserializeT4,
+            Deserialize<T5> deserializeT5)
+            throws DeserializationError {
+        T0 field0 = deserializeT0.deserialize(deserializer);
+        T1 field1 = deserializeT1.deserialize(deserializer);
+        T2 field2 = deserializeT2.deserialize(deserializer);
+        T3 field3 = deserializeT3.deserialize(deserializer);
+        T4 field4 = deserializeT4.deserialize(deserializer);
+        T5 field5 = deserializeT5.deserialize(deserializer);
+        return new Tuple6<>(field0, field1, field2, field3, field4, field5);
+    }
+
+    /** Converts the tuple to a JSON array of its elements. */
+    public Object toJson(
+            ToJson<T0> toJsonT0,
+            ToJson<T1> toJsonT1,
+            ToJson<T2> toJsonT2,
+            ToJson<T3> toJsonT3,
+            ToJson<T4> toJsonT4,
+            ToJson<T5> toJsonT5) {
+        return Json.array(
+                toJsonT0.toJson(field0),
+                toJsonT1.toJson(field1),
+                toJsonT2.toJson(field2),
+                toJsonT3.toJson(field3),
+                toJsonT4.toJson(field4),
+                toJsonT5.toJson(field5));
+    }
+
+    /** Reads the tuple from a JSON array of 6 elements. */
+    public static <T0, T1, T2, T3, T4, T5> Tuple6<T0, T1, T2, T3, T4, T5> fromJson(
+            Object json,
+            FromJson<T0> fromJsonT0,
+            FromJson<T1> fromJsonT1,
+            FromJson<T2> fromJsonT2,
+            FromJson<T3> fromJsonT3,
+            FromJson<T4> fromJsonT4,
+            FromJson<T5> fromJsonT5)
+            throws DeserializationError {
+        java.util.List<Object> elements = Json.tupleFromJson(json, 6);
+        return new Tuple6<>(
+                fromJsonT0.fromJson(elements.get(0)),
+                fromJsonT1.fromJson(elements.get(1)),
+                fromJsonT2.fromJson(elements.get(2)),
+                fromJsonT3.fromJson(elements.get(3)),
+                fromJsonT4.fromJson(elements.get(4)),
+                fromJsonT5.fromJson(elements.get(5)));
+    }
+}
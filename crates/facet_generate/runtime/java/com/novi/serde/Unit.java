@@ -0,0 +1,4 @@
+package com.novi.serde;
+
+/** The Rust unit type {@code ()}, which has a single value and is written as nothing. */
+public record Unit() {}
@@ -0,0 +1,55 @@
+# Copyright (c) Facebook, Inc. and its affiliates
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""The bincode format, used by the generated ``bincode_serialize`` and
+``bincode_deserialize`` methods."""
+
+from __future__ import annotations
+
+from ..serde import BinaryDeserializer, BinarySerializer, DeserializationError
+
+__all__ = ["BincodeDeserializer", "BincodeSerializer"]
+
+# Bincode doesn't limit the nesting of containers.
+MAX_CONTAINER_DEPTH = 0x7FFFFFFF
+
+
+class BincodeSerializer(BinarySerializer):
+    """Writes values in the bincode format: lengths as ``u64`` and variant
+    indices as ``u32``."""
+
+    def __init__(self) -> None:
+        super().__init__(MAX_CONTAINER_DEPTH)
+
+    def serialize_len(self, value: int) -> None:
+        self.serialize_u64(value)
+
+    def serialize_variant_index(self, value: int) -> None:
+        self.serialize_u32(value)
+
+    def sort_map_entries(self, offsets: list[int]) -> None:
+        # Not required by the format.
+        pass
+
+
+class BincodeDeserializer(BinaryDeserializer):
+    """Reads values in the bincode format: lengths as ``u64`` and variant
+    indices as ``u32``."""
+
+    def __init__(self, data: bytes) -> None:
+        super().__init__(data, MAX_CONTAINER_DEPTH)
+
+    def deserialize_len(self) -> int:
+        value = self.deserialize_u64()
+        if value > 0x7FFFFFFF:
+            raise DeserializationError("Incorrect length value")
+        return value
+
+    def deserialize_variant_index(self) -> int:
+        return self.deserialize_u32()
+
+    def check_that_key_slices_are_increasing(
+        self, key1: tuple[int, int], key2: tuple[int, int]
+    ) -> None:
+        # Not required by the format.
+        pass
@@ -0,0 +1,62 @@
+# Copyright (c) Facebook, Inc. and its affiliates
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Serialization runtime for the generated ``serialize`` and ``to_json`` methods."""
+
+from .binary import BinaryDeserializer, BinarySerializer
+from .deserializer import Deserializer
+from .errors import DeserializationError, SerializationError
+from .json import (
+    bool_from_json,
+    bytes_from_json,
+    bytes_to_json,
+    char_from_json,
+    date_from_json,
+    duration_from_json,
+    duration_to_json,
+    external_tag,
+    float_from_json,
+    int_from_json,
+    list_from_json,
+    object_from_json,
+    option_from_json,
+    option_to_json,
+    str_from_json,
+    time_from_json,
+    timestamp_from_json,
+    timestamp_to_json,
+    tuple_from_json,
+    unit_from_json,
+    uuid_from_json,
+)
+from .serializer import Serializer
+
+__all__ = [
+    "BinaryDeserializer",
+    "BinarySerializer",
+    "DeserializationError",
+    "Deserializer",
+    "SerializationError",
+    "Serializer",
+    "bool_from_json",
+    "bytes_from_json",
+    "bytes_to_json",
+    "char_from_json",
+    "date_from_json",
+    "duration_from_json",
+    "duration_to_json",
+    "external_tag",
+    "float_from_json",
+    "int_from_json",
+    "list_from_json",
+    "object_from_json",
+    "option_from_json",
+    "option_to_json",
+    "str_from_json",
+    "time_from_json",
+    "timestamp_from_json",
+    "timestamp_to_json",
+    "tuple_from_json",
+    "unit_from_json",
+    "uuid_from_json",
+]
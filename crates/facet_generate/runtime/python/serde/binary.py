@@ -0,0 +1,208 @@
+# Copyright (c) Facebook, Inc. and its affiliates
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+from __future__ import annotations
+
+import struct
+
+from .deserializer import Deserializer
+from .errors import DeserializationError, SerializationError
+from .serializer import Serializer
+
+
+class BinarySerializer(Serializer):
+    """A ``Serializer`` that writes little-endian values to a byte buffer.
+
+    Formats built on it decide how lengths and variant indices are written.
+    """
+
+    def __init__(self, max_container_depth: int) -> None:
+        self._buffer = bytearray()
+        self._container_depth_budget = max_container_depth
+
+    def increase_container_depth(self) -> None:
+        if self._container_depth_budget == 0:
+            raise SerializationError("Exceeded maximum container depth")
+        self._container_depth_budget -= 1
+
+    def decrease_container_depth(self) -> None:
+        self._container_depth_budget += 1
+
+    def _serialize_int(self, value: int, size: int, signed: bool) -> None:
+        try:
+            self._buffer += value.to_bytes(size, "little", signed=signed)
+        except OverflowError:
+            kind = "i" if signed else "u"
+            raise SerializationError(
+                f"Value out of range for {kind}{size * 8}: {value}"
+            ) from None
+
+    def serialize_str(self, value: str) -> None:
+        self.serialize_bytes(value.encode("utf-8"))
+
+    def serialize_bytes(self, value: bytes) -> None:
+        self.serialize_len(len(value))
+        self._buffer += value
+
+    def serialize_bool(self, value: bool) -> None:
+        self.serialize_u8(1 if value else 0)
+
+    def serialize_unit(self, value: None) -> None:
+        pass
+
+    def serialize_char(self, value: str) -> None:
+        """Writes the UTF-8 encoding of a single character."""
+        if len(value) != 1:
+            raise SerializationError(f"Expected a single character, got {value!r}")
+        self._buffer += value.encode("utf-8")
+
+    def serialize_f32(self, value: float) -> None:
+        self._buffer += struct.pack("<f", value)
+
+    def serialize_f64(self, value: float) -> None:
+        self._buffer += struct.pack("<d", value)
+
+    def serialize_u8(self, value: int) -> None:
+        self._serialize_int(value, 1, False)
+
+    def serialize_u16(self, value: int) -> None:
+        self._serialize_int(value, 2, False)
+
+    def serialize_u32(self, value: int) -> None:
+        self._serialize_int(value, 4, False)
+
+    def serialize_u64(self, value: int) -> None:
+        self._serialize_int(value, 8, False)
+
+    def serialize_u128(self, value: int) -> None:
+        self._serialize_int(value, 16, False)
+
+    def serialize_i8(self, value: int) -> None:
+        self._serialize_int(value, 1, True)
+
+    def serialize_i16(self, value: int) -> None:
+        self._serialize_int(value, 2, True)
+
+    def serialize_i32(self, value: int) -> None:
+        self._serialize_int(value, 4, True)
+
+    def serialize_i64(self, value: int) -> None:
+        self._serialize_int(value, 8, True)
+
+    def serialize_i128(self, value: int) -> None:
+        self._serialize_int(value, 16, True)
+
+    def serialize_option_tag(self, value: bool) -> None:
+        self.serialize_bool(value)
+
+    def get_buffer_offset(self) -> int:
+        return len(self._buffer)
+
+    def get_bytes(self) -> bytes:
+        return bytes(self._buffer)
+
+
+class BinaryDeserializer(Deserializer):
+    """A ``Deserializer`` that reads little-endian values from a byte buffer.
+
+    Formats built on it decide how lengths and variant indices are read.
+    """
+
+    def __init__(self, data: bytes, max_container_depth: int) -> None:
+        self._input = bytes(data)
+        self._offset = 0
+        self._container_depth_budget = max_container_depth
+
+    def _read(self, length: int) -> bytes:
+        remaining = len(self._input) - self._offset
+        if length > remaining:
+            raise DeserializationError(
+                f"Unexpected end of input: tried to read {length} byte(s) at "
+                f"offset {self._offset}, but only {remaining} remain"
+            )
+        value = self._input[self._offset : self._offset + length]
+        self._offset += length
+        return value
+
+    def _deserialize_int(self, size: int, signed: bool) -> int:
+        return int.from_bytes(self._read(size), "little", signed=signed)
+
+    def increase_container_depth(self) -> None:
+        if self._container_depth_budget == 0:
+            raise DeserializationError("Exceeded maximum container depth")
+        self._container_depth_budget -= 1
+
+    def decrease_container_depth(self) -> None:
+        self._container_depth_budget += 1
+
+    def deserialize_str(self) -> str:
+        try:
+            return self.deserialize_bytes().decode("utf-8")
+        except UnicodeDecodeError as error:
+            raise DeserializationError(f"Invalid UTF-8 string: {error}") from None
+
+    def deserialize_bytes(self) -> bytes:
+        return self._read(self.deserialize_len())
+
+    def deserialize_bool(self) -> bool:
+        value = self.deserialize_u8()
+        if value > 1:
+            raise DeserializationError(f"Invalid bool: {value}")
+        return value == 1
+
+    def deserialize_unit(self) -> None:
+        return None
+
+    def deserialize_char(self) -> str:
+        """Reads the UTF-8 encoding of a single character."""
+        first = self._input[self._offset : self._offset + 1]
+        if not first:
+            self._read(1)
+        lead = first[0]
+        length = 1 if lead < 0x80 else 2 if lead < 0xE0 else 3 if lead < 0xF0 else 4
+        try:
+            return self._read(length).decode("utf-8")
+        except UnicodeDecodeError as error:
+            raise DeserializationError(f"Invalid UTF-8 character: {error}") from None
+
+    def deserialize_f32(self) -> float:
+        return struct.unpack("<f", self._read(4))[0]
+
+    def deserialize_f64(self) -> float:
+        return struct.unpack("<d", self._read(8))[0]
+
+    def deserialize_u8(self) -> int:
+        return self._deserialize_int(1, False)
+
+    def deserialize_u16(self) -> int:
+        return self._deserialize_int(2, False)
+
+    def deserialize_u32(self) -> int:
+        return self._deserialize_int(4, False)
+
+    def deserialize_u64(self) -> int:
+        return self._deserialize_int(8, False)
+
+    def deserialize_u128(self) -> int:
+        return self._deserialize_int(16, False)
+
+    def deserialize_i8(self) -> int:
+        return self._deserialize_int(1, True)
+
+    def deserialize_i16(self) -> int:
+        return self._deserialize_int(2, True)
+
+    def deserialize_i32(self) -> int:
+        return self._deserialize_int(4, True)
+
+    def deserialize_i64(self) -> int:
+        return self._deserialize_int(8, True)
+
+    def deserialize_i128(self) -> int:
+        return self._deserialize_int(16, True)
+
+    def deserialize_option_tag(self) -> bool:
+        return self.deserialize_bool()
+
+    def get_buffer_offset(self) -> int:
+        return self._offset
@@ -0,0 +1,171 @@
+# Copyright (c) Facebook, Inc. and its affiliates
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+from __future__ import annotations
+
+from abc import ABC, abstractmethod
+from datetime import date, datetime, time, timedelta
+from typing import Callable, Optional, TypeVar
+from uuid import UUID
+
+from .errors import DeserializationError
+from .serializer import parse_time, parse_timestamp
+
+T = TypeVar("T")
+K = TypeVar("K")
+V = TypeVar("V")
+
+
+class Deserializer(ABC):
+    """Reads values in a binary format.
+
+    The generated ``deserialize`` methods call the primitive methods for their
+    fields, and the container helpers with a callback for the elements.
+    """
+
+    @abstractmethod
+    def deserialize_str(self) -> str: ...
+
+    @abstractmethod
+    def deserialize_bytes(self) -> bytes: ...
+
+    @abstractmethod
+    def deserialize_bool(self) -> bool: ...
+
+    @abstractmethod
+    def deserialize_unit(self) -> None: ...
+
+    @abstractmethod
+    def deserialize_char(self) -> str: ...
+
+    @abstractmethod
+    def deserialize_f32(self) -> float: ...
+
+    @abstractmethod
+    def deserialize_f64(self) -> float: ...
+
+    @abstractmethod
+    def deserialize_u8(self) -> int: ...
+
+    @abstractmethod
+    def deserialize_u16(self) -> int: ...
+
+    @abstractmethod
+    def deserialize_u32(self) -> int: ...
+
+    @abstractmethod
+    def deserialize_u64(self) -> int: ...
+
+    @abstractmethod
+    def deserialize_u128(self) -> int: ...
+
+    @abstractmethod
+    def deserialize_i8(self) -> int: ...
+
+    @abstractmethod
+    def deserialize_i16(self) -> int: ...
+
+    @abstractmethod
+    def deserialize_i32(self) -> int: ...
+
+    @abstractmethod
+    def deserialize_i64(self) -> int: ...
+
+    @abstractmethod
+    def deserialize_i128(self) -> int: ...
+
+    @abstractmethod
+    def deserialize_len(self) -> int: ...
+
+    @abstractmethod
+    def deserialize_variant_index(self) -> int: ...
+
+    @abstractmethod
+    def deserialize_option_tag(self) -> bool: ...
+
+    @abstractmethod
+    def increase_container_depth(self) -> None: ...
+
+    @abstractmethod
+    def decrease_container_depth(self) -> None: ...
+
+    @abstractmethod
+    def get_buffer_offset(self) -> int: ...
+
+    @abstractmethod
+    def check_that_key_slices_are_increasing(
+        self, key1: tuple[int, int], key2: tuple[int, int]
+    ) -> None: ...
+
+    def deserialize_option(self, deserialize_value: Callable[[], T]) -> Optional[T]:
+        return deserialize_value() if self.deserialize_option_tag() else None
+
+    def deserialize_seq(self, deserialize_element: Callable[[], T]) -> list[T]:
+        length = self.deserialize_len()
+        return [deserialize_element() for _ in range(length)]
+
+    def deserialize_set(self, deserialize_element: Callable[[], T]) -> set[T]:
+        length = self.deserialize_len()
+        return {deserialize_element() for _ in range(length)}
+
+    def deserialize_map(
+        self, deserialize_key: Callable[[], K], deserialize_value: Callable[[], V]
+    ) -> dict[K, V]:
+        length = self.deserialize_len()
+        result: dict[K, V] = {}
+        previous: Optional[tuple[int, int]] = None
+        for _ in range(length):
+            start = self.get_buffer_offset()
+            key = deserialize_key()
+            end = self.get_buffer_offset()
+            if previous is not None:
+                self.check_that_key_slices_are_increasing(previous, (start, end))
+            previous = (start, end)
+            result[key] = deserialize_value()
+        return result
+
+    def deserialize_tuple_array(
+        self, size: int, deserialize_element: Callable[[], T]
+    ) -> tuple[T, ...]:
+        """Reads the ``size`` elements of a fixed-size array, which has no length."""
+        return tuple(deserialize_element() for _ in range(size))
+
+    def deserialize_uuid(self) -> UUID:
+        """Reads the 16 bytes of a UUID."""
+        value = self.deserialize_bytes()
+        if len(value) != 16:
+            raise DeserializationError(f"UUID must be 16 bytes, got {len(value)}")
+        return UUID(bytes=value)
+
+    def deserialize_timestamp(self) -> datetime:
+        """Reads a timestamp written as an RFC 3339 string."""
+        text = self.deserialize_str()
+        try:
+            return parse_timestamp(text)
+        except ValueError:
+            raise DeserializationError(f"Invalid RFC 3339 timestamp: {text}") from None
+
+    def deserialize_date(self) -> date:
+        """Reads a date written as an ISO 8601 string."""
+        text = self.deserialize_str()
+        try:
+            return date.fromisoformat(text)
+        except ValueError:
+            raise DeserializationError(f"Invalid ISO 8601 date: {text}") from None
+
+    def deserialize_time(self) -> time:
+        """Reads a time written as an ISO 8601 string."""
+        text = self.deserialize_str()
+        try:
+            return parse_time(text)
+        except ValueError:
+            raise DeserializationError(f"Invalid ISO 8601 time: {text}") from None
+
+    def deserialize_duration(self) -> timedelta:
+        """Reads a duration written as its whole seconds and the remaining
+        nanoseconds."""
+        self.increase_container_depth()
+        secs = self.deserialize_u64()
+        nanos = self.deserialize_u32()
+        self.decrease_container_depth()
+        return timedelta(seconds=secs, microseconds=nanos // 1000)
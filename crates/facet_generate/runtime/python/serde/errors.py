@@ -0,0 +1,12 @@
+# Copyright (c) Facebook, Inc. and its affiliates
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""The errors raised by serializers and deserializers."""
+
+
+class SerializationError(ValueError):
+    """Raised when a value can't be serialized."""
+
+
+class DeserializationError(ValueError):
+    """Raised when the input can't be deserialized."""
@@ -0,0 +1,159 @@
+# Copyright (c) Facebook, Inc. and its affiliates
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Helpers for the generated ``to_json`` and ``from_json`` methods, which read
+and write the shapes that ``serde_json`` gives Rust types.
+
+The readers check the type of the value they're given, so that a mismatch
+raises a ``DeserializationError`` rather than building a wrong value.
+"""
+
+from __future__ import annotations
+
+from datetime import date, datetime, time, timedelta
+from typing import Any, Callable, Optional, TypeVar
+from uuid import UUID
+
+from .errors import DeserializationError
+from .serializer import format_timestamp, parse_time, parse_timestamp
+
+T = TypeVar("T")
+
+
+def bool_from_json(json: Any) -> bool:
+    if not isinstance(json, bool):
+        raise DeserializationError(f"Expected a bool, got {json!r}")
+    return json
+
+
+def int_from_json(json: Any) -> int:
+    if isinstance(json, bool) or not isinstance(json, int):
+        raise DeserializationError(f"Expected an integer, got {json!r}")
+    return json
+
+
+def float_from_json(json: Any) -> float:
+    if isinstance(json, bool) or not isinstance(json, (int, float)):
+        raise DeserializationError(f"Expected a number, got {json!r}")
+    return float(json)
+
+
+def str_from_json(json: Any) -> str:
+    if not isinstance(json, str):
+        raise DeserializationError(f"Expected a string, got {json!r}")
+    return json
+
+
+def char_from_json(json: Any) -> str:
+    if not isinstance(json, str) or len(json) != 1:
+        raise DeserializationError(f"Expected a single character, got {json!r}")
+    return json
+
+
+def unit_from_json(json: Any) -> None:
+    if json is not None:
+        raise DeserializationError(f"Expected null, got {json!r}")
+
+
+def list_from_json(json: Any) -> list[Any]:
+    if not isinstance(json, list):
+        raise DeserializationError(f"Expected an array, got {json!r}")
+    return json
+
+
+def object_from_json(json: Any) -> dict[str, Any]:
+    if not isinstance(json, dict):
+        raise DeserializationError(f"Expected an object, got {json!r}")
+    return json
+
+
+def option_to_json(value: Optional[T], to_json: Callable[[T], Any]) -> Any:
+    """Converts ``value`` with ``to_json``, unless it is ``None``."""
+    return None if value is None else to_json(value)
+
+
+def option_from_json(json: Any, from_json: Callable[[Any], T]) -> Optional[T]:
+    """Reads ``json`` with ``from_json``, unless it is ``null``."""
+    return None if json is None else from_json(json)
+
+
+def tuple_from_json(json: Any, length: int, from_json: Callable[[list[Any]], T]) -> T:
+    """Reads a tuple, written as an array of ``length`` elements."""
+    if not isinstance(json, list) or len(json) != length:
+        raise DeserializationError(f"Expected an array of {length} elements")
+    return from_json(json)
+
+
+def bytes_to_json(value: bytes) -> Any:
+    """Writes bytes as an array of numbers."""
+    return list(value)
+
+
+def bytes_from_json(json: Any) -> bytes:
+    """Reads bytes, written as an array of numbers."""
+    try:
+        return bytes(int_from_json(byte) for byte in list_from_json(json))
+    except ValueError as error:
+        raise DeserializationError(f"Invalid bytes: {error}") from None
+
+
+def uuid_from_json(json: Any) -> UUID:
+    try:
+        return UUID(str_from_json(json))
+    except ValueError:
+        raise DeserializationError(f"Invalid UUID: {json!r}") from None
+
+
+def timestamp_to_json(value: datetime) -> Any:
+    """Writes a timestamp as an RFC 3339 string in UTC."""
+    return format_timestamp(value)
+
+
+def timestamp_from_json(json: Any) -> datetime:
+    try:
+        return parse_timestamp(str_from_json(json))
+    except ValueError:
+        raise DeserializationError(f"Invalid RFC 3339 timestamp: {json!r}") from None
+
+
+def date_from_json(json: Any) -> date:
+    try:
+        return date.fromisoformat(str_from_json(json))
+    except ValueError:
+        raise DeserializationError(f"Invalid ISO 8601 date: {json!r}") from None
+
+
+def time_from_json(json: Any) -> time:
+    try:
+        return parse_time(str_from_json(json))
+    except ValueError:
+        raise DeserializationError(f"Invalid ISO 8601 time: {json!r}") from None
+
+
+def duration_to_json(value: timedelta) -> Any:
+    """Writes a duration as ``{"secs": …, "nanos": …}``, the shape ``serde``
+    gives ``std::time::Duration``."""
+    return {
+        "secs": value.days * 86400 + value.seconds,
+        "nanos": value.microseconds * 1000,
+    }
+
+
+def duration_from_json(json: Any) -> timedelta:
+    """Reads a duration written as ``{"secs": …, "nanos": …}``."""
+    obj = object_from_json(json)
+    return timedelta(
+        seconds=int_from_json(obj.get("secs")),
+        microseconds=int_from_json(obj.get("nanos")) // 1000,
+    )
+
+
+def external_tag(json: Any) -> tuple[str, Any]:
+    """Splits an externally tagged enum into its tag and content: a unit variant
+    is written as its name, and any other as ``{"Name": content}``."""
+    if isinstance(json, str):
+        return json, None
+    if isinstance(json, dict) and len(json) == 1:
+        ((tag, content),) = json.items()
+        return tag, content
+    raise DeserializationError(f"Expected an enum variant, got {json!r}")
@@ -0,0 +1,206 @@
+# Copyright (c) Facebook, Inc. and its affiliates
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+from __future__ import annotations
+
+import re
+from abc import ABC, abstractmethod
+from datetime import date, datetime, time, timedelta, timezone
+from typing import Callable, Iterable, Optional, TypeVar
+from uuid import UUID
+
+from .errors import SerializationError
+
+T = TypeVar("T")
+K = TypeVar("K")
+V = TypeVar("V")
+
+
+class Serializer(ABC):
+    """Writes values in a binary format.
+
+    The generated ``serialize`` methods call the primitive methods for their
+    fields, and the container helpers with a callback for the elements.
+    """
+
+    @abstractmethod
+    def serialize_str(self, value: str) -> None: ...
+
+    @abstractmethod
+    def serialize_bytes(self, value: bytes) -> None: ...
+
+    @abstractmethod
+    def serialize_bool(self, value: bool) -> None: ...
+
+    @abstractmethod
+    def serialize_unit(self, value: None) -> None: ...
+
+    @abstractmethod
+    def serialize_char(self, value: str) -> None: ...
+
+    @abstractmethod
+    def serialize_f32(self, value: float) -> None: ...
+
+    @abstractmethod
+    def serialize_f64(self, value: float) -> None: ...
+
+    @abstractmethod
+    def serialize_u8(self, value: int) -> None: ...
+
+    @abstractmethod
+    def serialize_u16(self, value: int) -> None: ...
+
+    @abstractmethod
+    def serialize_u32(self, value: int) -> None: ...
+
+    @abstractmethod
+    def serialize_u64(self, value: int) -> None: ...
+
+    @abstractmethod
+    def serialize_u128(self, value: int) -> None: ...
+
+    @abstractmethod
+    def serialize_i8(self, value: int) -> None: ...
+
+    @abstractmethod
+    def serialize_i16(self, value: int) -> None: ...
+
+    @abstractmethod
+    def serialize_i32(self, value: int) -> None: ...
+
+    @abstractmethod
+    def serialize_i64(self, value: int) -> None: ...
+
+    @abstractmethod
+    def serialize_i128(self, value: int) -> None: ...
+
+    @abstractmethod
+    def serialize_len(self, value: int) -> None: ...
+
+    @abstractmethod
+    def serialize_variant_index(self, value: int) -> None: ...
+
+    @abstractmethod
+    def serialize_option_tag(self, value: bool) -> None: ...
+
+    @abstractmethod
+    def increase_container_depth(self) -> None: ...
+
+    @abstractmethod
+    def decrease_container_depth(self) -> None: ...
+
+    @abstractmethod
+    def get_buffer_offset(self) -> int: ...
+
+    @abstractmethod
+    def sort_map_entries(self, offsets: list[int]) -> None: ...
+
+    @abstractmethod
+    def get_bytes(self) -> bytes: ...
+
+    def serialize_option(
+        self, value: Optional[T], serialize_value: Callable[[T], None]
+    ) -> None:
+        if value is None:
+            self.serialize_option_tag(False)
+        else:
+            self.serialize_option_tag(True)
+            serialize_value(value)
+
+    def serialize_seq(
+        self, value: list[T], serialize_element: Callable[[T], None]
+    ) -> None:
+        self.serialize_len(len(value))
+        for element in value:
+            serialize_element(element)
+
+    def serialize_set(
+        self, value: Iterable[T], serialize_element: Callable[[T], None]
+    ) -> None:
+        """Writes the elements of a set in order, if they can be ordered, like
+        a Rust ``BTreeSet``."""
+        elements = list(value)
+        try:
+            elements.sort()
+        except TypeError:
+            pass
+        self.serialize_len(len(elements))
+        for element in elements:
+            serialize_element(element)
+
+    def serialize_map(
+        self,
+        value: dict[K, V],
+        serialize_key: Callable[[K], None],
+        serialize_value: Callable[[V], None],
+    ) -> None:
+        self.serialize_len(len(value))
+        offsets = []
+        for key, item in value.items():
+            offsets.append(self.get_buffer_offset())
+            serialize_key(key)
+            serialize_value(item)
+        self.sort_map_entries(offsets)
+
+    def serialize_tuple_array(
+        self, value: tuple[T, ...], size: int, serialize_element: Callable[[T], None]
+    ) -> None:
+        """Writes the ``size`` elements of a fixed-size array, without a length."""
+        if len(value) != size:
+            raise SerializationError(
+                f"Expected an array of {size} elements, got {len(value)}"
+            )
+        for element in value:
+            serialize_element(element)
+
+    def serialize_uuid(self, value: UUID) -> None:
+        """Writes a UUID as its 16 bytes."""
+        self.serialize_bytes(value.bytes)
+
+    def serialize_timestamp(self, value: datetime) -> None:
+        """Writes a timestamp as an RFC 3339 string in UTC."""
+        self.serialize_str(format_timestamp(value))
+
+    def serialize_date(self, value: date) -> None:
+        """Writes a date as an ISO 8601 string."""
+        self.serialize_str(value.isoformat())
+
+    def serialize_time(self, value: time) -> None:
+        """Writes a time as an ISO 8601 string."""
+        self.serialize_str(value.isoformat())
+
+    def serialize_duration(self, value: timedelta) -> None:
+        """Writes a duration as its whole seconds and the remaining nanoseconds."""
+        if value < timedelta(0):
+            raise SerializationError(f"Duration must not be negative: {value}")
+        self.increase_container_depth()
+        self.serialize_u64(value.days * 86400 + value.seconds)
+        self.serialize_u32(value.microseconds * 1000)
+        self.decrease_container_depth()
+
+
+def format_timestamp(value: datetime) -> str:
+    """The RFC 3339 string of a timestamp in UTC, taking naive timestamps to be
+    in UTC."""
+    if value.tzinfo is None:
+        value = value.replace(tzinfo=timezone.utc)
+    text = value.astimezone(timezone.utc).isoformat()
+    return text.removesuffix("+00:00") + "Z"
+
+
+_FRACTION = re.compile(r"(\.\d{6})\d+")
+
+
+def parse_timestamp(text: str) -> datetime:
+    """Reads an RFC 3339 timestamp, dropping digits below microseconds."""
+    timestamp = datetime.fromisoformat(
+        _FRACTION.sub(r"\1", text).replace("Z", "+00:00").replace("z", "+00:00")
+    )
+    if timestamp.tzinfo is None:
+        raise ValueError(f"Timestamp without an offset: {text}")
+    return timestamp
+
+
+def parse_time(text: str) -> time:
+    """Reads an ISO 8601 time, dropping digits below microseconds."""
+    return time.fromisoformat(_FRACTION.sub(r"\1", text))
@@ -0,0 +1,710 @@
+//! Compatibility checking between two versions of a [`Registry`].
+//!
+//! Apps built against an older registry stay in the field long after the Rust types change, so
+//! [`check`] compares the registry they were built with (`old`) with the current one (`new`)
+//! and classifies each change for each [`Encoding`]:
+//!
+//! - **Bincode** encodes fields and tuple elements by position and variants by index, so
+//!   inserting, removing, reordering or retyping a field, or changing a variant's index, is
+//!   breaking, while renaming a field or variant is not.
+//! - **JSON** encodes fields and variants by name, so renaming them, removing a required field or
+//!   adding one without a default is breaking, while reordering fields, changing variant indices
+//!   and adding or removing optional fields are not.
+//!
+//! Type names appear in neither encoding: a field whose type is renamed is compared with the
+//! renamed type, and only reported if their shapes differ. A type is only reported as removed
+//! (or added) if no field reaches it under another name.
+//!
+//! ```rust,ignore
+//! let report = compat::check(&schema::load("released.json")?, &registry);
+//! assert!(!report.is_breaking(Encoding::Bincode), "{report}");
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt,
+};
+
+use serde::Serialize;
+
+use crate::{
+    Registry,
+    reflection::format::{
+        ContainerFormat, EnumTagging, Format, Named, Namespace, QualifiedTypeName, VariantFormat,
+    },
+};
+
+/// A wire encoding whose compatibility is checked.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum Encoding {
+    Bincode,
+    Json,
+}
+
+impl Encoding {
+    /// Every encoding.
+    pub const ALL: [Self; 2] = [Self::Bincode, Self::Json];
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Bincode => "bincode",
+            Self::Json => "json",
+        })
+    }
+}
+
+/// Whether data written with one registry can still be read with the other.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum Impact {
+    Compatible,
+    Breaking,
+}
+
+/// A difference between the two registries.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// A type of the old registry is gone.
+    TypeRemoved,
+    /// The new registry has a type that the old one doesn't.
+    TypeAdded,
+    /// A type changed between a struct, tuple struct, newtype, unit struct and enum.
+    ContainerChanged { from: String, to: String },
+    /// The format of a field, element or payload changed.
+    FormatChanged { from: String, to: String },
+    /// A struct field was added at `index`.
+    FieldAdded { index: usize, optional: bool },
+    /// A struct field was removed from `index`.
+    FieldRemoved { index: usize, optional: bool },
+    /// A struct field moved relative to the other fields.
+    FieldMoved { from: usize, to: usize },
+    /// A struct field at the same position, with the same format, was renamed.
+    FieldRenamed { from: String, to: String },
+    /// The number of elements of a tuple struct or tuple variant changed.
+    ArityChanged { from: usize, to: usize },
+    /// An enum variant was added with `index`.
+    VariantAdded { index: u32 },
+    /// An enum variant was removed.
+    VariantRemoved { index: u32 },
+    /// An enum variant with the same name has a different index.
+    VariantIndexChanged { from: u32, to: u32 },
+    /// An enum variant with the same index and payload was renamed.
+    VariantRenamed { from: String, to: String },
+    /// The JSON tagging of an enum changed.
+    TaggingChanged { from: String, to: String },
+}
+
+/// A change at `path` (e.g. `api.User.name` or `Event.Created.0`), with its impact on each
+/// encoding.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub path: String,
+    #[serde(flatten)]
+    pub kind: ChangeKind,
+    pub bincode: Impact,
+    pub json: Impact,
+}
+
+impl Change {
+    /// The impact of this change on `encoding`.
+    #[must_use]
+    pub const fn impact(&self, encoding: Encoding) -> Impact {
+        match encoding {
+            Encoding::Bincode => self.bincode,
+            Encoding::Json => self.json,
+        }
+    }
+
+    /// Whether this change breaks `encoding`.
+    #[must_use]
+    pub fn is_breaking(&self, encoding: Encoding) -> bool {
+        self.impact(encoding) == Impact::Breaking
+    }
+}
+
+/// Every change between two registries, in the order of the old registry's types.
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    pub changes: Vec<Change>,
+}
+
+impl Report {
+    /// Whether any change breaks `encoding`.
+    #[must_use]
+    pub fn is_breaking(&self, encoding: Encoding) -> bool {
+        self.changes
+            .iter()
+            .any(|change| change.is_breaking(encoding))
+    }
+
+    /// The changes that break `encoding`.
+    pub fn breaking(&self, encoding: Encoding) -> impl Iterator<Item = &Change> {
+        self.changes
+            .iter()
+            .filter(move |change| change.is_breaking(encoding))
+    }
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let optional = |optional: bool| if optional { "optional" } else { "required" };
+        match self {
+            Self::TypeRemoved => write!(f, "type removed"),
+            Self::TypeAdded => write!(f, "type added"),
+            Self::ContainerChanged { from, to } => write!(f, "changed from {from} to {to}"),
+            Self::FormatChanged { from, to } => write!(f, "changed from `{from}` to `{to}`"),
+            Self::FieldAdded { index, optional: o } => {
+                write!(f, "{} field added at position {index}", optional(*o))
+            }
+            Self::FieldRemoved { index, optional: o } => {
+                write!(f, "{} field removed from position {index}", optional(*o))
+            }
+            Self::FieldMoved { from, to } => {
+                write!(f, "field moved from position {from} to {to}")
+            }
+            Self::FieldRenamed { from, to } => write!(f, "field renamed from `{from}` to `{to}`"),
+            Self::ArityChanged { from, to } => {
+                write!(f, "number of elements changed from {from} to {to}")
+            }
+            Self::VariantAdded { index } => write!(f, "variant added with index {index}"),
+            Self::VariantRemoved { index } => write!(f, "variant removed from index {index}"),
+            Self::VariantIndexChanged { from, to } => {
+                write!(f, "variant index changed from {from} to {to}")
+            }
+            Self::VariantRenamed { from, to } => {
+                write!(f, "variant renamed from `{from}` to `{to}`")
+            }
+            Self::TaggingChanged { from, to } => {
+                write!(f, "tagging changed from {from} to {to}")
+            }
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let breaks = Encoding::ALL
+            .into_iter()
+            .filter(|encoding| self.is_breaking(*encoding))
+            .map(|encoding| encoding.to_string())
+            .collect::<Vec<_>>();
+        if breaks.is_empty() {
+            write!(f, "compatible: {}: {}", self.path, self.kind)
+        } else {
+            write!(
+                f,
+                "breaks {}: {}: {}",
+                breaks.join(", "),
+                self.path,
+                self.kind
+            )
+        }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "no changes");
+        }
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares the registry that existing clients were built with (`old`) with the current one
+/// (`new`).
+#[must_use]
+pub fn check(old: &Registry, new: &Registry) -> Report {
+    let mut checker = Checker {
+        old,
+        new,
+        compared: HashMap::new(),
+        changes: vec![],
+    };
+    // The types that are gone, with the position of their change among the others.
+    let mut removed = vec![];
+    for (name, old_container) in old {
+        match new.get(name) {
+            Some(new_container) => {
+                checker.container(&type_path(name), old_container, new_container)
+            }
+            None => removed.push((checker.changes.len(), name)),
+        }
+    }
+
+    // Types compared under different names are reached by the same fields, so they were
+    // renamed rather than removed and added.
+    let renamed_from = checker
+        .compared
+        .keys()
+        .map(|(old, _)| *old)
+        .collect::<BTreeSet<_>>();
+    let renamed_to = checker
+        .compared
+        .keys()
+        .map(|(_, new)| *new)
+        .collect::<BTreeSet<_>>();
+    for (position, name) in removed.into_iter().rev() {
+        if !renamed_from.contains(name) {
+            let change = Change {
+                path: type_path(name),
+                kind: ChangeKind::TypeRemoved,
+                bincode: BREAKING,
+                json: BREAKING,
+            };
+            checker.changes.insert(position, change);
+        }
+    }
+    for name in new
+        .keys()
+        .filter(|name| !old.contains_key(*name) && !renamed_to.contains(name))
+    {
+        checker.push(
+            &type_path(name),
+            ChangeKind::TypeAdded,
+            COMPATIBLE,
+            COMPATIBLE,
+        );
+    }
+    Report {
+        changes: checker.changes,
+    }
+}
+
+const COMPATIBLE: Impact = Impact::Compatible;
+const BREAKING: Impact = Impact::Breaking;
+
+struct Checker<'a> {
+    old: &'a Registry,
+    new: &'a Registry,
+    /// Whether pairs of differently named types have the same shape, so that each pair is
+    /// compared once. A pair that is still being compared counts as the same, which only
+    /// stops recursive types from being compared forever.
+    compared: HashMap<(&'a QualifiedTypeName, &'a QualifiedTypeName), bool>,
+    changes: Vec<Change>,
+}
+
+impl<'a> Checker<'a> {
+    fn push(&mut self, path: &str, kind: ChangeKind, bincode: Impact, json: Impact) {
+        self.changes.push(Change {
+            path: path.to_string(),
+            kind,
+            bincode,
+            json,
+        });
+    }
+
+    fn container(&mut self, path: &str, old: &'a ContainerFormat, new: &'a ContainerFormat) {
+        match (old, new) {
+            (ContainerFormat::UnitStruct(..), ContainerFormat::UnitStruct(..)) => {}
+            (ContainerFormat::NewTypeStruct(old, ..), ContainerFormat::NewTypeStruct(new, ..)) => {
+                self.format(path, old, new);
+            }
+            (ContainerFormat::TupleStruct(old, ..), ContainerFormat::TupleStruct(new, ..)) => {
+                self.elements(path, old, new);
+            }
+            (ContainerFormat::Struct(old, ..), ContainerFormat::Struct(new, ..)) => {
+                self.fields(path, old, new);
+            }
+            (
+                ContainerFormat::Enum(old, old_tagging, ..),
+                ContainerFormat::Enum(new, new_tagging, ..),
+            ) => {
+                if old_tagging != new_tagging {
+                    let kind = ChangeKind::TaggingChanged {
+                        from: tagging(old_tagging),
+                        to: tagging(new_tagging),
+                    };
+                    self.push(path, kind, COMPATIBLE, BREAKING);
+                }
+                let untagged = *new_tagging == EnumTagging::Untagged;
+                self.variants(path, old, new, untagged);
+            }
+            _ => {
+                let kind = ChangeKind::ContainerChanged {
+                    from: container_kind(old).to_string(),
+                    to: container_kind(new).to_string(),
+                };
+                self.push(path, kind, BREAKING, BREAKING);
+            }
+        }
+    }
+
+    /// Compares formats at the same position. Differently named types are compared by shape,
+    /// as type names are not encoded.
+    fn format(&mut self, path: &str, old: &'a Format, new: &'a Format) {
+        if !self.same_shape(old, new) {
+            let kind = ChangeKind::FormatChanged {
+                from: describe(old),
+                to: describe(new),
+            };
+            self.push(path, kind, BREAKING, BREAKING);
+        }
+    }
+
+    fn same_shape(&mut self, old: &'a Format, new: &'a Format) -> bool {
+        match (old, new) {
+            (Format::TypeName(old), Format::TypeName(new)) => self.same_type(old, new),
+            (
+                Format::Generic {
+                    name: old_name,
+                    args: old_args,
+                },
+                Format::Generic {
+                    name: new_name,
+                    args: new_args,
+                },
+            ) => {
+                self.same_type(old_name, new_name)
+                    && old_args.len() == new_args.len()
+                    && old_args
+                        .iter()
+                        .zip(new_args)
+                        .all(|(old, new)| self.same_shape(old, new))
+            }
+            (Format::Option(old), Format::Option(new))
+            | (Format::Seq(old), Format::Seq(new))
+            | (Format::Set(old), Format::Set(new)) => self.same_shape(old, new),
+            (
+                Format::Map {
+                    key: old_key,
+                    value: old_value,
+                },
+                Format::Map {
+                    key: new_key,
+                    value: new_value,
+                },
+            ) => self.same_shape(old_key, new_key) && self.same_shape(old_value, new_value),
+            (Format::Tuple(old), Format::Tuple(new)) => {
+                old.len() == new.len()
+                    && old
+                        .iter()
+                        .zip(new)
+                        .all(|(old, new)| self.same_shape(old, new))
+            }
+            (
+                Format::TupleArray {
+                    content: old_content,
+                    size: old_size,
+                },
+                Format::TupleArray {
+                    content: new_content,
+                    size: new_size,
+                },
+            ) => old_size == new_size && self.same_shape(old_content, new_content),
+            (old, new) => old == new,
+        }
+    }
+
+    /// Whether two types have the same shape. A type with the same name in both registries is
+    /// compared (and its changes reported) on its own, so it is treated as unchanged here.
+    fn same_type(&mut self, old: &'a QualifiedTypeName, new: &'a QualifiedTypeName) -> bool {
+        if old == new {
+            return true;
+        }
+        if let Some(&same) = self.compared.get(&(old, new)) {
+            return same;
+        }
+        let (Some(old_container), Some(new_container)) = (self.old.get(old), self.new.get(new))
+        else {
+            self.compared.insert((old, new), false);
+            return false;
+        };
+        self.compared.insert((old, new), true);
+        let changes = self.changes.len();
+        self.container(&type_path(old), old_container, new_container);
+        let same = self.changes[changes..]
+            .iter()
+            .all(|change| Encoding::ALL.iter().all(|e| !change.is_breaking(*e)));
+        self.changes.truncate(changes);
+        self.compared.insert((old, new), same);
+        same
+    }
+
+    fn elements(&mut self, path: &str, old: &'a [Format], new: &'a [Format]) {
+        if old.len() != new.len() {
+            let kind = ChangeKind::ArityChanged {
+                from: old.len(),
+                to: new.len(),
+            };
+            self.push(path, kind, BREAKING, BREAKING);
+            return;
+        }
+        for (index, (old, new)) in old.iter().zip(new).enumerate() {
+            self.format(&format!("{path}.{index}"), old, new);
+        }
+    }
+
+    fn fields(&mut self, path: &str, old: &'a [Named<Format>], new: &'a [Named<Format>]) {
+        let old_index = index_by_name(old);
+        let new_index = index_by_name(new);
+
+        // A field at the same position, with the same format, whose name only exists on one
+        // side, was renamed rather than removed and added.
+        let mut renamed = BTreeSet::new();
+        for (index, (old_field, new_field)) in old.iter().zip(new).enumerate() {
+            if old_field.name != new_field.name
+                && !new_index.contains_key(old_field.name.as_str())
+                && !old_index.contains_key(new_field.name.as_str())
+                && self.same_shape(&old_field.value, &new_field.value)
+            {
+                renamed.insert(index);
+                let kind = ChangeKind::FieldRenamed {
+                    from: old_field.name.clone(),
+                    to: new_field.name.clone(),
+                };
+                self.push(
+                    &format!("{path}.{}", old_field.name),
+                    kind,
+                    COMPATIBLE,
+                    BREAKING,
+                );
+            }
+        }
+
+        // Fields on both sides, in the order of each side.
+        let old_common = old
+            .iter()
+            .filter(|field| new_index.contains_key(field.name.as_str()))
+            .map(|field| field.name.as_str())
+            .collect::<Vec<_>>();
+        let new_common = new
+            .iter()
+            .filter(|field| old_index.contains_key(field.name.as_str()))
+            .map(|field| field.name.as_str())
+            .collect::<Vec<_>>();
+
+        for (index, field) in old.iter().enumerate() {
+            let field_path = format!("{path}.{}", field.name);
+            match new_index.get(field.name.as_str()) {
+                Some(&new_position) => {
+                    let rank = old_common.iter().position(|name| *name == field.name);
+                    let new_rank = new_common.iter().position(|name| *name == field.name);
+                    if rank != new_rank {
+                        let kind = ChangeKind::FieldMoved {
+                            from: index,
+                            to: new_position,
+                        };
+                        self.push(&field_path, kind, BREAKING, COMPATIBLE);
+                    }
+                    self.format(&field_path, &field.value, &new[new_position].value);
+                }
+                None if renamed.contains(&index) => {}
+                None => {
+                    // Clients that still expect a required field can't read data without it.
+                    let optional = is_optional(field);
+                    let kind = ChangeKind::FieldRemoved { index, optional };
+                    let json = if optional { COMPATIBLE } else { BREAKING };
+                    self.push(&field_path, kind, BREAKING, json);
+                }
+            }
+        }
+
+        for (index, field) in new.iter().enumerate() {
+            if old_index.contains_key(field.name.as_str()) || renamed.contains(&index) {
+                continue;
+            }
+            // Data written by clients that don't know the field lacks it.
+            let optional = is_optional(field);
+            let kind = ChangeKind::FieldAdded { index, optional };
+            let json = if optional { COMPATIBLE } else { BREAKING };
+            self.push(&format!("{path}.{}", field.name), kind, BREAKING, json);
+        }
+    }
+
+    fn variants(
+        &mut self,
+        path: &str,
+        old: &'a BTreeMap<u32, Named<VariantFormat>>,
+        new: &'a BTreeMap<u32, Named<VariantFormat>>,
+        untagged: bool,
+    ) {
+        let new_by_name = new
+            .iter()
+            .map(|(index, variant)| (variant.name.as_str(), (*index, variant)))
+            .collect::<BTreeMap<_, _>>();
+        let old_names = old
+            .values()
+            .map(|variant| variant.name.as_str())
+            .collect::<BTreeSet<_>>();
+
+        let mut renamed = BTreeSet::new();
+        for (index, variant) in old {
+            let variant_path = format!("{path}.{}", variant.name);
+            if let Some(&(new_index, new_variant)) = new_by_name.get(variant.name.as_str()) {
+                if new_index != *index {
+                    let kind = ChangeKind::VariantIndexChanged {
+                        from: *index,
+                        to: new_index,
+                    };
+                    self.push(&variant_path, kind, BREAKING, COMPATIBLE);
+                }
+                self.payload(&variant_path, &variant.value, &new_variant.value);
+                continue;
+            }
+            match new.get(index) {
+                Some(new_variant)
+                    if !old_names.contains(new_variant.name.as_str())
+                        && self.same_payload(&variant.value, &new_variant.value) =>
+                {
+                    renamed.insert(*index);
+                    let kind = ChangeKind::VariantRenamed {
+                        from: variant.name.clone(),
+                        to: new_variant.name.clone(),
+                    };
+                    // Untagged JSON doesn't carry variant names.
+                    let json = if untagged { COMPATIBLE } else { BREAKING };
+                    self.push(&variant_path, kind, COMPATIBLE, json);
+                }
+                _ => {
+                    let kind = ChangeKind::VariantRemoved { index: *index };
+                    self.push(&variant_path, kind, BREAKING, BREAKING);
+                }
+            }
+        }
+
+        // Old clients can't read a new variant, but it is compatible as long as they are not
+        // sent one.
+        for (index, variant) in new {
+            if !old_names.contains(variant.name.as_str()) && !renamed.contains(index) {
+                let kind = ChangeKind::VariantAdded { index: *index };
+                self.push(
+                    &format!("{path}.{}", variant.name),
+                    kind,
+                    COMPATIBLE,
+                    COMPATIBLE,
+                );
+            }
+        }
+    }
+
+    fn payload(&mut self, path: &str, old: &'a VariantFormat, new: &'a VariantFormat) {
+        match (old, new) {
+            (VariantFormat::Unit, VariantFormat::Unit) => {}
+            (VariantFormat::NewType(old), VariantFormat::NewType(new)) => {
+                self.format(&format!("{path}.0"), old, new);
+            }
+            (VariantFormat::Tuple(old), VariantFormat::Tuple(new)) => {
+                self.elements(path, old, new);
+            }
+            (VariantFormat::Struct(old), VariantFormat::Struct(new)) => {
+                self.fields(path, old, new);
+            }
+            _ => {
+                let kind = ChangeKind::ContainerChanged {
+                    from: variant_kind(old).to_string(),
+                    to: variant_kind(new).to_string(),
+                };
+                self.push(path, kind, BREAKING, BREAKING);
+            }
+        }
+    }
+
+    fn same_payload(&mut self, old: &'a VariantFormat, new: &'a VariantFormat) -> bool {
+        let changes = self.changes.len();
+        self.payload("", old, new);
+        let same = self.changes.len() == changes;
+        self.changes.truncate(changes);
+        same
+    }
+}
+
+fn index_by_name(fields: &[Named<Format>]) -> BTreeMap<&str, usize> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| (field.name.as_str(), index))
+        .collect()
+}
+
+/// Whether a field can be missing from JSON input: an `Option` or a field with a default.
+fn is_optional(field: &Named<Format>) -> bool {
+    matches!(field.value, Format::Option(_)) || field.metadata.default.is_some()
+}
+
+fn type_path(name: &QualifiedTypeName) -> String {
+    match &name.namespace {
+        Namespace::Root => name.name.clone(),
+        Namespace::Named(namespace) => format!("{namespace}.{}", name.name),
+    }
+}
+
+const fn container_kind(container: &ContainerFormat) -> &'static str {
+    match container {
+        ContainerFormat::UnitStruct(..) => "a unit struct",
+        ContainerFormat::NewTypeStruct(..) => "a newtype struct",
+        ContainerFormat::TupleStruct(..) => "a tuple struct",
+        ContainerFormat::Struct(..) => "a struct",
+        ContainerFormat::Enum(..) => "an enum",
+    }
+}
+
+const fn variant_kind(variant: &VariantFormat) -> &'static str {
+    match variant {
+        VariantFormat::Variable(_) => "an unknown variant",
+        VariantFormat::Unit => "a unit variant",
+        VariantFormat::NewType(_) => "a newtype variant",
+        VariantFormat::Tuple(_) => "a tuple variant",
+        VariantFormat::Struct(_) => "a struct variant",
+    }
+}
+
+fn tagging(tagging: &EnumTagging) -> String {
+    match tagging {
+        EnumTagging::External => "external".to_string(),
+        EnumTagging::Internal { tag } => format!("internal (tag `{tag}`)"),
+        EnumTagging::Adjacent { tag, content } => {
+            format!("adjacent (tag `{tag}`, content `{content}`)")
+        }
+        EnumTagging::Untagged => "untagged".to_string(),
+    }
+}
+
+/// A format in Rust-like syntax.
+fn describe(format: &Format) -> String {
+    let list = |formats: &[Format]| formats.iter().map(describe).collect::<Vec<_>>().join(", ");
+    match format {
+        Format::Variable(_) => "?".to_string(),
+        Format::TypeName(name) => type_path(name),
+        Format::Generic { name, args } => format!("{}<{}>", type_path(name), list(args)),
+        Format::TypeParameter(name) => name.clone(),
+        Format::Unit => "()".to_string(),
+        Format::Bool => "bool".to_string(),
+        Format::I8 => "i8".to_string(),
+        Format::I16 => "i16".to_string(),
+        Format::I32 => "i32".to_string(),
+        Format::I64 => "i64".to_string(),
+        Format::I128 => "i128".to_string(),
+        Format::U8 => "u8".to_string(),
+        Format::U16 => "u16".to_string(),
+        Format::U32 => "u32".to_string(),
+        Format::U64 => "u64".to_string(),
+        Format::U128 => "u128".to_string(),
+        Format::F32 => "f32".to_string(),
+        Format::F64 => "f64".to_string(),
+        Format::Char => "char".to_string(),
+        Format::Str => "String".to_string(),
+        Format::Bytes => "bytes".to_string(),
+        Format::Uuid => "Uuid".to_string(),
+        Format::Timestamp => "Timestamp".to_string(),
+        Format::Date => "Date".to_string(),
+        Format::Time => "Time".to_string(),
+        Format::Duration => "Duration".to_string(),
+        Format::Option(format) => format!("Option<{}>", describe(format)),
+        Format::Seq(format) => format!("Vec<{}>", describe(format)),
+        Format::Set(format) => format!("Set<{}>", describe(format)),
+        Format::Map { key, value } => format!("Map<{}, {}>", describe(key), describe(value)),
+        Format::Tuple(formats) => format!("({})", list(formats)),
+        Format::TupleArray { content, size } => format!("[{}; {size}]", describe(content)),
+    }
+}
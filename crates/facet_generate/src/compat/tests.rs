@@ -0,0 +1,374 @@
+#![allow(dead_code)]
+
+use facet::Facet;
+
+use super::{Encoding, check};
+use crate::{self as fg, Registry, reflection::RegistryBuilder};
+
+fn registry<'a, T: Facet<'a>>() -> Registry {
+    RegistryBuilder::new()
+        .add_type::<T>()
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+mod v1 {
+    use facet::Facet;
+
+    use super::fg;
+
+    #[derive(Facet)]
+    #[facet(fg::namespace = "api")]
+    pub struct User {
+        pub id: u64,
+        pub name: String,
+        pub email: String,
+        pub nickname: Option<String>,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    pub enum Event {
+        Created(User),
+        Deleted { id: u64 },
+        Archived,
+    }
+
+    #[derive(Facet)]
+    pub struct Point(pub i32, pub i32);
+}
+
+#[test]
+fn identical_registries() {
+    let registry = registry::<v1::Event>();
+    let report = check(&registry, &registry);
+    assert!(report.changes.is_empty());
+    insta::assert_snapshot!(report, @"no changes");
+}
+
+#[test]
+fn reordered_fields_break_bincode_only() {
+    mod v2 {
+        use facet::Facet;
+
+        use super::fg;
+
+        #[derive(Facet)]
+        #[facet(fg::namespace = "api")]
+        pub struct User {
+            pub id: u64,
+            pub email: String,
+            pub name: String,
+            pub nickname: Option<String>,
+        }
+    }
+
+    let report = check(&registry::<v1::User>(), &registry::<v2::User>());
+    assert!(report.is_breaking(Encoding::Bincode));
+    assert!(!report.is_breaking(Encoding::Json));
+    insta::assert_snapshot!(report, @"
+    breaks bincode: api.User.name: field moved from position 1 to 2
+    breaks bincode: api.User.email: field moved from position 2 to 1
+    ");
+}
+
+#[test]
+fn added_and_removed_fields() {
+    mod v2 {
+        use facet::Facet;
+
+        use super::fg;
+
+        #[derive(Facet)]
+        #[facet(fg::namespace = "api")]
+        pub struct User {
+            pub id: u64,
+            pub name: String,
+            pub email: String,
+            pub avatar: Option<Vec<u8>>,
+            #[facet(default)]
+            pub verified: bool,
+            pub created_at: u64,
+        }
+    }
+
+    let report = check(&registry::<v1::User>(), &registry::<v2::User>());
+    insta::assert_snapshot!(report, @"
+    breaks bincode: api.User.nickname: optional field removed from position 3
+    breaks bincode: api.User.avatar: optional field added at position 3
+    breaks bincode: api.User.verified: optional field added at position 4
+    breaks bincode, json: api.User.created_at: required field added at position 5
+    ");
+    assert_eq!(
+        report
+            .breaking(Encoding::Json)
+            .map(|change| change.path.as_str())
+            .collect::<Vec<_>>(),
+        ["api.User.created_at"]
+    );
+}
+
+#[test]
+fn renamed_field_breaks_json_only() {
+    mod v2 {
+        use facet::Facet;
+
+        use super::fg;
+
+        #[derive(Facet)]
+        #[facet(fg::namespace = "api")]
+        pub struct User {
+            pub id: u64,
+            pub full_name: String,
+            pub email: String,
+            pub nickname: Option<String>,
+        }
+    }
+
+    let report = check(&registry::<v1::User>(), &registry::<v2::User>());
+    insta::assert_snapshot!(report, @"breaks json: api.User.name: field renamed from `name` to `full_name`");
+}
+
+#[test]
+fn changed_field_format() {
+    mod v2 {
+        use facet::Facet;
+
+        use super::fg;
+
+        #[derive(Facet)]
+        #[facet(fg::namespace = "api")]
+        pub struct User {
+            pub id: u32,
+            pub name: String,
+            pub email: Vec<String>,
+            pub nickname: Option<String>,
+        }
+    }
+
+    let report = check(&registry::<v1::User>(), &registry::<v2::User>());
+    insta::assert_snapshot!(report, @r"
+    breaks bincode, json: api.User.id: changed from `u64` to `u32`
+    breaks bincode, json: api.User.email: changed from `String` to `Vec<String>`
+    ");
+}
+
+#[test]
+fn shifted_variant_indices_break_bincode_only() {
+    mod v2 {
+        use facet::Facet;
+
+        use super::v1::User;
+
+        #[derive(Facet)]
+        #[repr(C)]
+        pub enum Event {
+            Created(User),
+            Updated(User),
+            Deleted { id: u64 },
+            Archived,
+        }
+    }
+
+    let report = check(&registry::<v1::Event>(), &registry::<v2::Event>());
+    assert!(!report.is_breaking(Encoding::Json));
+    insta::assert_snapshot!(report, @r"
+    breaks bincode: Event.Deleted: variant index changed from 1 to 2
+    breaks bincode: Event.Archived: variant index changed from 2 to 3
+    compatible: Event.Updated: variant added with index 1
+    ");
+}
+
+#[test]
+fn variant_changes() {
+    mod v2 {
+        use facet::Facet;
+
+        use super::v1::User;
+
+        #[derive(Facet)]
+        #[repr(C)]
+        #[facet(tag = "type")]
+        pub enum Event {
+            Registered(User),
+            Deleted { id: u64, reason: String },
+        }
+    }
+
+    let report = check(&registry::<v1::Event>(), &registry::<v2::Event>());
+    insta::assert_snapshot!(report, @r"
+    breaks json: Event: tagging changed from external to internal (tag `type`)
+    breaks json: Event.Created: variant renamed from `Created` to `Registered`
+    breaks bincode, json: Event.Deleted.reason: required field added at position 1
+    breaks bincode, json: Event.Archived: variant removed from index 2
+    ");
+}
+
+#[test]
+fn renamed_untagged_variant() {
+    mod v1 {
+        use facet::Facet;
+
+        #[derive(Facet)]
+        #[repr(C)]
+        #[facet(untagged)]
+        pub enum Value {
+            Number(i32),
+            Text(String),
+        }
+    }
+
+    mod v2 {
+        use facet::Facet;
+
+        #[derive(Facet)]
+        #[repr(C)]
+        #[facet(untagged)]
+        pub enum Value {
+            Integer(i32),
+            Text(String),
+        }
+    }
+
+    let report = check(&registry::<v1::Value>(), &registry::<v2::Value>());
+    insta::assert_snapshot!(report, @"compatible: Value.Number: variant renamed from `Number` to `Integer`");
+}
+
+#[test]
+fn types_compared_by_shape() {
+    mod v2 {
+        use facet::Facet;
+
+        #[derive(Facet)]
+        pub struct Coordinates(pub i32, pub i32);
+
+        #[derive(Facet)]
+        pub struct Shape {
+            pub origin: Coordinates,
+            pub size: Coordinates,
+        }
+    }
+
+    mod v1_shape {
+        use facet::Facet;
+
+        use super::v1::Point;
+
+        #[derive(Facet)]
+        pub struct Shape {
+            pub origin: Point,
+            pub size: (i32, i32, i32),
+        }
+    }
+
+    let report = check(&registry::<v1_shape::Shape>(), &registry::<v2::Shape>());
+    insta::assert_snapshot!(report, @"breaks bincode, json: Shape.size: changed from `(i32, i32, i32)` to `Coordinates`");
+}
+
+#[test]
+fn renamed_type_is_compatible() {
+    mod v1_shape {
+        use facet::Facet;
+
+        use super::v1::Point;
+
+        #[derive(Facet)]
+        pub struct Shape {
+            pub origin: Point,
+        }
+    }
+
+    mod v2_shape {
+        use facet::Facet;
+
+        #[derive(Facet)]
+        pub struct Coords(pub i32, pub i32);
+
+        #[derive(Facet)]
+        pub struct Shape {
+            pub origin: Coords,
+        }
+    }
+
+    let report = check(
+        &registry::<v1_shape::Shape>(),
+        &registry::<v2_shape::Shape>(),
+    );
+    assert!(!report.is_breaking(Encoding::Bincode));
+    assert!(!report.is_breaking(Encoding::Json));
+    insta::assert_snapshot!(report, @"no changes");
+}
+
+#[test]
+fn every_field_of_a_changed_type_is_reported() {
+    mod v1_shape {
+        use facet::Facet;
+
+        use super::v1::Point;
+
+        #[derive(Facet)]
+        pub struct Shape {
+            pub a: Point,
+            pub b: Point,
+        }
+    }
+
+    mod v2_shape {
+        use facet::Facet;
+
+        #[derive(Facet)]
+        pub struct Coords {
+            pub x: i64,
+            pub y: i64,
+        }
+
+        #[derive(Facet)]
+        pub struct Shape {
+            pub a: Coords,
+            pub b: Coords,
+        }
+    }
+
+    let report = check(
+        &registry::<v1_shape::Shape>(),
+        &registry::<v2_shape::Shape>(),
+    );
+    insta::assert_snapshot!(report, @"
+    breaks bincode, json: Shape.a: changed from `Point` to `Coords`
+    breaks bincode, json: Shape.b: changed from `Point` to `Coords`
+    ");
+}
+
+#[test]
+fn report_serializes() {
+    mod v2 {
+        use facet::Facet;
+
+        use super::fg;
+
+        #[derive(Facet)]
+        #[facet(fg::namespace = "api")]
+        pub struct User {
+            pub id: u64,
+            pub name: String,
+            pub email: String,
+        }
+    }
+
+    let report = check(&registry::<v1::User>(), &registry::<v2::User>());
+    insta::assert_snapshot!(serde_json::to_string_pretty(&report).unwrap(), @r#"
+    {
+      "changes": [
+        {
+          "path": "api.User.nickname",
+          "kind": "field_removed",
+          "index": 3,
+          "optional": true,
+          "bincode": "breaking",
+          "json": "compatible"
+        }
+      ]
+    }
+    "#);
+}
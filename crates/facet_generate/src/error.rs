@@ -6,10 +6,6 @@ pub enum Error {
     ReflectionError { type_name: String, message: String },
     #[error("incomplete reflection detected")]
     UnknownFormat,
-    #[error(
-        "unsupported generic type: {0}, the type may have already been used with different parameters"
-    )]
-    UnsupportedGenericType(String),
     #[error("unsupported layout: {0}")]
     LayoutUnsized(String),
     #[error(
@@ -24,4 +20,73 @@ pub enum Error {
         existing_namespace: String,
         new_namespace: String,
     },
+    /// A type that can't be reflected, found at `path` from the root type, e.g.
+    /// `Root.field.Variant.0`.
+    #[error("unsupported type `{type_name}` at `{path}`: {hint}")]
+    Unsupported {
+        path: String,
+        type_name: String,
+        hint: String,
+    },
+    /// Two variants of an enum have the same bincode index, pinned by `fg::index` or a
+    /// discriminant.
+    #[error("variants `{first}` and `{second}` of `{type_name}` both have index {index}")]
+    DuplicateVariantIndex {
+        type_name: String,
+        index: u32,
+        first: String,
+        second: String,
+    },
+    /// A struct has two fields with the same name, one of them from a field marked
+    /// `#[facet(flatten)]`.
+    #[error("field `{field}` of `{type_name}` is defined more than once by flattening")]
+    DuplicateField { type_name: String, field: String },
+    /// A variant's `fg::index` or discriminant doesn't fit a bincode variant index (a `u32`).
+    #[error("variant `{variant}` of `{type_name}` has index {index}, which isn't a `u32`")]
+    InvalidVariantIndex {
+        type_name: String,
+        variant: String,
+        index: i128,
+    },
+    /// A type parameter of a generic type has the same argument in every reachable
+    /// instantiation, so the fields that use it can't be told apart from fields of that type.
+    #[error(
+        "type parameter `{param}` of `{type_name}` is `{argument}` in every instantiation, so its uses can't be told apart from fields of type `{argument}`"
+    )]
+    AmbiguousGenericType {
+        type_name: String,
+        param: String,
+        argument: String,
+    },
+    /// Every error found by a [`RegistryBuilder`](crate::reflection::RegistryBuilder) that
+    /// [collects errors](crate::reflection::RegistryBuilder::collect_errors).
+    #[error("{}", list(.0))]
+    Multiple(Vec<Error>),
+}
+
+impl Error {
+    /// Sets the path of an [`Error::Unsupported`] that doesn't have one yet.
+    #[must_use]
+    pub(crate) fn at(self, path: String) -> Self {
+        match self {
+            Self::Unsupported {
+                path: unset,
+                type_name,
+                hint,
+            } if unset.is_empty() => Self::Unsupported {
+                path,
+                type_name,
+                hint,
+            },
+            error => error,
+        }
+    }
+}
+
+fn list(errors: &[Error]) -> String {
+    let lines = errors
+        .iter()
+        .map(|error| format!("\n  - {error}"))
+        .collect::<String>();
+    format!("{} errors:{lines}", errors.len())
 }
@@ -0,0 +1,235 @@
+//! Narrowing a [`Registry`] down to the types that one client needs.
+//!
+//! A model crate usually describes more types than any one app uses, and
+//! [`RegistryBuilder`](crate::reflection::RegistryBuilder) includes every type reachable from
+//! the types it is given. [`prune`] keeps only the types reachable from a chosen set of roots,
+//! and a [`Filter`] leaves types out by name, namespace or predicate, replacing every use of them
+//! as its [`Replacement`] says:
+//!
+//! ```rust,ignore
+//! let registry = RegistryBuilder::new().add_type::<Api>()?.build()?;
+//!
+//! // the types of the login screen, without the admin types they mention
+//! let registry = filter::prune(&registry, ["LoginRequest", "LoginResponse"])?;
+//! let registry = Filter::new()
+//!     .exclude_namespace("admin")
+//!     .replace_with(Replacement::Opaque(Format::Str))
+//!     .apply(&registry)?;
+//! ```
+//!
+//! Types are named as in [`compat`](crate::compat) reports: `User` for a type in the root
+//! namespace and `api.User` for one in the `api` namespace.
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    collections::{BTreeSet, VecDeque},
+    fmt,
+};
+
+use thiserror::Error;
+
+use crate::{
+    Registry,
+    reflection::format::{ContainerFormat, Format, FormatHolder, Namespace, QualifiedTypeName},
+};
+
+/// Errors that can occur while filtering or pruning a registry.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum Error {
+    /// A type that was kept uses a type that was removed with [`Replacement::Remove`].
+    #[error("`{used_by}` uses the excluded type `{type_name}`")]
+    Excluded { type_name: String, used_by: String },
+
+    /// A root given to [`prune`] isn't in the registry.
+    #[error("root type `{0}` isn't in the registry")]
+    UnknownRoot(String),
+
+    /// A type's format still has a placeholder that reflection didn't fill in.
+    #[error("the format of `{0}` is incomplete")]
+    UnknownFormat(String),
+}
+
+/// What a [`Filter`] puts in place of the uses of the types it excludes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Replacement {
+    /// Nothing: the excluded types must not be used by the types that are kept.
+    #[default]
+    Remove,
+    /// A reference to a type of another package. Uses of excluded types in a named namespace
+    /// are left as they are, since [`split`](crate::generation::module::split) already treats
+    /// other namespaces as external modules, and uses of excluded types in the root namespace
+    /// are moved into this namespace, whose package can be set with
+    /// [`ExternalPackage`](crate::generation::ExternalPackage).
+    External(String),
+    /// An opaque placeholder: every use of an excluded type is replaced with this format, e.g.
+    /// [`Format::Str`] for an id that the client only passes along. The format must match the
+    /// excluded type's wire format for (de)serialization to work.
+    Opaque(Format),
+}
+
+type Predicate = Box<dyn Fn(&QualifiedTypeName, &ContainerFormat) -> bool>;
+
+/// Leaves types out of a registry.
+///
+/// A type is excluded if any of the filter's rules matches it; the uses of excluded types by the
+/// types that are kept are then handled as the filter's [`Replacement`] says.
+#[derive(Default)]
+pub struct Filter {
+    rules: Vec<Predicate>,
+    replacement: Replacement,
+}
+
+impl Filter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Excludes the type named `name`, e.g. `User` or `api.User`.
+    #[must_use]
+    pub fn exclude_type(self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        self.exclude_if(move |qualified_name, _| display_name(qualified_name) == name)
+    }
+
+    /// Excludes every type in the namespace `namespace`.
+    #[must_use]
+    pub fn exclude_namespace(self, namespace: impl Into<String>) -> Self {
+        let namespace = Namespace::Named(namespace.into());
+        self.exclude_if(move |name, _| name.namespace == namespace)
+    }
+
+    /// Excludes every type for which `predicate` returns `true`.
+    #[must_use]
+    pub fn exclude_if(
+        mut self,
+        predicate: impl Fn(&QualifiedTypeName, &ContainerFormat) -> bool + 'static,
+    ) -> Self {
+        self.rules.push(Box::new(predicate));
+        self
+    }
+
+    /// Sets what replaces the uses of excluded types ([`Replacement::Remove`] by default).
+    #[must_use]
+    pub fn replace_with(mut self, replacement: Replacement) -> Self {
+        self.replacement = replacement;
+        self
+    }
+
+    /// The registry without the excluded types.
+    ///
+    /// # Errors
+    /// Returns [`Error::Excluded`] if a type that is kept uses an excluded type and the
+    /// replacement is [`Replacement::Remove`], or [`Error::UnknownFormat`] if a type that is
+    /// kept has an incomplete format.
+    pub fn apply(&self, registry: &Registry) -> Result<Registry, Error> {
+        let excluded = registry
+            .iter()
+            .filter(|(name, format)| self.rules.iter().any(|rule| rule(name, format)))
+            .map(|(name, _)| name.clone())
+            .collect::<BTreeSet<_>>();
+
+        let mut filtered = Registry::new();
+        for (name, format) in registry {
+            if excluded.contains(name) {
+                continue;
+            }
+            let mut format = format.clone();
+            let mut used = None;
+            format
+                .visit_mut(&mut |format| {
+                    let (Format::TypeName(type_name)
+                    | Format::Generic {
+                        name: type_name, ..
+                    }) = format
+                    else {
+                        return Ok(());
+                    };
+                    if !excluded.contains(type_name) {
+                        return Ok(());
+                    }
+                    match &self.replacement {
+                        Replacement::Remove => {
+                            used.get_or_insert_with(|| type_name.clone());
+                        }
+                        Replacement::External(namespace) => {
+                            if type_name.namespace == Namespace::Root {
+                                type_name.namespace = Namespace::Named(namespace.clone());
+                            }
+                        }
+                        Replacement::Opaque(opaque) => *format = opaque.clone(),
+                    }
+                    Ok(())
+                })
+                .map_err(|_| Error::UnknownFormat(display_name(name)))?;
+            if let Some(type_name) = used {
+                return Err(Error::Excluded {
+                    type_name: display_name(&type_name),
+                    used_by: display_name(name),
+                });
+            }
+            filtered.insert(name.clone(), format);
+        }
+        Ok(filtered)
+    }
+}
+
+impl fmt::Debug for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Filter")
+            .field("rules", &self.rules.len())
+            .field("replacement", &self.replacement)
+            .finish()
+    }
+}
+
+/// The part of `registry` that is reachable from `roots`, named e.g. `User` or `api.User`.
+///
+/// # Errors
+/// Returns [`Error::UnknownRoot`] if a root isn't in the registry, or
+/// [`Error::UnknownFormat`] if a type reachable from the roots has an incomplete format.
+pub fn prune<S: AsRef<str>>(
+    registry: &Registry,
+    roots: impl IntoIterator<Item = S>,
+) -> Result<Registry, Error> {
+    let mut queue = VecDeque::new();
+    for root in roots {
+        let root = root.as_ref();
+        let name = registry
+            .keys()
+            .find(|name| display_name(name) == root)
+            .ok_or_else(|| Error::UnknownRoot(root.to_string()))?;
+        queue.push_back(name.clone());
+    }
+
+    let mut pruned = Registry::new();
+    while let Some(name) = queue.pop_front() {
+        if pruned.contains_key(&name) {
+            continue;
+        }
+        // Uses of types that aren't in the registry, e.g. from another package, are left alone
+        let Some(format) = registry.get(&name) else {
+            continue;
+        };
+        format
+            .visit(&mut |format| {
+                if let Format::TypeName(type_name)
+                | Format::Generic {
+                    name: type_name, ..
+                } = format
+                {
+                    queue.push_back(type_name.clone());
+                }
+                Ok(())
+            })
+            .map_err(|_| Error::UnknownFormat(display_name(&name)))?;
+        pruned.insert(name, format.clone());
+    }
+    Ok(pruned)
+}
+
+fn display_name(name: &QualifiedTypeName) -> String {
+    name.format(ToString::to_string, ".")
+}
@@ -0,0 +1,242 @@
+#![allow(dead_code)]
+
+use facet::Facet;
+
+use super::{Error, Filter, Replacement, prune};
+use crate::{
+    self as fg,
+    generation::{module::split, typescript::TypeScriptCodeGenerator},
+    reflect,
+    reflection::format::{
+        ContainerFormat, ContainerMetadata, Doc, Format, QualifiedTypeName, Variable,
+    },
+};
+
+#[derive(Facet)]
+#[facet(fg::namespace = "admin")]
+struct AuditLog {
+    entries: Vec<String>,
+}
+
+#[derive(Facet)]
+struct UserId(String);
+
+#[derive(Facet)]
+struct User {
+    id: UserId,
+    name: String,
+}
+
+#[derive(Facet)]
+struct Account {
+    owner: User,
+    audit: Option<AuditLog>,
+}
+
+#[derive(Facet)]
+struct Settings {
+    theme: String,
+}
+
+#[derive(Facet)]
+#[repr(C)]
+enum Api {
+    GetAccount(Account),
+    GetSettings(Settings),
+}
+
+#[test]
+fn prune_keeps_types_reachable_from_roots() {
+    let registry = reflect!(Api).unwrap();
+    let pruned = prune(&registry, ["User", "Settings"]).unwrap();
+
+    let names = pruned
+        .keys()
+        .map(|name| name.format(ToString::to_string, "."))
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["Settings", "User", "UserId"]);
+}
+
+#[test]
+fn prune_accepts_namespaced_roots() {
+    let registry = reflect!(Api).unwrap();
+    let pruned = prune(&registry, ["admin.AuditLog"]).unwrap();
+    insta::assert_yaml_snapshot!(pruned, @"
+    ? namespace:
+        NAMED: admin
+      name: AuditLog
+    : STRUCT:
+        - - entries:
+              - SEQ: STR
+              - []
+        - []
+    ");
+}
+
+#[test]
+fn prune_rejects_unknown_roots() {
+    let registry = reflect!(Api).unwrap();
+    assert_eq!(
+        prune(&registry, ["AuditLog"]),
+        Err(Error::UnknownRoot("AuditLog".to_string()))
+    );
+}
+
+#[test]
+fn incomplete_formats_are_errors() {
+    let mut registry = reflect!(Settings).unwrap();
+    registry.insert(
+        QualifiedTypeName::root("Draft".to_string()),
+        ContainerFormat::NewTypeStruct(
+            Box::new(Format::Variable(Variable::new(None))),
+            Doc::default(),
+            ContainerMetadata::default(),
+        ),
+    );
+
+    let expected = Err(Error::UnknownFormat("Draft".to_string()));
+    assert_eq!(prune(&registry, ["Draft"]), expected);
+    assert_eq!(
+        Filter::new().exclude_type("Settings").apply(&registry),
+        expected
+    );
+}
+
+#[test]
+fn exclude_unused_types() {
+    let registry = reflect!(Api).unwrap();
+    let filtered = Filter::new()
+        .exclude_type("Api")
+        .exclude_type("Settings")
+        .apply(&registry)
+        .unwrap();
+
+    let names = filtered
+        .keys()
+        .map(|name| name.format(ToString::to_string, "."))
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["Account", "User", "UserId", "admin.AuditLog"]);
+}
+
+#[test]
+fn exclude_used_types_without_replacement() {
+    let registry = reflect!(Api).unwrap();
+    let error = Filter::new()
+        .exclude_namespace("admin")
+        .apply(&registry)
+        .unwrap_err();
+    assert_eq!(
+        error,
+        Error::Excluded {
+            type_name: "admin.AuditLog".to_string(),
+            used_by: "Account".to_string(),
+        }
+    );
+}
+
+#[test]
+fn replace_excluded_types_with_external_references() {
+    let registry = reflect!(Account).unwrap();
+    let filtered = Filter::new()
+        .exclude_namespace("admin")
+        .exclude_type("User")
+        .replace_with(Replacement::External("shared".to_string()))
+        .apply(&registry)
+        .unwrap();
+    insta::assert_yaml_snapshot!(filtered, @"
+    ? namespace: ROOT
+      name: Account
+    : STRUCT:
+        - - owner:
+              - TYPENAME:
+                  namespace:
+                    NAMED: shared
+                  name: User
+              - []
+          - audit:
+              - OPTION:
+                  TYPENAME:
+                    namespace:
+                      NAMED: admin
+                    name: AuditLog
+              - []
+        - []
+    ? namespace: ROOT
+      name: UserId
+    : NEWTYPESTRUCT:
+        - STR
+        - []
+    ");
+}
+
+#[test]
+fn external_references_are_imported_by_the_generated_code() {
+    let registry = reflect!(Account).unwrap();
+    let filtered = Filter::new()
+        .exclude_namespace("admin")
+        .exclude_type("User")
+        .replace_with(Replacement::External("shared".to_string()))
+        .apply(&registry)
+        .unwrap();
+
+    let modules = split("example", &filtered);
+    let (module, registry) = modules
+        .iter()
+        .find(|(module, _)| module.config().module_name() == "example")
+        .unwrap();
+    let mut output = Vec::new();
+    TypeScriptCodeGenerator::new(module.config())
+        .output(&mut output, registry)
+        .unwrap();
+    insta::assert_snapshot!(String::from_utf8(output).unwrap(), @r#"
+    import * as Admin from "./admin";
+    import * as Shared from "./shared";
+    type Optional<T> = T | null;
+    type str = string;
+
+    export class Account {
+        constructor (public owner: Shared.User, public audit: Optional<Admin.AuditLog>) {
+        }
+    }
+
+    export class UserId {
+        constructor (public value: str) {
+        }
+    }
+    "#);
+}
+
+#[test]
+fn replace_excluded_types_with_opaque_placeholders() {
+    let registry = reflect!(Account).unwrap();
+    let filtered = Filter::new()
+        .exclude_if(|_, format| matches!(format, ContainerFormat::NewTypeStruct(..)))
+        .exclude_namespace("admin")
+        .replace_with(Replacement::Opaque(Format::Str))
+        .apply(&registry)
+        .unwrap();
+    insta::assert_yaml_snapshot!(filtered, @"
+    ? namespace: ROOT
+      name: Account
+    : STRUCT:
+        - - owner:
+              - TYPENAME:
+                  namespace: ROOT
+                  name: User
+              - []
+          - audit:
+              - OPTION: STR
+              - []
+        - []
+    ? namespace: ROOT
+      name: User
+    : STRUCT:
+        - - id:
+              - STR
+              - []
+          - name:
+              - STR
+              - []
+        - []
+    ");
+}
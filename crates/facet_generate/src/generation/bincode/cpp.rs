@@ -0,0 +1,277 @@
+//! `EmitterPlugin<Cpp>` implementation for the bincode plugin.
+//!
+//! Writes `serialize` / `bincodeSerialize` member functions and static
+//! `deserialize` / `bincodeDeserialize` ones into the body of every C++ type.
+//! Each member is (de)serialized by the serde runtime's
+//! `serde::serialize` / `serde::deserialize<T>`, which dispatch on the
+//! member's type at compile time (`std::optional`, `std::vector`, a generated
+//! type, …), so no per-type helper code is needed, and generic types need no
+//! extra parameters.
+//!
+//! An enum serializes the variant that its `std::variant` holds, whose
+//! `struct` writes its index before its fields; the enum's static
+//! `deserialize` switches on the index.
+//!
+//! A field's `fg::override` code for C++ is a statement for `serialize`, and
+//! an expression for `deserialize`.
+
+use std::collections::BTreeMap;
+use std::io::{self, Result, Write as _};
+
+use super::BincodePlugin;
+use crate::Registry;
+use crate::generation::{
+    CodeGeneratorConfig, Error,
+    cpp::{Cpp, field_name, string_literal, variant_fields, variant_struct},
+    indent::{IndentWrite, IndentedWriter},
+    plugin::{EmitContext, EmitterPlugin, RuntimeFile},
+};
+use crate::reflection::format::{ContainerFormat, Format, Language, Named, VariantFormat};
+
+/// The statement that serializes a field of `this`, with its `fg::override`
+/// code for C++ if it has any.
+fn field_serialize(field: &Named<Format>) -> String {
+    let value = field_name(&field.name);
+    let statement = field
+        .metadata
+        .type_override(Language::Cpp)
+        .and_then(|type_override| type_override.serialize(&value))
+        .unwrap_or_else(|| format!("serde::serialize({value}, serializer)"));
+    if statement.trim_end().ends_with(';') || statement.trim_end().ends_with('}') {
+        statement
+    } else {
+        format!("{statement};")
+    }
+}
+
+/// The statement that deserializes a field into its member of `object`, with
+/// its `fg::override` code for C++ if it has any.
+fn field_deserialize(object: &str, field: &Named<Format>) -> String {
+    let member = format!("{object}.{}", field_name(&field.name));
+    match field
+        .metadata
+        .type_override(Language::Cpp)
+        .and_then(|type_override| type_override.deserialize.clone())
+    {
+        Some(expression) => format!("{member} = {expression};"),
+        None => format!("{member} = serde::deserialize<decltype({member})>(deserializer);"),
+    }
+}
+
+/// Writes `lines` (which may contain line breaks) at the writer's
+/// indentation.
+fn write_lines<W: IndentWrite>(w: &mut W, text: &str) -> Result<()> {
+    for line in text.lines() {
+        writeln!(w, "{line}")?;
+    }
+    Ok(())
+}
+
+/// Writes the `serialize` member function of a `struct`, which writes the
+/// variant index if it has one, then its fields, within a container.
+fn write_serialize<W: IndentWrite>(
+    w: &mut W,
+    index: Option<usize>,
+    fields: &[Named<Format>],
+) -> Result<()> {
+    writeln!(w, "template <typename Serializer>")?;
+    if index.is_none() && fields.is_empty() {
+        return writeln!(w, "void serialize(Serializer &) const {{}}");
+    }
+    writeln!(w, "void serialize(Serializer &serializer) const {{")?;
+    w.indent();
+    writeln!(w, "serializer.increase_container_depth();")?;
+    if let Some(index) = index {
+        writeln!(w, "serializer.serialize_variant_index({index});")?;
+    }
+    for field in fields {
+        write_lines(w, &field_serialize(field))?;
+    }
+    writeln!(w, "serializer.decrease_container_depth();")?;
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// Writes the static `deserialize` member function of a `struct`, which
+/// reads its fields in order.
+fn write_deserialize<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    fields: &[Named<Format>],
+) -> Result<()> {
+    writeln!(w, "template <typename Deserializer>")?;
+    if fields.is_empty() {
+        return writeln!(
+            w,
+            "static {name} deserialize(Deserializer &) {{ return {name}{{}}; }}"
+        );
+    }
+    writeln!(
+        w,
+        "static {name} deserialize(Deserializer &deserializer) {{"
+    )?;
+    w.indent();
+    writeln!(w, "deserializer.increase_container_depth();")?;
+    writeln!(w, "{name} obj{{}};")?;
+    for field in fields {
+        write_lines(w, &field_deserialize("obj", field))?;
+    }
+    writeln!(w, "deserializer.decrease_container_depth();")?;
+    writeln!(w, "return obj;")?;
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// Writes `bincodeSerialize` and `bincodeDeserialize`, which (de)serialize a
+/// value from the whole of a byte buffer.
+fn write_bincode<W: IndentWrite>(w: &mut W, name: &str) -> Result<()> {
+    writeln!(w, "std::vector<std::uint8_t> bincodeSerialize() const {{")?;
+    w.indent();
+    writeln!(w, "return serde::bincode_serialize(*this);")?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "static {name} bincodeDeserialize(const std::vector<std::uint8_t> &input) {{"
+    )?;
+    w.indent();
+    writeln!(w, "return serde::bincode_deserialize<{name}>(input);")?;
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// Writes the member functions of an enum, which serializes the variant that
+/// it holds, and reads any of them by their variant index.
+fn write_enum<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+) -> Result<()> {
+    writeln!(w, "template <typename Serializer>")?;
+    writeln!(w, "void serialize(Serializer &serializer) const {{")?;
+    w.indent();
+    writeln!(
+        w,
+        "std::visit([&serializer](const auto &variant) {{ variant.serialize(serializer); }}, value);"
+    )?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "template <typename Deserializer>")?;
+    writeln!(
+        w,
+        "static {name} deserialize(Deserializer &deserializer) {{"
+    )?;
+    w.indent();
+    writeln!(w, "deserializer.increase_container_depth();")?;
+    writeln!(
+        w,
+        "std::uint32_t index = deserializer.deserialize_variant_index();"
+    )?;
+    writeln!(w, "{name} obj{{}};")?;
+    writeln!(w, "switch (index) {{")?;
+    w.indent();
+    for (index, variant) in variants {
+        let variant_name = variant_struct(&variant.name);
+        let fields = variant_fields(&variant.value);
+        if fields.is_empty() {
+            writeln!(w, "case {index}:")?;
+            w.indent();
+            writeln!(w, "obj.value = {variant_name}{{}};")?;
+        } else {
+            writeln!(w, "case {index}: {{")?;
+            w.indent();
+            writeln!(w, "{variant_name} variant{{}};")?;
+            for field in &fields {
+                write_lines(w, &field_deserialize("variant", field))?;
+            }
+            writeln!(w, "obj.value = std::move(variant);")?;
+        }
+        writeln!(w, "break;")?;
+        w.unindent();
+        if !fields.is_empty() {
+            writeln!(w, "}}")?;
+        }
+    }
+    writeln!(w, "default:")?;
+    w.indent();
+    // Unknown variants, e.g. added by a later version, are read as the catch-all.
+    match variants.values().find(|variant| variant.metadata.other) {
+        Some(other) => {
+            writeln!(w, "obj.value = {}{{}};", variant_struct(&other.name))?;
+            writeln!(w, "break;")?;
+        }
+        None => writeln!(
+            w,
+            "throw serde::deserialization_error({} + std::to_string(index));",
+            string_literal(&format!("Unknown variant index for {name}: "))
+        )?,
+    }
+    w.unindent();
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w, "deserializer.decrease_container_depth();")?;
+    writeln!(w, "return obj;")?;
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+// ---------------------------------------------------------------------------
+// EmitterPlugin implementation
+// ---------------------------------------------------------------------------
+
+impl EmitterPlugin<Cpp> for BincodePlugin {
+    /// Every header includes the bincode runtime.
+    fn imports(&self, _config: &CodeGeneratorConfig) -> Vec<String> {
+        vec!["serde/bincode.hpp".to_string()]
+    }
+
+    /// Returns the `serde/serde.hpp` and `serde/bincode.hpp` runtime headers,
+    /// to be written into the generated library's `include` directory.
+    fn runtime_files(&self) -> Vec<RuntimeFile> {
+        static SERDE: include_dir::Dir<'static> =
+            include_dir::include_dir!("$CARGO_MANIFEST_DIR/runtime/cpp/serde");
+
+        SERDE
+            .files()
+            .map(|f| RuntimeFile {
+                relative_path: format!("serde/{}", f.path().display()),
+                contents: f.contents().to_vec(),
+            })
+            .collect()
+    }
+
+    /// Every type gets member functions.
+    fn has_type_body(&self, _ctx: &EmitContext) -> bool {
+        true
+    }
+
+    /// The member functions of every type: a variant's `struct` serializes
+    /// itself, and its enum reads any of them.
+    fn type_body(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
+        let config = w.config();
+        let mut w = IndentedWriter::new(&mut *w, config);
+        let name = ctx.name();
+        if let Some(variant) = &ctx.variant {
+            return write_serialize(&mut w, Some(variant.index), variant.fields);
+        }
+        match ctx.container.format {
+            ContainerFormat::Enum(variants, ..) => write_enum(&mut w, name, variants)?,
+            _ => {
+                let fields = ctx.fields();
+                write_serialize(&mut w, None, &fields)?;
+                writeln!(w)?;
+                write_deserialize(&mut w, name, &fields)?;
+            }
+        }
+        writeln!(w)?;
+        write_bincode(&mut w, name)
+    }
+
+    /// Rejects untagged enums, which bincode can't encode.
+    fn check_registry(&self, registry: &Registry) -> std::result::Result<(), Error> {
+        super::check_registry(registry)
+    }
+}
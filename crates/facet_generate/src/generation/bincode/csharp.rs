@@ -8,7 +8,9 @@
 //! [`EmitContext::config`](crate::generation::plugin::EmitContext)
 //! (`unit_variant_enums`) at call time.  C-style enums (all-unit-variant enums) are
 //! emitted as plain C# `enum` types and must be serialized via a static
-//! `{EnumName}Bincode` helper class rather than instance methods.
+//! `{EnumName}Bincode` helper class rather than instance methods. Likewise, the
+//! names of branded newtypes (`branded_type_names`) are read to pick the
+//! `Nullable<T>` option helpers for them, since they are emitted as `record struct`s.
 //!
 //! # Extension points implemented
 //!
@@ -21,21 +23,22 @@
 //! | `after_type` | `{EnumName}Bincode` static helper class for all-unit enums |
 
 use std::borrow::Cow;
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::BTreeMap;
 use std::io;
 
 use super::BincodePlugin;
 
 use heck::{ToLowerCamelCase, ToUpperCamelCase};
 
+use crate::Registry;
 use crate::generation::{
-    CodeGeneratorConfig, Feature,
-    csharp::CSharp,
+    CodeGeneratorConfig, Error, Feature,
+    csharp::{CSharp, access_modifier},
     indent::{IndentWrite, Newlines, with_block},
     plugin::{EmitContext, EmitterPlugin, RuntimeFile},
 };
 use crate::reflection::format::{
-    ContainerFormat, Format, Named, Namespace, QualifiedTypeName, VariantFormat,
+    ContainerFormat, Format, Language, Named, Namespace, QualifiedTypeName, VariantFormat,
 };
 
 // ---------------------------------------------------------------------------
@@ -136,16 +139,31 @@ impl EmitterPlugin<CSharp> for BincodePlugin {
                 contents: include_bytes!("../csharp/installer/runtime/bincode/FacetHelpers.cs")
                     .to_vec(),
             },
+            RuntimeFile {
+                relative_path: "Facet/Runtime/Bincode/TemporalSerde.cs".to_string(),
+                contents: include_bytes!("../csharp/installer/runtime/bincode/TemporalSerde.cs")
+                    .to_vec(),
+            },
         ]
     }
 
     /// Returns `using` directives needed for bincode support.
     ///
-    /// Always includes `Facet.Runtime.Bincode`.  When `Feature::Uuid` is
-    /// active, also adds `System` so that `Guid` resolves.
+    /// Always includes `Facet.Runtime.Bincode`.  When `Feature::Uuid` or a
+    /// date/time feature is active, also adds `System` so that `Guid`,
+    /// `DateTimeOffset`, `DateOnly`, `TimeOnly` and `TimeSpan` resolve.
     fn imports(&self, config: &CodeGeneratorConfig) -> Vec<String> {
         let mut imports = vec!["using Facet.Runtime.Bincode;".to_string()];
-        if config.features.contains(&Feature::Uuid) {
+        if [
+            Feature::Uuid,
+            Feature::Timestamp,
+            Feature::Date,
+            Feature::Time,
+            Feature::Duration,
+        ]
+        .iter()
+        .any(|feature| config.features.contains(feature))
+        {
             imports.push("using System;".to_string());
         }
         imports
@@ -171,9 +189,11 @@ impl EmitterPlugin<CSharp> for BincodePlugin {
     ///
     /// All-unit enums are plain C# `enum` types that cannot implement interfaces, so
     /// they return an empty list here; their bincode helpers are emitted in
-    /// [`after_type`](Self::after_type) instead.
+    /// [`after_type`](Self::after_type) instead. Generic types also return an
+    /// empty list, because their methods take a serializer or deserializer
+    /// delegate per type parameter.
     fn type_conformances(&self, ctx: &EmitContext) -> Vec<String> {
-        if is_all_unit_enum(ctx.container.format) {
+        if is_all_unit_enum(ctx.container.format) || !ctx.type_params().is_empty() {
             vec![]
         } else {
             let name = ctx.name().to_upper_camel_case();
@@ -196,32 +216,46 @@ impl EmitterPlugin<CSharp> for BincodePlugin {
     /// - Non-unit enum → abstract `Serialize`, per-variant helpers, static `Deserialize`
     /// - Everything else → `Serialize`, `Deserialize`, `BincodeSerialize`, `BincodeDeserialize`
     fn type_body(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
-        if let ContainerFormat::Enum(variants_map, _, _) = ctx.container.format {
+        if let ContainerFormat::Enum(variants_map, _, _, _) = ctx.container.format {
             if is_all_unit_enum(ctx.container.format) {
                 return Ok(());
             }
-            let variants: Vec<Named<VariantFormat>> = variants_map.values().cloned().collect();
-            write_record_bincode_helpers(w, ctx.name(), &variants, &ctx.config.unit_variant_enums)
+            write_record_bincode_helpers(w, ctx.name(), ctx.type_params(), variants_map, ctx.config)
         } else {
             write_class_bincode_methods(
                 w,
-                &ctx.name().to_upper_camel_case(),
+                &generic_name(ctx.name(), ctx.type_params()),
+                ctx.type_params(),
                 &ctx.fields(),
-                &ctx.config.unit_variant_enums,
+                ctx.config,
             )
         }
     }
 
     /// Emits the `{EnumName}Bincode` static helper class after all-unit enum declarations.
     fn after_type(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
-        if let ContainerFormat::Enum(variants_map, _, _) = ctx.container.format
+        if let ContainerFormat::Enum(variants_map, _, _, _) = ctx.container.format
             && is_all_unit_enum(ctx.container.format)
         {
             writeln!(w)?;
-            return write_enum_bincode_helpers(w, &ctx.name().to_upper_camel_case(), variants_map);
+            let access = access_modifier(
+                ctx.config
+                    .visibility(ctx.container.format.metadata().visibility),
+            );
+            return write_enum_bincode_helpers(
+                w,
+                access,
+                &ctx.name().to_upper_camel_case(),
+                variants_map,
+            );
         }
         Ok(())
     }
+
+    /// Rejects untagged enums, which bincode can't encode.
+    fn check_registry(&self, registry: &Registry) -> Result<(), Error> {
+        super::check_registry(registry)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -231,7 +265,7 @@ impl EmitterPlugin<CSharp> for BincodePlugin {
 /// Returns `true` when every variant of the given `ContainerFormat::Enum` is
 /// [`VariantFormat::Unit`] (i.e. this is a C-style enum).
 fn is_all_unit_enum(format: &ContainerFormat) -> bool {
-    if let ContainerFormat::Enum(variants, _, _) = format {
+    if let ContainerFormat::Enum(variants, _, _, _) = format {
         variants
             .values()
             .all(|v| matches!(v.value, VariantFormat::Unit))
@@ -343,15 +377,20 @@ fn escape_identifier(identifier: &str) -> Cow<'_, str> {
 fn write_class_bincode_methods(
     w: &mut dyn IndentWrite,
     class_name: &str,
+    type_params: &[String],
     fields: &[Named<Format>],
-    c_style_enums: &BTreeSet<String>,
+    config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
-    writeln!(w, "public void Serialize(ISerializer serializer)")?;
+    writeln!(
+        w,
+        "public void Serialize(ISerializer serializer{})",
+        serializer_params(type_params)
+    )?;
     with_block(w, Newlines::BOTH, |w| {
         writeln!(w, "serializer.IncreaseContainerDepth();")?;
         for field in fields {
             let field_name = field.name.to_upper_camel_case();
-            write_serialize_value(w, &field_name, &field.value, c_style_enums)?;
+            write_field_serialize(w, &field_name, field, config)?;
         }
         writeln!(w, "serializer.DecreaseContainerDepth();")?;
         Ok(())
@@ -360,14 +399,15 @@ fn write_class_bincode_methods(
     writeln!(w)?;
     writeln!(
         w,
-        "public static {class_name} Deserialize(IDeserializer deserializer)"
+        "public static {class_name} Deserialize(IDeserializer deserializer{})",
+        deserializer_params(type_params)
     )?;
     with_block(w, Newlines::BOTH, |w| {
         writeln!(w, "deserializer.IncreaseContainerDepth();")?;
         for field in fields {
             let lower_camel_name = field.name.to_lower_camel_case();
             let local_name = escape_identifier(&lower_camel_name);
-            write_deserialize_binding(w, &local_name, &field.value, c_style_enums)?;
+            write_field_deserialize(w, &local_name, field, config)?;
         }
         writeln!(w, "deserializer.DecreaseContainerDepth();")?;
         if fields.is_empty() {
@@ -388,11 +428,29 @@ fn write_class_bincode_methods(
         Ok(())
     })?;
 
+    write_bincode_wrappers(w, class_name, type_params)
+}
+
+/// Writes the `BincodeSerialize` / `BincodeDeserialize` wrappers around a
+/// type's `Serialize` / `Deserialize` methods.
+fn write_bincode_wrappers(
+    w: &mut dyn IndentWrite,
+    type_name: &str,
+    type_params: &[String],
+) -> io::Result<()> {
     writeln!(w)?;
-    writeln!(w, "public byte[] BincodeSerialize()")?;
+    writeln!(
+        w,
+        "public byte[] BincodeSerialize({})",
+        serializer_params(type_params).trim_start_matches(", ")
+    )?;
     with_block(w, Newlines::BOTH, |w| {
         writeln!(w, "var serializer = new BincodeSerializer();")?;
-        writeln!(w, "Serialize(serializer);")?;
+        writeln!(
+            w,
+            "Serialize(serializer{});",
+            delegate_args("serialize", type_params)
+        )?;
         writeln!(w, "return serializer.GetBytes();")?;
         Ok(())
     })?;
@@ -400,7 +458,8 @@ fn write_class_bincode_methods(
     writeln!(w)?;
     writeln!(
         w,
-        "public static {class_name} BincodeDeserialize(byte[] input)"
+        "public static {type_name} BincodeDeserialize(byte[] input{})",
+        deserializer_params(type_params)
     )?;
     with_block(w, Newlines::BOTH, |w| {
         writeln!(w, "if (input is null)")?;
@@ -412,7 +471,11 @@ fn write_class_bincode_methods(
             Ok(())
         })?;
         writeln!(w, "var deserializer = new BincodeDeserializer(input);")?;
-        writeln!(w, "var value = Deserialize(deserializer);")?;
+        writeln!(
+            w,
+            "var value = Deserialize(deserializer{});",
+            delegate_args("deserialize", type_params)
+        )?;
         writeln!(w, "if (deserializer.GetBufferOffset() < input.Length)")?;
         with_block(w, Newlines::BOTH, |w| {
             writeln!(
@@ -437,32 +500,44 @@ fn write_class_bincode_methods(
 /// - `BincodeSerialize` / `BincodeDeserialize` wrappers
 fn write_record_bincode_helpers(
     w: &mut dyn IndentWrite,
-    base_name: &str,
-    variants: &[Named<VariantFormat>],
-    c_style_enums: &BTreeSet<String>,
+    name: &str,
+    type_params: &[String],
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+    config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
-    writeln!(w, "public abstract void Serialize(ISerializer serializer);")?;
+    let base_name = generic_name(name, type_params);
+    let serializer_params = serializer_params(type_params);
+    let deserializer_params = deserializer_params(type_params);
+    let deserializer_args = delegate_args("deserialize", type_params);
+
+    writeln!(
+        w,
+        "public abstract void Serialize(ISerializer serializer{serializer_params});"
+    )?;
     writeln!(w)?;
 
-    for (index, variant) in variants.iter().enumerate() {
+    for (index, variant) in variants {
         let variant_name = variant.name.to_upper_camel_case();
 
         writeln!(
             w,
-            "private static {base_name} Deserialize{variant_name}(IDeserializer deserializer)"
+            "private static {base_name} Deserialize{variant_name}(IDeserializer deserializer{deserializer_params})"
         )?;
         with_block(w, Newlines::BOTH, |w| {
-            deserializer_variant_body(w, variant, c_style_enums)
+            deserializer_variant_body(w, variant, config)
         })?;
         writeln!(w)?;
 
         writeln!(w, "public sealed partial record {variant_name}")?;
         with_block(w, Newlines::BOTH, |w| {
-            writeln!(w, "public override void Serialize(ISerializer serializer)")?;
+            writeln!(
+                w,
+                "public override void Serialize(ISerializer serializer{serializer_params})"
+            )?;
             with_block(w, Newlines::BOTH, |w| {
                 writeln!(w, "serializer.IncreaseContainerDepth();")?;
                 writeln!(w, "serializer.SerializeVariantIndex({index});")?;
-                serializer_variant_body_write(w, variant, c_style_enums)?;
+                serializer_variant_body_write(w, variant, config)?;
                 writeln!(w, "serializer.DecreaseContainerDepth();")?;
                 Ok(())
             })?;
@@ -473,64 +548,38 @@ fn write_record_bincode_helpers(
 
     writeln!(
         w,
-        "public static {base_name} Deserialize(IDeserializer deserializer)"
+        "public static {base_name} Deserialize(IDeserializer deserializer{deserializer_params})"
     )?;
     with_block(w, Newlines::BOTH, |w| {
         writeln!(w, "var index = deserializer.DeserializeVariantIndex();")?;
         writeln!(w, "return index switch")?;
         with_block(w, Newlines::BOTH, |w| {
-            for (index, variant) in variants.iter().enumerate() {
+            for (index, variant) in variants {
                 let variant_name = variant.name.to_upper_camel_case();
-                writeln!(w, "{index} => Deserialize{variant_name}(deserializer),")?;
+                writeln!(
+                    w,
+                    "{index} => Deserialize{variant_name}(deserializer{deserializer_args}),"
+                )?;
+            }
+            // Unknown variants, e.g. added by a later version, are read as the catch-all.
+            match variants.values().find(|variant| variant.metadata.other) {
+                Some(other) => writeln!(
+                    w,
+                    "_ => Deserialize{}(deserializer{deserializer_args}),",
+                    other.name.to_upper_camel_case()
+                )?,
+                None => writeln!(
+                    w,
+                    "_ => throw new DeserializationError(\"Unknown variant index for {name}: \" + index),"
+                )?,
             }
-            writeln!(
-                w,
-                "_ => throw new DeserializationError(\"Unknown variant index for {base_name}: \" + index),"
-            )?;
             Ok(())
         })?;
         writeln!(w, ";")?;
         Ok(())
     })?;
 
-    writeln!(w)?;
-    writeln!(w, "public byte[] BincodeSerialize()")?;
-    with_block(w, Newlines::BOTH, |w| {
-        writeln!(w, "var serializer = new BincodeSerializer();")?;
-        writeln!(w, "Serialize(serializer);")?;
-        writeln!(w, "return serializer.GetBytes();")?;
-        Ok(())
-    })?;
-
-    writeln!(w)?;
-    writeln!(
-        w,
-        "public static {base_name} BincodeDeserialize(byte[] input)"
-    )?;
-    with_block(w, Newlines::BOTH, |w| {
-        writeln!(w, "if (input is null)")?;
-        with_block(w, Newlines::BOTH, |w| {
-            writeln!(
-                w,
-                "throw new DeserializationError(\"Cannot deserialize null array\");"
-            )?;
-            Ok(())
-        })?;
-        writeln!(w, "var deserializer = new BincodeDeserializer(input);")?;
-        writeln!(w, "var value = Deserialize(deserializer);")?;
-        writeln!(w, "if (deserializer.GetBufferOffset() < input.Length)")?;
-        with_block(w, Newlines::BOTH, |w| {
-            writeln!(
-                w,
-                "throw new DeserializationError(\"Some input bytes were not read\");"
-            )?;
-            Ok(())
-        })?;
-        writeln!(w, "return value;")?;
-        Ok(())
-    })?;
-
-    Ok(())
+    write_bincode_wrappers(w, &base_name, type_params)
 }
 
 /// Writes the static `{EnumName}Bincode` helper class for a C-style (all-unit-variant) enum.
@@ -540,6 +589,7 @@ fn write_record_bincode_helpers(
 /// `BincodeSerialize`, and `BincodeDeserialize` static methods.
 fn write_enum_bincode_helpers(
     w: &mut dyn IndentWrite,
+    access: &str,
     enum_name: &str,
     variants: &BTreeMap<u32, Named<VariantFormat>>,
 ) -> io::Result<()> {
@@ -549,7 +599,7 @@ fn write_enum_bincode_helpers(
         "/// Bincode serialization helpers for <see cref=\"{enum_name}\"/>."
     )?;
     writeln!(w, "/// </summary>")?;
-    write!(w, "public static class {enum_name}Bincode ")?;
+    write!(w, "{access} static class {enum_name}Bincode ")?;
     with_block(w, Newlines::BOTH, |w| {
         writeln!(
             w,
@@ -573,7 +623,7 @@ fn write_enum_bincode_helpers(
             writeln!(w, "deserializer.DecreaseContainerDepth();")?;
             writeln!(w, "return index switch")?;
             with_block(w, Newlines::BOTH, |w| {
-                for (index, variant) in variants.values().enumerate() {
+                for (index, variant) in variants {
                     writeln!(
                         w,
                         "{} => {}.{},",
@@ -582,10 +632,15 @@ fn write_enum_bincode_helpers(
                         variant.name.to_upper_camel_case()
                     )?;
                 }
-                writeln!(
-                    w,
-                    "_ => throw new DeserializationError(\"Unknown variant index for {enum_name}: \" + index),"
-                )?;
+                match variants.values().find(|variant| variant.metadata.other) {
+                    Some(other) => {
+                        writeln!(w, "_ => {enum_name}.{},", other.name.to_upper_camel_case())?
+                    }
+                    None => writeln!(
+                        w,
+                        "_ => throw new DeserializationError(\"Unknown variant index for {enum_name}: \" + index),"
+                    )?,
+                }
                 Ok(())
             })?;
             writeln!(w, ";")?;
@@ -648,25 +703,20 @@ fn write_enum_bincode_helpers(
 fn serializer_variant_body_write(
     w: &mut dyn IndentWrite,
     variant: &Named<VariantFormat>,
-    c_style_enums: &BTreeSet<String>,
+    config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
     match &variant.value {
         VariantFormat::Unit => Ok(()),
-        VariantFormat::NewType(format) => write_serialize_value(w, "Value", format, c_style_enums),
+        VariantFormat::NewType(format) => write_serialize_value(w, "Value", format, config),
         VariantFormat::Tuple(formats) => {
             for (index, format) in formats.iter().enumerate() {
-                write_serialize_value(w, &format!("Field{index}"), format, c_style_enums)?;
+                write_serialize_value(w, &format!("Field{index}"), format, config)?;
             }
             Ok(())
         }
         VariantFormat::Struct(fields) => {
             for field in fields {
-                write_serialize_value(
-                    w,
-                    &field.name.to_upper_camel_case(),
-                    &field.value,
-                    c_style_enums,
-                )?;
+                write_field_serialize(w, &field.name.to_upper_camel_case(), field, config)?;
             }
             Ok(())
         }
@@ -679,14 +729,14 @@ fn serializer_variant_body_write(
 fn deserializer_variant_body(
     w: &mut dyn IndentWrite,
     variant: &Named<VariantFormat>,
-    c_style_enums: &BTreeSet<String>,
+    config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
     match &variant.value {
         VariantFormat::Unit => {
             writeln!(w, "return new {}();", variant.name.to_upper_camel_case())
         }
         VariantFormat::NewType(format) => {
-            write_deserialize_binding(w, "value", format, c_style_enums)?;
+            write_deserialize_binding(w, "value", format, config)?;
             writeln!(
                 w,
                 "return new {}(value);",
@@ -695,7 +745,7 @@ fn deserializer_variant_body(
         }
         VariantFormat::Tuple(formats) => {
             for (index, format) in formats.iter().enumerate() {
-                write_deserialize_binding(w, &format!("field{index}"), format, c_style_enums)?;
+                write_deserialize_binding(w, &format!("field{index}"), format, config)?;
             }
             let args = (0..formats.len())
                 .map(|i| format!("field{i}"))
@@ -712,7 +762,7 @@ fn deserializer_variant_body(
             for field in fields {
                 let lower_camel_name = field.name.to_lower_camel_case();
                 let local_name = escape_identifier(&lower_camel_name);
-                write_deserialize_binding(w, &local_name, &field.value, c_style_enums)?;
+                write_field_deserialize(w, &local_name, field, config)?;
             }
             let args = fields
                 .iter()
@@ -748,15 +798,26 @@ fn write_serialize_expr(
     val: &str,
     ser: &str,
     format: &Format,
-    c_style_enums: &BTreeSet<String>,
+    config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
     match format {
         Format::Variable(_) => unreachable!("placeholders should not get this far"),
-        Format::TypeName(qtn) if c_style_enums.contains(&qtn.name) => {
+        Format::TypeName(qtn) if config.unit_variant_enums.contains(&qtn.name) => {
             let type_name = format_qualified_type_name(qtn);
             write!(w, "{type_name}Bincode.Serialize({val}, {ser})")
         }
         Format::TypeName(_) => write!(w, "{val}.Serialize({ser})"),
+        Format::Generic { args, .. } => {
+            write!(w, "{val}.Serialize({ser}")?;
+            for arg in args {
+                write!(w, ", ")?;
+                write_serialize_lambda(w, arg, config)?;
+            }
+            write!(w, ")")
+        }
+        Format::TypeParameter(param) => {
+            write!(w, "{}({val}, {ser})", delegate_name("serialize", param))
+        }
         Format::Unit => write!(w, "{ser}.SerializeUnit({val})"),
         Format::Bool => write!(w, "{ser}.SerializeBool({val})"),
         Format::I8 => write!(w, "{ser}.SerializeI8({val})"),
@@ -775,28 +836,31 @@ fn write_serialize_expr(
         Format::Str => write!(w, "{ser}.SerializeStr({val})"),
         Format::Bytes => write!(w, "{ser}.SerializeBytes({val})"),
         Format::Uuid => write!(w, "UuidSerde.Serialize({val}, {ser})"),
+        Format::Timestamp | Format::Date | Format::Time | Format::Duration => {
+            write!(w, "TemporalSerde.Serialize{format:?}({val}, {ser})")
+        }
         Format::Option(inner) => {
-            let helper = option_serialize_helper(inner);
+            let helper = option_serialize_helper(inner, config);
             write!(w, "FacetHelpers.{helper}({val}, {ser}, ")?;
-            write_serialize_lambda(w, inner, c_style_enums)?;
+            write_serialize_lambda(w, inner, config)?;
             write!(w, ")")
         }
         Format::Seq(inner) | Format::Set(inner) => {
             write!(w, "FacetHelpers.SerializeCollection({val}, {ser}, ")?;
-            write_serialize_lambda(w, inner, c_style_enums)?;
+            write_serialize_lambda(w, inner, config)?;
             write!(w, ")")
         }
         Format::Map { key, value } => {
             write!(w, "FacetHelpers.SerializeMap({val}, {ser}, ")?;
-            write_serialize_lambda(w, key, c_style_enums)?;
+            write_serialize_lambda(w, key, config)?;
             write!(w, ", ")?;
-            write_serialize_lambda(w, value, c_style_enums)?;
+            write_serialize_lambda(w, value, config)?;
             write!(w, ")")
         }
         Format::Tuple(_) => unreachable!("tuples are handled by callers"),
         Format::TupleArray { content, .. } => {
             write!(w, "FacetHelpers.SerializeArray({val}, {ser}, ")?;
-            write_serialize_lambda(w, content, c_style_enums)?;
+            write_serialize_lambda(w, content, config)?;
             write!(w, ")")
         }
     }
@@ -815,11 +879,11 @@ fn write_deserialize_expr(
     w: &mut dyn IndentWrite,
     de: &str,
     format: &Format,
-    c_style_enums: &BTreeSet<String>,
+    config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
     match format {
         Format::Variable(_) => unreachable!("placeholders should not get this far"),
-        Format::TypeName(qtn) if c_style_enums.contains(&qtn.name) => {
+        Format::TypeName(qtn) if config.unit_variant_enums.contains(&qtn.name) => {
             let type_name = format_qualified_type_name(qtn);
             write!(w, "{type_name}Bincode.Deserialize({de})")
         }
@@ -828,6 +892,17 @@ fn write_deserialize_expr(
             "{}.Deserialize({de})",
             csharp_type(&Format::TypeName(type_name.clone()))
         ),
+        Format::Generic { args, .. } => {
+            write!(w, "{}.Deserialize({de}", csharp_type(format))?;
+            for arg in args {
+                write!(w, ", ")?;
+                write_deserialize_lambda(w, arg, config)?;
+            }
+            write!(w, ")")
+        }
+        Format::TypeParameter(param) => {
+            write!(w, "{}({de})", delegate_name("deserialize", param))
+        }
         Format::Unit => write!(w, "{de}.DeserializeUnit()"),
         Format::Bool => write!(w, "{de}.DeserializeBool()"),
         Format::I8 => write!(w, "{de}.DeserializeI8()"),
@@ -846,38 +921,71 @@ fn write_deserialize_expr(
         Format::Str => write!(w, "{de}.DeserializeStr()"),
         Format::Bytes => write!(w, "{de}.DeserializeBytes()"),
         Format::Uuid => write!(w, "UuidSerde.Deserialize({de})"),
+        Format::Timestamp | Format::Date | Format::Time | Format::Duration => {
+            write!(w, "TemporalSerde.Deserialize{format:?}({de})")
+        }
         Format::Option(inner) => {
-            let helper = option_deserialize_helper(inner);
+            let helper = option_deserialize_helper(inner, config);
             write!(w, "FacetHelpers.{helper}({de}, ")?;
-            write_deserialize_lambda(w, inner, c_style_enums)?;
+            write_deserialize_lambda(w, inner, config)?;
             write!(w, ")")
         }
         Format::Seq(inner) => {
             write!(w, "FacetHelpers.DeserializeList({de}, ")?;
-            write_deserialize_lambda(w, inner, c_style_enums)?;
+            write_deserialize_lambda(w, inner, config)?;
             write!(w, ")")
         }
         Format::Set(inner) => {
             write!(w, "FacetHelpers.DeserializeSet({de}, ")?;
-            write_deserialize_lambda(w, inner, c_style_enums)?;
+            write_deserialize_lambda(w, inner, config)?;
             write!(w, ")")
         }
         Format::Map { key, value } => {
             write!(w, "FacetHelpers.DeserializeMap({de}, ")?;
-            write_deserialize_lambda(w, key, c_style_enums)?;
+            write_deserialize_lambda(w, key, config)?;
             write!(w, ", ")?;
-            write_deserialize_lambda(w, value, c_style_enums)?;
+            write_deserialize_lambda(w, value, config)?;
             write!(w, ")")
         }
         Format::Tuple(_) => unreachable!("tuples are handled by callers"),
         Format::TupleArray { content, size } => {
             write!(w, "FacetHelpers.DeserializeArray({de}, {size}, ")?;
-            write_deserialize_lambda(w, content, c_style_enums)?;
+            write_deserialize_lambda(w, content, config)?;
             write!(w, ")")
         }
     }
 }
 
+/// Serializes the struct or variant field held by `value_expr`, with its `fg::override` code
+/// for C# if it has any.
+fn write_field_serialize(
+    w: &mut dyn IndentWrite,
+    value_expr: &str,
+    field: &Named<Format>,
+    config: &CodeGeneratorConfig,
+) -> io::Result<()> {
+    let type_override = field.metadata.type_override(Language::CSharp);
+    match type_override.and_then(|type_override| type_override.serialize(value_expr)) {
+        Some(serialize) => writeln!(w, "{serialize}"),
+        None => write_serialize_value(w, value_expr, &field.value, config),
+    }
+}
+
+/// Deserializes a struct or variant field into the local `var_name`, with its `fg::override`
+/// code for C# if it has any.
+fn write_field_deserialize(
+    w: &mut dyn IndentWrite,
+    var_name: &str,
+    field: &Named<Format>,
+    config: &CodeGeneratorConfig,
+) -> io::Result<()> {
+    let type_override = field.metadata.type_override(Language::CSharp);
+    match type_override.and_then(|type_override| type_override.deserialize.as_ref()) {
+        Some(deserialize) => writeln!(w, "var {var_name} = {deserialize};"),
+        None => write_deserialize_binding(w, var_name, &field.value, config),
+    }
+}
+
 /// Writes a top-level serialize statement: `expr;\n`.
 ///
 /// Tuples are expanded inline — each element becomes its own statement, accessing
@@ -886,20 +994,15 @@ fn write_serialize_value(
     w: &mut dyn IndentWrite,
     value_expr: &str,
     format: &Format,
-    c_style_enums: &BTreeSet<String>,
+    config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
     if let Format::Tuple(formats) = format {
         for (index, inner) in formats.iter().enumerate() {
-            write_serialize_value(
-                w,
-                &format!("{value_expr}.Item{}", index + 1),
-                inner,
-                c_style_enums,
-            )?;
+            write_serialize_value(w, &format!("{value_expr}.Item{}", index + 1), inner, config)?;
         }
         Ok(())
     } else {
-        write_serialize_expr(w, value_expr, "serializer", format, c_style_enums)?;
+        write_serialize_expr(w, value_expr, "serializer", format, config)?;
         writeln!(w, ";")
     }
 }
@@ -912,16 +1015,11 @@ fn write_deserialize_binding(
     w: &mut dyn IndentWrite,
     var_name: &str,
     format: &Format,
-    c_style_enums: &BTreeSet<String>,
+    config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
     if let Format::Tuple(formats) = format {
         for (index, inner) in formats.iter().enumerate() {
-            write_deserialize_binding(
-                w,
-                &format!("{var_name}_item{}", index + 1),
-                inner,
-                c_style_enums,
-            )?;
+            write_deserialize_binding(w, &format!("{var_name}_item{}", index + 1), inner, config)?;
         }
         if formats.is_empty() {
             writeln!(w, "var {var_name} = new Unit();")
@@ -934,7 +1032,7 @@ fn write_deserialize_binding(
         }
     } else {
         write!(w, "var {var_name} = ")?;
-        write_deserialize_expr(w, "deserializer", format, c_style_enums)?;
+        write_deserialize_expr(w, "deserializer", format, config)?;
         writeln!(w, ";")
     }
 }
@@ -946,7 +1044,7 @@ fn write_deserialize_binding(
 fn write_serialize_lambda(
     w: &mut dyn IndentWrite,
     format: &Format,
-    c_style_enums: &BTreeSet<String>,
+    config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
     match format {
         Format::Tuple(formats) if formats.is_empty() => {
@@ -960,14 +1058,14 @@ fn write_serialize_lambda(
                     &format!("item.Item{}", index + 1),
                     "s",
                     inner,
-                    c_style_enums,
+                    config,
                 )?;
             }
             write!(w, "}}")
         }
         _ => {
             write!(w, "(item, s) => ")?;
-            write_serialize_expr(w, "item", "s", format, c_style_enums)
+            write_serialize_expr(w, "item", "s", format, config)
         }
     }
 }
@@ -979,7 +1077,7 @@ fn write_serialize_lambda(
 fn write_deserialize_lambda(
     w: &mut dyn IndentWrite,
     format: &Format,
-    c_style_enums: &BTreeSet<String>,
+    config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
     match format {
         Format::Tuple(formats) if formats.is_empty() => {
@@ -989,7 +1087,7 @@ fn write_deserialize_lambda(
             write!(w, "d => {{ ")?;
             for (index, inner) in formats.iter().enumerate() {
                 write!(w, "var item{} = ", index + 1)?;
-                write_deserialize_expr(w, "d", inner, c_style_enums)?;
+                write_deserialize_expr(w, "d", inner, config)?;
                 write!(w, "; ")?;
             }
             let values = (0..formats.len())
@@ -1000,7 +1098,7 @@ fn write_deserialize_lambda(
         }
         _ => {
             write!(w, "d => ")?;
-            write_deserialize_expr(w, "d", format, c_style_enums)
+            write_deserialize_expr(w, "d", format, config)
         }
     }
 }
@@ -1015,7 +1113,7 @@ fn write_serialize_tuple_stmts(
     val: &str,
     ser: &str,
     format: &Format,
-    c_style_enums: &BTreeSet<String>,
+    config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
     if let Format::Tuple(formats) = format {
         for (index, inner) in formats.iter().enumerate() {
@@ -1024,25 +1122,83 @@ fn write_serialize_tuple_stmts(
                 &format!("{val}.Item{}", index + 1),
                 ser,
                 inner,
-                c_style_enums,
+                config,
             )?;
         }
         Ok(())
     } else {
-        write_serialize_expr(w, val, ser, format, c_style_enums)?;
+        write_serialize_expr(w, val, ser, format, config)?;
         write!(w, "; ")
     }
 }
 
+// ---------------------------------------------------------------------------
+// Generic type parameters
+// ---------------------------------------------------------------------------
+
+/// The type name with its type parameter list (e.g. `Page<T>`).
+fn generic_name(name: &str, type_params: &[String]) -> String {
+    let name = name.to_upper_camel_case();
+    if type_params.is_empty() {
+        name
+    } else {
+        format!("{name}<{}>", type_params.join(", "))
+    }
+}
+
+/// Name of the delegate parameter that (de)serializes values of a type
+/// parameter, e.g. `serializeT` for `prefix = "serialize"` and `T`.
+fn delegate_name(prefix: &str, param: &str) -> String {
+    format!("{prefix}{}", param.to_upper_camel_case())
+}
+
+/// Trailing `Action<T, ISerializer>` parameters, one per type parameter.
+fn serializer_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| {
+            format!(
+                ", Action<{param}, ISerializer> {}",
+                delegate_name("serialize", param)
+            )
+        })
+        .collect()
+}
+
+/// Trailing `Func<IDeserializer, T>` parameters, one per type parameter.
+fn deserializer_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| {
+            format!(
+                ", Func<IDeserializer, {param}> {}",
+                delegate_name("deserialize", param)
+            )
+        })
+        .collect()
+}
+
+/// Trailing arguments forwarding the delegate parameters of `type_params`.
+fn delegate_args(prefix: &str, type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", {}", delegate_name(prefix, param)))
+        .collect()
+}
+
 // ---------------------------------------------------------------------------
 // Option helpers
 // ---------------------------------------------------------------------------
 
 /// Returns the `FacetHelpers` method name for serializing an `Option<T>`.
 ///
-/// Value types use `SerializeOption`; reference types use `SerializeOptionRef`.
-const fn option_serialize_helper(inner: &Format) -> &'static str {
-    if is_csharp_value_type(inner) {
+/// Value types (including branded newtypes, which are `record struct`s) use
+/// `SerializeOption`; reference types use `SerializeOptionRef`; unconstrained type
+/// parameters use `SerializeOptionGeneric`.
+fn option_serialize_helper(inner: &Format, config: &CodeGeneratorConfig) -> &'static str {
+    if matches!(inner, Format::TypeParameter(_)) {
+        "SerializeOptionGeneric"
+    } else if is_csharp_value_type(inner) || is_branded(inner, config) {
         "SerializeOption"
     } else {
         "SerializeOptionRef"
@@ -1051,9 +1207,13 @@ const fn option_serialize_helper(inner: &Format) -> &'static str {
 
 /// Returns the `FacetHelpers` method name for deserializing an `Option<T>`.
 ///
-/// Value types use `DeserializeOption`; reference types use `DeserializeOptionRef`.
-const fn option_deserialize_helper(inner: &Format) -> &'static str {
-    if is_csharp_value_type(inner) {
+/// Value types (including branded newtypes) use `DeserializeOption`; reference types
+/// use `DeserializeOptionRef`; unconstrained type parameters use
+/// `DeserializeOptionGeneric`.
+fn option_deserialize_helper(inner: &Format, config: &CodeGeneratorConfig) -> &'static str {
+    if matches!(inner, Format::TypeParameter(_)) {
+        "DeserializeOptionGeneric"
+    } else if is_csharp_value_type(inner) || is_branded(inner, config) {
         "DeserializeOption"
     } else {
         "DeserializeOptionRef"
@@ -1069,6 +1229,11 @@ fn csharp_type(format: &Format) -> String {
     match format {
         Format::Variable(_) => unreachable!("placeholders should not get this far"),
         Format::TypeName(qualified_type_name) => format_qualified_type_name(qualified_type_name),
+        Format::Generic { name, args } => {
+            let args = args.iter().map(csharp_type).collect::<Vec<_>>().join(", ");
+            format!("{}<{args}>", format_qualified_type_name(name))
+        }
+        Format::TypeParameter(name) => name.clone(),
         Format::Unit => "Unit".to_string(),
         Format::Bool => "bool".to_string(),
         Format::I8 => "sbyte".to_string(),
@@ -1087,6 +1252,10 @@ fn csharp_type(format: &Format) -> String {
         Format::Str => "string".to_string(),
         Format::Bytes => "byte[]".to_string(),
         Format::Uuid => "Guid".to_string(),
+        Format::Timestamp => "DateTimeOffset".to_string(),
+        Format::Date => "DateOnly".to_string(),
+        Format::Time => "TimeOnly".to_string(),
+        Format::Duration => "TimeSpan".to_string(),
         Format::Option(inner) => format!("{}?", csharp_type(inner)),
         Format::Seq(inner) => format!("ObservableCollection<{}>", csharp_type(inner)),
         Format::Set(inner) => format!("HashSet<{}>", csharp_type(inner)),
@@ -1131,6 +1300,11 @@ fn namespace_name(namespace: &str) -> String {
         .join(".")
 }
 
+/// Returns `true` for a reference to a branded newtype, emitted as a `readonly record struct`.
+fn is_branded(format: &Format, config: &CodeGeneratorConfig) -> bool {
+    matches!(format, Format::TypeName(qtn) if config.branded_type_names.contains(&qtn.name))
+}
+
 /// Returns `true` for C# value types (structs, primitives, tuples) that use
 /// `SerializeOption` / `DeserializeOption` rather than the `…Ref` variants.
 const fn is_csharp_value_type(format: &Format) -> bool {
@@ -1152,6 +1326,10 @@ const fn is_csharp_value_type(format: &Format) -> bool {
             | Format::F64
             | Format::Char
             | Format::Uuid
+            | Format::Timestamp
+            | Format::Date
+            | Format::Time
+            | Format::Duration
             | Format::Tuple(_)
     )
 }
@@ -1169,7 +1347,9 @@ mod tests {
         indent::{IndentConfig, IndentedWriter},
         plugin::EmitContext,
     };
-    use crate::reflection::format::{ContainerFormat, Doc, EnumTagging, QualifiedTypeName};
+    use crate::reflection::format::{
+        ContainerFormat, ContainerMetadata, Doc, EnumTagging, QualifiedTypeName,
+    };
 
     fn render(f: impl FnOnce(&mut dyn IndentWrite) -> io::Result<()>) -> String {
         let mut buf = Vec::new();
@@ -1199,7 +1379,7 @@ mod tests {
         let plugin = &BincodePlugin as &dyn EmitterPlugin<CSharp>;
         let config = CodeGeneratorConfig::new("test".to_string());
         let name = QualifiedTypeName::root("MyStruct".to_string());
-        let format = ContainerFormat::Struct(vec![], Doc::default());
+        let format = ContainerFormat::Struct(vec![], Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -1227,7 +1407,12 @@ mod tests {
         variants.insert(0u32, Named::new(&VariantFormat::Unit, "A".to_string()));
 
         let name = QualifiedTypeName::root("MyEnum".to_string());
-        let format = ContainerFormat::Enum(variants, EnumTagging::External, Doc::default());
+        let format = ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::default(),
+            ContainerMetadata::default(),
+        );
         let container = Container {
             name: &name,
             format: &format,
@@ -1246,7 +1431,7 @@ mod tests {
         let plugin = &BincodePlugin as &dyn EmitterPlugin<CSharp>;
         let config = CodeGeneratorConfig::new("test".to_string());
         let name = QualifiedTypeName::root("Foo".to_string());
-        let format = ContainerFormat::UnitStruct(Doc::default());
+        let format = ContainerFormat::UnitStruct(Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -1264,7 +1449,12 @@ mod tests {
         variants.insert(0u32, Named::new(&VariantFormat::Unit, "A".to_string()));
 
         let name = QualifiedTypeName::root("MyEnum".to_string());
-        let format = ContainerFormat::Enum(variants, EnumTagging::External, Doc::default());
+        let format = ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::default(),
+            ContainerMetadata::default(),
+        );
         let container = Container {
             name: &name,
             format: &format,
@@ -1283,7 +1473,7 @@ mod tests {
         let plugin = &BincodePlugin as &dyn EmitterPlugin<CSharp>;
         let config = CodeGeneratorConfig::new("test".to_string());
         let name = QualifiedTypeName::root("UnitStruct".to_string());
-        let format = ContainerFormat::UnitStruct(Doc::default());
+        let format = ContainerFormat::UnitStruct(Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -1320,7 +1510,12 @@ mod tests {
         variants.insert(1u32, Named::new(&VariantFormat::Unit, "Beta".to_string()));
 
         let name = QualifiedTypeName::root("MyEnum".to_string());
-        let format = ContainerFormat::Enum(variants, EnumTagging::External, Doc::default());
+        let format = ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::default(),
+            ContainerMetadata::default(),
+        );
         let container = Container {
             name: &name,
             format: &format,
@@ -1344,7 +1539,7 @@ mod tests {
         let plugin = &BincodePlugin as &dyn EmitterPlugin<CSharp>;
         let config = CodeGeneratorConfig::new("test".to_string());
         let name = QualifiedTypeName::root("Foo".to_string());
-        let format = ContainerFormat::Struct(vec![], Doc::default());
+        let format = ContainerFormat::Struct(vec![], Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -0,0 +1,595 @@
+//! `EmitterPlugin<Dart>` implementation for the bincode plugin.
+//!
+//! Writes `serialize` / `bincodeSerialize` methods and `deserialize` /
+//! `bincodeDeserialize` factory constructors into the body of every top-level
+//! Dart type. Generic containers are handled by the helpers of the serde
+//! runtime's `Serializer` and `Deserializer` (`serializeSeq`,
+//! `deserializeMap`, …), which take the (de)serializer of their elements as a
+//! closure, so no per-module helper code is needed.
+//!
+//! Sealed classes switch over their variants, so variant classes get no code
+//! of their own.
+
+use std::collections::BTreeMap;
+use std::io::{self, Result, Write};
+
+use heck::ToSnakeCase;
+
+use super::BincodePlugin;
+use crate::Registry;
+use crate::generation::{
+    BINCODE_NAMESPACE, CodeGeneratorConfig, Error, SERDE_NAMESPACE,
+    dart::{Dart, enum_value, identifier, import_uri, variant_class},
+    indent::{IndentWrite, IndentedWriter, Newlines},
+    plugin::{EmitContext, EmitterPlugin, RuntimeFile},
+};
+use crate::reflection::format::{
+    ContainerFormat, Format, Language, Named, QualifiedTypeName, VariantFormat,
+};
+
+/// The per-type-parameter serializer closure parameters of `serialize`
+/// (e.g. `, void Function(Serializer, T) serializeT`).
+fn serializer_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", void Function(Serializer, {param}) serialize{param}"))
+        .collect()
+}
+
+/// The per-type-parameter deserializer closure parameters of `deserialize`
+/// (e.g. `, T Function(Deserializer) deserializeT`).
+fn deserializer_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", {param} Function(Deserializer) deserialize{param}"))
+        .collect()
+}
+
+/// Forwards the closures for `type_params` (e.g. `, serializeT`).
+fn closure_args(prefix: &str, type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", {prefix}{param}"))
+        .collect()
+}
+
+/// The name of a type as the generated code refers to it, e.g. `other.Child`.
+fn type_name(name: &QualifiedTypeName) -> String {
+    name.format(|namespace| namespace.to_snake_case(), ".")
+}
+
+/// A closure of `params` running `statements`: `(level1) => …` for a single
+/// statement, or a block body for several.
+fn closure(params: &str, statements: &[String]) -> String {
+    match statements {
+        [statement] => format!("({params}) => {statement}"),
+        statements => format!("({params}) {{ {}; }}", statements.join("; ")),
+    }
+}
+
+/// The statements that serialize `value` (without their `;`). Most formats
+/// take a single statement; tuples take one per element.
+fn serialize(value: &str, format: &Format, level: usize) -> Vec<String> {
+    let call = |method: &str| vec![format!("serializer.{method}({value})")];
+    let element = |format: &Format| {
+        let param = format!("level{}", level + 1);
+        closure(&param, &serialize(&param, format, level + 1))
+    };
+    match format {
+        Format::Unit => call("serializeUnit"),
+        Format::Bool => call("serializeBool"),
+        Format::I8 => call("serializeI8"),
+        Format::I16 => call("serializeI16"),
+        Format::I32 => call("serializeI32"),
+        Format::I64 => call("serializeI64"),
+        Format::I128 => call("serializeI128"),
+        Format::U8 => call("serializeU8"),
+        Format::U16 => call("serializeU16"),
+        Format::U32 => call("serializeU32"),
+        Format::U64 => call("serializeU64"),
+        Format::U128 => call("serializeU128"),
+        Format::F32 => call("serializeF32"),
+        Format::F64 => call("serializeF64"),
+        Format::Char => call("serializeChar"),
+        Format::Str | Format::Date | Format::Time => call("serializeStr"),
+        Format::Bytes => call("serializeBytes"),
+        Format::Uuid => call("serializeUuid"),
+        Format::Timestamp => call("serializeTimestamp"),
+        Format::Duration => call("serializeDuration"),
+        Format::Option(format) => {
+            vec![format!(
+                "serializer.serializeOption({value}, {})",
+                element(format)
+            )]
+        }
+        Format::Seq(format) => {
+            vec![format!(
+                "serializer.serializeSeq({value}, {})",
+                element(format)
+            )]
+        }
+        Format::Set(format) => {
+            vec![format!(
+                "serializer.serializeSet({value}, {})",
+                element(format)
+            )]
+        }
+        Format::Map {
+            key,
+            value: map_value,
+        } => {
+            let key_param = format!("key{}", level + 1);
+            let value_param = format!("value{}", level + 1);
+            vec![format!(
+                "serializer.serializeMap({value}, {}, {})",
+                closure(&key_param, &serialize(&key_param, key, level + 1)),
+                closure(&value_param, &serialize(&value_param, map_value, level + 1)),
+            )]
+        }
+        Format::TupleArray { content, size } => {
+            vec![format!(
+                "serializer.serializeTupleArray({value}, {size}, {})",
+                element(content)
+            )]
+        }
+        Format::Tuple(formats) => match formats.as_slice() {
+            [] => call("serializeUnit"),
+            [format] => serialize(value, format, level),
+            formats => formats
+                .iter()
+                .enumerate()
+                .flat_map(|(i, format)| serialize(&format!("{value}.${}", i + 1), format, level))
+                .collect(),
+        },
+        Format::TypeName(_) => vec![format!("{value}.serialize(serializer)")],
+        Format::TypeParameter(name) => vec![format!("serialize{name}(serializer, {value})")],
+        Format::Generic { args, .. } => {
+            let param = format!("level{}", level + 1);
+            let closures = args
+                .iter()
+                .map(|arg| {
+                    closure(
+                        &format!("serializer, {param}"),
+                        &serialize(&param, arg, level + 1),
+                    )
+                })
+                .collect::<Vec<_>>();
+            vec![format!(
+                "{value}.serialize(serializer, {})",
+                closures.join(", ")
+            )]
+        }
+        Format::Variable(_variable) => unreachable!("placeholders should not get this far"),
+    }
+}
+
+/// The expression that deserializes a value of `format`.
+fn deserialize(format: &Format) -> String {
+    let call = |method: &str| format!("deserializer.{method}()");
+    match format {
+        Format::Unit => call("deserializeUnit"),
+        Format::Bool => call("deserializeBool"),
+        Format::I8 => call("deserializeI8"),
+        Format::I16 => call("deserializeI16"),
+        Format::I32 => call("deserializeI32"),
+        Format::I64 => call("deserializeI64"),
+        Format::I128 => call("deserializeI128"),
+        Format::U8 => call("deserializeU8"),
+        Format::U16 => call("deserializeU16"),
+        Format::U32 => call("deserializeU32"),
+        Format::U64 => call("deserializeU64"),
+        Format::U128 => call("deserializeU128"),
+        Format::F32 => call("deserializeF32"),
+        Format::F64 => call("deserializeF64"),
+        Format::Char => call("deserializeChar"),
+        Format::Str | Format::Date | Format::Time => call("deserializeStr"),
+        Format::Bytes => call("deserializeBytes"),
+        Format::Uuid => call("deserializeUuid"),
+        Format::Timestamp => call("deserializeTimestamp"),
+        Format::Duration => call("deserializeDuration"),
+        Format::Option(format) => {
+            format!(
+                "deserializer.deserializeOption(() => {})",
+                deserialize(format)
+            )
+        }
+        Format::Seq(format) => {
+            format!("deserializer.deserializeSeq(() => {})", deserialize(format))
+        }
+        Format::Set(format) => {
+            format!("deserializer.deserializeSet(() => {})", deserialize(format))
+        }
+        Format::Map { key, value } => format!(
+            "deserializer.deserializeMap(() => {}, () => {})",
+            deserialize(key),
+            deserialize(value)
+        ),
+        Format::TupleArray { content, size } => format!(
+            "deserializer.deserializeTupleArray({size}, () => {})",
+            deserialize(content)
+        ),
+        Format::Tuple(formats) => match formats.as_slice() {
+            [] => call("deserializeUnit"),
+            [format] => deserialize(format),
+            formats => {
+                let elements = formats.iter().map(deserialize).collect::<Vec<_>>();
+                format!("({})", elements.join(", "))
+            }
+        },
+        Format::TypeName(name) => format!("{}.deserialize(deserializer)", type_name(name)),
+        Format::TypeParameter(name) => format!("deserialize{name}(deserializer)"),
+        Format::Generic { name, args } => {
+            let closures = args
+                .iter()
+                .map(|arg| format!("(deserializer) => {}", deserialize(arg)))
+                .collect::<Vec<_>>();
+            format!(
+                "{}.deserialize(deserializer, {})",
+                type_name(name),
+                closures.join(", ")
+            )
+        }
+        Format::Variable(_variable) => unreachable!("placeholders should not get this far"),
+    }
+}
+
+/// Serializes a struct or variant field, with its `fg::override` code for Dart if it has any.
+fn write_field_serialize<W: IndentWrite>(w: &mut W, field: &Named<Format>) -> Result<()> {
+    let name = identifier(&field.name);
+    let type_override = field.metadata.type_override(Language::Dart);
+    match type_override.and_then(|type_override| type_override.serialize(&name)) {
+        Some(serialize) => writeln!(w, "{serialize};"),
+        None => {
+            for statement in serialize(&name, &field.value, 0) {
+                writeln!(w, "{statement};")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Deserializes a struct or variant field into a local of the same name, with its
+/// `fg::override` code for Dart if it has any.
+fn write_field_deserialize<W: IndentWrite>(w: &mut W, field: &Named<Format>) -> Result<()> {
+    let name = identifier(&field.name);
+    let type_override = field.metadata.type_override(Language::Dart);
+    match type_override.and_then(|type_override| type_override.deserialize.as_ref()) {
+        Some(deserialize) => writeln!(w, "final {name} = {deserialize};"),
+        None => writeln!(w, "final {name} = {};", deserialize(&field.value)),
+    }
+}
+
+/// Reads `fields` into locals, within a container.
+fn write_fields_deserialize<W: IndentWrite>(w: &mut W, fields: &[Named<Format>]) -> Result<()> {
+    if fields.is_empty() {
+        return Ok(());
+    }
+    writeln!(w, "deserializer.increaseContainerDepth();")?;
+    for field in fields {
+        write_field_deserialize(w, field)?;
+    }
+    writeln!(w, "deserializer.decreaseContainerDepth();")
+}
+
+/// The expression that creates a `class` from the locals of its `fields`.
+fn construct(class: &str, fields: &[Named<Format>], positional: bool) -> String {
+    if fields.is_empty() {
+        return format!("const {class}()");
+    }
+    let args = fields
+        .iter()
+        .map(|field| {
+            let name = identifier(&field.name);
+            if positional {
+                name
+            } else {
+                format!("{name}: {name}")
+            }
+        })
+        .collect::<Vec<_>>();
+    format!("{class}({})", args.join(", "))
+}
+
+/// Writes `bincodeSerialize`, which serializes the value into a new byte array.
+fn write_bincode_serialize<W: IndentWrite>(w: &mut W, type_params: &[String]) -> Result<()> {
+    let params = serializer_params(type_params);
+    let args = closure_args("serialize", type_params);
+    write!(
+        w,
+        "Uint8List bincodeSerialize({}) ",
+        params.trim_start_matches(", ")
+    )?;
+    let mut w = w.block(Newlines::BOTH)?;
+    writeln!(w, "final serializer = BincodeSerializer();")?;
+    writeln!(w, "serialize(serializer{args});")?;
+    writeln!(w, "return serializer.getBytes();")
+}
+
+/// Writes `bincodeDeserialize`, which deserializes a value from the whole of `input`.
+fn write_bincode_deserialize<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    type_params: &[String],
+) -> Result<()> {
+    let params = deserializer_params(type_params);
+    let args = closure_args("deserialize", type_params);
+    write!(
+        w,
+        "factory {name}.bincodeDeserialize(Uint8List input{params}) "
+    )?;
+    let mut w = w.block(Newlines::BOTH)?;
+    writeln!(w, "final deserializer = BincodeDeserializer(input);")?;
+    writeln!(w, "final value = {name}.deserialize(deserializer{args});")?;
+    write!(w, "if (deserializer.getBufferOffset() < input.length) ")?;
+    {
+        let mut w = w.block(Newlines::BOTH)?;
+        writeln!(
+            w,
+            "throw const DeserializationError('Some input bytes were not read');"
+        )?;
+    }
+    writeln!(w, "return value;")
+}
+
+/// Writes the methods of a class: a struct, or a newtype or tuple struct (whose fields are
+/// `positional`).
+fn write_class_body<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    type_params: &[String],
+    fields: &[Named<Format>],
+    positional: bool,
+) -> Result<()> {
+    let params = serializer_params(type_params);
+    write!(w, "void serialize(Serializer serializer{params}) ")?;
+    if fields.is_empty() {
+        writeln!(w, "{{}}")?;
+    } else {
+        let mut w = w.block(Newlines::BOTH)?;
+        writeln!(w, "serializer.increaseContainerDepth();")?;
+        for field in fields {
+            write_field_serialize(&mut w, field)?;
+        }
+        writeln!(w, "serializer.decreaseContainerDepth();")?;
+    }
+    writeln!(w)?;
+    write_bincode_serialize(w, type_params)?;
+    writeln!(w)?;
+
+    let params = deserializer_params(type_params);
+    write!(
+        w,
+        "factory {name}.deserialize(Deserializer deserializer{params})"
+    )?;
+    if fields.is_empty() {
+        writeln!(w, " => {};", construct(name, fields, positional))?;
+    } else {
+        write!(w, " ")?;
+        let mut w = w.block(Newlines::BOTH)?;
+        write_fields_deserialize(&mut w, fields)?;
+        writeln!(w, "return {};", construct(name, fields, positional))?;
+    }
+    writeln!(w)?;
+    write_bincode_deserialize(w, name, type_params)
+}
+
+/// Writes the methods of an all-unit Dart `enum`.
+fn write_enum_body<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+) -> Result<()> {
+    write!(w, "void serialize(Serializer serializer) ")?;
+    {
+        let mut w = w.block(Newlines::BOTH)?;
+        writeln!(w, "serializer.increaseContainerDepth();")?;
+        if variants.keys().zip(0..).all(|(index, i)| *index == i) {
+            writeln!(w, "serializer.serializeVariantIndex(index);")?;
+        } else {
+            // Some variants are left out of this language or have pinned indices, so the
+            // values' indices don't match the variant indices.
+            write!(w, "serializer.serializeVariantIndex(switch (this) ")?;
+            {
+                let mut w = w.block(Newlines::OPEN)?;
+                for (index, variant) in variants {
+                    writeln!(w, "{name}.{} => {index},", enum_value(&variant.name))?;
+                }
+            }
+            writeln!(w, ");")?;
+        }
+        writeln!(w, "serializer.decreaseContainerDepth();")?;
+    }
+    writeln!(w)?;
+    write_bincode_serialize(w, &[])?;
+    writeln!(w)?;
+
+    write!(w, "factory {name}.deserialize(Deserializer deserializer) ")?;
+    {
+        let mut w = w.block(Newlines::BOTH)?;
+        writeln!(w, "deserializer.increaseContainerDepth();")?;
+        writeln!(w, "final index = deserializer.deserializeVariantIndex();")?;
+        writeln!(w, "deserializer.decreaseContainerDepth();")?;
+        write!(w, "return switch (index) ")?;
+        {
+            let mut w = w.block(Newlines::OPEN)?;
+            for (index, variant) in variants {
+                writeln!(w, "{index} => {name}.{},", enum_value(&variant.name))?;
+            }
+            match variants.values().find(|variant| variant.metadata.other) {
+                Some(other) => writeln!(w, "_ => {name}.{},", enum_value(&other.name))?,
+                None => writeln!(
+                    w,
+                    "_ => throw DeserializationError('Unknown variant index for {name}: $index'),"
+                )?,
+            }
+        }
+        writeln!(w, ";")?;
+    }
+    writeln!(w)?;
+    write_bincode_deserialize(w, name, &[])
+}
+
+/// The fields of a variant, and whether they are positional.
+fn variant_fields(variant: &VariantFormat) -> (Vec<Named<Format>>, bool) {
+    match variant {
+        VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+        VariantFormat::Unit => (vec![], true),
+        VariantFormat::NewType(format) => {
+            (vec![Named::new(format.as_ref(), "value".to_string())], true)
+        }
+        VariantFormat::Tuple(formats) => (
+            formats
+                .iter()
+                .enumerate()
+                .map(|(i, format)| Named::new(format, format!("field{i}")))
+                .collect(),
+            true,
+        ),
+        VariantFormat::Struct(fields) => (fields.clone(), false),
+    }
+}
+
+/// Writes the methods of a `sealed class`, which switch over its variants.
+fn write_sealed_class_body<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    type_params: &[String],
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+) -> Result<()> {
+    let params = serializer_params(type_params);
+    write!(w, "void serialize(Serializer serializer{params}) ")?;
+    {
+        let mut w = w.block(Newlines::BOTH)?;
+        write!(w, "switch (this) ")?;
+        let mut w = w.block(Newlines::BOTH)?;
+        for (index, variant) in variants {
+            let (fields, _) = variant_fields(&variant.value);
+            let class = variant_class(name, &variant.name);
+            let bindings = fields
+                .iter()
+                .map(|field| format!(":final {}", identifier(&field.name)))
+                .collect::<Vec<_>>();
+            writeln!(w, "case {class}({}):", bindings.join(", "))?;
+            w.indent();
+            writeln!(w, "serializer.increaseContainerDepth();")?;
+            writeln!(w, "serializer.serializeVariantIndex({index});")?;
+            for field in &fields {
+                write_field_serialize(&mut w, field)?;
+            }
+            writeln!(w, "serializer.decreaseContainerDepth();")?;
+            w.unindent();
+        }
+    }
+    writeln!(w)?;
+    write_bincode_serialize(w, type_params)?;
+    writeln!(w)?;
+
+    let params = deserializer_params(type_params);
+    write!(
+        w,
+        "factory {name}.deserialize(Deserializer deserializer{params}) "
+    )?;
+    {
+        let mut w = w.block(Newlines::BOTH)?;
+        writeln!(w, "final index = deserializer.deserializeVariantIndex();")?;
+        for (index, variant) in variants {
+            let (fields, positional) = variant_fields(&variant.value);
+            let class = variant_class(name, &variant.name);
+            write!(w, "if (index == {index}) ")?;
+            let mut w = w.block(Newlines::BOTH)?;
+            write_fields_deserialize(&mut w, &fields)?;
+            writeln!(w, "return {};", construct(&class, &fields, positional))?;
+        }
+        // Unknown variants, e.g. added by a later version, are read as the catch-all.
+        match variants.values().find(|variant| variant.metadata.other) {
+            Some(other) => writeln!(w, "return const {}();", variant_class(name, &other.name))?,
+            None => writeln!(
+                w,
+                "throw DeserializationError('Unknown variant index for {name}: $index');"
+            )?,
+        }
+    }
+    writeln!(w)?;
+    write_bincode_deserialize(w, name, type_params)
+}
+
+// ---------------------------------------------------------------------------
+// EmitterPlugin implementation
+// ---------------------------------------------------------------------------
+
+impl EmitterPlugin<Dart> for BincodePlugin {
+    /// Returns the serde and bincode Dart runtime libraries to be written
+    /// into the package's `lib/` directory.
+    fn runtime_files(&self) -> Vec<RuntimeFile> {
+        static SERDE: include_dir::Dir<'static> =
+            include_dir::include_dir!("$CARGO_MANIFEST_DIR/runtime/dart/serde");
+        static BINCODE: include_dir::Dir<'static> =
+            include_dir::include_dir!("$CARGO_MANIFEST_DIR/runtime/dart/bincode");
+
+        let mut files: Vec<RuntimeFile> = SERDE
+            .files()
+            .map(|f| RuntimeFile {
+                relative_path: format!("lib/serde/{}", f.path().display()),
+                contents: f.contents().to_vec(),
+            })
+            .collect();
+        files.extend(BINCODE.files().map(|f| RuntimeFile {
+            relative_path: format!("lib/bincode/{}", f.path().display()),
+            contents: f.contents().to_vec(),
+        }));
+        files
+    }
+
+    /// The serde and bincode runtime libraries, and `dart:typed_data` for the
+    /// byte arrays of `bincodeSerialize` / `bincodeDeserialize`.
+    fn imports(&self, config: &CodeGeneratorConfig) -> Vec<String> {
+        vec![
+            "import 'dart:typed_data';".to_string(),
+            format!(
+                "import '{}';",
+                import_uri(config, SERDE_NAMESPACE, "serde/serde.dart")
+            ),
+            format!(
+                "import '{}';",
+                import_uri(config, BINCODE_NAMESPACE, "bincode/bincode.dart")
+            ),
+        ]
+    }
+
+    /// Every top-level type gets bincode methods; variant classes get none.
+    fn has_type_body(&self, ctx: &EmitContext) -> bool {
+        !ctx.is_variant()
+    }
+
+    fn type_body(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
+        if ctx.is_variant() {
+            return Ok(());
+        }
+        let config = w.config();
+        let mut w = IndentedWriter::new(&mut *w, config);
+        let name = ctx.name();
+        match ctx.container.format {
+            ContainerFormat::Enum(variants, _, _, _)
+                if variants
+                    .values()
+                    .all(|variant| matches!(variant.value, VariantFormat::Unit)) =>
+            {
+                write_enum_body(&mut w, name, variants)
+            }
+            ContainerFormat::Enum(variants, _, _, _) => {
+                write_sealed_class_body(&mut w, name, ctx.type_params(), variants)
+            }
+            ContainerFormat::NewTypeStruct(..) | ContainerFormat::TupleStruct(..) => {
+                write_class_body(&mut w, name, ctx.type_params(), &ctx.fields(), true)
+            }
+            ContainerFormat::UnitStruct(..) | ContainerFormat::Struct(..) => {
+                write_class_body(&mut w, name, ctx.type_params(), &ctx.fields(), false)
+            }
+        }
+    }
+
+    /// Rejects untagged enums, which bincode can't encode.
+    fn check_registry(&self, registry: &Registry) -> std::result::Result<(), Error> {
+        super::check_registry(registry)
+    }
+}
@@ -0,0 +1,693 @@
+//! `EmitterPlugin<Go>` implementation for the bincode plugin.
+//!
+//! Writes `Serialize` / `BincodeSerialize` methods and `Deserialize<Type>` /
+//! `BincodeDeserialize<Type>` functions after every Go type. Generic containers
+//! are handled by the generic functions of the serde runtime
+//! (`serde.SerializeSeq`, `serde.DeserializeMap`, …), which take the
+//! (de)serializer of their elements as a function value, so no per-package
+//! helper code is needed.
+//!
+//! The interface of an enum whose variants carry data declares `Serialize` and
+//! `BincodeSerialize`, which each variant struct implements by writing its
+//! index before its fields; `Deserialize<Enum>` switches on the index.
+//!
+//! A field's `fg::override` code for Go is an expression: its `serialize`
+//! code returns an `error`, and its `deserialize` code a value and an `error`.
+
+use std::collections::BTreeMap;
+use std::io::{self, Result};
+
+use heck::ToUpperCamelCase;
+
+use super::BincodePlugin;
+use crate::Registry;
+use crate::generation::{
+    BINCODE_NAMESPACE, CodeGeneratorConfig, Error, SERDE_NAMESPACE,
+    go::{
+        Go, field_name, field_type, is_defined_type, is_union_format, qualified, string_literal,
+        type_args, type_expr, type_params_decl, variant_fields, variant_type,
+    },
+    indent::{IndentWrite, IndentedWriter},
+    plugin::{EmitContext, EmitterPlugin, RuntimeFile},
+};
+use crate::reflection::format::{ContainerFormat, Format, Language, Named, VariantFormat};
+
+/// The name of a type parameter's (de)serializer function, e.g. `serializeT`.
+fn closure_name(prefix: &str, param: &str) -> String {
+    format!("{prefix}{}", param.to_upper_camel_case())
+}
+
+/// The per-type-parameter serializer parameters of `Serialize`
+/// (e.g. `, serializeT func(serde.Serializer, T) error`).
+fn serializer_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| {
+            format!(
+                ", {} func(serde.Serializer, {param}) error",
+                closure_name("serialize", param)
+            )
+        })
+        .collect()
+}
+
+/// The per-type-parameter deserializer parameters of `Deserialize<Type>`
+/// (e.g. `, deserializeT func(serde.Deserializer) (T, error)`).
+fn deserializer_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| {
+            format!(
+                ", {} func(serde.Deserializer) ({param}, error)",
+                closure_name("deserialize", param)
+            )
+        })
+        .collect()
+}
+
+/// Forwards the functions for `type_params` (e.g. `, serializeT`).
+fn closure_args(prefix: &str, type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", {}", closure_name(prefix, param)))
+        .collect()
+}
+
+/// The runtime method that (de)serializes a primitive format, e.g. `U32` for
+/// `SerializeU32` / `DeserializeU32`.
+fn primitive(format: &Format) -> Option<&'static str> {
+    Some(match format {
+        Format::Unit => "Unit",
+        Format::Bool => "Bool",
+        Format::I8 => "I8",
+        Format::I16 => "I16",
+        Format::I32 => "I32",
+        Format::I64 => "I64",
+        Format::I128 => "I128",
+        Format::U8 => "U8",
+        Format::U16 => "U16",
+        Format::U32 => "U32",
+        Format::U64 => "U64",
+        Format::U128 => "U128",
+        Format::F32 => "F32",
+        Format::F64 => "F64",
+        Format::Char => "Char",
+        Format::Str => "Str",
+        Format::Bytes => "Bytes",
+        Format::Uuid => "UUID",
+        Format::Date => "Date",
+        Format::Time => "Time",
+        Format::Timestamp => "Timestamp",
+        Format::Duration => "Duration",
+        Format::Tuple(formats) if formats.is_empty() => "Unit",
+        _ => return None,
+    })
+}
+
+/// The functions that serialize each of `formats`, comma-separated.
+fn serializers(formats: &[Format]) -> String {
+    formats
+        .iter()
+        .map(serializer)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A function of type `func(serde.Serializer, T) error` that serializes a value
+/// of `format`.
+fn serializer(format: &Format) -> String {
+    match format {
+        Format::Tuple(formats) if formats.len() == 1 => serializer(&formats[0]),
+        Format::TypeParameter(name) => closure_name("serialize", name),
+        format => match primitive(format) {
+            Some(method) => format!("serde.Serializer.Serialize{method}"),
+            None => format!(
+                "func(serializer serde.Serializer, value {}) error {{ return {} }}",
+                type_expr(format),
+                serialize("value", format)
+            ),
+        },
+    }
+}
+
+/// The expression that serializes `value`, returning an `error`.
+fn serialize(value: &str, format: &Format) -> String {
+    if let Some(method) = primitive(format) {
+        return format!("serializer.Serialize{method}({value})");
+    }
+    match format {
+        Format::Option(format) => format!(
+            "serde.SerializeOption(serializer, {value}, {})",
+            serializer(format)
+        ),
+        Format::Seq(format) | Format::Set(format) => format!(
+            "serde.SerializeSeq(serializer, {value}, {})",
+            serializer(format)
+        ),
+        Format::Map { key, value: item } => format!(
+            "serde.SerializeMap(serializer, {value}, {}, {})",
+            serializer(key),
+            serializer(item)
+        ),
+        Format::TupleArray { content, .. } => format!(
+            "serde.SerializeArray(serializer, {value}[:], {})",
+            serializer(content)
+        ),
+        Format::Tuple(formats) => match formats.as_slice() {
+            [format] => serialize(value, format),
+            formats => format!(
+                "serde.SerializeTuple{}(serializer, {value}, {})",
+                formats.len(),
+                serializers(formats)
+            ),
+        },
+        Format::TypeName(_) => format!("{value}.Serialize(serializer)"),
+        Format::Generic { args, .. } => {
+            format!("{value}.Serialize(serializer, {})", serializers(args))
+        }
+        Format::TypeParameter(name) => {
+            format!("{}(serializer, {value})", closure_name("serialize", name))
+        }
+        Format::Variable(_variable) => unreachable!("placeholders should not get this far"),
+        _ => unreachable!("primitives are serialized by the runtime"),
+    }
+}
+
+/// The functions that deserialize each of `formats`, comma-separated.
+fn deserializers(formats: &[Format]) -> String {
+    formats
+        .iter()
+        .map(deserializer)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A function of type `func(serde.Deserializer) (T, error)` that deserializes a
+/// value of `format`.
+fn deserializer(format: &Format) -> String {
+    match format {
+        Format::Tuple(formats) if formats.len() == 1 => deserializer(&formats[0]),
+        Format::TypeParameter(name) => closure_name("deserialize", name),
+        Format::TypeName(name) => qualified(name, &format!("Deserialize{}", name.name)),
+        // Arrays are read into the array that the function returns.
+        Format::TupleArray { content, size } => format!(
+            "func(deserializer serde.Deserializer) (value [{size}]{}, err error) {{ err = serde.DeserializeArray(deserializer, value[:], {}); return }}",
+            type_expr(content),
+            deserializer(content)
+        ),
+        format => match primitive(format) {
+            Some(method) => format!("serde.Deserializer.Deserialize{method}"),
+            None => format!(
+                "func(deserializer serde.Deserializer) ({}, error) {{ return {} }}",
+                type_expr(format),
+                deserialize(format)
+            ),
+        },
+    }
+}
+
+/// The expression that deserializes a value of `format`, returning the value
+/// and an `error`.
+fn deserialize(format: &Format) -> String {
+    if let Some(method) = primitive(format) {
+        return format!("deserializer.Deserialize{method}()");
+    }
+    match format {
+        Format::Option(format) => format!(
+            "serde.DeserializeOption(deserializer, {})",
+            deserializer(format)
+        ),
+        Format::Seq(format) | Format::Set(format) => format!(
+            "serde.DeserializeSeq(deserializer, {})",
+            deserializer(format)
+        ),
+        Format::Map { key, value } => format!(
+            "serde.DeserializeMap(deserializer, {}, {})",
+            deserializer(key),
+            deserializer(value)
+        ),
+        Format::Tuple(formats) => match formats.as_slice() {
+            [format] => deserialize(format),
+            formats => format!(
+                "serde.DeserializeTuple{}(deserializer, {})",
+                formats.len(),
+                deserializers(formats)
+            ),
+        },
+        Format::Generic { name, args } => {
+            let type_args = args.iter().map(type_expr).collect::<Vec<_>>().join(", ");
+            format!(
+                "{}[{type_args}](deserializer, {})",
+                qualified(name, &format!("Deserialize{}", name.name)),
+                deserializers(args)
+            )
+        }
+        Format::TypeName(_) | Format::TypeParameter(_) | Format::TupleArray { .. } => {
+            format!("{}(deserializer)", deserializer(format))
+        }
+        Format::Variable(_variable) => unreachable!("placeholders should not get this far"),
+        _ => unreachable!("primitives are deserialized by the runtime"),
+    }
+}
+
+/// Writes the statement that returns `err` if it isn't `nil`, e.g. after
+/// `if err := …; err != nil {`.
+fn write_check<W: IndentWrite>(w: &mut W, call: &str, zero: &str) -> Result<()> {
+    writeln!(w, "if err := {call}; err != nil {{")?;
+    w.indent();
+    writeln!(w, "return {zero}err")?;
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// Serializes the field of `value` (e.g. `obj.X`), with its `fg::override` code for Go if it
+/// has any.
+fn write_field_serialize<W: IndentWrite>(
+    w: &mut W,
+    value: &str,
+    field: &Named<Format>,
+) -> Result<()> {
+    let type_override = field.metadata.type_override(Language::Go);
+    let call = type_override
+        .and_then(|type_override| type_override.serialize(value))
+        .unwrap_or_else(|| serialize(value, &field.value));
+    write_check(w, &call, "")
+}
+
+/// Deserializes a field, with its `fg::override` code for Go if it has any, assigning it with
+/// `assign` (e.g. `obj.X = val`) or returning `zero` and the error.
+fn write_field_deserialize<W: IndentWrite>(
+    w: &mut W,
+    field: &Named<Format>,
+    assign: &str,
+    zero: &str,
+) -> Result<()> {
+    let type_override = field.metadata.type_override(Language::Go);
+    let call = type_override
+        .and_then(|type_override| type_override.deserialize.clone())
+        .unwrap_or_else(|| deserialize(&field.value));
+    writeln!(w, "if val, err := {call}; err == nil {{")?;
+    w.indent();
+    writeln!(w, "{assign}")?;
+    w.unindent();
+    writeln!(w, "}} else {{")?;
+    w.indent();
+    writeln!(w, "return {zero}, err")?;
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// Writes the `Serialize` method of `ty` (e.g. `Page[T]`), which writes the variant index if
+/// it has one, then the `values` of its fields, within a container.
+fn write_serialize<W: IndentWrite>(
+    w: &mut W,
+    ty: &str,
+    type_params: &[String],
+    index: Option<usize>,
+    fields: &[(String, Named<Format>)],
+) -> Result<()> {
+    writeln!(
+        w,
+        "func (obj *{ty}) Serialize(serializer serde.Serializer{}) error {{",
+        serializer_params(type_params)
+    )?;
+    w.indent();
+    if index.is_some() || !fields.is_empty() {
+        write_check(w, "serializer.IncreaseContainerDepth()", "")?;
+        if let Some(index) = index {
+            write_check(w, &format!("serializer.SerializeVariantIndex({index})"), "")?;
+        }
+        for (value, field) in fields {
+            write_field_serialize(w, value, field)?;
+        }
+        writeln!(w, "serializer.DecreaseContainerDepth()")?;
+    }
+    writeln!(w, "return nil")?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write_bincode_serialize(w, ty, type_params)
+}
+
+/// Writes `BincodeSerialize`, which serializes the value into a new byte slice.
+fn write_bincode_serialize<W: IndentWrite>(
+    w: &mut W,
+    ty: &str,
+    type_params: &[String],
+) -> Result<()> {
+    writeln!(
+        w,
+        "func (obj *{ty}) BincodeSerialize({}) ([]byte, error) {{",
+        serializer_params(type_params).trim_start_matches(", ")
+    )?;
+    w.indent();
+    writeln!(w, "serializer := bincode.NewSerializer()")?;
+    write_check(
+        w,
+        &format!(
+            "obj.Serialize(serializer{})",
+            closure_args("serialize", type_params)
+        ),
+        "nil, ",
+    )?;
+    writeln!(w, "return serializer.GetBytes(), nil")?;
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// Writes the signature of a `Deserialize<Type>` function, up to its opening brace.
+fn write_deserialize_signature<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    type_params: &[String],
+) -> Result<()> {
+    writeln!(
+        w,
+        "func Deserialize{name}{}(deserializer serde.Deserializer{}) ({name}{}, error) {{",
+        type_params_decl(type_params),
+        deserializer_params(type_params),
+        type_args(type_params)
+    )
+}
+
+/// Writes `BincodeDeserialize<Type>`, which deserializes a value from the whole of `input`.
+fn write_bincode_deserialize<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    type_params: &[String],
+) -> Result<()> {
+    writeln!(
+        w,
+        "func BincodeDeserialize{name}{}(input []byte{}) ({name}{}, error) {{",
+        type_params_decl(type_params),
+        deserializer_params(type_params),
+        type_args(type_params)
+    )?;
+    w.indent();
+    writeln!(w, "deserializer := bincode.NewDeserializer(input)")?;
+    writeln!(
+        w,
+        "obj, err := Deserialize{name}{}(deserializer{})",
+        type_args(type_params),
+        closure_args("deserialize", type_params)
+    )?;
+    writeln!(
+        w,
+        "if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {{"
+    )?;
+    w.indent();
+    writeln!(
+        w,
+        "return obj, serde.NewDeserializationError(\"Some input bytes were not read\")"
+    )?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w, "return obj, err")?;
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// Writes the statements that deserialize the `fields` of `obj` within a container.
+fn write_fields_deserialize<W: IndentWrite>(
+    w: &mut W,
+    fields: &[Named<Format>],
+    zero: &str,
+) -> Result<()> {
+    for field in fields {
+        let assign = format!("obj.{} = val", field_name(&field.name));
+        write_field_deserialize(w, field, &assign, zero)?;
+    }
+    Ok(())
+}
+
+/// Writes the methods and functions of a struct: a struct, or a newtype, tuple or unit
+/// struct.
+fn write_struct<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    type_params: &[String],
+    fields: &[Named<Format>],
+) -> Result<()> {
+    let ty = format!("{name}{}", type_args(type_params));
+    let values = fields
+        .iter()
+        .map(|field| (format!("obj.{}", field_name(&field.name)), field.clone()))
+        .collect::<Vec<_>>();
+    write_serialize(w, &ty, type_params, None, &values)?;
+    writeln!(w)?;
+
+    write_deserialize_signature(w, name, type_params)?;
+    w.indent();
+    writeln!(w, "var obj {ty}")?;
+    if !fields.is_empty() {
+        write_check(w, "deserializer.IncreaseContainerDepth()", "obj, ")?;
+        write_fields_deserialize(w, fields, "obj")?;
+        writeln!(w, "deserializer.DecreaseContainerDepth()")?;
+    }
+    writeln!(w, "return obj, nil")?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write_bincode_deserialize(w, name, type_params)
+}
+
+/// Writes the methods and functions of a defined type (a branded newtype of a scalar), which
+/// is (de)serialized as its underlying type within a container.
+fn write_defined_type<W: IndentWrite>(w: &mut W, name: &str, field: &Named<Format>) -> Result<()> {
+    let value = format!("{}(*obj)", field_type(field));
+    write_serialize(w, name, &[], None, &[(value, field.clone())])?;
+    writeln!(w)?;
+
+    write_deserialize_signature(w, name, &[])?;
+    w.indent();
+    writeln!(w, "var obj {name}")?;
+    write_check(w, "deserializer.IncreaseContainerDepth()", "obj, ")?;
+    write_field_deserialize(w, field, &format!("obj = {name}(val)"), "obj")?;
+    writeln!(w, "deserializer.DecreaseContainerDepth()")?;
+    writeln!(w, "return obj, nil")?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write_bincode_deserialize(w, name, &[])
+}
+
+/// Writes the methods and functions of an enum whose variants are all unit variants, a
+/// `string` type whose constants are told apart by their variant index.
+fn write_enum<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+) -> Result<()> {
+    writeln!(
+        w,
+        "func (obj *{name}) Serialize(serializer serde.Serializer) error {{"
+    )?;
+    w.indent();
+    write_check(w, "serializer.IncreaseContainerDepth()", "")?;
+    writeln!(w, "var index uint32")?;
+    writeln!(w, "switch *obj {{")?;
+    for (index, variant) in variants {
+        writeln!(w, "case {}:", variant_type(name, &variant.name))?;
+        w.indent();
+        writeln!(w, "index = {index}")?;
+        w.unindent();
+    }
+    writeln!(w, "default:")?;
+    w.indent();
+    writeln!(
+        w,
+        "return serde.UnknownValueError({}, string(*obj))",
+        string_literal(name)
+    )?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    write_check(w, "serializer.SerializeVariantIndex(index)", "")?;
+    writeln!(w, "serializer.DecreaseContainerDepth()")?;
+    writeln!(w, "return nil")?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write_bincode_serialize(w, name, &[])?;
+    writeln!(w)?;
+
+    write_deserialize_signature(w, name, &[])?;
+    w.indent();
+    writeln!(w, "var obj {name}")?;
+    write_check(w, "deserializer.IncreaseContainerDepth()", "obj, ")?;
+    writeln!(w, "index, err := deserializer.DeserializeVariantIndex()")?;
+    writeln!(w, "if err != nil {{")?;
+    w.indent();
+    writeln!(w, "return obj, err")?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w, "switch index {{")?;
+    for (index, variant) in variants {
+        writeln!(w, "case {index}:")?;
+        w.indent();
+        writeln!(w, "obj = {}", variant_type(name, &variant.name))?;
+        w.unindent();
+    }
+    writeln!(w, "default:")?;
+    w.indent();
+    match variants.values().find(|variant| variant.metadata.other) {
+        Some(other) => writeln!(w, "obj = {}", variant_type(name, &other.name))?,
+        None => writeln!(
+            w,
+            "return obj, serde.UnknownVariantError({}, index)",
+            string_literal(name)
+        )?,
+    }
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w, "deserializer.DecreaseContainerDepth()")?;
+    writeln!(w, "return obj, nil")?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write_bincode_deserialize(w, name, &[])
+}
+
+/// Writes the functions that read an enum whose variants carry data, which switch on its
+/// variant index.
+fn write_union<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    type_params: &[String],
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+) -> Result<()> {
+    let args = type_args(type_params);
+    write_deserialize_signature(w, name, type_params)?;
+    w.indent();
+    write_check(w, "deserializer.IncreaseContainerDepth()", "nil, ")?;
+    writeln!(w, "index, err := deserializer.DeserializeVariantIndex()")?;
+    writeln!(w, "if err != nil {{")?;
+    w.indent();
+    writeln!(w, "return nil, err")?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w, "var value {name}{args}")?;
+    writeln!(w, "switch index {{")?;
+    for (index, variant) in variants {
+        let (fields, _) = variant_fields(&variant.value);
+        writeln!(w, "case {index}:")?;
+        w.indent();
+        writeln!(w, "var obj {}{args}", variant_type(name, &variant.name))?;
+        write_fields_deserialize(w, &fields, "nil")?;
+        writeln!(w, "value = &obj")?;
+        w.unindent();
+    }
+    // Unknown variants, e.g. added by a later version, are read as the catch-all.
+    writeln!(w, "default:")?;
+    w.indent();
+    match variants.values().find(|variant| variant.metadata.other) {
+        Some(other) => writeln!(w, "value = &{}{args}{{}}", variant_type(name, &other.name))?,
+        None => writeln!(
+            w,
+            "return nil, serde.UnknownVariantError({}, index)",
+            string_literal(name)
+        )?,
+    }
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w, "deserializer.DecreaseContainerDepth()")?;
+    writeln!(w, "return value, nil")?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write_bincode_deserialize(w, name, type_params)
+}
+
+// ---------------------------------------------------------------------------
+// EmitterPlugin implementation
+// ---------------------------------------------------------------------------
+
+impl EmitterPlugin<Go> for BincodePlugin {
+    /// Returns the serde and bincode Go runtime packages, to be written into
+    /// the generated module next to its packages.
+    fn runtime_files(&self) -> Vec<RuntimeFile> {
+        static SERDE: include_dir::Dir<'static> =
+            include_dir::include_dir!("$CARGO_MANIFEST_DIR/runtime/go/serde");
+        static BINCODE: include_dir::Dir<'static> =
+            include_dir::include_dir!("$CARGO_MANIFEST_DIR/runtime/go/bincode");
+
+        let mut files: Vec<RuntimeFile> = SERDE
+            .files()
+            .map(|f| RuntimeFile {
+                relative_path: format!("serde/{}", f.path().display()),
+                contents: f.contents().to_vec(),
+            })
+            .collect();
+        files.extend(BINCODE.files().map(|f| RuntimeFile {
+            relative_path: format!("bincode/{}", f.path().display()),
+            contents: f.contents().to_vec(),
+        }));
+        files
+    }
+
+    /// The serde and bincode runtime packages.
+    fn imports(&self, _config: &CodeGeneratorConfig) -> Vec<String> {
+        vec![SERDE_NAMESPACE.to_string(), BINCODE_NAMESPACE.to_string()]
+    }
+
+    /// The interface of an enum whose variants carry data declares the methods
+    /// that its variants implement.
+    fn has_type_body(&self, ctx: &EmitContext) -> bool {
+        !ctx.is_variant() && is_union_format(ctx.container.format)
+    }
+
+    fn type_body(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
+        let params = serializer_params(ctx.type_params());
+        writeln!(w, "Serialize(serializer serde.Serializer{params}) error")?;
+        writeln!(
+            w,
+            "BincodeSerialize({}) ([]byte, error)",
+            params.trim_start_matches(", ")
+        )
+    }
+
+    /// The methods and functions of every type: variant structs serialize themselves, and the
+    /// enum's function reads any of them.
+    fn after_type(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
+        let config = w.config();
+        let mut w = IndentedWriter::new(&mut *w, config);
+        if let Some(variant) = &ctx.variant {
+            let ty = format!(
+                "{}{}",
+                variant_type(variant.parent_name, variant.name),
+                type_args(variant.parent_type_params)
+            );
+            let values = variant
+                .fields
+                .iter()
+                .map(|field| (format!("obj.{}", field_name(&field.name)), field.clone()))
+                .collect::<Vec<_>>();
+            return write_serialize(
+                &mut w,
+                &ty,
+                variant.parent_type_params,
+                Some(variant.index),
+                &values,
+            );
+        }
+        let name = ctx.name();
+        match ctx.container.format {
+            ContainerFormat::Enum(variants, ..) if is_union_format(ctx.container.format) => {
+                write_union(&mut w, name, ctx.type_params(), variants)
+            }
+            ContainerFormat::Enum(variants, ..) => write_enum(&mut w, name, variants),
+            ContainerFormat::NewTypeStruct(_, _, metadata) if metadata.proxy => Ok(()),
+            ContainerFormat::NewTypeStruct(format, _, metadata)
+                if is_defined_type(ctx.container.format) =>
+            {
+                let field = Named::new(format.as_ref(), "value".to_string()).inheriting(metadata);
+                write_defined_type(&mut w, name, &field)
+            }
+            _ => write_struct(&mut w, name, ctx.type_params(), &ctx.fields()),
+        }
+    }
+
+    /// Rejects untagged enums, which bincode can't encode.
+    fn check_registry(&self, registry: &Registry) -> std::result::Result<(), Error> {
+        super::check_registry(registry)
+    }
+}
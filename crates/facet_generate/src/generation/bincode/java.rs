@@ -0,0 +1,688 @@
+//! `EmitterPlugin<Java>` implementation for the bincode plugin.
+//!
+//! Writes `serialize` / `bincodeSerialize` methods and static `deserialize` /
+//! `bincodeDeserialize` methods into the body of every Java type. Generic
+//! containers are handled by the default methods of the serde runtime's
+//! `Serializer` and `Deserializer` (`serialize_seq`, `deserialize_map`, …),
+//! which take the (de)serializer of their elements as a lambda or a method
+//! reference, so no per-package helper code is needed.
+//!
+//! The sealed interface of an enum whose variants carry data declares
+//! `serialize`, which each variant record implements by writing its index
+//! before its fields; the interface's static `deserialize` switches on the
+//! index.
+//!
+//! A field's `fg::override` code for Java is a statement for `serialize`, and
+//! an expression for `deserialize`.
+
+use std::collections::BTreeMap;
+use std::io::{self, Result, Write as _};
+
+use heck::ToUpperCamelCase;
+
+use super::BincodePlugin;
+use crate::Registry;
+use crate::generation::{
+    Error,
+    indent::{IndentWrite, IndentedWriter},
+    java::{
+        Java, Scope, enum_constant, field_name, is_union_format, string_literal, type_params_decl,
+        variant_class, variant_fields, write_call,
+    },
+    plugin::{EmitContext, EmitterPlugin, RuntimeFile},
+};
+use crate::reflection::format::{ContainerFormat, Format, Language, Named, VariantFormat};
+
+/// The name of a type parameter's (de)serializer, e.g. `serializeT`.
+fn closure_name(prefix: &str, param: &str) -> String {
+    format!("{prefix}{}", param.to_upper_camel_case())
+}
+
+/// The per-type-parameter serializer parameters of `serialize`
+/// (e.g. `, com.novi.serde.Serialize<T> serializeT`).
+fn serializer_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| {
+            format!(
+                ", com.novi.serde.Serialize<{param}> {}",
+                closure_name("serialize", param)
+            )
+        })
+        .collect()
+}
+
+/// The per-type-parameter deserializer parameters of `deserialize`
+/// (e.g. `, com.novi.serde.Deserialize<T> deserializeT`).
+fn deserializer_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| {
+            format!(
+                ", com.novi.serde.Deserialize<{param}> {}",
+                closure_name("deserialize", param)
+            )
+        })
+        .collect()
+}
+
+/// Forwards the (de)serializers of `type_params` (e.g. `, serializeT`).
+fn closure_args(prefix: &str, type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", {}", closure_name(prefix, param)))
+        .collect()
+}
+
+/// The name of the (de)serializer at the nesting `level` of lambdas: Java
+/// lambdas can't reuse the names of the enclosing method's variables.
+fn local(name: &str, level: usize) -> String {
+    if level == 0 {
+        name.to_string()
+    } else {
+        format!("{name}{level}")
+    }
+}
+
+/// The runtime method that (de)serializes a primitive format, e.g. `u32` for
+/// `serialize_u32` / `deserialize_u32`.
+fn primitive(format: &Format) -> Option<&'static str> {
+    Some(match format {
+        Format::Unit => "unit",
+        Format::Bool => "bool",
+        Format::I8 => "i8",
+        Format::I16 => "i16",
+        Format::I32 => "i32",
+        Format::I64 => "i64",
+        Format::I128 => "i128",
+        Format::U8 => "u8",
+        Format::U16 => "u16",
+        Format::U32 => "u32",
+        Format::U64 => "u64",
+        Format::U128 => "u128",
+        Format::F32 => "f32",
+        Format::F64 => "f64",
+        Format::Char => "char",
+        Format::Str => "str",
+        Format::Bytes => "bytes",
+        Format::Uuid => "uuid",
+        Format::Date => "date",
+        Format::Time => "time",
+        Format::Timestamp => "timestamp",
+        Format::Duration => "duration",
+        Format::Tuple(formats) if formats.is_empty() => "unit",
+        _ => return None,
+    })
+}
+
+/// The serializers of each of `formats`, comma-separated.
+fn serializers(scope: &Scope, formats: &[Format], level: usize) -> String {
+    formats
+        .iter()
+        .map(|format| serializer(scope, format, level))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A `com.novi.serde.Serialize<T>` that serializes a value of `format`, as a
+/// method reference or a lambda at the nesting `level`.
+fn serializer(scope: &Scope, format: &Format, level: usize) -> String {
+    match format {
+        Format::Tuple(formats) if formats.len() == 1 => serializer(scope, &formats[0], level),
+        Format::TypeParameter(name) => closure_name("serialize", name),
+        format => match primitive(format) {
+            Some(method) => format!("com.novi.serde.Serializer::serialize_{method}"),
+            None => format!(
+                "({}, {}) -> {}",
+                local("serializer", level),
+                local("value", level),
+                serialize(scope, &local("value", level), format, level)
+            ),
+        },
+    }
+}
+
+/// The expression that serializes `value` with the serializer of the nesting
+/// `level`.
+fn serialize(scope: &Scope, value: &str, format: &Format, level: usize) -> String {
+    let serializer = local("serializer", level);
+    if let Some(method) = primitive(format) {
+        return format!("{serializer}.serialize_{method}({value})");
+    }
+    let level = level + 1;
+    match format {
+        Format::Option(format) => format!(
+            "{serializer}.serialize_option({value}, {})",
+            self::serializer(scope, format, level)
+        ),
+        Format::Seq(format) | Format::Set(format) => format!(
+            "{serializer}.serialize_seq({value}, {})",
+            self::serializer(scope, format, level)
+        ),
+        Format::Map { key, value: item } => format!(
+            "{serializer}.serialize_map({value}, {}, {})",
+            self::serializer(scope, key, level),
+            self::serializer(scope, item, level)
+        ),
+        Format::TupleArray { content, size } => format!(
+            "{serializer}.serialize_tuple_array({value}, {size}, {})",
+            self::serializer(scope, content, level)
+        ),
+        Format::Tuple(formats) => match formats.as_slice() {
+            [format] => serialize(scope, value, format, level - 1),
+            formats => format!(
+                "{value}.serialize({serializer}, {})",
+                serializers(scope, formats, level)
+            ),
+        },
+        Format::TypeName(_) => format!("{value}.serialize({serializer})"),
+        Format::Generic { args, .. } => format!(
+            "{value}.serialize({serializer}, {})",
+            serializers(scope, args, level)
+        ),
+        Format::TypeParameter(name) => format!(
+            "{}.serialize({serializer}, {value})",
+            closure_name("serialize", name)
+        ),
+        Format::Variable(_variable) => unreachable!("placeholders should not get this far"),
+        _ => unreachable!("primitives are serialized by the runtime"),
+    }
+}
+
+/// The deserializers of each of `formats`, comma-separated.
+fn deserializers(scope: &Scope, formats: &[Format], level: usize) -> String {
+    formats
+        .iter()
+        .map(|format| deserializer(scope, format, level))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A `com.novi.serde.Deserialize<T>` that deserializes a value of `format`,
+/// as a method reference or a lambda at the nesting `level`.
+fn deserializer(scope: &Scope, format: &Format, level: usize) -> String {
+    match format {
+        Format::Tuple(formats) if formats.len() == 1 => deserializer(scope, &formats[0], level),
+        Format::TypeParameter(name) => closure_name("deserialize", name),
+        Format::TypeName(name) => format!("{}::deserialize", scope.type_name(name)),
+        format => match primitive(format) {
+            Some(method) => format!("com.novi.serde.Deserializer::deserialize_{method}"),
+            None => format!(
+                "{} -> {}",
+                local("deserializer", level),
+                deserialize(scope, format, level)
+            ),
+        },
+    }
+}
+
+/// The expression that deserializes a value of `format` with the
+/// deserializer of the nesting `level`.
+fn deserialize(scope: &Scope, format: &Format, level: usize) -> String {
+    let deserializer = local("deserializer", level);
+    if let Some(method) = primitive(format) {
+        return format!("{deserializer}.deserialize_{method}()");
+    }
+    let level = level + 1;
+    match format {
+        Format::Option(format) => format!(
+            "{deserializer}.deserialize_option({})",
+            self::deserializer(scope, format, level)
+        ),
+        Format::Seq(format) => format!(
+            "{deserializer}.deserialize_seq({})",
+            self::deserializer(scope, format, level)
+        ),
+        Format::Set(format) => format!(
+            "{deserializer}.deserialize_set({})",
+            self::deserializer(scope, format, level)
+        ),
+        Format::Map { key, value } => format!(
+            "{deserializer}.deserialize_map({}, {})",
+            self::deserializer(scope, key, level),
+            self::deserializer(scope, value, level)
+        ),
+        Format::TupleArray { content, size } => format!(
+            "{deserializer}.deserialize_tuple_array({size}, {})",
+            self::deserializer(scope, content, level)
+        ),
+        Format::Tuple(formats) => match formats.as_slice() {
+            [format] => deserialize(scope, format, level - 1),
+            formats => format!(
+                "com.novi.serde.Tuple{}.deserialize({deserializer}, {})",
+                formats.len(),
+                deserializers(scope, formats, level)
+            ),
+        },
+        Format::TypeName(name) => format!("{}.deserialize({deserializer})", scope.type_name(name)),
+        Format::Generic { name, args } => format!(
+            "{}.deserialize({deserializer}, {})",
+            scope.type_name(name),
+            deserializers(scope, args, level)
+        ),
+        Format::TypeParameter(name) => format!(
+            "{}.deserialize({deserializer})",
+            closure_name("deserialize", name)
+        ),
+        Format::Variable(_variable) => unreachable!("placeholders should not get this far"),
+        _ => unreachable!("primitives are deserialized by the runtime"),
+    }
+}
+
+/// The statement that serializes a field of `this`, with its `fg::override`
+/// code for Java if it has any.
+fn field_serialize(scope: &Scope, field: &Named<Format>) -> String {
+    let value = format!("this.{}", field_name(&field.name));
+    let statement = field
+        .metadata
+        .type_override(Language::Java)
+        .and_then(|type_override| type_override.serialize(&value))
+        .unwrap_or_else(|| serialize(scope, &value, &field.value, 0));
+    if statement.trim_end().ends_with(';') || statement.trim_end().ends_with('}') {
+        statement
+    } else {
+        format!("{statement};")
+    }
+}
+
+/// The expression that deserializes a field, with its `fg::override` code for
+/// Java if it has any.
+fn field_deserialize(scope: &Scope, field: &Named<Format>) -> String {
+    field
+        .metadata
+        .type_override(Language::Java)
+        .and_then(|type_override| type_override.deserialize.clone())
+        .unwrap_or_else(|| deserialize(scope, &field.value, 0))
+}
+
+/// Writes `lines` (which may contain line breaks) at the writer's
+/// indentation.
+fn write_lines<W: IndentWrite>(w: &mut W, text: &str) -> Result<()> {
+    for line in text.lines() {
+        writeln!(w, "{line}")?;
+    }
+    Ok(())
+}
+
+/// Writes the `serialize` method of a record, which writes the variant index
+/// if it has one, then its fields, within a container.
+fn write_serialize<W: IndentWrite>(
+    w: &mut W,
+    scope: &Scope,
+    type_params: &[String],
+    index: Option<usize>,
+    fields: &[Named<Format>],
+) -> Result<()> {
+    if index.is_some() {
+        writeln!(w, "@{}", scope.lang("Override"))?;
+    }
+    writeln!(
+        w,
+        "public void serialize(com.novi.serde.Serializer serializer{}) throws com.novi.serde.SerializationError {{",
+        serializer_params(type_params)
+    )?;
+    w.indent();
+    if index.is_some() || !fields.is_empty() {
+        writeln!(w, "serializer.increase_container_depth();")?;
+        if let Some(index) = index {
+            writeln!(w, "serializer.serialize_variant_index({index});")?;
+        }
+        for field in fields {
+            write_lines(w, &field_serialize(scope, field))?;
+        }
+        writeln!(w, "serializer.decrease_container_depth();")?;
+    }
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// Writes `bincodeSerialize`, which serializes the value into a new byte
+/// array, as a `default` method of an interface if `modifier` says so.
+fn write_bincode_serialize<W: IndentWrite>(
+    w: &mut W,
+    modifier: &str,
+    type_params: &[String],
+) -> Result<()> {
+    writeln!(
+        w,
+        "{modifier} byte[] bincodeSerialize({}) throws com.novi.serde.SerializationError {{",
+        serializer_params(type_params).trim_start_matches(", ")
+    )?;
+    w.indent();
+    writeln!(
+        w,
+        "com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();"
+    )?;
+    writeln!(
+        w,
+        "serialize(serializer{});",
+        closure_args("serialize", type_params)
+    )?;
+    writeln!(w, "return serializer.get_bytes();")?;
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// Writes the signature of a static `deserialize` method, up to its opening
+/// brace.
+fn write_deserialize_signature<W: IndentWrite>(
+    w: &mut W,
+    modifier: &str,
+    name: &str,
+    type_params: &[String],
+) -> Result<()> {
+    let args = type_params_decl(type_params);
+    let generic = if args.is_empty() {
+        String::new()
+    } else {
+        format!("{args} ")
+    };
+    writeln!(
+        w,
+        "{modifier} {generic}{name}{args} deserialize(com.novi.serde.Deserializer deserializer{}) throws com.novi.serde.DeserializationError {{",
+        deserializer_params(type_params)
+    )
+}
+
+/// Writes `bincodeDeserialize`, which deserializes a value from the whole of
+/// `input`.
+fn write_bincode_deserialize<W: IndentWrite>(
+    w: &mut W,
+    modifier: &str,
+    name: &str,
+    type_params: &[String],
+) -> Result<()> {
+    let args = type_params_decl(type_params);
+    let generic = if args.is_empty() {
+        String::new()
+    } else {
+        format!("{args} ")
+    };
+    writeln!(
+        w,
+        "{modifier} {generic}{name}{args} bincodeDeserialize(byte[] input{}) throws com.novi.serde.DeserializationError {{",
+        deserializer_params(type_params)
+    )?;
+    w.indent();
+    writeln!(w, "if (input == null) {{")?;
+    w.indent();
+    writeln!(
+        w,
+        "throw new com.novi.serde.DeserializationError(\"Cannot deserialize null array\");"
+    )?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(
+        w,
+        "com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);"
+    )?;
+    writeln!(
+        w,
+        "{name}{args} value = deserialize(deserializer{});",
+        closure_args("deserialize", type_params)
+    )?;
+    writeln!(w, "if (deserializer.get_buffer_offset() < input.length) {{")?;
+    w.indent();
+    writeln!(
+        w,
+        "throw new com.novi.serde.DeserializationError(\"Some input bytes were not read\");"
+    )?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w, "return value;")?;
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// The arguments that construct a record of `fields`, which deserialize them
+/// in order.
+fn constructor_args(scope: &Scope, fields: &[Named<Format>]) -> Vec<String> {
+    fields
+        .iter()
+        .map(|field| field_deserialize(scope, field))
+        .collect()
+}
+
+/// The diamond of a generic type's construction, or nothing.
+const fn diamond(type_params: &[String]) -> &'static str {
+    if type_params.is_empty() { "" } else { "<>" }
+}
+
+/// Writes the members of a record: a struct, or a newtype, tuple or unit
+/// struct.
+fn write_record<W: IndentWrite>(
+    w: &mut W,
+    scope: &Scope,
+    name: &str,
+    type_params: &[String],
+    fields: &[Named<Format>],
+) -> Result<()> {
+    write_serialize(w, scope, type_params, None, fields)?;
+    writeln!(w)?;
+    write_bincode_serialize(w, "public", type_params)?;
+    writeln!(w)?;
+
+    write_deserialize_signature(w, "public static", name, type_params)?;
+    w.indent();
+    let new = format!("new {name}{}", diamond(type_params));
+    if fields.is_empty() {
+        writeln!(w, "return {new}();")?;
+    } else {
+        writeln!(w, "deserializer.increase_container_depth();")?;
+        write_call(
+            w,
+            &format!("{name}{} value = {new}", type_params_decl(type_params)),
+            &constructor_args(scope, fields),
+            ";",
+        )?;
+        writeln!(w, "deserializer.decrease_container_depth();")?;
+        writeln!(w, "return value;")?;
+    }
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write_bincode_deserialize(w, "public static", name, type_params)
+}
+
+/// Writes the members of an enum whose variants are all unit variants, which
+/// are told apart by their variant index.
+fn write_enum<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+) -> Result<()> {
+    writeln!(
+        w,
+        "public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {{"
+    )?;
+    w.indent();
+    if variants.is_empty() {
+        // An enum without constants has no values to serialize.
+        writeln!(
+            w,
+            "throw new com.novi.serde.SerializationError({});",
+            string_literal(&format!("{name} has no values"))
+        )?;
+    } else {
+        writeln!(w, "serializer.increase_container_depth();")?;
+        writeln!(w, "int index = switch (this) {{")?;
+        w.indent();
+        for (index, variant) in variants {
+            writeln!(w, "case {} -> {index};", enum_constant(&variant.name))?;
+        }
+        w.unindent();
+        writeln!(w, "}};")?;
+        writeln!(w, "serializer.serialize_variant_index(index);")?;
+        writeln!(w, "serializer.decrease_container_depth();")?;
+    }
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write_bincode_serialize(w, "public", &[])?;
+    writeln!(w)?;
+
+    write_deserialize_signature(w, "public static", name, &[])?;
+    w.indent();
+    writeln!(w, "deserializer.increase_container_depth();")?;
+    writeln!(w, "int index = deserializer.deserialize_variant_index();")?;
+    let unknown = format!(
+        "throw new com.novi.serde.DeserializationError({} + index);",
+        string_literal(&format!("Unknown variant index for {name}: "))
+    );
+    if variants.is_empty() {
+        writeln!(w, "{unknown}")?;
+    } else {
+        writeln!(w, "{name} value = switch (index) {{")?;
+        w.indent();
+        for (index, variant) in variants {
+            writeln!(w, "case {index} -> {};", enum_constant(&variant.name))?;
+        }
+        // Unknown variants, e.g. added by a later version, are read as the catch-all.
+        match variants.values().find(|variant| variant.metadata.other) {
+            Some(other) => writeln!(w, "default -> {};", enum_constant(&other.name))?,
+            None => writeln!(w, "default -> {unknown}")?,
+        }
+        w.unindent();
+        writeln!(w, "}};")?;
+        writeln!(w, "deserializer.decrease_container_depth();")?;
+        writeln!(w, "return value;")?;
+    }
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write_bincode_deserialize(w, "public static", name, &[])
+}
+
+/// Writes the static methods of the interface of an enum whose variants carry
+/// data, which switch on its variant index.
+fn write_union<W: IndentWrite>(
+    w: &mut W,
+    scope: &Scope,
+    name: &str,
+    type_params: &[String],
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+) -> Result<()> {
+    let args = type_params_decl(type_params);
+    write_deserialize_signature(w, "static", name, type_params)?;
+    w.indent();
+    writeln!(w, "deserializer.increase_container_depth();")?;
+    writeln!(w, "int index = deserializer.deserialize_variant_index();")?;
+    writeln!(w, "{name}{args} value = switch (index) {{")?;
+    w.indent();
+    for (index, variant) in variants {
+        let fields = variant_fields(&variant.value);
+        write_call(
+            w,
+            &format!(
+                "case {index} -> new {}{}",
+                variant_class(&variant.name),
+                diamond(type_params)
+            ),
+            &constructor_args(scope, &fields),
+            ";",
+        )?;
+    }
+    // Unknown variants, e.g. added by a later version, are read as the catch-all.
+    match variants.values().find(|variant| variant.metadata.other) {
+        Some(other) => writeln!(
+            w,
+            "default -> new {}{}();",
+            variant_class(&other.name),
+            diamond(type_params)
+        )?,
+        None => writeln!(
+            w,
+            "default -> throw new com.novi.serde.DeserializationError({} + index);",
+            string_literal(&format!("Unknown variant index for {name}: "))
+        )?,
+    }
+    w.unindent();
+    writeln!(w, "}};")?;
+    writeln!(w, "deserializer.decrease_container_depth();")?;
+    writeln!(w, "return value;")?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write_bincode_deserialize(w, "static", name, type_params)
+}
+
+// ---------------------------------------------------------------------------
+// EmitterPlugin implementation
+// ---------------------------------------------------------------------------
+
+impl EmitterPlugin<Java> for BincodePlugin {
+    /// Returns the `com.novi.serde` and `com.novi.bincode` runtime packages,
+    /// to be written into the generated project's sources.
+    fn runtime_files(&self) -> Vec<RuntimeFile> {
+        static SERDE: include_dir::Dir<'static> =
+            include_dir::include_dir!("$CARGO_MANIFEST_DIR/runtime/java/com/novi/serde");
+        static BINCODE: include_dir::Dir<'static> =
+            include_dir::include_dir!("$CARGO_MANIFEST_DIR/runtime/java/com/novi/bincode");
+
+        let mut files: Vec<RuntimeFile> = SERDE
+            .files()
+            .map(|f| RuntimeFile {
+                relative_path: format!("com/novi/serde/{}", f.path().display()),
+                contents: f.contents().to_vec(),
+            })
+            .collect();
+        files.extend(BINCODE.files().map(|f| RuntimeFile {
+            relative_path: format!("com/novi/bincode/{}", f.path().display()),
+            contents: f.contents().to_vec(),
+        }));
+        files
+    }
+
+    /// Every type gets methods.
+    fn has_type_body(&self, _ctx: &EmitContext) -> bool {
+        true
+    }
+
+    /// The interface of an enum whose variants carry data declares the
+    /// `serialize` method that its variants implement.
+    fn type_body_preamble(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
+        if ctx.is_variant() || !is_union_format(ctx.container.format) {
+            return Ok(());
+        }
+        let config = w.config();
+        let mut w = IndentedWriter::new(&mut *w, config);
+        let type_params = ctx.type_params();
+        writeln!(
+            w,
+            "void serialize(com.novi.serde.Serializer serializer{}) throws com.novi.serde.SerializationError;",
+            serializer_params(type_params)
+        )?;
+        writeln!(w)?;
+        write_bincode_serialize(&mut w, "default", type_params)
+    }
+
+    /// The methods of every type: variant records serialize themselves, and
+    /// the enum's interface reads any of them.
+    fn type_body(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
+        let config = w.config();
+        let mut w = IndentedWriter::new(&mut *w, config);
+        let scope = Scope::new(ctx.container, ctx.config);
+        if let Some(variant) = &ctx.variant {
+            return write_serialize(
+                &mut w,
+                &scope,
+                variant.parent_type_params,
+                Some(variant.index),
+                variant.fields,
+            );
+        }
+        let name = ctx.name();
+        match ctx.container.format {
+            ContainerFormat::Enum(variants, ..) if is_union_format(ctx.container.format) => {
+                write_union(&mut w, &scope, name, ctx.type_params(), variants)
+            }
+            ContainerFormat::Enum(variants, ..) => write_enum(&mut w, name, variants),
+            _ => write_record(&mut w, &scope, name, ctx.type_params(), &ctx.fields()),
+        }
+    }
+
+    /// Rejects untagged enums, which bincode can't encode.
+    fn check_registry(&self, registry: &Registry) -> std::result::Result<(), Error> {
+        super::check_registry(registry)
+    }
+}
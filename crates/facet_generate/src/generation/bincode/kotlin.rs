@@ -14,13 +14,14 @@ use heck::ToLowerCamelCase;
 use indoc::writedoc;
 
 use super::BincodePlugin;
+use crate::Registry;
 use crate::generation::{
-    BINCODE_NAMESPACE, CodeGeneratorConfig, Feature, PackageLocation, SERDE_NAMESPACE,
+    BINCODE_NAMESPACE, CodeGeneratorConfig, Error, Feature, PackageLocation, SERDE_NAMESPACE,
     indent::{IndentWrite, IndentedWriter, Newlines},
-    kotlin::Kotlin,
+    kotlin::{Kotlin, emitter::enum_entry_name},
     plugin::{EmitContext, EmitterPlugin, RuntimeFile},
 };
-use crate::reflection::format::{ContainerFormat, Format, Named, VariantFormat};
+use crate::reflection::format::{ContainerFormat, Format, Language, Named, VariantFormat};
 
 /// Look up the package path for `namespace` in the config's external packages.
 /// Falls back to `default` when no override is configured.
@@ -151,30 +152,82 @@ fun Deserializer.deserializeUuid(): UUID {
 }
 "#;
 
-fn write_bincode_serialize<W: Write>(w: &mut W) -> Result<()> {
+const FEATURE_TIMESTAMP: &str = r"fun Instant.serialize(serializer: Serializer) {
+    serializer.serialize_str(toString())
+}
+
+fun Deserializer.deserializeInstant(): Instant =
+    java.time.OffsetDateTime.parse(deserialize_str()).toInstant()
+";
+
+const FEATURE_DATE: &str = r"fun LocalDate.serialize(serializer: Serializer) {
+    serializer.serialize_str(toString())
+}
+
+fun Deserializer.deserializeLocalDate(): LocalDate = LocalDate.parse(deserialize_str())
+";
+
+const FEATURE_TIME: &str = r"fun LocalTime.serialize(serializer: Serializer) {
+    serializer.serialize_str(java.time.format.DateTimeFormatter.ISO_LOCAL_TIME.format(this))
+}
+
+fun Deserializer.deserializeLocalTime(): LocalTime = LocalTime.parse(deserialize_str())
+";
+
+const FEATURE_DURATION: &str = r#"fun Duration.serialize(serializer: Serializer) {
+    if (isNegative) {
+        throw SerializationError("Duration must not be negative: $this")
+    }
+    serializer.increase_container_depth()
+    serializer.serialize_u64(seconds.toULong())
+    serializer.serialize_u32(nano.toUInt())
+    serializer.decrease_container_depth()
+}
+
+fun Deserializer.deserializeDuration(): Duration {
+    increase_container_depth()
+    val secs = deserialize_u64()
+    val nanos = deserialize_u32()
+    decrease_container_depth()
+    return Duration.ofSeconds(secs.toLong(), nanos.toLong())
+}
+"#;
+
+fn write_bincode_serialize<W: Write>(w: &mut W, type_params: &[String]) -> Result<()> {
+    let params = serializer_params(type_params);
+    let args = closure_args("serialize", type_params);
     writedoc!(
         w,
         r"
-        fun bincodeSerialize(): ByteArray {{
+        fun bincodeSerialize({params}): ByteArray {{
             val serializer = BincodeSerializer()
-            serialize(serializer)
+            serialize(serializer{args})
             return serializer.get_bytes()
         }}
-        "
+        ",
+        params = params.trim_start_matches(", "),
     )
 }
 
-fn write_bincode_deserialize<W: Write>(w: &mut W, name: &str) -> Result<()> {
+fn write_bincode_deserialize<W: Write>(
+    w: &mut W,
+    name: &str,
+    type_params: &[String],
+) -> Result<()> {
+    let generics = fun_type_params(type_params);
+    let params = deserializer_params(type_params);
+    let args = closure_args("deserialize", type_params);
+    let ty = generic_type(name, type_params);
     writedoc!(
         w,
         r#"
         @Throws(DeserializationError::class)
-        fun bincodeDeserialize(input: ByteArray?): {name} {{
+        fun {generics}bincodeDeserialize(input: ByteArray?{params}): {ty} {{
             if (input == null) {{
                 throw DeserializationError("Cannot deserialize null array")
             }}
             val deserializer = BincodeDeserializer(input)
-            val value = deserialize(deserializer)
+            val value = deserialize(deserializer{args})
             if (deserializer.get_buffer_offset() < input.size) {{
                 throw DeserializationError("Some input bytes were not read")
             }}
@@ -184,6 +237,68 @@ fn write_bincode_deserialize<W: Write>(w: &mut W, name: &str) -> Result<()> {
     )
 }
 
+/// The type parameter list of a generic function (e.g. `<T, E> `), empty
+/// for a non-generic type.
+fn fun_type_params(type_params: &[String]) -> String {
+    if type_params.is_empty() {
+        String::new()
+    } else {
+        format!("<{}> ", type_params.join(", "))
+    }
+}
+
+/// A generic type applied to its own type parameters (e.g. `Page<T>`).
+fn generic_type(name: &str, type_params: &[String]) -> String {
+    if type_params.is_empty() {
+        name.to_string()
+    } else {
+        format!("{name}<{}>", type_params.join(", "))
+    }
+}
+
+/// The per-type-parameter serializer closure parameters of `serialize`
+/// (e.g. `, serializeT: Serializer.(T) -> Unit`).
+fn serializer_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", serialize{param}: Serializer.({param}) -> Unit"))
+        .collect()
+}
+
+/// The serializer closure parameters of a variant's `serialize` override.
+/// They mirror the sealed interface's, with `Nothing` for the type
+/// parameters that the variant doesn't use.
+fn override_serializer_params(parent_type_params: &[String], type_params: &[String]) -> String {
+    parent_type_params
+        .iter()
+        .map(|param| {
+            let ty = if type_params.contains(param) {
+                param.as_str()
+            } else {
+                "Nothing"
+            };
+            format!(", serialize{param}: Serializer.({ty}) -> Unit")
+        })
+        .collect()
+}
+
+/// The per-type-parameter deserializer closure parameters of `deserialize`
+/// (e.g. `, deserializeT: (Deserializer) -> T`).
+fn deserializer_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", deserialize{param}: (Deserializer) -> {param}"))
+        .collect()
+}
+
+/// Forwards the closures for `type_params` (e.g. `, serializeT`).
+fn closure_args(prefix: &str, type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", {prefix}{param}"))
+        .collect()
+}
+
 fn write_serialize<W: IndentWrite>(
     w: &mut W,
     field_name: &str,
@@ -208,7 +323,9 @@ fn write_serialize<W: IndentWrite>(
         Format::Char => writeln!(w, "serializer.serialize_char({field_name})"),
         Format::Str => writeln!(w, "serializer.serialize_str({field_name})"),
         Format::Bytes => writeln!(w, "serializer.serialize_bytes({field_name})"),
-        Format::Uuid => writeln!(w, "{field_name}.serialize(serializer)"),
+        Format::Uuid | Format::Timestamp | Format::Date | Format::Time | Format::Duration => {
+            writeln!(w, "{field_name}.serialize(serializer)")
+        }
 
         Format::Option(inner_format) => {
             write!(w, "{field_name}.serializeOptionOf(serializer) ")?;
@@ -232,6 +349,20 @@ fn write_serialize<W: IndentWrite>(
             writeln!(w, "{field_name}.serialize(serializer)")
         }
 
+        Format::TypeParameter(name) => writeln!(w, "serializer.serialize{name}({field_name})"),
+
+        Format::Generic { args, .. } => {
+            write!(w, "{field_name}.serialize(serializer")?;
+            for arg in args {
+                write!(w, ", ")?;
+                let param_name = format!("level{}", level + 1);
+                let mut w = w.block(Newlines::NONE)?;
+                writeln!(w, " {param_name} ->")?;
+                write_serialize(&mut w, &param_name, arg, level + 1)?;
+            }
+            writeln!(w, ")")
+        }
+
         Format::Tuple(formats) => {
             let len = formats.len();
             match len {
@@ -263,6 +394,27 @@ fn write_serialize<W: IndentWrite>(
     }
 }
 
+/// Serializes a struct or variant field, with its `fg::override` code for Kotlin if it has any.
+fn write_field_serialize<W: IndentWrite>(w: &mut W, field: &Named<Format>) -> Result<()> {
+    let name = field.name.to_lower_camel_case();
+    let type_override = field.metadata.type_override(Language::Kotlin);
+    match type_override.and_then(|type_override| type_override.serialize(&name)) {
+        Some(serialize) => writeln!(w, "{serialize}"),
+        None => write_serialize(w, &name, &field.value, 0),
+    }
+}
+
+/// Deserializes a struct or variant field into a local of the same name, with its
+/// `fg::override` code for Kotlin if it has any.
+fn write_field_deserialize<W: IndentWrite>(w: &mut W, field: &Named<Format>) -> Result<()> {
+    let name = field.name.to_lower_camel_case();
+    let type_override = field.metadata.type_override(Language::Kotlin);
+    match type_override.and_then(|type_override| type_override.deserialize.as_ref()) {
+        Some(deserialize) => writeln!(w, "val {name} = {deserialize}"),
+        None => write_deserialize(w, Some(&name), &field.value, true),
+    }
+}
+
 fn write_serialize_lambda<W: IndentWrite>(w: &mut W, format: &Format, level: usize) -> Result<()> {
     if format.is_leaf() {
         let mut w = w.block(Newlines::BOTH)?;
@@ -314,6 +466,17 @@ fn write_deserialize<W: IndentWrite>(
             let fully_qualified_name = qualified_name.format(ToString::to_string, ".");
             write!(w, "{fully_qualified_name}.deserialize(deserializer)")
         }
+        Format::TypeParameter(name) => write!(w, "deserialize{name}(deserializer)"),
+        Format::Generic { name, args } => {
+            let fully_qualified_name = name.format(ToString::to_string, ".");
+            write!(w, "{fully_qualified_name}.deserialize(deserializer")?;
+            for arg in args {
+                write!(w, ", ")?;
+                let mut w = w.block(Newlines::OPEN)?;
+                write_deserialize(&mut w, None, arg, true)?;
+            }
+            write!(w, ")")
+        }
         Format::Unit => write!(w, "deserializer.deserialize_unit()"),
         Format::Bool => write!(w, "deserializer.deserialize_bool()"),
         Format::I8 => write!(w, "deserializer.deserialize_i8()"),
@@ -332,6 +495,10 @@ fn write_deserialize<W: IndentWrite>(
         Format::Str => write!(w, "deserializer.deserialize_str()"),
         Format::Bytes => write!(w, "deserializer.deserialize_bytes()"),
         Format::Uuid => write!(w, "deserializer.deserializeUuid()"),
+        Format::Timestamp => write!(w, "deserializer.deserializeInstant()"),
+        Format::Date => write!(w, "deserializer.deserializeLocalDate()"),
+        Format::Time => write!(w, "deserializer.deserializeLocalTime()"),
+        Format::Duration => write!(w, "deserializer.deserializeDuration()"),
         Format::Seq(format) => {
             write!(w, "deserializer.deserializeListOf ")?;
             write_deserialize_lambda(w, format)
@@ -486,7 +653,7 @@ fn write_data_object_top_level<W: IndentWrite>(w: &mut W, name: &str) -> Result<
     let _ = w.block(Newlines::CLOSE)?;
     writeln!(w)?;
 
-    write_bincode_serialize(w)?;
+    write_bincode_serialize(w, &[])?;
     writeln!(w)?;
 
     write!(w, "fun deserialize(deserializer: Deserializer): {name} ")?;
@@ -495,7 +662,7 @@ fn write_data_object_top_level<W: IndentWrite>(w: &mut W, name: &str) -> Result<
         writeln!(w, "return {name}")?;
     }
     writeln!(w)?;
-    write_bincode_deserialize(w, name)?;
+    write_bincode_deserialize(w, name, &[])?;
     Ok(())
 }
 
@@ -505,8 +672,10 @@ fn write_data_object_variant<W: IndentWrite>(
     w: &mut W,
     name: &str,
     variant_index: usize,
+    parent_type_params: &[String],
 ) -> Result<()> {
-    write!(w, "override fun serialize(serializer: Serializer) ")?;
+    let params = override_serializer_params(parent_type_params, &[]);
+    write!(w, "override fun serialize(serializer: Serializer{params}) ")?;
     {
         let mut w = w.block(Newlines::BOTH)?;
         push_serializer(&mut w)?;
@@ -528,30 +697,38 @@ fn write_data_object_variant<W: IndentWrite>(
 fn write_data_class_top_level<W: IndentWrite>(
     w: &mut W,
     name: &str,
+    type_params: &[String],
     fields: &[Named<Format>],
 ) -> Result<()> {
     // serialize
-    write!(w, "fun serialize(serializer: Serializer) ")?;
+    let params = serializer_params(type_params);
+    write!(w, "fun serialize(serializer: Serializer{params}) ")?;
     if fields.is_empty() {
         let _ = w.block(Newlines::CLOSE)?;
     } else {
         let mut w = w.block(Newlines::BOTH)?;
         push_serializer(&mut w)?;
         for field in fields {
-            write_serialize(&mut w, &field.name.to_lower_camel_case(), &field.value, 0)?;
+            write_field_serialize(&mut w, field)?;
         }
         pop_serializer(&mut w)?;
     }
     writeln!(w)?;
 
-    write_bincode_serialize(w)?;
+    write_bincode_serialize(w, type_params)?;
     writeln!(w)?;
 
     // companion object
+    let generics = fun_type_params(type_params);
+    let params = deserializer_params(type_params);
+    let ty = generic_type(name, type_params);
     write!(w, "companion object ")?;
     {
         let mut w = w.block(Newlines::BOTH)?;
-        write!(w, "fun deserialize(deserializer: Deserializer): {name} ")?;
+        write!(
+            w,
+            "fun {generics}deserialize(deserializer: Deserializer{params}): {ty} "
+        )?;
         {
             let mut w = w.block(Newlines::BOTH)?;
             if fields.is_empty() {
@@ -559,12 +736,7 @@ fn write_data_class_top_level<W: IndentWrite>(
             } else {
                 push_deserializer(&mut w)?;
                 for field in fields {
-                    write_deserialize(
-                        &mut w,
-                        Some(&field.name.to_lower_camel_case()),
-                        &field.value,
-                        true,
-                    )?;
+                    write_field_deserialize(&mut w, field)?;
                 }
                 pop_deserializer(&mut w)?;
                 write!(w, "return {name}(")?;
@@ -578,7 +750,7 @@ fn write_data_class_top_level<W: IndentWrite>(
             }
         }
         writeln!(w)?;
-        write_bincode_deserialize(&mut w, name)?;
+        write_bincode_deserialize(&mut w, name, type_params)?;
     }
     Ok(())
 }
@@ -588,11 +760,14 @@ fn write_data_class_top_level<W: IndentWrite>(
 fn write_data_class_variant<W: IndentWrite>(
     w: &mut W,
     name: &str,
+    type_params: &[String],
     fields: &[Named<Format>],
     variant_index: usize,
+    parent_type_params: &[String],
 ) -> Result<()> {
     // serialize (override)
-    write!(w, "override fun serialize(serializer: Serializer) ")?;
+    let params = override_serializer_params(parent_type_params, type_params);
+    write!(w, "override fun serialize(serializer: Serializer{params}) ")?;
     if fields.is_empty() {
         let _ = w.block(Newlines::CLOSE)?;
     } else {
@@ -600,17 +775,23 @@ fn write_data_class_variant<W: IndentWrite>(
         push_serializer(&mut w)?;
         writeln!(w, "serializer.serialize_variant_index({variant_index})")?;
         for field in fields {
-            write_serialize(&mut w, &field.name.to_lower_camel_case(), &field.value, 0)?;
+            write_field_serialize(&mut w, field)?;
         }
         pop_serializer(&mut w)?;
     }
     writeln!(w)?;
 
     // companion object (deserialize only, no bincodeDeserialize)
+    let generics = fun_type_params(type_params);
+    let params = deserializer_params(type_params);
+    let ty = generic_type(name, type_params);
     write!(w, "companion object ")?;
     {
         let mut w = w.block(Newlines::BOTH)?;
-        write!(w, "fun deserialize(deserializer: Deserializer): {name} ")?;
+        write!(
+            w,
+            "fun {generics}deserialize(deserializer: Deserializer{params}): {ty} "
+        )?;
         {
             let mut w = w.block(Newlines::BOTH)?;
             if fields.is_empty() {
@@ -618,12 +799,7 @@ fn write_data_class_variant<W: IndentWrite>(
             } else {
                 push_deserializer(&mut w)?;
                 for field in fields {
-                    write_deserialize(
-                        &mut w,
-                        Some(&field.name.to_lower_camel_case()),
-                        &field.value,
-                        true,
-                    )?;
+                    write_field_deserialize(&mut w, field)?;
                 }
                 pop_deserializer(&mut w)?;
                 write!(w, "return {name}(")?;
@@ -651,12 +827,25 @@ fn write_enum_class_body<W: IndentWrite>(
     {
         let mut w = w.block(Newlines::BOTH)?;
         push_serializer(&mut w)?;
-        writeln!(w, "serializer.serialize_variant_index(ordinal)")?;
+        if variants.keys().zip(0..).all(|(index, i)| *index == i) {
+            writeln!(w, "serializer.serialize_variant_index(ordinal)")?;
+        } else {
+            // Some variants are left out of this language or have pinned indices, so ordinals don't
+            // match the indices.
+            write!(w, "val index = when (this) ")?;
+            {
+                let mut w = w.block(Newlines::BOTH)?;
+                for (i, variant) in variants {
+                    writeln!(w, "{} -> {i}", enum_entry_name(&variant.name))?;
+                }
+            }
+            writeln!(w, "serializer.serialize_variant_index(index)")?;
+        }
         pop_serializer(&mut w)?;
     }
     writeln!(w)?;
 
-    write_bincode_serialize(w)?;
+    write_bincode_serialize(w, &[])?;
     writeln!(w)?;
 
     write!(w, "companion object ")?;
@@ -674,17 +863,20 @@ fn write_enum_class_body<W: IndentWrite>(
             {
                 let mut w = w.block(Newlines::BOTH)?;
                 for (i, variant) in variants {
-                    let upper = variant.name.to_uppercase();
+                    let upper = enum_entry_name(&variant.name);
                     writeln!(w, "{i} -> {upper}")?;
                 }
-                writeln!(
-                    w,
-                    r#"else -> throw DeserializationError("Unknown variant index for {name}: $index")"#
-                )?;
+                match variants.values().find(|variant| variant.metadata.other) {
+                    Some(other) => writeln!(w, "else -> {}", enum_entry_name(&other.name))?,
+                    None => writeln!(
+                        w,
+                        r#"else -> throw DeserializationError("Unknown variant index for {name}: $index")"#
+                    )?,
+                }
             }
         }
         writeln!(w)?;
-        write_bincode_deserialize(&mut w, name)?;
+        write_bincode_deserialize(&mut w, name, &[])?;
     }
     Ok(())
 }
@@ -694,14 +886,21 @@ fn write_enum_class_body<W: IndentWrite>(
 fn write_sealed_interface_body<W: IndentWrite>(
     w: &mut W,
     name: &str,
+    type_params: &[String],
     variants: &std::collections::BTreeMap<u32, Named<VariantFormat>>,
 ) -> Result<()> {
+    let generics = fun_type_params(type_params);
+    let params = deserializer_params(type_params);
+    let ty = generic_type(name, type_params);
     writeln!(w)?;
     write!(w, "companion object ")?;
     {
         let mut w = w.block(Newlines::BOTH)?;
         writeln!(w, "@Throws(DeserializationError::class)")?;
-        write!(w, "fun deserialize(deserializer: Deserializer): {name} ")?;
+        write!(
+            w,
+            "fun {generics}deserialize(deserializer: Deserializer{params}): {ty} "
+        )?;
         {
             let mut w = w.block(Newlines::BOTH)?;
             writeln!(w, "val index = deserializer.deserialize_variant_index()")?;
@@ -710,17 +909,27 @@ fn write_sealed_interface_body<W: IndentWrite>(
                 let mut w = w.block(Newlines::BOTH)?;
                 for (i, variant) in variants {
                     let vname = &variant.name;
-                    writeln!(w, "{i} -> {vname}.deserialize(deserializer)")?;
+                    let used = type_params
+                        .iter()
+                        .filter(|param| variant.value.uses_type_parameter(param))
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    let args = closure_args("deserialize", &used);
+                    writeln!(w, "{i} -> {vname}.deserialize(deserializer{args})")?;
+                }
+                // Unknown variants, e.g. added by a later version, are read as the catch-all.
+                match variants.values().find(|variant| variant.metadata.other) {
+                    Some(other) => writeln!(w, "else -> {}", other.name)?,
+                    None => writeln!(
+                        w,
+                        r#"else -> throw DeserializationError("Unknown variant index for {name}: $index")"#
+                    )?,
                 }
-                writeln!(
-                    w,
-                    r#"else -> throw DeserializationError("Unknown variant index for {name}: $index")"#
-                )?;
             }
         }
 
         writeln!(w)?;
-        write_bincode_deserialize(&mut w, name)?;
+        write_bincode_deserialize(&mut w, name, type_params)?;
     }
     Ok(())
 }
@@ -778,6 +987,19 @@ impl EmitterPlugin<Kotlin> for BincodePlugin {
                     imports.push(format!("import {sp}.Bytes"));
                     imports.push("import java.util.UUID".to_string());
                 }
+                Feature::Timestamp => {
+                    imports.push("import java.time.Instant".to_string());
+                }
+                Feature::Date => {
+                    imports.push("import java.time.LocalDate".to_string());
+                }
+                Feature::Time => {
+                    imports.push("import java.time.LocalTime".to_string());
+                }
+                Feature::Duration => {
+                    imports.push(format!("import {sp}.SerializationError"));
+                    imports.push("import java.time.Duration".to_string());
+                }
                 Feature::BigInt => {
                     // BigInteger is JVM-only; kept for backward compat.
                     imports.push("import java.math.BigInteger".to_string());
@@ -826,6 +1048,22 @@ impl EmitterPlugin<Kotlin> for BincodePlugin {
                     write!(w, "{FEATURE_UUID}")?;
                     writeln!(w)?;
                 }
+                Feature::Timestamp => {
+                    write!(w, "{FEATURE_TIMESTAMP}")?;
+                    writeln!(w)?;
+                }
+                Feature::Date => {
+                    write!(w, "{FEATURE_DATE}")?;
+                    writeln!(w)?;
+                }
+                Feature::Time => {
+                    write!(w, "{FEATURE_TIME}")?;
+                    writeln!(w)?;
+                }
+                Feature::Duration => {
+                    write!(w, "{FEATURE_DURATION}")?;
+                    writeln!(w)?;
+                }
                 // BigInt and Bytes add imports (handled above); TupleArray is
                 // encoding-independent and stays in the emitter.
                 _ => {}
@@ -850,7 +1088,7 @@ impl EmitterPlugin<Kotlin> for BincodePlugin {
             return Ok(());
         }
 
-        if let ContainerFormat::Enum(variants, _, _) = ctx.container.format {
+        if let ContainerFormat::Enum(variants, _, _, _) = ctx.container.format {
             let all_unit = variants
                 .values()
                 .all(|v| matches!(v.value, VariantFormat::Unit));
@@ -860,9 +1098,11 @@ impl EmitterPlugin<Kotlin> for BincodePlugin {
                 {
                     let config = w.config();
                     let mut iw = IndentedWriter::new(&mut *w, config);
-                    writeln!(iw, "fun serialize(serializer: Serializer)")?;
+                    let type_params = ctx.type_params();
+                    let params = serializer_params(type_params);
+                    writeln!(iw, "fun serialize(serializer: Serializer{params})")?;
                     writeln!(iw)?;
-                    write_bincode_serialize(&mut iw)?;
+                    write_bincode_serialize(&mut iw, type_params)?;
                     writeln!(iw)?;
                 }
             }
@@ -901,17 +1141,25 @@ impl EmitterPlugin<Kotlin> for BincodePlugin {
             {
                 let config = w.config();
                 let mut iw = IndentedWriter::new(&mut *w, config);
+                let parent_type_params = variant_info.parent_type_params;
                 if fields.is_empty() {
-                    write_data_object_variant(&mut iw, name, variant_index)?;
+                    write_data_object_variant(&mut iw, name, variant_index, parent_type_params)?;
                 } else {
-                    write_data_class_variant(&mut iw, name, &fields, variant_index)?;
+                    write_data_class_variant(
+                        &mut iw,
+                        name,
+                        ctx.type_params(),
+                        &fields,
+                        variant_index,
+                        parent_type_params,
+                    )?;
                 }
             }
             return Ok(());
         }
 
         // ---- Top-level enum (enum class / sealed interface) ----
-        if let ContainerFormat::Enum(variants, _, _) = ctx.container.format {
+        if let ContainerFormat::Enum(variants, _, _, _) = ctx.container.format {
             let all_unit = variants
                 .values()
                 .all(|v| matches!(v.value, VariantFormat::Unit));
@@ -922,7 +1170,7 @@ impl EmitterPlugin<Kotlin> for BincodePlugin {
                 if all_unit {
                     write_enum_class_body(&mut iw, name, variants)?;
                 } else {
-                    write_sealed_interface_body(&mut iw, name, variants)?;
+                    write_sealed_interface_body(&mut iw, name, ctx.type_params(), variants)?;
                 }
             }
             return Ok(());
@@ -935,12 +1183,17 @@ impl EmitterPlugin<Kotlin> for BincodePlugin {
             if fields.is_empty() {
                 write_data_object_top_level(&mut iw, name)?;
             } else {
-                write_data_class_top_level(&mut iw, name, &fields)?;
+                write_data_class_top_level(&mut iw, name, ctx.type_params(), &fields)?;
             }
         }
 
         Ok(())
     }
+
+    /// Rejects untagged enums, which bincode can't encode.
+    fn check_registry(&self, registry: &Registry) -> std::result::Result<(), Error> {
+        super::check_registry(registry)
+    }
 }
 
 #[cfg(test)]
@@ -1008,13 +1261,15 @@ mod tests {
     #[test]
     fn has_type_body_always_true() {
         use crate::generation::Container;
-        use crate::reflection::format::{ContainerFormat, Doc, QualifiedTypeName};
+        use crate::reflection::format::{
+            ContainerFormat, ContainerMetadata, Doc, QualifiedTypeName,
+        };
 
         let cfg = make_config(&[]);
         let plugin = &BincodePlugin as &dyn EmitterPlugin<Kotlin>;
 
         let name = QualifiedTypeName::root("Foo".to_string());
-        let format = ContainerFormat::UnitStruct(Doc::default());
+        let format = ContainerFormat::UnitStruct(Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -1026,7 +1281,9 @@ mod tests {
     #[test]
     fn type_body_preamble_sealed_interface() {
         use crate::generation::Container;
-        use crate::reflection::format::{ContainerFormat, Doc, Format, QualifiedTypeName};
+        use crate::reflection::format::{
+            ContainerFormat, ContainerMetadata, Doc, FieldMetadata, Format, QualifiedTypeName,
+        };
         use std::collections::BTreeMap;
 
         let cfg = make_config(&[]);
@@ -1040,10 +1297,16 @@ mod tests {
                 name: "A".to_string(),
                 doc: Doc::default(),
                 value: VariantFormat::NewType(Box::new(Format::Str)),
+                metadata: FieldMetadata::default(),
             },
         );
         let name = QualifiedTypeName::root("MyEnum".to_string());
-        let format = ContainerFormat::Enum(variants, EnumTagging::External, Doc::default());
+        let format = ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::default(),
+            ContainerMetadata::default(),
+        );
         let container = Container {
             name: &name,
             format: &format,
@@ -1065,7 +1328,9 @@ mod tests {
     #[test]
     fn type_body_preamble_noop_for_enum_class() {
         use crate::generation::Container;
-        use crate::reflection::format::{ContainerFormat, Doc, QualifiedTypeName};
+        use crate::reflection::format::{
+            ContainerFormat, ContainerMetadata, Doc, FieldMetadata, QualifiedTypeName,
+        };
         use std::collections::BTreeMap;
 
         let cfg = make_config(&[]);
@@ -1079,10 +1344,16 @@ mod tests {
                 name: "A".to_string(),
                 doc: Doc::default(),
                 value: VariantFormat::Unit,
+                metadata: FieldMetadata::default(),
             },
         );
         let name = QualifiedTypeName::root("MyEnum".to_string());
-        let format = ContainerFormat::Enum(variants, EnumTagging::External, Doc::default());
+        let format = ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::default(),
+            ContainerMetadata::default(),
+        );
         let container = Container {
             name: &name,
             format: &format,
@@ -1103,13 +1374,15 @@ mod tests {
     #[test]
     fn type_body_data_object_top_level() {
         use crate::generation::Container;
-        use crate::reflection::format::{ContainerFormat, Doc, QualifiedTypeName};
+        use crate::reflection::format::{
+            ContainerFormat, ContainerMetadata, Doc, QualifiedTypeName,
+        };
 
         let cfg = make_config(&[]);
         let plugin = &BincodePlugin as &dyn EmitterPlugin<Kotlin>;
 
         let name = QualifiedTypeName::root("UnitStruct".to_string());
-        let format = ContainerFormat::UnitStruct(Doc::default());
+        let format = ContainerFormat::UnitStruct(Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -1133,7 +1406,9 @@ mod tests {
     #[test]
     fn type_body_data_class_top_level() {
         use crate::generation::Container;
-        use crate::reflection::format::{ContainerFormat, Doc, QualifiedTypeName};
+        use crate::reflection::format::{
+            ContainerFormat, ContainerMetadata, Doc, QualifiedTypeName,
+        };
 
         let cfg = make_config(&[]);
         let plugin = &BincodePlugin as &dyn EmitterPlugin<Kotlin>;
@@ -1143,7 +1418,7 @@ mod tests {
             Named::new(&Format::Str, "name".to_string()),
             Named::new(&Format::I32, "age".to_string()),
         ];
-        let format = ContainerFormat::Struct(fields, Doc::default());
+        let format = ContainerFormat::Struct(fields, Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -1170,7 +1445,9 @@ mod tests {
     #[test]
     fn type_body_enum_top_level_skips_to_avoid_duplication() {
         use crate::generation::Container;
-        use crate::reflection::format::{ContainerFormat, Doc, QualifiedTypeName};
+        use crate::reflection::format::{
+            ContainerFormat, ContainerMetadata, Doc, FieldMetadata, QualifiedTypeName,
+        };
         use std::collections::BTreeMap;
 
         let cfg = make_config(&[]);
@@ -1183,10 +1460,16 @@ mod tests {
                 name: "A".to_string(),
                 doc: Doc::default(),
                 value: VariantFormat::Unit,
+                metadata: FieldMetadata::default(),
             },
         );
         let name = QualifiedTypeName::root("MyEnum".to_string());
-        let format = ContainerFormat::Enum(variants, EnumTagging::External, Doc::default());
+        let format = ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::default(),
+            ContainerMetadata::default(),
+        );
         let container = Container {
             name: &name,
             format: &format,
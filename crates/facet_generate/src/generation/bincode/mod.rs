@@ -12,11 +12,18 @@
 //! | `module_helpers` | Feature helper snippets (`ListOfT`, `SetOfT`, …) |
 //! | `has_type_body` | Always `true` |
 //! | `type_body` | `serialize` / `deserialize` methods + wrappers |
+//! | `check_registry` | Rejects untagged enums, flattened fields and fields left out of some languages |
 //!
 //! # Language-specific variants
 //!
-//! - **Kotlin**, **Swift**, **TypeScript**, **C#** — use `BincodePlugin`
-//!   directly (no language-specific fields required).
+//! - **Kotlin**, **Swift**, **TypeScript**, **C#**, **Dart**, **Python**, **Go**, **Java**,
+//!   **C++** — use `BincodePlugin` directly (no language-specific fields required).
+
+use crate::{
+    Registry,
+    generation::Error,
+    reflection::format::{ContainerFormat, EnumTagging, Format, Named, VariantFormat},
+};
 
 #[cfg(feature = "kotlin")]
 pub mod kotlin;
@@ -30,9 +37,77 @@ pub mod typescript;
 #[cfg(feature = "csharp")]
 pub mod csharp;
 
+#[cfg(feature = "dart")]
+pub mod dart;
+
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(feature = "go")]
+pub mod go;
+
+#[cfg(feature = "java")]
+pub mod java;
+
+#[cfg(feature = "cpp")]
+pub mod cpp;
+
 /// Bincode serialization plugin.
 ///
 /// A lightweight, language-agnostic plugin token. All languages currently
 /// use this struct directly — no language-specific fields are required.
 #[derive(Debug, Clone, Default)]
 pub struct BincodePlugin;
+
+/// Rejects untagged enums: bincode tells variants apart by their index, which
+/// an untagged enum doesn't write.
+///
+/// Also rejects fields that `fg::skip` or `fg::only` leave out of some languages:
+/// bincode writes fields by position, so those languages would read and write
+/// different bytes for the same value.
+///
+/// Also rejects flattened fields, which serde writes as a map that bincode can't
+/// encode.
+fn check_registry(registry: &Registry) -> Result<(), Error> {
+    for (name, format) in registry {
+        let unsupported = |reason: String| Error::Unsupported {
+            plugin: "bincode".to_string(),
+            type_name: name.format(ToString::to_string, "."),
+            reason,
+        };
+        if let ContainerFormat::Enum(_, EnumTagging::Untagged, _, _) = format {
+            return Err(unsupported(
+                "untagged enums don't write the variant index bincode needs".to_string(),
+            ));
+        }
+        if let Some(field) = fields(format).find(|field| field.metadata.targets.is_some()) {
+            return Err(unsupported(format!(
+                "field `{}` is only generated for some languages, which would then write different fields",
+                field.name
+            )));
+        }
+        if let Some(field) = fields(format).find(|field| field.metadata.flattened) {
+            return Err(unsupported(format!(
+                "field `{}` is flattened, which serde writes as a map that bincode can't encode",
+                field.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// The fields of a struct and of its struct variants.
+fn fields(format: &ContainerFormat) -> Box<dyn Iterator<Item = &Named<Format>> + '_> {
+    match format {
+        ContainerFormat::Struct(fields, ..) => Box::new(fields.iter()),
+        ContainerFormat::Enum(variants, ..) => {
+            Box::new(variants.values().flat_map(|variant| match &variant.value {
+                VariantFormat::Struct(fields) => fields.as_slice(),
+                _ => &[],
+            }))
+        }
+        ContainerFormat::UnitStruct(..)
+        | ContainerFormat::NewTypeStruct(..)
+        | ContainerFormat::TupleStruct(..) => Box::new(std::iter::empty()),
+    }
+}
@@ -0,0 +1,831 @@
+//! `EmitterPlugin<Python>` implementation for the bincode plugin.
+//!
+//! Writes `serialize` / `bincode_serialize` methods and `deserialize` /
+//! `bincode_deserialize` class methods into the body of every Python class.
+//! Generic containers are handled by the helpers of the serde runtime's
+//! `Serializer` and `Deserializer` (`serialize_seq`, `deserialize_map`, …),
+//! which take the (de)serializer of their elements as a `lambda`, so no
+//! per-module helper code is needed.
+//!
+//! The variant classes of an enum only serialize themselves: the `Union` of
+//! them can't have methods, so it is read by the module functions
+//! `deserialize_<enum>` / `bincode_deserialize_<enum>`. Branded newtypes, which
+//! are `NewType`s, get module functions for both directions.
+
+use std::collections::BTreeMap;
+use std::io::{self, Result};
+
+use heck::ToSnakeCase;
+
+use super::BincodePlugin;
+use crate::Registry;
+use crate::generation::{
+    BINCODE_NAMESPACE, CodeGeneratorConfig, Error, SERDE_NAMESPACE,
+    indent::{IndentWrite, IndentedWriter},
+    plugin::{EmitContext, EmitterPlugin, RuntimeFile},
+    python::{Python, enum_member, function_name, identifier, import_module, variant_class},
+};
+use crate::reflection::format::{
+    ContainerFormat, Format, Language, Named, Namespace, QualifiedTypeName, VariantFormat,
+};
+
+/// The name of a type parameter's closure, e.g. `serialize_t`.
+fn closure_name(prefix: &str, param: &str) -> String {
+    format!("{prefix}_{}", param.to_snake_case())
+}
+
+/// The per-type-parameter serializer closure parameters of `serialize`
+/// (e.g. `, serialize_t: Callable[[Serializer, T], None]`).
+fn serializer_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| {
+            format!(
+                ", {}: Callable[[Serializer, {param}], None]",
+                closure_name("serialize", param)
+            )
+        })
+        .collect()
+}
+
+/// The per-type-parameter deserializer closure parameters of `deserialize`
+/// (e.g. `, deserialize_t: Callable[[Deserializer], T]`).
+fn deserializer_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| {
+            format!(
+                ", {}: Callable[[Deserializer], {param}]",
+                closure_name("deserialize", param)
+            )
+        })
+        .collect()
+}
+
+/// Forwards the closures for `type_params` (e.g. `, serialize_t`).
+fn closure_args(prefix: &str, type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", {}", closure_name(prefix, param)))
+        .collect()
+}
+
+/// A type with its type parameters, e.g. `Page[T]`.
+fn generic_type(name: &str, type_params: &[String]) -> String {
+    if type_params.is_empty() {
+        name.to_string()
+    } else {
+        format!("{name}[{}]", type_params.join(", "))
+    }
+}
+
+/// The name of a type as the generated code refers to it, e.g. `other.Child`.
+fn type_name(name: &QualifiedTypeName) -> String {
+    name.format(|namespace| namespace.to_snake_case(), ".")
+}
+
+/// The module function that does `action` for the type `name`, e.g.
+/// `other.deserialize_shape`.
+fn function(action: &str, name: &QualifiedTypeName) -> String {
+    let function = function_name(action, &name.name);
+    match &name.namespace {
+        Namespace::Root => function,
+        Namespace::Named(namespace) => format!("{}.{function}", namespace.to_snake_case()),
+    }
+}
+
+/// Whether the type `name` is an enum whose variants carry data, which is read by a module
+/// function.
+fn is_union(config: &CodeGeneratorConfig, name: &QualifiedTypeName) -> bool {
+    config.enum_type_names.contains(&name.name) && !config.unit_variant_enums.contains(&name.name)
+}
+
+/// A `lambda` of `params` evaluating `statements`: the statement itself if there is one, or a
+/// tuple of them otherwise.
+fn closure(params: &str, statements: &[String]) -> String {
+    match statements {
+        [statement] => format!("lambda {params}: {statement}"),
+        statements => format!("lambda {params}: ({})", statements.join(", ")),
+    }
+}
+
+/// The statements that serialize `value`. Most formats take a single statement; tuples take one
+/// per element.
+fn serialize(
+    config: &CodeGeneratorConfig,
+    value: &str,
+    format: &Format,
+    level: usize,
+) -> Vec<String> {
+    let call = |method: &str| vec![format!("serializer.{method}({value})")];
+    let element = |format: &Format| {
+        let param = format!("level{}", level + 1);
+        closure(&param, &serialize(config, &param, format, level + 1))
+    };
+    match format {
+        Format::Unit => call("serialize_unit"),
+        Format::Bool => call("serialize_bool"),
+        Format::I8 => call("serialize_i8"),
+        Format::I16 => call("serialize_i16"),
+        Format::I32 => call("serialize_i32"),
+        Format::I64 => call("serialize_i64"),
+        Format::I128 => call("serialize_i128"),
+        Format::U8 => call("serialize_u8"),
+        Format::U16 => call("serialize_u16"),
+        Format::U32 => call("serialize_u32"),
+        Format::U64 => call("serialize_u64"),
+        Format::U128 => call("serialize_u128"),
+        Format::F32 => call("serialize_f32"),
+        Format::F64 => call("serialize_f64"),
+        Format::Char => call("serialize_char"),
+        Format::Str => call("serialize_str"),
+        Format::Bytes => call("serialize_bytes"),
+        Format::Uuid => call("serialize_uuid"),
+        Format::Date => call("serialize_date"),
+        Format::Time => call("serialize_time"),
+        Format::Timestamp => call("serialize_timestamp"),
+        Format::Duration => call("serialize_duration"),
+        Format::Option(format) => {
+            vec![format!(
+                "serializer.serialize_option({value}, {})",
+                element(format)
+            )]
+        }
+        Format::Seq(format) => {
+            vec![format!(
+                "serializer.serialize_seq({value}, {})",
+                element(format)
+            )]
+        }
+        Format::Set(format) => {
+            vec![format!(
+                "serializer.serialize_set({value}, {})",
+                element(format)
+            )]
+        }
+        Format::Map {
+            key,
+            value: map_value,
+        } => {
+            let key_param = format!("key{}", level + 1);
+            let value_param = format!("value{}", level + 1);
+            vec![format!(
+                "serializer.serialize_map({value}, {}, {})",
+                closure(&key_param, &serialize(config, &key_param, key, level + 1)),
+                closure(
+                    &value_param,
+                    &serialize(config, &value_param, map_value, level + 1)
+                ),
+            )]
+        }
+        Format::TupleArray { content, size } => {
+            vec![format!(
+                "serializer.serialize_tuple_array({value}, {size}, {})",
+                element(content)
+            )]
+        }
+        Format::Tuple(formats) => match formats.as_slice() {
+            [] => call("serialize_unit"),
+            [format] => serialize(config, value, format, level),
+            formats => formats
+                .iter()
+                .enumerate()
+                .flat_map(|(i, format)| serialize(config, &format!("{value}[{i}]"), format, level))
+                .collect(),
+        },
+        Format::TypeName(name) if config.branded_type_names.contains(&name.name) => {
+            vec![format!(
+                "{}({value}, serializer)",
+                function("serialize", name)
+            )]
+        }
+        Format::TypeName(_) => vec![format!("{value}.serialize(serializer)")],
+        Format::TypeParameter(name) => {
+            vec![format!(
+                "{}(serializer, {value})",
+                closure_name("serialize", name)
+            )]
+        }
+        Format::Generic { args, .. } => {
+            let param = format!("level{}", level + 1);
+            let closures = args
+                .iter()
+                .map(|arg| {
+                    closure(
+                        &format!("serializer, {param}"),
+                        &serialize(config, &param, arg, level + 1),
+                    )
+                })
+                .collect::<Vec<_>>();
+            vec![format!(
+                "{value}.serialize(serializer, {})",
+                closures.join(", ")
+            )]
+        }
+        Format::Variable(_variable) => unreachable!("placeholders should not get this far"),
+    }
+}
+
+/// The expression that deserializes a value of `format`.
+fn deserialize(config: &CodeGeneratorConfig, format: &Format) -> String {
+    let call = |method: &str| format!("deserializer.{method}()");
+    match format {
+        Format::Unit => call("deserialize_unit"),
+        Format::Bool => call("deserialize_bool"),
+        Format::I8 => call("deserialize_i8"),
+        Format::I16 => call("deserialize_i16"),
+        Format::I32 => call("deserialize_i32"),
+        Format::I64 => call("deserialize_i64"),
+        Format::I128 => call("deserialize_i128"),
+        Format::U8 => call("deserialize_u8"),
+        Format::U16 => call("deserialize_u16"),
+        Format::U32 => call("deserialize_u32"),
+        Format::U64 => call("deserialize_u64"),
+        Format::U128 => call("deserialize_u128"),
+        Format::F32 => call("deserialize_f32"),
+        Format::F64 => call("deserialize_f64"),
+        Format::Char => call("deserialize_char"),
+        Format::Str => call("deserialize_str"),
+        Format::Bytes => call("deserialize_bytes"),
+        Format::Uuid => call("deserialize_uuid"),
+        Format::Date => call("deserialize_date"),
+        Format::Time => call("deserialize_time"),
+        Format::Timestamp => call("deserialize_timestamp"),
+        Format::Duration => call("deserialize_duration"),
+        Format::Option(format) => {
+            format!(
+                "deserializer.deserialize_option(lambda: {})",
+                deserialize(config, format)
+            )
+        }
+        Format::Seq(format) => {
+            format!(
+                "deserializer.deserialize_seq(lambda: {})",
+                deserialize(config, format)
+            )
+        }
+        Format::Set(format) => {
+            format!(
+                "deserializer.deserialize_set(lambda: {})",
+                deserialize(config, format)
+            )
+        }
+        Format::Map { key, value } => format!(
+            "deserializer.deserialize_map(lambda: {}, lambda: {})",
+            deserialize(config, key),
+            deserialize(config, value)
+        ),
+        Format::TupleArray { content, size } => format!(
+            "deserializer.deserialize_tuple_array({size}, lambda: {})",
+            deserialize(config, content)
+        ),
+        Format::Tuple(formats) => match formats.as_slice() {
+            [] => call("deserialize_unit"),
+            [format] => deserialize(config, format),
+            formats => {
+                let elements = formats
+                    .iter()
+                    .map(|format| deserialize(config, format))
+                    .collect::<Vec<_>>();
+                format!("({})", elements.join(", "))
+            }
+        },
+        Format::TypeName(name)
+            if is_union(config, name) || config.branded_type_names.contains(&name.name) =>
+        {
+            format!("{}(deserializer)", function("deserialize", name))
+        }
+        Format::TypeName(name) => format!("{}.deserialize(deserializer)", type_name(name)),
+        Format::TypeParameter(name) => {
+            format!("{}(deserializer)", closure_name("deserialize", name))
+        }
+        Format::Generic { name, args } => {
+            let closures = args
+                .iter()
+                .map(|arg| format!(", lambda deserializer: {}", deserialize(config, arg)))
+                .collect::<String>();
+            if is_union(config, name) {
+                format!("{}(deserializer{closures})", function("deserialize", name))
+            } else {
+                format!("{}.deserialize(deserializer{closures})", type_name(name))
+            }
+        }
+        Format::Variable(_variable) => unreachable!("placeholders should not get this far"),
+    }
+}
+
+/// Serializes the field of `value` (e.g. `self.x`), with its `fg::override` code for Python if
+/// it has any.
+fn write_field_serialize<W: IndentWrite>(
+    w: &mut W,
+    config: &CodeGeneratorConfig,
+    value: &str,
+    field: &Named<Format>,
+) -> Result<()> {
+    let type_override = field.metadata.type_override(Language::Python);
+    match type_override.and_then(|type_override| type_override.serialize(value)) {
+        Some(serialize) => writeln!(w, "{serialize}"),
+        None => {
+            for statement in serialize(config, value, &field.value, 0) {
+                writeln!(w, "{statement}")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// The expression that deserializes a field, with its `fg::override` code for Python if it has
+/// any.
+fn field_deserialize(config: &CodeGeneratorConfig, field: &Named<Format>) -> String {
+    let type_override = field.metadata.type_override(Language::Python);
+    match type_override.and_then(|type_override| type_override.deserialize.clone()) {
+        Some(deserialize) => deserialize,
+        None => deserialize(config, &field.value),
+    }
+}
+
+/// Writes the statements that create `class` from its deserialized `fields` (keyword arguments
+/// unless they are `positional`), within a container, and return it.
+fn write_construct<W: IndentWrite>(
+    w: &mut W,
+    config: &CodeGeneratorConfig,
+    class: &str,
+    fields: &[Named<Format>],
+    positional: bool,
+) -> Result<()> {
+    if fields.is_empty() {
+        return writeln!(w, "return {class}()");
+    }
+    writeln!(w, "deserializer.increase_container_depth()")?;
+    writeln!(w, "value = {class}(")?;
+    w.indent();
+    for field in fields {
+        let value = field_deserialize(config, field);
+        if positional {
+            writeln!(w, "{value},")?;
+        } else {
+            writeln!(w, "{}={value},", identifier(&field.name))?;
+        }
+    }
+    w.unindent();
+    writeln!(w, ")")?;
+    writeln!(w, "deserializer.decrease_container_depth()")?;
+    writeln!(w, "return value")
+}
+
+/// Writes `bincode_serialize`, which serializes the value into a new byte string.
+fn write_bincode_serialize<W: IndentWrite>(w: &mut W, type_params: &[String]) -> Result<()> {
+    writeln!(
+        w,
+        "def bincode_serialize(self{}) -> bytes:",
+        serializer_params(type_params)
+    )?;
+    w.indent();
+    writeln!(w, "serializer = BincodeSerializer()")?;
+    writeln!(
+        w,
+        "self.serialize(serializer{})",
+        closure_args("serialize", type_params)
+    )?;
+    writeln!(w, "return serializer.get_bytes()")?;
+    w.unindent();
+    Ok(())
+}
+
+/// Writes the body of a `bincode_deserialize` function, which deserializes a value from the
+/// whole of `data` with `deserialize`.
+fn write_bincode_deserialize_body<W: IndentWrite>(
+    w: &mut W,
+    deserialize: &str,
+    type_params: &[String],
+) -> Result<()> {
+    w.indent();
+    writeln!(w, "deserializer = BincodeDeserializer(data)")?;
+    writeln!(
+        w,
+        "value = {deserialize}(deserializer{})",
+        closure_args("deserialize", type_params)
+    )?;
+    writeln!(w, "if deserializer.get_buffer_offset() < len(data):")?;
+    w.indent();
+    writeln!(
+        w,
+        "raise DeserializationError(\"Some input bytes were not read\")"
+    )?;
+    w.unindent();
+    writeln!(w, "return value")?;
+    w.unindent();
+    Ok(())
+}
+
+/// Writes the `deserialize` and `bincode_deserialize` class methods of a class.
+fn write_class_methods<W: IndentWrite>(
+    w: &mut W,
+    config: &CodeGeneratorConfig,
+    name: &str,
+    type_params: &[String],
+    fields: &[Named<Format>],
+    positional: bool,
+) -> Result<()> {
+    let ty = generic_type(name, type_params);
+    writeln!(w, "@classmethod")?;
+    writeln!(
+        w,
+        "def deserialize(cls, deserializer: Deserializer{}) -> {ty}:",
+        deserializer_params(type_params)
+    )?;
+    w.indent();
+    write_construct(w, config, "cls", fields, positional)?;
+    w.unindent();
+    writeln!(w)?;
+
+    writeln!(w, "@classmethod")?;
+    writeln!(
+        w,
+        "def bincode_deserialize(cls, data: bytes{}) -> {ty}:",
+        deserializer_params(type_params)
+    )?;
+    write_bincode_deserialize_body(w, "cls.deserialize", type_params)
+}
+
+/// Writes the methods of a class: a struct, or a newtype or tuple struct (whose fields are
+/// `positional`).
+fn write_class_body<W: IndentWrite>(
+    w: &mut W,
+    config: &CodeGeneratorConfig,
+    name: &str,
+    type_params: &[String],
+    fields: &[Named<Format>],
+    positional: bool,
+) -> Result<()> {
+    writeln!(
+        w,
+        "def serialize(self, serializer: Serializer{}) -> None:",
+        serializer_params(type_params)
+    )?;
+    w.indent();
+    if fields.is_empty() {
+        writeln!(w, "pass")?;
+    } else {
+        writeln!(w, "serializer.increase_container_depth()")?;
+        for field in fields {
+            let value = format!("self.{}", identifier(&field.name));
+            write_field_serialize(w, config, &value, field)?;
+        }
+        writeln!(w, "serializer.decrease_container_depth()")?;
+    }
+    w.unindent();
+    writeln!(w)?;
+    write_bincode_serialize(w, type_params)?;
+    writeln!(w)?;
+    write_class_methods(w, config, name, type_params, fields, positional)
+}
+
+/// Writes the methods of an `Enum`.
+fn write_enum_body<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+) -> Result<()> {
+    writeln!(w, "def serialize(self, serializer: Serializer) -> None:")?;
+    w.indent();
+    writeln!(w, "serializer.increase_container_depth()")?;
+    writeln!(w, "match self:")?;
+    w.indent();
+    for (index, variant) in variants {
+        writeln!(w, "case {name}.{}:", enum_member(&variant.name))?;
+        w.indent();
+        writeln!(w, "serializer.serialize_variant_index({index})")?;
+        w.unindent();
+    }
+    w.unindent();
+    writeln!(w, "serializer.decrease_container_depth()")?;
+    w.unindent();
+    writeln!(w)?;
+    write_bincode_serialize(w, &[])?;
+    writeln!(w)?;
+
+    writeln!(w, "@classmethod")?;
+    writeln!(
+        w,
+        "def deserialize(cls, deserializer: Deserializer) -> {name}:"
+    )?;
+    w.indent();
+    writeln!(w, "deserializer.increase_container_depth()")?;
+    writeln!(w, "index = deserializer.deserialize_variant_index()")?;
+    writeln!(w, "deserializer.decrease_container_depth()")?;
+    writeln!(w, "match index:")?;
+    w.indent();
+    for (index, variant) in variants {
+        writeln!(w, "case {index}:")?;
+        w.indent();
+        writeln!(w, "return cls.{}", enum_member(&variant.name))?;
+        w.unindent();
+    }
+    writeln!(w, "case _:")?;
+    w.indent();
+    match variants.values().find(|variant| variant.metadata.other) {
+        Some(other) => writeln!(w, "return cls.{}", enum_member(&other.name))?,
+        None => writeln!(
+            w,
+            "raise DeserializationError(f\"Unknown variant index for {name}: {{index}}\")"
+        )?,
+    }
+    w.unindent();
+    w.unindent();
+    w.unindent();
+    writeln!(w)?;
+
+    writeln!(w, "@classmethod")?;
+    writeln!(w, "def bincode_deserialize(cls, data: bytes) -> {name}:")?;
+    write_bincode_deserialize_body(w, "cls.deserialize", &[])
+}
+
+/// The fields of a variant, and whether they are positional.
+fn variant_fields(variant: &VariantFormat) -> (Vec<Named<Format>>, bool) {
+    match variant {
+        VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+        VariantFormat::Unit => (vec![], true),
+        VariantFormat::NewType(format) => {
+            (vec![Named::new(format.as_ref(), "value".to_string())], true)
+        }
+        VariantFormat::Tuple(formats) => (
+            formats
+                .iter()
+                .enumerate()
+                .map(|(i, format)| Named::new(format, format!("field{i}")))
+                .collect(),
+            true,
+        ),
+        VariantFormat::Struct(fields) => (fields.clone(), false),
+    }
+}
+
+/// Writes the methods of a variant class, which write its index before its fields. They take
+/// the serializers of all the enum's type parameters, so that any variant can be serialized
+/// as the enum.
+fn write_variant_body<W: IndentWrite>(
+    w: &mut W,
+    config: &CodeGeneratorConfig,
+    index: usize,
+    fields: &[Named<Format>],
+    type_params: &[String],
+) -> Result<()> {
+    writeln!(
+        w,
+        "def serialize(self, serializer: Serializer{}) -> None:",
+        serializer_params(type_params)
+    )?;
+    w.indent();
+    writeln!(w, "serializer.increase_container_depth()")?;
+    writeln!(w, "serializer.serialize_variant_index({index})")?;
+    for field in fields {
+        let value = format!("self.{}", identifier(&field.name));
+        write_field_serialize(w, config, &value, field)?;
+    }
+    writeln!(w, "serializer.decrease_container_depth()")?;
+    w.unindent();
+    writeln!(w)?;
+    write_bincode_serialize(w, type_params)
+}
+
+/// Writes the module functions that read an enum whose variants carry data, which match on its
+/// variant index.
+fn write_union_functions<W: IndentWrite>(
+    w: &mut W,
+    config: &CodeGeneratorConfig,
+    name: &str,
+    type_params: &[String],
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+) -> Result<()> {
+    let ty = generic_type(name, type_params);
+    let deserialize = function_name("deserialize", name);
+    writeln!(
+        w,
+        "def {deserialize}(deserializer: Deserializer{}) -> {ty}:",
+        deserializer_params(type_params)
+    )?;
+    w.indent();
+    writeln!(w, "index = deserializer.deserialize_variant_index()")?;
+    writeln!(w, "match index:")?;
+    w.indent();
+    for (index, variant) in variants {
+        let (fields, positional) = variant_fields(&variant.value);
+        writeln!(w, "case {index}:")?;
+        w.indent();
+        let class = variant_class(name, &variant.name);
+        write_construct(w, config, &class, &fields, positional)?;
+        w.unindent();
+    }
+    // Unknown variants, e.g. added by a later version, are read as the catch-all.
+    writeln!(w, "case _:")?;
+    w.indent();
+    match variants.values().find(|variant| variant.metadata.other) {
+        Some(other) => writeln!(w, "return {}()", variant_class(name, &other.name))?,
+        None => writeln!(
+            w,
+            "raise DeserializationError(f\"Unknown variant index for {name}: {{index}}\")"
+        )?,
+    }
+    w.unindent();
+    w.unindent();
+    w.unindent();
+    writeln!(w)?;
+    writeln!(w)?;
+
+    writeln!(
+        w,
+        "def {}(data: bytes{}) -> {ty}:",
+        function_name("bincode_deserialize", name),
+        deserializer_params(type_params)
+    )?;
+    write_bincode_deserialize_body(w, &deserialize, type_params)
+}
+
+/// Writes the module functions that (de)serialize a branded newtype, whose `NewType` can't
+/// have methods.
+fn write_branded_functions<W: IndentWrite>(
+    w: &mut W,
+    config: &CodeGeneratorConfig,
+    name: &str,
+    type_params: &[String],
+    field: &Named<Format>,
+) -> Result<()> {
+    let ty = generic_type(name, type_params);
+    writeln!(
+        w,
+        "def {}(value: {ty}, serializer: Serializer{}) -> None:",
+        function_name("serialize", name),
+        serializer_params(type_params)
+    )?;
+    w.indent();
+    writeln!(w, "serializer.increase_container_depth()")?;
+    write_field_serialize(w, config, "value", field)?;
+    writeln!(w, "serializer.decrease_container_depth()")?;
+    w.unindent();
+    writeln!(w)?;
+    writeln!(w)?;
+
+    writeln!(
+        w,
+        "def {}(value: {ty}{}) -> bytes:",
+        function_name("bincode_serialize", name),
+        serializer_params(type_params)
+    )?;
+    w.indent();
+    writeln!(w, "serializer = BincodeSerializer()")?;
+    writeln!(
+        w,
+        "{}(value, serializer{})",
+        function_name("serialize", name),
+        closure_args("serialize", type_params)
+    )?;
+    writeln!(w, "return serializer.get_bytes()")?;
+    w.unindent();
+    writeln!(w)?;
+    writeln!(w)?;
+
+    let deserialize = function_name("deserialize", name);
+    writeln!(
+        w,
+        "def {deserialize}(deserializer: Deserializer{}) -> {ty}:",
+        deserializer_params(type_params)
+    )?;
+    w.indent();
+    writeln!(w, "deserializer.increase_container_depth()")?;
+    writeln!(w, "value = {name}({})", field_deserialize(config, field))?;
+    writeln!(w, "deserializer.decrease_container_depth()")?;
+    writeln!(w, "return value")?;
+    w.unindent();
+    writeln!(w)?;
+    writeln!(w)?;
+
+    writeln!(
+        w,
+        "def {}(data: bytes{}) -> {ty}:",
+        function_name("bincode_deserialize", name),
+        deserializer_params(type_params)
+    )?;
+    write_bincode_deserialize_body(w, &deserialize, type_params)
+}
+
+/// Whether a container is an enum whose variants carry data.
+fn is_union_format(format: &ContainerFormat) -> bool {
+    matches!(
+        format,
+        ContainerFormat::Enum(variants, ..)
+            if variants
+                .values()
+                .any(|variant| !matches!(variant.value, VariantFormat::Unit))
+    )
+}
+
+// ---------------------------------------------------------------------------
+// EmitterPlugin implementation
+// ---------------------------------------------------------------------------
+
+impl EmitterPlugin<Python> for BincodePlugin {
+    /// Returns the serde and bincode Python runtime packages, to be written
+    /// into the generated package next to its modules.
+    fn runtime_files(&self) -> Vec<RuntimeFile> {
+        static SERDE: include_dir::Dir<'static> =
+            include_dir::include_dir!("$CARGO_MANIFEST_DIR/runtime/python/serde");
+        static BINCODE: include_dir::Dir<'static> =
+            include_dir::include_dir!("$CARGO_MANIFEST_DIR/runtime/python/bincode");
+
+        let mut files: Vec<RuntimeFile> = SERDE
+            .files()
+            .map(|f| RuntimeFile {
+                relative_path: format!("serde/{}", f.path().display()),
+                contents: f.contents().to_vec(),
+            })
+            .collect();
+        files.extend(BINCODE.files().map(|f| RuntimeFile {
+            relative_path: format!("bincode/{}", f.path().display()),
+            contents: f.contents().to_vec(),
+        }));
+        files
+    }
+
+    /// The serde and bincode runtime packages.
+    fn imports(&self, config: &CodeGeneratorConfig) -> Vec<String> {
+        vec![
+            format!(
+                "from {} import DeserializationError, Deserializer, Serializer",
+                import_module(config, SERDE_NAMESPACE, ".serde")
+            ),
+            format!(
+                "from {} import BincodeDeserializer, BincodeSerializer",
+                import_module(config, BINCODE_NAMESPACE, ".bincode")
+            ),
+        ]
+    }
+
+    /// Every class gets bincode methods: structs, `Enum`s and variant classes.
+    fn has_type_body(&self, ctx: &EmitContext) -> bool {
+        let metadata = ctx.container.format.metadata();
+        ctx.is_variant()
+            || !(is_union_format(ctx.container.format) || metadata.branded || metadata.proxy)
+    }
+
+    fn type_body(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
+        let config = w.config();
+        let mut w = IndentedWriter::new(&mut *w, config);
+        if let Some(variant) = &ctx.variant {
+            return write_variant_body(
+                &mut w,
+                ctx.config,
+                variant.index,
+                variant.fields,
+                variant.parent_type_params,
+            );
+        }
+        let name = ctx.name();
+        match ctx.container.format {
+            ContainerFormat::Enum(variants, _, _, _) => write_enum_body(&mut w, name, variants),
+            ContainerFormat::NewTypeStruct(..) | ContainerFormat::TupleStruct(..) => {
+                write_class_body(
+                    &mut w,
+                    ctx.config,
+                    name,
+                    ctx.type_params(),
+                    &ctx.fields(),
+                    true,
+                )
+            }
+            ContainerFormat::UnitStruct(..) | ContainerFormat::Struct(..) => write_class_body(
+                &mut w,
+                ctx.config,
+                name,
+                ctx.type_params(),
+                &ctx.fields(),
+                false,
+            ),
+        }
+    }
+
+    /// The module functions of enums whose variants carry data and of branded newtypes.
+    fn after_type(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
+        if ctx.is_variant() {
+            return Ok(());
+        }
+        let config = w.config();
+        let mut w = IndentedWriter::new(&mut *w, config);
+        let name = ctx.name();
+        match ctx.container.format {
+            ContainerFormat::Enum(variants, ..) if is_union_format(ctx.container.format) => {
+                write_union_functions(&mut w, ctx.config, name, ctx.type_params(), variants)
+            }
+            ContainerFormat::NewTypeStruct(format, _, metadata) if metadata.branded => {
+                let field = Named::new(format.as_ref(), "value".to_string()).inheriting(metadata);
+                write_branded_functions(&mut w, ctx.config, name, ctx.type_params(), &field)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Rejects untagged enums, which bincode can't encode.
+    fn check_registry(&self, registry: &Registry) -> std::result::Result<(), Error> {
+        super::check_registry(registry)
+    }
+}
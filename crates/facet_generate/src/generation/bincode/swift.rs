@@ -30,13 +30,14 @@ use std::io;
 use heck::ToLowerCamelCase as _;
 use indoc::writedoc;
 
+use crate::Registry;
 use crate::generation::{
-    CodeGeneratorConfig, Feature,
+    CodeGeneratorConfig, Error, Feature,
     indent::{IndentWrite, Newlines, with_block},
     plugin::{EmitContext, EmitterPlugin, RuntimeFile},
     swift::Swift,
 };
-use crate::reflection::format::{ContainerFormat, Format, Named, VariantFormat};
+use crate::reflection::format::{ContainerFormat, Format, Language, Named, VariantFormat};
 
 use super::BincodePlugin;
 
@@ -171,6 +172,120 @@ func deserializeUuid<D: Deserializer>(
 }
 "#;
 
+const FEATURE_TIMESTAMP: &str = r#"func serializeTimestamp<S: Serializer>(
+    value: Date,
+    serializer: S
+) throws {
+    let formatter = ISO8601DateFormatter()
+    formatter.formatOptions = [.withInternetDateTime, .withFractionalSeconds]
+    try serializer.serialize_str(value: formatter.string(from: value))
+}
+
+func deserializeTimestamp<D: Deserializer>(
+    deserializer: D
+) throws -> Date {
+    let s = try deserializer.deserialize_str()
+    // ISO8601DateFormatter accepts at most millisecond precision, so the
+    // fractional seconds are parsed separately.
+    var text = s
+    var fraction = 0.0
+    if let dot = text.firstIndex(of: "."),
+       let end = text[dot...].firstIndex(where: { !$0.isNumber && $0 != "." }) {
+        fraction = Double("0" + text[dot..<end]) ?? 0
+        text.removeSubrange(dot..<end)
+    }
+    guard let date = ISO8601DateFormatter().date(from: text) else {
+        throw DeserializationError.invalidInput(issue: "Invalid RFC 3339 timestamp: \(s)")
+    }
+    return date.addingTimeInterval(fraction)
+}
+"#;
+
+const FEATURE_DATE: &str = r#"func serializeDate<S: Serializer>(
+    value: DateComponents,
+    serializer: S
+) throws {
+    guard let year = value.year, let month = value.month, let day = value.day else {
+        throw SerializationError.invalidValue(issue: "Date requires a year, month and day")
+    }
+    try serializer.serialize_str(value: String(format: "%04d-%02d-%02d", year, month, day))
+}
+
+func deserializeDate<D: Deserializer>(
+    deserializer: D
+) throws -> DateComponents {
+    let s = try deserializer.deserialize_str()
+    let parts = s.split(separator: "-").compactMap { Int($0) }
+    guard parts.count == 3 else {
+        throw DeserializationError.invalidInput(issue: "Invalid date: \(s)")
+    }
+    return DateComponents(year: parts[0], month: parts[1], day: parts[2])
+}
+"#;
+
+const FEATURE_TIME: &str = r#"func serializeTime<S: Serializer>(
+    value: DateComponents,
+    serializer: S
+) throws {
+    guard let hour = value.hour, let minute = value.minute else {
+        throw SerializationError.invalidValue(issue: "Time requires an hour and minute")
+    }
+    var text = String(format: "%02d:%02d:%02d", hour, minute, value.second ?? 0)
+    if let nanosecond = value.nanosecond, nanosecond > 0 {
+        text += String(format: ".%09d", nanosecond)
+    }
+    try serializer.serialize_str(value: text)
+}
+
+func deserializeTime<D: Deserializer>(
+    deserializer: D
+) throws -> DateComponents {
+    let s = try deserializer.deserialize_str()
+    let parts = s.split(separator: ":")
+    let seconds = parts.count == 3 ? parts[2].split(separator: ".", maxSplits: 1) : []
+    guard parts.count == 3,
+          let hour = Int(parts[0]),
+          let minute = Int(parts[1]),
+          let second = seconds.first.flatMap({ Int($0) }) else {
+        throw DeserializationError.invalidInput(issue: "Invalid time: \(s)")
+    }
+    var nanosecond = 0
+    if seconds.count == 2 {
+        let digits = String(seconds[1].prefix(9))
+        guard let fraction = Int(digits) else {
+            throw DeserializationError.invalidInput(issue: "Invalid time: \(s)")
+        }
+        nanosecond = fraction * Int(pow(10.0, Double(9 - digits.count)))
+    }
+    return DateComponents(hour: hour, minute: minute, second: second, nanosecond: nanosecond)
+}
+"#;
+
+const FEATURE_DURATION: &str = r#"func serializeDuration<S: Serializer>(
+    value: TimeInterval,
+    serializer: S
+) throws {
+    guard value >= 0 else {
+        throw SerializationError.invalidValue(issue: "Duration must not be negative: \(value)")
+    }
+    let secs = value.rounded(.down)
+    try serializer.increase_container_depth()
+    try serializer.serialize_u64(value: UInt64(secs))
+    try serializer.serialize_u32(value: min(UInt32(((value - secs) * 1_000_000_000).rounded()), 999_999_999))
+    try serializer.decrease_container_depth()
+}
+
+func deserializeDuration<D: Deserializer>(
+    deserializer: D
+) throws -> TimeInterval {
+    try deserializer.increase_container_depth()
+    let secs = try deserializer.deserialize_u64()
+    let nanos = try deserializer.deserialize_u32()
+    try deserializer.decrease_container_depth()
+    return TimeInterval(secs) + TimeInterval(nanos) / 1_000_000_000
+}
+"#;
+
 const FEATURE_TUPLE_ARRAY: &str = r"func serializeTupleArray<T, S: Serializer>(
     value: [T],
     serializer: S,
@@ -254,6 +369,22 @@ impl EmitterPlugin<Swift> for BincodePlugin {
                     writeln!(w)?;
                     write!(w, "{FEATURE_UUID}")?;
                 }
+                Feature::Timestamp => {
+                    writeln!(w)?;
+                    write!(w, "{FEATURE_TIMESTAMP}")?;
+                }
+                Feature::Date => {
+                    writeln!(w)?;
+                    write!(w, "{FEATURE_DATE}")?;
+                }
+                Feature::Time => {
+                    writeln!(w)?;
+                    write!(w, "{FEATURE_TIME}")?;
+                }
+                Feature::Duration => {
+                    writeln!(w)?;
+                    write!(w, "{FEATURE_DURATION}")?;
+                }
                 _ => {}
             }
         }
@@ -266,12 +397,18 @@ impl EmitterPlugin<Swift> for BincodePlugin {
 
     fn type_body(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
         let name = ctx.name();
-        if let ContainerFormat::Enum(variants, _, _) = ctx.container.format {
-            write_enum_type_body(w, name, variants)
+        let type_params = ctx.type_params();
+        if let ContainerFormat::Enum(variants, _, _, _) = ctx.container.format {
+            write_enum_type_body(w, name, type_params, variants)
         } else {
-            write_struct_type_body(w, name, &ctx.fields())
+            write_struct_type_body(w, name, type_params, &ctx.fields())
         }
     }
+
+    /// Rejects untagged enums, which bincode can't encode.
+    fn check_registry(&self, registry: &Registry) -> Result<(), Error> {
+        super::check_registry(registry)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -281,33 +418,36 @@ impl EmitterPlugin<Swift> for BincodePlugin {
 fn write_struct_type_body(
     w: &mut dyn IndentWrite,
     name: &str,
+    type_params: &[String],
     fields: &[Named<Format>],
 ) -> io::Result<()> {
     writeln!(w)?;
     write!(
         w,
-        "public func serialize<S: Serializer>(serializer: S) throws "
+        "public func serialize<S: Serializer>(serializer: S{}) throws ",
+        serializer_params(type_params, "S")
     )?;
     with_block(w, Newlines::BOTH, |w| {
         push_serializer(w)?;
         for field in fields {
             let fname = field.name.to_lower_camel_case();
-            write_format_serialize(w, &field.value, &format!("self.{fname}"))?;
+            write_field_serialize(w, field, &format!("self.{fname}"))?;
         }
         pop_serializer(w)
     })?;
-    write_bincode_serialize(w)?;
+    write_bincode_serialize(w, type_params)?;
 
     writeln!(w)?;
     write!(
         w,
-        "public static func deserialize<D: Deserializer>(deserializer: D) throws -> {name} "
+        "public static func deserialize<D: Deserializer>(deserializer: D{}) throws -> {name} ",
+        deserializer_params(type_params, "D")
     )?;
     with_block(w, Newlines::BOTH, |w| {
         push_deserializer(w)?;
         for field in fields {
             let fname = field.name.to_lower_camel_case();
-            write_format_deserialize(w, &field.value, &fname)?;
+            write_field_deserialize(w, field, &fname)?;
         }
         pop_deserializer(w)?;
         write!(w, "return {name}(")?;
@@ -320,7 +460,7 @@ fn write_struct_type_body(
         }
         writeln!(w, ")")
     })?;
-    write_bincode_deserialize(w, name)?;
+    write_bincode_deserialize(w, name, type_params)?;
 
     Ok(())
 }
@@ -332,32 +472,35 @@ fn write_struct_type_body(
 fn write_enum_type_body(
     w: &mut dyn IndentWrite,
     name: &str,
+    type_params: &[String],
     variants: &BTreeMap<u32, Named<VariantFormat>>,
 ) -> io::Result<()> {
     writeln!(w)?;
     write!(
         w,
-        "public func serialize<S: Serializer>(serializer: S) throws "
+        "public func serialize<S: Serializer>(serializer: S{}) throws ",
+        serializer_params(type_params, "S")
     )?;
     with_block(w, Newlines::BOTH, |w| {
         push_serializer(w)?;
         write!(w, "switch self ")?;
         with_block(w, Newlines::BOTH, |w| {
             w.unindent();
-            for (i, variant) in variants.values().enumerate() {
-                write_variant_serialize_case(w, variant, i)?;
+            for (index, variant) in variants {
+                write_variant_serialize_case(w, variant, *index)?;
             }
             w.indent();
             Ok(())
         })?;
         pop_serializer(w)
     })?;
-    write_bincode_serialize(w)?;
+    write_bincode_serialize(w, type_params)?;
 
     writeln!(w)?;
     write!(
         w,
-        "public static func deserialize<D: Deserializer>(deserializer: D) throws -> {name} "
+        "public static func deserialize<D: Deserializer>(deserializer: D{}) throws -> {name} ",
+        deserializer_params(type_params, "D")
     )?;
     with_block(w, Newlines::BOTH, |w| {
         writeln!(
@@ -368,18 +511,15 @@ fn write_enum_type_body(
         write!(w, "switch index ")?;
         with_block(w, Newlines::BOTH, |w| {
             w.unindent();
-            for (i, variant) in variants.values().enumerate() {
-                write_variant_deserialize_case(w, variant, i)?;
+            for (index, variant) in variants {
+                write_variant_deserialize_case(w, variant, *index)?;
             }
-            writeln!(
-                w,
-                r#"default: throw DeserializationError.invalidInput(issue: "Unknown variant index for {name}: \(index)")"#
-            )?;
+            write_unknown_variant_case(w, name, variants)?;
             w.indent();
             Ok(())
         })
     })?;
-    write_bincode_deserialize(w, name)?;
+    write_bincode_deserialize(w, name, type_params)?;
 
     Ok(())
 }
@@ -391,7 +531,7 @@ fn write_enum_type_body(
 fn write_variant_serialize_case(
     w: &mut dyn IndentWrite,
     variant: &Named<VariantFormat>,
-    index: usize,
+    index: u32,
 ) -> io::Result<()> {
     let name = variant.name.to_lower_camel_case();
     match &variant.value {
@@ -439,7 +579,7 @@ fn write_variant_serialize_case(
             writeln!(w, "try serializer.serialize_variant_index(value: {index})")?;
             for named in nameds {
                 let field_name = named.name.to_lower_camel_case();
-                write_format_serialize(w, &named.value, &field_name)?;
+                write_field_serialize(w, named, &field_name)?;
             }
             w.unindent();
         }
@@ -447,10 +587,31 @@ fn write_variant_serialize_case(
     Ok(())
 }
 
+/// Writes the `default` case of a deserializer's `switch`, which returns the catch-all variant
+/// for unknown indices if there is one.
+fn write_unknown_variant_case(
+    w: &mut dyn IndentWrite,
+    name: &str,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+) -> io::Result<()> {
+    let Some(other) = variants.values().find(|variant| variant.metadata.other) else {
+        return writeln!(
+            w,
+            r#"default: throw DeserializationError.invalidInput(issue: "Unknown variant index for {name}: \(index)")"#
+        );
+    };
+    writeln!(w, "default:")?;
+    w.indent();
+    pop_deserializer(w)?;
+    writeln!(w, "return .{}", other.name.to_lower_camel_case())?;
+    w.unindent();
+    Ok(())
+}
+
 fn write_variant_deserialize_case(
     w: &mut dyn IndentWrite,
     variant: &Named<VariantFormat>,
-    index: usize,
+    index: u32,
 ) -> io::Result<()> {
     let name = variant.name.to_lower_camel_case();
     writeln!(w, "case {index}:")?;
@@ -483,7 +644,7 @@ fn write_variant_deserialize_case(
         VariantFormat::Struct(nameds) => {
             for named in nameds {
                 let field_name = named.name.to_lower_camel_case();
-                write_format_deserialize(w, &named.value, &field_name)?;
+                write_field_deserialize(w, named, &field_name)?;
             }
             pop_deserializer(w)?;
             write!(w, "return .{name}(")?;
@@ -505,28 +666,37 @@ fn write_variant_deserialize_case(
 // Serialization wrappers
 // ---------------------------------------------------------------------------
 
-fn write_bincode_serialize(w: &mut dyn IndentWrite) -> io::Result<()> {
+fn write_bincode_serialize(w: &mut dyn IndentWrite, type_params: &[String]) -> io::Result<()> {
+    let params = serializer_params(type_params, "BincodeSerializer");
+    let args = closure_args("serialize", type_params);
     writeln!(w)?;
     writedoc!(
         w,
         r"
-        public func bincodeSerialize() throws -> [UInt8] {{
+        public func bincodeSerialize({params}) throws -> [UInt8] {{
             let serializer = BincodeSerializer.init();
-            try self.serialize(serializer: serializer)
+            try self.serialize(serializer: serializer{args})
             return serializer.get_bytes()
         }}
-        "
+        ",
+        params = params.trim_start_matches(", "),
     )
 }
 
-fn write_bincode_deserialize(w: &mut dyn IndentWrite, name: &str) -> io::Result<()> {
+fn write_bincode_deserialize(
+    w: &mut dyn IndentWrite,
+    name: &str,
+    type_params: &[String],
+) -> io::Result<()> {
+    let params = deserializer_params(type_params, "BincodeDeserializer");
+    let args = closure_args("deserialize", type_params);
     writeln!(w)?;
     writedoc!(
         w,
         r#"
-        public static func bincodeDeserialize(input: [UInt8]) throws -> {name} {{
+        public static func bincodeDeserialize(input: [UInt8]{params}) throws -> {name} {{
             let deserializer = BincodeDeserializer.init(input: input);
-            let obj = try deserialize(deserializer: deserializer)
+            let obj = try deserialize(deserializer: deserializer{args})
             if deserializer.get_buffer_offset() < input.count {{
                 throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
             }}
@@ -548,6 +718,18 @@ fn write_format_serialize(
     match format {
         Format::Variable(_) => unreachable!("placeholders should not get this far"),
         Format::TypeName(_) => writeln!(w, "try {value_expr}.serialize(serializer: serializer)"),
+        Format::TypeParameter(name) => writeln!(w, "try serialize{name}({value_expr}, serializer)"),
+        Format::Generic { args, .. } => {
+            write!(w, "try {value_expr}.serialize(serializer: serializer")?;
+            for arg in args {
+                write!(w, ", ")?;
+                with_block(w, Newlines::NONE, |w| {
+                    writeln!(w, " value, serializer in")?;
+                    write_format_serialize(w, arg, "value")
+                })?;
+            }
+            writeln!(w, ")")
+        }
         Format::Option(inner) => {
             write!(
                 w,
@@ -611,6 +793,12 @@ fn write_format_serialize(
                 "try serializeUuid(value: {value_expr}, serializer: serializer)"
             )
         }
+        Format::Timestamp | Format::Date | Format::Time | Format::Duration => {
+            writeln!(
+                w,
+                "try serialize{format:?}(value: {value_expr}, serializer: serializer)"
+            )
+        }
         primitive => {
             let t = format!("{primitive:?}").to_lowercase();
             writeln!(w, "try serializer.serialize_{t}(value: {value_expr})")
@@ -618,6 +806,34 @@ fn write_format_serialize(
     }
 }
 
+/// Serializes the struct or variant field held by `var`, with its `fg::override` code for Swift
+/// if it has any.
+fn write_field_serialize(
+    w: &mut dyn IndentWrite,
+    field: &Named<Format>,
+    var: &str,
+) -> io::Result<()> {
+    let type_override = field.metadata.type_override(Language::Swift);
+    match type_override.and_then(|type_override| type_override.serialize(var)) {
+        Some(serialize) => writeln!(w, "{serialize}"),
+        None => write_format_serialize(w, &field.value, var),
+    }
+}
+
+/// Deserializes a struct or variant field into `var`, with its `fg::override` code for Swift if
+/// it has any.
+fn write_field_deserialize(
+    w: &mut dyn IndentWrite,
+    field: &Named<Format>,
+    var: &str,
+) -> io::Result<()> {
+    let type_override = field.metadata.type_override(Language::Swift);
+    match type_override.and_then(|type_override| type_override.deserialize.as_ref()) {
+        Some(deserialize) => writeln!(w, "let {var} = {deserialize}"),
+        None => write_format_deserialize(w, &field.value, var),
+    }
+}
+
 fn write_format_deserialize(w: &mut dyn IndentWrite, format: &Format, var: &str) -> io::Result<()> {
     match format {
         Format::Tuple(formats) if formats.len() > 1 => {
@@ -648,6 +864,20 @@ fn write_deserialize_expr(w: &mut dyn IndentWrite, format: &Format) -> io::Resul
             let type_name = qtn.format(|ns| heck::AsUpperCamelCase(ns).to_string(), ".");
             write!(w, "try {type_name}.deserialize(deserializer: deserializer)")
         }
+        Format::TypeParameter(name) => write!(w, "try deserialize{name}(deserializer)"),
+        Format::Generic { name, args } => {
+            let type_name = name.format(|ns| heck::AsUpperCamelCase(ns).to_string(), ".");
+            write!(w, "try {type_name}.deserialize(deserializer: deserializer")?;
+            for arg in args {
+                writeln!(w, ", {{ deserializer in")?;
+                w.indent();
+                write_deserialize_expr(w, arg)?;
+                writeln!(w)?;
+                w.unindent();
+                write!(w, "}}")?;
+            }
+            write!(w, ")")
+        }
         Format::Option(inner) => {
             writeln!(
                 w,
@@ -719,6 +949,9 @@ fn write_deserialize_expr(w: &mut dyn IndentWrite, format: &Format) -> io::Resul
             write!(w, "}}")
         }
         Format::Uuid => write!(w, "try deserializeUuid(deserializer: deserializer)"),
+        Format::Timestamp | Format::Date | Format::Time | Format::Duration => {
+            write!(w, "try deserialize{format:?}(deserializer: deserializer)")
+        }
         primitive => {
             let t = format!("{primitive:?}").to_lowercase();
             write!(w, "try deserializer.deserialize_{t}()")
@@ -726,6 +959,38 @@ fn write_deserialize_expr(w: &mut dyn IndentWrite, format: &Format) -> io::Resul
     }
 }
 
+// ---------------------------------------------------------------------------
+// Generic type parameters
+// ---------------------------------------------------------------------------
+
+/// The per-type-parameter serializer closure parameters of `serialize` (e.g.
+/// `, _ serializeT: (T, S) throws -> Void`), where `serializer` is the
+/// serializer type.
+fn serializer_params(type_params: &[String], serializer: &str) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", _ serialize{param}: ({param}, {serializer}) throws -> Void"))
+        .collect()
+}
+
+/// The per-type-parameter deserializer closure parameters of `deserialize`
+/// (e.g. `, _ deserializeT: (D) throws -> T`), where `deserializer` is the
+/// deserializer type.
+fn deserializer_params(type_params: &[String], deserializer: &str) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", _ deserialize{param}: ({deserializer}) throws -> {param}"))
+        .collect()
+}
+
+/// Forwards the closures for `type_params` (e.g. `, serializeT`).
+fn closure_args(prefix: &str, type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", {prefix}{param}"))
+        .collect()
+}
+
 // ---------------------------------------------------------------------------
 // Depth tracking
 // ---------------------------------------------------------------------------
@@ -805,13 +1070,15 @@ mod tests {
     #[test]
     fn has_type_body_always_true() {
         use crate::generation::Container;
-        use crate::reflection::format::{ContainerFormat, Doc, QualifiedTypeName};
+        use crate::reflection::format::{
+            ContainerFormat, ContainerMetadata, Doc, QualifiedTypeName,
+        };
 
         let plugin = &BincodePlugin as &dyn EmitterPlugin<Swift>;
         let config = CodeGeneratorConfig::new("test".to_string());
 
         let name = QualifiedTypeName::root("Foo".to_string());
-        let format = ContainerFormat::UnitStruct(Doc::default());
+        let format = ContainerFormat::UnitStruct(Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -823,13 +1090,15 @@ mod tests {
     #[test]
     fn type_body_unit_struct() {
         use crate::generation::Container;
-        use crate::reflection::format::{ContainerFormat, Doc, QualifiedTypeName};
+        use crate::reflection::format::{
+            ContainerFormat, ContainerMetadata, Doc, QualifiedTypeName,
+        };
 
         let cfg = make_config(&[]);
         let plugin = &BincodePlugin as &dyn EmitterPlugin<Swift>;
 
         let name = QualifiedTypeName::root("UnitStruct".to_string());
-        let format = ContainerFormat::UnitStruct(Doc::default());
+        let format = ContainerFormat::UnitStruct(Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -856,7 +1125,9 @@ mod tests {
     #[test]
     fn type_body_struct_with_fields() {
         use crate::generation::Container;
-        use crate::reflection::format::{ContainerFormat, Doc, Format, QualifiedTypeName};
+        use crate::reflection::format::{
+            ContainerFormat, ContainerMetadata, Doc, Format, QualifiedTypeName,
+        };
 
         let cfg = make_config(&[]);
         let plugin = &BincodePlugin as &dyn EmitterPlugin<Swift>;
@@ -866,7 +1137,7 @@ mod tests {
             Named::new(&Format::Str, "label".to_string()),
             Named::new(&Format::I32, "count".to_string()),
         ];
-        let format = ContainerFormat::Struct(fields, Doc::default());
+        let format = ContainerFormat::Struct(fields, Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -893,7 +1164,9 @@ mod tests {
     #[test]
     fn type_body_struct_tuple_field_no_extra_depth() {
         use crate::generation::Container;
-        use crate::reflection::format::{ContainerFormat, Doc, Format, QualifiedTypeName};
+        use crate::reflection::format::{
+            ContainerFormat, ContainerMetadata, Doc, Format, QualifiedTypeName,
+        };
 
         let cfg = make_config(&[]);
         let plugin = &BincodePlugin as &dyn EmitterPlugin<Swift>;
@@ -903,7 +1176,7 @@ mod tests {
             &Format::Tuple(vec![Format::Str, Format::I32]),
             "pair".to_string(),
         )];
-        let format = ContainerFormat::Struct(fields, Doc::default());
+        let format = ContainerFormat::Struct(fields, Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -933,7 +1206,9 @@ mod tests {
     #[test]
     fn type_body_enum_with_variants() {
         use crate::generation::Container;
-        use crate::reflection::format::{ContainerFormat, Doc, Format, QualifiedTypeName};
+        use crate::reflection::format::{
+            ContainerFormat, ContainerMetadata, Doc, FieldMetadata, Format, QualifiedTypeName,
+        };
 
         let cfg = make_config(&[]);
         let plugin = &BincodePlugin as &dyn EmitterPlugin<Swift>;
@@ -945,6 +1220,7 @@ mod tests {
                 name: "unit".to_string(),
                 doc: Doc::default(),
                 value: VariantFormat::Unit,
+                metadata: FieldMetadata::default(),
             },
         );
         variants.insert(
@@ -953,10 +1229,16 @@ mod tests {
                 name: "withValue".to_string(),
                 doc: Doc::default(),
                 value: VariantFormat::NewType(Box::new(Format::Str)),
+                metadata: FieldMetadata::default(),
             },
         );
         let name = QualifiedTypeName::root("MyEnum".to_string());
-        let format = ContainerFormat::Enum(variants, EnumTagging::External, Doc::default());
+        let format = ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::default(),
+            ContainerMetadata::default(),
+        );
         let container = Container {
             name: &name,
             format: &format,
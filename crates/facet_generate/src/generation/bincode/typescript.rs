@@ -23,13 +23,16 @@ use std::io;
 
 use heck::ToUpperCamelCase;
 
+use crate::Registry;
 use crate::generation::{
-    CodeGeneratorConfig, Feature, PackageLocation, SERDE_NAMESPACE,
+    CodeGeneratorConfig, Error, Feature, PackageLocation, SERDE_NAMESPACE,
     indent::{IndentWrite, Newlines, with_block},
     plugin::{EmitContext, EmitterPlugin, RuntimeFile},
-    typescript::TypeScript,
+    typescript::{TypeScript, emitter::constructor_order},
+};
+use crate::reflection::format::{
+    ContainerFormat, EnumTagging, Format, Language, Named, VariantFormat,
 };
-use crate::reflection::format::{ContainerFormat, EnumTagging, Format, Named, VariantFormat};
 
 use super::BincodePlugin;
 
@@ -206,6 +209,33 @@ function deserializeUuid(deserializer: Deserializer): Uuid {
 }
 ";
 
+const FEATURE_TIMESTAMP: &str = r"function serializeTimestamp(value: Date, serializer: Serializer): void {
+    serializer.serializeStr(value.toISOString());
+}
+
+function deserializeTimestamp(deserializer: Deserializer): Date {
+    const s = deserializer.deserializeStr();
+    // `Date` has millisecond precision, so longer fractions are truncated.
+    const value = new Date(s.replace(/(\.\d{3})\d+/, '$1'));
+    if (Number.isNaN(value.getTime())) {
+        throw new Error(`Invalid RFC 3339 timestamp: ${s}`);
+    }
+    return value;
+}
+";
+
+const FEATURE_DURATION: &str = r"function serializeDuration(value: duration, serializer: Serializer): void {
+    serializer.serializeU64(value.secs);
+    serializer.serializeU32(value.nanos);
+}
+
+function deserializeDuration(deserializer: Deserializer): duration {
+    const secs = deserializer.deserializeU64();
+    const nanos = deserializer.deserializeU32();
+    return { secs, nanos };
+}
+";
+
 // ---------------------------------------------------------------------------
 // EmitterPlugin implementation
 // ---------------------------------------------------------------------------
@@ -285,7 +315,16 @@ impl EmitterPlugin<TypeScript> for BincodePlugin {
                     writeln!(w)?;
                     write!(w, "{FEATURE_UUID}")?;
                 }
-                Feature::BigInt | Feature::Bytes => {}
+                Feature::Timestamp => {
+                    writeln!(w)?;
+                    write!(w, "{FEATURE_TIMESTAMP}")?;
+                }
+                Feature::Duration => {
+                    writeln!(w)?;
+                    write!(w, "{FEATURE_DURATION}")?;
+                }
+                // dates and times are plain strings on both sides
+                Feature::BigInt | Feature::Bytes | Feature::Date | Feature::Time => {}
             }
         }
         Ok(())
@@ -296,18 +335,39 @@ impl EmitterPlugin<TypeScript> for BincodePlugin {
     }
 
     fn type_body(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
-        if matches!(ctx.container.format, ContainerFormat::Enum(_, _, _)) {
+        if matches!(ctx.container.format, ContainerFormat::Enum(_, _, _, _)) {
             // Enums are union types; serialize/deserialize are emitted via after_type
             return Ok(());
         }
-        write_struct_type_body(w, ctx.name(), &ctx.fields(), ctx.config)
+        write_struct_type_body(w, ctx.name(), ctx.type_params(), &ctx.fields(), ctx.config)
     }
 
     fn after_type(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
-        if let ContainerFormat::Enum(variants, tagging, _) = ctx.container.format {
-            write_enum_standalone_functions(w, ctx.name(), variants, tagging, ctx.config)?;
+        match ctx.container.format {
+            ContainerFormat::Enum(variants, tagging, _, _) => write_enum_standalone_functions(
+                w,
+                ctx.name(),
+                ctx.type_params(),
+                variants,
+                tagging,
+                ctx.config,
+            ),
+            ContainerFormat::NewTypeStruct(format, _, metadata) if metadata.branded => {
+                write_branded_standalone_functions(
+                    w,
+                    ctx.name(),
+                    ctx.type_params(),
+                    format,
+                    ctx.config,
+                )
+            }
+            _ => Ok(()),
         }
-        Ok(())
+    }
+
+    /// Rejects untagged enums, which bincode can't encode.
+    fn check_registry(&self, registry: &Registry) -> Result<(), Error> {
+        super::check_registry(registry)
     }
 }
 
@@ -319,28 +379,38 @@ impl EmitterPlugin<TypeScript> for BincodePlugin {
 fn write_struct_type_body(
     w: &mut dyn IndentWrite,
     name: &str,
+    type_params: &[String],
     fields: &[Named<Format>],
     config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
     writeln!(w)?;
-    write!(w, "public serialize(serializer: Serializer): void ")?;
+    write!(
+        w,
+        "public serialize(serializer: Serializer{}): void ",
+        serializer_params(type_params)
+    )?;
     with_block(w, Newlines::BOTH, |w| {
         for field in fields {
-            write_serialize(w, &format!("this.{}", field.name), &field.value, config)?;
+            write_field_serialize(w, &format!("this.{}", field.name), field, config)?;
         }
         Ok(())
     })?;
     writeln!(w)?;
-    write!(w, "static deserialize(deserializer: Deserializer): {name} ")?;
+    let generics = generic_list(type_params);
+    write!(
+        w,
+        "static deserialize{generics}(deserializer: Deserializer{}): {name}{generics} ",
+        deserializer_params(type_params)
+    )?;
     with_block(w, Newlines::BOTH, |w| {
         for field in fields {
-            write_deserialize(w, Some(&field.name), &field.value, config)?;
+            write_field_deserialize(w, field, config)?;
         }
         writeln!(
             w,
             "return new {name}({args});",
-            args = fields
-                .iter()
+            args = constructor_order(fields)
+                .into_iter()
                 .map(|f| f.name.clone())
                 .collect::<Vec<_>>()
                 .join(",")
@@ -349,23 +419,58 @@ fn write_struct_type_body(
     Ok(())
 }
 
+/// Emit standalone `serialize{Name}` / `deserialize{Name}` functions for a branded
+/// newtype, which is a type alias of its inner type and so encodes exactly like it.
+fn write_branded_standalone_functions(
+    w: &mut dyn IndentWrite,
+    name: &str,
+    type_params: &[String],
+    format: &Format,
+    config: &CodeGeneratorConfig,
+) -> io::Result<()> {
+    let generics = generic_list(type_params);
+    writeln!(w)?;
+    write!(
+        w,
+        "export function serialize{name}{generics}(value: {name}{generics}, serializer: Serializer{}): void ",
+        serializer_params(type_params)
+    )?;
+    with_block(w, Newlines::BOTH, |w| {
+        write_serialize(w, "value", format, config)
+    })?;
+
+    writeln!(w)?;
+    write!(
+        w,
+        "export function deserialize{name}{generics}(deserializer: Deserializer{}): {name}{generics} ",
+        deserializer_params(type_params)
+    )?;
+    with_block(w, Newlines::BOTH, |w| {
+        write_deserialize(w, Some("value"), format, config)?;
+        writeln!(w, "return value as {name}{generics};")
+    })
+}
+
 /// Emit standalone `export function serialize{Name}` and `export function deserialize{Name}`.
 fn write_enum_standalone_functions(
     w: &mut dyn IndentWrite,
     name: &str,
+    type_params: &[String],
     variants: &BTreeMap<u32, Named<VariantFormat>>,
     tagging: &EnumTagging,
     config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
     let tag_field = match tagging {
-        EnumTagging::External => "kind",
+        EnumTagging::External | EnumTagging::Untagged => "kind",
         EnumTagging::Internal { tag } | EnumTagging::Adjacent { tag, .. } => tag.as_str(),
     };
 
+    let generics = generic_list(type_params);
     writeln!(w)?;
     write!(
         w,
-        "export function serialize{name}(value: {name}, serializer: Serializer): void "
+        "export function serialize{name}{generics}(value: {name}{generics}, serializer: Serializer{}): void ",
+        serializer_params(type_params)
     )?;
     with_block(w, Newlines::BOTH, |w| {
         write!(w, "switch (value.{tag_field}) ")?;
@@ -389,7 +494,8 @@ fn write_enum_standalone_functions(
     writeln!(w)?;
     write!(
         w,
-        "export function deserialize{name}(deserializer: Deserializer): {name} "
+        "export function deserialize{name}{generics}(deserializer: Deserializer{}): {name}{generics} ",
+        deserializer_params(type_params)
     )?;
     with_block(w, Newlines::BOTH, |w| {
         writeln!(w, "const index = deserializer.deserializeVariantIndex();")?;
@@ -407,10 +513,25 @@ fn write_enum_standalone_functions(
                     )
                 })?;
             }
-            writeln!(
-                w,
-                r#"default: throw new Error("Unknown variant index for {name}: " + index);"#
-            )
+            // Unknown variants, e.g. added by a later version, are read as the catch-all.
+            match variants.values().find(|variant| variant.metadata.other) {
+                Some(other) => {
+                    write!(w, "default: ")?;
+                    with_block(w, Newlines::BOTH, |w| {
+                        write_deserialize_variant_return(
+                            w,
+                            &other.name,
+                            tagging,
+                            &other.value,
+                            config,
+                        )
+                    })
+                }
+                None => writeln!(
+                    w,
+                    r#"default: throw new Error("Unknown variant index for {name}: " + index);"#
+                ),
+            }
         })
     })?;
 
@@ -444,10 +565,10 @@ fn write_serialize_variant_fields(
         }
         (EnumTagging::Adjacent { content, .. }, VariantFormat::Struct(fields)) => {
             for field in fields {
-                write_serialize(
+                write_field_serialize(
                     w,
                     &format!("value.{content}.{}", field.name),
-                    &field.value,
+                    field,
                     config,
                 )?;
             }
@@ -455,7 +576,7 @@ fn write_serialize_variant_fields(
         }
         (_, VariantFormat::Struct(fields)) => {
             for field in fields {
-                write_serialize(w, &format!("value.{}", field.name), &field.value, config)?;
+                write_field_serialize(w, &format!("value.{}", field.name), field, config)?;
             }
             Ok(())
         }
@@ -472,7 +593,7 @@ fn write_deserialize_variant_return(
     config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
     let tag_field = match tagging {
-        EnumTagging::External => "kind",
+        EnumTagging::External | EnumTagging::Untagged => "kind",
         EnumTagging::Internal { tag } | EnumTagging::Adjacent { tag, .. } => tag.as_str(),
     };
 
@@ -518,7 +639,7 @@ fn write_deserialize_variant_return(
         }
         (EnumTagging::Adjacent { content, .. }, VariantFormat::Struct(fields)) => {
             for field in fields {
-                write_deserialize(w, Some(&field.name), &field.value, config)?;
+                write_field_deserialize(w, field, config)?;
             }
             let struct_fields = fields
                 .iter()
@@ -532,7 +653,7 @@ fn write_deserialize_variant_return(
         }
         (_, VariantFormat::Struct(fields)) => {
             for field in fields {
-                write_deserialize(w, Some(&field.name), &field.value, config)?;
+                write_field_deserialize(w, field, config)?;
             }
             let field_names: Vec<String> = fields.iter().map(|f| f.name.clone()).collect();
             let all_parts: Vec<String> =
@@ -558,12 +679,30 @@ fn write_serialize(
     match format {
         Format::TypeName(qualified_name) => {
             let type_name = qualified_name.format(ToUpperCamelCase::to_upper_camel_case, ".");
-            if config.enum_type_names.contains(&type_name) {
+            if has_standalone_functions(&type_name, config) {
                 writeln!(w, "serialize{type_name}({value_expr}, serializer);")
             } else {
                 writeln!(w, "{value_expr}.serialize(serializer);")
             }
         }
+        Format::Generic { name, args } => {
+            let type_name = name.format(ToUpperCamelCase::to_upper_camel_case, ".");
+            if has_standalone_functions(&type_name, config) {
+                write!(w, "serialize{type_name}({value_expr}, serializer")?;
+            } else {
+                write!(w, "{value_expr}.serialize(serializer")?;
+            }
+            for arg in args {
+                write!(w, ", (value, serializer) => ")?;
+                with_block(w, Newlines::OPEN, |w| {
+                    write_serialize(w, "value", arg, config)
+                })?;
+            }
+            writeln!(w, ");")
+        }
+        Format::TypeParameter(param) => {
+            writeln!(w, "{}({value_expr}, serializer);", serializer_name(param))
+        }
         Format::Unit => writeln!(w, "serializer.serializeUnit({value_expr});"),
         Format::Bool => writeln!(w, "serializer.serializeBool({value_expr});"),
         Format::I8 => writeln!(w, "serializer.serializeI8({value_expr});"),
@@ -582,6 +721,9 @@ fn write_serialize(
         Format::Str => writeln!(w, "serializer.serializeStr({value_expr});"),
         Format::Bytes => writeln!(w, "serializer.serializeBytes({value_expr});"),
         Format::Uuid => writeln!(w, "serializeUuid({value_expr}, serializer);"),
+        Format::Timestamp => writeln!(w, "serializeTimestamp({value_expr}, serializer);"),
+        Format::Date | Format::Time => writeln!(w, "serializer.serializeStr({value_expr});"),
+        Format::Duration => writeln!(w, "serializeDuration({value_expr}, serializer);"),
         Format::Option(inner) => {
             write!(
                 w,
@@ -643,6 +785,56 @@ fn write_serialize(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Generic type parameters
+// ---------------------------------------------------------------------------
+
+/// The type parameter list of a generic type (e.g. `<T, E>`), or an empty
+/// string for a non-generic type.
+fn generic_list(type_params: &[String]) -> String {
+    if type_params.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", type_params.join(", "))
+    }
+}
+
+/// Name of the closure parameter that serializes values of a type parameter.
+fn serializer_name(param: &str) -> String {
+    format!("serialize{}", param.to_upper_camel_case())
+}
+
+/// Name of the closure parameter that deserializes values of a type parameter.
+fn deserializer_name(param: &str) -> String {
+    format!("deserialize{}", param.to_upper_camel_case())
+}
+
+/// Trailing serializer closure parameters, one per type parameter.
+fn serializer_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| {
+            format!(
+                ", {}: (value: {param}, serializer: Serializer) => void",
+                serializer_name(param)
+            )
+        })
+        .collect()
+}
+
+/// Trailing deserializer closure parameters, one per type parameter.
+fn deserializer_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| {
+            format!(
+                ", {}: (deserializer: Deserializer) => {param}",
+                deserializer_name(param)
+            )
+        })
+        .collect()
+}
+
 // ---------------------------------------------------------------------------
 // Deserialize helpers
 // ---------------------------------------------------------------------------
@@ -652,6 +844,14 @@ fn write_serialize(
 fn quote_type(format: &Format) -> String {
     match format {
         Format::TypeName(type_) => type_.format(ToUpperCamelCase::to_upper_camel_case, "."),
+        Format::Generic { name, args } => {
+            let args = args.iter().map(quote_type).collect::<Vec<_>>().join(", ");
+            format!(
+                "{}<{args}>",
+                name.format(ToUpperCamelCase::to_upper_camel_case, ".")
+            )
+        }
+        Format::TypeParameter(param) => param.clone(),
         Format::Unit => "unit".to_string(),
         Format::Bool => "bool".to_string(),
         Format::I8 => "int8".to_string(),
@@ -670,6 +870,10 @@ fn quote_type(format: &Format) -> String {
         Format::Str => "str".to_string(),
         Format::Bytes => "bytes".to_string(),
         Format::Uuid => "Uuid".to_string(),
+        Format::Timestamp => "timestamp".to_string(),
+        Format::Date => "date".to_string(),
+        Format::Time => "time".to_string(),
+        Format::Duration => "duration".to_string(),
         Format::Option(inner) => format!("Optional<{}>", quote_type(inner)),
         Format::Seq(inner) | Format::Set(inner) => format!("Seq<{}>", quote_type(inner)),
         Format::Map { key, value } => {
@@ -695,12 +899,13 @@ fn deserialize_primitive_expr(format: &Format, config: &CodeGeneratorConfig) ->
     match format {
         Format::TypeName(qualified_name) => {
             let type_name = qualified_name.format(ToUpperCamelCase::to_upper_camel_case, ".");
-            if config.enum_type_names.contains(&type_name) {
+            if has_standalone_functions(&type_name, config) {
                 format!("deserialize{type_name}(deserializer)")
             } else {
                 format!("{type_name}.deserialize(deserializer)")
             }
         }
+        Format::TypeParameter(param) => format!("{}(deserializer)", deserializer_name(param)),
         Format::Unit => "deserializer.deserializeUnit()".to_string(),
         Format::Bool => "deserializer.deserializeBool()".to_string(),
         Format::I8 => "deserializer.deserializeI8()".to_string(),
@@ -719,15 +924,26 @@ fn deserialize_primitive_expr(format: &Format, config: &CodeGeneratorConfig) ->
         Format::Str => "deserializer.deserializeStr()".to_string(),
         Format::Bytes => "deserializer.deserializeBytes()".to_string(),
         Format::Uuid => "deserializeUuid(deserializer)".to_string(),
+        Format::Timestamp => "deserializeTimestamp(deserializer)".to_string(),
+        Format::Date | Format::Time => "deserializer.deserializeStr()".to_string(),
+        Format::Duration => "deserializeDuration(deserializer)".to_string(),
         _ => panic!("deserialize_primitive_expr called with non-primitive format"),
     }
 }
 
+/// Whether values of the named type are (de)serialized by standalone
+/// `serialize{Name}` / `deserialize{Name}` functions rather than methods: enums, which
+/// are union types, and branded newtypes, which are aliases of their inner type.
+fn has_standalone_functions(type_name: &str, config: &CodeGeneratorConfig) -> bool {
+    config.enum_type_names.contains(type_name) || config.branded_type_names.contains(type_name)
+}
+
 /// Returns `true` for primitive types and named (user-defined) type references.
 const fn is_primitive_or_named(format: &Format) -> bool {
     matches!(
         format,
         Format::TypeName(_)
+            | Format::TypeParameter(_)
             | Format::Unit
             | Format::Bool
             | Format::I8
@@ -746,6 +962,10 @@ const fn is_primitive_or_named(format: &Format) -> bool {
             | Format::Str
             | Format::Bytes
             | Format::Uuid
+            | Format::Timestamp
+            | Format::Date
+            | Format::Time
+            | Format::Duration
     )
 }
 
@@ -754,6 +974,35 @@ const fn is_primitive_or_named(format: &Format) -> bool {
 /// When `field_name` is `Some`, emits `const <name> = <expr>;`.
 /// When `field_name` is `None`, emits `return <expr>;`.
 #[allow(clippy::too_many_lines)]
+/// Serializes the struct or variant field held by `value_expr`, with its `fg::override` code for
+/// TypeScript if it has any.
+fn write_field_serialize(
+    w: &mut dyn IndentWrite,
+    value_expr: &str,
+    field: &Named<Format>,
+    config: &CodeGeneratorConfig,
+) -> io::Result<()> {
+    let type_override = field.metadata.type_override(Language::TypeScript);
+    match type_override.and_then(|type_override| type_override.serialize(value_expr)) {
+        Some(serialize) => writeln!(w, "{serialize}"),
+        None => write_serialize(w, value_expr, &field.value, config),
+    }
+}
+
+/// Deserializes a struct or variant field into a local of the same name, with its
+/// `fg::override` code for TypeScript if it has any.
+fn write_field_deserialize(
+    w: &mut dyn IndentWrite,
+    field: &Named<Format>,
+    config: &CodeGeneratorConfig,
+) -> io::Result<()> {
+    let type_override = field.metadata.type_override(Language::TypeScript);
+    match type_override.and_then(|type_override| type_override.deserialize.as_ref()) {
+        Some(deserialize) => writeln!(w, "const {} = {deserialize};", field.name),
+        None => write_deserialize(w, Some(&field.name), &field.value, config),
+    }
+}
+
 fn write_deserialize(
     w: &mut dyn IndentWrite,
     field_name: Option<&str>,
@@ -771,6 +1020,27 @@ fn write_deserialize(
             }
         }
 
+        Format::Generic { name, args } => {
+            let type_name = name.format(ToUpperCamelCase::to_upper_camel_case, ".");
+            let callee = if has_standalone_functions(&type_name, config) {
+                format!("deserialize{type_name}")
+            } else {
+                format!("{type_name}.deserialize")
+            };
+            if let Some(name) = field_name {
+                write!(w, "const {name} = {callee}(deserializer")?;
+            } else {
+                write!(w, "return {callee}(deserializer")?;
+            }
+            for arg in args {
+                write!(w, ", (deserializer) => ")?;
+                with_block(w, Newlines::OPEN, |w| {
+                    write_deserialize(w, None, arg, config)
+                })?;
+            }
+            writeln!(w, ");")
+        }
+
         Format::Option(inner) => {
             if let Some(name) = field_name {
                 write!(
@@ -913,7 +1183,9 @@ mod tests {
         indent::{IndentConfig, IndentedWriter},
         plugin::EmitContext,
     };
-    use crate::reflection::format::{ContainerFormat, Doc, EnumTagging, QualifiedTypeName};
+    use crate::reflection::format::{
+        ContainerFormat, ContainerMetadata, Doc, EnumTagging, QualifiedTypeName,
+    };
 
     fn make_config(features: &[Feature]) -> CodeGeneratorConfig {
         let mut cfg = CodeGeneratorConfig::new("test".to_string());
@@ -1002,7 +1274,7 @@ mod tests {
         let plugin = &BincodePlugin as &dyn EmitterPlugin<TypeScript>;
 
         let name = QualifiedTypeName::root("Foo".to_string());
-        let format = ContainerFormat::Struct(vec![], Doc::default());
+        let format = ContainerFormat::Struct(vec![], Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -1022,7 +1294,7 @@ mod tests {
         let plugin = &BincodePlugin as &dyn EmitterPlugin<TypeScript>;
 
         let name = QualifiedTypeName::root("Foo".to_string());
-        let format = ContainerFormat::UnitStruct(Doc::default());
+        let format = ContainerFormat::UnitStruct(Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -1052,7 +1324,7 @@ mod tests {
             Named::new(&Format::Str, "label".to_string()),
             Named::new(&Format::I32, "count".to_string()),
         ];
-        let format = ContainerFormat::Struct(fields, Doc::default());
+        let format = ContainerFormat::Struct(fields, Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -1094,7 +1366,12 @@ mod tests {
         variants.insert(1u32, Named::new(&VariantFormat::Unit, "Beta".to_string()));
 
         let name = QualifiedTypeName::root("MyEnum".to_string());
-        let format = ContainerFormat::Enum(variants, EnumTagging::External, Doc::default());
+        let format = ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::default(),
+            ContainerMetadata::default(),
+        );
         let container = Container {
             name: &name,
             format: &format,
@@ -1119,7 +1396,12 @@ mod tests {
         variants.insert(1u32, Named::new(&VariantFormat::Unit, "Beta".to_string()));
 
         let name = QualifiedTypeName::root("MyEnum".to_string());
-        let format = ContainerFormat::Enum(variants, EnumTagging::External, Doc::default());
+        let format = ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::default(),
+            ContainerMetadata::default(),
+        );
         let container = Container {
             name: &name,
             format: &format,
@@ -1153,7 +1435,7 @@ mod tests {
         let plugin = &BincodePlugin as &dyn EmitterPlugin<TypeScript>;
 
         let name = QualifiedTypeName::root("Foo".to_string());
-        let format = ContainerFormat::Struct(vec![], Doc::default());
+        let format = ContainerFormat::Struct(vec![], Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -33,7 +33,9 @@ use thiserror::Error;
 use crate::{
     Registry,
     generation::indent::IndentConfig,
-    reflection::format::{ContainerFormat, Format, FormatHolder, Namespace, VariantFormat},
+    reflection::format::{
+        ContainerFormat, Format, FormatHolder, Namespace, VariantFormat, Visibility,
+    },
 };
 
 /// Code generation options meant to be supported by all languages.
@@ -66,6 +68,16 @@ pub struct CodeGeneratorConfig {
     /// branch `Format::TypeName` serialization: enums use standalone
     /// `serializeX(value, serializer)` functions while structs use `.serialize(serializer)`.
     pub enum_type_names: BTreeSet<String>,
+    /// Names of all branded newtypes (`#[facet(fg::branded)]`) in the registry.
+    /// Populated by `update_from`. Used by plugins whose branded types have no methods of
+    /// their own (TypeScript) or are value types (C#).
+    pub branded_type_names: BTreeSet<String>,
+    /// The visibility of types and fields that don't set one with `#[facet(fg::public)]`.
+    ///
+    /// Defaults to [`Visibility::Public`]. Pass [`Visibility::Internal`] via
+    /// [`with_default_visibility`](Self::with_default_visibility) to keep generated types
+    /// out of the module's public API unless they opt in.
+    pub default_visibility: Visibility,
 }
 
 /// Container or leaf types in the registry that need a runtime support file
@@ -84,6 +96,10 @@ pub enum Feature {
     SetOfT,
     TupleArray,
     Uuid,
+    Timestamp,
+    Date,
+    Time,
+    Duration,
 }
 
 /// Track type definitions provided by other modules (key = `module`, value = `type names`).
@@ -111,6 +127,15 @@ pub enum Error {
     /// JSON serialization failed (e.g. when writing a TypeScript `package.json`).
     #[error("JSON serialization error: {0}")]
     Json(#[from] serde_json::Error),
+
+    /// A plugin can't encode a type of the registry (e.g. the bincode plugin an
+    /// untagged enum).
+    #[error("{plugin} can't encode `{type_name}`: {reason}")]
+    Unsupported {
+        plugin: String,
+        type_name: String,
+        reason: String,
+    },
 }
 
 /// Writes generated source code and runtime support files to disk.
@@ -149,6 +174,8 @@ impl CodeGeneratorConfig {
             referenced_namespaces: BTreeSet::new(),
             unit_variant_enums: BTreeSet::new(),
             enum_type_names: BTreeSet::new(),
+            branded_type_names: BTreeSet::new(),
+            default_visibility: Visibility::Public,
             indent: IndentConfig::Space(4),
         }
     }
@@ -176,6 +203,19 @@ impl CodeGeneratorConfig {
         self
     }
 
+    /// The visibility of types and fields without an explicit one.
+    #[must_use]
+    pub const fn with_default_visibility(mut self, visibility: Visibility) -> Self {
+        self.default_visibility = visibility;
+        self
+    }
+
+    /// The visibility of a declaration, given its explicit visibility (if any).
+    #[must_use]
+    pub fn visibility(&self, explicit: Option<Visibility>) -> Visibility {
+        explicit.unwrap_or(self.default_visibility)
+    }
+
     /// Container names provided by other modules.
     #[must_use]
     pub fn with_external_definitions(mut self, external_definitions: ExternalDefinitions) -> Self {
@@ -220,6 +260,18 @@ impl CodeGeneratorConfig {
                         Format::Uuid => {
                             self.features.insert(Feature::Uuid);
                         }
+                        Format::Timestamp => {
+                            self.features.insert(Feature::Timestamp);
+                        }
+                        Format::Date => {
+                            self.features.insert(Feature::Date);
+                        }
+                        Format::Time => {
+                            self.features.insert(Feature::Time);
+                        }
+                        Format::Duration => {
+                            self.features.insert(Feature::Duration);
+                        }
                         Format::Seq(..) => {
                             self.features.insert(Feature::ListOfT);
                         }
@@ -239,7 +291,11 @@ impl CodeGeneratorConfig {
                     }
 
                     // Track external namespaces actually referenced in format types.
-                    if let Format::TypeName(qualified_name) = f
+                    if let Format::TypeName(qualified_name)
+                    | Format::Generic {
+                        name: qualified_name,
+                        ..
+                    } = f
                         && let Namespace::Named(ns) = &qualified_name.namespace
                         && ns != &self.module_name
                     {
@@ -266,12 +322,19 @@ impl CodeGeneratorConfig {
                         Format::Str => "str",
                         Format::Bytes => "bytes",
                         Format::Uuid => "uuid",
+                        Format::Timestamp => "timestamp",
+                        Format::Date => "date",
+                        Format::Time => "time",
+                        Format::Duration => "duration",
                         Format::Option(_) => "option",
                         Format::Seq(_) | Format::Set(_) => "seq",
                         Format::Map { .. } => "map",
                         Format::Tuple(_) => "tuple",
                         Format::TupleArray { .. } => "list_tuple",
-                        Format::TypeName(_) | Format::Variable(_) => "",
+                        Format::TypeName(_)
+                        | Format::Generic { .. }
+                        | Format::TypeParameter(_)
+                        | Format::Variable(_) => "",
                     };
                     if !format_key.is_empty() {
                         self.used_format_types.insert(format_key.to_string());
@@ -290,7 +353,7 @@ impl CodeGeneratorConfig {
                 entry.push(name.name.clone());
             }
 
-            if let ContainerFormat::Enum(variants, _, _) = format {
+            if let ContainerFormat::Enum(variants, _, _, _) = format {
                 self.enum_type_names.insert(name.name.clone());
                 if variants
                     .values()
@@ -299,6 +362,10 @@ impl CodeGeneratorConfig {
                     self.unit_variant_enums.insert(name.name.clone());
                 }
             }
+
+            if format.is_branded() {
+                self.branded_type_names.insert(name.name.clone());
+            }
         }
     }
 }
@@ -348,7 +415,7 @@ impl ConfigBuilder {
 /// Where an external package can be found.
 #[derive(Debug, Clone, Serialize, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum PackageLocation {
-    /// Either a local file path or, for Kotlin, a dot-separated package name.
+    /// Either a local file path or, for Kotlin and Java, a dot-separated package name.
     Path(String),
     // The URL of a remote package.
     Url(String),
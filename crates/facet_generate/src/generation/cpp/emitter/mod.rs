@@ -0,0 +1,971 @@
+//! AST-to-C++ source rendering.
+//!
+//! This module implements [`Emitter<Cpp>`](super::super::Emitter) for each
+//! node type in the format AST, turning abstract type descriptions into C++
+//! declarations.
+//!
+//! # Emitter implementations
+//!
+//! | AST node | C++ output |
+//! |---|---|
+//! | [`Module`] | `#pragma once`, `#include`s and the opening of the `namespace` |
+//! | [`Container`] | A `struct`, or a `struct` holding a `std::variant` of a nested `struct` per variant |
+//! | [`Named<Format>`](Named) | A single data member |
+//! | [`Format`] | An inline type expression (`std::int32_t`, `std::vector<std::string>`, …) |
+//! | [`Doc`] | `///` comments |
+//!
+//! # C++ type mapping
+//!
+//! The [`Format`] emitter maps Rust/reflection types to C++17 equivalents —
+//! for example `u32` → `std::uint32_t`, `Seq(T)` → `std::vector<T>`,
+//! `Option(T)` → `std::optional<T>`, `Map` → `std::map<K, V>`, `Set` →
+//! `std::set<T>`, fixed-size arrays → `std::array<T, N>` and tuples →
+//! `std::tuple<A, B>`, and the types of the serde runtime for the rest:
+//! `i128` / `u128` → `serde::int128_t` / `serde::uint128_t`, `Uuid` →
+//! `serde::uuid` and `Duration` → `serde::duration`. `()` is a
+//! `std::monostate`, `char` a `char32_t`, and dates and times are held in the
+//! `std::string`s that they are serialized as.
+//!
+//! # Declarations
+//!
+//! Structs become `struct`s; newtype and tuple structs have `value` and
+//! `field0`, `field1`, … members. Enums become a `struct` with a nested
+//! `struct` per variant (e.g. `Shape::Circle`) and a `std::variant` of them as
+//! its `value`. Every type gets `==`, `!=` and `<` operators that compare its
+//! members in order, so that it can be an element of a `std::set` or a key of
+//! a `std::map`. Serialization proxies are declared as aliases of their
+//! format.
+//!
+//! A type can't hold a member of its own type, so each reference to a type of
+//! the same recursive group (a type that contains itself, or types that
+//! contain each other) is held in a `serde::value_ptr`, a copyable owning
+//! pointer. The [generator](super::CppCodeGenerator) declares the types of a
+//! group ahead of them.
+//!
+//! # Plugin-dependent output
+//!
+//! The [`Cpp`] language tag's [`EmitterPlugin`]s write their member functions
+//! into the body of each type, including each variant's `struct`.
+//!
+//! - `BincodePlugin` supplies `serialize` / `bincodeSerialize` member
+//!   functions and static `deserialize` / `bincodeDeserialize` ones.
+//! - With no plugins, only plain type declarations are emitted.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::{Result, Write as _},
+    sync::Arc,
+};
+
+use heck::ToUpperCamelCase;
+
+use crate::{
+    Registry,
+    generation::{
+        CodeGeneratorConfig, Container, Emitter,
+        indent::IndentWrite,
+        module::Module,
+        plugin::{
+            EmitContext, EmitterPlugin, VariantInfo, collect_from_plugins, type_annotations,
+            type_conformances,
+        },
+    },
+    reflection::format::{
+        ContainerFormat, DefaultValue, Deprecation, Doc, Format, FormatHolder, Language, Named,
+        Namespace, QualifiedTypeName, VariantFormat,
+    },
+};
+
+/// The header of the serde runtime, which every header includes.
+const SERDE_HEADER: &str = "serde/serde.hpp";
+
+/// The names that a data member can't have: C++'s keywords and alternative
+/// tokens, the member functions that the plugins generate, and the names of
+/// the parameters and namespaces that they refer to.
+const RESERVED: &[&str] = &[
+    "alignas",
+    "alignof",
+    "and",
+    "and_eq",
+    "asm",
+    "auto",
+    "bincodeDeserialize",
+    "bincodeSerialize",
+    "bitand",
+    "bitor",
+    "bool",
+    "break",
+    "case",
+    "catch",
+    "char",
+    "char16_t",
+    "char32_t",
+    "char8_t",
+    "class",
+    "co_await",
+    "co_return",
+    "co_yield",
+    "compl",
+    "concept",
+    "const",
+    "const_cast",
+    "consteval",
+    "constexpr",
+    "constinit",
+    "continue",
+    "decltype",
+    "default",
+    "delete",
+    "deserialize",
+    "deserializer",
+    "do",
+    "double",
+    "dynamic_cast",
+    "else",
+    "enum",
+    "explicit",
+    "export",
+    "extern",
+    "false",
+    "float",
+    "for",
+    "friend",
+    "goto",
+    "if",
+    "inline",
+    "int",
+    "long",
+    "mutable",
+    "namespace",
+    "new",
+    "noexcept",
+    "not",
+    "not_eq",
+    "nullptr",
+    "operator",
+    "or",
+    "or_eq",
+    "private",
+    "protected",
+    "public",
+    "register",
+    "reinterpret_cast",
+    "requires",
+    "return",
+    "serde",
+    "serialize",
+    "serializer",
+    "short",
+    "signed",
+    "sizeof",
+    "static",
+    "static_assert",
+    "static_cast",
+    "std",
+    "struct",
+    "switch",
+    "template",
+    "this",
+    "thread_local",
+    "throw",
+    "true",
+    "try",
+    "typedef",
+    "typeid",
+    "typename",
+    "union",
+    "unsigned",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "wchar_t",
+    "while",
+    "xor",
+    "xor_eq",
+];
+
+/// The length up to which a comparison of a type's members is written on one
+/// line.
+const MAX_LINE_LENGTH: usize = 100;
+
+/// Language tag for C++ code generation.
+///
+/// Passed as the `L` parameter to every [`Emitter<L>`](super::super::Emitter)
+/// call. Carries a plugin list that controls all encoding-specific behaviour.
+#[derive(Debug, Clone)]
+pub struct Cpp {
+    pub(crate) config: CodeGeneratorConfig,
+    pub(crate) plugins: Vec<Arc<dyn EmitterPlugin<Self>>>,
+    /// The recursive group of each type of the namespace that belongs to one.
+    groups: BTreeMap<String, BTreeSet<String>>,
+    /// The headers of the other namespaces that the declarations refer to.
+    headers: BTreeSet<String>,
+}
+
+impl Cpp {
+    /// Create a C++ language tag with no default plugins, for the namespace
+    /// named by the config's module name.
+    ///
+    /// Use [`with_plugin`](Self::with_plugin) to attach plugins.
+    #[must_use]
+    pub fn new(config: &CodeGeneratorConfig, registry: &Registry) -> Self {
+        let mut groups = BTreeMap::new();
+        for (group, recursive) in declaration_groups(registry) {
+            if recursive {
+                let members = group.iter().cloned().collect::<BTreeSet<_>>();
+                for name in group {
+                    groups.insert(name, members.clone());
+                }
+            }
+        }
+        let mut headers = BTreeSet::new();
+        for container in registry.values() {
+            let _ = container.visit(&mut |format| {
+                if let Format::TypeName(name) | Format::Generic { name, .. } = format
+                    && let Namespace::Named(namespace) = &name.namespace
+                {
+                    headers.insert(header_path(namespace));
+                }
+                Ok(())
+            });
+        }
+        Self {
+            config: config.clone(),
+            plugins: vec![],
+            groups,
+            headers,
+        }
+    }
+
+    /// Access the generator config.
+    #[must_use]
+    pub const fn config(&self) -> &CodeGeneratorConfig {
+        &self.config
+    }
+
+    /// Add a plugin to this language tag, returning the modified tag.
+    ///
+    /// Plugins are invoked in the order they are added.
+    #[must_use]
+    pub fn with_plugin(mut self, plugin: Arc<dyn EmitterPlugin<Self>>) -> Self {
+        self.plugins.push(plugin);
+        self
+    }
+
+    /// Access the plugin list.
+    #[must_use]
+    pub fn plugins(&self) -> &[Arc<dyn EmitterPlugin<Self>>] {
+        &self.plugins
+    }
+}
+
+impl Emitter<Cpp> for Module {
+    /// Writes the include guard, the `#include`s of the serde runtime, of the
+    /// plugins and of the other namespaces' headers, and opens the namespace.
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Cpp) -> Result<()> {
+        let config = self.config();
+        writeln!(w, "#pragma once")?;
+        writeln!(w)?;
+        let mut includes = BTreeSet::from([SERDE_HEADER.to_string()]);
+        includes.extend(collect_from_plugins(lang.plugins(), |p| p.imports(config)));
+        includes.extend(lang.headers.iter().cloned());
+        for include in includes {
+            writeln!(w, "#include \"{include}\"")?;
+        }
+        writeln!(w)?;
+        writeln!(w, "namespace {} {{", config.module_name())
+    }
+}
+
+impl Emitter<Cpp> for Container<'_> {
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Cpp) -> Result<()> {
+        let metadata = self.format.metadata();
+        let scope = Scope::new(self, lang);
+        let name = &self.name.name;
+        write_doc(w, self.format.doc(), None)?;
+        write_template(w, &metadata.type_params)?;
+        if metadata.proxy {
+            // Uses of the type have already been replaced by the proxy's format, so the alias
+            // needs no plugin code.
+            let ContainerFormat::NewTypeStruct(format, ..) = self.format else {
+                unreachable!("only newtype structs are proxies")
+            };
+            return writeln!(w, "using {name} = {};", scope.type_expr(format));
+        }
+
+        let ctx = EmitContext::top_level(self, &lang.config);
+        let mut attributes = vec![];
+        if let Some(deprecated) = &metadata.deprecated {
+            attributes.push(deprecated_attribute(deprecated));
+        }
+        attributes.extend(type_annotations(lang.plugins(), &ctx, Language::Cpp));
+        let conformances = type_conformances(lang.plugins(), &ctx, Language::Cpp);
+        let header = declaration_header(name, &attributes, &conformances);
+        match self.format {
+            ContainerFormat::Enum(variants, ..) => {
+                let mut sections = vec![];
+                for plugin in lang.plugins() {
+                    if plugin.has_type_body(&ctx) {
+                        let mut preamble = vec![];
+                        plugin.type_body_preamble(&mut w.child(&mut preamble), &ctx)?;
+                        sections.push(preamble);
+                    }
+                }
+                for (index, variant) in variants {
+                    let fields = variant_fields(&variant.value);
+                    let variant_ctx = EmitContext::for_variant(
+                        self,
+                        &lang.config,
+                        VariantInfo {
+                            name: &variant.name,
+                            index: *index as usize,
+                            format: &variant.value,
+                            fields: &fields,
+                            parent_name: name,
+                            parent_type_params: &metadata.type_params,
+                        },
+                    );
+                    let mut section = vec![];
+                    {
+                        let mut vw = w.child(&mut section);
+                        write_doc(&mut vw, &variant.doc, variant.metadata.deprecated.as_ref())?;
+                        let attributes =
+                            type_annotations(lang.plugins(), &variant_ctx, Language::Cpp);
+                        let conformances =
+                            type_conformances(lang.plugins(), &variant_ctx, Language::Cpp);
+                        let header = declaration_header(
+                            &variant_struct(&variant.name),
+                            &attributes,
+                            &conformances,
+                        );
+                        write_struct(&mut vw, &header, &scope, &fields, &variant_ctx, lang)?;
+                    }
+                    sections.push(section);
+                }
+                {
+                    let mut members = vec![];
+                    {
+                        let mut mw = w.child(&mut members);
+                        let alternatives = variants
+                            .values()
+                            .map(|variant| variant_struct(&variant.name))
+                            .collect::<Vec<_>>();
+                        writeln!(mw, "std::variant<{}> value;", alternatives.join(", "))?;
+                        writeln!(mw)?;
+                        write_operators(&mut mw, name, &["value".to_string()])?;
+                    }
+                    sections.push(members);
+                }
+                sections.extend(plugin_bodies(w, &ctx, lang)?);
+                write_declaration(w, &header, &sections)
+            }
+            _ => write_struct(w, &header, &scope, &ctx.fields(), &ctx, lang),
+        }
+    }
+}
+
+impl Emitter<Cpp> for Named<Format> {
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Cpp) -> Result<()> {
+        let scope = Scope::module(lang);
+        write!(w, "{} {}", scope.field_type(self), field_name(&self.name))
+    }
+}
+
+impl Emitter<Cpp> for Doc {
+    fn write<W: IndentWrite>(&self, w: &mut W, _lang: &Cpp) -> Result<()> {
+        write_doc(w, self, None)
+    }
+}
+
+impl Emitter<Cpp> for Format {
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Cpp) -> Result<()> {
+        write!(w, "{}", Scope::module(lang).type_expr(self))
+    }
+}
+
+/// How the code inside a type refers to other types.
+///
+/// A type's own name, and the names of its variants' `struct`s, hide the
+/// namespace's types of the same names inside it: those are then referred to
+/// by their qualified names. References to the types of the type's recursive
+/// group are held in a `serde::value_ptr`.
+#[derive(Debug, Clone)]
+pub(crate) struct Scope {
+    namespace: String,
+    own: String,
+    hidden: BTreeSet<String>,
+    group: BTreeSet<String>,
+}
+
+impl Scope {
+    /// The scope of the code inside `container`.
+    #[must_use]
+    pub(crate) fn new(container: &Container, lang: &Cpp) -> Self {
+        let name = &container.name.name;
+        let mut hidden = BTreeSet::from([name.clone()]);
+        if let ContainerFormat::Enum(variants, ..) = container.format {
+            hidden.extend(
+                variants
+                    .values()
+                    .map(|variant| variant_struct(&variant.name)),
+            );
+        }
+        Self {
+            namespace: lang.config.module_name().to_string(),
+            own: name.clone(),
+            hidden,
+            group: lang.groups.get(name).cloned().unwrap_or_default(),
+        }
+    }
+
+    /// The scope of code outside of any type, where no names are hidden.
+    #[must_use]
+    pub(crate) fn module(lang: &Cpp) -> Self {
+        Self {
+            namespace: lang.config.module_name().to_string(),
+            own: String::new(),
+            hidden: BTreeSet::new(),
+            group: BTreeSet::new(),
+        }
+    }
+
+    /// The name of a type as the generated code refers to it, e.g. `Child`,
+    /// or `example::other::Child` for a type of another namespace.
+    #[must_use]
+    pub(crate) fn type_name(&self, name: &QualifiedTypeName) -> String {
+        match &name.namespace {
+            Namespace::Root if name.name != self.own && self.hidden.contains(&name.name) => {
+                format!("{}::{}", self.namespace, name.name)
+            }
+            _ => name.format(ToString::to_string, "::"),
+        }
+    }
+
+    /// The C++ type of `format`, e.g. `std::vector<std::int32_t>`.
+    #[must_use]
+    pub(crate) fn type_expr(&self, format: &Format) -> String {
+        let list = |formats: &[Format]| {
+            formats
+                .iter()
+                .map(|format| self.type_expr(format))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match format {
+            Format::Variable(_variable) => unreachable!("placeholders should not get this far"),
+            Format::TypeName(name) => self.reference(name, self.type_name(name)),
+            Format::Generic { name, args } => {
+                self.reference(name, format!("{}<{}>", self.type_name(name), list(args)))
+            }
+            Format::TypeParameter(name) => name.clone(),
+            Format::Unit => "std::monostate".to_string(),
+            Format::Bool => "bool".to_string(),
+            Format::I8 => "std::int8_t".to_string(),
+            Format::I16 => "std::int16_t".to_string(),
+            Format::I32 => "std::int32_t".to_string(),
+            Format::I64 => "std::int64_t".to_string(),
+            Format::I128 => "serde::int128_t".to_string(),
+            Format::U8 => "std::uint8_t".to_string(),
+            Format::U16 => "std::uint16_t".to_string(),
+            Format::U32 => "std::uint32_t".to_string(),
+            Format::U64 => "std::uint64_t".to_string(),
+            Format::U128 => "serde::uint128_t".to_string(),
+            Format::F32 => "float".to_string(),
+            Format::F64 => "double".to_string(),
+            Format::Char => "char32_t".to_string(),
+            Format::Str | Format::Timestamp | Format::Date | Format::Time => {
+                "std::string".to_string()
+            }
+            Format::Bytes => "std::vector<std::uint8_t>".to_string(),
+            Format::Uuid => "serde::uuid".to_string(),
+            Format::Duration => "serde::duration".to_string(),
+            Format::Option(format) => format!("std::optional<{}>", self.type_expr(format)),
+            Format::Seq(format) => format!("std::vector<{}>", self.type_expr(format)),
+            Format::Set(format) => format!("std::set<{}>", self.type_expr(format)),
+            Format::Map { key, value } => {
+                format!(
+                    "std::map<{}, {}>",
+                    self.type_expr(key),
+                    self.type_expr(value)
+                )
+            }
+            Format::TupleArray { content, size } => {
+                format!("std::array<{}, {size}>", self.type_expr(content))
+            }
+            Format::Tuple(formats) => match formats.as_slice() {
+                [] => "std::monostate".to_string(),
+                // A single-element tuple is just the element itself
+                [format] => self.type_expr(format),
+                formats => format!("std::tuple<{}>", list(formats)),
+            },
+        }
+    }
+
+    /// The C++ type of a field: its `fg::override` type for C++ if it has
+    /// one.
+    #[must_use]
+    pub(crate) fn field_type(&self, field: &Named<Format>) -> String {
+        match field.metadata.type_override(Language::Cpp) {
+            Some(type_override) => type_override.ty.clone(),
+            None => self.type_expr(&field.value),
+        }
+    }
+
+    /// The type `expr` of a reference to `name`, held in a
+    /// `serde::value_ptr` if it is a type of the scope's recursive group.
+    fn reference(&self, name: &QualifiedTypeName, expr: String) -> String {
+        if name.namespace == Namespace::Root && self.group.contains(&name.name) {
+            format!("serde::value_ptr<{expr}>")
+        } else {
+            expr
+        }
+    }
+}
+
+/// The types of `registry`'s namespace in the order that its header declares
+/// them, each after the types that it contains: groups of types that contain
+/// each other, in the order of their names, and whether each group is
+/// recursive (a type that contains itself, or several types).
+///
+/// Only the references to the namespace's own types, which the generator has
+/// put in the root namespace, count: the headers of other namespaces are
+/// included before it.
+#[must_use]
+pub(crate) fn declaration_groups(registry: &Registry) -> Vec<(Vec<String>, bool)> {
+    let edges = registry
+        .iter()
+        .map(|(name, container)| {
+            let mut references = BTreeSet::new();
+            let _ = container.visit(&mut |format| {
+                if let Format::TypeName(reference)
+                | Format::Generic {
+                    name: reference, ..
+                } = format
+                    && reference.namespace == Namespace::Root
+                {
+                    references.insert(reference.name.clone());
+                }
+                Ok(())
+            });
+            (name.name.clone(), references)
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    let mut search = Tarjan {
+        edges: &edges,
+        index: BTreeMap::new(),
+        low: BTreeMap::new(),
+        stack: vec![],
+        groups: vec![],
+    };
+    for name in edges.keys() {
+        if !search.index.contains_key(name) {
+            search.connect(name);
+        }
+    }
+    search
+        .groups
+        .into_iter()
+        .map(|group| {
+            let recursive = group.len() > 1 || edges[&group[0]].contains(&group[0]);
+            (group, recursive)
+        })
+        .collect()
+}
+
+/// Tarjan's search for the strongly connected components of the graph of
+/// the types that contain each other, which finds each group after the groups
+/// that it contains.
+struct Tarjan<'a> {
+    edges: &'a BTreeMap<String, BTreeSet<String>>,
+    index: BTreeMap<String, usize>,
+    low: BTreeMap<String, usize>,
+    stack: Vec<String>,
+    groups: Vec<Vec<String>>,
+}
+
+impl Tarjan<'_> {
+    fn connect(&mut self, name: &str) {
+        let index = self.index.len();
+        self.index.insert(name.to_string(), index);
+        self.low.insert(name.to_string(), index);
+        self.stack.push(name.to_string());
+
+        let edges = self.edges;
+        for next in edges[name].iter().filter(|next| edges.contains_key(*next)) {
+            let low = if !self.index.contains_key(next) {
+                self.connect(next);
+                self.low[next]
+            } else if self.stack.contains(next) {
+                self.index[next]
+            } else {
+                continue;
+            };
+            if low < self.low[name] {
+                self.low.insert(name.to_string(), low);
+            }
+        }
+
+        if self.low[name] == index {
+            let position = self
+                .stack
+                .iter()
+                .position(|member| member == name)
+                .expect("the type is on the stack");
+            let mut group = self.stack.split_off(position);
+            group.sort();
+            self.groups.push(group);
+        }
+    }
+}
+
+/// Returns the bodies that the plugins write into the type of `ctx`, leaving
+/// out empty ones.
+fn plugin_bodies<W: IndentWrite>(w: &mut W, ctx: &EmitContext, lang: &Cpp) -> Result<Vec<Vec<u8>>> {
+    let mut bodies = vec![];
+    for plugin in lang.plugins() {
+        if plugin.has_type_body(ctx) {
+            let mut body = vec![];
+            plugin.type_body(&mut w.child(&mut body), ctx)?;
+            bodies.push(body);
+        }
+    }
+    Ok(bodies)
+}
+
+/// Writes a `struct` of `fields` with its comparison operators and the
+/// plugins' member functions.
+fn write_struct<W: IndentWrite>(
+    w: &mut W,
+    header: &str,
+    scope: &Scope,
+    fields: &[Named<Format>],
+    ctx: &EmitContext,
+    lang: &Cpp,
+) -> Result<()> {
+    let mut sections = vec![];
+    {
+        let mut members = vec![];
+        {
+            let mut mw = w.child(&mut members);
+            for field in fields {
+                write_doc(&mut mw, &field.doc, field.metadata.deprecated.as_ref())?;
+                for annotation in
+                    collect_from_plugins(lang.plugins(), |p| p.field_annotations(field, ctx))
+                {
+                    write!(mw, "{annotation} ")?;
+                }
+                write!(
+                    mw,
+                    "{} {}",
+                    scope.field_type(field),
+                    field_name(&field.name)
+                )?;
+                if let Some(default) = field_default(field) {
+                    write!(mw, " = {default}")?;
+                }
+                writeln!(mw, ";")?;
+            }
+            if !fields.is_empty() {
+                writeln!(mw)?;
+            }
+            let names = fields
+                .iter()
+                .map(|field| field_name(&field.name))
+                .collect::<Vec<_>>();
+            write_operators(&mut mw, ctx.name(), &names)?;
+        }
+        sections.push(members);
+    }
+    sections.extend(plugin_bodies(w, ctx, lang)?);
+    write_declaration(w, header, &sections)
+}
+
+/// Writes a declaration: its `header` (e.g. `struct Point`), and its body
+/// `sections`, which were written at no indentation, separated by blank
+/// lines.
+fn write_declaration<W: IndentWrite>(w: &mut W, header: &str, sections: &[Vec<u8>]) -> Result<()> {
+    writeln!(w, "{header} {{")?;
+    w.indent();
+    let sections = sections.iter().filter(|section| !section.is_empty());
+    for (i, section) in sections.enumerate() {
+        if i > 0 {
+            writeln!(w)?;
+        }
+        w.write_all(section)?;
+    }
+    w.unindent();
+    writeln!(w, "}};")
+}
+
+/// The head of a `struct` declaration, e.g.
+/// `struct [[deprecated]] Point : Base`.
+fn declaration_header(name: &str, attributes: &[String], bases: &[String]) -> String {
+    let mut header = String::from("struct ");
+    for attribute in attributes {
+        header.push_str(attribute);
+        header.push(' ');
+    }
+    header.push_str(name);
+    if !bases.is_empty() {
+        header.push_str(" : ");
+        header.push_str(&bases.join(", "));
+    }
+    header
+}
+
+/// Writes the `template` head of a generic declaration, or nothing.
+pub(crate) fn write_template<W: IndentWrite>(w: &mut W, type_params: &[String]) -> Result<()> {
+    if type_params.is_empty() {
+        return Ok(());
+    }
+    let params = type_params
+        .iter()
+        .map(|param| format!("typename {param}"))
+        .collect::<Vec<_>>();
+    writeln!(w, "template <{}>", params.join(", "))
+}
+
+/// Writes the `==`, `!=` and `<` operators of the type `name`, which compare
+/// its `members` in order.
+fn write_operators<W: IndentWrite>(w: &mut W, name: &str, members: &[String]) -> Result<()> {
+    for (op, empty) in [("==", "true"), ("!=", ""), ("<", "false")] {
+        if members.is_empty() && op != "!=" {
+            writeln!(
+                w,
+                "friend bool operator{op}(const {name} &, const {name} &) {{"
+            )?;
+            w.indent();
+            writeln!(w, "return {empty};")?;
+        } else {
+            writeln!(
+                w,
+                "friend bool operator{op}(const {name} &lhs, const {name} &rhs) {{"
+            )?;
+            w.indent();
+            match members {
+                _ if op == "!=" => writeln!(w, "return !(lhs == rhs);")?,
+                [member] => writeln!(w, "return lhs.{member} {op} rhs.{member};")?,
+                members => write_comparison(w, op, members)?,
+            }
+        }
+        w.unindent();
+        writeln!(w, "}}")?;
+    }
+    Ok(())
+}
+
+/// Writes the `return` of a comparison of several `members` with `op`, as a
+/// comparison of `std::tie`s: on one line if it fits, else with a line per
+/// `std::tie` if they fit, else with a line per member.
+fn write_comparison<W: IndentWrite>(w: &mut W, op: &str, members: &[String]) -> Result<()> {
+    let side = |side: &str| {
+        members
+            .iter()
+            .map(|member| format!("{side}.{member}"))
+            .collect::<Vec<_>>()
+    };
+    let (lhs, rhs) = (side("lhs"), side("rhs"));
+    let (lhs_tie, rhs_tie) = (
+        format!("std::tie({})", lhs.join(", ")),
+        format!("std::tie({})", rhs.join(", ")),
+    );
+    let line = format!("return {lhs_tie} {op} {rhs_tie};");
+    if line.len() + 8 <= MAX_LINE_LENGTH {
+        return writeln!(w, "{line}");
+    }
+    if lhs_tie.len() + 20 <= MAX_LINE_LENGTH {
+        writeln!(w, "return {lhs_tie} {op}")?;
+        w.indent();
+        writeln!(w, "{rhs_tie};")?;
+        w.unindent();
+        return Ok(());
+    }
+    for (i, side) in [lhs, rhs].iter().enumerate() {
+        match i {
+            0 => writeln!(w, "return std::tie(")?,
+            _ => writeln!(w, ") {op} std::tie(")?,
+        }
+        w.indent();
+        for (j, member) in side.iter().enumerate() {
+            let comma = if j + 1 < side.len() { "," } else { "" };
+            writeln!(w, "{member}{comma}")?;
+        }
+        w.unindent();
+    }
+    writeln!(w, ");")
+}
+
+/// The `[[deprecated]]` attribute, with the deprecation notice if there is
+/// one.
+fn deprecated_attribute(deprecated: &Deprecation) -> String {
+    match deprecated.message() {
+        Some(message) => format!("[[deprecated({})]]", string_literal(&message)),
+        None => "[[deprecated]]".to_string(),
+    }
+}
+
+/// Writes a `///` comment of `doc`, and a `@deprecated` line with the
+/// deprecation notice of a data member or variant if it is deprecated.
+///
+/// Data members and variants aren't marked `[[deprecated]]`: compilers warn
+/// about the uses of such a member in the operators and member functions of
+/// its own type.
+fn write_doc<W: IndentWrite>(w: &mut W, doc: &Doc, deprecated: Option<&Deprecation>) -> Result<()> {
+    for comment in doc.comments() {
+        if comment.trim().is_empty() {
+            writeln!(w, "///")?;
+        } else {
+            writeln!(w, "/// {comment}")?;
+        }
+    }
+    if let Some(deprecated) = deprecated {
+        if !doc.comments().is_empty() {
+            writeln!(w, "///")?;
+        }
+        match deprecated.message() {
+            Some(message) => writeln!(w, "/// @deprecated {message}")?,
+            None => writeln!(w, "/// @deprecated")?,
+        }
+    }
+    Ok(())
+}
+
+/// The C++ initializer of a field's default value, if it has one that can be
+/// written as a literal. Empty collections and `None` are what a
+/// value-initialized member holds already.
+#[must_use]
+pub(crate) fn field_default(field: &Named<Format>) -> Option<String> {
+    if field.metadata.type_override(Language::Cpp).is_some() {
+        return None;
+    }
+    let default = field.metadata.default.as_ref()?;
+    Some(match (default, &field.value) {
+        (DefaultValue::Bool(true), Format::Bool) => "true".to_string(),
+        (DefaultValue::Bool(false), Format::Bool) => "false".to_string(),
+        (DefaultValue::Integer(value), Format::I8 | Format::I16 | Format::I32 | Format::I64) => {
+            signed_literal(value.parse().ok()?)
+        }
+        (DefaultValue::Integer(value), Format::U8 | Format::U16 | Format::U32 | Format::U64) => {
+            format!("{value}u")
+        }
+        (DefaultValue::Integer(value), Format::I128) => {
+            let value: i128 = value.parse().ok()?;
+            format!(
+                "{{{}, {}u}}",
+                signed_literal((value >> 64) as i64),
+                value as u64
+            )
+        }
+        (DefaultValue::Integer(value), Format::U128) => {
+            let value: u128 = value.parse().ok()?;
+            format!("{{{}u, {}u}}", (value >> 64) as u64, value as u64)
+        }
+        (DefaultValue::Float(value), format @ (Format::F32 | Format::F64)) => {
+            let mut literal = value.clone();
+            if !literal.contains(['.', 'e', 'E']) {
+                literal.push_str(".0");
+            }
+            if *format == Format::F32 {
+                literal.push('f');
+            }
+            literal
+        }
+        (DefaultValue::Char(value), Format::Char) => char_literal(*value),
+        (DefaultValue::Str(value), Format::Str) => string_literal(value),
+        _ => return None,
+    })
+}
+
+/// A signed integer literal of `value`. The smallest `std::int64_t` is
+/// written as a subtraction, because its magnitude has no signed type.
+fn signed_literal(value: i64) -> String {
+    if value == i64::MIN {
+        format!("{} - 1", i64::MIN + 1)
+    } else {
+        value.to_string()
+    }
+}
+
+/// A C++ string literal of `text`, with its non-ASCII characters written as
+/// the octal escapes of their UTF-8 bytes, which don't depend on the
+/// compiler's execution character set.
+#[must_use]
+pub(crate) fn string_literal(text: &str) -> String {
+    let mut literal = String::from("\"");
+    for byte in text.bytes() {
+        match byte {
+            b'\\' => literal.push_str("\\\\"),
+            b'"' => literal.push_str("\\\""),
+            b'\n' => literal.push_str("\\n"),
+            b'\r' => literal.push_str("\\r"),
+            b'\t' => literal.push_str("\\t"),
+            b' '..=b'~' => literal.push(char::from(byte)),
+            byte => literal.push_str(&format!("\\{byte:03o}")),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// A `char32_t` literal of `c`, e.g. `U'a'`.
+fn char_literal(c: char) -> String {
+    let escaped = match c {
+        '\\' => "\\\\".to_string(),
+        '\'' => "\\'".to_string(),
+        '\n' => "\\n".to_string(),
+        '\r' => "\\r".to_string(),
+        '\t' => "\\t".to_string(),
+        ' '..='~' => c.to_string(),
+        c if c.is_ascii() => format!("\\{:03o}", c as u32),
+        c => format!("\\U{:08x}", c as u32),
+    };
+    format!("U'{escaped}'")
+}
+
+/// The data members of a variant's `struct`.
+#[must_use]
+pub(crate) fn variant_fields(variant: &VariantFormat) -> Vec<Named<Format>> {
+    match variant {
+        VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+        VariantFormat::Unit => vec![],
+        VariantFormat::NewType(format) => vec![Named::new(format.as_ref(), "value".to_string())],
+        VariantFormat::Tuple(formats) => formats
+            .iter()
+            .enumerate()
+            .map(|(i, format)| Named::new(format, format!("field{i}")))
+            .collect(),
+        VariantFormat::Struct(fields) => fields.clone(),
+    }
+}
+
+/// The name of a data member: the field's name, with a trailing `_` if it is
+/// reserved, e.g. `class_`.
+#[must_use]
+pub(crate) fn field_name(name: &str) -> String {
+    if RESERVED.contains(&name) {
+        format!("{name}_")
+    } else {
+        name.to_string()
+    }
+}
+
+/// The name of a variant's `struct`, e.g. `Circle`.
+#[must_use]
+pub(crate) fn variant_struct(variant: &str) -> String {
+    variant.to_upper_camel_case()
+}
+
+/// The path of the header that declares the C++ namespace `namespace`
+/// (relative to the `include` directory), e.g. `example/other.hpp` for
+/// `example::other`.
+#[must_use]
+pub(crate) fn header_path(namespace: &str) -> String {
+    format!("{}.hpp", namespace.replace("::", "/"))
+}
+
+#[cfg(test)]
+mod tests;
+#[cfg(test)]
+mod tests_bincode;
@@ -0,0 +1,1048 @@
+//! Snapshot tests for the C++ emitter — **no serialization**.
+//!
+//! Each test defines one or more Rust types annotated with `#[derive(Facet)]`,
+//! runs them through the [`emit!`] macro with no plugins, and asserts the
+//! generated C++ source against an [`insta`] inline snapshot.
+//!
+//! Because no plugins are configured, the output contains only plain type
+//! declarations (`struct`s, and `struct`s holding a `std::variant` of variant
+//! `struct`s) with their comparison operators, and no serialization member
+//! functions.
+//!
+//! # Coverage
+//!
+//! | Category | What is tested |
+//! |----------|----------------|
+//! | Structs | Unit structs, newtype wrappers, tuple structs, structs with primitive and user-defined fields |
+//! | Enums | Unit-only and data enums (a `std::variant` of nested `struct`s), generic enums |
+//! | Collections | `Vec`, `HashMap`, `BTreeSet`, fixed-size arrays, tuples |
+//! | Optional | `Option<T>` fields (`std::optional`) |
+//! | Recursion | Self-referencing and mutually recursive types held in `serde::value_ptr` |
+//! | Declarations | Branded newtypes, proxies, deprecation, defaults, keywords, name clashes with variants |
+//! | Namespaces | Multi-namespace generation via [`emit_two_modules!`] |
+
+#![allow(clippy::too_many_lines)]
+use std::collections::{BTreeSet, HashMap};
+
+use crate as fg;
+use facet::Facet;
+
+use super::*;
+use crate::{emit, emit_two_modules, generation::cpp::CppCodeGenerator};
+
+#[test]
+fn unit_struct() {
+    /// line 1
+    #[derive(Facet)]
+    /// line 2
+    struct UnitStruct;
+
+    let actual = emit!(UnitStruct as Cpp).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    /// line 1
+    /// line 2
+    struct UnitStruct {
+        friend bool operator==(const UnitStruct &, const UnitStruct &) {
+            return true;
+        }
+        friend bool operator!=(const UnitStruct &lhs, const UnitStruct &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const UnitStruct &, const UnitStruct &) {
+            return false;
+        }
+    };
+    ");
+}
+
+#[test]
+fn newtype_struct() {
+    #[derive(Facet)]
+    struct NewType(String);
+
+    let actual = emit!(NewType as Cpp).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    struct NewType {
+        std::string value;
+
+        friend bool operator==(const NewType &lhs, const NewType &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const NewType &lhs, const NewType &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const NewType &lhs, const NewType &rhs) {
+            return lhs.value < rhs.value;
+        }
+    };
+    ");
+}
+
+#[test]
+fn tuple_struct() {
+    #[derive(Facet)]
+    struct TupleStruct(String, i32);
+
+    let actual = emit!(TupleStruct as Cpp).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    struct TupleStruct {
+        std::string field0;
+        std::int32_t field1;
+
+        friend bool operator==(const TupleStruct &lhs, const TupleStruct &rhs) {
+            return std::tie(lhs.field0, lhs.field1) == std::tie(rhs.field0, rhs.field1);
+        }
+        friend bool operator!=(const TupleStruct &lhs, const TupleStruct &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const TupleStruct &lhs, const TupleStruct &rhs) {
+            return std::tie(lhs.field0, lhs.field1) < std::tie(rhs.field0, rhs.field1);
+        }
+    };
+    ");
+}
+
+#[test]
+fn struct_with_fields() {
+    #[derive(Facet)]
+    struct Inner {
+        value: u64,
+    }
+
+    /// A struct.
+    #[derive(Facet)]
+    struct Outer {
+        /// The name.
+        name: String,
+        flag: bool,
+        big: i128,
+        ratio: f32,
+        letter: char,
+        nickname: Option<String>,
+        inner: Inner,
+        tags: Vec<String>,
+        counts: HashMap<String, u32>,
+        ids: BTreeSet<u16>,
+        pair: (u8, String),
+        grid: [i16; 3],
+        nothing: (),
+    }
+
+    let actual = emit!(Outer as Cpp).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    struct Inner {
+        std::uint64_t value;
+
+        friend bool operator==(const Inner &lhs, const Inner &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const Inner &lhs, const Inner &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Inner &lhs, const Inner &rhs) {
+            return lhs.value < rhs.value;
+        }
+    };
+
+    /// A struct.
+    struct Outer {
+        /// The name.
+        std::string name;
+        bool flag;
+        serde::int128_t big;
+        float ratio;
+        char32_t letter;
+        std::optional<std::string> nickname;
+        Inner inner;
+        std::vector<std::string> tags;
+        std::map<std::string, std::uint32_t> counts;
+        std::set<std::uint16_t> ids;
+        std::tuple<std::uint8_t, std::string> pair;
+        std::array<std::int16_t, 3> grid;
+        std::monostate nothing;
+
+        friend bool operator==(const Outer &lhs, const Outer &rhs) {
+            return std::tie(
+                lhs.name,
+                lhs.flag,
+                lhs.big,
+                lhs.ratio,
+                lhs.letter,
+                lhs.nickname,
+                lhs.inner,
+                lhs.tags,
+                lhs.counts,
+                lhs.ids,
+                lhs.pair,
+                lhs.grid,
+                lhs.nothing
+            ) == std::tie(
+                rhs.name,
+                rhs.flag,
+                rhs.big,
+                rhs.ratio,
+                rhs.letter,
+                rhs.nickname,
+                rhs.inner,
+                rhs.tags,
+                rhs.counts,
+                rhs.ids,
+                rhs.pair,
+                rhs.grid,
+                rhs.nothing
+            );
+        }
+        friend bool operator!=(const Outer &lhs, const Outer &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Outer &lhs, const Outer &rhs) {
+            return std::tie(
+                lhs.name,
+                lhs.flag,
+                lhs.big,
+                lhs.ratio,
+                lhs.letter,
+                lhs.nickname,
+                lhs.inner,
+                lhs.tags,
+                lhs.counts,
+                lhs.ids,
+                lhs.pair,
+                lhs.grid,
+                lhs.nothing
+            ) < std::tie(
+                rhs.name,
+                rhs.flag,
+                rhs.big,
+                rhs.ratio,
+                rhs.letter,
+                rhs.nickname,
+                rhs.inner,
+                rhs.tags,
+                rhs.counts,
+                rhs.ids,
+                rhs.pair,
+                rhs.grid,
+                rhs.nothing
+            );
+        }
+    };
+    ");
+}
+
+#[test]
+fn keywords() {
+    #[derive(Facet)]
+    struct Keywords {
+        class: String,
+        r#static: bool,
+        serialize: u32,
+        std: String,
+    }
+
+    let actual = emit!(Keywords as Cpp).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    struct Keywords {
+        std::string class_;
+        bool static_;
+        std::uint32_t serialize_;
+        std::string std_;
+
+        friend bool operator==(const Keywords &lhs, const Keywords &rhs) {
+            return std::tie(lhs.class_, lhs.static_, lhs.serialize_, lhs.std_) ==
+                std::tie(rhs.class_, rhs.static_, rhs.serialize_, rhs.std_);
+        }
+        friend bool operator!=(const Keywords &lhs, const Keywords &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Keywords &lhs, const Keywords &rhs) {
+            return std::tie(lhs.class_, lhs.static_, lhs.serialize_, lhs.std_) <
+                std::tie(rhs.class_, rhs.static_, rhs.serialize_, rhs.std_);
+        }
+    };
+    ");
+}
+
+#[test]
+fn unit_enum() {
+    /// A colour.
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Colour {
+        /// The first one.
+        Red,
+        Green,
+        LightBlue,
+    }
+
+    let actual = emit!(Colour as Cpp).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    /// A colour.
+    struct Colour {
+        /// The first one.
+        struct Red {
+            friend bool operator==(const Red &, const Red &) {
+                return true;
+            }
+            friend bool operator!=(const Red &lhs, const Red &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Red &, const Red &) {
+                return false;
+            }
+        };
+
+        struct Green {
+            friend bool operator==(const Green &, const Green &) {
+                return true;
+            }
+            friend bool operator!=(const Green &lhs, const Green &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Green &, const Green &) {
+                return false;
+            }
+        };
+
+        struct LightBlue {
+            friend bool operator==(const LightBlue &, const LightBlue &) {
+                return true;
+            }
+            friend bool operator!=(const LightBlue &lhs, const LightBlue &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const LightBlue &, const LightBlue &) {
+                return false;
+            }
+        };
+
+        std::variant<Red, Green, LightBlue> value;
+
+        friend bool operator==(const Colour &lhs, const Colour &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const Colour &lhs, const Colour &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Colour &lhs, const Colour &rhs) {
+            return lhs.value < rhs.value;
+        }
+    };
+    ");
+}
+
+#[test]
+fn data_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Line(f64, f64),
+        Rectangle { width: f64, height: f64 },
+    }
+
+    let actual = emit!(Shape as Cpp).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    struct Shape {
+        struct Point {
+            friend bool operator==(const Point &, const Point &) {
+                return true;
+            }
+            friend bool operator!=(const Point &lhs, const Point &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Point &, const Point &) {
+                return false;
+            }
+        };
+
+        struct Circle {
+            double value;
+
+            friend bool operator==(const Circle &lhs, const Circle &rhs) {
+                return lhs.value == rhs.value;
+            }
+            friend bool operator!=(const Circle &lhs, const Circle &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Circle &lhs, const Circle &rhs) {
+                return lhs.value < rhs.value;
+            }
+        };
+
+        struct Line {
+            double field0;
+            double field1;
+
+            friend bool operator==(const Line &lhs, const Line &rhs) {
+                return std::tie(lhs.field0, lhs.field1) == std::tie(rhs.field0, rhs.field1);
+            }
+            friend bool operator!=(const Line &lhs, const Line &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Line &lhs, const Line &rhs) {
+                return std::tie(lhs.field0, lhs.field1) < std::tie(rhs.field0, rhs.field1);
+            }
+        };
+
+        struct Rectangle {
+            double width;
+            double height;
+
+            friend bool operator==(const Rectangle &lhs, const Rectangle &rhs) {
+                return std::tie(lhs.width, lhs.height) == std::tie(rhs.width, rhs.height);
+            }
+            friend bool operator!=(const Rectangle &lhs, const Rectangle &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Rectangle &lhs, const Rectangle &rhs) {
+                return std::tie(lhs.width, lhs.height) < std::tie(rhs.width, rhs.height);
+            }
+        };
+
+        std::variant<Point, Circle, Line, Rectangle> value;
+
+        friend bool operator==(const Shape &lhs, const Shape &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const Shape &lhs, const Shape &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Shape &lhs, const Shape &rhs) {
+            return lhs.value < rhs.value;
+        }
+    };
+    ");
+}
+
+#[test]
+fn variant_named_like_a_type() {
+    #[derive(Facet)]
+    struct Circle {
+        radius: f64,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Circle(Circle),
+        Empty,
+    }
+
+    let actual = emit!(Shape as Cpp).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    struct Circle {
+        double radius;
+
+        friend bool operator==(const Circle &lhs, const Circle &rhs) {
+            return lhs.radius == rhs.radius;
+        }
+        friend bool operator!=(const Circle &lhs, const Circle &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Circle &lhs, const Circle &rhs) {
+            return lhs.radius < rhs.radius;
+        }
+    };
+
+    struct Shape {
+        struct Circle {
+            test::Circle value;
+
+            friend bool operator==(const Circle &lhs, const Circle &rhs) {
+                return lhs.value == rhs.value;
+            }
+            friend bool operator!=(const Circle &lhs, const Circle &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Circle &lhs, const Circle &rhs) {
+                return lhs.value < rhs.value;
+            }
+        };
+
+        struct Empty {
+            friend bool operator==(const Empty &, const Empty &) {
+                return true;
+            }
+            friend bool operator!=(const Empty &lhs, const Empty &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Empty &, const Empty &) {
+                return false;
+            }
+        };
+
+        std::variant<Circle, Empty> value;
+
+        friend bool operator==(const Shape &lhs, const Shape &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const Shape &lhs, const Shape &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Shape &lhs, const Shape &rhs) {
+            return lhs.value < rhs.value;
+        }
+    };
+    ");
+}
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as Cpp).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    struct Listing {
+        Page<std::string> names;
+        Page<std::uint32_t> ids;
+
+        friend bool operator==(const Listing &lhs, const Listing &rhs) {
+            return std::tie(lhs.names, lhs.ids) == std::tie(rhs.names, rhs.ids);
+        }
+        friend bool operator!=(const Listing &lhs, const Listing &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Listing &lhs, const Listing &rhs) {
+            return std::tie(lhs.names, lhs.ids) < std::tie(rhs.names, rhs.ids);
+        }
+    };
+
+    template <typename T>
+    struct Page {
+        std::vector<T> items;
+        std::optional<T> next;
+        std::uint32_t total;
+
+        friend bool operator==(const Page &lhs, const Page &rhs) {
+            return std::tie(lhs.items, lhs.next, lhs.total) == std::tie(rhs.items, rhs.next, rhs.total);
+        }
+        friend bool operator!=(const Page &lhs, const Page &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Page &lhs, const Page &rhs) {
+            return std::tie(lhs.items, lhs.next, lhs.total) < std::tie(rhs.items, rhs.next, rhs.total);
+        }
+    };
+    ");
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E, retry: bool },
+        Pending,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        first: Outcome<String, i32>,
+        second: Outcome<bool, String>,
+    }
+
+    let actual = emit!(Response as Cpp).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    template <typename T, typename E>
+    struct Outcome {
+        struct Success {
+            T value;
+
+            friend bool operator==(const Success &lhs, const Success &rhs) {
+                return lhs.value == rhs.value;
+            }
+            friend bool operator!=(const Success &lhs, const Success &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Success &lhs, const Success &rhs) {
+                return lhs.value < rhs.value;
+            }
+        };
+
+        struct Failure {
+            E error;
+            bool retry;
+
+            friend bool operator==(const Failure &lhs, const Failure &rhs) {
+                return std::tie(lhs.error, lhs.retry) == std::tie(rhs.error, rhs.retry);
+            }
+            friend bool operator!=(const Failure &lhs, const Failure &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Failure &lhs, const Failure &rhs) {
+                return std::tie(lhs.error, lhs.retry) < std::tie(rhs.error, rhs.retry);
+            }
+        };
+
+        struct Pending {
+            friend bool operator==(const Pending &, const Pending &) {
+                return true;
+            }
+            friend bool operator!=(const Pending &lhs, const Pending &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Pending &, const Pending &) {
+                return false;
+            }
+        };
+
+        std::variant<Success, Failure, Pending> value;
+
+        friend bool operator==(const Outcome &lhs, const Outcome &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const Outcome &lhs, const Outcome &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Outcome &lhs, const Outcome &rhs) {
+            return lhs.value < rhs.value;
+        }
+    };
+
+    struct Response {
+        Outcome<std::string, std::int32_t> first;
+        Outcome<bool, std::string> second;
+
+        friend bool operator==(const Response &lhs, const Response &rhs) {
+            return std::tie(lhs.first, lhs.second) == std::tie(rhs.first, rhs.second);
+        }
+        friend bool operator!=(const Response &lhs, const Response &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Response &lhs, const Response &rhs) {
+            return std::tie(lhs.first, lhs.second) < std::tie(rhs.first, rhs.second);
+        }
+    };
+    ");
+}
+
+#[test]
+fn recursive_types() {
+    #[derive(Facet)]
+    struct Node {
+        value: i32,
+        children: Vec<Node>,
+        next: Option<Box<Node>>,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Expr {
+        Literal(i64),
+        Group(Box<Group>),
+    }
+
+    #[derive(Facet)]
+    struct Group {
+        items: Vec<Expr>,
+    }
+
+    #[derive(Facet)]
+    struct Tree {
+        root: Node,
+        expr: Expr,
+    }
+
+    let actual = emit!(Tree as Cpp).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    struct Expr {
+        struct Literal {
+            std::int64_t value;
+
+            friend bool operator==(const Literal &lhs, const Literal &rhs) {
+                return lhs.value == rhs.value;
+            }
+            friend bool operator!=(const Literal &lhs, const Literal &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Literal &lhs, const Literal &rhs) {
+                return lhs.value < rhs.value;
+            }
+        };
+
+        struct Group {
+            serde::value_ptr<test::Group> value;
+
+            friend bool operator==(const Group &lhs, const Group &rhs) {
+                return lhs.value == rhs.value;
+            }
+            friend bool operator!=(const Group &lhs, const Group &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Group &lhs, const Group &rhs) {
+                return lhs.value < rhs.value;
+            }
+        };
+
+        std::variant<Literal, Group> value;
+
+        friend bool operator==(const Expr &lhs, const Expr &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const Expr &lhs, const Expr &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Expr &lhs, const Expr &rhs) {
+            return lhs.value < rhs.value;
+        }
+    };
+
+    struct Group {
+        std::vector<serde::value_ptr<Expr>> items;
+
+        friend bool operator==(const Group &lhs, const Group &rhs) {
+            return lhs.items == rhs.items;
+        }
+        friend bool operator!=(const Group &lhs, const Group &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Group &lhs, const Group &rhs) {
+            return lhs.items < rhs.items;
+        }
+    };
+
+    struct Node {
+        std::int32_t value;
+        std::vector<serde::value_ptr<Node>> children;
+        std::optional<serde::value_ptr<Node>> next;
+
+        friend bool operator==(const Node &lhs, const Node &rhs) {
+            return std::tie(lhs.value, lhs.children, lhs.next) ==
+                std::tie(rhs.value, rhs.children, rhs.next);
+        }
+        friend bool operator!=(const Node &lhs, const Node &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Node &lhs, const Node &rhs) {
+            return std::tie(lhs.value, lhs.children, lhs.next) <
+                std::tie(rhs.value, rhs.children, rhs.next);
+        }
+    };
+
+    struct Tree {
+        Node root;
+        Expr expr;
+
+        friend bool operator==(const Tree &lhs, const Tree &rhs) {
+            return std::tie(lhs.root, lhs.expr) == std::tie(rhs.root, rhs.expr);
+        }
+        friend bool operator!=(const Tree &lhs, const Tree &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Tree &lhs, const Tree &rhs) {
+            return std::tie(lhs.root, lhs.expr) < std::tie(rhs.root, rhs.expr);
+        }
+    };
+    ");
+}
+
+#[test]
+fn branded_newtype() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    let actual = emit!(UserId as Cpp).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    struct UserId {
+        std::string value;
+
+        friend bool operator==(const UserId &lhs, const UserId &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const UserId &lhs, const UserId &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const UserId &lhs, const UserId &rhs) {
+            return lhs.value < rhs.value;
+        }
+    };
+    ");
+}
+
+#[test]
+fn serialized_as_proxy() {
+    #[derive(Facet)]
+    #[facet(fg::serialized_as = "String")]
+    struct ItemId(u64);
+
+    #[derive(Facet)]
+    struct Item {
+        id: ItemId,
+    }
+
+    let actual = emit!(Item as Cpp).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    struct Item {
+        std::string id;
+
+        friend bool operator==(const Item &lhs, const Item &rhs) {
+            return lhs.id == rhs.id;
+        }
+        friend bool operator!=(const Item &lhs, const Item &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Item &lhs, const Item &rhs) {
+            return lhs.id < rhs.id;
+        }
+    };
+
+    using ItemId = std::string;
+    ");
+}
+
+#[test]
+fn deprecated() {
+    #[derive(Facet)]
+    #[facet(fg::deprecated(since = "0.2.0", note = "Use `Circle` instead"))]
+    struct Round {
+        /// The radius.
+        #[facet(fg::deprecated)]
+        radius: f64,
+    }
+
+    let actual = emit!(Round as Cpp).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    struct [[deprecated("Use `Circle` instead (since 0.2.0)")]] Round {
+        /// The radius.
+        ///
+        /// @deprecated
+        double radius;
+
+        friend bool operator==(const Round &lhs, const Round &rhs) {
+            return lhs.radius == rhs.radius;
+        }
+        friend bool operator!=(const Round &lhs, const Round &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Round &lhs, const Round &rhs) {
+            return lhs.radius < rhs.radius;
+        }
+    };
+    "#);
+}
+
+#[test]
+fn defaults() {
+    #[derive(Facet)]
+    struct Settings {
+        #[facet(default = "$HOME\n\"é\"".to_string())]
+        directory: String,
+        #[facet(default = 30)]
+        timeout: u64,
+        #[facet(default = i64::MIN)]
+        offset: i64,
+        #[facet(default = -1)]
+        big: i128,
+        #[facet(default = 2.0f32)]
+        ratio: f32,
+        #[facet(default = 'é')]
+        letter: char,
+        #[facet(default = true)]
+        enabled: bool,
+        #[facet(default)]
+        counts: HashMap<String, u32>,
+        retries: u32,
+    }
+
+    let actual = emit!(Settings as Cpp).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    struct Settings {
+        std::string directory = "$HOME\n\"\303\251\"";
+        std::uint64_t timeout = 30u;
+        std::int64_t offset = -9223372036854775807 - 1;
+        serde::int128_t big = {-1, 18446744073709551615u};
+        float ratio = 2.0f;
+        char32_t letter = U'\U000000e9';
+        bool enabled = true;
+        std::map<std::string, std::uint32_t> counts;
+        std::uint32_t retries;
+
+        friend bool operator==(const Settings &lhs, const Settings &rhs) {
+            return std::tie(
+                lhs.directory,
+                lhs.timeout,
+                lhs.offset,
+                lhs.big,
+                lhs.ratio,
+                lhs.letter,
+                lhs.enabled,
+                lhs.counts,
+                lhs.retries
+            ) == std::tie(
+                rhs.directory,
+                rhs.timeout,
+                rhs.offset,
+                rhs.big,
+                rhs.ratio,
+                rhs.letter,
+                rhs.enabled,
+                rhs.counts,
+                rhs.retries
+            );
+        }
+        friend bool operator!=(const Settings &lhs, const Settings &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Settings &lhs, const Settings &rhs) {
+            return std::tie(
+                lhs.directory,
+                lhs.timeout,
+                lhs.offset,
+                lhs.big,
+                lhs.ratio,
+                lhs.letter,
+                lhs.enabled,
+                lhs.counts,
+                lhs.retries
+            ) < std::tie(
+                rhs.directory,
+                rhs.timeout,
+                rhs.offset,
+                rhs.big,
+                rhs.ratio,
+                rhs.letter,
+                rhs.enabled,
+                rhs.counts,
+                rhs.retries
+            );
+        }
+    };
+    "#);
+}
+
+#[test]
+fn many_fields() {
+    #[derive(Facet)]
+    struct Address {
+        street_name: String,
+        house_number: u32,
+        postal_code: String,
+        city_name: String,
+    }
+
+    let actual = emit!(Address as Cpp).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    struct Address {
+        std::string street_name;
+        std::uint32_t house_number;
+        std::string postal_code;
+        std::string city_name;
+
+        friend bool operator==(const Address &lhs, const Address &rhs) {
+            return std::tie(lhs.street_name, lhs.house_number, lhs.postal_code, lhs.city_name) ==
+                std::tie(rhs.street_name, rhs.house_number, rhs.postal_code, rhs.city_name);
+        }
+        friend bool operator!=(const Address &lhs, const Address &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Address &lhs, const Address &rhs) {
+            return std::tie(lhs.street_name, lhs.house_number, lhs.postal_code, lhs.city_name) <
+                std::tie(rhs.street_name, rhs.house_number, rhs.postal_code, rhs.city_name);
+        }
+    };
+    ");
+}
+
+#[test]
+fn two_modules() {
+    mod other {
+        use crate as fg;
+        use facet::Facet;
+
+        #[derive(Facet)]
+        #[facet(fg::namespace = "other")]
+        pub struct Child {
+            value: i32,
+        }
+    }
+
+    #[derive(Facet)]
+    struct Parent {
+        other_child: other::Child,
+    }
+
+    let (other, root) = emit_two_modules!(CppCodeGenerator, Parent, "root");
+    insta::assert_snapshot!(other, @r#"
+    #pragma once
+
+    #include "serde/serde.hpp"
+
+    namespace other {
+
+    struct Child {
+        std::int32_t value;
+
+        friend bool operator==(const Child &lhs, const Child &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const Child &lhs, const Child &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Child &lhs, const Child &rhs) {
+            return lhs.value < rhs.value;
+        }
+    };
+
+    } // namespace other
+    "#);
+    insta::assert_snapshot!(root, @r#"
+    #pragma once
+
+    #include "other.hpp"
+    #include "serde/serde.hpp"
+
+    namespace root {
+
+    struct Parent {
+        other::Child other_child;
+
+        friend bool operator==(const Parent &lhs, const Parent &rhs) {
+            return lhs.other_child == rhs.other_child;
+        }
+        friend bool operator!=(const Parent &lhs, const Parent &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Parent &lhs, const Parent &rhs) {
+            return lhs.other_child < rhs.other_child;
+        }
+    };
+
+    } // namespace root
+    "#);
+}
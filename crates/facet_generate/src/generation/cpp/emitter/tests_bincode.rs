@@ -0,0 +1,622 @@
+//! Snapshot tests for the C++ emitter — **Bincode encoding**.
+//!
+//! Mirrors the structure of [`tests`](super::tests) but uses `BincodePlugin`,
+//! so that every type gets `serialize` / `bincodeSerialize` member functions
+//! and static `deserialize` / `bincodeDeserialize` ones, templated on the
+//! runtime's serializer and deserializer.
+//!
+//! These tests verify field ordering, container depth tracking, variant
+//! indices, the catch-all of unknown variants and `fg::override` code.
+
+#![allow(clippy::too_many_lines)]
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::{self as fg, generation::bincode::BincodePlugin};
+use facet::Facet;
+
+use super::*;
+use crate::emit;
+
+#[test]
+fn unit_struct() {
+    #[derive(Facet)]
+    struct UnitStruct;
+
+    let actual = emit!(UnitStruct as Cpp with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    struct UnitStruct {
+        friend bool operator==(const UnitStruct &, const UnitStruct &) {
+            return true;
+        }
+        friend bool operator!=(const UnitStruct &lhs, const UnitStruct &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const UnitStruct &, const UnitStruct &) {
+            return false;
+        }
+
+        template <typename Serializer>
+        void serialize(Serializer &) const {}
+
+        template <typename Deserializer>
+        static UnitStruct deserialize(Deserializer &) { return UnitStruct{}; }
+
+        std::vector<std::uint8_t> bincodeSerialize() const {
+            return serde::bincode_serialize(*this);
+        }
+
+        static UnitStruct bincodeDeserialize(const std::vector<std::uint8_t> &input) {
+            return serde::bincode_deserialize<UnitStruct>(input);
+        }
+    };
+    ");
+}
+
+#[test]
+fn newtype_struct() {
+    #[derive(Facet)]
+    struct NewType(Vec<u8>);
+
+    let actual = emit!(NewType as Cpp with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    struct NewType {
+        std::vector<std::uint8_t> value;
+
+        friend bool operator==(const NewType &lhs, const NewType &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const NewType &lhs, const NewType &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const NewType &lhs, const NewType &rhs) {
+            return lhs.value < rhs.value;
+        }
+
+        template <typename Serializer>
+        void serialize(Serializer &serializer) const {
+            serializer.increase_container_depth();
+            serde::serialize(value, serializer);
+            serializer.decrease_container_depth();
+        }
+
+        template <typename Deserializer>
+        static NewType deserialize(Deserializer &deserializer) {
+            deserializer.increase_container_depth();
+            NewType obj{};
+            obj.value = serde::deserialize<decltype(obj.value)>(deserializer);
+            deserializer.decrease_container_depth();
+            return obj;
+        }
+
+        std::vector<std::uint8_t> bincodeSerialize() const {
+            return serde::bincode_serialize(*this);
+        }
+
+        static NewType bincodeDeserialize(const std::vector<std::uint8_t> &input) {
+            return serde::bincode_deserialize<NewType>(input);
+        }
+    };
+    ");
+}
+
+#[test]
+fn struct_with_fields() {
+    #[derive(Facet)]
+    struct Inner {
+        value: u64,
+    }
+
+    #[derive(Facet)]
+    struct Outer {
+        name: String,
+        inner: Inner,
+        nickname: Option<String>,
+        counts: BTreeMap<String, (u8, i16)>,
+    }
+
+    let actual = emit!(Outer as Cpp with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    struct Inner {
+        std::uint64_t value;
+
+        friend bool operator==(const Inner &lhs, const Inner &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const Inner &lhs, const Inner &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Inner &lhs, const Inner &rhs) {
+            return lhs.value < rhs.value;
+        }
+
+        template <typename Serializer>
+        void serialize(Serializer &serializer) const {
+            serializer.increase_container_depth();
+            serde::serialize(value, serializer);
+            serializer.decrease_container_depth();
+        }
+
+        template <typename Deserializer>
+        static Inner deserialize(Deserializer &deserializer) {
+            deserializer.increase_container_depth();
+            Inner obj{};
+            obj.value = serde::deserialize<decltype(obj.value)>(deserializer);
+            deserializer.decrease_container_depth();
+            return obj;
+        }
+
+        std::vector<std::uint8_t> bincodeSerialize() const {
+            return serde::bincode_serialize(*this);
+        }
+
+        static Inner bincodeDeserialize(const std::vector<std::uint8_t> &input) {
+            return serde::bincode_deserialize<Inner>(input);
+        }
+    };
+
+    struct Outer {
+        std::string name;
+        Inner inner;
+        std::optional<std::string> nickname;
+        std::map<std::string, std::tuple<std::uint8_t, std::int16_t>> counts;
+
+        friend bool operator==(const Outer &lhs, const Outer &rhs) {
+            return std::tie(lhs.name, lhs.inner, lhs.nickname, lhs.counts) ==
+                std::tie(rhs.name, rhs.inner, rhs.nickname, rhs.counts);
+        }
+        friend bool operator!=(const Outer &lhs, const Outer &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Outer &lhs, const Outer &rhs) {
+            return std::tie(lhs.name, lhs.inner, lhs.nickname, lhs.counts) <
+                std::tie(rhs.name, rhs.inner, rhs.nickname, rhs.counts);
+        }
+
+        template <typename Serializer>
+        void serialize(Serializer &serializer) const {
+            serializer.increase_container_depth();
+            serde::serialize(name, serializer);
+            serde::serialize(inner, serializer);
+            serde::serialize(nickname, serializer);
+            serde::serialize(counts, serializer);
+            serializer.decrease_container_depth();
+        }
+
+        template <typename Deserializer>
+        static Outer deserialize(Deserializer &deserializer) {
+            deserializer.increase_container_depth();
+            Outer obj{};
+            obj.name = serde::deserialize<decltype(obj.name)>(deserializer);
+            obj.inner = serde::deserialize<decltype(obj.inner)>(deserializer);
+            obj.nickname = serde::deserialize<decltype(obj.nickname)>(deserializer);
+            obj.counts = serde::deserialize<decltype(obj.counts)>(deserializer);
+            deserializer.decrease_container_depth();
+            return obj;
+        }
+
+        std::vector<std::uint8_t> bincodeSerialize() const {
+            return serde::bincode_serialize(*this);
+        }
+
+        static Outer bincodeDeserialize(const std::vector<std::uint8_t> &input) {
+            return serde::bincode_deserialize<Outer>(input);
+        }
+    };
+    ");
+}
+
+#[test]
+fn data_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Rectangle { width: f64, height: f64 },
+    }
+
+    let actual = emit!(Shape as Cpp with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    struct Shape {
+        struct Point {
+            friend bool operator==(const Point &, const Point &) {
+                return true;
+            }
+            friend bool operator!=(const Point &lhs, const Point &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Point &, const Point &) {
+                return false;
+            }
+
+            template <typename Serializer>
+            void serialize(Serializer &serializer) const {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(0);
+                serializer.decrease_container_depth();
+            }
+        };
+
+        struct Circle {
+            double value;
+
+            friend bool operator==(const Circle &lhs, const Circle &rhs) {
+                return lhs.value == rhs.value;
+            }
+            friend bool operator!=(const Circle &lhs, const Circle &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Circle &lhs, const Circle &rhs) {
+                return lhs.value < rhs.value;
+            }
+
+            template <typename Serializer>
+            void serialize(Serializer &serializer) const {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(1);
+                serde::serialize(value, serializer);
+                serializer.decrease_container_depth();
+            }
+        };
+
+        struct Rectangle {
+            double width;
+            double height;
+
+            friend bool operator==(const Rectangle &lhs, const Rectangle &rhs) {
+                return std::tie(lhs.width, lhs.height) == std::tie(rhs.width, rhs.height);
+            }
+            friend bool operator!=(const Rectangle &lhs, const Rectangle &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Rectangle &lhs, const Rectangle &rhs) {
+                return std::tie(lhs.width, lhs.height) < std::tie(rhs.width, rhs.height);
+            }
+
+            template <typename Serializer>
+            void serialize(Serializer &serializer) const {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(2);
+                serde::serialize(width, serializer);
+                serde::serialize(height, serializer);
+                serializer.decrease_container_depth();
+            }
+        };
+
+        std::variant<Point, Circle, Rectangle> value;
+
+        friend bool operator==(const Shape &lhs, const Shape &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const Shape &lhs, const Shape &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Shape &lhs, const Shape &rhs) {
+            return lhs.value < rhs.value;
+        }
+
+        template <typename Serializer>
+        void serialize(Serializer &serializer) const {
+            std::visit([&serializer](const auto &variant) { variant.serialize(serializer); }, value);
+        }
+
+        template <typename Deserializer>
+        static Shape deserialize(Deserializer &deserializer) {
+            deserializer.increase_container_depth();
+            std::uint32_t index = deserializer.deserialize_variant_index();
+            Shape obj{};
+            switch (index) {
+                case 0:
+                    obj.value = Point{};
+                    break;
+                case 1: {
+                    Circle variant{};
+                    variant.value = serde::deserialize<decltype(variant.value)>(deserializer);
+                    obj.value = std::move(variant);
+                    break;
+                }
+                case 2: {
+                    Rectangle variant{};
+                    variant.width = serde::deserialize<decltype(variant.width)>(deserializer);
+                    variant.height = serde::deserialize<decltype(variant.height)>(deserializer);
+                    obj.value = std::move(variant);
+                    break;
+                }
+                default:
+                    throw serde::deserialization_error("Unknown variant index for Shape: " + std::to_string(index));
+            }
+            deserializer.decrease_container_depth();
+            return obj;
+        }
+
+        std::vector<std::uint8_t> bincodeSerialize() const {
+            return serde::bincode_serialize(*this);
+        }
+
+        static Shape bincodeDeserialize(const std::vector<std::uint8_t> &input) {
+            return serde::bincode_deserialize<Shape>(input);
+        }
+    };
+    "#);
+}
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as Cpp with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    struct Listing {
+        Page<std::string> names;
+        Page<std::uint32_t> ids;
+
+        friend bool operator==(const Listing &lhs, const Listing &rhs) {
+            return std::tie(lhs.names, lhs.ids) == std::tie(rhs.names, rhs.ids);
+        }
+        friend bool operator!=(const Listing &lhs, const Listing &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Listing &lhs, const Listing &rhs) {
+            return std::tie(lhs.names, lhs.ids) < std::tie(rhs.names, rhs.ids);
+        }
+
+        template <typename Serializer>
+        void serialize(Serializer &serializer) const {
+            serializer.increase_container_depth();
+            serde::serialize(names, serializer);
+            serde::serialize(ids, serializer);
+            serializer.decrease_container_depth();
+        }
+
+        template <typename Deserializer>
+        static Listing deserialize(Deserializer &deserializer) {
+            deserializer.increase_container_depth();
+            Listing obj{};
+            obj.names = serde::deserialize<decltype(obj.names)>(deserializer);
+            obj.ids = serde::deserialize<decltype(obj.ids)>(deserializer);
+            deserializer.decrease_container_depth();
+            return obj;
+        }
+
+        std::vector<std::uint8_t> bincodeSerialize() const {
+            return serde::bincode_serialize(*this);
+        }
+
+        static Listing bincodeDeserialize(const std::vector<std::uint8_t> &input) {
+            return serde::bincode_deserialize<Listing>(input);
+        }
+    };
+
+    template <typename T>
+    struct Page {
+        std::vector<T> items;
+        std::uint32_t total;
+
+        friend bool operator==(const Page &lhs, const Page &rhs) {
+            return std::tie(lhs.items, lhs.total) == std::tie(rhs.items, rhs.total);
+        }
+        friend bool operator!=(const Page &lhs, const Page &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Page &lhs, const Page &rhs) {
+            return std::tie(lhs.items, lhs.total) < std::tie(rhs.items, rhs.total);
+        }
+
+        template <typename Serializer>
+        void serialize(Serializer &serializer) const {
+            serializer.increase_container_depth();
+            serde::serialize(items, serializer);
+            serde::serialize(total, serializer);
+            serializer.decrease_container_depth();
+        }
+
+        template <typename Deserializer>
+        static Page deserialize(Deserializer &deserializer) {
+            deserializer.increase_container_depth();
+            Page obj{};
+            obj.items = serde::deserialize<decltype(obj.items)>(deserializer);
+            obj.total = serde::deserialize<decltype(obj.total)>(deserializer);
+            deserializer.decrease_container_depth();
+            return obj;
+        }
+
+        std::vector<std::uint8_t> bincodeSerialize() const {
+            return serde::bincode_serialize(*this);
+        }
+
+        static Page bincodeDeserialize(const std::vector<std::uint8_t> &input) {
+            return serde::bincode_deserialize<Page>(input);
+        }
+    };
+    ");
+}
+
+#[test]
+fn unknown_variants() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Click {
+            x: i32,
+            y: i32,
+        },
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Event as Cpp with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    struct Event {
+        struct Click {
+            std::int32_t x;
+            std::int32_t y;
+
+            friend bool operator==(const Click &lhs, const Click &rhs) {
+                return std::tie(lhs.x, lhs.y) == std::tie(rhs.x, rhs.y);
+            }
+            friend bool operator!=(const Click &lhs, const Click &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Click &lhs, const Click &rhs) {
+                return std::tie(lhs.x, lhs.y) < std::tie(rhs.x, rhs.y);
+            }
+
+            template <typename Serializer>
+            void serialize(Serializer &serializer) const {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(0);
+                serde::serialize(x, serializer);
+                serde::serialize(y, serializer);
+                serializer.decrease_container_depth();
+            }
+        };
+
+        struct Unknown {
+            friend bool operator==(const Unknown &, const Unknown &) {
+                return true;
+            }
+            friend bool operator!=(const Unknown &lhs, const Unknown &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Unknown &, const Unknown &) {
+                return false;
+            }
+
+            template <typename Serializer>
+            void serialize(Serializer &serializer) const {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(1);
+                serializer.decrease_container_depth();
+            }
+        };
+
+        std::variant<Click, Unknown> value;
+
+        friend bool operator==(const Event &lhs, const Event &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const Event &lhs, const Event &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Event &lhs, const Event &rhs) {
+            return lhs.value < rhs.value;
+        }
+
+        template <typename Serializer>
+        void serialize(Serializer &serializer) const {
+            std::visit([&serializer](const auto &variant) { variant.serialize(serializer); }, value);
+        }
+
+        template <typename Deserializer>
+        static Event deserialize(Deserializer &deserializer) {
+            deserializer.increase_container_depth();
+            std::uint32_t index = deserializer.deserialize_variant_index();
+            Event obj{};
+            switch (index) {
+                case 0: {
+                    Click variant{};
+                    variant.x = serde::deserialize<decltype(variant.x)>(deserializer);
+                    variant.y = serde::deserialize<decltype(variant.y)>(deserializer);
+                    obj.value = std::move(variant);
+                    break;
+                }
+                case 1:
+                    obj.value = Unknown{};
+                    break;
+                default:
+                    obj.value = Unknown{};
+                    break;
+            }
+            deserializer.decrease_container_depth();
+            return obj;
+        }
+
+        std::vector<std::uint8_t> bincodeSerialize() const {
+            return serde::bincode_serialize(*this);
+        }
+
+        static Event bincodeDeserialize(const std::vector<std::uint8_t> &input) {
+            return serde::bincode_deserialize<Event>(input);
+        }
+    };
+    ");
+}
+
+#[test]
+fn type_override() {
+    #[derive(Facet)]
+    struct Price {
+        #[facet(fg::override(
+            lang = "cpp",
+            ty = "Decimal",
+            serialize = "serializer.serialize_str({value}.to_string())",
+            deserialize = "Decimal::parse(deserializer.deserialize_str())"
+        ))]
+        amount: String,
+        #[facet(fg::override(lang = "swift", ty = "CGFloat"))]
+        scale: f64,
+    }
+
+    let actual = emit!(Price as Cpp with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    struct Price {
+        Decimal amount;
+        double scale;
+
+        friend bool operator==(const Price &lhs, const Price &rhs) {
+            return std::tie(lhs.amount, lhs.scale) == std::tie(rhs.amount, rhs.scale);
+        }
+        friend bool operator!=(const Price &lhs, const Price &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Price &lhs, const Price &rhs) {
+            return std::tie(lhs.amount, lhs.scale) < std::tie(rhs.amount, rhs.scale);
+        }
+
+        template <typename Serializer>
+        void serialize(Serializer &serializer) const {
+            serializer.increase_container_depth();
+            serializer.serialize_str(amount.to_string());
+            serde::serialize(scale, serializer);
+            serializer.decrease_container_depth();
+        }
+
+        template <typename Deserializer>
+        static Price deserialize(Deserializer &deserializer) {
+            deserializer.increase_container_depth();
+            Price obj{};
+            obj.amount = Decimal::parse(deserializer.deserialize_str());
+            obj.scale = serde::deserialize<decltype(obj.scale)>(deserializer);
+            deserializer.decrease_container_depth();
+            return obj;
+        }
+
+        std::vector<std::uint8_t> bincodeSerialize() const {
+            return serde::bincode_serialize(*this);
+        }
+
+        static Price bincodeDeserialize(const std::vector<std::uint8_t> &input) {
+            return serde::bincode_deserialize<Price>(input);
+        }
+    };
+    ");
+}
@@ -0,0 +1,297 @@
+//! Top-level orchestrator for C++ code generation.
+//!
+//! [`CppCodeGenerator`] implements [`CodeGenerator`] and is the entry point
+//! for producing the header of a single C++ namespace from a [`Registry`]. It
+//! delegates writing to the emitter layer, after ordering the declarations so
+//! that each type follows the types that it contains: C++ needs a type to be
+//! complete before it can be held by value.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::{Result, Write},
+    sync::Arc,
+};
+
+use crate::{
+    Registry,
+    generation::{
+        CodeGenerator, CodeGeneratorConfig, Container, Emitter, Error,
+        cpp::{
+            emitter::{Cpp, declaration_groups, write_template},
+            variant_struct,
+        },
+        indent::IndentedWriter,
+        module::{self, Module},
+        plugin::{self, EmitterPlugin},
+    },
+    reflection::format::{
+        ContainerFormat, Format, FormatHolder, Language, Namespace, QualifiedTypeName,
+    },
+};
+
+/// Main configuration object for C++ code generation.
+///
+/// Wraps a [`CodeGeneratorConfig`] and implements [`CodeGenerator`] so it
+/// can be used by the installer pipeline.
+pub struct CppCodeGenerator<'a> {
+    /// Language-independent configuration.
+    pub(crate) config: &'a CodeGeneratorConfig,
+    /// Plugins that control encoding-specific code generation.
+    pub(crate) plugins: Vec<Arc<dyn EmitterPlugin<Cpp>>>,
+    /// The root namespace, which holds a nested namespace for each other
+    /// namespace, or `None` if namespaces are top-level namespaces of their
+    /// own.
+    pub(crate) package: Option<String>,
+}
+
+impl<'a> CodeGenerator<'a> for CppCodeGenerator<'a> {
+    fn new(config: &'a CodeGeneratorConfig) -> Self {
+        Self::new(config)
+    }
+
+    fn write_output<W: Write>(&mut self, writer: &mut W, registry: &Registry) -> Result<()> {
+        self.output(writer, registry)
+    }
+}
+
+impl<'a> CppCodeGenerator<'a> {
+    /// Create a C++ code generator with no plugins (plain types only).
+    ///
+    /// The declarations are in the namespace named by the config's module
+    /// name. Call [`with_package`](Self::with_package) to nest the namespaces
+    /// of other namespaces in a root namespace, and
+    /// [`with_plugins`](Self::with_plugins) to enable serialization.
+    #[must_use]
+    pub fn new(config: &'a CodeGeneratorConfig) -> Self {
+        Self {
+            config,
+            plugins: vec![],
+            package: None,
+        }
+    }
+
+    /// Set pre-built plugins, returning the modified generator.
+    #[must_use]
+    pub fn with_plugins(mut self, plugins: Vec<Arc<dyn EmitterPlugin<Cpp>>>) -> Self {
+        self.plugins = plugins;
+        self
+    }
+
+    /// Set the root namespace (e.g. `example`), which holds the root
+    /// namespace's types and a nested namespace for each other namespace
+    /// (e.g. `example::other`), returning the modified generator.
+    #[must_use]
+    pub fn with_package(mut self, package: &str) -> Self {
+        self.package = Some(package.to_string());
+        self
+    }
+
+    /// Produce the header that declares the types in `registry`: its
+    /// `#include`s, and the namespace that holds the declarations.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `out` fails, or if a plugin or C++
+    /// can't declare a type of the registry.
+    pub fn output(&self, out: &mut impl Write, registry: &Registry) -> Result<()> {
+        let checked = module::for_plugin_checks(registry, Language::Cpp);
+        let registry = &module::for_language(registry, Language::Cpp);
+        check_registry(registry).map_err(std::io::Error::other)?;
+        plugin::check_registry(&self.plugins, &checked).map_err(std::io::Error::other)?;
+
+        let mut config = self.config.clone();
+        config.update_from(registry);
+
+        let updated_registry = self.update_qualified_names(&config, registry);
+        let mut lang = Cpp::new(&config, &updated_registry);
+        for p in &self.plugins {
+            lang = lang.with_plugin(p.clone());
+        }
+
+        let w = &mut IndentedWriter::new(out, config.indent);
+        Module::new(&config).write(w, &lang)?;
+
+        let containers = updated_registry
+            .iter()
+            .map(|entry| (entry.0.name.as_str(), Container::from(entry)))
+            .collect::<BTreeMap<_, _>>();
+        for (group, recursive) in declaration_groups(&updated_registry) {
+            if recursive {
+                // The types of a recursive group refer to each other before they are complete.
+                writeln!(w)?;
+                for name in &group {
+                    let metadata = containers[name.as_str()].format.metadata();
+                    if !metadata.proxy {
+                        write_template(w, &metadata.type_params)?;
+                        writeln!(w, "struct {name};")?;
+                    }
+                }
+            }
+            for name in &group {
+                writeln!(w)?;
+                containers[name.as_str()].write(w, &lang)?;
+            }
+        }
+
+        writeln!(w)?;
+        writeln!(w, "}} // namespace {}", config.module_name())
+    }
+
+    /// Updates [`QualifiedTypeName`] instances for C++'s namespaces:
+    ///
+    /// 1. **Same-namespace type** — strip namespace to `Root` so it renders
+    ///    as a bare name (e.g. `Child`).
+    /// 2. **External package** — qualify it with the namespace that the
+    ///    package declares it in: its module name if it has one, or the
+    ///    namespace itself (e.g. `shared::Child`).
+    /// 3. **Type of another namespace** — qualify it with the nested namespace
+    ///    of the root namespace (e.g. `example::other::Child`), or with the
+    ///    namespace alone if there is no root namespace.
+    /// 4. **Root type, seen from another namespace** — qualify it with the
+    ///    root namespace.
+    fn update_qualified_names(
+        &self,
+        config: &CodeGeneratorConfig,
+        registry: &Registry,
+    ) -> Registry {
+        let module_name = config.module_name();
+        let nested = |namespace: &str| match &self.package {
+            Some(package) if package != namespace => format!("{package}::{namespace}"),
+            _ => namespace.to_string(),
+        };
+
+        let mut updated_registry = registry.clone();
+        for container_format in updated_registry.values_mut() {
+            let _ = container_format.visit_mut(&mut |format| {
+                if let Format::TypeName(qualified_name)
+                | Format::Generic {
+                    name: qualified_name,
+                    ..
+                } = format
+                {
+                    let name = qualified_name.name.clone();
+                    match &qualified_name.namespace {
+                        Namespace::Named(namespace)
+                            if namespace == module_name || nested(namespace) == module_name =>
+                        {
+                            *qualified_name = QualifiedTypeName::root(name);
+                        }
+                        Namespace::Named(namespace) => {
+                            let namespace = match config.external_packages.get(namespace) {
+                                Some(external) => external
+                                    .module_name
+                                    .clone()
+                                    .unwrap_or_else(|| namespace.clone()),
+                                None => nested(namespace),
+                            };
+                            *qualified_name = QualifiedTypeName::namespaced(namespace, name);
+                        }
+                        Namespace::Root => {
+                            if let Some(package) = &self.package
+                                && package != module_name
+                            {
+                                *qualified_name =
+                                    QualifiedTypeName::namespaced(package.clone(), name);
+                            }
+                        }
+                    }
+                }
+                Ok(())
+            });
+        }
+
+        updated_registry
+    }
+}
+
+/// Checks that the registry can be declared in C++: that every enum has a
+/// variant for its `std::variant` to hold, that no variant's `struct` has the
+/// name of its enum, which C++ forbids, and that no two namespaces refer to
+/// each other, because their headers would have to include each other.
+///
+/// # Errors
+///
+/// Returns [`Error::Unsupported`] for the first type that can't be declared.
+pub(crate) fn check_registry(registry: &Registry) -> std::result::Result<(), Error> {
+    let unsupported = |name: &QualifiedTypeName, reason| Error::Unsupported {
+        plugin: "cpp".to_string(),
+        type_name: name.format(ToString::to_string, "."),
+        reason,
+    };
+    for (name, container) in registry {
+        if let ContainerFormat::Enum(variants, ..) = container {
+            if variants.is_empty() {
+                return Err(unsupported(
+                    name,
+                    "an enum without variants can't be a `std::variant`".to_string(),
+                ));
+            }
+            if let Some(variant) = variants
+                .values()
+                .find(|variant| variant_struct(&variant.name) == name.name)
+            {
+                return Err(unsupported(
+                    name,
+                    format!(
+                        "the struct of variant `{}` would have the name of its enum",
+                        variant.name
+                    ),
+                ));
+            }
+        }
+    }
+
+    let mut includes = BTreeMap::<&Namespace, BTreeMap<&Namespace, &QualifiedTypeName>>::new();
+    for (name, container) in registry {
+        let _ = container.visit(&mut |format| {
+            if let Format::TypeName(reference)
+            | Format::Generic {
+                name: reference, ..
+            } = format
+                && reference.namespace != name.namespace
+            {
+                includes
+                    .entry(&name.namespace)
+                    .or_default()
+                    .entry(&reference.namespace)
+                    .or_insert(name);
+            }
+            Ok(())
+        });
+    }
+    for (namespace, included) in &includes {
+        for (other, name) in included {
+            let mut seen = BTreeSet::new();
+            let mut pending = vec![*other];
+            while let Some(next) = pending.pop() {
+                if next == *namespace {
+                    return Err(unsupported(
+                        name,
+                        format!(
+                            "{} and {} refer to each other, so their headers would include each other",
+                            describe(namespace),
+                            describe(other)
+                        ),
+                    ));
+                }
+                if seen.insert(next)
+                    && let Some(included) = includes.get(next)
+                {
+                    pending.extend(included.keys().copied());
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// How an error refers to a namespace, e.g. "namespace `other`".
+fn describe(namespace: &Namespace) -> String {
+    match namespace {
+        Namespace::Root => "the root namespace".to_string(),
+        Namespace::Named(namespace) => format!("namespace `{namespace}`"),
+    }
+}
+
+#[cfg(test)]
+mod tests;
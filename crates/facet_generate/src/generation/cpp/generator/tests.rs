@@ -0,0 +1,537 @@
+//! Unit tests for [`CppCodeGenerator`] — qualified-name resolution, the
+//! header's includes and namespace, declaration order and rejections.
+//!
+//! Tests build small [`Registry`](crate::Registry) values by hand (rather than
+//! via the `reflect!` macro) so that namespace and external-package
+//! configurations can be controlled precisely.
+//!
+//! # Coverage
+//!
+//! | Area | What is tested |
+//! |------|----------------|
+//! | Same-namespace stripping | `Named` namespace matching the namespace → bare name, also for generics |
+//! | Other namespaces | Nested namespaces of the root namespace (`example::other::Child`) and their headers, and the root namespace seen from them |
+//! | External packages | Their module name is the namespace that declares their types |
+//! | Declaration order | Types follow the types they contain; recursive groups are declared ahead |
+//! | Filtering | Variants skipped for C++ keep their bincode indices |
+//! | Rejection | Registries that C++ can't declare |
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use facet::Facet;
+
+use super::*;
+use crate::{
+    self as fg,
+    generation::{
+        CodeGeneratorConfig,
+        bincode::BincodePlugin,
+        config::{ExternalPackage, PackageLocation},
+        plugin::EmitterPlugin,
+    },
+    reflect,
+    reflection::format::{ContainerMetadata, Doc, FieldMetadata, Named},
+};
+
+fn registry_with_struct_field(field_type: Format) -> Registry {
+    let mut registry = Registry::new();
+    let fields = vec![Named {
+        name: "value".to_string(),
+        doc: Doc::new(),
+        value: field_type,
+        metadata: FieldMetadata::default(),
+    }];
+    registry.insert(
+        QualifiedTypeName::root("Holder".to_string()),
+        ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default()),
+    );
+    registry
+}
+
+fn first_field_type(registry: &Registry) -> &Format {
+    let (_, container) = registry.iter().next().unwrap();
+    let ContainerFormat::Struct(fields, _, _) = container else {
+        panic!("expected struct container");
+    };
+    &fields[0].value
+}
+
+fn render_output(
+    generator: CppCodeGenerator,
+    plugins: Vec<Arc<dyn EmitterPlugin<Cpp>>>,
+    registry: &Registry,
+) -> String {
+    let generator = generator.with_plugins(plugins);
+    let mut output = Vec::new();
+    generator.output(&mut output, registry).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn update_qualified_names_strips_same_namespace() {
+    let config = CodeGeneratorConfig::new("root".to_string());
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "root".to_string(),
+        "Child".to_string(),
+    )));
+
+    let updated = CppCodeGenerator::new(&config).update_qualified_names(&config, &registry);
+
+    let Format::TypeName(type_name) = first_field_type(&updated) else {
+        panic!("expected type name");
+    };
+    assert_eq!(type_name.namespace, Namespace::Root);
+    assert_eq!(type_name.name, "Child");
+}
+
+#[test]
+fn update_qualified_names_strips_same_namespace_generic() {
+    let config = CodeGeneratorConfig::new("example::other".to_string());
+    let registry = registry_with_struct_field(Format::Generic {
+        name: QualifiedTypeName::namespaced("other".to_string(), "Page".to_string()),
+        args: vec![Format::Str],
+    });
+
+    let updated = CppCodeGenerator::new(&config)
+        .with_package("example")
+        .update_qualified_names(&config, &registry);
+
+    let Format::Generic { name, .. } = first_field_type(&updated) else {
+        panic!("expected generic");
+    };
+    assert_eq!(name.namespace, Namespace::Root);
+}
+
+#[test]
+fn output_qualifies_other_namespace_with_nested_namespace() {
+    let config = CodeGeneratorConfig::new("example".to_string());
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "other".to_string(),
+        "Child".to_string(),
+    )));
+
+    let generator = CppCodeGenerator::new(&config).with_package("example");
+    let output = render_output(generator, vec![], &registry);
+    insta::assert_snapshot!(output, @r#"
+    #pragma once
+
+    #include "example/other.hpp"
+    #include "serde/serde.hpp"
+
+    namespace example {
+
+    struct Holder {
+        example::other::Child value;
+
+        friend bool operator==(const Holder &lhs, const Holder &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const Holder &lhs, const Holder &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Holder &lhs, const Holder &rhs) {
+            return lhs.value < rhs.value;
+        }
+    };
+
+    } // namespace example
+    "#);
+}
+
+#[test]
+fn output_qualifies_root_types_from_nested_namespace() {
+    let config = CodeGeneratorConfig::new("example::other".to_string());
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::root(
+        "Parent".to_string(),
+    )));
+
+    let generator = CppCodeGenerator::new(&config).with_package("example");
+    let output = render_output(generator, vec![], &registry);
+    insta::assert_snapshot!(output, @r#"
+    #pragma once
+
+    #include "example.hpp"
+    #include "serde/serde.hpp"
+
+    namespace example::other {
+
+    struct Holder {
+        example::Parent value;
+
+        friend bool operator==(const Holder &lhs, const Holder &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const Holder &lhs, const Holder &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Holder &lhs, const Holder &rhs) {
+            return lhs.value < rhs.value;
+        }
+    };
+
+    } // namespace example::other
+    "#);
+}
+
+#[test]
+fn output_qualifies_external_package() {
+    let mut config = CodeGeneratorConfig::new("example".to_string());
+    config.external_packages = BTreeMap::from([(
+        "other".to_string(),
+        ExternalPackage {
+            for_namespace: "other".to_string(),
+            location: PackageLocation::Path("../shared".to_string()),
+            module_name: Some("acme::shared".to_string()),
+            version: None,
+        },
+    )]);
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "other".to_string(),
+        "Child".to_string(),
+    )));
+
+    let generator = CppCodeGenerator::new(&config).with_package("example");
+    let output = render_output(generator, vec![], &registry);
+    insta::assert_snapshot!(output, @r#"
+    #pragma once
+
+    #include "acme/shared.hpp"
+    #include "serde/serde.hpp"
+
+    namespace example {
+
+    struct Holder {
+        acme::shared::Child value;
+
+        friend bool operator==(const Holder &lhs, const Holder &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const Holder &lhs, const Holder &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Holder &lhs, const Holder &rhs) {
+            return lhs.value < rhs.value;
+        }
+    };
+
+    } // namespace example
+    "#);
+}
+
+#[test]
+fn output_declares_contained_types_first() {
+    #[derive(Facet)]
+    struct Wrapper {
+        inner: Alpha,
+    }
+
+    #[derive(Facet)]
+    struct Alpha {
+        tree: Tree,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Tree {
+        Leaf(u8),
+        Forest(Forest),
+    }
+
+    #[derive(Facet)]
+    struct Forest {
+        trees: Vec<Tree>,
+    }
+
+    let registry = reflect!(Wrapper).unwrap();
+    let config = CodeGeneratorConfig::new("root".to_string());
+
+    let generator = CppCodeGenerator::new(&config);
+    let output = render_output(generator, vec![], &registry);
+    insta::assert_snapshot!(output, @r#"
+    #pragma once
+
+    #include "serde/serde.hpp"
+
+    namespace root {
+
+    struct Forest;
+    struct Tree;
+
+    struct Forest {
+        std::vector<serde::value_ptr<Tree>> trees;
+
+        friend bool operator==(const Forest &lhs, const Forest &rhs) {
+            return lhs.trees == rhs.trees;
+        }
+        friend bool operator!=(const Forest &lhs, const Forest &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Forest &lhs, const Forest &rhs) {
+            return lhs.trees < rhs.trees;
+        }
+    };
+
+    struct Tree {
+        struct Leaf {
+            std::uint8_t value;
+
+            friend bool operator==(const Leaf &lhs, const Leaf &rhs) {
+                return lhs.value == rhs.value;
+            }
+            friend bool operator!=(const Leaf &lhs, const Leaf &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Leaf &lhs, const Leaf &rhs) {
+                return lhs.value < rhs.value;
+            }
+        };
+
+        struct Forest {
+            serde::value_ptr<root::Forest> value;
+
+            friend bool operator==(const Forest &lhs, const Forest &rhs) {
+                return lhs.value == rhs.value;
+            }
+            friend bool operator!=(const Forest &lhs, const Forest &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Forest &lhs, const Forest &rhs) {
+                return lhs.value < rhs.value;
+            }
+        };
+
+        std::variant<Leaf, Forest> value;
+
+        friend bool operator==(const Tree &lhs, const Tree &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const Tree &lhs, const Tree &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Tree &lhs, const Tree &rhs) {
+            return lhs.value < rhs.value;
+        }
+    };
+
+    struct Alpha {
+        Tree tree;
+
+        friend bool operator==(const Alpha &lhs, const Alpha &rhs) {
+            return lhs.tree == rhs.tree;
+        }
+        friend bool operator!=(const Alpha &lhs, const Alpha &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Alpha &lhs, const Alpha &rhs) {
+            return lhs.tree < rhs.tree;
+        }
+    };
+
+    struct Wrapper {
+        Alpha inner;
+
+        friend bool operator==(const Wrapper &lhs, const Wrapper &rhs) {
+            return lhs.inner == rhs.inner;
+        }
+        friend bool operator!=(const Wrapper &lhs, const Wrapper &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Wrapper &lhs, const Wrapper &rhs) {
+            return lhs.inner < rhs.inner;
+        }
+    };
+
+    } // namespace root
+    "#);
+}
+
+#[test]
+fn output_keeps_indices_of_skipped_variants() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Colour {
+        Red,
+        #[facet(fg::skip(cpp))]
+        Green,
+        Blue,
+    }
+
+    let registry = reflect!(Colour).unwrap();
+    let config = CodeGeneratorConfig::new("root".to_string());
+
+    let generator = CppCodeGenerator::new(&config);
+    let output = render_output(generator, vec![Arc::new(BincodePlugin)], &registry);
+    insta::assert_snapshot!(output, @r#"
+    #pragma once
+
+    #include "serde/bincode.hpp"
+    #include "serde/serde.hpp"
+
+    namespace root {
+
+    struct Colour {
+        struct Red {
+            friend bool operator==(const Red &, const Red &) {
+                return true;
+            }
+            friend bool operator!=(const Red &lhs, const Red &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Red &, const Red &) {
+                return false;
+            }
+
+            template <typename Serializer>
+            void serialize(Serializer &serializer) const {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(0);
+                serializer.decrease_container_depth();
+            }
+        };
+
+        struct Blue {
+            friend bool operator==(const Blue &, const Blue &) {
+                return true;
+            }
+            friend bool operator!=(const Blue &lhs, const Blue &rhs) {
+                return !(lhs == rhs);
+            }
+            friend bool operator<(const Blue &, const Blue &) {
+                return false;
+            }
+
+            template <typename Serializer>
+            void serialize(Serializer &serializer) const {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(2);
+                serializer.decrease_container_depth();
+            }
+        };
+
+        std::variant<Red, Blue> value;
+
+        friend bool operator==(const Colour &lhs, const Colour &rhs) {
+            return lhs.value == rhs.value;
+        }
+        friend bool operator!=(const Colour &lhs, const Colour &rhs) {
+            return !(lhs == rhs);
+        }
+        friend bool operator<(const Colour &lhs, const Colour &rhs) {
+            return lhs.value < rhs.value;
+        }
+
+        template <typename Serializer>
+        void serialize(Serializer &serializer) const {
+            std::visit([&serializer](const auto &variant) { variant.serialize(serializer); }, value);
+        }
+
+        template <typename Deserializer>
+        static Colour deserialize(Deserializer &deserializer) {
+            deserializer.increase_container_depth();
+            std::uint32_t index = deserializer.deserialize_variant_index();
+            Colour obj{};
+            switch (index) {
+                case 0:
+                    obj.value = Red{};
+                    break;
+                case 2:
+                    obj.value = Blue{};
+                    break;
+                default:
+                    throw serde::deserialization_error("Unknown variant index for Colour: " + std::to_string(index));
+            }
+            deserializer.decrease_container_depth();
+            return obj;
+        }
+
+        std::vector<std::uint8_t> bincodeSerialize() const {
+            return serde::bincode_serialize(*this);
+        }
+
+        static Colour bincodeDeserialize(const std::vector<std::uint8_t> &input) {
+            return serde::bincode_deserialize<Colour>(input);
+        }
+    };
+
+    } // namespace root
+    "#);
+}
+
+#[test]
+fn rejects_enums_without_variants() {
+    let mut registry = Registry::new();
+    registry.insert(
+        QualifiedTypeName::root("Never".to_string()),
+        ContainerFormat::Enum(
+            BTreeMap::new(),
+            crate::reflection::format::EnumTagging::External,
+            Doc::new(),
+            ContainerMetadata::default(),
+        ),
+    );
+    let config = CodeGeneratorConfig::new("root".to_string());
+
+    let generator = CppCodeGenerator::new(&config);
+    let error = generator.output(&mut Vec::new(), &registry).unwrap_err();
+    insta::assert_snapshot!(error, @"cpp can't encode `Never`: an enum without variants can't be a `std::variant`");
+}
+
+#[test]
+fn rejects_variants_named_like_their_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Event(String),
+        Other,
+    }
+
+    let registry = reflect!(Event).unwrap();
+    let config = CodeGeneratorConfig::new("root".to_string());
+
+    let generator = CppCodeGenerator::new(&config);
+    let error = generator.output(&mut Vec::new(), &registry).unwrap_err();
+    insta::assert_snapshot!(error, @"cpp can't encode `Event`: the struct of variant `Event` would have the name of its enum");
+}
+
+#[test]
+fn rejects_namespaces_that_refer_to_each_other() {
+    let mut registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "other".to_string(),
+        "Child".to_string(),
+    )));
+    registry.insert(
+        QualifiedTypeName::namespaced("other".to_string(), "Child".to_string()),
+        ContainerFormat::NewTypeStruct(
+            Box::new(Format::Option(Box::new(Format::TypeName(
+                QualifiedTypeName::root("Holder".to_string()),
+            )))),
+            Doc::new(),
+            ContainerMetadata::default(),
+        ),
+    );
+
+    let error = check_registry(&registry).unwrap_err();
+    insta::assert_snapshot!(error, @"cpp can't encode `Holder`: the root namespace and namespace `other` refer to each other, so their headers would include each other");
+}
+
+#[test]
+fn bincode_rejects_untagged_enums() {
+    #[derive(Facet)]
+    #[facet(untagged)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Value {
+        Number(f64),
+        Text(String),
+    }
+
+    let registry = reflect!(Value).unwrap();
+    let config = CodeGeneratorConfig::new("root".to_string());
+
+    let generator = CppCodeGenerator::new(&config).with_plugins(vec![Arc::new(BincodePlugin)]);
+    let error = generator.output(&mut Vec::new(), &registry).unwrap_err();
+    insta::assert_snapshot!(error, @"bincode can't encode `Value`: untagged enums don't write the variant index bincode needs");
+}
@@ -0,0 +1,293 @@
+//! Project scaffolding — writes a header-only CMake library to disk.
+//!
+//! The [`Installer`] is the final stage of the C++ generation pipeline.
+//! While [`CppCodeGenerator`] produces the *contents* of a header, the
+//! installer is responsible for the surrounding project structure:
+//!
+//! 1. **Runtime** — copies the `serde` runtime headers (and those of the
+//!    plugins, e.g. `serde/bincode.hpp`) into `include/`.
+//!
+//! 2. **Per-namespace headers** — splits the registry by namespace (via
+//!    [`module::split`]) into the root namespace and a namespace nested in it
+//!    for each other namespace, and writes a header for each (e.g.
+//!    `include/example.hpp` and `include/example/other.hpp`).
+//!
+//! 3. **`CMakeLists.txt`** — generates an `INTERFACE` library target that
+//!    puts `include/` on the include path, requires C++17, and links the
+//!    external packages it depends on.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::{File, create_dir_all},
+    io::Write as _,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::{
+    Registry,
+    generation::{
+        CodeGeneratorConfig, Error, ExternalPackage, ExternalPackages, PackageLocation,
+        SERDE_NAMESPACE, SourceInstaller,
+        cpp::{Cpp, CppCodeGenerator, emitter::header_path, generator},
+        indent::IndentConfig,
+        module,
+        plugin::{self, EmitterPlugin},
+    },
+    reflection::format::Language,
+};
+
+/// The directory of the library's headers.
+const INCLUDE_DIR: &str = "include";
+
+/// The header of the serde runtime, which every generated header includes.
+const SERDE_RUNTIME: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/runtime/cpp/serde/serde.hpp"
+));
+
+/// Installer for generated source files in C++.
+///
+/// # Examples
+///
+/// ```rust
+/// use facet_generate::generation::cpp;
+///
+/// let output_dir = std::path::PathBuf::from("output");
+/// let installer = cpp::Installer::new("example", &output_dir);
+/// ```
+pub struct Installer {
+    package: String,
+    install_dir: PathBuf,
+    external_packages: ExternalPackages,
+    indent: IndentConfig,
+    plugins: Vec<Arc<dyn EmitterPlugin<Cpp>>>,
+}
+
+impl Installer {
+    /// Create a new installer for the given root namespace and output
+    /// directory.
+    ///
+    /// The root namespace (e.g. `example`, or `acme::types`) holds the types
+    /// of the registry's root namespace, and a nested namespace for each other
+    /// namespace. Use the builder methods [`plugin`](Self::plugin) and
+    /// [`external_packages`](Self::external_packages) to configure, then call
+    /// [`generate`](Self::generate) to produce the output.
+    #[must_use]
+    pub fn new(package: &str, install_dir: impl AsRef<Path>) -> Self {
+        Self {
+            package: package.to_string(),
+            install_dir: install_dir.as_ref().to_path_buf(),
+            external_packages: ExternalPackages::new(),
+            indent: IndentConfig::Space(4),
+            plugins: vec![],
+        }
+    }
+
+    /// Add a plugin to be used during code generation.
+    ///
+    /// When multiple plugins are added, they are invoked in the order they were registered.
+    #[must_use]
+    pub fn plugin<P: EmitterPlugin<Cpp> + 'static>(mut self, plugin: P) -> Self {
+        self.plugins.push(Arc::new(plugin));
+        self
+    }
+
+    /// Set external packages to reference.
+    #[must_use]
+    pub fn external_packages(mut self, packages: &[ExternalPackage]) -> Self {
+        self.external_packages = packages
+            .iter()
+            .map(|d| (d.for_namespace.clone(), d.clone()))
+            .collect();
+        self
+    }
+
+    /// Set the indentation of generated source files (four spaces by
+    /// default).
+    #[must_use]
+    pub const fn indent(mut self, indent: IndentConfig) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Generate all code for the given registry.
+    ///
+    /// This method:
+    /// 1. Installs the serde runtime and the runtimes of the configured plugins
+    /// 2. Splits the registry by namespace and installs each header
+    /// 3. Writes the project's `CMakeLists.txt`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any file operation or code generation step fails.
+    pub fn generate(mut self, registry: &Registry) -> Result<(), Error> {
+        let checked = module::for_plugin_checks(registry, Language::Cpp);
+        let registry = &module::for_language(registry, Language::Cpp);
+        generator::check_registry(registry)?;
+        plugin::check_registry(&self.plugins, &checked)?;
+
+        if !self.external_packages.contains_key(SERDE_NAMESPACE) {
+            self.install_serde_runtime()?;
+            let mut written = BTreeSet::from(["serde/serde.hpp".to_string()]);
+            for plugin in &self.plugins {
+                for file in plugin.runtime_files() {
+                    if written.insert(file.relative_path.clone()) {
+                        self.install_runtime_file(&file.relative_path, &file.contents)?;
+                    }
+                }
+            }
+        }
+
+        for (m, module_registry) in &module::split(&self.package, registry) {
+            // Namespaces that correspond to external packages are included
+            // rather than generated.
+            if self
+                .external_packages
+                .contains_key(m.config().module_name())
+            {
+                continue;
+            }
+            let mut config = m.config().clone().with_indent(self.indent);
+            if config.module_name != self.package {
+                config.module_name = format!("{}::{}", self.package, config.module_name);
+            }
+            self.install_module(&config, module_registry)?;
+        }
+
+        let package = self.package.clone();
+        self.install_manifest(&package)?;
+
+        Ok(())
+    }
+
+    /// Installs the `serde/serde.hpp` runtime header.
+    ///
+    /// Most callers should prefer [`generate`](Self::generate).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any file I/O fails.
+    pub fn install_serde_runtime(&self) -> Result<(), Error> {
+        self.install_runtime_file("serde/serde.hpp", SERDE_RUNTIME)
+    }
+
+    fn install_runtime_file(&self, relative_path: &str, contents: &[u8]) -> Result<(), Error> {
+        let dest = self.install_dir.join(INCLUDE_DIR).join(relative_path);
+        if let Some(parent) = dest.parent() {
+            create_dir_all(parent)?;
+        }
+        std::fs::write(&dest, contents)?;
+        Ok(())
+    }
+
+    /// Produce the contents of a `CMakeLists.txt`.
+    ///
+    /// The library's target is the root namespace with `_` for `::` (e.g.
+    /// `acme_types` for `acme::types`). External packages at a `Url` are Git
+    /// repositories that `FetchContent` downloads, at their version if they
+    /// have one, and those at a `Path` are CMake projects on disk; the target
+    /// links both by their namespace's name, as it does the plugins' manifest
+    /// dependencies.
+    #[must_use]
+    pub fn make_manifest(&self, package: &str) -> String {
+        let target = package.replace("::", "_");
+
+        let plugin_config = CodeGeneratorConfig::new(package.to_string());
+        let lang = self
+            .plugins
+            .iter()
+            .fold(Cpp::new(&plugin_config, &BTreeMap::default()), |lang, p| {
+                lang.with_plugin(p.clone())
+            });
+        let mut dependencies: Vec<String> = lang
+            .plugins()
+            .iter()
+            .flat_map(|p| p.manifest_dependencies())
+            .collect();
+
+        let mut manifest = format!(
+            "cmake_minimum_required(VERSION 3.14)
+project({target} LANGUAGES CXX)
+
+add_library({target} INTERFACE)
+target_include_directories({target} INTERFACE ${{CMAKE_CURRENT_SOURCE_DIR}}/{INCLUDE_DIR})
+target_compile_features({target} INTERFACE cxx_std_17)
+"
+        );
+
+        if self
+            .external_packages
+            .values()
+            .any(|external| matches!(external.location, PackageLocation::Url(_)))
+        {
+            manifest.push_str("\ninclude(FetchContent)\n");
+        }
+        for external_package in self.external_packages.values() {
+            let name = &external_package.for_namespace;
+            match &external_package.location {
+                PackageLocation::Url(url) => {
+                    manifest.push_str(&format!(
+                        "\nFetchContent_Declare(\n    {name}\n    GIT_REPOSITORY {url}\n"
+                    ));
+                    if let Some(version) = &external_package.version {
+                        manifest.push_str(&format!("    GIT_TAG {version}\n"));
+                    }
+                    manifest.push_str(&format!(")\nFetchContent_MakeAvailable({name})\n"));
+                }
+                PackageLocation::Path(path) => manifest.push_str(&format!(
+                    "\nadd_subdirectory({path} ${{CMAKE_CURRENT_BINARY_DIR}}/{name})\n"
+                )),
+            }
+            dependencies.push(name.clone());
+        }
+
+        if !dependencies.is_empty() {
+            manifest.push_str(&format!(
+                "\ntarget_link_libraries({target} INTERFACE {})\n",
+                dependencies.join(" ")
+            ));
+        }
+        manifest
+    }
+}
+
+impl SourceInstaller for Installer {
+    /// Generate the header of one namespace, at
+    /// `include/<namespace path>.hpp`.
+    fn install_module(
+        &mut self,
+        config: &CodeGeneratorConfig,
+        registry: &Registry,
+    ) -> Result<(), Error> {
+        let path = self
+            .install_dir
+            .join(INCLUDE_DIR)
+            .join(header_path(config.module_name()));
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        let mut updated_config = config.clone();
+        updated_config.external_packages = self.external_packages.clone();
+
+        let generator = CppCodeGenerator::new(&updated_config)
+            .with_package(&self.package)
+            .with_plugins(self.plugins.clone());
+        let mut file = File::create(path)?;
+        generator.output(&mut file, registry)?;
+
+        Ok(())
+    }
+
+    /// Write `CMakeLists.txt` to the output directory.
+    fn install_manifest(&self, package: &str) -> Result<(), Error> {
+        create_dir_all(&self.install_dir)?;
+        let mut file = File::create(self.install_dir.join("CMakeLists.txt"))?;
+        file.write_all(self.make_manifest(package).as_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
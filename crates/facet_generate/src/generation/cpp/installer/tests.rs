@@ -0,0 +1,203 @@
+//! Tests for the C++ [`Installer`] — **project scaffolding**.
+//!
+//! These tests verify the `CMakeLists.txt` manifest that the installer
+//! generates, and the layout of the library it writes:
+//!
+//! - Basic manifest structure: an `INTERFACE` target named after the root
+//!   namespace, C++17, no dependencies.
+//! - External packages: fetched from their URL, or added from their path,
+//!   and linked either way.
+//! - Installed files: a header for the root namespace and one nested in its
+//!   directory per other namespace, with the runtime headers next to them.
+//! - Registries that C++ can't declare.
+
+use facet::Facet;
+
+use crate::generation::{ExternalPackage, PackageLocation, bincode::BincodePlugin, cpp::Installer};
+use crate::reflect;
+
+#[test]
+fn make_manifest_basic() {
+    let installer = Installer::new("acme::types", "/tmp");
+    let manifest = installer.make_manifest("acme::types");
+
+    insta::assert_snapshot!(manifest, @"
+    cmake_minimum_required(VERSION 3.14)
+    project(acme_types LANGUAGES CXX)
+
+    add_library(acme_types INTERFACE)
+    target_include_directories(acme_types INTERFACE ${CMAKE_CURRENT_SOURCE_DIR}/include)
+    target_compile_features(acme_types INTERFACE cxx_std_17)
+    ");
+}
+
+#[test]
+fn make_manifest_with_external_packages() {
+    let external_packages = vec![
+        ExternalPackage {
+            for_namespace: "local_types".to_string(),
+            module_name: None,
+            location: PackageLocation::Path("../local_types".to_string()),
+            version: None,
+        },
+        ExternalPackage {
+            for_namespace: "remote_types".to_string(),
+            module_name: None,
+            location: PackageLocation::Url(
+                "https://github.com/example/remote-types.git".to_string(),
+            ),
+            version: Some("v2.0.0".to_string()),
+        },
+    ];
+
+    let installer = Installer::new("example", "/tmp").external_packages(&external_packages);
+    let manifest = installer.make_manifest("example");
+
+    insta::assert_snapshot!(manifest, @"
+    cmake_minimum_required(VERSION 3.14)
+    project(example LANGUAGES CXX)
+
+    add_library(example INTERFACE)
+    target_include_directories(example INTERFACE ${CMAKE_CURRENT_SOURCE_DIR}/include)
+    target_compile_features(example INTERFACE cxx_std_17)
+
+    include(FetchContent)
+
+    add_subdirectory(../local_types ${CMAKE_CURRENT_BINARY_DIR}/local_types)
+
+    FetchContent_Declare(
+        remote_types
+        GIT_REPOSITORY https://github.com/example/remote-types.git
+        GIT_TAG v2.0.0
+    )
+    FetchContent_MakeAvailable(remote_types)
+
+    target_link_libraries(example INTERFACE local_types remote_types)
+    ");
+}
+
+#[test]
+fn generate_writes_project_layout() {
+    #[derive(Facet)]
+    struct Child {
+        value: i32,
+    }
+
+    mod other {
+        use crate as fg;
+        use facet::Facet;
+
+        #[derive(Facet)]
+        #[facet(fg::namespace = "other")]
+        pub struct Child {
+            value: String,
+        }
+    }
+
+    #[derive(Facet)]
+    struct Parent {
+        child: Child,
+        other_child: other::Child,
+    }
+
+    let registry = reflect!(Parent).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    Installer::new("example", install_dir.path())
+        .plugin(BincodePlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let mut files = ignore::Walk::new(install_dir.path())
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_file())
+        .map(|entry| {
+            entry
+                .path()
+                .strip_prefix(install_dir.path())
+                .unwrap()
+                .display()
+                .to_string()
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    insta::assert_snapshot!(files.join("\n"), @"
+    CMakeLists.txt
+    include/example.hpp
+    include/example/other.hpp
+    include/serde/bincode.hpp
+    include/serde/serde.hpp
+    ");
+
+    let parent = std::fs::read_to_string(install_dir.path().join("include/example.hpp")).unwrap();
+    let declaration = parent
+        .split("\n\n")
+        .take(4)
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    insta::assert_snapshot!(declaration, @r#"
+    #pragma once
+
+    #include "example/other.hpp"
+    #include "serde/bincode.hpp"
+    #include "serde/serde.hpp"
+
+    namespace example {
+
+    struct Child {
+        std::int32_t value;
+    "#);
+}
+
+#[test]
+fn generate_skips_external_serde_runtime() {
+    #[derive(Facet)]
+    struct Child {
+        value: i32,
+    }
+
+    let registry = reflect!(Child).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    Installer::new("example", install_dir.path())
+        .plugin(BincodePlugin)
+        .external_packages(&[ExternalPackage {
+            for_namespace: "serde".to_string(),
+            module_name: None,
+            location: PackageLocation::Url("https://github.com/example/serde.git".to_string()),
+            version: Some("0.1.0".to_string()),
+        }])
+        .generate(&registry)
+        .unwrap();
+
+    assert!(!install_dir.path().join("include/serde").exists());
+    assert!(install_dir.path().join("include/example.hpp").exists());
+}
+
+#[test]
+fn rejects_namespaces_that_refer_to_each_other() {
+    mod types {
+        use crate as fg;
+        use facet::Facet;
+
+        #[derive(Facet)]
+        pub struct Parent {
+            pub child: Child,
+        }
+
+        #[derive(Facet)]
+        #[facet(fg::namespace = "other")]
+        pub struct Child {
+            pub parent: Option<Box<Parent>>,
+        }
+    }
+    use types::Parent;
+
+    let registry = reflect!(Parent).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    let error = Installer::new("example", install_dir.path())
+        .generate(&registry)
+        .unwrap_err();
+    insta::assert_snapshot!(error, @"cpp can't encode `Parent`: the root namespace and namespace `other` refer to each other, so their headers would include each other");
+}
@@ -0,0 +1,37 @@
+//! C++ code generation.
+//!
+//! This module translates a [`Registry`](crate::Registry) of reflected type
+//! definitions into header-only C++17, for native applications that embed the
+//! Rust core behind a C ABI.
+//!
+//! # Submodules (in pipeline order)
+//!
+//! 1. **generator** — Top-level orchestrator. [`CppCodeGenerator`](crate::generation::cpp::CppCodeGenerator) implements
+//!    [`CodeGenerator`](crate::generation::CodeGenerator) to produce the header
+//!    of a namespace from a registry. It resolves qualified type names against
+//!    the configuration (external packages, namespaces), orders the
+//!    declarations so that each type follows those it contains, and delegates
+//!    writing to the emitter layer.
+//!
+//! 2. **emitter** — AST-to-source rendering. Implements
+//!    [`Emitter<Cpp>`](crate::generation::Emitter) for each AST node type
+//!    ([`Module`](crate::generation::module::Module), [`Container`](crate::generation::Container),
+//!    `Named<Format>`, `Format`, `Doc`). This is where the C++ language
+//!    mapping lives: `struct`s for structs, and a `struct` holding a
+//!    `std::variant` of a nested `struct` per variant for enums.
+//!
+//! 3. **installer** — Project scaffolding. [`Installer`](crate::generation::cpp::Installer) implements
+//!    [`SourceInstaller`](crate::generation::SourceInstaller) to write a
+//!    header-only library: it copies the `serde` runtime headers into it,
+//!    splits the registry by namespace into one header per namespace, and
+//!    generates a `CMakeLists.txt`.
+
+pub use emitter::Cpp;
+pub use generator::CppCodeGenerator;
+pub use installer::Installer;
+
+pub(crate) use emitter::{field_name, string_literal, variant_fields, variant_struct};
+
+mod emitter;
+mod generator;
+mod installer;
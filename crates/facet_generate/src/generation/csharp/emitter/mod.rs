@@ -10,7 +10,7 @@
 //! |---|---|
 //! | [`Module`] | `using` directives, file-scoped `namespace` declaration |
 //! | [`Container`] | `sealed record`, `partial class : ObservableObject`, `public enum`, or `abstract record` + `sealed record` variant hierarchy |
-//! | [`Named<Format>`](Named) | `[ObservableProperty]` private field (+ `[JsonPropertyName]` for JSON) |
+//! | [`Named<Format>`](Named) | `[ObservableProperty]` private field, or `{ get; init; }` property if read-only (+ `[JsonPropertyName]` for JSON) |
 //! | [`Format`] | Inline type expression (`int`, `string`, `ObservableCollection<T>`, …) |
 //! | [`Doc`] | `///` XML doc comments |
 //!
@@ -68,10 +68,14 @@ use crate::{
         CodeGeneratorConfig, Container, Emitter,
         indent::{IndentWrite, Newlines},
         module::Module,
-        plugin::{EmitContext, EmitterPlugin, any_plugin, collect_from_plugins},
+        plugin::{
+            EmitContext, EmitterPlugin, any_plugin, collect_from_plugins, type_annotations,
+            type_conformances,
+        },
     },
     reflection::format::{
-        ContainerFormat, Doc, Format, Named, Namespace, QualifiedTypeName, VariantFormat,
+        ContainerFormat, DefaultValue, Deprecation, Doc, Format, Language, Named, Namespace,
+        QualifiedTypeName, VariantFormat, Visibility,
     },
 };
 
@@ -157,8 +161,11 @@ impl Emitter<CSharp> for Container<'_> {
         } = self;
 
         match format {
-            ContainerFormat::UnitStruct(doc) => write_sealed_record(w, self, name, doc, lang),
-            ContainerFormat::NewTypeStruct(format, doc) => write_class(
+            ContainerFormat::UnitStruct(doc, _) => write_sealed_record(w, self, name, doc, lang),
+            ContainerFormat::NewTypeStruct(format, doc, metadata) if metadata.branded => {
+                write_branded_record_struct(w, self, name, format, doc, lang)
+            }
+            ContainerFormat::NewTypeStruct(format, doc, _) => write_class(
                 w,
                 self,
                 name,
@@ -166,17 +173,17 @@ impl Emitter<CSharp> for Container<'_> {
                 doc,
                 lang,
             ),
-            ContainerFormat::TupleStruct(formats, doc) => {
+            ContainerFormat::TupleStruct(formats, doc, _) => {
                 write_class(w, self, name, &named(formats), doc, lang)
             }
-            ContainerFormat::Struct(fields, doc) => {
+            ContainerFormat::Struct(fields, doc, _) => {
                 if fields.is_empty() {
                     write_sealed_record(w, self, name, doc, lang)
                 } else {
                     write_class(w, self, name, fields, doc, lang)
                 }
             }
-            ContainerFormat::Enum(variants, _, doc) => {
+            ContainerFormat::Enum(variants, _, doc, _) => {
                 let all_unit_variants = variants
                     .values()
                     .all(|variant| matches!(variant.value, VariantFormat::Unit));
@@ -211,13 +218,8 @@ impl Emitter<CSharp> for Named<Format> {
     /// `plugin.field_annotations()`. This impl is kept for completeness.
     fn write<W: IndentWrite>(&self, w: &mut W, lang: &CSharp) -> Result<()> {
         self.doc.write(w, lang)?;
-        writeln!(w, "[ObservableProperty]")?;
-        writeln!(
-            w,
-            "private {} _{};",
-            csharp_type(&self.value),
-            self.name.to_lower_camel_case()
-        )
+        write_obsolete(w, self.metadata.deprecated.as_ref())?;
+        write_property(w, self)
     }
 }
 
@@ -230,18 +232,109 @@ fn write_field<W: IndentWrite>(
     lang: &CSharp,
 ) -> Result<()> {
     field.doc.write(w, lang)?;
+    write_obsolete(w, field.metadata.deprecated.as_ref())?;
     for annotation in collect_from_plugins(lang.plugins(), |p| p.field_annotations(field, ctx)) {
         writeln!(w, "{annotation}")?;
     }
+    write_property(w, field)
+}
+
+/// Write the property backing a field: an `[ObservableProperty]` private field, or an
+/// init-only auto-property for a read-only field, which can't raise change notifications.
+fn write_property<W: IndentWrite>(w: &mut W, field: &Named<Format>) -> Result<()> {
+    let initializer = default_value(field)
+        .map(|value| format!(" = {value}"))
+        .unwrap_or_default();
+    if field.metadata.readonly {
+        let semicolon = if initializer.is_empty() { "" } else { ";" };
+        return writeln!(
+            w,
+            "public {} {} {{ get; init; }}{initializer}{semicolon}",
+            field_type(field),
+            field.name.to_upper_camel_case()
+        );
+    }
     writeln!(w, "[ObservableProperty]")?;
     writeln!(
         w,
-        "private {} _{};",
-        csharp_type(&field.value),
+        "private {} _{}{initializer};",
+        field_type(field),
         field.name.to_lower_camel_case()
     )
 }
 
+/// The C# expression of a field's default value, or `None` if it has none that suits its type
+/// (e.g. because a serialization proxy or `fg::override` changed it).
+fn default_value(field: &Named<Format>) -> Option<String> {
+    if field.metadata.type_override(Language::CSharp).is_some() {
+        return None;
+    }
+    Some(match (field.metadata.default.as_ref()?, &field.value) {
+        (DefaultValue::Empty, Format::Option(_)) => "null".to_string(),
+        (DefaultValue::Empty, Format::Seq(_) | Format::Set(_) | Format::Bytes) => "[]".to_string(),
+        (DefaultValue::Empty, Format::Map { .. }) => "new()".to_string(),
+        (DefaultValue::Bool(value), Format::Bool) => value.to_string(),
+        // `Int128` and `UInt128` convert implicitly from `long` and `ulong` only.
+        (DefaultValue::Integer(value), Format::I128) if value.parse::<i64>().is_err() => {
+            format!(r#"Int128.Parse("{value}")"#)
+        }
+        (DefaultValue::Integer(value), Format::U128) if value.parse::<u64>().is_err() => {
+            format!(r#"UInt128.Parse("{value}")"#)
+        }
+        (
+            DefaultValue::Integer(value),
+            Format::I8
+            | Format::I16
+            | Format::I32
+            | Format::I64
+            | Format::I128
+            | Format::U8
+            | Format::U16
+            | Format::U32
+            | Format::U64
+            | Format::U128,
+        )
+        | (DefaultValue::Float(value), Format::F64) => value.clone(),
+        (DefaultValue::Float(value), Format::F32) => format!("{value}f"),
+        (DefaultValue::Char(value), Format::Char) => {
+            format!("'{}'", escape(&value.to_string()).replace('\'', "\\'"))
+        }
+        (DefaultValue::Str(value), Format::Str) => format!("\"{}\"", escape(value)),
+        _ => return None,
+    })
+}
+
+/// Whether a default value of `format` is a compile-time constant, as the default value of a
+/// parameter has to be.
+const fn is_constant(format: &Format) -> bool {
+    !matches!(
+        format,
+        Format::I128
+            | Format::U128
+            | Format::Seq(_)
+            | Format::Set(_)
+            | Format::Map { .. }
+            | Format::Bytes
+    )
+}
+
+/// Escapes text for a string or character literal.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t")
+}
+
+/// The type of a field: its `fg::override` type for C#, if any, or its format's.
+fn field_type(field: &Named<Format>) -> String {
+    match field.metadata.type_override(Language::CSharp) {
+        Some(type_override) => type_override.ty.clone(),
+        None => csharp_type(&field.value),
+    }
+}
+
 impl Emitter<CSharp> for Doc {
     fn write<W: IndentWrite>(&self, w: &mut W, _lang: &CSharp) -> Result<()> {
         for comment in self.comments() {
@@ -259,11 +352,21 @@ fn write_sealed_record<W: IndentWrite>(
     lang: &CSharp,
 ) -> Result<()> {
     doc.write(w, lang)?;
-
-    let record_name = name.to_upper_camel_case();
+    write_obsolete(w, container.format.metadata().deprecated.as_ref())?;
+
+    let access = container_access(container, lang);
+    let record_name = format!(
+        "{}{}",
+        name.to_upper_camel_case(),
+        type_params(container.format.type_params())
+    );
     let ctx = EmitContext::top_level(container, &lang.config);
 
-    let conformances = collect_from_plugins(lang.plugins(), |p| p.type_conformances(&ctx));
+    for annotation in type_annotations(lang.plugins(), &ctx, Language::CSharp) {
+        writeln!(w, "{annotation}")?;
+    }
+
+    let conformances = type_conformances(lang.plugins(), &ctx, Language::CSharp);
     let conforms = if conformances.is_empty() {
         String::new()
     } else {
@@ -271,11 +374,11 @@ fn write_sealed_record<W: IndentWrite>(
     };
 
     if !any_plugin(lang.plugins(), |p| p.has_type_body(&ctx)) {
-        writeln!(w, "public sealed record {record_name}{conforms};")?;
+        writeln!(w, "{access} sealed record {record_name}{conforms};")?;
         return Ok(());
     }
 
-    write!(w, "public sealed record {record_name}{conforms} ")?;
+    write!(w, "{access} sealed record {record_name}{conforms} ")?;
     {
         let mut w = w.block(Newlines::BOTH)?;
         for plugin in lang.plugins() {
@@ -286,6 +389,65 @@ fn write_sealed_record<W: IndentWrite>(
     Ok(())
 }
 
+/// Emits a branded newtype as a `readonly record struct` with a single positional
+/// `Value`, so that it can't be mixed up with its inner type or with other branded
+/// types wrapping the same inner type.
+fn write_branded_record_struct<W: IndentWrite>(
+    w: &mut W,
+    container: &Container<'_>,
+    name: &str,
+    format: &Format,
+    doc: &Doc,
+    lang: &CSharp,
+) -> Result<()> {
+    doc.write(w, lang)?;
+    write_obsolete(w, container.format.metadata().deprecated.as_ref())?;
+
+    let access = container_access(container, lang);
+    let struct_name = format!(
+        "{}{}",
+        name.to_upper_camel_case(),
+        type_params(container.format.type_params())
+    );
+    let ctx = EmitContext::top_level(container, &lang.config);
+
+    for annotation in type_annotations(lang.plugins(), &ctx, Language::CSharp) {
+        writeln!(w, "{annotation}")?;
+    }
+
+    let conformances = type_conformances(lang.plugins(), &ctx, Language::CSharp);
+    let conforms = if conformances.is_empty() {
+        String::new()
+    } else {
+        format!(" : {}", conformances.join(", "))
+    };
+
+    let declaration = format!(
+        "{access} readonly record struct {struct_name}({} Value){conforms}",
+        csharp_type(format)
+    );
+    if !any_plugin(lang.plugins(), |p| p.has_type_body(&ctx)) {
+        writeln!(w, "{declaration};")?;
+        return Ok(());
+    }
+
+    write!(w, "{declaration} ")?;
+    let mut w = w.block(Newlines::BOTH)?;
+    for (i, plugin) in lang
+        .plugins()
+        .iter()
+        .filter(|p| p.has_type_body(&ctx))
+        .enumerate()
+    {
+        if i > 0 {
+            writeln!(w)?;
+        }
+        plugin.type_body(&mut w as &mut dyn IndentWrite, &ctx)?;
+    }
+
+    Ok(())
+}
+
 fn write_class<W: IndentWrite>(
     w: &mut W,
     container: &Container<'_>,
@@ -295,11 +457,21 @@ fn write_class<W: IndentWrite>(
     lang: &CSharp,
 ) -> Result<()> {
     doc.write(w, lang)?;
-
-    let class_name = name.to_upper_camel_case();
+    write_obsolete(w, container.format.metadata().deprecated.as_ref())?;
+
+    let access = container_access(container, lang);
+    let class_name = format!(
+        "{}{}",
+        name.to_upper_camel_case(),
+        type_params(container.format.type_params())
+    );
     let ctx = EmitContext::top_level(container, &lang.config);
 
-    let conformances = collect_from_plugins(lang.plugins(), |p| p.type_conformances(&ctx));
+    for annotation in type_annotations(lang.plugins(), &ctx, Language::CSharp) {
+        writeln!(w, "{annotation}")?;
+    }
+
+    let conformances = type_conformances(lang.plugins(), &ctx, Language::CSharp);
     let conforms = if conformances.is_empty() {
         String::new()
     } else {
@@ -308,7 +480,7 @@ fn write_class<W: IndentWrite>(
 
     write!(
         w,
-        "public partial class {class_name} : ObservableObject{conforms} "
+        "{access} partial class {class_name} : ObservableObject{conforms} "
     )?;
 
     let has_plugin_body = any_plugin(lang.plugins(), |p| p.has_type_body(&ctx));
@@ -320,7 +492,8 @@ fn write_class<W: IndentWrite>(
 
     let mut w = w.block(Newlines::BOTH)?;
     for field in fields {
-        write_field(&mut w, field, &ctx, lang)?;
+        let field = field.inheriting(container.format.metadata());
+        write_field(&mut w, &field, &ctx, lang)?;
     }
 
     for plugin in lang.plugins() {
@@ -347,20 +520,29 @@ fn write_enum<W: IndentWrite>(
     let ctx = EmitContext::top_level(container, &lang.config);
 
     doc.write(w, lang)?;
+    write_obsolete(w, container.format.metadata().deprecated.as_ref())?;
 
     // Type annotations from plugins (e.g. [JsonConverter(typeof(JsonStringEnumConverter))]).
-    for annotation in collect_from_plugins(lang.plugins(), |p| p.type_annotations(&ctx)) {
+    for annotation in type_annotations(lang.plugins(), &ctx, Language::CSharp) {
         writeln!(w, "{annotation}")?;
     }
 
-    write!(w, "public enum {enum_name} ")?;
+    let access = container_access(container, lang);
+    write!(w, "{access} enum {enum_name} ")?;
     {
         let mut w = w.block(Newlines::BOTH)?;
         let len = variants.len();
-        for (index, variant) in variants.values().enumerate() {
+        // The indices have gaps when variants are left out of this language or have pinned
+        // indices, so the values are spelled out to keep them in step with the indices.
+        let explicit = !variants.keys().zip(0..).all(|(index, i)| *index == i);
+        for (i, (index, variant)) in variants.iter().enumerate() {
             variant.doc.write(&mut w, lang)?;
+            write_obsolete(&mut w, variant.metadata.deprecated.as_ref())?;
             write!(w, "{}", variant.name.to_upper_camel_case())?;
-            if index + 1 < len {
+            if explicit {
+                write!(w, " = {index}")?;
+            }
+            if i + 1 < len {
                 writeln!(w, ",")?;
             } else {
                 writeln!(w)?;
@@ -384,18 +566,25 @@ fn write_variant_record_hierarchy<W: IndentWrite>(
     doc: &Doc,
     lang: &CSharp,
 ) -> Result<()> {
-    let base_name = name.to_upper_camel_case();
+    let generic_params = container.format.type_params();
+    let base_name = format!(
+        "{}{}",
+        name.to_upper_camel_case(),
+        type_params(generic_params)
+    );
     let ctx = EmitContext::top_level(container, &lang.config);
 
     doc.write(w, lang)?;
+    write_obsolete(w, container.format.metadata().deprecated.as_ref())?;
 
     // Type annotations from plugins (e.g. [JsonPolymorphic] + [JsonDerivedType(…)]).
-    for annotation in collect_from_plugins(lang.plugins(), |p| p.type_annotations(&ctx)) {
+    for annotation in type_annotations(lang.plugins(), &ctx, Language::CSharp) {
         writeln!(w, "{annotation}")?;
     }
 
-    // Type conformances from plugins (e.g. IFacetSerializable, IFacetDeserializable<T>).
-    let conformances = collect_from_plugins(lang.plugins(), |p| p.type_conformances(&ctx));
+    // Type conformances from plugins (e.g. IFacetSerializable, IFacetDeserializable<T>), and
+    // any declared with `fg::conforms`.
+    let conformances = type_conformances(lang.plugins(), &ctx, Language::CSharp);
     let conforms = if conformances.is_empty() {
         String::new()
     } else {
@@ -404,68 +593,138 @@ fn write_variant_record_hierarchy<W: IndentWrite>(
 
     // `partial` is required when bincode is active — variant partial records must
     // re-open the primary record declaration to add the Serialize override.
-    let partial = if conformances.is_empty() {
-        ""
+    // Generic hierarchies have no conformances (their methods take extra
+    // per-parameter closures), so any plugin body may re-open them.
+    let reopened = if generic_params.is_empty() {
+        !collect_from_plugins(lang.plugins(), |p| p.type_conformances(&ctx)).is_empty()
     } else {
-        " partial"
+        any_plugin(lang.plugins(), |p| p.has_type_body(&ctx))
     };
+    let partial = if reopened { " partial" } else { "" };
 
-    write!(w, "public abstract record {base_name}{conforms} ")?;
-    let mut w = w.block(Newlines::BOTH)?;
+    let access = container_access(container, lang);
+    write!(w, "{access} abstract record {base_name}{conforms} ")?;
+    {
+        let mut w = w.block(Newlines::BOTH)?;
 
-    for variant in variants {
-        variant.doc.write(&mut w, lang)?;
-        let variant_name = variant.name.to_upper_camel_case();
-        write!(w, "public sealed{partial} record {variant_name}")?;
-        match &variant.value {
-            VariantFormat::Unit => {
-                writeln!(w, "() : {base_name};")?;
-            }
-            VariantFormat::NewType(inner) => {
-                writeln!(w, "({} Value) : {};", csharp_type(inner), base_name)?;
-            }
-            VariantFormat::Tuple(values) => {
-                write!(w, "(")?;
-                for (index, format) in values.iter().enumerate() {
-                    if index > 0 {
-                        write!(w, ", ")?;
+        for variant in variants {
+            variant.doc.write(&mut w, lang)?;
+            write_obsolete(&mut w, variant.metadata.deprecated.as_ref())?;
+            let variant_name = variant.name.to_upper_camel_case();
+            write!(w, "public sealed{partial} record {variant_name}")?;
+            match &variant.value {
+                VariantFormat::Unit => {
+                    writeln!(w, "() : {base_name};")?;
+                }
+                VariantFormat::NewType(inner) => {
+                    writeln!(w, "({} Value) : {};", csharp_type(inner), base_name)?;
+                }
+                VariantFormat::Tuple(values) => {
+                    write!(w, "(")?;
+                    for (index, format) in values.iter().enumerate() {
+                        if index > 0 {
+                            write!(w, ", ")?;
+                        }
+                        write!(w, "{} Field{}", csharp_type(format), index)?;
                     }
-                    write!(w, "{} Field{}", csharp_type(format), index)?;
+                    writeln!(w, ") : {base_name};")?;
                 }
-                writeln!(w, ") : {base_name};")?;
-            }
-            VariantFormat::Struct(fields) => {
-                write!(w, "(")?;
-                for (index, field) in fields.iter().enumerate() {
-                    if index > 0 {
-                        write!(w, ", ")?;
+                VariantFormat::Struct(fields) => {
+                    let defaults = fields
+                        .iter()
+                        .map(|field| default_value(field).filter(|_| is_constant(&field.value)))
+                        .collect::<Vec<_>>();
+                    // Optional parameters have to come after all the required ones.
+                    let first_optional = defaults
+                        .iter()
+                        .rposition(Option::is_none)
+                        .map_or(0, |i| i + 1);
+                    write!(w, "(")?;
+                    for (index, field) in fields.iter().enumerate() {
+                        if index > 0 {
+                            write!(w, ", ")?;
+                        }
+                        if let Some(obsolete) = obsolete(field.metadata.deprecated.as_ref()) {
+                            // Applied to the property generated for the parameter.
+                            write!(w, "[property: {obsolete}] ")?;
+                        }
+                        write!(
+                            w,
+                            "{} {}",
+                            field_type(field),
+                            field.name.to_upper_camel_case()
+                        )?;
+                        if index >= first_optional
+                            && let Some(value) = &defaults[index]
+                        {
+                            write!(w, " = {value}")?;
+                        }
                     }
-                    write!(
-                        w,
-                        "{} {}",
-                        csharp_type(&field.value),
-                        field.name.to_upper_camel_case()
-                    )?;
+                    writeln!(w, ") : {base_name};")?;
                 }
-                writeln!(w, ") : {base_name};")?;
+                VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
             }
-            VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+            writeln!(w)?;
+        }
+
+        // Plugin type bodies (JSON helpers or Bincode abstract method + partial record overrides).
+        for plugin in lang.plugins() {
+            plugin.type_body(&mut w as &mut dyn IndentWrite, &ctx)?;
         }
-        writeln!(w)?;
     }
 
-    // Plugin type bodies (JSON helpers or Bincode abstract method + partial record overrides).
+    // After-type content from plugins (e.g. a JSON converter).
     for plugin in lang.plugins() {
-        plugin.type_body(&mut w as &mut dyn IndentWrite, &ctx)?;
+        plugin.after_type(w as &mut dyn IndentWrite, &ctx)?;
     }
 
     Ok(())
 }
 
+/// Writes the `[Obsolete]` attribute of a deprecated declaration, if it is one.
+fn write_obsolete<W: IndentWrite>(w: &mut W, deprecated: Option<&Deprecation>) -> Result<()> {
+    match obsolete(deprecated) {
+        Some(obsolete) => writeln!(w, "[{obsolete}]"),
+        None => Ok(()),
+    }
+}
+
+/// The `Obsolete` attribute of a deprecated declaration, without brackets, if it is one.
+fn obsolete(deprecated: Option<&Deprecation>) -> Option<String> {
+    Some(match deprecated?.message() {
+        Some(message) => {
+            let message = message.replace('\\', "\\\\").replace('"', "\\\"");
+            format!(r#"Obsolete("{message}")"#)
+        }
+        None => "Obsolete".to_string(),
+    })
+}
+
+/// The access modifier of a top-level declaration with the given visibility.
+pub(crate) const fn access_modifier(visibility: Visibility) -> &'static str {
+    match visibility {
+        Visibility::Public => "public",
+        Visibility::Internal => "internal",
+    }
+}
+
+/// The access modifier of the declaration of `container`.
+fn container_access(container: &Container<'_>, lang: &CSharp) -> &'static str {
+    access_modifier(
+        lang.config
+            .visibility(container.format.metadata().visibility),
+    )
+}
+
 fn csharp_type(format: &Format) -> String {
     match format {
         Format::Variable(_) => unreachable!("placeholders should not get this far"),
         Format::TypeName(qualified_type_name) => format_qualified_type_name(qualified_type_name),
+        Format::Generic { name, args } => {
+            let args = args.iter().map(csharp_type).collect::<Vec<_>>().join(", ");
+            format!("{}<{args}>", format_qualified_type_name(name))
+        }
+        Format::TypeParameter(name) => name.clone(),
         Format::Unit => "Unit".to_string(),
         Format::Bool => "bool".to_string(),
         Format::I8 => "sbyte".to_string(),
@@ -484,6 +743,10 @@ fn csharp_type(format: &Format) -> String {
         Format::Str => "string".to_string(),
         Format::Bytes => "byte[]".to_string(),
         Format::Uuid => "Guid".to_string(),
+        Format::Timestamp => "DateTimeOffset".to_string(),
+        Format::Date => "DateOnly".to_string(),
+        Format::Time => "TimeOnly".to_string(),
+        Format::Duration => "TimeSpan".to_string(),
         Format::Option(inner) => format!("{}?", csharp_type(inner)),
         Format::Seq(inner) => format!("ObservableCollection<{}>", csharp_type(inner)),
         Format::Set(inner) => format!("HashSet<{}>", csharp_type(inner)),
@@ -505,6 +768,16 @@ fn csharp_type(format: &Format) -> String {
     }
 }
 
+/// The type parameter list of a generic type (e.g. `<T, E>`), or an empty
+/// string for a non-generic type.
+fn type_params(params: &[String]) -> String {
+    if params.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", params.join(", "))
+    }
+}
+
 fn format_qualified_type_name(qualified_type_name: &QualifiedTypeName) -> String {
     match &qualified_type_name.namespace {
         Namespace::Root => qualified_type_name.name.to_upper_camel_case(),
@@ -753,3 +753,77 @@ fn struct_with_external_namespace_type() {
     }
     ");
 }
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as CSharp).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    public partial class Listing : ObservableObject {
+        [ObservableProperty]
+        private Page<string> _names;
+        [ObservableProperty]
+        private Page<uint> _ids;
+    }
+
+    public partial class Page<T> : ObservableObject {
+        [ObservableProperty]
+        private ObservableCollection<T> _items;
+        [ObservableProperty]
+        private T? _next;
+        [ObservableProperty]
+        private uint _total;
+    }
+    ");
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E, retry: bool },
+        Pending,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        first: Outcome<String, i32>,
+        second: Outcome<bool, String>,
+    }
+
+    let actual = emit!(Response as CSharp).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    public abstract record Outcome<T, E> {
+        public sealed record Success(T Value) : Outcome<T, E>;
+
+        public sealed record Failure(E Error, bool Retry) : Outcome<T, E>;
+
+        public sealed record Pending() : Outcome<T, E>;
+
+    }
+
+    public partial class Response : ObservableObject {
+        [ObservableProperty]
+        private Outcome<string, int> _first;
+        [ObservableProperty]
+        private Outcome<bool, string> _second;
+    }
+    ");
+}
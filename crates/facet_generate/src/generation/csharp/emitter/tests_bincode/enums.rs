@@ -7,7 +7,7 @@
 use facet::Facet;
 
 use super::super::*;
-use crate::{emit, generation::bincode::BincodePlugin};
+use crate::{self as fg, emit, generation::bincode::BincodePlugin};
 
 #[test]
 fn struct_variant_with_csharp_keyword_fields_escapes_deserialize_locals() {
@@ -663,3 +663,533 @@ fn c_style_enum_field_uses_static_bincode_helpers() {
         "c-style enum deserialize should dispatch to static helper\n{actual}"
     );
 }
+
+#[test]
+fn generic_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E, retry: bool },
+        Pending,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        first: Outcome<String, i32>,
+        second: Outcome<bool, String>,
+    }
+
+    let actual = emit!(Response as CSharp with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public abstract record Outcome<T, E> {
+        public sealed partial record Success(T Value) : Outcome<T, E>;
+
+        public sealed partial record Failure(E Error, bool Retry) : Outcome<T, E>;
+
+        public sealed partial record Pending() : Outcome<T, E>;
+
+        public abstract void Serialize(ISerializer serializer, Action<T, ISerializer> serializeT, Action<E, ISerializer> serializeE);
+
+        private static Outcome<T, E> DeserializeSuccess(IDeserializer deserializer, Func<IDeserializer, T> deserializeT, Func<IDeserializer, E> deserializeE)
+        {
+            var value = deserializeT(deserializer);
+            return new Success(value);
+        }
+
+        public sealed partial record Success
+        {
+            public override void Serialize(ISerializer serializer, Action<T, ISerializer> serializeT, Action<E, ISerializer> serializeE)
+            {
+                serializer.IncreaseContainerDepth();
+                serializer.SerializeVariantIndex(0);
+                serializeT(Value, serializer);
+                serializer.DecreaseContainerDepth();
+            }
+
+        }
+        private static Outcome<T, E> DeserializeFailure(IDeserializer deserializer, Func<IDeserializer, T> deserializeT, Func<IDeserializer, E> deserializeE)
+        {
+            var error = deserializeE(deserializer);
+            var retry = deserializer.DeserializeBool();
+            return new Failure(error, retry);
+        }
+
+        public sealed partial record Failure
+        {
+            public override void Serialize(ISerializer serializer, Action<T, ISerializer> serializeT, Action<E, ISerializer> serializeE)
+            {
+                serializer.IncreaseContainerDepth();
+                serializer.SerializeVariantIndex(1);
+                serializeE(Error, serializer);
+                serializer.SerializeBool(Retry);
+                serializer.DecreaseContainerDepth();
+            }
+
+        }
+        private static Outcome<T, E> DeserializePending(IDeserializer deserializer, Func<IDeserializer, T> deserializeT, Func<IDeserializer, E> deserializeE)
+        {
+            return new Pending();
+        }
+
+        public sealed partial record Pending
+        {
+            public override void Serialize(ISerializer serializer, Action<T, ISerializer> serializeT, Action<E, ISerializer> serializeE)
+            {
+                serializer.IncreaseContainerDepth();
+                serializer.SerializeVariantIndex(2);
+                serializer.DecreaseContainerDepth();
+            }
+
+        }
+        public static Outcome<T, E> Deserialize(IDeserializer deserializer, Func<IDeserializer, T> deserializeT, Func<IDeserializer, E> deserializeE)
+        {
+            var index = deserializer.DeserializeVariantIndex();
+            return index switch
+            {
+                0 => DeserializeSuccess(deserializer, deserializeT, deserializeE),
+                1 => DeserializeFailure(deserializer, deserializeT, deserializeE),
+                2 => DeserializePending(deserializer, deserializeT, deserializeE),
+                _ => throw new DeserializationError("Unknown variant index for Outcome: " + index),
+            }
+            ;
+        }
+
+        public byte[] BincodeSerialize(Action<T, ISerializer> serializeT, Action<E, ISerializer> serializeE)
+        {
+            var serializer = new BincodeSerializer();
+            Serialize(serializer, serializeT, serializeE);
+            return serializer.GetBytes();
+        }
+
+        public static Outcome<T, E> BincodeDeserialize(byte[] input, Func<IDeserializer, T> deserializeT, Func<IDeserializer, E> deserializeE)
+        {
+            if (input is null)
+            {
+                throw new DeserializationError("Cannot deserialize null array");
+            }
+            var deserializer = new BincodeDeserializer(input);
+            var value = Deserialize(deserializer, deserializeT, deserializeE);
+            if (deserializer.GetBufferOffset() < input.Length)
+            {
+                throw new DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+
+    public partial class Response : ObservableObject, IFacetSerializable, IFacetDeserializable<Response> {
+        [ObservableProperty]
+        private Outcome<string, int> _first;
+        [ObservableProperty]
+        private Outcome<bool, string> _second;
+
+        public void Serialize(ISerializer serializer)
+        {
+            serializer.IncreaseContainerDepth();
+            First.Serialize(serializer, (item, s) => s.SerializeStr(item), (item, s) => s.SerializeI32(item));
+            Second.Serialize(serializer, (item, s) => s.SerializeBool(item), (item, s) => s.SerializeStr(item));
+            serializer.DecreaseContainerDepth();
+        }
+
+        public static Response Deserialize(IDeserializer deserializer)
+        {
+            deserializer.IncreaseContainerDepth();
+            var first = Outcome<string, int>.Deserialize(deserializer, d => d.DeserializeStr(), d => d.DeserializeI32());
+            var second = Outcome<bool, string>.Deserialize(deserializer, d => d.DeserializeBool(), d => d.DeserializeStr());
+            deserializer.DecreaseContainerDepth();
+            return new Response {
+                First = first,
+                Second = second,
+            };
+        }
+
+        public byte[] BincodeSerialize()
+        {
+            var serializer = new BincodeSerializer();
+            Serialize(serializer);
+            return serializer.GetBytes();
+        }
+
+        public static Response BincodeDeserialize(byte[] input)
+        {
+            if (input is null)
+            {
+                throw new DeserializationError("Cannot deserialize null array");
+            }
+            var deserializer = new BincodeDeserializer(input);
+            var value = Deserialize(deserializer);
+            if (deserializer.GetBufferOffset() < input.Length)
+            {
+                throw new DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
+
+#[test]
+fn enum_with_pinned_indices() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Status {
+        Active,
+        #[facet(fg::index = 3)]
+        Suspended(String),
+        Closed,
+    }
+
+    let actual = emit!(Status as CSharp with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public abstract record Status : IFacetSerializable, IFacetDeserializable<Status> {
+        public sealed partial record Active() : Status;
+
+        public sealed partial record Suspended(string Value) : Status;
+
+        public sealed partial record Closed() : Status;
+
+        public abstract void Serialize(ISerializer serializer);
+
+        private static Status DeserializeActive(IDeserializer deserializer)
+        {
+            return new Active();
+        }
+
+        public sealed partial record Active
+        {
+            public override void Serialize(ISerializer serializer)
+            {
+                serializer.IncreaseContainerDepth();
+                serializer.SerializeVariantIndex(0);
+                serializer.DecreaseContainerDepth();
+            }
+
+        }
+        private static Status DeserializeSuspended(IDeserializer deserializer)
+        {
+            var value = deserializer.DeserializeStr();
+            return new Suspended(value);
+        }
+
+        public sealed partial record Suspended
+        {
+            public override void Serialize(ISerializer serializer)
+            {
+                serializer.IncreaseContainerDepth();
+                serializer.SerializeVariantIndex(3);
+                serializer.SerializeStr(Value);
+                serializer.DecreaseContainerDepth();
+            }
+
+        }
+        private static Status DeserializeClosed(IDeserializer deserializer)
+        {
+            return new Closed();
+        }
+
+        public sealed partial record Closed
+        {
+            public override void Serialize(ISerializer serializer)
+            {
+                serializer.IncreaseContainerDepth();
+                serializer.SerializeVariantIndex(4);
+                serializer.DecreaseContainerDepth();
+            }
+
+        }
+        public static Status Deserialize(IDeserializer deserializer)
+        {
+            var index = deserializer.DeserializeVariantIndex();
+            return index switch
+            {
+                0 => DeserializeActive(deserializer),
+                3 => DeserializeSuspended(deserializer),
+                4 => DeserializeClosed(deserializer),
+                _ => throw new DeserializationError("Unknown variant index for Status: " + index),
+            }
+            ;
+        }
+
+        public byte[] BincodeSerialize()
+        {
+            var serializer = new BincodeSerializer();
+            Serialize(serializer);
+            return serializer.GetBytes();
+        }
+
+        public static Status BincodeDeserialize(byte[] input)
+        {
+            if (input is null)
+            {
+                throw new DeserializationError("Cannot deserialize null array");
+            }
+            var deserializer = new BincodeDeserializer(input);
+            var value = Deserialize(deserializer);
+            if (deserializer.GetBufferOffset() < input.Length)
+            {
+                throw new DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
+
+#[test]
+fn enum_with_other_variant() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Created(String),
+        Deleted {
+            id: u64,
+        },
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Event as CSharp with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public abstract record Event : IFacetSerializable, IFacetDeserializable<Event> {
+        public sealed partial record Created(string Value) : Event;
+
+        public sealed partial record Deleted(ulong Id) : Event;
+
+        public sealed partial record Unknown() : Event;
+
+        public abstract void Serialize(ISerializer serializer);
+
+        private static Event DeserializeCreated(IDeserializer deserializer)
+        {
+            var value = deserializer.DeserializeStr();
+            return new Created(value);
+        }
+
+        public sealed partial record Created
+        {
+            public override void Serialize(ISerializer serializer)
+            {
+                serializer.IncreaseContainerDepth();
+                serializer.SerializeVariantIndex(0);
+                serializer.SerializeStr(Value);
+                serializer.DecreaseContainerDepth();
+            }
+
+        }
+        private static Event DeserializeDeleted(IDeserializer deserializer)
+        {
+            var id = deserializer.DeserializeU64();
+            return new Deleted(id);
+        }
+
+        public sealed partial record Deleted
+        {
+            public override void Serialize(ISerializer serializer)
+            {
+                serializer.IncreaseContainerDepth();
+                serializer.SerializeVariantIndex(1);
+                serializer.SerializeU64(Id);
+                serializer.DecreaseContainerDepth();
+            }
+
+        }
+        private static Event DeserializeUnknown(IDeserializer deserializer)
+        {
+            return new Unknown();
+        }
+
+        public sealed partial record Unknown
+        {
+            public override void Serialize(ISerializer serializer)
+            {
+                serializer.IncreaseContainerDepth();
+                serializer.SerializeVariantIndex(2);
+                serializer.DecreaseContainerDepth();
+            }
+
+        }
+        public static Event Deserialize(IDeserializer deserializer)
+        {
+            var index = deserializer.DeserializeVariantIndex();
+            return index switch
+            {
+                0 => DeserializeCreated(deserializer),
+                1 => DeserializeDeleted(deserializer),
+                2 => DeserializeUnknown(deserializer),
+                _ => DeserializeUnknown(deserializer),
+            }
+            ;
+        }
+
+        public byte[] BincodeSerialize()
+        {
+            var serializer = new BincodeSerializer();
+            Serialize(serializer);
+            return serializer.GetBytes();
+        }
+
+        public static Event BincodeDeserialize(byte[] input)
+        {
+            if (input is null)
+            {
+                throw new DeserializationError("Cannot deserialize null array");
+            }
+            var deserializer = new BincodeDeserializer(input);
+            var value = Deserialize(deserializer);
+            if (deserializer.GetBufferOffset() < input.Length)
+            {
+                throw new DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
+
+#[test]
+fn unit_enum_with_pinned_indices() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Level {
+        Low,
+        #[facet(fg::index = 5)]
+        High,
+    }
+
+    let actual = emit!(Level as CSharp with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public enum Level {
+        Low = 0,
+        High = 5
+    }
+
+    /// <summary>
+    /// Bincode serialization helpers for <see cref="Level"/>.
+    /// </summary>
+    public static class LevelBincode {
+        public static void Serialize(Level value, ISerializer serializer)
+        {
+            serializer.IncreaseContainerDepth();
+            serializer.SerializeVariantIndex((uint)value);
+            serializer.DecreaseContainerDepth();
+        }
+
+        public static Level Deserialize(IDeserializer deserializer)
+        {
+            deserializer.IncreaseContainerDepth();
+            var index = deserializer.DeserializeVariantIndex();
+            deserializer.DecreaseContainerDepth();
+            return index switch
+            {
+                0 => Level.Low,
+                5 => Level.High,
+                _ => throw new DeserializationError("Unknown variant index for Level: " + index),
+            }
+            ;
+        }
+
+        public static byte[] BincodeSerialize(Level value)
+        {
+            var serializer = new BincodeSerializer();
+            Serialize(value, serializer);
+            return serializer.GetBytes();
+        }
+
+        public static Level BincodeDeserialize(byte[] input)
+        {
+            if (input is null)
+            {
+                throw new DeserializationError("Cannot deserialize null array");
+            }
+            var deserializer = new BincodeDeserializer(input);
+            var value = Deserialize(deserializer);
+            if (deserializer.GetBufferOffset() < input.Length)
+            {
+                throw new DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
+
+#[test]
+fn unit_enum_with_other_variant() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Source {
+        Embedded,
+        Custom,
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Source as CSharp with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public enum Source {
+        Embedded,
+        Custom,
+        Unknown
+    }
+
+    /// <summary>
+    /// Bincode serialization helpers for <see cref="Source"/>.
+    /// </summary>
+    public static class SourceBincode {
+        public static void Serialize(Source value, ISerializer serializer)
+        {
+            serializer.IncreaseContainerDepth();
+            serializer.SerializeVariantIndex((uint)value);
+            serializer.DecreaseContainerDepth();
+        }
+
+        public static Source Deserialize(IDeserializer deserializer)
+        {
+            deserializer.IncreaseContainerDepth();
+            var index = deserializer.DeserializeVariantIndex();
+            deserializer.DecreaseContainerDepth();
+            return index switch
+            {
+                0 => Source.Embedded,
+                1 => Source.Custom,
+                2 => Source.Unknown,
+                _ => Source.Unknown,
+            }
+            ;
+        }
+
+        public static byte[] BincodeSerialize(Source value)
+        {
+            var serializer = new BincodeSerializer();
+            Serialize(value, serializer);
+            return serializer.GetBytes();
+        }
+
+        public static Source BincodeDeserialize(byte[] input)
+        {
+            if (input is null)
+            {
+                throw new DeserializationError("Cannot deserialize null array");
+            }
+            var deserializer = new BincodeDeserializer(input);
+            var value = Deserialize(deserializer);
+            if (deserializer.GetBufferOffset() < input.Length)
+            {
+                throw new DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
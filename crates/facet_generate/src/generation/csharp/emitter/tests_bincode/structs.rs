@@ -143,6 +143,113 @@ fn newtype_struct() {
     "#);
 }
 
+#[test]
+fn branded_newtype_struct() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    #[derive(Facet)]
+    struct User {
+        id: UserId,
+        manager: Option<UserId>,
+    }
+
+    let actual = emit!(User as CSharp with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public partial class User : ObservableObject, IFacetSerializable, IFacetDeserializable<User> {
+        [ObservableProperty]
+        private UserId _id;
+        [ObservableProperty]
+        private UserId? _manager;
+
+        public void Serialize(ISerializer serializer)
+        {
+            serializer.IncreaseContainerDepth();
+            Id.Serialize(serializer);
+            FacetHelpers.SerializeOption(Manager, serializer, (item, s) => item.Serialize(s));
+            serializer.DecreaseContainerDepth();
+        }
+
+        public static User Deserialize(IDeserializer deserializer)
+        {
+            deserializer.IncreaseContainerDepth();
+            var id = UserId.Deserialize(deserializer);
+            var manager = FacetHelpers.DeserializeOption(deserializer, d => UserId.Deserialize(d));
+            deserializer.DecreaseContainerDepth();
+            return new User {
+                Id = id,
+                Manager = manager,
+            };
+        }
+
+        public byte[] BincodeSerialize()
+        {
+            var serializer = new BincodeSerializer();
+            Serialize(serializer);
+            return serializer.GetBytes();
+        }
+
+        public static User BincodeDeserialize(byte[] input)
+        {
+            if (input is null)
+            {
+                throw new DeserializationError("Cannot deserialize null array");
+            }
+            var deserializer = new BincodeDeserializer(input);
+            var value = Deserialize(deserializer);
+            if (deserializer.GetBufferOffset() < input.Length)
+            {
+                throw new DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+
+    public readonly record struct UserId(string Value) : IFacetSerializable, IFacetDeserializable<UserId> {
+        public void Serialize(ISerializer serializer)
+        {
+            serializer.IncreaseContainerDepth();
+            serializer.SerializeStr(Value);
+            serializer.DecreaseContainerDepth();
+        }
+
+        public static UserId Deserialize(IDeserializer deserializer)
+        {
+            deserializer.IncreaseContainerDepth();
+            var value = deserializer.DeserializeStr();
+            deserializer.DecreaseContainerDepth();
+            return new UserId {
+                Value = value,
+            };
+        }
+
+        public byte[] BincodeSerialize()
+        {
+            var serializer = new BincodeSerializer();
+            Serialize(serializer);
+            return serializer.GetBytes();
+        }
+
+        public static UserId BincodeDeserialize(byte[] input)
+        {
+            if (input is null)
+            {
+                throw new DeserializationError("Cannot deserialize null array");
+            }
+            var deserializer = new BincodeDeserializer(input);
+            var value = Deserialize(deserializer);
+            if (deserializer.GetBufferOffset() < input.Length)
+            {
+                throw new DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
+
 #[test]
 fn tuple_struct() {
     #[derive(Facet)]
@@ -893,3 +1000,249 @@ fn struct_with_bytes_field_and_slice() {
     }
     "#);
 }
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as CSharp with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public partial class Listing : ObservableObject, IFacetSerializable, IFacetDeserializable<Listing> {
+        [ObservableProperty]
+        private Page<string> _names;
+        [ObservableProperty]
+        private Page<uint> _ids;
+
+        public void Serialize(ISerializer serializer)
+        {
+            serializer.IncreaseContainerDepth();
+            Names.Serialize(serializer, (item, s) => s.SerializeStr(item));
+            Ids.Serialize(serializer, (item, s) => s.SerializeU32(item));
+            serializer.DecreaseContainerDepth();
+        }
+
+        public static Listing Deserialize(IDeserializer deserializer)
+        {
+            deserializer.IncreaseContainerDepth();
+            var names = Page<string>.Deserialize(deserializer, d => d.DeserializeStr());
+            var ids = Page<uint>.Deserialize(deserializer, d => d.DeserializeU32());
+            deserializer.DecreaseContainerDepth();
+            return new Listing {
+                Names = names,
+                Ids = ids,
+            };
+        }
+
+        public byte[] BincodeSerialize()
+        {
+            var serializer = new BincodeSerializer();
+            Serialize(serializer);
+            return serializer.GetBytes();
+        }
+
+        public static Listing BincodeDeserialize(byte[] input)
+        {
+            if (input is null)
+            {
+                throw new DeserializationError("Cannot deserialize null array");
+            }
+            var deserializer = new BincodeDeserializer(input);
+            var value = Deserialize(deserializer);
+            if (deserializer.GetBufferOffset() < input.Length)
+            {
+                throw new DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+
+    public partial class Page<T> : ObservableObject {
+        [ObservableProperty]
+        private ObservableCollection<T> _items;
+        [ObservableProperty]
+        private T? _next;
+        [ObservableProperty]
+        private uint _total;
+
+        public void Serialize(ISerializer serializer, Action<T, ISerializer> serializeT)
+        {
+            serializer.IncreaseContainerDepth();
+            FacetHelpers.SerializeCollection(Items, serializer, (item, s) => serializeT(item, s));
+            FacetHelpers.SerializeOptionGeneric(Next, serializer, (item, s) => serializeT(item, s));
+            serializer.SerializeU32(Total);
+            serializer.DecreaseContainerDepth();
+        }
+
+        public static Page<T> Deserialize(IDeserializer deserializer, Func<IDeserializer, T> deserializeT)
+        {
+            deserializer.IncreaseContainerDepth();
+            var items = FacetHelpers.DeserializeList(deserializer, d => deserializeT(d));
+            var next = FacetHelpers.DeserializeOptionGeneric(deserializer, d => deserializeT(d));
+            var total = deserializer.DeserializeU32();
+            deserializer.DecreaseContainerDepth();
+            return new Page<T> {
+                Items = items,
+                Next = next,
+                Total = total,
+            };
+        }
+
+        public byte[] BincodeSerialize(Action<T, ISerializer> serializeT)
+        {
+            var serializer = new BincodeSerializer();
+            Serialize(serializer, serializeT);
+            return serializer.GetBytes();
+        }
+
+        public static Page<T> BincodeDeserialize(byte[] input, Func<IDeserializer, T> deserializeT)
+        {
+            if (input is null)
+            {
+                throw new DeserializationError("Cannot deserialize null array");
+            }
+            var deserializer = new BincodeDeserializer(input);
+            var value = Deserialize(deserializer, deserializeT);
+            if (deserializer.GetBufferOffset() < input.Length)
+            {
+                throw new DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
+
+#[test]
+fn type_override() {
+    #[derive(Facet)]
+    struct Account {
+        #[facet(fg::override(
+            lang = "csharp",
+            ty = "decimal",
+            serialize = "serializer.SerializeStr({value}.ToString(CultureInfo.InvariantCulture));",
+            deserialize = "decimal.Parse(deserializer.DeserializeStr(), CultureInfo.InvariantCulture)"
+        ))]
+        balance: String,
+    }
+
+    let actual = emit!(Account as CSharp with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public partial class Account : ObservableObject, IFacetSerializable, IFacetDeserializable<Account> {
+        [ObservableProperty]
+        private decimal _balance;
+
+        public void Serialize(ISerializer serializer)
+        {
+            serializer.IncreaseContainerDepth();
+            serializer.SerializeStr(Balance.ToString(CultureInfo.InvariantCulture));
+            serializer.DecreaseContainerDepth();
+        }
+
+        public static Account Deserialize(IDeserializer deserializer)
+        {
+            deserializer.IncreaseContainerDepth();
+            var balance = decimal.Parse(deserializer.DeserializeStr(), CultureInfo.InvariantCulture);
+            deserializer.DecreaseContainerDepth();
+            return new Account {
+                Balance = balance,
+            };
+        }
+
+        public byte[] BincodeSerialize()
+        {
+            var serializer = new BincodeSerializer();
+            Serialize(serializer);
+            return serializer.GetBytes();
+        }
+
+        public static Account BincodeDeserialize(byte[] input)
+        {
+            if (input is null)
+            {
+                throw new DeserializationError("Cannot deserialize null array");
+            }
+            var deserializer = new BincodeDeserializer(input);
+            var value = Deserialize(deserializer);
+            if (deserializer.GetBufferOffset() < input.Length)
+            {
+                throw new DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
+
+#[test]
+fn conformances_and_annotations() {
+    #[derive(Facet)]
+    #[facet(
+        fg::annotate(lang = "csharp", with = "[Serializable]"),
+        fg::conforms(lang = "csharp", to = "IComparable<Counter>")
+    )]
+    struct Counter {
+        count: u32,
+    }
+
+    let actual = emit!(Counter as CSharp with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    [Serializable]
+    public partial class Counter : ObservableObject, IFacetSerializable, IFacetDeserializable<Counter>, IComparable<Counter> {
+        [ObservableProperty]
+        private uint _count;
+
+        public void Serialize(ISerializer serializer)
+        {
+            serializer.IncreaseContainerDepth();
+            serializer.SerializeU32(Count);
+            serializer.DecreaseContainerDepth();
+        }
+
+        public static Counter Deserialize(IDeserializer deserializer)
+        {
+            deserializer.IncreaseContainerDepth();
+            var count = deserializer.DeserializeU32();
+            deserializer.DecreaseContainerDepth();
+            return new Counter {
+                Count = count,
+            };
+        }
+
+        public byte[] BincodeSerialize()
+        {
+            var serializer = new BincodeSerializer();
+            Serialize(serializer);
+            return serializer.GetBytes();
+        }
+
+        public static Counter BincodeDeserialize(byte[] input)
+        {
+            if (input is null)
+            {
+                throw new DeserializationError("Cannot deserialize null array");
+            }
+            var deserializer = new BincodeDeserializer(input);
+            var value = Deserialize(deserializer);
+            if (deserializer.GetBufferOffset() < input.Length)
+            {
+                throw new DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
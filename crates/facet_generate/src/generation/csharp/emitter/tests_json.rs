@@ -71,6 +71,54 @@ fn newtype_struct() {
     "#);
 }
 
+#[test]
+fn branded_newtype_struct() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    #[derive(Facet)]
+    struct User {
+        id: UserId,
+        manager: Option<UserId>,
+    }
+
+    let actual = emit!(User as CSharp with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public partial class User : ObservableObject {
+        [JsonPropertyName("id")]
+        [ObservableProperty]
+        private UserId _id;
+        [JsonPropertyName("manager")]
+        [ObservableProperty]
+        private UserId? _manager;
+
+        public string JsonSerialize()
+        {
+            return JsonSerde.Serialize(this);
+        }
+
+        public static User JsonDeserialize(string input)
+        {
+            return JsonSerde.Deserialize<User>(input);
+        }
+    }
+
+    public readonly record struct UserId(string Value) : IBranded {
+        public string JsonSerialize()
+        {
+            return JsonSerde.Serialize(this);
+        }
+
+        public static UserId JsonDeserialize(string input)
+        {
+            return JsonSerde.Deserialize<UserId>(input);
+        }
+    }
+    "#);
+}
+
 #[test]
 fn tuple_struct() {
     #[derive(Facet)]
@@ -583,6 +631,202 @@ fn enum_with_mixed_variants() {
     "#);
 }
 
+#[test]
+fn unit_enum_with_other_variant() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Source {
+        Embedded,
+        Custom,
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Source as CSharp with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    [JsonConverter(typeof(SourceJsonConverter))]
+    public enum Source {
+        Embedded,
+        Custom,
+        Unknown
+    }
+
+    /// <summary>
+    /// JSON converter for <see cref="Source"/> that reads unknown variants as <see cref="Source.Unknown"/>.
+    /// </summary>
+    public sealed class SourceJsonConverter : JsonConverter<Source> {
+        public override Source Read(ref System.Text.Json.Utf8JsonReader reader, System.Type typeToConvert, System.Text.Json.JsonSerializerOptions options) {
+            return reader.GetString() switch {
+                "Embedded" => Source.Embedded,
+                "Custom" => Source.Custom,
+                "Unknown" => Source.Unknown,
+                _ => Source.Unknown,
+            };
+        }
+
+        public override void Write(System.Text.Json.Utf8JsonWriter writer, Source value, System.Text.Json.JsonSerializerOptions options) {
+            writer.WriteStringValue(value.ToString());
+        }
+    }
+    "#);
+}
+
+#[test]
+fn enum_with_other_variant() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Created(String),
+        Deleted {
+            id: u64,
+        },
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Event as CSharp with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    [JsonConverter(typeof(EventJsonConverter))]
+    public abstract record Event {
+        public sealed record Created(string Value) : Event;
+
+        public sealed record Deleted(ulong Id) : Event;
+
+        public sealed record Unknown() : Event;
+
+        public string JsonSerialize()
+        {
+            return JsonSerde.Serialize(this);
+        }
+
+        public static Event JsonDeserialize(string input)
+        {
+            return JsonSerde.Deserialize<Event>(input);
+        }
+    }
+
+    /// <summary>
+    /// JSON converter for <see cref="Event"/> that reads unknown variants as <see cref="Event.Unknown"/>.
+    /// </summary>
+    public sealed class EventJsonConverter : JsonConverter<Event> {
+        public override Event Read(ref System.Text.Json.Utf8JsonReader reader, System.Type typeToConvert, System.Text.Json.JsonSerializerOptions options) {
+            using var document = System.Text.Json.JsonDocument.ParseValue(ref reader);
+            var element = document.RootElement;
+            var type = element.TryGetProperty("type", out var tag) ? tag.GetString() : null;
+            return type switch {
+                "Created" => System.Text.Json.JsonSerializer.Deserialize<Event.Created>(element, options)!,
+                "Deleted" => System.Text.Json.JsonSerializer.Deserialize<Event.Deleted>(element, options)!,
+                "Unknown" => System.Text.Json.JsonSerializer.Deserialize<Event.Unknown>(element, options)!,
+                _ => new Event.Unknown(),
+            };
+        }
+
+        public override void Write(System.Text.Json.Utf8JsonWriter writer, Event value, System.Text.Json.JsonSerializerOptions options) {
+            var type = value switch {
+                Event.Created => "Created",
+                Event.Deleted => "Deleted",
+                Event.Unknown => "Unknown",
+                _ => throw new System.ArgumentOutOfRangeException(nameof(value)),
+            };
+            writer.WriteStartObject();
+            writer.WriteString("type", type);
+            foreach (var property in System.Text.Json.JsonSerializer.SerializeToElement(value, value.GetType(), options).EnumerateObject()) {
+                property.WriteTo(writer);
+            }
+            writer.WriteEndObject();
+        }
+    }
+    "#);
+}
+
+#[test]
+fn untagged_enum() {
+    #[derive(Facet)]
+    #[facet(untagged)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Value {
+        Number(i32),
+        Point(i32, i32),
+        Named { name: String, alias: Option<String> },
+        Nothing,
+    }
+
+    let actual = emit!(Value as CSharp with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    [JsonConverter(typeof(ValueJsonConverter))]
+    public abstract record Value {
+        public sealed record Number(int Value) : Value;
+
+        public sealed record Point(int Field0, int Field1) : Value;
+
+        public sealed record Named(string Name, string? Alias) : Value;
+
+        public sealed record Nothing() : Value;
+
+        public string JsonSerialize()
+        {
+            return JsonSerde.Serialize(this);
+        }
+
+        public static Value JsonDeserialize(string input)
+        {
+            return JsonSerde.Deserialize<Value>(input);
+        }
+    }
+
+    /// <summary>
+    /// JSON converter for the untagged <see cref="Value"/>, which reads the first variant that matches.
+    /// </summary>
+    public sealed class ValueJsonConverter : JsonConverter<Value> {
+        public override bool HandleNull => true;
+
+        public override Value Read(ref System.Text.Json.Utf8JsonReader reader, System.Type typeToConvert, System.Text.Json.JsonSerializerOptions options) {
+            using var document = System.Text.Json.JsonDocument.ParseValue(ref reader);
+            var element = document.RootElement;
+            if (JsonSerde.TryDeserialize<Value.Number>(JsonSerde.ToObject(("Value", element)), options, out var variant0)) return variant0;
+            if (element.ValueKind == System.Text.Json.JsonValueKind.Array && element.GetArrayLength() == 2 && JsonSerde.TryDeserialize<Value.Point>(JsonSerde.ToObject(("Field0", element[0]), ("Field1", element[1])), options, out var variant1)) return variant1;
+            if (element.ValueKind == System.Text.Json.JsonValueKind.Object && element.TryGetProperty("name", out _) && JsonSerde.TryDeserialize<Value.Named>(JsonSerde.ToObject(("Name", JsonSerde.Property(element, "name")), ("Alias", JsonSerde.Property(element, "alias"))), options, out var variant2)) return variant2;
+            if (element.ValueKind == System.Text.Json.JsonValueKind.Null) return new Value.Nothing();
+            throw new System.Text.Json.JsonException("No variant of Value matches the input");
+        }
+
+        public override void Write(System.Text.Json.Utf8JsonWriter writer, Value value, System.Text.Json.JsonSerializerOptions options) {
+            var element = System.Text.Json.JsonSerializer.SerializeToElement(value, value.GetType(), options);
+            switch (value) {
+                case Value.Number:
+                    element.GetProperty("Value").WriteTo(writer);
+                    break;
+                case Value.Point:
+                    writer.WriteStartArray();
+                    element.GetProperty("Field0").WriteTo(writer);
+                    element.GetProperty("Field1").WriteTo(writer);
+                    writer.WriteEndArray();
+                    break;
+                case Value.Named:
+                    writer.WriteStartObject();
+                    writer.WritePropertyName("name");
+                    element.GetProperty("Name").WriteTo(writer);
+                    writer.WritePropertyName("alias");
+                    element.GetProperty("Alias").WriteTo(writer);
+                    writer.WriteEndObject();
+                    break;
+                case Value.Nothing:
+                    writer.WriteNullValue();
+                    break;
+                default:
+                    throw new System.ArgumentOutOfRangeException(nameof(value));
+            }
+        }
+    }
+    "#);
+}
+
 #[test]
 fn struct_with_vec_field() {
     #[derive(Facet)]
@@ -1086,3 +1330,398 @@ fn struct_with_bytes_field_and_slice() {
     }
     "#);
 }
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as CSharp with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public partial class Listing : ObservableObject {
+        [JsonPropertyName("names")]
+        [ObservableProperty]
+        private Page<string> _names;
+        [JsonPropertyName("ids")]
+        [ObservableProperty]
+        private Page<uint> _ids;
+
+        public string JsonSerialize()
+        {
+            return JsonSerde.Serialize(this);
+        }
+
+        public static Listing JsonDeserialize(string input)
+        {
+            return JsonSerde.Deserialize<Listing>(input);
+        }
+    }
+
+    public partial class Page<T> : ObservableObject {
+        [JsonPropertyName("items")]
+        [ObservableProperty]
+        private ObservableCollection<T> _items;
+        [JsonPropertyName("next")]
+        [ObservableProperty]
+        private T? _next;
+        [JsonPropertyName("total")]
+        [ObservableProperty]
+        private uint _total;
+
+        public string JsonSerialize()
+        {
+            return JsonSerde.Serialize(this);
+        }
+
+        public static Page<T> JsonDeserialize(string input)
+        {
+            return JsonSerde.Deserialize<Page<T>>(input);
+        }
+    }
+    "#);
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E, retry: bool },
+        Pending,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        first: Outcome<String, i32>,
+        second: Outcome<bool, String>,
+    }
+
+    let actual = emit!(Response as CSharp with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public abstract record Outcome<T, E> {
+        public sealed partial record Success(T Value) : Outcome<T, E>;
+
+        public sealed partial record Failure(E Error, bool Retry) : Outcome<T, E>;
+
+        public sealed partial record Pending() : Outcome<T, E>;
+
+        public string JsonSerialize()
+        {
+            return JsonSerde.Serialize(this);
+        }
+
+        public static Outcome<T, E> JsonDeserialize(string input)
+        {
+            return JsonSerde.Deserialize<Outcome<T, E>>(input);
+        }
+    }
+
+    public partial class Response : ObservableObject {
+        [JsonPropertyName("first")]
+        [ObservableProperty]
+        private Outcome<string, int> _first;
+        [JsonPropertyName("second")]
+        [ObservableProperty]
+        private Outcome<bool, string> _second;
+
+        public string JsonSerialize()
+        {
+            return JsonSerde.Serialize(this);
+        }
+
+        public static Response JsonDeserialize(string input)
+        {
+            return JsonSerde.Deserialize<Response>(input);
+        }
+    }
+    "#);
+}
+
+#[test]
+fn defaults() {
+    #[derive(Facet)]
+    struct Settings {
+        #[facet(default = "guest".to_string())]
+        user: String,
+        #[facet(fg::readonly, default = 'x')]
+        marker: char,
+        #[facet(default)]
+        tags: Vec<String>,
+        #[facet(default = i128::MAX)]
+        limit: i128,
+        retries: u32,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Command {
+        Move {
+            #[facet(default)]
+            speed: u32,
+            x: i32,
+            #[facet(default = 1.5)]
+            scale: f64,
+        },
+        Stop,
+    }
+
+    let actual = emit!(Settings, Command as CSharp with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    [JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+    [JsonDerivedType(typeof(Move), "Move")]
+    [JsonDerivedType(typeof(Stop), "Stop")]
+    public abstract record Command {
+        public sealed record Move(uint Speed, int X, double Scale = 1.5) : Command;
+
+        public sealed record Stop() : Command;
+
+        public string JsonSerialize()
+        {
+            return JsonSerde.Serialize(this);
+        }
+
+        public static Command JsonDeserialize(string input)
+        {
+            return JsonSerde.Deserialize<Command>(input);
+        }
+    }
+
+    public partial class Settings : ObservableObject {
+        [JsonPropertyName("user")]
+        [ObservableProperty]
+        private string _user = "guest";
+        [JsonPropertyName("marker")]
+        public char Marker { get; init; } = 'x';
+        [JsonPropertyName("tags")]
+        [ObservableProperty]
+        private ObservableCollection<string> _tags = [];
+        [JsonPropertyName("limit")]
+        [ObservableProperty]
+        private Int128 _limit = Int128.Parse("170141183460469231731687303715884105727");
+        [JsonPropertyName("retries")]
+        [ObservableProperty]
+        private uint _retries;
+
+        public string JsonSerialize()
+        {
+            return JsonSerde.Serialize(this);
+        }
+
+        public static Settings JsonDeserialize(string input)
+        {
+            return JsonSerde.Deserialize<Settings>(input);
+        }
+    }
+    "#);
+}
+
+#[test]
+fn internally_tagged_enum() {
+    #[derive(Facet)]
+    struct Disc {
+        radius: u32,
+    }
+
+    #[derive(Facet)]
+    #[facet(tag = "type")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Circle(Disc),
+        Rectangle { width: u32, height: u32 },
+        Empty,
+    }
+
+    let actual = emit!(Shape as CSharp with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public partial class Disc : ObservableObject {
+        [JsonPropertyName("radius")]
+        [ObservableProperty]
+        private uint _radius;
+
+        public string JsonSerialize()
+        {
+            return JsonSerde.Serialize(this);
+        }
+
+        public static Disc JsonDeserialize(string input)
+        {
+            return JsonSerde.Deserialize<Disc>(input);
+        }
+    }
+
+    [JsonConverter(typeof(ShapeJsonConverter))]
+    public abstract record Shape {
+        public sealed record Circle(Disc Value) : Shape;
+
+        public sealed record Rectangle(uint Width, uint Height) : Shape;
+
+        public sealed record Empty() : Shape;
+
+        public string JsonSerialize()
+        {
+            return JsonSerde.Serialize(this);
+        }
+
+        public static Shape JsonDeserialize(string input)
+        {
+            return JsonSerde.Deserialize<Shape>(input);
+        }
+    }
+
+    /// <summary>
+    /// JSON converter for the internally tagged <see cref="Shape"/>, whose tag is <c>"type"</c>.
+    /// </summary>
+    public sealed class ShapeJsonConverter : JsonConverter<Shape> {
+        public override bool HandleNull => true;
+
+        public override Shape Read(ref System.Text.Json.Utf8JsonReader reader, System.Type typeToConvert, System.Text.Json.JsonSerializerOptions options) {
+            using var document = System.Text.Json.JsonDocument.ParseValue(ref reader);
+            var element = document.RootElement;
+            var tag = element.TryGetProperty("type", out var property) ? property.GetString() : null;
+            return tag switch {
+                "Circle" => System.Text.Json.JsonSerializer.Deserialize<Shape.Circle>(JsonSerde.ToObject(("Value", element)), options)!,
+                "Rectangle" => System.Text.Json.JsonSerializer.Deserialize<Shape.Rectangle>(JsonSerde.ToObject(("Width", JsonSerde.Property(element, "width")), ("Height", JsonSerde.Property(element, "height"))), options)!,
+                "Empty" => new Shape.Empty(),
+                _ => throw new System.Text.Json.JsonException($"Unknown variant of Shape: {tag}"),
+            };
+        }
+
+        public override void Write(System.Text.Json.Utf8JsonWriter writer, Shape value, System.Text.Json.JsonSerializerOptions options) {
+            var element = System.Text.Json.JsonSerializer.SerializeToElement(value, value.GetType(), options);
+            writer.WriteStartObject();
+            switch (value) {
+                case Shape.Circle:
+                    writer.WriteString("type", "Circle");
+                    foreach (var payload in element.GetProperty("Value").EnumerateObject()) payload.WriteTo(writer);
+                    break;
+                case Shape.Rectangle:
+                    writer.WriteString("type", "Rectangle");
+                    writer.WritePropertyName("width");
+                    element.GetProperty("Width").WriteTo(writer);
+                    writer.WritePropertyName("height");
+                    element.GetProperty("Height").WriteTo(writer);
+                    break;
+                case Shape.Empty:
+                    writer.WriteString("type", "Empty");
+                    break;
+                default:
+                    throw new System.ArgumentOutOfRangeException(nameof(value));
+            }
+            writer.WriteEndObject();
+        }
+    }
+    "#);
+}
+
+#[test]
+fn adjacently_tagged_enum() {
+    #[derive(Facet)]
+    #[facet(tag = "t", content = "c")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Message {
+        Text(String),
+        Move(i32, i32),
+        Resize { width: u32, height: u32 },
+        Quit,
+    }
+
+    let actual = emit!(Message as CSharp with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    [JsonConverter(typeof(MessageJsonConverter))]
+    public abstract record Message {
+        public sealed record Text(string Value) : Message;
+
+        public sealed record Move(int Field0, int Field1) : Message;
+
+        public sealed record Resize(uint Width, uint Height) : Message;
+
+        public sealed record Quit() : Message;
+
+        public string JsonSerialize()
+        {
+            return JsonSerde.Serialize(this);
+        }
+
+        public static Message JsonDeserialize(string input)
+        {
+            return JsonSerde.Deserialize<Message>(input);
+        }
+    }
+
+    /// <summary>
+    /// JSON converter for the adjacently tagged <see cref="Message"/>, whose tag is <c>"t"</c> and content <c>"c"</c>.
+    /// </summary>
+    public sealed class MessageJsonConverter : JsonConverter<Message> {
+        public override bool HandleNull => true;
+
+        public override Message Read(ref System.Text.Json.Utf8JsonReader reader, System.Type typeToConvert, System.Text.Json.JsonSerializerOptions options) {
+            using var document = System.Text.Json.JsonDocument.ParseValue(ref reader);
+            var element = document.RootElement;
+            var tag = element.TryGetProperty("t", out var property) ? property.GetString() : null;
+            return tag switch {
+                "Text" => System.Text.Json.JsonSerializer.Deserialize<Message.Text>(JsonSerde.ToObject(("Value", element.GetProperty("c"))), options)!,
+                "Move" => System.Text.Json.JsonSerializer.Deserialize<Message.Move>(JsonSerde.ToObject(("Field0", element.GetProperty("c")[0]), ("Field1", element.GetProperty("c")[1])), options)!,
+                "Resize" => System.Text.Json.JsonSerializer.Deserialize<Message.Resize>(JsonSerde.ToObject(("Width", JsonSerde.Property(element.GetProperty("c"), "width")), ("Height", JsonSerde.Property(element.GetProperty("c"), "height"))), options)!,
+                "Quit" => new Message.Quit(),
+                _ => throw new System.Text.Json.JsonException($"Unknown variant of Message: {tag}"),
+            };
+        }
+
+        public override void Write(System.Text.Json.Utf8JsonWriter writer, Message value, System.Text.Json.JsonSerializerOptions options) {
+            var element = System.Text.Json.JsonSerializer.SerializeToElement(value, value.GetType(), options);
+            writer.WriteStartObject();
+            switch (value) {
+                case Message.Text:
+                    writer.WriteString("t", "Text");
+                    writer.WritePropertyName("c");
+                    element.GetProperty("Value").WriteTo(writer);
+                    break;
+                case Message.Move:
+                    writer.WriteString("t", "Move");
+                    writer.WritePropertyName("c");
+                    writer.WriteStartArray();
+                    element.GetProperty("Field0").WriteTo(writer);
+                    element.GetProperty("Field1").WriteTo(writer);
+                    writer.WriteEndArray();
+                    break;
+                case Message.Resize:
+                    writer.WriteString("t", "Resize");
+                    writer.WritePropertyName("c");
+                    writer.WriteStartObject();
+                    writer.WritePropertyName("width");
+                    element.GetProperty("Width").WriteTo(writer);
+                    writer.WritePropertyName("height");
+                    element.GetProperty("Height").WriteTo(writer);
+                    writer.WriteEndObject();
+                    break;
+                case Message.Quit:
+                    writer.WriteString("t", "Quit");
+                    break;
+                default:
+                    throw new System.ArgumentOutOfRangeException(nameof(value));
+            }
+            writer.WriteEndObject();
+        }
+    }
+    "#);
+}
@@ -12,10 +12,13 @@ use std::sync::Arc;
 use crate::{
     Registry,
     generation::{
-        CodeGenerator, CodeGeneratorConfig, Container, Emitter, csharp::emitter::CSharp,
-        indent::IndentedWriter, module::Module, plugin::EmitterPlugin,
+        CodeGenerator, CodeGeneratorConfig, Container, Emitter,
+        csharp::emitter::CSharp,
+        indent::IndentedWriter,
+        module::{self, Module},
+        plugin::{self, EmitterPlugin},
     },
-    reflection::format::{Format, FormatHolder, Namespace, QualifiedTypeName},
+    reflection::format::{Format, FormatHolder, Language, Namespace, QualifiedTypeName},
 };
 
 /// Main configuration object for C# code generation.
@@ -68,6 +71,9 @@ impl<'a> CSharpCodeGenerator<'a> {
     /// Returns an error if writing to `out` fails.
     pub fn output(&self, out: &mut impl Write, registry: &Registry) -> Result<()> {
         let w = &mut IndentedWriter::new(out, self.config.indent);
+        let checked = module::for_plugin_checks(registry, Language::CSharp);
+        let registry = &module::for_language(registry, Language::CSharp);
+        plugin::check_registry(&self.plugins, &checked).map_err(std::io::Error::other)?;
 
         let mut config = self.config.clone();
         config.update_from(registry);
@@ -80,7 +86,15 @@ impl<'a> CSharpCodeGenerator<'a> {
 
         Module::new(&config).write(w, &lang)?;
 
-        for (index, container) in updated_registry.iter().map(Container::from).enumerate() {
+        // C# has no type aliases that can be shared between files, so the aliases declared by
+        // serialization proxies are left out; uses of them are already replaced by the proxy's
+        // format in the registry.
+        for (index, container) in updated_registry
+            .iter()
+            .filter(|(_, format)| !format.is_proxy())
+            .map(Container::from)
+            .enumerate()
+        {
             if index > 0 {
                 writeln!(w)?;
             }
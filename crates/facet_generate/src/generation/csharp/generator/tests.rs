@@ -21,7 +21,10 @@ use crate::{
         CodeGeneratorConfig, bincode::BincodePlugin, csharp::emitter::CSharp, json::JsonPlugin,
         plugin::EmitterPlugin,
     },
-    reflection::format::{ContainerFormat, Doc, Format, Named, Namespace, QualifiedTypeName},
+    reflection::format::{
+        ContainerFormat, ContainerMetadata, Doc, FieldMetadata, Format, Named, Namespace,
+        QualifiedTypeName,
+    },
 };
 
 fn registry_with_struct_field(field_type: Format) -> Registry {
@@ -30,17 +33,18 @@ fn registry_with_struct_field(field_type: Format) -> Registry {
         name: "value".to_string(),
         doc: Doc::new(),
         value: field_type,
+        metadata: FieldMetadata::default(),
     }];
     registry.insert(
         QualifiedTypeName::root("Holder".to_string()),
-        ContainerFormat::Struct(fields, Doc::new()),
+        ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default()),
     );
     registry
 }
 
 fn first_field_type(registry: &Registry) -> &Format {
     let (_, container) = registry.iter().next().unwrap();
-    let ContainerFormat::Struct(fields, _) = container else {
+    let ContainerFormat::Struct(fields, _, _) = container else {
         panic!("expected struct container");
     };
     &fields[0].value
@@ -37,16 +37,20 @@ use crate::{
         CodeGeneratorConfig, Error, ExternalPackage, ExternalPackages, PackageLocation,
         SourceInstaller,
         csharp::{CSharp, CSharpCodeGenerator},
+        indent::IndentConfig,
         module,
-        plugin::EmitterPlugin,
+        plugin::{self, EmitterPlugin},
     },
+    reflection::format::{Language, Visibility},
 };
 /// Installer for generated source files in C#.
 pub struct Installer {
     package_name: String,
     install_dir: PathBuf,
     external_packages: ExternalPackages,
+    indent: IndentConfig,
     plugins: Vec<Arc<dyn EmitterPlugin<CSharp>>>,
+    default_visibility: Visibility,
 }
 
 impl Installer {
@@ -61,7 +65,9 @@ impl Installer {
             package_name: package_name.to_string(),
             install_dir: install_dir.as_ref().to_path_buf(),
             external_packages: ExternalPackages::new(),
+            indent: IndentConfig::Space(4),
             plugins: vec![],
+            default_visibility: Visibility::Public,
         }
     }
 
@@ -85,6 +91,21 @@ impl Installer {
         self
     }
 
+    /// Set the visibility of generated types and fields that aren't marked
+    /// `#[facet(fg::public)]` (public by default).
+    #[must_use]
+    pub const fn default_visibility(mut self, visibility: Visibility) -> Self {
+        self.default_visibility = visibility;
+        self
+    }
+
+    /// Set the indentation of generated source files (four spaces by default).
+    #[must_use]
+    pub const fn indent(mut self, indent: IndentConfig) -> Self {
+        self.indent = indent;
+        self
+    }
+
     /// Generate all code for the given registry.
     ///
     /// This method:
@@ -96,6 +117,11 @@ impl Installer {
     ///
     /// Returns an error if any file operation or code generation step fails.
     pub fn generate(mut self, registry: &Registry) -> Result<(), Error> {
+        plugin::check_registry(
+            &self.plugins,
+            &module::for_plugin_checks(registry, Language::CSharp),
+        )?;
+
         // Unit.cs is always required (even with no plugins) because Format::Unit
         // maps to the C# Unit struct in generated type declarations.
         self.install_core_runtime()?;
@@ -130,8 +156,16 @@ impl Installer {
             }
         }
 
-        for (m, module_registry) in module::split(&self.package_name, registry) {
-            let config = m.config().clone().with_parent(&self.package_name);
+        for (m, module_registry) in module::split(
+            &self.package_name,
+            &module::for_language(registry, Language::CSharp),
+        ) {
+            let config = m
+                .config()
+                .clone()
+                .with_parent(&self.package_name)
+                .with_default_visibility(self.default_visibility)
+                .with_indent(self.indent);
             self.install_module(&config, &module_registry)?;
         }
 
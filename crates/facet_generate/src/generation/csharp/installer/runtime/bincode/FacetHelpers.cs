@@ -131,4 +131,26 @@ public static class FacetHelpers
         }
         return null;
     }
+
+    public static void SerializeOptionGeneric<T>(T? value, ISerializer serializer, Action<T, ISerializer> serializeValue)
+    {
+        if (value is not null)
+        {
+            serializer.SerializeOptionTag(true);
+            serializeValue(value, serializer);
+        }
+        else
+        {
+            serializer.SerializeOptionTag(false);
+        }
+    }
+
+    public static T? DeserializeOptionGeneric<T>(IDeserializer deserializer, Func<IDeserializer, T> deserializeValue)
+    {
+        if (deserializer.DeserializeOptionTag())
+        {
+            return deserializeValue(deserializer);
+        }
+        return default;
+    }
 }
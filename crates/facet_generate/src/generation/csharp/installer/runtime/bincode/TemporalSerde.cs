@@ -0,0 +1,95 @@
+// Serialization helpers for date and time types.
+//
+// Timestamps, dates and times travel as strings (RFC 3339, `YYYY-MM-DD` and
+// `HH:MM:SS[.fraction]`), matching the `serde` implementations of the chrono
+// and jiff types they come from. Durations travel as whole seconds (u64)
+// followed by nanoseconds (u32), matching `std::time::Duration`.
+
+using System;
+using System.Globalization;
+using System.Text.RegularExpressions;
+using Facet.Runtime.Serde;
+
+namespace Facet.Runtime.Bincode;
+
+public static class TemporalSerde
+{
+    private const long NanosecondsPerTick = 100;
+
+    // .NET parses at most 7 fractional digits (100 ns ticks); RFC 3339 allows more.
+    private static readonly Regex ExtraFractionDigits = new(@"(\.\d{7})\d+");
+
+    public static void SerializeTimestamp(DateTimeOffset value, ISerializer serializer)
+    {
+        serializer.SerializeStr(value.ToString("O", CultureInfo.InvariantCulture));
+    }
+
+    public static DateTimeOffset DeserializeTimestamp(IDeserializer deserializer)
+    {
+        var value = deserializer.DeserializeStr();
+        var text = ExtraFractionDigits.Replace(value, "$1");
+        if (!DateTimeOffset.TryParse(text, CultureInfo.InvariantCulture, DateTimeStyles.RoundtripKind, out var timestamp))
+        {
+            throw new DeserializationError($"Invalid RFC 3339 timestamp: {value}");
+        }
+        return timestamp;
+    }
+
+    public static void SerializeDate(DateOnly value, ISerializer serializer)
+    {
+        serializer.SerializeStr(value.ToString("yyyy-MM-dd", CultureInfo.InvariantCulture));
+    }
+
+    public static DateOnly DeserializeDate(IDeserializer deserializer)
+    {
+        var value = deserializer.DeserializeStr();
+        if (!DateOnly.TryParseExact(value, "yyyy-MM-dd", CultureInfo.InvariantCulture, DateTimeStyles.None, out var date))
+        {
+            throw new DeserializationError($"Invalid date: {value}");
+        }
+        return date;
+    }
+
+    public static void SerializeTime(TimeOnly value, ISerializer serializer)
+    {
+        var text = value.ToString("HH:mm:ss", CultureInfo.InvariantCulture);
+        var ticks = value.Ticks % TimeSpan.TicksPerSecond;
+        if (ticks != 0)
+        {
+            text += "." + (ticks * NanosecondsPerTick).ToString("D9", CultureInfo.InvariantCulture).TrimEnd('0');
+        }
+        serializer.SerializeStr(text);
+    }
+
+    public static TimeOnly DeserializeTime(IDeserializer deserializer)
+    {
+        var value = deserializer.DeserializeStr();
+        var text = ExtraFractionDigits.Replace(value, "$1");
+        if (!TimeOnly.TryParse(text, CultureInfo.InvariantCulture, DateTimeStyles.None, out var time))
+        {
+            throw new DeserializationError($"Invalid time: {value}");
+        }
+        return time;
+    }
+
+    public static void SerializeDuration(TimeSpan value, ISerializer serializer)
+    {
+        if (value < TimeSpan.Zero)
+        {
+            throw new SerializationError($"Duration must not be negative: {value}");
+        }
+        serializer.IncreaseContainerDepth();
+        serializer.SerializeU64((ulong)(value.Ticks / TimeSpan.TicksPerSecond));
+        serializer.SerializeU32((uint)(value.Ticks % TimeSpan.TicksPerSecond * NanosecondsPerTick));
+        serializer.DecreaseContainerDepth();
+    }
+
+    public static TimeSpan DeserializeDuration(IDeserializer deserializer)
+    {
+        deserializer.IncreaseContainerDepth();
+        var secs = deserializer.DeserializeU64();
+        var nanos = deserializer.DeserializeU32();
+        deserializer.DecreaseContainerDepth();
+        return TimeSpan.FromTicks((long)secs * TimeSpan.TicksPerSecond + nanos / NanosecondsPerTick);
+    }
+}
@@ -13,8 +13,10 @@ public static class JsonSerde
     {
         Converters =
         {
-            new JsonStringEnumConverter(),
-            new ObservableCollectionJsonConverterFactory()
+            new StringEnumJsonConverterFactory(),
+            new ObservableCollectionJsonConverterFactory(),
+            new BrandedJsonConverterFactory(),
+            new DurationJsonConverter()
         }
     };
 
@@ -43,6 +45,45 @@ public static class JsonSerde
 
         return value;
     }
+
+    // Reads `element` as a `T`, returning false instead of throwing when it
+    // doesn't match, e.g. to try the next variant of an untagged enum.
+    public static bool TryDeserialize<T>(JsonElement element, JsonSerializerOptions options, out T value)
+    {
+        try
+        {
+            value = element.Deserialize<T>(options)!;
+            return value is not null;
+        }
+        catch (Exception e) when (e is JsonException or InvalidOperationException or FormatException)
+        {
+            value = default!;
+            return false;
+        }
+    }
+
+    // Builds an object from the given properties, leaving out undefined ones,
+    // e.g. to read the payload of a variant through the variant's record.
+    public static JsonElement ToObject(params (string Name, JsonElement Value)[] properties)
+    {
+        var node = new System.Text.Json.Nodes.JsonObject();
+        foreach (var (name, value) in properties)
+        {
+            if (value.ValueKind != JsonValueKind.Undefined)
+            {
+                node[name] = System.Text.Json.Nodes.JsonNode.Parse(value.GetRawText());
+            }
+        }
+        return JsonSerializer.SerializeToElement(node);
+    }
+
+    // The value of an object's property, undefined if it's missing.
+    public static JsonElement Property(JsonElement element, string name)
+    {
+        return element.ValueKind == JsonValueKind.Object && element.TryGetProperty(name, out var value)
+            ? value
+            : default;
+    }
 }
 
 internal sealed class ObservableCollectionJsonConverterFactory : JsonConverterFactory
@@ -81,3 +122,124 @@ internal sealed class ObservableCollectionJsonConverterFactory : JsonConverterFa
         }
     }
 }
+
+// Reads and writes enums as their member names, like `JsonStringEnumConverter`,
+// except for those with a `[JsonConverter]` of their own (e.g. one that reads
+// unknown names as a catch-all member): converters in the options would
+// otherwise take precedence over it.
+internal sealed class StringEnumJsonConverterFactory : JsonConverterFactory
+{
+    private readonly JsonStringEnumConverter inner = new();
+
+    public override bool CanConvert(Type typeToConvert)
+    {
+        return inner.CanConvert(typeToConvert) &&
+               !Attribute.IsDefined(typeToConvert, typeof(JsonConverterAttribute));
+    }
+
+    public override JsonConverter? CreateConverter(Type typeToConvert, JsonSerializerOptions options)
+    {
+        return inner.CreateConverter(typeToConvert, options);
+    }
+}
+
+// Implemented by branded newtypes: a `readonly record struct` with a single
+// positional `Value`, which serializes exactly like the value it wraps.
+public interface IBranded
+{
+}
+
+internal sealed class BrandedJsonConverterFactory : JsonConverterFactory
+{
+    public override bool CanConvert(Type typeToConvert)
+    {
+        return typeof(IBranded).IsAssignableFrom(typeToConvert);
+    }
+
+    public override JsonConverter CreateConverter(Type typeToConvert, JsonSerializerOptions options)
+    {
+        var valueType = typeToConvert.GetProperty("Value")!.PropertyType;
+        var converterType = typeof(BrandedJsonConverter<,>).MakeGenericType(typeToConvert, valueType);
+        return (JsonConverter)Activator.CreateInstance(converterType)!;
+    }
+
+    private sealed class BrandedJsonConverter<TBranded, TValue> : JsonConverter<TBranded>
+    {
+        private static readonly System.Reflection.PropertyInfo ValueProperty =
+            typeof(TBranded).GetProperty("Value")!;
+
+        public override bool HandleNull => true;
+
+        public override TBranded Read(
+            ref Utf8JsonReader reader,
+            Type typeToConvert,
+            JsonSerializerOptions options)
+        {
+            var value = JsonSerializer.Deserialize<TValue>(ref reader, options);
+            return (TBranded)Activator.CreateInstance(typeof(TBranded), value)!;
+        }
+
+        public override void Write(
+            Utf8JsonWriter writer,
+            TBranded value,
+            JsonSerializerOptions options)
+        {
+            JsonSerializer.Serialize(writer, (TValue)ValueProperty.GetValue(value)!, options);
+        }
+    }
+}
+
+// Reads and writes `TimeSpan` as `{"secs": ..., "nanos": ...}`, the shape
+// `serde` gives `std::time::Duration`.
+internal sealed class DurationJsonConverter : JsonConverter<TimeSpan>
+{
+    private const long NanosecondsPerTick = 100;
+
+    public override TimeSpan Read(
+        ref Utf8JsonReader reader,
+        Type typeToConvert,
+        JsonSerializerOptions options)
+    {
+        if (reader.TokenType != JsonTokenType.StartObject)
+        {
+            throw new DeserializationError("Expected a duration object");
+        }
+
+        ulong secs = 0;
+        uint nanos = 0;
+        while (reader.Read() && reader.TokenType != JsonTokenType.EndObject)
+        {
+            var name = reader.GetString();
+            reader.Read();
+            switch (name)
+            {
+                case "secs":
+                    secs = reader.GetUInt64();
+                    break;
+                case "nanos":
+                    nanos = reader.GetUInt32();
+                    break;
+                default:
+                    reader.Skip();
+                    break;
+            }
+        }
+        return TimeSpan.FromTicks((long)secs * TimeSpan.TicksPerSecond + nanos / NanosecondsPerTick);
+    }
+
+    public override void Write(
+        Utf8JsonWriter writer,
+        TimeSpan value,
+        JsonSerializerOptions options)
+    {
+        if (value < TimeSpan.Zero)
+        {
+            throw new SerializationError($"Duration must not be negative: {value}");
+        }
+
+        writer.WriteStartObject();
+        writer.WriteNumber("secs", value.Ticks / TimeSpan.TicksPerSecond);
+        writer.WriteNumber("nanos", value.Ticks % TimeSpan.TicksPerSecond * NanosecondsPerTick);
+        writer.WriteEndObject();
+    }
+}
@@ -38,5 +38,6 @@ mod generator;
 mod installer;
 
 pub use emitter::CSharp;
+pub(crate) use emitter::access_modifier;
 pub use generator::CSharpCodeGenerator;
 pub use installer::Installer;
@@ -0,0 +1,827 @@
+//! AST-to-Dart source rendering.
+//!
+//! This module implements [`Emitter<Dart>`](super::super::Emitter) for each
+//! node type in the format AST, turning abstract type descriptions into
+//! idiomatic Dart code.
+//!
+//! # Emitter implementations
+//!
+//! | AST node | Dart output |
+//! |---|---|
+//! | [`Module`] | `import` statements |
+//! | [`Container`] | `final class`, `enum`, `sealed class` + variant classes, `extension type` or `typedef` |
+//! | [`Named<Format>`](Named) | A single `final` field declaration |
+//! | [`Format`] | An inline type expression (`int`, `List<String>`, `(int, String)`, …) |
+//! | [`Doc`] | `///` doc comments |
+//!
+//! # Dart type mapping
+//!
+//! The [`Format`] emitter maps Rust/reflection types to Dart equivalents —
+//! for example all integers up to 64 bits → `int`, `I128`/`U128` →
+//! `BigInt`, `Seq(T)` → `List<T>`, `Option(T)` → `T?`, `Bytes` →
+//! `Uint8List` and tuples → records such as `(int, String)`.
+//!
+//! # Declarations
+//!
+//! Every type is immutable: structs become `final class`es with `final`
+//! fields and a `const` constructor (named parameters for structs, positional
+//! ones for newtype and tuple structs). Enums whose variants are all unit
+//! variants become Dart `enum`s; other enums become a `sealed class` with a
+//! top-level `final class` per variant (e.g. `ShapeCircle extends Shape`), so
+//! that `switch` expressions over them are checked for exhaustiveness.
+//!
+//! # Plugin-dependent output
+//!
+//! The [`Dart`] language tag carries a list of [`EmitterPlugin`]s, which
+//! write the serialization methods into the body of each top-level type:
+//!
+//! - `BincodePlugin` supplies `serialize` / `deserialize` and
+//!   `bincodeSerialize` / `bincodeDeserialize`.
+//! - `JsonPlugin` supplies `toJson` / `fromJson`.
+//! - With no plugins, only plain type declarations are emitted.
+//!
+//! Variant classes get no plugin code: the methods of the `sealed class`
+//! switch over its variants.
+
+use std::{
+    io::{Result, Write},
+    sync::Arc,
+};
+
+use heck::{ToLowerCamelCase, ToSnakeCase, ToUpperCamelCase};
+
+use crate::{
+    Registry,
+    generation::{
+        CodeGeneratorConfig, Container, Emitter, Feature,
+        indent::{IndentWrite, Newlines},
+        module::Module,
+        plugin::{
+            EmitContext, EmitterPlugin, VariantInfo, collect_from_plugins, type_annotations,
+            type_conformances,
+        },
+    },
+    reflection::format::{
+        ContainerFormat, DefaultValue, Deprecation, Doc, Format, Language, Named, VariantFormat,
+    },
+};
+
+/// Dart's reserved words, which can't be used as identifiers, and the members
+/// of `Object`, which a field can't override with a different type.
+const RESERVED: &[&str] = &[
+    "assert",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "for",
+    "hashCode",
+    "if",
+    "in",
+    "is",
+    "new",
+    "noSuchMethod",
+    "null",
+    "rethrow",
+    "return",
+    "runtimeType",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "toString",
+    "true",
+    "try",
+    "var",
+    "void",
+    "while",
+    "with",
+];
+
+/// Members of every Dart `enum`, which its values can't be named after.
+const ENUM_MEMBERS: &[&str] = &["index", "name", "values"];
+
+/// Language tag for Dart code generation.
+///
+/// Passed as the `L` parameter to every [`Emitter<L>`](super::super::Emitter)
+/// call. Carries a plugin list that controls all encoding-specific behaviour.
+#[derive(Debug, Clone)]
+pub struct Dart {
+    pub(crate) config: CodeGeneratorConfig,
+    pub(crate) plugins: Vec<Arc<dyn EmitterPlugin<Self>>>,
+}
+
+impl Dart {
+    /// Create a Dart language tag with no default plugins.
+    ///
+    /// Use [`with_plugin`](Self::with_plugin) to attach plugins.
+    #[must_use]
+    pub fn new(config: &CodeGeneratorConfig, _registry: &Registry) -> Self {
+        Self {
+            config: config.clone(),
+            plugins: vec![],
+        }
+    }
+
+    /// Access the generator config.
+    #[must_use]
+    pub const fn config(&self) -> &CodeGeneratorConfig {
+        &self.config
+    }
+
+    /// Add a plugin to this language tag, returning the modified tag.
+    ///
+    /// Plugins are invoked in the order they are added.
+    #[must_use]
+    pub fn with_plugin(mut self, plugin: Arc<dyn EmitterPlugin<Self>>) -> Self {
+        self.plugins.push(plugin);
+        self
+    }
+
+    /// Access the plugin list.
+    #[must_use]
+    pub fn plugins(&self) -> &[Arc<dyn EmitterPlugin<Self>>] {
+        &self.plugins
+    }
+}
+
+impl Emitter<Dart> for Module {
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Dart) -> Result<()> {
+        let config = self.config();
+
+        let mut imports = vec![];
+        if config.features.contains(&Feature::Bytes) {
+            imports.push("import 'dart:typed_data';".to_string());
+        }
+        for namespace in &config.referenced_namespaces {
+            let uri = import_uri(config, namespace, &format!("{namespace}.dart"));
+            let prefix = library_prefix(namespace);
+            imports.push(format!("import '{uri}' as {prefix};"));
+        }
+        imports.extend(collect_from_plugins(lang.plugins(), |p| p.imports(config)));
+
+        // `dart:` libraries first, then packages, then the package's own libraries.
+        imports.sort_unstable_by_key(|import| {
+            let rank = if import.starts_with("import 'dart:") {
+                0
+            } else if import.starts_with("import 'package:") {
+                1
+            } else {
+                2
+            };
+            (rank, import.clone())
+        });
+        imports.dedup();
+        if !imports.is_empty() {
+            for import in imports {
+                writeln!(w, "{import}")?;
+            }
+            writeln!(w)?;
+        }
+
+        let mut helpers = vec![];
+        {
+            let mut hw = w.child(&mut helpers);
+            for plugin in lang.plugins() {
+                plugin.module_helpers(&mut hw, config)?;
+            }
+        }
+        if !helpers.is_empty() {
+            w.write_all(&helpers)?;
+            writeln!(w)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Emitter<Dart> for Container<'_> {
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Dart) -> Result<()> {
+        let metadata = self.format.metadata();
+        let value = |format: &Format| Named::new(format, "value".to_string()).inheriting(metadata);
+        let ctx = EmitContext::top_level(self, &lang.config);
+        match self.format {
+            ContainerFormat::UnitStruct(doc, _) => {
+                let class = Class::top_level(self, Parameters::Positional, vec![]);
+                class.write(w, doc, &ctx, lang)
+            }
+            ContainerFormat::NewTypeStruct(format, doc, metadata) if metadata.proxy => {
+                typedef(w, self, format, doc, lang)
+            }
+            ContainerFormat::NewTypeStruct(format, doc, metadata) if metadata.branded => {
+                extension_type(w, self, &value(format), doc, &ctx, lang)
+            }
+            ContainerFormat::NewTypeStruct(format, doc, _) => {
+                let class = Class::top_level(self, Parameters::Positional, vec![value(format)]);
+                class.write(w, doc, &ctx, lang)
+            }
+            ContainerFormat::TupleStruct(formats, doc, _) => {
+                let fields = named(formats)
+                    .iter()
+                    .map(|field| field.inheriting(metadata))
+                    .collect();
+                let class = Class::top_level(self, Parameters::Positional, fields);
+                class.write(w, doc, &ctx, lang)
+            }
+            ContainerFormat::Struct(fields, doc, _) => {
+                let fields = fields
+                    .iter()
+                    .map(|field| field.inheriting(metadata))
+                    .collect();
+                let class = Class::top_level(self, Parameters::Named, fields);
+                class.write(w, doc, &ctx, lang)
+            }
+            ContainerFormat::Enum(variants, _, doc, _) => {
+                if variants
+                    .values()
+                    .all(|variant| matches!(variant.value, VariantFormat::Unit))
+                {
+                    enumeration(w, self, doc, &ctx, lang)
+                } else {
+                    sealed_class(w, self, doc, &ctx, lang)
+                }
+            }
+        }
+    }
+}
+
+impl Emitter<Dart> for Named<Format> {
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Dart) -> Result<()> {
+        self.doc.write(w, lang)?;
+        if let Some(annotation) = deprecated_annotation(self.metadata.deprecated.as_ref()) {
+            writeln!(w, "{annotation}")?;
+        }
+        write!(w, "final ")?;
+        match self.metadata.type_override(Language::Dart) {
+            Some(type_override) => write!(w, "{}", type_override.ty)?,
+            None => self.value.write(w, lang)?,
+        }
+        writeln!(w, " {};", identifier(&self.name))
+    }
+}
+
+impl Emitter<Dart> for Doc {
+    fn write<W: IndentWrite>(&self, w: &mut W, _lang: &Dart) -> Result<()> {
+        for comment in self.comments() {
+            writeln!(w, "/// {comment}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Emitter<Dart> for Format {
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Dart) -> Result<()> {
+        match self {
+            Self::Variable(_variable) => unreachable!("placeholders should not get this far"),
+            Self::TypeName(qualified_name) => {
+                write!(w, "{}", qualified_name.format(library_prefix, "."))
+            }
+            Self::Generic { name, args } => {
+                write!(w, "{}<", name.format(library_prefix, "."))?;
+                write_formats(w, args, lang)?;
+                write!(w, ">")
+            }
+            Self::TypeParameter(name) => write!(w, "{name}"),
+            Self::Unit => write!(w, "()"),
+            Self::Bool => write!(w, "bool"),
+            Self::I8
+            | Self::I16
+            | Self::I32
+            | Self::I64
+            | Self::U8
+            | Self::U16
+            | Self::U32
+            | Self::U64 => write!(w, "int"),
+            Self::I128 | Self::U128 => write!(w, "BigInt"),
+            Self::F32 | Self::F64 => write!(w, "double"),
+            Self::Char | Self::Str | Self::Uuid | Self::Date | Self::Time => write!(w, "String"),
+            Self::Bytes => write!(w, "Uint8List"),
+            Self::Timestamp => write!(w, "DateTime"),
+            Self::Duration => write!(w, "Duration"),
+            Self::Option(format) => {
+                format.write(w, lang)?;
+                write!(w, "?")
+            }
+            Self::Seq(format)
+            | Self::TupleArray {
+                content: format, ..
+            } => {
+                write!(w, "List<")?;
+                format.write(w, lang)?;
+                write!(w, ">")
+            }
+            Self::Set(format) => {
+                write!(w, "Set<")?;
+                format.write(w, lang)?;
+                write!(w, ">")
+            }
+            Self::Map { key, value } => {
+                write!(w, "Map<")?;
+                key.write(w, lang)?;
+                write!(w, ", ")?;
+                value.write(w, lang)?;
+                write!(w, ">")
+            }
+            Self::Tuple(formats) => match formats.as_slice() {
+                [] => write!(w, "()"),
+                // A single-element tuple is just the element itself
+                [format] => format.write(w, lang),
+                formats => {
+                    write!(w, "(")?;
+                    write_formats(w, formats, lang)?;
+                    write!(w, ")")
+                }
+            },
+        }
+    }
+}
+
+fn write_formats<W: IndentWrite>(w: &mut W, formats: &[Format], lang: &Dart) -> Result<()> {
+    for (i, format) in formats.iter().enumerate() {
+        if i > 0 {
+            write!(w, ", ")?;
+        }
+        format.write(w, lang)?;
+    }
+    Ok(())
+}
+
+/// The prefix that a module's library is imported with, e.g. `other_module`.
+fn library_prefix(namespace: &str) -> String {
+    namespace.to_snake_case()
+}
+
+/// How a class's constructor takes its fields.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Parameters {
+    /// `const Point({required this.x, this.label})` — for structs and struct variants.
+    Named,
+    /// `const UserId(this.value)` — for newtype and tuple structs and variants.
+    Positional,
+}
+
+/// An immutable `final class`: a struct, or a variant of a `sealed class`.
+struct Class<'a> {
+    name: String,
+    type_params: Vec<String>,
+    /// The `extends` clause of a variant, e.g. `Shape<T, Never>`.
+    supertype: Option<String>,
+    parameters: Parameters,
+    fields: Vec<Named<Format>>,
+    deprecated: Option<&'a Deprecation>,
+}
+
+impl<'a> Class<'a> {
+    fn top_level(
+        container: &'a Container,
+        parameters: Parameters,
+        fields: Vec<Named<Format>>,
+    ) -> Self {
+        let metadata = container.format.metadata();
+        Self {
+            name: container.name.name.clone(),
+            type_params: metadata.type_params.clone(),
+            supertype: None,
+            parameters,
+            fields,
+            deprecated: metadata.deprecated.as_ref(),
+        }
+    }
+
+    fn write<W: IndentWrite>(
+        &self,
+        w: &mut W,
+        doc: &Doc,
+        ctx: &EmitContext,
+        lang: &Dart,
+    ) -> Result<()> {
+        doc.write(w, lang)?;
+        write_annotations(w, self.deprecated, ctx, lang)?;
+
+        write!(w, "final class {}", self.name)?;
+        write_type_params(w, &self.type_params)?;
+        if let Some(supertype) = &self.supertype {
+            write!(w, " extends {supertype}")?;
+        }
+        write_conformances(w, ctx, lang)?;
+        write!(w, " ")?;
+        let mut w = w.block(Newlines::BOTH)?;
+
+        self.write_constructor(&mut w)?;
+        if !self.fields.is_empty() {
+            writeln!(w)?;
+            for field in &self.fields {
+                field.write(&mut w, lang)?;
+            }
+        }
+
+        write_plugin_bodies(&mut w, ctx, lang, true)
+    }
+
+    fn write_constructor<W: IndentWrite>(&self, w: &mut W) -> Result<()> {
+        let name = &self.name;
+        if self.fields.is_empty() {
+            return writeln!(w, "const {name}();");
+        }
+        match self.parameters {
+            Parameters::Positional => {
+                let params = self
+                    .fields
+                    .iter()
+                    .map(|field| format!("this.{}", identifier(&field.name)))
+                    .collect::<Vec<_>>();
+                writeln!(w, "const {name}({});", params.join(", "))
+            }
+            Parameters::Named => {
+                writeln!(w, "const {name}({{")?;
+                w.indent();
+                for field in &self.fields {
+                    writeln!(w, "{},", named_parameter(field))?;
+                }
+                w.unindent();
+                writeln!(w, "}});")
+            }
+        }
+    }
+}
+
+/// The named constructor parameter of a field: `required` unless it is optional or has a
+/// default value.
+fn named_parameter(field: &Named<Format>) -> String {
+    let name = identifier(&field.name);
+    if field.metadata.type_override(Language::Dart).is_some() {
+        return format!("required this.{name}");
+    }
+    match field_default(field) {
+        Some(value) => format!("this.{name} = {value}"),
+        None if matches!(field.value, Format::Option(_)) => format!("this.{name}"),
+        None => format!("required this.{name}"),
+    }
+}
+
+/// The Dart constant of a field's default value, if it has one that Dart can express as a
+/// constant (so not for `Uint8List` or `BigInt` fields).
+pub(crate) fn field_default(field: &Named<Format>) -> Option<String> {
+    if field.metadata.type_override(Language::Dart).is_some() {
+        return None;
+    }
+    let default = field.metadata.default.as_ref()?;
+    Some(match (default, &field.value) {
+        (DefaultValue::Empty, Format::Seq(_)) => "const []".to_string(),
+        (DefaultValue::Empty, Format::Set(_) | Format::Map { .. }) => "const {}".to_string(),
+        (DefaultValue::Bool(value), Format::Bool) => value.to_string(),
+        // Dart integers are 64-bit, so a `u64` above `i64::MAX` has no literal.
+        (
+            DefaultValue::Integer(value),
+            Format::I8
+            | Format::I16
+            | Format::I32
+            | Format::I64
+            | Format::U8
+            | Format::U16
+            | Format::U32
+            | Format::U64,
+        ) if value.parse::<i64>().is_ok() => value.clone(),
+        (DefaultValue::Float(value), Format::F32 | Format::F64) => value.clone(),
+        (DefaultValue::Char(value), Format::Char) => string_literal(&value.to_string()),
+        (DefaultValue::Str(value), Format::Str) => string_literal(value),
+        _ => return None,
+    })
+}
+
+/// Emits the `typedef` declared by a serialization proxy. Uses of the type have
+/// already been replaced by the proxy's format, so it needs no plugin code.
+fn typedef<W: IndentWrite>(
+    w: &mut W,
+    container: &Container,
+    format: &Format,
+    doc: &Doc,
+    lang: &Dart,
+) -> Result<()> {
+    doc.write(w, lang)?;
+    let metadata = container.format.metadata();
+    if let Some(annotation) = deprecated_annotation(metadata.deprecated.as_ref()) {
+        writeln!(w, "{annotation}")?;
+    }
+    write!(w, "typedef {} = ", container.name.name)?;
+    format.write(w, lang)?;
+    writeln!(w, ";")
+}
+
+/// Emits a branded newtype as an `extension type`, which wraps its single `value` field
+/// without allocating and can't be passed where the inner type (or another branded type)
+/// is expected.
+fn extension_type<W: IndentWrite>(
+    w: &mut W,
+    container: &Container,
+    field: &Named<Format>,
+    doc: &Doc,
+    ctx: &EmitContext,
+    lang: &Dart,
+) -> Result<()> {
+    doc.write(w, lang)?;
+    let metadata = container.format.metadata();
+    write_annotations(w, metadata.deprecated.as_ref(), ctx, lang)?;
+
+    write!(w, "extension type const {}", container.name.name)?;
+    write_type_params(w, &metadata.type_params)?;
+    write!(w, "(")?;
+    match field.metadata.type_override(Language::Dart) {
+        Some(type_override) => write!(w, "{}", type_override.ty)?,
+        None => field.value.write(w, lang)?,
+    }
+    write!(w, " {})", identifier(&field.name))?;
+    write_conformances(w, ctx, lang)?;
+    write!(w, " ")?;
+
+    if lang.plugins().iter().any(|p| p.has_type_body(ctx)) {
+        let mut w = w.block(Newlines::BOTH)?;
+        write_plugin_bodies(&mut w, ctx, lang, false)
+    } else {
+        writeln!(w, "{{}}")
+    }
+}
+
+/// Emits a Dart `enum` — used when all variants are unit variants.
+fn enumeration<W: IndentWrite>(
+    w: &mut W,
+    container: &Container,
+    doc: &Doc,
+    ctx: &EmitContext,
+    lang: &Dart,
+) -> Result<()> {
+    let ContainerFormat::Enum(variants, _, _, metadata) = container.format else {
+        unreachable!("only enums are emitted as Dart enums")
+    };
+    doc.write(w, lang)?;
+    write_annotations(w, metadata.deprecated.as_ref(), ctx, lang)?;
+
+    write!(w, "enum {}", container.name.name)?;
+    write_conformances(w, ctx, lang)?;
+    write!(w, " ")?;
+    let mut w = w.block(Newlines::BOTH)?;
+
+    let has_body = lang.plugins().iter().any(|p| p.has_type_body(ctx));
+    for (i, variant) in variants.values().enumerate() {
+        variant.doc.write(&mut w, lang)?;
+        let annotations = deprecated_annotation(variant.metadata.deprecated.as_ref())
+            .into_iter()
+            .chain(collect_from_plugins(lang.plugins(), |p| {
+                p.enum_variant_annotations(&variant.name)
+            }))
+            .collect::<Vec<_>>();
+        for annotation in annotations {
+            writeln!(w, "{annotation}")?;
+        }
+        let terminator = if has_body && i == variants.len() - 1 {
+            ";"
+        } else {
+            ","
+        };
+        writeln!(w, "{}{terminator}", enum_value(&variant.name))?;
+    }
+
+    write_plugin_bodies(&mut w, ctx, lang, true)
+}
+
+/// Emits a `sealed class` — used when at least one variant carries data —
+/// followed by a `final class` for each variant.
+///
+/// Variant classes only declare the type parameters they use; the others are
+/// `Never` in their supertype (Dart's type parameters are covariant).
+fn sealed_class<W: IndentWrite>(
+    w: &mut W,
+    container: &Container,
+    doc: &Doc,
+    ctx: &EmitContext,
+    lang: &Dart,
+) -> Result<()> {
+    let ContainerFormat::Enum(variants, _, _, metadata) = container.format else {
+        unreachable!("only enums are emitted as sealed classes")
+    };
+    let name = &container.name.name;
+    let type_params = &metadata.type_params;
+
+    doc.write(w, lang)?;
+    write_annotations(w, metadata.deprecated.as_ref(), ctx, lang)?;
+    write!(w, "sealed class {name}")?;
+    write_type_params(w, type_params)?;
+    write_conformances(w, ctx, lang)?;
+    write!(w, " ")?;
+    {
+        let mut w = w.block(Newlines::BOTH)?;
+        writeln!(w, "const {name}();")?;
+        write_plugin_bodies(&mut w, ctx, lang, true)?;
+    }
+
+    for (index, variant) in variants {
+        writeln!(w)?;
+        let (parameters, fields) = match &variant.value {
+            VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+            VariantFormat::Unit => (Parameters::Positional, vec![]),
+            VariantFormat::NewType(format) => (
+                Parameters::Positional,
+                vec![Named::new(format.as_ref(), "value".to_string())],
+            ),
+            VariantFormat::Tuple(formats) => (Parameters::Positional, named(formats)),
+            VariantFormat::Struct(fields) => (Parameters::Named, fields.clone()),
+        };
+        let used = used_type_params(type_params, &fields);
+        let supertype = if type_params.is_empty() {
+            name.clone()
+        } else {
+            let args = type_params
+                .iter()
+                .map(|param| {
+                    if used.contains(param) {
+                        param.as_str()
+                    } else {
+                        "Never"
+                    }
+                })
+                .collect::<Vec<_>>();
+            format!("{name}<{}>", args.join(", "))
+        };
+        let class = Class {
+            name: variant_class(name, &variant.name),
+            type_params: used,
+            supertype: Some(supertype),
+            parameters,
+            fields,
+            deprecated: variant.metadata.deprecated.as_ref(),
+        };
+        let variant_ctx = EmitContext::for_variant(
+            container,
+            &lang.config,
+            VariantInfo {
+                name: &variant.name,
+                index: *index as usize,
+                format: &variant.value,
+                fields: &class.fields,
+                parent_name: name,
+                parent_type_params: type_params,
+            },
+        );
+        class.write(w, &variant.doc, &variant_ctx, lang)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the bodies of the plugins that have one, separated by blank lines, and after a blank
+/// line if `separate` (i.e. members were written before them).
+fn write_plugin_bodies<W: IndentWrite>(
+    w: &mut W,
+    ctx: &EmitContext,
+    lang: &Dart,
+    mut separate: bool,
+) -> Result<()> {
+    for plugin in lang.plugins() {
+        if plugin.has_type_body(ctx) {
+            if separate {
+                writeln!(w)?;
+            }
+            plugin.type_body(w as &mut dyn IndentWrite, ctx)?;
+            separate = true;
+        }
+    }
+    Ok(())
+}
+
+/// Emits the annotations of a declaration: `@Deprecated` if it is deprecated, followed by those
+/// of the plugins and any declared with `fg::annotate` on a top-level type.
+fn write_annotations<W: IndentWrite>(
+    w: &mut W,
+    deprecated: Option<&Deprecation>,
+    ctx: &EmitContext,
+    lang: &Dart,
+) -> Result<()> {
+    if let Some(annotation) = deprecated_annotation(deprecated) {
+        writeln!(w, "{annotation}")?;
+    }
+    for annotation in type_annotations(lang.plugins(), ctx, Language::Dart) {
+        writeln!(w, "{annotation}")?;
+    }
+    Ok(())
+}
+
+/// Writes the ` implements A, B` clause of a declaration: the conformances of the plugins,
+/// followed by any declared with `fg::conforms`. Writes nothing if there are none.
+fn write_conformances<W: IndentWrite>(w: &mut W, ctx: &EmitContext, lang: &Dart) -> Result<()> {
+    let conformances = type_conformances(lang.plugins(), ctx, Language::Dart);
+    if conformances.is_empty() {
+        return Ok(());
+    }
+    write!(w, " implements {}", conformances.join(", "))
+}
+
+/// The `@Deprecated` annotation of a deprecated declaration, if it is one, or the `@deprecated`
+/// constant when the deprecation has no message.
+fn deprecated_annotation(deprecated: Option<&Deprecation>) -> Option<String> {
+    Some(match deprecated?.message() {
+        Some(message) => format!("@Deprecated({})", string_literal(&message)),
+        None => "@deprecated".to_string(),
+    })
+}
+
+/// Writes a type parameter list such as `<T, E>`, or nothing for a non-generic type.
+fn write_type_params<W: IndentWrite>(w: &mut W, type_params: &[String]) -> Result<()> {
+    if !type_params.is_empty() {
+        write!(w, "<{}>", type_params.join(", "))?;
+    }
+    Ok(())
+}
+
+/// The type parameters of a generic enum that a variant's fields use.
+fn used_type_params(type_params: &[String], fields: &[Named<Format>]) -> Vec<String> {
+    type_params
+        .iter()
+        .filter(|param| {
+            fields
+                .iter()
+                .any(|field| field.value.uses_type_parameter(param))
+        })
+        .cloned()
+        .collect()
+}
+
+fn named<Format: Clone>(formats: &[Format]) -> Vec<Named<Format>> {
+    formats
+        .iter()
+        .enumerate()
+        .map(|(i, f)| Named::new(f, format!("field{i}")))
+        .collect()
+}
+
+/// The URI that a module imports the library of `namespace` with: the library of the external
+/// package configured for it, if there is one, or else `local` (relative to the module).
+#[must_use]
+pub(crate) fn import_uri(config: &CodeGeneratorConfig, namespace: &str, local: &str) -> String {
+    config.external_packages.get(namespace).map_or_else(
+        || local.to_string(),
+        |package| {
+            let library = package.module_name.as_deref().unwrap_or(namespace);
+            format!("package:{}/{library}.dart", package.for_namespace)
+        },
+    )
+}
+
+/// The Dart identifier of a field, e.g. `createdAt` for `created_at`, with a trailing `_` if it
+/// is reserved (`class_`).
+#[must_use]
+pub(crate) fn identifier(name: &str) -> String {
+    let name = name.to_lower_camel_case();
+    if RESERVED.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+/// The Dart identifier of a value of an all-unit enum.
+#[must_use]
+pub(crate) fn enum_value(name: &str) -> String {
+    let value = identifier(name);
+    if ENUM_MEMBERS.contains(&value.as_str()) {
+        format!("{value}_")
+    } else {
+        value
+    }
+}
+
+/// The name of the class of a variant of a `sealed class`, e.g. `ShapeCircle`.
+#[must_use]
+pub(crate) fn variant_class(parent: &str, variant: &str) -> String {
+    format!("{parent}{}", variant.to_upper_camel_case())
+}
+
+/// A Dart string literal of `text`.
+#[must_use]
+pub(crate) fn string_literal(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('\'', "\\'")
+        .replace('$', "\\$")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t");
+    format!("'{escaped}'")
+}
+
+#[cfg(test)]
+mod tests;
+#[cfg(test)]
+mod tests_bincode;
+#[cfg(test)]
+mod tests_json;
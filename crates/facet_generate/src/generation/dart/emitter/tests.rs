@@ -0,0 +1,487 @@
+//! Snapshot tests for the Dart emitter — **no serialization**.
+//!
+//! Each test defines one or more Rust types annotated with `#[derive(Facet)]`,
+//! runs them through the [`emit!`] macro with no plugins, and asserts the
+//! generated Dart source against an [`insta`] inline snapshot.
+//!
+//! Because no plugins are configured, the output contains only plain type
+//! declarations (`final class`, `enum`, `sealed class` + variant classes) with
+//! no serialization methods.
+//!
+//! # Coverage
+//!
+//! | Category | What is tested |
+//! |----------|----------------|
+//! | Structs | Unit structs, newtype wrappers, tuple structs, structs with primitive and user-defined fields |
+//! | Enums | All-unit enums (Dart `enum`), data enums (`sealed class`), generic enums |
+//! | Collections | `Vec`, `HashMap`, `BTreeSet`, fixed-size arrays, tuples (records) |
+//! | Optional | `Option<T>` fields (nullable, not `required`) |
+//! | Declarations | Branded newtypes (`extension type`), proxies (`typedef`), deprecation, reserved words |
+//! | Namespaces | Multi-module generation via [`emit_two_modules!`] |
+
+#![allow(clippy::too_many_lines)]
+use std::collections::{BTreeSet, HashMap};
+
+use crate as fg;
+use facet::Facet;
+
+use super::*;
+use crate::{emit, emit_two_modules, generation::dart::DartCodeGenerator};
+
+#[test]
+fn unit_struct() {
+    /// line 1
+    #[derive(Facet)]
+    /// line 2
+    struct UnitStruct;
+
+    let actual = emit!(UnitStruct as Dart).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    /// line 1
+    /// line 2
+    final class UnitStruct {
+        const UnitStruct();
+    }
+    ");
+}
+
+#[test]
+fn newtype_struct() {
+    #[derive(Facet)]
+    struct NewType(String);
+
+    let actual = emit!(NewType as Dart).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    final class NewType {
+        const NewType(this.value);
+
+        final String value;
+    }
+    ");
+}
+
+#[test]
+fn tuple_struct() {
+    #[derive(Facet)]
+    struct TupleStruct(String, i32);
+
+    let actual = emit!(TupleStruct as Dart).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    final class TupleStruct {
+        const TupleStruct(this.field0, this.field1);
+
+        final String field0;
+        final int field1;
+    }
+    ");
+}
+
+#[test]
+fn struct_with_fields() {
+    #[derive(Facet)]
+    struct Inner {
+        value: u64,
+    }
+
+    /// A struct.
+    #[derive(Facet)]
+    struct Outer {
+        /// The name.
+        name: String,
+        flag: bool,
+        big: i128,
+        ratio: f32,
+        letter: char,
+        nickname: Option<String>,
+        inner: Inner,
+        tags: Vec<String>,
+        counts: HashMap<String, u32>,
+        ids: BTreeSet<u16>,
+        pair: (u8, String),
+        grid: [i16; 3],
+        nothing: (),
+    }
+
+    let actual = emit!(Outer as Dart).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    final class Inner {
+        const Inner({
+            required this.value,
+        });
+
+        final int value;
+    }
+
+    /// A struct.
+    final class Outer {
+        const Outer({
+            required this.name,
+            required this.flag,
+            required this.big,
+            required this.ratio,
+            required this.letter,
+            this.nickname,
+            required this.inner,
+            required this.tags,
+            required this.counts,
+            required this.ids,
+            required this.pair,
+            required this.grid,
+            required this.nothing,
+        });
+
+        /// The name.
+        final String name;
+        final bool flag;
+        final BigInt big;
+        final double ratio;
+        final String letter;
+        final String? nickname;
+        final Inner inner;
+        final List<String> tags;
+        final Map<String, int> counts;
+        final Set<int> ids;
+        final (int, String) pair;
+        final List<int> grid;
+        final () nothing;
+    }
+    ");
+}
+
+#[test]
+fn reserved_names() {
+    #[derive(Facet)]
+    struct Keywords {
+        class: String,
+        r#in: bool,
+        hash_code: u32,
+    }
+
+    let actual = emit!(Keywords as Dart).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    final class Keywords {
+        const Keywords({
+            required this.class_,
+            required this.in_,
+            required this.hashCode_,
+        });
+
+        final String class_;
+        final bool in_;
+        final int hashCode_;
+    }
+    ");
+}
+
+#[test]
+fn unit_enum() {
+    /// A colour.
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Colour {
+        /// The first one.
+        Red,
+        Green,
+        Values,
+    }
+
+    let actual = emit!(Colour as Dart).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    /// A colour.
+    enum Colour {
+        /// The first one.
+        red,
+        green,
+        values_,
+    }
+    ");
+}
+
+#[test]
+fn data_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Line(f64, f64),
+        Rectangle { width: f64, height: f64 },
+    }
+
+    let actual = emit!(Shape as Dart).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    sealed class Shape {
+        const Shape();
+    }
+
+    final class ShapePoint extends Shape {
+        const ShapePoint();
+    }
+
+    final class ShapeCircle extends Shape {
+        const ShapeCircle(this.value);
+
+        final double value;
+    }
+
+    final class ShapeLine extends Shape {
+        const ShapeLine(this.field0, this.field1);
+
+        final double field0;
+        final double field1;
+    }
+
+    final class ShapeRectangle extends Shape {
+        const ShapeRectangle({
+            required this.width,
+            required this.height,
+        });
+
+        final double width;
+        final double height;
+    }
+    ");
+}
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as Dart).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    final class Listing {
+        const Listing({
+            required this.names,
+            required this.ids,
+        });
+
+        final Page<String> names;
+        final Page<int> ids;
+    }
+
+    final class Page<T> {
+        const Page({
+            required this.items,
+            this.next,
+            required this.total,
+        });
+
+        final List<T> items;
+        final T? next;
+        final int total;
+    }
+    ");
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E, retry: bool },
+        Pending,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        first: Outcome<String, i32>,
+        second: Outcome<bool, String>,
+    }
+
+    let actual = emit!(Response as Dart).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    sealed class Outcome<T, E> {
+        const Outcome();
+    }
+
+    final class OutcomeSuccess<T> extends Outcome<T, Never> {
+        const OutcomeSuccess(this.value);
+
+        final T value;
+    }
+
+    final class OutcomeFailure<E> extends Outcome<Never, E> {
+        const OutcomeFailure({
+            required this.error,
+            required this.retry,
+        });
+
+        final E error;
+        final bool retry;
+    }
+
+    final class OutcomePending extends Outcome<Never, Never> {
+        const OutcomePending();
+    }
+
+    final class Response {
+        const Response({
+            required this.first,
+            required this.second,
+        });
+
+        final Outcome<String, int> first;
+        final Outcome<bool, String> second;
+    }
+    ");
+}
+
+#[test]
+fn branded_newtype() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    let actual = emit!(UserId as Dart).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    extension type const UserId(String value) {}
+    ");
+}
+
+#[test]
+fn serialized_as_proxy() {
+    #[derive(Facet)]
+    #[facet(fg::serialized_as = "String")]
+    struct ItemId(u64);
+
+    #[derive(Facet)]
+    struct Item {
+        id: ItemId,
+    }
+
+    let actual = emit!(Item as Dart).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    final class Item {
+        const Item({
+            required this.id,
+        });
+
+        final String id;
+    }
+
+    typedef ItemId = String;
+    ");
+}
+
+#[test]
+fn deprecated() {
+    #[derive(Facet)]
+    #[facet(fg::deprecated(since = "0.2.0", note = "Use `Circle` instead"))]
+    struct Round {
+        #[facet(fg::deprecated)]
+        radius: f64,
+    }
+
+    let actual = emit!(Round as Dart).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    @Deprecated('Use `Circle` instead (since 0.2.0)')
+    final class Round {
+        const Round({
+            required this.radius,
+        });
+
+        @deprecated
+        final double radius;
+    }
+    ");
+}
+
+#[test]
+fn defaults() {
+    #[derive(Facet)]
+    struct Settings {
+        #[facet(default = "$HOME".to_string())]
+        directory: String,
+        #[facet(default = 30)]
+        timeout: u64,
+        #[facet(default)]
+        counts: HashMap<String, u32>,
+        retries: u32,
+    }
+
+    let actual = emit!(Settings as Dart).unwrap();
+    insta::assert_snapshot!(actual, @r"
+
+    final class Settings {
+        const Settings({
+            this.directory = '\$HOME',
+            this.timeout = 30,
+            this.counts = const {},
+            required this.retries,
+        });
+
+        final String directory;
+        final int timeout;
+        final Map<String, int> counts;
+        final int retries;
+    }
+    ");
+}
+
+#[test]
+fn two_modules() {
+    mod other {
+        use crate as fg;
+        use facet::Facet;
+
+        #[derive(Facet)]
+        #[facet(fg::namespace = "other")]
+        pub struct Child {
+            value: i32,
+        }
+    }
+
+    #[derive(Facet)]
+    struct Parent {
+        other_child: other::Child,
+    }
+
+    let (other, root) = emit_two_modules!(DartCodeGenerator, Parent, "root");
+    insta::assert_snapshot!(other, @"
+    final class Child {
+        const Child({
+            required this.value,
+        });
+
+        final int value;
+    }
+    ");
+    insta::assert_snapshot!(root, @"
+    import 'other.dart' as other;
+
+    final class Parent {
+        const Parent({
+            required this.otherChild,
+        });
+
+        final other.Child otherChild;
+    }
+    ");
+}
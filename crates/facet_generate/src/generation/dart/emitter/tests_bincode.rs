@@ -0,0 +1,814 @@
+//! Snapshot tests for the Dart emitter — **Bincode encoding**.
+//!
+//! Mirrors the structure of [`tests`](super::tests) but uses `BincodePlugin`,
+//! so that every top-level type gets `serialize` / `bincodeSerialize` methods
+//! and `deserialize` / `bincodeDeserialize` factory constructors written
+//! against the runtime's `Serializer` / `Deserializer`.
+//!
+//! These tests verify field ordering, container depth tracking, the closures
+//! passed to the collection helpers (`serializeSeq`, `deserializeMap`, …),
+//! variant indices and the (de)serializers of type parameters.
+
+#![allow(clippy::too_many_lines)]
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use crate::{self as fg, generation::bincode::BincodePlugin};
+use facet::Facet;
+
+use super::*;
+use crate::emit;
+
+#[test]
+fn unit_struct() {
+    #[derive(Facet)]
+    struct UnitStruct;
+
+    let actual = emit!(UnitStruct as Dart with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    final class UnitStruct {
+        const UnitStruct();
+
+        void serialize(Serializer serializer) {}
+
+        Uint8List bincodeSerialize() {
+            final serializer = BincodeSerializer();
+            serialize(serializer);
+            return serializer.getBytes();
+        }
+
+        factory UnitStruct.deserialize(Deserializer deserializer) => const UnitStruct();
+
+        factory UnitStruct.bincodeDeserialize(Uint8List input) {
+            final deserializer = BincodeDeserializer(input);
+            final value = UnitStruct.deserialize(deserializer);
+            if (deserializer.getBufferOffset() < input.length) {
+                throw const DeserializationError('Some input bytes were not read');
+            }
+            return value;
+        }
+    }
+    ");
+}
+
+#[test]
+fn newtype_struct() {
+    #[derive(Facet)]
+    struct NewType(String);
+
+    let actual = emit!(NewType as Dart with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    final class NewType {
+        const NewType(this.value);
+
+        final String value;
+
+        void serialize(Serializer serializer) {
+            serializer.increaseContainerDepth();
+            serializer.serializeStr(value);
+            serializer.decreaseContainerDepth();
+        }
+
+        Uint8List bincodeSerialize() {
+            final serializer = BincodeSerializer();
+            serialize(serializer);
+            return serializer.getBytes();
+        }
+
+        factory NewType.deserialize(Deserializer deserializer) {
+            deserializer.increaseContainerDepth();
+            final value = deserializer.deserializeStr();
+            deserializer.decreaseContainerDepth();
+            return NewType(value);
+        }
+
+        factory NewType.bincodeDeserialize(Uint8List input) {
+            final deserializer = BincodeDeserializer(input);
+            final value = NewType.deserialize(deserializer);
+            if (deserializer.getBufferOffset() < input.length) {
+                throw const DeserializationError('Some input bytes were not read');
+            }
+            return value;
+        }
+    }
+    ");
+}
+
+#[test]
+fn tuple_struct() {
+    #[derive(Facet)]
+    struct TupleStruct(String, i32);
+
+    let actual = emit!(TupleStruct as Dart with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    final class TupleStruct {
+        const TupleStruct(this.field0, this.field1);
+
+        final String field0;
+        final int field1;
+
+        void serialize(Serializer serializer) {
+            serializer.increaseContainerDepth();
+            serializer.serializeStr(field0);
+            serializer.serializeI32(field1);
+            serializer.decreaseContainerDepth();
+        }
+
+        Uint8List bincodeSerialize() {
+            final serializer = BincodeSerializer();
+            serialize(serializer);
+            return serializer.getBytes();
+        }
+
+        factory TupleStruct.deserialize(Deserializer deserializer) {
+            deserializer.increaseContainerDepth();
+            final field0 = deserializer.deserializeStr();
+            final field1 = deserializer.deserializeI32();
+            deserializer.decreaseContainerDepth();
+            return TupleStruct(field0, field1);
+        }
+
+        factory TupleStruct.bincodeDeserialize(Uint8List input) {
+            final deserializer = BincodeDeserializer(input);
+            final value = TupleStruct.deserialize(deserializer);
+            if (deserializer.getBufferOffset() < input.length) {
+                throw const DeserializationError('Some input bytes were not read');
+            }
+            return value;
+        }
+    }
+    ");
+}
+
+#[test]
+fn struct_with_fields() {
+    #[derive(Facet)]
+    struct Inner {
+        value: u64,
+    }
+
+    #[derive(Facet)]
+    struct Outer {
+        name: String,
+        big: u128,
+        nickname: Option<String>,
+        inner: Inner,
+        tags: Vec<Option<Inner>>,
+        counts: BTreeMap<String, Vec<u32>>,
+        ids: BTreeSet<u16>,
+        pair: (u8, String),
+        grid: [i16; 3],
+        #[facet(fg::bytes)]
+        data: Vec<u8>,
+    }
+
+    let actual = emit!(Outer as Dart with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    final class Inner {
+        const Inner({
+            required this.value,
+        });
+
+        final int value;
+
+        void serialize(Serializer serializer) {
+            serializer.increaseContainerDepth();
+            serializer.serializeU64(value);
+            serializer.decreaseContainerDepth();
+        }
+
+        Uint8List bincodeSerialize() {
+            final serializer = BincodeSerializer();
+            serialize(serializer);
+            return serializer.getBytes();
+        }
+
+        factory Inner.deserialize(Deserializer deserializer) {
+            deserializer.increaseContainerDepth();
+            final value = deserializer.deserializeU64();
+            deserializer.decreaseContainerDepth();
+            return Inner(value: value);
+        }
+
+        factory Inner.bincodeDeserialize(Uint8List input) {
+            final deserializer = BincodeDeserializer(input);
+            final value = Inner.deserialize(deserializer);
+            if (deserializer.getBufferOffset() < input.length) {
+                throw const DeserializationError('Some input bytes were not read');
+            }
+            return value;
+        }
+    }
+
+    final class Outer {
+        const Outer({
+            required this.name,
+            required this.big,
+            this.nickname,
+            required this.inner,
+            required this.tags,
+            required this.counts,
+            required this.ids,
+            required this.pair,
+            required this.grid,
+            required this.data,
+        });
+
+        final String name;
+        final BigInt big;
+        final String? nickname;
+        final Inner inner;
+        final List<Inner?> tags;
+        final Map<String, List<int>> counts;
+        final Set<int> ids;
+        final (int, String) pair;
+        final List<int> grid;
+        final Uint8List data;
+
+        void serialize(Serializer serializer) {
+            serializer.increaseContainerDepth();
+            serializer.serializeStr(name);
+            serializer.serializeU128(big);
+            serializer.serializeOption(nickname, (level1) => serializer.serializeStr(level1));
+            inner.serialize(serializer);
+            serializer.serializeSeq(tags, (level1) => serializer.serializeOption(level1, (level2) => level2.serialize(serializer)));
+            serializer.serializeMap(counts, (key1) => serializer.serializeStr(key1), (value1) => serializer.serializeSeq(value1, (level2) => serializer.serializeU32(level2)));
+            serializer.serializeSet(ids, (level1) => serializer.serializeU16(level1));
+            serializer.serializeU8(pair.$1);
+            serializer.serializeStr(pair.$2);
+            serializer.serializeTupleArray(grid, 3, (level1) => serializer.serializeI16(level1));
+            serializer.serializeBytes(data);
+            serializer.decreaseContainerDepth();
+        }
+
+        Uint8List bincodeSerialize() {
+            final serializer = BincodeSerializer();
+            serialize(serializer);
+            return serializer.getBytes();
+        }
+
+        factory Outer.deserialize(Deserializer deserializer) {
+            deserializer.increaseContainerDepth();
+            final name = deserializer.deserializeStr();
+            final big = deserializer.deserializeU128();
+            final nickname = deserializer.deserializeOption(() => deserializer.deserializeStr());
+            final inner = Inner.deserialize(deserializer);
+            final tags = deserializer.deserializeSeq(() => deserializer.deserializeOption(() => Inner.deserialize(deserializer)));
+            final counts = deserializer.deserializeMap(() => deserializer.deserializeStr(), () => deserializer.deserializeSeq(() => deserializer.deserializeU32()));
+            final ids = deserializer.deserializeSet(() => deserializer.deserializeU16());
+            final pair = (deserializer.deserializeU8(), deserializer.deserializeStr());
+            final grid = deserializer.deserializeTupleArray(3, () => deserializer.deserializeI16());
+            final data = deserializer.deserializeBytes();
+            deserializer.decreaseContainerDepth();
+            return Outer(name: name, big: big, nickname: nickname, inner: inner, tags: tags, counts: counts, ids: ids, pair: pair, grid: grid, data: data);
+        }
+
+        factory Outer.bincodeDeserialize(Uint8List input) {
+            final deserializer = BincodeDeserializer(input);
+            final value = Outer.deserialize(deserializer);
+            if (deserializer.getBufferOffset() < input.length) {
+                throw const DeserializationError('Some input bytes were not read');
+            }
+            return value;
+        }
+    }
+    ");
+}
+
+#[test]
+fn unit_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Colour {
+        Red,
+        Green,
+        Other,
+    }
+
+    let actual = emit!(Colour as Dart with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    enum Colour {
+        red,
+        green,
+        other;
+
+        void serialize(Serializer serializer) {
+            serializer.increaseContainerDepth();
+            serializer.serializeVariantIndex(index);
+            serializer.decreaseContainerDepth();
+        }
+
+        Uint8List bincodeSerialize() {
+            final serializer = BincodeSerializer();
+            serialize(serializer);
+            return serializer.getBytes();
+        }
+
+        factory Colour.deserialize(Deserializer deserializer) {
+            deserializer.increaseContainerDepth();
+            final index = deserializer.deserializeVariantIndex();
+            deserializer.decreaseContainerDepth();
+            return switch (index) {
+                0 => Colour.red,
+                1 => Colour.green,
+                2 => Colour.other,
+                _ => throw DeserializationError('Unknown variant index for Colour: $index'),
+            };
+        }
+
+        factory Colour.bincodeDeserialize(Uint8List input) {
+            final deserializer = BincodeDeserializer(input);
+            final value = Colour.deserialize(deserializer);
+            if (deserializer.getBufferOffset() < input.length) {
+                throw const DeserializationError('Some input bytes were not read');
+            }
+            return value;
+        }
+    }
+    ");
+}
+
+#[test]
+fn data_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Line(f64, f64),
+        Rectangle { width: f64, height: f64 },
+    }
+
+    let actual = emit!(Shape as Dart with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    sealed class Shape {
+        const Shape();
+
+        void serialize(Serializer serializer) {
+            switch (this) {
+                case ShapePoint():
+                    serializer.increaseContainerDepth();
+                    serializer.serializeVariantIndex(0);
+                    serializer.decreaseContainerDepth();
+                case ShapeCircle(:final value):
+                    serializer.increaseContainerDepth();
+                    serializer.serializeVariantIndex(1);
+                    serializer.serializeF64(value);
+                    serializer.decreaseContainerDepth();
+                case ShapeLine(:final field0, :final field1):
+                    serializer.increaseContainerDepth();
+                    serializer.serializeVariantIndex(2);
+                    serializer.serializeF64(field0);
+                    serializer.serializeF64(field1);
+                    serializer.decreaseContainerDepth();
+                case ShapeRectangle(:final width, :final height):
+                    serializer.increaseContainerDepth();
+                    serializer.serializeVariantIndex(3);
+                    serializer.serializeF64(width);
+                    serializer.serializeF64(height);
+                    serializer.decreaseContainerDepth();
+            }
+        }
+
+        Uint8List bincodeSerialize() {
+            final serializer = BincodeSerializer();
+            serialize(serializer);
+            return serializer.getBytes();
+        }
+
+        factory Shape.deserialize(Deserializer deserializer) {
+            final index = deserializer.deserializeVariantIndex();
+            if (index == 0) {
+                return const ShapePoint();
+            }
+            if (index == 1) {
+                deserializer.increaseContainerDepth();
+                final value = deserializer.deserializeF64();
+                deserializer.decreaseContainerDepth();
+                return ShapeCircle(value);
+            }
+            if (index == 2) {
+                deserializer.increaseContainerDepth();
+                final field0 = deserializer.deserializeF64();
+                final field1 = deserializer.deserializeF64();
+                deserializer.decreaseContainerDepth();
+                return ShapeLine(field0, field1);
+            }
+            if (index == 3) {
+                deserializer.increaseContainerDepth();
+                final width = deserializer.deserializeF64();
+                final height = deserializer.deserializeF64();
+                deserializer.decreaseContainerDepth();
+                return ShapeRectangle(width: width, height: height);
+            }
+            throw DeserializationError('Unknown variant index for Shape: $index');
+        }
+
+        factory Shape.bincodeDeserialize(Uint8List input) {
+            final deserializer = BincodeDeserializer(input);
+            final value = Shape.deserialize(deserializer);
+            if (deserializer.getBufferOffset() < input.length) {
+                throw const DeserializationError('Some input bytes were not read');
+            }
+            return value;
+        }
+    }
+
+    final class ShapePoint extends Shape {
+        const ShapePoint();
+    }
+
+    final class ShapeCircle extends Shape {
+        const ShapeCircle(this.value);
+
+        final double value;
+    }
+
+    final class ShapeLine extends Shape {
+        const ShapeLine(this.field0, this.field1);
+
+        final double field0;
+        final double field1;
+    }
+
+    final class ShapeRectangle extends Shape {
+        const ShapeRectangle({
+            required this.width,
+            required this.height,
+        });
+
+        final double width;
+        final double height;
+    }
+    ");
+}
+
+#[test]
+fn generic_struct_and_enum() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E },
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        page: Page<String>,
+        outcome: Outcome<Page<u32>, String>,
+        fallback: Outcome<bool, i32>,
+    }
+
+    let actual = emit!(Response as Dart with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    sealed class Outcome<T, E> {
+        const Outcome();
+
+        void serialize(Serializer serializer, void Function(Serializer, T) serializeT, void Function(Serializer, E) serializeE) {
+            switch (this) {
+                case OutcomeSuccess(:final value):
+                    serializer.increaseContainerDepth();
+                    serializer.serializeVariantIndex(0);
+                    serializeT(serializer, value);
+                    serializer.decreaseContainerDepth();
+                case OutcomeFailure(:final error):
+                    serializer.increaseContainerDepth();
+                    serializer.serializeVariantIndex(1);
+                    serializeE(serializer, error);
+                    serializer.decreaseContainerDepth();
+            }
+        }
+
+        Uint8List bincodeSerialize(void Function(Serializer, T) serializeT, void Function(Serializer, E) serializeE) {
+            final serializer = BincodeSerializer();
+            serialize(serializer, serializeT, serializeE);
+            return serializer.getBytes();
+        }
+
+        factory Outcome.deserialize(Deserializer deserializer, T Function(Deserializer) deserializeT, E Function(Deserializer) deserializeE) {
+            final index = deserializer.deserializeVariantIndex();
+            if (index == 0) {
+                deserializer.increaseContainerDepth();
+                final value = deserializeT(deserializer);
+                deserializer.decreaseContainerDepth();
+                return OutcomeSuccess(value);
+            }
+            if (index == 1) {
+                deserializer.increaseContainerDepth();
+                final error = deserializeE(deserializer);
+                deserializer.decreaseContainerDepth();
+                return OutcomeFailure(error: error);
+            }
+            throw DeserializationError('Unknown variant index for Outcome: $index');
+        }
+
+        factory Outcome.bincodeDeserialize(Uint8List input, T Function(Deserializer) deserializeT, E Function(Deserializer) deserializeE) {
+            final deserializer = BincodeDeserializer(input);
+            final value = Outcome.deserialize(deserializer, deserializeT, deserializeE);
+            if (deserializer.getBufferOffset() < input.length) {
+                throw const DeserializationError('Some input bytes were not read');
+            }
+            return value;
+        }
+    }
+
+    final class OutcomeSuccess<T> extends Outcome<T, Never> {
+        const OutcomeSuccess(this.value);
+
+        final T value;
+    }
+
+    final class OutcomeFailure<E> extends Outcome<Never, E> {
+        const OutcomeFailure({
+            required this.error,
+        });
+
+        final E error;
+    }
+
+    final class Page<T> {
+        const Page({
+            required this.items,
+            this.next,
+        });
+
+        final List<T> items;
+        final T? next;
+
+        void serialize(Serializer serializer, void Function(Serializer, T) serializeT) {
+            serializer.increaseContainerDepth();
+            serializer.serializeSeq(items, (level1) => serializeT(serializer, level1));
+            serializer.serializeOption(next, (level1) => serializeT(serializer, level1));
+            serializer.decreaseContainerDepth();
+        }
+
+        Uint8List bincodeSerialize(void Function(Serializer, T) serializeT) {
+            final serializer = BincodeSerializer();
+            serialize(serializer, serializeT);
+            return serializer.getBytes();
+        }
+
+        factory Page.deserialize(Deserializer deserializer, T Function(Deserializer) deserializeT) {
+            deserializer.increaseContainerDepth();
+            final items = deserializer.deserializeSeq(() => deserializeT(deserializer));
+            final next = deserializer.deserializeOption(() => deserializeT(deserializer));
+            deserializer.decreaseContainerDepth();
+            return Page(items: items, next: next);
+        }
+
+        factory Page.bincodeDeserialize(Uint8List input, T Function(Deserializer) deserializeT) {
+            final deserializer = BincodeDeserializer(input);
+            final value = Page.deserialize(deserializer, deserializeT);
+            if (deserializer.getBufferOffset() < input.length) {
+                throw const DeserializationError('Some input bytes were not read');
+            }
+            return value;
+        }
+    }
+
+    final class Response {
+        const Response({
+            required this.page,
+            required this.outcome,
+            required this.fallback,
+        });
+
+        final Page<String> page;
+        final Outcome<Page<int>, String> outcome;
+        final Outcome<bool, int> fallback;
+
+        void serialize(Serializer serializer) {
+            serializer.increaseContainerDepth();
+            page.serialize(serializer, (serializer, level1) => serializer.serializeStr(level1));
+            outcome.serialize(serializer, (serializer, level1) => level1.serialize(serializer, (serializer, level2) => serializer.serializeU32(level2)), (serializer, level1) => serializer.serializeStr(level1));
+            fallback.serialize(serializer, (serializer, level1) => serializer.serializeBool(level1), (serializer, level1) => serializer.serializeI32(level1));
+            serializer.decreaseContainerDepth();
+        }
+
+        Uint8List bincodeSerialize() {
+            final serializer = BincodeSerializer();
+            serialize(serializer);
+            return serializer.getBytes();
+        }
+
+        factory Response.deserialize(Deserializer deserializer) {
+            deserializer.increaseContainerDepth();
+            final page = Page.deserialize(deserializer, (deserializer) => deserializer.deserializeStr());
+            final outcome = Outcome.deserialize(deserializer, (deserializer) => Page.deserialize(deserializer, (deserializer) => deserializer.deserializeU32()), (deserializer) => deserializer.deserializeStr());
+            final fallback = Outcome.deserialize(deserializer, (deserializer) => deserializer.deserializeBool(), (deserializer) => deserializer.deserializeI32());
+            deserializer.decreaseContainerDepth();
+            return Response(page: page, outcome: outcome, fallback: fallback);
+        }
+
+        factory Response.bincodeDeserialize(Uint8List input) {
+            final deserializer = BincodeDeserializer(input);
+            final value = Response.deserialize(deserializer);
+            if (deserializer.getBufferOffset() < input.length) {
+                throw const DeserializationError('Some input bytes were not read');
+            }
+            return value;
+        }
+    }
+    ");
+}
+
+#[test]
+fn branded_newtype() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    let actual = emit!(UserId as Dart with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    extension type const UserId(String value) {
+        void serialize(Serializer serializer) {
+            serializer.increaseContainerDepth();
+            serializer.serializeStr(value);
+            serializer.decreaseContainerDepth();
+        }
+
+        Uint8List bincodeSerialize() {
+            final serializer = BincodeSerializer();
+            serialize(serializer);
+            return serializer.getBytes();
+        }
+
+        factory UserId.deserialize(Deserializer deserializer) {
+            deserializer.increaseContainerDepth();
+            final value = deserializer.deserializeStr();
+            deserializer.decreaseContainerDepth();
+            return UserId(value);
+        }
+
+        factory UserId.bincodeDeserialize(Uint8List input) {
+            final deserializer = BincodeDeserializer(input);
+            final value = UserId.deserialize(deserializer);
+            if (deserializer.getBufferOffset() < input.length) {
+                throw const DeserializationError('Some input bytes were not read');
+            }
+            return value;
+        }
+    }
+    ");
+}
+
+#[test]
+fn unknown_variants() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Click {
+            x: i32,
+            y: i32,
+        },
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Event as Dart with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    sealed class Event {
+        const Event();
+
+        void serialize(Serializer serializer) {
+            switch (this) {
+                case EventClick(:final x, :final y):
+                    serializer.increaseContainerDepth();
+                    serializer.serializeVariantIndex(0);
+                    serializer.serializeI32(x);
+                    serializer.serializeI32(y);
+                    serializer.decreaseContainerDepth();
+                case EventUnknown():
+                    serializer.increaseContainerDepth();
+                    serializer.serializeVariantIndex(1);
+                    serializer.decreaseContainerDepth();
+            }
+        }
+
+        Uint8List bincodeSerialize() {
+            final serializer = BincodeSerializer();
+            serialize(serializer);
+            return serializer.getBytes();
+        }
+
+        factory Event.deserialize(Deserializer deserializer) {
+            final index = deserializer.deserializeVariantIndex();
+            if (index == 0) {
+                deserializer.increaseContainerDepth();
+                final x = deserializer.deserializeI32();
+                final y = deserializer.deserializeI32();
+                deserializer.decreaseContainerDepth();
+                return EventClick(x: x, y: y);
+            }
+            if (index == 1) {
+                return const EventUnknown();
+            }
+            return const EventUnknown();
+        }
+
+        factory Event.bincodeDeserialize(Uint8List input) {
+            final deserializer = BincodeDeserializer(input);
+            final value = Event.deserialize(deserializer);
+            if (deserializer.getBufferOffset() < input.length) {
+                throw const DeserializationError('Some input bytes were not read');
+            }
+            return value;
+        }
+    }
+
+    final class EventClick extends Event {
+        const EventClick({
+            required this.x,
+            required this.y,
+        });
+
+        final int x;
+        final int y;
+    }
+
+    final class EventUnknown extends Event {
+        const EventUnknown();
+    }
+    ");
+}
+
+#[test]
+fn type_override() {
+    #[derive(Facet)]
+    struct Price {
+        #[facet(fg::override(
+            lang = "dart",
+            ty = "Decimal",
+            serialize = "serializer.serializeStr({value}.toString())",
+            deserialize = "Decimal.parse(deserializer.deserializeStr())"
+        ))]
+        amount: String,
+        #[facet(fg::override(lang = "swift", ty = "CGFloat"))]
+        scale: f64,
+    }
+
+    let actual = emit!(Price as Dart with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    final class Price {
+        const Price({
+            required this.amount,
+            required this.scale,
+        });
+
+        final Decimal amount;
+        final double scale;
+
+        void serialize(Serializer serializer) {
+            serializer.increaseContainerDepth();
+            serializer.serializeStr(amount.toString());
+            serializer.serializeF64(scale);
+            serializer.decreaseContainerDepth();
+        }
+
+        Uint8List bincodeSerialize() {
+            final serializer = BincodeSerializer();
+            serialize(serializer);
+            return serializer.getBytes();
+        }
+
+        factory Price.deserialize(Deserializer deserializer) {
+            deserializer.increaseContainerDepth();
+            final amount = Decimal.parse(deserializer.deserializeStr());
+            final scale = deserializer.deserializeF64();
+            deserializer.decreaseContainerDepth();
+            return Price(amount: amount, scale: scale);
+        }
+
+        factory Price.bincodeDeserialize(Uint8List input) {
+            final deserializer = BincodeDeserializer(input);
+            final value = Price.deserialize(deserializer);
+            if (deserializer.getBufferOffset() < input.length) {
+                throw const DeserializationError('Some input bytes were not read');
+            }
+            return value;
+        }
+    }
+    ");
+}
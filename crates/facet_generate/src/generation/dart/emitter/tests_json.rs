@@ -0,0 +1,755 @@
+//! Snapshot tests for the Dart emitter — **JSON encoding**.
+//!
+//! Mirrors the structure of [`tests`](super::tests) but uses `JsonPlugin`, so
+//! that every top-level type gets a `toJson` method and a `fromJson` factory
+//! constructor, converting to and from the values of `dart:convert`.
+//!
+//! These tests verify the JSON shapes of each container (objects for structs,
+//! the inner value for newtypes, arrays for tuples), field defaults, the four
+//! enum taggings and the converters of type parameters.
+
+#![allow(clippy::too_many_lines)]
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::generation::json::JsonPlugin;
+use facet::Facet;
+
+use super::*;
+use crate::emit;
+
+#[test]
+fn unit_and_empty_structs() {
+    #[derive(Facet)]
+    struct UnitStruct;
+
+    #[derive(Facet)]
+    struct EmptyStruct {}
+
+    #[derive(Facet)]
+    struct Both {
+        unit: UnitStruct,
+        empty: EmptyStruct,
+    }
+
+    let actual = emit!(Both as Dart with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    final class Both {
+        const Both({
+            required this.unit,
+            required this.empty,
+        });
+
+        final UnitStruct unit;
+        final EmptyStruct empty;
+
+        Object? toJson() => {
+            'unit': unit.toJson(),
+            'empty': empty.toJson(),
+        };
+
+        factory Both.fromJson(Object? json) {
+            final map = json as Map<String, Object?>;
+            return Both(
+                unit: UnitStruct.fromJson(map['unit']),
+                empty: EmptyStruct.fromJson(map['empty']),
+            );
+        }
+    }
+
+    final class EmptyStruct {
+        const EmptyStruct();
+
+        Object? toJson() => null;
+
+        factory EmptyStruct.fromJson(Object? json) => const EmptyStruct();
+    }
+
+    final class UnitStruct {
+        const UnitStruct();
+
+        Object? toJson() => null;
+
+        factory UnitStruct.fromJson(Object? json) => const UnitStruct();
+    }
+    ");
+}
+
+#[test]
+fn newtype_and_tuple_structs() {
+    #[derive(Facet)]
+    struct NewType(Vec<String>);
+
+    #[derive(Facet)]
+    struct TupleStruct(String, f64);
+
+    #[derive(Facet)]
+    struct Both {
+        newtype: NewType,
+        tuple: TupleStruct,
+    }
+
+    let actual = emit!(Both as Dart with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    final class Both {
+        const Both({
+            required this.newtype,
+            required this.tuple,
+        });
+
+        final NewType newtype;
+        final TupleStruct tuple;
+
+        Object? toJson() => {
+            'newtype': newtype.toJson(),
+            'tuple': tuple.toJson(),
+        };
+
+        factory Both.fromJson(Object? json) {
+            final map = json as Map<String, Object?>;
+            return Both(
+                newtype: NewType.fromJson(map['newtype']),
+                tuple: TupleStruct.fromJson(map['tuple']),
+            );
+        }
+    }
+
+    final class NewType {
+        const NewType(this.value);
+
+        final List<String> value;
+
+        Object? toJson() => value;
+
+        factory NewType.fromJson(Object? json) => NewType([for (final level1 in json as List<Object?>) level1 as String]);
+    }
+
+    final class TupleStruct {
+        const TupleStruct(this.field0, this.field1);
+
+        final String field0;
+        final double field1;
+
+        Object? toJson() => [field0, field1];
+
+        factory TupleStruct.fromJson(Object? json) => tupleFromJson(json, 2, (elements) => TupleStruct(elements[0] as String, (elements[1] as num).toDouble()));
+    }
+    ");
+}
+
+#[test]
+fn struct_with_fields() {
+    #[derive(Facet, PartialEq, Eq, PartialOrd, Ord)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Kind {
+        Small,
+        Large,
+    }
+
+    #[derive(Facet)]
+    struct Outer {
+        name: String,
+        big: i128,
+        ratio: f32,
+        nickname: Option<String>,
+        kind: Option<Kind>,
+        tags: Vec<Kind>,
+        counts: BTreeMap<u32, Vec<f64>>,
+        kinds: BTreeMap<Kind, String>,
+        ids: HashSet<u16>,
+        pair: (u8, String),
+        timeout: Duration,
+        #[facet(default = 3)]
+        retries: u32,
+    }
+
+    let actual = emit!(Outer as Dart with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    enum Kind {
+        small,
+        large;
+
+        Object? toJson() => switch (this) {
+            Kind.small => 'Small',
+            Kind.large => 'Large',
+        };
+
+        factory Kind.fromJson(Object? json) => switch (json) {
+            'Small' => Kind.small,
+            'Large' => Kind.large,
+            _ => throw DeserializationError('Unknown variant for Kind: $json'),
+        };
+    }
+
+    final class Outer {
+        const Outer({
+            required this.name,
+            required this.big,
+            required this.ratio,
+            this.nickname,
+            this.kind,
+            required this.tags,
+            required this.counts,
+            required this.kinds,
+            required this.ids,
+            required this.pair,
+            required this.timeout,
+            this.retries = 3,
+        });
+
+        final String name;
+        final BigInt big;
+        final double ratio;
+        final String? nickname;
+        final Kind? kind;
+        final List<Kind> tags;
+        final Map<int, List<double>> counts;
+        final Map<Kind, String> kinds;
+        final Set<int> ids;
+        final (int, String) pair;
+        final Duration timeout;
+        final int retries;
+
+        Object? toJson() => {
+            'name': name,
+            'big': bigIntToJson(big),
+            'ratio': ratio,
+            'nickname': nickname,
+            'kind': optionToJson(kind, (level1) => level1.toJson()),
+            'tags': [for (final level1 in tags) level1.toJson()],
+            'counts': {for (final MapEntry(key: key1, value: value1) in counts.entries) key1.toString(): value1},
+            'kinds': {for (final MapEntry(key: key1, value: value1) in kinds.entries) key1.toJson() as String: value1},
+            'ids': ids.toList(),
+            'pair': [pair.$1, pair.$2],
+            'timeout': durationToJson(timeout),
+            'retries': retries,
+        };
+
+        factory Outer.fromJson(Object? json) {
+            final map = json as Map<String, Object?>;
+            return Outer(
+                name: map['name'] as String,
+                big: bigIntFromJson(map['big']),
+                ratio: (map['ratio'] as num).toDouble(),
+                nickname: map['nickname'] as String?,
+                kind: optionFromJson(map['kind'], (level1) => Kind.fromJson(level1)),
+                tags: [for (final level1 in map['tags'] as List<Object?>) Kind.fromJson(level1)],
+                counts: {for (final MapEntry(key: key1, value: value1) in (map['counts'] as Map<String, Object?>).entries) int.parse(key1): [for (final level2 in value1 as List<Object?>) (level2 as num).toDouble()]},
+                kinds: {for (final MapEntry(key: key1, value: value1) in (map['kinds'] as Map<String, Object?>).entries) Kind.fromJson(key1): value1 as String},
+                ids: {for (final level1 in map['ids'] as List<Object?>) level1 as int},
+                pair: tupleFromJson(map['pair'], 2, (level1) => (level1[0] as int, level1[1] as String)),
+                timeout: durationFromJson(map['timeout']),
+                retries: map.containsKey('retries') ? map['retries'] as int : 3,
+            );
+        }
+    }
+    ");
+}
+
+#[test]
+fn externally_tagged_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Line(f64, f64),
+        Rectangle { width: f64, height: f64 },
+    }
+
+    let actual = emit!(Shape as Dart with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    sealed class Shape {
+        const Shape();
+
+        Object? toJson() => switch (this) {
+            ShapePoint() => 'Point',
+            ShapeCircle(:final value) => {'Circle': value},
+            ShapeLine(:final field0, :final field1) => {'Line': [field0, field1]},
+            ShapeRectangle(:final width, :final height) => {'Rectangle': {'width': width, 'height': height}},
+        };
+
+        factory Shape.fromJson(Object? json) {
+            final (tag, content) = externalTag(json);
+            if (tag == 'Point') {
+                return const ShapePoint();
+            }
+            if (tag == 'Circle') {
+                return ShapeCircle((content as num).toDouble());
+            }
+            if (tag == 'Line') {
+                return tupleFromJson(content, 2, (elements) => ShapeLine((elements[0] as num).toDouble(), (elements[1] as num).toDouble()));
+            }
+            if (tag == 'Rectangle') {
+                final fields = content as Map<String, Object?>;
+                return ShapeRectangle(
+                    width: (fields['width'] as num).toDouble(),
+                    height: (fields['height'] as num).toDouble(),
+                );
+            }
+            throw DeserializationError('Unknown variant for Shape: $tag');
+        }
+    }
+
+    final class ShapePoint extends Shape {
+        const ShapePoint();
+    }
+
+    final class ShapeCircle extends Shape {
+        const ShapeCircle(this.value);
+
+        final double value;
+    }
+
+    final class ShapeLine extends Shape {
+        const ShapeLine(this.field0, this.field1);
+
+        final double field0;
+        final double field1;
+    }
+
+    final class ShapeRectangle extends Shape {
+        const ShapeRectangle({
+            required this.width,
+            required this.height,
+        });
+
+        final double width;
+        final double height;
+    }
+    ");
+}
+
+#[test]
+fn internally_tagged_enum() {
+    #[derive(Facet)]
+    struct Position {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Facet)]
+    #[facet(tag = "type")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Started,
+        Moved(Position),
+        Clicked {
+            button: u8,
+        },
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Event as Dart with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    sealed class Event {
+        const Event();
+
+        Object? toJson() => switch (this) {
+            EventStarted() => {'type': 'Started'},
+            EventMoved(:final value) => {'type': 'Moved', ...(value.toJson() as Map<String, Object?>)},
+            EventClicked(:final button) => {'type': 'Clicked', 'button': button},
+            EventUnknown() => {'type': 'Unknown'},
+        };
+
+        factory Event.fromJson(Object? json) {
+            final map = json as Map<String, Object?>;
+            final tag = map['type'];
+            if (tag == 'Started') {
+                return const EventStarted();
+            }
+            if (tag == 'Moved') {
+                return EventMoved(Position.fromJson(map));
+            }
+            if (tag == 'Clicked') {
+                return EventClicked(
+                    button: map['button'] as int,
+                );
+            }
+            if (tag == 'Unknown') {
+                return const EventUnknown();
+            }
+            return const EventUnknown();
+        }
+    }
+
+    final class EventStarted extends Event {
+        const EventStarted();
+    }
+
+    final class EventMoved extends Event {
+        const EventMoved(this.value);
+
+        final Position value;
+    }
+
+    final class EventClicked extends Event {
+        const EventClicked({
+            required this.button,
+        });
+
+        final int button;
+    }
+
+    final class EventUnknown extends Event {
+        const EventUnknown();
+    }
+
+    final class Position {
+        const Position({
+            required this.x,
+            required this.y,
+        });
+
+        final int x;
+        final int y;
+
+        Object? toJson() => {
+            'x': x,
+            'y': y,
+        };
+
+        factory Position.fromJson(Object? json) {
+            final map = json as Map<String, Object?>;
+            return Position(
+                x: map['x'] as int,
+                y: map['y'] as int,
+            );
+        }
+    }
+    ");
+}
+
+#[test]
+fn adjacently_tagged_enum() {
+    #[derive(Facet)]
+    #[facet(tag = "t", content = "c")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Message {
+        Ping,
+        Text(String),
+        Pair(u32, u32),
+    }
+
+    let actual = emit!(Message as Dart with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    sealed class Message {
+        const Message();
+
+        Object? toJson() => switch (this) {
+            MessagePing() => {'t': 'Ping'},
+            MessageText(:final value) => {'t': 'Text', 'c': value},
+            MessagePair(:final field0, :final field1) => {'t': 'Pair', 'c': [field0, field1]},
+        };
+
+        factory Message.fromJson(Object? json) {
+            final map = json as Map<String, Object?>;
+            final tag = map['t'];
+            final content = map['c'];
+            if (tag == 'Ping') {
+                return const MessagePing();
+            }
+            if (tag == 'Text') {
+                return MessageText(content as String);
+            }
+            if (tag == 'Pair') {
+                return tupleFromJson(content, 2, (elements) => MessagePair(elements[0] as int, elements[1] as int));
+            }
+            throw DeserializationError('Unknown variant for Message: $tag');
+        }
+    }
+
+    final class MessagePing extends Message {
+        const MessagePing();
+    }
+
+    final class MessageText extends Message {
+        const MessageText(this.value);
+
+        final String value;
+    }
+
+    final class MessagePair extends Message {
+        const MessagePair(this.field0, this.field1);
+
+        final int field0;
+        final int field1;
+    }
+    ");
+}
+
+#[test]
+fn untagged_enum() {
+    #[derive(Facet)]
+    #[facet(untagged)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Value {
+        Nothing,
+        Number(f64),
+        Named { name: String },
+    }
+
+    let actual = emit!(Value as Dart with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    sealed class Value {
+        const Value();
+
+        Object? toJson() => switch (this) {
+            ValueNothing() => null,
+            ValueNumber(:final value) => value,
+            ValueNamed(:final name) => {'name': name},
+        };
+
+        factory Value.fromJson(Object? json) {
+            if (json == null) {
+                return const ValueNothing();
+            }
+            try {
+                return ValueNumber((json as num).toDouble());
+            } catch (_) {}
+            try {
+                final fields = json as Map<String, Object?>;
+                return ValueNamed(
+                    name: fields['name'] as String,
+                );
+            } catch (_) {}
+            throw DeserializationError('No variant of Value matches $json');
+        }
+    }
+
+    final class ValueNothing extends Value {
+        const ValueNothing();
+    }
+
+    final class ValueNumber extends Value {
+        const ValueNumber(this.value);
+
+        final double value;
+    }
+
+    final class ValueNamed extends Value {
+        const ValueNamed({
+            required this.name,
+        });
+
+        final String name;
+    }
+    ");
+}
+
+#[test]
+fn tagged_unit_enums() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Plain {
+        On,
+        Off,
+    }
+
+    #[derive(Facet)]
+    #[facet(tag = "kind")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Tagged {
+        Up,
+        #[facet(other)]
+        Unknown,
+    }
+
+    #[derive(Facet)]
+    struct Both {
+        plain: Plain,
+        tagged: Tagged,
+    }
+
+    let actual = emit!(Both as Dart with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    final class Both {
+        const Both({
+            required this.plain,
+            required this.tagged,
+        });
+
+        final Plain plain;
+        final Tagged tagged;
+
+        Object? toJson() => {
+            'plain': plain.toJson(),
+            'tagged': tagged.toJson(),
+        };
+
+        factory Both.fromJson(Object? json) {
+            final map = json as Map<String, Object?>;
+            return Both(
+                plain: Plain.fromJson(map['plain']),
+                tagged: Tagged.fromJson(map['tagged']),
+            );
+        }
+    }
+
+    enum Plain {
+        on,
+        off;
+
+        Object? toJson() => switch (this) {
+            Plain.on => 'On',
+            Plain.off => 'Off',
+        };
+
+        factory Plain.fromJson(Object? json) => switch (json) {
+            'On' => Plain.on,
+            'Off' => Plain.off,
+            _ => throw DeserializationError('Unknown variant for Plain: $json'),
+        };
+    }
+
+    enum Tagged {
+        up,
+        unknown;
+
+        Object? toJson() => switch (this) {
+            Tagged.up => {'kind': 'Up'},
+            Tagged.unknown => {'kind': 'Unknown'},
+        };
+
+        factory Tagged.fromJson(Object? json) => switch ((json as Map<String, Object?>)['kind']) {
+            'Up' => Tagged.up,
+            'Unknown' => Tagged.unknown,
+            _ => Tagged.unknown,
+        };
+    }
+    ");
+}
+
+#[test]
+fn generic_struct_and_enum() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E },
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        page: Page<String>,
+        outcome: Outcome<Page<u32>, String>,
+        fallback: Outcome<bool, i32>,
+    }
+
+    let actual = emit!(Response as Dart with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    sealed class Outcome<T, E> {
+        const Outcome();
+
+        Object? toJson(Object? Function(T) toJsonT, Object? Function(E) toJsonE) => switch (this) {
+            OutcomeSuccess(:final value) => {'Success': toJsonT(value)},
+            OutcomeFailure(:final error) => {'Failure': {'error': toJsonE(error)}},
+        };
+
+        factory Outcome.fromJson(Object? json, T Function(Object?) fromJsonT, E Function(Object?) fromJsonE) {
+            final (tag, content) = externalTag(json);
+            if (tag == 'Success') {
+                return OutcomeSuccess(fromJsonT(content));
+            }
+            if (tag == 'Failure') {
+                final fields = content as Map<String, Object?>;
+                return OutcomeFailure(
+                    error: fromJsonE(fields['error']),
+                );
+            }
+            throw DeserializationError('Unknown variant for Outcome: $tag');
+        }
+    }
+
+    final class OutcomeSuccess<T> extends Outcome<T, Never> {
+        const OutcomeSuccess(this.value);
+
+        final T value;
+    }
+
+    final class OutcomeFailure<E> extends Outcome<Never, E> {
+        const OutcomeFailure({
+            required this.error,
+        });
+
+        final E error;
+    }
+
+    final class Page<T> {
+        const Page({
+            required this.items,
+            this.next,
+        });
+
+        final List<T> items;
+        final T? next;
+
+        Object? toJson(Object? Function(T) toJsonT) => {
+            'items': [for (final level1 in items) toJsonT(level1)],
+            'next': optionToJson(next, (level1) => toJsonT(level1)),
+        };
+
+        factory Page.fromJson(Object? json, T Function(Object?) fromJsonT) {
+            final map = json as Map<String, Object?>;
+            return Page(
+                items: [for (final level1 in map['items'] as List<Object?>) fromJsonT(level1)],
+                next: optionFromJson(map['next'], (level1) => fromJsonT(level1)),
+            );
+        }
+    }
+
+    final class Response {
+        const Response({
+            required this.page,
+            required this.outcome,
+            required this.fallback,
+        });
+
+        final Page<String> page;
+        final Outcome<Page<int>, String> outcome;
+        final Outcome<bool, int> fallback;
+
+        Object? toJson() => {
+            'page': page.toJson((level1) => level1),
+            'outcome': outcome.toJson((level1) => level1.toJson((level2) => level2), (level1) => level1),
+            'fallback': fallback.toJson((level1) => level1, (level1) => level1),
+        };
+
+        factory Response.fromJson(Object? json) {
+            final map = json as Map<String, Object?>;
+            return Response(
+                page: Page.fromJson(map['page'], (level1) => level1 as String),
+                outcome: Outcome.fromJson(map['outcome'], (level1) => Page.fromJson(level1, (level2) => level2 as int), (level1) => level1 as String),
+                fallback: Outcome.fromJson(map['fallback'], (level1) => level1 as bool, (level1) => level1 as int),
+            );
+        }
+    }
+    ");
+}
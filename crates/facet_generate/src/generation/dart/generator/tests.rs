@@ -0,0 +1,267 @@
+//! Unit tests for [`DartCodeGenerator`] — import generation, qualified-name
+//! resolution and per-language filtering.
+//!
+//! Tests build small [`Registry`](crate::Registry) values by hand (rather than
+//! via the `reflect!` macro) so that module and external-package configurations
+//! can be controlled precisely.
+//!
+//! # Coverage
+//!
+//! | Area | What is tested |
+//! |------|----------------|
+//! | Same-module stripping | `Named` namespace matching the module name → bare name, also for generics |
+//! | Other namespaces | `Named` namespace for a different module → preserved as `prefix.Type` |
+//! | Import generation | Relative (`other.dart`) and external package (`package:…`) imports, with prefixes |
+//! | Plugin imports | Runtime imports sorted after `dart:` libraries |
+//! | Filtering | Variants skipped for Dart keep their bincode indices |
+//! | Rejection | Registries the plugins can't encode |
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use facet::Facet;
+
+use super::*;
+use crate::{
+    self as fg,
+    generation::{
+        CodeGeneratorConfig,
+        bincode::BincodePlugin,
+        config::{ExternalPackage, PackageLocation},
+        json::JsonPlugin,
+        plugin::EmitterPlugin,
+    },
+    reflect,
+    reflection::format::{
+        ContainerFormat, ContainerMetadata, Doc, FieldMetadata, Format, Named, Namespace,
+        QualifiedTypeName,
+    },
+};
+
+fn registry_with_struct_field(field_type: Format) -> Registry {
+    let mut registry = Registry::new();
+    let fields = vec![Named {
+        name: "value".to_string(),
+        doc: Doc::new(),
+        value: field_type,
+        metadata: FieldMetadata::default(),
+    }];
+    registry.insert(
+        QualifiedTypeName::root("Holder".to_string()),
+        ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default()),
+    );
+    registry
+}
+
+fn first_field_type(registry: &Registry) -> &Format {
+    let (_, container) = registry.iter().next().unwrap();
+    let ContainerFormat::Struct(fields, _, _) = container else {
+        panic!("expected struct container");
+    };
+    &fields[0].value
+}
+
+fn render_output(
+    config: &CodeGeneratorConfig,
+    plugins: Vec<Arc<dyn EmitterPlugin<Dart>>>,
+    registry: &Registry,
+) -> String {
+    let generator = DartCodeGenerator::new(config).with_plugins(plugins);
+    let mut output = Vec::new();
+    generator.output(&mut output, registry).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn update_qualified_names_strips_same_module_namespace() {
+    let config = CodeGeneratorConfig::new("root".to_string());
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "root".to_string(),
+        "Child".to_string(),
+    )));
+
+    let updated = DartCodeGenerator::update_qualified_names(&config, &registry);
+
+    let Format::TypeName(type_name) = first_field_type(&updated) else {
+        panic!("expected type name");
+    };
+    assert_eq!(type_name.namespace, Namespace::Root);
+    assert_eq!(type_name.name, "Child");
+}
+
+#[test]
+fn update_qualified_names_strips_same_module_generic() {
+    let config = CodeGeneratorConfig::new("root".to_string());
+    let registry = registry_with_struct_field(Format::Generic {
+        name: QualifiedTypeName::namespaced("root".to_string(), "Page".to_string()),
+        args: vec![Format::Str],
+    });
+
+    let updated = DartCodeGenerator::update_qualified_names(&config, &registry);
+
+    let Format::Generic { name, .. } = first_field_type(&updated) else {
+        panic!("expected generic");
+    };
+    assert_eq!(name.namespace, Namespace::Root);
+}
+
+#[test]
+fn update_qualified_names_keeps_other_namespace() {
+    let config = CodeGeneratorConfig::new("root".to_string());
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "other".to_string(),
+        "Child".to_string(),
+    )));
+
+    let updated = DartCodeGenerator::update_qualified_names(&config, &registry);
+
+    let Format::TypeName(type_name) = first_field_type(&updated) else {
+        panic!("expected type name");
+    };
+    assert_eq!(type_name.namespace, Namespace::Named("other".to_string()));
+}
+
+#[test]
+fn output_imports_other_namespace_with_prefix() {
+    let config = CodeGeneratorConfig::new("root".to_string());
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "other".to_string(),
+        "Child".to_string(),
+    )));
+
+    let output = render_output(&config, vec![], &registry);
+    insta::assert_snapshot!(output, @"
+    import 'other.dart' as other;
+
+    final class Holder {
+        const Holder({
+            required this.value,
+        });
+
+        final other.Child value;
+    }
+    ");
+}
+
+#[test]
+fn output_imports_external_package() {
+    let mut config = CodeGeneratorConfig::new("root".to_string());
+    config.external_packages = BTreeMap::from([(
+        "other".to_string(),
+        ExternalPackage {
+            for_namespace: "shared_types".to_string(),
+            location: PackageLocation::Path("../shared_types".to_string()),
+            module_name: Some("models".to_string()),
+            version: None,
+        },
+    )]);
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "other".to_string(),
+        "Child".to_string(),
+    )));
+
+    let output = render_output(&config, vec![], &registry);
+    assert!(output.contains("import 'package:shared_types/models.dart' as other;"));
+    assert!(!output.contains("import 'other.dart'"));
+}
+
+#[test]
+fn output_sorts_plugin_imports() {
+    let config = CodeGeneratorConfig::new("root".to_string());
+    let registry = registry_with_struct_field(Format::Bytes);
+
+    let output = render_output(
+        &config,
+        vec![Arc::new(BincodePlugin), Arc::new(JsonPlugin)],
+        &registry,
+    );
+    let header = output.lines().take(6).collect::<Vec<_>>().join("\n");
+    insta::assert_snapshot!(header, @"
+    import 'dart:typed_data';
+    import 'bincode/bincode.dart';
+    import 'serde/serde.dart';
+
+    // ignore_for_file: unused_import
+    ");
+}
+
+#[test]
+fn output_keeps_indices_of_skipped_variants() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Colour {
+        Red,
+        #[facet(fg::skip(dart))]
+        Green,
+        Blue,
+    }
+
+    let registry = reflect!(Colour).unwrap();
+    let config = CodeGeneratorConfig::new("root".to_string());
+
+    let output = render_output(&config, vec![Arc::new(BincodePlugin)], &registry);
+    insta::assert_snapshot!(output, @"
+    import 'dart:typed_data';
+    import 'bincode/bincode.dart';
+    import 'serde/serde.dart';
+
+    enum Colour {
+        red,
+        blue;
+
+        void serialize(Serializer serializer) {
+            serializer.increaseContainerDepth();
+            serializer.serializeVariantIndex(switch (this) {
+                Colour.red => 0,
+                Colour.blue => 2,
+            });
+            serializer.decreaseContainerDepth();
+        }
+
+        Uint8List bincodeSerialize() {
+            final serializer = BincodeSerializer();
+            serialize(serializer);
+            return serializer.getBytes();
+        }
+
+        factory Colour.deserialize(Deserializer deserializer) {
+            deserializer.increaseContainerDepth();
+            final index = deserializer.deserializeVariantIndex();
+            deserializer.decreaseContainerDepth();
+            return switch (index) {
+                0 => Colour.red,
+                2 => Colour.blue,
+                _ => throw DeserializationError('Unknown variant index for Colour: $index'),
+            };
+        }
+
+        factory Colour.bincodeDeserialize(Uint8List input) {
+            final deserializer = BincodeDeserializer(input);
+            final value = Colour.deserialize(deserializer);
+            if (deserializer.getBufferOffset() < input.length) {
+                throw const DeserializationError('Some input bytes were not read');
+            }
+            return value;
+        }
+    }
+    ");
+}
+
+#[test]
+fn bincode_rejects_untagged_enums() {
+    #[derive(Facet)]
+    #[facet(untagged)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Value {
+        Number(f64),
+        Text(String),
+    }
+
+    let registry = reflect!(Value).unwrap();
+    let config = CodeGeneratorConfig::new("root".to_string());
+
+    let generator = DartCodeGenerator::new(&config).with_plugins(vec![Arc::new(BincodePlugin)]);
+    let error = generator.output(&mut Vec::new(), &registry).unwrap_err();
+    insta::assert_snapshot!(error, @"bincode can't encode `Value`: untagged enums don't write the variant index bincode needs");
+}
@@ -0,0 +1,272 @@
+//! Project scaffolding — writes a ready-to-build Dart package to disk.
+//!
+//! The [`Installer`] is the final stage of the Dart generation pipeline.
+//! While [`DartCodeGenerator`] produces the *contents* of a single library,
+//! the installer is responsible for the surrounding package structure:
+//!
+//! 1. **Runtime files** — copies the serde and/or bincode runtime libraries
+//!    into `lib/serde/` and `lib/bincode/`, where the generated libraries
+//!    import them with relative URIs.
+//!
+//! 2. **Per-module libraries** — splits the registry by namespace (via
+//!    [`module::split`]) and calls [`DartCodeGenerator`] once per namespace,
+//!    writing each to `lib/<namespace>.dart`. Cross-module type references
+//!    use prefixed imports (`import 'other.dart' as other;`) with
+//!    `other.Type` syntax.
+//!
+//! 3. **`pubspec.yaml`** — generates a pub manifest with the Dart SDK
+//!    constraint and dependencies (external packages as `path:` or `git:`
+//!    dependencies).
+
+use std::{
+    collections::BTreeSet,
+    fs::{File, create_dir_all},
+    io::Write as _,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use indoc::formatdoc;
+
+use crate::{
+    Registry,
+    generation::{
+        CodeGeneratorConfig, Error, ExternalPackage, ExternalPackages, PackageLocation,
+        SERDE_NAMESPACE, SourceInstaller,
+        bincode::BincodePlugin,
+        dart::{Dart, DartCodeGenerator},
+        indent::IndentConfig,
+        json::JsonPlugin,
+        module,
+        plugin::{self, EmitterPlugin},
+    },
+    reflection::format::Language,
+};
+
+/// The Dart SDKs that generated packages support. Branded types are emitted as
+/// extension types, which need Dart 3.3.
+const SDK_CONSTRAINT: &str = "^3.3.0";
+
+/// Installer for generated source files in Dart.
+///
+/// # Examples
+///
+/// ```rust
+/// use facet_generate::generation::dart;
+///
+/// let output_dir = std::path::PathBuf::from("output");
+/// let installer = dart::Installer::new("my_package", &output_dir);
+/// ```
+pub struct Installer {
+    package_name: String,
+    install_dir: PathBuf,
+    external_packages: ExternalPackages,
+    indent: IndentConfig,
+    plugins: Vec<Arc<dyn EmitterPlugin<Dart>>>,
+}
+
+impl Installer {
+    /// Create a new installer for the given package name and output directory.
+    ///
+    /// The package name must be a valid pub package name (e.g. `my_package`).
+    /// Use the builder methods [`plugin`](Self::plugin) and
+    /// [`external_packages`](Self::external_packages) to configure, then call
+    /// [`generate`](Self::generate) to produce the output.
+    #[must_use]
+    pub fn new(package_name: &str, install_dir: impl AsRef<Path>) -> Self {
+        Self {
+            package_name: package_name.to_string(),
+            install_dir: install_dir.as_ref().to_path_buf(),
+            external_packages: ExternalPackages::new(),
+            indent: IndentConfig::Space(4),
+            plugins: vec![],
+        }
+    }
+
+    /// Add a plugin to be used during code generation.
+    ///
+    /// When multiple plugins are added, they are invoked in the order they were registered.
+    #[must_use]
+    pub fn plugin<P: EmitterPlugin<Dart> + 'static>(mut self, plugin: P) -> Self {
+        self.plugins.push(Arc::new(plugin));
+        self
+    }
+
+    /// Set external packages to reference.
+    #[must_use]
+    pub fn external_packages(mut self, packages: &[ExternalPackage]) -> Self {
+        self.external_packages = packages
+            .iter()
+            .map(|d| (d.for_namespace.clone(), d.clone()))
+            .collect();
+        self
+    }
+
+    /// Set the indentation of generated source files (four spaces by default).
+    #[must_use]
+    pub const fn indent(mut self, indent: IndentConfig) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Generate all code for the given registry.
+    ///
+    /// This method:
+    /// 1. Installs the runtimes of the configured plugins
+    /// 2. Splits the registry by namespace and installs each module
+    /// 3. Writes the package manifest
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any file operation or code generation step fails.
+    pub fn generate(mut self, registry: &Registry) -> Result<(), Error> {
+        let checked = module::for_plugin_checks(registry, Language::Dart);
+        let registry = &module::for_language(registry, Language::Dart);
+        plugin::check_registry(&self.plugins, &checked)?;
+
+        if !self.external_packages.contains_key(SERDE_NAMESPACE) {
+            let mut written = BTreeSet::new();
+            for plugin in &self.plugins {
+                for file in plugin.runtime_files() {
+                    if written.insert(file.relative_path.clone()) {
+                        self.install_runtime_file(&file.relative_path, &file.contents)?;
+                    }
+                }
+            }
+        }
+
+        for (m, module_registry) in module::split(&self.package_name, registry) {
+            let config = m.config().clone().with_indent(self.indent);
+            self.install_module(&config, &module_registry)?;
+        }
+
+        let package_name = self.package_name.clone();
+        self.install_manifest(&package_name)?;
+
+        Ok(())
+    }
+
+    /// Installs the serde Dart runtime into `lib/serde/`.
+    ///
+    /// Most callers should prefer [`generate`](Self::generate).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any file I/O fails.
+    pub fn install_serde_runtime(&self) -> Result<(), Error> {
+        for file in EmitterPlugin::<Dart>::runtime_files(&JsonPlugin) {
+            self.install_runtime_file(&file.relative_path, &file.contents)?;
+        }
+        Ok(())
+    }
+
+    /// Installs the bincode Dart runtime into `lib/bincode/`.
+    ///
+    /// It needs the serde runtime too. Most callers should prefer
+    /// [`generate`](Self::generate).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any file I/O fails.
+    pub fn install_bincode_runtime(&self) -> Result<(), Error> {
+        for file in EmitterPlugin::<Dart>::runtime_files(&BincodePlugin)
+            .into_iter()
+            .filter(|f| f.relative_path.starts_with("lib/bincode/"))
+        {
+            self.install_runtime_file(&file.relative_path, &file.contents)?;
+        }
+        Ok(())
+    }
+
+    fn install_runtime_file(&self, relative_path: &str, contents: &[u8]) -> Result<(), Error> {
+        let dest = self.install_dir.join(relative_path);
+        if let Some(parent) = dest.parent() {
+            create_dir_all(parent)?;
+        }
+        std::fs::write(&dest, contents)?;
+        Ok(())
+    }
+
+    /// Produce the contents of a `pubspec.yaml` manifest.
+    ///
+    /// Dependencies are derived from external packages: `Path` locations
+    /// become `path:` dependencies, and `Url` locations `git:` dependencies
+    /// at the package's version, if it has one.
+    #[must_use]
+    pub fn make_manifest(&self, package_name: &str) -> String {
+        let mut manifest = formatdoc!(
+            "
+            name: {package_name}
+            version: 0.1.0
+            publish_to: none
+
+            environment:
+              sdk: {SDK_CONSTRAINT}
+            "
+        );
+
+        let dependencies = self
+            .external_packages
+            .values()
+            .map(|package| {
+                let name = &package.for_namespace;
+                match &package.location {
+                    PackageLocation::Path(path) => format!("  {name}:\n    path: {path}\n"),
+                    PackageLocation::Url(url) => {
+                        let reference = package
+                            .version
+                            .as_ref()
+                            .map(|version| format!("      ref: {version}\n"))
+                            .unwrap_or_default();
+                        format!("  {name}:\n    git:\n      url: {url}\n{reference}")
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        if !dependencies.is_empty() {
+            manifest.push_str("\ndependencies:\n");
+            manifest.push_str(&dependencies.concat());
+        }
+
+        manifest
+    }
+}
+
+impl SourceInstaller for Installer {
+    /// Generate a single library for one namespace.
+    ///
+    /// The library is written as `lib/<namespace>.dart`. Namespaces that
+    /// correspond to external packages are skipped — their types are
+    /// imported rather than generated.
+    fn install_module(
+        &mut self,
+        config: &CodeGeneratorConfig,
+        registry: &Registry,
+    ) -> Result<(), Error> {
+        if self.external_packages.contains_key(config.module_name()) {
+            return Ok(());
+        }
+        let dir = self.install_dir.join("lib");
+        create_dir_all(&dir)?;
+        let mut file = File::create(dir.join(format!("{}.dart", config.module_name())))?;
+
+        let mut updated_config = config.clone();
+        updated_config.external_packages = self.external_packages.clone();
+
+        let generator = DartCodeGenerator::new(&updated_config).with_plugins(self.plugins.clone());
+        generator.output(&mut file, registry)?;
+
+        Ok(())
+    }
+
+    /// Write `pubspec.yaml` to the output directory.
+    fn install_manifest(&self, package_name: &str) -> Result<(), Error> {
+        create_dir_all(&self.install_dir)?;
+        let mut file = File::create(self.install_dir.join("pubspec.yaml"))?;
+        file.write_all(self.make_manifest(package_name).as_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
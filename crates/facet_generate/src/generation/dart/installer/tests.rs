@@ -0,0 +1,153 @@
+//! Tests for the Dart [`Installer`] — **package scaffolding**.
+//!
+//! These tests verify the `pubspec.yaml` manifest that the installer
+//! generates, and the layout of the package it writes:
+//!
+//! - Basic manifest structure: package name, SDK constraint, no dependencies.
+//! - External dependencies: `path:` and `git:` entries.
+//! - Installed files: one library per namespace under `lib/`, with the
+//!   runtime libraries of the configured plugins next to them.
+//! - Registries the plugins can't encode.
+
+use facet::Facet;
+
+use crate::generation::{
+    ExternalPackage, PackageLocation, bincode::BincodePlugin, dart::Installer, json::JsonPlugin,
+};
+use crate::reflect;
+
+#[test]
+fn make_manifest_basic() {
+    let installer = Installer::new("my_package", "/tmp");
+    let manifest = installer.make_manifest("my_package");
+
+    insta::assert_snapshot!(manifest, @"
+    name: my_package
+    version: 0.1.0
+    publish_to: none
+
+    environment:
+      sdk: ^3.3.0
+    ");
+}
+
+#[test]
+fn make_manifest_with_external_packages() {
+    let external_packages = vec![
+        ExternalPackage {
+            for_namespace: "local_types".to_string(),
+            module_name: None,
+            location: PackageLocation::Path("../local_types".to_string()),
+            version: None,
+        },
+        ExternalPackage {
+            for_namespace: "remote_types".to_string(),
+            module_name: None,
+            location: PackageLocation::Url("https://example.com/remote_types.git".to_string()),
+            version: Some("v2.0.0".to_string()),
+        },
+    ];
+
+    let installer = Installer::new("my_package", "/tmp").external_packages(&external_packages);
+    let manifest = installer.make_manifest("my_package");
+
+    insta::assert_snapshot!(manifest, @"
+    name: my_package
+    version: 0.1.0
+    publish_to: none
+
+    environment:
+      sdk: ^3.3.0
+
+    dependencies:
+      local_types:
+        path: ../local_types
+      remote_types:
+        git:
+          url: https://example.com/remote_types.git
+          ref: v2.0.0
+    ");
+}
+
+#[test]
+fn generate_writes_package_layout() {
+    #[derive(Facet)]
+    struct Child {
+        value: i32,
+    }
+
+    mod other {
+        use crate as fg;
+        use facet::Facet;
+
+        #[derive(Facet)]
+        #[facet(fg::namespace = "other")]
+        pub struct Child {
+            value: String,
+        }
+    }
+
+    #[derive(Facet)]
+    struct Parent {
+        child: Child,
+        other_child: other::Child,
+    }
+
+    let registry = reflect!(Parent).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    Installer::new("my_package", install_dir.path())
+        .plugin(BincodePlugin)
+        .plugin(JsonPlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let mut files = ignore::Walk::new(install_dir.path())
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_file())
+        .map(|entry| {
+            entry
+                .path()
+                .strip_prefix(install_dir.path())
+                .unwrap()
+                .display()
+                .to_string()
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    insta::assert_snapshot!(files.join("\n"), @"
+    lib/bincode/bincode.dart
+    lib/bincode/bincode_deserializer.dart
+    lib/bincode/bincode_serializer.dart
+    lib/my_package.dart
+    lib/other.dart
+    lib/serde/binary_deserializer.dart
+    lib/serde/binary_serializer.dart
+    lib/serde/deserializer.dart
+    lib/serde/errors.dart
+    lib/serde/json.dart
+    lib/serde/serde.dart
+    lib/serde/serializer.dart
+    pubspec.yaml
+    ");
+}
+
+#[test]
+fn json_rejects_internally_tagged_tuple_variants() {
+    #[derive(Facet)]
+    #[facet(tag = "type")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point(i32, i32),
+        Empty,
+    }
+
+    let registry = reflect!(Shape).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    let installer = Installer::new("my_package", install_dir.path()).plugin(JsonPlugin);
+
+    let error = installer.generate(&registry).unwrap_err();
+    insta::assert_snapshot!(error, @"json (Dart) can't encode `Shape`: internally tagged enums can't have tuple variants");
+}
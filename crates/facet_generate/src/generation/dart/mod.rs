@@ -0,0 +1,38 @@
+//! Dart code generation.
+//!
+//! This module translates a [`Registry`](crate::Registry) of reflected type
+//! definitions into idiomatic Dart source code, for Flutter and other Dart
+//! clients.
+//!
+//! # Submodules (in pipeline order)
+//!
+//! 1. **generator** — Top-level orchestrator. [`DartCodeGenerator`](crate::generation::dart::DartCodeGenerator) implements
+//!    [`CodeGenerator`](crate::generation::CodeGenerator) to produce a complete Dart library
+//!    from a registry. It resolves qualified type names against the
+//!    configuration (external packages, namespaces), and delegates writing to the emitter layer.
+//!
+//! 2. **emitter** — AST-to-source rendering. Implements
+//!    [`Emitter<Dart>`](crate::generation::Emitter) for each AST node type
+//!    ([`Module`](crate::generation::module::Module), [`Container`](crate::generation::Container),
+//!    `Named<Format>`, `Format`, `Doc`). This is where the Dart language
+//!    mapping lives: immutable `final class`es with `const` constructors,
+//!    `enum`s for all-unit enums and `sealed class` hierarchies for enums
+//!    whose variants carry data.
+//!
+//! 3. **installer** — Project scaffolding. [`Installer`](crate::generation::dart::Installer) implements
+//!    [`SourceInstaller`](crate::generation::SourceInstaller) to write a ready-to-build
+//!    Dart package: it copies the serde/bincode runtime libraries into `lib/`,
+//!    splits the registry by namespace into one library per module, and
+//!    generates a `pubspec.yaml` manifest.
+
+pub use emitter::Dart;
+pub use generator::DartCodeGenerator;
+pub use installer::Installer;
+
+pub(crate) use emitter::{
+    enum_value, field_default, identifier, import_uri, string_literal, variant_class,
+};
+
+mod emitter;
+mod generator;
+mod installer;
@@ -0,0 +1,763 @@
+//! AST-to-Go source rendering.
+//!
+//! This module implements [`Emitter<Go>`](super::super::Emitter) for each
+//! node type in the format AST, turning abstract type descriptions into Go
+//! declarations.
+//!
+//! # Emitter implementations
+//!
+//! | AST node | Go output |
+//! |---|---|
+//! | [`Module`] | `package` clause and `import` declaration |
+//! | [`Container`] | `struct` type, `string` type and constants, `interface` and variant structs, or type alias |
+//! | [`Named<Format>`](Named) | A single struct field, with its `json` tag if it has one |
+//! | [`Format`] | An inline type expression (`uint32`, `[]string`, `*int64`, …) |
+//! | [`Doc`] | `//` doc comments |
+//!
+//! # Go type mapping
+//!
+//! The [`Format`] emitter maps Rust/reflection types to Go equivalents — for
+//! example `u32` → `uint32`, `Seq(T)` → `[]T`, `[T; N]` → `[N]T`, `Option(T)`
+//! → `*T`, `Map` → `map[K]V`, `Timestamp` → `time.Time`, and the types of the
+//! serde runtime for the rest: `()` → `serde.Unit`, `Bytes` → `serde.Bytes`,
+//! `u128` → `serde.Uint128`, `Uuid` → `serde.UUID` and tuples →
+//! `serde.Tuple2[A, B]`.
+//!
+//! # Declarations
+//!
+//! Structs become Go structs whose exported fields have `json` tags of the
+//! Rust names; newtype and tuple structs have `Value` and `Field0`, `Field1`,
+//! … fields. Branded newtypes of scalars become defined types
+//! (`type UserId string`). Enums whose variants are all unit variants become a
+//! `string` type and a constant per variant, whose value is the variant's
+//! name. Other enums become an interface with an unexported marker method
+//! (`isShape()`), and a struct per variant (e.g. `ShapeCircle`) whose pointer
+//! implements it, so that a type switch tells the variants apart.
+//!
+//! # Plugin-dependent output
+//!
+//! Go methods are declared outside of their types, so the [`Go`] language tag's
+//! [`EmitterPlugin`]s write their methods and functions after each type,
+//! including each variant struct, in `after_type`. The `type_body` of an
+//! enum is written inside its interface, and declares the methods that its
+//! variants implement.
+//!
+//! - `BincodePlugin` supplies `Serialize` / `BincodeSerialize` methods and
+//!   `Deserialize<Type>` / `BincodeDeserialize<Type>` functions.
+//! - `JsonPlugin` supplies `MarshalJSON` / `UnmarshalJSON` methods where the
+//!   `json` tags don't suffice, and `<Enum>FromJSON` functions.
+//! - With no plugins, only plain type declarations are emitted.
+
+use std::{collections::BTreeSet, io::Result, sync::Arc};
+
+use heck::ToUpperCamelCase;
+
+use crate::{
+    Registry,
+    generation::{
+        CodeGeneratorConfig, Container, Emitter, SERDE_NAMESPACE,
+        indent::IndentWrite,
+        module::Module,
+        plugin::{
+            EmitContext, EmitterPlugin, VariantInfo, collect_from_plugins, type_annotations,
+            type_conformances,
+        },
+    },
+    reflection::format::{
+        ContainerFormat, Deprecation, Doc, Format, Language, Named, Namespace, QualifiedTypeName,
+        VariantFormat,
+    },
+};
+
+/// The standard library packages that generated code can use.
+const STANDARD_LIBRARY: &[&str] = &["encoding/json", "time"];
+
+/// Language tag for Go code generation.
+///
+/// Passed as the `L` parameter to every [`Emitter<L>`](super::super::Emitter)
+/// call. Carries a plugin list that controls all encoding-specific behaviour.
+#[derive(Debug, Clone)]
+pub struct Go {
+    pub(crate) config: CodeGeneratorConfig,
+    pub(crate) plugins: Vec<Arc<dyn EmitterPlugin<Self>>>,
+    /// The path of the Go module that the packages belong to, e.g.
+    /// `github.com/example/types`, under which the runtime packages are
+    /// imported.
+    module_path: String,
+    /// The packages that the declarations use, by the name they're referred to
+    /// by: Go rejects unused imports, so only these are imported.
+    used_packages: BTreeSet<String>,
+}
+
+impl Go {
+    /// Create a Go language tag with no default plugins, for a package of the
+    /// Go module whose path is the config's module name.
+    ///
+    /// Use [`with_plugin`](Self::with_plugin) to attach plugins.
+    #[must_use]
+    pub fn new(config: &CodeGeneratorConfig, _registry: &Registry) -> Self {
+        Self {
+            config: config.clone(),
+            plugins: vec![],
+            module_path: config.module_name().to_string(),
+            used_packages: BTreeSet::new(),
+        }
+    }
+
+    /// Set the path of the Go module that the package belongs to, returning
+    /// the modified tag.
+    #[must_use]
+    pub fn with_module_path(mut self, module_path: &str) -> Self {
+        self.module_path = module_path.to_string();
+        self
+    }
+
+    /// Access the generator config.
+    #[must_use]
+    pub const fn config(&self) -> &CodeGeneratorConfig {
+        &self.config
+    }
+
+    /// Add a plugin to this language tag, returning the modified tag.
+    ///
+    /// Plugins are invoked in the order they are added.
+    #[must_use]
+    pub fn with_plugin(mut self, plugin: Arc<dyn EmitterPlugin<Self>>) -> Self {
+        self.plugins.push(plugin);
+        self
+    }
+
+    /// Access the plugin list.
+    #[must_use]
+    pub fn plugins(&self) -> &[Arc<dyn EmitterPlugin<Self>>] {
+        &self.plugins
+    }
+
+    /// Set the packages that the declarations use, returning the modified tag.
+    #[must_use]
+    pub(crate) fn with_used_packages(mut self, used_packages: BTreeSet<String>) -> Self {
+        self.used_packages = used_packages;
+        self
+    }
+}
+
+/// An import of a package: its path, and the name it is referred to by if
+/// that isn't the last element of its path.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Import {
+    path: String,
+    alias: Option<String>,
+}
+
+impl Import {
+    fn new(path: String, name: &str) -> Self {
+        let alias = (path.rsplit('/').next() != Some(name)).then(|| name.to_string());
+        Self { path, alias }
+    }
+}
+
+impl std::fmt::Display for Import {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.alias {
+            Some(alias) => write!(f, "{alias} \"{}\"", self.path),
+            None => write!(f, "\"{}\"", self.path),
+        }
+    }
+}
+
+impl Emitter<Go> for Module {
+    /// Writes the `package` clause and the imports of the packages that the
+    /// declarations use, standard library first, like `goimports`.
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Go) -> Result<()> {
+        let config = self.config();
+        writeln!(w, "package {}", package_name(config.module_name()))?;
+
+        let mut imports = BTreeSet::new();
+        let mut import = |path: String, name: &str| {
+            if lang.used_packages.contains(name) {
+                imports.insert(Import::new(path, name));
+            }
+        };
+        for path in STANDARD_LIBRARY {
+            import((*path).to_string(), path.rsplit('/').next().unwrap_or(path));
+        }
+        let plugin_imports = collect_from_plugins(lang.plugins(), |p| p.imports(config));
+        for package in plugin_imports
+            .iter()
+            .map(String::as_str)
+            .chain([SERDE_NAMESPACE])
+        {
+            if STANDARD_LIBRARY.contains(&package) {
+                continue;
+            }
+            let local = format!("{}/{package}", lang.module_path);
+            import(import_path(config, package, &local), package);
+        }
+        for namespace in &config.referenced_namespaces {
+            let name = package_name(namespace);
+            let local = format!("{}/{name}", lang.module_path);
+            import(import_path(config, namespace, &local), &name);
+        }
+
+        let (standard, other): (Vec<_>, Vec<_>) = imports
+            .iter()
+            .partition(|import| STANDARD_LIBRARY.contains(&import.path.as_str()));
+        match (standard.as_slice(), other.as_slice()) {
+            ([], []) => {}
+            ([import], []) | ([], [import]) => {
+                writeln!(w)?;
+                writeln!(w, "import {import}")?;
+            }
+            (standard, other) => {
+                writeln!(w)?;
+                writeln!(w, "import (")?;
+                w.indent();
+                for import in standard {
+                    writeln!(w, "{import}")?;
+                }
+                if !standard.is_empty() && !other.is_empty() {
+                    writeln!(w)?;
+                }
+                for import in other {
+                    writeln!(w, "{import}")?;
+                }
+                w.unindent();
+                writeln!(w, ")")?;
+            }
+        }
+
+        let mut helpers = vec![];
+        {
+            let mut hw = w.child(&mut helpers);
+            for plugin in lang.plugins() {
+                plugin.module_helpers(&mut hw, config)?;
+            }
+        }
+        if !helpers.is_empty() {
+            writeln!(w)?;
+            w.write_all(&helpers)?;
+        }
+
+        writeln!(w)
+    }
+}
+
+impl Emitter<Go> for Container<'_> {
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Go) -> Result<()> {
+        let metadata = self.format.metadata();
+        let name = &self.name.name;
+        let params = type_params_decl(&metadata.type_params);
+        let ctx = EmitContext::top_level(self, &lang.config);
+        write_doc(w, self.format.doc(), metadata.deprecated.as_ref())?;
+        for annotation in type_annotations(lang.plugins(), &ctx, Language::Go) {
+            writeln!(w, "{annotation}")?;
+        }
+        match self.format {
+            ContainerFormat::UnitStruct(..) => writeln!(w, "type {name}{params} struct{{}}")?,
+            ContainerFormat::NewTypeStruct(format, _, metadata) if metadata.proxy => {
+                // Uses of the type have already been replaced by the proxy's format.
+                writeln!(w, "type {name}{params} = {}", type_expr(format))?;
+            }
+            ContainerFormat::NewTypeStruct(format, _, metadata) if is_defined_type(self.format) => {
+                let field = Named::new(format.as_ref(), "value".to_string()).inheriting(metadata);
+                writeln!(w, "type {name}{params} {}", field_type(&field))?;
+            }
+            ContainerFormat::NewTypeStruct(format, _, _) => {
+                let fields = [Named::new(format.as_ref(), "value".to_string())];
+                write_struct(w, name, &params, &fields, metadata, false, lang)?;
+            }
+            ContainerFormat::TupleStruct(formats, _, _) => {
+                write_struct(w, name, &params, &named(formats), metadata, false, lang)?;
+            }
+            ContainerFormat::Struct(fields, _, _) => {
+                write_struct(w, name, &params, fields, metadata, true, lang)?;
+            }
+            ContainerFormat::Enum(variants, _, _, _) if !is_union_format(self.format) => {
+                writeln!(w, "type {name} string")?;
+                if !variants.is_empty() {
+                    writeln!(w)?;
+                    writeln!(w, "const (")?;
+                    w.indent();
+                    let rows = variants
+                        .values()
+                        .map(|variant| Row {
+                            doc: &variant.doc,
+                            deprecated: variant.metadata.deprecated.as_ref(),
+                            cells: vec![
+                                variant_type(name, &variant.name),
+                                format!("{name} = {}", string_literal(&variant.name)),
+                            ],
+                        })
+                        .collect::<Vec<_>>();
+                    write_rows(w, &rows)?;
+                    w.unindent();
+                    writeln!(w, ")")?;
+                }
+            }
+            ContainerFormat::Enum(..) => return union(w, self, &ctx, lang),
+        }
+
+        let mut conformances = type_conformances(lang.plugins(), &ctx, Language::Go);
+        if !metadata.type_params.is_empty() {
+            // A generic type can only be checked once instantiated.
+            conformances.clear();
+        }
+        for conformance in conformances {
+            writeln!(w)?;
+            writeln!(w, "var _ {conformance} = (*{name})(nil)")?;
+        }
+        write_after_type(w, &ctx, lang)
+    }
+}
+
+/// A row of aligned cells (e.g. a struct field's name, type and tag), with the
+/// docs written above it.
+struct Row<'a> {
+    doc: &'a Doc,
+    deprecated: Option<&'a Deprecation>,
+    cells: Vec<String>,
+}
+
+/// Writes rows whose cells are aligned in columns, like `gofmt` does. Doc
+/// comments start a new block of aligned rows.
+fn write_rows<W: IndentWrite>(w: &mut W, rows: &[Row]) -> Result<()> {
+    let mut start = 0;
+    while start < rows.len() {
+        let mut end = start + 1;
+        while end < rows.len() && !has_doc(rows[end].doc, rows[end].deprecated) {
+            end += 1;
+        }
+        let block = &rows[start..end];
+        write_doc(w, block[0].doc, block[0].deprecated)?;
+        for row in block {
+            let mut line = String::new();
+            for (i, cell) in row.cells.iter().enumerate() {
+                if i + 1 == row.cells.len() {
+                    line.push_str(cell);
+                    break;
+                }
+                // A cell is padded to the widest of the column's cells that are followed by
+                // another.
+                let width = block
+                    .iter()
+                    .filter(|row| row.cells.len() > i + 1)
+                    .map(|row| row.cells[i].len())
+                    .max()
+                    .unwrap_or_default();
+                line.push_str(&format!("{cell:width$} "));
+            }
+            writeln!(w, "{line}")?;
+        }
+        start = end;
+    }
+    Ok(())
+}
+
+/// Writes a struct of `fields`, which have `json` tags of their names if `tagged`.
+fn write_struct<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    params: &str,
+    fields: &[Named<Format>],
+    metadata: &crate::reflection::format::ContainerMetadata,
+    tagged: bool,
+    lang: &Go,
+) -> Result<()> {
+    if fields.is_empty() {
+        return writeln!(w, "type {name}{params} struct{{}}");
+    }
+    writeln!(w, "type {name}{params} struct {{")?;
+    w.indent();
+    let fields = fields
+        .iter()
+        .map(|field| field.inheriting(metadata))
+        .collect::<Vec<_>>();
+    write_fields(w, &fields, tagged, lang)?;
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// Writes the fields of a struct, aligned like `gofmt` does.
+fn write_fields<W: IndentWrite>(
+    w: &mut W,
+    fields: &[Named<Format>],
+    tagged: bool,
+    _lang: &Go,
+) -> Result<()> {
+    let rows = fields
+        .iter()
+        .map(|field| {
+            let mut cells = vec![field_name(&field.name), field_type(field)];
+            if tagged {
+                cells.push(json_tag(&field.name));
+            }
+            Row {
+                doc: &field.doc,
+                deprecated: field.metadata.deprecated.as_ref(),
+                cells,
+            }
+        })
+        .collect::<Vec<_>>();
+    write_rows(w, &rows)
+}
+
+impl Emitter<Go> for Named<Format> {
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Go) -> Result<()> {
+        write_fields(w, std::slice::from_ref(self), true, lang)
+    }
+}
+
+impl Emitter<Go> for Doc {
+    fn write<W: IndentWrite>(&self, w: &mut W, _lang: &Go) -> Result<()> {
+        write_doc(w, self, None)
+    }
+}
+
+impl Emitter<Go> for Format {
+    fn write<W: IndentWrite>(&self, w: &mut W, _lang: &Go) -> Result<()> {
+        write!(w, "{}", type_expr(self))
+    }
+}
+
+/// The Go type of `format`, e.g. `map[string][]uint32`.
+#[must_use]
+pub(crate) fn type_expr(format: &Format) -> String {
+    let list = |formats: &[Format]| formats.iter().map(type_expr).collect::<Vec<_>>().join(", ");
+    match format {
+        Format::Variable(_variable) => unreachable!("placeholders should not get this far"),
+        Format::TypeName(name) => type_name(name),
+        Format::Generic { name, args } => format!("{}[{}]", type_name(name), list(args)),
+        Format::TypeParameter(name) => name.clone(),
+        Format::Unit => "serde.Unit".to_string(),
+        Format::Bool => "bool".to_string(),
+        Format::I8 => "int8".to_string(),
+        Format::I16 => "int16".to_string(),
+        Format::I32 => "int32".to_string(),
+        Format::I64 => "int64".to_string(),
+        Format::I128 => "serde.Int128".to_string(),
+        Format::U8 => "uint8".to_string(),
+        Format::U16 => "uint16".to_string(),
+        Format::U32 => "uint32".to_string(),
+        Format::U64 => "uint64".to_string(),
+        Format::U128 => "serde.Uint128".to_string(),
+        Format::F32 => "float32".to_string(),
+        Format::F64 => "float64".to_string(),
+        Format::Char | Format::Str | Format::Date | Format::Time => "string".to_string(),
+        Format::Bytes => "serde.Bytes".to_string(),
+        Format::Uuid => "serde.UUID".to_string(),
+        Format::Timestamp => "time.Time".to_string(),
+        Format::Duration => "serde.Duration".to_string(),
+        Format::Option(format) => format!("*{}", type_expr(format)),
+        Format::Seq(format) | Format::Set(format) => format!("[]{}", type_expr(format)),
+        Format::Map { key, value } => format!("map[{}]{}", type_expr(key), type_expr(value)),
+        Format::TupleArray { content, size } => format!("[{size}]{}", type_expr(content)),
+        Format::Tuple(formats) => match formats.as_slice() {
+            [] => "serde.Unit".to_string(),
+            // A single-element tuple is just the element itself
+            [format] => type_expr(format),
+            formats => format!("serde.Tuple{}[{}]", formats.len(), list(formats)),
+        },
+    }
+}
+
+/// The Go type of a field: its `fg::override` type for Go if it has one.
+#[must_use]
+pub(crate) fn field_type(field: &Named<Format>) -> String {
+    match field.metadata.type_override(Language::Go) {
+        Some(type_override) => type_override.ty.clone(),
+        None => type_expr(&field.value),
+    }
+}
+
+/// Emits the interface of an enum whose variants carry data, then a struct per
+/// variant whose pointer implements it.
+///
+/// Variant structs take all of the enum's type parameters, so that any of them
+/// is an instance of the interface with the same type arguments.
+fn union<W: IndentWrite>(
+    w: &mut W,
+    container: &Container,
+    ctx: &EmitContext,
+    lang: &Go,
+) -> Result<()> {
+    let ContainerFormat::Enum(variants, _, _, metadata) = container.format else {
+        unreachable!("only enums are emitted as interfaces")
+    };
+    let name = &container.name.name;
+    let type_params = &metadata.type_params;
+    let params = type_params_decl(type_params);
+    let args = type_args(type_params);
+
+    writeln!(w, "type {name}{params} interface {{")?;
+    w.indent();
+    for conformance in type_conformances(lang.plugins(), ctx, Language::Go) {
+        writeln!(w, "{conformance}")?;
+    }
+    writeln!(w, "{}()", marker_method(name))?;
+    for plugin in lang.plugins() {
+        if plugin.has_type_body(ctx) {
+            plugin.type_body(w as &mut dyn IndentWrite, ctx)?;
+        }
+    }
+    w.unindent();
+    writeln!(w, "}}")?;
+
+    for (index, variant) in variants {
+        let (fields, tagged) = variant_fields(&variant.value);
+        let variant_name = variant_type(name, &variant.name);
+        let variant_ctx = EmitContext::for_variant(
+            container,
+            &lang.config,
+            VariantInfo {
+                name: &variant.name,
+                index: *index as usize,
+                format: &variant.value,
+                fields: &fields,
+                parent_name: name,
+                parent_type_params: type_params,
+            },
+        );
+        writeln!(w)?;
+        write_doc(w, &variant.doc, variant.metadata.deprecated.as_ref())?;
+        for annotation in type_annotations(lang.plugins(), &variant_ctx, Language::Go) {
+            writeln!(w, "{annotation}")?;
+        }
+        if fields.is_empty() {
+            writeln!(w, "type {variant_name}{params} struct{{}}")?;
+        } else {
+            writeln!(w, "type {variant_name}{params} struct {{")?;
+            w.indent();
+            write_fields(w, &fields, tagged, lang)?;
+            w.unindent();
+            writeln!(w, "}}")?;
+        }
+        writeln!(w)?;
+        writeln!(
+            w,
+            "func (*{variant_name}{args}) {}() {{}}",
+            marker_method(name)
+        )?;
+        write_after_type(w, &variant_ctx, lang)?;
+    }
+
+    write_after_type(w, ctx, lang)
+}
+
+/// Writes what the plugins write after a type, each after a blank line.
+fn write_after_type<W: IndentWrite>(w: &mut W, ctx: &EmitContext, lang: &Go) -> Result<()> {
+    for plugin in lang.plugins() {
+        let mut after = vec![];
+        plugin.after_type(&mut w.child(&mut after), ctx)?;
+        if !after.is_empty() {
+            writeln!(w)?;
+            w.write_all(&after)?;
+        }
+    }
+    Ok(())
+}
+
+/// The fields of a variant, and whether they have `json` tags.
+#[must_use]
+pub(crate) fn variant_fields(variant: &VariantFormat) -> (Vec<Named<Format>>, bool) {
+    match variant {
+        VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+        VariantFormat::Unit => (vec![], false),
+        VariantFormat::NewType(format) => (
+            vec![Named::new(format.as_ref(), "value".to_string())],
+            false,
+        ),
+        VariantFormat::Tuple(formats) => (named(formats), false),
+        VariantFormat::Struct(fields) => (fields.clone(), true),
+    }
+}
+
+fn named<Format: Clone>(formats: &[Format]) -> Vec<Named<Format>> {
+    formats
+        .iter()
+        .enumerate()
+        .map(|(i, f)| Named::new(f, format!("field{i}")))
+        .collect()
+}
+
+/// Whether a declaration has docs to write.
+fn has_doc(doc: &Doc, deprecated: Option<&Deprecation>) -> bool {
+    !doc.comments().is_empty() || deprecated.is_some()
+}
+
+/// Writes docs as `//` comments, with a `Deprecated:` paragraph if the
+/// declaration is deprecated, which Go tools recognize.
+fn write_doc<W: IndentWrite>(w: &mut W, doc: &Doc, deprecated: Option<&Deprecation>) -> Result<()> {
+    for comment in doc.comments() {
+        if comment.is_empty() {
+            writeln!(w, "//")?;
+        } else {
+            writeln!(w, "// {comment}")?;
+        }
+    }
+    if let Some(deprecated) = deprecated {
+        if !doc.comments().is_empty() {
+            writeln!(w, "//")?;
+        }
+        match deprecated.message() {
+            Some(message) => writeln!(w, "// Deprecated: {message}")?,
+            None => writeln!(w, "// Deprecated: do not use.")?,
+        }
+    }
+    Ok(())
+}
+
+/// The `json` tag of a field that serde names `name`.
+fn json_tag(name: &str) -> String {
+    format!("`json:\"{name}\"`")
+}
+
+/// The name of a type as the generated code refers to it, e.g. `other.Child`.
+fn type_name(name: &QualifiedTypeName) -> String {
+    name.format(package_name, ".")
+}
+
+/// The name of a package, as the generated code refers to it, e.g. `shared`
+/// for `Shared` or `github.com/example/types` for the types module: the last
+/// element of its path, in lower case without underscores or hyphens.
+#[must_use]
+pub(crate) fn package_name(path: &str) -> String {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    name.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// `ident` qualified with the package of the type `name`, e.g.
+/// `other.DeserializeChild`.
+#[must_use]
+pub(crate) fn qualified(name: &QualifiedTypeName, ident: &str) -> String {
+    match &name.namespace {
+        Namespace::Root => ident.to_string(),
+        Namespace::Named(namespace) => format!("{}.{ident}", package_name(namespace)),
+    }
+}
+
+/// The type parameter list of a generic declaration, e.g. `[T any]`, or
+/// nothing.
+#[must_use]
+pub(crate) fn type_params_decl(type_params: &[String]) -> String {
+    if type_params.is_empty() {
+        String::new()
+    } else {
+        format!("[{} any]", type_params.join(", "))
+    }
+}
+
+/// The type arguments of a generic type's own type parameters, e.g. `[T]`, or
+/// nothing.
+#[must_use]
+pub(crate) fn type_args(type_params: &[String]) -> String {
+    if type_params.is_empty() {
+        String::new()
+    } else {
+        format!("[{}]", type_params.join(", "))
+    }
+}
+
+/// The path that a package imports `namespace` from: the package of the
+/// external package configured for it (e.g. `github.com/acme/shared/models`),
+/// if there is one, or else `local`.
+#[must_use]
+pub(crate) fn import_path(config: &CodeGeneratorConfig, namespace: &str, local: &str) -> String {
+    config.external_packages.get(namespace).map_or_else(
+        || local.to_string(),
+        |package| {
+            let module = super::installer::module_path(package);
+            match &package.module_name {
+                Some(module_name) => format!("{module}/{module_name}"),
+                None => module,
+            }
+        },
+    )
+}
+
+/// The exported Go name of a field, e.g. `CreatedAt` for `created_at`.
+#[must_use]
+pub(crate) fn field_name(name: &str) -> String {
+    name.to_upper_camel_case()
+}
+
+/// The name of a variant's struct, or of a unit variant's constant, e.g.
+/// `ShapeCircle`.
+#[must_use]
+pub(crate) fn variant_type(parent: &str, variant: &str) -> String {
+    format!("{parent}{}", variant.to_upper_camel_case())
+}
+
+/// The unexported method that marks the variants of an enum, e.g. `isShape`.
+#[must_use]
+pub(crate) fn marker_method(name: &str) -> String {
+    format!("is{name}")
+}
+
+/// Whether the type `name` is an enum whose variants carry data, which is an
+/// interface.
+#[must_use]
+pub(crate) fn is_union(config: &CodeGeneratorConfig, name: &QualifiedTypeName) -> bool {
+    config.enum_type_names.contains(&name.name) && !config.unit_variant_enums.contains(&name.name)
+}
+
+/// Whether a container is an enum whose variants carry data.
+#[must_use]
+pub(crate) fn is_union_format(format: &ContainerFormat) -> bool {
+    matches!(
+        format,
+        ContainerFormat::Enum(variants, ..)
+            if variants
+                .values()
+                .any(|variant| !matches!(variant.value, VariantFormat::Unit))
+    )
+}
+
+/// Whether a container is a branded newtype of a scalar, which is a defined
+/// type of it (`type UserId string`) rather than a struct.
+#[must_use]
+pub(crate) fn is_defined_type(format: &ContainerFormat) -> bool {
+    matches!(
+        format,
+        ContainerFormat::NewTypeStruct(format, _, metadata)
+            if metadata.branded
+                && !metadata.proxy
+                && matches!(
+                    format.as_ref(),
+                    Format::Bool
+                        | Format::I8
+                        | Format::I16
+                        | Format::I32
+                        | Format::I64
+                        | Format::U8
+                        | Format::U16
+                        | Format::U32
+                        | Format::U64
+                        | Format::F32
+                        | Format::F64
+                        | Format::Char
+                        | Format::Str
+                )
+    )
+}
+
+/// A Go string literal of `text`.
+#[must_use]
+pub(crate) fn string_literal(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t");
+    format!("\"{escaped}\"")
+}
+
+#[cfg(test)]
+mod tests;
+#[cfg(test)]
+mod tests_bincode;
+#[cfg(test)]
+mod tests_json;
@@ -0,0 +1,418 @@
+//! Snapshot tests for the Go emitter — **no serialization**.
+//!
+//! Each test defines one or more Rust types annotated with `#[derive(Facet)]`,
+//! runs them through the [`emit!`] macro with no plugins, and asserts the
+//! generated Go source against an [`insta`] inline snapshot.
+//!
+//! Because no plugins are configured, the output contains only plain type
+//! declarations (structs with `json` tags, `string` constants, interfaces and
+//! variant structs) with no serialization methods.
+//!
+//! # Coverage
+//!
+//! | Category | What is tested |
+//! |----------|----------------|
+//! | Structs | Unit structs, newtype wrappers, tuple structs, structs with primitive and user-defined fields |
+//! | Enums | All-unit enums (`string` constants), data enums (interface + variant structs), generic enums |
+//! | Collections | `Vec`, `HashMap`, `BTreeSet`, fixed-size arrays, tuples |
+//! | Optional | `Option<T>` fields (pointers) |
+//! | Declarations | Branded newtypes (defined types), proxies (type aliases), deprecation, keywords |
+//! | Namespaces | Multi-package generation via [`emit_two_modules!`] |
+
+#![allow(clippy::too_many_lines)]
+use std::collections::{BTreeSet, HashMap};
+
+use crate as fg;
+use facet::Facet;
+
+use super::*;
+use crate::{emit, emit_two_modules, generation::go::GoCodeGenerator};
+
+#[test]
+fn unit_struct() {
+    /// line 1
+    #[derive(Facet)]
+    /// line 2
+    struct UnitStruct;
+
+    let actual = emit!(UnitStruct as Go).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    // line 1
+    // line 2
+    type UnitStruct struct{}
+    ");
+}
+
+#[test]
+fn newtype_struct() {
+    #[derive(Facet)]
+    struct NewType(String);
+
+    let actual = emit!(NewType as Go).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    type NewType struct {
+        Value string
+    }
+    ");
+}
+
+#[test]
+fn tuple_struct() {
+    #[derive(Facet)]
+    struct TupleStruct(String, i32);
+
+    let actual = emit!(TupleStruct as Go).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    type TupleStruct struct {
+        Field0 string
+        Field1 int32
+    }
+    ");
+}
+
+#[test]
+fn struct_with_fields() {
+    #[derive(Facet)]
+    struct Inner {
+        value: u64,
+    }
+
+    /// A struct.
+    #[derive(Facet)]
+    struct Outer {
+        /// The name.
+        name: String,
+        flag: bool,
+        big: i128,
+        ratio: f32,
+        letter: char,
+        nickname: Option<String>,
+        inner: Inner,
+        tags: Vec<String>,
+        counts: HashMap<String, u32>,
+        ids: BTreeSet<u16>,
+        pair: (u8, String),
+        grid: [i16; 3],
+        nothing: (),
+    }
+
+    let actual = emit!(Outer as Go).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Inner struct {
+        Value uint64 `json:"value"`
+    }
+
+    // A struct.
+    type Outer struct {
+        // The name.
+        Name     string                      `json:"name"`
+        Flag     bool                        `json:"flag"`
+        Big      serde.Int128                `json:"big"`
+        Ratio    float32                     `json:"ratio"`
+        Letter   string                      `json:"letter"`
+        Nickname *string                     `json:"nickname"`
+        Inner    Inner                       `json:"inner"`
+        Tags     []string                    `json:"tags"`
+        Counts   map[string]uint32           `json:"counts"`
+        Ids      []uint16                    `json:"ids"`
+        Pair     serde.Tuple2[uint8, string] `json:"pair"`
+        Grid     [3]int16                    `json:"grid"`
+        Nothing  serde.Unit                  `json:"nothing"`
+    }
+    "#);
+}
+
+#[test]
+fn keywords() {
+    #[derive(Facet)]
+    struct Keywords {
+        r#type: String,
+        func: bool,
+        range: u32,
+        map: String,
+    }
+
+    let actual = emit!(Keywords as Go).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Keywords struct {
+        Type  string `json:"type"`
+        Func  bool   `json:"func"`
+        Range uint32 `json:"range"`
+        Map   string `json:"map"`
+    }
+    "#);
+}
+
+#[test]
+fn unit_enum() {
+    /// A colour.
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Colour {
+        /// The first one.
+        Red,
+        Green,
+        LightBlue,
+    }
+
+    let actual = emit!(Colour as Go).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    // A colour.
+    type Colour string
+
+    const (
+        // The first one.
+        ColourRed       Colour = "Red"
+        ColourGreen     Colour = "Green"
+        ColourLightBlue Colour = "LightBlue"
+    )
+    "#);
+}
+
+#[test]
+fn data_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Line(f64, f64),
+        Rectangle { width: f64, height: f64 },
+    }
+
+    let actual = emit!(Shape as Go).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Shape interface {
+        isShape()
+    }
+
+    type ShapePoint struct{}
+
+    func (*ShapePoint) isShape() {}
+
+    type ShapeCircle struct {
+        Value float64
+    }
+
+    func (*ShapeCircle) isShape() {}
+
+    type ShapeLine struct {
+        Field0 float64
+        Field1 float64
+    }
+
+    func (*ShapeLine) isShape() {}
+
+    type ShapeRectangle struct {
+        Width  float64 `json:"width"`
+        Height float64 `json:"height"`
+    }
+
+    func (*ShapeRectangle) isShape() {}
+    "#);
+}
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as Go).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Listing struct {
+        Names Page[string] `json:"names"`
+        Ids   Page[uint32] `json:"ids"`
+    }
+
+    type Page[T any] struct {
+        Items []T    `json:"items"`
+        Next  *T     `json:"next"`
+        Total uint32 `json:"total"`
+    }
+    "#);
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E, retry: bool },
+        Pending,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        first: Outcome<String, i32>,
+        second: Outcome<bool, String>,
+    }
+
+    let actual = emit!(Response as Go).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Outcome[T, E any] interface {
+        isOutcome()
+    }
+
+    type OutcomeSuccess[T, E any] struct {
+        Value T
+    }
+
+    func (*OutcomeSuccess[T, E]) isOutcome() {}
+
+    type OutcomeFailure[T, E any] struct {
+        Error E    `json:"error"`
+        Retry bool `json:"retry"`
+    }
+
+    func (*OutcomeFailure[T, E]) isOutcome() {}
+
+    type OutcomePending[T, E any] struct{}
+
+    func (*OutcomePending[T, E]) isOutcome() {}
+
+    type Response struct {
+        First  Outcome[string, int32] `json:"first"`
+        Second Outcome[bool, string]  `json:"second"`
+    }
+    "#);
+}
+
+#[test]
+fn branded_newtype() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    let actual = emit!(UserId as Go).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    type UserId string
+    ");
+}
+
+#[test]
+fn serialized_as_proxy() {
+    #[derive(Facet)]
+    #[facet(fg::serialized_as = "String")]
+    struct ItemId(u64);
+
+    #[derive(Facet)]
+    struct Item {
+        id: ItemId,
+    }
+
+    let actual = emit!(Item as Go).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Item struct {
+        Id string `json:"id"`
+    }
+
+    type ItemId = string
+    "#);
+}
+
+#[test]
+fn deprecated() {
+    #[derive(Facet)]
+    #[facet(fg::deprecated(since = "0.2.0", note = "Use `Circle` instead"))]
+    struct Round {
+        #[facet(fg::deprecated)]
+        radius: f64,
+    }
+
+    let actual = emit!(Round as Go).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    // Deprecated: Use `Circle` instead (since 0.2.0)
+    type Round struct {
+        // Deprecated: do not use.
+        Radius float64 `json:"radius"`
+    }
+    "#);
+}
+
+#[test]
+fn defaults() {
+    #[derive(Facet)]
+    struct Settings {
+        #[facet(default = "$HOME".to_string())]
+        directory: String,
+        #[facet(default = 30)]
+        timeout: u64,
+        #[facet(default)]
+        counts: HashMap<String, u32>,
+        retries: u32,
+    }
+
+    let actual = emit!(Settings as Go).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Settings struct {
+        Directory string            `json:"directory"`
+        Timeout   uint64            `json:"timeout"`
+        Counts    map[string]uint32 `json:"counts"`
+        Retries   uint32            `json:"retries"`
+    }
+    "#);
+}
+
+#[test]
+fn two_modules() {
+    mod other {
+        use crate as fg;
+        use facet::Facet;
+
+        #[derive(Facet)]
+        #[facet(fg::namespace = "other")]
+        pub struct Child {
+            value: i32,
+        }
+    }
+
+    #[derive(Facet)]
+    struct Parent {
+        other_child: other::Child,
+    }
+
+    let (other, root) = emit_two_modules!(GoCodeGenerator, Parent, "root");
+    insta::assert_snapshot!(other, @r#"
+    package other
+
+    type Child struct {
+        Value int32 `json:"value"`
+    }
+    "#);
+    insta::assert_snapshot!(root, @r#"
+    package root
+
+    import "root/other"
+
+    type Parent struct {
+        OtherChild other.Child `json:"other_child"`
+    }
+    "#);
+}
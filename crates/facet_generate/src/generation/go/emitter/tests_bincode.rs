@@ -0,0 +1,1172 @@
+//! Snapshot tests for the Go emitter — **Bincode encoding**.
+//!
+//! Mirrors the structure of [`tests`](super::tests) but uses `BincodePlugin`,
+//! so that every type gets `Serialize` / `BincodeSerialize` methods and
+//! `Deserialize<Type>` / `BincodeDeserialize<Type>` functions written against
+//! the runtime's `Serializer` / `Deserializer`.
+//!
+//! These tests verify field ordering, container depth tracking, the functions
+//! passed to the collection helpers (`serde.SerializeSeq`,
+//! `serde.DeserializeMap`, …), variant indices and the (de)serializers of type
+//! parameters.
+
+#![allow(clippy::too_many_lines)]
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use crate::{self as fg, generation::bincode::BincodePlugin};
+use facet::Facet;
+
+use super::*;
+use crate::emit;
+
+#[test]
+fn unit_struct() {
+    #[derive(Facet)]
+    struct UnitStruct;
+
+    let actual = emit!(UnitStruct as Go with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type UnitStruct struct{}
+
+    func (obj *UnitStruct) Serialize(serializer serde.Serializer) error {
+        return nil
+    }
+
+    func (obj *UnitStruct) BincodeSerialize() ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    func DeserializeUnitStruct(deserializer serde.Deserializer) (UnitStruct, error) {
+        var obj UnitStruct
+        return obj, nil
+    }
+
+    func BincodeDeserializeUnitStruct(input []byte) (UnitStruct, error) {
+        deserializer := bincode.NewDeserializer(input)
+        obj, err := DeserializeUnitStruct(deserializer)
+        if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+            return obj, serde.NewDeserializationError("Some input bytes were not read")
+        }
+        return obj, err
+    }
+    "#);
+}
+
+#[test]
+fn newtype_struct() {
+    #[derive(Facet)]
+    struct NewType(String);
+
+    let actual = emit!(NewType as Go with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type NewType struct {
+        Value string
+    }
+
+    func (obj *NewType) Serialize(serializer serde.Serializer) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        if err := serializer.SerializeStr(obj.Value); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *NewType) BincodeSerialize() ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    func DeserializeNewType(deserializer serde.Deserializer) (NewType, error) {
+        var obj NewType
+        if err := deserializer.IncreaseContainerDepth(); err != nil {
+            return obj, err
+        }
+        if val, err := deserializer.DeserializeStr(); err == nil {
+            obj.Value = val
+        } else {
+            return obj, err
+        }
+        deserializer.DecreaseContainerDepth()
+        return obj, nil
+    }
+
+    func BincodeDeserializeNewType(input []byte) (NewType, error) {
+        deserializer := bincode.NewDeserializer(input)
+        obj, err := DeserializeNewType(deserializer)
+        if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+            return obj, serde.NewDeserializationError("Some input bytes were not read")
+        }
+        return obj, err
+    }
+    "#);
+}
+
+#[test]
+fn tuple_struct() {
+    #[derive(Facet)]
+    struct TupleStruct(String, i32);
+
+    let actual = emit!(TupleStruct as Go with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type TupleStruct struct {
+        Field0 string
+        Field1 int32
+    }
+
+    func (obj *TupleStruct) Serialize(serializer serde.Serializer) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        if err := serializer.SerializeStr(obj.Field0); err != nil {
+            return err
+        }
+        if err := serializer.SerializeI32(obj.Field1); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *TupleStruct) BincodeSerialize() ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    func DeserializeTupleStruct(deserializer serde.Deserializer) (TupleStruct, error) {
+        var obj TupleStruct
+        if err := deserializer.IncreaseContainerDepth(); err != nil {
+            return obj, err
+        }
+        if val, err := deserializer.DeserializeStr(); err == nil {
+            obj.Field0 = val
+        } else {
+            return obj, err
+        }
+        if val, err := deserializer.DeserializeI32(); err == nil {
+            obj.Field1 = val
+        } else {
+            return obj, err
+        }
+        deserializer.DecreaseContainerDepth()
+        return obj, nil
+    }
+
+    func BincodeDeserializeTupleStruct(input []byte) (TupleStruct, error) {
+        deserializer := bincode.NewDeserializer(input)
+        obj, err := DeserializeTupleStruct(deserializer)
+        if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+            return obj, serde.NewDeserializationError("Some input bytes were not read")
+        }
+        return obj, err
+    }
+    "#);
+}
+
+#[test]
+fn struct_with_fields() {
+    #[derive(Facet)]
+    struct Inner {
+        value: u64,
+    }
+
+    #[derive(Facet)]
+    struct Outer {
+        name: String,
+        big: u128,
+        nickname: Option<String>,
+        inner: Inner,
+        tags: Vec<Option<Inner>>,
+        counts: BTreeMap<String, Vec<u32>>,
+        ids: BTreeSet<u16>,
+        pair: (u8, String),
+        grid: [i16; 3],
+        #[facet(fg::bytes)]
+        data: Vec<u8>,
+    }
+
+    let actual = emit!(Outer as Go with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Inner struct {
+        Value uint64 `json:"value"`
+    }
+
+    func (obj *Inner) Serialize(serializer serde.Serializer) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        if err := serializer.SerializeU64(obj.Value); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *Inner) BincodeSerialize() ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    func DeserializeInner(deserializer serde.Deserializer) (Inner, error) {
+        var obj Inner
+        if err := deserializer.IncreaseContainerDepth(); err != nil {
+            return obj, err
+        }
+        if val, err := deserializer.DeserializeU64(); err == nil {
+            obj.Value = val
+        } else {
+            return obj, err
+        }
+        deserializer.DecreaseContainerDepth()
+        return obj, nil
+    }
+
+    func BincodeDeserializeInner(input []byte) (Inner, error) {
+        deserializer := bincode.NewDeserializer(input)
+        obj, err := DeserializeInner(deserializer)
+        if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+            return obj, serde.NewDeserializationError("Some input bytes were not read")
+        }
+        return obj, err
+    }
+
+    type Outer struct {
+        Name     string                      `json:"name"`
+        Big      serde.Uint128               `json:"big"`
+        Nickname *string                     `json:"nickname"`
+        Inner    Inner                       `json:"inner"`
+        Tags     []*Inner                    `json:"tags"`
+        Counts   map[string][]uint32         `json:"counts"`
+        Ids      []uint16                    `json:"ids"`
+        Pair     serde.Tuple2[uint8, string] `json:"pair"`
+        Grid     [3]int16                    `json:"grid"`
+        Data     serde.Bytes                 `json:"data"`
+    }
+
+    func (obj *Outer) Serialize(serializer serde.Serializer) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        if err := serializer.SerializeStr(obj.Name); err != nil {
+            return err
+        }
+        if err := serializer.SerializeU128(obj.Big); err != nil {
+            return err
+        }
+        if err := serde.SerializeOption(serializer, obj.Nickname, serde.Serializer.SerializeStr); err != nil {
+            return err
+        }
+        if err := obj.Inner.Serialize(serializer); err != nil {
+            return err
+        }
+        if err := serde.SerializeSeq(serializer, obj.Tags, func(serializer serde.Serializer, value *Inner) error { return serde.SerializeOption(serializer, value, func(serializer serde.Serializer, value Inner) error { return value.Serialize(serializer) }) }); err != nil {
+            return err
+        }
+        if err := serde.SerializeMap(serializer, obj.Counts, serde.Serializer.SerializeStr, func(serializer serde.Serializer, value []uint32) error { return serde.SerializeSeq(serializer, value, serde.Serializer.SerializeU32) }); err != nil {
+            return err
+        }
+        if err := serde.SerializeSeq(serializer, obj.Ids, serde.Serializer.SerializeU16); err != nil {
+            return err
+        }
+        if err := serde.SerializeTuple2(serializer, obj.Pair, serde.Serializer.SerializeU8, serde.Serializer.SerializeStr); err != nil {
+            return err
+        }
+        if err := serde.SerializeArray(serializer, obj.Grid[:], serde.Serializer.SerializeI16); err != nil {
+            return err
+        }
+        if err := serializer.SerializeBytes(obj.Data); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *Outer) BincodeSerialize() ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    func DeserializeOuter(deserializer serde.Deserializer) (Outer, error) {
+        var obj Outer
+        if err := deserializer.IncreaseContainerDepth(); err != nil {
+            return obj, err
+        }
+        if val, err := deserializer.DeserializeStr(); err == nil {
+            obj.Name = val
+        } else {
+            return obj, err
+        }
+        if val, err := deserializer.DeserializeU128(); err == nil {
+            obj.Big = val
+        } else {
+            return obj, err
+        }
+        if val, err := serde.DeserializeOption(deserializer, serde.Deserializer.DeserializeStr); err == nil {
+            obj.Nickname = val
+        } else {
+            return obj, err
+        }
+        if val, err := DeserializeInner(deserializer); err == nil {
+            obj.Inner = val
+        } else {
+            return obj, err
+        }
+        if val, err := serde.DeserializeSeq(deserializer, func(deserializer serde.Deserializer) (*Inner, error) { return serde.DeserializeOption(deserializer, DeserializeInner) }); err == nil {
+            obj.Tags = val
+        } else {
+            return obj, err
+        }
+        if val, err := serde.DeserializeMap(deserializer, serde.Deserializer.DeserializeStr, func(deserializer serde.Deserializer) ([]uint32, error) { return serde.DeserializeSeq(deserializer, serde.Deserializer.DeserializeU32) }); err == nil {
+            obj.Counts = val
+        } else {
+            return obj, err
+        }
+        if val, err := serde.DeserializeSeq(deserializer, serde.Deserializer.DeserializeU16); err == nil {
+            obj.Ids = val
+        } else {
+            return obj, err
+        }
+        if val, err := serde.DeserializeTuple2(deserializer, serde.Deserializer.DeserializeU8, serde.Deserializer.DeserializeStr); err == nil {
+            obj.Pair = val
+        } else {
+            return obj, err
+        }
+        if val, err := func(deserializer serde.Deserializer) (value [3]int16, err error) { err = serde.DeserializeArray(deserializer, value[:], serde.Deserializer.DeserializeI16); return }(deserializer); err == nil {
+            obj.Grid = val
+        } else {
+            return obj, err
+        }
+        if val, err := deserializer.DeserializeBytes(); err == nil {
+            obj.Data = val
+        } else {
+            return obj, err
+        }
+        deserializer.DecreaseContainerDepth()
+        return obj, nil
+    }
+
+    func BincodeDeserializeOuter(input []byte) (Outer, error) {
+        deserializer := bincode.NewDeserializer(input)
+        obj, err := DeserializeOuter(deserializer)
+        if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+            return obj, serde.NewDeserializationError("Some input bytes were not read")
+        }
+        return obj, err
+    }
+    "#);
+}
+
+#[test]
+fn unit_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Colour {
+        Red,
+        Green,
+        Other,
+    }
+
+    let actual = emit!(Colour as Go with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Colour string
+
+    const (
+        ColourRed   Colour = "Red"
+        ColourGreen Colour = "Green"
+        ColourOther Colour = "Other"
+    )
+
+    func (obj *Colour) Serialize(serializer serde.Serializer) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        var index uint32
+        switch *obj {
+        case ColourRed:
+            index = 0
+        case ColourGreen:
+            index = 1
+        case ColourOther:
+            index = 2
+        default:
+            return serde.UnknownValueError("Colour", string(*obj))
+        }
+        if err := serializer.SerializeVariantIndex(index); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *Colour) BincodeSerialize() ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    func DeserializeColour(deserializer serde.Deserializer) (Colour, error) {
+        var obj Colour
+        if err := deserializer.IncreaseContainerDepth(); err != nil {
+            return obj, err
+        }
+        index, err := deserializer.DeserializeVariantIndex()
+        if err != nil {
+            return obj, err
+        }
+        switch index {
+        case 0:
+            obj = ColourRed
+        case 1:
+            obj = ColourGreen
+        case 2:
+            obj = ColourOther
+        default:
+            return obj, serde.UnknownVariantError("Colour", index)
+        }
+        deserializer.DecreaseContainerDepth()
+        return obj, nil
+    }
+
+    func BincodeDeserializeColour(input []byte) (Colour, error) {
+        deserializer := bincode.NewDeserializer(input)
+        obj, err := DeserializeColour(deserializer)
+        if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+            return obj, serde.NewDeserializationError("Some input bytes were not read")
+        }
+        return obj, err
+    }
+    "#);
+}
+
+#[test]
+fn data_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Line(f64, f64),
+        Rectangle { width: f64, height: f64 },
+    }
+
+    let actual = emit!(Shape as Go with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Shape interface {
+        isShape()
+        Serialize(serializer serde.Serializer) error
+        BincodeSerialize() ([]byte, error)
+    }
+
+    type ShapePoint struct{}
+
+    func (*ShapePoint) isShape() {}
+
+    func (obj *ShapePoint) Serialize(serializer serde.Serializer) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        if err := serializer.SerializeVariantIndex(0); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *ShapePoint) BincodeSerialize() ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    type ShapeCircle struct {
+        Value float64
+    }
+
+    func (*ShapeCircle) isShape() {}
+
+    func (obj *ShapeCircle) Serialize(serializer serde.Serializer) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        if err := serializer.SerializeVariantIndex(1); err != nil {
+            return err
+        }
+        if err := serializer.SerializeF64(obj.Value); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *ShapeCircle) BincodeSerialize() ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    type ShapeLine struct {
+        Field0 float64
+        Field1 float64
+    }
+
+    func (*ShapeLine) isShape() {}
+
+    func (obj *ShapeLine) Serialize(serializer serde.Serializer) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        if err := serializer.SerializeVariantIndex(2); err != nil {
+            return err
+        }
+        if err := serializer.SerializeF64(obj.Field0); err != nil {
+            return err
+        }
+        if err := serializer.SerializeF64(obj.Field1); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *ShapeLine) BincodeSerialize() ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    type ShapeRectangle struct {
+        Width  float64 `json:"width"`
+        Height float64 `json:"height"`
+    }
+
+    func (*ShapeRectangle) isShape() {}
+
+    func (obj *ShapeRectangle) Serialize(serializer serde.Serializer) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        if err := serializer.SerializeVariantIndex(3); err != nil {
+            return err
+        }
+        if err := serializer.SerializeF64(obj.Width); err != nil {
+            return err
+        }
+        if err := serializer.SerializeF64(obj.Height); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *ShapeRectangle) BincodeSerialize() ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    func DeserializeShape(deserializer serde.Deserializer) (Shape, error) {
+        if err := deserializer.IncreaseContainerDepth(); err != nil {
+            return nil, err
+        }
+        index, err := deserializer.DeserializeVariantIndex()
+        if err != nil {
+            return nil, err
+        }
+        var value Shape
+        switch index {
+        case 0:
+            var obj ShapePoint
+            value = &obj
+        case 1:
+            var obj ShapeCircle
+            if val, err := deserializer.DeserializeF64(); err == nil {
+                obj.Value = val
+            } else {
+                return nil, err
+            }
+            value = &obj
+        case 2:
+            var obj ShapeLine
+            if val, err := deserializer.DeserializeF64(); err == nil {
+                obj.Field0 = val
+            } else {
+                return nil, err
+            }
+            if val, err := deserializer.DeserializeF64(); err == nil {
+                obj.Field1 = val
+            } else {
+                return nil, err
+            }
+            value = &obj
+        case 3:
+            var obj ShapeRectangle
+            if val, err := deserializer.DeserializeF64(); err == nil {
+                obj.Width = val
+            } else {
+                return nil, err
+            }
+            if val, err := deserializer.DeserializeF64(); err == nil {
+                obj.Height = val
+            } else {
+                return nil, err
+            }
+            value = &obj
+        default:
+            return nil, serde.UnknownVariantError("Shape", index)
+        }
+        deserializer.DecreaseContainerDepth()
+        return value, nil
+    }
+
+    func BincodeDeserializeShape(input []byte) (Shape, error) {
+        deserializer := bincode.NewDeserializer(input)
+        obj, err := DeserializeShape(deserializer)
+        if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+            return obj, serde.NewDeserializationError("Some input bytes were not read")
+        }
+        return obj, err
+    }
+    "#);
+}
+
+#[test]
+fn generic_struct_and_enum() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E },
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        page: Page<String>,
+        outcome: Outcome<Page<u32>, String>,
+        fallback: Outcome<bool, i32>,
+    }
+
+    let actual = emit!(Response as Go with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Outcome[T, E any] interface {
+        isOutcome()
+        Serialize(serializer serde.Serializer, serializeT func(serde.Serializer, T) error, serializeE func(serde.Serializer, E) error) error
+        BincodeSerialize(serializeT func(serde.Serializer, T) error, serializeE func(serde.Serializer, E) error) ([]byte, error)
+    }
+
+    type OutcomeSuccess[T, E any] struct {
+        Value T
+    }
+
+    func (*OutcomeSuccess[T, E]) isOutcome() {}
+
+    func (obj *OutcomeSuccess[T, E]) Serialize(serializer serde.Serializer, serializeT func(serde.Serializer, T) error, serializeE func(serde.Serializer, E) error) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        if err := serializer.SerializeVariantIndex(0); err != nil {
+            return err
+        }
+        if err := serializeT(serializer, obj.Value); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *OutcomeSuccess[T, E]) BincodeSerialize(serializeT func(serde.Serializer, T) error, serializeE func(serde.Serializer, E) error) ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer, serializeT, serializeE); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    type OutcomeFailure[T, E any] struct {
+        Error E `json:"error"`
+    }
+
+    func (*OutcomeFailure[T, E]) isOutcome() {}
+
+    func (obj *OutcomeFailure[T, E]) Serialize(serializer serde.Serializer, serializeT func(serde.Serializer, T) error, serializeE func(serde.Serializer, E) error) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        if err := serializer.SerializeVariantIndex(1); err != nil {
+            return err
+        }
+        if err := serializeE(serializer, obj.Error); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *OutcomeFailure[T, E]) BincodeSerialize(serializeT func(serde.Serializer, T) error, serializeE func(serde.Serializer, E) error) ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer, serializeT, serializeE); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    func DeserializeOutcome[T, E any](deserializer serde.Deserializer, deserializeT func(serde.Deserializer) (T, error), deserializeE func(serde.Deserializer) (E, error)) (Outcome[T, E], error) {
+        if err := deserializer.IncreaseContainerDepth(); err != nil {
+            return nil, err
+        }
+        index, err := deserializer.DeserializeVariantIndex()
+        if err != nil {
+            return nil, err
+        }
+        var value Outcome[T, E]
+        switch index {
+        case 0:
+            var obj OutcomeSuccess[T, E]
+            if val, err := deserializeT(deserializer); err == nil {
+                obj.Value = val
+            } else {
+                return nil, err
+            }
+            value = &obj
+        case 1:
+            var obj OutcomeFailure[T, E]
+            if val, err := deserializeE(deserializer); err == nil {
+                obj.Error = val
+            } else {
+                return nil, err
+            }
+            value = &obj
+        default:
+            return nil, serde.UnknownVariantError("Outcome", index)
+        }
+        deserializer.DecreaseContainerDepth()
+        return value, nil
+    }
+
+    func BincodeDeserializeOutcome[T, E any](input []byte, deserializeT func(serde.Deserializer) (T, error), deserializeE func(serde.Deserializer) (E, error)) (Outcome[T, E], error) {
+        deserializer := bincode.NewDeserializer(input)
+        obj, err := DeserializeOutcome[T, E](deserializer, deserializeT, deserializeE)
+        if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+            return obj, serde.NewDeserializationError("Some input bytes were not read")
+        }
+        return obj, err
+    }
+
+    type Page[T any] struct {
+        Items []T `json:"items"`
+        Next  *T  `json:"next"`
+    }
+
+    func (obj *Page[T]) Serialize(serializer serde.Serializer, serializeT func(serde.Serializer, T) error) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        if err := serde.SerializeSeq(serializer, obj.Items, serializeT); err != nil {
+            return err
+        }
+        if err := serde.SerializeOption(serializer, obj.Next, serializeT); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *Page[T]) BincodeSerialize(serializeT func(serde.Serializer, T) error) ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer, serializeT); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    func DeserializePage[T any](deserializer serde.Deserializer, deserializeT func(serde.Deserializer) (T, error)) (Page[T], error) {
+        var obj Page[T]
+        if err := deserializer.IncreaseContainerDepth(); err != nil {
+            return obj, err
+        }
+        if val, err := serde.DeserializeSeq(deserializer, deserializeT); err == nil {
+            obj.Items = val
+        } else {
+            return obj, err
+        }
+        if val, err := serde.DeserializeOption(deserializer, deserializeT); err == nil {
+            obj.Next = val
+        } else {
+            return obj, err
+        }
+        deserializer.DecreaseContainerDepth()
+        return obj, nil
+    }
+
+    func BincodeDeserializePage[T any](input []byte, deserializeT func(serde.Deserializer) (T, error)) (Page[T], error) {
+        deserializer := bincode.NewDeserializer(input)
+        obj, err := DeserializePage[T](deserializer, deserializeT)
+        if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+            return obj, serde.NewDeserializationError("Some input bytes were not read")
+        }
+        return obj, err
+    }
+
+    type Response struct {
+        Page     Page[string]                  `json:"page"`
+        Outcome  Outcome[Page[uint32], string] `json:"outcome"`
+        Fallback Outcome[bool, int32]          `json:"fallback"`
+    }
+
+    func (obj *Response) Serialize(serializer serde.Serializer) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        if err := obj.Page.Serialize(serializer, serde.Serializer.SerializeStr); err != nil {
+            return err
+        }
+        if err := obj.Outcome.Serialize(serializer, func(serializer serde.Serializer, value Page[uint32]) error { return value.Serialize(serializer, serde.Serializer.SerializeU32) }, serde.Serializer.SerializeStr); err != nil {
+            return err
+        }
+        if err := obj.Fallback.Serialize(serializer, serde.Serializer.SerializeBool, serde.Serializer.SerializeI32); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *Response) BincodeSerialize() ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    func DeserializeResponse(deserializer serde.Deserializer) (Response, error) {
+        var obj Response
+        if err := deserializer.IncreaseContainerDepth(); err != nil {
+            return obj, err
+        }
+        if val, err := DeserializePage[string](deserializer, serde.Deserializer.DeserializeStr); err == nil {
+            obj.Page = val
+        } else {
+            return obj, err
+        }
+        if val, err := DeserializeOutcome[Page[uint32], string](deserializer, func(deserializer serde.Deserializer) (Page[uint32], error) { return DeserializePage[uint32](deserializer, serde.Deserializer.DeserializeU32) }, serde.Deserializer.DeserializeStr); err == nil {
+            obj.Outcome = val
+        } else {
+            return obj, err
+        }
+        if val, err := DeserializeOutcome[bool, int32](deserializer, serde.Deserializer.DeserializeBool, serde.Deserializer.DeserializeI32); err == nil {
+            obj.Fallback = val
+        } else {
+            return obj, err
+        }
+        deserializer.DecreaseContainerDepth()
+        return obj, nil
+    }
+
+    func BincodeDeserializeResponse(input []byte) (Response, error) {
+        deserializer := bincode.NewDeserializer(input)
+        obj, err := DeserializeResponse(deserializer)
+        if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+            return obj, serde.NewDeserializationError("Some input bytes were not read")
+        }
+        return obj, err
+    }
+    "#);
+}
+
+#[test]
+fn branded_newtype() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    let actual = emit!(UserId as Go with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type UserId string
+
+    func (obj *UserId) Serialize(serializer serde.Serializer) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        if err := serializer.SerializeStr(string(*obj)); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *UserId) BincodeSerialize() ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    func DeserializeUserId(deserializer serde.Deserializer) (UserId, error) {
+        var obj UserId
+        if err := deserializer.IncreaseContainerDepth(); err != nil {
+            return obj, err
+        }
+        if val, err := deserializer.DeserializeStr(); err == nil {
+            obj = UserId(val)
+        } else {
+            return obj, err
+        }
+        deserializer.DecreaseContainerDepth()
+        return obj, nil
+    }
+
+    func BincodeDeserializeUserId(input []byte) (UserId, error) {
+        deserializer := bincode.NewDeserializer(input)
+        obj, err := DeserializeUserId(deserializer)
+        if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+            return obj, serde.NewDeserializationError("Some input bytes were not read")
+        }
+        return obj, err
+    }
+    "#);
+}
+
+#[test]
+fn unknown_variants() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Click {
+            x: i32,
+            y: i32,
+        },
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Event as Go with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Event interface {
+        isEvent()
+        Serialize(serializer serde.Serializer) error
+        BincodeSerialize() ([]byte, error)
+    }
+
+    type EventClick struct {
+        X int32 `json:"x"`
+        Y int32 `json:"y"`
+    }
+
+    func (*EventClick) isEvent() {}
+
+    func (obj *EventClick) Serialize(serializer serde.Serializer) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        if err := serializer.SerializeVariantIndex(0); err != nil {
+            return err
+        }
+        if err := serializer.SerializeI32(obj.X); err != nil {
+            return err
+        }
+        if err := serializer.SerializeI32(obj.Y); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *EventClick) BincodeSerialize() ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    type EventUnknown struct{}
+
+    func (*EventUnknown) isEvent() {}
+
+    func (obj *EventUnknown) Serialize(serializer serde.Serializer) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        if err := serializer.SerializeVariantIndex(1); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *EventUnknown) BincodeSerialize() ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    func DeserializeEvent(deserializer serde.Deserializer) (Event, error) {
+        if err := deserializer.IncreaseContainerDepth(); err != nil {
+            return nil, err
+        }
+        index, err := deserializer.DeserializeVariantIndex()
+        if err != nil {
+            return nil, err
+        }
+        var value Event
+        switch index {
+        case 0:
+            var obj EventClick
+            if val, err := deserializer.DeserializeI32(); err == nil {
+                obj.X = val
+            } else {
+                return nil, err
+            }
+            if val, err := deserializer.DeserializeI32(); err == nil {
+                obj.Y = val
+            } else {
+                return nil, err
+            }
+            value = &obj
+        case 1:
+            var obj EventUnknown
+            value = &obj
+        default:
+            value = &EventUnknown{}
+        }
+        deserializer.DecreaseContainerDepth()
+        return value, nil
+    }
+
+    func BincodeDeserializeEvent(input []byte) (Event, error) {
+        deserializer := bincode.NewDeserializer(input)
+        obj, err := DeserializeEvent(deserializer)
+        if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+            return obj, serde.NewDeserializationError("Some input bytes were not read")
+        }
+        return obj, err
+    }
+    "#);
+}
+
+#[test]
+fn type_override() {
+    #[derive(Facet)]
+    struct Price {
+        #[facet(fg::override(
+            lang = "go",
+            ty = "Decimal",
+            serialize = "serializer.SerializeStr({value}.String())",
+            deserialize = "ParseDecimal(deserializer.DeserializeStr())"
+        ))]
+        amount: String,
+        #[facet(fg::override(lang = "swift", ty = "CGFloat"))]
+        scale: f64,
+    }
+
+    let actual = emit!(Price as Go with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Price struct {
+        Amount Decimal `json:"amount"`
+        Scale  float64 `json:"scale"`
+    }
+
+    func (obj *Price) Serialize(serializer serde.Serializer) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        if err := serializer.SerializeStr(obj.Amount.String()); err != nil {
+            return err
+        }
+        if err := serializer.SerializeF64(obj.Scale); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *Price) BincodeSerialize() ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    func DeserializePrice(deserializer serde.Deserializer) (Price, error) {
+        var obj Price
+        if err := deserializer.IncreaseContainerDepth(); err != nil {
+            return obj, err
+        }
+        if val, err := ParseDecimal(deserializer.DeserializeStr()); err == nil {
+            obj.Amount = val
+        } else {
+            return obj, err
+        }
+        if val, err := deserializer.DeserializeF64(); err == nil {
+            obj.Scale = val
+        } else {
+            return obj, err
+        }
+        deserializer.DecreaseContainerDepth()
+        return obj, nil
+    }
+
+    func BincodeDeserializePrice(input []byte) (Price, error) {
+        deserializer := bincode.NewDeserializer(input)
+        obj, err := DeserializePrice(deserializer)
+        if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+            return obj, serde.NewDeserializationError("Some input bytes were not read")
+        }
+        return obj, err
+    }
+    "#);
+}
@@ -0,0 +1,1007 @@
+//! Snapshot tests for the Go emitter — **JSON encoding**.
+//!
+//! Mirrors the structure of [`tests`](super::tests) but uses `JsonPlugin`, so
+//! that the types that `encoding/json` can't read or write as they are get
+//! `MarshalJSON` / `UnmarshalJSON` methods, and every enum whose variants
+//! carry data gets an `<Enum>FromJSON` function.
+//!
+//! These tests verify the JSON shapes of structs, of the enum taggings
+//! (external, internal, adjacent and untagged), of map keys and of generic
+//! types.
+
+#![allow(clippy::too_many_lines)]
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::generation::json::JsonPlugin;
+use facet::Facet;
+
+use super::*;
+use crate::emit;
+
+#[test]
+fn unit_and_empty_structs() {
+    #[derive(Facet)]
+    struct UnitStruct;
+
+    #[derive(Facet)]
+    struct EmptyStruct {}
+
+    #[derive(Facet)]
+    struct Both {
+        unit: UnitStruct,
+        empty: EmptyStruct,
+    }
+
+    let actual = emit!(Both as Go with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Both struct {
+        Unit  UnitStruct  `json:"unit"`
+        Empty EmptyStruct `json:"empty"`
+    }
+
+    type EmptyStruct struct{}
+
+    func (obj EmptyStruct) MarshalJSON() ([]byte, error) {
+        return []byte("null"), nil
+    }
+
+    func (obj *EmptyStruct) UnmarshalJSON(data []byte) error {
+        return serde.UnitFromJSON(data)
+    }
+
+    type UnitStruct struct{}
+
+    func (obj UnitStruct) MarshalJSON() ([]byte, error) {
+        return []byte("null"), nil
+    }
+
+    func (obj *UnitStruct) UnmarshalJSON(data []byte) error {
+        return serde.UnitFromJSON(data)
+    }
+    "#);
+}
+
+#[test]
+fn newtype_and_tuple_structs() {
+    #[derive(Facet)]
+    struct NewType(Vec<String>);
+
+    #[derive(Facet)]
+    struct TupleStruct(String, f64);
+
+    #[derive(Facet)]
+    struct Both {
+        newtype: NewType,
+        tuple: TupleStruct,
+    }
+
+    let actual = emit!(Both as Go with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Both struct {
+        Newtype NewType     `json:"newtype"`
+        Tuple   TupleStruct `json:"tuple"`
+    }
+
+    type NewType struct {
+        Value []string
+    }
+
+    func (obj NewType) MarshalJSON() ([]byte, error) {
+        return json.Marshal(obj.Value)
+    }
+
+    func (obj *NewType) UnmarshalJSON(data []byte) error {
+        var err error
+        obj.Value, err = serde.FromJSON[[]string](data)
+        return err
+    }
+
+    type TupleStruct struct {
+        Field0 string
+        Field1 float64
+    }
+
+    func (obj TupleStruct) MarshalJSON() ([]byte, error) {
+        return json.Marshal([]any{obj.Field0, obj.Field1})
+    }
+
+    func (obj *TupleStruct) UnmarshalJSON(data []byte) error {
+        elements, err := serde.ElementsFromJSON(data, 2)
+        if err != nil {
+            return err
+        }
+        if obj.Field0, err = serde.FromJSON[string](elements[0]); err != nil {
+            return err
+        }
+        if obj.Field1, err = serde.FromJSON[float64](elements[1]); err != nil {
+            return err
+        }
+        return nil
+    }
+    "#);
+}
+
+#[test]
+fn struct_with_fields() {
+    #[derive(Facet, PartialEq, Eq, PartialOrd, Ord)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Kind {
+        Small,
+        Large,
+    }
+
+    #[derive(Facet)]
+    struct Outer {
+        name: String,
+        big: i128,
+        ratio: f32,
+        nickname: Option<String>,
+        kind: Option<Kind>,
+        tags: Vec<Kind>,
+        counts: BTreeMap<u32, Vec<f64>>,
+        kinds: BTreeMap<Kind, String>,
+        ids: HashSet<u16>,
+        pair: (u8, String),
+        timeout: Duration,
+        #[facet(default = 3)]
+        retries: u32,
+    }
+
+    let actual = emit!(Outer as Go with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Kind string
+
+    const (
+        KindSmall Kind = "Small"
+        KindLarge Kind = "Large"
+    )
+
+    func (obj *Kind) UnmarshalJSON(data []byte) error {
+        name, err := serde.FromJSON[string](data)
+        if err != nil {
+            return err
+        }
+        switch value := Kind(name); value {
+        case KindSmall, KindLarge:
+            *obj = value
+        default:
+            return serde.UnknownVariantNameError("Kind", name)
+        }
+        return nil
+    }
+
+    type Outer struct {
+        Name     string                      `json:"name"`
+        Big      serde.Int128                `json:"big"`
+        Ratio    float32                     `json:"ratio"`
+        Nickname *string                     `json:"nickname"`
+        Kind     *Kind                       `json:"kind"`
+        Tags     []Kind                      `json:"tags"`
+        Counts   map[uint32][]float64        `json:"counts"`
+        Kinds    map[Kind]string             `json:"kinds"`
+        Ids      []uint16                    `json:"ids"`
+        Pair     serde.Tuple2[uint8, string] `json:"pair"`
+        Timeout  serde.Duration              `json:"timeout"`
+        Retries  uint32                      `json:"retries"`
+    }
+
+    func (obj *Outer) UnmarshalJSON(data []byte) error {
+        fields, err := serde.ObjectFromJSON(data)
+        if err != nil {
+            return err
+        }
+        if field, ok := fields["name"]; ok {
+            if obj.Name, err = serde.FromJSON[string](field); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("name")
+        }
+        if field, ok := fields["big"]; ok {
+            if obj.Big, err = serde.FromJSON[serde.Int128](field); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("big")
+        }
+        if field, ok := fields["ratio"]; ok {
+            if obj.Ratio, err = serde.FromJSON[float32](field); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("ratio")
+        }
+        if field, ok := fields["nickname"]; ok {
+            if obj.Nickname, err = serde.FromJSON[*string](field); err != nil {
+                return err
+            }
+        }
+        if field, ok := fields["kind"]; ok {
+            if obj.Kind, err = serde.FromJSON[*Kind](field); err != nil {
+                return err
+            }
+        }
+        if field, ok := fields["tags"]; ok {
+            if obj.Tags, err = serde.FromJSON[[]Kind](field); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("tags")
+        }
+        if field, ok := fields["counts"]; ok {
+            if obj.Counts, err = serde.FromJSON[map[uint32][]float64](field); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("counts")
+        }
+        if field, ok := fields["kinds"]; ok {
+            if obj.Kinds, err = serde.FromJSON[map[Kind]string](field); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("kinds")
+        }
+        if field, ok := fields["ids"]; ok {
+            if obj.Ids, err = serde.FromJSON[[]uint16](field); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("ids")
+        }
+        if field, ok := fields["pair"]; ok {
+            if obj.Pair, err = serde.FromJSON[serde.Tuple2[uint8, string]](field); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("pair")
+        }
+        if field, ok := fields["timeout"]; ok {
+            if obj.Timeout, err = serde.FromJSON[serde.Duration](field); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("timeout")
+        }
+        if field, ok := fields["retries"]; ok {
+            if obj.Retries, err = serde.FromJSON[uint32](field); err != nil {
+                return err
+            }
+        } else {
+            obj.Retries = 3
+        }
+        return nil
+    }
+    "#);
+}
+
+#[test]
+fn externally_tagged_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Line(f64, f64),
+        Rectangle { width: f64, height: f64 },
+    }
+
+    let actual = emit!(Shape as Go with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Shape interface {
+        isShape()
+    }
+
+    type ShapePoint struct{}
+
+    func (*ShapePoint) isShape() {}
+
+    func (obj ShapePoint) MarshalJSON() ([]byte, error) {
+        return json.Marshal("Point")
+    }
+
+    func (obj *ShapePoint) unmarshalContent(data []byte) error {
+        return nil
+    }
+
+    type ShapeCircle struct {
+        Value float64
+    }
+
+    func (*ShapeCircle) isShape() {}
+
+    func (obj ShapeCircle) MarshalJSON() ([]byte, error) {
+        return json.Marshal(serde.Object{{Key: "Circle", Value: obj.Value}})
+    }
+
+    func (obj *ShapeCircle) unmarshalContent(data []byte) error {
+        var err error
+        obj.Value, err = serde.FromJSON[float64](data)
+        return err
+    }
+
+    type ShapeLine struct {
+        Field0 float64
+        Field1 float64
+    }
+
+    func (*ShapeLine) isShape() {}
+
+    func (obj ShapeLine) MarshalJSON() ([]byte, error) {
+        return json.Marshal(serde.Object{{Key: "Line", Value: []any{obj.Field0, obj.Field1}}})
+    }
+
+    func (obj *ShapeLine) unmarshalContent(data []byte) error {
+        elements, err := serde.ElementsFromJSON(data, 2)
+        if err != nil {
+            return err
+        }
+        if obj.Field0, err = serde.FromJSON[float64](elements[0]); err != nil {
+            return err
+        }
+        if obj.Field1, err = serde.FromJSON[float64](elements[1]); err != nil {
+            return err
+        }
+        return nil
+    }
+
+    type ShapeRectangle struct {
+        Width  float64 `json:"width"`
+        Height float64 `json:"height"`
+    }
+
+    func (*ShapeRectangle) isShape() {}
+
+    func (obj ShapeRectangle) MarshalJSON() ([]byte, error) {
+        return json.Marshal(serde.Object{{Key: "Rectangle", Value: serde.Object{{Key: "width", Value: obj.Width}, {Key: "height", Value: obj.Height}}}})
+    }
+
+    func (obj *ShapeRectangle) unmarshalContent(data []byte) error {
+        fields, err := serde.ObjectFromJSON(data)
+        if err != nil {
+            return err
+        }
+        if field, ok := fields["width"]; ok {
+            if obj.Width, err = serde.FromJSON[float64](field); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("width")
+        }
+        if field, ok := fields["height"]; ok {
+            if obj.Height, err = serde.FromJSON[float64](field); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("height")
+        }
+        return nil
+    }
+
+    func ShapeFromJSON(data []byte) (Shape, error) {
+        name, content, err := serde.ExternalTag(data)
+        if err != nil {
+            return nil, err
+        }
+        switch name {
+        case "Point":
+            var obj ShapePoint
+            if err := obj.unmarshalContent(content); err != nil {
+                return nil, err
+            }
+            return &obj, nil
+        case "Circle":
+            var obj ShapeCircle
+            if err := obj.unmarshalContent(content); err != nil {
+                return nil, err
+            }
+            return &obj, nil
+        case "Line":
+            var obj ShapeLine
+            if err := obj.unmarshalContent(content); err != nil {
+                return nil, err
+            }
+            return &obj, nil
+        case "Rectangle":
+            var obj ShapeRectangle
+            if err := obj.unmarshalContent(content); err != nil {
+                return nil, err
+            }
+            return &obj, nil
+        default:
+            return nil, serde.UnknownVariantNameError("Shape", name)
+        }
+    }
+
+    func init() {
+        serde.RegisterUnion(ShapeFromJSON)
+    }
+    "#);
+}
+
+#[test]
+fn internally_tagged_enum() {
+    #[derive(Facet)]
+    struct Position {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Facet)]
+    #[facet(tag = "type")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Started,
+        Moved(Position),
+        Clicked {
+            button: u8,
+        },
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Event as Go with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Event interface {
+        isEvent()
+    }
+
+    type EventStarted struct{}
+
+    func (*EventStarted) isEvent() {}
+
+    func (obj EventStarted) MarshalJSON() ([]byte, error) {
+        return json.Marshal(serde.Object{{Key: "type", Value: "Started"}})
+    }
+
+    func (obj *EventStarted) unmarshalContent(data []byte) error {
+        return nil
+    }
+
+    type EventMoved struct {
+        Value Position
+    }
+
+    func (*EventMoved) isEvent() {}
+
+    func (obj EventMoved) MarshalJSON() ([]byte, error) {
+        return serde.InternallyTagged("type", "Moved", obj.Value)
+    }
+
+    func (obj *EventMoved) unmarshalContent(data []byte) error {
+        var err error
+        obj.Value, err = serde.FromJSON[Position](data)
+        return err
+    }
+
+    type EventClicked struct {
+        Button uint8 `json:"button"`
+    }
+
+    func (*EventClicked) isEvent() {}
+
+    func (obj EventClicked) MarshalJSON() ([]byte, error) {
+        return json.Marshal(serde.Object{{Key: "type", Value: "Clicked"}, {Key: "button", Value: obj.Button}})
+    }
+
+    func (obj *EventClicked) unmarshalContent(data []byte) error {
+        fields, err := serde.ObjectFromJSON(data)
+        if err != nil {
+            return err
+        }
+        if field, ok := fields["button"]; ok {
+            if obj.Button, err = serde.FromJSON[uint8](field); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("button")
+        }
+        return nil
+    }
+
+    type EventUnknown struct{}
+
+    func (*EventUnknown) isEvent() {}
+
+    func (obj EventUnknown) MarshalJSON() ([]byte, error) {
+        return json.Marshal(serde.Object{{Key: "type", Value: "Unknown"}})
+    }
+
+    func (obj *EventUnknown) unmarshalContent(data []byte) error {
+        return nil
+    }
+
+    func EventFromJSON(data []byte) (Event, error) {
+        name, err := serde.InternalTag(data, "type")
+        if err != nil {
+            return nil, err
+        }
+        content := data
+        switch name {
+        case "Started":
+            var obj EventStarted
+            if err := obj.unmarshalContent(content); err != nil {
+                return nil, err
+            }
+            return &obj, nil
+        case "Moved":
+            var obj EventMoved
+            if err := obj.unmarshalContent(content); err != nil {
+                return nil, err
+            }
+            return &obj, nil
+        case "Clicked":
+            var obj EventClicked
+            if err := obj.unmarshalContent(content); err != nil {
+                return nil, err
+            }
+            return &obj, nil
+        case "Unknown":
+            var obj EventUnknown
+            if err := obj.unmarshalContent(content); err != nil {
+                return nil, err
+            }
+            return &obj, nil
+        default:
+            return &EventUnknown{}, nil
+        }
+    }
+
+    func init() {
+        serde.RegisterUnion(EventFromJSON)
+    }
+
+    type Position struct {
+        X int32 `json:"x"`
+        Y int32 `json:"y"`
+    }
+    "#);
+}
+
+#[test]
+fn adjacently_tagged_enum() {
+    #[derive(Facet)]
+    #[facet(tag = "t", content = "c")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Message {
+        Ping,
+        Text(String),
+        Pair(u32, u32),
+    }
+
+    let actual = emit!(Message as Go with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Message interface {
+        isMessage()
+    }
+
+    type MessagePing struct{}
+
+    func (*MessagePing) isMessage() {}
+
+    func (obj MessagePing) MarshalJSON() ([]byte, error) {
+        return json.Marshal(serde.Object{{Key: "t", Value: "Ping"}})
+    }
+
+    func (obj *MessagePing) unmarshalContent(data []byte) error {
+        return nil
+    }
+
+    type MessageText struct {
+        Value string
+    }
+
+    func (*MessageText) isMessage() {}
+
+    func (obj MessageText) MarshalJSON() ([]byte, error) {
+        return json.Marshal(serde.Object{{Key: "t", Value: "Text"}, {Key: "c", Value: obj.Value}})
+    }
+
+    func (obj *MessageText) unmarshalContent(data []byte) error {
+        var err error
+        obj.Value, err = serde.FromJSON[string](data)
+        return err
+    }
+
+    type MessagePair struct {
+        Field0 uint32
+        Field1 uint32
+    }
+
+    func (*MessagePair) isMessage() {}
+
+    func (obj MessagePair) MarshalJSON() ([]byte, error) {
+        return json.Marshal(serde.Object{{Key: "t", Value: "Pair"}, {Key: "c", Value: []any{obj.Field0, obj.Field1}}})
+    }
+
+    func (obj *MessagePair) unmarshalContent(data []byte) error {
+        elements, err := serde.ElementsFromJSON(data, 2)
+        if err != nil {
+            return err
+        }
+        if obj.Field0, err = serde.FromJSON[uint32](elements[0]); err != nil {
+            return err
+        }
+        if obj.Field1, err = serde.FromJSON[uint32](elements[1]); err != nil {
+            return err
+        }
+        return nil
+    }
+
+    func MessageFromJSON(data []byte) (Message, error) {
+        name, content, err := serde.AdjacentTag(data, "t", "c")
+        if err != nil {
+            return nil, err
+        }
+        switch name {
+        case "Ping":
+            var obj MessagePing
+            if err := obj.unmarshalContent(content); err != nil {
+                return nil, err
+            }
+            return &obj, nil
+        case "Text":
+            var obj MessageText
+            if err := obj.unmarshalContent(content); err != nil {
+                return nil, err
+            }
+            return &obj, nil
+        case "Pair":
+            var obj MessagePair
+            if err := obj.unmarshalContent(content); err != nil {
+                return nil, err
+            }
+            return &obj, nil
+        default:
+            return nil, serde.UnknownVariantNameError("Message", name)
+        }
+    }
+
+    func init() {
+        serde.RegisterUnion(MessageFromJSON)
+    }
+    "#);
+}
+
+#[test]
+fn untagged_enum() {
+    #[derive(Facet)]
+    #[facet(untagged)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Value {
+        Nothing,
+        Number(f64),
+        Named { name: String },
+    }
+
+    let actual = emit!(Value as Go with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Value interface {
+        isValue()
+    }
+
+    type ValueNothing struct{}
+
+    func (*ValueNothing) isValue() {}
+
+    func (obj ValueNothing) MarshalJSON() ([]byte, error) {
+        return []byte("null"), nil
+    }
+
+    func (obj *ValueNothing) unmarshalContent(data []byte) error {
+        return serde.UnitFromJSON(data)
+    }
+
+    type ValueNumber struct {
+        Value float64
+    }
+
+    func (*ValueNumber) isValue() {}
+
+    func (obj ValueNumber) MarshalJSON() ([]byte, error) {
+        return json.Marshal(obj.Value)
+    }
+
+    func (obj *ValueNumber) unmarshalContent(data []byte) error {
+        var err error
+        obj.Value, err = serde.FromJSON[float64](data)
+        return err
+    }
+
+    type ValueNamed struct {
+        Name string `json:"name"`
+    }
+
+    func (*ValueNamed) isValue() {}
+
+    func (obj ValueNamed) MarshalJSON() ([]byte, error) {
+        return json.Marshal(serde.Object{{Key: "name", Value: obj.Name}})
+    }
+
+    func (obj *ValueNamed) unmarshalContent(data []byte) error {
+        fields, err := serde.ObjectFromJSON(data)
+        if err != nil {
+            return err
+        }
+        if field, ok := fields["name"]; ok {
+            if obj.Name, err = serde.FromJSON[string](field); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("name")
+        }
+        return nil
+    }
+
+    func ValueFromJSON(data []byte) (Value, error) {
+        if obj := (ValueNothing{}); obj.unmarshalContent(data) == nil {
+            return &obj, nil
+        }
+        if obj := (ValueNumber{}); obj.unmarshalContent(data) == nil {
+            return &obj, nil
+        }
+        if obj := (ValueNamed{}); obj.unmarshalContent(data) == nil {
+            return &obj, nil
+        }
+        return nil, serde.NoMatchingVariantError("Value")
+    }
+
+    func init() {
+        serde.RegisterUnion(ValueFromJSON)
+    }
+    "#);
+}
+
+#[test]
+fn tagged_unit_enums() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Plain {
+        On,
+        Off,
+    }
+
+    #[derive(Facet)]
+    #[facet(tag = "kind")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Tagged {
+        Up,
+        #[facet(other)]
+        Unknown,
+    }
+
+    #[derive(Facet)]
+    struct Both {
+        plain: Plain,
+        tagged: Tagged,
+    }
+
+    let actual = emit!(Both as Go with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Both struct {
+        Plain  Plain  `json:"plain"`
+        Tagged Tagged `json:"tagged"`
+    }
+
+    type Plain string
+
+    const (
+        PlainOn  Plain = "On"
+        PlainOff Plain = "Off"
+    )
+
+    func (obj *Plain) UnmarshalJSON(data []byte) error {
+        name, err := serde.FromJSON[string](data)
+        if err != nil {
+            return err
+        }
+        switch value := Plain(name); value {
+        case PlainOn, PlainOff:
+            *obj = value
+        default:
+            return serde.UnknownVariantNameError("Plain", name)
+        }
+        return nil
+    }
+
+    type Tagged string
+
+    const (
+        TaggedUp      Tagged = "Up"
+        TaggedUnknown Tagged = "Unknown"
+    )
+
+    func (obj Tagged) MarshalJSON() ([]byte, error) {
+        return json.Marshal(serde.Object{{Key: "kind", Value: string(obj)}})
+    }
+
+    func (obj *Tagged) UnmarshalJSON(data []byte) error {
+        name, err := serde.InternalTag(data, "kind")
+        if err != nil {
+            return err
+        }
+        switch value := Tagged(name); value {
+        case TaggedUp, TaggedUnknown:
+            *obj = value
+        default:
+            *obj = TaggedUnknown
+        }
+        return nil
+    }
+    "#);
+}
+
+#[test]
+fn generic_struct_and_enum() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E },
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        page: Page<String>,
+        outcome: Outcome<Page<u32>, String>,
+        fallback: Outcome<bool, i32>,
+    }
+
+    let actual = emit!(Response as Go with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    type Outcome[T, E any] interface {
+        isOutcome()
+    }
+
+    type OutcomeSuccess[T, E any] struct {
+        Value T
+    }
+
+    func (*OutcomeSuccess[T, E]) isOutcome() {}
+
+    func (obj OutcomeSuccess[T, E]) MarshalJSON() ([]byte, error) {
+        return json.Marshal(serde.Object{{Key: "Success", Value: obj.Value}})
+    }
+
+    func (obj *OutcomeSuccess[T, E]) unmarshalContent(data []byte) error {
+        var err error
+        obj.Value, err = serde.FromJSON[T](data)
+        return err
+    }
+
+    type OutcomeFailure[T, E any] struct {
+        Error E `json:"error"`
+    }
+
+    func (*OutcomeFailure[T, E]) isOutcome() {}
+
+    func (obj OutcomeFailure[T, E]) MarshalJSON() ([]byte, error) {
+        return json.Marshal(serde.Object{{Key: "Failure", Value: serde.Object{{Key: "error", Value: obj.Error}}}})
+    }
+
+    func (obj *OutcomeFailure[T, E]) unmarshalContent(data []byte) error {
+        fields, err := serde.ObjectFromJSON(data)
+        if err != nil {
+            return err
+        }
+        if field, ok := fields["error"]; ok {
+            if obj.Error, err = serde.FromJSON[E](field); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("error")
+        }
+        return nil
+    }
+
+    func OutcomeFromJSON[T, E any](data []byte) (Outcome[T, E], error) {
+        name, content, err := serde.ExternalTag(data)
+        if err != nil {
+            return nil, err
+        }
+        switch name {
+        case "Success":
+            var obj OutcomeSuccess[T, E]
+            if err := obj.unmarshalContent(content); err != nil {
+                return nil, err
+            }
+            return &obj, nil
+        case "Failure":
+            var obj OutcomeFailure[T, E]
+            if err := obj.unmarshalContent(content); err != nil {
+                return nil, err
+            }
+            return &obj, nil
+        default:
+            return nil, serde.UnknownVariantNameError("Outcome", name)
+        }
+    }
+
+    type Page[T any] struct {
+        Items []T `json:"items"`
+        Next  *T  `json:"next"`
+    }
+
+    func (obj *Page[T]) UnmarshalJSON(data []byte) error {
+        fields, err := serde.ObjectFromJSON(data)
+        if err != nil {
+            return err
+        }
+        if field, ok := fields["items"]; ok {
+            if obj.Items, err = serde.SeqFromJSON(field, serde.FromJSON[T]); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("items")
+        }
+        if field, ok := fields["next"]; ok {
+            if obj.Next, err = serde.OptionFromJSON(field, serde.FromJSON[T]); err != nil {
+                return err
+            }
+        }
+        return nil
+    }
+
+    type Response struct {
+        Page     Page[string]                  `json:"page"`
+        Outcome  Outcome[Page[uint32], string] `json:"outcome"`
+        Fallback Outcome[bool, int32]          `json:"fallback"`
+    }
+
+    func (obj *Response) UnmarshalJSON(data []byte) error {
+        fields, err := serde.ObjectFromJSON(data)
+        if err != nil {
+            return err
+        }
+        if field, ok := fields["page"]; ok {
+            if obj.Page, err = serde.FromJSON[Page[string]](field); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("page")
+        }
+        if field, ok := fields["outcome"]; ok {
+            if obj.Outcome, err = OutcomeFromJSON[Page[uint32], string](field); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("outcome")
+        }
+        if field, ok := fields["fallback"]; ok {
+            if obj.Fallback, err = OutcomeFromJSON[bool, int32](field); err != nil {
+                return err
+            }
+        } else {
+            return serde.MissingFieldError("fallback")
+        }
+        return nil
+    }
+    "#);
+}
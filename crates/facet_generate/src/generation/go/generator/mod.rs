@@ -0,0 +1,220 @@
+//! Top-level orchestrator for Go code generation.
+//!
+//! [`GoCodeGenerator`] implements [`CodeGenerator`] and is the entry point for
+//! producing a single Go package from a [`Registry`]. It delegates writing to
+//! the emitter layer.
+
+use std::{
+    collections::BTreeSet,
+    io::{Result, Write},
+    sync::Arc,
+};
+
+use crate::{
+    Registry,
+    generation::{
+        CodeGenerator, CodeGeneratorConfig, Container, Emitter, Error,
+        go::emitter::Go,
+        indent::IndentedWriter,
+        module::{self, Module},
+        plugin::{self, EmitterPlugin},
+    },
+    reflection::format::{Format, FormatHolder, Language, Namespace, QualifiedTypeName},
+};
+
+/// The largest tuple that the serde runtime has a type for (`serde.Tuple6`).
+const MAX_TUPLE_SIZE: usize = 6;
+
+/// Main configuration object for Go code generation.
+///
+/// Wraps a [`CodeGeneratorConfig`] and implements [`CodeGenerator`] so it
+/// can be used by the installer pipeline.
+pub struct GoCodeGenerator<'a> {
+    /// Language-independent configuration.
+    pub(crate) config: &'a CodeGeneratorConfig,
+    /// Plugins that control encoding-specific code generation.
+    pub(crate) plugins: Vec<Arc<dyn EmitterPlugin<Go>>>,
+    /// The path of the Go module that the package belongs to.
+    pub(crate) module_path: String,
+}
+
+impl<'a> CodeGenerator<'a> for GoCodeGenerator<'a> {
+    fn new(config: &'a CodeGeneratorConfig) -> Self {
+        Self::new(config)
+    }
+
+    fn write_output<W: Write>(&mut self, writer: &mut W, registry: &Registry) -> Result<()> {
+        self.output(writer, registry)
+    }
+}
+
+impl<'a> GoCodeGenerator<'a> {
+    /// Create a Go code generator with no plugins (plain types only).
+    ///
+    /// The package belongs to a Go module whose path is the config's module
+    /// name. Call [`with_module_path`](Self::with_module_path) to change it,
+    /// and [`with_plugins`](Self::with_plugins) to enable serialization.
+    #[must_use]
+    pub fn new(config: &'a CodeGeneratorConfig) -> Self {
+        Self {
+            config,
+            plugins: vec![],
+            module_path: config.module_name().to_string(),
+        }
+    }
+
+    /// Set pre-built plugins, returning the modified generator.
+    #[must_use]
+    pub fn with_plugins(mut self, plugins: Vec<Arc<dyn EmitterPlugin<Go>>>) -> Self {
+        self.plugins = plugins;
+        self
+    }
+
+    /// Set the path of the Go module that the package belongs to (e.g.
+    /// `github.com/example/types`), which the runtime packages are imported
+    /// from, returning the modified generator.
+    #[must_use]
+    pub fn with_module_path(mut self, module_path: &str) -> Self {
+        self.module_path = module_path.to_string();
+        self
+    }
+
+    /// Produce a complete Go package for the types in `registry`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `out` fails, or if a plugin or the
+    /// serde runtime can't encode a type of the registry.
+    pub fn output(&self, out: &mut impl Write, registry: &Registry) -> Result<()> {
+        let checked = module::for_plugin_checks(registry, Language::Go);
+        let registry = &module::for_language(registry, Language::Go);
+        check_registry(registry).map_err(std::io::Error::other)?;
+        plugin::check_registry(&self.plugins, &checked).map_err(std::io::Error::other)?;
+
+        let mut config = self.config.clone();
+        config.update_from(registry);
+
+        let mut lang = Go::new(&config, registry).with_module_path(&self.module_path);
+        for p in &self.plugins {
+            lang = lang.with_plugin(p.clone());
+        }
+
+        // Go rejects unused imports, so the declarations are written first to
+        // find the packages that they use.
+        let mut body = vec![];
+        {
+            let w = &mut IndentedWriter::new(&mut body, config.indent);
+            let updated_registry = Self::update_qualified_names(&config, registry);
+            for (i, container) in updated_registry.iter().map(Container::from).enumerate() {
+                if i > 0 {
+                    writeln!(w)?;
+                }
+                container.write(w, &lang)?;
+            }
+        }
+        let lang = lang.with_used_packages(used_packages(&String::from_utf8_lossy(&body)));
+
+        let w = &mut IndentedWriter::new(&mut *out, config.indent);
+        Module::new(&config).write(w, &lang)?;
+        out.write_all(&body)
+    }
+
+    /// Updates [`QualifiedTypeName`] instances for Go's packages:
+    ///
+    /// 1. **Same-package type** — strip namespace to `Root` so it renders as a
+    ///    bare name (e.g. `Child`).
+    /// 2. **External type in different namespace** — keep its `Named` namespace,
+    ///    which renders as `package.Type` (e.g. `other.Child`) via the
+    ///    package import added by the [`Module`] emitter.
+    fn update_qualified_names(config: &CodeGeneratorConfig, registry: &Registry) -> Registry {
+        let mut updated_registry = registry.clone();
+
+        for container_format in updated_registry.values_mut() {
+            let _ = container_format.visit_mut(&mut |format| {
+                if let Format::TypeName(qualified_name)
+                | Format::Generic {
+                    name: qualified_name,
+                    ..
+                } = format
+                    && let Namespace::Named(namespace) = &qualified_name.namespace
+                    && namespace == config.module_name()
+                {
+                    *qualified_name = QualifiedTypeName::root(qualified_name.name.clone());
+                }
+                Ok(())
+            });
+        }
+
+        updated_registry
+    }
+}
+
+/// Checks that the serde runtime has a type for each tuple of the registry.
+///
+/// # Errors
+///
+/// Returns [`Error::Unsupported`] for the first type with a tuple of more
+/// than six elements.
+pub(crate) fn check_registry(registry: &Registry) -> std::result::Result<(), Error> {
+    for (name, container) in registry {
+        let mut size = None;
+        let _ = container.visit(&mut |format| {
+            if let Format::Tuple(formats) = format
+                && formats.len() > MAX_TUPLE_SIZE
+            {
+                size = Some(formats.len());
+            }
+            Ok(())
+        });
+        if let Some(size) = size {
+            return Err(Error::Unsupported {
+                plugin: "go".to_string(),
+                type_name: name.format(ToString::to_string, "."),
+                reason: format!(
+                    "a tuple of {size} elements is larger than the {MAX_TUPLE_SIZE} that the serde runtime supports"
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// The identifiers that Go code qualifies with a package, e.g. `serde` for
+/// `serde.Unit`: identifiers followed by a `.` that aren't themselves a field
+/// or method, outside of comments and string literals.
+fn used_packages(code: &str) -> BTreeSet<String> {
+    let mut used = BTreeSet::new();
+    for line in code.lines() {
+        let mut chars = line.chars().peekable();
+        let mut ident = String::new();
+        let mut after_dot = false;
+        while let Some(c) = chars.next() {
+            match c {
+                '/' if chars.peek() == Some(&'/') => break,
+                '"' | '`' => {
+                    while let Some(s) = chars.next() {
+                        if s == '\\' && c == '"' {
+                            chars.next();
+                        } else if s == c {
+                            break;
+                        }
+                    }
+                    ident.clear();
+                    after_dot = false;
+                }
+                c if c.is_alphanumeric() || c == '_' => ident.push(c),
+                _ => {
+                    if c == '.' && !ident.is_empty() && !after_dot {
+                        used.insert(std::mem::take(&mut ident));
+                    }
+                    after_dot = c == '.';
+                    ident.clear();
+                }
+            }
+        }
+    }
+    used
+}
+
+#[cfg(test)]
+mod tests;
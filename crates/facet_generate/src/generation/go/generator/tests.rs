@@ -0,0 +1,315 @@
+//! Unit tests for [`GoCodeGenerator`] — import generation, qualified-name
+//! resolution and per-language filtering.
+//!
+//! Tests build small [`Registry`](crate::Registry) values by hand (rather than
+//! via the `reflect!` macro) so that module and external-package configurations
+//! can be controlled precisely.
+//!
+//! # Coverage
+//!
+//! | Area | What is tested |
+//! |------|----------------|
+//! | Same-package stripping | `Named` namespace matching the module name → bare name, also for generics |
+//! | Other namespaces | `Named` namespace for a different module → preserved as `package.Type` |
+//! | Import generation | Packages of the same module (`root/other`) and of external modules (`github.com/acme/shared`) |
+//! | Plugin imports | Only the packages that the declarations use, the standard library first, like `gofmt` |
+//! | Filtering | Variants skipped for Go keep their bincode indices |
+//! | Rejection | Registries the serde runtime or the plugins can't encode |
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use facet::Facet;
+
+use super::*;
+use crate::{
+    self as fg,
+    generation::{
+        CodeGeneratorConfig,
+        bincode::BincodePlugin,
+        config::{ExternalPackage, PackageLocation},
+        json::JsonPlugin,
+        plugin::EmitterPlugin,
+    },
+    reflect,
+    reflection::format::{
+        ContainerFormat, ContainerMetadata, Doc, FieldMetadata, Format, Named, Namespace,
+        QualifiedTypeName,
+    },
+};
+
+fn registry_with_struct_field(field_type: Format) -> Registry {
+    let mut registry = Registry::new();
+    let fields = vec![Named {
+        name: "value".to_string(),
+        doc: Doc::new(),
+        value: field_type,
+        metadata: FieldMetadata::default(),
+    }];
+    registry.insert(
+        QualifiedTypeName::root("Holder".to_string()),
+        ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default()),
+    );
+    registry
+}
+
+fn first_field_type(registry: &Registry) -> &Format {
+    let (_, container) = registry.iter().next().unwrap();
+    let ContainerFormat::Struct(fields, _, _) = container else {
+        panic!("expected struct container");
+    };
+    &fields[0].value
+}
+
+fn render_output(
+    config: &CodeGeneratorConfig,
+    plugins: Vec<Arc<dyn EmitterPlugin<Go>>>,
+    registry: &Registry,
+) -> String {
+    let generator = GoCodeGenerator::new(config).with_plugins(plugins);
+    let mut output = Vec::new();
+    generator.output(&mut output, registry).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn update_qualified_names_strips_same_module_namespace() {
+    let config = CodeGeneratorConfig::new("root".to_string());
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "root".to_string(),
+        "Child".to_string(),
+    )));
+
+    let updated = GoCodeGenerator::update_qualified_names(&config, &registry);
+
+    let Format::TypeName(type_name) = first_field_type(&updated) else {
+        panic!("expected type name");
+    };
+    assert_eq!(type_name.namespace, Namespace::Root);
+    assert_eq!(type_name.name, "Child");
+}
+
+#[test]
+fn update_qualified_names_strips_same_module_generic() {
+    let config = CodeGeneratorConfig::new("root".to_string());
+    let registry = registry_with_struct_field(Format::Generic {
+        name: QualifiedTypeName::namespaced("root".to_string(), "Page".to_string()),
+        args: vec![Format::Str],
+    });
+
+    let updated = GoCodeGenerator::update_qualified_names(&config, &registry);
+
+    let Format::Generic { name, .. } = first_field_type(&updated) else {
+        panic!("expected generic");
+    };
+    assert_eq!(name.namespace, Namespace::Root);
+}
+
+#[test]
+fn update_qualified_names_keeps_other_namespace() {
+    let config = CodeGeneratorConfig::new("root".to_string());
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "other".to_string(),
+        "Child".to_string(),
+    )));
+
+    let updated = GoCodeGenerator::update_qualified_names(&config, &registry);
+
+    let Format::TypeName(type_name) = first_field_type(&updated) else {
+        panic!("expected type name");
+    };
+    assert_eq!(type_name.namespace, Namespace::Named("other".to_string()));
+}
+
+#[test]
+fn output_imports_other_namespace_with_prefix() {
+    let config = CodeGeneratorConfig::new("root".to_string());
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "other".to_string(),
+        "Child".to_string(),
+    )));
+
+    let output = render_output(&config, vec![], &registry);
+    insta::assert_snapshot!(output, @r#"
+    package root
+
+    import "root/other"
+
+    type Holder struct {
+        Value other.Child `json:"value"`
+    }
+    "#);
+}
+
+#[test]
+fn output_imports_external_package() {
+    let mut config = CodeGeneratorConfig::new("root".to_string());
+    config.external_packages = BTreeMap::from([(
+        "other".to_string(),
+        ExternalPackage {
+            for_namespace: "other".to_string(),
+            location: PackageLocation::Url("https://github.com/acme/shared.git".to_string()),
+            module_name: Some("models".to_string()),
+            version: None,
+        },
+    )]);
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "other".to_string(),
+        "Child".to_string(),
+    )));
+
+    let output = render_output(&config, vec![], &registry);
+    insta::assert_snapshot!(output, @r#"
+    package root
+
+    import other "github.com/acme/shared/models"
+
+    type Holder struct {
+        Value other.Child `json:"value"`
+    }
+    "#);
+}
+
+#[test]
+fn output_imports_used_packages_only() {
+    let config = CodeGeneratorConfig::new("root".to_string());
+    let registry = registry_with_struct_field(Format::Bytes);
+
+    let output = render_output(
+        &config,
+        vec![Arc::new(BincodePlugin), Arc::new(JsonPlugin)],
+        &registry,
+    );
+    let header = output
+        .split("\n\n")
+        .take(2)
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    insta::assert_snapshot!(header, @r#"
+    package root
+
+    import (
+        "root/bincode"
+        "root/serde"
+    )
+    "#);
+}
+
+#[test]
+fn output_keeps_indices_of_skipped_variants() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Colour {
+        Red,
+        #[facet(fg::skip(go))]
+        Green,
+        Blue,
+    }
+
+    let registry = reflect!(Colour).unwrap();
+    let config = CodeGeneratorConfig::new("root".to_string());
+
+    let output = render_output(&config, vec![Arc::new(BincodePlugin)], &registry);
+    insta::assert_snapshot!(output, @r#"
+    package root
+
+    import (
+        "root/bincode"
+        "root/serde"
+    )
+
+    type Colour string
+
+    const (
+        ColourRed  Colour = "Red"
+        ColourBlue Colour = "Blue"
+    )
+
+    func (obj *Colour) Serialize(serializer serde.Serializer) error {
+        if err := serializer.IncreaseContainerDepth(); err != nil {
+            return err
+        }
+        var index uint32
+        switch *obj {
+        case ColourRed:
+            index = 0
+        case ColourBlue:
+            index = 2
+        default:
+            return serde.UnknownValueError("Colour", string(*obj))
+        }
+        if err := serializer.SerializeVariantIndex(index); err != nil {
+            return err
+        }
+        serializer.DecreaseContainerDepth()
+        return nil
+    }
+
+    func (obj *Colour) BincodeSerialize() ([]byte, error) {
+        serializer := bincode.NewSerializer()
+        if err := obj.Serialize(serializer); err != nil {
+            return nil, err
+        }
+        return serializer.GetBytes(), nil
+    }
+
+    func DeserializeColour(deserializer serde.Deserializer) (Colour, error) {
+        var obj Colour
+        if err := deserializer.IncreaseContainerDepth(); err != nil {
+            return obj, err
+        }
+        index, err := deserializer.DeserializeVariantIndex()
+        if err != nil {
+            return obj, err
+        }
+        switch index {
+        case 0:
+            obj = ColourRed
+        case 2:
+            obj = ColourBlue
+        default:
+            return obj, serde.UnknownVariantError("Colour", index)
+        }
+        deserializer.DecreaseContainerDepth()
+        return obj, nil
+    }
+
+    func BincodeDeserializeColour(input []byte) (Colour, error) {
+        deserializer := bincode.NewDeserializer(input)
+        obj, err := DeserializeColour(deserializer)
+        if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+            return obj, serde.NewDeserializationError("Some input bytes were not read")
+        }
+        return obj, err
+    }
+    "#);
+}
+
+#[test]
+fn rejects_large_tuples() {
+    let registry = registry_with_struct_field(Format::Tuple(vec![Format::U8; 7]));
+    let config = CodeGeneratorConfig::new("root".to_string());
+
+    let generator = GoCodeGenerator::new(&config);
+    let error = generator.output(&mut Vec::new(), &registry).unwrap_err();
+    insta::assert_snapshot!(error, @"go can't encode `Holder`: a tuple of 7 elements is larger than the 6 that the serde runtime supports");
+}
+
+#[test]
+fn bincode_rejects_untagged_enums() {
+    #[derive(Facet)]
+    #[facet(untagged)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Value {
+        Number(f64),
+        Text(String),
+    }
+
+    let registry = reflect!(Value).unwrap();
+    let config = CodeGeneratorConfig::new("root".to_string());
+
+    let generator = GoCodeGenerator::new(&config).with_plugins(vec![Arc::new(BincodePlugin)]);
+    let error = generator.output(&mut Vec::new(), &registry).unwrap_err();
+    insta::assert_snapshot!(error, @"bincode can't encode `Value`: untagged enums don't write the variant index bincode needs");
+}
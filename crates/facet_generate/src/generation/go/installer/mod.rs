@@ -0,0 +1,321 @@
+//! Project scaffolding — writes a Go module to disk.
+//!
+//! The [`Installer`] is the final stage of the Go generation pipeline.
+//! While [`GoCodeGenerator`] produces the *contents* of a single package,
+//! the installer is responsible for the surrounding module structure:
+//!
+//! 1. **Runtime packages** — copies the serde and/or bincode runtime packages
+//!    into `serde/` and `bincode/`, rewriting their imports of each other to
+//!    the module's path.
+//!
+//! 2. **Per-namespace packages** — splits the registry by namespace (via
+//!    [`module::split`]) and calls [`GoCodeGenerator`] once per namespace,
+//!    writing the root namespace to the module's own package and each other
+//!    to `<namespace>/<namespace>.go`. Cross-package type references import
+//!    the other package and use `other.Type`.
+//!
+//! 3. **`go.mod`** — generates a manifest with the module's path, the Go
+//!    version and the external packages it requires (with a `replace`
+//!    directive for those at a local path).
+
+use std::{
+    collections::BTreeSet,
+    fs::{File, create_dir_all},
+    io::Write as _,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::{
+    Registry,
+    generation::{
+        CodeGeneratorConfig, Error, ExternalPackage, ExternalPackages, PackageLocation,
+        SERDE_NAMESPACE, SourceInstaller,
+        bincode::BincodePlugin,
+        go::{Go, GoCodeGenerator, generator, package_name},
+        indent::IndentConfig,
+        json::JsonPlugin,
+        module,
+        plugin::{self, EmitterPlugin},
+    },
+    reflection::format::Language,
+};
+
+/// The Go version that generated modules need: the runtime uses generics and
+/// the `min` builtin.
+const GO_VERSION: &str = "1.21";
+
+/// The path that the runtime's packages import each other by, which the
+/// installer replaces with the path they're installed at.
+const RUNTIME_MODULE_PATH: &str = "github.com/redbadger/facet-generate/runtime/go";
+
+/// Installer for generated source files in Go.
+///
+/// # Examples
+///
+/// ```rust
+/// use facet_generate::generation::go;
+///
+/// let output_dir = std::path::PathBuf::from("output");
+/// let installer = go::Installer::new("github.com/example/types", &output_dir);
+/// ```
+pub struct Installer {
+    module_path: String,
+    install_dir: PathBuf,
+    external_packages: ExternalPackages,
+    indent: IndentConfig,
+    plugins: Vec<Arc<dyn EmitterPlugin<Go>>>,
+    /// The names of all the registry's enums, unit-variant enums and branded
+    /// types, across namespaces: how a type is encoded depends on its kind,
+    /// which the package that refers to it can't see.
+    type_names: Option<CodeGeneratorConfig>,
+}
+
+impl Installer {
+    /// Create a new installer for the given module path and output directory.
+    ///
+    /// The module path is the Go module's path (e.g.
+    /// `github.com/example/types`), the last element of which names the root
+    /// package. Use the builder methods [`plugin`](Self::plugin) and
+    /// [`external_packages`](Self::external_packages) to configure, then call
+    /// [`generate`](Self::generate) to produce the output.
+    #[must_use]
+    pub fn new(module_path: &str, install_dir: impl AsRef<Path>) -> Self {
+        Self {
+            module_path: module_path.to_string(),
+            install_dir: install_dir.as_ref().to_path_buf(),
+            external_packages: ExternalPackages::new(),
+            indent: IndentConfig::Tab,
+            plugins: vec![],
+            type_names: None,
+        }
+    }
+
+    /// Add a plugin to be used during code generation.
+    ///
+    /// When multiple plugins are added, they are invoked in the order they were registered.
+    #[must_use]
+    pub fn plugin<P: EmitterPlugin<Go> + 'static>(mut self, plugin: P) -> Self {
+        self.plugins.push(Arc::new(plugin));
+        self
+    }
+
+    /// Set external packages to reference.
+    #[must_use]
+    pub fn external_packages(mut self, packages: &[ExternalPackage]) -> Self {
+        self.external_packages = packages
+            .iter()
+            .map(|d| (d.for_namespace.clone(), d.clone()))
+            .collect();
+        self
+    }
+
+    /// Set the indentation of generated source files (tabs by default, as
+    /// `gofmt` writes).
+    #[must_use]
+    pub const fn indent(mut self, indent: IndentConfig) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Generate all code for the given registry.
+    ///
+    /// This method:
+    /// 1. Installs the runtimes of the configured plugins
+    /// 2. Splits the registry by namespace and installs each package
+    /// 3. Writes the module's `go.mod`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any file operation or code generation step fails.
+    pub fn generate(mut self, registry: &Registry) -> Result<(), Error> {
+        let checked = module::for_plugin_checks(registry, Language::Go);
+        let registry = &module::for_language(registry, Language::Go);
+        generator::check_registry(registry)?;
+        plugin::check_registry(&self.plugins, &checked)?;
+
+        if !self.external_packages.contains_key(SERDE_NAMESPACE) {
+            let mut written = BTreeSet::new();
+            for plugin in &self.plugins {
+                for file in plugin.runtime_files() {
+                    if written.insert(file.relative_path.clone()) {
+                        self.install_runtime_file(&file.relative_path, &file.contents)?;
+                    }
+                }
+            }
+        }
+
+        let mut type_names = CodeGeneratorConfig::new(self.module_path.clone());
+        type_names.update_from(registry);
+        self.type_names = Some(type_names);
+
+        for (m, module_registry) in &module::split(&self.module_path, registry) {
+            let config = m.config().clone().with_indent(self.indent);
+            self.install_module(&config, module_registry)?;
+        }
+
+        let module_path = self.module_path.clone();
+        self.install_manifest(&module_path)?;
+
+        Ok(())
+    }
+
+    /// Installs the serde Go runtime into `serde/`.
+    ///
+    /// Most callers should prefer [`generate`](Self::generate).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any file I/O fails.
+    pub fn install_serde_runtime(&self) -> Result<(), Error> {
+        for file in EmitterPlugin::<Go>::runtime_files(&JsonPlugin) {
+            self.install_runtime_file(&file.relative_path, &file.contents)?;
+        }
+        Ok(())
+    }
+
+    /// Installs the bincode Go runtime into `bincode/`.
+    ///
+    /// It needs the serde runtime too. Most callers should prefer
+    /// [`generate`](Self::generate).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any file I/O fails.
+    pub fn install_bincode_runtime(&self) -> Result<(), Error> {
+        for file in EmitterPlugin::<Go>::runtime_files(&BincodePlugin)
+            .into_iter()
+            .filter(|f| f.relative_path.starts_with("bincode/"))
+        {
+            self.install_runtime_file(&file.relative_path, &file.contents)?;
+        }
+        Ok(())
+    }
+
+    /// Writes a runtime file, whose imports of the other runtime packages are
+    /// rewritten to the module's path.
+    fn install_runtime_file(&self, relative_path: &str, contents: &[u8]) -> Result<(), Error> {
+        let dest = self.install_dir.join(relative_path);
+        if let Some(parent) = dest.parent() {
+            create_dir_all(parent)?;
+        }
+        let contents = std::str::from_utf8(contents)?.replace(
+            &format!("\"{RUNTIME_MODULE_PATH}/{SERDE_NAMESPACE}\""),
+            &format!("\"{}/{SERDE_NAMESPACE}\"", self.module_path),
+        );
+        std::fs::write(&dest, contents)?;
+        Ok(())
+    }
+
+    /// Produce the contents of a `go.mod` manifest.
+    ///
+    /// External packages are required at their version, or at `v0.0.0` if
+    /// they don't have one: `Url` locations are required by the module path
+    /// of their URL, and `Path` locations by their namespace, which a
+    /// `replace` directive points at the path.
+    #[must_use]
+    pub fn make_manifest(&self, module_path: &str) -> String {
+        let mut requires = vec![];
+        let mut replaces = vec![];
+        for package in self.external_packages.values() {
+            let path = self::module_path(package);
+            let version = package.version.as_deref().unwrap_or("v0.0.0");
+            requires.push(format!("{path} {version}"));
+            if let PackageLocation::Path(location) = &package.location {
+                replaces.push(format!("{path} => {location}"));
+            }
+        }
+
+        let mut manifest = format!("module {module_path}\n\ngo {GO_VERSION}\n");
+        for (directive, lines) in [("require", requires), ("replace", replaces)] {
+            match lines.as_slice() {
+                [] => {}
+                [line] => manifest.push_str(&format!("\n{directive} {line}\n")),
+                lines => {
+                    manifest.push_str(&format!("\n{directive} (\n"));
+                    for line in lines {
+                        manifest.push_str(&format!("\t{line}\n"));
+                    }
+                    manifest.push_str(")\n");
+                }
+            }
+        }
+
+        manifest
+    }
+}
+
+/// The path of the Go module of an external package: its URL without the
+/// scheme or a `.git` suffix (e.g. `github.com/acme/shared` for
+/// `https://github.com/acme/shared.git`), or the namespace it's for if it's
+/// at a local path.
+#[must_use]
+pub(crate) fn module_path(package: &ExternalPackage) -> String {
+    match &package.location {
+        PackageLocation::Url(url) => {
+            let url = url.strip_prefix("git+").unwrap_or(url);
+            let url = url.split_once("://").map_or(url, |(_, rest)| rest);
+            let url = url.trim_end_matches('/');
+            url.strip_suffix(".git").unwrap_or(url).to_string()
+        }
+        PackageLocation::Path(_) => package.for_namespace.clone(),
+    }
+}
+
+impl SourceInstaller for Installer {
+    /// Generate a single package for one namespace.
+    ///
+    /// The root namespace is written to `<package>.go` in the module's
+    /// directory, and any other to `<namespace>/<namespace>.go`. Namespaces
+    /// that correspond to external packages are skipped — their types are
+    /// imported rather than generated.
+    fn install_module(
+        &mut self,
+        config: &CodeGeneratorConfig,
+        registry: &Registry,
+    ) -> Result<(), Error> {
+        if self.external_packages.contains_key(config.module_name()) {
+            return Ok(());
+        }
+        let package = package_name(config.module_name());
+        let dir = if config.module_name() == self.module_path {
+            self.install_dir.clone()
+        } else {
+            self.install_dir.join(&package)
+        };
+        create_dir_all(&dir)?;
+        let mut file = File::create(dir.join(format!("{package}.go")))?;
+
+        let mut updated_config = config.clone();
+        updated_config.external_packages = self.external_packages.clone();
+        if let Some(type_names) = &self.type_names {
+            updated_config
+                .enum_type_names
+                .extend(type_names.enum_type_names.iter().cloned());
+            updated_config
+                .unit_variant_enums
+                .extend(type_names.unit_variant_enums.iter().cloned());
+            updated_config
+                .branded_type_names
+                .extend(type_names.branded_type_names.iter().cloned());
+        }
+
+        let generator = GoCodeGenerator::new(&updated_config)
+            .with_module_path(&self.module_path)
+            .with_plugins(self.plugins.clone());
+        generator.output(&mut file, registry)?;
+
+        Ok(())
+    }
+
+    /// Write `go.mod` to the output directory.
+    fn install_manifest(&self, module_path: &str) -> Result<(), Error> {
+        create_dir_all(&self.install_dir)?;
+        let mut file = File::create(self.install_dir.join("go.mod"))?;
+        file.write_all(self.make_manifest(module_path).as_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
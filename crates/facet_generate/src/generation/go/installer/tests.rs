@@ -0,0 +1,205 @@
+//! Tests for the Go [`Installer`] — **module scaffolding**.
+//!
+//! These tests verify the `go.mod` manifest that the installer generates, and
+//! the layout of the module it writes:
+//!
+//! - Basic manifest structure: module path and Go version, no requirements.
+//! - External packages: required by the module path of their URL, or by their
+//!   namespace with a `replace` directive for a local path.
+//! - Installed files: the root package in the module's directory and one
+//!   package per other namespace, with the runtime packages of the configured
+//!   plugins next to them.
+//! - Runtime packages, which import each other by the module's path.
+//! - Registries the plugins can't encode, such as generic enums as type
+//!   arguments.
+
+use facet::Facet;
+
+use crate::generation::{
+    ExternalPackage, PackageLocation, bincode::BincodePlugin, go::Installer, json::JsonPlugin,
+};
+use crate::reflect;
+
+#[test]
+fn make_manifest_basic() {
+    let installer = Installer::new("github.com/example/types", "/tmp");
+    let manifest = installer.make_manifest("github.com/example/types");
+
+    insta::assert_snapshot!(manifest, @"
+    module github.com/example/types
+
+    go 1.21
+    ");
+}
+
+#[test]
+fn make_manifest_with_external_packages() {
+    let external_packages = vec![
+        ExternalPackage {
+            for_namespace: "local_types".to_string(),
+            module_name: None,
+            location: PackageLocation::Path("../local_types".to_string()),
+            version: None,
+        },
+        ExternalPackage {
+            for_namespace: "remote_types".to_string(),
+            module_name: None,
+            location: PackageLocation::Url(
+                "https://github.com/example/remote_types.git".to_string(),
+            ),
+            version: Some("v2.0.0".to_string()),
+        },
+    ];
+
+    let installer =
+        Installer::new("github.com/example/types", "/tmp").external_packages(&external_packages);
+    let manifest = installer.make_manifest("github.com/example/types");
+
+    insta::assert_snapshot!(manifest, @"
+    module github.com/example/types
+
+    go 1.21
+
+    require (
+    	local_types v0.0.0
+    	github.com/example/remote_types v2.0.0
+    )
+
+    replace local_types => ../local_types
+    ");
+}
+
+#[test]
+fn generate_writes_module_layout() {
+    #[derive(Facet)]
+    struct Child {
+        value: i32,
+    }
+
+    mod other {
+        use crate as fg;
+        use facet::Facet;
+
+        #[derive(Facet)]
+        #[facet(fg::namespace = "other")]
+        pub struct Child {
+            value: String,
+        }
+    }
+
+    #[derive(Facet)]
+    struct Parent {
+        child: Child,
+        other_child: other::Child,
+    }
+
+    let registry = reflect!(Parent).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    Installer::new("github.com/example/types", install_dir.path())
+        .plugin(BincodePlugin)
+        .plugin(JsonPlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let mut files = ignore::Walk::new(install_dir.path())
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_file())
+        .map(|entry| {
+            entry
+                .path()
+                .strip_prefix(install_dir.path())
+                .unwrap()
+                .display()
+                .to_string()
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    insta::assert_snapshot!(files.join("\n"), @"
+    bincode/bincode.go
+    go.mod
+    other/other.go
+    serde/generic.go
+    serde/json.go
+    serde/serde.go
+    serde/tuples.go
+    serde/types.go
+    types.go
+    ");
+}
+
+#[test]
+fn generate_rewrites_runtime_imports() {
+    #[derive(Facet)]
+    struct Child {
+        value: i32,
+    }
+
+    let registry = reflect!(Child).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    Installer::new("github.com/example/types", install_dir.path())
+        .plugin(BincodePlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let runtime = std::fs::read_to_string(install_dir.path().join("bincode/bincode.go")).unwrap();
+    let imports = runtime
+        .lines()
+        .filter(|line| line.contains("/serde\""))
+        .collect::<Vec<_>>();
+    insta::assert_snapshot!(imports.join("\n"), @r#"	"github.com/example/types/serde""#);
+}
+
+#[test]
+fn json_rejects_internally_tagged_tuple_variants() {
+    #[derive(Facet)]
+    #[facet(tag = "type")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point(i32, i32),
+        Empty,
+    }
+
+    let registry = reflect!(Shape).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    let installer =
+        Installer::new("github.com/example/types", install_dir.path()).plugin(JsonPlugin);
+
+    let error = installer.generate(&registry).unwrap_err();
+    insta::assert_snapshot!(error, @"json (Go) can't encode `Shape`: internally tagged enums can't have tuple variants");
+}
+
+#[test]
+fn json_rejects_generic_enum_type_arguments() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T> {
+        Success(T),
+        Failure(String),
+    }
+
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        page: Page<Outcome<u32>>,
+        names: Page<String>,
+        outcome: Outcome<bool>,
+    }
+
+    let registry = reflect!(Response).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    let installer =
+        Installer::new("github.com/example/types", install_dir.path()).plugin(JsonPlugin);
+
+    let error = installer.generate(&registry).unwrap_err();
+    insta::assert_snapshot!(error, @"json (Go) can't encode `Response`: the type argument `Outcome[uint32]` is a generic enum whose variants carry data, which generic types can't read");
+}
@@ -0,0 +1,39 @@
+//! Go code generation.
+//!
+//! This module translates a [`Registry`](crate::Registry) of reflected type
+//! definitions into Go source code, for backend services that exchange data
+//! with the Rust core.
+//!
+//! # Submodules (in pipeline order)
+//!
+//! 1. **generator** — Top-level orchestrator. [`GoCodeGenerator`](crate::generation::go::GoCodeGenerator) implements
+//!    [`CodeGenerator`](crate::generation::CodeGenerator) to produce a complete Go package
+//!    from a registry. It resolves qualified type names against the
+//!    configuration (external packages, namespaces), and delegates writing to the emitter layer.
+//!
+//! 2. **emitter** — AST-to-source rendering. Implements
+//!    [`Emitter<Go>`](crate::generation::Emitter) for each AST node type
+//!    ([`Module`](crate::generation::module::Module), [`Container`](crate::generation::Container),
+//!    `Named<Format>`, `Format`, `Doc`). This is where the Go language
+//!    mapping lives: structs with `json` tags, `string` constants for all-unit
+//!    enums, and interfaces with an `isShape()` marker method, implemented by
+//!    a struct per variant, for enums whose variants carry data.
+//!
+//! 3. **installer** — Project scaffolding. [`Installer`](crate::generation::go::Installer) implements
+//!    [`SourceInstaller`](crate::generation::SourceInstaller) to write a Go
+//!    module: it copies the serde/bincode runtime packages into it, splits the
+//!    registry by namespace into one package per namespace, and generates a
+//!    `go.mod` manifest.
+
+pub use emitter::Go;
+pub use generator::GoCodeGenerator;
+pub use installer::Installer;
+
+pub(crate) use emitter::{
+    field_name, field_type, is_defined_type, is_union, is_union_format, package_name, qualified,
+    string_literal, type_args, type_expr, type_params_decl, variant_fields, variant_type,
+};
+
+mod emitter;
+mod generator;
+mod installer;
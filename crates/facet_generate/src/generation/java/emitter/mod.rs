@@ -0,0 +1,852 @@
+//! AST-to-Java source rendering.
+//!
+//! This module implements [`Emitter<Java>`](super::super::Emitter) for each
+//! node type in the format AST, turning abstract type descriptions into Java
+//! declarations.
+//!
+//! # Emitter implementations
+//!
+//! | AST node | Java output |
+//! |---|---|
+//! | [`Module`] | `package` declaration and `import`s |
+//! | [`Container`] | `record`, `enum`, or `sealed interface` with a nested `record` per variant |
+//! | [`Named<Format>`](Named) | A single record component |
+//! | [`Format`] | An inline type expression (`int`, `java.util.List<java.lang.Long>`, …) |
+//! | [`Doc`] | `/** */` Javadoc comments |
+//!
+//! # Java type mapping
+//!
+//! The [`Format`] emitter maps Rust/reflection types to Java equivalents — for
+//! example `u32` → `int`, `Seq(T)` → `java.util.List<T>`, `Option(T)` →
+//! `java.util.Optional<T>`, `Map` → `java.util.Map<K, V>`, `Timestamp` →
+//! `java.time.Instant`, and the types of the serde runtime for the rest: `()`
+//! → `com.novi.serde.Unit`, `Bytes` → `com.novi.serde.Bytes` and tuples →
+//! `com.novi.serde.Tuple2<A, B>`. Unsigned integers are held in the signed
+//! primitive of the same width, and 128-bit integers in
+//! `java.math.BigInteger`s. Primitives are boxed when they're type arguments.
+//!
+//! Types are written with their fully qualified names, so that the generated
+//! types can have any name without clashing with an import.
+//!
+//! # Declarations
+//!
+//! Structs become records; newtype and tuple structs have `value` and
+//! `field0`, `field1`, … components. Enums whose variants are all unit
+//! variants become Java `enum`s, with a constant per variant. Other enums
+//! become a sealed interface with a nested record per variant (e.g.
+//! `Shape.Circle`) that implements it, so that a `switch` over its variants is
+//! exhaustive. Serialization proxies are not declared: uses of the type have
+//! already been replaced by the proxy's format.
+//!
+//! # Plugin-dependent output
+//!
+//! The [`Java`] language tag's [`EmitterPlugin`]s write their methods into
+//! the body of each type, including each variant record. The
+//! `type_body_preamble` of an enum's interface declares the methods that its
+//! variants implement, and its `type_body` the static methods that read any of
+//! them.
+//!
+//! - `BincodePlugin` supplies `serialize` / `bincodeSerialize` methods and
+//!   static `deserialize` / `bincodeDeserialize` methods.
+//! - `JsonPlugin` supplies `toJson` / `jsonSerialize` methods and static
+//!   `fromJson` / `jsonDeserialize` methods.
+//! - With no plugins, only plain type declarations are emitted.
+
+use std::{
+    collections::BTreeSet,
+    io::{Result, Write as _},
+    sync::Arc,
+};
+
+use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToUpperCamelCase};
+
+use crate::{
+    Registry,
+    generation::{
+        CodeGeneratorConfig, Container, Emitter,
+        indent::IndentWrite,
+        module::Module,
+        plugin::{
+            EmitContext, EmitterPlugin, VariantInfo, collect_from_plugins, type_annotations,
+            type_conformances,
+        },
+    },
+    reflection::format::{
+        ContainerFormat, Deprecation, Doc, Format, Language, Named, Namespace, QualifiedTypeName,
+        VariantFormat,
+    },
+};
+
+/// The `java.lang` types that generated code refers to by their simple names,
+/// unless a declaration of the same name hides them.
+const JAVA_LANG: &[&str] = &[
+    "Boolean",
+    "Byte",
+    "Deprecated",
+    "Double",
+    "Float",
+    "Integer",
+    "Long",
+    "Object",
+    "Override",
+    "Short",
+    "String",
+];
+
+/// The names that a record component can't have: Java's keywords and
+/// literals, the methods of `Object` that records forbid, and the methods
+/// that the plugins generate without parameters.
+const RESERVED: &[&str] = &[
+    "_",
+    "abstract",
+    "assert",
+    "bincodeSerialize",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "clone",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finalize",
+    "finally",
+    "float",
+    "for",
+    "getClass",
+    "goto",
+    "hashCode",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "jsonSerialize",
+    "long",
+    "native",
+    "new",
+    "notify",
+    "notifyAll",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "toJson",
+    "toString",
+    "transient",
+    "true",
+    "try",
+    "void",
+    "volatile",
+    "wait",
+    "while",
+    "yield",
+];
+
+/// The length up to which a record's components are written on one line.
+const MAX_LINE_LENGTH: usize = 100;
+
+/// Language tag for Java code generation.
+///
+/// Passed as the `L` parameter to every [`Emitter<L>`](super::super::Emitter)
+/// call. Carries a plugin list that controls all encoding-specific behaviour.
+#[derive(Debug, Clone)]
+pub struct Java {
+    pub(crate) config: CodeGeneratorConfig,
+    pub(crate) plugins: Vec<Arc<dyn EmitterPlugin<Self>>>,
+}
+
+impl Java {
+    /// Create a Java language tag with no default plugins, for the package
+    /// named by the config's module name.
+    ///
+    /// Use [`with_plugin`](Self::with_plugin) to attach plugins.
+    #[must_use]
+    pub fn new(config: &CodeGeneratorConfig, _registry: &Registry) -> Self {
+        Self {
+            config: config.clone(),
+            plugins: vec![],
+        }
+    }
+
+    /// Access the generator config.
+    #[must_use]
+    pub const fn config(&self) -> &CodeGeneratorConfig {
+        &self.config
+    }
+
+    /// Add a plugin to this language tag, returning the modified tag.
+    ///
+    /// Plugins are invoked in the order they are added.
+    #[must_use]
+    pub fn with_plugin(mut self, plugin: Arc<dyn EmitterPlugin<Self>>) -> Self {
+        self.plugins.push(plugin);
+        self
+    }
+
+    /// Access the plugin list.
+    #[must_use]
+    pub fn plugins(&self) -> &[Arc<dyn EmitterPlugin<Self>>] {
+        &self.plugins
+    }
+}
+
+impl Emitter<Java> for Module {
+    /// Writes the `package` declaration, and the imports of the plugins if
+    /// they have any.
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Java) -> Result<()> {
+        let config = self.config();
+        writeln!(w, "package {};", config.module_name())?;
+        let imports = collect_from_plugins(lang.plugins(), |p| p.imports(config))
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        if !imports.is_empty() {
+            writeln!(w)?;
+            for import in imports {
+                writeln!(w, "import {import};")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Emitter<Java> for Container<'_> {
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Java) -> Result<()> {
+        let metadata = self.format.metadata();
+        if metadata.proxy {
+            // Uses of the type have already been replaced by the proxy's format, and Java has no
+            // type aliases.
+            return Ok(());
+        }
+        let scope = Scope::new(self, &lang.config);
+        let ctx = EmitContext::top_level(self, &lang.config);
+        let name = &self.name.name;
+        let fields = ctx.fields();
+        write_doc(w, self.format.doc(), &fields, metadata.deprecated.as_ref())?;
+        if let Some(deprecated) = &metadata.deprecated {
+            writeln!(w, "{}", deprecated_annotation(&scope, deprecated))?;
+        }
+        for annotation in type_annotations(lang.plugins(), &ctx, Language::Java) {
+            writeln!(w, "{annotation}")?;
+        }
+        let conformances = type_conformances(lang.plugins(), &ctx, Language::Java);
+        match self.format {
+            ContainerFormat::Enum(variants, ..) if !is_union_format(self.format) => {
+                let mut body = vec![];
+                {
+                    let mut bw = w.child(&mut body);
+                    write_constants(&mut bw, &scope, variants.values(), has_body(&ctx, lang))?;
+                    write_body(&mut bw, &ctx, lang, true)?;
+                }
+                write_declaration(
+                    w,
+                    &format!("public enum {name}"),
+                    None,
+                    &clause("implements", &conformances),
+                    &body,
+                )
+            }
+            ContainerFormat::Enum(..) => union(w, self, &scope, &ctx, &conformances, lang),
+            _ => {
+                let mut body = vec![];
+                write_body(&mut w.child(&mut body), &ctx, lang, false)?;
+                let params = type_params_decl(&metadata.type_params);
+                write_declaration(
+                    w,
+                    &format!("public record {name}{params}"),
+                    Some(&components(&scope, &fields, &ctx, lang)),
+                    &clause("implements", &conformances),
+                    &body,
+                )
+            }
+        }
+    }
+}
+
+impl Emitter<Java> for Named<Format> {
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Java) -> Result<()> {
+        let scope = Scope::module(&lang.config);
+        write!(w, "{} {}", scope.field_type(self), field_name(&self.name))
+    }
+}
+
+impl Emitter<Java> for Doc {
+    fn write<W: IndentWrite>(&self, w: &mut W, _lang: &Java) -> Result<()> {
+        write_doc(w, self, &[], None)
+    }
+}
+
+impl Emitter<Java> for Format {
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Java) -> Result<()> {
+        write!(w, "{}", Scope::module(&lang.config).type_expr(self))
+    }
+}
+
+/// How the code inside a type refers to other types.
+///
+/// A type's own name, and the names of its variants' records, hide the
+/// `java.lang` types and the package's types of the same names inside it:
+/// those are then referred to by their fully qualified names.
+#[derive(Debug, Clone)]
+pub(crate) struct Scope {
+    package: String,
+    own: String,
+    hidden: BTreeSet<String>,
+}
+
+impl Scope {
+    /// The scope of the code inside `container`, in the package named by the
+    /// config's module name.
+    #[must_use]
+    pub(crate) fn new(container: &Container, config: &CodeGeneratorConfig) -> Self {
+        let mut hidden = BTreeSet::from([container.name.name.clone()]);
+        if let ContainerFormat::Enum(variants, ..) = container.format
+            && is_union_format(container.format)
+        {
+            hidden.extend(
+                variants
+                    .values()
+                    .map(|variant| variant_class(&variant.name)),
+            );
+        }
+        Self {
+            package: config.module_name().to_string(),
+            own: container.name.name.clone(),
+            hidden,
+        }
+    }
+
+    /// The scope of code outside of any type, where no names are hidden.
+    #[must_use]
+    pub(crate) fn module(config: &CodeGeneratorConfig) -> Self {
+        Self {
+            package: config.module_name().to_string(),
+            own: String::new(),
+            hidden: BTreeSet::new(),
+        }
+    }
+
+    /// The name that refers to the `java.lang` type `name`, e.g. `String`, or
+    /// `java.lang.String` where a declaration hides it.
+    #[must_use]
+    pub(crate) fn lang(&self, name: &str) -> String {
+        debug_assert!(JAVA_LANG.contains(&name));
+        if self.hidden.contains(name) {
+            format!("java.lang.{name}")
+        } else {
+            name.to_string()
+        }
+    }
+
+    /// The name of a type as the generated code refers to it, e.g. `Child`,
+    /// or `com.example.other.Child` for a type of another package.
+    #[must_use]
+    pub(crate) fn type_name(&self, name: &QualifiedTypeName) -> String {
+        match &name.namespace {
+            Namespace::Root if name.name != self.own && self.hidden.contains(&name.name) => {
+                format!("{}.{}", self.package, name.name)
+            }
+            _ => name.format(ToString::to_string, "."),
+        }
+    }
+
+    /// The Java type of `format` where primitives can be used, e.g. `int`.
+    #[must_use]
+    pub(crate) fn type_expr(&self, format: &Format) -> String {
+        match format {
+            Format::Bool => "boolean".to_string(),
+            Format::I8 | Format::U8 => "byte".to_string(),
+            Format::I16 | Format::U16 => "short".to_string(),
+            Format::I32 | Format::U32 => "int".to_string(),
+            Format::I64 | Format::U64 => "long".to_string(),
+            Format::F32 => "float".to_string(),
+            Format::F64 => "double".to_string(),
+            Format::Tuple(formats) if formats.len() == 1 => self.type_expr(&formats[0]),
+            format => self.boxed(format),
+        }
+    }
+
+    /// The Java type of `format` as a type argument, with primitives boxed,
+    /// e.g. `java.util.List<Integer>`.
+    #[must_use]
+    pub(crate) fn boxed(&self, format: &Format) -> String {
+        let list = |formats: &[Format]| {
+            formats
+                .iter()
+                .map(|format| self.boxed(format))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match format {
+            Format::Variable(_variable) => unreachable!("placeholders should not get this far"),
+            Format::TypeName(name) => self.type_name(name),
+            Format::Generic { name, args } => format!("{}<{}>", self.type_name(name), list(args)),
+            Format::TypeParameter(name) => name.clone(),
+            Format::Unit => "com.novi.serde.Unit".to_string(),
+            Format::Bool => self.lang("Boolean"),
+            Format::I8 | Format::U8 => self.lang("Byte"),
+            Format::I16 | Format::U16 => self.lang("Short"),
+            Format::I32 | Format::U32 => self.lang("Integer"),
+            Format::I64 | Format::U64 => self.lang("Long"),
+            Format::I128 | Format::U128 => "java.math.BigInteger".to_string(),
+            Format::F32 => self.lang("Float"),
+            Format::F64 => self.lang("Double"),
+            Format::Char | Format::Str => self.lang("String"),
+            Format::Bytes => "com.novi.serde.Bytes".to_string(),
+            Format::Uuid => "java.util.UUID".to_string(),
+            Format::Timestamp => "java.time.Instant".to_string(),
+            Format::Date => "java.time.LocalDate".to_string(),
+            Format::Time => "java.time.LocalTime".to_string(),
+            Format::Duration => "java.time.Duration".to_string(),
+            Format::Option(format) => format!("java.util.Optional<{}>", self.boxed(format)),
+            Format::Seq(format)
+            | Format::TupleArray {
+                content: format, ..
+            } => format!("java.util.List<{}>", self.boxed(format)),
+            Format::Set(format) => format!("java.util.Set<{}>", self.boxed(format)),
+            Format::Map { key, value } => {
+                format!("java.util.Map<{}, {}>", self.boxed(key), self.boxed(value))
+            }
+            Format::Tuple(formats) => match formats.as_slice() {
+                [] => "com.novi.serde.Unit".to_string(),
+                // A single-element tuple is just the element itself
+                [format] => self.boxed(format),
+                formats => format!("com.novi.serde.Tuple{}<{}>", formats.len(), list(formats)),
+            },
+        }
+    }
+
+    /// The Java type of a field: its `fg::override` type for Java if it has
+    /// one.
+    #[must_use]
+    pub(crate) fn field_type(&self, field: &Named<Format>) -> String {
+        match field.metadata.type_override(Language::Java) {
+            Some(type_override) => type_override.ty.clone(),
+            None => self.type_expr(&field.value),
+        }
+    }
+}
+
+/// Emits the sealed interface of an enum whose variants carry data, with a
+/// nested record per variant that implements it.
+///
+/// Variant records take all of the enum's type parameters, so that any of
+/// them is an instance of the interface with the same type arguments.
+fn union<W: IndentWrite>(
+    w: &mut W,
+    container: &Container,
+    scope: &Scope,
+    ctx: &EmitContext,
+    conformances: &[String],
+    lang: &Java,
+) -> Result<()> {
+    let ContainerFormat::Enum(variants, _, _, metadata) = container.format else {
+        unreachable!("only enums are emitted as sealed interfaces")
+    };
+    let name = &container.name.name;
+    let type_params = &metadata.type_params;
+    let params = type_params_decl(type_params);
+
+    let mut sections = vec![];
+    for plugin in lang.plugins() {
+        if plugin.has_type_body(ctx) {
+            let mut preamble = vec![];
+            plugin.type_body_preamble(&mut w.child(&mut preamble), ctx)?;
+            sections.push(preamble);
+        }
+    }
+    for (index, variant) in variants {
+        let fields = variant_fields(&variant.value);
+        let variant_ctx = EmitContext::for_variant(
+            container,
+            &lang.config,
+            VariantInfo {
+                name: &variant.name,
+                index: *index as usize,
+                format: &variant.value,
+                fields: &fields,
+                parent_name: name,
+                parent_type_params: type_params,
+            },
+        );
+        let mut section = vec![];
+        {
+            let mut vw = w.child(&mut section);
+            write_doc(
+                &mut vw,
+                &variant.doc,
+                &fields,
+                variant.metadata.deprecated.as_ref(),
+            )?;
+            if let Some(deprecated) = &variant.metadata.deprecated {
+                writeln!(vw, "{}", deprecated_annotation(scope, deprecated))?;
+            }
+            for annotation in type_annotations(lang.plugins(), &variant_ctx, Language::Java) {
+                writeln!(vw, "{annotation}")?;
+            }
+            let mut implements = vec![format!("{name}{params}")];
+            implements.extend(type_conformances(
+                lang.plugins(),
+                &variant_ctx,
+                Language::Java,
+            ));
+            let mut body = vec![];
+            write_body(&mut vw.child(&mut body), &variant_ctx, lang, false)?;
+            write_declaration(
+                &mut vw,
+                &format!("record {}{params}", variant_class(&variant.name)),
+                Some(&components(scope, &fields, &variant_ctx, lang)),
+                &clause("implements", &implements),
+                &body,
+            )?;
+        }
+        sections.push(section);
+    }
+    {
+        let mut body = vec![];
+        write_body(&mut w.child(&mut body), ctx, lang, false)?;
+        sections.push(body);
+    }
+
+    let body = sections
+        .into_iter()
+        .filter(|section| !section.is_empty())
+        .collect::<Vec<_>>()
+        .join(&b"\n"[..]);
+    write_declaration(
+        w,
+        &format!("public sealed interface {name}{params}"),
+        None,
+        &clause("extends", conformances),
+        &body,
+    )
+}
+
+/// Writes the constants of an enum whose variants are all unit variants,
+/// ending the last one with a `;` if the enum has a body after them.
+fn write_constants<'a, W: IndentWrite>(
+    w: &mut W,
+    scope: &Scope,
+    variants: impl ExactSizeIterator<Item = &'a Named<VariantFormat>>,
+    has_body: bool,
+) -> Result<()> {
+    let count = variants.len();
+    if count == 0 && has_body {
+        writeln!(w, ";")?;
+    }
+    for (i, variant) in variants.enumerate() {
+        write_doc(w, &variant.doc, &[], variant.metadata.deprecated.as_ref())?;
+        if let Some(deprecated) = &variant.metadata.deprecated {
+            writeln!(w, "{}", deprecated_annotation(scope, deprecated))?;
+        }
+        let end = match (i + 1 == count, has_body) {
+            (false, _) => ",",
+            (true, true) => ";",
+            (true, false) => "",
+        };
+        writeln!(w, "{}{end}", enum_constant(&variant.name))?;
+    }
+    Ok(())
+}
+
+/// Whether any plugin writes into the body of the type of `ctx`.
+fn has_body(ctx: &EmitContext, lang: &Java) -> bool {
+    lang.plugins()
+        .iter()
+        .any(|plugin| plugin.has_type_body(ctx))
+}
+
+/// Writes the plugins' `type_body` for `ctx`, each after a blank line, or
+/// only between them if the body starts with them.
+fn write_body<W: IndentWrite>(
+    w: &mut W,
+    ctx: &EmitContext,
+    lang: &Java,
+    after_members: bool,
+) -> Result<()> {
+    let mut first = !after_members;
+    for plugin in lang.plugins() {
+        if !plugin.has_type_body(ctx) {
+            continue;
+        }
+        let mut body = vec![];
+        plugin.type_body(&mut w.child(&mut body), ctx)?;
+        if body.is_empty() {
+            continue;
+        }
+        if !first {
+            writeln!(w)?;
+        }
+        first = false;
+        w.write_all(&body)?;
+    }
+    Ok(())
+}
+
+/// Writes a declaration: its `header` (e.g. `public record Point`), its
+/// record `components` if it has any, on one line if they fit, its
+/// `implements` or `extends` clause, and its `body`, which was written at no
+/// indentation.
+fn write_declaration<W: IndentWrite>(
+    w: &mut W,
+    header: &str,
+    components: Option<&[String]>,
+    clause: &str,
+    body: &[u8],
+) -> Result<()> {
+    match components {
+        None => write!(w, "{header}{clause}")?,
+        Some(components) => {
+            let line = format!("{header}({}){clause}", components.join(", "));
+            if components.is_empty() || line.len() + 2 <= MAX_LINE_LENGTH {
+                write!(w, "{line}")?;
+            } else {
+                writeln!(w, "{header}(")?;
+                w.indent();
+                for (i, component) in components.iter().enumerate() {
+                    let comma = if i + 1 < components.len() { "," } else { "" };
+                    writeln!(w, "{component}{comma}")?;
+                }
+                w.unindent();
+                write!(w, "){clause}")?;
+            }
+        }
+    }
+    if body.is_empty() {
+        return writeln!(w, " {{}}");
+    }
+    writeln!(w, " {{")?;
+    w.indent();
+    w.write_all(body)?;
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// The record components of `fields`, e.g. `@Deprecated int x`.
+fn components(
+    scope: &Scope,
+    fields: &[Named<Format>],
+    ctx: &EmitContext,
+    lang: &Java,
+) -> Vec<String> {
+    fields
+        .iter()
+        .map(|field| {
+            let mut component = String::new();
+            if let Some(deprecated) = &field.metadata.deprecated {
+                component.push_str(&deprecated_annotation(scope, deprecated));
+                component.push(' ');
+            }
+            for annotation in
+                collect_from_plugins(lang.plugins(), |p| p.field_annotations(field, ctx))
+            {
+                component.push_str(&annotation);
+                component.push(' ');
+            }
+            component.push_str(&format!(
+                "{} {}",
+                scope.field_type(field),
+                field_name(&field.name)
+            ));
+            component
+        })
+        .collect()
+}
+
+/// An `implements` or `extends` clause of `types`, or nothing.
+fn clause(keyword: &str, types: &[String]) -> String {
+    if types.is_empty() {
+        String::new()
+    } else {
+        format!(" {keyword} {}", types.join(", "))
+    }
+}
+
+/// The `@Deprecated` annotation, with the version that deprecated it.
+fn deprecated_annotation(scope: &Scope, deprecated: &Deprecation) -> String {
+    let annotation = scope.lang("Deprecated");
+    match &deprecated.since {
+        Some(since) => format!("@{annotation}(since = {})", string_literal(since)),
+        None => format!("@{annotation}"),
+    }
+}
+
+/// Writes a Javadoc comment of `doc`, with a `@param` for each of the record
+/// `components` that have docs, and a `@deprecated` tag with the deprecation
+/// notice if there is one.
+fn write_doc<W: IndentWrite>(
+    w: &mut W,
+    doc: &Doc,
+    components: &[Named<Format>],
+    deprecated: Option<&Deprecation>,
+) -> Result<()> {
+    let mut lines = doc.comments().to_vec();
+    let mut tags = vec![];
+    for component in components {
+        let mut comments = component.doc.comments().iter();
+        if let Some(first) = comments.next() {
+            tags.push(format!("@param {} {first}", field_name(&component.name)));
+            tags.extend(comments.map(|comment| format!("    {comment}")));
+        }
+    }
+    if let Some(message) = deprecated.and_then(Deprecation::message) {
+        tags.push(format!("@deprecated {message}"));
+    }
+    if !lines.is_empty() && !tags.is_empty() {
+        lines.push(String::new());
+    }
+    lines.extend(tags);
+
+    let lines = lines
+        .iter()
+        .map(|line| line.replace("*/", "*&#47;"))
+        .collect::<Vec<_>>();
+    match lines.as_slice() {
+        [] => Ok(()),
+        [line] => writeln!(w, "/** {line} */"),
+        lines => {
+            writeln!(w, "/**")?;
+            for line in lines {
+                if line.trim().is_empty() {
+                    writeln!(w, " *")?;
+                } else {
+                    writeln!(w, " * {line}")?;
+                }
+            }
+            writeln!(w, " */")
+        }
+    }
+}
+
+/// The fields of a variant's record.
+#[must_use]
+pub(crate) fn variant_fields(variant: &VariantFormat) -> Vec<Named<Format>> {
+    match variant {
+        VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+        VariantFormat::Unit => vec![],
+        VariantFormat::NewType(format) => vec![Named::new(format.as_ref(), "value".to_string())],
+        VariantFormat::Tuple(formats) => formats
+            .iter()
+            .enumerate()
+            .map(|(i, format)| Named::new(format, format!("field{i}")))
+            .collect(),
+        VariantFormat::Struct(fields) => fields.clone(),
+    }
+}
+
+/// The name of a record component, e.g. `createdAt` for `created_at`, with a
+/// trailing `_` if it is reserved, e.g. `class_`.
+#[must_use]
+pub(crate) fn field_name(name: &str) -> String {
+    let name = name.to_lower_camel_case();
+    if RESERVED.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+/// The name of a variant's record, e.g. `Circle`.
+#[must_use]
+pub(crate) fn variant_class(variant: &str) -> String {
+    variant.to_upper_camel_case()
+}
+
+/// The name of a unit variant's enum constant, e.g. `DARK_RED`.
+#[must_use]
+pub(crate) fn enum_constant(variant: &str) -> String {
+    variant.to_shouty_snake_case()
+}
+
+/// The type parameter list of a generic declaration, e.g. `<T>`, or nothing.
+#[must_use]
+pub(crate) fn type_params_decl(type_params: &[String]) -> String {
+    if type_params.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", type_params.join(", "))
+    }
+}
+
+/// Whether a container is an enum whose variants carry data.
+#[must_use]
+pub(crate) fn is_union_format(format: &ContainerFormat) -> bool {
+    matches!(
+        format,
+        ContainerFormat::Enum(variants, ..)
+            if variants
+                .values()
+                .any(|variant| !matches!(variant.value, VariantFormat::Unit))
+    )
+}
+
+/// A Java string literal of `text`.
+#[must_use]
+pub(crate) fn string_literal(text: &str) -> String {
+    let mut literal = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '\\' => literal.push_str("\\\\"),
+            '"' => literal.push_str("\\\""),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_control() => literal.push_str(&format!("\\u{:04x}", c as u32)),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// Writes a call of `callee` with `args` (e.g. `return new Point(x, y);` for
+/// `return new Point`, `["x", "y"]` and `;`), with one argument per line if
+/// they don't fit on one.
+pub(crate) fn write_call<W: IndentWrite>(
+    w: &mut W,
+    callee: &str,
+    args: &[String],
+    end: &str,
+) -> Result<()> {
+    let line = format!("{callee}({}){end}", args.join(", "));
+    if args.is_empty() || line.len() + 8 <= MAX_LINE_LENGTH {
+        return writeln!(w, "{line}");
+    }
+    writeln!(w, "{callee}(")?;
+    w.indent();
+    for (i, arg) in args.iter().enumerate() {
+        let comma = if i + 1 < args.len() { "," } else { "" };
+        writeln!(w, "{arg}{comma}")?;
+    }
+    w.unindent();
+    writeln!(w, "){end}")
+}
+
+#[cfg(test)]
+mod tests;
+#[cfg(test)]
+mod tests_bincode;
+#[cfg(test)]
+mod tests_json;
@@ -0,0 +1,377 @@
+//! Snapshot tests for the Java emitter — **no serialization**.
+//!
+//! Each test defines one or more Rust types annotated with `#[derive(Facet)]`,
+//! runs them through the [`emit!`] macro with no plugins, and asserts the
+//! generated Java source against an [`insta`] inline snapshot.
+//!
+//! Because no plugins are configured, the output contains only plain type
+//! declarations (records, enums, and sealed interfaces of variant records)
+//! with no serialization methods.
+//!
+//! # Coverage
+//!
+//! | Category | What is tested |
+//! |----------|----------------|
+//! | Structs | Unit structs, newtype wrappers, tuple structs, structs with primitive and user-defined fields |
+//! | Enums | All-unit enums (`enum`s), data enums (sealed interface + variant records), generic enums |
+//! | Collections | `Vec`, `HashMap`, `BTreeSet`, fixed-size arrays, tuples |
+//! | Optional | `Option<T>` fields (`java.util.Optional`) |
+//! | Declarations | Branded newtypes, proxies, deprecation, keywords, name clashes with `java.lang` |
+//! | Namespaces | Multi-package generation via [`emit_two_modules!`] |
+
+#![allow(clippy::too_many_lines)]
+use std::collections::{BTreeSet, HashMap};
+
+use crate as fg;
+use facet::Facet;
+
+use super::*;
+use crate::{emit, emit_two_modules, generation::java::JavaCodeGenerator};
+
+#[test]
+fn unit_struct() {
+    /// line 1
+    #[derive(Facet)]
+    /// line 2
+    struct UnitStruct;
+
+    let actual = emit!(UnitStruct as Java).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    /**
+     * line 1
+     * line 2
+     */
+    public record UnitStruct() {}
+    ");
+}
+
+#[test]
+fn newtype_struct() {
+    #[derive(Facet)]
+    struct NewType(String);
+
+    let actual = emit!(NewType as Java).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    public record NewType(String value) {}
+    ");
+}
+
+#[test]
+fn tuple_struct() {
+    #[derive(Facet)]
+    struct TupleStruct(String, i32);
+
+    let actual = emit!(TupleStruct as Java).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    public record TupleStruct(String field0, int field1) {}
+    ");
+}
+
+#[test]
+fn struct_with_fields() {
+    #[derive(Facet)]
+    struct Inner {
+        value: u64,
+    }
+
+    /// A struct.
+    #[derive(Facet)]
+    struct Outer {
+        /// The name.
+        name: String,
+        flag: bool,
+        big: i128,
+        ratio: f32,
+        letter: char,
+        nickname: Option<String>,
+        inner: Inner,
+        tags: Vec<String>,
+        counts: HashMap<String, u32>,
+        ids: BTreeSet<u16>,
+        pair: (u8, String),
+        grid: [i16; 3],
+        nothing: (),
+    }
+
+    let actual = emit!(Outer as Java).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    public record Inner(long value) {}
+
+    /**
+     * A struct.
+     *
+     * @param name The name.
+     */
+    public record Outer(
+        String name,
+        boolean flag,
+        java.math.BigInteger big,
+        float ratio,
+        String letter,
+        java.util.Optional<String> nickname,
+        Inner inner,
+        java.util.List<String> tags,
+        java.util.Map<String, Integer> counts,
+        java.util.Set<Short> ids,
+        com.novi.serde.Tuple2<Byte, String> pair,
+        java.util.List<Short> grid,
+        com.novi.serde.Unit nothing
+    ) {}
+    ");
+}
+
+#[test]
+fn keywords() {
+    #[derive(Facet)]
+    struct Keywords {
+        class: String,
+        r#static: bool,
+        hash_code: u32,
+        to_string: String,
+    }
+
+    let actual = emit!(Keywords as Java).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    public record Keywords(String class_, boolean static_, int hashCode_, String toString_) {}
+    ");
+}
+
+#[test]
+fn unit_enum() {
+    /// A colour.
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Colour {
+        /// The first one.
+        Red,
+        Green,
+        LightBlue,
+    }
+
+    let actual = emit!(Colour as Java).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    /** A colour. */
+    public enum Colour {
+        /** The first one. */
+        RED,
+        GREEN,
+        LIGHT_BLUE
+    }
+    ");
+}
+
+#[test]
+fn data_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Line(f64, f64),
+        Rectangle { width: f64, height: f64 },
+    }
+
+    let actual = emit!(Shape as Java).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    public sealed interface Shape {
+        record Point() implements Shape {}
+
+        record Circle(double value) implements Shape {}
+
+        record Line(double field0, double field1) implements Shape {}
+
+        record Rectangle(double width, double height) implements Shape {}
+    }
+    ");
+}
+
+#[test]
+fn shadowed_java_lang() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Value {
+        String(String),
+        Integer(Option<i32>),
+    }
+
+    let actual = emit!(Value as Java).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    public sealed interface Value {
+        record String(java.lang.String value) implements Value {}
+
+        record Integer(java.util.Optional<java.lang.Integer> value) implements Value {}
+    }
+    ");
+}
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as Java).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    public record Listing(Page<String> names, Page<Integer> ids) {}
+
+    public record Page<T>(java.util.List<T> items, java.util.Optional<T> next, int total) {}
+    ");
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E, retry: bool },
+        Pending,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        first: Outcome<String, i32>,
+        second: Outcome<bool, String>,
+    }
+
+    let actual = emit!(Response as Java).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    public sealed interface Outcome<T, E> {
+        record Success<T, E>(T value) implements Outcome<T, E> {}
+
+        record Failure<T, E>(E error, boolean retry) implements Outcome<T, E> {}
+
+        record Pending<T, E>() implements Outcome<T, E> {}
+    }
+
+    public record Response(Outcome<String, Integer> first, Outcome<Boolean, String> second) {}
+    ");
+}
+
+#[test]
+fn branded_newtype() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    let actual = emit!(UserId as Java).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    public record UserId(String value) {}
+    ");
+}
+
+#[test]
+fn serialized_as_proxy() {
+    #[derive(Facet)]
+    #[facet(fg::serialized_as = "String")]
+    struct ItemId(u64);
+
+    #[derive(Facet)]
+    struct Item {
+        id: ItemId,
+    }
+
+    let actual = emit!(Item as Java).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    public record Item(String id) {}
+    ");
+}
+
+#[test]
+fn deprecated() {
+    #[derive(Facet)]
+    #[facet(fg::deprecated(since = "0.2.0", note = "Use `Circle` instead"))]
+    struct Round {
+        #[facet(fg::deprecated)]
+        radius: f64,
+    }
+
+    let actual = emit!(Round as Java).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    /** @deprecated Use `Circle` instead (since 0.2.0) */
+    @Deprecated(since = "0.2.0")
+    public record Round(@Deprecated double radius) {}
+    "#);
+}
+
+#[test]
+fn defaults() {
+    #[derive(Facet)]
+    struct Settings {
+        #[facet(default = "$HOME".to_string())]
+        directory: String,
+        #[facet(default = 30)]
+        timeout: u64,
+        #[facet(default)]
+        counts: HashMap<String, u32>,
+        retries: u32,
+    }
+
+    let actual = emit!(Settings as Java).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    public record Settings(
+        String directory,
+        long timeout,
+        java.util.Map<String, Integer> counts,
+        int retries
+    ) {}
+    ");
+}
+
+#[test]
+fn two_modules() {
+    mod other {
+        use crate as fg;
+        use facet::Facet;
+
+        #[derive(Facet)]
+        #[facet(fg::namespace = "other")]
+        pub struct Child {
+            value: i32,
+        }
+    }
+
+    #[derive(Facet)]
+    struct Parent {
+        other_child: other::Child,
+    }
+
+    let (other, root) = emit_two_modules!(JavaCodeGenerator, Parent, "root");
+    insta::assert_snapshot!(other, @"
+    package other;
+
+    public record Child(int value) {}
+    ");
+    insta::assert_snapshot!(root, @"
+    package root;
+
+    public record Parent(other.Child otherChild) {}
+    ");
+}
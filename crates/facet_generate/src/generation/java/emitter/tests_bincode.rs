@@ -0,0 +1,767 @@
+//! Snapshot tests for the Java emitter — **Bincode encoding**.
+//!
+//! Mirrors the structure of [`tests`](super::tests) but uses `BincodePlugin`,
+//! so that every type gets `serialize` / `bincodeSerialize` methods and static
+//! `deserialize` / `bincodeDeserialize` methods written against the runtime's
+//! `com.novi.serde.Serializer` / `Deserializer`.
+//!
+//! These tests verify field ordering, container depth tracking, the functions
+//! passed to the collection helpers (`serialize_seq`, `deserialize_map`, …),
+//! variant indices and the (de)serializers of type parameters.
+
+#![allow(clippy::too_many_lines)]
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use crate::{self as fg, generation::bincode::BincodePlugin};
+use facet::Facet;
+
+use super::*;
+use crate::emit;
+
+#[test]
+fn unit_struct() {
+    #[derive(Facet)]
+    struct UnitStruct;
+
+    let actual = emit!(UnitStruct as Java with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public record UnitStruct() {
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+        }
+
+        public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+            com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+            serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static UnitStruct deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            return new UnitStruct();
+        }
+
+        public static UnitStruct bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+            if (input == null) {
+                throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+            }
+            com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+            UnitStruct value = deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.length) {
+                throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
+
+#[test]
+fn newtype_struct() {
+    #[derive(Facet)]
+    struct NewType(String);
+
+    let actual = emit!(NewType as Java with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public record NewType(String value) {
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_str(this.value);
+            serializer.decrease_container_depth();
+        }
+
+        public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+            com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+            serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static NewType deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            NewType value = new NewType(deserializer.deserialize_str());
+            deserializer.decrease_container_depth();
+            return value;
+        }
+
+        public static NewType bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+            if (input == null) {
+                throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+            }
+            com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+            NewType value = deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.length) {
+                throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
+
+#[test]
+fn tuple_struct() {
+    #[derive(Facet)]
+    struct TupleStruct(String, i32);
+
+    let actual = emit!(TupleStruct as Java with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public record TupleStruct(String field0, int field1) {
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_str(this.field0);
+            serializer.serialize_i32(this.field1);
+            serializer.decrease_container_depth();
+        }
+
+        public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+            com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+            serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static TupleStruct deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            TupleStruct value = new TupleStruct(
+                deserializer.deserialize_str(),
+                deserializer.deserialize_i32()
+            );
+            deserializer.decrease_container_depth();
+            return value;
+        }
+
+        public static TupleStruct bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+            if (input == null) {
+                throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+            }
+            com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+            TupleStruct value = deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.length) {
+                throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
+
+#[test]
+fn struct_with_fields() {
+    #[derive(Facet)]
+    struct Inner {
+        value: u64,
+    }
+
+    #[derive(Facet)]
+    struct Outer {
+        name: String,
+        big: u128,
+        nickname: Option<String>,
+        inner: Inner,
+        tags: Vec<Option<Inner>>,
+        counts: BTreeMap<String, Vec<u32>>,
+        ids: BTreeSet<u16>,
+        pair: (u8, String),
+        grid: [i16; 3],
+        #[facet(fg::bytes)]
+        data: Vec<u8>,
+    }
+
+    let actual = emit!(Outer as Java with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public record Inner(long value) {
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_u64(this.value);
+            serializer.decrease_container_depth();
+        }
+
+        public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+            com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+            serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static Inner deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Inner value = new Inner(deserializer.deserialize_u64());
+            deserializer.decrease_container_depth();
+            return value;
+        }
+
+        public static Inner bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+            if (input == null) {
+                throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+            }
+            com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+            Inner value = deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.length) {
+                throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+
+    public record Outer(
+        String name,
+        java.math.BigInteger big,
+        java.util.Optional<String> nickname,
+        Inner inner,
+        java.util.List<java.util.Optional<Inner>> tags,
+        java.util.Map<String, java.util.List<Integer>> counts,
+        java.util.Set<Short> ids,
+        com.novi.serde.Tuple2<Byte, String> pair,
+        java.util.List<Short> grid,
+        com.novi.serde.Bytes data
+    ) {
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_str(this.name);
+            serializer.serialize_u128(this.big);
+            serializer.serialize_option(this.nickname, com.novi.serde.Serializer::serialize_str);
+            this.inner.serialize(serializer);
+            serializer.serialize_seq(this.tags, (serializer1, value1) -> serializer1.serialize_option(value1, (serializer2, value2) -> value2.serialize(serializer2)));
+            serializer.serialize_map(this.counts, com.novi.serde.Serializer::serialize_str, (serializer1, value1) -> serializer1.serialize_seq(value1, com.novi.serde.Serializer::serialize_u32));
+            serializer.serialize_seq(this.ids, com.novi.serde.Serializer::serialize_u16);
+            this.pair.serialize(serializer, com.novi.serde.Serializer::serialize_u8, com.novi.serde.Serializer::serialize_str);
+            serializer.serialize_tuple_array(this.grid, 3, com.novi.serde.Serializer::serialize_i16);
+            serializer.serialize_bytes(this.data);
+            serializer.decrease_container_depth();
+        }
+
+        public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+            com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+            serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static Outer deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Outer value = new Outer(
+                deserializer.deserialize_str(),
+                deserializer.deserialize_u128(),
+                deserializer.deserialize_option(com.novi.serde.Deserializer::deserialize_str),
+                Inner.deserialize(deserializer),
+                deserializer.deserialize_seq(deserializer1 -> deserializer1.deserialize_option(Inner::deserialize)),
+                deserializer.deserialize_map(com.novi.serde.Deserializer::deserialize_str, deserializer1 -> deserializer1.deserialize_seq(com.novi.serde.Deserializer::deserialize_u32)),
+                deserializer.deserialize_set(com.novi.serde.Deserializer::deserialize_u16),
+                com.novi.serde.Tuple2.deserialize(deserializer, com.novi.serde.Deserializer::deserialize_u8, com.novi.serde.Deserializer::deserialize_str),
+                deserializer.deserialize_tuple_array(3, com.novi.serde.Deserializer::deserialize_i16),
+                deserializer.deserialize_bytes()
+            );
+            deserializer.decrease_container_depth();
+            return value;
+        }
+
+        public static Outer bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+            if (input == null) {
+                throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+            }
+            com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+            Outer value = deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.length) {
+                throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
+
+#[test]
+fn unit_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Colour {
+        Red,
+        Green,
+        Other,
+    }
+
+    let actual = emit!(Colour as Java with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public enum Colour {
+        RED,
+        GREEN,
+        OTHER;
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            int index = switch (this) {
+                case RED -> 0;
+                case GREEN -> 1;
+                case OTHER -> 2;
+            };
+            serializer.serialize_variant_index(index);
+            serializer.decrease_container_depth();
+        }
+
+        public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+            com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+            serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static Colour deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            int index = deserializer.deserialize_variant_index();
+            Colour value = switch (index) {
+                case 0 -> RED;
+                case 1 -> GREEN;
+                case 2 -> OTHER;
+                default -> throw new com.novi.serde.DeserializationError("Unknown variant index for Colour: " + index);
+            };
+            deserializer.decrease_container_depth();
+            return value;
+        }
+
+        public static Colour bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+            if (input == null) {
+                throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+            }
+            com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+            Colour value = deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.length) {
+                throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
+
+#[test]
+fn data_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Line(f64, f64),
+        Rectangle { width: f64, height: f64 },
+    }
+
+    let actual = emit!(Shape as Java with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public sealed interface Shape {
+        void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError;
+
+        default byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+            com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+            serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        record Point() implements Shape {
+            @Override
+            public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(0);
+                serializer.decrease_container_depth();
+            }
+        }
+
+        record Circle(double value) implements Shape {
+            @Override
+            public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(1);
+                serializer.serialize_f64(this.value);
+                serializer.decrease_container_depth();
+            }
+        }
+
+        record Line(double field0, double field1) implements Shape {
+            @Override
+            public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(2);
+                serializer.serialize_f64(this.field0);
+                serializer.serialize_f64(this.field1);
+                serializer.decrease_container_depth();
+            }
+        }
+
+        record Rectangle(double width, double height) implements Shape {
+            @Override
+            public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(3);
+                serializer.serialize_f64(this.width);
+                serializer.serialize_f64(this.height);
+                serializer.decrease_container_depth();
+            }
+        }
+
+        static Shape deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            int index = deserializer.deserialize_variant_index();
+            Shape value = switch (index) {
+                case 0 -> new Point();
+                case 1 -> new Circle(deserializer.deserialize_f64());
+                case 2 -> new Line(deserializer.deserialize_f64(), deserializer.deserialize_f64());
+                case 3 -> new Rectangle(deserializer.deserialize_f64(), deserializer.deserialize_f64());
+                default -> throw new com.novi.serde.DeserializationError("Unknown variant index for Shape: " + index);
+            };
+            deserializer.decrease_container_depth();
+            return value;
+        }
+
+        static Shape bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+            if (input == null) {
+                throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+            }
+            com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+            Shape value = deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.length) {
+                throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
+
+#[test]
+fn generic_struct_and_enum() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E },
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        page: Page<String>,
+        outcome: Outcome<Page<u32>, String>,
+        fallback: Outcome<bool, i32>,
+    }
+
+    let actual = emit!(Response as Java with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public sealed interface Outcome<T, E> {
+        void serialize(com.novi.serde.Serializer serializer, com.novi.serde.Serialize<T> serializeT, com.novi.serde.Serialize<E> serializeE) throws com.novi.serde.SerializationError;
+
+        default byte[] bincodeSerialize(com.novi.serde.Serialize<T> serializeT, com.novi.serde.Serialize<E> serializeE) throws com.novi.serde.SerializationError {
+            com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+            serialize(serializer, serializeT, serializeE);
+            return serializer.get_bytes();
+        }
+
+        record Success<T, E>(T value) implements Outcome<T, E> {
+            @Override
+            public void serialize(com.novi.serde.Serializer serializer, com.novi.serde.Serialize<T> serializeT, com.novi.serde.Serialize<E> serializeE) throws com.novi.serde.SerializationError {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(0);
+                serializeT.serialize(serializer, this.value);
+                serializer.decrease_container_depth();
+            }
+        }
+
+        record Failure<T, E>(E error) implements Outcome<T, E> {
+            @Override
+            public void serialize(com.novi.serde.Serializer serializer, com.novi.serde.Serialize<T> serializeT, com.novi.serde.Serialize<E> serializeE) throws com.novi.serde.SerializationError {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(1);
+                serializeE.serialize(serializer, this.error);
+                serializer.decrease_container_depth();
+            }
+        }
+
+        static <T, E> Outcome<T, E> deserialize(com.novi.serde.Deserializer deserializer, com.novi.serde.Deserialize<T> deserializeT, com.novi.serde.Deserialize<E> deserializeE) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            int index = deserializer.deserialize_variant_index();
+            Outcome<T, E> value = switch (index) {
+                case 0 -> new Success<>(deserializeT.deserialize(deserializer));
+                case 1 -> new Failure<>(deserializeE.deserialize(deserializer));
+                default -> throw new com.novi.serde.DeserializationError("Unknown variant index for Outcome: " + index);
+            };
+            deserializer.decrease_container_depth();
+            return value;
+        }
+
+        static <T, E> Outcome<T, E> bincodeDeserialize(byte[] input, com.novi.serde.Deserialize<T> deserializeT, com.novi.serde.Deserialize<E> deserializeE) throws com.novi.serde.DeserializationError {
+            if (input == null) {
+                throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+            }
+            com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+            Outcome<T, E> value = deserialize(deserializer, deserializeT, deserializeE);
+            if (deserializer.get_buffer_offset() < input.length) {
+                throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+
+    public record Page<T>(java.util.List<T> items, java.util.Optional<T> next) {
+        public void serialize(com.novi.serde.Serializer serializer, com.novi.serde.Serialize<T> serializeT) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_seq(this.items, serializeT);
+            serializer.serialize_option(this.next, serializeT);
+            serializer.decrease_container_depth();
+        }
+
+        public byte[] bincodeSerialize(com.novi.serde.Serialize<T> serializeT) throws com.novi.serde.SerializationError {
+            com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+            serialize(serializer, serializeT);
+            return serializer.get_bytes();
+        }
+
+        public static <T> Page<T> deserialize(com.novi.serde.Deserializer deserializer, com.novi.serde.Deserialize<T> deserializeT) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Page<T> value = new Page<>(
+                deserializer.deserialize_seq(deserializeT),
+                deserializer.deserialize_option(deserializeT)
+            );
+            deserializer.decrease_container_depth();
+            return value;
+        }
+
+        public static <T> Page<T> bincodeDeserialize(byte[] input, com.novi.serde.Deserialize<T> deserializeT) throws com.novi.serde.DeserializationError {
+            if (input == null) {
+                throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+            }
+            com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+            Page<T> value = deserialize(deserializer, deserializeT);
+            if (deserializer.get_buffer_offset() < input.length) {
+                throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+
+    public record Response(
+        Page<String> page,
+        Outcome<Page<Integer>, String> outcome,
+        Outcome<Boolean, Integer> fallback
+    ) {
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            this.page.serialize(serializer, com.novi.serde.Serializer::serialize_str);
+            this.outcome.serialize(serializer, (serializer1, value1) -> value1.serialize(serializer1, com.novi.serde.Serializer::serialize_u32), com.novi.serde.Serializer::serialize_str);
+            this.fallback.serialize(serializer, com.novi.serde.Serializer::serialize_bool, com.novi.serde.Serializer::serialize_i32);
+            serializer.decrease_container_depth();
+        }
+
+        public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+            com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+            serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static Response deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Response value = new Response(
+                Page.deserialize(deserializer, com.novi.serde.Deserializer::deserialize_str),
+                Outcome.deserialize(deserializer, deserializer1 -> Page.deserialize(deserializer1, com.novi.serde.Deserializer::deserialize_u32), com.novi.serde.Deserializer::deserialize_str),
+                Outcome.deserialize(deserializer, com.novi.serde.Deserializer::deserialize_bool, com.novi.serde.Deserializer::deserialize_i32)
+            );
+            deserializer.decrease_container_depth();
+            return value;
+        }
+
+        public static Response bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+            if (input == null) {
+                throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+            }
+            com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+            Response value = deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.length) {
+                throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
+
+#[test]
+fn branded_newtype() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    let actual = emit!(UserId as Java with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public record UserId(String value) {
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_str(this.value);
+            serializer.decrease_container_depth();
+        }
+
+        public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+            com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+            serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static UserId deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            UserId value = new UserId(deserializer.deserialize_str());
+            deserializer.decrease_container_depth();
+            return value;
+        }
+
+        public static UserId bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+            if (input == null) {
+                throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+            }
+            com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+            UserId value = deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.length) {
+                throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
+
+#[test]
+fn unknown_variants() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Click {
+            x: i32,
+            y: i32,
+        },
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Event as Java with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public sealed interface Event {
+        void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError;
+
+        default byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+            com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+            serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        record Click(int x, int y) implements Event {
+            @Override
+            public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(0);
+                serializer.serialize_i32(this.x);
+                serializer.serialize_i32(this.y);
+                serializer.decrease_container_depth();
+            }
+        }
+
+        record Unknown() implements Event {
+            @Override
+            public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(1);
+                serializer.decrease_container_depth();
+            }
+        }
+
+        static Event deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            int index = deserializer.deserialize_variant_index();
+            Event value = switch (index) {
+                case 0 -> new Click(deserializer.deserialize_i32(), deserializer.deserialize_i32());
+                case 1 -> new Unknown();
+                default -> new Unknown();
+            };
+            deserializer.decrease_container_depth();
+            return value;
+        }
+
+        static Event bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+            if (input == null) {
+                throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+            }
+            com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+            Event value = deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.length) {
+                throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
+
+#[test]
+fn type_override() {
+    #[derive(Facet)]
+    struct Price {
+        #[facet(fg::override(
+            lang = "java",
+            ty = "java.math.BigDecimal",
+            serialize = "serializer.serialize_str({value}.toPlainString())",
+            deserialize = "new java.math.BigDecimal(deserializer.deserialize_str())"
+        ))]
+        amount: String,
+        #[facet(fg::override(lang = "swift", ty = "CGFloat"))]
+        scale: f64,
+    }
+
+    let actual = emit!(Price as Java with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public record Price(java.math.BigDecimal amount, double scale) {
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_str(this.amount.toPlainString());
+            serializer.serialize_f64(this.scale);
+            serializer.decrease_container_depth();
+        }
+
+        public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+            com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+            serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static Price deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Price value = new Price(
+                new java.math.BigDecimal(deserializer.deserialize_str()),
+                deserializer.deserialize_f64()
+            );
+            deserializer.decrease_container_depth();
+            return value;
+        }
+
+        public static Price bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+            if (input == null) {
+                throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+            }
+            com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+            Price value = deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.length) {
+                throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
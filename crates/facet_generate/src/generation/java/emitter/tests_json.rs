@@ -0,0 +1,879 @@
+//! Snapshot tests for the Java emitter — **JSON encoding**.
+//!
+//! Mirrors the structure of [`tests`](super::tests) but uses `JsonPlugin`, so
+//! that every type gets `toJson` / `jsonSerialize` methods and static
+//! `fromJson` / `jsonDeserialize` methods written against the runtime's
+//! `com.novi.serde.Json`.
+//!
+//! These tests verify the JSON shapes of structs, of the enum taggings
+//! (external, internal, adjacent and untagged), of map keys, of default
+//! values and of generic types.
+
+#![allow(clippy::too_many_lines)]
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::generation::json::JsonPlugin;
+use facet::Facet;
+
+use super::*;
+use crate::emit;
+
+#[test]
+fn unit_and_empty_structs() {
+    #[derive(Facet)]
+    struct UnitStruct;
+
+    #[derive(Facet)]
+    struct EmptyStruct {}
+
+    #[derive(Facet)]
+    struct Both {
+        unit: UnitStruct,
+        empty: EmptyStruct,
+    }
+
+    let actual = emit!(Both as Java with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public record Both(UnitStruct unit, EmptyStruct empty) {
+        public Object toJson() {
+            return com.novi.serde.Json.object("unit", this.unit.toJson(), "empty", this.empty.toJson());
+        }
+
+        public String jsonSerialize() {
+            return com.novi.serde.Json.stringify(toJson());
+        }
+
+        public static Both fromJson(Object json) throws com.novi.serde.DeserializationError {
+            java.util.Map<String, Object> fields = com.novi.serde.Json.objectFromJson(json);
+            return new Both(
+                UnitStruct.fromJson(com.novi.serde.Json.field(fields, "unit")),
+                EmptyStruct.fromJson(com.novi.serde.Json.field(fields, "empty"))
+            );
+        }
+
+        public static Both jsonDeserialize(String input) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input));
+        }
+    }
+
+    public record EmptyStruct() {
+        public Object toJson() {
+            return null;
+        }
+
+        public String jsonSerialize() {
+            return com.novi.serde.Json.stringify(toJson());
+        }
+
+        public static EmptyStruct fromJson(Object json) throws com.novi.serde.DeserializationError {
+            com.novi.serde.Json.unitFromJson(json);
+            return new EmptyStruct();
+        }
+
+        public static EmptyStruct jsonDeserialize(String input) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input));
+        }
+    }
+
+    public record UnitStruct() {
+        public Object toJson() {
+            return null;
+        }
+
+        public String jsonSerialize() {
+            return com.novi.serde.Json.stringify(toJson());
+        }
+
+        public static UnitStruct fromJson(Object json) throws com.novi.serde.DeserializationError {
+            com.novi.serde.Json.unitFromJson(json);
+            return new UnitStruct();
+        }
+
+        public static UnitStruct jsonDeserialize(String input) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input));
+        }
+    }
+    "#);
+}
+
+#[test]
+fn newtype_and_tuple_structs() {
+    #[derive(Facet)]
+    struct NewType(Vec<String>);
+
+    #[derive(Facet)]
+    struct TupleStruct(String, f64);
+
+    #[derive(Facet)]
+    struct Both {
+        newtype: NewType,
+        tuple: TupleStruct,
+    }
+
+    let actual = emit!(Both as Java with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public record Both(NewType newtype, TupleStruct tuple) {
+        public Object toJson() {
+            return com.novi.serde.Json.object(
+                "newtype", this.newtype.toJson(),
+                "tuple", this.tuple.toJson()
+            );
+        }
+
+        public String jsonSerialize() {
+            return com.novi.serde.Json.stringify(toJson());
+        }
+
+        public static Both fromJson(Object json) throws com.novi.serde.DeserializationError {
+            java.util.Map<String, Object> fields = com.novi.serde.Json.objectFromJson(json);
+            return new Both(
+                NewType.fromJson(com.novi.serde.Json.field(fields, "newtype")),
+                TupleStruct.fromJson(com.novi.serde.Json.field(fields, "tuple"))
+            );
+        }
+
+        public static Both jsonDeserialize(String input) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input));
+        }
+    }
+
+    public record NewType(java.util.List<String> value) {
+        public Object toJson() {
+            return com.novi.serde.Json.seqToJson(this.value, value1 -> value1);
+        }
+
+        public String jsonSerialize() {
+            return com.novi.serde.Json.stringify(toJson());
+        }
+
+        public static NewType fromJson(Object json) throws com.novi.serde.DeserializationError {
+            return new NewType(com.novi.serde.Json.seqFromJson(json, com.novi.serde.Json::strFromJson));
+        }
+
+        public static NewType jsonDeserialize(String input) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input));
+        }
+    }
+
+    public record TupleStruct(String field0, double field1) {
+        public Object toJson() {
+            return com.novi.serde.Json.array(this.field0, this.field1);
+        }
+
+        public String jsonSerialize() {
+            return com.novi.serde.Json.stringify(toJson());
+        }
+
+        public static TupleStruct fromJson(Object json) throws com.novi.serde.DeserializationError {
+            java.util.List<Object> elements = com.novi.serde.Json.tupleFromJson(json, 2);
+            return new TupleStruct(
+                com.novi.serde.Json.strFromJson(elements.get(0)),
+                com.novi.serde.Json.f64FromJson(elements.get(1))
+            );
+        }
+
+        public static TupleStruct jsonDeserialize(String input) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input));
+        }
+    }
+    "#);
+}
+
+#[test]
+fn struct_with_fields() {
+    #[derive(Facet, PartialEq, Eq, PartialOrd, Ord)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Kind {
+        Small,
+        Large,
+    }
+
+    #[derive(Facet)]
+    struct Outer {
+        name: String,
+        big: i128,
+        ratio: f32,
+        nickname: Option<String>,
+        kind: Option<Kind>,
+        tags: Vec<Kind>,
+        counts: BTreeMap<u32, Vec<f64>>,
+        kinds: BTreeMap<Kind, String>,
+        ids: HashSet<u16>,
+        pair: (u8, String),
+        timeout: Duration,
+        #[facet(default = 3)]
+        retries: u32,
+    }
+
+    let actual = emit!(Outer as Java with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public enum Kind {
+        SMALL,
+        LARGE;
+
+        public Object toJson() {
+            String name = switch (this) {
+                case SMALL -> "Small";
+                case LARGE -> "Large";
+            };
+            return name;
+        }
+
+        public String jsonSerialize() {
+            return com.novi.serde.Json.stringify(toJson());
+        }
+
+        public static Kind fromJson(Object json) throws com.novi.serde.DeserializationError {
+            String name = com.novi.serde.Json.strFromJson(json);
+            return switch (name) {
+                case "Small" -> SMALL;
+                case "Large" -> LARGE;
+                default -> throw new com.novi.serde.DeserializationError("Unknown variant for Kind: " + name);
+            };
+        }
+
+        public static Kind jsonDeserialize(String input) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input));
+        }
+    }
+
+    public record Outer(
+        String name,
+        java.math.BigInteger big,
+        float ratio,
+        java.util.Optional<String> nickname,
+        java.util.Optional<Kind> kind,
+        java.util.List<Kind> tags,
+        java.util.Map<Integer, java.util.List<Double>> counts,
+        java.util.Map<Kind, String> kinds,
+        java.util.Set<Short> ids,
+        com.novi.serde.Tuple2<Byte, String> pair,
+        java.time.Duration timeout,
+        int retries
+    ) {
+        public Object toJson() {
+            return com.novi.serde.Json.object(
+                "name", this.name,
+                "big", this.big,
+                "ratio", this.ratio,
+                "nickname", com.novi.serde.Json.optionToJson(this.nickname, value1 -> value1),
+                "kind", com.novi.serde.Json.optionToJson(this.kind, value1 -> value1.toJson()),
+                "tags", com.novi.serde.Json.seqToJson(this.tags, value1 -> value1.toJson()),
+                "counts", com.novi.serde.Json.mapToJson(this.counts, value1 -> com.novi.serde.Json.u32ToJson(value1), value1 -> com.novi.serde.Json.seqToJson(value1, value2 -> value2)),
+                "kinds", com.novi.serde.Json.mapToJson(this.kinds, value1 -> value1.toJson(), value1 -> value1),
+                "ids", com.novi.serde.Json.seqToJson(this.ids, value1 -> com.novi.serde.Json.u16ToJson(value1)),
+                "pair", this.pair.toJson(value1 -> com.novi.serde.Json.u8ToJson(value1), value1 -> value1),
+                "timeout", com.novi.serde.Json.durationToJson(this.timeout),
+                "retries", com.novi.serde.Json.u32ToJson(this.retries)
+            );
+        }
+
+        public String jsonSerialize() {
+            return com.novi.serde.Json.stringify(toJson());
+        }
+
+        public static Outer fromJson(Object json) throws com.novi.serde.DeserializationError {
+            java.util.Map<String, Object> fields = com.novi.serde.Json.objectFromJson(json);
+            return new Outer(
+                com.novi.serde.Json.strFromJson(com.novi.serde.Json.field(fields, "name")),
+                com.novi.serde.Json.i128FromJson(com.novi.serde.Json.field(fields, "big")),
+                com.novi.serde.Json.f32FromJson(com.novi.serde.Json.field(fields, "ratio")),
+                com.novi.serde.Json.optionFromJson(fields.get("nickname"), com.novi.serde.Json::strFromJson),
+                com.novi.serde.Json.optionFromJson(fields.get("kind"), Kind::fromJson),
+                com.novi.serde.Json.seqFromJson(com.novi.serde.Json.field(fields, "tags"), Kind::fromJson),
+                com.novi.serde.Json.mapFromJson(com.novi.serde.Json.field(fields, "counts"), key1 -> com.novi.serde.Json.u32FromJson(com.novi.serde.Json.numberFromKey(key1)), json1 -> com.novi.serde.Json.seqFromJson(json1, com.novi.serde.Json::f64FromJson)),
+                com.novi.serde.Json.mapFromJson(com.novi.serde.Json.field(fields, "kinds"), Kind::fromJson, com.novi.serde.Json::strFromJson),
+                com.novi.serde.Json.setFromJson(com.novi.serde.Json.field(fields, "ids"), com.novi.serde.Json::u16FromJson),
+                com.novi.serde.Tuple2.fromJson(com.novi.serde.Json.field(fields, "pair"), com.novi.serde.Json::u8FromJson, com.novi.serde.Json::strFromJson),
+                com.novi.serde.Json.durationFromJson(com.novi.serde.Json.field(fields, "timeout")),
+                fields.containsKey("retries") ? com.novi.serde.Json.u32FromJson(fields.get("retries")) : (int) 3L
+            );
+        }
+
+        public static Outer jsonDeserialize(String input) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input));
+        }
+    }
+    "#);
+}
+
+#[test]
+fn externally_tagged_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Line(f64, f64),
+        Rectangle { width: f64, height: f64 },
+    }
+
+    let actual = emit!(Shape as Java with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public sealed interface Shape {
+        Object toJson();
+
+        default String jsonSerialize() {
+            return com.novi.serde.Json.stringify(toJson());
+        }
+
+        record Point() implements Shape {
+            @Override
+            public Object toJson() {
+                return "Point";
+            }
+        }
+
+        record Circle(double value) implements Shape {
+            @Override
+            public Object toJson() {
+                return com.novi.serde.Json.object("Circle", this.value);
+            }
+        }
+
+        record Line(double field0, double field1) implements Shape {
+            @Override
+            public Object toJson() {
+                Object content = com.novi.serde.Json.array(this.field0, this.field1);
+                return com.novi.serde.Json.object("Line", content);
+            }
+        }
+
+        record Rectangle(double width, double height) implements Shape {
+            @Override
+            public Object toJson() {
+                Object content = com.novi.serde.Json.object("width", this.width, "height", this.height);
+                return com.novi.serde.Json.object("Rectangle", content);
+            }
+        }
+
+        static Shape fromJson(Object json) throws com.novi.serde.DeserializationError {
+            java.util.Map.Entry<String, Object> entry = com.novi.serde.Json.externalTag(json);
+            String name = entry.getKey();
+            return switch (name) {
+                case "Point" -> new Point();
+                case "Circle" -> new Circle(com.novi.serde.Json.f64FromJson(entry.getValue()));
+                case "Line" -> {
+                    java.util.List<Object> elements = com.novi.serde.Json.tupleFromJson(entry.getValue(), 2);
+                    yield new Line(
+                        com.novi.serde.Json.f64FromJson(elements.get(0)),
+                        com.novi.serde.Json.f64FromJson(elements.get(1))
+                    );
+                }
+                case "Rectangle" -> {
+                    java.util.Map<String, Object> fields = com.novi.serde.Json.objectFromJson(entry.getValue());
+                    yield new Rectangle(
+                        com.novi.serde.Json.f64FromJson(com.novi.serde.Json.field(fields, "width")),
+                        com.novi.serde.Json.f64FromJson(com.novi.serde.Json.field(fields, "height"))
+                    );
+                }
+                default -> throw new com.novi.serde.DeserializationError("Unknown variant for Shape: " + name);
+            };
+        }
+
+        static Shape jsonDeserialize(String input) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input));
+        }
+    }
+    "#);
+}
+
+#[test]
+fn internally_tagged_enum() {
+    #[derive(Facet)]
+    struct Position {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Facet)]
+    #[facet(tag = "type")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Started,
+        Moved(Position),
+        Clicked {
+            button: u8,
+        },
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Event as Java with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public sealed interface Event {
+        Object toJson();
+
+        default String jsonSerialize() {
+            return com.novi.serde.Json.stringify(toJson());
+        }
+
+        record Started() implements Event {
+            @Override
+            public Object toJson() {
+                return com.novi.serde.Json.object("type", "Started");
+            }
+        }
+
+        record Moved(Position value) implements Event {
+            @Override
+            public Object toJson() {
+                return com.novi.serde.Json.withTag("type", "Moved", this.value.toJson());
+            }
+        }
+
+        record Clicked(byte button) implements Event {
+            @Override
+            public Object toJson() {
+                return com.novi.serde.Json.object(
+                    "type", "Clicked",
+                    "button", com.novi.serde.Json.u8ToJson(this.button)
+                );
+            }
+        }
+
+        record Unknown() implements Event {
+            @Override
+            public Object toJson() {
+                return com.novi.serde.Json.object("type", "Unknown");
+            }
+        }
+
+        static Event fromJson(Object json) throws com.novi.serde.DeserializationError {
+            java.util.Map<String, Object> object = com.novi.serde.Json.objectFromJson(json);
+            String name = com.novi.serde.Json.strFromJson(com.novi.serde.Json.field(object, "type"));
+            return switch (name) {
+                case "Started" -> new Started();
+                case "Moved" -> new Moved(Position.fromJson(json));
+                case "Clicked" -> {
+                    java.util.Map<String, Object> fields = com.novi.serde.Json.objectFromJson(json);
+                    yield new Clicked(
+                        com.novi.serde.Json.u8FromJson(com.novi.serde.Json.field(fields, "button"))
+                    );
+                }
+                case "Unknown" -> new Unknown();
+                default -> new Unknown();
+            };
+        }
+
+        static Event jsonDeserialize(String input) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input));
+        }
+    }
+
+    public record Position(int x, int y) {
+        public Object toJson() {
+            return com.novi.serde.Json.object("x", this.x, "y", this.y);
+        }
+
+        public String jsonSerialize() {
+            return com.novi.serde.Json.stringify(toJson());
+        }
+
+        public static Position fromJson(Object json) throws com.novi.serde.DeserializationError {
+            java.util.Map<String, Object> fields = com.novi.serde.Json.objectFromJson(json);
+            return new Position(
+                com.novi.serde.Json.i32FromJson(com.novi.serde.Json.field(fields, "x")),
+                com.novi.serde.Json.i32FromJson(com.novi.serde.Json.field(fields, "y"))
+            );
+        }
+
+        public static Position jsonDeserialize(String input) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input));
+        }
+    }
+    "#);
+}
+
+#[test]
+fn adjacently_tagged_enum() {
+    #[derive(Facet)]
+    #[facet(tag = "t", content = "c")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Message {
+        Ping,
+        Text(String),
+        Pair(u32, u32),
+    }
+
+    let actual = emit!(Message as Java with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public sealed interface Message {
+        Object toJson();
+
+        default String jsonSerialize() {
+            return com.novi.serde.Json.stringify(toJson());
+        }
+
+        record Ping() implements Message {
+            @Override
+            public Object toJson() {
+                return com.novi.serde.Json.object("t", "Ping");
+            }
+        }
+
+        record Text(String value) implements Message {
+            @Override
+            public Object toJson() {
+                return com.novi.serde.Json.object("t", "Text", "c", this.value);
+            }
+        }
+
+        record Pair(int field0, int field1) implements Message {
+            @Override
+            public Object toJson() {
+                Object content = com.novi.serde.Json.array(
+                    com.novi.serde.Json.u32ToJson(this.field0),
+                    com.novi.serde.Json.u32ToJson(this.field1)
+                );
+                return com.novi.serde.Json.object("t", "Pair", "c", content);
+            }
+        }
+
+        static Message fromJson(Object json) throws com.novi.serde.DeserializationError {
+            java.util.Map<String, Object> object = com.novi.serde.Json.objectFromJson(json);
+            String name = com.novi.serde.Json.strFromJson(com.novi.serde.Json.field(object, "t"));
+            return switch (name) {
+                case "Ping" -> new Ping();
+                case "Text" -> new Text(com.novi.serde.Json.strFromJson(object.get("c")));
+                case "Pair" -> {
+                    java.util.List<Object> elements = com.novi.serde.Json.tupleFromJson(object.get("c"), 2);
+                    yield new Pair(
+                        com.novi.serde.Json.u32FromJson(elements.get(0)),
+                        com.novi.serde.Json.u32FromJson(elements.get(1))
+                    );
+                }
+                default -> throw new com.novi.serde.DeserializationError("Unknown variant for Message: " + name);
+            };
+        }
+
+        static Message jsonDeserialize(String input) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input));
+        }
+    }
+    "#);
+}
+
+#[test]
+fn untagged_enum() {
+    #[derive(Facet)]
+    #[facet(untagged)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Value {
+        Nothing,
+        Number(f64),
+        Named { name: String },
+    }
+
+    let actual = emit!(Value as Java with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public sealed interface Value {
+        Object toJson();
+
+        default String jsonSerialize() {
+            return com.novi.serde.Json.stringify(toJson());
+        }
+
+        record Nothing() implements Value {
+            @Override
+            public Object toJson() {
+                return null;
+            }
+        }
+
+        record Number(double value) implements Value {
+            @Override
+            public Object toJson() {
+                return this.value;
+            }
+        }
+
+        record Named(String name) implements Value {
+            @Override
+            public Object toJson() {
+                return com.novi.serde.Json.object("name", this.name);
+            }
+        }
+
+        static Value fromJson(Object json) throws com.novi.serde.DeserializationError {
+            if (json == null) {
+                return new Nothing();
+            }
+            try {
+                return new Number(com.novi.serde.Json.f64FromJson(json));
+            } catch (com.novi.serde.DeserializationError e) {
+                // Try the next variant.
+            }
+            try {
+                java.util.Map<String, Object> fields = com.novi.serde.Json.objectFromJson(json);
+                return new Named(
+                    com.novi.serde.Json.strFromJson(com.novi.serde.Json.field(fields, "name"))
+                );
+            } catch (com.novi.serde.DeserializationError e) {
+                // Try the next variant.
+            }
+            throw new com.novi.serde.DeserializationError("No variant of Value matches " + com.novi.serde.Json.stringify(json));
+        }
+
+        static Value jsonDeserialize(String input) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input));
+        }
+    }
+    "#);
+}
+
+#[test]
+fn tagged_unit_enums() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Plain {
+        On,
+        Off,
+    }
+
+    #[derive(Facet)]
+    #[facet(tag = "kind")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Tagged {
+        Up,
+        #[facet(other)]
+        Unknown,
+    }
+
+    #[derive(Facet)]
+    struct Both {
+        plain: Plain,
+        tagged: Tagged,
+    }
+
+    let actual = emit!(Both as Java with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public record Both(Plain plain, Tagged tagged) {
+        public Object toJson() {
+            return com.novi.serde.Json.object(
+                "plain", this.plain.toJson(),
+                "tagged", this.tagged.toJson()
+            );
+        }
+
+        public String jsonSerialize() {
+            return com.novi.serde.Json.stringify(toJson());
+        }
+
+        public static Both fromJson(Object json) throws com.novi.serde.DeserializationError {
+            java.util.Map<String, Object> fields = com.novi.serde.Json.objectFromJson(json);
+            return new Both(
+                Plain.fromJson(com.novi.serde.Json.field(fields, "plain")),
+                Tagged.fromJson(com.novi.serde.Json.field(fields, "tagged"))
+            );
+        }
+
+        public static Both jsonDeserialize(String input) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input));
+        }
+    }
+
+    public enum Plain {
+        ON,
+        OFF;
+
+        public Object toJson() {
+            String name = switch (this) {
+                case ON -> "On";
+                case OFF -> "Off";
+            };
+            return name;
+        }
+
+        public String jsonSerialize() {
+            return com.novi.serde.Json.stringify(toJson());
+        }
+
+        public static Plain fromJson(Object json) throws com.novi.serde.DeserializationError {
+            String name = com.novi.serde.Json.strFromJson(json);
+            return switch (name) {
+                case "On" -> ON;
+                case "Off" -> OFF;
+                default -> throw new com.novi.serde.DeserializationError("Unknown variant for Plain: " + name);
+            };
+        }
+
+        public static Plain jsonDeserialize(String input) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input));
+        }
+    }
+
+    public enum Tagged {
+        UP,
+        UNKNOWN;
+
+        public Object toJson() {
+            String name = switch (this) {
+                case UP -> "Up";
+                case UNKNOWN -> "Unknown";
+            };
+            return com.novi.serde.Json.object("kind", name);
+        }
+
+        public String jsonSerialize() {
+            return com.novi.serde.Json.stringify(toJson());
+        }
+
+        public static Tagged fromJson(Object json) throws com.novi.serde.DeserializationError {
+            String name = com.novi.serde.Json.strFromJson(com.novi.serde.Json.field(com.novi.serde.Json.objectFromJson(json), "kind"));
+            return switch (name) {
+                case "Up" -> UP;
+                case "Unknown" -> UNKNOWN;
+                default -> UNKNOWN;
+            };
+        }
+
+        public static Tagged jsonDeserialize(String input) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input));
+        }
+    }
+    "#);
+}
+
+#[test]
+fn generic_struct_and_enum() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E },
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        page: Page<String>,
+        outcome: Outcome<Page<u32>, String>,
+        fallback: Outcome<bool, i32>,
+    }
+
+    let actual = emit!(Response as Java with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public sealed interface Outcome<T, E> {
+        Object toJson(com.novi.serde.ToJson<T> toJsonT, com.novi.serde.ToJson<E> toJsonE);
+
+        default String jsonSerialize(com.novi.serde.ToJson<T> toJsonT, com.novi.serde.ToJson<E> toJsonE) {
+            return com.novi.serde.Json.stringify(toJson(toJsonT, toJsonE));
+        }
+
+        record Success<T, E>(T value) implements Outcome<T, E> {
+            @Override
+            public Object toJson(com.novi.serde.ToJson<T> toJsonT, com.novi.serde.ToJson<E> toJsonE) {
+                return com.novi.serde.Json.object("Success", toJsonT.toJson(this.value));
+            }
+        }
+
+        record Failure<T, E>(E error) implements Outcome<T, E> {
+            @Override
+            public Object toJson(com.novi.serde.ToJson<T> toJsonT, com.novi.serde.ToJson<E> toJsonE) {
+                Object content = com.novi.serde.Json.object("error", toJsonE.toJson(this.error));
+                return com.novi.serde.Json.object("Failure", content);
+            }
+        }
+
+        static <T, E> Outcome<T, E> fromJson(Object json, com.novi.serde.FromJson<T> fromJsonT, com.novi.serde.FromJson<E> fromJsonE) throws com.novi.serde.DeserializationError {
+            java.util.Map.Entry<String, Object> entry = com.novi.serde.Json.externalTag(json);
+            String name = entry.getKey();
+            return switch (name) {
+                case "Success" -> new Success<>(fromJsonT.fromJson(entry.getValue()));
+                case "Failure" -> {
+                    java.util.Map<String, Object> fields = com.novi.serde.Json.objectFromJson(entry.getValue());
+                    yield new Failure<>(fromJsonE.fromJson(com.novi.serde.Json.field(fields, "error")));
+                }
+                default -> throw new com.novi.serde.DeserializationError("Unknown variant for Outcome: " + name);
+            };
+        }
+
+        static <T, E> Outcome<T, E> jsonDeserialize(String input, com.novi.serde.FromJson<T> fromJsonT, com.novi.serde.FromJson<E> fromJsonE) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input), fromJsonT, fromJsonE);
+        }
+    }
+
+    public record Page<T>(java.util.List<T> items, java.util.Optional<T> next) {
+        public Object toJson(com.novi.serde.ToJson<T> toJsonT) {
+            return com.novi.serde.Json.object(
+                "items", com.novi.serde.Json.seqToJson(this.items, toJsonT),
+                "next", com.novi.serde.Json.optionToJson(this.next, toJsonT)
+            );
+        }
+
+        public String jsonSerialize(com.novi.serde.ToJson<T> toJsonT) {
+            return com.novi.serde.Json.stringify(toJson(toJsonT));
+        }
+
+        public static <T> Page<T> fromJson(Object json, com.novi.serde.FromJson<T> fromJsonT) throws com.novi.serde.DeserializationError {
+            java.util.Map<String, Object> fields = com.novi.serde.Json.objectFromJson(json);
+            return new Page<>(
+                com.novi.serde.Json.seqFromJson(com.novi.serde.Json.field(fields, "items"), fromJsonT),
+                com.novi.serde.Json.optionFromJson(fields.get("next"), fromJsonT)
+            );
+        }
+
+        public static <T> Page<T> jsonDeserialize(String input, com.novi.serde.FromJson<T> fromJsonT) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input), fromJsonT);
+        }
+    }
+
+    public record Response(
+        Page<String> page,
+        Outcome<Page<Integer>, String> outcome,
+        Outcome<Boolean, Integer> fallback
+    ) {
+        public Object toJson() {
+            return com.novi.serde.Json.object(
+                "page", this.page.toJson(value1 -> value1),
+                "outcome", this.outcome.toJson(value1 -> value1.toJson(value2 -> com.novi.serde.Json.u32ToJson(value2)), value1 -> value1),
+                "fallback", this.fallback.toJson(value1 -> value1, value1 -> value1)
+            );
+        }
+
+        public String jsonSerialize() {
+            return com.novi.serde.Json.stringify(toJson());
+        }
+
+        public static Response fromJson(Object json) throws com.novi.serde.DeserializationError {
+            java.util.Map<String, Object> fields = com.novi.serde.Json.objectFromJson(json);
+            return new Response(
+                Page.fromJson(com.novi.serde.Json.field(fields, "page"), com.novi.serde.Json::strFromJson),
+                Outcome.fromJson(com.novi.serde.Json.field(fields, "outcome"), json1 -> Page.fromJson(json1, com.novi.serde.Json::u32FromJson), com.novi.serde.Json::strFromJson),
+                Outcome.fromJson(com.novi.serde.Json.field(fields, "fallback"), com.novi.serde.Json::boolFromJson, com.novi.serde.Json::i32FromJson)
+            );
+        }
+
+        public static Response jsonDeserialize(String input) throws com.novi.serde.DeserializationError {
+            return fromJson(com.novi.serde.Json.parse(input));
+        }
+    }
+    "#);
+}
@@ -0,0 +1,234 @@
+//! Top-level orchestrator for Java code generation.
+//!
+//! [`JavaCodeGenerator`] implements [`CodeGenerator`] and is the entry point
+//! for producing the Java declarations of a single package from a
+//! [`Registry`]. It delegates writing to the emitter layer. Java wants each
+//! public type in a file of its own, so the [`Installer`](super::Installer)
+//! calls it once per type.
+
+use std::{
+    io::{Result, Write},
+    sync::Arc,
+};
+
+use crate::{
+    Registry,
+    generation::{
+        CodeGenerator, CodeGeneratorConfig, Container, Emitter, Error, PackageLocation,
+        indent::IndentedWriter,
+        java::{emitter::Java, variant_class},
+        module::{self, Module},
+        plugin::{self, EmitterPlugin},
+    },
+    reflection::format::{
+        ContainerFormat, Format, FormatHolder, Language, Namespace, QualifiedTypeName,
+    },
+};
+
+/// The largest tuple that the serde runtime has a type for
+/// (`com.novi.serde.Tuple6`).
+const MAX_TUPLE_SIZE: usize = 6;
+
+/// Main configuration object for Java code generation.
+///
+/// Wraps a [`CodeGeneratorConfig`] and implements [`CodeGenerator`] so it
+/// can be used by the installer pipeline.
+pub struct JavaCodeGenerator<'a> {
+    /// Language-independent configuration.
+    pub(crate) config: &'a CodeGeneratorConfig,
+    /// Plugins that control encoding-specific code generation.
+    pub(crate) plugins: Vec<Arc<dyn EmitterPlugin<Java>>>,
+    /// The root package, whose subpackages hold the other namespaces, or
+    /// `None` if namespaces are packages of their own.
+    pub(crate) package: Option<String>,
+}
+
+impl<'a> CodeGenerator<'a> for JavaCodeGenerator<'a> {
+    fn new(config: &'a CodeGeneratorConfig) -> Self {
+        Self::new(config)
+    }
+
+    fn write_output<W: Write>(&mut self, writer: &mut W, registry: &Registry) -> Result<()> {
+        self.output(writer, registry)
+    }
+}
+
+impl<'a> JavaCodeGenerator<'a> {
+    /// Create a Java code generator with no plugins (plain types only).
+    ///
+    /// The declarations are in the package named by the config's module
+    /// name. Call [`with_package`](Self::with_package) to nest the packages of
+    /// other namespaces in a root package, and
+    /// [`with_plugins`](Self::with_plugins) to enable serialization.
+    #[must_use]
+    pub fn new(config: &'a CodeGeneratorConfig) -> Self {
+        Self {
+            config,
+            plugins: vec![],
+            package: None,
+        }
+    }
+
+    /// Set pre-built plugins, returning the modified generator.
+    #[must_use]
+    pub fn with_plugins(mut self, plugins: Vec<Arc<dyn EmitterPlugin<Java>>>) -> Self {
+        self.plugins = plugins;
+        self
+    }
+
+    /// Set the root package (e.g. `com.example.types`), which holds the root
+    /// namespace's types and a subpackage for each other namespace (e.g.
+    /// `com.example.types.other`), returning the modified generator.
+    #[must_use]
+    pub fn with_package(mut self, package: &str) -> Self {
+        self.package = Some(package.to_string());
+        self
+    }
+
+    /// Produce the Java declarations of the types in `registry`, after the
+    /// package declaration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `out` fails, or if a plugin or the
+    /// serde runtime can't encode a type of the registry.
+    pub fn output(&self, out: &mut impl Write, registry: &Registry) -> Result<()> {
+        let checked = module::for_plugin_checks(registry, Language::Java);
+        let registry = &module::for_language(registry, Language::Java);
+        check_registry(registry).map_err(std::io::Error::other)?;
+        plugin::check_registry(&self.plugins, &checked).map_err(std::io::Error::other)?;
+
+        let mut config = self.config.clone();
+        config.update_from(registry);
+
+        let mut lang = Java::new(&config, registry);
+        for p in &self.plugins {
+            lang = lang.with_plugin(p.clone());
+        }
+
+        let w = &mut IndentedWriter::new(out, config.indent);
+        Module::new(&config).write(w, &lang)?;
+
+        let updated_registry = self.update_qualified_names(&config, registry);
+        for container in updated_registry.iter().map(Container::from) {
+            if container.format.metadata().proxy {
+                continue;
+            }
+            writeln!(w)?;
+            container.write(w, &lang)?;
+        }
+
+        Ok(())
+    }
+
+    /// Updates [`QualifiedTypeName`] instances for Java's packages:
+    ///
+    /// 1. **Same-package type** — strip namespace to `Root` so it renders as a
+    ///    bare name (e.g. `Child`).
+    /// 2. **External package at a path** — prefix the namespace with the
+    ///    package's path (e.g. `com.acme.shared.other.Child`).
+    /// 3. **Type of another namespace** — qualify it with the subpackage of
+    ///    the root package (e.g. `com.example.types.other.Child`), or with the
+    ///    namespace alone if there is no root package.
+    /// 4. **Root type, seen from another namespace** — qualify it with the
+    ///    root package.
+    fn update_qualified_names(
+        &self,
+        config: &CodeGeneratorConfig,
+        registry: &Registry,
+    ) -> Registry {
+        let module_name = config.module_name();
+        let subpackage = |namespace: &str| match &self.package {
+            Some(package) if package != namespace => format!("{package}.{namespace}"),
+            _ => namespace.to_string(),
+        };
+
+        let mut updated_registry = registry.clone();
+        for container_format in updated_registry.values_mut() {
+            let _ = container_format.visit_mut(&mut |format| {
+                if let Format::TypeName(qualified_name)
+                | Format::Generic {
+                    name: qualified_name,
+                    ..
+                } = format
+                {
+                    let name = qualified_name.name.clone();
+                    match &qualified_name.namespace {
+                        Namespace::Named(namespace)
+                            if namespace == module_name || subpackage(namespace) == module_name =>
+                        {
+                            *qualified_name = QualifiedTypeName::root(name);
+                        }
+                        Namespace::Named(namespace) => {
+                            let package = match config.external_packages.get(namespace) {
+                                Some(external) => match &external.location {
+                                    PackageLocation::Path(path) => format!("{path}.{namespace}"),
+                                    PackageLocation::Url(_) => subpackage(namespace),
+                                },
+                                None => subpackage(namespace),
+                            };
+                            *qualified_name = QualifiedTypeName::namespaced(package, name);
+                        }
+                        Namespace::Root => {
+                            if let Some(package) = &self.package
+                                && package != module_name
+                            {
+                                *qualified_name =
+                                    QualifiedTypeName::namespaced(package.clone(), name);
+                            }
+                        }
+                    }
+                }
+                Ok(())
+            });
+        }
+
+        updated_registry
+    }
+}
+
+/// Checks that the registry can be declared in Java: that the serde runtime
+/// has a type for each of its tuples, and that no variant's record has the
+/// name of its enum, which Java forbids.
+///
+/// # Errors
+///
+/// Returns [`Error::Unsupported`] for the first type that can't be declared.
+pub(crate) fn check_registry(registry: &Registry) -> std::result::Result<(), Error> {
+    for (name, container) in registry {
+        let unsupported = |reason| Error::Unsupported {
+            plugin: "java".to_string(),
+            type_name: name.format(ToString::to_string, "."),
+            reason,
+        };
+        let mut size = None;
+        let _ = container.visit(&mut |format| {
+            if let Format::Tuple(formats) = format
+                && formats.len() > MAX_TUPLE_SIZE
+            {
+                size = Some(formats.len());
+            }
+            Ok(())
+        });
+        if let Some(size) = size {
+            return Err(unsupported(format!(
+                "a tuple of {size} elements is larger than the {MAX_TUPLE_SIZE} that the serde runtime supports"
+            )));
+        }
+        if let ContainerFormat::Enum(variants, ..) = container
+            && super::is_union_format(container)
+            && let Some(variant) = variants
+                .values()
+                .find(|variant| variant_class(&variant.name) == name.name)
+        {
+            return Err(unsupported(format!(
+                "the record of variant `{}` would have the name of its enum",
+                variant.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
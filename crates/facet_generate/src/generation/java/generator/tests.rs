@@ -0,0 +1,279 @@
+//! Unit tests for [`JavaCodeGenerator`] — qualified-name resolution, package
+//! declarations and per-language filtering.
+//!
+//! Tests build small [`Registry`](crate::Registry) values by hand (rather than
+//! via the `reflect!` macro) so that package and external-package
+//! configurations can be controlled precisely.
+//!
+//! # Coverage
+//!
+//! | Area | What is tested |
+//! |------|----------------|
+//! | Same-package stripping | `Named` namespace matching the package → bare name, also for generics |
+//! | Other namespaces | Subpackages of the root package (`com.example.types.other.Child`), and the root package seen from them |
+//! | External packages | A `Path` location prefixes the namespace (`com.acme.shared.other.Child`) |
+//! | Filtering | Variants skipped for Java keep their bincode indices |
+//! | Rejection | Registries the serde runtime or Java can't declare |
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use facet::Facet;
+
+use super::*;
+use crate::{
+    self as fg,
+    generation::{
+        CodeGeneratorConfig,
+        bincode::BincodePlugin,
+        config::{ExternalPackage, PackageLocation},
+        plugin::EmitterPlugin,
+    },
+    reflect,
+    reflection::format::{ContainerMetadata, Doc, FieldMetadata, Named},
+};
+
+fn registry_with_struct_field(field_type: Format) -> Registry {
+    let mut registry = Registry::new();
+    let fields = vec![Named {
+        name: "value".to_string(),
+        doc: Doc::new(),
+        value: field_type,
+        metadata: FieldMetadata::default(),
+    }];
+    registry.insert(
+        QualifiedTypeName::root("Holder".to_string()),
+        ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default()),
+    );
+    registry
+}
+
+fn first_field_type(registry: &Registry) -> &Format {
+    let (_, container) = registry.iter().next().unwrap();
+    let ContainerFormat::Struct(fields, _, _) = container else {
+        panic!("expected struct container");
+    };
+    &fields[0].value
+}
+
+fn render_output(
+    generator: JavaCodeGenerator,
+    plugins: Vec<Arc<dyn EmitterPlugin<Java>>>,
+    registry: &Registry,
+) -> String {
+    let generator = generator.with_plugins(plugins);
+    let mut output = Vec::new();
+    generator.output(&mut output, registry).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn update_qualified_names_strips_same_package_namespace() {
+    let config = CodeGeneratorConfig::new("root".to_string());
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "root".to_string(),
+        "Child".to_string(),
+    )));
+
+    let updated = JavaCodeGenerator::new(&config).update_qualified_names(&config, &registry);
+
+    let Format::TypeName(type_name) = first_field_type(&updated) else {
+        panic!("expected type name");
+    };
+    assert_eq!(type_name.namespace, Namespace::Root);
+    assert_eq!(type_name.name, "Child");
+}
+
+#[test]
+fn update_qualified_names_strips_same_package_generic() {
+    let config = CodeGeneratorConfig::new("com.example.types.other".to_string());
+    let registry = registry_with_struct_field(Format::Generic {
+        name: QualifiedTypeName::namespaced("other".to_string(), "Page".to_string()),
+        args: vec![Format::Str],
+    });
+
+    let updated = JavaCodeGenerator::new(&config)
+        .with_package("com.example.types")
+        .update_qualified_names(&config, &registry);
+
+    let Format::Generic { name, .. } = first_field_type(&updated) else {
+        panic!("expected generic");
+    };
+    assert_eq!(name.namespace, Namespace::Root);
+}
+
+#[test]
+fn update_qualified_names_qualifies_other_namespace_with_subpackage() {
+    let config = CodeGeneratorConfig::new("com.example.types".to_string());
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "other".to_string(),
+        "Child".to_string(),
+    )));
+
+    let updated = JavaCodeGenerator::new(&config)
+        .with_package("com.example.types")
+        .update_qualified_names(&config, &registry);
+
+    let Format::TypeName(type_name) = first_field_type(&updated) else {
+        panic!("expected type name");
+    };
+    assert_eq!(
+        type_name.namespace,
+        Namespace::Named("com.example.types.other".to_string())
+    );
+}
+
+#[test]
+fn output_qualifies_root_types_from_subpackage() {
+    let config = CodeGeneratorConfig::new("com.example.types.other".to_string());
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::root(
+        "Parent".to_string(),
+    )));
+
+    let generator = JavaCodeGenerator::new(&config).with_package("com.example.types");
+    let output = render_output(generator, vec![], &registry);
+    insta::assert_snapshot!(output, @"
+    package com.example.types.other;
+
+    public record Holder(com.example.types.Parent value) {}
+    ");
+}
+
+#[test]
+fn output_qualifies_external_package_at_path() {
+    let mut config = CodeGeneratorConfig::new("com.example.types".to_string());
+    config.external_packages = BTreeMap::from([(
+        "other".to_string(),
+        ExternalPackage {
+            for_namespace: "other".to_string(),
+            location: PackageLocation::Path("com.acme.shared".to_string()),
+            module_name: None,
+            version: None,
+        },
+    )]);
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "other".to_string(),
+        "Child".to_string(),
+    )));
+
+    let generator = JavaCodeGenerator::new(&config).with_package("com.example.types");
+    let output = render_output(generator, vec![], &registry);
+    insta::assert_snapshot!(output, @"
+    package com.example.types;
+
+    public record Holder(com.acme.shared.other.Child value) {}
+    ");
+}
+
+#[test]
+fn output_keeps_indices_of_skipped_variants() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Colour {
+        Red,
+        #[facet(fg::skip(java))]
+        Green,
+        Blue,
+    }
+
+    let registry = reflect!(Colour).unwrap();
+    let config = CodeGeneratorConfig::new("root".to_string());
+
+    let generator = JavaCodeGenerator::new(&config);
+    let output = render_output(generator, vec![Arc::new(BincodePlugin)], &registry);
+    insta::assert_snapshot!(output, @r#"
+    package root;
+
+    public enum Colour {
+        RED,
+        BLUE;
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            int index = switch (this) {
+                case RED -> 0;
+                case BLUE -> 2;
+            };
+            serializer.serialize_variant_index(index);
+            serializer.decrease_container_depth();
+        }
+
+        public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+            com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+            serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static Colour deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            int index = deserializer.deserialize_variant_index();
+            Colour value = switch (index) {
+                case 0 -> RED;
+                case 2 -> BLUE;
+                default -> throw new com.novi.serde.DeserializationError("Unknown variant index for Colour: " + index);
+            };
+            deserializer.decrease_container_depth();
+            return value;
+        }
+
+        public static Colour bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+            if (input == null) {
+                throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+            }
+            com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+            Colour value = deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.length) {
+                throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+            }
+            return value;
+        }
+    }
+    "#);
+}
+
+#[test]
+fn rejects_large_tuples() {
+    let registry = registry_with_struct_field(Format::Tuple(vec![Format::U8; 7]));
+    let config = CodeGeneratorConfig::new("root".to_string());
+
+    let generator = JavaCodeGenerator::new(&config);
+    let error = generator.output(&mut Vec::new(), &registry).unwrap_err();
+    insta::assert_snapshot!(error, @"java can't encode `Holder`: a tuple of 7 elements is larger than the 6 that the serde runtime supports");
+}
+
+#[test]
+fn rejects_variants_named_like_their_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Event(String),
+        Other,
+    }
+
+    let registry = reflect!(Event).unwrap();
+    let config = CodeGeneratorConfig::new("root".to_string());
+
+    let generator = JavaCodeGenerator::new(&config);
+    let error = generator.output(&mut Vec::new(), &registry).unwrap_err();
+    insta::assert_snapshot!(error, @"java can't encode `Event`: the record of variant `Event` would have the name of its enum");
+}
+
+#[test]
+fn bincode_rejects_untagged_enums() {
+    #[derive(Facet)]
+    #[facet(untagged)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Value {
+        Number(f64),
+        Text(String),
+    }
+
+    let registry = reflect!(Value).unwrap();
+    let config = CodeGeneratorConfig::new("root".to_string());
+
+    let generator = JavaCodeGenerator::new(&config).with_plugins(vec![Arc::new(BincodePlugin)]);
+    let error = generator.output(&mut Vec::new(), &registry).unwrap_err();
+    insta::assert_snapshot!(error, @"bincode can't encode `Value`: untagged enums don't write the variant index bincode needs");
+}
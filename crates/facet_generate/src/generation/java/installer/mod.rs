@@ -0,0 +1,349 @@
+//! Project scaffolding — writes a Maven project to disk.
+//!
+//! The [`Installer`] is the final stage of the Java generation pipeline.
+//! While [`JavaCodeGenerator`] produces the *contents* of a package, the
+//! installer is responsible for the surrounding project structure:
+//!
+//! 1. **Runtime** — copies the `com.novi.serde` and/or `com.novi.bincode`
+//!    runtime packages into `src/main/java/`.
+//!
+//! 2. **Per-namespace packages** — splits the registry by namespace (via
+//!    [`module::split`]) into the root package and a subpackage of it for each
+//!    other namespace, and calls [`JavaCodeGenerator`] once per type, writing
+//!    each to a file of its own (e.g.
+//!    `src/main/java/com/example/types/other/Child.java`), as Java requires of
+//!    public types.
+//!
+//! 3. **`pom.xml`** — generates a Maven manifest with the project's
+//!    coordinates, the Java release and the external packages it depends on.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::{File, create_dir_all},
+    io::Write as _,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::{
+    Registry,
+    generation::{
+        CodeGeneratorConfig, Error, ExternalPackage, ExternalPackages, PackageLocation,
+        SERDE_NAMESPACE, SourceInstaller,
+        bincode::BincodePlugin,
+        indent::IndentConfig,
+        java::{Java, JavaCodeGenerator, generator},
+        json::JsonPlugin,
+        module,
+        plugin::{self, EmitterPlugin},
+    },
+    reflection::format::Language,
+};
+
+/// The Java release that generated projects need: the types are records and
+/// sealed interfaces.
+const JAVA_RELEASE: &str = "17";
+
+/// The version of generated projects, and of external packages that don't
+/// have one.
+const VERSION: &str = "1.0.0";
+
+/// The directory of a Maven project's Java sources.
+const SOURCE_DIR: &str = "src/main/java";
+
+/// Installer for generated source files in Java.
+///
+/// # Examples
+///
+/// ```rust
+/// use facet_generate::generation::java;
+///
+/// let output_dir = std::path::PathBuf::from("output");
+/// let installer = java::Installer::new("com.example.types", &output_dir);
+/// ```
+pub struct Installer {
+    package: String,
+    install_dir: PathBuf,
+    external_packages: ExternalPackages,
+    indent: IndentConfig,
+    plugins: Vec<Arc<dyn EmitterPlugin<Java>>>,
+}
+
+impl Installer {
+    /// Create a new installer for the given root package and output directory.
+    ///
+    /// The root package (e.g. `com.example.types`) holds the types of the
+    /// root namespace, and a subpackage for each other namespace. Use the
+    /// builder methods [`plugin`](Self::plugin) and
+    /// [`external_packages`](Self::external_packages) to configure, then call
+    /// [`generate`](Self::generate) to produce the output.
+    #[must_use]
+    pub fn new(package: &str, install_dir: impl AsRef<Path>) -> Self {
+        Self {
+            package: package.to_string(),
+            install_dir: install_dir.as_ref().to_path_buf(),
+            external_packages: ExternalPackages::new(),
+            indent: IndentConfig::Space(4),
+            plugins: vec![],
+        }
+    }
+
+    /// Add a plugin to be used during code generation.
+    ///
+    /// When multiple plugins are added, they are invoked in the order they were registered.
+    #[must_use]
+    pub fn plugin<P: EmitterPlugin<Java> + 'static>(mut self, plugin: P) -> Self {
+        self.plugins.push(Arc::new(plugin));
+        self
+    }
+
+    /// Set external packages to reference.
+    #[must_use]
+    pub fn external_packages(mut self, packages: &[ExternalPackage]) -> Self {
+        self.external_packages = packages
+            .iter()
+            .map(|d| (d.for_namespace.clone(), d.clone()))
+            .collect();
+        self
+    }
+
+    /// Set the indentation of generated source files (four spaces by
+    /// default).
+    #[must_use]
+    pub const fn indent(mut self, indent: IndentConfig) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Generate all code for the given registry.
+    ///
+    /// This method:
+    /// 1. Installs the runtimes of the configured plugins
+    /// 2. Splits the registry by namespace and installs each package
+    /// 3. Writes the project's `pom.xml`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any file operation or code generation step fails.
+    pub fn generate(mut self, registry: &Registry) -> Result<(), Error> {
+        let checked = module::for_plugin_checks(registry, Language::Java);
+        let registry = &module::for_language(registry, Language::Java);
+        generator::check_registry(registry)?;
+        plugin::check_registry(&self.plugins, &checked)?;
+
+        if !self.external_packages.contains_key(SERDE_NAMESPACE) {
+            let mut written = BTreeSet::new();
+            for plugin in &self.plugins {
+                for file in plugin.runtime_files() {
+                    if written.insert(file.relative_path.clone()) {
+                        self.install_runtime_file(&file.relative_path, &file.contents)?;
+                    }
+                }
+            }
+        }
+
+        for (m, module_registry) in &module::split(&self.package, registry) {
+            // Namespaces that correspond to external packages are imported
+            // rather than generated.
+            if self
+                .external_packages
+                .contains_key(m.config().module_name())
+            {
+                continue;
+            }
+            let config = m
+                .config()
+                .clone()
+                .with_parent(&self.package)
+                .with_indent(self.indent);
+            self.install_module(&config, module_registry)?;
+        }
+
+        let package = self.package.clone();
+        self.install_manifest(&package)?;
+
+        Ok(())
+    }
+
+    /// Installs the `com.novi.serde` runtime.
+    ///
+    /// Most callers should prefer [`generate`](Self::generate).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any file I/O fails.
+    pub fn install_serde_runtime(&self) -> Result<(), Error> {
+        for file in EmitterPlugin::<Java>::runtime_files(&JsonPlugin) {
+            self.install_runtime_file(&file.relative_path, &file.contents)?;
+        }
+        Ok(())
+    }
+
+    /// Installs the `com.novi.bincode` runtime.
+    ///
+    /// It needs the serde runtime too. Most callers should prefer
+    /// [`generate`](Self::generate).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any file I/O fails.
+    pub fn install_bincode_runtime(&self) -> Result<(), Error> {
+        for file in EmitterPlugin::<Java>::runtime_files(&BincodePlugin)
+            .into_iter()
+            .filter(|f| f.relative_path.starts_with("com/novi/bincode/"))
+        {
+            self.install_runtime_file(&file.relative_path, &file.contents)?;
+        }
+        Ok(())
+    }
+
+    fn install_runtime_file(&self, relative_path: &str, contents: &[u8]) -> Result<(), Error> {
+        let dest = self.install_dir.join(SOURCE_DIR).join(relative_path);
+        if let Some(parent) = dest.parent() {
+            create_dir_all(parent)?;
+        }
+        std::fs::write(&dest, contents)?;
+        Ok(())
+    }
+
+    /// Produce the contents of a Maven `pom.xml`.
+    ///
+    /// The project's group is the root package up to its last `.`, and its
+    /// artifact the rest (e.g. `com.example` and `types` for
+    /// `com.example.types`). External packages at a `Url` are dependencies,
+    /// whose `group:artifact` coordinates are the last segment of the URL, at
+    /// their version or `1.0.0`. Those at a `Path` name the package that
+    /// their types are in, which must be on the classpath, so they aren't
+    /// dependencies.
+    #[must_use]
+    pub fn make_manifest(&self, package: &str) -> String {
+        let (group, artifact) = package.rsplit_once('.').unwrap_or((package, package));
+
+        let plugin_config = CodeGeneratorConfig::new(package.to_string());
+        let lang = self.plugins.iter().fold(
+            Java::new(&plugin_config, &BTreeMap::default()),
+            |lang, p| lang.with_plugin(p.clone()),
+        );
+        let mut dependencies: Vec<String> = lang
+            .plugins()
+            .iter()
+            .flat_map(|p| p.manifest_dependencies())
+            .collect();
+        for external_package in self.external_packages.values() {
+            if let PackageLocation::Url(url) = &external_package.location {
+                let coordinates = url
+                    .trim_end_matches('/')
+                    .rsplit('/')
+                    .next()
+                    .filter(|segment| !segment.is_empty())
+                    .unwrap_or(&external_package.for_namespace);
+                let version = external_package.version.as_deref().unwrap_or(VERSION);
+                dependencies.push(format!("{coordinates}:{version}"));
+            }
+        }
+
+        let mut manifest = format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0"
+         xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"
+         xsi:schemaLocation="http://maven.apache.org/POM/4.0.0 https://maven.apache.org/xsd/maven-4.0.0.xsd">
+    <modelVersion>4.0.0</modelVersion>
+
+    <groupId>{}</groupId>
+    <artifactId>{}</artifactId>
+    <version>{VERSION}</version>
+    <packaging>jar</packaging>
+
+    <properties>
+        <maven.compiler.release>{JAVA_RELEASE}</maven.compiler.release>
+        <project.build.sourceEncoding>UTF-8</project.build.sourceEncoding>
+    </properties>
+"#,
+            xml_escape(group),
+            xml_escape(artifact)
+        );
+        if !dependencies.is_empty() {
+            manifest.push_str("\n    <dependencies>\n");
+            for dependency in &dependencies {
+                manifest.push_str(&dependency_element(dependency));
+            }
+            manifest.push_str("    </dependencies>\n");
+        }
+        manifest.push_str("</project>\n");
+        manifest
+    }
+}
+
+/// The `<dependency>` element of a `group:artifact:version` coordinate, whose
+/// group is also its artifact if it has only two parts.
+fn dependency_element(coordinates: &str) -> String {
+    let parts = coordinates.split(':').map(xml_escape).collect::<Vec<_>>();
+    let (group, artifact, version) = match parts.as_slice() {
+        [group, artifact, version, ..] => (group, artifact, version),
+        [artifact, version] => (artifact, artifact, version),
+        [artifact] => (artifact, artifact, &VERSION.to_string()),
+        [] => unreachable!("split always yields a part"),
+    };
+    format!(
+        "        <dependency>
+            <groupId>{group}</groupId>
+            <artifactId>{artifact}</artifactId>
+            <version>{version}</version>
+        </dependency>
+"
+    )
+}
+
+/// Escapes the characters of `text` that XML reserves.
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl SourceInstaller for Installer {
+    /// Generate the package of one namespace, writing each of its types to
+    /// `src/main/java/<package path>/<Type>.java`.
+    fn install_module(
+        &mut self,
+        config: &CodeGeneratorConfig,
+        registry: &Registry,
+    ) -> Result<(), Error> {
+        let dir = config
+            .module_name()
+            .split('.')
+            .fold(self.install_dir.join(SOURCE_DIR), |dir, part| {
+                dir.join(part)
+            });
+        create_dir_all(&dir)?;
+
+        let mut updated_config = config.clone();
+        updated_config.external_packages = self.external_packages.clone();
+
+        let generator = JavaCodeGenerator::new(&updated_config)
+            .with_package(&self.package)
+            .with_plugins(self.plugins.clone());
+        for (name, format) in registry {
+            if format.metadata().proxy {
+                continue;
+            }
+            let mut file = File::create(dir.join(format!("{}.java", name.name)))?;
+            let single = Registry::from([(name.clone(), format.clone())]);
+            generator.output(&mut file, &single)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write `pom.xml` to the output directory.
+    fn install_manifest(&self, package: &str) -> Result<(), Error> {
+        create_dir_all(&self.install_dir)?;
+        let mut file = File::create(self.install_dir.join("pom.xml"))?;
+        file.write_all(self.make_manifest(package).as_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
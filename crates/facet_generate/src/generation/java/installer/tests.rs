@@ -0,0 +1,246 @@
+//! Tests for the Java [`Installer`] — **project scaffolding**.
+//!
+//! These tests verify the `pom.xml` manifest that the installer generates, and
+//! the layout of the project it writes:
+//!
+//! - Basic manifest structure: coordinates from the root package and the Java
+//!   release, no dependencies.
+//! - External packages: dependencies on the coordinates of their URL, but not
+//!   on those at a path, which are on the classpath.
+//! - Installed files: a file per type in the root package and in a subpackage
+//!   per other namespace, with the runtime packages of the configured plugins
+//!   next to them.
+//! - Registries the plugins can't encode.
+
+use facet::Facet;
+
+use crate::generation::{
+    ExternalPackage, PackageLocation, bincode::BincodePlugin, java::Installer, json::JsonPlugin,
+};
+use crate::reflect;
+
+#[test]
+fn make_manifest_basic() {
+    let installer = Installer::new("com.example.types", "/tmp");
+    let manifest = installer.make_manifest("com.example.types");
+
+    insta::assert_snapshot!(manifest, @r#"
+    <?xml version="1.0" encoding="UTF-8"?>
+    <project xmlns="http://maven.apache.org/POM/4.0.0"
+             xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"
+             xsi:schemaLocation="http://maven.apache.org/POM/4.0.0 https://maven.apache.org/xsd/maven-4.0.0.xsd">
+        <modelVersion>4.0.0</modelVersion>
+
+        <groupId>com.example</groupId>
+        <artifactId>types</artifactId>
+        <version>1.0.0</version>
+        <packaging>jar</packaging>
+
+        <properties>
+            <maven.compiler.release>17</maven.compiler.release>
+            <project.build.sourceEncoding>UTF-8</project.build.sourceEncoding>
+        </properties>
+    </project>
+    "#);
+}
+
+#[test]
+fn make_manifest_with_external_packages() {
+    let external_packages = vec![
+        ExternalPackage {
+            for_namespace: "local_types".to_string(),
+            module_name: None,
+            location: PackageLocation::Path("com.example.local".to_string()),
+            version: None,
+        },
+        ExternalPackage {
+            for_namespace: "remote_types".to_string(),
+            module_name: None,
+            location: PackageLocation::Url(
+                "https://repo.example.com/maven/com.example:remote-types".to_string(),
+            ),
+            version: Some("2.0.0".to_string()),
+        },
+    ];
+
+    let installer =
+        Installer::new("com.example.types", "/tmp").external_packages(&external_packages);
+    let manifest = installer.make_manifest("com.example.types");
+
+    insta::assert_snapshot!(manifest, @r#"
+    <?xml version="1.0" encoding="UTF-8"?>
+    <project xmlns="http://maven.apache.org/POM/4.0.0"
+             xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"
+             xsi:schemaLocation="http://maven.apache.org/POM/4.0.0 https://maven.apache.org/xsd/maven-4.0.0.xsd">
+        <modelVersion>4.0.0</modelVersion>
+
+        <groupId>com.example</groupId>
+        <artifactId>types</artifactId>
+        <version>1.0.0</version>
+        <packaging>jar</packaging>
+
+        <properties>
+            <maven.compiler.release>17</maven.compiler.release>
+            <project.build.sourceEncoding>UTF-8</project.build.sourceEncoding>
+        </properties>
+
+        <dependencies>
+            <dependency>
+                <groupId>com.example</groupId>
+                <artifactId>remote-types</artifactId>
+                <version>2.0.0</version>
+            </dependency>
+        </dependencies>
+    </project>
+    "#);
+}
+
+#[test]
+fn generate_writes_project_layout() {
+    #[derive(Facet)]
+    struct Child {
+        value: i32,
+    }
+
+    mod other {
+        use crate as fg;
+        use facet::Facet;
+
+        #[derive(Facet)]
+        #[facet(fg::namespace = "other")]
+        pub struct Child {
+            value: String,
+        }
+    }
+
+    #[derive(Facet)]
+    struct Parent {
+        child: Child,
+        other_child: other::Child,
+    }
+
+    let registry = reflect!(Parent).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    Installer::new("com.example.types", install_dir.path())
+        .plugin(BincodePlugin)
+        .plugin(JsonPlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let mut files = ignore::Walk::new(install_dir.path())
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_file())
+        .map(|entry| {
+            entry
+                .path()
+                .strip_prefix(install_dir.path())
+                .unwrap()
+                .display()
+                .to_string()
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    insta::assert_snapshot!(files.join("\n"), @"
+    pom.xml
+    src/main/java/com/example/types/Child.java
+    src/main/java/com/example/types/Parent.java
+    src/main/java/com/example/types/other/Child.java
+    src/main/java/com/novi/bincode/BincodeDeserializer.java
+    src/main/java/com/novi/bincode/BincodeSerializer.java
+    src/main/java/com/novi/serde/BinaryDeserializer.java
+    src/main/java/com/novi/serde/BinarySerializer.java
+    src/main/java/com/novi/serde/Bytes.java
+    src/main/java/com/novi/serde/DeserializationError.java
+    src/main/java/com/novi/serde/Deserialize.java
+    src/main/java/com/novi/serde/Deserializer.java
+    src/main/java/com/novi/serde/FromJson.java
+    src/main/java/com/novi/serde/Json.java
+    src/main/java/com/novi/serde/SerializationError.java
+    src/main/java/com/novi/serde/Serialize.java
+    src/main/java/com/novi/serde/Serializer.java
+    src/main/java/com/novi/serde/Slice.java
+    src/main/java/com/novi/serde/ToJson.java
+    src/main/java/com/novi/serde/Tuple2.java
+    src/main/java/com/novi/serde/Tuple3.java
+    src/main/java/com/novi/serde/Tuple4.java
+    src/main/java/com/novi/serde/Tuple5.java
+    src/main/java/com/novi/serde/Tuple6.java
+    src/main/java/com/novi/serde/Unit.java
+    ");
+
+    let parent = std::fs::read_to_string(
+        install_dir
+            .path()
+            .join("src/main/java/com/example/types/Parent.java"),
+    )
+    .unwrap();
+    let declaration = parent
+        .split("\n\n")
+        .take(2)
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    insta::assert_snapshot!(declaration, @"
+    package com.example.types;
+
+    public record Parent(Child child, com.example.types.other.Child otherChild) {
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            this.child.serialize(serializer);
+            this.otherChild.serialize(serializer);
+            serializer.decrease_container_depth();
+        }
+    ");
+}
+
+#[test]
+fn generate_skips_external_serde_runtime() {
+    #[derive(Facet)]
+    struct Child {
+        value: i32,
+    }
+
+    let registry = reflect!(Child).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    Installer::new("com.example.types", install_dir.path())
+        .plugin(BincodePlugin)
+        .external_packages(&[ExternalPackage {
+            for_namespace: "serde".to_string(),
+            module_name: None,
+            location: PackageLocation::Url(
+                "https://repo.example.com/maven/com.novi:serde".to_string(),
+            ),
+            version: Some("0.1.0".to_string()),
+        }])
+        .generate(&registry)
+        .unwrap();
+
+    assert!(!install_dir.path().join("src/main/java/com/novi").exists());
+    assert!(
+        install_dir
+            .path()
+            .join("src/main/java/com/example/types/Child.java")
+            .exists()
+    );
+}
+
+#[test]
+fn json_rejects_internally_tagged_tuple_variants() {
+    #[derive(Facet)]
+    #[facet(tag = "type")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point(i32, i32),
+        Empty,
+    }
+
+    let registry = reflect!(Shape).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    let installer = Installer::new("com.example.types", install_dir.path()).plugin(JsonPlugin);
+
+    let error = installer.generate(&registry).unwrap_err();
+    insta::assert_snapshot!(error, @"json (Java) can't encode `Shape`: internally tagged enums can't have tuple variants");
+}
@@ -0,0 +1,39 @@
+//! Java code generation.
+//!
+//! This module translates a [`Registry`](crate::Registry) of reflected type
+//! definitions into Java 17 source code, for JVM consumers that don't use
+//! Kotlin.
+//!
+//! # Submodules (in pipeline order)
+//!
+//! 1. **generator** — Top-level orchestrator. [`JavaCodeGenerator`](crate::generation::java::JavaCodeGenerator) implements
+//!    [`CodeGenerator`](crate::generation::CodeGenerator) to produce Java source
+//!    from a registry. It resolves qualified type names against the
+//!    configuration (external packages, namespaces), and delegates writing to the emitter layer.
+//!
+//! 2. **emitter** — AST-to-source rendering. Implements
+//!    [`Emitter<Java>`](crate::generation::Emitter) for each AST node type
+//!    ([`Module`](crate::generation::module::Module), [`Container`](crate::generation::Container),
+//!    `Named<Format>`, `Format`, `Doc`). This is where the Java language
+//!    mapping lives: records for structs, `enum`s for all-unit enums, and
+//!    sealed interfaces with a nested record per variant for enums whose
+//!    variants carry data.
+//!
+//! 3. **installer** — Project scaffolding. [`Installer`](crate::generation::java::Installer) implements
+//!    [`SourceInstaller`](crate::generation::SourceInstaller) to write a Maven
+//!    project: it copies the `com.novi.serde` runtime into it, splits the
+//!    registry by namespace into one package per namespace, writes each type
+//!    to its own source file, and generates a `pom.xml`.
+
+pub use emitter::Java;
+pub use generator::JavaCodeGenerator;
+pub use installer::Installer;
+
+pub(crate) use emitter::{
+    Scope, enum_constant, field_name, is_union_format, string_literal, type_params_decl,
+    variant_class, variant_fields, write_call,
+};
+
+mod emitter;
+mod generator;
+mod installer;
@@ -2,8 +2,10 @@
 //!
 //! Provides JSON-specific code generation for C# types: `System.Text.Json`
 //! `using` directives, `[JsonPropertyName]` / `[JsonPolymorphic]` /
-//! `[JsonDerivedType]` / `[JsonConverter]` annotations, and
-//! `JsonSerialize` / `JsonDeserialize` convenience methods.
+//! `[JsonDerivedType]` / `[JsonConverter]` annotations,
+//! `JsonSerialize` / `JsonDeserialize` convenience methods, and converters
+//! for enums with a catch-all variant and for untagged, internally tagged and
+//! adjacently tagged enums.
 //!
 //! # What this plugin handles
 //!
@@ -11,21 +13,25 @@
 //! |---|---|
 //! | `imports` | `using Facet.Runtime.Json;` + `using System.Text.Json.Serialization;` |
 //! | `type_annotations` | `[JsonConverter]` (unit enums), `[JsonPolymorphic]` + `[JsonDerivedType(…)]` (variant hierarchies) |
+//! | `type_conformances` | `IBranded` (branded newtypes) |
 //! | `field_annotations` | `[JsonPropertyName("camelCaseName")]` |
 //! | `has_type_body` | `true` for non-unit-enum types |
 //! | `type_body` | `JsonSerialize` / `JsonDeserialize` static helper methods |
+//! | `after_type` | `{Name}JsonConverter` (enums with a catch-all variant or that aren't externally tagged) |
+//! | `check_registry` | Rejects generic enums that aren't externally tagged |
 
-use std::io;
+use std::{collections::BTreeMap, io};
 
 use heck::{ToLowerCamelCase, ToUpperCamelCase};
 
+use crate::Registry;
 use crate::generation::{
-    CodeGeneratorConfig, Feature,
-    csharp::CSharp,
+    CodeGeneratorConfig, Error, Feature,
+    csharp::{CSharp, access_modifier},
     indent::{IndentWrite, Newlines, with_block},
     plugin::{EmitContext, EmitterPlugin, RuntimeFile},
 };
-use crate::reflection::format::{ContainerFormat, Format, Named, VariantFormat};
+use crate::reflection::format::{ContainerFormat, EnumTagging, Format, Named, VariantFormat};
 
 use super::JsonPlugin;
 
@@ -34,6 +40,13 @@ use super::JsonPlugin;
 // ---------------------------------------------------------------------------
 
 impl EmitterPlugin<CSharp> for JsonPlugin {
+    /// Rejects generic enums that aren't externally tagged, which get no
+    /// converter (see [`type_annotations`](Self::type_annotations)), and
+    /// internally tagged tuple variants.
+    fn check_registry(&self, registry: &Registry) -> Result<(), Error> {
+        super::check_enums(registry, "C#", |tagging| *tagging != EnumTagging::External)
+    }
+
     /// Returns the core, serde, and JSON C# runtime sources to be written
     /// into the output directory alongside the generated code.
     fn runtime_files(&self) -> Vec<RuntimeFile> {
@@ -74,15 +87,27 @@ impl EmitterPlugin<CSharp> for JsonPlugin {
     /// Returns `using` directives for JSON support.
     ///
     /// Always includes `Facet.Runtime.Json` and `System.Text.Json.Serialization`.
-    /// When `Feature::Uuid` is active, also adds `System` so that `Guid`
-    /// resolves — `System.Text.Json` serializes `Guid` as a hyphenated
-    /// lowercase UUID string automatically.
+    /// When `Feature::Uuid` or a date/time feature is active, also adds
+    /// `System` so that `Guid` and the date/time types resolve —
+    /// `System.Text.Json` serializes `Guid` as a hyphenated lowercase UUID
+    /// string, and `DateTimeOffset`, `DateOnly` and `TimeOnly` as ISO 8601
+    /// strings, automatically. `TimeSpan` goes through the runtime's
+    /// `DurationJsonConverter`.
     fn imports(&self, config: &CodeGeneratorConfig) -> Vec<String> {
         let mut imports = vec![
             "using Facet.Runtime.Json;".to_string(),
             "using System.Text.Json.Serialization;".to_string(),
         ];
-        if config.features.contains(&Feature::Uuid) {
+        if [
+            Feature::Uuid,
+            Feature::Timestamp,
+            Feature::Date,
+            Feature::Time,
+            Feature::Duration,
+        ]
+        .iter()
+        .any(|feature| config.features.contains(feature))
+        {
             imports.push("using System;".to_string());
         }
         imports
@@ -93,10 +118,24 @@ impl EmitterPlugin<CSharp> for JsonPlugin {
     /// - All-unit enum → `[JsonConverter(typeof(JsonStringEnumConverter))]`
     /// - Non-unit enum (variant hierarchy) → `[JsonPolymorphic(…)]` +
     ///   one `[JsonDerivedType(…)]` per variant
+    /// - Enum that isn't externally tagged, or has a catch-all variant →
+    ///   `[JsonConverter(typeof(…JsonConverter))]`, the converter emitted by
+    ///   [`after_type`](Self::after_type)
     /// - Everything else → nothing
+    ///
+    /// Generic variant hierarchies get no annotations: attribute arguments
+    /// cannot refer to type parameters, so `System.Text.Json` polymorphism is
+    /// unavailable for them.
     fn type_annotations(&self, ctx: &EmitContext) -> Vec<String> {
+        if !ctx.type_params().is_empty() {
+            return vec![];
+        }
         match ctx.container.format {
-            ContainerFormat::Enum(variants, _, _) => {
+            ContainerFormat::Enum(variants, tagging, _, _) => {
+                if *tagging != EnumTagging::External || other_variant(variants).is_some() {
+                    let name = ctx.name().to_upper_camel_case();
+                    return vec![format!("[JsonConverter(typeof({name}JsonConverter))]")];
+                }
                 let all_unit = variants
                     .values()
                     .all(|v| matches!(v.value, VariantFormat::Unit));
@@ -121,6 +160,16 @@ impl EmitterPlugin<CSharp> for JsonPlugin {
         }
     }
 
+    /// Branded newtypes implement the runtime's `IBranded` marker interface, so that
+    /// `JsonSerde` reads and writes them as the value they wrap.
+    fn type_conformances(&self, ctx: &EmitContext) -> Vec<String> {
+        if ctx.container.format.is_branded() {
+            vec!["IBranded".to_string()]
+        } else {
+            vec![]
+        }
+    }
+
     /// Emits `[JsonPropertyName("camelCaseName")]` before each field.
     fn field_annotations(&self, field: &Named<Format>, _ctx: &EmitContext) -> Vec<String> {
         vec![format!(
@@ -133,7 +182,7 @@ impl EmitterPlugin<CSharp> for JsonPlugin {
     /// methods — i.e. everything except all-unit enums (plain C# `enum` types
     /// don't need instance helpers).
     fn has_type_body(&self, ctx: &EmitContext) -> bool {
-        if let ContainerFormat::Enum(variants, _, _) = ctx.container.format
+        if let ContainerFormat::Enum(variants, _, _, _) = ctx.container.format
             && variants
                 .values()
                 .all(|v| matches!(v.value, VariantFormat::Unit))
@@ -145,15 +194,468 @@ impl EmitterPlugin<CSharp> for JsonPlugin {
 
     /// Emits `JsonSerialize` and `JsonDeserialize` convenience methods.
     fn type_body(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
-        let type_name = ctx.name().to_upper_camel_case();
+        let mut type_name = ctx.name().to_upper_camel_case();
+        if !ctx.type_params().is_empty() {
+            type_name = format!("{type_name}<{}>", ctx.type_params().join(", "));
+        }
         write_json_helpers(w, &type_name)
     }
+
+    /// Emits the converter of an enum with a catch-all variant, which reads
+    /// the names it doesn't know (e.g. those of variants added by a later
+    /// version) as the catch-all. `System.Text.Json` can't do this by itself:
+    /// its polymorphism throws on unknown type discriminators.
+    ///
+    /// Untagged, internally tagged and adjacently tagged enums get a converter
+    /// that encodes them as serde does, which also reads unknown tags as the
+    /// catch-all.
+    fn after_type(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
+        if !ctx.type_params().is_empty() {
+            return Ok(());
+        }
+        let access = access_modifier(
+            ctx.config
+                .visibility(ctx.container.format.metadata().visibility),
+        );
+        let name = ctx.name().to_upper_camel_case();
+        if let ContainerFormat::Enum(variants, tagging, _, _) = ctx.container.format
+            && *tagging != EnumTagging::External
+        {
+            let summary = match tagging {
+                EnumTagging::Internal { tag } => format!(
+                    "the internally tagged <see cref=\"{name}\"/>, whose tag is <c>\"{tag}\"</c>"
+                ),
+                EnumTagging::Adjacent { tag, content } => format!(
+                    "the adjacently tagged <see cref=\"{name}\"/>, whose tag is <c>\"{tag}\"</c> and content <c>\"{content}\"</c>"
+                ),
+                _ => format!(
+                    "the untagged <see cref=\"{name}\"/>, which reads the first variant that matches"
+                ),
+            };
+            writeln!(w)?;
+            writeln!(w, "/// <summary>")?;
+            writeln!(w, "/// JSON converter for {summary}.")?;
+            writeln!(w, "/// </summary>")?;
+            write!(
+                w,
+                "{access} sealed class {name}JsonConverter : JsonConverter<{name}> "
+            )?;
+            with_block(w, Newlines::BOTH, |w| {
+                write_enum_converter(w, &name, variants, tagging)
+            })?;
+        } else if let ContainerFormat::Enum(variants, _, _, _) = ctx.container.format
+            && let Some(other) = other_variant(variants)
+        {
+            let other = other.name.to_upper_camel_case();
+            writeln!(w)?;
+            writeln!(w, "/// <summary>")?;
+            writeln!(
+                w,
+                "/// JSON converter for <see cref=\"{name}\"/> that reads unknown variants as <see cref=\"{name}.{other}\"/>."
+            )?;
+            writeln!(w, "/// </summary>")?;
+            write!(
+                w,
+                "{access} sealed class {name}JsonConverter : JsonConverter<{name}> "
+            )?;
+            with_block(w, Newlines::BOTH, |w| {
+                if is_all_unit(variants) {
+                    write_unit_enum_converter(w, &name, &other, variants)
+                } else {
+                    write_variant_hierarchy_converter(w, &name, &other, variants)
+                }
+            })?;
+        }
+        Ok(())
+    }
 }
 
 // ---------------------------------------------------------------------------
-// Helper
+// Helpers
 // ---------------------------------------------------------------------------
 
+/// Returns `true` when every variant is [`VariantFormat::Unit`], i.e. the
+/// enum is emitted as a C# `enum`.
+fn is_all_unit(variants: &BTreeMap<u32, Named<VariantFormat>>) -> bool {
+    variants
+        .values()
+        .all(|v| matches!(v.value, VariantFormat::Unit))
+}
+
+/// The catch-all (`#[facet(other)]`) variant of an enum, if it has one.
+fn other_variant(variants: &BTreeMap<u32, Named<VariantFormat>>) -> Option<&Named<VariantFormat>> {
+    variants.values().find(|variant| variant.metadata.other)
+}
+
+/// Writes the `Read` / `Write` overrides of a C# `enum` converter, which
+/// (like `JsonStringEnumConverter`) uses the member names as strings.
+fn write_unit_enum_converter(
+    w: &mut dyn IndentWrite,
+    name: &str,
+    other: &str,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+) -> io::Result<()> {
+    write!(
+        w,
+        "public override {name} Read(ref System.Text.Json.Utf8JsonReader reader, System.Type typeToConvert, System.Text.Json.JsonSerializerOptions options) "
+    )?;
+    with_block(w, Newlines::BOTH, |w| {
+        write!(w, "return reader.GetString() switch ")?;
+        with_block(w, Newlines::OPEN, |w| {
+            for variant in variants.values() {
+                let variant = variant.name.to_upper_camel_case();
+                writeln!(w, "\"{variant}\" => {name}.{variant},")?;
+            }
+            writeln!(w, "_ => {name}.{other},")
+        })?;
+        writeln!(w, ";")
+    })?;
+    writeln!(w)?;
+    write!(
+        w,
+        "public override void Write(System.Text.Json.Utf8JsonWriter writer, {name} value, System.Text.Json.JsonSerializerOptions options) "
+    )?;
+    with_block(w, Newlines::BOTH, |w| {
+        writeln!(w, "writer.WriteStringValue(value.ToString());")
+    })
+}
+
+/// Writes the `Read` / `Write` overrides of a variant hierarchy converter,
+/// which stands in for `[JsonPolymorphic]` with the same `"type"`
+/// discriminator.
+fn write_variant_hierarchy_converter(
+    w: &mut dyn IndentWrite,
+    name: &str,
+    other: &str,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+) -> io::Result<()> {
+    write!(
+        w,
+        "public override {name} Read(ref System.Text.Json.Utf8JsonReader reader, System.Type typeToConvert, System.Text.Json.JsonSerializerOptions options) "
+    )?;
+    with_block(w, Newlines::BOTH, |w| {
+        writeln!(
+            w,
+            "using var document = System.Text.Json.JsonDocument.ParseValue(ref reader);"
+        )?;
+        writeln!(w, "var element = document.RootElement;")?;
+        writeln!(
+            w,
+            "var type = element.TryGetProperty(\"type\", out var tag) ? tag.GetString() : null;"
+        )?;
+        write!(w, "return type switch ")?;
+        with_block(w, Newlines::OPEN, |w| {
+            for variant in variants.values() {
+                let variant_name = variant.name.to_upper_camel_case();
+                writeln!(
+                    w,
+                    "\"{}\" => System.Text.Json.JsonSerializer.Deserialize<{name}.{variant_name}>(element, options)!,",
+                    variant.name
+                )?;
+            }
+            writeln!(w, "_ => new {name}.{other}(),")
+        })?;
+        writeln!(w, ";")
+    })?;
+    writeln!(w)?;
+    write!(
+        w,
+        "public override void Write(System.Text.Json.Utf8JsonWriter writer, {name} value, System.Text.Json.JsonSerializerOptions options) "
+    )?;
+    with_block(w, Newlines::BOTH, |w| {
+        write!(w, "var type = value switch ")?;
+        with_block(w, Newlines::OPEN, |w| {
+            for variant in variants.values() {
+                let variant_name = variant.name.to_upper_camel_case();
+                writeln!(w, "{name}.{variant_name} => \"{}\",", variant.name)?;
+            }
+            writeln!(
+                w,
+                "_ => throw new System.ArgumentOutOfRangeException(nameof(value)),"
+            )
+        })?;
+        writeln!(w, ";")?;
+        writeln!(w, "writer.WriteStartObject();")?;
+        writeln!(w, "writer.WriteString(\"type\", type);")?;
+        write!(
+            w,
+            "foreach (var property in System.Text.Json.JsonSerializer.SerializeToElement(value, value.GetType(), options).EnumerateObject()) "
+        )?;
+        with_block(w, Newlines::BOTH, |w| {
+            writeln!(w, "property.WriteTo(writer);")
+        })?;
+        writeln!(w, "writer.WriteEndObject();")
+    })
+}
+
+/// Writes the `Read` / `Write` overrides of the converter of an enum that
+/// isn't externally tagged, which `System.Text.Json` polymorphism can't encode.
+///
+/// The payload of a variant is written as serde does: a newtype as its value,
+/// a tuple as an array and a struct as an object keyed by the Rust field
+/// names. An untagged enum writes the payload alone (`null` for a unit
+/// variant) and reads the first variant that matches. An internally tagged
+/// one adds the tag to the payload's object, and an adjacently tagged one
+/// writes the tag and the payload under their own keys. Payloads are read
+/// through the variant record by way of an object keyed by its property names.
+fn write_enum_converter(
+    w: &mut dyn IndentWrite,
+    name: &str,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+    tagging: &EnumTagging,
+) -> io::Result<()> {
+    const JSON: &str = "System.Text.Json";
+    let all_unit = is_all_unit(variants);
+    // Members of a C# `enum` are values, variant records are constructed.
+    let unit = |variant: &str| {
+        if all_unit {
+            format!("{name}.{variant}")
+        } else {
+            format!("new {name}.{variant}()")
+        }
+    };
+    // The properties of a variant record, read from the payload in `content`.
+    let properties = |variant: &VariantFormat, content: &str| match variant {
+        VariantFormat::NewType(_) => format!(r#"("Value", {content})"#),
+        VariantFormat::Tuple(formats) => (0..formats.len())
+            .map(|i| format!(r#"("Field{i}", {content}[{i}])"#))
+            .collect::<Vec<_>>()
+            .join(", "),
+        VariantFormat::Struct(fields) => fields
+            .iter()
+            .map(|field| {
+                format!(
+                    r#"("{}", JsonSerde.Property({content}, "{}"))"#,
+                    field.name.to_upper_camel_case(),
+                    field.name
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
+        VariantFormat::Unit | VariantFormat::Variable(_) => String::new(),
+    };
+
+    writeln!(w, "public override bool HandleNull => true;")?;
+    writeln!(w)?;
+    write!(
+        w,
+        "public override {name} Read(ref {JSON}.Utf8JsonReader reader, System.Type typeToConvert, {JSON}.JsonSerializerOptions options) "
+    )?;
+    with_block(w, Newlines::BOTH, |w| {
+        writeln!(
+            w,
+            "using var document = {JSON}.JsonDocument.ParseValue(ref reader);"
+        )?;
+        writeln!(w, "var element = document.RootElement;")?;
+        let (tag, content) = match tagging {
+            EnumTagging::Internal { tag } => (tag, "element".to_string()),
+            EnumTagging::Adjacent { tag, content } => {
+                (tag, format!(r#"element.GetProperty("{content}")"#))
+            }
+            EnumTagging::External | EnumTagging::Untagged => {
+                return write_untagged_read(w, name, variants, &unit, &properties);
+            }
+        };
+        writeln!(
+            w,
+            r#"var tag = element.TryGetProperty("{tag}", out var property) ? property.GetString() : null;"#
+        )?;
+        write!(w, "return tag switch ")?;
+        with_block(w, Newlines::OPEN, |w| {
+            for variant in variants.values() {
+                let variant_name = variant.name.to_upper_camel_case();
+                let value = if variant.value == VariantFormat::Unit {
+                    unit(&variant_name)
+                } else {
+                    format!(
+                        "{JSON}.JsonSerializer.Deserialize<{name}.{variant_name}>(JsonSerde.ToObject({}), options)!",
+                        properties(&variant.value, &content)
+                    )
+                };
+                writeln!(w, r#""{}" => {value},"#, variant.name)?;
+            }
+            // Unknown variants, e.g. added by a later version, are read as the catch-all.
+            match other_variant(variants) {
+                Some(other) => writeln!(w, "_ => {},", unit(&other.name.to_upper_camel_case())),
+                None => writeln!(
+                    w,
+                    r#"_ => throw new {JSON}.JsonException($"Unknown variant of {name}: {{tag}}"),"#
+                ),
+            }
+        })?;
+        writeln!(w, ";")
+    })?;
+    writeln!(w)?;
+    write!(
+        w,
+        "public override void Write({JSON}.Utf8JsonWriter writer, {name} value, {JSON}.JsonSerializerOptions options) "
+    )?;
+    with_block(w, Newlines::BOTH, |w| {
+        let tag = match tagging {
+            EnumTagging::Internal { tag } | EnumTagging::Adjacent { tag, .. } => Some(tag),
+            EnumTagging::External | EnumTagging::Untagged => None,
+        };
+        if all_unit {
+            let Some(tag) = tag else {
+                return writeln!(w, "writer.WriteNullValue();");
+            };
+            write!(w, "var tag = value switch ")?;
+            with_block(w, Newlines::OPEN, |w| {
+                for variant in variants.values() {
+                    let variant_name = variant.name.to_upper_camel_case();
+                    writeln!(w, "{name}.{variant_name} => \"{}\",", variant.name)?;
+                }
+                writeln!(
+                    w,
+                    "_ => throw new System.ArgumentOutOfRangeException(nameof(value)),"
+                )
+            })?;
+            writeln!(w, ";")?;
+            writeln!(w, "writer.WriteStartObject();")?;
+            writeln!(w, "writer.WriteString(\"{tag}\", tag);")?;
+            return writeln!(w, "writer.WriteEndObject();");
+        }
+        writeln!(
+            w,
+            "var element = {JSON}.JsonSerializer.SerializeToElement(value, value.GetType(), options);"
+        )?;
+        if tag.is_some() {
+            writeln!(w, "writer.WriteStartObject();")?;
+        }
+        write!(w, "switch (value) ")?;
+        with_block(w, Newlines::BOTH, |w| {
+            for variant in variants.values() {
+                let variant_name = variant.name.to_upper_camel_case();
+                writeln!(w, "case {name}.{variant_name}:")?;
+                w.indent();
+                if let Some(tag) = tag {
+                    writeln!(w, "writer.WriteString(\"{tag}\", \"{}\");", variant.name)?;
+                }
+                match (tagging, &variant.value) {
+                    (
+                        EnumTagging::Internal { .. } | EnumTagging::Adjacent { .. },
+                        VariantFormat::Unit,
+                    ) => {}
+                    (EnumTagging::Internal { .. }, VariantFormat::NewType(_)) => writeln!(
+                        w,
+                        r#"foreach (var payload in element.GetProperty("Value").EnumerateObject()) payload.WriteTo(writer);"#
+                    )?,
+                    (EnumTagging::Internal { .. }, VariantFormat::Struct(fields)) => {
+                        write_struct_properties(w, fields)?;
+                    }
+                    (EnumTagging::Adjacent { content, .. }, payload) => {
+                        writeln!(w, "writer.WritePropertyName(\"{content}\");")?;
+                        write_payload(w, payload)?;
+                    }
+                    (_, payload) => write_payload(w, payload)?,
+                }
+                writeln!(w, "break;")?;
+                w.unindent();
+            }
+            writeln!(w, "default:")?;
+            writeln!(
+                w,
+                "    throw new System.ArgumentOutOfRangeException(nameof(value));"
+            )
+        })?;
+        if tag.is_some() {
+            writeln!(w, "writer.WriteEndObject();")?;
+        }
+        Ok(())
+    })
+}
+
+/// Writes the body of an untagged enum converter's `Read`, which tries the
+/// variants in order.
+fn write_untagged_read(
+    w: &mut dyn IndentWrite,
+    name: &str,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+    unit: &dyn Fn(&str) -> String,
+    properties: &dyn Fn(&VariantFormat, &str) -> String,
+) -> io::Result<()> {
+    const JSON: &str = "System.Text.Json";
+    for (i, variant) in variants.values().enumerate() {
+        let variant_name = variant.name.to_upper_camel_case();
+        let record = format!("{name}.{variant_name}");
+        let shape = match &variant.value {
+            VariantFormat::Unit => {
+                writeln!(
+                    w,
+                    "if (element.ValueKind == {JSON}.JsonValueKind.Null) return {};",
+                    unit(&variant_name)
+                )?;
+                continue;
+            }
+            VariantFormat::NewType(_) => String::new(),
+            VariantFormat::Tuple(formats) => format!(
+                "element.ValueKind == {JSON}.JsonValueKind.Array && element.GetArrayLength() == {} && ",
+                formats.len()
+            ),
+            // `System.Text.Json` fills in missing constructor parameters, so
+            // the required ones are checked up front.
+            VariantFormat::Struct(fields) => fields
+                .iter()
+                .filter(|field| {
+                    !matches!(field.value, Format::Option(_)) && field.metadata.default.is_none()
+                })
+                .map(|field| format!(r#"element.TryGetProperty("{}", out _) && "#, field.name))
+                .fold(
+                    format!("element.ValueKind == {JSON}.JsonValueKind.Object && "),
+                    |shape, check| shape + &check,
+                ),
+            VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+        };
+        writeln!(
+            w,
+            "if ({shape}JsonSerde.TryDeserialize<{record}>(JsonSerde.ToObject({}), options, out var variant{i})) return variant{i};",
+            properties(&variant.value, "element")
+        )?;
+    }
+    writeln!(
+        w,
+        r#"throw new {JSON}.JsonException("No variant of {name} matches the input");"#
+    )
+}
+
+/// Writes a variant's payload, serialized to the record `element`, as a JSON
+/// value of its own.
+fn write_payload(w: &mut dyn IndentWrite, payload: &VariantFormat) -> io::Result<()> {
+    match payload {
+        VariantFormat::Unit => writeln!(w, "writer.WriteNullValue();"),
+        VariantFormat::NewType(_) => {
+            writeln!(w, r#"element.GetProperty("Value").WriteTo(writer);"#)
+        }
+        VariantFormat::Tuple(formats) => {
+            writeln!(w, "writer.WriteStartArray();")?;
+            for i in 0..formats.len() {
+                writeln!(w, r#"element.GetProperty("Field{i}").WriteTo(writer);"#)?;
+            }
+            writeln!(w, "writer.WriteEndArray();")
+        }
+        VariantFormat::Struct(fields) => {
+            writeln!(w, "writer.WriteStartObject();")?;
+            write_struct_properties(w, fields)?;
+            writeln!(w, "writer.WriteEndObject();")
+        }
+        VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+    }
+}
+
+/// Writes the properties of a struct variant's record `element` under their
+/// Rust field names.
+fn write_struct_properties(w: &mut dyn IndentWrite, fields: &[Named<Format>]) -> io::Result<()> {
+    for field in fields {
+        writeln!(w, "writer.WritePropertyName(\"{}\");", field.name)?;
+        writeln!(
+            w,
+            "element.GetProperty(\"{}\").WriteTo(writer);",
+            field.name.to_upper_camel_case()
+        )?;
+    }
+    Ok(())
+}
+
 /// Writes `JsonSerialize` / `JsonDeserialize` methods backed by `JsonSerde`.
 fn write_json_helpers(w: &mut dyn IndentWrite, type_name: &str) -> io::Result<()> {
     writeln!(w, "public string JsonSerialize()")?;
@@ -181,7 +683,7 @@ mod tests {
         plugin::EmitContext,
     };
     use crate::reflection::format::{
-        ContainerFormat, Doc, EnumTagging, Format, Named, QualifiedTypeName,
+        ContainerFormat, ContainerMetadata, Doc, EnumTagging, Format, Named, QualifiedTypeName,
     };
 
     fn render(f: impl FnOnce(&mut dyn IndentWrite) -> io::Result<()>) -> String {
@@ -221,7 +723,12 @@ mod tests {
         variants.insert(1u32, Named::new(&VariantFormat::Unit, "Beta".to_string()));
 
         let name = QualifiedTypeName::root("MyEnum".to_string());
-        let format = ContainerFormat::Enum(variants, EnumTagging::External, Doc::default());
+        let format = ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::default(),
+            ContainerMetadata::default(),
+        );
         let container = Container {
             name: &name,
             format: &format,
@@ -258,7 +765,12 @@ mod tests {
         );
 
         let name = QualifiedTypeName::root("Result".to_string());
-        let format = ContainerFormat::Enum(variants, EnumTagging::External, Doc::default());
+        let format = ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::default(),
+            ContainerMetadata::default(),
+        );
         let container = Container {
             name: &name,
             format: &format,
@@ -290,7 +802,7 @@ mod tests {
         let plugin = &JsonPlugin as &dyn EmitterPlugin<CSharp>;
 
         let name = QualifiedTypeName::root("Foo".to_string());
-        let format = ContainerFormat::Struct(vec![], Doc::default());
+        let format = ContainerFormat::Struct(vec![], Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -312,7 +824,7 @@ mod tests {
         let field = Named::new(&Format::Str, "firstName".to_string());
 
         let name = QualifiedTypeName::root("Foo".to_string());
-        let format = ContainerFormat::Struct(vec![], Doc::default());
+        let format = ContainerFormat::Struct(vec![], Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -332,7 +844,7 @@ mod tests {
         let field = Named::new(&Format::I32, "MyField".to_string());
 
         let name = QualifiedTypeName::root("Foo".to_string());
-        let format = ContainerFormat::Struct(vec![], Doc::default());
+        let format = ContainerFormat::Struct(vec![], Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -353,7 +865,7 @@ mod tests {
         let plugin = &JsonPlugin as &dyn EmitterPlugin<CSharp>;
 
         let name = QualifiedTypeName::root("Foo".to_string());
-        let format = ContainerFormat::Struct(vec![], Doc::default());
+        let format = ContainerFormat::Struct(vec![], Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -372,7 +884,12 @@ mod tests {
         variants.insert(0u32, Named::new(&VariantFormat::Unit, "A".to_string()));
 
         let name = QualifiedTypeName::root("MyEnum".to_string());
-        let format = ContainerFormat::Enum(variants, EnumTagging::External, Doc::default());
+        let format = ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::default(),
+            ContainerMetadata::default(),
+        );
         let container = Container {
             name: &name,
             format: &format,
@@ -392,7 +909,7 @@ mod tests {
         let plugin = &JsonPlugin as &dyn EmitterPlugin<CSharp>;
 
         let name = QualifiedTypeName::root("MyRecord".to_string());
-        let format = ContainerFormat::UnitStruct(Doc::default());
+        let format = ContainerFormat::UnitStruct(Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -0,0 +1,729 @@
+//! `EmitterPlugin<Dart>` implementation for the JSON plugin.
+//!
+//! Writes a `toJson` method and a `fromJson` factory constructor into the body
+//! of every top-level Dart type. They convert to and from the values that
+//! `dart:convert`'s `jsonEncode` / `jsonDecode` work with (`Map`, `List`,
+//! `String`, `num`, `bool` and `null`), in the shapes that `serde_json` gives
+//! the Rust types.
+//!
+//! Generic types take a converter per type parameter (`toJsonT` /
+//! `fromJsonT`), so generic enums are supported in every tagging.
+
+use std::collections::BTreeMap;
+use std::io::{self, Result, Write};
+
+use heck::ToSnakeCase;
+
+use super::JsonPlugin;
+use crate::Registry;
+use crate::generation::{
+    CodeGeneratorConfig, Error, SERDE_NAMESPACE,
+    dart::{
+        Dart, enum_value, field_default, identifier, import_uri, string_literal, variant_class,
+    },
+    indent::{IndentWrite, IndentedWriter, Newlines},
+    plugin::{EmitContext, EmitterPlugin, RuntimeFile},
+};
+use crate::reflection::format::{
+    ContainerFormat, EnumTagging, Format, Named, QualifiedTypeName, VariantFormat,
+};
+
+/// The converter parameters of `toJson` (e.g. `Object? Function(T) toJsonT`).
+fn to_json_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| format!("Object? Function({param}) toJson{param}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The converter parameters of `fromJson` (e.g. `, T Function(Object?) fromJsonT`).
+fn from_json_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", {param} Function(Object?) fromJson{param}"))
+        .collect()
+}
+
+/// The name of a type as the generated code refers to it, e.g. `other.Child`.
+fn type_name(name: &QualifiedTypeName) -> String {
+    name.format(|namespace| namespace.to_snake_case(), ".")
+}
+
+/// Whether values of `format` are already JSON values, so they convert to JSON as they are.
+fn is_json(format: &Format) -> bool {
+    match format {
+        Format::Bool
+        | Format::I8
+        | Format::I16
+        | Format::I32
+        | Format::I64
+        | Format::U8
+        | Format::U16
+        | Format::U32
+        | Format::U64
+        | Format::F32
+        | Format::F64
+        | Format::Char
+        | Format::Str
+        | Format::Bytes
+        | Format::Uuid
+        | Format::Date
+        | Format::Time => true,
+        Format::Option(format) | Format::Seq(format) => is_json(format),
+        Format::TupleArray { content, .. } => is_json(content),
+        Format::Tuple(formats) => matches!(formats.as_slice(), [format] if is_json(format)),
+        _ => false,
+    }
+}
+
+/// The Dart type that a JSON value of `format` can be cast to, if it is read with a cast.
+fn cast_type(format: &Format) -> Option<&'static str> {
+    match format {
+        Format::Bool => Some("bool"),
+        Format::I8
+        | Format::I16
+        | Format::I32
+        | Format::I64
+        | Format::U8
+        | Format::U16
+        | Format::U32
+        | Format::U64 => Some("int"),
+        Format::Char | Format::Str | Format::Uuid | Format::Date | Format::Time => Some("String"),
+        _ => None,
+    }
+}
+
+/// The expression that converts `value` of `format` to JSON.
+fn to_json(value: &str, format: &Format, level: usize) -> String {
+    if is_json(format) {
+        return value.to_string();
+    }
+    let param = format!("level{}", level + 1);
+    match format {
+        Format::Unit => "null".to_string(),
+        Format::I128 | Format::U128 => format!("bigIntToJson({value})"),
+        Format::Timestamp => format!("{value}.toUtc().toIso8601String()"),
+        Format::Duration => format!("durationToJson({value})"),
+        Format::Option(format) => format!(
+            "optionToJson({value}, ({param}) => {})",
+            to_json(&param, format, level + 1)
+        ),
+        Format::Seq(format)
+        | Format::Set(format)
+        | Format::TupleArray {
+            content: format, ..
+        } => {
+            if is_json(format) {
+                format!("{value}.toList()")
+            } else {
+                format!(
+                    "[for (final {param} in {value}) {}]",
+                    to_json(&param, format, level + 1)
+                )
+            }
+        }
+        Format::Map {
+            key,
+            value: map_value,
+        } => {
+            let key_param = format!("key{}", level + 1);
+            let value_param = format!("value{}", level + 1);
+            format!(
+                "{{for (final MapEntry(key: {key_param}, value: {value_param}) in {value}.entries) {}: {}}}",
+                key_to_json(&key_param, key),
+                to_json(&value_param, map_value, level + 1)
+            )
+        }
+        Format::Tuple(formats) => match formats.as_slice() {
+            [] => "null".to_string(),
+            [format] => to_json(value, format, level),
+            formats => {
+                let elements = formats
+                    .iter()
+                    .enumerate()
+                    .map(|(i, format)| to_json(&format!("{value}.${}", i + 1), format, level))
+                    .collect::<Vec<_>>();
+                format!("[{}]", elements.join(", "))
+            }
+        },
+        Format::TypeName(_) => format!("{value}.toJson()"),
+        Format::TypeParameter(name) => format!("toJson{name}({value})"),
+        Format::Generic { args, .. } => {
+            let converters = args
+                .iter()
+                .map(|arg| format!("({param}) => {}", to_json(&param, arg, level + 1)))
+                .collect::<Vec<_>>();
+            format!("{value}.toJson({})", converters.join(", "))
+        }
+        Format::Variable(_variable) => unreachable!("placeholders should not get this far"),
+        _ => unreachable!("JSON values are handled above"),
+    }
+}
+
+/// The expression that converts the map key `key` of `format` to a JSON object key.
+fn key_to_json(key: &str, format: &Format) -> String {
+    match format {
+        Format::Char | Format::Str | Format::Uuid | Format::Date | Format::Time => key.to_string(),
+        // Unit variants of enums convert to strings.
+        Format::TypeName(_) | Format::Generic { .. } => format!("{key}.toJson() as String"),
+        _ => format!("{key}.toString()"),
+    }
+}
+
+/// The expression that reads a value of `format` from the JSON value `json`.
+fn from_json(json: &str, format: &Format, level: usize) -> String {
+    if let Some(cast) = cast_type(format) {
+        return format!("{json} as {cast}");
+    }
+    let param = format!("level{}", level + 1);
+    match format {
+        Format::Unit => "()".to_string(),
+        Format::F32 | Format::F64 => format!("({json} as num).toDouble()"),
+        Format::I128 | Format::U128 => format!("bigIntFromJson({json})"),
+        Format::Bytes => format!("bytesFromJson({json})"),
+        Format::Timestamp => format!("DateTime.parse({json} as String)"),
+        Format::Duration => format!("durationFromJson({json})"),
+        Format::Option(format) => match cast_type(format) {
+            Some(cast) => format!("{json} as {cast}?"),
+            None => format!(
+                "optionFromJson({json}, ({param}) => {})",
+                from_json(&param, format, level + 1)
+            ),
+        },
+        Format::Seq(format)
+        | Format::TupleArray {
+            content: format, ..
+        } => format!(
+            "[for (final {param} in {json} as List<Object?>) {}]",
+            from_json(&param, format, level + 1)
+        ),
+        Format::Set(format) => format!(
+            "{{for (final {param} in {json} as List<Object?>) {}}}",
+            from_json(&param, format, level + 1)
+        ),
+        Format::Map { key, value } => {
+            let key_param = format!("key{}", level + 1);
+            let value_param = format!("value{}", level + 1);
+            format!(
+                "{{for (final MapEntry(key: {key_param}, value: {value_param}) in ({json} as Map<String, Object?>).entries) {}: {}}}",
+                key_from_json(&key_param, key),
+                from_json(&value_param, value, level + 1)
+            )
+        }
+        Format::Tuple(formats) => match formats.as_slice() {
+            [] => "()".to_string(),
+            [format] => from_json(json, format, level),
+            formats => {
+                let elements = formats
+                    .iter()
+                    .enumerate()
+                    .map(|(i, format)| from_json(&format!("{param}[{i}]"), format, level + 1))
+                    .collect::<Vec<_>>();
+                format!(
+                    "tupleFromJson({json}, {}, ({param}) => ({}))",
+                    formats.len(),
+                    elements.join(", ")
+                )
+            }
+        },
+        Format::TypeName(name) => format!("{}.fromJson({json})", type_name(name)),
+        Format::TypeParameter(name) => format!("fromJson{name}({json})"),
+        Format::Generic { name, args } => {
+            let converters = args
+                .iter()
+                .map(|arg| format!(", ({param}) => {}", from_json(&param, arg, level + 1)))
+                .collect::<String>();
+            format!("{}.fromJson({json}{converters})", type_name(name))
+        }
+        Format::Variable(_variable) => unreachable!("placeholders should not get this far"),
+        _ => unreachable!("casts are handled above"),
+    }
+}
+
+/// The expression that reads a map key of `format` from the JSON object key `key`.
+fn key_from_json(key: &str, format: &Format) -> String {
+    match format {
+        Format::Bool => format!("{key} == 'true'"),
+        Format::I8
+        | Format::I16
+        | Format::I32
+        | Format::I64
+        | Format::U8
+        | Format::U16
+        | Format::U32
+        | Format::U64 => format!("int.parse({key})"),
+        Format::I128 | Format::U128 => format!("BigInt.parse({key})"),
+        Format::F32 | Format::F64 => format!("double.parse({key})"),
+        Format::TypeName(name) => format!("{}.fromJson({key})", type_name(name)),
+        _ => key.to_string(),
+    }
+}
+
+/// The JSON object of `fields`, read from the locals of the same names.
+fn fields_to_json(fields: &[Named<Format>]) -> String {
+    if fields.is_empty() {
+        return "{}".to_string();
+    }
+    let entries = fields
+        .iter()
+        .map(|field| {
+            format!(
+                "{}: {}",
+                string_literal(&field.name),
+                to_json(&identifier(&field.name), &field.value, 0)
+            )
+        })
+        .collect::<Vec<_>>();
+    format!("{{{}}}", entries.join(", "))
+}
+
+/// The arguments that create a class from the JSON object `map` of its `fields`. A field
+/// with a default takes it when the object doesn't have the field.
+fn fields_from_json(map: &str, fields: &[Named<Format>]) -> Vec<String> {
+    fields
+        .iter()
+        .map(|field| {
+            let key = string_literal(&field.name);
+            let value = from_json(&format!("{map}[{key}]"), &field.value, 0);
+            let value = match field_default(field) {
+                Some(default) => format!("{map}.containsKey({key}) ? {value} : {default}"),
+                None => value,
+            };
+            format!("{}: {value}", identifier(&field.name))
+        })
+        .collect()
+}
+
+/// The fields of a variant, and whether they are positional.
+fn variant_fields(variant: &VariantFormat) -> (Vec<Named<Format>>, bool) {
+    match variant {
+        VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+        VariantFormat::Unit => (vec![], true),
+        VariantFormat::NewType(format) => {
+            (vec![Named::new(format.as_ref(), "value".to_string())], true)
+        }
+        VariantFormat::Tuple(formats) => (
+            formats
+                .iter()
+                .enumerate()
+                .map(|(i, format)| Named::new(format, format!("field{i}")))
+                .collect(),
+            true,
+        ),
+        VariantFormat::Struct(fields) => (fields.clone(), false),
+    }
+}
+
+/// The JSON value of a variant's content (`None` for unit variants), read from the locals
+/// that its fields are bound to.
+fn variant_content(variant: &VariantFormat) -> Option<String> {
+    match variant {
+        VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+        VariantFormat::Unit => None,
+        VariantFormat::NewType(format) => Some(to_json("value", format, 0)),
+        VariantFormat::Tuple(formats) => {
+            let elements = formats
+                .iter()
+                .enumerate()
+                .map(|(i, format)| to_json(&format!("field{i}"), format, 0))
+                .collect::<Vec<_>>();
+            Some(format!("[{}]", elements.join(", ")))
+        }
+        VariantFormat::Struct(fields) => Some(fields_to_json(fields)),
+    }
+}
+
+/// The JSON value of a variant named `name` with `content`, in `tagging`.
+fn tagged_to_json(tagging: &EnumTagging, name: &str, content: Option<String>) -> String {
+    let name = string_literal(name);
+    match (tagging, content) {
+        (EnumTagging::External, None) => name,
+        (EnumTagging::External, Some(content)) => format!("{{{name}: {content}}}"),
+        (EnumTagging::Internal { tag }, None) | (EnumTagging::Adjacent { tag, .. }, None) => {
+            format!("{{{}: {name}}}", string_literal(tag))
+        }
+        (EnumTagging::Internal { tag }, Some(content)) => {
+            // The content is an object (struct variant) or a newtype's value, which
+            // must be an object too: the tag is added to its fields.
+            let content = content
+                .strip_prefix('{')
+                .and_then(|content| content.strip_suffix('}'))
+                .map_or_else(
+                    || format!("...({content} as Map<String, Object?>)"),
+                    ToString::to_string,
+                );
+            if content.is_empty() {
+                format!("{{{}: {name}}}", string_literal(tag))
+            } else {
+                format!("{{{}: {name}, {content}}}", string_literal(tag))
+            }
+        }
+        (EnumTagging::Adjacent { tag, content: key }, Some(content)) => format!(
+            "{{{}: {name}, {}: {content}}}",
+            string_literal(tag),
+            string_literal(key)
+        ),
+        (EnumTagging::Untagged, content) => content.unwrap_or_else(|| "null".to_string()),
+    }
+}
+
+/// Writes `fromJson` for a class: a struct, or a newtype or tuple struct (whose fields are
+/// `positional`).
+fn write_class_body<W: IndentWrite>(
+    w: &mut W,
+    ctx: &EmitContext,
+    fields: &[Named<Format>],
+    positional: bool,
+) -> Result<()> {
+    let name = ctx.name();
+    let type_params = ctx.type_params();
+    let to_params = to_json_params(type_params);
+    let from_params = from_json_params(type_params);
+    let unit = matches!(ctx.container.format, ContainerFormat::UnitStruct(..));
+
+    write!(w, "Object? toJson({to_params}) => ")?;
+    match fields {
+        _ if unit => writeln!(w, "null;")?,
+        [] => writeln!(w, "const <String, Object?>{{}};")?,
+        [field] if positional => {
+            writeln!(w, "{};", to_json(&identifier(&field.name), &field.value, 0))?;
+        }
+        fields if positional => {
+            let elements = fields
+                .iter()
+                .map(|field| to_json(&identifier(&field.name), &field.value, 0))
+                .collect::<Vec<_>>();
+            writeln!(w, "[{}];", elements.join(", "))?;
+        }
+        fields => {
+            {
+                let mut w = w.block(Newlines::OPEN)?;
+                for field in fields {
+                    writeln!(
+                        w,
+                        "{}: {},",
+                        string_literal(&field.name),
+                        to_json(&identifier(&field.name), &field.value, 0)
+                    )?;
+                }
+            }
+            writeln!(w, ";")?;
+        }
+    }
+    writeln!(w)?;
+
+    write!(w, "factory {name}.fromJson(Object? json{from_params})")?;
+    match fields {
+        [] => writeln!(w, " => const {name}();")?,
+        [field] if positional => {
+            writeln!(w, " => {name}({});", from_json("json", &field.value, 0))?;
+        }
+        fields if positional => {
+            let elements = fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| from_json(&format!("elements[{i}]"), &field.value, 0))
+                .collect::<Vec<_>>();
+            writeln!(
+                w,
+                " => tupleFromJson(json, {}, (elements) => {name}({}));",
+                fields.len(),
+                elements.join(", ")
+            )?;
+        }
+        fields => {
+            write!(w, " ")?;
+            let mut w = w.block(Newlines::BOTH)?;
+            writeln!(w, "final map = json as Map<String, Object?>;")?;
+            write!(w, "return {name}(")?;
+            writeln!(w)?;
+            w.indent();
+            for arg in fields_from_json("map", fields) {
+                writeln!(w, "{arg},")?;
+            }
+            w.unindent();
+            writeln!(w, ");")?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes `toJson` and `fromJson` for an all-unit Dart `enum`.
+fn write_enum_body<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+    tagging: &EnumTagging,
+) -> Result<()> {
+    write!(w, "Object? toJson() => switch (this) ")?;
+    {
+        let mut w = w.block(Newlines::OPEN)?;
+        for variant in variants.values() {
+            writeln!(
+                w,
+                "{name}.{} => {},",
+                enum_value(&variant.name),
+                tagged_to_json(tagging, &variant.name, None)
+            )?;
+        }
+    }
+    writeln!(w, ";")?;
+    writeln!(w)?;
+
+    let tag = match tagging {
+        EnumTagging::External | EnumTagging::Untagged => "json".to_string(),
+        EnumTagging::Internal { tag } | EnumTagging::Adjacent { tag, .. } => {
+            format!("(json as Map<String, Object?>)[{}]", string_literal(tag))
+        }
+    };
+    write!(
+        w,
+        "factory {name}.fromJson(Object? json) => switch ({tag}) "
+    )?;
+    {
+        let mut w = w.block(Newlines::OPEN)?;
+        match tagging {
+            // Only the first variant can be read: they are all `null`.
+            EnumTagging::Untagged => {
+                if let Some(variant) = variants.values().next() {
+                    writeln!(w, "null => {name}.{},", enum_value(&variant.name))?;
+                }
+            }
+            _ => {
+                for variant in variants.values() {
+                    writeln!(
+                        w,
+                        "{} => {name}.{},",
+                        string_literal(&variant.name),
+                        enum_value(&variant.name)
+                    )?;
+                }
+            }
+        }
+        match variants.values().find(|variant| variant.metadata.other) {
+            Some(other) => writeln!(w, "_ => {name}.{},", enum_value(&other.name))?,
+            None => writeln!(
+                w,
+                "_ => throw DeserializationError('Unknown variant for {name}: $json'),"
+            )?,
+        }
+    }
+    writeln!(w, ";")
+}
+
+/// Writes the statements that return variant `class` from its JSON `content`, or from the
+/// enum's JSON object `map` for the fields of internally tagged struct variants.
+fn write_variant_from_json<W: IndentWrite>(
+    w: &mut W,
+    class: &str,
+    variant: &VariantFormat,
+    content: &str,
+) -> Result<()> {
+    let (fields, positional) = variant_fields(variant);
+    match fields.as_slice() {
+        [] => writeln!(w, "return const {class}();"),
+        [field] if positional => writeln!(
+            w,
+            "return {class}({});",
+            from_json(content, &field.value, 0)
+        ),
+        fields if positional => {
+            let elements = fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| from_json(&format!("elements[{i}]"), &field.value, 0))
+                .collect::<Vec<_>>();
+            writeln!(
+                w,
+                "return tupleFromJson({content}, {}, (elements) => {class}({}));",
+                fields.len(),
+                elements.join(", ")
+            )
+        }
+        fields => {
+            let map = if content == "map" {
+                "map"
+            } else {
+                writeln!(w, "final fields = {content} as Map<String, Object?>;")?;
+                "fields"
+            };
+            writeln!(w, "return {class}(")?;
+            w.indent();
+            for arg in fields_from_json(map, fields) {
+                writeln!(w, "{arg},")?;
+            }
+            w.unindent();
+            writeln!(w, ");")
+        }
+    }
+}
+
+/// Writes `toJson` and `fromJson` for a `sealed class`, which switch over its variants.
+fn write_sealed_class_body<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    type_params: &[String],
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+    tagging: &EnumTagging,
+) -> Result<()> {
+    write!(
+        w,
+        "Object? toJson({}) => switch (this) ",
+        to_json_params(type_params)
+    )?;
+    {
+        let mut w = w.block(Newlines::OPEN)?;
+        for variant in variants.values() {
+            let (fields, _) = variant_fields(&variant.value);
+            let bindings = fields
+                .iter()
+                .map(|field| format!(":final {}", identifier(&field.name)))
+                .collect::<Vec<_>>();
+            writeln!(
+                w,
+                "{}({}) => {},",
+                variant_class(name, &variant.name),
+                bindings.join(", "),
+                tagged_to_json(tagging, &variant.name, variant_content(&variant.value))
+            )?;
+        }
+    }
+    writeln!(w, ";")?;
+    writeln!(w)?;
+
+    write!(
+        w,
+        "factory {name}.fromJson(Object? json{}) ",
+        from_json_params(type_params)
+    )?;
+    let mut w = w.block(Newlines::BOTH)?;
+    let content = match tagging {
+        EnumTagging::External => {
+            writeln!(w, "final (tag, content) = externalTag(json);")?;
+            "content"
+        }
+        EnumTagging::Internal { tag } => {
+            writeln!(w, "final map = json as Map<String, Object?>;")?;
+            writeln!(w, "final tag = map[{}];", string_literal(tag))?;
+            "map"
+        }
+        EnumTagging::Adjacent { tag, content } => {
+            writeln!(w, "final map = json as Map<String, Object?>;")?;
+            writeln!(w, "final tag = map[{}];", string_literal(tag))?;
+            writeln!(w, "final content = map[{}];", string_literal(content))?;
+            "content"
+        }
+        EnumTagging::Untagged => "json",
+    };
+    for variant in variants.values() {
+        let class = variant_class(name, &variant.name);
+        if matches!(tagging, EnumTagging::Untagged) {
+            // Variants are tried in order, like serde does.
+            if matches!(variant.value, VariantFormat::Unit) {
+                write!(w, "if (json == null) ")?;
+                let mut w = w.block(Newlines::BOTH)?;
+                writeln!(w, "return const {class}();")?;
+            } else {
+                write!(w, "try ")?;
+                {
+                    let mut w = w.block(Newlines::OPEN)?;
+                    write_variant_from_json(&mut w, &class, &variant.value, content)?;
+                }
+                writeln!(w, " catch (_) {{}}")?;
+            }
+            continue;
+        }
+        write!(w, "if (tag == {}) ", string_literal(&variant.name))?;
+        let mut w = w.block(Newlines::BOTH)?;
+        write_variant_from_json(&mut w, &class, &variant.value, content)?;
+    }
+    // Unknown variants, e.g. added by a later version, are read as the catch-all.
+    match variants.values().find(|variant| variant.metadata.other) {
+        Some(other) => writeln!(w, "return const {}();", variant_class(name, &other.name)),
+        None if matches!(tagging, EnumTagging::Untagged) => writeln!(
+            w,
+            "throw DeserializationError('No variant of {name} matches $json');"
+        ),
+        None => writeln!(
+            w,
+            "throw DeserializationError('Unknown variant for {name}: $tag');"
+        ),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// EmitterPlugin implementation
+// ---------------------------------------------------------------------------
+
+impl EmitterPlugin<Dart> for JsonPlugin {
+    /// Returns the serde Dart runtime library, whose JSON helpers the
+    /// generated code calls, to be written into the package's `lib/`
+    /// directory.
+    fn runtime_files(&self) -> Vec<RuntimeFile> {
+        static SERDE: include_dir::Dir<'static> =
+            include_dir::include_dir!("$CARGO_MANIFEST_DIR/runtime/dart/serde");
+        SERDE
+            .files()
+            .map(|f| RuntimeFile {
+                relative_path: format!("lib/serde/{}", f.path().display()),
+                contents: f.contents().to_vec(),
+            })
+            .collect()
+    }
+
+    fn imports(&self, config: &CodeGeneratorConfig) -> Vec<String> {
+        vec![format!(
+            "import '{}';",
+            import_uri(config, SERDE_NAMESPACE, "serde/serde.dart")
+        )]
+    }
+
+    /// Libraries whose types need none of the JSON helpers don't use the
+    /// serde import, which `dart analyze` would warn about.
+    fn module_helpers(
+        &self,
+        w: &mut dyn IndentWrite,
+        _config: &CodeGeneratorConfig,
+    ) -> io::Result<()> {
+        writeln!(w, "// ignore_for_file: unused_import")
+    }
+
+    /// Every top-level type gets JSON methods; variant classes get none.
+    fn has_type_body(&self, ctx: &EmitContext) -> bool {
+        !ctx.is_variant()
+    }
+
+    fn type_body(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
+        if ctx.is_variant() {
+            return Ok(());
+        }
+        let config = w.config();
+        let mut w = IndentedWriter::new(&mut *w, config);
+        let name = ctx.name();
+        match ctx.container.format {
+            ContainerFormat::Enum(variants, tagging, _, _)
+                if variants
+                    .values()
+                    .all(|variant| matches!(variant.value, VariantFormat::Unit)) =>
+            {
+                write_enum_body(&mut w, name, variants, tagging)
+            }
+            ContainerFormat::Enum(variants, tagging, _, _) => {
+                write_sealed_class_body(&mut w, name, ctx.type_params(), variants, tagging)
+            }
+            ContainerFormat::NewTypeStruct(..) | ContainerFormat::TupleStruct(..) => {
+                write_class_body(&mut w, ctx, &ctx.fields(), true)
+            }
+            ContainerFormat::UnitStruct(..) | ContainerFormat::Struct(..) => {
+                write_class_body(&mut w, ctx, &ctx.fields(), false)
+            }
+        }
+    }
+
+    /// Generic enums are supported in every tagging, so only internally
+    /// tagged enums with tuple variants are rejected.
+    fn check_registry(&self, registry: &Registry) -> std::result::Result<(), Error> {
+        super::check_enums(registry, "Dart", |_| false)
+    }
+}
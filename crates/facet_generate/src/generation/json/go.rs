@@ -0,0 +1,749 @@
+//! `EmitterPlugin<Go>` implementation for the JSON plugin.
+//!
+//! Most Go types already have the shape that `serde_json` gives the Rust
+//! types when `encoding/json` writes them: struct fields have `json` tags, and
+//! the serde runtime's types (`serde.Unit`, `serde.Bytes`, `serde.Uint128`,
+//! tuples, …) have `MarshalJSON` / `UnmarshalJSON` methods. The plugin writes
+//! the methods that the rest need after each type:
+//!
+//! - newtype, tuple and unit structs, which are written as their value, an
+//!   array and `null`;
+//! - enums whose variants are all unit variants, whose names are checked when
+//!   they're read, and which are objects unless they're externally tagged;
+//! - variant structs, which are written with their enum's tagging, and read by
+//!   the `<Enum>FromJSON` function, since `encoding/json` can't read an
+//!   interface, and which is registered with the serde runtime so that
+//!   `serde.FromJSON` can read the enum as a type argument;
+//! - structs with a field of such an enum, of a type parameter or with a
+//!   default value, which read their fields one by one.
+//!
+//! Like `encoding/json`, the generated code writes nil slices and maps as
+//! `null`, which serde only reads as an `Option`.
+
+use std::collections::BTreeMap;
+use std::io::{self, Result};
+
+use super::JsonPlugin;
+use crate::Registry;
+use crate::generation::{
+    CodeGeneratorConfig, Error, SERDE_NAMESPACE,
+    go::{
+        Go, field_name, is_defined_type, is_union, is_union_format, qualified, string_literal,
+        type_args, type_expr, type_params_decl, variant_type,
+    },
+    indent::{IndentWrite, IndentedWriter},
+    plugin::{EmitContext, EmitterPlugin, RuntimeFile},
+};
+use crate::reflection::format::{
+    ContainerFormat, DefaultValue, EnumTagging, Format, FormatHolder, Language, Named,
+    QualifiedTypeName, VariantFormat,
+};
+
+/// Whether `format` contains an enum whose variants carry data, which
+/// `encoding/json` can't read, or a type parameter, which may be one.
+fn contains_union(config: &CodeGeneratorConfig, format: &Format) -> bool {
+    let mut found = false;
+    let _ = format.visit(&mut |format| {
+        match format {
+            Format::TypeName(name) | Format::Generic { name, .. } => {
+                found |= is_union(config, name);
+            }
+            Format::TypeParameter(_) => found = true,
+            _ => {}
+        }
+        Ok(())
+    });
+    found
+}
+
+/// The first generic enum whose variants carry data in `format`, which can't
+/// be registered with the serde runtime, so `serde.FromJSON` can't read it.
+fn generic_union(config: &CodeGeneratorConfig, format: &Format) -> Option<String> {
+    let mut found = None;
+    let _ = format.visit(&mut |format| {
+        if let Format::Generic { name, .. } = format
+            && is_union(config, name)
+        {
+            found.get_or_insert_with(|| type_expr(format));
+        }
+        Ok(())
+    });
+    found
+}
+
+/// The function that reads an enum whose variants carry data, e.g.
+/// `other.ShapeFromJSON`.
+fn union_reader(name: &QualifiedTypeName) -> String {
+    qualified(name, &format!("{}FromJSON", name.name))
+}
+
+/// A function of type `func([]byte) (T, error)` that reads a value of `format`.
+fn decoder(config: &CodeGeneratorConfig, format: &Format) -> String {
+    match format {
+        format if !contains_union(config, format) => {
+            format!("serde.FromJSON[{}]", type_expr(format))
+        }
+        // `serde.FromJSON` reads an enum that the type argument is with the
+        // function that its package registered.
+        Format::TypeParameter(name) => format!("serde.FromJSON[{name}]"),
+        Format::TypeName(name) => union_reader(name),
+        Format::Generic { name, args } => {
+            let args = args.iter().map(type_expr).collect::<Vec<_>>().join(", ");
+            format!("{}[{args}]", union_reader(name))
+        }
+        Format::Tuple(formats) if formats.len() == 1 => decoder(config, &formats[0]),
+        // Arrays are read into the array that the function returns.
+        Format::TupleArray { content, size } => format!(
+            "func(data []byte) (value [{size}]{}, err error) {{ err = serde.ArrayFromJSON(data, value[:], {}); return }}",
+            type_expr(content),
+            decoder(config, content)
+        ),
+        format => format!(
+            "func(data []byte) ({}, error) {{ return {} }}",
+            type_expr(format),
+            decode(config, format, "data")
+        ),
+    }
+}
+
+/// The expression that reads a value of `format` from `data`, returning the
+/// value and an `error`.
+fn decode(config: &CodeGeneratorConfig, format: &Format, data: &str) -> String {
+    if !contains_union(config, format) {
+        return format!("{}({data})", decoder(config, format));
+    }
+    match format {
+        Format::Option(format) => {
+            format!("serde.OptionFromJSON({data}, {})", decoder(config, format))
+        }
+        Format::Seq(format) | Format::Set(format) => {
+            format!("serde.SeqFromJSON({data}, {})", decoder(config, format))
+        }
+        Format::Map { key, value } => format!(
+            "serde.MapFromJSON[{}]({data}, {})",
+            type_expr(key),
+            decoder(config, value)
+        ),
+        Format::Tuple(formats) if formats.len() > 1 => {
+            let decoders = formats
+                .iter()
+                .map(|format| decoder(config, format))
+                .collect::<Vec<_>>();
+            format!(
+                "serde.Tuple{}FromJSON({data}, {})",
+                formats.len(),
+                decoders.join(", ")
+            )
+        }
+        format => format!("{}({data})", decoder(config, format)),
+    }
+}
+
+/// The expression that reads a field from `data`, as its `fg::override` type for Go if it has
+/// one.
+fn field_decode(config: &CodeGeneratorConfig, field: &Named<Format>, data: &str) -> String {
+    match field.metadata.type_override(Language::Go) {
+        Some(type_override) => format!("serde.FromJSON[{}]({data})", type_override.ty),
+        None => decode(config, &field.value, data),
+    }
+}
+
+/// The default value of a field, as a Go expression, if it isn't the zero value of its type
+/// that `encoding/json` leaves a missing field with.
+fn default_value(field: &Named<Format>) -> Option<String> {
+    if field.metadata.type_override(Language::Go).is_some() {
+        return None;
+    }
+    let default = field.metadata.default.as_ref()?;
+    match (default, &field.value) {
+        (DefaultValue::Bool(true), Format::Bool) => Some("true".to_string()),
+        (
+            DefaultValue::Integer(value),
+            Format::I8
+            | Format::I16
+            | Format::I32
+            | Format::I64
+            | Format::U8
+            | Format::U16
+            | Format::U32
+            | Format::U64,
+        )
+        | (DefaultValue::Float(value), Format::F32 | Format::F64)
+            if value.parse::<f64>() != Ok(0.0) =>
+        {
+            Some(value.clone())
+        }
+        (DefaultValue::Char(value), Format::Char) => Some(string_literal(&value.to_string())),
+        (DefaultValue::Str(value), Format::Str) if !value.is_empty() => Some(string_literal(value)),
+        _ => None,
+    }
+}
+
+/// Whether a field is optional when it's read: an `Option`, or a field with a default.
+fn is_optional(field: &Named<Format>) -> bool {
+    matches!(field.value, Format::Option(_)) || field.metadata.default.is_some()
+}
+
+/// Writes the statements that read the `fields` of `obj` from the JSON object `data`,
+/// returning an error if a required field is missing.
+fn write_fields_from_json<W: IndentWrite>(
+    w: &mut W,
+    config: &CodeGeneratorConfig,
+    data: &str,
+    fields: &[Named<Format>],
+) -> Result<()> {
+    writeln!(w, "fields, err := serde.ObjectFromJSON({data})")?;
+    writeln!(w, "if err != nil {{")?;
+    w.indent();
+    writeln!(w, "return err")?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    for field in fields {
+        let key = string_literal(&field.name);
+        writeln!(w, "if field, ok := fields[{key}]; ok {{")?;
+        w.indent();
+        writeln!(
+            w,
+            "if obj.{}, err = {}; err != nil {{",
+            field_name(&field.name),
+            field_decode(config, field, "field")
+        )?;
+        w.indent();
+        writeln!(w, "return err")?;
+        w.unindent();
+        writeln!(w, "}}")?;
+        w.unindent();
+        match default_value(field) {
+            Some(default) => {
+                writeln!(w, "}} else {{")?;
+                w.indent();
+                writeln!(w, "obj.{} = {default}", field_name(&field.name))?;
+                w.unindent();
+            }
+            None if !is_optional(field) => {
+                writeln!(w, "}} else {{")?;
+                w.indent();
+                writeln!(w, "return serde.MissingFieldError({key})")?;
+                w.unindent();
+            }
+            None => {}
+        }
+        writeln!(w, "}}")?;
+    }
+    writeln!(w, "return nil")
+}
+
+/// Writes the statements that read the positional `fields` of `obj` from the JSON array
+/// `data`.
+fn write_elements_from_json<W: IndentWrite>(
+    w: &mut W,
+    config: &CodeGeneratorConfig,
+    data: &str,
+    fields: &[Named<Format>],
+) -> Result<()> {
+    writeln!(
+        w,
+        "elements, err := serde.ElementsFromJSON({data}, {})",
+        fields.len()
+    )?;
+    writeln!(w, "if err != nil {{")?;
+    w.indent();
+    writeln!(w, "return err")?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    for (i, field) in fields.iter().enumerate() {
+        writeln!(
+            w,
+            "if obj.{}, err = {}; err != nil {{",
+            field_name(&field.name),
+            field_decode(config, field, &format!("elements[{i}]"))
+        )?;
+        w.indent();
+        writeln!(w, "return err")?;
+        w.unindent();
+        writeln!(w, "}}")?;
+    }
+    writeln!(w, "return nil")
+}
+
+/// Writes the statements that read the single field of `obj` from `data`.
+fn write_value_from_json<W: IndentWrite>(
+    w: &mut W,
+    config: &CodeGeneratorConfig,
+    data: &str,
+    field: &Named<Format>,
+) -> Result<()> {
+    writeln!(w, "var err error")?;
+    writeln!(
+        w,
+        "obj.{}, err = {}",
+        field_name(&field.name),
+        field_decode(config, field, data)
+    )?;
+    writeln!(w, "return err")
+}
+
+/// The JSON array of the positional `fields` of `obj`.
+fn elements(fields: &[Named<Format>]) -> String {
+    let elements = fields
+        .iter()
+        .map(|field| format!("obj.{}", field_name(&field.name)))
+        .collect::<Vec<_>>();
+    format!("[]any{{{}}}", elements.join(", "))
+}
+
+/// Writes a `MarshalJSON` method of `ty` that returns `statements`' last expression.
+fn write_marshal<W: IndentWrite>(w: &mut W, ty: &str, statements: &[String]) -> Result<()> {
+    writeln!(w, "func (obj {ty}) MarshalJSON() ([]byte, error) {{")?;
+    w.indent();
+    for statement in statements {
+        writeln!(w, "{statement}")?;
+    }
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// Writes the `UnmarshalJSON` method of `ty` around `body`.
+fn write_unmarshal<W: IndentWrite>(
+    w: &mut W,
+    ty: &str,
+    body: impl FnOnce(&mut W) -> Result<()>,
+) -> Result<()> {
+    writeln!(w, "func (obj *{ty}) UnmarshalJSON(data []byte) error {{")?;
+    w.indent();
+    body(w)?;
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// Writes the methods of a struct that `encoding/json` can't read or write as it is.
+fn write_struct<W: IndentWrite>(w: &mut W, ctx: &EmitContext) -> Result<()> {
+    let config = ctx.config;
+    let ty = format!("{}{}", ctx.name(), type_args(ctx.type_params()));
+    let fields = ctx.fields();
+    match ctx.container.format {
+        ContainerFormat::UnitStruct(..) => {
+            write_marshal(w, &ty, &["return []byte(\"null\"), nil".to_string()])?;
+            writeln!(w)?;
+            write_unmarshal(w, &ty, |w| writeln!(w, "return serde.UnitFromJSON(data)"))
+        }
+        ContainerFormat::NewTypeStruct(..) => {
+            write_marshal(w, &ty, &["return json.Marshal(obj.Value)".to_string()])?;
+            writeln!(w)?;
+            write_unmarshal(w, &ty, |w| {
+                write_value_from_json(w, config, "data", &fields[0])
+            })
+        }
+        ContainerFormat::TupleStruct(..) => {
+            write_marshal(
+                w,
+                &ty,
+                &[format!("return json.Marshal({})", elements(&fields))],
+            )?;
+            writeln!(w)?;
+            write_unmarshal(w, &ty, |w| {
+                write_elements_from_json(w, config, "data", &fields)
+            })
+        }
+        ContainerFormat::Struct(..)
+            if fields.iter().any(|field| {
+                contains_union(config, &field.value) || default_value(field).is_some()
+            }) =>
+        {
+            write_unmarshal(w, &ty, |w| {
+                write_fields_from_json(w, config, "data", &fields)
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The statement that reads the name of a variant from `data` in `tagging`, and its content
+/// if it's separate.
+fn read_tag(tagging: &EnumTagging) -> String {
+    match tagging {
+        EnumTagging::External => "name, content, err := serde.ExternalTag(data)".to_string(),
+        EnumTagging::Internal { tag } => {
+            format!(
+                "name, err := serde.InternalTag(data, {})",
+                string_literal(tag)
+            )
+        }
+        EnumTagging::Adjacent { tag, content } => format!(
+            "name, content, err := serde.AdjacentTag(data, {}, {})",
+            string_literal(tag),
+            string_literal(content)
+        ),
+        EnumTagging::Untagged => unreachable!("untagged enums have no tag"),
+    }
+}
+
+/// Writes the methods of an enum whose variants are all unit variants: a `string` type whose
+/// value is the variant's name.
+fn write_enum<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+    tagging: &EnumTagging,
+) -> Result<()> {
+    match tagging {
+        // The name itself.
+        EnumTagging::External => {}
+        EnumTagging::Internal { tag } | EnumTagging::Adjacent { tag, .. } => {
+            write_marshal(
+                w,
+                name,
+                &[format!(
+                    "return json.Marshal(serde.Object{{{{Key: {}, Value: string(obj)}}}})",
+                    string_literal(tag)
+                )],
+            )?;
+            writeln!(w)?;
+        }
+        EnumTagging::Untagged => {
+            write_marshal(w, name, &["return []byte(\"null\"), nil".to_string()])?;
+            writeln!(w)?;
+        }
+    }
+
+    write_unmarshal(w, name, |w| {
+        if let EnumTagging::Untagged = tagging {
+            // Every variant is `null`, which serde reads as the first.
+            writeln!(w, "if err := serde.UnitFromJSON(data); err != nil {{")?;
+            w.indent();
+            writeln!(w, "return err")?;
+            w.unindent();
+            writeln!(w, "}}")?;
+            if let Some(first) = variants.values().next() {
+                writeln!(w, "*obj = {}", variant_type(name, &first.name))?;
+            }
+            return writeln!(w, "return nil");
+        }
+        match tagging {
+            EnumTagging::External => writeln!(w, "name, err := serde.FromJSON[string](data)")?,
+            EnumTagging::Internal { tag } => writeln!(
+                w,
+                "name, err := serde.InternalTag(data, {})",
+                string_literal(tag)
+            )?,
+            EnumTagging::Adjacent { tag, .. } => writeln!(
+                w,
+                "name, _, err := serde.AdjacentTag(data, {}, \"\")",
+                string_literal(tag)
+            )?,
+            EnumTagging::Untagged => unreachable!("untagged enums are read above"),
+        }
+        writeln!(w, "if err != nil {{")?;
+        w.indent();
+        writeln!(w, "return err")?;
+        w.unindent();
+        writeln!(w, "}}")?;
+        let constants = variants
+            .values()
+            .map(|variant| variant_type(name, &variant.name))
+            .collect::<Vec<_>>();
+        writeln!(w, "switch value := {name}(name); value {{")?;
+        if !constants.is_empty() {
+            writeln!(w, "case {}:", constants.join(", "))?;
+            w.indent();
+            writeln!(w, "*obj = value")?;
+            w.unindent();
+        }
+        writeln!(w, "default:")?;
+        w.indent();
+        match variants.values().find(|variant| variant.metadata.other) {
+            Some(other) => writeln!(w, "*obj = {}", variant_type(name, &other.name))?,
+            None => writeln!(
+                w,
+                "return serde.UnknownVariantNameError({}, name)",
+                string_literal(name)
+            )?,
+        }
+        w.unindent();
+        writeln!(w, "}}")?;
+        writeln!(w, "return nil")
+    })
+}
+
+/// The statements of a variant's `MarshalJSON`, which writes it with its enum's tagging.
+fn variant_marshal(
+    name: &str,
+    variant: &VariantFormat,
+    fields: &[Named<Format>],
+    tagging: &EnumTagging,
+) -> Vec<String> {
+    let name = string_literal(name);
+    let object = |entries: Vec<String>| {
+        format!(
+            "serde.Object{{{}}}",
+            entries
+                .iter()
+                .map(|entry| format!("{{{entry}}}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    };
+    let entry = |key: &str, value: &str| format!("Key: {key}, Value: {value}");
+    let field_entries = || {
+        fields
+            .iter()
+            .map(|field| {
+                entry(
+                    &string_literal(&field.name),
+                    &format!("obj.{}", field_name(&field.name)),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    let content = match variant {
+        VariantFormat::Unit => None,
+        VariantFormat::NewType(_) => Some("obj.Value".to_string()),
+        VariantFormat::Tuple(_) => Some(elements(fields)),
+        VariantFormat::Struct(_) => Some(object(field_entries())),
+        VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+    };
+    let marshal = |value: String| vec![format!("return json.Marshal({value})")];
+    match (tagging, content) {
+        (EnumTagging::External, None) => marshal(name),
+        (EnumTagging::External, Some(content)) => marshal(object(vec![entry(&name, &content)])),
+        (EnumTagging::Internal { tag } | EnumTagging::Adjacent { tag, .. }, None) => {
+            marshal(object(vec![entry(&string_literal(tag), &name)]))
+        }
+        (EnumTagging::Internal { tag }, Some(_))
+            if matches!(variant, VariantFormat::NewType(_)) =>
+        {
+            // The tag is added to the fields of the newtype's value, which must be an object.
+            vec![format!(
+                "return serde.InternallyTagged({}, {name}, obj.Value)",
+                string_literal(tag)
+            )]
+        }
+        (EnumTagging::Internal { tag }, Some(_)) => {
+            let mut entries = vec![entry(&string_literal(tag), &name)];
+            entries.extend(field_entries());
+            marshal(object(entries))
+        }
+        (EnumTagging::Adjacent { tag, content: key }, Some(content)) => marshal(object(vec![
+            entry(&string_literal(tag), &name),
+            entry(&string_literal(key), &content),
+        ])),
+        (EnumTagging::Untagged, None) => vec!["return []byte(\"null\"), nil".to_string()],
+        (EnumTagging::Untagged, Some(content)) => marshal(content),
+    }
+}
+
+/// Writes the methods of a variant struct: `MarshalJSON`, and `unmarshalContent`, which reads
+/// its content once its enum's function has found which variant it is.
+fn write_variant<W: IndentWrite>(
+    w: &mut W,
+    ctx: &EmitContext,
+    tagging: &EnumTagging,
+) -> Result<()> {
+    let Some(variant) = &ctx.variant else {
+        unreachable!("only variants are written as variants")
+    };
+    let ty = format!(
+        "{}{}",
+        variant_type(variant.parent_name, variant.name),
+        type_args(variant.parent_type_params)
+    );
+    let statements = variant_marshal(variant.name, variant.format, variant.fields, tagging);
+    write_marshal(w, &ty, &statements)?;
+    writeln!(w)?;
+
+    writeln!(w, "func (obj *{ty}) unmarshalContent(data []byte) error {{")?;
+    w.indent();
+    match variant.format {
+        VariantFormat::Unit if matches!(tagging, EnumTagging::Untagged) => {
+            writeln!(w, "return serde.UnitFromJSON(data)")?;
+        }
+        VariantFormat::Unit => writeln!(w, "return nil")?,
+        VariantFormat::NewType(_) => {
+            write_value_from_json(w, ctx.config, "data", &variant.fields[0])?;
+        }
+        VariantFormat::Tuple(_) => {
+            write_elements_from_json(w, ctx.config, "data", variant.fields)?;
+        }
+        VariantFormat::Struct(_) => {
+            write_fields_from_json(w, ctx.config, "data", variant.fields)?;
+        }
+        VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+    }
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// Writes the function that reads an enum whose variants carry data, which switches on its
+/// tag, or tries each variant in turn if it's untagged.
+fn write_union<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    type_params: &[String],
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+    tagging: &EnumTagging,
+) -> Result<()> {
+    let args = type_args(type_params);
+    writeln!(
+        w,
+        "func {name}FromJSON{}(data []byte) ({name}{args}, error) {{",
+        type_params_decl(type_params)
+    )?;
+    w.indent();
+    if let EnumTagging::Untagged = tagging {
+        // Like serde, the first variant that can be read wins.
+        for variant in variants.values() {
+            let ty = variant_type(name, &variant.name);
+            writeln!(
+                w,
+                "if obj := ({ty}{args}{{}}); obj.unmarshalContent(data) == nil {{"
+            )?;
+            w.indent();
+            writeln!(w, "return &obj, nil")?;
+            w.unindent();
+            writeln!(w, "}}")?;
+        }
+        writeln!(
+            w,
+            "return nil, serde.NoMatchingVariantError({})",
+            string_literal(name)
+        )?;
+    } else {
+        writeln!(w, "{}", read_tag(tagging))?;
+        writeln!(w, "if err != nil {{")?;
+        w.indent();
+        writeln!(w, "return nil, err")?;
+        w.unindent();
+        writeln!(w, "}}")?;
+        if let EnumTagging::Internal { .. } = tagging {
+            // The variant's fields are the object's other fields.
+            writeln!(w, "content := data")?;
+        }
+        writeln!(w, "switch name {{")?;
+        for variant in variants.values() {
+            writeln!(w, "case {}:", string_literal(&variant.name))?;
+            w.indent();
+            writeln!(w, "var obj {}{args}", variant_type(name, &variant.name))?;
+            writeln!(w, "if err := obj.unmarshalContent(content); err != nil {{")?;
+            w.indent();
+            writeln!(w, "return nil, err")?;
+            w.unindent();
+            writeln!(w, "}}")?;
+            writeln!(w, "return &obj, nil")?;
+            w.unindent();
+        }
+        // Unknown variants, e.g. added by a later version, are read as the catch-all.
+        writeln!(w, "default:")?;
+        w.indent();
+        match variants.values().find(|variant| variant.metadata.other) {
+            Some(other) => writeln!(
+                w,
+                "return &{}{args}{{}}, nil",
+                variant_type(name, &other.name)
+            )?,
+            None => writeln!(
+                w,
+                "return nil, serde.UnknownVariantNameError({}, name)",
+                string_literal(name)
+            )?,
+        }
+        w.unindent();
+        writeln!(w, "}}")?;
+    }
+    w.unindent();
+    writeln!(w, "}}")?;
+
+    // Generic types read their type arguments with `serde.FromJSON`, which
+    // can only find the function of an enum that isn't generic.
+    if type_params.is_empty() {
+        writeln!(w)?;
+        writeln!(w, "func init() {{")?;
+        w.indent();
+        writeln!(w, "serde.RegisterUnion({name}FromJSON)")?;
+        w.unindent();
+        writeln!(w, "}}")?;
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// EmitterPlugin implementation
+// ---------------------------------------------------------------------------
+
+impl EmitterPlugin<Go> for JsonPlugin {
+    /// Returns the serde Go runtime package, whose JSON helpers the generated
+    /// code calls, to be written into the generated module next to its
+    /// packages.
+    fn runtime_files(&self) -> Vec<RuntimeFile> {
+        static SERDE: include_dir::Dir<'static> =
+            include_dir::include_dir!("$CARGO_MANIFEST_DIR/runtime/go/serde");
+        SERDE
+            .files()
+            .map(|f| RuntimeFile {
+                relative_path: format!("serde/{}", f.path().display()),
+                contents: f.contents().to_vec(),
+            })
+            .collect()
+    }
+
+    /// `encoding/json` and the serde runtime package.
+    fn imports(&self, _config: &CodeGeneratorConfig) -> Vec<String> {
+        vec!["encoding/json".to_string(), SERDE_NAMESPACE.to_string()]
+    }
+
+    /// The JSON methods of the types that need them, and the functions that read enums whose
+    /// variants carry data.
+    fn after_type(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
+        let config = w.config();
+        let mut w = IndentedWriter::new(&mut *w, config);
+        match ctx.container.format {
+            ContainerFormat::Enum(_, tagging, ..) if ctx.is_variant() => {
+                write_variant(&mut w, ctx, tagging)
+            }
+            ContainerFormat::Enum(variants, tagging, ..)
+                if is_union_format(ctx.container.format) =>
+            {
+                write_union(&mut w, ctx.name(), ctx.type_params(), variants, tagging)
+            }
+            ContainerFormat::Enum(variants, tagging, ..) => {
+                write_enum(&mut w, ctx.name(), variants, tagging)
+            }
+            ContainerFormat::NewTypeStruct(_, _, metadata)
+                if metadata.proxy || is_defined_type(ctx.container.format) =>
+            {
+                Ok(())
+            }
+            _ => write_struct(&mut w, ctx),
+        }
+    }
+
+    /// Generic enums are supported in every tagging, but generic types read
+    /// their type arguments with `serde.FromJSON`, which only knows the enums
+    /// whose variants carry data that aren't generic, so a generic one can't
+    /// be a type argument.
+    fn check_registry(&self, registry: &Registry) -> std::result::Result<(), Error> {
+        super::check_enums(registry, "Go", |_| false)?;
+        let mut config = CodeGeneratorConfig::new(String::new());
+        config.update_from(registry);
+        for (name, format) in registry {
+            let mut union_arg = None;
+            let _ = format.visit(&mut |format| {
+                if let Format::Generic { args, .. } = format
+                    && let Some(arg) = args.iter().find_map(|arg| generic_union(&config, arg))
+                {
+                    union_arg.get_or_insert(arg);
+                }
+                Ok(())
+            });
+            if let Some(arg) = union_arg {
+                return Err(Error::Unsupported {
+                    plugin: "json (Go)".to_string(),
+                    type_name: name.format(ToString::to_string, "."),
+                    reason: format!(
+                        "the type argument `{arg}` is a generic enum whose variants carry data, which generic types can't read"
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+}
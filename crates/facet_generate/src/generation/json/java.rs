@@ -0,0 +1,1004 @@
+//! `EmitterPlugin<Java>` implementation for the JSON plugin.
+//!
+//! Java has no JSON library in its standard library, so the serde runtime's
+//! `com.novi.serde.Json` parses and writes JSON text as plain Java values:
+//! `Map`s, `List`s, `String`s, `Boolean`s, numbers and `null`. The plugin
+//! writes the methods that convert each type to and from those values into
+//! the body of every type:
+//!
+//! - `toJson` and `jsonSerialize`, which write a value in the shape that
+//!   `serde_json` gives the Rust type;
+//! - the static `fromJson` and `jsonDeserialize`, which read it back,
+//!   checking the shape of the JSON as they go.
+//!
+//! Structs are objects, newtype structs their value, tuple structs arrays and
+//! unit structs `null`. Enums are written with their tagging: the variant
+//! records of an enum whose variants carry data implement the `toJson` that
+//! its interface declares, and the interface's static `fromJson` switches on
+//! the variant's name, or tries each variant in turn if it's untagged.
+//!
+//! Generic types take a converter for each type parameter (e.g. `toJsonT`
+//! and `fromJsonT`), like the bincode methods take (de)serializers.
+//!
+//! A field's `fg::override` type for Java is written as it is, and read by
+//! casting the value that the parser gives.
+
+use std::collections::BTreeMap;
+use std::io::{self, Result, Write as _};
+
+use heck::ToUpperCamelCase;
+
+use super::JsonPlugin;
+use crate::Registry;
+use crate::generation::{
+    Error,
+    indent::{IndentWrite, IndentedWriter},
+    java::{
+        Java, Scope, enum_constant, field_name, is_union_format, string_literal, type_params_decl,
+        variant_class, variant_fields, write_call,
+    },
+    plugin::{EmitContext, EmitterPlugin, RuntimeFile},
+};
+use crate::reflection::format::{
+    ContainerFormat, DefaultValue, EnumTagging, Format, Language, Named, VariantFormat,
+};
+
+/// The name of a type parameter's converter, e.g. `toJsonT`.
+fn closure_name(prefix: &str, param: &str) -> String {
+    format!("{prefix}{}", param.to_upper_camel_case())
+}
+
+/// The per-type-parameter converter parameters of `toJson` (e.g.
+/// `com.novi.serde.ToJson<T> toJsonT`) or `fromJson`, comma-separated.
+fn converter_params(interface: &str, prefix: &str, type_params: &[String]) -> Vec<String> {
+    type_params
+        .iter()
+        .map(|param| {
+            format!(
+                "com.novi.serde.{interface}<{param}> {}",
+                closure_name(prefix, param)
+            )
+        })
+        .collect()
+}
+
+/// Forwards the converters of `type_params` (e.g. `, toJsonT`).
+fn closure_args(prefix: &str, type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", {}", closure_name(prefix, param)))
+        .collect()
+}
+
+/// The name of a lambda's parameter at the nesting `level`: Java lambdas
+/// can't reuse the names of the enclosing method's variables.
+fn local(name: &str, level: usize) -> String {
+    if level == 0 {
+        name.to_string()
+    } else {
+        format!("{name}{level}")
+    }
+}
+
+/// The name of the runtime's reader of a primitive format, e.g. `u32` for
+/// `Json.u32FromJson`.
+fn primitive(format: &Format) -> Option<&'static str> {
+    Some(match format {
+        Format::Unit => "unit",
+        Format::Bool => "bool",
+        Format::I8 => "i8",
+        Format::I16 => "i16",
+        Format::I32 => "i32",
+        Format::I64 => "i64",
+        Format::I128 => "i128",
+        Format::U8 => "u8",
+        Format::U16 => "u16",
+        Format::U32 => "u32",
+        Format::U64 => "u64",
+        Format::U128 => "u128",
+        Format::F32 => "f32",
+        Format::F64 => "f64",
+        Format::Char => "char",
+        Format::Str => "str",
+        Format::Bytes => "bytes",
+        Format::Uuid => "uuid",
+        Format::Date => "date",
+        Format::Time => "time",
+        Format::Timestamp => "timestamp",
+        Format::Duration => "duration",
+        Format::Tuple(formats) if formats.is_empty() => "unit",
+        _ => return None,
+    })
+}
+
+/// The converters to JSON of each of `formats`, comma-separated.
+fn to_json_fns(formats: &[Format], level: usize) -> String {
+    formats
+        .iter()
+        .map(|format| to_json_fn(format, level))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A `com.novi.serde.ToJson<T>` of `format`, as a lambda at the nesting
+/// `level`.
+fn to_json_fn(format: &Format, level: usize) -> String {
+    match format {
+        Format::Tuple(formats) if formats.len() == 1 => to_json_fn(&formats[0], level),
+        Format::TypeParameter(name) => closure_name("toJson", name),
+        format => format!(
+            "{} -> {}",
+            local("value", level),
+            to_json(&local("value", level), format, level)
+        ),
+    }
+}
+
+/// The expression that converts `value` to JSON.
+fn to_json(value: &str, format: &Format, level: usize) -> String {
+    let level = level + 1;
+    match format {
+        Format::Bool
+        | Format::I8
+        | Format::I16
+        | Format::I32
+        | Format::I64
+        | Format::I128
+        | Format::U128
+        | Format::F32
+        | Format::F64
+        | Format::Char
+        | Format::Str => value.to_string(),
+        Format::Unit => "null".to_string(),
+        Format::U8 => format!("com.novi.serde.Json.u8ToJson({value})"),
+        Format::U16 => format!("com.novi.serde.Json.u16ToJson({value})"),
+        Format::U32 => format!("com.novi.serde.Json.u32ToJson({value})"),
+        Format::U64 => format!("com.novi.serde.Json.u64ToJson({value})"),
+        Format::Bytes => format!("com.novi.serde.Json.bytesToJson({value})"),
+        Format::Timestamp => format!("com.novi.serde.Json.timestampToJson({value})"),
+        Format::Date => format!("com.novi.serde.Json.dateToJson({value})"),
+        Format::Time => format!("com.novi.serde.Json.timeToJson({value})"),
+        Format::Duration => format!("com.novi.serde.Json.durationToJson({value})"),
+        Format::Uuid => format!("{value}.toString()"),
+        Format::Option(format) => format!(
+            "com.novi.serde.Json.optionToJson({value}, {})",
+            to_json_fn(format, level)
+        ),
+        Format::Seq(format)
+        | Format::Set(format)
+        | Format::TupleArray {
+            content: format, ..
+        } => format!(
+            "com.novi.serde.Json.seqToJson({value}, {})",
+            to_json_fn(format, level)
+        ),
+        Format::Map { key, value: item } => format!(
+            "com.novi.serde.Json.mapToJson({value}, {}, {})",
+            to_json_fn(key, level),
+            to_json_fn(item, level)
+        ),
+        Format::Tuple(formats) => match formats.as_slice() {
+            [] => "null".to_string(),
+            [format] => to_json(value, format, level - 1),
+            formats => format!("{value}.toJson({})", to_json_fns(formats, level)),
+        },
+        Format::TypeName(_) => format!("{value}.toJson()"),
+        Format::Generic { args, .. } => format!("{value}.toJson({})", to_json_fns(args, level)),
+        Format::TypeParameter(name) => {
+            format!("{}.toJson({value})", closure_name("toJson", name))
+        }
+        Format::Variable(_variable) => unreachable!("placeholders should not get this far"),
+    }
+}
+
+/// The converters from JSON of each of `formats`, comma-separated.
+fn from_json_fns(scope: &Scope, formats: &[Format], level: usize) -> String {
+    formats
+        .iter()
+        .map(|format| from_json_fn(scope, format, level))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A `com.novi.serde.FromJson<T>` of `format`, as a method reference or a
+/// lambda at the nesting `level`.
+fn from_json_fn(scope: &Scope, format: &Format, level: usize) -> String {
+    match format {
+        Format::Tuple(formats) if formats.len() == 1 => from_json_fn(scope, &formats[0], level),
+        Format::TypeParameter(name) => closure_name("fromJson", name),
+        Format::TypeName(name) => format!("{}::fromJson", scope.type_name(name)),
+        format => match primitive(format) {
+            Some(reader) => format!("com.novi.serde.Json::{reader}FromJson"),
+            None => format!(
+                "{} -> {}",
+                local("json", level),
+                from_json(scope, &local("json", level), format, level)
+            ),
+        },
+    }
+}
+
+/// A `com.novi.serde.FromJson<K>` of a map's keys, which JSON writes as
+/// strings: numbers and bools are parsed from them first.
+fn key_from_json_fn(scope: &Scope, format: &Format, level: usize) -> String {
+    match format {
+        Format::Bool => "com.novi.serde.Json::boolFromKey".to_string(),
+        Format::I8
+        | Format::I16
+        | Format::I32
+        | Format::I64
+        | Format::I128
+        | Format::U8
+        | Format::U16
+        | Format::U32
+        | Format::U64
+        | Format::U128
+        | Format::F32
+        | Format::F64 => {
+            let key = local("key", level);
+            format!(
+                "{key} -> {}",
+                from_json(
+                    scope,
+                    &format!("com.novi.serde.Json.numberFromKey({key})"),
+                    format,
+                    level
+                )
+            )
+        }
+        format => from_json_fn(scope, format, level),
+    }
+}
+
+/// The expression that reads a value of `format` from the JSON `json`.
+fn from_json(scope: &Scope, json: &str, format: &Format, level: usize) -> String {
+    if let Some(reader) = primitive(format) {
+        return format!("com.novi.serde.Json.{reader}FromJson({json})");
+    }
+    let level = level + 1;
+    match format {
+        Format::Option(format) => format!(
+            "com.novi.serde.Json.optionFromJson({json}, {})",
+            from_json_fn(scope, format, level)
+        ),
+        Format::Seq(format) => format!(
+            "com.novi.serde.Json.seqFromJson({json}, {})",
+            from_json_fn(scope, format, level)
+        ),
+        Format::Set(format) => format!(
+            "com.novi.serde.Json.setFromJson({json}, {})",
+            from_json_fn(scope, format, level)
+        ),
+        Format::TupleArray { content, size } => format!(
+            "com.novi.serde.Json.tupleArrayFromJson({json}, {size}, {})",
+            from_json_fn(scope, content, level)
+        ),
+        Format::Map { key, value } => format!(
+            "com.novi.serde.Json.mapFromJson({json}, {}, {})",
+            key_from_json_fn(scope, key, level),
+            from_json_fn(scope, value, level)
+        ),
+        Format::Tuple(formats) => match formats.as_slice() {
+            [format] => from_json(scope, json, format, level - 1),
+            formats => format!(
+                "com.novi.serde.Tuple{}.fromJson({json}, {})",
+                formats.len(),
+                from_json_fns(scope, formats, level)
+            ),
+        },
+        Format::TypeName(name) => format!("{}.fromJson({json})", scope.type_name(name)),
+        Format::Generic { name, args } => format!(
+            "{}.fromJson({json}, {})",
+            scope.type_name(name),
+            from_json_fns(scope, args, level)
+        ),
+        Format::TypeParameter(name) => {
+            format!("{}.fromJson({json})", closure_name("fromJson", name))
+        }
+        Format::Variable(_variable) => unreachable!("placeholders should not get this far"),
+        _ => unreachable!("primitives are read by the runtime"),
+    }
+}
+
+/// The expression that converts a field of `this` to JSON, as it is if it
+/// has an `fg::override` type for Java.
+fn field_to_json(field: &Named<Format>) -> String {
+    let value = format!("this.{}", field_name(&field.name));
+    if field.metadata.type_override(Language::Java).is_some() {
+        return value;
+    }
+    to_json(&value, &field.value, 0)
+}
+
+/// The expression that reads a field from `json`, by casting it to its
+/// `fg::override` type for Java if it has one.
+fn field_from_json(scope: &Scope, field: &Named<Format>, json: &str) -> String {
+    match field.metadata.type_override(Language::Java) {
+        Some(type_override) => format!("({}) {json}", type_override.ty),
+        None => from_json(scope, json, &field.value, 0),
+    }
+}
+
+/// The default value of a field, as a Java expression, for when it's missing
+/// from its object.
+fn default_value(field: &Named<Format>) -> Option<String> {
+    if field.metadata.type_override(Language::Java).is_some() {
+        return None;
+    }
+    let default = field.metadata.default.as_ref()?;
+    Some(match (default, &field.value) {
+        (DefaultValue::Empty, Format::Option(_)) => "java.util.Optional.empty()".to_string(),
+        (DefaultValue::Empty, Format::Seq(_)) => "new java.util.ArrayList<>()".to_string(),
+        (DefaultValue::Empty, Format::Set(_)) => "new java.util.LinkedHashSet<>()".to_string(),
+        (DefaultValue::Empty, Format::Map { .. }) => "new java.util.LinkedHashMap<>()".to_string(),
+        (DefaultValue::Empty, Format::Bytes) => "new com.novi.serde.Bytes(new byte[0])".to_string(),
+        (DefaultValue::Bool(value), Format::Bool) => value.to_string(),
+        (DefaultValue::Integer(value), Format::I8 | Format::U8) => format!("(byte) {value}"),
+        (DefaultValue::Integer(value), Format::I16 | Format::U16) => format!("(short) {value}"),
+        (DefaultValue::Integer(value), Format::I32) => value.clone(),
+        (DefaultValue::Integer(value), Format::U32) => format!("(int) {value}L"),
+        (DefaultValue::Integer(value), Format::I64) => format!("{value}L"),
+        (DefaultValue::Integer(value), Format::U64) => {
+            format!("java.lang.Long.parseUnsignedLong(\"{value}\")")
+        }
+        (DefaultValue::Integer(value), Format::I128 | Format::U128) => {
+            format!("new java.math.BigInteger(\"{value}\")")
+        }
+        (DefaultValue::Float(value), Format::F32) => format!("{value}f"),
+        (DefaultValue::Float(value), Format::F64) => format!("{value}d"),
+        (DefaultValue::Char(value), Format::Char) => string_literal(&value.to_string()),
+        (DefaultValue::Str(value), Format::Str) => string_literal(value),
+        _ => return None,
+    })
+}
+
+/// The expressions that read the `fields` of a record from the JSON object
+/// `object`: an `Option` is empty if it's missing, and a field with a
+/// default value has it.
+fn fields_from_json(scope: &Scope, object: &str, fields: &[Named<Format>]) -> Vec<String> {
+    fields
+        .iter()
+        .map(|field| {
+            let key = string_literal(&field.name);
+            if matches!(field.value, Format::Option(_))
+                && field.metadata.type_override(Language::Java).is_none()
+            {
+                return field_from_json(scope, field, &format!("{object}.get({key})"));
+            }
+            match default_value(field) {
+                Some(default) => format!(
+                    "{object}.containsKey({key}) ? {} : {default}",
+                    field_from_json(scope, field, &format!("{object}.get({key})"))
+                ),
+                None => field_from_json(
+                    scope,
+                    field,
+                    &format!("com.novi.serde.Json.field({object}, {key})"),
+                ),
+            }
+        })
+        .collect()
+}
+
+/// The arguments of `Json.object` that write the `fields` of `this`.
+fn object_entries(fields: &[Named<Format>]) -> Vec<String> {
+    fields
+        .iter()
+        .map(|field| format!("{}, {}", string_literal(&field.name), field_to_json(field)))
+        .collect()
+}
+
+/// The arguments of `Json.array` that write the positional `fields` of
+/// `this`.
+fn array_elements(fields: &[Named<Format>]) -> Vec<String> {
+    fields.iter().map(field_to_json).collect()
+}
+
+/// The diamond of a generic type's construction, or nothing.
+const fn diamond(type_params: &[String]) -> &'static str {
+    if type_params.is_empty() { "" } else { "<>" }
+}
+
+/// Writes the statements that read a record whose fields are `fields` from
+/// the JSON `json`, ending with a call of `constructor` (e.g. `return new
+/// Point`) whose arguments read them and `end`.
+#[allow(clippy::too_many_arguments)]
+fn write_read<W: IndentWrite>(
+    w: &mut W,
+    scope: &Scope,
+    format: &VariantFormat,
+    fields: &[Named<Format>],
+    json: &str,
+    constructor: &str,
+    end: &str,
+) -> Result<()> {
+    match format {
+        VariantFormat::Unit => {
+            writeln!(w, "com.novi.serde.Json.unitFromJson({json});")?;
+            write_call(w, constructor, &[], end)
+        }
+        VariantFormat::NewType(_) => write_call(
+            w,
+            constructor,
+            &[field_from_json(scope, &fields[0], json)],
+            end,
+        ),
+        VariantFormat::Tuple(_) => {
+            writeln!(
+                w,
+                "java.util.List<{}> elements = com.novi.serde.Json.tupleFromJson({json}, {});",
+                scope.lang("Object"),
+                fields.len()
+            )?;
+            let args = fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| field_from_json(scope, field, &format!("elements.get({i})")))
+                .collect::<Vec<_>>();
+            write_call(w, constructor, &args, end)
+        }
+        VariantFormat::Struct(_) if fields.is_empty() => {
+            writeln!(w, "com.novi.serde.Json.objectFromJson({json});")?;
+            write_call(w, constructor, &[], end)
+        }
+        VariantFormat::Struct(_) => {
+            writeln!(
+                w,
+                "java.util.Map<{}, {}> fields = com.novi.serde.Json.objectFromJson({json});",
+                scope.lang("String"),
+                scope.lang("Object")
+            )?;
+            write_call(
+                w,
+                constructor,
+                &fields_from_json(scope, "fields", fields),
+                end,
+            )
+        }
+        VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+    }
+}
+
+/// The shape of a struct's record, as the variant it would be.
+fn record_format(format: &ContainerFormat) -> VariantFormat {
+    match format {
+        ContainerFormat::UnitStruct(..) => VariantFormat::Unit,
+        ContainerFormat::NewTypeStruct(format, ..) => VariantFormat::NewType(format.clone()),
+        ContainerFormat::TupleStruct(formats, ..) => VariantFormat::Tuple(formats.clone()),
+        ContainerFormat::Struct(fields, ..) => VariantFormat::Struct(fields.clone()),
+        ContainerFormat::Enum(..) => unreachable!("enums aren't records"),
+    }
+}
+
+/// The expression that writes a record's content: its value, an array of its
+/// fields, an object of them, or nothing for a unit.
+fn content(format: &VariantFormat, fields: &[Named<Format>]) -> Option<(String, Vec<String>)> {
+    match format {
+        VariantFormat::Unit => None,
+        VariantFormat::NewType(_) => Some((field_to_json(&fields[0]), vec![])),
+        VariantFormat::Tuple(_) => Some((
+            "com.novi.serde.Json.array".to_string(),
+            array_elements(fields),
+        )),
+        VariantFormat::Struct(_) => Some((
+            "com.novi.serde.Json.object".to_string(),
+            object_entries(fields),
+        )),
+        VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+    }
+}
+
+/// Writes the signature of `toJson`, up to its opening brace.
+fn write_to_json_signature<W: IndentWrite>(
+    w: &mut W,
+    scope: &Scope,
+    modifier: &str,
+    type_params: &[String],
+    end: &str,
+) -> Result<()> {
+    writeln!(
+        w,
+        "{modifier}{} toJson({}){end}",
+        scope.lang("Object"),
+        converter_params("ToJson", "toJson", type_params).join(", ")
+    )
+}
+
+/// Writes `jsonSerialize`, which writes the value as JSON text.
+fn write_json_serialize<W: IndentWrite>(
+    w: &mut W,
+    scope: &Scope,
+    modifier: &str,
+    type_params: &[String],
+) -> Result<()> {
+    writeln!(
+        w,
+        "{modifier} {} jsonSerialize({}) {{",
+        scope.lang("String"),
+        converter_params("ToJson", "toJson", type_params).join(", ")
+    )?;
+    w.indent();
+    writeln!(
+        w,
+        "return com.novi.serde.Json.stringify(toJson({}));",
+        closure_args("toJson", type_params).trim_start_matches(", ")
+    )?;
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// Writes the signature of a static `fromJson` method, up to its opening
+/// brace.
+fn write_from_json_signature<W: IndentWrite>(
+    w: &mut W,
+    scope: &Scope,
+    modifier: &str,
+    name: &str,
+    type_params: &[String],
+) -> Result<()> {
+    let args = type_params_decl(type_params);
+    let generic = if args.is_empty() {
+        String::new()
+    } else {
+        format!("{args} ")
+    };
+    let mut params = vec![format!("{} json", scope.lang("Object"))];
+    params.extend(converter_params("FromJson", "fromJson", type_params));
+    writeln!(
+        w,
+        "{modifier} {generic}{name}{args} fromJson({}) throws com.novi.serde.DeserializationError {{",
+        params.join(", ")
+    )
+}
+
+/// Writes `jsonDeserialize`, which reads a value from JSON text.
+fn write_json_deserialize<W: IndentWrite>(
+    w: &mut W,
+    scope: &Scope,
+    modifier: &str,
+    name: &str,
+    type_params: &[String],
+) -> Result<()> {
+    let args = type_params_decl(type_params);
+    let generic = if args.is_empty() {
+        String::new()
+    } else {
+        format!("{args} ")
+    };
+    let mut params = vec![format!("{} input", scope.lang("String"))];
+    params.extend(converter_params("FromJson", "fromJson", type_params));
+    writeln!(
+        w,
+        "{modifier} {generic}{name}{args} jsonDeserialize({}) throws com.novi.serde.DeserializationError {{",
+        params.join(", ")
+    )?;
+    w.indent();
+    writeln!(
+        w,
+        "return fromJson(com.novi.serde.Json.parse(input){});",
+        closure_args("fromJson", type_params)
+    )?;
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// Writes the members of a record: a struct, or a newtype, tuple or unit
+/// struct.
+fn write_record<W: IndentWrite>(w: &mut W, scope: &Scope, ctx: &EmitContext) -> Result<()> {
+    let name = ctx.name();
+    let type_params = ctx.type_params();
+    let fields = ctx.fields();
+    let format = record_format(ctx.container.format);
+
+    write_to_json_signature(w, scope, "public ", type_params, " {")?;
+    w.indent();
+    match content(&format, &fields) {
+        None => writeln!(w, "return null;")?,
+        Some((value, args)) if args.is_empty() && !value.ends_with(".object") => {
+            writeln!(w, "return {value};")?;
+        }
+        Some((callee, args)) => write_call(w, &format!("return {callee}"), &args, ";")?,
+    }
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write_json_serialize(w, scope, "public", type_params)?;
+    writeln!(w)?;
+
+    write_from_json_signature(w, scope, "public static", name, type_params)?;
+    w.indent();
+    let constructor = format!("return new {name}{}", diamond(type_params));
+    write_read(w, scope, &format, &fields, "json", &constructor, ";")?;
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write_json_deserialize(w, scope, "public static", name, type_params)
+}
+
+/// Writes the members of an enum whose variants are all unit variants, which
+/// are written as their names with the enum's tagging.
+fn write_enum<W: IndentWrite>(
+    w: &mut W,
+    scope: &Scope,
+    name: &str,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+    tagging: &EnumTagging,
+) -> Result<()> {
+    let string = scope.lang("String");
+    write_to_json_signature(w, scope, "public ", &[], " {")?;
+    w.indent();
+    if let EnumTagging::Untagged = tagging {
+        writeln!(w, "return null;")?;
+    } else if variants.is_empty() {
+        // An enum without constants has no values to write.
+        writeln!(
+            w,
+            "throw new java.lang.IllegalStateException({});",
+            string_literal(&format!("{name} has no values"))
+        )?;
+    } else {
+        writeln!(w, "{string} name = switch (this) {{")?;
+        w.indent();
+        for variant in variants.values() {
+            writeln!(
+                w,
+                "case {} -> {};",
+                enum_constant(&variant.name),
+                string_literal(&variant.name)
+            )?;
+        }
+        w.unindent();
+        writeln!(w, "}};")?;
+        match tagging {
+            EnumTagging::External => writeln!(w, "return name;")?,
+            EnumTagging::Internal { tag } | EnumTagging::Adjacent { tag, .. } => writeln!(
+                w,
+                "return com.novi.serde.Json.object({}, name);",
+                string_literal(tag)
+            )?,
+            EnumTagging::Untagged => unreachable!("untagged enums are written above"),
+        }
+    }
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write_json_serialize(w, scope, "public", &[])?;
+    writeln!(w)?;
+
+    write_from_json_signature(w, scope, "public static", name, &[])?;
+    w.indent();
+    let unknown = format!(
+        "throw new com.novi.serde.DeserializationError({} + name);",
+        string_literal(&format!("Unknown variant for {name}: "))
+    );
+    match tagging {
+        EnumTagging::External => {
+            writeln!(w, "{string} name = com.novi.serde.Json.strFromJson(json);")?;
+        }
+        EnumTagging::Internal { tag } | EnumTagging::Adjacent { tag, .. } => writeln!(
+            w,
+            "{string} name = com.novi.serde.Json.strFromJson(com.novi.serde.Json.field(com.novi.serde.Json.objectFromJson(json), {}));",
+            string_literal(tag)
+        )?,
+        EnumTagging::Untagged => {
+            // Every variant is `null`, which serde reads as the first.
+            writeln!(w, "com.novi.serde.Json.unitFromJson(json);")?;
+        }
+    }
+    match (tagging, variants.values().next()) {
+        (EnumTagging::Untagged, Some(first)) => {
+            writeln!(w, "return {};", enum_constant(&first.name))?;
+        }
+        (EnumTagging::Untagged, None) => writeln!(
+            w,
+            "throw new com.novi.serde.DeserializationError({});",
+            string_literal(&format!("{name} has no values"))
+        )?,
+        (_, None) => writeln!(w, "{unknown}")?,
+        (_, Some(_)) => {
+            writeln!(w, "return switch (name) {{")?;
+            w.indent();
+            for variant in variants.values() {
+                writeln!(
+                    w,
+                    "case {} -> {};",
+                    string_literal(&variant.name),
+                    enum_constant(&variant.name)
+                )?;
+            }
+            // Unknown variants, e.g. added by a later version, are read as the catch-all.
+            match variants.values().find(|variant| variant.metadata.other) {
+                Some(other) => writeln!(w, "default -> {};", enum_constant(&other.name))?,
+                None => writeln!(w, "default -> {unknown}")?,
+            }
+            w.unindent();
+            writeln!(w, "}};")?;
+        }
+    }
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write_json_deserialize(w, scope, "public static", name, &[])
+}
+
+/// Writes the `toJson` of a variant's record, which writes it with its enum's
+/// tagging.
+fn write_variant<W: IndentWrite>(
+    w: &mut W,
+    scope: &Scope,
+    ctx: &EmitContext,
+    tagging: &EnumTagging,
+) -> Result<()> {
+    let Some(variant) = &ctx.variant else {
+        unreachable!("only variants are written as variants")
+    };
+    writeln!(w, "@{}", scope.lang("Override"))?;
+    write_to_json_signature(w, scope, "public ", variant.parent_type_params, " {")?;
+    w.indent();
+    let name = string_literal(variant.name);
+    let object = "com.novi.serde.Json.object";
+    let content = content(variant.format, variant.fields);
+    // Arrays and objects are built before the enum's tagging wraps them.
+    let content = match content {
+        Some((callee, args)) if !args.is_empty() || callee.ends_with(".object") => {
+            if matches!(tagging, EnumTagging::Untagged)
+                || matches!(
+                    (tagging, variant.format),
+                    (EnumTagging::Internal { .. }, VariantFormat::Struct(_))
+                )
+            {
+                Some((callee, args))
+            } else {
+                write_call(
+                    w,
+                    &format!("{} content = {callee}", scope.lang("Object")),
+                    &args,
+                    ";",
+                )?;
+                Some(("content".to_string(), vec![]))
+            }
+        }
+        content => content,
+    };
+    let value =
+        |content: &Option<(String, Vec<String>)>| content.as_ref().map(|(value, _)| value.clone());
+    match (tagging, &content) {
+        (EnumTagging::External, None) => writeln!(w, "return {name};")?,
+        (EnumTagging::External, Some(_)) => writeln!(
+            w,
+            "return {object}({name}, {});",
+            value(&content).unwrap_or_default()
+        )?,
+        (EnumTagging::Internal { tag } | EnumTagging::Adjacent { tag, .. }, None) => {
+            writeln!(w, "return {object}({}, {name});", string_literal(tag))?;
+        }
+        (EnumTagging::Internal { tag }, Some(_))
+            if matches!(variant.format, VariantFormat::NewType(_)) =>
+        {
+            // The tag is added to the fields of the newtype's value, which must be an object.
+            writeln!(
+                w,
+                "return com.novi.serde.Json.withTag({}, {name}, {});",
+                string_literal(tag),
+                value(&content).unwrap_or_default()
+            )?;
+        }
+        (EnumTagging::Internal { tag }, Some((_, args))) => {
+            let mut entries = vec![format!("{}, {name}", string_literal(tag))];
+            entries.extend(args.iter().cloned());
+            write_call(w, &format!("return {object}"), &entries, ";")?;
+        }
+        (EnumTagging::Adjacent { tag, content: key }, Some((value, _))) => writeln!(
+            w,
+            "return {object}({}, {name}, {}, {value});",
+            string_literal(tag),
+            string_literal(key)
+        )?,
+        (EnumTagging::Untagged, None) => writeln!(w, "return null;")?,
+        (EnumTagging::Untagged, Some((callee, args)))
+            if !args.is_empty() || callee.ends_with(".object") =>
+        {
+            write_call(w, &format!("return {callee}"), args, ";")?;
+        }
+        (EnumTagging::Untagged, Some((value, _))) => writeln!(w, "return {value};")?,
+    }
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// Writes the static methods of the interface of an enum whose variants carry
+/// data, which switch on the variant's name, or try each variant in turn if
+/// it's untagged.
+fn write_union<W: IndentWrite>(
+    w: &mut W,
+    scope: &Scope,
+    name: &str,
+    type_params: &[String],
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+    tagging: &EnumTagging,
+) -> Result<()> {
+    let string = scope.lang("String");
+    let object = scope.lang("Object");
+    let new = |variant: &str| format!("new {}{}", variant_class(variant), diamond(type_params));
+    let catch_all = variants.values().find(|variant| variant.metadata.other);
+
+    write_from_json_signature(w, scope, "static", name, type_params)?;
+    w.indent();
+    if let EnumTagging::Untagged = tagging {
+        // Like serde, the first variant that can be read wins.
+        for variant in variants.values() {
+            let fields = variant_fields(&variant.value);
+            if let VariantFormat::Unit = variant.value {
+                writeln!(w, "if (json == null) {{")?;
+                w.indent();
+                writeln!(w, "return {}();", new(&variant.name))?;
+                w.unindent();
+                writeln!(w, "}}")?;
+                continue;
+            }
+            writeln!(w, "try {{")?;
+            w.indent();
+            let constructor = format!("return {}", new(&variant.name));
+            write_read(w, scope, &variant.value, &fields, "json", &constructor, ";")?;
+            w.unindent();
+            writeln!(w, "}} catch (com.novi.serde.DeserializationError e) {{")?;
+            w.indent();
+            writeln!(w, "// Try the next variant.")?;
+            w.unindent();
+            writeln!(w, "}}")?;
+        }
+        match catch_all {
+            Some(other) => writeln!(w, "return {}();", new(&other.name))?,
+            None => writeln!(
+                w,
+                "throw new com.novi.serde.DeserializationError({} + com.novi.serde.Json.stringify(json));",
+                string_literal(&format!("No variant of {name} matches "))
+            )?,
+        }
+    } else {
+        let content = match tagging {
+            EnumTagging::External => {
+                writeln!(
+                    w,
+                    "java.util.Map.Entry<{string}, {object}> entry = com.novi.serde.Json.externalTag(json);"
+                )?;
+                writeln!(w, "{string} name = entry.getKey();")?;
+                "entry.getValue()".to_string()
+            }
+            EnumTagging::Internal { tag } | EnumTagging::Adjacent { tag, .. } => {
+                writeln!(
+                    w,
+                    "java.util.Map<{string}, {object}> object = com.novi.serde.Json.objectFromJson(json);"
+                )?;
+                writeln!(
+                    w,
+                    "{string} name = com.novi.serde.Json.strFromJson(com.novi.serde.Json.field(object, {}));",
+                    string_literal(tag)
+                )?;
+                match tagging {
+                    // The variant's fields are the object's other fields.
+                    EnumTagging::Internal { .. } => "json".to_string(),
+                    EnumTagging::Adjacent { content, .. } => {
+                        format!("object.get({})", string_literal(content))
+                    }
+                    _ => unreachable!("only tags are read here"),
+                }
+            }
+            EnumTagging::Untagged => unreachable!("untagged enums are read above"),
+        };
+        writeln!(w, "return switch (name) {{")?;
+        w.indent();
+        for variant in variants.values() {
+            let fields = variant_fields(&variant.value);
+            let case = format!("case {} ->", string_literal(&variant.name));
+            match &variant.value {
+                // Unit variants have no content to read.
+                VariantFormat::Unit => writeln!(w, "{case} {}();", new(&variant.name))?,
+                VariantFormat::NewType(_) => write_call(
+                    w,
+                    &format!("{case} {}", new(&variant.name)),
+                    &[field_from_json(scope, &fields[0], &content)],
+                    ";",
+                )?,
+                format => {
+                    writeln!(w, "{case} {{")?;
+                    w.indent();
+                    let constructor = format!("yield {}", new(&variant.name));
+                    write_read(w, scope, format, &fields, &content, &constructor, ";")?;
+                    w.unindent();
+                    writeln!(w, "}}")?;
+                }
+            }
+        }
+        // Unknown variants, e.g. added by a later version, are read as the catch-all.
+        match catch_all {
+            Some(other) => writeln!(w, "default -> {}();", new(&other.name))?,
+            None => writeln!(
+                w,
+                "default -> throw new com.novi.serde.DeserializationError({} + name);",
+                string_literal(&format!("Unknown variant for {name}: "))
+            )?,
+        }
+        w.unindent();
+        writeln!(w, "}};")?;
+    }
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    write_json_deserialize(w, scope, "static", name, type_params)
+}
+
+// ---------------------------------------------------------------------------
+// EmitterPlugin implementation
+// ---------------------------------------------------------------------------
+
+impl EmitterPlugin<Java> for JsonPlugin {
+    /// Returns the `com.novi.serde` runtime package, whose JSON parser and
+    /// converters the generated code calls, to be written into the generated
+    /// project's sources.
+    fn runtime_files(&self) -> Vec<RuntimeFile> {
+        static SERDE: include_dir::Dir<'static> =
+            include_dir::include_dir!("$CARGO_MANIFEST_DIR/runtime/java/com/novi/serde");
+        SERDE
+            .files()
+            .map(|f| RuntimeFile {
+                relative_path: format!("com/novi/serde/{}", f.path().display()),
+                contents: f.contents().to_vec(),
+            })
+            .collect()
+    }
+
+    /// Every type gets methods.
+    fn has_type_body(&self, _ctx: &EmitContext) -> bool {
+        true
+    }
+
+    /// The interface of an enum whose variants carry data declares the
+    /// `toJson` method that its variants implement.
+    fn type_body_preamble(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
+        if ctx.is_variant() || !is_union_format(ctx.container.format) {
+            return Ok(());
+        }
+        let config = w.config();
+        let mut w = IndentedWriter::new(&mut *w, config);
+        let scope = Scope::new(ctx.container, ctx.config);
+        write_to_json_signature(&mut w, &scope, "", ctx.type_params(), ";")?;
+        writeln!(w)?;
+        write_json_serialize(&mut w, &scope, "default", ctx.type_params())
+    }
+
+    /// The methods of every type: variant records write themselves, and the
+    /// enum's interface reads any of them.
+    fn type_body(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
+        let config = w.config();
+        let mut w = IndentedWriter::new(&mut *w, config);
+        let scope = Scope::new(ctx.container, ctx.config);
+        match ctx.container.format {
+            ContainerFormat::Enum(_, tagging, ..) if ctx.is_variant() => {
+                write_variant(&mut w, &scope, ctx, tagging)
+            }
+            ContainerFormat::Enum(variants, tagging, ..)
+                if is_union_format(ctx.container.format) =>
+            {
+                write_union(
+                    &mut w,
+                    &scope,
+                    ctx.name(),
+                    ctx.type_params(),
+                    variants,
+                    tagging,
+                )
+            }
+            ContainerFormat::Enum(variants, tagging, ..) => {
+                write_enum(&mut w, &scope, ctx.name(), variants, tagging)
+            }
+            _ => write_record(&mut w, &scope, ctx),
+        }
+    }
+
+    /// Generic enums are supported in every tagging: their methods take the
+    /// converters of their type arguments.
+    fn check_registry(&self, registry: &Registry) -> std::result::Result<(), Error> {
+        super::check_enums(registry, "Java", |_| false)
+    }
+}
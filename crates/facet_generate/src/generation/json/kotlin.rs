@@ -1,18 +1,20 @@
 //! `EmitterPlugin<Kotlin>` implementation for the [`JsonPlugin`].
 //!
 //! Provides JSON-specific imports, `@Serializable` / `@SerialName` type
-//! annotations, `BigInt` helper snippets, and the `serialName` accessor for
-//! all-unit enum classes.
+//! annotations, `BigInt` helper snippets, the `serialName` accessor for
+//! all-unit enum classes, fallbacks for enums with a catch-all variant, and
+//! serializers for untagged, internally tagged and adjacently tagged enums.
 
 use std::io;
 
+use crate::Registry;
 use crate::generation::{
-    CodeGeneratorConfig, Feature,
+    CodeGeneratorConfig, Error, Feature,
     indent::IndentWrite,
-    kotlin::Kotlin,
+    kotlin::{Kotlin, emitter::enum_entry_name},
     plugin::{EmitContext, EmitterPlugin, RuntimeFile},
 };
-use crate::reflection::format::{ContainerFormat, VariantFormat};
+use crate::reflection::format::{ContainerFormat, EnumTagging, Named, VariantFormat};
 
 use super::JsonPlugin;
 
@@ -49,7 +51,305 @@ const FEATURE_UUID: &str = r#"private object UUIDSerializer : KSerializer<java.u
 typealias UUID = @Serializable(with = UUIDSerializer::class) java.util.UUID
 "#;
 
+/// The `Instant` JSON helper — a custom `KSerializer<java.time.Instant>` that
+/// round-trips instants through RFC 3339 strings.
+const FEATURE_TIMESTAMP: &str = r#"private object InstantSerializer : KSerializer<java.time.Instant> {
+    override val descriptor = PrimitiveSerialDescriptor("Instant", PrimitiveKind.STRING)
+    override fun deserialize(decoder: Decoder): java.time.Instant = java.time.OffsetDateTime.parse(decoder.decodeString()).toInstant()
+    override fun serialize(encoder: Encoder, value: java.time.Instant) = encoder.encodeString(value.toString())
+}
+
+typealias Instant = @Serializable(with = InstantSerializer::class) java.time.Instant
+"#;
+
+/// The `LocalDate` JSON helper — a custom `KSerializer<java.time.LocalDate>`
+/// that round-trips dates through `YYYY-MM-DD` strings.
+const FEATURE_DATE: &str = r#"private object LocalDateSerializer : KSerializer<java.time.LocalDate> {
+    override val descriptor = PrimitiveSerialDescriptor("LocalDate", PrimitiveKind.STRING)
+    override fun deserialize(decoder: Decoder): java.time.LocalDate = java.time.LocalDate.parse(decoder.decodeString())
+    override fun serialize(encoder: Encoder, value: java.time.LocalDate) = encoder.encodeString(value.toString())
+}
+
+typealias LocalDate = @Serializable(with = LocalDateSerializer::class) java.time.LocalDate
+"#;
+
+/// The `LocalTime` JSON helper — a custom `KSerializer<java.time.LocalTime>`
+/// that round-trips times of day through `HH:MM:SS[.fraction]` strings.
+const FEATURE_TIME: &str = r#"private object LocalTimeSerializer : KSerializer<java.time.LocalTime> {
+    override val descriptor = PrimitiveSerialDescriptor("LocalTime", PrimitiveKind.STRING)
+    override fun deserialize(decoder: Decoder): java.time.LocalTime = java.time.LocalTime.parse(decoder.decodeString())
+    override fun serialize(encoder: Encoder, value: java.time.LocalTime) =
+        encoder.encodeString(java.time.format.DateTimeFormatter.ISO_LOCAL_TIME.format(value))
+}
+
+typealias LocalTime = @Serializable(with = LocalTimeSerializer::class) java.time.LocalTime
+"#;
+
+/// The `Duration` JSON helper — a custom `KSerializer<java.time.Duration>`
+/// that round-trips durations through `{"secs": …, "nanos": …}` objects, the
+/// shape `serde` gives `std::time::Duration`.
+const FEATURE_DURATION: &str = r#"@Serializable
+private class DurationSurrogate(val secs: Long, val nanos: Int)
+
+private object DurationSerializer : KSerializer<java.time.Duration> {
+    override val descriptor = DurationSurrogate.serializer().descriptor
+    override fun deserialize(decoder: Decoder): java.time.Duration {
+        val surrogate = decoder.decodeSerializableValue(DurationSurrogate.serializer())
+        return java.time.Duration.ofSeconds(surrogate.secs, surrogate.nanos.toLong())
+    }
+    override fun serialize(encoder: Encoder, value: java.time.Duration) =
+        encoder.encodeSerializableValue(DurationSurrogate.serializer(), DurationSurrogate(value.seconds, value.nano))
+}
+
+typealias Duration = @Serializable(with = DurationSerializer::class) java.time.Duration
+"#;
+
+/// The variants of the top-level enum being emitted, if it is one.
+fn enum_variants<'a>(
+    ctx: &EmitContext<'a>,
+) -> Option<impl Iterator<Item = &'a Named<VariantFormat>>> {
+    match ctx.container.format {
+        ContainerFormat::Enum(variants, _, _, _) if !ctx.is_variant() => Some(variants.values()),
+        _ => None,
+    }
+}
+
+/// The catch-all (`#[facet(other)]`) variant of the top-level enum being
+/// emitted, if it has one.
+fn other_variant<'a>(ctx: &EmitContext<'a>) -> Option<&'a Named<VariantFormat>> {
+    enum_variants(ctx)?.find(|variant| variant.metadata.other)
+}
+
+/// Whether the top-level entity is an enum whose variants are all unit
+/// variants, i.e. a Kotlin `enum class`.
+fn is_enum_class(ctx: &EmitContext) -> bool {
+    enum_variants(ctx).is_some_and(|mut variants| {
+        variants.all(|variant| matches!(variant.value, VariantFormat::Unit))
+    })
+}
+
+/// The tagging of the top-level enum being emitted, if its JSON coding is
+/// generated, i.e. if it isn't externally tagged.
+fn generated_tagging<'a>(ctx: &EmitContext<'a>) -> Option<&'a EnumTagging> {
+    match ctx.container.format {
+        ContainerFormat::Enum(_, tagging, _, _)
+            if !ctx.is_variant() && *tagging != EnumTagging::External =>
+        {
+            Some(tagging)
+        }
+        _ => None,
+    }
+}
+
+/// Writes the `Serializer` of an enum that isn't externally tagged, which
+/// kotlinx's polymorphism can't encode.
+///
+/// The payload of a variant is encoded as serde does: a newtype as its value,
+/// a tuple as an array and a struct as an object. An untagged enum writes the
+/// payload alone (`null` for a unit variant) and reads the first variant that
+/// matches. An internally tagged one adds the tag to the payload's object, and
+/// an adjacently tagged one writes the tag and the payload under their own
+/// keys. Newtype and tuple payloads go through the variant's own data class
+/// serializer by way of an object keyed by its property names.
+fn write_enum_serializer(
+    w: &mut dyn IndentWrite,
+    name: &str,
+    variants: &[&Named<VariantFormat>],
+    tagging: &EnumTagging,
+    enum_class: bool,
+) -> io::Result<()> {
+    const JSON: &str = "kotlinx.serialization.json";
+    // Entries of an enum class are referenced by their uppercased name.
+    let reference = |variant: &Named<VariantFormat>| {
+        if enum_class {
+            format!("{name}.{}", enum_entry_name(&variant.name))
+        } else {
+            variant.name.clone()
+        }
+    };
+    // The payload of `value`, smart cast to the variant's data class.
+    let encode = |variant: &Named<VariantFormat>| {
+        let reference = reference(variant);
+        let encoded = format!(
+            "json.encodeToJsonElement({reference}.serializer(), value) as {JSON}.JsonObject"
+        );
+        match &variant.value {
+            VariantFormat::Unit => None,
+            VariantFormat::NewType(_) => Some(format!(r#"({encoded}).getValue("value")"#)),
+            VariantFormat::Tuple(formats) => {
+                let items = (0..formats.len())
+                    .map(|i| format!(r#"it.getValue("field{i}")"#))
+                    .collect::<Vec<_>>();
+                Some(format!(
+                    "({encoded}).let {{ {JSON}.JsonArray(listOf({})) }}",
+                    items.join(", ")
+                ))
+            }
+            VariantFormat::Struct(_) => Some(format!(
+                "json.encodeToJsonElement({reference}.serializer(), value)"
+            )),
+            VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+        }
+    };
+    // The variant read from the payload in `content`.
+    let decode = |variant: &Named<VariantFormat>, content: &str| {
+        let reference = reference(variant);
+        match &variant.value {
+            VariantFormat::Unit => reference,
+            VariantFormat::NewType(_) => format!(
+                r#"json.decodeFromJsonElement({reference}.serializer(), {JSON}.JsonObject(mapOf("value" to {content})))"#
+            ),
+            VariantFormat::Tuple(formats) => {
+                let entries = (0..formats.len())
+                    .map(|i| format!(r#""field{i}" to it[{i}]"#))
+                    .collect::<Vec<_>>();
+                format!(
+                    "({content} as {JSON}.JsonArray).let {{ require(it.size == {}); json.decodeFromJsonElement({reference}.serializer(), {JSON}.JsonObject(mapOf({}))) }}",
+                    formats.len(),
+                    entries.join(", ")
+                )
+            }
+            VariantFormat::Struct(_) => {
+                format!("json.decodeFromJsonElement({reference}.serializer(), {content})")
+            }
+            VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+        }
+    };
+
+    writeln!(w)?;
+    writeln!(
+        w,
+        "object Serializer : kotlinx.serialization.KSerializer<{name}> {{"
+    )?;
+    w.indent();
+    writeln!(
+        w,
+        "override val descriptor: kotlinx.serialization.descriptors.SerialDescriptor ="
+    )?;
+    writeln!(
+        w,
+        r#"    kotlinx.serialization.descriptors.buildClassSerialDescriptor("{name}")"#
+    )?;
+
+    writeln!(w)?;
+    writeln!(
+        w,
+        "override fun serialize(encoder: kotlinx.serialization.encoding.Encoder, value: {name}) {{"
+    )?;
+    w.indent();
+    writeln!(w, "val json = (encoder as {JSON}.JsonEncoder).json")?;
+    writeln!(w, "val element = when (value) {{")?;
+    w.indent();
+    for variant in variants {
+        let case = if enum_class {
+            reference(variant)
+        } else {
+            format!("is {}", reference(variant))
+        };
+        let payload = encode(variant);
+        let tag = |tag: &str| format!(r#""{tag}" to {JSON}.JsonPrimitive("{}")"#, variant.name);
+        let element = match (tagging, payload) {
+            (EnumTagging::Internal { tag: key }, None) => {
+                format!("{JSON}.JsonObject(mapOf({}))", tag(key))
+            }
+            (EnumTagging::Internal { tag: key }, Some(payload)) => format!(
+                "{JSON}.JsonObject(mapOf({}) + ({payload} as {JSON}.JsonObject))",
+                tag(key)
+            ),
+            (EnumTagging::Adjacent { tag: key, .. }, None) => {
+                format!("{JSON}.JsonObject(mapOf({}))", tag(key))
+            }
+            (EnumTagging::Adjacent { tag: key, content }, Some(payload)) => format!(
+                r#"{JSON}.JsonObject(mapOf({}, "{content}" to {payload}))"#,
+                tag(key)
+            ),
+            (_, None) => format!("{JSON}.JsonNull"),
+            (_, Some(payload)) => payload,
+        };
+        writeln!(w, "{case} -> {element}")?;
+    }
+    w.unindent();
+    writeln!(w, "}}")?;
+    writeln!(w, "encoder.encodeJsonElement(element)")?;
+    w.unindent();
+    writeln!(w, "}}")?;
+
+    writeln!(w)?;
+    writeln!(
+        w,
+        "override fun deserialize(decoder: kotlinx.serialization.encoding.Decoder): {name} {{"
+    )?;
+    w.indent();
+    writeln!(w, "val json = (decoder as {JSON}.JsonDecoder).json")?;
+    let key = match tagging {
+        EnumTagging::Internal { tag } | EnumTagging::Adjacent { tag, .. } => tag,
+        EnumTagging::External | EnumTagging::Untagged => {
+            writeln!(w, "val element = decoder.decodeJsonElement()")?;
+            for variant in variants {
+                if variant.value == VariantFormat::Unit {
+                    writeln!(
+                        w,
+                        "if (element is {JSON}.JsonNull) return {}",
+                        reference(variant)
+                    )?;
+                } else {
+                    writeln!(w, "runCatching {{ return {} }}", decode(variant, "element"))?;
+                }
+            }
+            writeln!(
+                w,
+                r#"throw kotlinx.serialization.SerializationException("No variant of {name} matches the input")"#
+            )?;
+            w.unindent();
+            writeln!(w, "}}")?;
+            w.unindent();
+            return writeln!(w, "}}");
+        }
+    };
+    writeln!(
+        w,
+        r#"val element = decoder.decodeJsonElement() as? {JSON}.JsonObject ?: throw kotlinx.serialization.SerializationException("Expected an object for {name}")"#
+    )?;
+    writeln!(
+        w,
+        r#"val tag = (element["{key}"] as? {JSON}.JsonPrimitive)?.content"#
+    )?;
+    let content = match tagging {
+        EnumTagging::Adjacent { content, .. } => format!(r#"element.getValue("{content}")"#),
+        _ => format!(r#"{JSON}.JsonObject(element - "{key}")"#),
+    };
+    writeln!(w, "return when (tag) {{")?;
+    w.indent();
+    for variant in variants {
+        writeln!(w, r#""{}" -> {}"#, variant.name, decode(variant, &content))?;
+    }
+    // Unknown variants, e.g. added by a later version, are read as the catch-all.
+    match variants.iter().find(|variant| variant.metadata.other) {
+        Some(other) => writeln!(w, "else -> {}", reference(other))?,
+        None => writeln!(
+            w,
+            r#"else -> throw kotlinx.serialization.SerializationException("Unknown variant of {name}: $tag")"#
+        )?,
+    }
+    w.unindent();
+    writeln!(w, "}}")?;
+    w.unindent();
+    writeln!(w, "}}")?;
+
+    w.unindent();
+    writeln!(w, "}}")
+}
+
 impl EmitterPlugin<Kotlin> for JsonPlugin {
+    /// Rejects generic enums that aren't externally tagged, whose `Serializer`
+    /// would need the serializers of their type arguments, and internally
+    /// tagged tuple variants.
+    fn check_registry(&self, registry: &Registry) -> Result<(), Error> {
+        super::check_enums(registry, "Kotlin", |tagging| {
+            *tagging != EnumTagging::External
+        })
+    }
+
     /// Returns the serde Kotlin runtime sources needed for JSON encoding.
     fn runtime_files(&self) -> Vec<RuntimeFile> {
         static SERDE: include_dir::Dir<'static> =
@@ -82,8 +382,17 @@ impl EmitterPlugin<Kotlin> for JsonPlugin {
             "import kotlinx.serialization.SerialName".to_string(),
         ];
 
-        // UUID JSON-specific imports
-        if config.features.contains(&Feature::Uuid) {
+        // UUID and date/time JSON-specific imports
+        if [
+            Feature::Uuid,
+            Feature::Timestamp,
+            Feature::Date,
+            Feature::Time,
+            Feature::Duration,
+        ]
+        .iter()
+        .any(|feature| config.features.contains(feature))
+        {
             imports.extend([
                 "import kotlinx.serialization.KSerializer".to_string(),
                 "import kotlinx.serialization.descriptors.PrimitiveKind".to_string(),
@@ -111,10 +420,11 @@ impl EmitterPlugin<Kotlin> for JsonPlugin {
         imports
     }
 
-    /// `BigInt` JSON helper snippet for a Kotlin module.
+    /// JSON helper snippets for a Kotlin module.
     ///
     /// When `BigInt` types are present, emits the custom `KSerializer<BigInteger>`
-    /// that serializes big integers as unquoted JSON number literals.
+    /// that serializes big integers as unquoted JSON number literals. UUID and
+    /// date/time types get a `KSerializer` and a same-named `typealias` each.
     fn module_helpers(
         &self,
         w: &mut dyn IndentWrite,
@@ -124,6 +434,22 @@ impl EmitterPlugin<Kotlin> for JsonPlugin {
             write!(w, "{FEATURE_UUID}")?;
             writeln!(w)?;
         }
+        if config.features.contains(&Feature::Timestamp) {
+            write!(w, "{FEATURE_TIMESTAMP}")?;
+            writeln!(w)?;
+        }
+        if config.features.contains(&Feature::Date) {
+            write!(w, "{FEATURE_DATE}")?;
+            writeln!(w)?;
+        }
+        if config.features.contains(&Feature::Time) {
+            write!(w, "{FEATURE_TIME}")?;
+            writeln!(w)?;
+        }
+        if config.features.contains(&Feature::Duration) {
+            write!(w, "{FEATURE_DURATION}")?;
+            writeln!(w)?;
+        }
         if config.features.contains(&Feature::BigInt) {
             write!(w, "{FEATURE_BIGINT}")?;
             writeln!(w)?;
@@ -134,13 +460,20 @@ impl EmitterPlugin<Kotlin> for JsonPlugin {
     /// `@Serializable` and `@SerialName("…")` annotations for each type.
     ///
     /// These are emitted on separate lines above every `data class`,
-    /// `data object`, `enum class`, and `sealed interface`.
+    /// `data object`, `enum class`, and `sealed interface`. An `enum class`
+    /// with a catch-all variant, and any enum that isn't externally tagged, is
+    /// serialized with its own `Serializer` instead (see
+    /// [`type_body`](Self::type_body)).
     fn type_annotations(&self, ctx: &EmitContext) -> Vec<String> {
         let name = ctx.name();
-        vec![
-            "@Serializable".to_string(),
-            format!(r#"@SerialName("{name}")"#),
-        ]
+        let serializable = if generated_tagging(ctx).is_some()
+            || is_enum_class(ctx) && other_variant(ctx).is_some()
+        {
+            format!("@Serializable(with = {name}.Serializer::class)")
+        } else {
+            "@Serializable".to_string()
+        };
+        vec![serializable, format!(r#"@SerialName("{name}")"#)]
     }
 
     /// `@SerialName("…")` inline annotation for each all-unit enum class
@@ -160,25 +493,90 @@ impl EmitterPlugin<Kotlin> for JsonPlugin {
 
     /// For all-unit enum classes, emits the `serialName` computed property
     /// that extracts the `@SerialName` annotation value at runtime.
+    ///
+    /// Enums with a catch-all variant also get a fallback for names they don't
+    /// know: enum classes a `Serializer` that decodes them as the catch-all,
+    /// and sealed interfaces a `Serializers.module` that registers the
+    /// catch-all as the default deserializer. Its payload is decoded as an
+    /// empty object, so the `Json` instance needs `ignoreUnknownKeys`.
+    ///
+    /// Untagged, internally tagged and adjacently tagged enums get a
+    /// `Serializer` that encodes them as serde does, which also reads unknown
+    /// tags as the catch-all.
     fn type_body(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
-        // Only applies to all-unit enum classes
-        if ctx.is_variant() {
+        // Only applies to top-level enums
+        let Some(variants) = enum_variants(ctx) else {
             return Ok(());
-        }
+        };
 
-        if let ContainerFormat::Enum(variants, _, _) = ctx.container.format {
-            let all_unit = variants
-                .values()
-                .all(|v| matches!(v.value, VariantFormat::Unit));
+        let name = ctx.name();
+        let other = other_variant(ctx);
+        if is_enum_class(ctx) {
+            writeln!(w)?;
+            writeln!(w, "val serialName: String")?;
+            writeln!(
+                w,
+                "    get() = javaClass.getDeclaredField(name).getAnnotation(SerialName::class.java)!!.value"
+            )?;
+        }
 
-            if all_unit {
+        if let Some(tagging) = generated_tagging(ctx) {
+            let variants = variants.collect::<Vec<_>>();
+            write_enum_serializer(w, name, &variants, tagging, is_enum_class(ctx))?;
+        } else if is_enum_class(ctx) {
+            if let Some(other) = other {
+                let other = enum_entry_name(&other.name);
+                writeln!(w)?;
+                writeln!(
+                    w,
+                    "object Serializer : kotlinx.serialization.KSerializer<{name}> {{"
+                )?;
+                w.indent();
+                writeln!(
+                    w,
+                    "override val descriptor: kotlinx.serialization.descriptors.SerialDescriptor ="
+                )?;
+                writeln!(
+                    w,
+                    r#"    kotlinx.serialization.descriptors.PrimitiveSerialDescriptor("{name}", kotlinx.serialization.descriptors.PrimitiveKind.STRING)"#
+                )?;
+                writeln!(w)?;
+                writeln!(
+                    w,
+                    "override fun serialize(encoder: kotlinx.serialization.encoding.Encoder, value: {name}) {{"
+                )?;
+                writeln!(w, "    encoder.encodeString(value.serialName)")?;
+                writeln!(w, "}}")?;
                 writeln!(w)?;
-                writeln!(w, "val serialName: String")?;
                 writeln!(
                     w,
-                    "    get() = javaClass.getDeclaredField(name).getAnnotation(SerialName::class.java)!!.value"
+                    "override fun deserialize(decoder: kotlinx.serialization.encoding.Decoder): {name} {{"
                 )?;
+                writeln!(w, "    val value = decoder.decodeString()")?;
+                writeln!(
+                    w,
+                    "    return {name}.entries.firstOrNull {{ it.serialName == value }} ?: {name}.{other}"
+                )?;
+                writeln!(w, "}}")?;
+                w.unindent();
+                writeln!(w, "}}")?;
             }
+        } else if let Some(other) = other {
+            writeln!(w)?;
+            writeln!(w, "object Serializers {{")?;
+            w.indent();
+            writeln!(
+                w,
+                "val module = kotlinx.serialization.modules.SerializersModule {{"
+            )?;
+            writeln!(
+                w,
+                "    polymorphicDefaultDeserializer({name}::class) {{ {}.serializer() }}",
+                other.name
+            )?;
+            writeln!(w, "}}")?;
+            w.unindent();
+            writeln!(w, "}}")?;
         }
 
         Ok(())
@@ -239,11 +637,13 @@ mod tests {
     #[test]
     fn type_annotations_include_serializable_and_serial_name() {
         use crate::generation::Container;
-        use crate::reflection::format::{ContainerFormat, Doc, QualifiedTypeName};
+        use crate::reflection::format::{
+            ContainerFormat, ContainerMetadata, Doc, QualifiedTypeName,
+        };
 
         let config = make_config(&[]);
         let name = QualifiedTypeName::root("Foo".to_string());
-        let format = ContainerFormat::Struct(vec![], Doc::default());
+        let format = ContainerFormat::Struct(vec![], Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
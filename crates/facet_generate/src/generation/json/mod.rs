@@ -13,6 +13,7 @@
 //! | `type_annotations` | `@Serializable`, `@SerialName("…")` above each type (Kotlin) |
 //! | `type_body` | `val serialName` accessor for enum classes (Kotlin); `serialize` / `deserialize` + `jsonSerialize` / `jsonDeserialize` wrappers (Swift) |
 //! | `has_type_body` | Always `true` (Swift) |
+//! | `check_registry` | Rejects generic enums with generated JSON coding and internally tagged tuple variants (Swift, Kotlin, C#) |
 //!
 
 #[cfg(feature = "kotlin")]
@@ -27,6 +28,33 @@ pub mod typescript;
 #[cfg(feature = "csharp")]
 pub mod csharp;
 
+#[cfg(feature = "dart")]
+pub mod dart;
+
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(feature = "go")]
+pub mod go;
+
+#[cfg(feature = "java")]
+pub mod java;
+
+#[cfg(any(
+    feature = "swift",
+    feature = "kotlin",
+    feature = "csharp",
+    feature = "dart",
+    feature = "python",
+    feature = "go",
+    feature = "java"
+))]
+use crate::{
+    Registry,
+    generation::Error,
+    reflection::format::{ContainerFormat, EnumTagging, VariantFormat},
+};
+
 /// JSON serialization plugin.
 ///
 /// When added to a language tag's plugin list, it provides the
@@ -37,3 +65,51 @@ pub mod csharp;
 /// submodule (e.g. [`kotlin`]).
 #[derive(Debug, Clone)]
 pub struct JsonPlugin;
+
+/// Rejects the enums whose JSON coding a language generates but can't write
+/// for them: generic enums whose tagging is in `generated` (the generated
+/// code can't name the serializers of their type arguments), and internally
+/// tagged enums with tuple variants, which serde can't encode either.
+#[cfg(any(
+    feature = "swift",
+    feature = "kotlin",
+    feature = "csharp",
+    feature = "dart",
+    feature = "python",
+    feature = "go",
+    feature = "java"
+))]
+fn check_enums(
+    registry: &Registry,
+    language: &str,
+    generated: fn(&EnumTagging) -> bool,
+) -> Result<(), Error> {
+    for (name, format) in registry {
+        let ContainerFormat::Enum(variants, tagging, _, _) = format else {
+            continue;
+        };
+        let kind = match tagging {
+            EnumTagging::External => "externally tagged",
+            EnumTagging::Internal { .. } => "internally tagged",
+            EnumTagging::Adjacent { .. } => "adjacently tagged",
+            EnumTagging::Untagged => "untagged",
+        };
+        let reason = if generated(tagging) && !format.type_params().is_empty() {
+            format!("generic {kind} enums are not supported")
+        } else if matches!(tagging, EnumTagging::Internal { .. })
+            && variants
+                .values()
+                .any(|variant| matches!(variant.value, VariantFormat::Tuple(_)))
+        {
+            "internally tagged enums can't have tuple variants".to_string()
+        } else {
+            continue;
+        };
+        return Err(Error::Unsupported {
+            plugin: format!("json ({language})"),
+            type_name: name.format(ToString::to_string, "."),
+            reason,
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,904 @@
+//! `EmitterPlugin<Python>` implementation for the JSON plugin.
+//!
+//! Writes a `to_json` method and a `from_json` class method into the body of
+//! every Python class. They convert to and from the values that the `json`
+//! module's `dumps` / `loads` work with (`dict`, `list`, `str`, `int`,
+//! `float`, `bool` and `None`), in the shapes that `serde_json` gives the Rust
+//! types.
+//!
+//! Generic types take a converter per type parameter (`to_json_t` /
+//! `from_json_t`), so generic enums are supported in every tagging. Like the
+//! bincode plugin, the plugin writes module functions to read enums whose
+//! variants carry data (`from_json_<enum>`) and to convert branded newtypes.
+
+use std::collections::BTreeMap;
+use std::io::{self, Result};
+
+use heck::ToSnakeCase;
+
+use super::JsonPlugin;
+use crate::Registry;
+use crate::generation::{
+    CodeGeneratorConfig, Error, SERDE_NAMESPACE,
+    indent::{IndentWrite, IndentedWriter},
+    plugin::{EmitContext, EmitterPlugin, RuntimeFile},
+    python::{
+        Python, enum_member, field_default, function_name, identifier, import_module,
+        string_literal, variant_class,
+    },
+};
+use crate::reflection::format::{
+    ContainerFormat, EnumTagging, Format, Named, Namespace, QualifiedTypeName, VariantFormat,
+};
+
+/// The helpers of the serde runtime that the generated code calls.
+const HELPERS: &[&str] = &[
+    "DeserializationError",
+    "bool_from_json",
+    "bytes_from_json",
+    "bytes_to_json",
+    "char_from_json",
+    "date_from_json",
+    "duration_from_json",
+    "duration_to_json",
+    "external_tag",
+    "float_from_json",
+    "int_from_json",
+    "list_from_json",
+    "object_from_json",
+    "option_from_json",
+    "option_to_json",
+    "str_from_json",
+    "time_from_json",
+    "timestamp_from_json",
+    "timestamp_to_json",
+    "tuple_from_json",
+    "unit_from_json",
+    "uuid_from_json",
+];
+
+/// The name of a type parameter's converter, e.g. `to_json_t`.
+fn converter_name(prefix: &str, param: &str) -> String {
+    format!("{prefix}_{}", param.to_snake_case())
+}
+
+/// The converter parameters of `to_json` (e.g. `, to_json_t: Callable[[T], Any]`).
+fn to_json_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| {
+            format!(
+                ", {}: Callable[[{param}], Any]",
+                converter_name("to_json", param)
+            )
+        })
+        .collect()
+}
+
+/// The converter parameters of `from_json` (e.g. `, from_json_t: Callable[[Any], T]`).
+fn from_json_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| {
+            format!(
+                ", {}: Callable[[Any], {param}]",
+                converter_name("from_json", param)
+            )
+        })
+        .collect()
+}
+
+/// A type with its type parameters, e.g. `Page[T]`.
+fn generic_type(name: &str, type_params: &[String]) -> String {
+    if type_params.is_empty() {
+        name.to_string()
+    } else {
+        format!("{name}[{}]", type_params.join(", "))
+    }
+}
+
+/// The name of a type as the generated code refers to it, e.g. `other.Child`.
+fn type_name(name: &QualifiedTypeName) -> String {
+    name.format(|namespace| namespace.to_snake_case(), ".")
+}
+
+/// The module function that does `action` for the type `name`, e.g.
+/// `other.from_json_shape`.
+fn function(action: &str, name: &QualifiedTypeName) -> String {
+    let function = function_name(action, &name.name);
+    match &name.namespace {
+        Namespace::Root => function,
+        Namespace::Named(namespace) => format!("{}.{function}", namespace.to_snake_case()),
+    }
+}
+
+/// Whether the type `name` is an enum whose variants carry data, which is read by a module
+/// function.
+fn is_union(config: &CodeGeneratorConfig, name: &QualifiedTypeName) -> bool {
+    config.enum_type_names.contains(&name.name) && !config.unit_variant_enums.contains(&name.name)
+}
+
+/// Whether values of `format` are already JSON values, so they convert to JSON as they are.
+fn is_json(format: &Format) -> bool {
+    match format {
+        Format::Bool
+        | Format::I8
+        | Format::I16
+        | Format::I32
+        | Format::I64
+        | Format::I128
+        | Format::U8
+        | Format::U16
+        | Format::U32
+        | Format::U64
+        | Format::U128
+        | Format::F32
+        | Format::F64
+        | Format::Char
+        | Format::Str => true,
+        Format::Option(format) => is_json(format),
+        Format::Tuple(formats) => matches!(formats.as_slice(), [format] if is_json(format)),
+        _ => false,
+    }
+}
+
+/// The expression that converts `value` of `format` to JSON.
+fn to_json(config: &CodeGeneratorConfig, value: &str, format: &Format, level: usize) -> String {
+    if is_json(format) {
+        return value.to_string();
+    }
+    let param = format!("level{}", level + 1);
+    match format {
+        Format::Unit => "None".to_string(),
+        Format::Bytes => format!("bytes_to_json({value})"),
+        Format::Uuid => format!("str({value})"),
+        Format::Date | Format::Time => format!("{value}.isoformat()"),
+        Format::Timestamp => format!("timestamp_to_json({value})"),
+        Format::Duration => format!("duration_to_json({value})"),
+        Format::Option(format) => format!(
+            "option_to_json({value}, lambda {param}: {})",
+            to_json(config, &param, format, level + 1)
+        ),
+        Format::Seq(format)
+        | Format::Set(format)
+        | Format::TupleArray {
+            content: format, ..
+        } => {
+            if is_json(format) {
+                format!("list({value})")
+            } else {
+                format!(
+                    "[{} for {param} in {value}]",
+                    to_json(config, &param, format, level + 1)
+                )
+            }
+        }
+        Format::Map {
+            key,
+            value: map_value,
+        } => {
+            let key_param = format!("key{}", level + 1);
+            let value_param = format!("value{}", level + 1);
+            format!(
+                "{{{}: {} for {key_param}, {value_param} in {value}.items()}}",
+                key_to_json(config, &key_param, key),
+                to_json(config, &value_param, map_value, level + 1)
+            )
+        }
+        Format::Tuple(formats) => match formats.as_slice() {
+            [] => "None".to_string(),
+            [format] => to_json(config, value, format, level),
+            formats => {
+                let elements = formats
+                    .iter()
+                    .enumerate()
+                    .map(|(i, format)| to_json(config, &format!("{value}[{i}]"), format, level))
+                    .collect::<Vec<_>>();
+                format!("[{}]", elements.join(", "))
+            }
+        },
+        Format::TypeName(name) if config.branded_type_names.contains(&name.name) => {
+            format!("{}({value})", function("to_json", name))
+        }
+        Format::TypeName(_) => format!("{value}.to_json()"),
+        Format::TypeParameter(name) => format!("{}({value})", converter_name("to_json", name)),
+        Format::Generic { args, .. } => {
+            let converters = args
+                .iter()
+                .map(|arg| {
+                    format!(
+                        "lambda {param}: {}",
+                        to_json(config, &param, arg, level + 1)
+                    )
+                })
+                .collect::<Vec<_>>();
+            format!("{value}.to_json({})", converters.join(", "))
+        }
+        Format::Variable(_variable) => unreachable!("placeholders should not get this far"),
+        _ => unreachable!("JSON values are handled above"),
+    }
+}
+
+/// The expression that converts the map key `key` of `format` to a JSON object key.
+fn key_to_json(config: &CodeGeneratorConfig, key: &str, format: &Format) -> String {
+    match format {
+        Format::Char | Format::Str => key.to_string(),
+        Format::Bool => format!("\"true\" if {key} else \"false\""),
+        // Unit variants of enums and branded strings convert to strings.
+        Format::TypeName(_) | Format::Generic { .. } => to_json(config, key, format, 0),
+        Format::Date | Format::Time => format!("{key}.isoformat()"),
+        _ => format!("str({key})"),
+    }
+}
+
+/// The expression that reads a value of `format` from the JSON value `json`.
+fn from_json(config: &CodeGeneratorConfig, json: &str, format: &Format, level: usize) -> String {
+    let call = |helper: &str| format!("{helper}({json})");
+    let param = format!("level{}", level + 1);
+    match format {
+        Format::Unit => call("unit_from_json"),
+        Format::Bool => call("bool_from_json"),
+        Format::I8
+        | Format::I16
+        | Format::I32
+        | Format::I64
+        | Format::I128
+        | Format::U8
+        | Format::U16
+        | Format::U32
+        | Format::U64
+        | Format::U128 => call("int_from_json"),
+        Format::F32 | Format::F64 => call("float_from_json"),
+        Format::Char => call("char_from_json"),
+        Format::Str => call("str_from_json"),
+        Format::Bytes => call("bytes_from_json"),
+        Format::Uuid => call("uuid_from_json"),
+        Format::Date => call("date_from_json"),
+        Format::Time => call("time_from_json"),
+        Format::Timestamp => call("timestamp_from_json"),
+        Format::Duration => call("duration_from_json"),
+        Format::Option(format) => format!(
+            "option_from_json({json}, lambda {param}: {})",
+            from_json(config, &param, format, level + 1)
+        ),
+        Format::Seq(format) => format!(
+            "[{} for {param} in list_from_json({json})]",
+            from_json(config, &param, format, level + 1)
+        ),
+        Format::TupleArray { content, .. } => format!(
+            "tuple({} for {param} in list_from_json({json}))",
+            from_json(config, &param, content, level + 1)
+        ),
+        Format::Set(format) => format!(
+            "{{{} for {param} in list_from_json({json})}}",
+            from_json(config, &param, format, level + 1)
+        ),
+        Format::Map { key, value } => {
+            let key_param = format!("key{}", level + 1);
+            let value_param = format!("value{}", level + 1);
+            format!(
+                "{{{}: {} for {key_param}, {value_param} in object_from_json({json}).items()}}",
+                key_from_json(config, &key_param, key),
+                from_json(config, &value_param, value, level + 1)
+            )
+        }
+        Format::Tuple(formats) => match formats.as_slice() {
+            [] => call("unit_from_json"),
+            [format] => from_json(config, json, format, level),
+            formats => {
+                let elements = formats
+                    .iter()
+                    .enumerate()
+                    .map(|(i, format)| {
+                        from_json(config, &format!("{param}[{i}]"), format, level + 1)
+                    })
+                    .collect::<Vec<_>>();
+                format!(
+                    "tuple_from_json({json}, {}, lambda {param}: ({}))",
+                    formats.len(),
+                    elements.join(", ")
+                )
+            }
+        },
+        Format::TypeName(name)
+            if is_union(config, name) || config.branded_type_names.contains(&name.name) =>
+        {
+            format!("{}({json})", function("from_json", name))
+        }
+        Format::TypeName(name) => format!("{}.from_json({json})", type_name(name)),
+        Format::TypeParameter(name) => format!("{}({json})", converter_name("from_json", name)),
+        Format::Generic { name, args } => {
+            let converters = args
+                .iter()
+                .map(|arg| {
+                    format!(
+                        ", lambda {param}: {}",
+                        from_json(config, &param, arg, level + 1)
+                    )
+                })
+                .collect::<String>();
+            if is_union(config, name) {
+                format!("{}({json}{converters})", function("from_json", name))
+            } else {
+                format!("{}.from_json({json}{converters})", type_name(name))
+            }
+        }
+        Format::Variable(_variable) => unreachable!("placeholders should not get this far"),
+    }
+}
+
+/// The expression that reads a map key of `format` from the JSON object key `key`.
+fn key_from_json(config: &CodeGeneratorConfig, key: &str, format: &Format) -> String {
+    match format {
+        Format::Bool => format!("{key} == \"true\""),
+        Format::I8
+        | Format::I16
+        | Format::I32
+        | Format::I64
+        | Format::I128
+        | Format::U8
+        | Format::U16
+        | Format::U32
+        | Format::U64
+        | Format::U128 => format!("int({key})"),
+        Format::F32 | Format::F64 => format!("float({key})"),
+        Format::Char | Format::Str => key.to_string(),
+        _ => from_json(config, key, format, 0),
+    }
+}
+
+/// The default value of a field, as an expression: `field(default_factory=list)` becomes
+/// `list()`.
+fn default_value(field: &Named<Format>) -> Option<String> {
+    let default = field_default(field)?;
+    Some(
+        match default
+            .strip_prefix("field(default_factory=")
+            .and_then(|factory| factory.strip_suffix(')'))
+        {
+            Some(factory) => format!("{factory}()"),
+            None => default,
+        },
+    )
+}
+
+/// The JSON object of `fields`, read from the attributes of `value`.
+fn fields_to_json(config: &CodeGeneratorConfig, value: &str, fields: &[Named<Format>]) -> String {
+    let entries = fields
+        .iter()
+        .map(|field| {
+            format!(
+                "{}: {}",
+                string_literal(&field.name),
+                to_json(
+                    config,
+                    &format!("{value}.{}", identifier(&field.name)),
+                    &field.value,
+                    0
+                )
+            )
+        })
+        .collect::<Vec<_>>();
+    format!("{{{}}}", entries.join(", "))
+}
+
+/// Writes the keyword arguments that create a class from the JSON object `obj` of its
+/// `fields`. A field with a default takes it when the object doesn't have the field.
+fn write_fields_from_json<W: IndentWrite>(
+    w: &mut W,
+    config: &CodeGeneratorConfig,
+    obj: &str,
+    fields: &[Named<Format>],
+) -> Result<()> {
+    for field in fields {
+        let key = string_literal(&field.name);
+        let value = from_json(config, &format!("{obj}.get({key})"), &field.value, 0);
+        let value = match default_value(field) {
+            Some(default) => format!("{value} if {key} in {obj} else {default}"),
+            None => value,
+        };
+        writeln!(w, "{}={value},", identifier(&field.name))?;
+    }
+    Ok(())
+}
+
+/// The fields of a variant, and whether they are positional.
+fn variant_fields(variant: &VariantFormat) -> (Vec<Named<Format>>, bool) {
+    match variant {
+        VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+        VariantFormat::Unit => (vec![], true),
+        VariantFormat::NewType(format) => {
+            (vec![Named::new(format.as_ref(), "value".to_string())], true)
+        }
+        VariantFormat::Tuple(formats) => (
+            formats
+                .iter()
+                .enumerate()
+                .map(|(i, format)| Named::new(format, format!("field{i}")))
+                .collect(),
+            true,
+        ),
+        VariantFormat::Struct(fields) => (fields.clone(), false),
+    }
+}
+
+/// The JSON value of a variant's content (`None` for unit variants), read from the attributes
+/// of `self`.
+fn variant_content(config: &CodeGeneratorConfig, variant: &VariantFormat) -> Option<String> {
+    match variant {
+        VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+        VariantFormat::Unit => None,
+        VariantFormat::NewType(format) => Some(to_json(config, "self.value", format, 0)),
+        VariantFormat::Tuple(formats) => {
+            let elements = formats
+                .iter()
+                .enumerate()
+                .map(|(i, format)| to_json(config, &format!("self.field{i}"), format, 0))
+                .collect::<Vec<_>>();
+            Some(format!("[{}]", elements.join(", ")))
+        }
+        VariantFormat::Struct(fields) => Some(fields_to_json(config, "self", fields)),
+    }
+}
+
+/// The JSON value of a variant named `name` with `content`, in `tagging`.
+fn tagged_to_json(tagging: &EnumTagging, name: &str, content: Option<String>) -> String {
+    let name = string_literal(name);
+    match (tagging, content) {
+        (EnumTagging::External, None) => name,
+        (EnumTagging::External, Some(content)) => format!("{{{name}: {content}}}"),
+        (EnumTagging::Internal { tag }, None) | (EnumTagging::Adjacent { tag, .. }, None) => {
+            format!("{{{}: {name}}}", string_literal(tag))
+        }
+        (EnumTagging::Internal { tag }, Some(content)) => {
+            // The content is an object (struct variant) or a newtype's value, which
+            // must be an object too: the tag is added to its fields.
+            let content = content
+                .strip_prefix('{')
+                .and_then(|content| content.strip_suffix('}'))
+                .map_or_else(|| format!("**{content}"), ToString::to_string);
+            if content.is_empty() {
+                format!("{{{}: {name}}}", string_literal(tag))
+            } else {
+                format!("{{{}: {name}, {content}}}", string_literal(tag))
+            }
+        }
+        (EnumTagging::Adjacent { tag, content: key }, Some(content)) => format!(
+            "{{{}: {name}, {}: {content}}}",
+            string_literal(tag),
+            string_literal(key)
+        ),
+        (EnumTagging::Untagged, content) => content.unwrap_or_else(|| "None".to_string()),
+    }
+}
+
+/// Writes `to_json` and `from_json` for a class: a struct, or a newtype or tuple struct (whose
+/// fields are `positional`).
+fn write_class_body<W: IndentWrite>(
+    w: &mut W,
+    ctx: &EmitContext,
+    fields: &[Named<Format>],
+    positional: bool,
+) -> Result<()> {
+    let config = ctx.config;
+    let type_params = ctx.type_params();
+    let unit = matches!(ctx.container.format, ContainerFormat::UnitStruct(..));
+
+    writeln!(
+        w,
+        "def to_json(self{}) -> Any:",
+        to_json_params(type_params)
+    )?;
+    w.indent();
+    match fields {
+        _ if unit => writeln!(w, "return None")?,
+        [] => writeln!(w, "return {{}}")?,
+        [field] if positional => {
+            let value = format!("self.{}", identifier(&field.name));
+            writeln!(w, "return {}", to_json(config, &value, &field.value, 0))?;
+        }
+        fields if positional => {
+            let elements = fields
+                .iter()
+                .map(|field| {
+                    let value = format!("self.{}", identifier(&field.name));
+                    to_json(config, &value, &field.value, 0)
+                })
+                .collect::<Vec<_>>();
+            writeln!(w, "return [{}]", elements.join(", "))?;
+        }
+        fields => {
+            writeln!(w, "return {{")?;
+            w.indent();
+            for field in fields {
+                let value = format!("self.{}", identifier(&field.name));
+                writeln!(
+                    w,
+                    "{}: {},",
+                    string_literal(&field.name),
+                    to_json(config, &value, &field.value, 0)
+                )?;
+            }
+            w.unindent();
+            writeln!(w, "}}")?;
+        }
+    }
+    w.unindent();
+    writeln!(w)?;
+
+    writeln!(w, "@classmethod")?;
+    writeln!(
+        w,
+        "def from_json(cls, json: Any{}) -> {}:",
+        from_json_params(type_params),
+        generic_type(ctx.name(), type_params)
+    )?;
+    w.indent();
+    write_from_json(w, config, "cls", fields, positional, "json")?;
+    w.unindent();
+    Ok(())
+}
+
+/// Writes the statements that return `class` from the JSON value `json` of its `fields`.
+fn write_from_json<W: IndentWrite>(
+    w: &mut W,
+    config: &CodeGeneratorConfig,
+    class: &str,
+    fields: &[Named<Format>],
+    positional: bool,
+    json: &str,
+) -> Result<()> {
+    match fields {
+        [] => writeln!(w, "return {class}()"),
+        [field] if positional => writeln!(
+            w,
+            "return {class}({})",
+            from_json(config, json, &field.value, 0)
+        ),
+        fields if positional => {
+            let elements = fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| from_json(config, &format!("elements[{i}]"), &field.value, 0))
+                .collect::<Vec<_>>();
+            writeln!(
+                w,
+                "return tuple_from_json({json}, {}, lambda elements: {class}({}))",
+                fields.len(),
+                elements.join(", ")
+            )
+        }
+        fields => {
+            // The fields of internally tagged struct variants are read from the enum's object.
+            if json != "obj" {
+                writeln!(w, "obj = object_from_json({json})")?;
+            }
+            writeln!(w, "return {class}(")?;
+            w.indent();
+            write_fields_from_json(w, config, "obj", fields)?;
+            w.unindent();
+            writeln!(w, ")")
+        }
+    }
+}
+
+/// Writes `to_json` and `from_json` for an `Enum`, whose members' values are the variants'
+/// names.
+fn write_enum_body<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+    tagging: &EnumTagging,
+) -> Result<()> {
+    writeln!(w, "def to_json(self) -> Any:")?;
+    w.indent();
+    match tagging {
+        EnumTagging::External => writeln!(w, "return self.value")?,
+        EnumTagging::Internal { tag } | EnumTagging::Adjacent { tag, .. } => {
+            writeln!(w, "return {{{}: self.value}}", string_literal(tag))?;
+        }
+        EnumTagging::Untagged => writeln!(w, "return None")?,
+    }
+    w.unindent();
+    writeln!(w)?;
+
+    writeln!(w, "@classmethod")?;
+    writeln!(w, "def from_json(cls, json: Any) -> {name}:")?;
+    w.indent();
+    match tagging {
+        EnumTagging::External | EnumTagging::Untagged => writeln!(w, "match json:")?,
+        EnumTagging::Internal { tag } | EnumTagging::Adjacent { tag, .. } => {
+            writeln!(
+                w,
+                "tag = object_from_json(json).get({})",
+                string_literal(tag)
+            )?;
+            writeln!(w, "match tag:")?;
+        }
+    }
+    w.indent();
+    match tagging {
+        // Only the first variant can be read: they are all `null`.
+        EnumTagging::Untagged => {
+            if let Some(variant) = variants.values().next() {
+                writeln!(w, "case None:")?;
+                w.indent();
+                writeln!(w, "return cls.{}", enum_member(&variant.name))?;
+                w.unindent();
+            }
+        }
+        _ => {
+            for variant in variants.values() {
+                writeln!(w, "case {}:", string_literal(&variant.name))?;
+                w.indent();
+                writeln!(w, "return cls.{}", enum_member(&variant.name))?;
+                w.unindent();
+            }
+        }
+    }
+    writeln!(w, "case _:")?;
+    w.indent();
+    match variants.values().find(|variant| variant.metadata.other) {
+        Some(other) => writeln!(w, "return cls.{}", enum_member(&other.name))?,
+        None => {
+            let value = match tagging {
+                EnumTagging::External | EnumTagging::Untagged => "json",
+                _ => "tag",
+            };
+            writeln!(
+                w,
+                "raise DeserializationError(f\"Unknown variant for {name}: {{{value}!r}}\")"
+            )?;
+        }
+    }
+    w.unindent();
+    w.unindent();
+    w.unindent();
+    Ok(())
+}
+
+/// Writes `to_json` for a variant class. It takes the converters of all the enum's type
+/// parameters, so that any variant can be converted as the enum.
+fn write_variant_body<W: IndentWrite>(
+    w: &mut W,
+    config: &CodeGeneratorConfig,
+    name: &str,
+    variant: &VariantFormat,
+    tagging: &EnumTagging,
+    type_params: &[String],
+) -> Result<()> {
+    writeln!(
+        w,
+        "def to_json(self{}) -> Any:",
+        to_json_params(type_params)
+    )?;
+    w.indent();
+    writeln!(
+        w,
+        "return {}",
+        tagged_to_json(tagging, name, variant_content(config, variant))
+    )?;
+    w.unindent();
+    Ok(())
+}
+
+/// Writes the module function that reads an enum whose variants carry data, which checks its
+/// tag, or tries each variant in order if it is untagged, like serde does.
+fn write_union_function<W: IndentWrite>(
+    w: &mut W,
+    config: &CodeGeneratorConfig,
+    name: &str,
+    type_params: &[String],
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+    tagging: &EnumTagging,
+) -> Result<()> {
+    writeln!(
+        w,
+        "def {}(json: Any{}) -> {}:",
+        function_name("from_json", name),
+        from_json_params(type_params),
+        generic_type(name, type_params)
+    )?;
+    w.indent();
+    let content = match tagging {
+        EnumTagging::External => {
+            writeln!(w, "tag, content = external_tag(json)")?;
+            "content"
+        }
+        EnumTagging::Internal { tag } => {
+            writeln!(w, "obj = object_from_json(json)")?;
+            writeln!(w, "tag = obj.get({})", string_literal(tag))?;
+            "obj"
+        }
+        EnumTagging::Adjacent { tag, content } => {
+            writeln!(w, "obj = object_from_json(json)")?;
+            writeln!(w, "tag = obj.get({})", string_literal(tag))?;
+            writeln!(w, "content = obj.get({})", string_literal(content))?;
+            "content"
+        }
+        EnumTagging::Untagged => "json",
+    };
+    for variant in variants.values() {
+        let class = variant_class(name, &variant.name);
+        let (fields, positional) = variant_fields(&variant.value);
+        if matches!(tagging, EnumTagging::Untagged) {
+            if fields.is_empty() {
+                writeln!(w, "if json is None:")?;
+                w.indent();
+                writeln!(w, "return {class}()")?;
+                w.unindent();
+            } else {
+                writeln!(w, "try:")?;
+                w.indent();
+                write_from_json(w, config, &class, &fields, positional, content)?;
+                w.unindent();
+                writeln!(w, "except ValueError:")?;
+                w.indent();
+                writeln!(w, "pass")?;
+                w.unindent();
+            }
+            continue;
+        }
+        writeln!(w, "if tag == {}:", string_literal(&variant.name))?;
+        w.indent();
+        write_from_json(w, config, &class, &fields, positional, content)?;
+        w.unindent();
+    }
+    // Unknown variants, e.g. added by a later version, are read as the catch-all.
+    match variants.values().find(|variant| variant.metadata.other) {
+        Some(other) => writeln!(w, "return {}()", variant_class(name, &other.name))?,
+        None if matches!(tagging, EnumTagging::Untagged) => writeln!(
+            w,
+            "raise DeserializationError(f\"No variant of {name} matches {{json!r}}\")"
+        )?,
+        None => writeln!(
+            w,
+            "raise DeserializationError(f\"Unknown variant for {name}: {{tag!r}}\")"
+        )?,
+    }
+    w.unindent();
+    Ok(())
+}
+
+/// Writes the module functions that convert a branded newtype, whose `NewType` can't have
+/// methods.
+fn write_branded_functions<W: IndentWrite>(
+    w: &mut W,
+    config: &CodeGeneratorConfig,
+    name: &str,
+    format: &Format,
+) -> Result<()> {
+    writeln!(
+        w,
+        "def {}(value: {name}) -> Any:",
+        function_name("to_json", name)
+    )?;
+    w.indent();
+    writeln!(w, "return {}", to_json(config, "value", format, 0))?;
+    w.unindent();
+    writeln!(w)?;
+    writeln!(w)?;
+
+    writeln!(
+        w,
+        "def {}(json: Any) -> {name}:",
+        function_name("from_json", name)
+    )?;
+    w.indent();
+    writeln!(w, "return {name}({})", from_json(config, "json", format, 0))?;
+    w.unindent();
+    Ok(())
+}
+
+/// Whether a container is an enum whose variants carry data.
+fn is_union_format(format: &ContainerFormat) -> bool {
+    matches!(
+        format,
+        ContainerFormat::Enum(variants, ..)
+            if variants
+                .values()
+                .any(|variant| !matches!(variant.value, VariantFormat::Unit))
+    )
+}
+
+// ---------------------------------------------------------------------------
+// EmitterPlugin implementation
+// ---------------------------------------------------------------------------
+
+impl EmitterPlugin<Python> for JsonPlugin {
+    /// Returns the serde Python runtime package, whose JSON helpers the
+    /// generated code calls, to be written into the generated package next to
+    /// its modules.
+    fn runtime_files(&self) -> Vec<RuntimeFile> {
+        static SERDE: include_dir::Dir<'static> =
+            include_dir::include_dir!("$CARGO_MANIFEST_DIR/runtime/python/serde");
+        SERDE
+            .files()
+            .map(|f| RuntimeFile {
+                relative_path: format!("serde/{}", f.path().display()),
+                contents: f.contents().to_vec(),
+            })
+            .collect()
+    }
+
+    fn imports(&self, config: &CodeGeneratorConfig) -> Vec<String> {
+        vec![
+            "from typing import Any".to_string(),
+            format!(
+                "from {} import {}",
+                import_module(config, SERDE_NAMESPACE, ".serde"),
+                HELPERS.join(", ")
+            ),
+        ]
+    }
+
+    /// Every class gets JSON methods: structs, `Enum`s and variant classes.
+    fn has_type_body(&self, ctx: &EmitContext) -> bool {
+        let metadata = ctx.container.format.metadata();
+        ctx.is_variant()
+            || !(is_union_format(ctx.container.format) || metadata.branded || metadata.proxy)
+    }
+
+    fn type_body(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
+        let config = w.config();
+        let mut w = IndentedWriter::new(&mut *w, config);
+        if let Some(variant) = &ctx.variant {
+            let ContainerFormat::Enum(_, tagging, _, _) = ctx.container.format else {
+                unreachable!("variants belong to enums")
+            };
+            return write_variant_body(
+                &mut w,
+                ctx.config,
+                variant.name,
+                variant.format,
+                tagging,
+                variant.parent_type_params,
+            );
+        }
+        match ctx.container.format {
+            ContainerFormat::Enum(variants, tagging, _, _) => {
+                write_enum_body(&mut w, ctx.name(), variants, tagging)
+            }
+            ContainerFormat::NewTypeStruct(..) | ContainerFormat::TupleStruct(..) => {
+                write_class_body(&mut w, ctx, &ctx.fields(), true)
+            }
+            ContainerFormat::UnitStruct(..) | ContainerFormat::Struct(..) => {
+                write_class_body(&mut w, ctx, &ctx.fields(), false)
+            }
+        }
+    }
+
+    /// The module functions of enums whose variants carry data and of branded newtypes.
+    fn after_type(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
+        if ctx.is_variant() {
+            return Ok(());
+        }
+        let config = w.config();
+        let mut w = IndentedWriter::new(&mut *w, config);
+        let name = ctx.name();
+        match ctx.container.format {
+            ContainerFormat::Enum(variants, tagging, ..)
+                if is_union_format(ctx.container.format) =>
+            {
+                write_union_function(
+                    &mut w,
+                    ctx.config,
+                    name,
+                    ctx.type_params(),
+                    variants,
+                    tagging,
+                )
+            }
+            ContainerFormat::NewTypeStruct(format, _, metadata) if metadata.branded => {
+                write_branded_functions(&mut w, ctx.config, name, format)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Generic enums are supported in every tagging, so only internally
+    /// tagged enums with tuple variants are rejected.
+    fn check_registry(&self, registry: &Registry) -> std::result::Result<(), Error> {
+        super::check_enums(registry, "Python", |_| false)
+    }
+}
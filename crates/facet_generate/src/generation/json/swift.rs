@@ -11,7 +11,9 @@
 //! | `imports` | `import Serde` |
 //! | `module_helpers` | Feature helper snippets (`ListOfT`, `SetOfT`, …) |
 //! | `has_type_body` | Always `true` |
-//! | `type_body` | `serialize` / `deserialize` methods + `jsonSerialize` / `jsonDeserialize` wrappers |
+//! | `type_conformances` | `Codable` (internally and adjacently tagged enums) |
+//! | `type_body` | `serialize` / `deserialize` methods + `jsonSerialize` / `jsonDeserialize` wrappers, which go through `Codable` for types that conform to it; `init(from:)` / `encode(to:)` for tagged enums |
+//! | `check_registry` | Rejects generic tagged enums and internally tagged tuple variants |
 //!
 //! # JSON struct-field tuple serialisation
 //!
@@ -32,13 +34,16 @@ use std::io;
 use heck::ToLowerCamelCase as _;
 use indoc::writedoc;
 
+use crate::Registry;
 use crate::generation::{
-    CodeGeneratorConfig, Feature,
+    CodeGeneratorConfig, Error, Feature,
     indent::{IndentWrite, Newlines, with_block},
     plugin::{EmitContext, EmitterPlugin, RuntimeFile},
-    swift::Swift,
+    swift::{Swift, emitter::default_value},
+};
+use crate::reflection::format::{
+    ContainerFormat, EnumTagging, Format, Language, Named, VariantFormat,
 };
-use crate::reflection::format::{ContainerFormat, Format, Named, VariantFormat};
 
 use super::JsonPlugin;
 
@@ -186,11 +191,137 @@ func deserializeUuid<D: Deserializer>(
 }
 "#;
 
+const FEATURE_TIMESTAMP: &str = r#"func serializeTimestamp<S: Serializer>(
+    value: Date,
+    serializer: S
+) throws {
+    let formatter = ISO8601DateFormatter()
+    formatter.formatOptions = [.withInternetDateTime, .withFractionalSeconds]
+    try serializer.serialize_str(value: formatter.string(from: value))
+}
+
+func deserializeTimestamp<D: Deserializer>(
+    deserializer: D
+) throws -> Date {
+    let s = try deserializer.deserialize_str()
+    // ISO8601DateFormatter accepts at most millisecond precision, so the
+    // fractional seconds are parsed separately.
+    var text = s
+    var fraction = 0.0
+    if let dot = text.firstIndex(of: "."),
+       let end = text[dot...].firstIndex(where: { !$0.isNumber && $0 != "." }) {
+        fraction = Double("0" + text[dot..<end]) ?? 0
+        text.removeSubrange(dot..<end)
+    }
+    guard let date = ISO8601DateFormatter().date(from: text) else {
+        throw DeserializationError.invalidInput(issue: "Invalid RFC 3339 timestamp: \(s)")
+    }
+    return date.addingTimeInterval(fraction)
+}
+"#;
+
+const FEATURE_DATE: &str = r#"func serializeDate<S: Serializer>(
+    value: DateComponents,
+    serializer: S
+) throws {
+    guard let year = value.year, let month = value.month, let day = value.day else {
+        throw SerializationError.invalidValue(issue: "Date requires a year, month and day")
+    }
+    try serializer.serialize_str(value: String(format: "%04d-%02d-%02d", year, month, day))
+}
+
+func deserializeDate<D: Deserializer>(
+    deserializer: D
+) throws -> DateComponents {
+    let s = try deserializer.deserialize_str()
+    let parts = s.split(separator: "-").compactMap { Int($0) }
+    guard parts.count == 3 else {
+        throw DeserializationError.invalidInput(issue: "Invalid date: \(s)")
+    }
+    return DateComponents(year: parts[0], month: parts[1], day: parts[2])
+}
+"#;
+
+const FEATURE_TIME: &str = r#"func serializeTime<S: Serializer>(
+    value: DateComponents,
+    serializer: S
+) throws {
+    guard let hour = value.hour, let minute = value.minute else {
+        throw SerializationError.invalidValue(issue: "Time requires an hour and minute")
+    }
+    var text = String(format: "%02d:%02d:%02d", hour, minute, value.second ?? 0)
+    if let nanosecond = value.nanosecond, nanosecond > 0 {
+        text += String(format: ".%09d", nanosecond)
+    }
+    try serializer.serialize_str(value: text)
+}
+
+func deserializeTime<D: Deserializer>(
+    deserializer: D
+) throws -> DateComponents {
+    let s = try deserializer.deserialize_str()
+    let parts = s.split(separator: ":")
+    let seconds = parts.count == 3 ? parts[2].split(separator: ".", maxSplits: 1) : []
+    guard parts.count == 3,
+          let hour = Int(parts[0]),
+          let minute = Int(parts[1]),
+          let second = seconds.first.flatMap({ Int($0) }) else {
+        throw DeserializationError.invalidInput(issue: "Invalid time: \(s)")
+    }
+    var nanosecond = 0
+    if seconds.count == 2 {
+        let digits = String(seconds[1].prefix(9))
+        guard let fraction = Int(digits) else {
+            throw DeserializationError.invalidInput(issue: "Invalid time: \(s)")
+        }
+        nanosecond = fraction * Int(pow(10.0, Double(9 - digits.count)))
+    }
+    return DateComponents(hour: hour, minute: minute, second: second, nanosecond: nanosecond)
+}
+"#;
+
+const FEATURE_DURATION: &str = r#"func serializeDuration<S: Serializer>(
+    value: TimeInterval,
+    serializer: S
+) throws {
+    guard value >= 0 else {
+        throw SerializationError.invalidValue(issue: "Duration must not be negative: \(value)")
+    }
+    let secs = value.rounded(.down)
+    try serializer.increase_container_depth()
+    try serializer.serialize_u64(value: UInt64(secs))
+    try serializer.serialize_u32(value: min(UInt32(((value - secs) * 1_000_000_000).rounded()), 999_999_999))
+    try serializer.decrease_container_depth()
+}
+
+func deserializeDuration<D: Deserializer>(
+    deserializer: D
+) throws -> TimeInterval {
+    try deserializer.increase_container_depth()
+    let secs = try deserializer.deserialize_u64()
+    let nanos = try deserializer.deserialize_u32()
+    try deserializer.decrease_container_depth()
+    return TimeInterval(secs) + TimeInterval(nanos) / 1_000_000_000
+}
+"#;
+
 // ---------------------------------------------------------------------------
 // EmitterPlugin implementation
 // ---------------------------------------------------------------------------
 
 impl EmitterPlugin<Swift> for JsonPlugin {
+    /// Rejects generic internally and adjacently tagged enums, whose `Codable`
+    /// conformance would need constraints on their type parameters, and
+    /// internally tagged tuple variants.
+    fn check_registry(&self, registry: &Registry) -> Result<(), Error> {
+        super::check_enums(registry, "Swift", |tagging| {
+            matches!(
+                tagging,
+                EnumTagging::Internal { .. } | EnumTagging::Adjacent { .. }
+            )
+        })
+    }
+
     fn runtime_files(&self) -> Vec<RuntimeFile> {
         static SERDE: include_dir::Dir<'static> =
             include_dir::include_dir!("$CARGO_MANIFEST_DIR/runtime/swift/Sources/Serde");
@@ -242,6 +373,22 @@ impl EmitterPlugin<Swift> for JsonPlugin {
                     writeln!(w)?;
                     write!(w, "{FEATURE_UUID}")?;
                 }
+                Feature::Timestamp => {
+                    writeln!(w)?;
+                    write!(w, "{FEATURE_TIMESTAMP}")?;
+                }
+                Feature::Date => {
+                    writeln!(w)?;
+                    write!(w, "{FEATURE_DATE}")?;
+                }
+                Feature::Time => {
+                    writeln!(w)?;
+                    write!(w, "{FEATURE_TIME}")?;
+                }
+                Feature::Duration => {
+                    writeln!(w)?;
+                    write!(w, "{FEATURE_DURATION}")?;
+                }
                 _ => {}
             }
         }
@@ -252,16 +399,66 @@ impl EmitterPlugin<Swift> for JsonPlugin {
         true
     }
 
+    /// Internally and adjacently tagged enums conform to `Codable`, which
+    /// encodes their tags as serde does (see [`type_body`](Self::type_body)).
+    fn type_conformances(&self, ctx: &EmitContext) -> Vec<String> {
+        match ctx.container.format {
+            ContainerFormat::Enum(
+                _,
+                EnumTagging::Internal { .. } | EnumTagging::Adjacent { .. },
+                _,
+                _,
+            ) if !ctx.is_variant() => vec!["Codable".to_string()],
+            _ => vec![],
+        }
+    }
+
     fn type_body(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
         let name = ctx.name();
-        if let ContainerFormat::Enum(variants, _, _) = ctx.container.format {
-            write_enum_type_body(w, name, variants)
+        let type_params = ctx.type_params();
+        let codable = is_codable(ctx);
+        if let ContainerFormat::Enum(variants, tagging, _, _) = ctx.container.format {
+            match tagging {
+                EnumTagging::Untagged => {
+                    write_untagged_enum_type_body(w, name, type_params, variants, codable)
+                }
+                EnumTagging::Internal { .. } | EnumTagging::Adjacent { .. } => {
+                    write_enum_type_body(w, name, type_params, variants, codable)?;
+                    write_tagged_enum_coding(w, name, variants, tagging)
+                }
+                EnumTagging::External => {
+                    write_enum_type_body(w, name, type_params, variants, codable)
+                }
+            }
         } else {
-            write_struct_type_body(w, name, &ctx.fields())
+            write_struct_type_body(w, name, type_params, &ctx.fields(), codable)
         }
     }
 }
 
+/// Whether a non-generic type conforms to `Codable`, because it is a tagged enum or
+/// is declared to with `fg::conforms`. Its `jsonSerialize` and `jsonDeserialize`
+/// then go through `Codable`, which, unlike `Serializer`, knows the field names.
+fn is_codable(ctx: &EmitContext) -> bool {
+    let tagged = matches!(
+        ctx.container.format,
+        ContainerFormat::Enum(
+            _,
+            EnumTagging::Internal { .. } | EnumTagging::Adjacent { .. },
+            _,
+            _
+        )
+    );
+    let declared = ctx
+        .container
+        .format
+        .metadata()
+        .conformances(Language::Swift)
+        .iter()
+        .any(|conformance| conformance == "Codable");
+    ctx.type_params().is_empty() && (tagged || declared)
+}
+
 // ---------------------------------------------------------------------------
 // Struct type body
 // ---------------------------------------------------------------------------
@@ -269,33 +466,37 @@ impl EmitterPlugin<Swift> for JsonPlugin {
 fn write_struct_type_body(
     w: &mut dyn IndentWrite,
     name: &str,
+    type_params: &[String],
     fields: &[Named<Format>],
+    codable: bool,
 ) -> io::Result<()> {
     writeln!(w)?;
     write!(
         w,
-        "public func serialize<S: Serializer>(serializer: S) throws "
+        "public func serialize<S: Serializer>(serializer: S{}) throws ",
+        serializer_params(type_params, "S")
     )?;
     with_block(w, Newlines::BOTH, |w| {
         push_serializer(w)?;
         for field in fields {
             let fname = field.name.to_lower_camel_case();
-            write_field_serialize(w, &fname, &field.value)?;
+            write_field_serialize(w, &fname, field)?;
         }
         pop_serializer(w)
     })?;
-    write_json_serialize(w)?;
+    write_json_serialize(w, type_params, codable)?;
 
     writeln!(w)?;
     write!(
         w,
-        "public static func deserialize<D: Deserializer>(deserializer: D) throws -> {name} "
+        "public static func deserialize<D: Deserializer>(deserializer: D{}) throws -> {name} ",
+        deserializer_params(type_params, "D")
     )?;
     with_block(w, Newlines::BOTH, |w| {
         push_deserializer(w)?;
         for field in fields {
             let fname = field.name.to_lower_camel_case();
-            write_field_deserialize(w, &fname, &field.value)?;
+            write_field_deserialize(w, &fname, field)?;
         }
         pop_deserializer(w)?;
         write!(w, "return {name}(")?;
@@ -308,15 +509,52 @@ fn write_struct_type_body(
         }
         writeln!(w, ")")
     })?;
-    write_json_deserialize(w, name)?;
+    write_json_deserialize(w, name, type_params, codable)?;
 
     Ok(())
 }
 
+/// The `fg::override` serialization code for Swift of the field held by `var`, if it has any.
+fn override_serialize(field: &Named<Format>, var: &str) -> Option<String> {
+    field
+        .metadata
+        .type_override(Language::Swift)
+        .and_then(|type_override| type_override.serialize(var))
+}
+
+/// The `fg::override` deserialization expression for Swift of a field, if it has any.
+fn override_deserialize(field: &Named<Format>) -> Option<&str> {
+    field
+        .metadata
+        .type_override(Language::Swift)
+        .and_then(|type_override| type_override.deserialize.as_deref())
+}
+
+/// The Swift expression of a field's default value, which fills it in if the
+/// input leaves it out. Like the initializer's default, it's only used without
+/// a type override.
+fn field_default(field: &Named<Format>) -> Option<String> {
+    if field.metadata.type_override(Language::Swift).is_some() {
+        return None;
+    }
+    field
+        .metadata
+        .default
+        .as_ref()
+        .and_then(|default| default_value(default, &field.value))
+}
+
 /// Serialise one struct field, applying JSON tuple wrapping when the field
 /// type is a native tuple.
-fn write_field_serialize(w: &mut dyn IndentWrite, fname: &str, format: &Format) -> io::Result<()> {
-    match format {
+fn write_field_serialize(
+    w: &mut dyn IndentWrite,
+    fname: &str,
+    field: &Named<Format>,
+) -> io::Result<()> {
+    if let Some(serialize) = override_serialize(field, &format!("self.{fname}")) {
+        return writeln!(w, "{serialize}");
+    }
+    match &field.value {
         // JSON wraps native tuple fields in an extra container-depth level.
         // The receiver is the field name (no `self.` prefix) so elements are
         // addressed as `fname.0`, `fname.1`, …
@@ -327,7 +565,7 @@ fn write_field_serialize(w: &mut dyn IndentWrite, fname: &str, format: &Format)
             }
             pop_serializer(w)
         }
-        _ => write_format_serialize(w, format, &format!("self.{fname}")),
+        format => write_format_serialize(w, format, &format!("self.{fname}")),
     }
 }
 
@@ -336,9 +574,15 @@ fn write_field_serialize(w: &mut dyn IndentWrite, fname: &str, format: &Format)
 fn write_field_deserialize(
     w: &mut dyn IndentWrite,
     fname: &str,
-    format: &Format,
+    field: &Named<Format>,
 ) -> io::Result<()> {
-    match format {
+    if let Some(deserialize) = override_deserialize(field) {
+        return writeln!(w, "let {fname} = {deserialize}");
+    }
+    if let Some(default) = field_default(field) {
+        return write_default_deserialize(w, fname, field, &default);
+    }
+    match &field.value {
         // JSON wraps native tuple fields in an extra container-depth level.
         // Intermediate variables are named `fname0`, `fname1`, … (no `Field`
         // infix, matching the original emitter behaviour).
@@ -358,7 +602,42 @@ fn write_field_deserialize(
             writeln!(w, ")")?;
             pop_deserializer(w)
         }
-        _ => write_format_deserialize(w, format, fname),
+        format => write_format_deserialize(w, format, fname),
+    }
+}
+
+/// Deserialise a field with a default value, which is used if the input
+/// doesn't have the field (see the runtime's `Deserializer.has_field`).
+fn write_default_deserialize(
+    w: &mut dyn IndentWrite,
+    var: &str,
+    field: &Named<Format>,
+    default: &str,
+) -> io::Result<()> {
+    writeln!(
+        w,
+        r#"let {var} = try deserializer.deserialize_field(name: "{}", default: {default}) {{"#,
+        field.name
+    )?;
+    w.indent();
+    write_deserialize_expr(w, &field.value)?;
+    writeln!(w)?;
+    w.unindent();
+    writeln!(w, "}}")
+}
+
+/// Deserialise one field of a struct variant.
+fn write_variant_field_deserialize(
+    w: &mut dyn IndentWrite,
+    var: &str,
+    field: &Named<Format>,
+) -> io::Result<()> {
+    if let Some(deserialize) = override_deserialize(field) {
+        return writeln!(w, "let {var} = {deserialize}");
+    }
+    match field_default(field) {
+        Some(default) => write_default_deserialize(w, var, field, &default),
+        None => write_format_deserialize(w, &field.value, var),
     }
 }
 
@@ -369,12 +648,15 @@ fn write_field_deserialize(
 fn write_enum_type_body(
     w: &mut dyn IndentWrite,
     name: &str,
+    type_params: &[String],
     variants: &BTreeMap<u32, Named<VariantFormat>>,
+    codable: bool,
 ) -> io::Result<()> {
     writeln!(w)?;
     write!(
         w,
-        "public func serialize<S: Serializer>(serializer: S) throws "
+        "public func serialize<S: Serializer>(serializer: S{}) throws ",
+        serializer_params(type_params, "S")
     )?;
     with_block(w, Newlines::BOTH, |w| {
         push_serializer(w)?;
@@ -389,12 +671,13 @@ fn write_enum_type_body(
         })?;
         pop_serializer(w)
     })?;
-    write_json_serialize(w)?;
+    write_json_serialize(w, type_params, codable)?;
 
     writeln!(w)?;
     write!(
         w,
-        "public static func deserialize<D: Deserializer>(deserializer: D) throws -> {name} "
+        "public static func deserialize<D: Deserializer>(deserializer: D{}) throws -> {name} ",
+        deserializer_params(type_params, "D")
     )?;
     with_block(w, Newlines::BOTH, |w| {
         writeln!(
@@ -408,16 +691,380 @@ fn write_enum_type_body(
             for (i, variant) in variants.values().enumerate() {
                 write_variant_deserialize_case(w, variant, i)?;
             }
-            writeln!(
-                w,
-                r#"default: throw DeserializationError.invalidInput(issue: "Unknown variant index for {name}: \(index)")"#
-            )?;
+            write_unknown_variant_case(w, name, variants)?;
+            w.indent();
+            Ok(())
+        })
+    })?;
+    write_json_deserialize(w, name, type_params, codable)?;
+
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Untagged enum type body
+// ---------------------------------------------------------------------------
+
+/// Like [`write_enum_type_body`], but without variant indices: only the
+/// payload is serialized, and the deserializer tries the variants in order,
+/// rewinding the input after each one that fails.
+fn write_untagged_enum_type_body(
+    w: &mut dyn IndentWrite,
+    name: &str,
+    type_params: &[String],
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+    codable: bool,
+) -> io::Result<()> {
+    writeln!(w)?;
+    write!(
+        w,
+        "public func serialize<S: Serializer>(serializer: S{}) throws ",
+        serializer_params(type_params, "S")
+    )?;
+    with_block(w, Newlines::BOTH, |w| {
+        write!(w, "switch self ")?;
+        with_block(w, Newlines::BOTH, |w| {
+            w.unindent();
+            for variant in variants.values() {
+                write_untagged_variant_serialize_case(w, variant)?;
+            }
+            w.indent();
+            Ok(())
+        })
+    })?;
+    write_json_serialize(w, type_params, codable)?;
+
+    writeln!(w)?;
+    write!(
+        w,
+        "public static func deserialize<D: Deserializer>(deserializer: D{}) throws -> {name} ",
+        deserializer_params(type_params, "D")
+    )?;
+    with_block(w, Newlines::BOTH, |w| {
+        writeln!(w, "let offset = deserializer.get_buffer_offset()")?;
+        for variant in variants.values() {
+            write!(w, "do ")?;
+            with_block(w, Newlines::OPEN, |w| {
+                write_untagged_variant_deserialize(w, variant)
+            })?;
+            write!(w, " catch ")?;
+            with_block(w, Newlines::BOTH, |w| {
+                writeln!(w, "deserializer.set_buffer_offset(value: offset)")
+            })?;
+        }
+        writeln!(
+            w,
+            r#"throw DeserializationError.invalidInput(issue: "No variant of {name} matches the input")"#
+        )
+    })?;
+    write_json_deserialize(w, name, type_params, codable)?;
+
+    Ok(())
+}
+
+/// Writes the `case` serializing an untagged variant's payload: nothing but
+/// a unit for unit variants, the value itself for newtype variants, and a
+/// container for tuple and struct variants.
+fn write_untagged_variant_serialize_case(
+    w: &mut dyn IndentWrite,
+    variant: &Named<VariantFormat>,
+) -> io::Result<()> {
+    let name = variant.name.to_lower_camel_case();
+    match &variant.value {
+        VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+        VariantFormat::Unit => {
+            writeln!(w, "case .{name}:")?;
+            w.indent();
+            writeln!(w, "try serializer.serialize_unit()")?;
+        }
+        VariantFormat::NewType(fmt) => {
+            writeln!(w, "case .{name}(let x):")?;
+            w.indent();
+            write_format_serialize(w, fmt, "x")?;
+        }
+        VariantFormat::Tuple(formats) => {
+            let vars = (0..formats.len())
+                .map(|i| format!("let x{i}"))
+                .collect::<Vec<_>>();
+            writeln!(w, "case .{name}({}):", vars.join(", "))?;
+            w.indent();
+            push_serializer(w)?;
+            for (i, fmt) in formats.iter().enumerate() {
+                write_format_serialize(w, fmt, &format!("x{i}"))?;
+            }
+            pop_serializer(w)?;
+        }
+        VariantFormat::Struct(nameds) => {
+            let vars = nameds
+                .iter()
+                .map(|named| format!("let {}", named.name.to_lower_camel_case()))
+                .collect::<Vec<_>>();
+            writeln!(w, "case .{name}({}):", vars.join(", "))?;
+            w.indent();
+            push_serializer(w)?;
+            for named in nameds {
+                let field_name = named.name.to_lower_camel_case();
+                match override_serialize(named, &field_name) {
+                    Some(serialize) => writeln!(w, "{serialize}")?,
+                    None => write_format_serialize(w, &named.value, &field_name)?,
+                }
+            }
+            pop_serializer(w)?;
+        }
+    }
+    w.unindent();
+    Ok(())
+}
+
+/// Writes the statements reading an untagged variant's payload and returning
+/// the variant, the counterpart of [`write_untagged_variant_serialize_case`].
+fn write_untagged_variant_deserialize(
+    w: &mut dyn IndentWrite,
+    variant: &Named<VariantFormat>,
+) -> io::Result<()> {
+    let name = variant.name.to_lower_camel_case();
+    match &variant.value {
+        VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+        VariantFormat::Unit => {
+            writeln!(w, "try deserializer.deserialize_unit()")?;
+            writeln!(w, "return .{name}")
+        }
+        VariantFormat::NewType(fmt) => {
+            write_format_deserialize(w, fmt, "x")?;
+            writeln!(w, "return .{name}(x)")
+        }
+        VariantFormat::Tuple(formats) => {
+            push_deserializer(w)?;
+            for (i, fmt) in formats.iter().enumerate() {
+                write_format_deserialize(w, fmt, &format!("x{i}"))?;
+            }
+            pop_deserializer(w)?;
+            let vars = (0..formats.len())
+                .map(|i| format!("x{i}"))
+                .collect::<Vec<_>>();
+            writeln!(w, "return .{name}({})", vars.join(", "))
+        }
+        VariantFormat::Struct(nameds) => {
+            push_deserializer(w)?;
+            for named in nameds {
+                let field_name = named.name.to_lower_camel_case();
+                write_variant_field_deserialize(w, &field_name, named)?;
+            }
+            pop_deserializer(w)?;
+            let args = nameds
+                .iter()
+                .map(|named| {
+                    let field_name = named.name.to_lower_camel_case();
+                    format!("{field_name}: {field_name}")
+                })
+                .collect::<Vec<_>>();
+            writeln!(w, "return .{name}({})", args.join(", "))
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tagged enum coding
+// ---------------------------------------------------------------------------
+
+/// Writes the `Codable` implementation of an internally or adjacently tagged
+/// enum, which reads and writes its tag as serde does.
+///
+/// An internally tagged enum adds the tag to its payload's object; an
+/// adjacently tagged one writes the tag and the payload under their own keys,
+/// a newtype payload as its value, a tuple as an array and a struct as an
+/// object. Payload types must be `Codable` themselves, and struct variant
+/// fields are keyed by their Rust names.
+fn write_tagged_enum_coding(
+    w: &mut dyn IndentWrite,
+    name: &str,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+    tagging: &EnumTagging,
+) -> io::Result<()> {
+    let (tag, content) = match tagging {
+        EnumTagging::Internal { tag } => (tag, None),
+        EnumTagging::Adjacent { tag, content } => (tag, Some(content)),
+        EnumTagging::External | EnumTagging::Untagged => return Ok(()),
+    };
+
+    writeln!(w)?;
+    write!(w, "public init(from decoder: Decoder) throws ")?;
+    with_block(w, Newlines::BOTH, |w| {
+        writeln!(
+            w,
+            "let container = try decoder.container(keyedBy: JsonKey.self)"
+        )?;
+        writeln!(w, r#"let tag: String = try container.field("{tag}")"#)?;
+        write!(w, "switch tag ")?;
+        with_block(w, Newlines::BOTH, |w| {
+            w.unindent();
+            for variant in variants.values() {
+                writeln!(w, r#"case "{}":"#, variant.name)?;
+                w.indent();
+                write_tagged_variant_decode(w, variant, content.map(String::as_str))?;
+                w.unindent();
+            }
+            writeln!(w, "default:")?;
             w.indent();
+            // Unknown variants, e.g. added by a later version, are read as the catch-all.
+            match variants.values().find(|variant| variant.metadata.other) {
+                Some(other) => writeln!(w, "self = .{}", other.name.to_lower_camel_case())?,
+                None => writeln!(
+                    w,
+                    r#"throw DecodingError.dataCorruptedError(forKey: "{tag}", in: container, debugDescription: "Unknown variant of {name}: \(tag)")"#
+                )?,
+            }
             Ok(())
         })
     })?;
-    write_json_deserialize(w, name)?;
 
+    writeln!(w)?;
+    write!(w, "public func encode(to encoder: Encoder) throws ")?;
+    with_block(w, Newlines::BOTH, |w| {
+        writeln!(
+            w,
+            "var container = encoder.container(keyedBy: JsonKey.self)"
+        )?;
+        write!(w, "switch self ")?;
+        with_block(w, Newlines::BOTH, |w| {
+            w.unindent();
+            for variant in variants.values() {
+                write_tagged_variant_encode(w, variant, tag, content.map(String::as_str))?;
+            }
+            w.indent();
+            Ok(())
+        })
+    })
+}
+
+/// Writes the statements of an `init(from:)` case reading a variant's payload
+/// from `container`, or from its `content` key if the enum is adjacently
+/// tagged.
+fn write_tagged_variant_decode(
+    w: &mut dyn IndentWrite,
+    variant: &Named<VariantFormat>,
+    content: Option<&str>,
+) -> io::Result<()> {
+    let name = variant.name.to_lower_camel_case();
+    match (&variant.value, content) {
+        (VariantFormat::Variable(_), _) => unreachable!("placeholders should not get this far"),
+        (VariantFormat::Unit, _) => writeln!(w, "self = .{name}"),
+        (VariantFormat::NewType(_), None) => {
+            writeln!(w, "self = try .{name}(.init(from: decoder))")
+        }
+        (VariantFormat::NewType(_), Some(content)) => {
+            writeln!(w, r#"self = try .{name}(container.field("{content}"))"#)
+        }
+        (VariantFormat::Tuple(formats), Some(content)) => {
+            writeln!(
+                w,
+                r#"var content = try container.nestedUnkeyedContainer(forKey: "{content}")"#
+            )?;
+            let elements = vec!["content.element()"; formats.len()];
+            writeln!(w, "self = try .{name}({})", elements.join(", "))
+        }
+        (VariantFormat::Tuple(_), None) => {
+            unreachable!("internally tagged tuple variants are rejected by `check_registry`")
+        }
+        (VariantFormat::Struct(fields), content) => {
+            let container = match content {
+                Some(content) => {
+                    writeln!(
+                        w,
+                        r#"let content = try container.nestedContainer(keyedBy: JsonKey.self, forKey: "{content}")"#
+                    )?;
+                    "content"
+                }
+                None => "container",
+            };
+            let args = fields
+                .iter()
+                .map(|field| {
+                    let default = field_default(field)
+                        .map(|default| format!(", default: {default}"))
+                        .unwrap_or_default();
+                    format!(
+                        r#"{}: {container}.field("{}"{default})"#,
+                        field.name.to_lower_camel_case(),
+                        field.name
+                    )
+                })
+                .collect::<Vec<_>>();
+            writeln!(w, "self = try .{name}({})", args.join(", "))
+        }
+    }
+}
+
+/// Writes the `encode(to:)` case writing a variant's tag and payload.
+fn write_tagged_variant_encode(
+    w: &mut dyn IndentWrite,
+    variant: &Named<VariantFormat>,
+    tag: &str,
+    content: Option<&str>,
+) -> io::Result<()> {
+    let name = variant.name.to_lower_camel_case();
+    let vars = match &variant.value {
+        VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+        VariantFormat::Unit => vec![],
+        VariantFormat::NewType(_) => vec!["x".to_string()],
+        VariantFormat::Tuple(formats) => (0..formats.len()).map(|i| format!("x{i}")).collect(),
+        VariantFormat::Struct(fields) => fields
+            .iter()
+            .map(|field| field.name.to_lower_camel_case())
+            .collect(),
+    };
+    if vars.is_empty() {
+        writeln!(w, "case .{name}:")?;
+    } else {
+        let bindings = vars
+            .iter()
+            .map(|var| format!("let {var}"))
+            .collect::<Vec<_>>();
+        writeln!(w, "case .{name}({}):", bindings.join(", "))?;
+    }
+    w.indent();
+    writeln!(
+        w,
+        r#"try container.encode("{}", forKey: "{tag}")"#,
+        variant.name
+    )?;
+    match (&variant.value, content) {
+        (VariantFormat::Unit | VariantFormat::Variable(_), _) => {}
+        (VariantFormat::NewType(_), None) => writeln!(w, "try x.encode(to: encoder)")?,
+        (VariantFormat::NewType(_), Some(content)) => {
+            writeln!(w, r#"try container.encode(x, forKey: "{content}")"#)?;
+        }
+        (VariantFormat::Tuple(_), content) => {
+            let content = content.expect("internally tagged tuple variants are rejected");
+            writeln!(
+                w,
+                r#"var content = container.nestedUnkeyedContainer(forKey: "{content}")"#
+            )?;
+            for var in &vars {
+                writeln!(w, "try content.encode({var})")?;
+            }
+        }
+        (VariantFormat::Struct(fields), content) => {
+            let container = match content {
+                Some(content) => {
+                    writeln!(
+                        w,
+                        r#"var content = container.nestedContainer(keyedBy: JsonKey.self, forKey: "{content}")"#
+                    )?;
+                    "content"
+                }
+                None => "container",
+            };
+            for (field, var) in fields.iter().zip(&vars) {
+                writeln!(
+                    w,
+                    r#"try {container}.encode({var}, forKey: "{}")"#,
+                    field.name
+                )?;
+            }
+        }
+    }
+    w.unindent();
     Ok(())
 }
 
@@ -476,7 +1123,10 @@ fn write_variant_serialize_case(
             writeln!(w, "try serializer.serialize_variant_index(value: {index})")?;
             for named in nameds {
                 let field_name = named.name.to_lower_camel_case();
-                write_format_serialize(w, &named.value, &field_name)?;
+                match override_serialize(named, &field_name) {
+                    Some(serialize) => writeln!(w, "{serialize}")?,
+                    None => write_format_serialize(w, &named.value, &field_name)?,
+                }
             }
             w.unindent();
         }
@@ -484,6 +1134,27 @@ fn write_variant_serialize_case(
     Ok(())
 }
 
+/// Writes the `default` case of a deserializer's `switch`, which returns the catch-all variant
+/// for unknown indices if there is one.
+fn write_unknown_variant_case(
+    w: &mut dyn IndentWrite,
+    name: &str,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+) -> io::Result<()> {
+    let Some(other) = variants.values().find(|variant| variant.metadata.other) else {
+        return writeln!(
+            w,
+            r#"default: throw DeserializationError.invalidInput(issue: "Unknown variant index for {name}: \(index)")"#
+        );
+    };
+    writeln!(w, "default:")?;
+    w.indent();
+    pop_deserializer(w)?;
+    writeln!(w, "return .{}", other.name.to_lower_camel_case())?;
+    w.unindent();
+    Ok(())
+}
+
 fn write_variant_deserialize_case(
     w: &mut dyn IndentWrite,
     variant: &Named<VariantFormat>,
@@ -520,7 +1191,7 @@ fn write_variant_deserialize_case(
         VariantFormat::Struct(nameds) => {
             for named in nameds {
                 let field_name = named.name.to_lower_camel_case();
-                write_format_deserialize(w, &named.value, &field_name)?;
+                write_variant_field_deserialize(w, &field_name, named)?;
             }
             pop_deserializer(w)?;
             write!(w, "return .{name}(")?;
@@ -542,28 +1213,64 @@ fn write_variant_deserialize_case(
 // Serialization wrappers
 // ---------------------------------------------------------------------------
 
-fn write_json_serialize(w: &mut dyn IndentWrite) -> io::Result<()> {
+fn write_json_serialize(
+    w: &mut dyn IndentWrite,
+    type_params: &[String],
+    codable: bool,
+) -> io::Result<()> {
+    if codable {
+        writeln!(w)?;
+        return writedoc!(
+            w,
+            r"
+            public func jsonSerialize() throws -> [UInt8] {{
+                try encodeJson()
+            }}
+            "
+        );
+    }
+    let params = serializer_params(type_params, "JsonSerializer");
+    let args = closure_args("serialize", type_params);
     writeln!(w)?;
     writedoc!(
         w,
         r"
-        public func jsonSerialize() throws -> [UInt8] {{
+        public func jsonSerialize({params}) throws -> [UInt8] {{
             let serializer = JsonSerializer.init();
-            try self.serialize(serializer: serializer)
+            try self.serialize(serializer: serializer{args})
             return serializer.get_bytes()
         }}
-        "
+        ",
+        params = params.trim_start_matches(", "),
     )
 }
 
-fn write_json_deserialize(w: &mut dyn IndentWrite, name: &str) -> io::Result<()> {
+fn write_json_deserialize(
+    w: &mut dyn IndentWrite,
+    name: &str,
+    type_params: &[String],
+    codable: bool,
+) -> io::Result<()> {
+    if codable {
+        writeln!(w)?;
+        return writedoc!(
+            w,
+            r"
+            public static func jsonDeserialize(input: [UInt8]) throws -> {name} {{
+                try decodeJson(input: input)
+            }}
+            "
+        );
+    }
+    let params = deserializer_params(type_params, "JsonDeserializer");
+    let args = closure_args("deserialize", type_params);
     writeln!(w)?;
     writedoc!(
         w,
         r#"
-        public static func jsonDeserialize(input: [UInt8]) throws -> {name} {{
+        public static func jsonDeserialize(input: [UInt8]{params}) throws -> {name} {{
             let deserializer = JsonDeserializer.init(input: input);
-            let obj = try deserialize(deserializer: deserializer)
+            let obj = try deserialize(deserializer: deserializer{args})
             if deserializer.get_buffer_offset() < input.count {{
                 throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
             }}
@@ -585,6 +1292,18 @@ fn write_format_serialize(
     match format {
         Format::Variable(_) => unreachable!("placeholders should not get this far"),
         Format::TypeName(_) => writeln!(w, "try {value_expr}.serialize(serializer: serializer)"),
+        Format::TypeParameter(name) => writeln!(w, "try serialize{name}({value_expr}, serializer)"),
+        Format::Generic { args, .. } => {
+            write!(w, "try {value_expr}.serialize(serializer: serializer")?;
+            for arg in args {
+                write!(w, ", ")?;
+                with_block(w, Newlines::NONE, |w| {
+                    writeln!(w, " value, serializer in")?;
+                    write_format_serialize(w, arg, "value")
+                })?;
+            }
+            writeln!(w, ")")
+        }
         Format::Option(inner) => {
             write!(
                 w,
@@ -648,6 +1367,12 @@ fn write_format_serialize(
                 "try serializeUuid(value: {value_expr}, serializer: serializer)"
             )
         }
+        Format::Timestamp | Format::Date | Format::Time | Format::Duration => {
+            writeln!(
+                w,
+                "try serialize{format:?}(value: {value_expr}, serializer: serializer)"
+            )
+        }
         primitive => {
             let t = format!("{primitive:?}").to_lowercase();
             writeln!(w, "try serializer.serialize_{t}(value: {value_expr})")
@@ -685,6 +1410,20 @@ fn write_deserialize_expr(w: &mut dyn IndentWrite, format: &Format) -> io::Resul
             let type_name = qtn.format(|ns| heck::AsUpperCamelCase(ns).to_string(), ".");
             write!(w, "try {type_name}.deserialize(deserializer: deserializer)")
         }
+        Format::TypeParameter(name) => write!(w, "try deserialize{name}(deserializer)"),
+        Format::Generic { name, args } => {
+            let type_name = name.format(|ns| heck::AsUpperCamelCase(ns).to_string(), ".");
+            write!(w, "try {type_name}.deserialize(deserializer: deserializer")?;
+            for arg in args {
+                writeln!(w, ", {{ deserializer in")?;
+                w.indent();
+                write_deserialize_expr(w, arg)?;
+                writeln!(w)?;
+                w.unindent();
+                write!(w, "}}")?;
+            }
+            write!(w, ")")
+        }
         Format::Option(inner) => {
             writeln!(
                 w,
@@ -756,6 +1495,9 @@ fn write_deserialize_expr(w: &mut dyn IndentWrite, format: &Format) -> io::Resul
             write!(w, "}}")
         }
         Format::Uuid => write!(w, "try deserializeUuid(deserializer: deserializer)"),
+        Format::Timestamp | Format::Date | Format::Time | Format::Duration => {
+            write!(w, "try deserialize{format:?}(deserializer: deserializer)")
+        }
         primitive => {
             let t = format!("{primitive:?}").to_lowercase();
             write!(w, "try deserializer.deserialize_{t}()")
@@ -763,6 +1505,38 @@ fn write_deserialize_expr(w: &mut dyn IndentWrite, format: &Format) -> io::Resul
     }
 }
 
+// ---------------------------------------------------------------------------
+// Generic type parameters
+// ---------------------------------------------------------------------------
+
+/// The per-type-parameter serializer closure parameters of `serialize` (e.g.
+/// `, _ serializeT: (T, S) throws -> Void`), where `serializer` is the
+/// serializer type.
+fn serializer_params(type_params: &[String], serializer: &str) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", _ serialize{param}: ({param}, {serializer}) throws -> Void"))
+        .collect()
+}
+
+/// The per-type-parameter deserializer closure parameters of `deserialize`
+/// (e.g. `, _ deserializeT: (D) throws -> T`), where `deserializer` is the
+/// deserializer type.
+fn deserializer_params(type_params: &[String], deserializer: &str) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", _ deserialize{param}: ({deserializer}) throws -> {param}"))
+        .collect()
+}
+
+/// Forwards the closures for `type_params` (e.g. `, serializeT`).
+fn closure_args(prefix: &str, type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| format!(", {prefix}{param}"))
+        .collect()
+}
+
 // ---------------------------------------------------------------------------
 // Depth tracking
 // ---------------------------------------------------------------------------
@@ -841,13 +1615,15 @@ mod tests {
     #[test]
     fn has_type_body_always_true() {
         use crate::generation::Container;
-        use crate::reflection::format::{ContainerFormat, Doc, QualifiedTypeName};
+        use crate::reflection::format::{
+            ContainerFormat, ContainerMetadata, Doc, QualifiedTypeName,
+        };
 
         let plugin = &JsonPlugin as &dyn EmitterPlugin<Swift>;
         let config = CodeGeneratorConfig::new("test".to_string());
 
         let name = QualifiedTypeName::root("Foo".to_string());
-        let format = ContainerFormat::UnitStruct(Doc::default());
+        let format = ContainerFormat::UnitStruct(Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -859,13 +1635,15 @@ mod tests {
     #[test]
     fn type_body_unit_struct() {
         use crate::generation::Container;
-        use crate::reflection::format::{ContainerFormat, Doc, QualifiedTypeName};
+        use crate::reflection::format::{
+            ContainerFormat, ContainerMetadata, Doc, QualifiedTypeName,
+        };
 
         let cfg = make_config(&[]);
         let plugin = &JsonPlugin as &dyn EmitterPlugin<Swift>;
 
         let name = QualifiedTypeName::root("UnitStruct".to_string());
-        let format = ContainerFormat::UnitStruct(Doc::default());
+        let format = ContainerFormat::UnitStruct(Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -892,7 +1670,9 @@ mod tests {
     #[test]
     fn type_body_struct_with_fields() {
         use crate::generation::Container;
-        use crate::reflection::format::{ContainerFormat, Doc, Format, QualifiedTypeName};
+        use crate::reflection::format::{
+            ContainerFormat, ContainerMetadata, Doc, Format, QualifiedTypeName,
+        };
 
         let cfg = make_config(&[]);
         let plugin = &JsonPlugin as &dyn EmitterPlugin<Swift>;
@@ -902,7 +1682,7 @@ mod tests {
             Named::new(&Format::Str, "name".to_string()),
             Named::new(&Format::I32, "age".to_string()),
         ];
-        let format = ContainerFormat::Struct(fields, Doc::default());
+        let format = ContainerFormat::Struct(fields, Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -929,7 +1709,9 @@ mod tests {
     #[test]
     fn type_body_tuple_field_extra_container_depth() {
         use crate::generation::Container;
-        use crate::reflection::format::{ContainerFormat, Doc, Format, QualifiedTypeName};
+        use crate::reflection::format::{
+            ContainerFormat, ContainerMetadata, Doc, Format, QualifiedTypeName,
+        };
 
         let cfg = make_config(&[]);
         let plugin = &JsonPlugin as &dyn EmitterPlugin<Swift>;
@@ -940,7 +1722,7 @@ mod tests {
             &Format::Tuple(vec![Format::Str, Format::I32]),
             "pair".to_string(),
         )];
-        let format = ContainerFormat::Struct(fields, Doc::default());
+        let format = ContainerFormat::Struct(fields, Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -980,7 +1762,9 @@ mod tests {
     #[test]
     fn type_body_enum() {
         use crate::generation::Container;
-        use crate::reflection::format::{ContainerFormat, Doc, Format, QualifiedTypeName};
+        use crate::reflection::format::{
+            ContainerFormat, ContainerMetadata, Doc, FieldMetadata, Format, QualifiedTypeName,
+        };
         use std::collections::BTreeMap;
 
         let cfg = make_config(&[]);
@@ -993,6 +1777,7 @@ mod tests {
                 name: "unit".to_string(),
                 doc: Doc::default(),
                 value: VariantFormat::Unit,
+                metadata: FieldMetadata::default(),
             },
         );
         variants.insert(
@@ -1001,10 +1786,16 @@ mod tests {
                 name: "withValue".to_string(),
                 doc: Doc::default(),
                 value: VariantFormat::NewType(Box::new(Format::Str)),
+                metadata: FieldMetadata::default(),
             },
         );
         let name = QualifiedTypeName::root("MyEnum".to_string());
-        let format = ContainerFormat::Enum(variants, EnumTagging::External, Doc::default());
+        let format = ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::default(),
+            ContainerMetadata::default(),
+        );
         let container = Container {
             name: &name,
             format: &format,
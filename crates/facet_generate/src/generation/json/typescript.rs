@@ -27,9 +27,14 @@ use crate::generation::{
     CodeGeneratorConfig, Feature, PackageLocation, SERDE_NAMESPACE,
     indent::{IndentWrite, Newlines, with_block},
     plugin::{EmitContext, EmitterPlugin, RuntimeFile},
-    typescript::TypeScript,
+    typescript::{
+        TypeScript,
+        emitter::{constructor_order, default_value},
+    },
+};
+use crate::reflection::format::{
+    ContainerFormat, EnumTagging, Format, Language, Named, VariantFormat,
 };
-use crate::reflection::format::{ContainerFormat, EnumTagging, Format, Named, VariantFormat};
 
 use super::JsonPlugin;
 
@@ -179,6 +184,33 @@ function deserializeUuid(deserializer: Deserializer): Uuid {
 }
 ";
 
+const FEATURE_TIMESTAMP: &str = r"function serializeTimestamp(value: Date, serializer: Serializer): void {
+    serializer.serializeStr(value.toISOString());
+}
+
+function deserializeTimestamp(deserializer: Deserializer): Date {
+    const s = deserializer.deserializeStr();
+    // `Date` has millisecond precision, so longer fractions are truncated.
+    const value = new Date(s.replace(/(\.\d{3})\d+/, '$1'));
+    if (Number.isNaN(value.getTime())) {
+        throw new Error(`Invalid RFC 3339 timestamp: ${s}`);
+    }
+    return value;
+}
+";
+
+const FEATURE_DURATION: &str = r"function serializeDuration(value: duration, serializer: Serializer): void {
+    serializer.serializeU64(value.secs);
+    serializer.serializeU32(value.nanos);
+}
+
+function deserializeDuration(deserializer: Deserializer): duration {
+    const secs = deserializer.deserializeU64();
+    const nanos = deserializer.deserializeU32();
+    return { secs, nanos };
+}
+";
+
 // ---------------------------------------------------------------------------
 // EmitterPlugin implementation
 // ---------------------------------------------------------------------------
@@ -245,7 +277,16 @@ impl EmitterPlugin<TypeScript> for JsonPlugin {
                     writeln!(w)?;
                     write!(w, "{FEATURE_UUID}")?;
                 }
-                Feature::BigInt | Feature::Bytes => {}
+                Feature::Timestamp => {
+                    writeln!(w)?;
+                    write!(w, "{FEATURE_TIMESTAMP}")?;
+                }
+                Feature::Duration => {
+                    writeln!(w)?;
+                    write!(w, "{FEATURE_DURATION}")?;
+                }
+                // dates and times are plain strings on both sides
+                Feature::BigInt | Feature::Bytes | Feature::Date | Feature::Time => {}
             }
         }
         Ok(())
@@ -256,17 +297,33 @@ impl EmitterPlugin<TypeScript> for JsonPlugin {
     }
 
     fn type_body(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
-        if matches!(ctx.container.format, ContainerFormat::Enum(_, _, _)) {
+        if matches!(ctx.container.format, ContainerFormat::Enum(_, _, _, _)) {
             return Ok(());
         }
-        write_struct_type_body(w, ctx.name(), &ctx.fields(), ctx.config)
+        write_struct_type_body(w, ctx.name(), ctx.type_params(), &ctx.fields(), ctx.config)
     }
 
     fn after_type(&self, w: &mut dyn IndentWrite, ctx: &EmitContext) -> io::Result<()> {
-        if let ContainerFormat::Enum(variants, tagging, _) = ctx.container.format {
-            write_enum_standalone_functions(w, ctx.name(), variants, tagging, ctx.config)?;
+        match ctx.container.format {
+            ContainerFormat::Enum(variants, tagging, _, _) => write_enum_standalone_functions(
+                w,
+                ctx.name(),
+                ctx.type_params(),
+                variants,
+                tagging,
+                ctx.config,
+            ),
+            ContainerFormat::NewTypeStruct(format, _, metadata) if metadata.branded => {
+                write_branded_standalone_functions(
+                    w,
+                    ctx.name(),
+                    ctx.type_params(),
+                    format,
+                    ctx.config,
+                )
+            }
+            _ => Ok(()),
         }
-        Ok(())
     }
 }
 
@@ -278,28 +335,38 @@ impl EmitterPlugin<TypeScript> for JsonPlugin {
 fn write_struct_type_body(
     w: &mut dyn IndentWrite,
     name: &str,
+    type_params: &[String],
     fields: &[Named<Format>],
     config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
     writeln!(w)?;
-    write!(w, "public serialize(serializer: Serializer): void ")?;
+    write!(
+        w,
+        "public serialize(serializer: Serializer{}): void ",
+        serializer_params(type_params)
+    )?;
     with_block(w, Newlines::BOTH, |w| {
         for field in fields {
-            write_serialize(w, &format!("this.{}", field.name), &field.value, config)?;
+            write_field_serialize(w, &format!("this.{}", field.name), field, config)?;
         }
         Ok(())
     })?;
     writeln!(w)?;
-    write!(w, "static deserialize(deserializer: Deserializer): {name} ")?;
+    let generics = generic_list(type_params);
+    write!(
+        w,
+        "static deserialize{generics}(deserializer: Deserializer{}): {name}{generics} ",
+        deserializer_params(type_params)
+    )?;
     with_block(w, Newlines::BOTH, |w| {
         for field in fields {
-            write_deserialize(w, Some(&field.name), &field.value, config)?;
+            write_field_deserialize(w, field, config)?;
         }
         writeln!(
             w,
             "return new {name}({args});",
-            args = fields
-                .iter()
+            args = constructor_order(fields)
+                .into_iter()
                 .map(|f| f.name.clone())
                 .collect::<Vec<_>>()
                 .join(",")
@@ -308,23 +375,59 @@ fn write_struct_type_body(
     Ok(())
 }
 
+/// Emit standalone `serialize{Name}` / `deserialize{Name}` functions for a branded
+/// newtype, which is a type alias of its inner type and so encodes exactly like it.
+fn write_branded_standalone_functions(
+    w: &mut dyn IndentWrite,
+    name: &str,
+    type_params: &[String],
+    format: &Format,
+    config: &CodeGeneratorConfig,
+) -> io::Result<()> {
+    let generics = generic_list(type_params);
+    writeln!(w)?;
+    write!(
+        w,
+        "export function serialize{name}{generics}(value: {name}{generics}, serializer: Serializer{}): void ",
+        serializer_params(type_params)
+    )?;
+    with_block(w, Newlines::BOTH, |w| {
+        write_serialize(w, "value", format, config)
+    })?;
+
+    writeln!(w)?;
+    write!(
+        w,
+        "export function deserialize{name}{generics}(deserializer: Deserializer{}): {name}{generics} ",
+        deserializer_params(type_params)
+    )?;
+    with_block(w, Newlines::BOTH, |w| {
+        write_deserialize(w, Some("value"), format, config)?;
+        writeln!(w, "return value as {name}{generics};")
+    })
+}
+
 /// Emit standalone `export function serialize{Name}` and `export function deserialize{Name}`.
 fn write_enum_standalone_functions(
     w: &mut dyn IndentWrite,
     name: &str,
+    type_params: &[String],
     variants: &BTreeMap<u32, Named<VariantFormat>>,
     tagging: &EnumTagging,
     config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
     let tag_field = match tagging {
-        EnumTagging::External => "kind",
+        EnumTagging::External | EnumTagging::Untagged => "kind",
         EnumTagging::Internal { tag } | EnumTagging::Adjacent { tag, .. } => tag.as_str(),
     };
 
+    let generics = generic_list(type_params);
+    let untagged = *tagging == EnumTagging::Untagged;
     writeln!(w)?;
     write!(
         w,
-        "export function serialize{name}(value: {name}, serializer: Serializer): void "
+        "export function serialize{name}{generics}(value: {name}{generics}, serializer: Serializer{}): void ",
+        serializer_params(type_params)
     )?;
     with_block(w, Newlines::BOTH, |w| {
         write!(w, "switch (value.{tag_field}) ")?;
@@ -333,7 +436,15 @@ fn write_enum_standalone_functions(
                 let vname = &variant.name;
                 write!(w, r#"case "{vname}": "#)?;
                 with_block(w, Newlines::BOTH, |w| {
-                    writeln!(w, "serializer.serializeVariantIndex({index});")?;
+                    // Untagged variants write their payload alone, so a unit
+                    // variant still needs something on the wire.
+                    match (untagged, &variant.value) {
+                        (false, _) => writeln!(w, "serializer.serializeVariantIndex({index});")?,
+                        (true, VariantFormat::Unit) => {
+                            writeln!(w, "serializer.serializeUnit(null);")?
+                        }
+                        (true, _) => {}
+                    }
                     write_serialize_variant_fields(w, tagging, &variant.value, config)?;
                     writeln!(w, "break;")
                 })?;
@@ -348,8 +459,14 @@ fn write_enum_standalone_functions(
     writeln!(w)?;
     write!(
         w,
-        "export function deserialize{name}(deserializer: Deserializer): {name} "
+        "export function deserialize{name}{generics}(deserializer: Deserializer{}): {name}{generics} ",
+        deserializer_params(type_params)
     )?;
+    if untagged {
+        return with_block(w, Newlines::BOTH, |w| {
+            write_untagged_deserialize_body(w, name, variants, tagging, config)
+        });
+    }
     with_block(w, Newlines::BOTH, |w| {
         writeln!(w, "const index = deserializer.deserializeVariantIndex();")?;
         write!(w, "switch (index) ")?;
@@ -366,16 +483,60 @@ fn write_enum_standalone_functions(
                     )
                 })?;
             }
-            writeln!(
-                w,
-                r#"default: throw new Error("Unknown variant index for {name}: " + index);"#
-            )
+            // Unknown variants, e.g. added by a later version, are read as the catch-all.
+            match variants.values().find(|variant| variant.metadata.other) {
+                Some(other) => {
+                    write!(w, "default: ")?;
+                    with_block(w, Newlines::BOTH, |w| {
+                        write_deserialize_variant_return(
+                            w,
+                            &other.name,
+                            tagging,
+                            &other.value,
+                            config,
+                        )
+                    })
+                }
+                None => writeln!(
+                    w,
+                    r#"default: throw new Error("Unknown variant index for {name}: " + index);"#
+                ),
+            }
         })
     })?;
 
     Ok(())
 }
 
+/// Emit the body of `deserialize{Name}` for an untagged enum: each variant is
+/// tried in order, rewinding the input after every one that fails to read.
+fn write_untagged_deserialize_body(
+    w: &mut dyn IndentWrite,
+    name: &str,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
+    tagging: &EnumTagging,
+    config: &CodeGeneratorConfig,
+) -> io::Result<()> {
+    writeln!(w, "const offset = deserializer.getBufferOffset();")?;
+    for variant in variants.values() {
+        write!(w, "try ")?;
+        with_block(w, Newlines::OPEN, |w| {
+            if variant.value == VariantFormat::Unit {
+                writeln!(w, "deserializer.deserializeUnit();")?;
+            }
+            write_deserialize_variant_return(w, &variant.name, tagging, &variant.value, config)
+        })?;
+        write!(w, " catch ")?;
+        with_block(w, Newlines::BOTH, |w| {
+            writeln!(w, "deserializer.setBufferOffset(offset);")
+        })?;
+    }
+    writeln!(
+        w,
+        r#"throw new Error("No variant of {name} matches the input");"#
+    )
+}
+
 /// Emit serialize statements for a single variant's payload fields.
 fn write_serialize_variant_fields(
     w: &mut dyn IndentWrite,
@@ -403,10 +564,10 @@ fn write_serialize_variant_fields(
         }
         (EnumTagging::Adjacent { content, .. }, VariantFormat::Struct(fields)) => {
             for field in fields {
-                write_serialize(
+                write_field_serialize(
                     w,
                     &format!("value.{content}.{}", field.name),
-                    &field.value,
+                    field,
                     config,
                 )?;
             }
@@ -414,7 +575,7 @@ fn write_serialize_variant_fields(
         }
         (_, VariantFormat::Struct(fields)) => {
             for field in fields {
-                write_serialize(w, &format!("value.{}", field.name), &field.value, config)?;
+                write_field_serialize(w, &format!("value.{}", field.name), field, config)?;
             }
             Ok(())
         }
@@ -431,7 +592,7 @@ fn write_deserialize_variant_return(
     config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
     let tag_field = match tagging {
-        EnumTagging::External => "kind",
+        EnumTagging::External | EnumTagging::Untagged => "kind",
         EnumTagging::Internal { tag } | EnumTagging::Adjacent { tag, .. } => tag.as_str(),
     };
 
@@ -477,7 +638,7 @@ fn write_deserialize_variant_return(
         }
         (EnumTagging::Adjacent { content, .. }, VariantFormat::Struct(fields)) => {
             for field in fields {
-                write_deserialize(w, Some(&field.name), &field.value, config)?;
+                write_field_deserialize(w, field, config)?;
             }
             let struct_fields = fields
                 .iter()
@@ -491,7 +652,7 @@ fn write_deserialize_variant_return(
         }
         (_, VariantFormat::Struct(fields)) => {
             for field in fields {
-                write_deserialize(w, Some(&field.name), &field.value, config)?;
+                write_field_deserialize(w, field, config)?;
             }
             let field_names: Vec<String> = fields.iter().map(|f| f.name.clone()).collect();
             let all_parts: Vec<String> =
@@ -517,12 +678,30 @@ fn write_serialize(
     match format {
         Format::TypeName(qualified_name) => {
             let type_name = qualified_name.format(ToUpperCamelCase::to_upper_camel_case, ".");
-            if config.enum_type_names.contains(&type_name) {
+            if has_standalone_functions(&type_name, config) {
                 writeln!(w, "serialize{type_name}({value_expr}, serializer);")
             } else {
                 writeln!(w, "{value_expr}.serialize(serializer);")
             }
         }
+        Format::Generic { name, args } => {
+            let type_name = name.format(ToUpperCamelCase::to_upper_camel_case, ".");
+            if has_standalone_functions(&type_name, config) {
+                write!(w, "serialize{type_name}({value_expr}, serializer")?;
+            } else {
+                write!(w, "{value_expr}.serialize(serializer")?;
+            }
+            for arg in args {
+                write!(w, ", (value, serializer) => ")?;
+                with_block(w, Newlines::OPEN, |w| {
+                    write_serialize(w, "value", arg, config)
+                })?;
+            }
+            writeln!(w, ");")
+        }
+        Format::TypeParameter(param) => {
+            writeln!(w, "{}({value_expr}, serializer);", serializer_name(param))
+        }
         Format::Unit => writeln!(w, "serializer.serializeUnit({value_expr});"),
         Format::Bool => writeln!(w, "serializer.serializeBool({value_expr});"),
         Format::I8 => writeln!(w, "serializer.serializeI8({value_expr});"),
@@ -541,6 +720,9 @@ fn write_serialize(
         Format::Str => writeln!(w, "serializer.serializeStr({value_expr});"),
         Format::Bytes => writeln!(w, "serializer.serializeBytes({value_expr});"),
         Format::Uuid => writeln!(w, "serializeUuid({value_expr}, serializer);"),
+        Format::Timestamp => writeln!(w, "serializeTimestamp({value_expr}, serializer);"),
+        Format::Date | Format::Time => writeln!(w, "serializer.serializeStr({value_expr});"),
+        Format::Duration => writeln!(w, "serializeDuration({value_expr}, serializer);"),
         Format::Option(inner) => {
             write!(
                 w,
@@ -602,6 +784,56 @@ fn write_serialize(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Generic type parameters
+// ---------------------------------------------------------------------------
+
+/// The type parameter list of a generic type (e.g. `<T, E>`), or an empty
+/// string for a non-generic type.
+fn generic_list(type_params: &[String]) -> String {
+    if type_params.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", type_params.join(", "))
+    }
+}
+
+/// Name of the closure parameter that serializes values of a type parameter.
+fn serializer_name(param: &str) -> String {
+    format!("serialize{}", param.to_upper_camel_case())
+}
+
+/// Name of the closure parameter that deserializes values of a type parameter.
+fn deserializer_name(param: &str) -> String {
+    format!("deserialize{}", param.to_upper_camel_case())
+}
+
+/// Trailing serializer closure parameters, one per type parameter.
+fn serializer_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| {
+            format!(
+                ", {}: (value: {param}, serializer: Serializer) => void",
+                serializer_name(param)
+            )
+        })
+        .collect()
+}
+
+/// Trailing deserializer closure parameters, one per type parameter.
+fn deserializer_params(type_params: &[String]) -> String {
+    type_params
+        .iter()
+        .map(|param| {
+            format!(
+                ", {}: (deserializer: Deserializer) => {param}",
+                deserializer_name(param)
+            )
+        })
+        .collect()
+}
+
 // ---------------------------------------------------------------------------
 // Deserialize helpers
 // ---------------------------------------------------------------------------
@@ -611,6 +843,14 @@ fn write_serialize(
 fn quote_type(format: &Format) -> String {
     match format {
         Format::TypeName(type_) => type_.format(ToUpperCamelCase::to_upper_camel_case, "."),
+        Format::Generic { name, args } => {
+            let args = args.iter().map(quote_type).collect::<Vec<_>>().join(", ");
+            format!(
+                "{}<{args}>",
+                name.format(ToUpperCamelCase::to_upper_camel_case, ".")
+            )
+        }
+        Format::TypeParameter(param) => param.clone(),
         Format::Unit => "unit".to_string(),
         Format::Bool => "bool".to_string(),
         Format::I8 => "int8".to_string(),
@@ -629,6 +869,10 @@ fn quote_type(format: &Format) -> String {
         Format::Str => "str".to_string(),
         Format::Bytes => "bytes".to_string(),
         Format::Uuid => "Uuid".to_string(),
+        Format::Timestamp => "timestamp".to_string(),
+        Format::Date => "date".to_string(),
+        Format::Time => "time".to_string(),
+        Format::Duration => "duration".to_string(),
         Format::Option(inner) => format!("Optional<{}>", quote_type(inner)),
         Format::Seq(inner) | Format::Set(inner) => format!("Seq<{}>", quote_type(inner)),
         Format::Map { key, value } => {
@@ -654,12 +898,13 @@ fn deserialize_primitive_expr(format: &Format, config: &CodeGeneratorConfig) ->
     match format {
         Format::TypeName(qualified_name) => {
             let type_name = qualified_name.format(ToUpperCamelCase::to_upper_camel_case, ".");
-            if config.enum_type_names.contains(&type_name) {
+            if has_standalone_functions(&type_name, config) {
                 format!("deserialize{type_name}(deserializer)")
             } else {
                 format!("{type_name}.deserialize(deserializer)")
             }
         }
+        Format::TypeParameter(param) => format!("{}(deserializer)", deserializer_name(param)),
         Format::Unit => "deserializer.deserializeUnit()".to_string(),
         Format::Bool => "deserializer.deserializeBool()".to_string(),
         Format::I8 => "deserializer.deserializeI8()".to_string(),
@@ -678,15 +923,26 @@ fn deserialize_primitive_expr(format: &Format, config: &CodeGeneratorConfig) ->
         Format::Str => "deserializer.deserializeStr()".to_string(),
         Format::Bytes => "deserializer.deserializeBytes()".to_string(),
         Format::Uuid => "deserializeUuid(deserializer)".to_string(),
+        Format::Timestamp => "deserializeTimestamp(deserializer)".to_string(),
+        Format::Date | Format::Time => "deserializer.deserializeStr()".to_string(),
+        Format::Duration => "deserializeDuration(deserializer)".to_string(),
         _ => panic!("deserialize_primitive_expr called with non-primitive format"),
     }
 }
 
+/// Whether values of the named type are (de)serialized by standalone
+/// `serialize{Name}` / `deserialize{Name}` functions rather than methods: enums, which
+/// are union types, and branded newtypes, which are aliases of their inner type.
+fn has_standalone_functions(type_name: &str, config: &CodeGeneratorConfig) -> bool {
+    config.enum_type_names.contains(type_name) || config.branded_type_names.contains(type_name)
+}
+
 /// Returns `true` for primitive types and named (user-defined) type references.
 const fn is_primitive_or_named(format: &Format) -> bool {
     matches!(
         format,
         Format::TypeName(_)
+            | Format::TypeParameter(_)
             | Format::Unit
             | Format::Bool
             | Format::I8
@@ -705,6 +961,10 @@ const fn is_primitive_or_named(format: &Format) -> bool {
             | Format::Str
             | Format::Bytes
             | Format::Uuid
+            | Format::Timestamp
+            | Format::Date
+            | Format::Time
+            | Format::Duration
     )
 }
 
@@ -713,35 +973,103 @@ const fn is_primitive_or_named(format: &Format) -> bool {
 /// When `field_name` is `Some`, emits `const <name> = <expr>;`.
 /// When `field_name` is `None`, emits `return <expr>;`.
 #[allow(clippy::too_many_lines)]
+/// Serializes the struct or variant field held by `value_expr`, with its `fg::override` code for
+/// TypeScript if it has any.
+fn write_field_serialize(
+    w: &mut dyn IndentWrite,
+    value_expr: &str,
+    field: &Named<Format>,
+    config: &CodeGeneratorConfig,
+) -> io::Result<()> {
+    let type_override = field.metadata.type_override(Language::TypeScript);
+    match type_override.and_then(|type_override| type_override.serialize(value_expr)) {
+        Some(serialize) => writeln!(w, "{serialize}"),
+        None => write_serialize(w, value_expr, &field.value, config),
+    }
+}
+
+/// Deserializes a struct or variant field into a local of the same name, with its
+/// `fg::override` code for TypeScript if it has any.
+///
+/// A field with a default value (and no override) takes the default if the deserializer's
+/// `hasField` says the input leaves it out.
+fn write_field_deserialize(
+    w: &mut dyn IndentWrite,
+    field: &Named<Format>,
+    config: &CodeGeneratorConfig,
+) -> io::Result<()> {
+    let name = &field.name;
+    let type_override = field.metadata.type_override(Language::TypeScript);
+    if let Some(deserialize) =
+        type_override.and_then(|type_override| type_override.deserialize.as_ref())
+    {
+        return writeln!(w, "const {name} = {deserialize};");
+    }
+    let default = field
+        .metadata
+        .default
+        .as_ref()
+        .filter(|_| type_override.is_none())
+        .and_then(|default| default_value(default, &field.value));
+    match default {
+        Some(default) => {
+            let target = format!(
+                r#"const {name} = deserializer.hasField?.("{name}") === false ? {default} : "#
+            );
+            write_deserialize_to(w, &target, &field.value, config)
+        }
+        None => write_deserialize(w, Some(name), &field.value, config),
+    }
+}
+
 fn write_deserialize(
     w: &mut dyn IndentWrite,
     field_name: Option<&str>,
     format: &Format,
     config: &CodeGeneratorConfig,
+) -> io::Result<()> {
+    let target =
+        field_name.map_or_else(|| "return ".to_string(), |name| format!("const {name} = "));
+    write_deserialize_to(w, &target, format, config)
+}
+
+/// Deserializes a value of `format` into `target`, the start of the statement that uses it
+/// (e.g. `const name = ` or `return `).
+fn write_deserialize_to(
+    w: &mut dyn IndentWrite,
+    target: &str,
+    format: &Format,
+    config: &CodeGeneratorConfig,
 ) -> io::Result<()> {
     match format {
         // Primitive and named types — simple single-expression form.
         f if is_primitive_or_named(f) => {
             let expr = deserialize_primitive_expr(f, config);
-            if let Some(name) = field_name {
-                writeln!(w, "const {name} = {expr};")
+            writeln!(w, "{target}{expr};")
+        }
+
+        Format::Generic { name, args } => {
+            let type_name = name.format(ToUpperCamelCase::to_upper_camel_case, ".");
+            let callee = if has_standalone_functions(&type_name, config) {
+                format!("deserialize{type_name}")
             } else {
-                writeln!(w, "return {expr};")
+                format!("{type_name}.deserialize")
+            };
+            write!(w, "{target}{callee}(deserializer")?;
+            for arg in args {
+                write!(w, ", (deserializer) => ")?;
+                with_block(w, Newlines::OPEN, |w| {
+                    write_deserialize(w, None, arg, config)
+                })?;
             }
+            writeln!(w, ");")
         }
 
         Format::Option(inner) => {
-            if let Some(name) = field_name {
-                write!(
-                    w,
-                    "const {name} = deserializeOption(deserializer, (deserializer) => "
-                )?;
-            } else {
-                write!(
-                    w,
-                    "return deserializeOption(deserializer, (deserializer) => "
-                )?;
-            }
+            write!(
+                w,
+                "{target}deserializeOption(deserializer, (deserializer) => "
+            )?;
             with_block(w, Newlines::OPEN, |w| {
                 write_deserialize(w, None, inner, config)
             })?;
@@ -749,17 +1077,10 @@ fn write_deserialize(
         }
 
         Format::Seq(inner) => {
-            if let Some(name) = field_name {
-                write!(
-                    w,
-                    "const {name} = deserializeArray(deserializer, (deserializer) => "
-                )?;
-            } else {
-                write!(
-                    w,
-                    "return deserializeArray(deserializer, (deserializer) => "
-                )?;
-            }
+            write!(
+                w,
+                "{target}deserializeArray(deserializer, (deserializer) => "
+            )?;
             with_block(w, Newlines::OPEN, |w| {
                 write_deserialize(w, None, inner, config)
             })?;
@@ -767,14 +1088,7 @@ fn write_deserialize(
         }
 
         Format::Set(inner) => {
-            if let Some(name) = field_name {
-                write!(
-                    w,
-                    "const {name} = deserializeSet(deserializer, (deserializer) => "
-                )?;
-            } else {
-                write!(w, "return deserializeSet(deserializer, (deserializer) => ")?;
-            }
+            write!(w, "{target}deserializeSet(deserializer, (deserializer) => ")?;
             with_block(w, Newlines::OPEN, |w| {
                 write_deserialize(w, None, inner, config)
             })?;
@@ -782,14 +1096,7 @@ fn write_deserialize(
         }
 
         Format::Map { key, value } => {
-            if let Some(name) = field_name {
-                write!(
-                    w,
-                    "const {name} = deserializeMap(deserializer, (deserializer) => "
-                )?;
-            } else {
-                write!(w, "return deserializeMap(deserializer, (deserializer) => ")?;
-            }
+            write!(w, "{target}deserializeMap(deserializer, (deserializer) => ")?;
             with_block(w, Newlines::OPEN, |w| {
                 if is_primitive_or_named(key) {
                     writeln!(
@@ -827,25 +1134,14 @@ fn write_deserialize(
                 .map(quote_type)
                 .collect::<Vec<_>>()
                 .join(", ");
-            if let Some(name) = field_name {
-                writeln!(w, "const {name} = [{fields_joined}] as [{type_str}];")
-            } else {
-                writeln!(w, "return [{fields_joined}] as [{type_str}];")
-            }
+            writeln!(w, "{target}[{fields_joined}] as [{type_str}];")
         }
 
         Format::TupleArray { content, size } => {
-            if let Some(name) = field_name {
-                write!(
-                    w,
-                    "const {name} = deserializeTupleArray(deserializer, {size}, (deserializer) => "
-                )?;
-            } else {
-                write!(
-                    w,
-                    "return deserializeTupleArray(deserializer, {size}, (deserializer) => "
-                )?;
-            }
+            write!(
+                w,
+                "{target}deserializeTupleArray(deserializer, {size}, (deserializer) => "
+            )?;
             with_block(w, Newlines::OPEN, |w| {
                 write_deserialize(w, Some("item"), content, config)?;
                 writeln!(w, "return [item];")
@@ -872,7 +1168,9 @@ mod tests {
         indent::{IndentConfig, IndentedWriter},
         plugin::EmitContext,
     };
-    use crate::reflection::format::{ContainerFormat, Doc, EnumTagging, QualifiedTypeName};
+    use crate::reflection::format::{
+        ContainerFormat, ContainerMetadata, Doc, EnumTagging, QualifiedTypeName,
+    };
 
     fn make_config(features: &[Feature]) -> CodeGeneratorConfig {
         let mut cfg = CodeGeneratorConfig::new("test".to_string());
@@ -961,7 +1259,7 @@ mod tests {
         let plugin = &JsonPlugin as &dyn EmitterPlugin<TypeScript>;
 
         let name = QualifiedTypeName::root("Foo".to_string());
-        let format = ContainerFormat::Struct(vec![], Doc::default());
+        let format = ContainerFormat::Struct(vec![], Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -981,7 +1279,7 @@ mod tests {
         let plugin = &JsonPlugin as &dyn EmitterPlugin<TypeScript>;
 
         let name = QualifiedTypeName::root("Foo".to_string());
-        let format = ContainerFormat::UnitStruct(Doc::default());
+        let format = ContainerFormat::UnitStruct(Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -1011,7 +1309,7 @@ mod tests {
             Named::new(&Format::Str, "label".to_string()),
             Named::new(&Format::I32, "count".to_string()),
         ];
-        let format = ContainerFormat::Struct(fields, Doc::default());
+        let format = ContainerFormat::Struct(fields, Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -1053,7 +1351,12 @@ mod tests {
         variants.insert(1u32, Named::new(&VariantFormat::Unit, "Beta".to_string()));
 
         let name = QualifiedTypeName::root("MyEnum".to_string());
-        let format = ContainerFormat::Enum(variants, EnumTagging::External, Doc::default());
+        let format = ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::default(),
+            ContainerMetadata::default(),
+        );
         let container = Container {
             name: &name,
             format: &format,
@@ -1078,7 +1381,12 @@ mod tests {
         variants.insert(1u32, Named::new(&VariantFormat::Unit, "Beta".to_string()));
 
         let name = QualifiedTypeName::root("MyEnum".to_string());
-        let format = ContainerFormat::Enum(variants, EnumTagging::External, Doc::default());
+        let format = ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::default(),
+            ContainerMetadata::default(),
+        );
         let container = Container {
             name: &name,
             format: &format,
@@ -1112,7 +1420,7 @@ mod tests {
         let plugin = &JsonPlugin as &dyn EmitterPlugin<TypeScript>;
 
         let name = QualifiedTypeName::root("Foo".to_string());
-        let format = ContainerFormat::Struct(vec![], Doc::default());
+        let format = ContainerFormat::Struct(vec![], Doc::default(), ContainerMetadata::default());
         let container = Container {
             name: &name,
             format: &format,
@@ -9,7 +9,7 @@
 //! | AST node | Kotlin output |
 //! |---|---|
 //! | [`Module`] | `package` declaration, `import` statements, feature helpers |
-//! | [`Container`] | `data class`, `data object`, `sealed interface`, or `enum class` |
+//! | [`Container`] | `data class`, `data object`, `sealed interface`, `enum class`, or `typealias` |
 //! | [`Named<Format>`](Named) | A single `val` property declaration |
 //! | [`Format`] | An inline type expression (`Int`, `List<String>`, `Pair<A, B>`, …) |
 //! | [`Doc`] | `///` doc comments |
@@ -51,7 +51,7 @@ use std::{
     sync::Arc,
 };
 
-use heck::ToLowerCamelCase;
+use heck::{ToLowerCamelCase, ToShoutySnakeCase as _};
 
 use crate::{
     Registry,
@@ -59,9 +59,12 @@ use crate::{
         CodeGeneratorConfig, Container, Emitter, Feature,
         indent::{IndentWrite, Newlines},
         module::Module,
-        plugin::{EmitContext, EmitterPlugin, VariantInfo},
+        plugin::{EmitContext, EmitterPlugin, VariantInfo, type_annotations, type_conformances},
+    },
+    reflection::format::{
+        ContainerFormat, ContainerMetadata, DefaultValue, Deprecation, Doc, Format, Language,
+        Named, QualifiedTypeName, VariantFormat, Visibility,
     },
-    reflection::format::{ContainerFormat, Doc, Format, Named, QualifiedTypeName, VariantFormat},
 };
 
 const FEATURE_TUPLE_ARRAY: &str = r"/**
@@ -209,42 +212,90 @@ impl Emitter<Kotlin> for Container<'_> {
             format,
             ..
         } = self;
+        let type_params = format.type_params();
+        let metadata = format.metadata();
+        let visibility = lang.config.visibility(metadata.visibility);
+        let value = |format: &Format| Named::new(format, "value".to_string()).inheriting(metadata);
         match format {
-            ContainerFormat::UnitStruct(doc) => {
-                data_object(w, name, None, doc, lang, None)?;
+            ContainerFormat::UnitStruct(doc, _) => {
+                data_object(w, name, visibility, metadata, None, doc, lang)?;
+            }
+            ContainerFormat::NewTypeStruct(format, doc, metadata) if metadata.proxy => {
+                type_alias(w, name, visibility, metadata, format, doc, lang)?;
             }
-            ContainerFormat::NewTypeStruct(format, doc) => {
+            ContainerFormat::NewTypeStruct(format, doc, metadata) if metadata.branded => {
+                let field = value(format);
+                value_class(
+                    w,
+                    name,
+                    visibility,
+                    metadata,
+                    type_params,
+                    &field,
+                    doc,
+                    lang,
+                )?;
+            }
+            ContainerFormat::NewTypeStruct(format, doc, _) => {
                 data_class(
                     w,
                     name,
+                    visibility,
+                    metadata,
+                    type_params,
                     None,
-                    &[Named::new(format, "value".to_string())],
+                    &[value(format)],
                     doc,
                     lang,
-                    None,
                 )?;
             }
-            ContainerFormat::TupleStruct(formats, doc) => {
-                data_class(w, name, None, &named(formats), doc, lang, None)?;
+            ContainerFormat::TupleStruct(formats, doc, _) => {
+                let fields = named(formats)
+                    .iter()
+                    .map(|field| field.inheriting(metadata))
+                    .collect::<Vec<_>>();
+                data_class(
+                    w,
+                    name,
+                    visibility,
+                    metadata,
+                    type_params,
+                    None,
+                    &fields,
+                    doc,
+                    lang,
+                )?;
             }
-            ContainerFormat::Struct(fields, doc) => {
+            ContainerFormat::Struct(fields, doc, _) => {
                 if fields.is_empty() {
-                    data_object(w, name, None, doc, lang, None)?;
+                    data_object(w, name, visibility, metadata, None, doc, lang)?;
                 } else {
-                    data_class(w, name, None, fields, doc, lang, None)?;
+                    let fields = fields
+                        .iter()
+                        .map(|field| field.inheriting(metadata))
+                        .collect::<Vec<_>>();
+                    data_class(
+                        w,
+                        name,
+                        visibility,
+                        metadata,
+                        type_params,
+                        None,
+                        &fields,
+                        doc,
+                        lang,
+                    )?;
                 }
             }
-            ContainerFormat::Enum(variants, _, doc) => {
-                let variant_list: Vec<_> = variants.values().cloned().collect();
-
+            ContainerFormat::Enum(variants, _, doc, _) => {
                 let all_unit_variants = variants
                     .values()
                     .all(|variant| matches!(variant.value, VariantFormat::Unit));
 
                 if all_unit_variants {
-                    enum_class(w, name, variants, doc, lang, self)?;
+                    enum_class(w, name, visibility, variants, doc, lang, self)?;
                 } else {
-                    sealed_interface(w, name, &variant_list, doc, lang, self)?;
+                    sealed_interface(w, name, visibility, variants, doc, lang, self)?;
                 }
             }
         }
@@ -256,15 +307,25 @@ impl Emitter<Kotlin> for Container<'_> {
 impl Emitter<Kotlin> for Named<Format> {
     fn write<W: IndentWrite>(&self, w: &mut W, lang: &Kotlin) -> Result<()> {
         self.doc.write(w, lang)?;
+        if let Some(annotation) = deprecated_annotation(self.metadata.deprecated.as_ref()) {
+            writeln!(w, "{annotation}")?;
+        }
 
         let name = &self.name.to_lower_camel_case();
         write!(w, "val {name}: ")?;
 
-        self.value.write(w, lang)?;
-
-        // Add = null default only for top-level Option types
-        if matches!(self.value, Format::Option(_)) {
-            write!(w, " = null")?;
+        if let Some(type_override) = self.metadata.type_override(Language::Kotlin) {
+            write!(w, "{}", type_override.ty)?;
+        } else {
+            self.value.write(w, lang)?;
+
+            let default = self.metadata.default.as_ref();
+            match default.and_then(|default| default_value(default, &self.value)) {
+                Some(value) => write!(w, " = {value}")?,
+                // Add = null default only for top-level Option types
+                None if matches!(self.value, Format::Option(_)) => write!(w, " = null")?,
+                None => {}
+            }
         }
 
         writeln!(w, ",")
@@ -285,9 +346,10 @@ impl Emitter<Kotlin> for Doc {
 /// `sealed interface` or an `enum class`, since the Kotlin syntax differs.
 #[derive(Clone)]
 pub enum VariantContext {
-    /// Variant inside a `sealed interface` — carries the interface name and
-    /// the variant's zero-based index (used as the bincode discriminant).
-    SealedInterface(String, usize),
+    /// Variant inside a `sealed interface` — carries the interface name, its
+    /// type parameters and the variant's zero-based index (used as the
+    /// bincode discriminant).
+    SealedInterface(String, Vec<String>, usize),
     /// Variant inside an `enum class` (all-unit variants only).
     EnumClass,
 }
@@ -300,24 +362,50 @@ impl Emitter<Kotlin> for (&Named<VariantFormat>, &VariantContext) {
                 name,
                 doc,
                 value: format,
+                metadata,
             },
             context,
         ) = self;
+        // Variants declared as a `data class` or `data object` take their
+        // deprecation from this metadata (see `write_annotations`).
+        let declaration = ContainerMetadata {
+            deprecated: metadata.deprecated.clone(),
+            ..ContainerMetadata::default()
+        };
 
         match (&format, context) {
             (VariantFormat::Variable(_), _) => {
                 unreachable!("placeholders should not get this far")
             }
-            (VariantFormat::Unit, VariantContext::SealedInterface(interface_name, index)) => {
-                data_object(w, name, Some(interface_name), doc, lang, Some(*index))?;
+            (
+                VariantFormat::Unit,
+                VariantContext::SealedInterface(interface_name, type_params, index),
+            ) => {
+                let interface = Interface {
+                    name: interface_name,
+                    type_params,
+                    variant_index: *index,
+                };
+                data_object(
+                    w,
+                    name,
+                    Visibility::Public,
+                    &declaration,
+                    Some(&interface),
+                    doc,
+                    lang,
+                )?;
             }
             (VariantFormat::Unit, VariantContext::EnumClass) => {
                 doc.write(w, lang)?;
-                let name_upper = name.to_uppercase();
-                let prefix_parts: Vec<String> = lang
-                    .plugins()
-                    .iter()
-                    .flat_map(|p| p.enum_variant_annotations(name))
+                let name_upper = enum_entry_name(name);
+                let prefix_parts: Vec<String> = deprecated_annotation(metadata.deprecated.as_ref())
+                    .into_iter()
+                    .chain(
+                        lang.plugins()
+                            .iter()
+                            .flat_map(|p| p.enum_variant_annotations(name)),
+                    )
                     .collect();
                 if prefix_parts.is_empty() {
                     write!(w, "{name_upper}")?;
@@ -328,16 +416,25 @@ impl Emitter<Kotlin> for (&Named<VariantFormat>, &VariantContext) {
             }
             (
                 VariantFormat::NewType(inner),
-                VariantContext::SealedInterface(interface_name, index),
+                VariantContext::SealedInterface(interface_name, type_params, index),
             ) => {
+                let fields: &[Named<Format>] = &[Named::new(inner, "value".to_string())];
+                let interface = Interface {
+                    name: interface_name,
+                    type_params,
+                    variant_index: *index,
+                };
+                let type_params = used_type_params(type_params, fields);
                 data_class(
                     w,
                     name,
-                    Some(interface_name),
-                    &[Named::new(inner, "value".to_string())],
+                    Visibility::Public,
+                    &declaration,
+                    &type_params,
+                    Some(&interface),
+                    fields,
                     doc,
                     lang,
-                    Some(*index),
                 )?;
             }
             (VariantFormat::NewType(_format), VariantContext::EnumClass) => {
@@ -345,16 +442,25 @@ impl Emitter<Kotlin> for (&Named<VariantFormat>, &VariantContext) {
             }
             (
                 VariantFormat::Tuple(formats),
-                VariantContext::SealedInterface(interface_name, index),
+                VariantContext::SealedInterface(interface_name, type_params, index),
             ) => {
+                let fields = &named(formats);
+                let interface = Interface {
+                    name: interface_name,
+                    type_params,
+                    variant_index: *index,
+                };
+                let type_params = used_type_params(type_params, fields);
                 data_class(
                     w,
                     name,
-                    Some(interface_name),
-                    &named(formats),
+                    Visibility::Public,
+                    &declaration,
+                    &type_params,
+                    Some(&interface),
+                    fields,
                     doc,
                     lang,
-                    Some(*index),
                 )?;
             }
             (VariantFormat::Tuple(_formats), VariantContext::EnumClass) => {
@@ -362,16 +468,24 @@ impl Emitter<Kotlin> for (&Named<VariantFormat>, &VariantContext) {
             }
             (
                 VariantFormat::Struct(fields),
-                VariantContext::SealedInterface(interface_name, index),
+                VariantContext::SealedInterface(interface_name, type_params, index),
             ) => {
+                let interface = Interface {
+                    name: interface_name,
+                    type_params,
+                    variant_index: *index,
+                };
+                let type_params = used_type_params(type_params, fields);
                 data_class(
                     w,
                     name,
-                    Some(interface_name),
+                    Visibility::Public,
+                    &declaration,
+                    &type_params,
+                    Some(&interface),
                     fields,
                     doc,
                     lang,
-                    Some(*index),
                 )?;
             }
             (VariantFormat::Struct(_fields), VariantContext::EnumClass) => {
@@ -394,6 +508,17 @@ impl Emitter<Kotlin> for Format {
                     ty = qualified_type_name.format(ToString::to_string, ".")
                 )
             }
+            Self::Generic { name, args } => {
+                write!(w, "{ty}<", ty = name.format(ToString::to_string, "."))?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(w, ", ")?;
+                    }
+                    arg.write(w, lang)?;
+                }
+                write!(w, ">")
+            }
+            Self::TypeParameter(name) => write!(w, "{name}"),
             Self::Unit => write!(w, "Unit"),
             Self::Bool => write!(w, "Boolean"),
             Self::I8 => write!(w, "Byte"),
@@ -410,6 +535,10 @@ impl Emitter<Kotlin> for Format {
             Self::Char | Self::Str => write!(w, "String"),
             Self::Bytes => write!(w, "Bytes"),
             Self::Uuid => write!(w, "UUID"),
+            Self::Timestamp => write!(w, "Instant"),
+            Self::Date => write!(w, "LocalDate"),
+            Self::Time => write!(w, "LocalTime"),
+            Self::Duration => write!(w, "Duration"),
 
             Self::Option(format) => {
                 format.write(w, lang)?;
@@ -478,6 +607,38 @@ impl Emitter<Kotlin> for Format {
     }
 }
 
+/// The `sealed interface` implemented by a variant `data object` or
+/// `data class`.
+struct Interface<'a> {
+    name: &'a str,
+    type_params: &'a [String],
+    variant_index: usize,
+}
+
+impl Interface<'_> {
+    /// Writes the interface as a supertype of a variant that declares
+    /// `type_params`. Type parameters the variant doesn't use are `Nothing`
+    /// (the interface's type parameters are covariant).
+    fn write_supertype<W: IndentWrite>(&self, w: &mut W, type_params: &[String]) -> Result<()> {
+        write!(w, "{}", self.name)?;
+        if !self.type_params.is_empty() {
+            let args = self
+                .type_params
+                .iter()
+                .map(|param| {
+                    if type_params.contains(param) {
+                        param.as_str()
+                    } else {
+                        "Nothing"
+                    }
+                })
+                .collect::<Vec<_>>();
+            write!(w, "<{}>", args.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
 /// Emits a Kotlin `data object` — used for unit structs and unit variants.
 ///
 /// When `interface` is `Some`, the object implements it (i.e. it is a variant
@@ -486,40 +647,46 @@ impl Emitter<Kotlin> for Format {
 fn data_object<W: IndentWrite>(
     w: &mut W,
     name: &str,
-    interface: Option<&str>,
+    visibility: Visibility,
+    metadata: &ContainerMetadata,
+    interface: Option<&Interface>,
     doc: &Doc,
     lang: &Kotlin,
-    variant_index: Option<usize>,
 ) -> Result<()> {
     doc.write(w, lang)?;
 
-    write_plugin_annotations(w, name, lang)?;
+    write_annotations(w, name, metadata, lang)?;
 
+    write_visibility(w, visibility)?;
     write!(w, "data object {name}")?;
 
     if let Some(interface) = interface {
-        write!(w, ": {interface}")?;
+        write!(w, ": ")?;
+        interface.write_supertype(w, &[])?;
+    } else {
+        write_conformances(w, name, metadata, lang)?;
     }
 
     // Plugin type body
     {
         let temp_name = QualifiedTypeName::root(name.to_string());
-        let temp_format = ContainerFormat::UnitStruct(Doc::default());
+        let temp_format = ContainerFormat::UnitStruct(Doc::default(), ContainerMetadata::default());
         let temp_container = Container {
             name: &temp_name,
             format: &temp_format,
         };
         let variant_format = VariantFormat::Unit;
-        let ctx = if let (Some(parent_name), Some(index)) = (interface, variant_index) {
+        let ctx = if let Some(interface) = interface {
             EmitContext::for_variant(
                 &temp_container,
                 &lang.config,
                 VariantInfo {
                     name,
-                    index,
+                    index: interface.variant_index,
                     format: &variant_format,
                     fields: &[],
-                    parent_name,
+                    parent_name: interface.name,
+                    parent_type_params: interface.type_params,
                 },
             )
         } else {
@@ -534,23 +701,30 @@ fn data_object<W: IndentWrite>(
 /// Emits a Kotlin `data class` — used for structs (with fields), newtype
 /// structs, tuple structs, and non-unit sealed-interface variants.
 ///
-/// When `interface` is `Some`, the class implements it. Encoding-specific
-/// body code (e.g. serialize / deserialize methods) is delegated to plugins
-/// via the `type_body` hook.
+/// The class declares `type_params` (e.g. `data class Page<T>`). When
+/// `interface` is `Some`, the class implements it. Encoding-specific body
+/// code (e.g. serialize / deserialize methods) is delegated to plugins via
+/// the `type_body` hook.
+#[allow(clippy::too_many_arguments)]
 fn data_class<W: IndentWrite>(
     w: &mut W,
     name: &str,
-    interface: Option<&str>,
+    visibility: Visibility,
+    metadata: &ContainerMetadata,
+    type_params: &[String],
+    interface: Option<&Interface>,
     fields: &[Named<Format>],
     doc: &Doc,
     lang: &Kotlin,
-    variant_index: Option<usize>,
 ) -> Result<()> {
     doc.write(w, lang)?;
 
-    write_plugin_annotations(w, name, lang)?;
+    write_annotations(w, name, metadata, lang)?;
 
-    writeln!(w, "data class {name}(")?;
+    write_visibility(w, visibility)?;
+    write!(w, "data class {name}")?;
+    write_type_params(w, type_params, "")?;
+    writeln!(w, "(")?;
 
     w.indent();
     for field in fields {
@@ -561,28 +735,39 @@ fn data_class<W: IndentWrite>(
     write!(w, ")")?;
 
     if let Some(interface) = interface {
-        write!(w, " : {interface}")?;
+        write!(w, " : ")?;
+        interface.write_supertype(w, type_params)?;
+    } else {
+        write_conformances(w, name, metadata, lang)?;
     }
 
     // Plugin type body
     {
         let temp_name = QualifiedTypeName::root(name.to_string());
-        let temp_format = ContainerFormat::Struct(fields.to_vec(), Doc::default());
+        let temp_format = ContainerFormat::Struct(
+            fields.to_vec(),
+            Doc::default(),
+            ContainerMetadata {
+                type_params: type_params.to_vec(),
+                ..ContainerMetadata::default()
+            },
+        );
         let temp_container = Container {
             name: &temp_name,
             format: &temp_format,
         };
         let variant_format = VariantFormat::Struct(fields.to_vec());
-        let ctx = if let (Some(parent_name), Some(index)) = (interface, variant_index) {
+        let ctx = if let Some(interface) = interface {
             EmitContext::for_variant(
                 &temp_container,
                 &lang.config,
                 VariantInfo {
                     name,
-                    index,
+                    index: interface.variant_index,
                     format: &variant_format,
                     fields,
-                    parent_name,
+                    parent_name: interface.name,
+                    parent_type_params: interface.type_params,
                 },
             )
         } else {
@@ -594,6 +779,79 @@ fn data_class<W: IndentWrite>(
     Ok(())
 }
 
+/// Emits the `typealias` declared by a serialization proxy. Uses of the type have
+/// already been replaced by the proxy's format, so it needs no plugin code.
+fn type_alias<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    visibility: Visibility,
+    metadata: &ContainerMetadata,
+    format: &Format,
+    doc: &Doc,
+    lang: &Kotlin,
+) -> Result<()> {
+    doc.write(w, lang)?;
+    if let Some(annotation) = deprecated_annotation(metadata.deprecated.as_ref()) {
+        writeln!(w, "{annotation}")?;
+    }
+    write_visibility(w, visibility)?;
+    write!(w, "typealias {name} = ")?;
+    format.write(w, lang)?;
+    writeln!(w)
+}
+
+/// Emits a branded newtype as a `@JvmInline value class`, which wraps its single
+/// `value` field without allocating and can't be passed where the inner type (or
+/// another branded type) is expected.
+#[allow(clippy::too_many_arguments)]
+fn value_class<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    visibility: Visibility,
+    metadata: &ContainerMetadata,
+    type_params: &[String],
+    field: &Named<Format>,
+    doc: &Doc,
+    lang: &Kotlin,
+) -> Result<()> {
+    doc.write(w, lang)?;
+
+    write_annotations(w, name, metadata, lang)?;
+
+    writeln!(w, "@JvmInline")?;
+    write_visibility(w, visibility)?;
+    write!(w, "value class {name}")?;
+    write_type_params(w, type_params, "")?;
+    writeln!(w, "(")?;
+
+    w.indent();
+    field.write(w, lang)?;
+    w.unindent();
+
+    write!(w, ")")?;
+    write_conformances(w, name, metadata, lang)?;
+
+    let temp_name = QualifiedTypeName::root(name.to_string());
+    let temp_format = ContainerFormat::Struct(
+        vec![field.clone()],
+        Doc::default(),
+        ContainerMetadata {
+            type_params: type_params.to_vec(),
+            branded: true,
+            ..ContainerMetadata::default()
+        },
+    );
+    let temp_container = Container {
+        name: &temp_name,
+        format: &temp_format,
+    };
+    write_plugin_body(
+        w,
+        lang,
+        &EmitContext::top_level(&temp_container, &lang.config),
+    )
+}
+
 /// Emits a Kotlin `enum class` — used when all variants are unit variants.
 ///
 /// Encoding-specific annotations (e.g. `@SerialName` for JSON) are handled
@@ -601,6 +859,7 @@ fn data_class<W: IndentWrite>(
 fn enum_class<W: IndentWrite>(
     w: &mut W,
     name: &str,
+    visibility: Visibility,
     variants: &BTreeMap<u32, Named<VariantFormat>>,
     doc: &Doc,
     lang: &Kotlin,
@@ -608,9 +867,13 @@ fn enum_class<W: IndentWrite>(
 ) -> Result<()> {
     doc.write(w, lang)?;
 
-    write_plugin_annotations(w, name, lang)?;
+    let metadata = container.format.metadata();
+    write_enum_annotations(w, container, lang)?;
 
-    write!(w, "enum class {name} ")?;
+    write_visibility(w, visibility)?;
+    write!(w, "enum class {name}")?;
+    write_conformances(w, name, metadata, lang)?;
+    write!(w, " ")?;
     let mut w = w.block(Newlines::BOTH)?;
 
     for (i, variant) in variants {
@@ -642,16 +905,23 @@ fn enum_class<W: IndentWrite>(
 fn sealed_interface<W: IndentWrite>(
     w: &mut W,
     name: &str,
-    variants: &[Named<VariantFormat>],
+    visibility: Visibility,
+    variants: &BTreeMap<u32, Named<VariantFormat>>,
     doc: &Doc,
     lang: &Kotlin,
     container: &Container,
 ) -> Result<()> {
     doc.write(w, lang)?;
 
-    write_plugin_annotations(w, name, lang)?;
+    let metadata = container.format.metadata();
+    write_enum_annotations(w, container, lang)?;
 
-    write!(w, "sealed interface {name} ")?;
+    let type_params = container.format.type_params();
+    write_visibility(w, visibility)?;
+    write!(w, "sealed interface {name}")?;
+    write_type_params(w, type_params, "out ")?;
+    write_conformances(w, name, metadata, lang)?;
+    write!(w, " ")?;
     let mut w = w.block(Newlines::BOTH)?;
 
     // Plugin type body preamble (before variants)
@@ -662,11 +932,15 @@ fn sealed_interface<W: IndentWrite>(
         }
     }
 
-    for (index, variant) in variants.iter().enumerate() {
-        if index > 0 {
+    for (i, (index, variant)) in variants.iter().enumerate() {
+        if i > 0 {
             writeln!(w)?;
         }
-        let ctx = VariantContext::SealedInterface(name.to_string(), index);
+        let ctx = VariantContext::SealedInterface(
+            name.to_string(),
+            type_params.to_vec(),
+            *index as usize,
+        );
         (variant, &ctx).write(&mut w, lang)?;
     }
 
@@ -697,29 +971,170 @@ fn write_plugin_body<W: IndentWrite>(w: &mut W, lang: &Kotlin, ctx: &EmitContext
     Ok(())
 }
 
-/// Emits plugin type annotations (e.g. `@Serializable`, `@SerialName`) for a
-/// named type. Creates a temporary [`Container`] so that the plugin
-/// [`EmitContext`] can be constructed without threading the real container
-/// through every helper function.
-fn write_plugin_annotations<W: IndentWrite>(w: &mut W, name: &str, lang: &Kotlin) -> Result<()> {
-    if lang.plugins().is_empty() {
+/// Emits the annotations of a named type: `@Deprecated` if it is deprecated, those of the
+/// plugins (e.g. `@Serializable`, `@SerialName`), and any declared with `fg::annotate` on a
+/// top-level type.
+fn write_annotations<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    metadata: &ContainerMetadata,
+    lang: &Kotlin,
+) -> Result<()> {
+    if let Some(annotation) = deprecated_annotation(metadata.deprecated.as_ref()) {
+        writeln!(w, "{annotation}")?;
+    }
+    for annotation in with_type_context(name, metadata, lang, |ctx| {
+        type_annotations(lang.plugins(), ctx, Language::Kotlin)
+    }) {
+        writeln!(w, "{annotation}")?;
+    }
+    Ok(())
+}
+
+/// Like [`write_annotations`], but for an enum, whose plugins get the real container so they
+/// can take its variants into account (e.g. the JSON serializer of an enum with a catch-all).
+fn write_enum_annotations<W: IndentWrite>(
+    w: &mut W,
+    container: &Container,
+    lang: &Kotlin,
+) -> Result<()> {
+    let metadata = container.format.metadata();
+    if let Some(annotation) = deprecated_annotation(metadata.deprecated.as_ref()) {
+        writeln!(w, "{annotation}")?;
+    }
+    let ctx = EmitContext::top_level(container, &lang.config);
+    for annotation in type_annotations(lang.plugins(), &ctx, Language::Kotlin) {
+        writeln!(w, "{annotation}")?;
+    }
+    Ok(())
+}
+
+/// The `@Deprecated` annotation of a deprecated declaration, if it is one. Kotlin requires a
+/// message, which is left empty when the deprecation has none.
+fn deprecated_annotation(deprecated: Option<&Deprecation>) -> Option<String> {
+    let message = deprecated?.message().unwrap_or_default();
+    Some(format!(
+        "@Deprecated(message = {})",
+        string_literal(&message)
+    ))
+}
+
+/// The Kotlin expression of a field's default value, or `None` if it doesn't suit the field's
+/// type (e.g. because a serialization proxy changed it).
+fn default_value(default: &DefaultValue, format: &Format) -> Option<String> {
+    Some(match (default, format) {
+        (DefaultValue::Empty, Format::Option(_)) => "null".to_string(),
+        (DefaultValue::Empty, Format::Seq(_)) => "listOf()".to_string(),
+        (DefaultValue::Empty, Format::Set(_)) => "setOf()".to_string(),
+        (DefaultValue::Empty, Format::Map { .. }) => "mapOf()".to_string(),
+        (DefaultValue::Empty, Format::Bytes) => "Bytes.empty()".to_string(),
+        (DefaultValue::Bool(value), Format::Bool) => value.to_string(),
+        (DefaultValue::Integer(value), Format::I8 | Format::I16 | Format::I32) => value.clone(),
+        // The literal `9223372036854775808L` is out of range, even when negated.
+        (DefaultValue::Integer(value), Format::I64) if value.parse() == Ok(i64::MIN) => {
+            "Long.MIN_VALUE".to_string()
+        }
+        (DefaultValue::Integer(value), Format::I64) => format!("{value}L"),
+        (DefaultValue::Integer(value), Format::U8 | Format::U16 | Format::U32) => {
+            format!("{value}u")
+        }
+        (DefaultValue::Integer(value), Format::U64) => format!("{value}uL"),
+        (DefaultValue::Integer(value), Format::I128 | Format::U128) => {
+            format!(r#"BigInteger("{value}")"#)
+        }
+        (DefaultValue::Float(value), Format::F32) => format!("{value}f"),
+        (DefaultValue::Float(value), Format::F64) => value.clone(),
+        (DefaultValue::Char(value), Format::Char) => string_literal(&value.to_string()),
+        (DefaultValue::Str(value), Format::Str) => string_literal(value),
+        _ => return None,
+    })
+}
+
+/// A Kotlin string literal of `text`.
+fn string_literal(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('$', "\\$")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t");
+    format!("\"{escaped}\"")
+}
+
+/// Writes the ` : A, B` supertypes of a top-level type: the conformances of the plugins,
+/// followed by any declared with `fg::conforms`. Writes nothing if there are none.
+fn write_conformances<W: IndentWrite>(
+    w: &mut W,
+    name: &str,
+    metadata: &ContainerMetadata,
+    lang: &Kotlin,
+) -> Result<()> {
+    let conformances = with_type_context(name, metadata, lang, |ctx| {
+        type_conformances(lang.plugins(), ctx, Language::Kotlin)
+    });
+    if conformances.is_empty() {
         return Ok(());
     }
+    write!(w, " : {}", conformances.join(", "))
+}
+
+/// Calls `f` with the plugin [`EmitContext`] of a top-level type. Creates a temporary
+/// [`Container`] so that the context can be constructed without threading the real container
+/// through every helper function.
+fn with_type_context<T>(
+    name: &str,
+    metadata: &ContainerMetadata,
+    lang: &Kotlin,
+    f: impl FnOnce(&EmitContext) -> T,
+) -> T {
     let temp_name = QualifiedTypeName::root(name.to_string());
-    let temp_format = ContainerFormat::UnitStruct(Doc::default());
+    let temp_format = ContainerFormat::UnitStruct(Doc::default(), metadata.clone());
     let temp_container = Container {
         name: &temp_name,
         format: &temp_format,
     };
-    let ctx = EmitContext::top_level(&temp_container, &lang.config);
-    for plugin in lang.plugins() {
-        for annotation in plugin.type_annotations(&ctx) {
-            writeln!(w, "{annotation}")?;
-        }
+    f(&EmitContext::top_level(&temp_container, &lang.config))
+}
+
+/// Writes the `internal` modifier of a top-level declaration (`public` is Kotlin's default).
+fn write_visibility<W: IndentWrite>(w: &mut W, visibility: Visibility) -> Result<()> {
+    match visibility {
+        Visibility::Public => Ok(()),
+        Visibility::Internal => write!(w, "internal "),
+    }
+}
+
+/// Writes a type parameter list such as `<T, E>`, each parameter prefixed
+/// with `variance`, or nothing for a non-generic type.
+fn write_type_params<W: IndentWrite>(
+    w: &mut W,
+    type_params: &[String],
+    variance: &str,
+) -> Result<()> {
+    if !type_params.is_empty() {
+        let params = type_params
+            .iter()
+            .map(|param| format!("{variance}{param}"))
+            .collect::<Vec<_>>();
+        write!(w, "<{}>", params.join(", "))?;
     }
     Ok(())
 }
 
+/// The type parameters of a generic enum that a variant's fields use.
+fn used_type_params(type_params: &[String], fields: &[Named<Format>]) -> Vec<String> {
+    type_params
+        .iter()
+        .filter(|param| {
+            fields
+                .iter()
+                .any(|field| field.value.uses_type_parameter(param))
+        })
+        .cloned()
+        .collect()
+}
+
 fn named<Format: Clone>(formats: &[Format]) -> Vec<Named<Format>> {
     formats
         .iter()
@@ -728,6 +1143,11 @@ fn named<Format: Clone>(formats: &[Format]) -> Vec<Named<Format>> {
         .collect()
 }
 
+/// The name of the `enum class` entry of a unit variant, e.g. `GOOGLE_FONT` for `GoogleFont`.
+pub(crate) fn enum_entry_name(variant: &str) -> String {
+    variant.to_shouty_snake_case()
+}
+
 #[cfg(test)]
 mod tests;
 #[cfg(test)]
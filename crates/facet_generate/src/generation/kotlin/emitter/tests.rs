@@ -764,3 +764,74 @@ fn type_in_root_and_named_namespace() {
     )
     ");
 }
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as Kotlin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    data class Listing(
+        val names: Page<String>,
+        val ids: Page<UInt>,
+    )
+
+    data class Page<T>(
+        val items: List<T>,
+        val next: T? = null,
+        val total: UInt,
+    )
+    ");
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E, retry: bool },
+        Pending,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        first: Outcome<String, i32>,
+        second: Outcome<bool, String>,
+    }
+
+    let actual = emit!(Response as Kotlin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    sealed interface Outcome<out T, out E> {
+        data class Success<T>(
+            val value: T,
+        ) : Outcome<T, Nothing>
+
+        data class Failure<E>(
+            val error: E,
+            val retry: Boolean,
+        ) : Outcome<Nothing, E>
+
+        data object Pending: Outcome<Nothing, Nothing>
+    }
+
+    data class Response(
+        val first: Outcome<String, Int>,
+        val second: Outcome<Boolean, String>,
+    )
+    ");
+}
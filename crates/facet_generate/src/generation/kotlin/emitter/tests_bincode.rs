@@ -157,6 +157,108 @@ fn newtype_struct() {
     "#);
 }
 
+#[test]
+fn branded_newtype_struct() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    #[derive(Facet)]
+    struct User {
+        id: UserId,
+        manager: Option<UserId>,
+    }
+
+    let actual = emit!(User as Kotlin with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    data class User(
+        val id: UserId,
+        val manager: UserId? = null,
+    ) {
+        fun serialize(serializer: Serializer) {
+            serializer.increase_container_depth()
+            id.serialize(serializer)
+            manager.serializeOptionOf(serializer) {
+                it.serialize(serializer)
+            }
+            serializer.decrease_container_depth()
+        }
+
+        fun bincodeSerialize(): ByteArray {
+            val serializer = BincodeSerializer()
+            serialize(serializer)
+            return serializer.get_bytes()
+        }
+
+        companion object {
+            fun deserialize(deserializer: Deserializer): User {
+                deserializer.increase_container_depth()
+                val id = UserId.deserialize(deserializer)
+                val manager =
+                    deserializer.deserializeOptionOf {
+                        UserId.deserialize(deserializer)
+                    }
+                deserializer.decrease_container_depth()
+                return User(id, manager)
+            }
+
+            @Throws(DeserializationError::class)
+            fun bincodeDeserialize(input: ByteArray?): User {
+                if (input == null) {
+                    throw DeserializationError("Cannot deserialize null array")
+                }
+                val deserializer = BincodeDeserializer(input)
+                val value = deserialize(deserializer)
+                if (deserializer.get_buffer_offset() < input.size) {
+                    throw DeserializationError("Some input bytes were not read")
+                }
+                return value
+            }
+        }
+    }
+
+    @JvmInline
+    value class UserId(
+        val value: String,
+    ) {
+        fun serialize(serializer: Serializer) {
+            serializer.increase_container_depth()
+            serializer.serialize_str(value)
+            serializer.decrease_container_depth()
+        }
+
+        fun bincodeSerialize(): ByteArray {
+            val serializer = BincodeSerializer()
+            serialize(serializer)
+            return serializer.get_bytes()
+        }
+
+        companion object {
+            fun deserialize(deserializer: Deserializer): UserId {
+                deserializer.increase_container_depth()
+                val value = deserializer.deserialize_str()
+                deserializer.decrease_container_depth()
+                return UserId(value)
+            }
+
+            @Throws(DeserializationError::class)
+            fun bincodeDeserialize(input: ByteArray?): UserId {
+                if (input == null) {
+                    throw DeserializationError("Cannot deserialize null array")
+                }
+                val deserializer = BincodeDeserializer(input)
+                val value = deserialize(deserializer)
+                if (deserializer.get_buffer_offset() < input.size) {
+                    throw DeserializationError("Some input bytes were not read")
+                }
+                return value
+            }
+        }
+    }
+    "#);
+}
+
 #[test]
 fn tuple_struct() {
     /// line 1
@@ -2550,3 +2652,840 @@ fn struct_with_bytes_field_and_slice() {
     }
     "#);
 }
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as Kotlin with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    data class Listing(
+        val names: Page<String>,
+        val ids: Page<UInt>,
+    ) {
+        fun serialize(serializer: Serializer) {
+            serializer.increase_container_depth()
+            names.serialize(serializer, { level1 ->
+                serializer.serialize_str(level1)
+            })
+            ids.serialize(serializer, { level1 ->
+                serializer.serialize_u32(level1)
+            })
+            serializer.decrease_container_depth()
+        }
+
+        fun bincodeSerialize(): ByteArray {
+            val serializer = BincodeSerializer()
+            serialize(serializer)
+            return serializer.get_bytes()
+        }
+
+        companion object {
+            fun deserialize(deserializer: Deserializer): Listing {
+                deserializer.increase_container_depth()
+                val names = Page.deserialize(deserializer, {
+                    deserializer.deserialize_str()
+                })
+                val ids = Page.deserialize(deserializer, {
+                    deserializer.deserialize_u32()
+                })
+                deserializer.decrease_container_depth()
+                return Listing(names, ids)
+            }
+
+            @Throws(DeserializationError::class)
+            fun bincodeDeserialize(input: ByteArray?): Listing {
+                if (input == null) {
+                    throw DeserializationError("Cannot deserialize null array")
+                }
+                val deserializer = BincodeDeserializer(input)
+                val value = deserialize(deserializer)
+                if (deserializer.get_buffer_offset() < input.size) {
+                    throw DeserializationError("Some input bytes were not read")
+                }
+                return value
+            }
+        }
+    }
+
+    data class Page<T>(
+        val items: List<T>,
+        val next: T? = null,
+        val total: UInt,
+    ) {
+        fun serialize(serializer: Serializer, serializeT: Serializer.(T) -> Unit) {
+            serializer.increase_container_depth()
+            items.serialize(serializer) {
+                serializer.serializeT(it)
+            }
+            next.serializeOptionOf(serializer) {
+                serializer.serializeT(it)
+            }
+            serializer.serialize_u32(total)
+            serializer.decrease_container_depth()
+        }
+
+        fun bincodeSerialize(serializeT: Serializer.(T) -> Unit): ByteArray {
+            val serializer = BincodeSerializer()
+            serialize(serializer, serializeT)
+            return serializer.get_bytes()
+        }
+
+        companion object {
+            fun <T> deserialize(deserializer: Deserializer, deserializeT: (Deserializer) -> T): Page<T> {
+                deserializer.increase_container_depth()
+                val items =
+                    deserializer.deserializeListOf {
+                        deserializeT(deserializer)
+                    }
+                val next =
+                    deserializer.deserializeOptionOf {
+                        deserializeT(deserializer)
+                    }
+                val total = deserializer.deserialize_u32()
+                deserializer.decrease_container_depth()
+                return Page(items, next, total)
+            }
+
+            @Throws(DeserializationError::class)
+            fun <T> bincodeDeserialize(input: ByteArray?, deserializeT: (Deserializer) -> T): Page<T> {
+                if (input == null) {
+                    throw DeserializationError("Cannot deserialize null array")
+                }
+                val deserializer = BincodeDeserializer(input)
+                val value = deserialize(deserializer, deserializeT)
+                if (deserializer.get_buffer_offset() < input.size) {
+                    throw DeserializationError("Some input bytes were not read")
+                }
+                return value
+            }
+        }
+    }
+    "#);
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E, retry: bool },
+        Pending,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        first: Outcome<String, i32>,
+        second: Outcome<bool, String>,
+    }
+
+    let actual = emit!(Response as Kotlin with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    sealed interface Outcome<out T, out E> {
+        fun serialize(serializer: Serializer, serializeT: Serializer.(T) -> Unit, serializeE: Serializer.(E) -> Unit)
+
+        fun bincodeSerialize(serializeT: Serializer.(T) -> Unit, serializeE: Serializer.(E) -> Unit): ByteArray {
+            val serializer = BincodeSerializer()
+            serialize(serializer, serializeT, serializeE)
+            return serializer.get_bytes()
+        }
+
+        data class Success<T>(
+            val value: T,
+        ) : Outcome<T, Nothing> {
+            override fun serialize(serializer: Serializer, serializeT: Serializer.(T) -> Unit, serializeE: Serializer.(Nothing) -> Unit) {
+                serializer.increase_container_depth()
+                serializer.serialize_variant_index(0)
+                serializer.serializeT(value)
+                serializer.decrease_container_depth()
+            }
+
+            companion object {
+                fun <T> deserialize(deserializer: Deserializer, deserializeT: (Deserializer) -> T): Success<T> {
+                    deserializer.increase_container_depth()
+                    val value = deserializeT(deserializer)
+                    deserializer.decrease_container_depth()
+                    return Success(value)
+                }
+            }
+        }
+
+        data class Failure<E>(
+            val error: E,
+            val retry: Boolean,
+        ) : Outcome<Nothing, E> {
+            override fun serialize(serializer: Serializer, serializeT: Serializer.(Nothing) -> Unit, serializeE: Serializer.(E) -> Unit) {
+                serializer.increase_container_depth()
+                serializer.serialize_variant_index(1)
+                serializer.serializeE(error)
+                serializer.serialize_bool(retry)
+                serializer.decrease_container_depth()
+            }
+
+            companion object {
+                fun <E> deserialize(deserializer: Deserializer, deserializeE: (Deserializer) -> E): Failure<E> {
+                    deserializer.increase_container_depth()
+                    val error = deserializeE(deserializer)
+                    val retry = deserializer.deserialize_bool()
+                    deserializer.decrease_container_depth()
+                    return Failure(error, retry)
+                }
+            }
+        }
+
+        data object Pending: Outcome<Nothing, Nothing> {
+            override fun serialize(serializer: Serializer, serializeT: Serializer.(Nothing) -> Unit, serializeE: Serializer.(Nothing) -> Unit) {
+                serializer.increase_container_depth()
+                serializer.serialize_variant_index(2)
+                serializer.decrease_container_depth()
+            }
+
+            fun deserialize(deserializer: Deserializer): Pending {
+                return Pending
+            }
+        }
+
+        companion object {
+            @Throws(DeserializationError::class)
+            fun <T, E> deserialize(deserializer: Deserializer, deserializeT: (Deserializer) -> T, deserializeE: (Deserializer) -> E): Outcome<T, E> {
+                val index = deserializer.deserialize_variant_index()
+                return when (index) {
+                    0 -> Success.deserialize(deserializer, deserializeT)
+                    1 -> Failure.deserialize(deserializer, deserializeE)
+                    2 -> Pending.deserialize(deserializer)
+                    else -> throw DeserializationError("Unknown variant index for Outcome: $index")
+                }
+            }
+
+            @Throws(DeserializationError::class)
+            fun <T, E> bincodeDeserialize(input: ByteArray?, deserializeT: (Deserializer) -> T, deserializeE: (Deserializer) -> E): Outcome<T, E> {
+                if (input == null) {
+                    throw DeserializationError("Cannot deserialize null array")
+                }
+                val deserializer = BincodeDeserializer(input)
+                val value = deserialize(deserializer, deserializeT, deserializeE)
+                if (deserializer.get_buffer_offset() < input.size) {
+                    throw DeserializationError("Some input bytes were not read")
+                }
+                return value
+            }
+        }
+    }
+
+    data class Response(
+        val first: Outcome<String, Int>,
+        val second: Outcome<Boolean, String>,
+    ) {
+        fun serialize(serializer: Serializer) {
+            serializer.increase_container_depth()
+            first.serialize(serializer, { level1 ->
+                serializer.serialize_str(level1)
+            }, { level1 ->
+                serializer.serialize_i32(level1)
+            })
+            second.serialize(serializer, { level1 ->
+                serializer.serialize_bool(level1)
+            }, { level1 ->
+                serializer.serialize_str(level1)
+            })
+            serializer.decrease_container_depth()
+        }
+
+        fun bincodeSerialize(): ByteArray {
+            val serializer = BincodeSerializer()
+            serialize(serializer)
+            return serializer.get_bytes()
+        }
+
+        companion object {
+            fun deserialize(deserializer: Deserializer): Response {
+                deserializer.increase_container_depth()
+                val first = Outcome.deserialize(deserializer, {
+                    deserializer.deserialize_str()
+                }, {
+                    deserializer.deserialize_i32()
+                })
+                val second = Outcome.deserialize(deserializer, {
+                    deserializer.deserialize_bool()
+                }, {
+                    deserializer.deserialize_str()
+                })
+                deserializer.decrease_container_depth()
+                return Response(first, second)
+            }
+
+            @Throws(DeserializationError::class)
+            fun bincodeDeserialize(input: ByteArray?): Response {
+                if (input == null) {
+                    throw DeserializationError("Cannot deserialize null array")
+                }
+                val deserializer = BincodeDeserializer(input)
+                val value = deserialize(deserializer)
+                if (deserializer.get_buffer_offset() < input.size) {
+                    throw DeserializationError("Some input bytes were not read")
+                }
+                return value
+            }
+        }
+    }
+    "#);
+}
+
+#[test]
+fn serialized_as_proxy() {
+    #[derive(Facet)]
+    #[facet(fg::serialized_as = "String")]
+    struct ItemId {
+        inner: i64,
+    }
+
+    #[derive(Facet)]
+    struct Item {
+        id: ItemId,
+        #[facet(fg::serialized_as = "(u32, u32)")]
+        version: String,
+    }
+
+    let actual = emit!(Item as Kotlin with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    data class Item(
+        val id: String,
+        val version: Pair<UInt, UInt>,
+    ) {
+        fun serialize(serializer: Serializer) {
+            serializer.increase_container_depth()
+            serializer.serialize_str(id)
+            serializer.serialize_u32(version.first)
+            serializer.serialize_u32(version.second)
+            serializer.decrease_container_depth()
+        }
+
+        fun bincodeSerialize(): ByteArray {
+            val serializer = BincodeSerializer()
+            serialize(serializer)
+            return serializer.get_bytes()
+        }
+
+        companion object {
+            fun deserialize(deserializer: Deserializer): Item {
+                deserializer.increase_container_depth()
+                val id = deserializer.deserialize_str()
+                val version = run {
+                    val first = deserializer.deserialize_u32()
+                    val second = deserializer.deserialize_u32()
+                    Pair(first, second)
+                }
+                deserializer.decrease_container_depth()
+                return Item(id, version)
+            }
+
+            @Throws(DeserializationError::class)
+            fun bincodeDeserialize(input: ByteArray?): Item {
+                if (input == null) {
+                    throw DeserializationError("Cannot deserialize null array")
+                }
+                val deserializer = BincodeDeserializer(input)
+                val value = deserialize(deserializer)
+                if (deserializer.get_buffer_offset() < input.size) {
+                    throw DeserializationError("Some input bytes were not read")
+                }
+                return value
+            }
+        }
+    }
+
+    typealias ItemId = String
+    "#);
+}
+
+#[test]
+fn type_override() {
+    #[derive(Facet)]
+    struct Price {
+        #[facet(fg::override(
+            lang = "kotlin",
+            ty = "java.math.BigDecimal",
+            serialize = "serializer.serialize_str({value}.toPlainString())",
+            deserialize = "java.math.BigDecimal(deserializer.deserialize_str())"
+        ))]
+        amount: String,
+        #[facet(fg::override(lang = "swift", ty = "CGFloat"))]
+        scale: f64,
+    }
+
+    let actual = emit!(Price as Kotlin with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    data class Price(
+        val amount: java.math.BigDecimal,
+        val scale: Double,
+    ) {
+        fun serialize(serializer: Serializer) {
+            serializer.increase_container_depth()
+            serializer.serialize_str(amount.toPlainString())
+            serializer.serialize_f64(scale)
+            serializer.decrease_container_depth()
+        }
+
+        fun bincodeSerialize(): ByteArray {
+            val serializer = BincodeSerializer()
+            serialize(serializer)
+            return serializer.get_bytes()
+        }
+
+        companion object {
+            fun deserialize(deserializer: Deserializer): Price {
+                deserializer.increase_container_depth()
+                val amount = java.math.BigDecimal(deserializer.deserialize_str())
+                val scale = deserializer.deserialize_f64()
+                deserializer.decrease_container_depth()
+                return Price(amount, scale)
+            }
+
+            @Throws(DeserializationError::class)
+            fun bincodeDeserialize(input: ByteArray?): Price {
+                if (input == null) {
+                    throw DeserializationError("Cannot deserialize null array")
+                }
+                val deserializer = BincodeDeserializer(input)
+                val value = deserialize(deserializer)
+                if (deserializer.get_buffer_offset() < input.size) {
+                    throw DeserializationError("Some input bytes were not read")
+                }
+                return value
+            }
+        }
+    }
+    "#);
+}
+
+#[test]
+fn deprecated_variant() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Circle(f64),
+        #[facet(fg::deprecated(since = "0.2.0", note = "Use `Circle` instead"))]
+        Dot,
+    }
+
+    let actual = emit!(Shape as Kotlin with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    sealed interface Shape {
+        fun serialize(serializer: Serializer)
+
+        fun bincodeSerialize(): ByteArray {
+            val serializer = BincodeSerializer()
+            serialize(serializer)
+            return serializer.get_bytes()
+        }
+
+        data class Circle(
+            val value: Double,
+        ) : Shape {
+            override fun serialize(serializer: Serializer) {
+                serializer.increase_container_depth()
+                serializer.serialize_variant_index(0)
+                serializer.serialize_f64(value)
+                serializer.decrease_container_depth()
+            }
+
+            companion object {
+                fun deserialize(deserializer: Deserializer): Circle {
+                    deserializer.increase_container_depth()
+                    val value = deserializer.deserialize_f64()
+                    deserializer.decrease_container_depth()
+                    return Circle(value)
+                }
+            }
+        }
+
+        @Deprecated(message = "Use `Circle` instead (since 0.2.0)")
+        data object Dot: Shape {
+            override fun serialize(serializer: Serializer) {
+                serializer.increase_container_depth()
+                serializer.serialize_variant_index(1)
+                serializer.decrease_container_depth()
+            }
+
+            fun deserialize(deserializer: Deserializer): Dot {
+                return Dot
+            }
+        }
+
+        companion object {
+            @Throws(DeserializationError::class)
+            fun deserialize(deserializer: Deserializer): Shape {
+                val index = deserializer.deserialize_variant_index()
+                return when (index) {
+                    0 -> Circle.deserialize(deserializer)
+                    1 -> Dot.deserialize(deserializer)
+                    else -> throw DeserializationError("Unknown variant index for Shape: $index")
+                }
+            }
+
+            @Throws(DeserializationError::class)
+            fun bincodeDeserialize(input: ByteArray?): Shape {
+                if (input == null) {
+                    throw DeserializationError("Cannot deserialize null array")
+                }
+                val deserializer = BincodeDeserializer(input)
+                val value = deserialize(deserializer)
+                if (deserializer.get_buffer_offset() < input.size) {
+                    throw DeserializationError("Some input bytes were not read")
+                }
+                return value
+            }
+        }
+    }
+    "#);
+}
+
+#[test]
+fn enum_with_pinned_indices() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Status {
+        Active,
+        #[facet(fg::index = 3)]
+        Suspended(String),
+        Closed,
+    }
+
+    let actual = emit!(Status as Kotlin with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    sealed interface Status {
+        fun serialize(serializer: Serializer)
+
+        fun bincodeSerialize(): ByteArray {
+            val serializer = BincodeSerializer()
+            serialize(serializer)
+            return serializer.get_bytes()
+        }
+
+        data object Active: Status {
+            override fun serialize(serializer: Serializer) {
+                serializer.increase_container_depth()
+                serializer.serialize_variant_index(0)
+                serializer.decrease_container_depth()
+            }
+
+            fun deserialize(deserializer: Deserializer): Active {
+                return Active
+            }
+        }
+
+        data class Suspended(
+            val value: String,
+        ) : Status {
+            override fun serialize(serializer: Serializer) {
+                serializer.increase_container_depth()
+                serializer.serialize_variant_index(3)
+                serializer.serialize_str(value)
+                serializer.decrease_container_depth()
+            }
+
+            companion object {
+                fun deserialize(deserializer: Deserializer): Suspended {
+                    deserializer.increase_container_depth()
+                    val value = deserializer.deserialize_str()
+                    deserializer.decrease_container_depth()
+                    return Suspended(value)
+                }
+            }
+        }
+
+        data object Closed: Status {
+            override fun serialize(serializer: Serializer) {
+                serializer.increase_container_depth()
+                serializer.serialize_variant_index(4)
+                serializer.decrease_container_depth()
+            }
+
+            fun deserialize(deserializer: Deserializer): Closed {
+                return Closed
+            }
+        }
+
+        companion object {
+            @Throws(DeserializationError::class)
+            fun deserialize(deserializer: Deserializer): Status {
+                val index = deserializer.deserialize_variant_index()
+                return when (index) {
+                    0 -> Active.deserialize(deserializer)
+                    3 -> Suspended.deserialize(deserializer)
+                    4 -> Closed.deserialize(deserializer)
+                    else -> throw DeserializationError("Unknown variant index for Status: $index")
+                }
+            }
+
+            @Throws(DeserializationError::class)
+            fun bincodeDeserialize(input: ByteArray?): Status {
+                if (input == null) {
+                    throw DeserializationError("Cannot deserialize null array")
+                }
+                val deserializer = BincodeDeserializer(input)
+                val value = deserialize(deserializer)
+                if (deserializer.get_buffer_offset() < input.size) {
+                    throw DeserializationError("Some input bytes were not read")
+                }
+                return value
+            }
+        }
+    }
+    "#);
+}
+
+#[test]
+fn enum_with_other_variant() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Created(String),
+        Deleted {
+            id: u64,
+        },
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Event as Kotlin with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    sealed interface Event {
+        fun serialize(serializer: Serializer)
+
+        fun bincodeSerialize(): ByteArray {
+            val serializer = BincodeSerializer()
+            serialize(serializer)
+            return serializer.get_bytes()
+        }
+
+        data class Created(
+            val value: String,
+        ) : Event {
+            override fun serialize(serializer: Serializer) {
+                serializer.increase_container_depth()
+                serializer.serialize_variant_index(0)
+                serializer.serialize_str(value)
+                serializer.decrease_container_depth()
+            }
+
+            companion object {
+                fun deserialize(deserializer: Deserializer): Created {
+                    deserializer.increase_container_depth()
+                    val value = deserializer.deserialize_str()
+                    deserializer.decrease_container_depth()
+                    return Created(value)
+                }
+            }
+        }
+
+        data class Deleted(
+            val id: ULong,
+        ) : Event {
+            override fun serialize(serializer: Serializer) {
+                serializer.increase_container_depth()
+                serializer.serialize_variant_index(1)
+                serializer.serialize_u64(id)
+                serializer.decrease_container_depth()
+            }
+
+            companion object {
+                fun deserialize(deserializer: Deserializer): Deleted {
+                    deserializer.increase_container_depth()
+                    val id = deserializer.deserialize_u64()
+                    deserializer.decrease_container_depth()
+                    return Deleted(id)
+                }
+            }
+        }
+
+        data object Unknown: Event {
+            override fun serialize(serializer: Serializer) {
+                serializer.increase_container_depth()
+                serializer.serialize_variant_index(2)
+                serializer.decrease_container_depth()
+            }
+
+            fun deserialize(deserializer: Deserializer): Unknown {
+                return Unknown
+            }
+        }
+
+        companion object {
+            @Throws(DeserializationError::class)
+            fun deserialize(deserializer: Deserializer): Event {
+                val index = deserializer.deserialize_variant_index()
+                return when (index) {
+                    0 -> Created.deserialize(deserializer)
+                    1 -> Deleted.deserialize(deserializer)
+                    2 -> Unknown.deserialize(deserializer)
+                    else -> Unknown
+                }
+            }
+
+            @Throws(DeserializationError::class)
+            fun bincodeDeserialize(input: ByteArray?): Event {
+                if (input == null) {
+                    throw DeserializationError("Cannot deserialize null array")
+                }
+                val deserializer = BincodeDeserializer(input)
+                val value = deserialize(deserializer)
+                if (deserializer.get_buffer_offset() < input.size) {
+                    throw DeserializationError("Some input bytes were not read")
+                }
+                return value
+            }
+        }
+    }
+    "#);
+}
+
+#[test]
+fn unit_enum_with_pinned_indices() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Level {
+        Low,
+        #[facet(fg::index = 5)]
+        High,
+    }
+
+    let actual = emit!(Level as Kotlin with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    enum class Level {
+        LOW,
+        HIGH;
+
+        fun serialize(serializer: Serializer) {
+            serializer.increase_container_depth()
+            val index = when (this) {
+                LOW -> 0
+                HIGH -> 5
+            }
+            serializer.serialize_variant_index(index)
+            serializer.decrease_container_depth()
+        }
+
+        fun bincodeSerialize(): ByteArray {
+            val serializer = BincodeSerializer()
+            serialize(serializer)
+            return serializer.get_bytes()
+        }
+
+        companion object {
+            @Throws(DeserializationError::class)
+            fun deserialize(deserializer: Deserializer): Level {
+                deserializer.increase_container_depth()
+                val index = deserializer.deserialize_variant_index()
+                deserializer.decrease_container_depth()
+                return when (index) {
+                    0 -> LOW
+                    5 -> HIGH
+                    else -> throw DeserializationError("Unknown variant index for Level: $index")
+                }
+            }
+
+            @Throws(DeserializationError::class)
+            fun bincodeDeserialize(input: ByteArray?): Level {
+                if (input == null) {
+                    throw DeserializationError("Cannot deserialize null array")
+                }
+                val deserializer = BincodeDeserializer(input)
+                val value = deserialize(deserializer)
+                if (deserializer.get_buffer_offset() < input.size) {
+                    throw DeserializationError("Some input bytes were not read")
+                }
+                return value
+            }
+        }
+    }
+    "#);
+}
+
+#[test]
+fn unit_enum_with_other_variant() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Source {
+        Embedded,
+        Custom,
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Source as Kotlin with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    enum class Source {
+        EMBEDDED,
+        CUSTOM,
+        UNKNOWN;
+
+        fun serialize(serializer: Serializer) {
+            serializer.increase_container_depth()
+            serializer.serialize_variant_index(ordinal)
+            serializer.decrease_container_depth()
+        }
+
+        fun bincodeSerialize(): ByteArray {
+            val serializer = BincodeSerializer()
+            serialize(serializer)
+            return serializer.get_bytes()
+        }
+
+        companion object {
+            @Throws(DeserializationError::class)
+            fun deserialize(deserializer: Deserializer): Source {
+                deserializer.increase_container_depth()
+                val index = deserializer.deserialize_variant_index()
+                deserializer.decrease_container_depth()
+                return when (index) {
+                    0 -> EMBEDDED
+                    1 -> CUSTOM
+                    2 -> UNKNOWN
+                    else -> UNKNOWN
+                }
+            }
+
+            @Throws(DeserializationError::class)
+            fun bincodeDeserialize(input: ByteArray?): Source {
+                if (input == null) {
+                    throw DeserializationError("Cannot deserialize null array")
+                }
+                val deserializer = BincodeDeserializer(input)
+                val value = deserialize(deserializer)
+                if (deserializer.get_buffer_offset() < input.size) {
+                    throw DeserializationError("Some input bytes were not read")
+                }
+                return value
+            }
+        }
+    }
+    "#);
+}
@@ -78,6 +78,37 @@ fn newtype_struct() {
     "#);
 }
 
+#[test]
+fn branded_newtype_struct() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    #[derive(Facet)]
+    struct User {
+        id: UserId,
+        manager: Option<UserId>,
+    }
+
+    let actual = emit!(User as Kotlin with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @Serializable
+    @SerialName("User")
+    data class User(
+        val id: UserId,
+        val manager: UserId? = null,
+    )
+
+    @Serializable
+    @SerialName("UserId")
+    @JvmInline
+    value class UserId(
+        val value: String,
+    )
+    "#);
+}
+
 #[test]
 fn tuple_struct() {
     /// line 1
@@ -482,6 +513,165 @@ fn enum_with_mixed_variants() {
     "#);
 }
 
+#[test]
+fn unit_enum_with_other_variant() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Source {
+        Embedded,
+        Custom,
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Source as Kotlin with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @Serializable(with = Source.Serializer::class)
+    @SerialName("Source")
+    enum class Source {
+        @SerialName("Embedded") EMBEDDED,
+        @SerialName("Custom") CUSTOM,
+        @SerialName("Unknown") UNKNOWN;
+
+        val serialName: String
+            get() = javaClass.getDeclaredField(name).getAnnotation(SerialName::class.java)!!.value
+
+        object Serializer : kotlinx.serialization.KSerializer<Source> {
+            override val descriptor: kotlinx.serialization.descriptors.SerialDescriptor =
+                kotlinx.serialization.descriptors.PrimitiveSerialDescriptor("Source", kotlinx.serialization.descriptors.PrimitiveKind.STRING)
+
+            override fun serialize(encoder: kotlinx.serialization.encoding.Encoder, value: Source) {
+                encoder.encodeString(value.serialName)
+            }
+
+            override fun deserialize(decoder: kotlinx.serialization.encoding.Decoder): Source {
+                val value = decoder.decodeString()
+                return Source.entries.firstOrNull { it.serialName == value } ?: Source.UNKNOWN
+            }
+        }
+    }
+    "#);
+}
+
+#[test]
+fn enum_with_other_variant() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Created(String),
+        Deleted {
+            id: u64,
+        },
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Event as Kotlin with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @Serializable
+    @SerialName("Event")
+    sealed interface Event {
+        @Serializable
+        @SerialName("Created")
+        data class Created(
+            val value: String,
+        ) : Event
+
+        @Serializable
+        @SerialName("Deleted")
+        data class Deleted(
+            val id: ULong,
+        ) : Event
+
+        @Serializable
+        @SerialName("Unknown")
+        data object Unknown: Event
+
+        object Serializers {
+            val module = kotlinx.serialization.modules.SerializersModule {
+                polymorphicDefaultDeserializer(Event::class) { Unknown.serializer() }
+            }
+        }
+    }
+    "#);
+}
+
+#[test]
+fn untagged_enum() {
+    #[derive(Facet)]
+    #[facet(untagged)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Value {
+        Number(i32),
+        Point(i32, i32),
+        Named { name: String, alias: Option<String> },
+        Nothing,
+    }
+
+    let actual = emit!(Value as Kotlin with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @Serializable(with = Value.Serializer::class)
+    @SerialName("Value")
+    sealed interface Value {
+        @Serializable
+        @SerialName("Number")
+        data class Number(
+            val value: Int,
+        ) : Value
+
+        @Serializable
+        @SerialName("Point")
+        data class Point(
+            val field0: Int,
+            val field1: Int,
+        ) : Value
+
+        @Serializable
+        @SerialName("Named")
+        data class Named(
+            val name: String,
+            val alias: String? = null,
+        ) : Value
+
+        @Serializable
+        @SerialName("Nothing")
+        data object Nothing: Value
+
+        object Serializer : kotlinx.serialization.KSerializer<Value> {
+            override val descriptor: kotlinx.serialization.descriptors.SerialDescriptor =
+                kotlinx.serialization.descriptors.buildClassSerialDescriptor("Value")
+
+            override fun serialize(encoder: kotlinx.serialization.encoding.Encoder, value: Value) {
+                val json = (encoder as kotlinx.serialization.json.JsonEncoder).json
+                val element = when (value) {
+                    is Number -> (json.encodeToJsonElement(Number.serializer(), value) as kotlinx.serialization.json.JsonObject).getValue("value")
+                    is Point -> (json.encodeToJsonElement(Point.serializer(), value) as kotlinx.serialization.json.JsonObject).let { kotlinx.serialization.json.JsonArray(listOf(it.getValue("field0"), it.getValue("field1"))) }
+                    is Named -> json.encodeToJsonElement(Named.serializer(), value)
+                    is Nothing -> kotlinx.serialization.json.JsonNull
+                }
+                encoder.encodeJsonElement(element)
+            }
+
+            override fun deserialize(decoder: kotlinx.serialization.encoding.Decoder): Value {
+                val json = (decoder as kotlinx.serialization.json.JsonDecoder).json
+                val element = decoder.decodeJsonElement()
+                runCatching { return json.decodeFromJsonElement(Number.serializer(), kotlinx.serialization.json.JsonObject(mapOf("value" to element))) }
+                runCatching { return (element as kotlinx.serialization.json.JsonArray).let { require(it.size == 2); json.decodeFromJsonElement(Point.serializer(), kotlinx.serialization.json.JsonObject(mapOf("field0" to it[0], "field1" to it[1]))) } }
+                runCatching { return json.decodeFromJsonElement(Named.serializer(), element) }
+                if (element is kotlinx.serialization.json.JsonNull) return Nothing
+                throw kotlinx.serialization.SerializationException("No variant of Value matches the input")
+            }
+        }
+    }
+    "#);
+}
+
 #[test]
 fn struct_with_vec_field() {
     #[derive(Facet)]
@@ -797,3 +987,325 @@ fn struct_with_bytes_field_and_slice() {
     )
     "#);
 }
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as Kotlin with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @Serializable
+    @SerialName("Listing")
+    data class Listing(
+        val names: Page<String>,
+        val ids: Page<UInt>,
+    )
+
+    @Serializable
+    @SerialName("Page")
+    data class Page<T>(
+        val items: List<T>,
+        val next: T? = null,
+        val total: UInt,
+    )
+    "#);
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E, retry: bool },
+        Pending,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        first: Outcome<String, i32>,
+        second: Outcome<bool, String>,
+    }
+
+    let actual = emit!(Response as Kotlin with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @Serializable
+    @SerialName("Outcome")
+    sealed interface Outcome<out T, out E> {
+        @Serializable
+        @SerialName("Success")
+        data class Success<T>(
+            val value: T,
+        ) : Outcome<T, Nothing>
+
+        @Serializable
+        @SerialName("Failure")
+        data class Failure<E>(
+            val error: E,
+            val retry: Boolean,
+        ) : Outcome<Nothing, E>
+
+        @Serializable
+        @SerialName("Pending")
+        data object Pending: Outcome<Nothing, Nothing>
+    }
+
+    @Serializable
+    @SerialName("Response")
+    data class Response(
+        val first: Outcome<String, Int>,
+        val second: Outcome<Boolean, String>,
+    )
+    "#);
+}
+
+#[test]
+fn serialized_as_proxy() {
+    #[derive(Facet)]
+    #[facet(fg::serialized_as = "String")]
+    struct ItemId {
+        inner: i64,
+    }
+
+    #[derive(Facet)]
+    struct Item {
+        id: ItemId,
+        #[facet(fg::serialized_as = "(u32, u32)")]
+        version: String,
+    }
+
+    let actual = emit!(Item as Kotlin with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @Serializable
+    @SerialName("Item")
+    data class Item(
+        val id: String,
+        val version: Pair<UInt, UInt>,
+    )
+
+    typealias ItemId = String
+    "#);
+}
+
+#[test]
+fn conformances_and_annotations() {
+    #[derive(Facet)]
+    #[facet(
+        fg::annotate(lang = "kotlin", with = "@Parcelize"),
+        fg::conforms(lang = "kotlin", to = "Parcelable"),
+        fg::conforms(lang = "swift", to = "Sendable")
+    )]
+    struct Counter {
+        count: u32,
+    }
+
+    let actual = emit!(Counter as Kotlin with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @Serializable
+    @SerialName("Counter")
+    @Parcelize
+    data class Counter(
+        val count: UInt,
+    ) : Parcelable
+    "#);
+}
+
+#[test]
+fn defaults() {
+    #[derive(Facet)]
+    struct Settings {
+        #[facet(default = "$HOME".to_string())]
+        directory: String,
+        #[facet(default = i64::MIN)]
+        offset: i64,
+        #[facet(default = 30)]
+        timeout: u64,
+        #[facet(fg::bytes, default)]
+        token: Vec<u8>,
+        #[facet(default)]
+        counts: HashMap<String, u32>,
+        retries: u32,
+    }
+
+    let actual = emit!(Settings as Kotlin with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @Serializable
+    @SerialName("Settings")
+    data class Settings(
+        val directory: String = "\$HOME",
+        val offset: Long = Long.MIN_VALUE,
+        val timeout: ULong = 30uL,
+        val token: Bytes = Bytes.empty(),
+        val counts: Map<String, UInt> = mapOf(),
+        val retries: UInt,
+    )
+    "#);
+}
+
+#[test]
+fn internally_tagged_enum() {
+    #[derive(Facet)]
+    struct Disc {
+        radius: u32,
+    }
+
+    #[derive(Facet)]
+    #[facet(tag = "type")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Circle(Disc),
+        Rectangle { width: u32, height: u32 },
+        Empty,
+    }
+
+    let actual = emit!(Shape as Kotlin with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @Serializable
+    @SerialName("Disc")
+    data class Disc(
+        val radius: UInt,
+    )
+
+    @Serializable(with = Shape.Serializer::class)
+    @SerialName("Shape")
+    sealed interface Shape {
+        @Serializable
+        @SerialName("Circle")
+        data class Circle(
+            val value: Disc,
+        ) : Shape
+
+        @Serializable
+        @SerialName("Rectangle")
+        data class Rectangle(
+            val width: UInt,
+            val height: UInt,
+        ) : Shape
+
+        @Serializable
+        @SerialName("Empty")
+        data object Empty: Shape
+
+        object Serializer : kotlinx.serialization.KSerializer<Shape> {
+            override val descriptor: kotlinx.serialization.descriptors.SerialDescriptor =
+                kotlinx.serialization.descriptors.buildClassSerialDescriptor("Shape")
+
+            override fun serialize(encoder: kotlinx.serialization.encoding.Encoder, value: Shape) {
+                val json = (encoder as kotlinx.serialization.json.JsonEncoder).json
+                val element = when (value) {
+                    is Circle -> kotlinx.serialization.json.JsonObject(mapOf("type" to kotlinx.serialization.json.JsonPrimitive("Circle")) + ((json.encodeToJsonElement(Circle.serializer(), value) as kotlinx.serialization.json.JsonObject).getValue("value") as kotlinx.serialization.json.JsonObject))
+                    is Rectangle -> kotlinx.serialization.json.JsonObject(mapOf("type" to kotlinx.serialization.json.JsonPrimitive("Rectangle")) + (json.encodeToJsonElement(Rectangle.serializer(), value) as kotlinx.serialization.json.JsonObject))
+                    is Empty -> kotlinx.serialization.json.JsonObject(mapOf("type" to kotlinx.serialization.json.JsonPrimitive("Empty")))
+                }
+                encoder.encodeJsonElement(element)
+            }
+
+            override fun deserialize(decoder: kotlinx.serialization.encoding.Decoder): Shape {
+                val json = (decoder as kotlinx.serialization.json.JsonDecoder).json
+                val element = decoder.decodeJsonElement() as? kotlinx.serialization.json.JsonObject ?: throw kotlinx.serialization.SerializationException("Expected an object for Shape")
+                val tag = (element["type"] as? kotlinx.serialization.json.JsonPrimitive)?.content
+                return when (tag) {
+                    "Circle" -> json.decodeFromJsonElement(Circle.serializer(), kotlinx.serialization.json.JsonObject(mapOf("value" to kotlinx.serialization.json.JsonObject(element - "type"))))
+                    "Rectangle" -> json.decodeFromJsonElement(Rectangle.serializer(), kotlinx.serialization.json.JsonObject(element - "type"))
+                    "Empty" -> Empty
+                    else -> throw kotlinx.serialization.SerializationException("Unknown variant of Shape: $tag")
+                }
+            }
+        }
+    }
+    "#);
+}
+
+#[test]
+fn adjacently_tagged_enum() {
+    #[derive(Facet)]
+    #[facet(tag = "t", content = "c")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Message {
+        Text(String),
+        Move(i32, i32),
+        Resize { width: u32, height: u32 },
+        Quit,
+    }
+
+    let actual = emit!(Message as Kotlin with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @Serializable(with = Message.Serializer::class)
+    @SerialName("Message")
+    sealed interface Message {
+        @Serializable
+        @SerialName("Text")
+        data class Text(
+            val value: String,
+        ) : Message
+
+        @Serializable
+        @SerialName("Move")
+        data class Move(
+            val field0: Int,
+            val field1: Int,
+        ) : Message
+
+        @Serializable
+        @SerialName("Resize")
+        data class Resize(
+            val width: UInt,
+            val height: UInt,
+        ) : Message
+
+        @Serializable
+        @SerialName("Quit")
+        data object Quit: Message
+
+        object Serializer : kotlinx.serialization.KSerializer<Message> {
+            override val descriptor: kotlinx.serialization.descriptors.SerialDescriptor =
+                kotlinx.serialization.descriptors.buildClassSerialDescriptor("Message")
+
+            override fun serialize(encoder: kotlinx.serialization.encoding.Encoder, value: Message) {
+                val json = (encoder as kotlinx.serialization.json.JsonEncoder).json
+                val element = when (value) {
+                    is Text -> kotlinx.serialization.json.JsonObject(mapOf("t" to kotlinx.serialization.json.JsonPrimitive("Text"), "c" to (json.encodeToJsonElement(Text.serializer(), value) as kotlinx.serialization.json.JsonObject).getValue("value")))
+                    is Move -> kotlinx.serialization.json.JsonObject(mapOf("t" to kotlinx.serialization.json.JsonPrimitive("Move"), "c" to (json.encodeToJsonElement(Move.serializer(), value) as kotlinx.serialization.json.JsonObject).let { kotlinx.serialization.json.JsonArray(listOf(it.getValue("field0"), it.getValue("field1"))) }))
+                    is Resize -> kotlinx.serialization.json.JsonObject(mapOf("t" to kotlinx.serialization.json.JsonPrimitive("Resize"), "c" to json.encodeToJsonElement(Resize.serializer(), value)))
+                    is Quit -> kotlinx.serialization.json.JsonObject(mapOf("t" to kotlinx.serialization.json.JsonPrimitive("Quit")))
+                }
+                encoder.encodeJsonElement(element)
+            }
+
+            override fun deserialize(decoder: kotlinx.serialization.encoding.Decoder): Message {
+                val json = (decoder as kotlinx.serialization.json.JsonDecoder).json
+                val element = decoder.decodeJsonElement() as? kotlinx.serialization.json.JsonObject ?: throw kotlinx.serialization.SerializationException("Expected an object for Message")
+                val tag = (element["t"] as? kotlinx.serialization.json.JsonPrimitive)?.content
+                return when (tag) {
+                    "Text" -> json.decodeFromJsonElement(Text.serializer(), kotlinx.serialization.json.JsonObject(mapOf("value" to element.getValue("c"))))
+                    "Move" -> (element.getValue("c") as kotlinx.serialization.json.JsonArray).let { require(it.size == 2); json.decodeFromJsonElement(Move.serializer(), kotlinx.serialization.json.JsonObject(mapOf("field0" to it[0], "field1" to it[1]))) }
+                    "Resize" -> json.decodeFromJsonElement(Resize.serializer(), element.getValue("c"))
+                    "Quit" -> Quit
+                    else -> throw kotlinx.serialization.SerializationException("Unknown variant of Message: $tag")
+                }
+            }
+        }
+    }
+    "#);
+}
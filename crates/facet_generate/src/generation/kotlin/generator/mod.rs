@@ -11,10 +11,14 @@ use std::{
 use crate::{
     Registry,
     generation::{
-        CodeGenerator, CodeGeneratorConfig, Container, Emitter, config::PackageLocation,
-        indent::IndentedWriter, kotlin::emitter::Kotlin, module::Module, plugin::EmitterPlugin,
+        CodeGenerator, CodeGeneratorConfig, Container, Emitter,
+        config::PackageLocation,
+        indent::IndentedWriter,
+        kotlin::emitter::Kotlin,
+        module::{self, Module},
+        plugin::{self, EmitterPlugin},
     },
-    reflection::format::{Format, FormatHolder, Namespace, QualifiedTypeName},
+    reflection::format::{Format, FormatHolder, Language, Namespace, QualifiedTypeName},
 };
 
 /// Kotlin code generator — holds a reference to the shared
@@ -67,6 +71,9 @@ impl<'a> KotlinCodeGenerator<'a> {
     /// Returns an error if the underlying writer fails.
     pub fn output(&self, out: &mut impl Write, registry: &Registry) -> Result<()> {
         let w = &mut IndentedWriter::new(out, self.config.indent);
+        let checked = module::for_plugin_checks(registry, Language::Kotlin);
+        let registry = &module::for_language(registry, Language::Kotlin);
+        plugin::check_registry(&self.plugins, &checked).map_err(std::io::Error::other)?;
 
         let mut config = self.config.clone();
         config.update_from(registry);
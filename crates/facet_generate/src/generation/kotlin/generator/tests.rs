@@ -28,7 +28,8 @@ use crate::{
         config::{ExternalPackage, PackageLocation},
     },
     reflection::format::{
-        ContainerFormat, Doc, EnumTagging, Named, Namespace, QualifiedTypeName, VariantFormat,
+        ContainerFormat, ContainerMetadata, Doc, EnumTagging, FieldMetadata, Named, Namespace,
+        QualifiedTypeName, VariantFormat,
     },
 };
 use std::collections::BTreeMap;
@@ -68,8 +69,10 @@ fn create_test_registry_with_struct_field(
         name: "field".to_string(),
         doc: Doc::new(),
         value: field_format,
+        metadata: FieldMetadata::default(),
     };
-    let struct_container = ContainerFormat::Struct(vec![named_field], Doc::new());
+    let struct_container =
+        ContainerFormat::Struct(vec![named_field], Doc::new(), ContainerMetadata::default());
     let struct_qualified_name = QualifiedTypeName::root("TestStruct".to_string());
 
     registry.insert(struct_qualified_name, struct_container);
@@ -86,7 +89,7 @@ fn test_update_qualified_names_no_external_definitions() {
 
     // Find the struct container and check its field type
     let (_, container) = updated_registry.iter().next().unwrap();
-    if let ContainerFormat::Struct(fields, _) = container {
+    if let ContainerFormat::Struct(fields, _, _) = container {
         if let Format::TypeName(qualified_name) = &fields[0].value {
             assert_eq!(
                 qualified_name.format(ToString::to_string, "."),
@@ -116,7 +119,7 @@ fn test_update_qualified_names_external_type() {
 
     // Find the struct container and check its field type
     let (_, container) = updated_registry.iter().next().unwrap();
-    if let ContainerFormat::Struct(fields, _) = container {
+    if let ContainerFormat::Struct(fields, _, _) = container {
         if let Format::TypeName(qualified_name) = &fields[0].value {
             assert_eq!(
                 qualified_name.format(ToString::to_string, "."),
@@ -146,7 +149,7 @@ fn test_update_qualified_names_same_namespace_type() {
 
     // Find the struct container and check its field type
     let (_, container) = updated_registry.iter().next().unwrap();
-    if let ContainerFormat::Struct(fields, _) = container {
+    if let ContainerFormat::Struct(fields, _, _) = container {
         if let Format::TypeName(qualified_name) = &fields[0].value {
             // Should use the current module name, not treat as external
             assert_eq!(
@@ -171,7 +174,7 @@ fn test_update_qualified_names_root_namespace() {
 
     // Find the struct container and check its field type
     let (_, container) = updated_registry.iter().next().unwrap();
-    if let ContainerFormat::Struct(fields, _) = container {
+    if let ContainerFormat::Struct(fields, _, _) = container {
         if let Format::TypeName(qualified_name) = &fields[0].value {
             assert_eq!(
                 qualified_name.format(ToString::to_string, "."),
@@ -203,6 +206,7 @@ fn test_update_qualified_names_multiple_external_namespaces() {
         name: "user".to_string(),
         doc: Doc::new(),
         value: Format::TypeName(auth_qualified_name),
+        metadata: FieldMetadata::default(),
     };
 
     // Billing type
@@ -212,9 +216,14 @@ fn test_update_qualified_names_multiple_external_namespaces() {
         name: "invoice".to_string(),
         doc: Doc::new(),
         value: Format::TypeName(billing_qualified_name),
+        metadata: FieldMetadata::default(),
     };
 
-    let struct_container = ContainerFormat::Struct(vec![auth_field, billing_field], Doc::new());
+    let struct_container = ContainerFormat::Struct(
+        vec![auth_field, billing_field],
+        Doc::new(),
+        ContainerMetadata::default(),
+    );
     let struct_qualified_name = QualifiedTypeName::root("TestStruct".to_string());
     registry.insert(struct_qualified_name, struct_container);
 
@@ -222,7 +231,7 @@ fn test_update_qualified_names_multiple_external_namespaces() {
 
     // Check both field types
     let (_, container) = updated_registry.iter().next().unwrap();
-    if let ContainerFormat::Struct(fields, _) = container {
+    if let ContainerFormat::Struct(fields, _, _) = container {
         // Check auth field
         if let Format::TypeName(qualified_name) = &fields[0].value {
             assert_eq!(
@@ -263,7 +272,7 @@ fn test_update_qualified_names_non_external_named_namespace() {
 
     // Find the struct container and check its field type
     let (_, container) = updated_registry.iter().next().unwrap();
-    if let ContainerFormat::Struct(fields, _) = container {
+    if let ContainerFormat::Struct(fields, _, _) = container {
         if let Format::TypeName(qualified_name) = &fields[0].value {
             // Should preserve the namespace for local named types
             assert_eq!(
@@ -301,7 +310,7 @@ fn test_update_qualified_names_immutability() {
 
     // Check that the original still has the short namespace
     let (_, container) = original_registry.iter().next().unwrap();
-    if let ContainerFormat::Struct(fields, _) = container {
+    if let ContainerFormat::Struct(fields, _, _) = container {
         if let Format::TypeName(qualified_name) = &fields[0].value {
             assert_eq!(
                 qualified_name.format(ToString::to_string, "."),
@@ -337,9 +346,11 @@ fn test_update_qualified_names_nested_complex_types() {
         name: "items".to_string(),
         doc: Doc::new(),
         value: option_type,
+        metadata: FieldMetadata::default(),
     };
 
-    let struct_container = ContainerFormat::Struct(vec![field], Doc::new());
+    let struct_container =
+        ContainerFormat::Struct(vec![field], Doc::new(), ContainerMetadata::default());
     let struct_qualified_name = QualifiedTypeName::root("Container".to_string());
     registry.insert(struct_qualified_name, struct_container);
 
@@ -347,7 +358,7 @@ fn test_update_qualified_names_nested_complex_types() {
 
     // Navigate through the nested structure to check the inner type
     let (_, container) = updated_registry.iter().next().unwrap();
-    if let ContainerFormat::Struct(fields, _) = container {
+    if let ContainerFormat::Struct(fields, _, _) = container {
         if let Format::Option(inner) = &fields[0].value {
             if let Format::Seq(inner2) = inner.as_ref() {
                 if let Format::TypeName(qualified_name) = inner2.as_ref() {
@@ -389,11 +400,17 @@ fn test_update_qualified_names_enum_variants() {
         name: "UserCreated".to_string(),
         doc: Doc::new(),
         value: VariantFormat::NewType(Box::new(user_type)),
+        metadata: FieldMetadata::default(),
     };
 
     let mut variants = BTreeMap::new();
     variants.insert(0, variant);
-    let enum_container = ContainerFormat::Enum(variants, EnumTagging::External, Doc::new());
+    let enum_container = ContainerFormat::Enum(
+        variants,
+        EnumTagging::External,
+        Doc::new(),
+        ContainerMetadata::default(),
+    );
     let enum_qualified_name = QualifiedTypeName::root("Event".to_string());
     registry.insert(enum_qualified_name, enum_container);
 
@@ -401,7 +418,7 @@ fn test_update_qualified_names_enum_variants() {
 
     // Check the enum variant type
     let (_, container) = updated_registry.iter().next().unwrap();
-    if let ContainerFormat::Enum(variants, _, _) = container {
+    if let ContainerFormat::Enum(variants, _, _, _) = container {
         let variant = variants.get(&0).unwrap();
         if let VariantFormat::NewType(boxed_format) = &variant.value {
             if let Format::TypeName(qualified_name) = boxed_format.as_ref() {
@@ -443,7 +460,7 @@ fn test_update_qualified_names_external_package_path() {
 
     // Find the struct container and check its field type
     let (_, container) = updated_registry.iter().next().unwrap();
-    if let ContainerFormat::Struct(fields, _) = container {
+    if let ContainerFormat::Struct(fields, _, _) = container {
         if let Format::TypeName(qualified_name) = &fields[0].value {
             assert_eq!(
                 qualified_name.format(ToString::to_string, "."),
@@ -482,6 +499,7 @@ fn test_update_qualified_names_user_example_scenario() {
         name: "fact".to_string(),
         doc: Doc::new(),
         value: Format::Str,
+        metadata: FieldMetadata::default(),
     };
 
     let cat_image_qualified_name =
@@ -490,12 +508,14 @@ fn test_update_qualified_names_user_example_scenario() {
         name: "image".to_string(),
         doc: Doc::new(),
         value: Format::Option(Box::new(Format::TypeName(cat_image_qualified_name))),
+        metadata: FieldMetadata::default(),
     };
 
     let platform_field = Named {
         name: "platform".to_string(),
         doc: Doc::new(),
         value: Format::Str,
+        metadata: FieldMetadata::default(),
     };
 
     let other_qualified_name =
@@ -504,12 +524,14 @@ fn test_update_qualified_names_user_example_scenario() {
         name: "other".to_string(),
         doc: Doc::new(),
         value: Format::TypeName(other_qualified_name.clone()),
+        metadata: FieldMetadata::default(),
     };
 
     let another_field = Named {
         name: "another".to_string(),
         doc: Doc::new(),
         value: Format::TypeName(other_qualified_name),
+        metadata: FieldMetadata::default(),
     };
 
     let struct_container = ContainerFormat::Struct(
@@ -521,6 +543,7 @@ fn test_update_qualified_names_user_example_scenario() {
             another_field,
         ],
         Doc::new(),
+        ContainerMetadata::default(),
     );
     let view_model_qualified_name =
         QualifiedTypeName::namespaced("App".to_string(), "ViewModel".to_string());
@@ -530,7 +553,7 @@ fn test_update_qualified_names_user_example_scenario() {
 
     // Check the field types
     let (_, container) = updated_registry.iter().next().unwrap();
-    if let ContainerFormat::Struct(fields, _) = container {
+    if let ContainerFormat::Struct(fields, _, _) = container {
         // Check image field (should be App.CatImage - local type)
         if let Format::Option(inner) = &fields[1].value {
             if let Format::TypeName(qualified_name) = inner.as_ref() {
@@ -593,7 +616,7 @@ fn test_update_qualified_names_external_package_url_ignored() {
 
     // Find the struct container and check its field type
     let (_, container) = updated_registry.iter().next().unwrap();
-    if let ContainerFormat::Struct(fields, _) = container {
+    if let ContainerFormat::Struct(fields, _, _) = container {
         if let Format::TypeName(qualified_name) = &fields[0].value {
             // URL locations are ignored, so it should fall back to local behavior
             assert_eq!(
@@ -635,7 +658,7 @@ fn test_update_qualified_names_external_package_priority() {
 
     // Find the struct container and check its field type
     let (_, container) = updated_registry.iter().next().unwrap();
-    if let ContainerFormat::Struct(fields, _) = container {
+    if let ContainerFormat::Struct(fields, _, _) = container {
         if let Format::TypeName(qualified_name) = &fields[0].value {
             // Should use external package location, not external definitions logic
             assert_eq!(
@@ -684,6 +707,7 @@ fn test_update_qualified_names_multiple_external_packages() {
         name: "user".to_string(),
         doc: Doc::new(),
         value: Format::TypeName(auth_qualified_name),
+        metadata: FieldMetadata::default(),
     };
 
     // Billing type
@@ -693,9 +717,14 @@ fn test_update_qualified_names_multiple_external_packages() {
         name: "invoice".to_string(),
         doc: Doc::new(),
         value: Format::TypeName(billing_qualified_name),
+        metadata: FieldMetadata::default(),
     };
 
-    let struct_container = ContainerFormat::Struct(vec![auth_field, billing_field], Doc::new());
+    let struct_container = ContainerFormat::Struct(
+        vec![auth_field, billing_field],
+        Doc::new(),
+        ContainerMetadata::default(),
+    );
     let struct_qualified_name = QualifiedTypeName::root("TestStruct".to_string());
     registry.insert(struct_qualified_name, struct_container);
 
@@ -703,7 +732,7 @@ fn test_update_qualified_names_multiple_external_packages() {
 
     // Check both field types
     let (_, container) = updated_registry.iter().next().unwrap();
-    if let ContainerFormat::Struct(fields, _) = container {
+    if let ContainerFormat::Struct(fields, _, _) = container {
         // Check auth field
         if let Format::TypeName(qualified_name) = &fields[0].value {
             assert_eq!(
@@ -755,7 +784,7 @@ fn test_update_qualified_names_fallback_to_external_definitions() {
 
     // Find the struct container and check its field type
     let (_, container) = updated_registry.iter().next().unwrap();
-    if let ContainerFormat::Struct(fields, _) = container {
+    if let ContainerFormat::Struct(fields, _, _) = container {
         if let Format::TypeName(qualified_name) = &fields[0].value {
             // Should use external definitions logic since no external package matches "Legacy"
             assert_eq!(
@@ -779,7 +808,9 @@ fn test_deserialization_uses_fully_qualified_names() {
             config::{ExternalPackage, PackageLocation},
             kotlin::KotlinCodeGenerator,
         },
-        reflection::format::{ContainerFormat, Doc, Format, Named, QualifiedTypeName},
+        reflection::format::{
+            ContainerFormat, Doc, FieldMetadata, Format, Named, QualifiedTypeName,
+        },
     };
     use std::collections::BTreeMap;
 
@@ -818,15 +849,21 @@ fn test_deserialization_uses_fully_qualified_names() {
         name: "external_field".to_string(),
         doc: Doc::new(),
         value: external_type,
+        metadata: FieldMetadata::default(),
     };
 
     let local_field = Named {
         name: "local_field".to_string(),
         doc: Doc::new(),
         value: local_type,
+        metadata: FieldMetadata::default(),
     };
 
-    let struct_container = ContainerFormat::Struct(vec![external_field, local_field], Doc::new());
+    let struct_container = ContainerFormat::Struct(
+        vec![external_field, local_field],
+        Doc::new(),
+        ContainerMetadata::default(),
+    );
 
     let struct_qualified_name =
         QualifiedTypeName::namespaced("App".to_string(), "TestStruct".to_string());
@@ -882,6 +919,7 @@ fn test_update_qualified_names_mixed_external_and_local() {
         name: "modern".to_string(),
         doc: Doc::new(),
         value: Format::TypeName(modern_qualified_name),
+        metadata: FieldMetadata::default(),
     };
 
     // External definitions type
@@ -891,6 +929,7 @@ fn test_update_qualified_names_mixed_external_and_local() {
         name: "legacy".to_string(),
         doc: Doc::new(),
         value: Format::TypeName(legacy_qualified_name),
+        metadata: FieldMetadata::default(),
     };
 
     // Local type
@@ -899,10 +938,14 @@ fn test_update_qualified_names_mixed_external_and_local() {
         name: "local".to_string(),
         doc: Doc::new(),
         value: Format::TypeName(local_qualified_name),
+        metadata: FieldMetadata::default(),
     };
 
-    let struct_container =
-        ContainerFormat::Struct(vec![modern_field, legacy_field, local_field], Doc::new());
+    let struct_container = ContainerFormat::Struct(
+        vec![modern_field, legacy_field, local_field],
+        Doc::new(),
+        ContainerMetadata::default(),
+    );
     let struct_qualified_name = QualifiedTypeName::root("TestStruct".to_string());
     registry.insert(struct_qualified_name, struct_container);
 
@@ -910,7 +953,7 @@ fn test_update_qualified_names_mixed_external_and_local() {
 
     // Check all field types
     let (_, container) = updated_registry.iter().next().unwrap();
-    if let ContainerFormat::Struct(fields, _) = container {
+    if let ContainerFormat::Struct(fields, _, _) = container {
         // Check external package field
         if let Format::TypeName(qualified_name) = &fields[0].value {
             assert_eq!(
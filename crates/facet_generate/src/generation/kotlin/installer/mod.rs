@@ -34,11 +34,13 @@ use crate::{
         CodeGeneratorConfig, Error, ExternalPackage, ExternalPackages, PackageLocation,
         SERDE_NAMESPACE, SourceInstaller,
         bincode::BincodePlugin,
+        indent::IndentConfig,
         json::JsonPlugin,
         kotlin::{Kotlin, KotlinCodeGenerator},
         module,
-        plugin::EmitterPlugin,
+        plugin::{self, EmitterPlugin},
     },
+    reflection::format::{Language, Visibility},
 };
 
 /// Writes a complete Kotlin project (source files, runtime, build script)
@@ -49,7 +51,9 @@ pub struct Installer {
     package_name: String,
     install_dir: PathBuf,
     external_packages: ExternalPackages,
+    indent: IndentConfig,
     plugins: Vec<Arc<dyn EmitterPlugin<Kotlin>>>,
+    default_visibility: Visibility,
 }
 
 impl Installer {
@@ -64,7 +68,9 @@ impl Installer {
             package_name: package_name.to_string(),
             install_dir: install_dir.as_ref().to_path_buf(),
             external_packages: ExternalPackages::new(),
+            indent: IndentConfig::Space(4),
             plugins: vec![],
+            default_visibility: Visibility::Public,
         }
     }
 
@@ -88,6 +94,21 @@ impl Installer {
         self
     }
 
+    /// Set the visibility of generated types and fields that aren't marked
+    /// `#[facet(fg::public)]` (public by default).
+    #[must_use]
+    pub const fn default_visibility(mut self, visibility: Visibility) -> Self {
+        self.default_visibility = visibility;
+        self
+    }
+
+    /// Set the indentation of generated source files (four spaces by default).
+    #[must_use]
+    pub const fn indent(mut self, indent: IndentConfig) -> Self {
+        self.indent = indent;
+        self
+    }
+
     /// Generate all code for the given registry.
     ///
     /// This method:
@@ -99,6 +120,11 @@ impl Installer {
     ///
     /// Returns an error if any file operation or code generation step fails.
     pub fn generate(mut self, registry: &Registry) -> Result<(), Error> {
+        plugin::check_registry(
+            &self.plugins,
+            &module::for_plugin_checks(registry, Language::Kotlin),
+        )?;
+
         // Build a lang tag to get the active plugins, then use them to install
         // runtime files.
         let mut config = CodeGeneratorConfig::new(self.package_name.clone());
@@ -128,8 +154,16 @@ impl Installer {
         }
 
         // Split by namespace and install each module
-        for (m, module_registry) in module::split(&self.package_name, registry) {
-            let config = m.config().clone().with_parent(&self.package_name);
+        for (m, module_registry) in module::split(
+            &self.package_name,
+            &module::for_language(registry, Language::Kotlin),
+        ) {
+            let config = m
+                .config()
+                .clone()
+                .with_parent(&self.package_name)
+                .with_default_visibility(self.default_visibility)
+                .with_indent(self.indent);
             self.install_module(&config, &module_registry)?;
         }
 
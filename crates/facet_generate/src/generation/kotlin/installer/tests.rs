@@ -9,8 +9,12 @@
 //!   version strings, plus the `kotlinx-serialization-json` runtime.
 //! - External path dependencies: local file-system dependencies via
 //!   `files("…")`.
+//! - Registries the plugins can't encode, such as generic untagged enums.
+
+use facet::Facet;
 
 use crate::generation::{ExternalPackage, PackageLocation, json::JsonPlugin, kotlin::Installer};
+use crate::reflect;
 
 #[test]
 fn test_new_installer() {
@@ -63,3 +67,49 @@ fn test_make_manifest_with_path_dependency() {
     // Check that path dependencies are handled correctly
     assert!(manifest.contains(r#"files("../local-lib")"#));
 }
+
+#[test]
+fn json_rejects_generic_untagged_enums() {
+    #[derive(Facet)]
+    #[facet(untagged)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Value<T> {
+        One(T),
+        Many(Vec<T>),
+    }
+
+    #[derive(Facet)]
+    struct Wrapper {
+        value: Value<i32>,
+        name: Value<String>,
+    }
+
+    let registry = reflect!(Wrapper).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    let installer = Installer::new("test-package", install_dir.path()).plugin(JsonPlugin);
+
+    let error = installer.generate(&registry).unwrap_err();
+    insta::assert_snapshot!(error, @"json (Kotlin) can't encode `Value`: generic untagged enums are not supported");
+}
+
+#[test]
+fn json_rejects_internally_tagged_tuple_variants() {
+    #[derive(Facet)]
+    #[facet(tag = "type")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point(i32, i32),
+        Empty,
+    }
+
+    let registry = reflect!(Shape).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    let installer = Installer::new("test-package", install_dir.path()).plugin(JsonPlugin);
+
+    let error = installer.generate(&registry).unwrap_err();
+    insta::assert_snapshot!(error, @"json (Kotlin) can't encode `Shape`: internally tagged enums can't have tuple variants");
+}
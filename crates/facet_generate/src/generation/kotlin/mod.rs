@@ -27,7 +27,7 @@
 //!    registry by namespace into per-module files, and generates a
 //!    `build.gradle.kts` manifest.
 
-mod emitter;
+pub(crate) mod emitter;
 mod generator;
 mod installer;
 
@@ -1,8 +1,8 @@
 #![allow(clippy::too_long_first_doc_paragraph)]
 //! Code generation — transforms a [`Registry`] into source code.
 //!
-//! Each language has its own submodule (`kotlin`, `csharp`, `swift`, `typescript`)
-//! behind a feature flag.
+//! Each language has its own submodule (`kotlin`, `csharp`, `swift`, `typescript`, `dart`,
+//! `python`, `go`, `java`, `cpp`) behind a feature flag.
 //!
 //! ## Generation pipeline
 //!
@@ -39,14 +39,34 @@ pub mod bincode;
 /// helpers through the plugin trait.
 pub mod json;
 
+/// Support for code-generation in C++
+#[cfg(feature = "cpp")]
+pub mod cpp;
+
 /// Support for code-generation in C#
 #[cfg(feature = "csharp")]
 pub mod csharp;
 
+/// Support for code-generation in Dart
+#[cfg(feature = "dart")]
+pub mod dart;
+
+/// Support for code-generation in Go
+#[cfg(feature = "go")]
+pub mod go;
+
+/// Support for code-generation in Java
+#[cfg(feature = "java")]
+pub mod java;
+
 /// Support for code-generation in Kotlin
 #[cfg(feature = "kotlin")]
 pub mod kotlin;
 
+/// Support for code-generation in Python
+#[cfg(feature = "python")]
+pub mod python;
+
 /// Support for code-generation in Swift
 #[cfg(feature = "swift")]
 pub mod swift;
@@ -104,7 +124,8 @@ impl<'a> From<(&'a QualifiedTypeName, &'a ContainerFormat)> for Container<'a> {
 ///
 /// `Emitter` is the core abstraction of the code-generation pipeline. Each target
 /// language defines a **language tag** type — e.g.
-/// [`csharp::CSharp`], [`kotlin::Kotlin`], [`swift::Swift`], [`typescript::TypeScript`]
+/// [`cpp::Cpp`], [`csharp::CSharp`], [`dart::Dart`], [`go::Go`], [`java::Java`],
+/// [`kotlin::Kotlin`], [`python::Python`], [`swift::Swift`], [`typescript::TypeScript`]
 /// — and then provides `Emitter<L>` implementations for the AST node types that
 /// need to be rendered in that language.
 ///
@@ -6,14 +6,19 @@
 //! generators can emit the correct import statements.
 //!
 //! The entry point is [`split`](crate::generation::module::split), which returns a `BTreeMap<Module, Registry>`
-//! — one entry per namespace, ordered by module name.
+//! — one entry per namespace, ordered by module name. Installers first narrow the registry to
+//! their language with [`for_language`](crate::generation::module::for_language), so that a
+//! namespace whose types are all skipped doesn't become an empty module.
 
 use std::{cmp::Ordering, collections::BTreeMap};
 
 use crate::{
     Registry,
     generation::CodeGeneratorConfig,
-    reflection::format::{ContainerFormat, Format, FormatHolder, Namespace, QualifiedTypeName},
+    reflection::format::{
+        ContainerFormat, Format, FormatHolder, Language, Namespace, QualifiedTypeName,
+        VariantFormat,
+    },
 };
 
 /// A single output module, identified by namespace name and carrying the
@@ -65,7 +70,7 @@ impl Ord for Module {
 /// Splits a registry into one [`Module`] per namespace.
 ///
 /// Types with [`Namespace::Root`] are assigned to the `root` module name.
-/// For each module, any `Format::TypeName` references that point to a
+/// For each module, any `Format::TypeName` (or `Format::Generic`) references that point to a
 /// *different* namespace are recorded as external definitions so generators
 /// can emit import statements.
 ///
@@ -99,7 +104,11 @@ pub fn split(root: &str, registry: &Registry) -> BTreeMap<Module, Registry> {
             let format_clone = format.clone();
             format_clone
                 .visit(&mut |format| {
-                    if let Format::TypeName(qualified_name) = format
+                    if let Format::TypeName(qualified_name)
+                    | Format::Generic {
+                        name: qualified_name,
+                        ..
+                    } = format
                         && let Namespace::Named(ns) = &qualified_name.namespace
                         && ns != &namespace_key
                     {
@@ -130,6 +139,54 @@ pub fn split(root: &str, registry: &Registry) -> BTreeMap<Module, Registry> {
     registries
 }
 
+/// The part of a registry that is generated for `language`.
+///
+/// Types, struct fields and enum variants that `#[facet(fg::skip(...))]` or
+/// `#[facet(fg::only(...))]` leave out of `language` are removed. Enum variants keep their
+/// indices, so the remaining variants are still (de)serialized with the indices that Rust uses.
+#[must_use]
+pub fn for_language(registry: &Registry, language: Language) -> Registry {
+    let mut registry = for_plugin_checks(registry, language);
+    for format in registry.values_mut() {
+        match format {
+            ContainerFormat::Struct(fields, ..) => {
+                fields.retain(|field| field.metadata.targets(language));
+            }
+            ContainerFormat::Enum(variants, ..) => {
+                for variant in variants.values_mut() {
+                    if let VariantFormat::Struct(fields) = &mut variant.value {
+                        fields.retain(|field| field.metadata.targets(language));
+                    }
+                }
+            }
+            ContainerFormat::UnitStruct(..)
+            | ContainerFormat::NewTypeStruct(..)
+            | ContainerFormat::TupleStruct(..) => {}
+        }
+    }
+    registry
+}
+
+/// The registry that plugins check before generating `language`: the types and enum variants
+/// of [`for_language`], with all of their fields.
+///
+/// Fields that are left out of some languages only are kept, so that a plugin whose encoding
+/// needs every language to have the same fields can reject them.
+#[must_use]
+pub fn for_plugin_checks(registry: &Registry, language: Language) -> Registry {
+    registry
+        .iter()
+        .filter(|(_, format)| format.metadata().targets(language))
+        .map(|(name, format)| {
+            let mut format = format.clone();
+            if let ContainerFormat::Enum(variants, ..) = &mut format {
+                variants.retain(|_, variant| variant.metadata.targets(language));
+            }
+            (name.clone(), format)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 #[path = "./module_tests.rs"]
 mod module_tests;
@@ -47,6 +47,8 @@ fn single_namespace() {
                 referenced_namespaces: {},
                 unit_variant_enums: {},
                 enum_type_names: {},
+                branded_type_names: {},
+                default_visibility: Public,
             },
         ): {
             QualifiedTypeName {
@@ -65,11 +67,32 @@ fn single_namespace() {
                                 name: "GrandChild",
                             },
                         ),
+                        metadata: FieldMetadata {
+                            readonly: false,
+                            visibility: None,
+                            targets: None,
+                            overrides: {},
+                            deprecated: None,
+                            default: None,
+                            other: false,
+                            flattened: false,
+                        },
                     },
                 ],
                 Doc(
                     [],
                 ),
+                ContainerMetadata {
+                    type_params: [],
+                    branded: false,
+                    proxy: false,
+                    readonly: false,
+                    visibility: None,
+                    targets: None,
+                    conformances: {},
+                    annotations: {},
+                    deprecated: None,
+                },
             ),
             QualifiedTypeName {
                 namespace: Root,
@@ -82,11 +105,32 @@ fn single_namespace() {
                             [],
                         ),
                         value: Str,
+                        metadata: FieldMetadata {
+                            readonly: false,
+                            visibility: None,
+                            targets: None,
+                            overrides: {},
+                            deprecated: None,
+                            default: None,
+                            other: false,
+                            flattened: false,
+                        },
                     },
                 ],
                 Doc(
                     [],
                 ),
+                ContainerMetadata {
+                    type_params: [],
+                    branded: false,
+                    proxy: false,
+                    readonly: false,
+                    visibility: None,
+                    targets: None,
+                    conformances: {},
+                    annotations: {},
+                    deprecated: None,
+                },
             ),
             QualifiedTypeName {
                 namespace: Root,
@@ -99,11 +143,32 @@ fn single_namespace() {
                             [],
                         ),
                         value: Str,
+                        metadata: FieldMetadata {
+                            readonly: false,
+                            visibility: None,
+                            targets: None,
+                            overrides: {},
+                            deprecated: None,
+                            default: None,
+                            other: false,
+                            flattened: false,
+                        },
                     },
                 ],
                 Doc(
                     [],
                 ),
+                ContainerMetadata {
+                    type_params: [],
+                    branded: false,
+                    proxy: false,
+                    readonly: false,
+                    visibility: None,
+                    targets: None,
+                    conformances: {},
+                    annotations: {},
+                    deprecated: None,
+                },
             ),
             QualifiedTypeName {
                 namespace: Root,
@@ -121,6 +186,16 @@ fn single_namespace() {
                                 name: "ChildOne",
                             },
                         ),
+                        metadata: FieldMetadata {
+                            readonly: false,
+                            visibility: None,
+                            targets: None,
+                            overrides: {},
+                            deprecated: None,
+                            default: None,
+                            other: false,
+                            flattened: false,
+                        },
                     },
                     Named {
                         name: "two",
@@ -133,11 +208,32 @@ fn single_namespace() {
                                 name: "ChildTwo",
                             },
                         ),
+                        metadata: FieldMetadata {
+                            readonly: false,
+                            visibility: None,
+                            targets: None,
+                            overrides: {},
+                            deprecated: None,
+                            default: None,
+                            other: false,
+                            flattened: false,
+                        },
                     },
                 ],
                 Doc(
                     [],
                 ),
+                ContainerMetadata {
+                    type_params: [],
+                    branded: false,
+                    proxy: false,
+                    readonly: false,
+                    visibility: None,
+                    targets: None,
+                    conformances: {},
+                    annotations: {},
+                    deprecated: None,
+                },
             ),
         },
     }
@@ -196,6 +292,8 @@ fn root_namespace_with_two_child_namespaces() {
                 referenced_namespaces: {},
                 unit_variant_enums: {},
                 enum_type_names: {},
+                branded_type_names: {},
+                default_visibility: Public,
             },
         ): {
             QualifiedTypeName {
@@ -216,6 +314,16 @@ fn root_namespace_with_two_child_namespaces() {
                                 name: "ChildOne",
                             },
                         ),
+                        metadata: FieldMetadata {
+                            readonly: false,
+                            visibility: None,
+                            targets: None,
+                            overrides: {},
+                            deprecated: None,
+                            default: None,
+                            other: false,
+                            flattened: false,
+                        },
                     },
                     Named {
                         name: "two",
@@ -230,11 +338,32 @@ fn root_namespace_with_two_child_namespaces() {
                                 name: "ChildTwo",
                             },
                         ),
+                        metadata: FieldMetadata {
+                            readonly: false,
+                            visibility: None,
+                            targets: None,
+                            overrides: {},
+                            deprecated: None,
+                            default: None,
+                            other: false,
+                            flattened: false,
+                        },
                     },
                 ],
                 Doc(
                     [],
                 ),
+                ContainerMetadata {
+                    type_params: [],
+                    branded: false,
+                    proxy: false,
+                    readonly: false,
+                    visibility: None,
+                    targets: None,
+                    conformances: {},
+                    annotations: {},
+                    deprecated: None,
+                },
             ),
         },
         Module(
@@ -252,6 +381,8 @@ fn root_namespace_with_two_child_namespaces() {
                 referenced_namespaces: {},
                 unit_variant_enums: {},
                 enum_type_names: {},
+                branded_type_names: {},
+                default_visibility: Public,
             },
         ): {
             QualifiedTypeName {
@@ -274,11 +405,32 @@ fn root_namespace_with_two_child_namespaces() {
                                 name: "GrandChild",
                             },
                         ),
+                        metadata: FieldMetadata {
+                            readonly: false,
+                            visibility: None,
+                            targets: None,
+                            overrides: {},
+                            deprecated: None,
+                            default: None,
+                            other: false,
+                            flattened: false,
+                        },
                     },
                 ],
                 Doc(
                     [],
                 ),
+                ContainerMetadata {
+                    type_params: [],
+                    branded: false,
+                    proxy: false,
+                    readonly: false,
+                    visibility: None,
+                    targets: None,
+                    conformances: {},
+                    annotations: {},
+                    deprecated: None,
+                },
             ),
             QualifiedTypeName {
                 namespace: Named(
@@ -293,11 +445,32 @@ fn root_namespace_with_two_child_namespaces() {
                             [],
                         ),
                         value: Str,
+                        metadata: FieldMetadata {
+                            readonly: false,
+                            visibility: None,
+                            targets: None,
+                            overrides: {},
+                            deprecated: None,
+                            default: None,
+                            other: false,
+                            flattened: false,
+                        },
                     },
                 ],
                 Doc(
                     [],
                 ),
+                ContainerMetadata {
+                    type_params: [],
+                    branded: false,
+                    proxy: false,
+                    readonly: false,
+                    visibility: None,
+                    targets: None,
+                    conformances: {},
+                    annotations: {},
+                    deprecated: None,
+                },
             ),
         },
         Module(
@@ -315,6 +488,8 @@ fn root_namespace_with_two_child_namespaces() {
                 referenced_namespaces: {},
                 unit_variant_enums: {},
                 enum_type_names: {},
+                branded_type_names: {},
+                default_visibility: Public,
             },
         ): {
             QualifiedTypeName {
@@ -330,11 +505,32 @@ fn root_namespace_with_two_child_namespaces() {
                             [],
                         ),
                         value: Str,
+                        metadata: FieldMetadata {
+                            readonly: false,
+                            visibility: None,
+                            targets: None,
+                            overrides: {},
+                            deprecated: None,
+                            default: None,
+                            other: false,
+                            flattened: false,
+                        },
                     },
                 ],
                 Doc(
                     [],
                 ),
+                ContainerMetadata {
+                    type_params: [],
+                    branded: false,
+                    proxy: false,
+                    readonly: false,
+                    visibility: None,
+                    targets: None,
+                    conformances: {},
+                    annotations: {},
+                    deprecated: None,
+                },
             ),
         },
     }
@@ -409,6 +605,8 @@ fn same_namespace_with_external_dependency_bug_regression() {
                 referenced_namespaces: {},
                 unit_variant_enums: {},
                 enum_type_names: {},
+                branded_type_names: {},
+                default_visibility: Public,
             },
         ): {
             QualifiedTypeName {
@@ -429,11 +627,32 @@ fn same_namespace_with_external_dependency_bug_regression() {
                                 name: "GrandChild",
                             },
                         ),
+                        metadata: FieldMetadata {
+                            readonly: false,
+                            visibility: None,
+                            targets: None,
+                            overrides: {},
+                            deprecated: None,
+                            default: None,
+                            other: false,
+                            flattened: false,
+                        },
                     },
                 ],
                 Doc(
                     [],
                 ),
+                ContainerMetadata {
+                    type_params: [],
+                    branded: false,
+                    proxy: false,
+                    readonly: false,
+                    visibility: None,
+                    targets: None,
+                    conformances: {},
+                    annotations: {},
+                    deprecated: None,
+                },
             ),
             QualifiedTypeName {
                 namespace: Root,
@@ -451,11 +670,32 @@ fn same_namespace_with_external_dependency_bug_regression() {
                                 name: "Child",
                             },
                         ),
+                        metadata: FieldMetadata {
+                            readonly: false,
+                            visibility: None,
+                            targets: None,
+                            overrides: {},
+                            deprecated: None,
+                            default: None,
+                            other: false,
+                            flattened: false,
+                        },
                     },
                 ],
                 Doc(
                     [],
                 ),
+                ContainerMetadata {
+                    type_params: [],
+                    branded: false,
+                    proxy: false,
+                    readonly: false,
+                    visibility: None,
+                    targets: None,
+                    conformances: {},
+                    annotations: {},
+                    deprecated: None,
+                },
             ),
         },
         Module(
@@ -473,6 +713,8 @@ fn same_namespace_with_external_dependency_bug_regression() {
                 referenced_namespaces: {},
                 unit_variant_enums: {},
                 enum_type_names: {},
+                branded_type_names: {},
+                default_visibility: Public,
             },
         ): {
             QualifiedTypeName {
@@ -488,13 +730,61 @@ fn same_namespace_with_external_dependency_bug_regression() {
                             [],
                         ),
                         value: Str,
+                        metadata: FieldMetadata {
+                            readonly: false,
+                            visibility: None,
+                            targets: None,
+                            overrides: {},
+                            deprecated: None,
+                            default: None,
+                            other: false,
+                            flattened: false,
+                        },
                     },
                 ],
                 Doc(
                     [],
                 ),
+                ContainerMetadata {
+                    type_params: [],
+                    branded: false,
+                    proxy: false,
+                    readonly: false,
+                    visibility: None,
+                    targets: None,
+                    conformances: {},
+                    annotations: {},
+                    deprecated: None,
+                },
             ),
         },
     }
     "#);
 }
+
+#[test]
+fn for_language_drops_modules_of_skipped_types() {
+    #[derive(Facet)]
+    #[facet(fg::namespace = "admin", fg::only(typescript))]
+    struct AuditLog {
+        entries: Vec<String>,
+    }
+
+    #[derive(Facet)]
+    struct Parent {
+        name: String,
+        #[facet(fg::only(typescript))]
+        audit: AuditLog,
+    }
+
+    let registry = reflect!(Parent).unwrap();
+    let module_names = |language| {
+        split("App", &for_language(&registry, language))
+            .into_keys()
+            .map(|module| module.config().module_name.clone())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(module_names(Language::TypeScript), ["App", "admin"]);
+    assert_eq!(module_names(Language::Swift), ["App"]);
+}
@@ -31,12 +31,16 @@
 //! | `field_annotations` | Before a field declaration | `@SerialName("foo")` |
 //! | `runtime_files` | During installation | serde/bincode runtime `.kt` files |
 //! | `manifest_dependencies` | When writing the build manifest | `kotlinx-serialization-json` |
+//! | `check_registry` | Before generating any code | reject types the encoding can't represent |
 
 use std::io;
 use std::sync::Arc;
 
-use super::{CodeGeneratorConfig, Container, indent::IndentWrite};
-use crate::reflection::format::{Format, Named, VariantFormat};
+use super::{CodeGeneratorConfig, Container, Error, indent::IndentWrite};
+use crate::{
+    Registry,
+    reflection::format::{Format, Language, Named, VariantFormat},
+};
 
 // ---------------------------------------------------------------------------
 // Context types passed to plugin methods
@@ -90,6 +94,15 @@ impl<'a> EmitContext<'a> {
         }
     }
 
+    /// The type parameters of the container being emitted (e.g. `["T"]` for
+    /// `Page<T>`), empty for non-generic types.
+    ///
+    /// Variants of a generic enum share the enum's type parameters.
+    #[must_use]
+    pub fn type_params(&self) -> &'a [String] {
+        self.container.format.type_params()
+    }
+
     /// Whether this context represents a variant (as opposed to a top-level
     /// type).
     #[must_use]
@@ -128,16 +141,16 @@ impl<'a> EmitContext<'a> {
         }
 
         match self.container.format {
-            ContainerFormat::UnitStruct(_) | ContainerFormat::Enum(_, _, _) => vec![],
-            ContainerFormat::NewTypeStruct(format, _) => {
+            ContainerFormat::UnitStruct(_, _) | ContainerFormat::Enum(_, _, _, _) => vec![],
+            ContainerFormat::NewTypeStruct(format, _, _) => {
                 vec![Named::new(format, "value".to_string())]
             }
-            ContainerFormat::TupleStruct(formats, _) => formats
+            ContainerFormat::TupleStruct(formats, _, _) => formats
                 .iter()
                 .enumerate()
                 .map(|(i, f)| Named::new(f, format!("field{i}")))
                 .collect(),
-            ContainerFormat::Struct(fields, _) => fields.clone(),
+            ContainerFormat::Struct(fields, _, _) => fields.clone(),
         }
     }
 }
@@ -161,6 +174,9 @@ pub struct VariantInfo<'a> {
 
     /// The name of the parent sealed interface / enum that owns this variant.
     pub parent_name: &'a str,
+
+    /// The type parameters of the parent enum (empty unless it is generic).
+    pub parent_type_params: &'a [String],
 }
 
 // ---------------------------------------------------------------------------
@@ -174,7 +190,12 @@ pub struct VariantInfo<'a> {
 /// [`Kotlin`](super::kotlin::Kotlin),
 /// [`Swift`](super::swift::Swift),
 /// [`TypeScript`](super::typescript::TypeScript),
-/// [`CSharp`](super::csharp::CSharp).
+/// [`CSharp`](super::csharp::CSharp),
+/// [`Dart`](super::dart::Dart),
+/// [`Python`](super::python::Python),
+/// [`Go`](super::go::Go),
+/// [`Java`](super::java::Java),
+/// [`Cpp`](super::cpp::Cpp).
 /// A plugin crate provides a separate `impl EmitterPlugin<L>` for each
 /// language it supports.
 ///
@@ -381,6 +402,22 @@ pub trait EmitterPlugin<L>: std::fmt::Debug {
     fn manifest_dependencies(&self) -> Vec<String> {
         vec![]
     }
+
+    // ----- validation hooks -----
+
+    /// Checks that the plugin can encode every type of the registry.
+    ///
+    /// Called by the generators and installers before any code is generated,
+    /// so that an unsupported type is reported instead of producing code that
+    /// can't round-trip.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Unsupported`] for the first type the plugin can't
+    /// encode.
+    fn check_registry(&self, _registry: &Registry) -> Result<(), Error> {
+        Ok(())
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -417,6 +454,41 @@ where
     plugins.iter().flat_map(|p| f(p.as_ref())).collect()
 }
 
+/// The annotations to write before a type declaration: those of the plugins, followed by the
+/// container's own `fg::annotate(...)` ones for `language`. Variants only get the plugins'.
+pub fn type_annotations<L>(
+    plugins: &[Arc<dyn EmitterPlugin<L>>],
+    ctx: &EmitContext,
+    language: Language,
+) -> Vec<String> {
+    let mut annotations = collect_from_plugins(plugins, |p| p.type_annotations(ctx));
+    if !ctx.is_variant() {
+        let metadata = ctx.container.format.metadata();
+        annotations.extend(metadata.annotations(language).iter().cloned());
+    }
+    annotations
+}
+
+/// The conformances to append to a type declaration: those of the plugins, followed by the
+/// container's own `fg::conforms(...)` ones for `language` that aren't already listed. Variants
+/// only get the plugins'.
+pub fn type_conformances<L>(
+    plugins: &[Arc<dyn EmitterPlugin<L>>],
+    ctx: &EmitContext,
+    language: Language,
+) -> Vec<String> {
+    let mut conformances = collect_from_plugins(plugins, |p| p.type_conformances(ctx));
+    if !ctx.is_variant() {
+        let metadata = ctx.container.format.metadata();
+        for conformance in metadata.conformances(language) {
+            if !conformances.contains(conformance) {
+                conformances.push(conformance.clone());
+            }
+        }
+    }
+    conformances
+}
+
 /// Invoke a writer-accepting plugin method across all plugins in order.
 ///
 /// Returns the first error encountered, if any.
@@ -438,6 +510,21 @@ where
     Ok(())
 }
 
+/// Check the registry with every plugin in the list.
+///
+/// # Errors
+///
+/// Returns the first error reported by a plugin's
+/// [`check_registry`](EmitterPlugin::check_registry).
+pub fn check_registry<L>(
+    plugins: &[Arc<dyn EmitterPlugin<L>>],
+    registry: &Registry,
+) -> Result<(), Error> {
+    plugins
+        .iter()
+        .try_for_each(|plugin| plugin.check_registry(registry))
+}
+
 /// Check whether *any* plugin in the list returns `true` for a predicate.
 pub fn any_plugin<L, F>(plugins: &[Arc<dyn EmitterPlugin<L>>], f: F) -> bool
 where
@@ -0,0 +1,981 @@
+//! AST-to-Python source rendering.
+//!
+//! This module implements [`Emitter<Python>`](super::super::Emitter) for each
+//! node type in the format AST, turning abstract type descriptions into
+//! type-hinted Python code.
+//!
+//! # Emitter implementations
+//!
+//! | AST node | Python output |
+//! |---|---|
+//! | [`Module`] | `import` statements and `TypeVar` declarations |
+//! | [`Container`] | `@dataclass` class, `Enum`, variant classes + `Union` alias, `NewType` or type alias |
+//! | [`Named<Format>`](Named) | A single annotated field declaration |
+//! | [`Format`] | An inline type expression (`int`, `list[str]`, `Optional[int]`, …) |
+//! | [`Doc`] | Docstrings of classes, `#:` comments of fields and aliases |
+//!
+//! # Python type mapping
+//!
+//! The [`Format`] emitter maps Rust/reflection types to Python equivalents —
+//! for example all integers → `int`, `Seq(T)` → `list[T]`, `[T; N]` →
+//! `tuple[T, ...]`, `Option(T)` → `Optional[T]`, `Bytes` → `bytes`, `Uuid` →
+//! `UUID`, `Timestamp` → `datetime` and tuples → `tuple[int, str]`.
+//!
+//! # Declarations
+//!
+//! Every type is immutable: structs become `@dataclass(frozen=True,
+//! kw_only=True)` classes, and newtype and tuple structs `@dataclass(frozen=True)`
+//! classes with positional fields. Enums whose variants are all unit variants
+//! become `Enum`s whose values are the variants' names; other enums become a
+//! dataclass per variant (e.g. `ShapeCircle`), whose `TAG` class variable is a
+//! `Literal` of the variant's name, and a `Union` of them
+//! (`Shape = Union[ShapeCircle, ShapeSquare]`), so that `match` statements and
+//! type checkers can tell the variants apart.
+//!
+//! # Plugin-dependent output
+//!
+//! The [`Python`] language tag carries a list of [`EmitterPlugin`]s, which
+//! write the serialization methods into the body of each class:
+//!
+//! - `BincodePlugin` supplies `serialize` / `deserialize` and
+//!   `bincode_serialize` / `bincode_deserialize`.
+//! - `JsonPlugin` supplies `to_json` / `from_json`.
+//! - With no plugins, only plain type declarations are emitted.
+//!
+//! A `Union` or a `NewType` can't have methods, so the plugins write module
+//! functions after them instead (e.g. `deserialize_shape`).
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::Result,
+    sync::Arc,
+};
+
+use heck::{ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+
+use crate::{
+    Registry,
+    generation::{
+        CodeGeneratorConfig, Container, Emitter,
+        indent::IndentWrite,
+        module::Module,
+        plugin::{
+            EmitContext, EmitterPlugin, VariantInfo, collect_from_plugins, type_annotations,
+            type_conformances,
+        },
+    },
+    reflection::format::{
+        ContainerFormat, DefaultValue, Deprecation, Doc, Format, FormatHolder, Language, Named,
+        VariantFormat,
+    },
+};
+
+/// Python's keywords, which can't be used as identifiers, and `self`, which a
+/// dataclass's `__init__` takes before its fields.
+const RESERVED: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "self", "try",
+    "while", "with", "yield",
+];
+
+/// The standard library modules that generated modules import from.
+const STANDARD_LIBRARY: &[&str] = &["dataclasses", "datetime", "enum", "typing", "uuid"];
+
+/// The longest line that an import is written on, as `black` formats them.
+const LINE_LENGTH: usize = 88;
+
+/// Language tag for Python code generation.
+///
+/// Passed as the `L` parameter to every [`Emitter<L>`](super::super::Emitter)
+/// call. Carries a plugin list that controls all encoding-specific behaviour.
+#[derive(Debug, Clone)]
+pub struct Python {
+    pub(crate) config: CodeGeneratorConfig,
+    pub(crate) plugins: Vec<Arc<dyn EmitterPlugin<Self>>>,
+    /// The names that the declarations of the registry import, by module.
+    imports: BTreeMap<&'static str, BTreeSet<&'static str>>,
+    /// The type parameters of the registry's generic types, declared as
+    /// module-level `TypeVar`s.
+    type_params: BTreeSet<String>,
+}
+
+impl Python {
+    /// Create a Python language tag with no default plugins.
+    ///
+    /// Use [`with_plugin`](Self::with_plugin) to attach plugins.
+    #[must_use]
+    pub fn new(config: &CodeGeneratorConfig, registry: &Registry) -> Self {
+        let mut imports = BTreeMap::<_, BTreeSet<_>>::new();
+        let mut import = |module, name| {
+            imports.entry(module).or_default().insert(name);
+        };
+        let mut type_params = BTreeSet::new();
+        for format in registry.values() {
+            let metadata = format.metadata();
+            match format {
+                ContainerFormat::NewTypeStruct(..) if metadata.proxy => {}
+                ContainerFormat::NewTypeStruct(..) if metadata.branded => {
+                    import("typing", "NewType");
+                }
+                ContainerFormat::Enum(variants, ..)
+                    if variants
+                        .values()
+                        .all(|variant| matches!(variant.value, VariantFormat::Unit)) =>
+                {
+                    import("enum", "Enum");
+                }
+                ContainerFormat::Enum(..) => {
+                    import("dataclasses", "dataclass");
+                    for name in ["ClassVar", "Literal", "Union"] {
+                        import("typing", name);
+                    }
+                }
+                ContainerFormat::Struct(fields, ..) => {
+                    import("dataclasses", "dataclass");
+                    if fields.iter().any(|field| {
+                        field_default(field).is_some_and(|value| value.starts_with("field("))
+                    }) {
+                        import("dataclasses", "field");
+                    }
+                }
+                _ => import("dataclasses", "dataclass"),
+            }
+            if !metadata.type_params.is_empty() {
+                import("typing", "Generic");
+                import("typing", "TypeVar");
+                type_params.extend(metadata.type_params.iter().cloned());
+            }
+            let _ = format.visit(&mut |format| {
+                match format {
+                    Format::Option(_) => import("typing", "Optional"),
+                    Format::Uuid => import("uuid", "UUID"),
+                    Format::Date => import("datetime", "date"),
+                    Format::Time => import("datetime", "time"),
+                    Format::Timestamp => import("datetime", "datetime"),
+                    Format::Duration => import("datetime", "timedelta"),
+                    _ => {}
+                }
+                Ok(())
+            });
+        }
+        Self {
+            config: config.clone(),
+            plugins: vec![],
+            imports,
+            type_params,
+        }
+    }
+
+    /// Access the generator config.
+    #[must_use]
+    pub const fn config(&self) -> &CodeGeneratorConfig {
+        &self.config
+    }
+
+    /// Add a plugin to this language tag, returning the modified tag.
+    ///
+    /// Plugins are invoked in the order they are added.
+    #[must_use]
+    pub fn with_plugin(mut self, plugin: Arc<dyn EmitterPlugin<Self>>) -> Self {
+        self.plugins.push(plugin);
+        self
+    }
+
+    /// Access the plugin list.
+    #[must_use]
+    pub fn plugins(&self) -> &[Arc<dyn EmitterPlugin<Self>>] {
+        &self.plugins
+    }
+}
+
+/// The imports of a module: the names imported from each module (`from typing import Any`),
+/// and the modules imported whole (`import other as other`).
+#[derive(Default)]
+struct Imports {
+    from: BTreeMap<String, BTreeSet<String>>,
+    modules: BTreeSet<String>,
+}
+
+impl Imports {
+    /// Adds an import statement, merging the names of a `from … import …` with those already
+    /// imported from the same module.
+    fn add(&mut self, statement: &str) {
+        if let Some((module, names)) = statement
+            .strip_prefix("from ")
+            .and_then(|statement| statement.split_once(" import "))
+        {
+            let names = names.split(", ").map(ToString::to_string);
+            self.from
+                .entry(module.to_string())
+                .or_default()
+                .extend(names);
+        } else if let Some(module) = statement.strip_prefix("import ") {
+            self.modules.insert(module.to_string());
+        }
+    }
+
+    /// Writes the imports in sections, like `isort`: the standard library, then other
+    /// packages, then the package's own modules, with whole-module imports first in each.
+    fn write<W: IndentWrite>(&self, w: &mut W) -> Result<()> {
+        let section = |module: &str| {
+            if STANDARD_LIBRARY.contains(&module) {
+                0
+            } else if module.starts_with('.') {
+                2
+            } else {
+                1
+            }
+        };
+        for rank in 0..3 {
+            let modules = self
+                .modules
+                .iter()
+                .filter(|module| section(module) == rank)
+                .collect::<Vec<_>>();
+            let from = self
+                .from
+                .iter()
+                .filter(|(module, _)| section(module) == rank)
+                .collect::<Vec<_>>();
+            if modules.is_empty() && from.is_empty() {
+                continue;
+            }
+            writeln!(w)?;
+            for module in modules {
+                writeln!(w, "import {module}")?;
+            }
+            for (module, names) in from {
+                let names = names.iter().map(String::as_str).collect::<Vec<_>>();
+                let line = format!("from {module} import {}", names.join(", "));
+                if line.len() <= LINE_LENGTH {
+                    writeln!(w, "{line}")?;
+                    continue;
+                }
+                // Too long for a line: one name per line, like `black`.
+                writeln!(w, "from {module} import (")?;
+                w.indent();
+                for name in names {
+                    writeln!(w, "{name},")?;
+                }
+                w.unindent();
+                writeln!(w, ")")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Emitter<Python> for Module {
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Python) -> Result<()> {
+        let config = self.config();
+
+        writeln!(w, "from __future__ import annotations")?;
+
+        let mut imports = Imports::default();
+        for (module, names) in &lang.imports {
+            for name in names {
+                imports.add(&format!("from {module} import {name}"));
+            }
+        }
+        if !lang.type_params.is_empty() && !lang.plugins().is_empty() {
+            // The (de)serializers of the type parameters.
+            imports.add("from typing import Callable");
+        }
+        for namespace in &config.referenced_namespaces {
+            let prefix = module_prefix(namespace);
+            let alias = |name: &str| {
+                if name == prefix {
+                    String::new()
+                } else {
+                    format!(" as {prefix}")
+                }
+            };
+            let module = import_module(config, namespace, &format!(".{namespace}"));
+            match module.rsplit_once('.') {
+                Some((package, name)) => {
+                    let package = if package.is_empty() { "." } else { package };
+                    imports.add(&format!("from {package} import {name}{}", alias(name)));
+                }
+                None => imports.add(&format!("import {module}{}", alias(&module))),
+            }
+        }
+        for statement in collect_from_plugins(lang.plugins(), |p| p.imports(config)) {
+            imports.add(&statement);
+        }
+        imports.write(w)?;
+
+        if !lang.type_params.is_empty() {
+            writeln!(w)?;
+            for param in &lang.type_params {
+                writeln!(w, "{param} = TypeVar({})", string_literal(param))?;
+            }
+        }
+
+        let mut helpers = vec![];
+        {
+            let mut hw = w.child(&mut helpers);
+            for plugin in lang.plugins() {
+                plugin.module_helpers(&mut hw, config)?;
+            }
+        }
+        if !helpers.is_empty() {
+            writeln!(w)?;
+            writeln!(w)?;
+            w.write_all(&helpers)?;
+        }
+
+        writeln!(w)?;
+        writeln!(w)
+    }
+}
+
+impl Emitter<Python> for Container<'_> {
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Python) -> Result<()> {
+        let metadata = self.format.metadata();
+        let value = |format: &Format| Named::new(format, "value".to_string()).inheriting(metadata);
+        let ctx = EmitContext::top_level(self, &lang.config);
+        match self.format {
+            ContainerFormat::UnitStruct(doc, _) => {
+                let class = Class::top_level(self, Parameters::Positional, vec![]);
+                class.write(w, doc, &ctx, lang)?;
+            }
+            ContainerFormat::NewTypeStruct(format, doc, metadata) if metadata.proxy => {
+                alias(w, self, doc, |w| format.write(w, lang))?;
+            }
+            ContainerFormat::NewTypeStruct(format, doc, metadata) if metadata.branded => {
+                let field = value(format);
+                alias(w, self, doc, |w| {
+                    write!(w, "NewType({}, ", string_literal(&self.name.name))?;
+                    match field.metadata.type_override(Language::Python) {
+                        Some(type_override) => write!(w, "{}", type_override.ty)?,
+                        None => field.value.write(w, lang)?,
+                    }
+                    write!(w, ")")
+                })?;
+            }
+            ContainerFormat::NewTypeStruct(format, doc, _) => {
+                let class = Class::top_level(self, Parameters::Positional, vec![value(format)]);
+                class.write(w, doc, &ctx, lang)?;
+            }
+            ContainerFormat::TupleStruct(formats, doc, _) => {
+                let fields = named(formats)
+                    .iter()
+                    .map(|field| field.inheriting(metadata))
+                    .collect();
+                let class = Class::top_level(self, Parameters::Positional, fields);
+                class.write(w, doc, &ctx, lang)?;
+            }
+            ContainerFormat::Struct(fields, doc, _) => {
+                let fields = fields
+                    .iter()
+                    .map(|field| field.inheriting(metadata))
+                    .collect();
+                let class = Class::top_level(self, Parameters::KeywordOnly, fields);
+                class.write(w, doc, &ctx, lang)?;
+            }
+            ContainerFormat::Enum(variants, _, doc, _) => {
+                if variants
+                    .values()
+                    .all(|variant| matches!(variant.value, VariantFormat::Unit))
+                {
+                    enumeration(w, self, doc, &ctx, lang)?;
+                } else {
+                    union(w, self, doc, lang)?;
+                }
+            }
+        }
+
+        for plugin in lang.plugins() {
+            let mut after = vec![];
+            plugin.after_type(&mut w.child(&mut after), &ctx)?;
+            if !after.is_empty() {
+                writeln!(w)?;
+                writeln!(w)?;
+                w.write_all(&after)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Emitter<Python> for Named<Format> {
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Python) -> Result<()> {
+        write_field(w, self, field_default(self).as_deref(), lang)
+    }
+}
+
+/// Writes a field declaration, with its docs as `#:` comments and `= default` if it has one.
+fn write_field<W: IndentWrite>(
+    w: &mut W,
+    field: &Named<Format>,
+    default: Option<&str>,
+    lang: &Python,
+) -> Result<()> {
+    write_comments(w, &field.doc, field.metadata.deprecated.as_ref())?;
+    write!(w, "{}: ", identifier(&field.name))?;
+    match field.metadata.type_override(Language::Python) {
+        Some(type_override) => write!(w, "{}", type_override.ty)?,
+        None => field.value.write(w, lang)?,
+    }
+    match default {
+        Some(default) => writeln!(w, " = {default}"),
+        None => writeln!(w),
+    }
+}
+
+impl Emitter<Python> for Doc {
+    /// Writes the docs as a docstring, on one line if they fit on one.
+    fn write<W: IndentWrite>(&self, w: &mut W, _lang: &Python) -> Result<()> {
+        write_docstring(w, self.comments())
+    }
+}
+
+impl Emitter<Python> for Format {
+    fn write<W: IndentWrite>(&self, w: &mut W, lang: &Python) -> Result<()> {
+        match self {
+            Self::Variable(_variable) => unreachable!("placeholders should not get this far"),
+            Self::TypeName(qualified_name) => {
+                write!(w, "{}", qualified_name.format(module_prefix, "."))
+            }
+            Self::Generic { name, args } => {
+                write!(w, "{}[", name.format(module_prefix, "."))?;
+                write_formats(w, args, lang)?;
+                write!(w, "]")
+            }
+            Self::TypeParameter(name) => write!(w, "{name}"),
+            Self::Unit => write!(w, "None"),
+            Self::Bool => write!(w, "bool"),
+            Self::I8
+            | Self::I16
+            | Self::I32
+            | Self::I64
+            | Self::I128
+            | Self::U8
+            | Self::U16
+            | Self::U32
+            | Self::U64
+            | Self::U128 => write!(w, "int"),
+            Self::F32 | Self::F64 => write!(w, "float"),
+            Self::Char | Self::Str => write!(w, "str"),
+            Self::Bytes => write!(w, "bytes"),
+            Self::Uuid => write!(w, "UUID"),
+            Self::Date => write!(w, "date"),
+            Self::Time => write!(w, "time"),
+            Self::Timestamp => write!(w, "datetime"),
+            Self::Duration => write!(w, "timedelta"),
+            Self::Option(format) => {
+                write!(w, "Optional[")?;
+                format.write(w, lang)?;
+                write!(w, "]")
+            }
+            Self::Seq(format) => {
+                write!(w, "list[")?;
+                format.write(w, lang)?;
+                write!(w, "]")
+            }
+            // Immutable, so that arrays can be keys of a `dict`
+            Self::TupleArray { content, .. } => {
+                write!(w, "tuple[")?;
+                content.write(w, lang)?;
+                write!(w, ", ...]")
+            }
+            Self::Set(format) => {
+                write!(w, "set[")?;
+                format.write(w, lang)?;
+                write!(w, "]")
+            }
+            Self::Map { key, value } => {
+                write!(w, "dict[")?;
+                key.write(w, lang)?;
+                write!(w, ", ")?;
+                value.write(w, lang)?;
+                write!(w, "]")
+            }
+            Self::Tuple(formats) => match formats.as_slice() {
+                [] => write!(w, "None"),
+                // A single-element tuple is just the element itself
+                [format] => format.write(w, lang),
+                formats => {
+                    write!(w, "tuple[")?;
+                    write_formats(w, formats, lang)?;
+                    write!(w, "]")
+                }
+            },
+        }
+    }
+}
+
+fn write_formats<W: IndentWrite>(w: &mut W, formats: &[Format], lang: &Python) -> Result<()> {
+    for (i, format) in formats.iter().enumerate() {
+        if i > 0 {
+            write!(w, ", ")?;
+        }
+        format.write(w, lang)?;
+    }
+    Ok(())
+}
+
+/// The name that a module is imported as, e.g. `other_module`.
+fn module_prefix(namespace: &str) -> String {
+    namespace.to_snake_case()
+}
+
+/// How a dataclass's `__init__` takes its fields.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Parameters {
+    /// `Point(x=1.0, y=2.0)` — for structs and struct variants. Optional fields default to
+    /// `None`.
+    KeywordOnly,
+    /// `UserId("abc")` — for newtype and tuple structs and variants.
+    Positional,
+}
+
+/// A frozen `@dataclass`: a struct, or a variant of an enum whose variants carry data.
+struct Class<'a> {
+    name: String,
+    type_params: Vec<String>,
+    /// The Rust name of a variant, which its `TAG` class variable holds.
+    tag: Option<&'a str>,
+    parameters: Parameters,
+    fields: Vec<Named<Format>>,
+    deprecated: Option<&'a Deprecation>,
+}
+
+impl<'a> Class<'a> {
+    fn top_level(
+        container: &'a Container,
+        parameters: Parameters,
+        fields: Vec<Named<Format>>,
+    ) -> Self {
+        let metadata = container.format.metadata();
+        Self {
+            name: container.name.name.clone(),
+            type_params: metadata.type_params.clone(),
+            tag: None,
+            parameters,
+            fields,
+            deprecated: metadata.deprecated.as_ref(),
+        }
+    }
+
+    fn write<W: IndentWrite>(
+        &self,
+        w: &mut W,
+        doc: &Doc,
+        ctx: &EmitContext,
+        lang: &Python,
+    ) -> Result<()> {
+        for annotation in annotations(ctx, lang) {
+            writeln!(w, "{annotation}")?;
+        }
+        match self.parameters {
+            Parameters::KeywordOnly => writeln!(w, "@dataclass(frozen=True, kw_only=True)")?,
+            Parameters::Positional => writeln!(w, "@dataclass(frozen=True)")?,
+        }
+        let mut bases = conformances(ctx, lang);
+        if !self.type_params.is_empty() {
+            bases.push(format!("Generic[{}]", self.type_params.join(", ")));
+        }
+        write!(w, "class {}", self.name)?;
+        write_bases(w, &bases)?;
+        writeln!(w, ":")?;
+        w.indent();
+
+        let mut separate = write_class_docstring(w, doc, self.deprecated)?;
+        if self.tag.is_some() || !self.fields.is_empty() {
+            if separate {
+                writeln!(w)?;
+            }
+            separate = true;
+        }
+        if let Some(tag) = self.tag {
+            let tag = string_literal(tag);
+            writeln!(w, "TAG: ClassVar[Literal[{tag}]] = {tag}")?;
+        }
+        for field in &self.fields {
+            match self.parameters {
+                Parameters::KeywordOnly => {
+                    let default = field_default(field).or_else(|| {
+                        let optional = matches!(field.value, Format::Option(_))
+                            && field.metadata.type_override(Language::Python).is_none();
+                        optional.then(|| "None".to_string())
+                    });
+                    write_field(w, field, default.as_deref(), lang)?;
+                }
+                Parameters::Positional => write_field(w, field, None, lang)?,
+            }
+        }
+
+        if !write_plugin_bodies(w, ctx, lang, separate)? && !separate {
+            writeln!(w, "pass")?;
+        }
+        w.unindent();
+        Ok(())
+    }
+}
+
+/// The Python value of a field's default value, if it has one: a literal, or a
+/// `field(default_factory=…)` for empty collections.
+pub(crate) fn field_default(field: &Named<Format>) -> Option<String> {
+    if field.metadata.type_override(Language::Python).is_some() {
+        return None;
+    }
+    let default = field.metadata.default.as_ref()?;
+    Some(match (default, &field.value) {
+        (DefaultValue::Empty, Format::Option(_)) => "None".to_string(),
+        (DefaultValue::Empty, Format::Seq(_)) => "field(default_factory=list)".to_string(),
+        (DefaultValue::Empty, Format::Set(_)) => "field(default_factory=set)".to_string(),
+        (DefaultValue::Empty, Format::Map { .. }) => "field(default_factory=dict)".to_string(),
+        (DefaultValue::Empty, Format::Bytes) => "b\"\"".to_string(),
+        (DefaultValue::Bool(true), Format::Bool) => "True".to_string(),
+        (DefaultValue::Bool(false), Format::Bool) => "False".to_string(),
+        (
+            DefaultValue::Integer(value),
+            Format::I8
+            | Format::I16
+            | Format::I32
+            | Format::I64
+            | Format::I128
+            | Format::U8
+            | Format::U16
+            | Format::U32
+            | Format::U64
+            | Format::U128,
+        )
+        | (DefaultValue::Float(value), Format::F32 | Format::F64) => value.clone(),
+        (DefaultValue::Char(value), Format::Char) => string_literal(&value.to_string()),
+        (DefaultValue::Str(value), Format::Str) => string_literal(value),
+        _ => return None,
+    })
+}
+
+/// Emits a module-level alias: a serialization proxy's type alias (uses of the type have
+/// already been replaced by the proxy's format, so it needs no plugin code), or a branded
+/// newtype's `NewType`.
+fn alias<W: IndentWrite>(
+    w: &mut W,
+    container: &Container,
+    doc: &Doc,
+    value: impl FnOnce(&mut W) -> Result<()>,
+) -> Result<()> {
+    let metadata = container.format.metadata();
+    write_comments(w, doc, metadata.deprecated.as_ref())?;
+    write!(w, "{} = ", container.name.name)?;
+    value(w)?;
+    writeln!(w)
+}
+
+/// Emits an `Enum` — used when all variants are unit variants. Each member's value is the
+/// variant's name.
+fn enumeration<W: IndentWrite>(
+    w: &mut W,
+    container: &Container,
+    doc: &Doc,
+    ctx: &EmitContext,
+    lang: &Python,
+) -> Result<()> {
+    let ContainerFormat::Enum(variants, _, _, metadata) = container.format else {
+        unreachable!("only enums are emitted as Python enums")
+    };
+    for annotation in annotations(ctx, lang) {
+        writeln!(w, "{annotation}")?;
+    }
+    let mut bases = conformances(ctx, lang);
+    bases.push("Enum".to_string());
+    write!(w, "class {}", container.name.name)?;
+    write_bases(w, &bases)?;
+    writeln!(w, ":")?;
+    w.indent();
+
+    let mut separate = write_class_docstring(w, doc, metadata.deprecated.as_ref())?;
+    if separate && !variants.is_empty() {
+        writeln!(w)?;
+    }
+    for variant in variants.values() {
+        write_comments(w, &variant.doc, variant.metadata.deprecated.as_ref())?;
+        writeln!(
+            w,
+            "{} = {}",
+            enum_member(&variant.name),
+            string_literal(&variant.name)
+        )?;
+    }
+
+    separate |= !variants.is_empty();
+
+    if !write_plugin_bodies(w, ctx, lang, separate)? && !separate {
+        writeln!(w, "pass")?;
+    }
+    w.unindent();
+    Ok(())
+}
+
+/// Emits a dataclass for each variant — used when at least one variant carries data —
+/// followed by the `Union` of them, which is the enum's type.
+///
+/// Variant classes only declare the type parameters they use, and get the enum's own
+/// `fg::annotate` decorators and `fg::conforms` bases.
+fn union<W: IndentWrite>(w: &mut W, container: &Container, doc: &Doc, lang: &Python) -> Result<()> {
+    let ContainerFormat::Enum(variants, _, _, metadata) = container.format else {
+        unreachable!("only enums are emitted as unions")
+    };
+    let name = &container.name.name;
+    let type_params = &metadata.type_params;
+
+    let mut members = vec![];
+    for (index, variant) in variants {
+        let (parameters, fields) = match &variant.value {
+            VariantFormat::Variable(_) => unreachable!("placeholders should not get this far"),
+            VariantFormat::Unit => (Parameters::Positional, vec![]),
+            VariantFormat::NewType(format) => (
+                Parameters::Positional,
+                vec![Named::new(format.as_ref(), "value".to_string())],
+            ),
+            VariantFormat::Tuple(formats) => (Parameters::Positional, named(formats)),
+            VariantFormat::Struct(fields) => (Parameters::KeywordOnly, fields.clone()),
+        };
+        let used = used_type_params(type_params, &fields);
+        let class = Class {
+            name: variant_class(name, &variant.name),
+            type_params: used,
+            tag: Some(&variant.name),
+            parameters,
+            fields,
+            deprecated: variant.metadata.deprecated.as_ref(),
+        };
+        members.push(if class.type_params.is_empty() {
+            class.name.clone()
+        } else {
+            format!("{}[{}]", class.name, class.type_params.join(", "))
+        });
+        let variant_ctx = EmitContext::for_variant(
+            container,
+            &lang.config,
+            VariantInfo {
+                name: &variant.name,
+                index: *index as usize,
+                format: &variant.value,
+                fields: &class.fields,
+                parent_name: name,
+                parent_type_params: type_params,
+            },
+        );
+        class.write(w, &variant.doc, &variant_ctx, lang)?;
+        writeln!(w)?;
+        writeln!(w)?;
+    }
+
+    write_comments(w, doc, metadata.deprecated.as_ref())?;
+    writeln!(w, "{name} = Union[{}]", members.join(", "))
+}
+
+/// Writes the bodies of the plugins that have one, separated by blank lines, and after a blank
+/// line if `separate` (i.e. members were written before them). Returns whether any was written.
+fn write_plugin_bodies<W: IndentWrite>(
+    w: &mut W,
+    ctx: &EmitContext,
+    lang: &Python,
+    mut separate: bool,
+) -> Result<bool> {
+    let mut written = false;
+    for plugin in lang.plugins() {
+        if plugin.has_type_body(ctx) {
+            if separate {
+                writeln!(w)?;
+            }
+            plugin.type_body(w as &mut dyn IndentWrite, ctx)?;
+            separate = true;
+            written = true;
+        }
+    }
+    Ok(written)
+}
+
+/// The decorators of a class: those of the plugins, followed by any declared with
+/// `fg::annotate`, which the variants of an enum share.
+fn annotations(ctx: &EmitContext, lang: &Python) -> Vec<String> {
+    let mut annotations = type_annotations(lang.plugins(), ctx, Language::Python);
+    if ctx.is_variant() {
+        let metadata = ctx.container.format.metadata();
+        annotations.extend(metadata.annotations(Language::Python).iter().cloned());
+    }
+    annotations
+}
+
+/// The base classes of a class: the conformances of the plugins, followed by any declared with
+/// `fg::conforms`, which the variants of an enum share.
+fn conformances(ctx: &EmitContext, lang: &Python) -> Vec<String> {
+    let mut conformances = type_conformances(lang.plugins(), ctx, Language::Python);
+    if ctx.is_variant() {
+        let metadata = ctx.container.format.metadata();
+        for conformance in metadata.conformances(Language::Python) {
+            if !conformances.contains(conformance) {
+                conformances.push(conformance.clone());
+            }
+        }
+    }
+    conformances
+}
+
+/// Writes the `(A, B)` base class list of a class, or nothing if it has none.
+fn write_bases<W: IndentWrite>(w: &mut W, bases: &[String]) -> Result<()> {
+    if !bases.is_empty() {
+        write!(w, "({})", bases.join(", "))?;
+    }
+    Ok(())
+}
+
+/// Writes the docstring of a class, with a note if it is deprecated. Returns whether one was
+/// written.
+fn write_class_docstring<W: IndentWrite>(
+    w: &mut W,
+    doc: &Doc,
+    deprecated: Option<&Deprecation>,
+) -> Result<bool> {
+    let mut lines = doc.comments().to_vec();
+    if let Some(note) = deprecation_note(deprecated) {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(note);
+    }
+    write_docstring(w, &lines)?;
+    Ok(!lines.is_empty())
+}
+
+/// Writes a docstring of `lines`, on one line if there is only one.
+fn write_docstring<W: IndentWrite>(w: &mut W, lines: &[String]) -> Result<()> {
+    let escape = |line: &str| {
+        let line = line.replace('\\', "\\\\").replace("\"\"\"", "\\\"\\\"\\\"");
+        match line.strip_suffix('"') {
+            Some(line) => format!("{line}\\\""),
+            None => line,
+        }
+    };
+    match lines {
+        [] => Ok(()),
+        [line] => writeln!(w, "\"\"\"{}\"\"\"", escape(line)),
+        lines => {
+            writeln!(w, "\"\"\"")?;
+            for line in lines {
+                writeln!(w, "{}", escape(line))?;
+            }
+            writeln!(w, "\"\"\"")
+        }
+    }
+}
+
+/// Writes the docs of a field, enum member or alias as `#:` comments, which documentation
+/// tools pick up, with a note if it is deprecated.
+fn write_comments<W: IndentWrite>(
+    w: &mut W,
+    doc: &Doc,
+    deprecated: Option<&Deprecation>,
+) -> Result<()> {
+    for comment in doc.comments() {
+        if comment.is_empty() {
+            writeln!(w, "#:")?;
+        } else {
+            writeln!(w, "#: {comment}")?;
+        }
+    }
+    if let Some(note) = deprecation_note(deprecated) {
+        writeln!(w, "#: {note}")?;
+    }
+    Ok(())
+}
+
+/// The note in the docs of a deprecated declaration, if it is one.
+fn deprecation_note(deprecated: Option<&Deprecation>) -> Option<String> {
+    Some(match deprecated?.message() {
+        Some(message) => format!("Deprecated: {message}"),
+        None => "Deprecated.".to_string(),
+    })
+}
+
+/// The type parameters of a generic enum that a variant's fields use.
+fn used_type_params(type_params: &[String], fields: &[Named<Format>]) -> Vec<String> {
+    type_params
+        .iter()
+        .filter(|param| {
+            fields
+                .iter()
+                .any(|field| field.value.uses_type_parameter(param))
+        })
+        .cloned()
+        .collect()
+}
+
+fn named<Format: Clone>(formats: &[Format]) -> Vec<Named<Format>> {
+    formats
+        .iter()
+        .enumerate()
+        .map(|(i, f)| Named::new(f, format!("field{i}")))
+        .collect()
+}
+
+/// The module that a module imports `namespace` from: the module of the external package
+/// configured for it (e.g. `shared_types.models`), if there is one, or else `local` (relative
+/// to the module, e.g. `.serde`).
+#[must_use]
+pub(crate) fn import_module(config: &CodeGeneratorConfig, namespace: &str, local: &str) -> String {
+    config.external_packages.get(namespace).map_or_else(
+        || local.to_string(),
+        |package| {
+            let module = package.module_name.as_deref().unwrap_or(namespace);
+            format!("{}.{module}", package.for_namespace)
+        },
+    )
+}
+
+/// The Python identifier of a field, e.g. `created_at` for `createdAt`, with a trailing `_` if
+/// it is reserved (`class_`).
+#[must_use]
+pub(crate) fn identifier(name: &str) -> String {
+    let name = name.to_snake_case();
+    if RESERVED.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+/// The Python identifier of a member of an `Enum`, e.g. `DARK_RED`.
+#[must_use]
+pub(crate) fn enum_member(name: &str) -> String {
+    name.to_shouty_snake_case()
+}
+
+/// The name of the class of a variant of an enum whose variants carry data, e.g.
+/// `ShapeCircle`.
+#[must_use]
+pub(crate) fn variant_class(parent: &str, variant: &str) -> String {
+    format!("{parent}{}", variant.to_upper_camel_case())
+}
+
+/// The name of the module function that does `action` for the type `name`, e.g.
+/// `deserialize_shape`.
+#[must_use]
+pub(crate) fn function_name(action: &str, name: &str) -> String {
+    format!("{action}_{}", name.to_snake_case())
+}
+
+/// A Python string literal of `text`.
+#[must_use]
+pub(crate) fn string_literal(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t");
+    format!("\"{escaped}\"")
+}
+
+#[cfg(test)]
+mod tests;
+#[cfg(test)]
+mod tests_bincode;
+#[cfg(test)]
+mod tests_json;
@@ -0,0 +1,425 @@
+//! Snapshot tests for the Python emitter — **no serialization**.
+//!
+//! Each test defines one or more Rust types annotated with `#[derive(Facet)]`,
+//! runs them through the [`emit!`] macro with no plugins, and asserts the
+//! generated Python source against an [`insta`] inline snapshot.
+//!
+//! Because no plugins are configured, the output contains only plain type
+//! declarations (frozen `@dataclass`es, `Enum`s, variant classes + `Union`)
+//! with no serialization methods.
+//!
+//! # Coverage
+//!
+//! | Category | What is tested |
+//! |----------|----------------|
+//! | Structs | Unit structs, newtype wrappers, tuple structs, structs with primitive and user-defined fields |
+//! | Enums | All-unit enums (`Enum`), data enums (`Union` of variant dataclasses), generic enums |
+//! | Collections | `Vec`, `HashMap`, `BTreeSet`, fixed-size arrays, tuples |
+//! | Optional | `Option<T>` fields (`Optional`, defaulting to `None`) |
+//! | Declarations | Branded newtypes (`NewType`), proxies (type aliases), deprecation, reserved words |
+//! | Namespaces | Multi-module generation via [`emit_two_modules!`] |
+
+#![allow(clippy::too_many_lines)]
+use std::collections::{BTreeSet, HashMap};
+
+use crate as fg;
+use facet::Facet;
+
+use super::*;
+use crate::{emit, emit_two_modules, generation::python::PythonCodeGenerator};
+
+#[test]
+fn unit_struct() {
+    /// line 1
+    #[derive(Facet)]
+    /// line 2
+    struct UnitStruct;
+
+    let actual = emit!(UnitStruct as Python).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True)
+    class UnitStruct:
+        """
+        line 1
+        line 2
+        """
+    "#);
+}
+
+#[test]
+fn newtype_struct() {
+    #[derive(Facet)]
+    struct NewType(String);
+
+    let actual = emit!(NewType as Python).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    @dataclass(frozen=True)
+    class NewType:
+        value: str
+    ");
+}
+
+#[test]
+fn tuple_struct() {
+    #[derive(Facet)]
+    struct TupleStruct(String, i32);
+
+    let actual = emit!(TupleStruct as Python).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    @dataclass(frozen=True)
+    class TupleStruct:
+        field0: str
+        field1: int
+    ");
+}
+
+#[test]
+fn struct_with_fields() {
+    #[derive(Facet)]
+    struct Inner {
+        value: u64,
+    }
+
+    /// A struct.
+    #[derive(Facet)]
+    struct Outer {
+        /// The name.
+        name: String,
+        flag: bool,
+        big: i128,
+        ratio: f32,
+        letter: char,
+        nickname: Option<String>,
+        inner: Inner,
+        tags: Vec<String>,
+        counts: HashMap<String, u32>,
+        ids: BTreeSet<u16>,
+        pair: (u8, String),
+        grid: [i16; 3],
+        nothing: (),
+    }
+
+    let actual = emit!(Outer as Python).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True, kw_only=True)
+    class Inner:
+        value: int
+
+    @dataclass(frozen=True, kw_only=True)
+    class Outer:
+        """A struct."""
+
+        #: The name.
+        name: str
+        flag: bool
+        big: int
+        ratio: float
+        letter: str
+        nickname: Optional[str] = None
+        inner: Inner
+        tags: list[str]
+        counts: dict[str, int]
+        ids: set[int]
+        pair: tuple[int, str]
+        grid: tuple[int, ...]
+        nothing: None
+    "#);
+}
+
+#[test]
+fn reserved_names() {
+    #[derive(Facet)]
+    struct Keywords {
+        class: String,
+        r#in: bool,
+        from: u32,
+        lambda: String,
+    }
+
+    let actual = emit!(Keywords as Python).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    @dataclass(frozen=True, kw_only=True)
+    class Keywords:
+        class_: str
+        in_: bool
+        from_: int
+        lambda_: str
+    ");
+}
+
+#[test]
+fn unit_enum() {
+    /// A colour.
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Colour {
+        /// The first one.
+        Red,
+        Green,
+        LightBlue,
+    }
+
+    let actual = emit!(Colour as Python).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    class Colour(Enum):
+        """A colour."""
+
+        #: The first one.
+        RED = "Red"
+        GREEN = "Green"
+        LIGHT_BLUE = "LightBlue"
+    "#);
+}
+
+#[test]
+fn data_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Line(f64, f64),
+        Rectangle { width: f64, height: f64 },
+    }
+
+    let actual = emit!(Shape as Python).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True)
+    class ShapePoint:
+        TAG: ClassVar[Literal["Point"]] = "Point"
+
+
+    @dataclass(frozen=True)
+    class ShapeCircle:
+        TAG: ClassVar[Literal["Circle"]] = "Circle"
+        value: float
+
+
+    @dataclass(frozen=True)
+    class ShapeLine:
+        TAG: ClassVar[Literal["Line"]] = "Line"
+        field0: float
+        field1: float
+
+
+    @dataclass(frozen=True, kw_only=True)
+    class ShapeRectangle:
+        TAG: ClassVar[Literal["Rectangle"]] = "Rectangle"
+        width: float
+        height: float
+
+
+    Shape = Union[ShapePoint, ShapeCircle, ShapeLine, ShapeRectangle]
+    "#);
+}
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as Python).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    @dataclass(frozen=True, kw_only=True)
+    class Listing:
+        names: Page[str]
+        ids: Page[int]
+
+    @dataclass(frozen=True, kw_only=True)
+    class Page(Generic[T]):
+        items: list[T]
+        next: Optional[T] = None
+        total: int
+    ");
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E, retry: bool },
+        Pending,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        first: Outcome<String, i32>,
+        second: Outcome<bool, String>,
+    }
+
+    let actual = emit!(Response as Python).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True)
+    class OutcomeSuccess(Generic[T]):
+        TAG: ClassVar[Literal["Success"]] = "Success"
+        value: T
+
+
+    @dataclass(frozen=True, kw_only=True)
+    class OutcomeFailure(Generic[E]):
+        TAG: ClassVar[Literal["Failure"]] = "Failure"
+        error: E
+        retry: bool
+
+
+    @dataclass(frozen=True)
+    class OutcomePending:
+        TAG: ClassVar[Literal["Pending"]] = "Pending"
+
+
+    Outcome = Union[OutcomeSuccess[T], OutcomeFailure[E], OutcomePending]
+
+    @dataclass(frozen=True, kw_only=True)
+    class Response:
+        first: Outcome[str, int]
+        second: Outcome[bool, str]
+    "#);
+}
+
+#[test]
+fn branded_newtype() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    let actual = emit!(UserId as Python).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    UserId = NewType("UserId", str)
+    "#);
+}
+
+#[test]
+fn serialized_as_proxy() {
+    #[derive(Facet)]
+    #[facet(fg::serialized_as = "String")]
+    struct ItemId(u64);
+
+    #[derive(Facet)]
+    struct Item {
+        id: ItemId,
+    }
+
+    let actual = emit!(Item as Python).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    @dataclass(frozen=True, kw_only=True)
+    class Item:
+        id: str
+
+    ItemId = str
+    ");
+}
+
+#[test]
+fn deprecated() {
+    #[derive(Facet)]
+    #[facet(fg::deprecated(since = "0.2.0", note = "Use `Circle` instead"))]
+    struct Round {
+        #[facet(fg::deprecated)]
+        radius: f64,
+    }
+
+    let actual = emit!(Round as Python).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True, kw_only=True)
+    class Round:
+        """Deprecated: Use `Circle` instead (since 0.2.0)"""
+
+        #: Deprecated.
+        radius: float
+    "#);
+}
+
+#[test]
+fn defaults() {
+    #[derive(Facet)]
+    struct Settings {
+        #[facet(default = "$HOME".to_string())]
+        directory: String,
+        #[facet(default = 30)]
+        timeout: u64,
+        #[facet(default)]
+        counts: HashMap<String, u32>,
+        retries: u32,
+    }
+
+    let actual = emit!(Settings as Python).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True, kw_only=True)
+    class Settings:
+        directory: str = "$HOME"
+        timeout: int = 30
+        counts: dict[str, int] = field(default_factory=dict)
+        retries: int
+    "#);
+}
+
+#[test]
+fn two_modules() {
+    mod other {
+        use crate as fg;
+        use facet::Facet;
+
+        #[derive(Facet)]
+        #[facet(fg::namespace = "other")]
+        pub struct Child {
+            value: i32,
+        }
+    }
+
+    #[derive(Facet)]
+    struct Parent {
+        other_child: other::Child,
+    }
+
+    let (other, root) = emit_two_modules!(PythonCodeGenerator, Parent, "root");
+    insta::assert_snapshot!(other, @"
+    from __future__ import annotations
+
+    from dataclasses import dataclass
+
+
+    @dataclass(frozen=True, kw_only=True)
+    class Child:
+        value: int
+    ");
+    insta::assert_snapshot!(root, @"
+    from __future__ import annotations
+
+    from dataclasses import dataclass
+
+    from . import other
+
+
+    @dataclass(frozen=True, kw_only=True)
+    class Parent:
+        other_child: other.Child
+    ");
+}
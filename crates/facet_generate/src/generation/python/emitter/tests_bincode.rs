@@ -0,0 +1,784 @@
+//! Snapshot tests for the Python emitter — **Bincode encoding**.
+//!
+//! Mirrors the structure of [`tests`](super::tests) but uses `BincodePlugin`,
+//! so that every class gets `serialize` / `bincode_serialize` methods and
+//! `deserialize` / `bincode_deserialize` class methods written against the
+//! runtime's `Serializer` / `Deserializer`, and every `Union` and `NewType`
+//! gets module functions.
+//!
+//! These tests verify field ordering, container depth tracking, the lambdas
+//! passed to the collection helpers (`serialize_seq`, `deserialize_map`, …),
+//! variant indices and the (de)serializers of type parameters.
+
+#![allow(clippy::too_many_lines)]
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use crate::{self as fg, generation::bincode::BincodePlugin};
+use facet::Facet;
+
+use super::*;
+use crate::emit;
+
+#[test]
+fn unit_struct() {
+    #[derive(Facet)]
+    struct UnitStruct;
+
+    let actual = emit!(UnitStruct as Python with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True)
+    class UnitStruct:
+        def serialize(self, serializer: Serializer) -> None:
+            pass
+
+        def bincode_serialize(self) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer)
+            return serializer.get_bytes()
+
+        @classmethod
+        def deserialize(cls, deserializer: Deserializer) -> UnitStruct:
+            return cls()
+
+        @classmethod
+        def bincode_deserialize(cls, data: bytes) -> UnitStruct:
+            deserializer = BincodeDeserializer(data)
+            value = cls.deserialize(deserializer)
+            if deserializer.get_buffer_offset() < len(data):
+                raise DeserializationError("Some input bytes were not read")
+            return value
+    "#);
+}
+
+#[test]
+fn newtype_struct() {
+    #[derive(Facet)]
+    struct NewType(String);
+
+    let actual = emit!(NewType as Python with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True)
+    class NewType:
+        value: str
+
+        def serialize(self, serializer: Serializer) -> None:
+            serializer.increase_container_depth()
+            serializer.serialize_str(self.value)
+            serializer.decrease_container_depth()
+
+        def bincode_serialize(self) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer)
+            return serializer.get_bytes()
+
+        @classmethod
+        def deserialize(cls, deserializer: Deserializer) -> NewType:
+            deserializer.increase_container_depth()
+            value = cls(
+                deserializer.deserialize_str(),
+            )
+            deserializer.decrease_container_depth()
+            return value
+
+        @classmethod
+        def bincode_deserialize(cls, data: bytes) -> NewType:
+            deserializer = BincodeDeserializer(data)
+            value = cls.deserialize(deserializer)
+            if deserializer.get_buffer_offset() < len(data):
+                raise DeserializationError("Some input bytes were not read")
+            return value
+    "#);
+}
+
+#[test]
+fn tuple_struct() {
+    #[derive(Facet)]
+    struct TupleStruct(String, i32);
+
+    let actual = emit!(TupleStruct as Python with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True)
+    class TupleStruct:
+        field0: str
+        field1: int
+
+        def serialize(self, serializer: Serializer) -> None:
+            serializer.increase_container_depth()
+            serializer.serialize_str(self.field0)
+            serializer.serialize_i32(self.field1)
+            serializer.decrease_container_depth()
+
+        def bincode_serialize(self) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer)
+            return serializer.get_bytes()
+
+        @classmethod
+        def deserialize(cls, deserializer: Deserializer) -> TupleStruct:
+            deserializer.increase_container_depth()
+            value = cls(
+                deserializer.deserialize_str(),
+                deserializer.deserialize_i32(),
+            )
+            deserializer.decrease_container_depth()
+            return value
+
+        @classmethod
+        def bincode_deserialize(cls, data: bytes) -> TupleStruct:
+            deserializer = BincodeDeserializer(data)
+            value = cls.deserialize(deserializer)
+            if deserializer.get_buffer_offset() < len(data):
+                raise DeserializationError("Some input bytes were not read")
+            return value
+    "#);
+}
+
+#[test]
+fn struct_with_fields() {
+    #[derive(Facet)]
+    struct Inner {
+        value: u64,
+    }
+
+    #[derive(Facet)]
+    struct Outer {
+        name: String,
+        big: u128,
+        nickname: Option<String>,
+        inner: Inner,
+        tags: Vec<Option<Inner>>,
+        counts: BTreeMap<String, Vec<u32>>,
+        ids: BTreeSet<u16>,
+        pair: (u8, String),
+        grid: [i16; 3],
+        #[facet(fg::bytes)]
+        data: Vec<u8>,
+    }
+
+    let actual = emit!(Outer as Python with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True, kw_only=True)
+    class Inner:
+        value: int
+
+        def serialize(self, serializer: Serializer) -> None:
+            serializer.increase_container_depth()
+            serializer.serialize_u64(self.value)
+            serializer.decrease_container_depth()
+
+        def bincode_serialize(self) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer)
+            return serializer.get_bytes()
+
+        @classmethod
+        def deserialize(cls, deserializer: Deserializer) -> Inner:
+            deserializer.increase_container_depth()
+            value = cls(
+                value=deserializer.deserialize_u64(),
+            )
+            deserializer.decrease_container_depth()
+            return value
+
+        @classmethod
+        def bincode_deserialize(cls, data: bytes) -> Inner:
+            deserializer = BincodeDeserializer(data)
+            value = cls.deserialize(deserializer)
+            if deserializer.get_buffer_offset() < len(data):
+                raise DeserializationError("Some input bytes were not read")
+            return value
+
+    @dataclass(frozen=True, kw_only=True)
+    class Outer:
+        name: str
+        big: int
+        nickname: Optional[str] = None
+        inner: Inner
+        tags: list[Optional[Inner]]
+        counts: dict[str, list[int]]
+        ids: set[int]
+        pair: tuple[int, str]
+        grid: tuple[int, ...]
+        data: bytes
+
+        def serialize(self, serializer: Serializer) -> None:
+            serializer.increase_container_depth()
+            serializer.serialize_str(self.name)
+            serializer.serialize_u128(self.big)
+            serializer.serialize_option(self.nickname, lambda level1: serializer.serialize_str(level1))
+            self.inner.serialize(serializer)
+            serializer.serialize_seq(self.tags, lambda level1: serializer.serialize_option(level1, lambda level2: level2.serialize(serializer)))
+            serializer.serialize_map(self.counts, lambda key1: serializer.serialize_str(key1), lambda value1: serializer.serialize_seq(value1, lambda level2: serializer.serialize_u32(level2)))
+            serializer.serialize_set(self.ids, lambda level1: serializer.serialize_u16(level1))
+            serializer.serialize_u8(self.pair[0])
+            serializer.serialize_str(self.pair[1])
+            serializer.serialize_tuple_array(self.grid, 3, lambda level1: serializer.serialize_i16(level1))
+            serializer.serialize_bytes(self.data)
+            serializer.decrease_container_depth()
+
+        def bincode_serialize(self) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer)
+            return serializer.get_bytes()
+
+        @classmethod
+        def deserialize(cls, deserializer: Deserializer) -> Outer:
+            deserializer.increase_container_depth()
+            value = cls(
+                name=deserializer.deserialize_str(),
+                big=deserializer.deserialize_u128(),
+                nickname=deserializer.deserialize_option(lambda: deserializer.deserialize_str()),
+                inner=Inner.deserialize(deserializer),
+                tags=deserializer.deserialize_seq(lambda: deserializer.deserialize_option(lambda: Inner.deserialize(deserializer))),
+                counts=deserializer.deserialize_map(lambda: deserializer.deserialize_str(), lambda: deserializer.deserialize_seq(lambda: deserializer.deserialize_u32())),
+                ids=deserializer.deserialize_set(lambda: deserializer.deserialize_u16()),
+                pair=(deserializer.deserialize_u8(), deserializer.deserialize_str()),
+                grid=deserializer.deserialize_tuple_array(3, lambda: deserializer.deserialize_i16()),
+                data=deserializer.deserialize_bytes(),
+            )
+            deserializer.decrease_container_depth()
+            return value
+
+        @classmethod
+        def bincode_deserialize(cls, data: bytes) -> Outer:
+            deserializer = BincodeDeserializer(data)
+            value = cls.deserialize(deserializer)
+            if deserializer.get_buffer_offset() < len(data):
+                raise DeserializationError("Some input bytes were not read")
+            return value
+    "#);
+}
+
+#[test]
+fn unit_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Colour {
+        Red,
+        Green,
+        Other,
+    }
+
+    let actual = emit!(Colour as Python with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    class Colour(Enum):
+        RED = "Red"
+        GREEN = "Green"
+        OTHER = "Other"
+
+        def serialize(self, serializer: Serializer) -> None:
+            serializer.increase_container_depth()
+            match self:
+                case Colour.RED:
+                    serializer.serialize_variant_index(0)
+                case Colour.GREEN:
+                    serializer.serialize_variant_index(1)
+                case Colour.OTHER:
+                    serializer.serialize_variant_index(2)
+            serializer.decrease_container_depth()
+
+        def bincode_serialize(self) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer)
+            return serializer.get_bytes()
+
+        @classmethod
+        def deserialize(cls, deserializer: Deserializer) -> Colour:
+            deserializer.increase_container_depth()
+            index = deserializer.deserialize_variant_index()
+            deserializer.decrease_container_depth()
+            match index:
+                case 0:
+                    return cls.RED
+                case 1:
+                    return cls.GREEN
+                case 2:
+                    return cls.OTHER
+                case _:
+                    raise DeserializationError(f"Unknown variant index for Colour: {index}")
+
+        @classmethod
+        def bincode_deserialize(cls, data: bytes) -> Colour:
+            deserializer = BincodeDeserializer(data)
+            value = cls.deserialize(deserializer)
+            if deserializer.get_buffer_offset() < len(data):
+                raise DeserializationError("Some input bytes were not read")
+            return value
+    "#);
+}
+
+#[test]
+fn data_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Line(f64, f64),
+        Rectangle { width: f64, height: f64 },
+    }
+
+    let actual = emit!(Shape as Python with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True)
+    class ShapePoint:
+        TAG: ClassVar[Literal["Point"]] = "Point"
+
+        def serialize(self, serializer: Serializer) -> None:
+            serializer.increase_container_depth()
+            serializer.serialize_variant_index(0)
+            serializer.decrease_container_depth()
+
+        def bincode_serialize(self) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer)
+            return serializer.get_bytes()
+
+
+    @dataclass(frozen=True)
+    class ShapeCircle:
+        TAG: ClassVar[Literal["Circle"]] = "Circle"
+        value: float
+
+        def serialize(self, serializer: Serializer) -> None:
+            serializer.increase_container_depth()
+            serializer.serialize_variant_index(1)
+            serializer.serialize_f64(self.value)
+            serializer.decrease_container_depth()
+
+        def bincode_serialize(self) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer)
+            return serializer.get_bytes()
+
+
+    @dataclass(frozen=True)
+    class ShapeLine:
+        TAG: ClassVar[Literal["Line"]] = "Line"
+        field0: float
+        field1: float
+
+        def serialize(self, serializer: Serializer) -> None:
+            serializer.increase_container_depth()
+            serializer.serialize_variant_index(2)
+            serializer.serialize_f64(self.field0)
+            serializer.serialize_f64(self.field1)
+            serializer.decrease_container_depth()
+
+        def bincode_serialize(self) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer)
+            return serializer.get_bytes()
+
+
+    @dataclass(frozen=True, kw_only=True)
+    class ShapeRectangle:
+        TAG: ClassVar[Literal["Rectangle"]] = "Rectangle"
+        width: float
+        height: float
+
+        def serialize(self, serializer: Serializer) -> None:
+            serializer.increase_container_depth()
+            serializer.serialize_variant_index(3)
+            serializer.serialize_f64(self.width)
+            serializer.serialize_f64(self.height)
+            serializer.decrease_container_depth()
+
+        def bincode_serialize(self) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer)
+            return serializer.get_bytes()
+
+
+    Shape = Union[ShapePoint, ShapeCircle, ShapeLine, ShapeRectangle]
+
+
+    def deserialize_shape(deserializer: Deserializer) -> Shape:
+        index = deserializer.deserialize_variant_index()
+        match index:
+            case 0:
+                return ShapePoint()
+            case 1:
+                deserializer.increase_container_depth()
+                value = ShapeCircle(
+                    deserializer.deserialize_f64(),
+                )
+                deserializer.decrease_container_depth()
+                return value
+            case 2:
+                deserializer.increase_container_depth()
+                value = ShapeLine(
+                    deserializer.deserialize_f64(),
+                    deserializer.deserialize_f64(),
+                )
+                deserializer.decrease_container_depth()
+                return value
+            case 3:
+                deserializer.increase_container_depth()
+                value = ShapeRectangle(
+                    width=deserializer.deserialize_f64(),
+                    height=deserializer.deserialize_f64(),
+                )
+                deserializer.decrease_container_depth()
+                return value
+            case _:
+                raise DeserializationError(f"Unknown variant index for Shape: {index}")
+
+
+    def bincode_deserialize_shape(data: bytes) -> Shape:
+        deserializer = BincodeDeserializer(data)
+        value = deserialize_shape(deserializer)
+        if deserializer.get_buffer_offset() < len(data):
+            raise DeserializationError("Some input bytes were not read")
+        return value
+    "#);
+}
+
+#[test]
+fn generic_struct_and_enum() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E },
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        page: Page<String>,
+        outcome: Outcome<Page<u32>, String>,
+        fallback: Outcome<bool, i32>,
+    }
+
+    let actual = emit!(Response as Python with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True)
+    class OutcomeSuccess(Generic[T]):
+        TAG: ClassVar[Literal["Success"]] = "Success"
+        value: T
+
+        def serialize(self, serializer: Serializer, serialize_t: Callable[[Serializer, T], None], serialize_e: Callable[[Serializer, E], None]) -> None:
+            serializer.increase_container_depth()
+            serializer.serialize_variant_index(0)
+            serialize_t(serializer, self.value)
+            serializer.decrease_container_depth()
+
+        def bincode_serialize(self, serialize_t: Callable[[Serializer, T], None], serialize_e: Callable[[Serializer, E], None]) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer, serialize_t, serialize_e)
+            return serializer.get_bytes()
+
+
+    @dataclass(frozen=True, kw_only=True)
+    class OutcomeFailure(Generic[E]):
+        TAG: ClassVar[Literal["Failure"]] = "Failure"
+        error: E
+
+        def serialize(self, serializer: Serializer, serialize_t: Callable[[Serializer, T], None], serialize_e: Callable[[Serializer, E], None]) -> None:
+            serializer.increase_container_depth()
+            serializer.serialize_variant_index(1)
+            serialize_e(serializer, self.error)
+            serializer.decrease_container_depth()
+
+        def bincode_serialize(self, serialize_t: Callable[[Serializer, T], None], serialize_e: Callable[[Serializer, E], None]) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer, serialize_t, serialize_e)
+            return serializer.get_bytes()
+
+
+    Outcome = Union[OutcomeSuccess[T], OutcomeFailure[E]]
+
+
+    def deserialize_outcome(deserializer: Deserializer, deserialize_t: Callable[[Deserializer], T], deserialize_e: Callable[[Deserializer], E]) -> Outcome[T, E]:
+        index = deserializer.deserialize_variant_index()
+        match index:
+            case 0:
+                deserializer.increase_container_depth()
+                value = OutcomeSuccess(
+                    deserialize_t(deserializer),
+                )
+                deserializer.decrease_container_depth()
+                return value
+            case 1:
+                deserializer.increase_container_depth()
+                value = OutcomeFailure(
+                    error=deserialize_e(deserializer),
+                )
+                deserializer.decrease_container_depth()
+                return value
+            case _:
+                raise DeserializationError(f"Unknown variant index for Outcome: {index}")
+
+
+    def bincode_deserialize_outcome(data: bytes, deserialize_t: Callable[[Deserializer], T], deserialize_e: Callable[[Deserializer], E]) -> Outcome[T, E]:
+        deserializer = BincodeDeserializer(data)
+        value = deserialize_outcome(deserializer, deserialize_t, deserialize_e)
+        if deserializer.get_buffer_offset() < len(data):
+            raise DeserializationError("Some input bytes were not read")
+        return value
+
+    @dataclass(frozen=True, kw_only=True)
+    class Page(Generic[T]):
+        items: list[T]
+        next: Optional[T] = None
+
+        def serialize(self, serializer: Serializer, serialize_t: Callable[[Serializer, T], None]) -> None:
+            serializer.increase_container_depth()
+            serializer.serialize_seq(self.items, lambda level1: serialize_t(serializer, level1))
+            serializer.serialize_option(self.next, lambda level1: serialize_t(serializer, level1))
+            serializer.decrease_container_depth()
+
+        def bincode_serialize(self, serialize_t: Callable[[Serializer, T], None]) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer, serialize_t)
+            return serializer.get_bytes()
+
+        @classmethod
+        def deserialize(cls, deserializer: Deserializer, deserialize_t: Callable[[Deserializer], T]) -> Page[T]:
+            deserializer.increase_container_depth()
+            value = cls(
+                items=deserializer.deserialize_seq(lambda: deserialize_t(deserializer)),
+                next=deserializer.deserialize_option(lambda: deserialize_t(deserializer)),
+            )
+            deserializer.decrease_container_depth()
+            return value
+
+        @classmethod
+        def bincode_deserialize(cls, data: bytes, deserialize_t: Callable[[Deserializer], T]) -> Page[T]:
+            deserializer = BincodeDeserializer(data)
+            value = cls.deserialize(deserializer, deserialize_t)
+            if deserializer.get_buffer_offset() < len(data):
+                raise DeserializationError("Some input bytes were not read")
+            return value
+
+    @dataclass(frozen=True, kw_only=True)
+    class Response:
+        page: Page[str]
+        outcome: Outcome[Page[int], str]
+        fallback: Outcome[bool, int]
+
+        def serialize(self, serializer: Serializer) -> None:
+            serializer.increase_container_depth()
+            self.page.serialize(serializer, lambda serializer, level1: serializer.serialize_str(level1))
+            self.outcome.serialize(serializer, lambda serializer, level1: level1.serialize(serializer, lambda serializer, level2: serializer.serialize_u32(level2)), lambda serializer, level1: serializer.serialize_str(level1))
+            self.fallback.serialize(serializer, lambda serializer, level1: serializer.serialize_bool(level1), lambda serializer, level1: serializer.serialize_i32(level1))
+            serializer.decrease_container_depth()
+
+        def bincode_serialize(self) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer)
+            return serializer.get_bytes()
+
+        @classmethod
+        def deserialize(cls, deserializer: Deserializer) -> Response:
+            deserializer.increase_container_depth()
+            value = cls(
+                page=Page.deserialize(deserializer, lambda deserializer: deserializer.deserialize_str()),
+                outcome=deserialize_outcome(deserializer, lambda deserializer: Page.deserialize(deserializer, lambda deserializer: deserializer.deserialize_u32()), lambda deserializer: deserializer.deserialize_str()),
+                fallback=deserialize_outcome(deserializer, lambda deserializer: deserializer.deserialize_bool(), lambda deserializer: deserializer.deserialize_i32()),
+            )
+            deserializer.decrease_container_depth()
+            return value
+
+        @classmethod
+        def bincode_deserialize(cls, data: bytes) -> Response:
+            deserializer = BincodeDeserializer(data)
+            value = cls.deserialize(deserializer)
+            if deserializer.get_buffer_offset() < len(data):
+                raise DeserializationError("Some input bytes were not read")
+            return value
+    "#);
+}
+
+#[test]
+fn branded_newtype() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    let actual = emit!(UserId as Python with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    UserId = NewType("UserId", str)
+
+
+    def serialize_user_id(value: UserId, serializer: Serializer) -> None:
+        serializer.increase_container_depth()
+        serializer.serialize_str(value)
+        serializer.decrease_container_depth()
+
+
+    def bincode_serialize_user_id(value: UserId) -> bytes:
+        serializer = BincodeSerializer()
+        serialize_user_id(value, serializer)
+        return serializer.get_bytes()
+
+
+    def deserialize_user_id(deserializer: Deserializer) -> UserId:
+        deserializer.increase_container_depth()
+        value = UserId(deserializer.deserialize_str())
+        deserializer.decrease_container_depth()
+        return value
+
+
+    def bincode_deserialize_user_id(data: bytes) -> UserId:
+        deserializer = BincodeDeserializer(data)
+        value = deserialize_user_id(deserializer)
+        if deserializer.get_buffer_offset() < len(data):
+            raise DeserializationError("Some input bytes were not read")
+        return value
+    "#);
+}
+
+#[test]
+fn unknown_variants() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Click {
+            x: i32,
+            y: i32,
+        },
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Event as Python with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True, kw_only=True)
+    class EventClick:
+        TAG: ClassVar[Literal["Click"]] = "Click"
+        x: int
+        y: int
+
+        def serialize(self, serializer: Serializer) -> None:
+            serializer.increase_container_depth()
+            serializer.serialize_variant_index(0)
+            serializer.serialize_i32(self.x)
+            serializer.serialize_i32(self.y)
+            serializer.decrease_container_depth()
+
+        def bincode_serialize(self) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer)
+            return serializer.get_bytes()
+
+
+    @dataclass(frozen=True)
+    class EventUnknown:
+        TAG: ClassVar[Literal["Unknown"]] = "Unknown"
+
+        def serialize(self, serializer: Serializer) -> None:
+            serializer.increase_container_depth()
+            serializer.serialize_variant_index(1)
+            serializer.decrease_container_depth()
+
+        def bincode_serialize(self) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer)
+            return serializer.get_bytes()
+
+
+    Event = Union[EventClick, EventUnknown]
+
+
+    def deserialize_event(deserializer: Deserializer) -> Event:
+        index = deserializer.deserialize_variant_index()
+        match index:
+            case 0:
+                deserializer.increase_container_depth()
+                value = EventClick(
+                    x=deserializer.deserialize_i32(),
+                    y=deserializer.deserialize_i32(),
+                )
+                deserializer.decrease_container_depth()
+                return value
+            case 1:
+                return EventUnknown()
+            case _:
+                return EventUnknown()
+
+
+    def bincode_deserialize_event(data: bytes) -> Event:
+        deserializer = BincodeDeserializer(data)
+        value = deserialize_event(deserializer)
+        if deserializer.get_buffer_offset() < len(data):
+            raise DeserializationError("Some input bytes were not read")
+        return value
+    "#);
+}
+
+#[test]
+fn type_override() {
+    #[derive(Facet)]
+    struct Price {
+        #[facet(fg::override(
+            lang = "python",
+            ty = "Decimal",
+            serialize = "serializer.serialize_str(str({value}))",
+            deserialize = "Decimal(deserializer.deserialize_str())"
+        ))]
+        amount: String,
+        #[facet(fg::override(lang = "swift", ty = "CGFloat"))]
+        scale: f64,
+    }
+
+    let actual = emit!(Price as Python with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True, kw_only=True)
+    class Price:
+        amount: Decimal
+        scale: float
+
+        def serialize(self, serializer: Serializer) -> None:
+            serializer.increase_container_depth()
+            serializer.serialize_str(str(self.amount))
+            serializer.serialize_f64(self.scale)
+            serializer.decrease_container_depth()
+
+        def bincode_serialize(self) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer)
+            return serializer.get_bytes()
+
+        @classmethod
+        def deserialize(cls, deserializer: Deserializer) -> Price:
+            deserializer.increase_container_depth()
+            value = cls(
+                amount=Decimal(deserializer.deserialize_str()),
+                scale=deserializer.deserialize_f64(),
+            )
+            deserializer.decrease_container_depth()
+            return value
+
+        @classmethod
+        def bincode_deserialize(cls, data: bytes) -> Price:
+            deserializer = BincodeDeserializer(data)
+            value = cls.deserialize(deserializer)
+            if deserializer.get_buffer_offset() < len(data):
+                raise DeserializationError("Some input bytes were not read")
+            return value
+    "#);
+}
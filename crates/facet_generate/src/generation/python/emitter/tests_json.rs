@@ -0,0 +1,715 @@
+//! Snapshot tests for the Python emitter — **JSON encoding**.
+//!
+//! Mirrors the structure of [`tests`](super::tests) but uses `JsonPlugin`, so
+//! that every class gets a `to_json` method and a `from_json` class method,
+//! and every `Union` and `NewType` gets module functions.
+//!
+//! These tests verify the JSON shapes of structs, of the enum taggings
+//! (external, internal, adjacent and untagged), of map keys and of generic
+//! types.
+
+#![allow(clippy::too_many_lines)]
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::generation::json::JsonPlugin;
+use facet::Facet;
+
+use super::*;
+use crate::emit;
+
+#[test]
+fn unit_and_empty_structs() {
+    #[derive(Facet)]
+    struct UnitStruct;
+
+    #[derive(Facet)]
+    struct EmptyStruct {}
+
+    #[derive(Facet)]
+    struct Both {
+        unit: UnitStruct,
+        empty: EmptyStruct,
+    }
+
+    let actual = emit!(Both as Python with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True, kw_only=True)
+    class Both:
+        unit: UnitStruct
+        empty: EmptyStruct
+
+        def to_json(self) -> Any:
+            return {
+                "unit": self.unit.to_json(),
+                "empty": self.empty.to_json(),
+            }
+
+        @classmethod
+        def from_json(cls, json: Any) -> Both:
+            obj = object_from_json(json)
+            return cls(
+                unit=UnitStruct.from_json(obj.get("unit")),
+                empty=EmptyStruct.from_json(obj.get("empty")),
+            )
+
+    @dataclass(frozen=True)
+    class EmptyStruct:
+        def to_json(self) -> Any:
+            return None
+
+        @classmethod
+        def from_json(cls, json: Any) -> EmptyStruct:
+            return cls()
+
+    @dataclass(frozen=True)
+    class UnitStruct:
+        def to_json(self) -> Any:
+            return None
+
+        @classmethod
+        def from_json(cls, json: Any) -> UnitStruct:
+            return cls()
+    "#);
+}
+
+#[test]
+fn newtype_and_tuple_structs() {
+    #[derive(Facet)]
+    struct NewType(Vec<String>);
+
+    #[derive(Facet)]
+    struct TupleStruct(String, f64);
+
+    #[derive(Facet)]
+    struct Both {
+        newtype: NewType,
+        tuple: TupleStruct,
+    }
+
+    let actual = emit!(Both as Python with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True, kw_only=True)
+    class Both:
+        newtype: NewType
+        tuple: TupleStruct
+
+        def to_json(self) -> Any:
+            return {
+                "newtype": self.newtype.to_json(),
+                "tuple": self.tuple.to_json(),
+            }
+
+        @classmethod
+        def from_json(cls, json: Any) -> Both:
+            obj = object_from_json(json)
+            return cls(
+                newtype=NewType.from_json(obj.get("newtype")),
+                tuple=TupleStruct.from_json(obj.get("tuple")),
+            )
+
+    @dataclass(frozen=True)
+    class NewType:
+        value: list[str]
+
+        def to_json(self) -> Any:
+            return list(self.value)
+
+        @classmethod
+        def from_json(cls, json: Any) -> NewType:
+            return cls([str_from_json(level1) for level1 in list_from_json(json)])
+
+    @dataclass(frozen=True)
+    class TupleStruct:
+        field0: str
+        field1: float
+
+        def to_json(self) -> Any:
+            return [self.field0, self.field1]
+
+        @classmethod
+        def from_json(cls, json: Any) -> TupleStruct:
+            return tuple_from_json(json, 2, lambda elements: cls(str_from_json(elements[0]), float_from_json(elements[1])))
+    "#);
+}
+
+#[test]
+fn struct_with_fields() {
+    #[derive(Facet, PartialEq, Eq, PartialOrd, Ord)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Kind {
+        Small,
+        Large,
+    }
+
+    #[derive(Facet)]
+    struct Outer {
+        name: String,
+        big: i128,
+        ratio: f32,
+        nickname: Option<String>,
+        kind: Option<Kind>,
+        tags: Vec<Kind>,
+        counts: BTreeMap<u32, Vec<f64>>,
+        kinds: BTreeMap<Kind, String>,
+        ids: HashSet<u16>,
+        pair: (u8, String),
+        timeout: Duration,
+        #[facet(default = 3)]
+        retries: u32,
+    }
+
+    let actual = emit!(Outer as Python with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    class Kind(Enum):
+        SMALL = "Small"
+        LARGE = "Large"
+
+        def to_json(self) -> Any:
+            return self.value
+
+        @classmethod
+        def from_json(cls, json: Any) -> Kind:
+            match json:
+                case "Small":
+                    return cls.SMALL
+                case "Large":
+                    return cls.LARGE
+                case _:
+                    raise DeserializationError(f"Unknown variant for Kind: {json!r}")
+
+    @dataclass(frozen=True, kw_only=True)
+    class Outer:
+        name: str
+        big: int
+        ratio: float
+        nickname: Optional[str] = None
+        kind: Optional[Kind] = None
+        tags: list[Kind]
+        counts: dict[int, list[float]]
+        kinds: dict[Kind, str]
+        ids: set[int]
+        pair: tuple[int, str]
+        timeout: timedelta
+        retries: int = 3
+
+        def to_json(self) -> Any:
+            return {
+                "name": self.name,
+                "big": self.big,
+                "ratio": self.ratio,
+                "nickname": self.nickname,
+                "kind": option_to_json(self.kind, lambda level1: level1.to_json()),
+                "tags": [level1.to_json() for level1 in self.tags],
+                "counts": {str(key1): list(value1) for key1, value1 in self.counts.items()},
+                "kinds": {key1.to_json(): value1 for key1, value1 in self.kinds.items()},
+                "ids": list(self.ids),
+                "pair": [self.pair[0], self.pair[1]],
+                "timeout": duration_to_json(self.timeout),
+                "retries": self.retries,
+            }
+
+        @classmethod
+        def from_json(cls, json: Any) -> Outer:
+            obj = object_from_json(json)
+            return cls(
+                name=str_from_json(obj.get("name")),
+                big=int_from_json(obj.get("big")),
+                ratio=float_from_json(obj.get("ratio")),
+                nickname=option_from_json(obj.get("nickname"), lambda level1: str_from_json(level1)),
+                kind=option_from_json(obj.get("kind"), lambda level1: Kind.from_json(level1)),
+                tags=[Kind.from_json(level1) for level1 in list_from_json(obj.get("tags"))],
+                counts={int(key1): [float_from_json(level2) for level2 in list_from_json(value1)] for key1, value1 in object_from_json(obj.get("counts")).items()},
+                kinds={Kind.from_json(key1): str_from_json(value1) for key1, value1 in object_from_json(obj.get("kinds")).items()},
+                ids={int_from_json(level1) for level1 in list_from_json(obj.get("ids"))},
+                pair=tuple_from_json(obj.get("pair"), 2, lambda level1: (int_from_json(level1[0]), str_from_json(level1[1]))),
+                timeout=duration_from_json(obj.get("timeout")),
+                retries=int_from_json(obj.get("retries")) if "retries" in obj else 3,
+            )
+    "#);
+}
+
+#[test]
+fn externally_tagged_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Line(f64, f64),
+        Rectangle { width: f64, height: f64 },
+    }
+
+    let actual = emit!(Shape as Python with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True)
+    class ShapePoint:
+        TAG: ClassVar[Literal["Point"]] = "Point"
+
+        def to_json(self) -> Any:
+            return "Point"
+
+
+    @dataclass(frozen=True)
+    class ShapeCircle:
+        TAG: ClassVar[Literal["Circle"]] = "Circle"
+        value: float
+
+        def to_json(self) -> Any:
+            return {"Circle": self.value}
+
+
+    @dataclass(frozen=True)
+    class ShapeLine:
+        TAG: ClassVar[Literal["Line"]] = "Line"
+        field0: float
+        field1: float
+
+        def to_json(self) -> Any:
+            return {"Line": [self.field0, self.field1]}
+
+
+    @dataclass(frozen=True, kw_only=True)
+    class ShapeRectangle:
+        TAG: ClassVar[Literal["Rectangle"]] = "Rectangle"
+        width: float
+        height: float
+
+        def to_json(self) -> Any:
+            return {"Rectangle": {"width": self.width, "height": self.height}}
+
+
+    Shape = Union[ShapePoint, ShapeCircle, ShapeLine, ShapeRectangle]
+
+
+    def from_json_shape(json: Any) -> Shape:
+        tag, content = external_tag(json)
+        if tag == "Point":
+            return ShapePoint()
+        if tag == "Circle":
+            return ShapeCircle(float_from_json(content))
+        if tag == "Line":
+            return tuple_from_json(content, 2, lambda elements: ShapeLine(float_from_json(elements[0]), float_from_json(elements[1])))
+        if tag == "Rectangle":
+            obj = object_from_json(content)
+            return ShapeRectangle(
+                width=float_from_json(obj.get("width")),
+                height=float_from_json(obj.get("height")),
+            )
+        raise DeserializationError(f"Unknown variant for Shape: {tag!r}")
+    "#);
+}
+
+#[test]
+fn internally_tagged_enum() {
+    #[derive(Facet)]
+    struct Position {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Facet)]
+    #[facet(tag = "type")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Started,
+        Moved(Position),
+        Clicked {
+            button: u8,
+        },
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Event as Python with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True)
+    class EventStarted:
+        TAG: ClassVar[Literal["Started"]] = "Started"
+
+        def to_json(self) -> Any:
+            return {"type": "Started"}
+
+
+    @dataclass(frozen=True)
+    class EventMoved:
+        TAG: ClassVar[Literal["Moved"]] = "Moved"
+        value: Position
+
+        def to_json(self) -> Any:
+            return {"type": "Moved", **self.value.to_json()}
+
+
+    @dataclass(frozen=True, kw_only=True)
+    class EventClicked:
+        TAG: ClassVar[Literal["Clicked"]] = "Clicked"
+        button: int
+
+        def to_json(self) -> Any:
+            return {"type": "Clicked", "button": self.button}
+
+
+    @dataclass(frozen=True)
+    class EventUnknown:
+        TAG: ClassVar[Literal["Unknown"]] = "Unknown"
+
+        def to_json(self) -> Any:
+            return {"type": "Unknown"}
+
+
+    Event = Union[EventStarted, EventMoved, EventClicked, EventUnknown]
+
+
+    def from_json_event(json: Any) -> Event:
+        obj = object_from_json(json)
+        tag = obj.get("type")
+        if tag == "Started":
+            return EventStarted()
+        if tag == "Moved":
+            return EventMoved(Position.from_json(obj))
+        if tag == "Clicked":
+            return EventClicked(
+                button=int_from_json(obj.get("button")),
+            )
+        if tag == "Unknown":
+            return EventUnknown()
+        return EventUnknown()
+
+    @dataclass(frozen=True, kw_only=True)
+    class Position:
+        x: int
+        y: int
+
+        def to_json(self) -> Any:
+            return {
+                "x": self.x,
+                "y": self.y,
+            }
+
+        @classmethod
+        def from_json(cls, json: Any) -> Position:
+            obj = object_from_json(json)
+            return cls(
+                x=int_from_json(obj.get("x")),
+                y=int_from_json(obj.get("y")),
+            )
+    "#);
+}
+
+#[test]
+fn adjacently_tagged_enum() {
+    #[derive(Facet)]
+    #[facet(tag = "t", content = "c")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Message {
+        Ping,
+        Text(String),
+        Pair(u32, u32),
+    }
+
+    let actual = emit!(Message as Python with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True)
+    class MessagePing:
+        TAG: ClassVar[Literal["Ping"]] = "Ping"
+
+        def to_json(self) -> Any:
+            return {"t": "Ping"}
+
+
+    @dataclass(frozen=True)
+    class MessageText:
+        TAG: ClassVar[Literal["Text"]] = "Text"
+        value: str
+
+        def to_json(self) -> Any:
+            return {"t": "Text", "c": self.value}
+
+
+    @dataclass(frozen=True)
+    class MessagePair:
+        TAG: ClassVar[Literal["Pair"]] = "Pair"
+        field0: int
+        field1: int
+
+        def to_json(self) -> Any:
+            return {"t": "Pair", "c": [self.field0, self.field1]}
+
+
+    Message = Union[MessagePing, MessageText, MessagePair]
+
+
+    def from_json_message(json: Any) -> Message:
+        obj = object_from_json(json)
+        tag = obj.get("t")
+        content = obj.get("c")
+        if tag == "Ping":
+            return MessagePing()
+        if tag == "Text":
+            return MessageText(str_from_json(content))
+        if tag == "Pair":
+            return tuple_from_json(content, 2, lambda elements: MessagePair(int_from_json(elements[0]), int_from_json(elements[1])))
+        raise DeserializationError(f"Unknown variant for Message: {tag!r}")
+    "#);
+}
+
+#[test]
+fn untagged_enum() {
+    #[derive(Facet)]
+    #[facet(untagged)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Value {
+        Nothing,
+        Number(f64),
+        Named { name: String },
+    }
+
+    let actual = emit!(Value as Python with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True)
+    class ValueNothing:
+        TAG: ClassVar[Literal["Nothing"]] = "Nothing"
+
+        def to_json(self) -> Any:
+            return None
+
+
+    @dataclass(frozen=True)
+    class ValueNumber:
+        TAG: ClassVar[Literal["Number"]] = "Number"
+        value: float
+
+        def to_json(self) -> Any:
+            return self.value
+
+
+    @dataclass(frozen=True, kw_only=True)
+    class ValueNamed:
+        TAG: ClassVar[Literal["Named"]] = "Named"
+        name: str
+
+        def to_json(self) -> Any:
+            return {"name": self.name}
+
+
+    Value = Union[ValueNothing, ValueNumber, ValueNamed]
+
+
+    def from_json_value(json: Any) -> Value:
+        if json is None:
+            return ValueNothing()
+        try:
+            return ValueNumber(float_from_json(json))
+        except ValueError:
+            pass
+        try:
+            obj = object_from_json(json)
+            return ValueNamed(
+                name=str_from_json(obj.get("name")),
+            )
+        except ValueError:
+            pass
+        raise DeserializationError(f"No variant of Value matches {json!r}")
+    "#);
+}
+
+#[test]
+fn tagged_unit_enums() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Plain {
+        On,
+        Off,
+    }
+
+    #[derive(Facet)]
+    #[facet(tag = "kind")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Tagged {
+        Up,
+        #[facet(other)]
+        Unknown,
+    }
+
+    #[derive(Facet)]
+    struct Both {
+        plain: Plain,
+        tagged: Tagged,
+    }
+
+    let actual = emit!(Both as Python with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True, kw_only=True)
+    class Both:
+        plain: Plain
+        tagged: Tagged
+
+        def to_json(self) -> Any:
+            return {
+                "plain": self.plain.to_json(),
+                "tagged": self.tagged.to_json(),
+            }
+
+        @classmethod
+        def from_json(cls, json: Any) -> Both:
+            obj = object_from_json(json)
+            return cls(
+                plain=Plain.from_json(obj.get("plain")),
+                tagged=Tagged.from_json(obj.get("tagged")),
+            )
+
+    class Plain(Enum):
+        ON = "On"
+        OFF = "Off"
+
+        def to_json(self) -> Any:
+            return self.value
+
+        @classmethod
+        def from_json(cls, json: Any) -> Plain:
+            match json:
+                case "On":
+                    return cls.ON
+                case "Off":
+                    return cls.OFF
+                case _:
+                    raise DeserializationError(f"Unknown variant for Plain: {json!r}")
+
+    class Tagged(Enum):
+        UP = "Up"
+        UNKNOWN = "Unknown"
+
+        def to_json(self) -> Any:
+            return {"kind": self.value}
+
+        @classmethod
+        def from_json(cls, json: Any) -> Tagged:
+            tag = object_from_json(json).get("kind")
+            match tag:
+                case "Up":
+                    return cls.UP
+                case "Unknown":
+                    return cls.UNKNOWN
+                case _:
+                    return cls.UNKNOWN
+    "#);
+}
+
+#[test]
+fn generic_struct_and_enum() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E },
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        page: Page<String>,
+        outcome: Outcome<Page<u32>, String>,
+        fallback: Outcome<bool, i32>,
+    }
+
+    let actual = emit!(Response as Python with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @dataclass(frozen=True)
+    class OutcomeSuccess(Generic[T]):
+        TAG: ClassVar[Literal["Success"]] = "Success"
+        value: T
+
+        def to_json(self, to_json_t: Callable[[T], Any], to_json_e: Callable[[E], Any]) -> Any:
+            return {"Success": to_json_t(self.value)}
+
+
+    @dataclass(frozen=True, kw_only=True)
+    class OutcomeFailure(Generic[E]):
+        TAG: ClassVar[Literal["Failure"]] = "Failure"
+        error: E
+
+        def to_json(self, to_json_t: Callable[[T], Any], to_json_e: Callable[[E], Any]) -> Any:
+            return {"Failure": {"error": to_json_e(self.error)}}
+
+
+    Outcome = Union[OutcomeSuccess[T], OutcomeFailure[E]]
+
+
+    def from_json_outcome(json: Any, from_json_t: Callable[[Any], T], from_json_e: Callable[[Any], E]) -> Outcome[T, E]:
+        tag, content = external_tag(json)
+        if tag == "Success":
+            return OutcomeSuccess(from_json_t(content))
+        if tag == "Failure":
+            obj = object_from_json(content)
+            return OutcomeFailure(
+                error=from_json_e(obj.get("error")),
+            )
+        raise DeserializationError(f"Unknown variant for Outcome: {tag!r}")
+
+    @dataclass(frozen=True, kw_only=True)
+    class Page(Generic[T]):
+        items: list[T]
+        next: Optional[T] = None
+
+        def to_json(self, to_json_t: Callable[[T], Any]) -> Any:
+            return {
+                "items": [to_json_t(level1) for level1 in self.items],
+                "next": option_to_json(self.next, lambda level1: to_json_t(level1)),
+            }
+
+        @classmethod
+        def from_json(cls, json: Any, from_json_t: Callable[[Any], T]) -> Page[T]:
+            obj = object_from_json(json)
+            return cls(
+                items=[from_json_t(level1) for level1 in list_from_json(obj.get("items"))],
+                next=option_from_json(obj.get("next"), lambda level1: from_json_t(level1)),
+            )
+
+    @dataclass(frozen=True, kw_only=True)
+    class Response:
+        page: Page[str]
+        outcome: Outcome[Page[int], str]
+        fallback: Outcome[bool, int]
+
+        def to_json(self) -> Any:
+            return {
+                "page": self.page.to_json(lambda level1: level1),
+                "outcome": self.outcome.to_json(lambda level1: level1.to_json(lambda level2: level2), lambda level1: level1),
+                "fallback": self.fallback.to_json(lambda level1: level1, lambda level1: level1),
+            }
+
+        @classmethod
+        def from_json(cls, json: Any) -> Response:
+            obj = object_from_json(json)
+            return cls(
+                page=Page.from_json(obj.get("page"), lambda level1: str_from_json(level1)),
+                outcome=from_json_outcome(obj.get("outcome"), lambda level1: Page.from_json(level1, lambda level2: int_from_json(level2)), lambda level1: str_from_json(level1)),
+                fallback=from_json_outcome(obj.get("fallback"), lambda level1: bool_from_json(level1), lambda level1: int_from_json(level1)),
+            )
+    "#);
+}
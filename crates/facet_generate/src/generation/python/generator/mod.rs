@@ -0,0 +1,132 @@
+//! Top-level orchestrator for Python code generation.
+//!
+//! [`PythonCodeGenerator`] implements [`CodeGenerator`] and is the entry point for
+//! producing a single Python module from a [`Registry`]. It delegates writing to
+//! the emitter layer.
+
+use std::{
+    io::{Result, Write},
+    sync::Arc,
+};
+
+use crate::{
+    Registry,
+    generation::{
+        CodeGenerator, CodeGeneratorConfig, Container, Emitter,
+        indent::IndentedWriter,
+        module::{self, Module},
+        plugin::{self, EmitterPlugin},
+        python::emitter::Python,
+    },
+    reflection::format::{Format, FormatHolder, Language, Namespace, QualifiedTypeName},
+};
+
+/// Main configuration object for Python code generation.
+///
+/// Wraps a [`CodeGeneratorConfig`] and implements [`CodeGenerator`] so it
+/// can be used by the installer pipeline.
+pub struct PythonCodeGenerator<'a> {
+    /// Language-independent configuration.
+    pub(crate) config: &'a CodeGeneratorConfig,
+    /// Plugins that control encoding-specific code generation.
+    pub(crate) plugins: Vec<Arc<dyn EmitterPlugin<Python>>>,
+}
+
+impl<'a> CodeGenerator<'a> for PythonCodeGenerator<'a> {
+    fn new(config: &'a CodeGeneratorConfig) -> Self {
+        Self {
+            config,
+            plugins: vec![],
+        }
+    }
+
+    fn write_output<W: Write>(&mut self, writer: &mut W, registry: &Registry) -> Result<()> {
+        self.output(writer, registry)
+    }
+}
+
+impl<'a> PythonCodeGenerator<'a> {
+    /// Create a Python code generator with no plugins (plain types only).
+    ///
+    /// Call [`with_plugins`](Self::with_plugins) to enable serialization.
+    #[must_use]
+    pub fn new(config: &'a CodeGeneratorConfig) -> Self {
+        Self {
+            config,
+            plugins: vec![],
+        }
+    }
+
+    /// Set pre-built plugins, returning the modified generator.
+    #[must_use]
+    pub fn with_plugins(mut self, plugins: Vec<Arc<dyn EmitterPlugin<Python>>>) -> Self {
+        self.plugins = plugins;
+        self
+    }
+
+    /// Produce a complete Python module for the types in `registry`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `out` fails, or if a plugin can't
+    /// encode a type of the registry.
+    pub fn output(&self, out: &mut impl Write, registry: &Registry) -> Result<()> {
+        let w = &mut IndentedWriter::new(out, self.config.indent);
+        let checked = module::for_plugin_checks(registry, Language::Python);
+        let registry = &module::for_language(registry, Language::Python);
+        plugin::check_registry(&self.plugins, &checked).map_err(std::io::Error::other)?;
+
+        let mut config = self.config.clone();
+        config.update_from(registry);
+
+        let mut lang = Python::new(&config, registry);
+        for p in &self.plugins {
+            lang = lang.with_plugin(p.clone());
+        }
+
+        Module::new(&config).write(w, &lang)?;
+
+        let updated_registry = Self::update_qualified_names(&config, registry);
+        for (i, container) in updated_registry.iter().map(Container::from).enumerate() {
+            if i > 0 {
+                writeln!(w)?;
+                writeln!(w)?;
+            }
+            container.write(w, &lang)?;
+        }
+
+        Ok(())
+    }
+
+    /// Updates [`QualifiedTypeName`] instances for Python's modules:
+    ///
+    /// 1. **Same-module type** — strip namespace to `Root` so it renders as a
+    ///    bare name (e.g. `Child`).
+    /// 2. **External type in different namespace** — keep its `Named` namespace,
+    ///    which renders as `namespace.Type` (e.g. `other.Child`) via the
+    ///    module import added by the [`Module`] emitter.
+    fn update_qualified_names(config: &CodeGeneratorConfig, registry: &Registry) -> Registry {
+        let mut updated_registry = registry.clone();
+
+        for container_format in updated_registry.values_mut() {
+            let _ = container_format.visit_mut(&mut |format| {
+                if let Format::TypeName(qualified_name)
+                | Format::Generic {
+                    name: qualified_name,
+                    ..
+                } = format
+                    && let Namespace::Named(namespace) = &qualified_name.namespace
+                    && namespace == config.module_name()
+                {
+                    *qualified_name = QualifiedTypeName::root(qualified_name.name.clone());
+                }
+                Ok(())
+            });
+        }
+
+        updated_registry
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,298 @@
+//! Unit tests for [`PythonCodeGenerator`] — import generation, qualified-name
+//! resolution and per-language filtering.
+//!
+//! Tests build small [`Registry`](crate::Registry) values by hand (rather than
+//! via the `reflect!` macro) so that module and external-package configurations
+//! can be controlled precisely.
+//!
+//! # Coverage
+//!
+//! | Area | What is tested |
+//! |------|----------------|
+//! | Same-module stripping | `Named` namespace matching the module name → bare name, also for generics |
+//! | Other namespaces | `Named` namespace for a different module → preserved as `prefix.Type` |
+//! | Import generation | Relative (`from . import other`) and external package (`from shared_types import models as other`) imports |
+//! | Plugin imports | Runtime imports merged and sorted after the standard library, like `isort` |
+//! | Filtering | Variants skipped for Python keep their bincode indices |
+//! | Rejection | Registries the plugins can't encode |
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use facet::Facet;
+
+use super::*;
+use crate::{
+    self as fg,
+    generation::{
+        CodeGeneratorConfig,
+        bincode::BincodePlugin,
+        config::{ExternalPackage, PackageLocation},
+        json::JsonPlugin,
+        plugin::EmitterPlugin,
+    },
+    reflect,
+    reflection::format::{
+        ContainerFormat, ContainerMetadata, Doc, FieldMetadata, Format, Named, Namespace,
+        QualifiedTypeName,
+    },
+};
+
+fn registry_with_struct_field(field_type: Format) -> Registry {
+    let mut registry = Registry::new();
+    let fields = vec![Named {
+        name: "value".to_string(),
+        doc: Doc::new(),
+        value: field_type,
+        metadata: FieldMetadata::default(),
+    }];
+    registry.insert(
+        QualifiedTypeName::root("Holder".to_string()),
+        ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default()),
+    );
+    registry
+}
+
+fn first_field_type(registry: &Registry) -> &Format {
+    let (_, container) = registry.iter().next().unwrap();
+    let ContainerFormat::Struct(fields, _, _) = container else {
+        panic!("expected struct container");
+    };
+    &fields[0].value
+}
+
+fn render_output(
+    config: &CodeGeneratorConfig,
+    plugins: Vec<Arc<dyn EmitterPlugin<Python>>>,
+    registry: &Registry,
+) -> String {
+    let generator = PythonCodeGenerator::new(config).with_plugins(plugins);
+    let mut output = Vec::new();
+    generator.output(&mut output, registry).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn update_qualified_names_strips_same_module_namespace() {
+    let config = CodeGeneratorConfig::new("root".to_string());
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "root".to_string(),
+        "Child".to_string(),
+    )));
+
+    let updated = PythonCodeGenerator::update_qualified_names(&config, &registry);
+
+    let Format::TypeName(type_name) = first_field_type(&updated) else {
+        panic!("expected type name");
+    };
+    assert_eq!(type_name.namespace, Namespace::Root);
+    assert_eq!(type_name.name, "Child");
+}
+
+#[test]
+fn update_qualified_names_strips_same_module_generic() {
+    let config = CodeGeneratorConfig::new("root".to_string());
+    let registry = registry_with_struct_field(Format::Generic {
+        name: QualifiedTypeName::namespaced("root".to_string(), "Page".to_string()),
+        args: vec![Format::Str],
+    });
+
+    let updated = PythonCodeGenerator::update_qualified_names(&config, &registry);
+
+    let Format::Generic { name, .. } = first_field_type(&updated) else {
+        panic!("expected generic");
+    };
+    assert_eq!(name.namespace, Namespace::Root);
+}
+
+#[test]
+fn update_qualified_names_keeps_other_namespace() {
+    let config = CodeGeneratorConfig::new("root".to_string());
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "other".to_string(),
+        "Child".to_string(),
+    )));
+
+    let updated = PythonCodeGenerator::update_qualified_names(&config, &registry);
+
+    let Format::TypeName(type_name) = first_field_type(&updated) else {
+        panic!("expected type name");
+    };
+    assert_eq!(type_name.namespace, Namespace::Named("other".to_string()));
+}
+
+#[test]
+fn output_imports_other_namespace_with_prefix() {
+    let config = CodeGeneratorConfig::new("root".to_string());
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "other".to_string(),
+        "Child".to_string(),
+    )));
+
+    let output = render_output(&config, vec![], &registry);
+    insta::assert_snapshot!(output, @"
+    from __future__ import annotations
+
+    from dataclasses import dataclass
+
+    from . import other
+
+
+    @dataclass(frozen=True, kw_only=True)
+    class Holder:
+        value: other.Child
+    ");
+}
+
+#[test]
+fn output_imports_external_package() {
+    let mut config = CodeGeneratorConfig::new("root".to_string());
+    config.external_packages = BTreeMap::from([(
+        "other".to_string(),
+        ExternalPackage {
+            for_namespace: "shared_types".to_string(),
+            location: PackageLocation::Path("../shared_types".to_string()),
+            module_name: Some("models".to_string()),
+            version: None,
+        },
+    )]);
+    let registry = registry_with_struct_field(Format::TypeName(QualifiedTypeName::namespaced(
+        "other".to_string(),
+        "Child".to_string(),
+    )));
+
+    let output = render_output(&config, vec![], &registry);
+    assert!(output.contains("from shared_types import models as other"));
+    assert!(!output.contains("from . import other"));
+}
+
+#[test]
+fn output_sorts_plugin_imports() {
+    let config = CodeGeneratorConfig::new("root".to_string());
+    let registry = registry_with_struct_field(Format::Bytes);
+
+    let output = render_output(
+        &config,
+        vec![Arc::new(BincodePlugin), Arc::new(JsonPlugin)],
+        &registry,
+    );
+    let header = output.split("\n\n\n").next().unwrap();
+    insta::assert_snapshot!(header, @"
+    from __future__ import annotations
+
+    from dataclasses import dataclass
+    from typing import Any
+
+    from .bincode import BincodeDeserializer, BincodeSerializer
+    from .serde import (
+        DeserializationError,
+        Deserializer,
+        Serializer,
+        bool_from_json,
+        bytes_from_json,
+        bytes_to_json,
+        char_from_json,
+        date_from_json,
+        duration_from_json,
+        duration_to_json,
+        external_tag,
+        float_from_json,
+        int_from_json,
+        list_from_json,
+        object_from_json,
+        option_from_json,
+        option_to_json,
+        str_from_json,
+        time_from_json,
+        timestamp_from_json,
+        timestamp_to_json,
+        tuple_from_json,
+        unit_from_json,
+        uuid_from_json,
+    )
+    ");
+}
+
+#[test]
+fn output_keeps_indices_of_skipped_variants() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Colour {
+        Red,
+        #[facet(fg::skip(python))]
+        Green,
+        Blue,
+    }
+
+    let registry = reflect!(Colour).unwrap();
+    let config = CodeGeneratorConfig::new("root".to_string());
+
+    let output = render_output(&config, vec![Arc::new(BincodePlugin)], &registry);
+    insta::assert_snapshot!(output, @r#"
+    from __future__ import annotations
+
+    from enum import Enum
+
+    from .bincode import BincodeDeserializer, BincodeSerializer
+    from .serde import DeserializationError, Deserializer, Serializer
+
+
+    class Colour(Enum):
+        RED = "Red"
+        BLUE = "Blue"
+
+        def serialize(self, serializer: Serializer) -> None:
+            serializer.increase_container_depth()
+            match self:
+                case Colour.RED:
+                    serializer.serialize_variant_index(0)
+                case Colour.BLUE:
+                    serializer.serialize_variant_index(2)
+            serializer.decrease_container_depth()
+
+        def bincode_serialize(self) -> bytes:
+            serializer = BincodeSerializer()
+            self.serialize(serializer)
+            return serializer.get_bytes()
+
+        @classmethod
+        def deserialize(cls, deserializer: Deserializer) -> Colour:
+            deserializer.increase_container_depth()
+            index = deserializer.deserialize_variant_index()
+            deserializer.decrease_container_depth()
+            match index:
+                case 0:
+                    return cls.RED
+                case 2:
+                    return cls.BLUE
+                case _:
+                    raise DeserializationError(f"Unknown variant index for Colour: {index}")
+
+        @classmethod
+        def bincode_deserialize(cls, data: bytes) -> Colour:
+            deserializer = BincodeDeserializer(data)
+            value = cls.deserialize(deserializer)
+            if deserializer.get_buffer_offset() < len(data):
+                raise DeserializationError("Some input bytes were not read")
+            return value
+    "#);
+}
+
+#[test]
+fn bincode_rejects_untagged_enums() {
+    #[derive(Facet)]
+    #[facet(untagged)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Value {
+        Number(f64),
+        Text(String),
+    }
+
+    let registry = reflect!(Value).unwrap();
+    let config = CodeGeneratorConfig::new("root".to_string());
+
+    let generator = PythonCodeGenerator::new(&config).with_plugins(vec![Arc::new(BincodePlugin)]);
+    let error = generator.output(&mut Vec::new(), &registry).unwrap_err();
+    insta::assert_snapshot!(error, @"bincode can't encode `Value`: untagged enums don't write the variant index bincode needs");
+}
@@ -0,0 +1,335 @@
+//! Project scaffolding — writes a ready-to-install Python package to disk.
+//!
+//! The [`Installer`] is the final stage of the Python generation pipeline.
+//! While [`PythonCodeGenerator`] produces the *contents* of a single module,
+//! the installer is responsible for the surrounding package structure:
+//!
+//! 1. **Runtime packages** — copies the serde and/or bincode runtime packages
+//!    into `<package>/serde/` and `<package>/bincode/`, where the generated
+//!    modules import them with relative imports.
+//!
+//! 2. **Per-namespace modules** — splits the registry by namespace (via
+//!    [`module::split`]) and calls [`PythonCodeGenerator`] once per namespace,
+//!    writing each to `<package>/<namespace>.py`. Cross-module type references
+//!    import the other module (`from . import other`) and use `other.Type`.
+//!
+//! 3. **`pyproject.toml`** — generates a manifest with the Python version
+//!    constraint and dependencies (external packages as `git+` URLs, or as
+//!    names with a `[tool.uv.sources]` path).
+
+use std::{
+    collections::BTreeSet,
+    fs::{File, create_dir_all},
+    io::Write as _,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use indoc::formatdoc;
+
+use crate::{
+    Registry,
+    generation::{
+        CodeGeneratorConfig, Error, ExternalPackage, ExternalPackages, PackageLocation,
+        SERDE_NAMESPACE, SourceInstaller,
+        bincode::BincodePlugin,
+        indent::IndentConfig,
+        json::JsonPlugin,
+        module,
+        plugin::{self, EmitterPlugin},
+        python::{Python, PythonCodeGenerator},
+    },
+    reflection::format::Language,
+};
+
+/// The Python versions that generated packages support. The generated code
+/// uses `match` statements and `kw_only` dataclasses, which need Python 3.10.
+const REQUIRES_PYTHON: &str = ">=3.10";
+
+/// Installer for generated source files in Python.
+///
+/// # Examples
+///
+/// ```rust
+/// use facet_generate::generation::python;
+///
+/// let output_dir = std::path::PathBuf::from("output");
+/// let installer = python::Installer::new("my_package", &output_dir);
+/// ```
+pub struct Installer {
+    package_name: String,
+    install_dir: PathBuf,
+    external_packages: ExternalPackages,
+    indent: IndentConfig,
+    plugins: Vec<Arc<dyn EmitterPlugin<Python>>>,
+    /// The names of all the registry's enums, unit-variant enums and branded
+    /// types, across namespaces: whether a type is read by a module function
+    /// depends on its kind, which the module that refers to it can't see.
+    type_names: Option<CodeGeneratorConfig>,
+}
+
+impl Installer {
+    /// Create a new installer for the given package name and output directory.
+    ///
+    /// The package name must be a valid Python package name (e.g.
+    /// `my_package`). Use the builder methods [`plugin`](Self::plugin) and
+    /// [`external_packages`](Self::external_packages) to configure, then call
+    /// [`generate`](Self::generate) to produce the output.
+    #[must_use]
+    pub fn new(package_name: &str, install_dir: impl AsRef<Path>) -> Self {
+        Self {
+            package_name: package_name.to_string(),
+            install_dir: install_dir.as_ref().to_path_buf(),
+            external_packages: ExternalPackages::new(),
+            indent: IndentConfig::Space(4),
+            plugins: vec![],
+            type_names: None,
+        }
+    }
+
+    /// Add a plugin to be used during code generation.
+    ///
+    /// When multiple plugins are added, they are invoked in the order they were registered.
+    #[must_use]
+    pub fn plugin<P: EmitterPlugin<Python> + 'static>(mut self, plugin: P) -> Self {
+        self.plugins.push(Arc::new(plugin));
+        self
+    }
+
+    /// Set external packages to reference.
+    #[must_use]
+    pub fn external_packages(mut self, packages: &[ExternalPackage]) -> Self {
+        self.external_packages = packages
+            .iter()
+            .map(|d| (d.for_namespace.clone(), d.clone()))
+            .collect();
+        self
+    }
+
+    /// Set the indentation of generated source files (four spaces by default).
+    #[must_use]
+    pub const fn indent(mut self, indent: IndentConfig) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Generate all code for the given registry.
+    ///
+    /// This method:
+    /// 1. Installs the runtimes of the configured plugins
+    /// 2. Splits the registry by namespace and installs each module
+    /// 3. Writes the package's `__init__.py` and `py.typed` marker
+    /// 4. Writes the package manifest
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any file operation or code generation step fails.
+    pub fn generate(mut self, registry: &Registry) -> Result<(), Error> {
+        let checked = module::for_plugin_checks(registry, Language::Python);
+        let registry = &module::for_language(registry, Language::Python);
+        plugin::check_registry(&self.plugins, &checked)?;
+
+        if !self.external_packages.contains_key(SERDE_NAMESPACE) {
+            let mut written = BTreeSet::new();
+            for plugin in &self.plugins {
+                for file in plugin.runtime_files() {
+                    if written.insert(file.relative_path.clone()) {
+                        self.install_runtime_file(&file.relative_path, &file.contents)?;
+                    }
+                }
+            }
+        }
+
+        let mut type_names = CodeGeneratorConfig::new(self.package_name.clone());
+        type_names.update_from(registry);
+        self.type_names = Some(type_names);
+
+        let modules = module::split(&self.package_name, registry);
+        for (m, module_registry) in &modules {
+            let config = m.config().clone().with_indent(self.indent);
+            self.install_module(&config, module_registry)?;
+        }
+
+        let has_root = modules
+            .keys()
+            .any(|m| m.config().module_name() == self.package_name);
+        self.install_package_init(has_root)?;
+
+        let package_name = self.package_name.clone();
+        self.install_manifest(&package_name)?;
+
+        Ok(())
+    }
+
+    /// Installs the serde Python runtime into `<package>/serde/`.
+    ///
+    /// Most callers should prefer [`generate`](Self::generate).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any file I/O fails.
+    pub fn install_serde_runtime(&self) -> Result<(), Error> {
+        for file in EmitterPlugin::<Python>::runtime_files(&JsonPlugin) {
+            self.install_runtime_file(&file.relative_path, &file.contents)?;
+        }
+        Ok(())
+    }
+
+    /// Installs the bincode Python runtime into `<package>/bincode/`.
+    ///
+    /// It needs the serde runtime too. Most callers should prefer
+    /// [`generate`](Self::generate).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any file I/O fails.
+    pub fn install_bincode_runtime(&self) -> Result<(), Error> {
+        for file in EmitterPlugin::<Python>::runtime_files(&BincodePlugin)
+            .into_iter()
+            .filter(|f| f.relative_path.starts_with("bincode/"))
+        {
+            self.install_runtime_file(&file.relative_path, &file.contents)?;
+        }
+        Ok(())
+    }
+
+    /// The directory of the package's modules.
+    fn package_dir(&self) -> PathBuf {
+        self.install_dir.join(&self.package_name)
+    }
+
+    fn install_runtime_file(&self, relative_path: &str, contents: &[u8]) -> Result<(), Error> {
+        let dest = self.package_dir().join(relative_path);
+        if let Some(parent) = dest.parent() {
+            create_dir_all(parent)?;
+        }
+        std::fs::write(&dest, contents)?;
+        Ok(())
+    }
+
+    /// Writes the package's `__init__.py`, which re-exports the types of the
+    /// root module if there is one, and the `py.typed` marker that tells type
+    /// checkers the package has type hints.
+    fn install_package_init(&self, has_root: bool) -> Result<(), Error> {
+        let dir = self.package_dir();
+        create_dir_all(&dir)?;
+        let mut init = format!("\"\"\"Types generated for `{}`.\"\"\"\n", self.package_name);
+        if has_root {
+            init.push_str(&format!(
+                "\nfrom .{} import *  # noqa: F403\n",
+                self.package_name
+            ));
+        }
+        std::fs::write(dir.join("__init__.py"), init)?;
+        std::fs::write(dir.join("py.typed"), "")?;
+        Ok(())
+    }
+
+    /// Produce the contents of a `pyproject.toml` manifest.
+    ///
+    /// Dependencies are derived from external packages: `Url` locations
+    /// become `git+` direct references at the package's version, if it has
+    /// one, and `Path` locations become dependencies on the package's name
+    /// whose source is set in `[tool.uv.sources]`.
+    #[must_use]
+    pub fn make_manifest(&self, package_name: &str) -> String {
+        let mut dependencies = vec![];
+        let mut sources = vec![];
+        for package in self.external_packages.values() {
+            let name = &package.for_namespace;
+            match &package.location {
+                PackageLocation::Url(url) => {
+                    let reference = package
+                        .version
+                        .as_ref()
+                        .map(|version| format!("@{version}"))
+                        .unwrap_or_default();
+                    dependencies.push(format!("    \"{name} @ git+{url}{reference}\",\n"));
+                }
+                PackageLocation::Path(path) => {
+                    dependencies.push(format!("    \"{name}\",\n"));
+                    sources.push(format!("{name} = {{ path = \"{path}\" }}\n"));
+                }
+            }
+        }
+        let dependencies = if dependencies.is_empty() {
+            "[]".to_string()
+        } else {
+            format!("[\n{}]", dependencies.concat())
+        };
+
+        let mut manifest = formatdoc!(
+            r#"
+            [build-system]
+            requires = ["setuptools>=61"]
+            build-backend = "setuptools.build_meta"
+
+            [project]
+            name = "{package_name}"
+            version = "0.1.0"
+            requires-python = "{REQUIRES_PYTHON}"
+            dependencies = {dependencies}
+
+            [tool.setuptools.package-data]
+            "*" = ["py.typed"]
+            "#
+        );
+        if !sources.is_empty() {
+            manifest.push_str("\n[tool.uv.sources]\n");
+            manifest.push_str(&sources.concat());
+        }
+
+        manifest
+    }
+}
+
+impl SourceInstaller for Installer {
+    /// Generate a single module for one namespace.
+    ///
+    /// The module is written as `<package>/<namespace>.py`. Namespaces that
+    /// correspond to external packages are skipped — their types are
+    /// imported rather than generated.
+    fn install_module(
+        &mut self,
+        config: &CodeGeneratorConfig,
+        registry: &Registry,
+    ) -> Result<(), Error> {
+        if self.external_packages.contains_key(config.module_name()) {
+            return Ok(());
+        }
+        let dir = self.package_dir();
+        create_dir_all(&dir)?;
+        let mut file = File::create(dir.join(format!("{}.py", config.module_name())))?;
+
+        let mut updated_config = config.clone();
+        updated_config.external_packages = self.external_packages.clone();
+        if let Some(type_names) = &self.type_names {
+            updated_config
+                .enum_type_names
+                .extend(type_names.enum_type_names.iter().cloned());
+            updated_config
+                .unit_variant_enums
+                .extend(type_names.unit_variant_enums.iter().cloned());
+            updated_config
+                .branded_type_names
+                .extend(type_names.branded_type_names.iter().cloned());
+        }
+
+        let generator =
+            PythonCodeGenerator::new(&updated_config).with_plugins(self.plugins.clone());
+        generator.output(&mut file, registry)?;
+
+        Ok(())
+    }
+
+    /// Write `pyproject.toml` to the output directory.
+    fn install_manifest(&self, package_name: &str) -> Result<(), Error> {
+        create_dir_all(&self.install_dir)?;
+        let mut file = File::create(self.install_dir.join("pyproject.toml"))?;
+        file.write_all(self.make_manifest(package_name).as_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
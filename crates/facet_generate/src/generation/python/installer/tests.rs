@@ -0,0 +1,206 @@
+//! Tests for the Python [`Installer`] — **package scaffolding**.
+//!
+//! These tests verify the `pyproject.toml` manifest that the installer
+//! generates, and the layout of the package it writes:
+//!
+//! - Basic manifest structure: project name, Python version, no dependencies.
+//! - External dependencies: `git+` references and `[tool.uv.sources]` paths.
+//! - Installed files: one module per namespace in the package's directory,
+//!   with the runtime packages of the configured plugins next to them.
+//! - Enums of other namespaces, which are read by their module functions.
+//! - Registries the plugins can't encode.
+
+use facet::Facet;
+
+use crate::generation::{
+    ExternalPackage, PackageLocation, bincode::BincodePlugin, json::JsonPlugin, python::Installer,
+};
+use crate::reflect;
+
+#[test]
+fn make_manifest_basic() {
+    let installer = Installer::new("my_package", "/tmp");
+    let manifest = installer.make_manifest("my_package");
+
+    insta::assert_snapshot!(manifest, @r#"
+    [build-system]
+    requires = ["setuptools>=61"]
+    build-backend = "setuptools.build_meta"
+
+    [project]
+    name = "my_package"
+    version = "0.1.0"
+    requires-python = ">=3.10"
+    dependencies = []
+
+    [tool.setuptools.package-data]
+    "*" = ["py.typed"]
+    "#);
+}
+
+#[test]
+fn make_manifest_with_external_packages() {
+    let external_packages = vec![
+        ExternalPackage {
+            for_namespace: "local_types".to_string(),
+            module_name: None,
+            location: PackageLocation::Path("../local_types".to_string()),
+            version: None,
+        },
+        ExternalPackage {
+            for_namespace: "remote_types".to_string(),
+            module_name: None,
+            location: PackageLocation::Url("https://example.com/remote_types.git".to_string()),
+            version: Some("v2.0.0".to_string()),
+        },
+    ];
+
+    let installer = Installer::new("my_package", "/tmp").external_packages(&external_packages);
+    let manifest = installer.make_manifest("my_package");
+
+    insta::assert_snapshot!(manifest, @r#"
+    [build-system]
+    requires = ["setuptools>=61"]
+    build-backend = "setuptools.build_meta"
+
+    [project]
+    name = "my_package"
+    version = "0.1.0"
+    requires-python = ">=3.10"
+    dependencies = [
+        "local_types",
+        "remote_types @ git+https://example.com/remote_types.git@v2.0.0",
+    ]
+
+    [tool.setuptools.package-data]
+    "*" = ["py.typed"]
+
+    [tool.uv.sources]
+    local_types = { path = "../local_types" }
+    "#);
+}
+
+#[test]
+fn generate_writes_package_layout() {
+    #[derive(Facet)]
+    struct Child {
+        value: i32,
+    }
+
+    mod other {
+        use crate as fg;
+        use facet::Facet;
+
+        #[derive(Facet)]
+        #[facet(fg::namespace = "other")]
+        pub struct Child {
+            value: String,
+        }
+    }
+
+    #[derive(Facet)]
+    struct Parent {
+        child: Child,
+        other_child: other::Child,
+    }
+
+    let registry = reflect!(Parent).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    Installer::new("my_package", install_dir.path())
+        .plugin(BincodePlugin)
+        .plugin(JsonPlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let mut files = ignore::Walk::new(install_dir.path())
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_file())
+        .map(|entry| {
+            entry
+                .path()
+                .strip_prefix(install_dir.path())
+                .unwrap()
+                .display()
+                .to_string()
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    insta::assert_snapshot!(files.join("\n"), @"
+    my_package/__init__.py
+    my_package/bincode/__init__.py
+    my_package/my_package.py
+    my_package/other.py
+    my_package/py.typed
+    my_package/serde/__init__.py
+    my_package/serde/binary.py
+    my_package/serde/deserializer.py
+    my_package/serde/errors.py
+    my_package/serde/json.py
+    my_package/serde/serializer.py
+    pyproject.toml
+    ");
+}
+
+#[test]
+fn generate_reads_enums_of_other_namespaces() {
+    mod other {
+        use crate as fg;
+        use facet::Facet;
+
+        #[derive(Facet)]
+        #[facet(fg::namespace = "other")]
+        #[repr(C)]
+        #[allow(unused)]
+        pub enum Shape {
+            Point,
+            Circle(f64),
+        }
+    }
+
+    #[derive(Facet)]
+    struct Drawing {
+        shapes: Vec<other::Shape>,
+    }
+
+    let registry = reflect!(Drawing).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    Installer::new("my_package", install_dir.path())
+        .plugin(BincodePlugin)
+        .plugin(JsonPlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let module =
+        std::fs::read_to_string(install_dir.path().join("my_package/my_package.py")).unwrap();
+    let reads = module
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with("shapes="))
+        .collect::<Vec<_>>();
+    insta::assert_snapshot!(reads.join("\n"), @r#"
+    shapes=deserializer.deserialize_seq(lambda: other.deserialize_shape(deserializer)),
+    shapes=[other.from_json_shape(level1) for level1 in list_from_json(obj.get("shapes"))],
+    "#);
+}
+
+#[test]
+fn json_rejects_internally_tagged_tuple_variants() {
+    #[derive(Facet)]
+    #[facet(tag = "type")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Point(i32, i32),
+        Empty,
+    }
+
+    let registry = reflect!(Shape).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    let installer = Installer::new("my_package", install_dir.path()).plugin(JsonPlugin);
+
+    let error = installer.generate(&registry).unwrap_err();
+    insta::assert_snapshot!(error, @"json (Python) can't encode `Shape`: internally tagged enums can't have tuple variants");
+}
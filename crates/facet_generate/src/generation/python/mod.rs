@@ -0,0 +1,39 @@
+//! Python code generation.
+//!
+//! This module translates a [`Registry`](crate::Registry) of reflected type
+//! definitions into type-hinted Python source code, for scripts and services
+//! that exchange data with the Rust core.
+//!
+//! # Submodules (in pipeline order)
+//!
+//! 1. **generator** — Top-level orchestrator. [`PythonCodeGenerator`](crate::generation::python::PythonCodeGenerator) implements
+//!    [`CodeGenerator`](crate::generation::CodeGenerator) to produce a complete Python module
+//!    from a registry. It resolves qualified type names against the
+//!    configuration (external packages, namespaces), and delegates writing to the emitter layer.
+//!
+//! 2. **emitter** — AST-to-source rendering. Implements
+//!    [`Emitter<Python>`](crate::generation::Emitter) for each AST node type
+//!    ([`Module`](crate::generation::module::Module), [`Container`](crate::generation::Container),
+//!    `Named<Format>`, `Format`, `Doc`). This is where the Python language
+//!    mapping lives: frozen `@dataclass`es, `Enum`s for all-unit enums and
+//!    `Union`s of variant dataclasses, told apart by their `Literal` tags, for
+//!    enums whose variants carry data.
+//!
+//! 3. **installer** — Project scaffolding. [`Installer`](crate::generation::python::Installer) implements
+//!    [`SourceInstaller`](crate::generation::SourceInstaller) to write a ready-to-install
+//!    Python package: it copies the serde/bincode runtime packages into it,
+//!    splits the registry by namespace into one module per namespace, and
+//!    generates a `pyproject.toml` manifest.
+
+pub use emitter::Python;
+pub use generator::PythonCodeGenerator;
+pub use installer::Installer;
+
+pub(crate) use emitter::{
+    enum_member, field_default, function_name, identifier, import_module, string_literal,
+    variant_class,
+};
+
+mod emitter;
+mod generator;
+mod installer;
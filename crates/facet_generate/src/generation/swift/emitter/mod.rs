@@ -9,8 +9,8 @@
 //! | AST node | Swift output |
 //! |---|---|
 //! | [`Module`] | `import` statements, feature helpers |
-//! | [`Container`] | `public struct` or `indirect public enum` |
-//! | [`Named<Format>`](Named) | `public var` property / `case` declaration |
+//! | [`Container`] | `public struct`, `indirect public enum` or `public typealias` (without `public` when internal) |
+//! | [`Named<Format>`](Named) | `public var` property (`let` if read-only) / `case` declaration |
 //! | [`Format`] | Inline type expression (`Int32`, `[String]`, `Set<T>`, …) |
 //! | [`Doc`] | `///` doc comments |
 //! | `(Named<VariantFormat>, Usage)` | An enum case declaration |
@@ -62,18 +62,19 @@ use indoc::formatdoc;
 
 use heck::ToUpperCamelCase as _;
 
-use crate::generation::CodeGeneratorConfig;
+use crate::generation::{CodeGeneratorConfig, Feature};
 use crate::{
     Registry,
     generation::{
         Container, Emitter,
         indent::{IndentWrite, Newlines},
         module::Module,
-        plugin::{EmitContext, EmitterPlugin},
+        plugin::{EmitContext, EmitterPlugin, type_annotations, type_conformances},
         swift::generator::{compute_equatable_types, compute_hashable_types},
     },
     reflection::format::{
-        ContainerFormat, Doc, Format, Named, Namespace, QualifiedTypeName, VariantFormat,
+        ContainerFormat, DefaultValue, Deprecation, Doc, Format, Language, Named, Namespace,
+        QualifiedTypeName, VariantFormat, Visibility,
     },
 };
 
@@ -161,6 +162,12 @@ pub fn is_hashable(format: &Format, lang: &Swift) -> bool {
         Format::TypeName(qtn) => {
             !lang.local_types.contains(qtn) || lang.hashable_types.contains(qtn)
         }
+        // Type parameters are constrained to `Hashable` on hashable generic types.
+        Format::TypeParameter(_) => true,
+        Format::Generic { name, args } => {
+            (!lang.local_types.contains(name) || lang.hashable_types.contains(name))
+                && args.iter().all(|arg| is_hashable(arg, lang))
+        }
 
         Format::Bool
         | Format::I8
@@ -178,7 +185,11 @@ pub fn is_hashable(format: &Format, lang: &Swift) -> bool {
         | Format::Char
         | Format::Str
         | Format::Bytes
-        | Format::Uuid => true,
+        | Format::Uuid
+        | Format::Timestamp
+        | Format::Date
+        | Format::Time
+        | Format::Duration => true,
 
         Format::Option(inner)
         | Format::Set(inner)
@@ -224,7 +235,7 @@ enum Usage {
 /// `struct_name`.
 fn needs_indirect(format: &Format, struct_name: &str) -> bool {
     match format {
-        Format::TypeName(qtn) => qtn.name == struct_name,
+        Format::TypeName(qtn) | Format::Generic { name: qtn, .. } => qtn.name == struct_name,
         Format::Option(inner) => needs_indirect(inner, struct_name),
         Format::Tuple(formats) => formats.iter().any(|f| needs_indirect(f, struct_name)),
         _ => false,
@@ -242,6 +253,12 @@ fn is_equatable_auto(format: &Format, lang: &Swift) -> bool {
         Format::TypeName(qtn) => {
             !lang.local_types.contains(qtn) || lang.equatable_types.contains(qtn)
         }
+        // Type parameters are constrained to `Equatable` on equatable generic types.
+        Format::TypeParameter(_) => true,
+        Format::Generic { name, args } => {
+            (!lang.local_types.contains(name) || lang.equatable_types.contains(name))
+                && args.iter().all(|arg| is_equatable_auto(arg, lang))
+        }
         Format::Variable(_) | Format::Unit => false,
         Format::Bool
         | Format::I8
@@ -259,7 +276,11 @@ fn is_equatable_auto(format: &Format, lang: &Swift) -> bool {
         | Format::Char
         | Format::Str
         | Format::Bytes
-        | Format::Uuid => true,
+        | Format::Uuid
+        | Format::Timestamp
+        | Format::Date
+        | Format::Time
+        | Format::Duration => true,
         Format::Option(inner) | Format::Set(inner) => is_equatable_auto(inner, lang),
         Format::Seq(inner) | Format::TupleArray { content: inner, .. } => {
             is_equatable_auto(inner, lang)
@@ -313,6 +334,20 @@ impl Emitter<Swift> for Module {
             imports.push(ns.to_upper_camel_case());
         }
 
+        // `Date`, `DateComponents` and `TimeInterval` come from Foundation.
+        let features = &self.config().features;
+        if [
+            Feature::Timestamp,
+            Feature::Date,
+            Feature::Time,
+            Feature::Duration,
+        ]
+        .iter()
+        .any(|feature| features.contains(feature))
+        {
+            imports.push("Foundation".to_string());
+        }
+
         // Plugin imports (e.g. `import Serde`).
         for plugin in lang.plugins() {
             imports.extend(plugin.imports(self.config()));
@@ -341,22 +376,37 @@ impl Emitter<Swift> for Container<'_> {
     fn write<W: IndentWrite>(&self, w: &mut W, lang: &Swift) -> Result<()> {
         let Container { format, .. } = self;
         match format {
-            ContainerFormat::UnitStruct(doc) => struct_(w, self, &[], doc, lang),
-            ContainerFormat::NewTypeStruct(format, doc) => struct_(
+            ContainerFormat::UnitStruct(doc, _) => struct_(w, self, &[], doc, lang),
+            ContainerFormat::NewTypeStruct(format, doc, metadata) if metadata.proxy => {
+                type_alias(w, self, format, doc, lang)
+            }
+            ContainerFormat::NewTypeStruct(format, doc, metadata) if metadata.branded => {
+                // A branded newtype stores its value as `rawValue`, so that it conforms to
+                // `RawRepresentable`; plugins see it as a struct with that single field.
+                let field = Named::new(format.as_ref(), "rawValue".to_string());
+                let format =
+                    ContainerFormat::Struct(vec![field.clone()], doc.clone(), metadata.clone());
+                let container = Container {
+                    name: self.name,
+                    format: &format,
+                };
+                struct_(w, &container, &[&field], doc, lang)
+            }
+            ContainerFormat::NewTypeStruct(format, doc, _) => struct_(
                 w,
                 self,
                 &[&Named::new(format, "value".to_string())],
                 doc,
                 lang,
             ),
-            ContainerFormat::TupleStruct(formats, doc) => {
+            ContainerFormat::TupleStruct(formats, doc, _) => {
                 let formats = named(formats, "field");
                 struct_(w, self, &formats.iter().collect::<Vec<_>>(), doc, lang)
             }
-            ContainerFormat::Struct(nameds, doc) => {
+            ContainerFormat::Struct(nameds, doc, _) => {
                 struct_(w, self, &nameds.iter().collect::<Vec<_>>(), doc, lang)
             }
-            ContainerFormat::Enum(variants, _, doc) => enum_(w, self, variants, doc, lang),
+            ContainerFormat::Enum(variants, _, doc, _) => enum_(w, self, variants, doc, lang),
         }
     }
 }
@@ -383,6 +433,17 @@ impl Emitter<Swift> for Format {
             Self::TypeName(qualified_type_name) => {
                 write!(w, "{}", render_type_name(qualified_type_name, &lang.config))
             }
+            Self::Generic { name, args } => {
+                write!(w, "{}<", render_type_name(name, &lang.config))?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(w, ", ")?;
+                    }
+                    arg.write(w, lang)?;
+                }
+                write!(w, ">")
+            }
+            Self::TypeParameter(name) => write!(w, "{name}"),
             Self::Unit => write!(w, "Void"),
             Self::Bool => write!(w, "Bool"),
             Self::I8 => write!(w, "Int8"),
@@ -401,6 +462,9 @@ impl Emitter<Swift> for Format {
             Self::Str => write!(w, "String"),
             Self::Bytes => write!(w, "[UInt8]"),
             Self::Uuid => write!(w, "UUID"),
+            Self::Timestamp => write!(w, "Date"),
+            Self::Date | Self::Time => write!(w, "DateComponents"),
+            Self::Duration => write!(w, "TimeInterval"),
 
             Self::Option(format) => {
                 format.write(w, lang)?;
@@ -472,25 +536,54 @@ impl Emitter<Swift> for Format {
 
 impl Emitter<Swift> for (&Named<Format>, Usage) {
     fn write<W: IndentWrite>(&self, w: &mut W, lang: &Swift) -> Result<()> {
-        let (Named { name, doc, value }, usage) = self;
+        let (
+            Named {
+                name,
+                doc,
+                value,
+                metadata,
+            },
+            usage,
+        ) = self;
         let name = &name.to_lower_camel_case();
+        let access = access_modifier(lang.config.visibility(metadata.visibility));
+        let write_type = |w: &mut W| match metadata.type_override(Language::Swift) {
+            Some(type_override) => write!(w, "{}", type_override.ty),
+            None => value.write(w, lang),
+        };
 
         match usage {
             Usage::Field => {
                 doc.write(w, lang)?;
-                write!(w, "public var {name}: ")?;
-                value.write(w, lang)?;
+                write_deprecation(w, metadata.deprecated.as_ref())?;
+                let keyword = if metadata.readonly { "let" } else { "var" };
+                write!(w, "{access}{keyword} {name}: ")?;
+                write_type(w)?;
                 writeln!(w)
             }
             Usage::IndirectField => {
+                // A property wrapper can't be a `let`, so a read-only field gets a private setter.
                 doc.write(w, lang)?;
-                write!(w, "@Indirect public var {name}: ")?;
-                value.write(w, lang)?;
+                write_deprecation(w, metadata.deprecated.as_ref())?;
+                let setter = if metadata.readonly {
+                    "private(set) "
+                } else {
+                    ""
+                };
+                write!(w, "@Indirect {access}{setter}var {name}: ")?;
+                write_type(w)?;
                 writeln!(w)
             }
             Usage::Parameter => {
                 write!(w, "{name}: ")?;
-                value.write(w, lang)
+                write_type(w)?;
+                if metadata.type_override(Language::Swift).is_none()
+                    && let Some(default) = metadata.default.as_ref()
+                    && let Some(value) = default_value(default, value)
+                {
+                    write!(w, " = {value}")?;
+                }
+                Ok(())
             }
             Usage::Assignment => writeln!(w, "self.{name} = {name}"),
         }
@@ -508,12 +601,14 @@ impl Emitter<Swift> for (&Named<VariantFormat>, Usage) {
                 name,
                 doc,
                 value: format,
+                metadata,
             },
             usage,
         ) = self;
         let name = name.to_lower_camel_case();
 
         doc.write(w, lang)?;
+        write_deprecation(w, metadata.deprecated.as_ref())?;
 
         match usage {
             Usage::IndirectField => {
@@ -568,6 +663,24 @@ impl Emitter<Swift> for Doc {
     }
 }
 
+/// Emits the `typealias` declared by a serialization proxy. Uses of the type have
+/// already been replaced by the proxy's format, so it needs no plugin code.
+fn type_alias<W: IndentWrite>(
+    w: &mut W,
+    container: &Container<'_>,
+    format: &Format,
+    doc: &Doc,
+    lang: &Swift,
+) -> Result<()> {
+    doc.write(w, lang)?;
+    let metadata = container.format.metadata();
+    write_deprecation(w, metadata.deprecated.as_ref())?;
+    let access = access_modifier(lang.config.visibility(metadata.visibility));
+    write!(w, "{access}typealias {} = ", container.name.name)?;
+    format.write(w, lang)?;
+    writeln!(w)
+}
+
 // ---------------------------------------------------------------------------
 // struct_ — emits a public struct
 // ---------------------------------------------------------------------------
@@ -585,6 +698,7 @@ fn struct_<W: IndentWrite>(
     let name = &container.name.name;
 
     doc.write(w, lang)?;
+    write_deprecation(w, container.format.metadata().deprecated.as_ref())?;
 
     let has_plugins = !lang.plugins().is_empty();
     let all_hashable = fields.iter().all(|f| is_hashable(&f.value, lang));
@@ -600,10 +714,27 @@ fn struct_<W: IndentWrite>(
         implements.push("Equatable");
     }
 
-    if has_plugins && !implements.is_empty() {
-        write!(w, "public struct {name}: {} ", implements.join(", "))?;
+    let generics = type_params(container, &implements, has_plugins);
+    if !has_plugins {
+        implements.clear();
+    }
+    if container.format.is_branded() {
+        implements.insert(0, "RawRepresentable");
+    }
+    let ctx = EmitContext::top_level(container, &lang.config);
+    let conformances = type_conformances(lang.plugins(), &ctx, Language::Swift);
+    extend_conformances(&mut implements, &conformances);
+    write_annotations(w, &ctx, lang)?;
+    let metadata = container.format.metadata();
+    let access = access_modifier(lang.config.visibility(metadata.visibility));
+    if !implements.is_empty() {
+        write!(
+            w,
+            "{access}struct {name}{generics}: {} ",
+            implements.join(", ")
+        )?;
     } else {
-        write!(w, "public struct {name} ")?;
+        write!(w, "{access}struct {name}{generics} ")?;
     }
 
     let mut w = w.block(Newlines::BOTH)?;
@@ -614,7 +745,7 @@ fn struct_<W: IndentWrite>(
         } else {
             Usage::Field
         };
-        (*field, usage).write(&mut w, lang)?;
+        (&field.inheriting(metadata), usage).write(&mut w, lang)?;
     }
 
     if !fields.is_empty() {
@@ -637,7 +768,6 @@ fn struct_<W: IndentWrite>(
     }
 
     // Plugin type bodies (serialize / deserialize methods).
-    let ctx = EmitContext::top_level(container, &lang.config);
     for plugin in lang.plugins() {
         plugin.type_body(&mut w as &mut dyn IndentWrite, &ctx)?;
     }
@@ -693,6 +823,7 @@ fn enum_<W: IndentWrite>(
     let name = &container.name.name;
 
     doc.write(w, lang)?;
+    write_deprecation(w, container.format.metadata().deprecated.as_ref())?;
 
     let has_plugins = !lang.plugins().is_empty();
     let all_hashable = variants
@@ -714,10 +845,26 @@ fn enum_<W: IndentWrite>(
         implements.push("Equatable");
     }
 
-    if has_plugins && !implements.is_empty() {
-        write!(w, "indirect public enum {name}: {} ", implements.join(", "))?;
+    let generics = type_params(container, &implements, has_plugins);
+    if !has_plugins {
+        implements.clear();
+    }
+    let ctx = EmitContext::top_level(container, &lang.config);
+    let conformances = type_conformances(lang.plugins(), &ctx, Language::Swift);
+    extend_conformances(&mut implements, &conformances);
+    write_annotations(w, &ctx, lang)?;
+    let access = access_modifier(
+        lang.config
+            .visibility(container.format.metadata().visibility),
+    );
+    if !implements.is_empty() {
+        write!(
+            w,
+            "indirect {access}enum {name}{generics}: {} ",
+            implements.join(", ")
+        )?;
     } else {
-        write!(w, "indirect public enum {name} ")?;
+        write!(w, "indirect {access}enum {name}{generics} ")?;
     }
 
     let mut w = w.block(Newlines::BOTH)?;
@@ -727,7 +874,6 @@ fn enum_<W: IndentWrite>(
     }
 
     // Plugin type bodies (serialize / deserialize methods).
-    let ctx = EmitContext::top_level(container, &lang.config);
     for plugin in lang.plugins() {
         plugin.type_body(&mut w as &mut dyn IndentWrite, &ctx)?;
     }
@@ -832,6 +978,118 @@ fn write_enum_eq<W: IndentWrite>(
 // Utility
 // ---------------------------------------------------------------------------
 
+/// The generic parameter clause of a type declaration (e.g. `<T: Hashable>`),
+/// empty for a non-generic type. Each type parameter is constrained to the
+/// first protocol the type conforms to, so that the conformance can still be
+/// synthesized.
+fn type_params(container: &Container<'_>, implements: &[&str], has_plugins: bool) -> String {
+    let type_params = container.format.type_params();
+    if type_params.is_empty() {
+        return String::new();
+    }
+    let constraint = match implements.first() {
+        Some(protocol) if has_plugins => format!(": {protocol}"),
+        _ => String::new(),
+    };
+    let params = type_params
+        .iter()
+        .map(|param| format!("{param}{constraint}"))
+        .collect::<Vec<_>>();
+    format!("<{}>", params.join(", "))
+}
+
+/// Appends the conformances of the plugins and those declared with `fg::conforms` to the
+/// protocols that the type already conforms to, leaving out any that are listed already.
+fn extend_conformances<'a>(implements: &mut Vec<&'a str>, conformances: &'a [String]) {
+    for conformance in conformances {
+        if !implements.contains(&conformance.as_str()) {
+            implements.push(conformance);
+        }
+    }
+}
+
+/// Writes the attributes of a type declaration: those of the plugins, followed by any declared
+/// with `fg::annotate`.
+fn write_annotations<W: IndentWrite>(w: &mut W, ctx: &EmitContext, lang: &Swift) -> Result<()> {
+    for annotation in type_annotations(lang.plugins(), ctx, Language::Swift) {
+        writeln!(w, "{annotation}")?;
+    }
+    Ok(())
+}
+
+/// Writes the `@available(*, deprecated)` attribute of a deprecated declaration, if it is one.
+fn write_deprecation<W: IndentWrite>(w: &mut W, deprecated: Option<&Deprecation>) -> Result<()> {
+    match deprecated.map(Deprecation::message) {
+        Some(Some(message)) => writeln!(
+            w,
+            "@available(*, deprecated, message: \"{}\")",
+            escape(&message)
+        ),
+        Some(None) => writeln!(w, "@available(*, deprecated)"),
+        None => Ok(()),
+    }
+}
+
+/// Escapes text for a string literal.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t")
+}
+
+/// The Swift expression of a field's default value, or `None` if it doesn't suit the field's
+/// type (e.g. because a serialization proxy changed it).
+pub(crate) fn default_value(default: &DefaultValue, format: &Format) -> Option<String> {
+    Some(match (default, format) {
+        (DefaultValue::Empty, Format::Option(_)) => "nil".to_string(),
+        (DefaultValue::Empty, Format::Seq(_) | Format::Set(_) | Format::Bytes) => "[]".to_string(),
+        (DefaultValue::Empty, Format::Map { .. }) => "[:]".to_string(),
+        (DefaultValue::Bool(value), Format::Bool) => value.to_string(),
+        // The runtime's 128-bit integers are pairs of 64-bit halves.
+        (DefaultValue::Integer(value), Format::I128) => {
+            let value = value.parse::<i128>().ok()?;
+            format!(
+                "Int128(high: {}, low: {})",
+                (value >> 64) as i64,
+                value as u64
+            )
+        }
+        (DefaultValue::Integer(value), Format::U128) => {
+            let value = value.parse::<u128>().ok()?;
+            format!(
+                "UInt128(high: {}, low: {})",
+                (value >> 64) as u64,
+                value as u64
+            )
+        }
+        (
+            DefaultValue::Integer(value),
+            Format::I8
+            | Format::I16
+            | Format::I32
+            | Format::I64
+            | Format::U8
+            | Format::U16
+            | Format::U32
+            | Format::U64,
+        )
+        | (DefaultValue::Float(value), Format::F32 | Format::F64) => value.clone(),
+        (DefaultValue::Char(value), Format::Char) => format!("\"{}\"", escape(&value.to_string())),
+        (DefaultValue::Str(value), Format::Str) => format!("\"{}\"", escape(value)),
+        _ => return None,
+    })
+}
+
+/// The access-level modifier of a declaration; `internal` is Swift's default, so it is left out.
+const fn access_modifier(visibility: Visibility) -> &'static str {
+    match visibility {
+        Visibility::Public => "public ",
+        Visibility::Internal => "",
+    }
+}
+
 fn named<Format: Clone>(formats: &[Format], prefix: &str) -> Vec<Named<Format>> {
     formats
         .iter()
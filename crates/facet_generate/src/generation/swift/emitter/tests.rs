@@ -1022,3 +1022,132 @@ fn type_in_root_and_map_in_named_namespace() {
     }
     ");
 }
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as Swift).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    public struct Listing {
+        public var names: Page<String>
+        public var ids: Page<UInt32>
+
+        public init(names: Page<String>, ids: Page<UInt32>) {
+            self.names = names
+            self.ids = ids
+        }
+    }
+
+    public struct Page<T> {
+        public var items: [T]
+        public var next: T?
+        public var total: UInt32
+
+        public init(items: [T], next: T?, total: UInt32) {
+            self.items = items
+            self.next = next
+            self.total = total
+        }
+    }
+    ");
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E, retry: bool },
+        Pending,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        first: Outcome<String, i32>,
+        second: Outcome<bool, String>,
+    }
+
+    let actual = emit!(Response as Swift).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+    indirect public enum Outcome<T, E> {
+        case success(T)
+        case failure(error: E, retry: Bool)
+        case pending
+    }
+
+    public struct Response {
+        public var first: Outcome<String, Int32>
+        public var second: Outcome<Bool, String>
+
+        public init(first: Outcome<String, Int32>, second: Outcome<Bool, String>) {
+            self.first = first
+            self.second = second
+        }
+    }
+    ");
+}
+
+#[test]
+fn defaults() {
+    #[derive(Facet)]
+    struct Settings {
+        #[facet(default = -1)]
+        limit: i128,
+        #[facet(default = 0.5_f32)]
+        ratio: f32,
+        #[facet(default)]
+        counts: HashMap<String, u32>,
+        retries: u32,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Command {
+        Move {
+            x: i32,
+            #[facet(default = "fast\n".to_string())]
+            speed: String,
+        },
+        Stop,
+    }
+
+    let actual = emit!(Settings, Command as Swift).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    indirect public enum Command {
+        case move(x: Int32, speed: String = "fast\n")
+        case stop
+    }
+
+    public struct Settings {
+        public var limit: Int128
+        public var ratio: Float
+        public var counts: [String: UInt32]
+        public var retries: UInt32
+
+        public init(limit: Int128 = Int128(high: -1, low: 18446744073709551615), ratio: Float = 0.5, counts: [String: UInt32] = [:], retries: UInt32) {
+            self.limit = limit
+            self.ratio = ratio
+            self.counts = counts
+            self.retries = retries
+        }
+    }
+    "#);
+}
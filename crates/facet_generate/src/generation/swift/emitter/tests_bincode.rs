@@ -162,6 +162,103 @@ fn newtype_struct() {
     "#);
 }
 
+#[test]
+fn branded_newtype_struct() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    #[derive(Facet)]
+    struct User {
+        id: UserId,
+        manager: Option<UserId>,
+    }
+
+    let actual = emit!(User as Swift with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public struct User: Hashable, Equatable {
+        public var id: UserId
+        public var manager: UserId?
+
+        public init(id: UserId, manager: UserId?) {
+            self.id = id
+            self.manager = manager
+        }
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            try self.id.serialize(serializer: serializer)
+            try serializeOption(value: self.manager, serializer: serializer) { value, serializer in
+                try value.serialize(serializer: serializer)
+            }
+            try serializer.decrease_container_depth()
+        }
+
+        public func bincodeSerialize() throws -> [UInt8] {
+            let serializer = BincodeSerializer.init();
+            try self.serialize(serializer: serializer)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> User {
+            try deserializer.increase_container_depth()
+            let id = try UserId.deserialize(deserializer: deserializer)
+            let manager = try deserializeOption(deserializer: deserializer) { deserializer in
+                try UserId.deserialize(deserializer: deserializer)
+            }
+            try deserializer.decrease_container_depth()
+            return User(id: id, manager: manager)
+        }
+
+        public static func bincodeDeserialize(input: [UInt8]) throws -> User {
+            let deserializer = BincodeDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+
+    public struct UserId: RawRepresentable, Hashable, Equatable {
+        public var rawValue: String
+
+        public init(rawValue: String) {
+            self.rawValue = rawValue
+        }
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            try serializer.serialize_str(value: self.rawValue)
+            try serializer.decrease_container_depth()
+        }
+
+        public func bincodeSerialize() throws -> [UInt8] {
+            let serializer = BincodeSerializer.init();
+            try self.serialize(serializer: serializer)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> UserId {
+            try deserializer.increase_container_depth()
+            let rawValue = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return UserId(rawValue: rawValue)
+        }
+
+        public static func bincodeDeserialize(input: [UInt8]) throws -> UserId {
+            let deserializer = BincodeDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+    "#);
+}
+
 #[test]
 fn tuple_struct() {
     /// line 1
@@ -2223,3 +2320,595 @@ fn namespaced_child() {
     }
     "#);
 }
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as Swift with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public struct Listing: Hashable, Equatable {
+        public var names: Page<String>
+        public var ids: Page<UInt32>
+
+        public init(names: Page<String>, ids: Page<UInt32>) {
+            self.names = names
+            self.ids = ids
+        }
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            try self.names.serialize(serializer: serializer, { value, serializer in
+                try serializer.serialize_str(value: value)
+            })
+            try self.ids.serialize(serializer: serializer, { value, serializer in
+                try serializer.serialize_u32(value: value)
+            })
+            try serializer.decrease_container_depth()
+        }
+
+        public func bincodeSerialize() throws -> [UInt8] {
+            let serializer = BincodeSerializer.init();
+            try self.serialize(serializer: serializer)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> Listing {
+            try deserializer.increase_container_depth()
+            let names = try Page.deserialize(deserializer: deserializer, { deserializer in
+                try deserializer.deserialize_str()
+            })
+            let ids = try Page.deserialize(deserializer: deserializer, { deserializer in
+                try deserializer.deserialize_u32()
+            })
+            try deserializer.decrease_container_depth()
+            return Listing(names: names, ids: ids)
+        }
+
+        public static func bincodeDeserialize(input: [UInt8]) throws -> Listing {
+            let deserializer = BincodeDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+
+    public struct Page<T: Hashable>: Hashable, Equatable {
+        public var items: [T]
+        public var next: T?
+        public var total: UInt32
+
+        public init(items: [T], next: T?, total: UInt32) {
+            self.items = items
+            self.next = next
+            self.total = total
+        }
+
+        public func serialize<S: Serializer>(serializer: S, _ serializeT: (T, S) throws -> Void) throws {
+            try serializer.increase_container_depth()
+            try serializeArray(value: self.items, serializer: serializer) { item, serializer in
+                try serializeT(item, serializer)
+            }
+            try serializeOption(value: self.next, serializer: serializer) { value, serializer in
+                try serializeT(value, serializer)
+            }
+            try serializer.serialize_u32(value: self.total)
+            try serializer.decrease_container_depth()
+        }
+
+        public func bincodeSerialize(_ serializeT: (T, BincodeSerializer) throws -> Void) throws -> [UInt8] {
+            let serializer = BincodeSerializer.init();
+            try self.serialize(serializer: serializer, serializeT)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D, _ deserializeT: (D) throws -> T) throws -> Page {
+            try deserializer.increase_container_depth()
+            let items = try deserializeArray(deserializer: deserializer) { deserializer in
+                try deserializeT(deserializer)
+            }
+            let next = try deserializeOption(deserializer: deserializer) { deserializer in
+                try deserializeT(deserializer)
+            }
+            let total = try deserializer.deserialize_u32()
+            try deserializer.decrease_container_depth()
+            return Page(items: items, next: next, total: total)
+        }
+
+        public static func bincodeDeserialize(input: [UInt8], _ deserializeT: (BincodeDeserializer) throws -> T) throws -> Page {
+            let deserializer = BincodeDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer, deserializeT)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+    "#);
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E, retry: bool },
+        Pending,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        first: Outcome<String, i32>,
+        second: Outcome<bool, String>,
+    }
+
+    let actual = emit!(Response as Swift with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    indirect public enum Outcome<T: Hashable, E: Hashable>: Hashable, Equatable {
+        case success(T)
+        case failure(error: E, retry: Bool)
+        case pending
+
+        public func serialize<S: Serializer>(serializer: S, _ serializeT: (T, S) throws -> Void, _ serializeE: (E, S) throws -> Void) throws {
+            try serializer.increase_container_depth()
+            switch self {
+            case .success(let x):
+                try serializer.serialize_variant_index(value: 0)
+                try serializeT(x, serializer)
+            case .failure(let error, let retry):
+                try serializer.serialize_variant_index(value: 1)
+                try serializeE(error, serializer)
+                try serializer.serialize_bool(value: retry)
+            case .pending:
+                try serializer.serialize_variant_index(value: 2)
+            }
+            try serializer.decrease_container_depth()
+        }
+
+        public func bincodeSerialize(_ serializeT: (T, BincodeSerializer) throws -> Void, _ serializeE: (E, BincodeSerializer) throws -> Void) throws -> [UInt8] {
+            let serializer = BincodeSerializer.init();
+            try self.serialize(serializer: serializer, serializeT, serializeE)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D, _ deserializeT: (D) throws -> T, _ deserializeE: (D) throws -> E) throws -> Outcome {
+            let index = try deserializer.deserialize_variant_index()
+            try deserializer.increase_container_depth()
+            switch index {
+            case 0:
+                let x = try deserializeT(deserializer)
+                try deserializer.decrease_container_depth()
+                return .success(x)
+            case 1:
+                let error = try deserializeE(deserializer)
+                let retry = try deserializer.deserialize_bool()
+                try deserializer.decrease_container_depth()
+                return .failure(error: error, retry: retry)
+            case 2:
+                try deserializer.decrease_container_depth()
+                return .pending
+            default: throw DeserializationError.invalidInput(issue: "Unknown variant index for Outcome: \(index)")
+            }
+        }
+
+        public static func bincodeDeserialize(input: [UInt8], _ deserializeT: (BincodeDeserializer) throws -> T, _ deserializeE: (BincodeDeserializer) throws -> E) throws -> Outcome {
+            let deserializer = BincodeDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer, deserializeT, deserializeE)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+
+    public struct Response: Hashable, Equatable {
+        public var first: Outcome<String, Int32>
+        public var second: Outcome<Bool, String>
+
+        public init(first: Outcome<String, Int32>, second: Outcome<Bool, String>) {
+            self.first = first
+            self.second = second
+        }
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            try self.first.serialize(serializer: serializer, { value, serializer in
+                try serializer.serialize_str(value: value)
+            }, { value, serializer in
+                try serializer.serialize_i32(value: value)
+            })
+            try self.second.serialize(serializer: serializer, { value, serializer in
+                try serializer.serialize_bool(value: value)
+            }, { value, serializer in
+                try serializer.serialize_str(value: value)
+            })
+            try serializer.decrease_container_depth()
+        }
+
+        public func bincodeSerialize() throws -> [UInt8] {
+            let serializer = BincodeSerializer.init();
+            try self.serialize(serializer: serializer)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> Response {
+            try deserializer.increase_container_depth()
+            let first = try Outcome.deserialize(deserializer: deserializer, { deserializer in
+                try deserializer.deserialize_str()
+            }, { deserializer in
+                try deserializer.deserialize_i32()
+            })
+            let second = try Outcome.deserialize(deserializer: deserializer, { deserializer in
+                try deserializer.deserialize_bool()
+            }, { deserializer in
+                try deserializer.deserialize_str()
+            })
+            try deserializer.decrease_container_depth()
+            return Response(first: first, second: second)
+        }
+
+        public static func bincodeDeserialize(input: [UInt8]) throws -> Response {
+            let deserializer = BincodeDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+    "#);
+}
+
+#[test]
+fn serialized_as_proxy() {
+    #[derive(Facet)]
+    #[facet(fg::serialized_as = "String")]
+    struct ItemId {
+        inner: i64,
+    }
+
+    #[derive(Facet)]
+    struct Item {
+        id: ItemId,
+        #[facet(fg::serialized_as = "(u32, u32)")]
+        version: String,
+    }
+
+    let actual = emit!(Item as Swift with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public struct Item: Equatable {
+        public var id: String
+        public var version: (UInt32, UInt32)
+
+        public init(id: String, version: (UInt32, UInt32)) {
+            self.id = id
+            self.version = version
+        }
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            try serializer.serialize_str(value: self.id)
+            try serializer.serialize_u32(value: self.version.0)
+            try serializer.serialize_u32(value: self.version.1)
+            try serializer.decrease_container_depth()
+        }
+
+        public func bincodeSerialize() throws -> [UInt8] {
+            let serializer = BincodeSerializer.init();
+            try self.serialize(serializer: serializer)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> Item {
+            try deserializer.increase_container_depth()
+            let id = try deserializer.deserialize_str()
+            let versionField0 = try deserializer.deserialize_u32()
+            let versionField1 = try deserializer.deserialize_u32()
+            let version = (versionField0, versionField1)
+            try deserializer.decrease_container_depth()
+            return Item(id: id, version: version)
+        }
+
+        public static func bincodeDeserialize(input: [UInt8]) throws -> Item {
+            let deserializer = BincodeDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+
+        public static func == (lhs: Item, rhs: Item) -> Bool {
+            return lhs.id == rhs.id
+                && lhs.version == rhs.version
+        }
+    }
+
+    public typealias ItemId = String
+    "#);
+}
+
+#[test]
+fn type_override() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Circle {
+            #[facet(fg::override(
+                lang = "swift",
+                ty = "CGFloat",
+                serialize = "try serializer.serialize_f64(value: Double({value}))",
+                deserialize = "CGFloat(try deserializer.deserialize_f64())"
+            ))]
+            radius: f64,
+            #[facet(fg::override(lang = "kotlin", ty = "Double"))]
+            label: String,
+        },
+    }
+
+    let actual = emit!(Shape as Swift with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    indirect public enum Shape: Hashable, Equatable {
+        case circle(radius: CGFloat, label: String)
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            switch self {
+            case .circle(let radius, let label):
+                try serializer.serialize_variant_index(value: 0)
+                try serializer.serialize_f64(value: Double(radius))
+                try serializer.serialize_str(value: label)
+            }
+            try serializer.decrease_container_depth()
+        }
+
+        public func bincodeSerialize() throws -> [UInt8] {
+            let serializer = BincodeSerializer.init();
+            try self.serialize(serializer: serializer)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> Shape {
+            let index = try deserializer.deserialize_variant_index()
+            try deserializer.increase_container_depth()
+            switch index {
+            case 0:
+                let radius = CGFloat(try deserializer.deserialize_f64())
+                let label = try deserializer.deserialize_str()
+                try deserializer.decrease_container_depth()
+                return .circle(radius: radius, label: label)
+            default: throw DeserializationError.invalidInput(issue: "Unknown variant index for Shape: \(index)")
+            }
+        }
+
+        public static func bincodeDeserialize(input: [UInt8]) throws -> Shape {
+            let deserializer = BincodeDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+    "#);
+}
+
+#[test]
+fn conformances_and_annotations() {
+    #[derive(Facet)]
+    #[facet(
+        fg::conforms(lang = "swift", to = "Sendable, Hashable"),
+        fg::conforms(lang = "kotlin", to = "Parcelable"),
+        fg::annotate(lang = "swift", with = "@MainActor")
+    )]
+    struct Counter {
+        count: u32,
+    }
+
+    let actual = emit!(Counter as Swift with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    @MainActor
+    public struct Counter: Hashable, Equatable, Sendable {
+        public var count: UInt32
+
+        public init(count: UInt32) {
+            self.count = count
+        }
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            try serializer.serialize_u32(value: self.count)
+            try serializer.decrease_container_depth()
+        }
+
+        public func bincodeSerialize() throws -> [UInt8] {
+            let serializer = BincodeSerializer.init();
+            try self.serialize(serializer: serializer)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> Counter {
+            try deserializer.increase_container_depth()
+            let count = try deserializer.deserialize_u32()
+            try deserializer.decrease_container_depth()
+            return Counter(count: count)
+        }
+
+        public static func bincodeDeserialize(input: [UInt8]) throws -> Counter {
+            let deserializer = BincodeDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+    "#);
+}
+
+#[test]
+fn enum_with_pinned_indices() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Status {
+        Active,
+        #[facet(fg::index = 3)]
+        Suspended(String),
+        Closed,
+    }
+
+    let actual = emit!(Status as Swift with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    indirect public enum Status: Hashable, Equatable {
+        case active
+        case suspended(String)
+        case closed
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            switch self {
+            case .active:
+                try serializer.serialize_variant_index(value: 0)
+            case .suspended(let x):
+                try serializer.serialize_variant_index(value: 3)
+                try serializer.serialize_str(value: x)
+            case .closed:
+                try serializer.serialize_variant_index(value: 4)
+            }
+            try serializer.decrease_container_depth()
+        }
+
+        public func bincodeSerialize() throws -> [UInt8] {
+            let serializer = BincodeSerializer.init();
+            try self.serialize(serializer: serializer)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> Status {
+            let index = try deserializer.deserialize_variant_index()
+            try deserializer.increase_container_depth()
+            switch index {
+            case 0:
+                try deserializer.decrease_container_depth()
+                return .active
+            case 3:
+                let x = try deserializer.deserialize_str()
+                try deserializer.decrease_container_depth()
+                return .suspended(x)
+            case 4:
+                try deserializer.decrease_container_depth()
+                return .closed
+            default: throw DeserializationError.invalidInput(issue: "Unknown variant index for Status: \(index)")
+            }
+        }
+
+        public static func bincodeDeserialize(input: [UInt8]) throws -> Status {
+            let deserializer = BincodeDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+    "#);
+}
+
+#[test]
+fn enum_with_other_variant() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Created(String),
+        Deleted {
+            id: u64,
+        },
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Event as Swift with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    indirect public enum Event: Hashable, Equatable {
+        case created(String)
+        case deleted(id: UInt64)
+        case unknown
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            switch self {
+            case .created(let x):
+                try serializer.serialize_variant_index(value: 0)
+                try serializer.serialize_str(value: x)
+            case .deleted(let id):
+                try serializer.serialize_variant_index(value: 1)
+                try serializer.serialize_u64(value: id)
+            case .unknown:
+                try serializer.serialize_variant_index(value: 2)
+            }
+            try serializer.decrease_container_depth()
+        }
+
+        public func bincodeSerialize() throws -> [UInt8] {
+            let serializer = BincodeSerializer.init();
+            try self.serialize(serializer: serializer)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> Event {
+            let index = try deserializer.deserialize_variant_index()
+            try deserializer.increase_container_depth()
+            switch index {
+            case 0:
+                let x = try deserializer.deserialize_str()
+                try deserializer.decrease_container_depth()
+                return .created(x)
+            case 1:
+                let id = try deserializer.deserialize_u64()
+                try deserializer.decrease_container_depth()
+                return .deleted(id: id)
+            case 2:
+                try deserializer.decrease_container_depth()
+                return .unknown
+            default:
+                try deserializer.decrease_container_depth()
+                return .unknown
+            }
+        }
+
+        public static func bincodeDeserialize(input: [UInt8]) throws -> Event {
+            let deserializer = BincodeDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+    "#);
+}
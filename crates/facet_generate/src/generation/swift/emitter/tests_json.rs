@@ -160,6 +160,103 @@ fn newtype_struct() {
     "#);
 }
 
+#[test]
+fn branded_newtype_struct() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    #[derive(Facet)]
+    struct User {
+        id: UserId,
+        manager: Option<UserId>,
+    }
+
+    let actual = emit!(User as Swift with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public struct User: Hashable, Equatable {
+        public var id: UserId
+        public var manager: UserId?
+
+        public init(id: UserId, manager: UserId?) {
+            self.id = id
+            self.manager = manager
+        }
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            try self.id.serialize(serializer: serializer)
+            try serializeOption(value: self.manager, serializer: serializer) { value, serializer in
+                try value.serialize(serializer: serializer)
+            }
+            try serializer.decrease_container_depth()
+        }
+
+        public func jsonSerialize() throws -> [UInt8] {
+            let serializer = JsonSerializer.init();
+            try self.serialize(serializer: serializer)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> User {
+            try deserializer.increase_container_depth()
+            let id = try UserId.deserialize(deserializer: deserializer)
+            let manager = try deserializeOption(deserializer: deserializer) { deserializer in
+                try UserId.deserialize(deserializer: deserializer)
+            }
+            try deserializer.decrease_container_depth()
+            return User(id: id, manager: manager)
+        }
+
+        public static func jsonDeserialize(input: [UInt8]) throws -> User {
+            let deserializer = JsonDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+
+    public struct UserId: RawRepresentable, Hashable, Equatable {
+        public var rawValue: String
+
+        public init(rawValue: String) {
+            self.rawValue = rawValue
+        }
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            try serializer.serialize_str(value: self.rawValue)
+            try serializer.decrease_container_depth()
+        }
+
+        public func jsonSerialize() throws -> [UInt8] {
+            let serializer = JsonSerializer.init();
+            try self.serialize(serializer: serializer)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> UserId {
+            try deserializer.increase_container_depth()
+            let rawValue = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return UserId(rawValue: rawValue)
+        }
+
+        public static func jsonDeserialize(input: [UInt8]) throws -> UserId {
+            let deserializer = JsonDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+    "#);
+}
+
 #[test]
 fn tuple_struct() {
     /// line 1
@@ -1161,6 +1258,180 @@ fn enum_with_mixed_variants() {
     "#);
 }
 
+#[test]
+fn enum_with_other_variant() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Created(String),
+        Deleted {
+            id: u64,
+        },
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Event as Swift with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    indirect public enum Event: Hashable, Equatable {
+        case created(String)
+        case deleted(id: UInt64)
+        case unknown
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            switch self {
+            case .created(let x):
+                try serializer.serialize_variant_index(value: 0)
+                try serializer.serialize_str(value: x)
+            case .deleted(let id):
+                try serializer.serialize_variant_index(value: 1)
+                try serializer.serialize_u64(value: id)
+            case .unknown:
+                try serializer.serialize_variant_index(value: 2)
+            }
+            try serializer.decrease_container_depth()
+        }
+
+        public func jsonSerialize() throws -> [UInt8] {
+            let serializer = JsonSerializer.init();
+            try self.serialize(serializer: serializer)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> Event {
+            let index = try deserializer.deserialize_variant_index()
+            try deserializer.increase_container_depth()
+            switch index {
+            case 0:
+                let x = try deserializer.deserialize_str()
+                try deserializer.decrease_container_depth()
+                return .created(x)
+            case 1:
+                let id = try deserializer.deserialize_u64()
+                try deserializer.decrease_container_depth()
+                return .deleted(id: id)
+            case 2:
+                try deserializer.decrease_container_depth()
+                return .unknown
+            default:
+                try deserializer.decrease_container_depth()
+                return .unknown
+            }
+        }
+
+        public static func jsonDeserialize(input: [UInt8]) throws -> Event {
+            let deserializer = JsonDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+    "#);
+}
+
+#[test]
+fn untagged_enum() {
+    #[derive(Facet)]
+    #[facet(untagged)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Value {
+        Number(i32),
+        Point(i32, i32),
+        Named { name: String, alias: Option<String> },
+        Nothing,
+    }
+
+    let actual = emit!(Value as Swift with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    indirect public enum Value: Hashable, Equatable {
+        case number(Int32)
+        case point(Int32, Int32)
+        case named(name: String, alias: String?)
+        case nothing
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            switch self {
+            case .number(let x):
+                try serializer.serialize_i32(value: x)
+            case .point(let x0, let x1):
+                try serializer.increase_container_depth()
+                try serializer.serialize_i32(value: x0)
+                try serializer.serialize_i32(value: x1)
+                try serializer.decrease_container_depth()
+            case .named(let name, let alias):
+                try serializer.increase_container_depth()
+                try serializer.serialize_str(value: name)
+                try serializeOption(value: alias, serializer: serializer) { value, serializer in
+                    try serializer.serialize_str(value: value)
+                }
+                try serializer.decrease_container_depth()
+            case .nothing:
+                try serializer.serialize_unit()
+            }
+        }
+
+        public func jsonSerialize() throws -> [UInt8] {
+            let serializer = JsonSerializer.init();
+            try self.serialize(serializer: serializer)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> Value {
+            let offset = deserializer.get_buffer_offset()
+            do {
+                let x = try deserializer.deserialize_i32()
+                return .number(x)
+            } catch {
+                deserializer.set_buffer_offset(value: offset)
+            }
+            do {
+                try deserializer.increase_container_depth()
+                let x0 = try deserializer.deserialize_i32()
+                let x1 = try deserializer.deserialize_i32()
+                try deserializer.decrease_container_depth()
+                return .point(x0, x1)
+            } catch {
+                deserializer.set_buffer_offset(value: offset)
+            }
+            do {
+                try deserializer.increase_container_depth()
+                let name = try deserializer.deserialize_str()
+                let alias = try deserializeOption(deserializer: deserializer) { deserializer in
+                    try deserializer.deserialize_str()
+                }
+                try deserializer.decrease_container_depth()
+                return .named(name: name, alias: alias)
+            } catch {
+                deserializer.set_buffer_offset(value: offset)
+            }
+            do {
+                try deserializer.deserialize_unit()
+                return .nothing
+            } catch {
+                deserializer.set_buffer_offset(value: offset)
+            }
+            throw DeserializationError.invalidInput(issue: "No variant of Value matches the input")
+        }
+
+        public static func jsonDeserialize(input: [UInt8]) throws -> Value {
+            let deserializer = JsonDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+    "#);
+}
+
 #[test]
 fn struct_with_vec_field() {
     #[derive(Facet)]
@@ -2229,3 +2500,718 @@ fn namespaced_child() {
     }
     "#);
 }
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as Swift with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public struct Listing: Hashable, Equatable {
+        public var names: Page<String>
+        public var ids: Page<UInt32>
+
+        public init(names: Page<String>, ids: Page<UInt32>) {
+            self.names = names
+            self.ids = ids
+        }
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            try self.names.serialize(serializer: serializer, { value, serializer in
+                try serializer.serialize_str(value: value)
+            })
+            try self.ids.serialize(serializer: serializer, { value, serializer in
+                try serializer.serialize_u32(value: value)
+            })
+            try serializer.decrease_container_depth()
+        }
+
+        public func jsonSerialize() throws -> [UInt8] {
+            let serializer = JsonSerializer.init();
+            try self.serialize(serializer: serializer)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> Listing {
+            try deserializer.increase_container_depth()
+            let names = try Page.deserialize(deserializer: deserializer, { deserializer in
+                try deserializer.deserialize_str()
+            })
+            let ids = try Page.deserialize(deserializer: deserializer, { deserializer in
+                try deserializer.deserialize_u32()
+            })
+            try deserializer.decrease_container_depth()
+            return Listing(names: names, ids: ids)
+        }
+
+        public static func jsonDeserialize(input: [UInt8]) throws -> Listing {
+            let deserializer = JsonDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+
+    public struct Page<T: Hashable>: Hashable, Equatable {
+        public var items: [T]
+        public var next: T?
+        public var total: UInt32
+
+        public init(items: [T], next: T?, total: UInt32) {
+            self.items = items
+            self.next = next
+            self.total = total
+        }
+
+        public func serialize<S: Serializer>(serializer: S, _ serializeT: (T, S) throws -> Void) throws {
+            try serializer.increase_container_depth()
+            try serializeArray(value: self.items, serializer: serializer) { item, serializer in
+                try serializeT(item, serializer)
+            }
+            try serializeOption(value: self.next, serializer: serializer) { value, serializer in
+                try serializeT(value, serializer)
+            }
+            try serializer.serialize_u32(value: self.total)
+            try serializer.decrease_container_depth()
+        }
+
+        public func jsonSerialize(_ serializeT: (T, JsonSerializer) throws -> Void) throws -> [UInt8] {
+            let serializer = JsonSerializer.init();
+            try self.serialize(serializer: serializer, serializeT)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D, _ deserializeT: (D) throws -> T) throws -> Page {
+            try deserializer.increase_container_depth()
+            let items = try deserializeArray(deserializer: deserializer) { deserializer in
+                try deserializeT(deserializer)
+            }
+            let next = try deserializeOption(deserializer: deserializer) { deserializer in
+                try deserializeT(deserializer)
+            }
+            let total = try deserializer.deserialize_u32()
+            try deserializer.decrease_container_depth()
+            return Page(items: items, next: next, total: total)
+        }
+
+        public static func jsonDeserialize(input: [UInt8], _ deserializeT: (JsonDeserializer) throws -> T) throws -> Page {
+            let deserializer = JsonDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer, deserializeT)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+    "#);
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E, retry: bool },
+        Pending,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        first: Outcome<String, i32>,
+        second: Outcome<bool, String>,
+    }
+
+    let actual = emit!(Response as Swift with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    indirect public enum Outcome<T: Hashable, E: Hashable>: Hashable, Equatable {
+        case success(T)
+        case failure(error: E, retry: Bool)
+        case pending
+
+        public func serialize<S: Serializer>(serializer: S, _ serializeT: (T, S) throws -> Void, _ serializeE: (E, S) throws -> Void) throws {
+            try serializer.increase_container_depth()
+            switch self {
+            case .success(let x):
+                try serializer.serialize_variant_index(value: 0)
+                try serializeT(x, serializer)
+            case .failure(let error, let retry):
+                try serializer.serialize_variant_index(value: 1)
+                try serializeE(error, serializer)
+                try serializer.serialize_bool(value: retry)
+            case .pending:
+                try serializer.serialize_variant_index(value: 2)
+            }
+            try serializer.decrease_container_depth()
+        }
+
+        public func jsonSerialize(_ serializeT: (T, JsonSerializer) throws -> Void, _ serializeE: (E, JsonSerializer) throws -> Void) throws -> [UInt8] {
+            let serializer = JsonSerializer.init();
+            try self.serialize(serializer: serializer, serializeT, serializeE)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D, _ deserializeT: (D) throws -> T, _ deserializeE: (D) throws -> E) throws -> Outcome {
+            let index = try deserializer.deserialize_variant_index()
+            try deserializer.increase_container_depth()
+            switch index {
+            case 0:
+                let x = try deserializeT(deserializer)
+                try deserializer.decrease_container_depth()
+                return .success(x)
+            case 1:
+                let error = try deserializeE(deserializer)
+                let retry = try deserializer.deserialize_bool()
+                try deserializer.decrease_container_depth()
+                return .failure(error: error, retry: retry)
+            case 2:
+                try deserializer.decrease_container_depth()
+                return .pending
+            default: throw DeserializationError.invalidInput(issue: "Unknown variant index for Outcome: \(index)")
+            }
+        }
+
+        public static func jsonDeserialize(input: [UInt8], _ deserializeT: (JsonDeserializer) throws -> T, _ deserializeE: (JsonDeserializer) throws -> E) throws -> Outcome {
+            let deserializer = JsonDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer, deserializeT, deserializeE)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+
+    public struct Response: Hashable, Equatable {
+        public var first: Outcome<String, Int32>
+        public var second: Outcome<Bool, String>
+
+        public init(first: Outcome<String, Int32>, second: Outcome<Bool, String>) {
+            self.first = first
+            self.second = second
+        }
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            try self.first.serialize(serializer: serializer, { value, serializer in
+                try serializer.serialize_str(value: value)
+            }, { value, serializer in
+                try serializer.serialize_i32(value: value)
+            })
+            try self.second.serialize(serializer: serializer, { value, serializer in
+                try serializer.serialize_bool(value: value)
+            }, { value, serializer in
+                try serializer.serialize_str(value: value)
+            })
+            try serializer.decrease_container_depth()
+        }
+
+        public func jsonSerialize() throws -> [UInt8] {
+            let serializer = JsonSerializer.init();
+            try self.serialize(serializer: serializer)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> Response {
+            try deserializer.increase_container_depth()
+            let first = try Outcome.deserialize(deserializer: deserializer, { deserializer in
+                try deserializer.deserialize_str()
+            }, { deserializer in
+                try deserializer.deserialize_i32()
+            })
+            let second = try Outcome.deserialize(deserializer: deserializer, { deserializer in
+                try deserializer.deserialize_bool()
+            }, { deserializer in
+                try deserializer.deserialize_str()
+            })
+            try deserializer.decrease_container_depth()
+            return Response(first: first, second: second)
+        }
+
+        public static func jsonDeserialize(input: [UInt8]) throws -> Response {
+            let deserializer = JsonDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+    "#);
+}
+
+#[test]
+fn type_override() {
+    #[derive(Facet)]
+    struct Size {
+        #[facet(fg::override(
+            lang = "swift",
+            ty = "CGFloat",
+            serialize = "try serializer.serialize_f64(value: Double({value}))",
+            deserialize = "CGFloat(try deserializer.deserialize_f64())"
+        ))]
+        width: f64,
+        #[facet(fg::override(lang = "swift", ty = "Int"))]
+        height: i64,
+    }
+
+    let actual = emit!(Size as Swift with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public struct Size: Hashable, Equatable {
+        public var width: CGFloat
+        public var height: Int
+
+        public init(width: CGFloat, height: Int) {
+            self.width = width
+            self.height = height
+        }
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            try serializer.serialize_f64(value: Double(self.width))
+            try serializer.serialize_i64(value: self.height)
+            try serializer.decrease_container_depth()
+        }
+
+        public func jsonSerialize() throws -> [UInt8] {
+            let serializer = JsonSerializer.init();
+            try self.serialize(serializer: serializer)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> Size {
+            try deserializer.increase_container_depth()
+            let width = CGFloat(try deserializer.deserialize_f64())
+            let height = try deserializer.deserialize_i64()
+            try deserializer.decrease_container_depth()
+            return Size(width: width, height: height)
+        }
+
+        public static func jsonDeserialize(input: [UInt8]) throws -> Size {
+            let deserializer = JsonDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+    "#);
+}
+
+#[test]
+fn internally_tagged_enum() {
+    #[derive(Facet)]
+    #[facet(fg::conforms(lang = "swift", to = "Codable"))]
+    struct Disc {
+        radius: u32,
+    }
+
+    #[derive(Facet)]
+    #[facet(tag = "type")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Shape {
+        Circle(Disc),
+        Rectangle { width: u32, height: u32 },
+        Empty,
+    }
+
+    let actual = emit!(Shape as Swift with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public struct Disc: Hashable, Equatable, Codable {
+        public var radius: UInt32
+
+        public init(radius: UInt32) {
+            self.radius = radius
+        }
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            try serializer.serialize_u32(value: self.radius)
+            try serializer.decrease_container_depth()
+        }
+
+        public func jsonSerialize() throws -> [UInt8] {
+            try encodeJson()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> Disc {
+            try deserializer.increase_container_depth()
+            let radius = try deserializer.deserialize_u32()
+            try deserializer.decrease_container_depth()
+            return Disc(radius: radius)
+        }
+
+        public static func jsonDeserialize(input: [UInt8]) throws -> Disc {
+            try decodeJson(input: input)
+        }
+    }
+
+    indirect public enum Shape: Hashable, Equatable, Codable {
+        case circle(Disc)
+        case rectangle(width: UInt32, height: UInt32)
+        case empty
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            switch self {
+            case .circle(let x):
+                try serializer.serialize_variant_index(value: 0)
+                try x.serialize(serializer: serializer)
+            case .rectangle(let width, let height):
+                try serializer.serialize_variant_index(value: 1)
+                try serializer.serialize_u32(value: width)
+                try serializer.serialize_u32(value: height)
+            case .empty:
+                try serializer.serialize_variant_index(value: 2)
+            }
+            try serializer.decrease_container_depth()
+        }
+
+        public func jsonSerialize() throws -> [UInt8] {
+            try encodeJson()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> Shape {
+            let index = try deserializer.deserialize_variant_index()
+            try deserializer.increase_container_depth()
+            switch index {
+            case 0:
+                let x = try Disc.deserialize(deserializer: deserializer)
+                try deserializer.decrease_container_depth()
+                return .circle(x)
+            case 1:
+                let width = try deserializer.deserialize_u32()
+                let height = try deserializer.deserialize_u32()
+                try deserializer.decrease_container_depth()
+                return .rectangle(width: width, height: height)
+            case 2:
+                try deserializer.decrease_container_depth()
+                return .empty
+            default: throw DeserializationError.invalidInput(issue: "Unknown variant index for Shape: \(index)")
+            }
+        }
+
+        public static func jsonDeserialize(input: [UInt8]) throws -> Shape {
+            try decodeJson(input: input)
+        }
+
+        public init(from decoder: Decoder) throws {
+            let container = try decoder.container(keyedBy: JsonKey.self)
+            let tag: String = try container.field("type")
+            switch tag {
+            case "Circle":
+                self = try .circle(.init(from: decoder))
+            case "Rectangle":
+                self = try .rectangle(width: container.field("width"), height: container.field("height"))
+            case "Empty":
+                self = .empty
+            default:
+                throw DecodingError.dataCorruptedError(forKey: "type", in: container, debugDescription: "Unknown variant of Shape: \(tag)")
+            }
+        }
+
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.container(keyedBy: JsonKey.self)
+            switch self {
+            case .circle(let x):
+                try container.encode("Circle", forKey: "type")
+                try x.encode(to: encoder)
+            case .rectangle(let width, let height):
+                try container.encode("Rectangle", forKey: "type")
+                try container.encode(width, forKey: "width")
+                try container.encode(height, forKey: "height")
+            case .empty:
+                try container.encode("Empty", forKey: "type")
+            }
+        }
+    }
+    "#);
+}
+
+#[test]
+fn adjacently_tagged_enum() {
+    #[derive(Facet)]
+    #[facet(tag = "t", content = "c")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Message {
+        Text(String),
+        Move(i32, i32),
+        Resize { width: u32, height: u32 },
+        Quit,
+    }
+
+    let actual = emit!(Message as Swift with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    indirect public enum Message: Hashable, Equatable, Codable {
+        case text(String)
+        case move(Int32, Int32)
+        case resize(width: UInt32, height: UInt32)
+        case quit
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            switch self {
+            case .text(let x):
+                try serializer.serialize_variant_index(value: 0)
+                try serializer.serialize_str(value: x)
+            case .move(let x0, let x1):
+                try serializer.serialize_variant_index(value: 1)
+                try serializer.serialize_i32(value: x0)
+                try serializer.serialize_i32(value: x1)
+            case .resize(let width, let height):
+                try serializer.serialize_variant_index(value: 2)
+                try serializer.serialize_u32(value: width)
+                try serializer.serialize_u32(value: height)
+            case .quit:
+                try serializer.serialize_variant_index(value: 3)
+            }
+            try serializer.decrease_container_depth()
+        }
+
+        public func jsonSerialize() throws -> [UInt8] {
+            try encodeJson()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> Message {
+            let index = try deserializer.deserialize_variant_index()
+            try deserializer.increase_container_depth()
+            switch index {
+            case 0:
+                let x = try deserializer.deserialize_str()
+                try deserializer.decrease_container_depth()
+                return .text(x)
+            case 1:
+                let x0 = try deserializer.deserialize_i32()
+                let x1 = try deserializer.deserialize_i32()
+                try deserializer.decrease_container_depth()
+                return .move(x0, x1)
+            case 2:
+                let width = try deserializer.deserialize_u32()
+                let height = try deserializer.deserialize_u32()
+                try deserializer.decrease_container_depth()
+                return .resize(width: width, height: height)
+            case 3:
+                try deserializer.decrease_container_depth()
+                return .quit
+            default: throw DeserializationError.invalidInput(issue: "Unknown variant index for Message: \(index)")
+            }
+        }
+
+        public static func jsonDeserialize(input: [UInt8]) throws -> Message {
+            try decodeJson(input: input)
+        }
+
+        public init(from decoder: Decoder) throws {
+            let container = try decoder.container(keyedBy: JsonKey.self)
+            let tag: String = try container.field("t")
+            switch tag {
+            case "Text":
+                self = try .text(container.field("c"))
+            case "Move":
+                var content = try container.nestedUnkeyedContainer(forKey: "c")
+                self = try .move(content.element(), content.element())
+            case "Resize":
+                let content = try container.nestedContainer(keyedBy: JsonKey.self, forKey: "c")
+                self = try .resize(width: content.field("width"), height: content.field("height"))
+            case "Quit":
+                self = .quit
+            default:
+                throw DecodingError.dataCorruptedError(forKey: "t", in: container, debugDescription: "Unknown variant of Message: \(tag)")
+            }
+        }
+
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.container(keyedBy: JsonKey.self)
+            switch self {
+            case .text(let x):
+                try container.encode("Text", forKey: "t")
+                try container.encode(x, forKey: "c")
+            case .move(let x0, let x1):
+                try container.encode("Move", forKey: "t")
+                var content = container.nestedUnkeyedContainer(forKey: "c")
+                try content.encode(x0)
+                try content.encode(x1)
+            case .resize(let width, let height):
+                try container.encode("Resize", forKey: "t")
+                var content = container.nestedContainer(keyedBy: JsonKey.self, forKey: "c")
+                try content.encode(width, forKey: "width")
+                try content.encode(height, forKey: "height")
+            case .quit:
+                try container.encode("Quit", forKey: "t")
+            }
+        }
+    }
+    "#);
+}
+
+#[test]
+fn defaults_fill_in_missing_fields() {
+    #[derive(Facet)]
+    struct Settings {
+        #[facet(default = 3)]
+        retries: u32,
+        #[facet(default)]
+        tags: Vec<String>,
+        id: u64,
+    }
+
+    let actual = emit!(Settings as Swift with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    public struct Settings: Hashable, Equatable {
+        public var retries: UInt32
+        public var tags: [String]
+        public var id: UInt64
+
+        public init(retries: UInt32 = 3, tags: [String] = [], id: UInt64) {
+            self.retries = retries
+            self.tags = tags
+            self.id = id
+        }
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            try serializer.serialize_u32(value: self.retries)
+            try serializeArray(value: self.tags, serializer: serializer) { item, serializer in
+                try serializer.serialize_str(value: item)
+            }
+            try serializer.serialize_u64(value: self.id)
+            try serializer.decrease_container_depth()
+        }
+
+        public func jsonSerialize() throws -> [UInt8] {
+            let serializer = JsonSerializer.init();
+            try self.serialize(serializer: serializer)
+            return serializer.get_bytes()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> Settings {
+            try deserializer.increase_container_depth()
+            let retries = try deserializer.deserialize_field(name: "retries", default: 3) {
+                try deserializer.deserialize_u32()
+            }
+            let tags = try deserializer.deserialize_field(name: "tags", default: []) {
+                try deserializeArray(deserializer: deserializer) { deserializer in
+                    try deserializer.deserialize_str()
+                }
+            }
+            let id = try deserializer.deserialize_u64()
+            try deserializer.decrease_container_depth()
+            return Settings(retries: retries, tags: tags, id: id)
+        }
+
+        public static func jsonDeserialize(input: [UInt8]) throws -> Settings {
+            let deserializer = JsonDeserializer.init(input: input);
+            let obj = try deserialize(deserializer: deserializer)
+            if deserializer.get_buffer_offset() < input.count {
+                throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+            }
+            return obj
+        }
+    }
+    "#);
+}
+
+#[test]
+fn defaults_fill_in_missing_fields_of_tagged_enums() {
+    #[derive(Facet)]
+    #[facet(tag = "type")]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Command {
+        Move {
+            x: i32,
+            #[facet(default)]
+            speed: Option<u8>,
+        },
+    }
+
+    let actual = emit!(Command as Swift with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+    indirect public enum Command: Hashable, Equatable, Codable {
+        case move(x: Int32, speed: UInt8? = nil)
+
+        public func serialize<S: Serializer>(serializer: S) throws {
+            try serializer.increase_container_depth()
+            switch self {
+            case .move(let x, let speed):
+                try serializer.serialize_variant_index(value: 0)
+                try serializer.serialize_i32(value: x)
+                try serializeOption(value: speed, serializer: serializer) { value, serializer in
+                    try serializer.serialize_u8(value: value)
+                }
+            }
+            try serializer.decrease_container_depth()
+        }
+
+        public func jsonSerialize() throws -> [UInt8] {
+            try encodeJson()
+        }
+
+        public static func deserialize<D: Deserializer>(deserializer: D) throws -> Command {
+            let index = try deserializer.deserialize_variant_index()
+            try deserializer.increase_container_depth()
+            switch index {
+            case 0:
+                let x = try deserializer.deserialize_i32()
+                let speed = try deserializer.deserialize_field(name: "speed", default: nil) {
+                    try deserializeOption(deserializer: deserializer) { deserializer in
+                        try deserializer.deserialize_u8()
+                    }
+                }
+                try deserializer.decrease_container_depth()
+                return .move(x: x, speed: speed)
+            default: throw DeserializationError.invalidInput(issue: "Unknown variant index for Command: \(index)")
+            }
+        }
+
+        public static func jsonDeserialize(input: [UInt8]) throws -> Command {
+            try decodeJson(input: input)
+        }
+
+        public init(from decoder: Decoder) throws {
+            let container = try decoder.container(keyedBy: JsonKey.self)
+            let tag: String = try container.field("type")
+            switch tag {
+            case "Move":
+                self = try .move(x: container.field("x"), speed: container.field("speed", default: nil))
+            default:
+                throw DecodingError.dataCorruptedError(forKey: "type", in: container, debugDescription: "Unknown variant of Command: \(tag)")
+            }
+        }
+
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.container(keyedBy: JsonKey.self)
+            switch self {
+            case .move(let x, let speed):
+                try container.encode("Move", forKey: "type")
+                try container.encode(x, forKey: "x")
+                try container.encode(speed, forKey: "speed")
+            }
+        }
+    }
+    "#);
+}
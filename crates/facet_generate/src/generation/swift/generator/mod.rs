@@ -12,10 +12,13 @@ use std::{
 use crate::{
     Registry,
     generation::{
-        CodeGenerator, CodeGeneratorConfig, Container, Emitter, indent::IndentedWriter,
-        module::Module, plugin::EmitterPlugin, swift::emitter::Swift,
+        CodeGenerator, CodeGeneratorConfig, Container, Emitter,
+        indent::IndentedWriter,
+        module::{self, Module},
+        plugin::{self, EmitterPlugin},
+        swift::emitter::Swift,
     },
-    reflection::format::{ContainerFormat, Format, QualifiedTypeName, VariantFormat},
+    reflection::format::{ContainerFormat, Format, Language, QualifiedTypeName, VariantFormat},
 };
 
 /// Main configuration object for Swift code generation.
@@ -72,6 +75,9 @@ impl<'a> SwiftCodeGenerator<'a> {
     /// Returns an error if writing to `out` fails.
     pub fn output(&self, out: &mut impl Write, registry: &Registry) -> Result<()> {
         let w = &mut IndentedWriter::new(out, self.config.indent);
+        let checked = module::for_plugin_checks(registry, Language::Swift);
+        let registry = &module::for_language(registry, Language::Swift);
+        plugin::check_registry(&self.plugins, &checked).map_err(std::io::Error::other)?;
 
         let mut config = self.config.clone();
         config.update_from(registry);
@@ -142,15 +148,17 @@ fn check_type_hashable<'a>(
     visiting.insert(qtn);
 
     let result = match container {
-        ContainerFormat::UnitStruct(_) => true,
-        ContainerFormat::NewTypeStruct(fmt, _) => fmt_is_hashable(registry, fmt, known, visiting),
-        ContainerFormat::TupleStruct(fmts, _) => fmts
+        ContainerFormat::UnitStruct(_, _) => true,
+        ContainerFormat::NewTypeStruct(fmt, _, _) => {
+            fmt_is_hashable(registry, fmt, known, visiting)
+        }
+        ContainerFormat::TupleStruct(fmts, _, _) => fmts
             .iter()
             .all(|f| fmt_is_hashable(registry, f, known, visiting)),
-        ContainerFormat::Struct(fields, _) => fields
+        ContainerFormat::Struct(fields, _, _) => fields
             .iter()
             .all(|f| fmt_is_hashable(registry, &f.value, known, visiting)),
-        ContainerFormat::Enum(variants, _, _) => variants
+        ContainerFormat::Enum(variants, _, _, _) => variants
             .values()
             .all(|v| variant_is_hashable(registry, &v.value, known, visiting)),
     };
@@ -191,6 +199,14 @@ fn fmt_is_hashable<'a>(
 ) -> bool {
     match format {
         Format::TypeName(qtn) => check_type_hashable(registry, qtn, known, visiting),
+        // Type parameters are constrained to `Hashable` on hashable generic types.
+        Format::TypeParameter(_) => true,
+        Format::Generic { name, args } => {
+            check_type_hashable(registry, name, known, visiting)
+                && args
+                    .iter()
+                    .all(|arg| fmt_is_hashable(registry, arg, known, visiting))
+        }
         Format::Bool
         | Format::I8
         | Format::I16
@@ -207,7 +223,11 @@ fn fmt_is_hashable<'a>(
         | Format::Char
         | Format::Str
         | Format::Bytes
-        | Format::Uuid => true,
+        | Format::Uuid
+        | Format::Timestamp
+        | Format::Date
+        | Format::Time
+        | Format::Duration => true,
         Format::Variable(_) | Format::Unit => false,
         Format::Option(inner)
         | Format::Set(inner)
@@ -279,15 +299,17 @@ fn check_type_equatable<'a>(
     visiting.insert(qtn);
 
     let result = match container {
-        ContainerFormat::UnitStruct(_) => true,
-        ContainerFormat::NewTypeStruct(fmt, _) => fmt_is_equatable(registry, fmt, known, visiting),
-        ContainerFormat::TupleStruct(fmts, _) => fmts
+        ContainerFormat::UnitStruct(_, _) => true,
+        ContainerFormat::NewTypeStruct(fmt, _, _) => {
+            fmt_is_equatable(registry, fmt, known, visiting)
+        }
+        ContainerFormat::TupleStruct(fmts, _, _) => fmts
             .iter()
             .all(|f| fmt_is_equatable(registry, f, known, visiting)),
-        ContainerFormat::Struct(fields, _) => fields
+        ContainerFormat::Struct(fields, _, _) => fields
             .iter()
             .all(|f| fmt_is_equatable(registry, &f.value, known, visiting)),
-        ContainerFormat::Enum(variants, _, _) => variants
+        ContainerFormat::Enum(variants, _, _, _) => variants
             .values()
             .all(|v| variant_is_equatable(registry, &v.value, known, visiting)),
     };
@@ -328,6 +350,14 @@ fn fmt_is_equatable<'a>(
 ) -> bool {
     match format {
         Format::TypeName(qtn) => check_type_equatable(registry, qtn, known, visiting),
+        // Type parameters are constrained to `Equatable` on equatable generic types.
+        Format::TypeParameter(_) => true,
+        Format::Generic { name, args } => {
+            check_type_equatable(registry, name, known, visiting)
+                && args
+                    .iter()
+                    .all(|arg| fmt_is_equatable(registry, arg, known, visiting))
+        }
         Format::Bool
         | Format::I8
         | Format::I16
@@ -344,7 +374,11 @@ fn fmt_is_equatable<'a>(
         | Format::Char
         | Format::Str
         | Format::Bytes
-        | Format::Uuid => true,
+        | Format::Uuid
+        | Format::Timestamp
+        | Format::Date
+        | Format::Time
+        | Format::Duration => true,
         Format::Variable(_) | Format::Unit => false,
         Format::Option(inner)
         | Format::Set(inner)
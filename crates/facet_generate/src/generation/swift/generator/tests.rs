@@ -21,7 +21,8 @@ use std::sync::Arc;
 use crate::{
     generation::{CodeGeneratorConfig, bincode::BincodePlugin, plugin::EmitterPlugin},
     reflection::format::{
-        ContainerFormat, Doc, EnumTagging, Format, Named, Namespace, QualifiedTypeName,
+        ContainerFormat, ContainerMetadata, Doc, EnumTagging, FieldMetadata, Format, Named,
+        Namespace, QualifiedTypeName,
     },
 };
 
@@ -48,10 +49,11 @@ fn test_no_encoding_does_not_import_serde() {
         name: "id".to_string(),
         doc: Doc::new(),
         value: Format::U32,
+        metadata: FieldMetadata::default(),
     }];
     registry.insert(
         QualifiedTypeName::root("MyStruct".to_string()),
-        ContainerFormat::Struct(fields, Doc::new()),
+        ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default()),
     );
 
     let output = generate(&config, vec![], &registry);
@@ -71,10 +73,11 @@ fn test_bincode_encoding_has_serde_import() {
         name: "name".to_string(),
         doc: Doc::new(),
         value: Format::Str,
+        metadata: FieldMetadata::default(),
     }];
     registry.insert(
         QualifiedTypeName::root("MyStruct".to_string()),
-        ContainerFormat::Struct(fields, Doc::new()),
+        ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default()),
     );
 
     let output = generate(&config, vec![Arc::new(BincodePlugin)], &registry);
@@ -96,7 +99,7 @@ fn test_preamble_includes_external_definition_imports() {
     let mut registry = Registry::new();
     registry.insert(
         QualifiedTypeName::root("MyStruct".to_string()),
-        ContainerFormat::UnitStruct(Doc::new()),
+        ContainerFormat::UnitStruct(Doc::new(), ContainerMetadata::default()),
     );
 
     let output = generate(&config, vec![Arc::new(BincodePlugin)], &registry);
@@ -120,10 +123,11 @@ fn test_trait_helpers_emitted_for_complex_types() {
         name: "items".to_string(),
         doc: Doc::new(),
         value: Format::Seq(Box::new(Format::Str)),
+        metadata: FieldMetadata::default(),
     }];
     registry.insert(
         QualifiedTypeName::root("MyStruct".to_string()),
-        ContainerFormat::Struct(fields, Doc::new()),
+        ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default()),
     );
 
     let output = generate(&config, vec![Arc::new(BincodePlugin)], &registry);
@@ -147,10 +151,11 @@ fn test_no_trait_helpers_without_encoding() {
         name: "items".to_string(),
         doc: Doc::new(),
         value: Format::Seq(Box::new(Format::Str)),
+        metadata: FieldMetadata::default(),
     }];
     registry.insert(
         QualifiedTypeName::root("MyStruct".to_string()),
-        ContainerFormat::Struct(fields, Doc::new()),
+        ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default()),
     );
 
     let output = generate(&config, vec![], &registry);
@@ -177,10 +182,11 @@ fn test_map_with_hashable_k_v_implement_hashable_and_equatable() {
             key: Box::new(Format::Str),
             value: Box::new(Format::Str),
         },
+        metadata: FieldMetadata::default(),
     }];
     registry.insert(
         QualifiedTypeName::root("MyStruct".to_string()),
-        ContainerFormat::Struct(fields, Doc::new()),
+        ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default()),
     );
 
     let output = generate(&config, vec![Arc::new(BincodePlugin)], &registry);
@@ -200,10 +206,11 @@ fn test_struct_with_hashable_scalar_implements_hashable_and_equatable() {
         name: "items".to_string(),
         doc: Doc::new(),
         value: Format::U8,
+        metadata: FieldMetadata::default(),
     }];
     registry.insert(
         QualifiedTypeName::root("MyStruct".to_string()),
-        ContainerFormat::Struct(fields, Doc::new()),
+        ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default()),
     );
 
     let output = generate(&config, vec![Arc::new(BincodePlugin)], &registry);
@@ -226,10 +233,11 @@ fn test_struct_with_hashable_map_implements_hashable_and_equatable() {
             key: Box::new(Format::Str),
             value: Box::new(Format::Str),
         },
+        metadata: FieldMetadata::default(),
     }];
     registry.insert(
         QualifiedTypeName::root("MyStruct".to_string()),
-        ContainerFormat::Struct(fields, Doc::new()),
+        ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default()),
     );
 
     let output = generate(&config, vec![Arc::new(BincodePlugin)], &registry);
@@ -251,6 +259,7 @@ fn test_enum_with_hashable_variants_implements_hashable_and_equatable() {
         name: "StrVariant".to_string(),
         doc: Doc::new(),
         value: VariantFormat::NewType(Box::new(Format::Str)),
+        metadata: FieldMetadata::default(),
     };
 
     variants.insert(0, str_variant);
@@ -262,12 +271,18 @@ fn test_enum_with_hashable_variants_implements_hashable_and_equatable() {
             key: Box::new(Format::Str),
             value: Box::new(Format::Str),
         })),
+        metadata: FieldMetadata::default(),
     };
     variants.insert(1, map_variant);
 
     registry.insert(
         QualifiedTypeName::root("MyEnum".to_string()),
-        ContainerFormat::Enum(variants, EnumTagging::External, Doc::new()),
+        ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::new(),
+            ContainerMetadata::default(),
+        ),
     );
 
     let output = generate(&config, vec![Arc::new(BincodePlugin)], &registry);
@@ -289,6 +304,7 @@ fn test_with_enum_and_struct_variant_implements_hashable_and_equatable() {
             name: "str_item".to_string(),
             doc: Doc::new(),
             value: Format::Str,
+            metadata: FieldMetadata::default(),
         },
         Named {
             name: "str_map".to_string(),
@@ -297,17 +313,19 @@ fn test_with_enum_and_struct_variant_implements_hashable_and_equatable() {
                 key: Box::new(Format::Str),
                 value: Box::new(Format::Str),
             },
+            metadata: FieldMetadata::default(),
         },
         Named {
             name: "str_option".to_string(),
             doc: Doc::new(),
             value: Format::Option(Box::new(Format::Str)),
+            metadata: FieldMetadata::default(),
         },
     ];
 
     registry.insert(
         QualifiedTypeName::root("MyStruct".to_string()),
-        ContainerFormat::Struct(fields, Doc::new()),
+        ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default()),
     );
 
     let mut variants = BTreeMap::new();
@@ -316,6 +334,7 @@ fn test_with_enum_and_struct_variant_implements_hashable_and_equatable() {
         name: "StrVariant".to_string(),
         doc: Doc::new(),
         value: VariantFormat::NewType(Box::new(Format::Str)),
+        metadata: FieldMetadata::default(),
     };
 
     variants.insert(0, str_variant);
@@ -327,12 +346,18 @@ fn test_with_enum_and_struct_variant_implements_hashable_and_equatable() {
             namespace: Namespace::Root,
             name: "MyStruct".to_string(),
         }))),
+        metadata: FieldMetadata::default(),
     };
     variants.insert(1, struct_variant);
 
     registry.insert(
         QualifiedTypeName::root("MyEnum".to_string()),
-        ContainerFormat::Enum(variants, EnumTagging::External, Doc::new()),
+        ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::new(),
+            ContainerMetadata::default(),
+        ),
     );
 
     let output = generate(&config, vec![Arc::new(BincodePlugin)], &registry);
@@ -353,11 +378,12 @@ fn test_type_cycle_is_hashable_and_equatable() {
         name: "str_item".to_string(),
         doc: Doc::new(),
         value: Format::Str,
+        metadata: FieldMetadata::default(),
     }];
 
     registry.insert(
         QualifiedTypeName::root("MyStruct".to_string()),
-        ContainerFormat::Struct(fields, Doc::new()),
+        ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default()),
     );
 
     let mut variants = BTreeMap::new();
@@ -369,6 +395,7 @@ fn test_type_cycle_is_hashable_and_equatable() {
             namespace: Namespace::Root,
             name: "MyStruct".to_string(),
         }))),
+        metadata: FieldMetadata::default(),
     };
 
     let self_enum_variant = Named {
@@ -378,13 +405,19 @@ fn test_type_cycle_is_hashable_and_equatable() {
             namespace: Namespace::Root,
             name: "MyEnum".to_string(),
         }))),
+        metadata: FieldMetadata::default(),
     };
     variants.insert(0, struct_variant);
     variants.insert(1, self_enum_variant);
 
     registry.insert(
         QualifiedTypeName::root("MyEnum".to_string()),
-        ContainerFormat::Enum(variants, EnumTagging::External, Doc::new()),
+        ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::new(),
+            ContainerMetadata::default(),
+        ),
     );
 
     let output = generate(&config, vec![Arc::new(BincodePlugin)], &registry);
@@ -406,6 +439,7 @@ fn test_struct_declaration_easy_order() {
                 key: Box::new(Format::Str),
                 value: Box::new(Format::Str),
             },
+            metadata: FieldMetadata::default(),
         },
         // MyStruct2, it will be added to the registry before MyStruct1
         Named {
@@ -415,10 +449,11 @@ fn test_struct_declaration_easy_order() {
                 namespace: Namespace::Root,
                 name: "MyStruct2".to_string(),
             }),
+            metadata: FieldMetadata::default(),
         },
     ];
-    let struct1 = ContainerFormat::Struct(fields.clone(), Doc::new());
-    let struct2 = ContainerFormat::Struct(fields, Doc::new());
+    let struct1 = ContainerFormat::Struct(fields.clone(), Doc::new(), ContainerMetadata::default());
+    let struct2 = ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default());
 
     let mut registry = Registry::new();
 
@@ -448,6 +483,7 @@ fn test_struct_declaration_inv_order() {
                 key: Box::new(Format::Str),
                 value: Box::new(Format::Str),
             },
+            metadata: FieldMetadata::default(),
         },
         // MyStruct2, will be added to the registry after MyStruct1
         Named {
@@ -457,10 +493,11 @@ fn test_struct_declaration_inv_order() {
                 namespace: Namespace::Root,
                 name: "MyStruct2".to_string(),
             }),
+            metadata: FieldMetadata::default(),
         },
     ];
-    let struct1 = ContainerFormat::Struct(fields.clone(), Doc::new());
-    let struct2 = ContainerFormat::Struct(fields, Doc::new());
+    let struct1 = ContainerFormat::Struct(fields.clone(), Doc::new(), ContainerMetadata::default());
+    let struct2 = ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default());
 
     let mut registry = Registry::new();
 
@@ -489,6 +526,7 @@ fn test_mutual_recursion_equatable() {
             name: "value".to_string(),
             doc: Doc::new(),
             value: Format::U32,
+            metadata: FieldMetadata::default(),
         },
         Named {
             name: "other".to_string(),
@@ -497,6 +535,7 @@ fn test_mutual_recursion_equatable() {
                 namespace: Namespace::Root,
                 name: "StructB".to_string(),
             }),
+            metadata: FieldMetadata::default(),
         },
     ];
 
@@ -505,6 +544,7 @@ fn test_mutual_recursion_equatable() {
             name: "value".to_string(),
             doc: Doc::new(),
             value: Format::U32,
+            metadata: FieldMetadata::default(),
         },
         Named {
             name: "other".to_string(),
@@ -513,11 +553,14 @@ fn test_mutual_recursion_equatable() {
                 namespace: Namespace::Root,
                 name: "StructA".to_string(),
             }),
+            metadata: FieldMetadata::default(),
         },
     ];
 
-    let struct_a = ContainerFormat::Struct(struct_a_fields, Doc::new());
-    let struct_b = ContainerFormat::Struct(struct_b_fields, Doc::new());
+    let struct_a =
+        ContainerFormat::Struct(struct_a_fields, Doc::new(), ContainerMetadata::default());
+    let struct_b =
+        ContainerFormat::Struct(struct_b_fields, Doc::new(), ContainerMetadata::default());
 
     let mut registry = Registry::new();
     registry.insert(QualifiedTypeName::root("StructA".to_string()), struct_a);
@@ -553,11 +596,21 @@ fn test_named_namespace_map_key_value_are_hashable() {
                     "Value".to_string(),
                 ))),
             },
+            metadata: FieldMetadata::default(),
         }],
         Doc::new(),
+        ContainerMetadata::default(),
+    );
+    let key = ContainerFormat::NewTypeStruct(
+        Box::new(Format::Str),
+        Doc::new(),
+        ContainerMetadata::default(),
+    );
+    let value = ContainerFormat::NewTypeStruct(
+        Box::new(Format::I32),
+        Doc::new(),
+        ContainerMetadata::default(),
     );
-    let key = ContainerFormat::NewTypeStruct(Box::new(Format::Str), Doc::new());
-    let value = ContainerFormat::NewTypeStruct(Box::new(Format::I32), Doc::new());
 
     let mut registry = Registry::new();
     registry.insert(
@@ -593,10 +646,16 @@ fn test_named_namespace_struct_implements_hashable_and_equatable() {
                 "other".to_string(),
                 "Child".to_string(),
             )),
+            metadata: FieldMetadata::default(),
         }],
         Doc::new(),
+        ContainerMetadata::default(),
+    );
+    let child = ContainerFormat::NewTypeStruct(
+        Box::new(Format::Str),
+        Doc::new(),
+        ContainerMetadata::default(),
     );
-    let child = ContainerFormat::NewTypeStruct(Box::new(Format::Str), Doc::new());
 
     let mut registry = Registry::new();
     registry.insert(
@@ -637,11 +696,21 @@ fn other_namespace_map_registry() -> Registry {
                     "Value".to_string(),
                 ))),
             },
+            metadata: FieldMetadata::default(),
         }],
         Doc::new(),
+        ContainerMetadata::default(),
+    );
+    let key = ContainerFormat::NewTypeStruct(
+        Box::new(Format::Str),
+        Doc::new(),
+        ContainerMetadata::default(),
+    );
+    let value = ContainerFormat::NewTypeStruct(
+        Box::new(Format::I32),
+        Doc::new(),
+        ContainerMetadata::default(),
     );
-    let key = ContainerFormat::NewTypeStruct(Box::new(Format::Str), Doc::new());
-    let value = ContainerFormat::NewTypeStruct(Box::new(Format::I32), Doc::new());
 
     let mut registry = Registry::new();
     registry.insert(
@@ -683,8 +752,10 @@ fn test_compute_hashable_types_is_collision_safe() {
             name: "x".to_string(),
             doc: Doc::new(),
             value: Format::U32,
+            metadata: FieldMetadata::default(),
         }],
         Doc::new(),
+        ContainerMetadata::default(),
     );
     // A multi-element native tuple is not Hashable in Swift.
     let other_child = ContainerFormat::Struct(
@@ -692,8 +763,10 @@ fn test_compute_hashable_types_is_collision_safe() {
             name: "pair".to_string(),
             doc: Doc::new(),
             value: Format::Tuple(vec![Format::U32, Format::U32]),
+            metadata: FieldMetadata::default(),
         }],
         Doc::new(),
+        ContainerMetadata::default(),
     );
 
     let mut registry = Registry::new();
@@ -731,8 +804,10 @@ fn test_compute_hashable_types_assumes_external_hashable() {
                 ))),
                 value: Box::new(Format::Str),
             },
+            metadata: FieldMetadata::default(),
         }],
         Doc::new(),
+        ContainerMetadata::default(),
     );
 
     let mut registry = Registry::new();
@@ -761,16 +836,20 @@ fn test_non_hashable_local_named_map_key_is_rejected() {
                 ))),
                 value: Box::new(Format::Str),
             },
+            metadata: FieldMetadata::default(),
         }],
         Doc::new(),
+        ContainerMetadata::default(),
     );
     let bad = ContainerFormat::Struct(
         vec![Named {
             name: "pair".to_string(),
             doc: Doc::new(),
             value: Format::Tuple(vec![Format::U32, Format::U32]),
+            metadata: FieldMetadata::default(),
         }],
         Doc::new(),
+        ContainerMetadata::default(),
     );
 
     let mut registry = Registry::new();
@@ -803,8 +882,10 @@ fn test_self_referential_named_type_is_hashable() {
                 "other".to_string(),
                 "Node".to_string(),
             )))),
+            metadata: FieldMetadata::default(),
         }],
         Doc::new(),
+        ContainerMetadata::default(),
     );
 
     let mut registry = Registry::new();
@@ -37,10 +37,13 @@ use crate::{
     Registry,
     generation::{
         CodeGeneratorConfig, Error, ExternalPackage, ExternalPackages, SERDE_NAMESPACE,
-        SourceInstaller, module,
-        plugin::EmitterPlugin,
+        SourceInstaller,
+        indent::IndentConfig,
+        module,
+        plugin::{self, EmitterPlugin},
         swift::{Swift, generator::SwiftCodeGenerator},
     },
+    reflection::format::{Language, Visibility},
 };
 
 /// Writes a complete Swift package — runtime sources, per-module generated
@@ -50,7 +53,9 @@ pub struct Installer {
     install_dir: PathBuf,
     targets: BTreeMap<String, BTreeSet<String>>,
     external_packages: ExternalPackages,
+    indent: IndentConfig,
     plugins: Vec<Arc<dyn EmitterPlugin<Swift>>>,
+    default_visibility: Visibility,
 }
 
 impl Installer {
@@ -66,7 +71,9 @@ impl Installer {
             install_dir: install_dir.as_ref().to_path_buf(),
             targets: BTreeMap::new(),
             external_packages: ExternalPackages::new(),
+            indent: IndentConfig::Space(4),
             plugins: vec![],
+            default_visibility: Visibility::Public,
         }
     }
 
@@ -90,6 +97,21 @@ impl Installer {
         self
     }
 
+    /// Set the visibility of generated types and fields that aren't marked
+    /// `#[facet(fg::public)]` (public by default).
+    #[must_use]
+    pub const fn default_visibility(mut self, visibility: Visibility) -> Self {
+        self.default_visibility = visibility;
+        self
+    }
+
+    /// Set the indentation of generated source files (four spaces by default).
+    #[must_use]
+    pub const fn indent(mut self, indent: IndentConfig) -> Self {
+        self.indent = indent;
+        self
+    }
+
     /// Generate all code for the given registry.
     ///
     /// This method:
@@ -101,6 +123,11 @@ impl Installer {
     ///
     /// Returns an error if any file operation or code generation step fails.
     pub fn generate(mut self, registry: &Registry) -> Result<(), Error> {
+        plugin::check_registry(
+            &self.plugins,
+            &module::for_plugin_checks(registry, Language::Swift),
+        )?;
+
         let mut config = CodeGeneratorConfig::new(self.package_name.clone());
         config.update_from(registry);
 
@@ -132,8 +159,15 @@ impl Installer {
         }
 
         // Split by namespace and install each module
-        for (m, module_registry) in module::split(&self.package_name, registry) {
-            let config = m.config().clone();
+        for (m, module_registry) in module::split(
+            &self.package_name,
+            &module::for_language(registry, Language::Swift),
+        ) {
+            let config = m
+                .config()
+                .clone()
+                .with_default_visibility(self.default_visibility)
+                .with_indent(self.indent);
             self.install_module(&config, &module_registry)?;
         }
 
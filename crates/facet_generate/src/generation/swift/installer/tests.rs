@@ -11,6 +11,7 @@
 //! - Serde runtime target registration and dependency edges.
 //! - Multi-module (namespace) scenarios where each namespace becomes a
 //!   separate SPM target.
+//! - Registries the plugins can't encode, such as untagged enums with bincode.
 
 use facet::Facet;
 
@@ -627,3 +628,87 @@ fn external_dependency_references_local_dependency() {
     )
     "#);
 }
+
+#[test]
+fn bincode_rejects_untagged_enums() {
+    #[derive(Facet)]
+    #[facet(untagged)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Value {
+        Number(i32),
+        Text(String),
+    }
+
+    let registry = reflect!(Value).unwrap();
+
+    let install_dir = tempfile::tempdir().unwrap();
+    let installer = Installer::new("MyPackage", install_dir.path()).plugin(BincodePlugin);
+
+    let error = installer.generate(&registry).unwrap_err();
+    insta::assert_snapshot!(error, @"bincode can't encode `Value`: untagged enums don't write the variant index bincode needs");
+}
+
+#[test]
+fn bincode_rejects_fields_left_out_of_some_languages() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Opened {
+            id: u32,
+            #[facet(fg::only(kotlin))]
+            source: String,
+        },
+    }
+
+    #[derive(Facet)]
+    struct Session {
+        id: u32,
+        #[facet(fg::skip(swift))]
+        token: String,
+    }
+
+    let install_dir = tempfile::tempdir().unwrap();
+
+    // The fields are left out of Swift, but Kotlin would still write them.
+    let error = Installer::new("MyPackage", install_dir.path())
+        .plugin(BincodePlugin)
+        .generate(&reflect!(Session).unwrap())
+        .unwrap_err();
+    insta::assert_snapshot!(error, @"bincode can't encode `Session`: field `token` is only generated for some languages, which would then write different fields");
+
+    let error = Installer::new("MyPackage", install_dir.path())
+        .plugin(BincodePlugin)
+        .generate(&reflect!(Event).unwrap())
+        .unwrap_err();
+    insta::assert_snapshot!(error, @"bincode can't encode `Event`: field `source` is only generated for some languages, which would then write different fields");
+
+    // Without bincode, the fields are just left out.
+    Installer::new("MyPackage", install_dir.path())
+        .generate(&reflect!(Session).unwrap())
+        .unwrap();
+}
+
+#[test]
+fn bincode_rejects_flattened_fields() {
+    #[derive(Facet)]
+    struct Pagination {
+        page: u32,
+    }
+
+    #[derive(Facet)]
+    struct Users {
+        #[facet(flatten)]
+        pagination: Pagination,
+        users: Vec<String>,
+    }
+
+    let install_dir = tempfile::tempdir().unwrap();
+
+    let error = Installer::new("MyPackage", install_dir.path())
+        .plugin(BincodePlugin)
+        .generate(&reflect!(Users).unwrap())
+        .unwrap_err();
+    insta::assert_snapshot!(error, @"bincode can't encode `Users`: field `page` is flattened, which serde writes as a map that bincode can't encode");
+}
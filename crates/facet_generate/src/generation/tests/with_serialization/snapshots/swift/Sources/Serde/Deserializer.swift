@@ -28,6 +28,29 @@ public protocol Deserializer {
     func deserialize_variant_index() throws -> UInt32
     func deserialize_option_tag() throws -> Bool
     func get_buffer_offset() -> Int
+    /// Rewinds to an offset returned by `get_buffer_offset`, e.g. to try the
+    /// next variant of an untagged enum.
+    func set_buffer_offset(value: Int)
     func increase_container_depth() throws
     func decrease_container_depth() throws
+    /// Whether the object being read has the field `name`, for formats such
+    /// as JSON whose fields can be missing.
+    func has_field(name: String) throws -> Bool
+}
+
+extension Deserializer {
+    /// Binary formats write every field.
+    public func has_field(name: String) throws -> Bool {
+        true
+    }
+
+    /// Reads the field `name` with `deserialize`, or returns `defaultValue`
+    /// if it is missing.
+    public func deserialize_field<T>(
+        name: String,
+        default defaultValue: T,
+        _ deserialize: () throws -> T
+    ) throws -> T {
+        try has_field(name: name) ? deserialize() : defaultValue
+    }
 }
@@ -0,0 +1,60 @@
+// Helpers for the `Codable` conformances generated for internally and
+// adjacently tagged enums, whose tag and payload keys are known only as strings.
+
+import Foundation
+
+/// A coding key for any JSON object key, e.g. `"type"`.
+public struct JsonKey: CodingKey, ExpressibleByStringLiteral {
+    public let stringValue: String
+    public var intValue: Int? { nil }
+
+    public init(stringValue: String) {
+        self.stringValue = stringValue
+    }
+
+    public init?(intValue: Int) {
+        nil
+    }
+
+    public init(stringLiteral value: String) {
+        self.stringValue = value
+    }
+}
+
+extension KeyedDecodingContainer where K == JsonKey {
+    /// Decodes a required field, whose type is inferred from the context.
+    public func field<T: Decodable>(_ key: JsonKey) throws -> T {
+        try decode(T.self, forKey: key)
+    }
+
+    /// Decodes an optional field, which may be missing.
+    public func field<T: Decodable>(_ key: JsonKey) throws -> T? {
+        try decodeIfPresent(T.self, forKey: key)
+    }
+
+    /// Decodes a field with a default value, which may be missing.
+    public func field<T: Decodable>(_ key: JsonKey, default defaultValue: T) throws -> T {
+        try decodeIfPresent(T.self, forKey: key) ?? defaultValue
+    }
+}
+
+extension UnkeyedDecodingContainer {
+    /// Decodes the next element of a tuple, whose type is inferred from the context.
+    public mutating func element<T: Decodable>() throws -> T {
+        try decode(T.self)
+    }
+}
+
+extension Encodable {
+    /// Encodes the value as JSON, using its `Codable` conformance.
+    public func encodeJson() throws -> [UInt8] {
+        Array(try JSONEncoder().encode(self))
+    }
+}
+
+extension Decodable {
+    /// Decodes a value from JSON, using its `Codable` conformance.
+    public static func decodeJson(input: [UInt8]) throws -> Self {
+        try JSONDecoder().decode(Self.self, from: Data(input))
+    }
+}
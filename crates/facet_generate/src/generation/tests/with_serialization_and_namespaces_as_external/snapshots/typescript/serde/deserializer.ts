@@ -46,6 +46,15 @@ export interface Deserializer {
 
   getBufferOffset(): number;
 
+  // Rewinds to an offset returned by `getBufferOffset`, e.g. to try the next
+  // variant of an untagged enum.
+  setBufferOffset(offset: number): void;
+
+  // Whether the object being read has the field `name`, for formats such as
+  // JSON whose fields can be missing. Fields with a default value are only
+  // read if it does; binary formats, which write every field, leave it out.
+  hasField?(name: string): boolean;
+
   checkThatKeySlicesAreIncreasing(
     key1: [number, number],
     key2: [number, number],
@@ -116,6 +116,10 @@ export abstract class BinaryDeserializer implements Deserializer {
     return this.offset;
   }
 
+  public setBufferOffset(offset: number): void {
+    this.offset = offset;
+  }
+
   public deserializeChar(): string {
     throw new Error("Method deserializeChar not implemented.");
   }
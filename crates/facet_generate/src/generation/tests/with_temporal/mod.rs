@@ -0,0 +1,123 @@
+use std::{fs, time::Duration};
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use expect_test::expect_file;
+use facet::Facet;
+use tempfile::tempdir;
+
+use crate::{
+    generation::{
+        bincode::BincodePlugin,
+        json::JsonPlugin,
+        kotlin, swift,
+        tests::{TargetLanguage, check, read_files_and_create_expect_dirs},
+        typescript,
+    },
+    reflect, source_dir,
+};
+
+/// A struct with a field of each temporal format, exercising both bincode
+/// (strings and a seconds/nanos pair) and JSON (RFC 3339 strings) encoding paths.
+#[test]
+fn test_bincode() {
+    #[derive(Facet)]
+    struct StructWithTemporal {
+        created_at: DateTime<Utc>,
+        birthday: Option<NaiveDate>,
+        alarm: NaiveTime,
+        timeout: Duration,
+    }
+
+    let registry = reflect!(StructWithTemporal).unwrap();
+
+    let this_dir = source_dir!().join("snapshots_bincode");
+
+    for target in [
+        TargetLanguage::Kotlin,
+        TargetLanguage::Swift,
+        TargetLanguage::TypeScript,
+    ] {
+        let tmp_dir = tempdir().unwrap();
+        let tmp_path = tmp_dir.path();
+
+        let snapshot_dir = this_dir.join(target.to_string().to_lowercase());
+        fs::create_dir_all(&snapshot_dir).unwrap();
+
+        match target {
+            TargetLanguage::Kotlin => {
+                kotlin::Installer::new("com.example", tmp_path)
+                    .plugin(BincodePlugin)
+                    .generate(&registry)
+                    .unwrap();
+            }
+            TargetLanguage::Swift => {
+                swift::Installer::new("Example", tmp_path)
+                    .plugin(BincodePlugin)
+                    .generate(&registry)
+                    .unwrap();
+            }
+            TargetLanguage::TypeScript => {
+                typescript::Installer::new("example", tmp_path)
+                    .plugin(BincodePlugin)
+                    .generate(&registry)
+                    .unwrap();
+            }
+        }
+
+        for (actual, expected) in read_files_and_create_expect_dirs(tmp_path, &snapshot_dir) {
+            check(&actual, &expect_file!(&expected));
+        }
+    }
+}
+
+#[test]
+fn test_json() {
+    #[derive(Facet)]
+    struct StructWithTemporal {
+        created_at: DateTime<Utc>,
+        birthday: Option<NaiveDate>,
+        alarm: NaiveTime,
+        timeout: Duration,
+    }
+
+    let registry = reflect!(StructWithTemporal).unwrap();
+
+    let this_dir = source_dir!().join("snapshots_json");
+
+    for target in [
+        TargetLanguage::Kotlin,
+        TargetLanguage::Swift,
+        TargetLanguage::TypeScript,
+    ] {
+        let tmp_dir = tempdir().unwrap();
+        let tmp_path = tmp_dir.path();
+
+        let snapshot_dir = this_dir.join(target.to_string().to_lowercase());
+        fs::create_dir_all(&snapshot_dir).unwrap();
+
+        match target {
+            TargetLanguage::Kotlin => {
+                kotlin::Installer::new("com.example", tmp_path)
+                    .plugin(JsonPlugin)
+                    .generate(&registry)
+                    .unwrap();
+            }
+            TargetLanguage::Swift => {
+                swift::Installer::new("Example", tmp_path)
+                    .plugin(JsonPlugin)
+                    .generate(&registry)
+                    .unwrap();
+            }
+            TargetLanguage::TypeScript => {
+                typescript::Installer::new("example", tmp_path)
+                    .plugin(JsonPlugin)
+                    .generate(&registry)
+                    .unwrap();
+            }
+        }
+
+        for (actual, expected) in read_files_and_create_expect_dirs(tmp_path, &snapshot_dir) {
+            check(&actual, &expect_file!(&expected));
+        }
+    }
+}
@@ -0,0 +1,21 @@
+plugins {
+    kotlin("jvm") version "2.2.0"
+    kotlin("plugin.serialization") version "2.2.0"
+    `java-library`
+}
+
+group = "com.example"
+version = "1.0.0"
+
+repositories {
+    mavenCentral()
+}
+
+dependencies {}
+
+tasks.withType<Jar> {
+    manifest {
+        attributes["Implementation-Title"] = "com.example"
+        attributes["Implementation-Version"] = "1.0.0"
+    }
+}
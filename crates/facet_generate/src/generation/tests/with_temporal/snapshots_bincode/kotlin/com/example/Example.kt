@@ -0,0 +1,122 @@
+package com.example
+
+import com.novi.bincode.BincodeDeserializer
+import com.novi.bincode.BincodeSerializer
+import com.novi.serde.DeserializationError
+import com.novi.serde.Deserializer
+import com.novi.serde.SerializationError
+import com.novi.serde.Serializer
+import java.time.Duration
+import java.time.Instant
+import java.time.LocalDate
+import java.time.LocalTime
+
+fun <T> T?.serializeOptionOf(
+    serializer: Serializer,
+    serializeElement: Serializer.(T) -> Unit,
+) {
+    if (this != null) {
+        serializer.serialize_option_tag(true)
+        serializer.serializeElement(this)
+    } else {
+        serializer.serialize_option_tag(false)
+    }
+}
+
+fun <T> Deserializer.deserializeOptionOf(deserializeElement: (Deserializer) -> T): T? {
+    val tag = deserialize_option_tag()
+    return if (tag) {
+        deserializeElement(this)
+    } else {
+        null
+    }
+}
+
+fun Instant.serialize(serializer: Serializer) {
+    serializer.serialize_str(toString())
+}
+
+fun Deserializer.deserializeInstant(): Instant =
+    java.time.OffsetDateTime.parse(deserialize_str()).toInstant()
+
+fun LocalDate.serialize(serializer: Serializer) {
+    serializer.serialize_str(toString())
+}
+
+fun Deserializer.deserializeLocalDate(): LocalDate = LocalDate.parse(deserialize_str())
+
+fun LocalTime.serialize(serializer: Serializer) {
+    serializer.serialize_str(java.time.format.DateTimeFormatter.ISO_LOCAL_TIME.format(this))
+}
+
+fun Deserializer.deserializeLocalTime(): LocalTime = LocalTime.parse(deserialize_str())
+
+fun Duration.serialize(serializer: Serializer) {
+    if (isNegative) {
+        throw SerializationError("Duration must not be negative: $this")
+    }
+    serializer.increase_container_depth()
+    serializer.serialize_u64(seconds.toULong())
+    serializer.serialize_u32(nano.toUInt())
+    serializer.decrease_container_depth()
+}
+
+fun Deserializer.deserializeDuration(): Duration {
+    increase_container_depth()
+    val secs = deserialize_u64()
+    val nanos = deserialize_u32()
+    decrease_container_depth()
+    return Duration.ofSeconds(secs.toLong(), nanos.toLong())
+}
+
+data class StructWithTemporal(
+    val createdAt: Instant,
+    val birthday: LocalDate? = null,
+    val alarm: LocalTime,
+    val timeout: Duration,
+) {
+    fun serialize(serializer: Serializer) {
+        serializer.increase_container_depth()
+        createdAt.serialize(serializer)
+        birthday.serializeOptionOf(serializer) {
+            it.serialize(serializer)
+        }
+        alarm.serialize(serializer)
+        timeout.serialize(serializer)
+        serializer.decrease_container_depth()
+    }
+
+    fun bincodeSerialize(): ByteArray {
+        val serializer = BincodeSerializer()
+        serialize(serializer)
+        return serializer.get_bytes()
+    }
+
+    companion object {
+        fun deserialize(deserializer: Deserializer): StructWithTemporal {
+            deserializer.increase_container_depth()
+            val createdAt = deserializer.deserializeInstant()
+            val birthday =
+                deserializer.deserializeOptionOf {
+                    deserializer.deserializeLocalDate()
+                }
+            val alarm = deserializer.deserializeLocalTime()
+            val timeout = deserializer.deserializeDuration()
+            deserializer.decrease_container_depth()
+            return StructWithTemporal(createdAt, birthday, alarm, timeout)
+        }
+
+        @Throws(DeserializationError::class)
+        fun bincodeDeserialize(input: ByteArray?): StructWithTemporal {
+            if (input == null) {
+                throw DeserializationError("Cannot deserialize null array")
+            }
+            val deserializer = BincodeDeserializer(input)
+            val value = deserialize(deserializer)
+            if (deserializer.get_buffer_offset() < input.size) {
+                throw DeserializationError("Some input bytes were not read")
+            }
+            return value
+        }
+    }
+}
@@ -0,0 +1,80 @@
+import { Serializer, Deserializer } from "./serde";
+type date = string;
+type duration = { secs: bigint; nanos: number };
+type Optional<T> = T | null;
+type time = string;
+type timestamp = Date;
+
+function serializeOption<T>(
+    value: T | null,
+    serializer: Serializer,
+    serializeElement: (value: T, serializer: Serializer) => void,
+): void {
+    if (value !== null) {
+        serializer.serializeOptionTag(true);
+        serializeElement(value, serializer);
+    } else {
+        serializer.serializeOptionTag(false);
+    }
+}
+
+function deserializeOption<T>(
+    deserializer: Deserializer,
+    deserializeElement: (deserializer: Deserializer) => T,
+): T | null {
+    const tag = deserializer.deserializeOptionTag();
+    if (!tag) {
+        return null;
+    } else {
+        return deserializeElement(deserializer);
+    }
+}
+
+function serializeTimestamp(value: Date, serializer: Serializer): void {
+    serializer.serializeStr(value.toISOString());
+}
+
+function deserializeTimestamp(deserializer: Deserializer): Date {
+    const s = deserializer.deserializeStr();
+    // `Date` has millisecond precision, so longer fractions are truncated.
+    const value = new Date(s.replace(/(\.\d{3})\d+/, '$1'));
+    if (Number.isNaN(value.getTime())) {
+        throw new Error(`Invalid RFC 3339 timestamp: ${s}`);
+    }
+    return value;
+}
+
+function serializeDuration(value: duration, serializer: Serializer): void {
+    serializer.serializeU64(value.secs);
+    serializer.serializeU32(value.nanos);
+}
+
+function deserializeDuration(deserializer: Deserializer): duration {
+    const secs = deserializer.deserializeU64();
+    const nanos = deserializer.deserializeU32();
+    return { secs, nanos };
+}
+
+export class StructWithTemporal {
+    constructor (public created_at: timestamp, public birthday: Optional<date>, public alarm: time, public timeout: duration) {
+    }
+
+    public serialize(serializer: Serializer): void {
+        serializeTimestamp(this.created_at, serializer);
+        serializeOption(this.birthday, serializer, (value, serializer) => {
+            serializer.serializeStr(value);
+        });
+        serializer.serializeStr(this.alarm);
+        serializeDuration(this.timeout, serializer);
+    }
+
+    static deserialize(deserializer: Deserializer): StructWithTemporal {
+        const created_at = deserializeTimestamp(deserializer);
+        const birthday = deserializeOption(deserializer, (deserializer) => {
+            return deserializer.deserializeStr();
+        });
+        const alarm = deserializer.deserializeStr();
+        const timeout = deserializeDuration(deserializer);
+        return new StructWithTemporal(created_at,birthday,alarm,timeout);
+    }
+}
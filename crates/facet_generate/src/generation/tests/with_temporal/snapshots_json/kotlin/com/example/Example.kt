@@ -0,0 +1,58 @@
+package com.example
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.descriptors.PrimitiveKind
+import kotlinx.serialization.descriptors.PrimitiveSerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+
+private object InstantSerializer : KSerializer<java.time.Instant> {
+    override val descriptor = PrimitiveSerialDescriptor("Instant", PrimitiveKind.STRING)
+    override fun deserialize(decoder: Decoder): java.time.Instant = java.time.OffsetDateTime.parse(decoder.decodeString()).toInstant()
+    override fun serialize(encoder: Encoder, value: java.time.Instant) = encoder.encodeString(value.toString())
+}
+
+typealias Instant = @Serializable(with = InstantSerializer::class) java.time.Instant
+
+private object LocalDateSerializer : KSerializer<java.time.LocalDate> {
+    override val descriptor = PrimitiveSerialDescriptor("LocalDate", PrimitiveKind.STRING)
+    override fun deserialize(decoder: Decoder): java.time.LocalDate = java.time.LocalDate.parse(decoder.decodeString())
+    override fun serialize(encoder: Encoder, value: java.time.LocalDate) = encoder.encodeString(value.toString())
+}
+
+typealias LocalDate = @Serializable(with = LocalDateSerializer::class) java.time.LocalDate
+
+private object LocalTimeSerializer : KSerializer<java.time.LocalTime> {
+    override val descriptor = PrimitiveSerialDescriptor("LocalTime", PrimitiveKind.STRING)
+    override fun deserialize(decoder: Decoder): java.time.LocalTime = java.time.LocalTime.parse(decoder.decodeString())
+    override fun serialize(encoder: Encoder, value: java.time.LocalTime) =
+        encoder.encodeString(java.time.format.DateTimeFormatter.ISO_LOCAL_TIME.format(value))
+}
+
+typealias LocalTime = @Serializable(with = LocalTimeSerializer::class) java.time.LocalTime
+
+@Serializable
+private class DurationSurrogate(val secs: Long, val nanos: Int)
+
+private object DurationSerializer : KSerializer<java.time.Duration> {
+    override val descriptor = DurationSurrogate.serializer().descriptor
+    override fun deserialize(decoder: Decoder): java.time.Duration {
+        val surrogate = decoder.decodeSerializableValue(DurationSurrogate.serializer())
+        return java.time.Duration.ofSeconds(surrogate.secs, surrogate.nanos.toLong())
+    }
+    override fun serialize(encoder: Encoder, value: java.time.Duration) =
+        encoder.encodeSerializableValue(DurationSurrogate.serializer(), DurationSurrogate(value.seconds, value.nano))
+}
+
+typealias Duration = @Serializable(with = DurationSerializer::class) java.time.Duration
+
+@Serializable
+@SerialName("StructWithTemporal")
+data class StructWithTemporal(
+    val createdAt: Instant,
+    val birthday: LocalDate? = null,
+    val alarm: LocalTime,
+    val timeout: Duration,
+)
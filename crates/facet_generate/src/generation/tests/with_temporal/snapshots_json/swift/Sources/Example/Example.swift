@@ -0,0 +1,189 @@
+import Foundation
+import Serde
+
+func serializeOption<T, S: Serializer>(
+    value: T?,
+    serializer: S,
+    serializeElement: (T, S) throws -> Void
+) throws {
+    if let value = value {
+        try serializer.serialize_option_tag(value: true)
+        try serializeElement(value, serializer)
+    } else {
+        try serializer.serialize_option_tag(value: false)
+    }
+}
+
+func deserializeOption<T, D: Deserializer>(
+    deserializer: D,
+    deserializeElement: (D) throws -> T
+) throws -> T? {
+    let tag = try deserializer.deserialize_option_tag()
+    if tag {
+        return try deserializeElement(deserializer)
+    } else {
+        return nil
+    }
+}
+
+func serializeTimestamp<S: Serializer>(
+    value: Date,
+    serializer: S
+) throws {
+    let formatter = ISO8601DateFormatter()
+    formatter.formatOptions = [.withInternetDateTime, .withFractionalSeconds]
+    try serializer.serialize_str(value: formatter.string(from: value))
+}
+
+func deserializeTimestamp<D: Deserializer>(
+    deserializer: D
+) throws -> Date {
+    let s = try deserializer.deserialize_str()
+    // ISO8601DateFormatter accepts at most millisecond precision, so the
+    // fractional seconds are parsed separately.
+    var text = s
+    var fraction = 0.0
+    if let dot = text.firstIndex(of: "."),
+       let end = text[dot...].firstIndex(where: { !$0.isNumber && $0 != "." }) {
+        fraction = Double("0" + text[dot..<end]) ?? 0
+        text.removeSubrange(dot..<end)
+    }
+    guard let date = ISO8601DateFormatter().date(from: text) else {
+        throw DeserializationError.invalidInput(issue: "Invalid RFC 3339 timestamp: \(s)")
+    }
+    return date.addingTimeInterval(fraction)
+}
+
+func serializeDate<S: Serializer>(
+    value: DateComponents,
+    serializer: S
+) throws {
+    guard let year = value.year, let month = value.month, let day = value.day else {
+        throw SerializationError.invalidValue(issue: "Date requires a year, month and day")
+    }
+    try serializer.serialize_str(value: String(format: "%04d-%02d-%02d", year, month, day))
+}
+
+func deserializeDate<D: Deserializer>(
+    deserializer: D
+) throws -> DateComponents {
+    let s = try deserializer.deserialize_str()
+    let parts = s.split(separator: "-").compactMap { Int($0) }
+    guard parts.count == 3 else {
+        throw DeserializationError.invalidInput(issue: "Invalid date: \(s)")
+    }
+    return DateComponents(year: parts[0], month: parts[1], day: parts[2])
+}
+
+func serializeTime<S: Serializer>(
+    value: DateComponents,
+    serializer: S
+) throws {
+    guard let hour = value.hour, let minute = value.minute else {
+        throw SerializationError.invalidValue(issue: "Time requires an hour and minute")
+    }
+    var text = String(format: "%02d:%02d:%02d", hour, minute, value.second ?? 0)
+    if let nanosecond = value.nanosecond, nanosecond > 0 {
+        text += String(format: ".%09d", nanosecond)
+    }
+    try serializer.serialize_str(value: text)
+}
+
+func deserializeTime<D: Deserializer>(
+    deserializer: D
+) throws -> DateComponents {
+    let s = try deserializer.deserialize_str()
+    let parts = s.split(separator: ":")
+    let seconds = parts.count == 3 ? parts[2].split(separator: ".", maxSplits: 1) : []
+    guard parts.count == 3,
+          let hour = Int(parts[0]),
+          let minute = Int(parts[1]),
+          let second = seconds.first.flatMap({ Int($0) }) else {
+        throw DeserializationError.invalidInput(issue: "Invalid time: \(s)")
+    }
+    var nanosecond = 0
+    if seconds.count == 2 {
+        let digits = String(seconds[1].prefix(9))
+        guard let fraction = Int(digits) else {
+            throw DeserializationError.invalidInput(issue: "Invalid time: \(s)")
+        }
+        nanosecond = fraction * Int(pow(10.0, Double(9 - digits.count)))
+    }
+    return DateComponents(hour: hour, minute: minute, second: second, nanosecond: nanosecond)
+}
+
+func serializeDuration<S: Serializer>(
+    value: TimeInterval,
+    serializer: S
+) throws {
+    guard value >= 0 else {
+        throw SerializationError.invalidValue(issue: "Duration must not be negative: \(value)")
+    }
+    let secs = value.rounded(.down)
+    try serializer.increase_container_depth()
+    try serializer.serialize_u64(value: UInt64(secs))
+    try serializer.serialize_u32(value: min(UInt32(((value - secs) * 1_000_000_000).rounded()), 999_999_999))
+    try serializer.decrease_container_depth()
+}
+
+func deserializeDuration<D: Deserializer>(
+    deserializer: D
+) throws -> TimeInterval {
+    try deserializer.increase_container_depth()
+    let secs = try deserializer.deserialize_u64()
+    let nanos = try deserializer.deserialize_u32()
+    try deserializer.decrease_container_depth()
+    return TimeInterval(secs) + TimeInterval(nanos) / 1_000_000_000
+}
+
+public struct StructWithTemporal: Hashable, Equatable {
+    public var createdAt: Date
+    public var birthday: DateComponents?
+    public var alarm: DateComponents
+    public var timeout: TimeInterval
+
+    public init(createdAt: Date, birthday: DateComponents?, alarm: DateComponents, timeout: TimeInterval) {
+        self.createdAt = createdAt
+        self.birthday = birthday
+        self.alarm = alarm
+        self.timeout = timeout
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializeTimestamp(value: self.createdAt, serializer: serializer)
+        try serializeOption(value: self.birthday, serializer: serializer) { value, serializer in
+            try serializeDate(value: value, serializer: serializer)
+        }
+        try serializeTime(value: self.alarm, serializer: serializer)
+        try serializeDuration(value: self.timeout, serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func jsonSerialize() throws -> [UInt8] {
+        let serializer = JsonSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> StructWithTemporal {
+        try deserializer.increase_container_depth()
+        let createdAt = try deserializeTimestamp(deserializer: deserializer)
+        let birthday = try deserializeOption(deserializer: deserializer) { deserializer in
+            try deserializeDate(deserializer: deserializer)
+        }
+        let alarm = try deserializeTime(deserializer: deserializer)
+        let timeout = try deserializeDuration(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return StructWithTemporal(createdAt: createdAt, birthday: birthday, alarm: alarm, timeout: timeout)
+    }
+
+    public static func jsonDeserialize(input: [UInt8]) throws -> StructWithTemporal {
+        let deserializer = JsonDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
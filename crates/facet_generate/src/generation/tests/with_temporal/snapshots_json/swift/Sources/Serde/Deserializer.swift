@@ -0,0 +1,56 @@
+//  Copyright (c) Facebook, Inc. and its affiliates.
+
+import Foundation
+
+public enum DeserializationError: Error {
+    case invalidInput(issue: String)
+}
+
+public protocol Deserializer {
+    func deserialize_str() throws -> String
+    func deserialize_bytes() throws -> [UInt8]
+    func deserialize_bool() throws -> Bool
+    func deserialize_unit() throws
+    func deserialize_char() throws -> Character
+    func deserialize_f32() throws -> Float
+    func deserialize_f64() throws -> Double
+    func deserialize_u8() throws -> UInt8
+    func deserialize_u16() throws -> UInt16
+    func deserialize_u32() throws -> UInt32
+    func deserialize_u64() throws -> UInt64
+    func deserialize_u128() throws -> UInt128
+    func deserialize_i8() throws -> Int8
+    func deserialize_i16() throws -> Int16
+    func deserialize_i32() throws -> Int32
+    func deserialize_i64() throws -> Int64
+    func deserialize_i128() throws -> Int128
+    func deserialize_len() throws -> Int
+    func deserialize_variant_index() throws -> UInt32
+    func deserialize_option_tag() throws -> Bool
+    func get_buffer_offset() -> Int
+    /// Rewinds to an offset returned by `get_buffer_offset`, e.g. to try the
+    /// next variant of an untagged enum.
+    func set_buffer_offset(value: Int)
+    func increase_container_depth() throws
+    func decrease_container_depth() throws
+    /// Whether the object being read has the field `name`, for formats such
+    /// as JSON whose fields can be missing.
+    func has_field(name: String) throws -> Bool
+}
+
+extension Deserializer {
+    /// Binary formats write every field.
+    public func has_field(name: String) throws -> Bool {
+        true
+    }
+
+    /// Reads the field `name` with `deserialize`, or returns `defaultValue`
+    /// if it is missing.
+    public func deserialize_field<T>(
+        name: String,
+        default defaultValue: T,
+        _ deserialize: () throws -> T
+    ) throws -> T {
+        try has_field(name: name) ? deserialize() : defaultValue
+    }
+}
@@ -0,0 +1,62 @@
+/**
+ * Copyright (c) Facebook, Inc. and its affiliates
+ * SPDX-License-Identifier: MIT OR Apache-2.0
+ */
+
+export interface Deserializer {
+  deserializeStr(): string;
+
+  deserializeBytes(): Uint8Array;
+
+  deserializeBool(): boolean;
+
+  deserializeUnit(): null;
+
+  deserializeChar(): string;
+
+  deserializeF32(): number;
+
+  deserializeF64(): number;
+
+  deserializeU8(): number;
+
+  deserializeU16(): number;
+
+  deserializeU32(): number;
+
+  deserializeU64(): bigint;
+
+  deserializeU128(): bigint;
+
+  deserializeI8(): number;
+
+  deserializeI16(): number;
+
+  deserializeI32(): number;
+
+  deserializeI64(): bigint;
+
+  deserializeI128(): bigint;
+
+  deserializeLen(): number;
+
+  deserializeVariantIndex(): number;
+
+  deserializeOptionTag(): boolean;
+
+  getBufferOffset(): number;
+
+  // Rewinds to an offset returned by `getBufferOffset`, e.g. to try the next
+  // variant of an untagged enum.
+  setBufferOffset(offset: number): void;
+
+  // Whether the object being read has the field `name`, for formats such as
+  // JSON whose fields can be missing. Fields with a default value are only
+  // read if it does; binary formats, which write every field, leave it out.
+  hasField?(name: string): boolean;
+
+  checkThatKeySlicesAreIncreasing(
+    key1: [number, number],
+    key2: [number, number],
+  ): void;
+}
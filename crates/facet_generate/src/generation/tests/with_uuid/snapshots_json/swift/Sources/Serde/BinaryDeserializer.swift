@@ -159,4 +159,8 @@ public class BinaryDeserializer: Deserializer {
         return location
     }
 
+    public func set_buffer_offset(value: Int) {
+        location = value
+    }
+
 }
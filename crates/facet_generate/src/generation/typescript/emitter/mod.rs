@@ -9,7 +9,7 @@
 //! | AST node | TypeScript output |
 //! |---|---|
 //! | [`Module`] | `import` statements, type aliases, feature helpers |
-//! | [`Container`] | `export class` or `export abstract class` + variant subclasses |
+//! | [`Container`] | `export class`, `export abstract class` + variant subclasses, or `export type` |
 //! | [`Named<Format>`](Named) | `public` property declaration |
 //! | [`Format`] | Inline type expression (`number`, `string`, `Array<T>`, …) |
 //! | [`Doc`] | `///` doc comments |
@@ -54,9 +54,14 @@ use crate::{
         CodeGeneratorConfig, Container, Emitter, PackageLocation,
         indent::{IndentConfig, IndentWrite, IndentedWriter, Newlines},
         module::Module,
-        plugin::{EmitContext, EmitterPlugin, collect_from_plugins},
+        plugin::{
+            EmitContext, EmitterPlugin, collect_from_plugins, type_annotations, type_conformances,
+        },
+    },
+    reflection::format::{
+        ContainerFormat, DefaultValue, Deprecation, Doc, EnumTagging, FieldMetadata, Format,
+        Language, Named, VariantFormat,
     },
-    reflection::format::{ContainerFormat, Doc, EnumTagging, Format, Named, VariantFormat},
 };
 
 /// Language tag for TypeScript code generation.
@@ -184,16 +189,25 @@ impl Emitter<TypeScript> for Container<'_> {
         let name = &qualified_name.name;
 
         match format {
-            ContainerFormat::UnitStruct(doc) => {
+            ContainerFormat::UnitStruct(doc, _) => {
                 let ctx = EmitContext::top_level(self, &lang.config);
                 output_struct_or_variant(w, &ctx, name, &[], doc, lang)
             }
-            ContainerFormat::NewTypeStruct(format, doc) => {
+            ContainerFormat::NewTypeStruct(format, doc, metadata) if metadata.proxy => {
+                writeln!(w)?;
+                doc.write(w, lang)?;
+                write_deprecated(w, metadata.deprecated.as_ref())?;
+                writeln!(w, "export type {name} = {};", quote_type(format, lang))
+            }
+            ContainerFormat::NewTypeStruct(format, doc, metadata) if metadata.branded => {
+                output_branded_alias(w, self, name, format, doc, lang)
+            }
+            ContainerFormat::NewTypeStruct(format, doc, _) => {
                 let fields = vec![Named::new(format.as_ref(), "value".to_string())];
                 let ctx = EmitContext::top_level(self, &lang.config);
                 output_struct_or_variant(w, &ctx, name, &fields, doc, lang)
             }
-            ContainerFormat::TupleStruct(formats, doc) => {
+            ContainerFormat::TupleStruct(formats, doc, _) => {
                 let fields: Vec<_> = formats
                     .iter()
                     .enumerate()
@@ -202,11 +216,11 @@ impl Emitter<TypeScript> for Container<'_> {
                 let ctx = EmitContext::top_level(self, &lang.config);
                 output_struct_or_variant(w, &ctx, name, &fields, doc, lang)
             }
-            ContainerFormat::Struct(fields, doc) => {
+            ContainerFormat::Struct(fields, doc, _) => {
                 let ctx = EmitContext::top_level(self, &lang.config);
                 output_struct_or_variant(w, &ctx, name, fields, doc, lang)
             }
-            ContainerFormat::Enum(variants, tagging, doc) => {
+            ContainerFormat::Enum(variants, tagging, doc, _) => {
                 output_enum_container(w, self, name, variants, tagging, doc, lang)
             }
         }
@@ -223,6 +237,21 @@ impl Emitter<TypeScript> for Format {
                     type_.format(ToUpperCamelCase::to_upper_camel_case, ".")
                 )
             }
+            Self::Generic { name, args } => {
+                write!(
+                    w,
+                    "{}<",
+                    name.format(ToUpperCamelCase::to_upper_camel_case, ".")
+                )?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(w, ", ")?;
+                    }
+                    arg.write(w, lang)?;
+                }
+                write!(w, ">")
+            }
+            Self::TypeParameter(name) => write!(w, "{name}"),
             Self::Unit => write!(w, "unit"),
             Self::Bool => write!(w, "bool"),
             Self::I8 => write!(w, "int8"),
@@ -241,6 +270,10 @@ impl Emitter<TypeScript> for Format {
             Self::Str => write!(w, "str"),
             Self::Bytes => write!(w, "bytes"),
             Self::Uuid => write!(w, "Uuid"),
+            Self::Timestamp => write!(w, "timestamp"),
+            Self::Date => write!(w, "date"),
+            Self::Time => write!(w, "time"),
+            Self::Duration => write!(w, "duration"),
 
             Self::Option(format) => {
                 write!(w, "Optional<")?;
@@ -281,11 +314,40 @@ impl Emitter<TypeScript> for Format {
 
 impl Emitter<TypeScript> for Named<Format> {
     fn write<W: IndentWrite>(&self, w: &mut W, lang: &TypeScript) -> Result<()> {
-        write!(w, "public {}: ", self.name)?;
-        self.value.write(w, lang)
+        write!(w, "{}{}: ", modifiers(&self.metadata), self.name)?;
+        match self.metadata.type_override(Language::TypeScript) {
+            Some(type_override) => write!(w, "{}", type_override.ty),
+            None => self.value.write(w, lang),
+        }
+    }
+}
+
+/// The modifiers of a class property: always `public` (TypeScript has no `internal`), and
+/// `readonly` for a read-only field.
+const fn modifiers(metadata: &FieldMetadata) -> &'static str {
+    if metadata.readonly {
+        "public readonly "
+    } else {
+        "public "
+    }
+}
+
+/// Writes the `@deprecated` tag of a deprecated declaration on its own line, if it is one.
+fn write_deprecated<W: IndentWrite>(w: &mut W, deprecated: Option<&Deprecation>) -> Result<()> {
+    match deprecated_tag(deprecated) {
+        Some(tag) => writeln!(w, "{tag}"),
+        None => Ok(()),
     }
 }
 
+/// The `/** @deprecated ... */` comment of a deprecated declaration, if it is one.
+fn deprecated_tag(deprecated: Option<&Deprecation>) -> Option<String> {
+    Some(match deprecated?.message() {
+        Some(message) => format!("/** @deprecated {} */", message.replace("*/", "*\\/")),
+        None => "/** @deprecated */".to_string(),
+    })
+}
+
 /// Render a type expression to a string (used for constructor argument types).
 fn quote_type(format: &Format, lang: &TypeScript) -> String {
     let mut buf = Vec::new();
@@ -296,6 +358,61 @@ fn quote_type(format: &Format, lang: &TypeScript) -> String {
     String::from_utf8(buf).expect("type expression should be valid UTF-8")
 }
 
+/// The type of a field: its `fg::override` type for TypeScript, if any, or its format's.
+fn field_type(field: &Named<Format>, lang: &TypeScript) -> String {
+    match field.metadata.type_override(Language::TypeScript) {
+        Some(type_override) => type_override.ty.clone(),
+        None => quote_type(&field.value, lang),
+    }
+}
+
+/// The ` = value` initializer that makes a field's parameter optional, if the field has a
+/// default value that suits its type.
+fn default_initializer(field: &Named<Format>) -> String {
+    if field.metadata.type_override(Language::TypeScript).is_some() {
+        return String::new();
+    }
+    field
+        .metadata
+        .default
+        .as_ref()
+        .and_then(|default| default_value(default, &field.value))
+        .map(|value| format!(" = {value}"))
+        .unwrap_or_default()
+}
+
+/// The fields in the order of a constructor's parameters: the ones without a default first,
+/// since a default only applies when the parameters after it are left out too.
+pub(crate) fn constructor_order(fields: &[Named<Format>]) -> Vec<&Named<Format>> {
+    let (required, defaulted): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .partition(|field| default_initializer(field).is_empty());
+    required.into_iter().chain(defaulted).collect()
+}
+
+/// The TypeScript expression of a field's default value, or `None` if it doesn't suit the
+/// field's type (e.g. because a serialization proxy changed it).
+pub(crate) fn default_value(default: &DefaultValue, format: &Format) -> Option<String> {
+    Some(match (default, format) {
+        (DefaultValue::Empty, Format::Option(_)) => "null".to_string(),
+        (DefaultValue::Empty, Format::Seq(_) | Format::Set(_)) => "[]".to_string(),
+        (DefaultValue::Empty, Format::Map { .. }) => "new Map()".to_string(),
+        (DefaultValue::Empty, Format::Bytes) => "new Uint8Array()".to_string(),
+        (DefaultValue::Bool(value), Format::Bool) => value.to_string(),
+        (DefaultValue::Integer(value), Format::I64 | Format::I128 | Format::U64 | Format::U128) => {
+            format!("{value}n")
+        }
+        (
+            DefaultValue::Integer(value),
+            Format::I8 | Format::I16 | Format::I32 | Format::U8 | Format::U16 | Format::U32,
+        )
+        | (DefaultValue::Float(value), Format::F32 | Format::F64) => value.clone(),
+        (DefaultValue::Char(value), Format::Char) => serde_json::to_string(value).ok()?,
+        (DefaultValue::Str(value), Format::Str) => serde_json::to_string(value).ok()?,
+        _ => return None,
+    })
+}
+
 fn output_struct_or_variant<W: IndentWrite>(
     w: &mut W,
     ctx: &EmitContext<'_>,
@@ -306,14 +423,32 @@ fn output_struct_or_variant<W: IndentWrite>(
 ) -> Result<()> {
     writeln!(w)?;
     doc.write(w, lang)?;
-    write!(w, "export class {name} ")?;
+    write_deprecated(w, ctx.container.format.metadata().deprecated.as_ref())?;
+    for annotation in type_annotations(lang.plugins(), ctx, Language::TypeScript) {
+        writeln!(w, "{annotation}")?;
+    }
+    write!(w, "export class {name}{} ", type_params(ctx.type_params()))?;
+    let conformances = type_conformances(lang.plugins(), ctx, Language::TypeScript);
+    if !conformances.is_empty() {
+        write!(w, "implements {} ", conformances.join(", "))?;
+    }
     let mut w = w.block(Newlines::BOTH)?;
 
-    let args: Vec<String> = fields
-        .iter()
+    let metadata = ctx.container.format.metadata();
+    let args: Vec<String> = constructor_order(fields)
+        .into_iter()
         .map(|f| {
-            let type_str = quote_type(&f.value, lang);
-            format!("public {}: {}", f.name, type_str)
+            let type_str = field_type(f, lang);
+            let modifiers = modifiers(&f.metadata.inherit(metadata));
+            let deprecated = deprecated_tag(f.metadata.deprecated.as_ref())
+                .map(|tag| format!("{tag} "))
+                .unwrap_or_default();
+            format!(
+                "{deprecated}{modifiers}{}: {}{}",
+                f.name,
+                type_str,
+                default_initializer(f)
+            )
         })
         .collect();
     let args = args.join(", ");
@@ -331,7 +466,7 @@ fn output_struct_or_variant<W: IndentWrite>(
 
 fn tag_field_name(tagging: &EnumTagging) -> &str {
     match tagging {
-        EnumTagging::External => "kind",
+        EnumTagging::External | EnumTagging::Untagged => "kind",
         EnumTagging::Internal { tag } | EnumTagging::Adjacent { tag, .. } => tag.as_str(),
     }
 }
@@ -356,7 +491,7 @@ fn write_variant_type_expr<W: std::io::Write>(
                     w,
                     r#"{{ {tag_field}: "{variant_name}"; {content}: {type_str} }}"#
                 )?;
-            } else if is_internal && matches!(inner.as_ref(), Format::TypeName(_)) {
+            } else if is_internal && is_object_type(inner) {
                 write!(w, r#"{{ {tag_field}: "{variant_name}" }} & {type_str}"#)?;
             } else {
                 write!(
@@ -385,13 +520,13 @@ fn write_variant_type_expr<W: std::io::Write>(
             if let Some(content) = content_field {
                 write!(w, r#"{{ {tag_field}: "{variant_name}"; {content}: {{ "#)?;
                 for field in fields {
-                    write!(w, "{}: {}; ", field.name, quote_type(&field.value, lang))?;
+                    write!(w, "{}: {}; ", field.name, field_type(field, lang))?;
                 }
                 write!(w, "}} }}")?;
             } else {
                 write!(w, r#"{{ {tag_field}: "{variant_name}""#)?;
                 for field in fields {
-                    write!(w, "; {}: {}", field.name, quote_type(&field.value, lang))?;
+                    write!(w, "; {}: {}", field.name, field_type(field, lang))?;
                 }
                 write!(w, " }}")?;
             }
@@ -401,10 +536,27 @@ fn write_variant_type_expr<W: std::io::Write>(
     Ok(())
 }
 
+/// Whether a newtype variant's payload is a user-defined object type, whose
+/// fields can be spread alongside an internal tag.
+fn is_object_type(format: &Format) -> bool {
+    matches!(format, Format::TypeName(_) | Format::Generic { .. })
+}
+
+/// Render the type parameter list of a generic type (e.g. `<T, E>`), or an
+/// empty string for a non-generic type.
+fn type_params(params: &[String]) -> String {
+    if params.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", params.join(", "))
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn write_variant_constructor<W: std::io::Write>(
     w: &mut W,
     enum_name: &str,
+    generic_params: &[String],
     variant_name: &str,
     variant: &VariantFormat,
     tag_field: &str,
@@ -427,7 +579,7 @@ fn write_variant_constructor<W: std::io::Write>(
             let type_str = quote_type(inner, lang);
             let obj = if let Some(content) = content_field {
                 format!(r#"{{ {tag_field}: "{variant_name}", {content}: value }}"#)
-            } else if is_internal && matches!(inner.as_ref(), Format::TypeName(_)) {
+            } else if is_internal && is_object_type(inner) {
                 format!(r#"{{ {tag_field}: "{variant_name}", ...value }}"#)
             } else {
                 format!(r#"{{ {tag_field}: "{variant_name}", value }}"#)
@@ -456,9 +608,16 @@ fn write_variant_constructor<W: std::io::Write>(
             (params.join(", "), obj)
         }
         VariantFormat::Struct(fields) => {
-            let params: Vec<String> = fields
-                .iter()
-                .map(|f| format!("{}: {}", f.name, quote_type(&f.value, lang)))
+            let params: Vec<String> = constructor_order(fields)
+                .into_iter()
+                .map(|f| {
+                    format!(
+                        "{}: {}{}",
+                        f.name,
+                        field_type(f, lang),
+                        default_initializer(f)
+                    )
+                })
                 .collect();
             let field_names: Vec<&str> = fields.iter().map(|f| f.name.as_str()).collect();
             let obj = if let Some(content) = content_field {
@@ -477,9 +636,10 @@ fn write_variant_constructor<W: std::io::Write>(
         VariantFormat::Variable(_) => panic!("unexpected variable format"),
     };
 
+    let generics = type_params(generic_params);
     writeln!(
         w,
-        "export const {fn_name} = ({params_str}): {enum_name} => ({object_str});"
+        "export const {fn_name} = {generics}({params_str}): {enum_name}{generics} => ({object_str});"
     )?;
     Ok(())
 }
@@ -506,26 +666,70 @@ fn js_property_key(s: &str) -> String {
 fn write_match_function<W: std::io::Write>(
     w: &mut W,
     name: &str,
+    generic_params: &[String],
     variants: &BTreeMap<u32, Named<VariantFormat>>,
     tag_field: &str,
 ) -> Result<()> {
-    writeln!(w, "export function match{name}<R>(value: {name}, cases: {{")?;
+    let fn_params = generic_params
+        .iter()
+        .map(String::as_str)
+        .chain(std::iter::once("R"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let ty = format!("{name}{}", type_params(generic_params));
+    writeln!(
+        w,
+        "export function match{name}<{fn_params}>(value: {ty}, cases: {{"
+    )?;
     for variant in variants.values() {
         let vname = &variant.name;
         let key = js_property_key(vname);
         writeln!(
             w,
-            r#"    {key}: (v: Extract<{name}, {{ {tag_field}: "{vname}" }}>) => R;"#
+            r#"    {key}: (v: Extract<{ty}, {{ {tag_field}: "{vname}" }}>) => R;"#
         )?;
     }
     writeln!(w, "}}): R {{")?;
     writeln!(
         w,
-        r#"    return cases[value.{tag_field} as {name}["{tag_field}"]](value as never);"#
+        r#"    return cases[value.{tag_field} as {ty}["{tag_field}"]](value as never);"#
     )?;
     writeln!(w, "}}")
 }
 
+/// Emits a branded newtype as an intersection of its inner type with a phantom
+/// `__brand` property, e.g. `export type UserId = str & { readonly __brand: "UserId" };`.
+///
+/// The value is its inner value at runtime, so it has no methods: plugins emit
+/// standalone `serialize{Name}` / `deserialize{Name}` functions in `after_type`.
+/// An optional inner type is branded inside the `Optional<…>`, so that `null` stays
+/// assignable.
+fn output_branded_alias<W: IndentWrite>(
+    w: &mut W,
+    container: &Container<'_>,
+    name: &str,
+    format: &Format,
+    doc: &Doc,
+    lang: &TypeScript,
+) -> Result<()> {
+    writeln!(w)?;
+    doc.write(w, lang)?;
+    write_deprecated(w, container.format.metadata().deprecated.as_ref())?;
+    let generics = type_params(container.format.type_params());
+    let brand = format!(r#"{{ readonly __brand: "{name}" }}"#);
+    let type_str = match format {
+        Format::Option(inner) => format!("Optional<{} & {brand}>", quote_type(inner, lang)),
+        _ => format!("{} & {brand}", quote_type(format, lang)),
+    };
+    writeln!(w, "export type {name}{generics} = {type_str};")?;
+
+    let ctx = EmitContext::top_level(container, &lang.config);
+    for plugin in lang.plugins() {
+        plugin.after_type(w as &mut dyn IndentWrite, &ctx)?;
+    }
+    Ok(())
+}
+
 fn output_enum_container<W: IndentWrite>(
     w: &mut W,
     container: &Container<'_>,
@@ -544,9 +748,12 @@ fn output_enum_container<W: IndentWrite>(
 
     writeln!(w)?;
     doc.write(w, lang)?;
+    write_deprecated(w, container.format.metadata().deprecated.as_ref())?;
+
+    let generic_params = container.format.type_params();
 
     // 1. Union type alias
-    write!(w, "export type {name} =")?;
+    write!(w, "export type {name}{} =", type_params(generic_params))?;
     for variant in variants.values() {
         writeln!(w)?;
         write!(w, "    | ")?;
@@ -562,12 +769,15 @@ fn output_enum_container<W: IndentWrite>(
     }
     writeln!(w, ";")?;
 
-    // 2. Constructor helpers
+    // 2. Constructor helpers. A union member can't be marked deprecated, so the
+    // constructor of a deprecated variant is.
     for variant in variants.values() {
         writeln!(w)?;
+        write_deprecated(w, variant.metadata.deprecated.as_ref())?;
         write_variant_constructor(
             w,
             name,
+            generic_params,
             &variant.name,
             &variant.value,
             tag_field,
@@ -579,7 +789,7 @@ fn output_enum_container<W: IndentWrite>(
 
     // 3. Match function
     writeln!(w)?;
-    write_match_function(w, name, variants, tag_field)?;
+    write_match_function(w, name, generic_params, variants, tag_field)?;
 
     // 4. Plugin after_type hook (for standalone serialize/deserialize functions)
     let ctx = EmitContext::top_level(container, &lang.config);
@@ -590,7 +800,7 @@ fn output_enum_container<W: IndentWrite>(
     Ok(())
 }
 
-const TYPE_ALIASES: [(&str, &str); 21] = [
+const TYPE_ALIASES: [(&str, &str); 25] = [
     ("unit", "type unit = null;"),
     ("bool", "type bool = boolean;"),
     ("int8", "type int8 = number;"),
@@ -608,6 +818,13 @@ const TYPE_ALIASES: [(&str, &str); 21] = [
     ("char", "type char = string;"),
     ("str", "type str = string;"),
     ("bytes", "type bytes = Uint8Array;"),
+    ("timestamp", "type timestamp = Date;"),
+    ("date", "type date = string;"),
+    ("time", "type time = string;"),
+    (
+        "duration",
+        "type duration = { secs: bigint; nanos: number };",
+    ),
     ("option", "type Optional<T> = T | null;"),
     ("seq", "type Seq<T> = T[];"),
     ("tuple", "type Tuple<T extends any[]> = T;"),
@@ -865,3 +865,181 @@ fn type_in_root_and_named_namespace() {
     }
     "#);
 }
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as TypeScript).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+
+    export class Listing {
+        constructor (public names: Page<str>, public ids: Page<uint32>) {
+        }
+    }
+
+
+    export class Page<T> {
+        constructor (public items: Seq<T>, public next: Optional<T>, public total: uint32) {
+        }
+    }
+    ");
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E, retry: bool },
+        Pending,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        first: Outcome<String, i32>,
+        second: Outcome<bool, String>,
+    }
+
+    let actual = emit!(Response as TypeScript).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+
+    export type Outcome<T, E> =
+        | { kind: "Success"; value: T }
+        | { kind: "Failure"; error: E; retry: bool }
+        | { kind: "Pending" };
+
+    export const outcomeSuccess = <T, E>(value: T): Outcome<T, E> => ({ kind: "Success", value });
+
+    export const outcomeFailure = <T, E>(error: E, retry: bool): Outcome<T, E> => ({ kind: "Failure", error, retry });
+
+    export const outcomePending = <T, E>(): Outcome<T, E> => ({ kind: "Pending" });
+
+    export function matchOutcome<T, E, R>(value: Outcome<T, E>, cases: {
+        Success: (v: Extract<Outcome<T, E>, { kind: "Success" }>) => R;
+        Failure: (v: Extract<Outcome<T, E>, { kind: "Failure" }>) => R;
+        Pending: (v: Extract<Outcome<T, E>, { kind: "Pending" }>) => R;
+    }): R {
+        return cases[value.kind as Outcome<T, E>["kind"]](value as never);
+    }
+
+
+    export class Response {
+        constructor (public first: Outcome<str, int32>, public second: Outcome<bool, str>) {
+        }
+    }
+    "#);
+}
+
+#[test]
+fn defaults() {
+    #[derive(Facet)]
+    struct Settings {
+        #[facet(default = 30)]
+        timeout: u64,
+        #[facet(default = 'x')]
+        marker: char,
+        #[facet(default)]
+        counts: HashMap<String, u32>,
+        retries: u32,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Command {
+        Move {
+            x: i32,
+            #[facet(default)]
+            speed: Option<u32>,
+        },
+        Stop,
+    }
+
+    let actual = emit!(Settings, Command as TypeScript).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+
+    export type Command =
+        | { kind: "Move"; x: int32; speed: Optional<uint32> }
+        | { kind: "Stop" };
+
+    export const commandMove = (x: int32, speed: Optional<uint32> = null): Command => ({ kind: "Move", x, speed });
+
+    export const commandStop = (): Command => ({ kind: "Stop" });
+
+    export function matchCommand<R>(value: Command, cases: {
+        Move: (v: Extract<Command, { kind: "Move" }>) => R;
+        Stop: (v: Extract<Command, { kind: "Stop" }>) => R;
+    }): R {
+        return cases[value.kind as Command["kind"]](value as never);
+    }
+
+
+    export class Settings {
+        constructor (public retries: uint32, public timeout: uint64 = 30n, public marker: char = "x", public counts: Map<str,uint32> = new Map()) {
+        }
+    }
+    "#);
+}
+
+#[test]
+fn required_fields_come_before_defaulted_ones_in_constructors() {
+    #[derive(Facet)]
+    struct Page {
+        #[facet(default = 1)]
+        number: u32,
+        title: String,
+        #[facet(default)]
+        tags: Vec<String>,
+        id: u64,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Command {
+        Move {
+            #[facet(default)]
+            speed: Option<u32>,
+            x: i32,
+        },
+    }
+
+    let actual = emit!(Page, Command as TypeScript).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+
+    export type Command =
+        | { kind: "Move"; speed: Optional<uint32>; x: int32 };
+
+    export const commandMove = (x: int32, speed: Optional<uint32> = null): Command => ({ kind: "Move", speed, x });
+
+    export function matchCommand<R>(value: Command, cases: {
+        Move: (v: Extract<Command, { kind: "Move" }>) => R;
+    }): R {
+        return cases[value.kind as Command["kind"]](value as never);
+    }
+
+
+    export class Page {
+        constructor (public title: str, public id: uint64, public number: uint32 = 1, public tags: Seq<str> = []) {
+        }
+    }
+    "#);
+}
@@ -95,6 +95,56 @@ fn newtype_struct() {
     ");
 }
 
+#[test]
+fn branded_newtype_struct() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    #[derive(Facet)]
+    struct User {
+        id: UserId,
+        manager: Option<UserId>,
+    }
+
+    let actual = emit!(User as TypeScript with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+
+    export class User {
+        constructor (public id: UserId, public manager: Optional<UserId>) {
+        }
+
+        public serialize(serializer: Serializer): void {
+            serializeUserId(this.id, serializer);
+            serializeOption(this.manager, serializer, (value, serializer) => {
+                serializeUserId(value, serializer);
+            });
+        }
+
+        static deserialize(deserializer: Deserializer): User {
+            const id = deserializeUserId(deserializer);
+            const manager = deserializeOption(deserializer, (deserializer) => {
+                return deserializeUserId(deserializer);
+            });
+            return new User(id,manager);
+        }
+    }
+
+
+    export type UserId = str & { readonly __brand: "UserId" };
+
+    export function serializeUserId(value: UserId, serializer: Serializer): void {
+        serializer.serializeStr(value);
+    }
+
+    export function deserializeUserId(deserializer: Deserializer): UserId {
+        const value = deserializer.deserializeStr();
+        return value as UserId;
+    }
+    "#);
+}
+
 #[test]
 fn tuple_struct() {
     #[derive(Facet)]
@@ -1631,3 +1681,450 @@ fn struct_with_bytes_field_and_slice() {
     }
     ");
 }
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as TypeScript with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+
+    export class Listing {
+        constructor (public names: Page<str>, public ids: Page<uint32>) {
+        }
+
+        public serialize(serializer: Serializer): void {
+            this.names.serialize(serializer, (value, serializer) => {
+                serializer.serializeStr(value);
+            });
+            this.ids.serialize(serializer, (value, serializer) => {
+                serializer.serializeU32(value);
+            });
+        }
+
+        static deserialize(deserializer: Deserializer): Listing {
+            const names = Page.deserialize(deserializer, (deserializer) => {
+                return deserializer.deserializeStr();
+            });
+            const ids = Page.deserialize(deserializer, (deserializer) => {
+                return deserializer.deserializeU32();
+            });
+            return new Listing(names,ids);
+        }
+    }
+
+
+    export class Page<T> {
+        constructor (public items: Seq<T>, public next: Optional<T>, public total: uint32) {
+        }
+
+        public serialize(serializer: Serializer, serializeT: (value: T, serializer: Serializer) => void): void {
+            serializeArray(this.items, serializer, (item, serializer) => {
+                serializeT(item, serializer);
+            });
+            serializeOption(this.next, serializer, (value, serializer) => {
+                serializeT(value, serializer);
+            });
+            serializer.serializeU32(this.total);
+        }
+
+        static deserialize<T>(deserializer: Deserializer, deserializeT: (deserializer: Deserializer) => T): Page<T> {
+            const items = deserializeArray(deserializer, (deserializer) => {
+                return deserializeT(deserializer);
+            });
+            const next = deserializeOption(deserializer, (deserializer) => {
+                return deserializeT(deserializer);
+            });
+            const total = deserializer.deserializeU32();
+            return new Page(items,next,total);
+        }
+    }
+    ");
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E, retry: bool },
+        Pending,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        first: Outcome<String, i32>,
+        second: Outcome<bool, String>,
+    }
+
+    let actual = emit!(Response as TypeScript with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+
+    export type Outcome<T, E> =
+        | { kind: "Success"; value: T }
+        | { kind: "Failure"; error: E; retry: bool }
+        | { kind: "Pending" };
+
+    export const outcomeSuccess = <T, E>(value: T): Outcome<T, E> => ({ kind: "Success", value });
+
+    export const outcomeFailure = <T, E>(error: E, retry: bool): Outcome<T, E> => ({ kind: "Failure", error, retry });
+
+    export const outcomePending = <T, E>(): Outcome<T, E> => ({ kind: "Pending" });
+
+    export function matchOutcome<T, E, R>(value: Outcome<T, E>, cases: {
+        Success: (v: Extract<Outcome<T, E>, { kind: "Success" }>) => R;
+        Failure: (v: Extract<Outcome<T, E>, { kind: "Failure" }>) => R;
+        Pending: (v: Extract<Outcome<T, E>, { kind: "Pending" }>) => R;
+    }): R {
+        return cases[value.kind as Outcome<T, E>["kind"]](value as never);
+    }
+
+    export function serializeOutcome<T, E>(value: Outcome<T, E>, serializer: Serializer, serializeT: (value: T, serializer: Serializer) => void, serializeE: (value: E, serializer: Serializer) => void): void {
+        switch (value.kind) {
+            case "Success": {
+                serializer.serializeVariantIndex(0);
+                serializeT(value.value, serializer);
+                break;
+            }
+            case "Failure": {
+                serializer.serializeVariantIndex(1);
+                serializeE(value.error, serializer);
+                serializer.serializeBool(value.retry);
+                break;
+            }
+            case "Pending": {
+                serializer.serializeVariantIndex(2);
+                break;
+            }
+            default: throw new Error("Unknown variant: " + (value as any).kind);
+        }
+    }
+
+    export function deserializeOutcome<T, E>(deserializer: Deserializer, deserializeT: (deserializer: Deserializer) => T, deserializeE: (deserializer: Deserializer) => E): Outcome<T, E> {
+        const index = deserializer.deserializeVariantIndex();
+        switch (index) {
+            case 0: {
+                const value = deserializeT(deserializer);
+                return { kind: "Success", value };
+            }
+            case 1: {
+                const error = deserializeE(deserializer);
+                const retry = deserializer.deserializeBool();
+                return { kind: "Failure", error, retry };
+            }
+            case 2: {
+                return { kind: "Pending" };
+            }
+            default: throw new Error("Unknown variant index for Outcome: " + index);
+        }
+    }
+
+
+    export class Response {
+        constructor (public first: Outcome<str, int32>, public second: Outcome<bool, str>) {
+        }
+
+        public serialize(serializer: Serializer): void {
+            serializeOutcome(this.first, serializer, (value, serializer) => {
+                serializer.serializeStr(value);
+            }, (value, serializer) => {
+                serializer.serializeI32(value);
+            });
+            serializeOutcome(this.second, serializer, (value, serializer) => {
+                serializer.serializeBool(value);
+            }, (value, serializer) => {
+                serializer.serializeStr(value);
+            });
+        }
+
+        static deserialize(deserializer: Deserializer): Response {
+            const first = deserializeOutcome(deserializer, (deserializer) => {
+                return deserializer.deserializeStr();
+            }, (deserializer) => {
+                return deserializer.deserializeI32();
+            });
+            const second = deserializeOutcome(deserializer, (deserializer) => {
+                return deserializer.deserializeBool();
+            }, (deserializer) => {
+                return deserializer.deserializeStr();
+            });
+            return new Response(first,second);
+        }
+    }
+    "#);
+}
+
+#[test]
+fn serialized_as_proxy() {
+    #[derive(Facet)]
+    #[facet(fg::serialized_as = "String")]
+    struct ItemId {
+        inner: i64,
+    }
+
+    #[derive(Facet)]
+    struct Item {
+        id: ItemId,
+        #[facet(fg::serialized_as = "(u32, u32)")]
+        version: String,
+    }
+
+    let actual = emit!(Item as TypeScript with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+
+    export class Item {
+        constructor (public id: str, public version: Tuple<[uint32, uint32]>) {
+        }
+
+        public serialize(serializer: Serializer): void {
+            serializer.serializeStr(this.id);
+            serializer.serializeU32(this.version[0]);
+            serializer.serializeU32(this.version[1]);
+        }
+
+        static deserialize(deserializer: Deserializer): Item {
+            const id = deserializer.deserializeStr();
+            const field0 = deserializer.deserializeU32();
+            const field1 = deserializer.deserializeU32();
+            const version = [field0, field1] as [uint32, uint32];
+            return new Item(id,version);
+        }
+    }
+
+
+    export type ItemId = str;
+    ");
+}
+
+#[test]
+fn type_override() {
+    #[derive(Facet)]
+    struct Account {
+        #[facet(fg::override(
+            lang = "typescript",
+            ty = "bigint",
+            serialize = "serializer.serializeStr({value}.toString());",
+            deserialize = "BigInt(deserializer.deserializeStr())"
+        ))]
+        balance: String,
+    }
+
+    let actual = emit!(Account as TypeScript with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+
+    export class Account {
+        constructor (public balance: bigint) {
+        }
+
+        public serialize(serializer: Serializer): void {
+            serializer.serializeStr(this.balance.toString());
+        }
+
+        static deserialize(deserializer: Deserializer): Account {
+            const balance = BigInt(deserializer.deserializeStr());
+            return new Account(balance);
+        }
+    }
+    ");
+}
+
+#[test]
+fn conformances_and_annotations() {
+    #[derive(Facet)]
+    #[facet(
+        fg::annotate(lang = "typescript", with = "@sealed"),
+        fg::conforms(lang = "typescript", to = "Countable, Comparable<Counter>")
+    )]
+    struct Counter {
+        count: u32,
+    }
+
+    let actual = emit!(Counter as TypeScript with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+
+    @sealed
+    export class Counter implements Countable, Comparable<Counter> {
+        constructor (public count: uint32) {
+        }
+
+        public serialize(serializer: Serializer): void {
+            serializer.serializeU32(this.count);
+        }
+
+        static deserialize(deserializer: Deserializer): Counter {
+            const count = deserializer.deserializeU32();
+            return new Counter(count);
+        }
+    }
+    ");
+}
+
+#[test]
+fn enum_with_pinned_indices() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Status {
+        Active,
+        #[facet(fg::index = 3)]
+        Suspended(String),
+        Closed,
+    }
+
+    let actual = emit!(Status as TypeScript with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+
+    export type Status =
+        | { kind: "Active" }
+        | { kind: "Suspended"; value: str }
+        | { kind: "Closed" };
+
+    export const statusActive = (): Status => ({ kind: "Active" });
+
+    export const statusSuspended = (value: str): Status => ({ kind: "Suspended", value });
+
+    export const statusClosed = (): Status => ({ kind: "Closed" });
+
+    export function matchStatus<R>(value: Status, cases: {
+        Active: (v: Extract<Status, { kind: "Active" }>) => R;
+        Suspended: (v: Extract<Status, { kind: "Suspended" }>) => R;
+        Closed: (v: Extract<Status, { kind: "Closed" }>) => R;
+    }): R {
+        return cases[value.kind as Status["kind"]](value as never);
+    }
+
+    export function serializeStatus(value: Status, serializer: Serializer): void {
+        switch (value.kind) {
+            case "Active": {
+                serializer.serializeVariantIndex(0);
+                break;
+            }
+            case "Suspended": {
+                serializer.serializeVariantIndex(3);
+                serializer.serializeStr(value.value);
+                break;
+            }
+            case "Closed": {
+                serializer.serializeVariantIndex(4);
+                break;
+            }
+            default: throw new Error("Unknown variant: " + (value as any).kind);
+        }
+    }
+
+    export function deserializeStatus(deserializer: Deserializer): Status {
+        const index = deserializer.deserializeVariantIndex();
+        switch (index) {
+            case 0: {
+                return { kind: "Active" };
+            }
+            case 3: {
+                const value = deserializer.deserializeStr();
+                return { kind: "Suspended", value };
+            }
+            case 4: {
+                return { kind: "Closed" };
+            }
+            default: throw new Error("Unknown variant index for Status: " + index);
+        }
+    }
+    "#);
+}
+
+#[test]
+fn enum_with_other_variant() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Created(String),
+        Deleted {
+            id: u64,
+        },
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Event as TypeScript with BincodePlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+
+    export type Event =
+        | { kind: "Created"; value: str }
+        | { kind: "Deleted"; id: uint64 }
+        | { kind: "Unknown" };
+
+    export const eventCreated = (value: str): Event => ({ kind: "Created", value });
+
+    export const eventDeleted = (id: uint64): Event => ({ kind: "Deleted", id });
+
+    export const eventUnknown = (): Event => ({ kind: "Unknown" });
+
+    export function matchEvent<R>(value: Event, cases: {
+        Created: (v: Extract<Event, { kind: "Created" }>) => R;
+        Deleted: (v: Extract<Event, { kind: "Deleted" }>) => R;
+        Unknown: (v: Extract<Event, { kind: "Unknown" }>) => R;
+    }): R {
+        return cases[value.kind as Event["kind"]](value as never);
+    }
+
+    export function serializeEvent(value: Event, serializer: Serializer): void {
+        switch (value.kind) {
+            case "Created": {
+                serializer.serializeVariantIndex(0);
+                serializer.serializeStr(value.value);
+                break;
+            }
+            case "Deleted": {
+                serializer.serializeVariantIndex(1);
+                serializer.serializeU64(value.id);
+                break;
+            }
+            case "Unknown": {
+                serializer.serializeVariantIndex(2);
+                break;
+            }
+            default: throw new Error("Unknown variant: " + (value as any).kind);
+        }
+    }
+
+    export function deserializeEvent(deserializer: Deserializer): Event {
+        const index = deserializer.deserializeVariantIndex();
+        switch (index) {
+            case 0: {
+                const value = deserializer.deserializeStr();
+                return { kind: "Created", value };
+            }
+            case 1: {
+                const id = deserializer.deserializeU64();
+                return { kind: "Deleted", id };
+            }
+            case 2: {
+                return { kind: "Unknown" };
+            }
+            default: {
+                return { kind: "Unknown" };
+            }
+        }
+    }
+    "#);
+}
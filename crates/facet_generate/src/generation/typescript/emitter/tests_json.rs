@@ -94,6 +94,56 @@ fn newtype_struct() {
     ");
 }
 
+#[test]
+fn branded_newtype_struct() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    #[derive(Facet)]
+    struct User {
+        id: UserId,
+        manager: Option<UserId>,
+    }
+
+    let actual = emit!(User as TypeScript with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+
+    export class User {
+        constructor (public id: UserId, public manager: Optional<UserId>) {
+        }
+
+        public serialize(serializer: Serializer): void {
+            serializeUserId(this.id, serializer);
+            serializeOption(this.manager, serializer, (value, serializer) => {
+                serializeUserId(value, serializer);
+            });
+        }
+
+        static deserialize(deserializer: Deserializer): User {
+            const id = deserializeUserId(deserializer);
+            const manager = deserializeOption(deserializer, (deserializer) => {
+                return deserializeUserId(deserializer);
+            });
+            return new User(id,manager);
+        }
+    }
+
+
+    export type UserId = str & { readonly __brand: "UserId" };
+
+    export function serializeUserId(value: UserId, serializer: Serializer): void {
+        serializer.serializeStr(value);
+    }
+
+    export function deserializeUserId(deserializer: Deserializer): UserId {
+        const value = deserializer.deserializeStr();
+        return value as UserId;
+    }
+    "#);
+}
+
 #[test]
 fn tuple_struct() {
     #[derive(Facet)]
@@ -815,6 +865,186 @@ fn enum_with_mixed_variants() {
     "#);
 }
 
+#[test]
+fn enum_with_other_variant() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Created(String),
+        Deleted {
+            id: u64,
+        },
+        #[facet(other)]
+        Unknown,
+    }
+
+    let actual = emit!(Event as TypeScript with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+
+    export type Event =
+        | { kind: "Created"; value: str }
+        | { kind: "Deleted"; id: uint64 }
+        | { kind: "Unknown" };
+
+    export const eventCreated = (value: str): Event => ({ kind: "Created", value });
+
+    export const eventDeleted = (id: uint64): Event => ({ kind: "Deleted", id });
+
+    export const eventUnknown = (): Event => ({ kind: "Unknown" });
+
+    export function matchEvent<R>(value: Event, cases: {
+        Created: (v: Extract<Event, { kind: "Created" }>) => R;
+        Deleted: (v: Extract<Event, { kind: "Deleted" }>) => R;
+        Unknown: (v: Extract<Event, { kind: "Unknown" }>) => R;
+    }): R {
+        return cases[value.kind as Event["kind"]](value as never);
+    }
+
+    export function serializeEvent(value: Event, serializer: Serializer): void {
+        switch (value.kind) {
+            case "Created": {
+                serializer.serializeVariantIndex(0);
+                serializer.serializeStr(value.value);
+                break;
+            }
+            case "Deleted": {
+                serializer.serializeVariantIndex(1);
+                serializer.serializeU64(value.id);
+                break;
+            }
+            case "Unknown": {
+                serializer.serializeVariantIndex(2);
+                break;
+            }
+            default: throw new Error("Unknown variant: " + (value as any).kind);
+        }
+    }
+
+    export function deserializeEvent(deserializer: Deserializer): Event {
+        const index = deserializer.deserializeVariantIndex();
+        switch (index) {
+            case 0: {
+                const value = deserializer.deserializeStr();
+                return { kind: "Created", value };
+            }
+            case 1: {
+                const id = deserializer.deserializeU64();
+                return { kind: "Deleted", id };
+            }
+            case 2: {
+                return { kind: "Unknown" };
+            }
+            default: {
+                return { kind: "Unknown" };
+            }
+        }
+    }
+    "#);
+}
+
+#[test]
+fn untagged_enum() {
+    #[derive(Facet)]
+    #[facet(untagged)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Value {
+        Number(i32),
+        Point(i32, i32),
+        Named { name: String, alias: Option<String> },
+        Nothing,
+    }
+
+    let actual = emit!(Value as TypeScript with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+
+    export type Value =
+        | { kind: "Number"; value: int32 }
+        | { kind: "Point"; field0: int32; field1: int32 }
+        | { kind: "Named"; name: str; alias: Optional<str> }
+        | { kind: "Nothing" };
+
+    export const valueNumber = (value: int32): Value => ({ kind: "Number", value });
+
+    export const valuePoint = (field0: int32, field1: int32): Value => ({ kind: "Point", field0, field1 });
+
+    export const valueNamed = (name: str, alias: Optional<str>): Value => ({ kind: "Named", name, alias });
+
+    export const valueNothing = (): Value => ({ kind: "Nothing" });
+
+    export function matchValue<R>(value: Value, cases: {
+        Number: (v: Extract<Value, { kind: "Number" }>) => R;
+        Point: (v: Extract<Value, { kind: "Point" }>) => R;
+        Named: (v: Extract<Value, { kind: "Named" }>) => R;
+        Nothing: (v: Extract<Value, { kind: "Nothing" }>) => R;
+    }): R {
+        return cases[value.kind as Value["kind"]](value as never);
+    }
+
+    export function serializeValue(value: Value, serializer: Serializer): void {
+        switch (value.kind) {
+            case "Number": {
+                serializer.serializeI32(value.value);
+                break;
+            }
+            case "Point": {
+                serializer.serializeI32(value.field0);
+                serializer.serializeI32(value.field1);
+                break;
+            }
+            case "Named": {
+                serializer.serializeStr(value.name);
+                serializeOption(value.alias, serializer, (value, serializer) => {
+                    serializer.serializeStr(value);
+                });
+                break;
+            }
+            case "Nothing": {
+                serializer.serializeUnit(null);
+                break;
+            }
+            default: throw new Error("Unknown variant: " + (value as any).kind);
+        }
+    }
+
+    export function deserializeValue(deserializer: Deserializer): Value {
+        const offset = deserializer.getBufferOffset();
+        try {
+            const value = deserializer.deserializeI32();
+            return { kind: "Number", value };
+        } catch {
+            deserializer.setBufferOffset(offset);
+        }
+        try {
+            const field0 = deserializer.deserializeI32();
+            const field1 = deserializer.deserializeI32();
+            return { kind: "Point", field0, field1 };
+        } catch {
+            deserializer.setBufferOffset(offset);
+        }
+        try {
+            const name = deserializer.deserializeStr();
+            const alias = deserializeOption(deserializer, (deserializer) => {
+                return deserializer.deserializeStr();
+            });
+            return { kind: "Named", name, alias };
+        } catch {
+            deserializer.setBufferOffset(offset);
+        }
+        try {
+            deserializer.deserializeUnit();
+            return { kind: "Nothing" };
+        } catch {
+            deserializer.setBufferOffset(offset);
+        }
+        throw new Error("No variant of Value matches the input");
+    }
+    "#);
+}
+
 #[test]
 fn struct_with_vec_field() {
     #[derive(Facet)]
@@ -1462,3 +1692,280 @@ fn struct_with_bytes_field_and_slice() {
     }
     ");
 }
+
+#[test]
+fn generic_struct() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let actual = emit!(Listing as TypeScript with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @"
+
+
+    export class Listing {
+        constructor (public names: Page<str>, public ids: Page<uint32>) {
+        }
+
+        public serialize(serializer: Serializer): void {
+            this.names.serialize(serializer, (value, serializer) => {
+                serializer.serializeStr(value);
+            });
+            this.ids.serialize(serializer, (value, serializer) => {
+                serializer.serializeU32(value);
+            });
+        }
+
+        static deserialize(deserializer: Deserializer): Listing {
+            const names = Page.deserialize(deserializer, (deserializer) => {
+                return deserializer.deserializeStr();
+            });
+            const ids = Page.deserialize(deserializer, (deserializer) => {
+                return deserializer.deserializeU32();
+            });
+            return new Listing(names,ids);
+        }
+    }
+
+
+    export class Page<T> {
+        constructor (public items: Seq<T>, public next: Optional<T>, public total: uint32) {
+        }
+
+        public serialize(serializer: Serializer, serializeT: (value: T, serializer: Serializer) => void): void {
+            serializeArray(this.items, serializer, (item, serializer) => {
+                serializeT(item, serializer);
+            });
+            serializeOption(this.next, serializer, (value, serializer) => {
+                serializeT(value, serializer);
+            });
+            serializer.serializeU32(this.total);
+        }
+
+        static deserialize<T>(deserializer: Deserializer, deserializeT: (deserializer: Deserializer) => T): Page<T> {
+            const items = deserializeArray(deserializer, (deserializer) => {
+                return deserializeT(deserializer);
+            });
+            const next = deserializeOption(deserializer, (deserializer) => {
+                return deserializeT(deserializer);
+            });
+            const total = deserializer.deserializeU32();
+            return new Page(items,next,total);
+        }
+    }
+    ");
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Outcome<T, E> {
+        Success(T),
+        Failure { error: E, retry: bool },
+        Pending,
+    }
+
+    #[derive(Facet)]
+    struct Response {
+        first: Outcome<String, i32>,
+        second: Outcome<bool, String>,
+    }
+
+    let actual = emit!(Response as TypeScript with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+
+    export type Outcome<T, E> =
+        | { kind: "Success"; value: T }
+        | { kind: "Failure"; error: E; retry: bool }
+        | { kind: "Pending" };
+
+    export const outcomeSuccess = <T, E>(value: T): Outcome<T, E> => ({ kind: "Success", value });
+
+    export const outcomeFailure = <T, E>(error: E, retry: bool): Outcome<T, E> => ({ kind: "Failure", error, retry });
+
+    export const outcomePending = <T, E>(): Outcome<T, E> => ({ kind: "Pending" });
+
+    export function matchOutcome<T, E, R>(value: Outcome<T, E>, cases: {
+        Success: (v: Extract<Outcome<T, E>, { kind: "Success" }>) => R;
+        Failure: (v: Extract<Outcome<T, E>, { kind: "Failure" }>) => R;
+        Pending: (v: Extract<Outcome<T, E>, { kind: "Pending" }>) => R;
+    }): R {
+        return cases[value.kind as Outcome<T, E>["kind"]](value as never);
+    }
+
+    export function serializeOutcome<T, E>(value: Outcome<T, E>, serializer: Serializer, serializeT: (value: T, serializer: Serializer) => void, serializeE: (value: E, serializer: Serializer) => void): void {
+        switch (value.kind) {
+            case "Success": {
+                serializer.serializeVariantIndex(0);
+                serializeT(value.value, serializer);
+                break;
+            }
+            case "Failure": {
+                serializer.serializeVariantIndex(1);
+                serializeE(value.error, serializer);
+                serializer.serializeBool(value.retry);
+                break;
+            }
+            case "Pending": {
+                serializer.serializeVariantIndex(2);
+                break;
+            }
+            default: throw new Error("Unknown variant: " + (value as any).kind);
+        }
+    }
+
+    export function deserializeOutcome<T, E>(deserializer: Deserializer, deserializeT: (deserializer: Deserializer) => T, deserializeE: (deserializer: Deserializer) => E): Outcome<T, E> {
+        const index = deserializer.deserializeVariantIndex();
+        switch (index) {
+            case 0: {
+                const value = deserializeT(deserializer);
+                return { kind: "Success", value };
+            }
+            case 1: {
+                const error = deserializeE(deserializer);
+                const retry = deserializer.deserializeBool();
+                return { kind: "Failure", error, retry };
+            }
+            case 2: {
+                return { kind: "Pending" };
+            }
+            default: throw new Error("Unknown variant index for Outcome: " + index);
+        }
+    }
+
+
+    export class Response {
+        constructor (public first: Outcome<str, int32>, public second: Outcome<bool, str>) {
+        }
+
+        public serialize(serializer: Serializer): void {
+            serializeOutcome(this.first, serializer, (value, serializer) => {
+                serializer.serializeStr(value);
+            }, (value, serializer) => {
+                serializer.serializeI32(value);
+            });
+            serializeOutcome(this.second, serializer, (value, serializer) => {
+                serializer.serializeBool(value);
+            }, (value, serializer) => {
+                serializer.serializeStr(value);
+            });
+        }
+
+        static deserialize(deserializer: Deserializer): Response {
+            const first = deserializeOutcome(deserializer, (deserializer) => {
+                return deserializer.deserializeStr();
+            }, (deserializer) => {
+                return deserializer.deserializeI32();
+            });
+            const second = deserializeOutcome(deserializer, (deserializer) => {
+                return deserializer.deserializeBool();
+            }, (deserializer) => {
+                return deserializer.deserializeStr();
+            });
+            return new Response(first,second);
+        }
+    }
+    "#);
+}
+
+#[test]
+fn defaults_fill_in_missing_fields() {
+    #[derive(Facet)]
+    struct Settings {
+        #[facet(default = 3)]
+        retries: u32,
+        #[facet(default)]
+        tags: Vec<String>,
+        id: u64,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Command {
+        Move {
+            x: i32,
+            #[facet(default)]
+            speed: Option<u8>,
+        },
+    }
+
+    let actual = emit!(Settings, Command as TypeScript with JsonPlugin).unwrap();
+    insta::assert_snapshot!(actual, @r#"
+
+
+    export type Command =
+        | { kind: "Move"; x: int32; speed: Optional<uint8> };
+
+    export const commandMove = (x: int32, speed: Optional<uint8> = null): Command => ({ kind: "Move", x, speed });
+
+    export function matchCommand<R>(value: Command, cases: {
+        Move: (v: Extract<Command, { kind: "Move" }>) => R;
+    }): R {
+        return cases[value.kind as Command["kind"]](value as never);
+    }
+
+    export function serializeCommand(value: Command, serializer: Serializer): void {
+        switch (value.kind) {
+            case "Move": {
+                serializer.serializeVariantIndex(0);
+                serializer.serializeI32(value.x);
+                serializeOption(value.speed, serializer, (value, serializer) => {
+                    serializer.serializeU8(value);
+                });
+                break;
+            }
+            default: throw new Error("Unknown variant: " + (value as any).kind);
+        }
+    }
+
+    export function deserializeCommand(deserializer: Deserializer): Command {
+        const index = deserializer.deserializeVariantIndex();
+        switch (index) {
+            case 0: {
+                const x = deserializer.deserializeI32();
+                const speed = deserializer.hasField?.("speed") === false ? null : deserializeOption(deserializer, (deserializer) => {
+                    return deserializer.deserializeU8();
+                });
+                return { kind: "Move", x, speed };
+            }
+            default: throw new Error("Unknown variant index for Command: " + index);
+        }
+    }
+
+
+    export class Settings {
+        constructor (public id: uint64, public retries: uint32 = 3, public tags: Seq<str> = []) {
+        }
+
+        public serialize(serializer: Serializer): void {
+            serializer.serializeU32(this.retries);
+            serializeArray(this.tags, serializer, (item, serializer) => {
+                serializer.serializeStr(item);
+            });
+            serializer.serializeU64(this.id);
+        }
+
+        static deserialize(deserializer: Deserializer): Settings {
+            const retries = deserializer.hasField?.("retries") === false ? 3 : deserializer.deserializeU32();
+            const tags = deserializer.hasField?.("tags") === false ? [] : deserializeArray(deserializer, (deserializer) => {
+                return deserializer.deserializeStr();
+            });
+            const id = deserializer.deserializeU64();
+            return new Settings(id,retries,tags);
+        }
+    }
+    "#);
+}
@@ -12,10 +12,13 @@ use std::{
 use crate::{
     Registry,
     generation::{
-        CodeGenerator, CodeGeneratorConfig, Container, Emitter, indent::IndentedWriter,
-        module::Module, plugin::EmitterPlugin, typescript::emitter::TypeScript,
+        CodeGenerator, CodeGeneratorConfig, Container, Emitter,
+        indent::IndentedWriter,
+        module::{self, Module},
+        plugin::{self, EmitterPlugin},
+        typescript::emitter::TypeScript,
     },
-    reflection::format::{Format, FormatHolder, Namespace, QualifiedTypeName},
+    reflection::format::{Format, FormatHolder, Language, Namespace, QualifiedTypeName},
 };
 
 /// Main configuration object for TypeScript code generation.
@@ -68,6 +71,9 @@ impl<'a> TypeScriptCodeGenerator<'a> {
     /// Returns an error if writing to `out` fails.
     pub fn output(&self, out: &mut impl Write, registry: &Registry) -> Result<()> {
         let w = &mut IndentedWriter::new(out, self.config.indent);
+        let checked = module::for_plugin_checks(registry, Language::TypeScript);
+        let registry = &module::for_language(registry, Language::TypeScript);
+        plugin::check_registry(&self.plugins, &checked).map_err(std::io::Error::other)?;
 
         let mut config = self.config.clone();
         config.update_from(registry);
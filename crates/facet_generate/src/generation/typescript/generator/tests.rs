@@ -31,8 +31,8 @@ use crate::{
         typescript::TypeScript,
     },
     reflection::format::{
-        ContainerFormat, Doc, EnumTagging, Format, Named, Namespace, QualifiedTypeName,
-        VariantFormat,
+        ContainerFormat, ContainerMetadata, Doc, EnumTagging, FieldMetadata, Format, Named,
+        Namespace, QualifiedTypeName, VariantFormat,
     },
 };
 
@@ -59,17 +59,18 @@ fn registry_with_struct_field(field_type: Format) -> Registry {
         name: "value".to_string(),
         doc: Doc::new(),
         value: field_type,
+        metadata: FieldMetadata::default(),
     }];
     registry.insert(
         QualifiedTypeName::root("Holder".to_string()),
-        ContainerFormat::Struct(fields, Doc::new()),
+        ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default()),
     );
     registry
 }
 
 fn first_field_type(registry: &Registry) -> &Format {
     let (_, container) = registry.iter().next().unwrap();
-    let ContainerFormat::Struct(fields, _) = container else {
+    let ContainerFormat::Struct(fields, _, _) = container else {
         panic!("expected struct container");
     };
     &fields[0].value
@@ -200,17 +201,23 @@ fn update_qualified_names_enum_variants() {
             "root".to_string(),
             "Child".to_string(),
         )))),
+        metadata: FieldMetadata::default(),
     };
     let mut variants = BTreeMap::new();
     variants.insert(0, variant);
     registry.insert(
         QualifiedTypeName::root("Event".to_string()),
-        ContainerFormat::Enum(variants, EnumTagging::External, Doc::new()),
+        ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::new(),
+            ContainerMetadata::default(),
+        ),
     );
 
     let updated = TypeScriptCodeGenerator::update_qualified_names(&config, &registry);
     let (_, container) = updated.iter().next().unwrap();
-    let ContainerFormat::Enum(variants, _, _) = container else {
+    let ContainerFormat::Enum(variants, _, _, _) = container else {
         panic!("expected enum");
     };
     let variant = variants.get(&0).unwrap();
@@ -390,6 +397,7 @@ fn output_handles_multiple_external_packages() {
                         "auth".to_string(),
                         "User".to_string(),
                     )),
+                    metadata: FieldMetadata::default(),
                 },
                 Named {
                     name: "invoice".to_string(),
@@ -398,9 +406,11 @@ fn output_handles_multiple_external_packages() {
                         "billing".to_string(),
                         "Invoice".to_string(),
                     )),
+                    metadata: FieldMetadata::default(),
                 },
             ],
             Doc::new(),
+            ContainerMetadata::default(),
         ),
     );
 
@@ -436,6 +446,7 @@ fn output_deserialization_uses_local_and_external_qualification() {
                         "root".to_string(),
                         "LocalType".to_string(),
                     )),
+                    metadata: FieldMetadata::default(),
                 },
                 Named {
                     name: "external".to_string(),
@@ -444,9 +455,11 @@ fn output_deserialization_uses_local_and_external_qualification() {
                         "other".to_string(),
                         "ExternalType".to_string(),
                     )),
+                    metadata: FieldMetadata::default(),
                 },
             ],
             Doc::new(),
+            ContainerMetadata::default(),
         ),
     );
 
@@ -477,6 +490,7 @@ fn output_mixed_external_and_local_references() {
                     name: "local_root".to_string(),
                     doc: Doc::new(),
                     value: Format::TypeName(QualifiedTypeName::root("LocalRoot".to_string())),
+                    metadata: FieldMetadata::default(),
                 },
                 Named {
                     name: "local_namespaced".to_string(),
@@ -485,6 +499,7 @@ fn output_mixed_external_and_local_references() {
                         "root".to_string(),
                         "LocalNamespaced".to_string(),
                     )),
+                    metadata: FieldMetadata::default(),
                 },
                 Named {
                     name: "external".to_string(),
@@ -493,9 +508,11 @@ fn output_mixed_external_and_local_references() {
                         "other".to_string(),
                         "Child".to_string(),
                     )),
+                    metadata: FieldMetadata::default(),
                 },
             ],
             Doc::new(),
+            ContainerMetadata::default(),
         ),
     );
 
@@ -530,6 +547,7 @@ fn output_user_example_multiple_external_references() {
                     name: "fact".to_string(),
                     doc: Doc::new(),
                     value: Format::Str,
+                    metadata: FieldMetadata::default(),
                 },
                 Named {
                     name: "image".to_string(),
@@ -537,6 +555,7 @@ fn output_user_example_multiple_external_references() {
                     value: Format::Option(Box::new(Format::TypeName(
                         QualifiedTypeName::namespaced("app".to_string(), "CatImage".to_string()),
                     ))),
+                    metadata: FieldMetadata::default(),
                 },
                 Named {
                     name: "other".to_string(),
@@ -545,6 +564,7 @@ fn output_user_example_multiple_external_references() {
                         "other".to_string(),
                         "Other".to_string(),
                     )),
+                    metadata: FieldMetadata::default(),
                 },
                 Named {
                     name: "another".to_string(),
@@ -553,9 +573,11 @@ fn output_user_example_multiple_external_references() {
                         "other".to_string(),
                         "Other".to_string(),
                     )),
+                    metadata: FieldMetadata::default(),
                 },
             ],
             Doc::new(),
+            ContainerMetadata::default(),
         ),
     );
 
@@ -38,11 +38,13 @@ use crate::{
         CodeGeneratorConfig, Error, ExternalPackage, ExternalPackages, PackageLocation,
         SERDE_NAMESPACE, SourceInstaller,
         bincode::BincodePlugin,
+        indent::IndentConfig,
         json::JsonPlugin,
         module,
-        plugin::EmitterPlugin,
+        plugin::{self, EmitterPlugin},
         typescript::{TypeScript, TypeScriptCodeGenerator},
     },
+    reflection::format::Language,
 };
 
 /// Installer for generated source files in TypeScript.
@@ -59,6 +61,7 @@ pub struct Installer {
     package_name: String,
     install_dir: PathBuf,
     external_packages: ExternalPackages,
+    indent: IndentConfig,
     plugins: Vec<Arc<dyn EmitterPlugin<TypeScript>>>,
 }
 
@@ -74,6 +77,7 @@ impl Installer {
             package_name: package_name.to_string(),
             install_dir: install_dir.as_ref().to_path_buf(),
             external_packages: ExternalPackages::new(),
+            indent: IndentConfig::Space(4),
             plugins: vec![],
         }
     }
@@ -97,6 +101,13 @@ impl Installer {
         self
     }
 
+    /// Set the indentation of generated source files (four spaces by default).
+    #[must_use]
+    pub const fn indent(mut self, indent: IndentConfig) -> Self {
+        self.indent = indent;
+        self
+    }
+
     /// Generate all code for the given registry.
     ///
     /// This method:
@@ -108,6 +119,11 @@ impl Installer {
     ///
     /// Returns an error if any file operation or code generation step fails.
     pub fn generate(mut self, registry: &Registry) -> Result<(), Error> {
+        plugin::check_registry(
+            &self.plugins,
+            &module::for_plugin_checks(registry, Language::TypeScript),
+        )?;
+
         // Build a lang tag to get the active plugins, then use them to install
         // runtime files (replacing the old encoding-based install_serde/bincode calls).
         let mut config = CodeGeneratorConfig::new(self.package_name.clone());
@@ -136,8 +152,11 @@ impl Installer {
         }
 
         // Split by namespace and install each module
-        for (m, module_registry) in module::split(&self.package_name, registry) {
-            let config = m.config().clone();
+        for (m, module_registry) in module::split(
+            &self.package_name,
+            &module::for_language(registry, Language::TypeScript),
+        ) {
+            let config = m.config().clone().with_indent(self.indent);
             self.install_module(&config, &module_registry)?;
         }
 
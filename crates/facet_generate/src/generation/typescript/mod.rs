@@ -30,6 +30,6 @@ pub use emitter::TypeScript;
 pub use generator::TypeScriptCodeGenerator;
 pub use installer::Installer;
 
-mod emitter;
+pub(crate) mod emitter;
 mod generator;
 mod installer;
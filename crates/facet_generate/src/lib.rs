@@ -1,5 +1,5 @@
-//! Generates idiomatic source code in Swift, Kotlin, TypeScript, and C# from Rust types
-//! annotated with `#[derive(Facet)]`.
+//! Generates idiomatic source code in Swift, Kotlin, TypeScript, C#, Dart, Python, Go, Java, and
+//! C++ from Rust types annotated with `#[derive(Facet)]`.
 //!
 //! When Rust is the source of truth for a data model shared across platforms (e.g. a mobile
 //! app talking to a Rust core over FFI), every target language needs matching type definitions
@@ -17,10 +17,16 @@
 //!   language-neutral [`Registry`]: a flat map from qualified type names to their
 //!   [`ContainerFormat`] descriptions.
 //! - [`generation`] — transforms a registry into source code for a target language.
-//!   Each language (`kotlin`, `csharp`, `swift`, `typescript`) lives behind a feature flag and
-//!   follows a three-layer pipeline: **Installer** (project scaffolding and manifests) →
-//!   **Generator** (file-level output with imports and namespaces) → **Emitter** (per-type
-//!   code emission).
+//!   Each language (`kotlin`, `csharp`, `cpp`, `dart`, `go`, `java`, `python`, `swift`,
+//!   `typescript`) lives behind a feature flag and follows a three-layer pipeline: **Installer**
+//!   (project scaffolding and manifests) → **Generator** (file-level output with imports and
+//!   namespaces) → **Emitter** (per-type code emission).
+//! - [`schema`] — reads and writes a registry as a versioned JSON or YAML document, so that code
+//!   can be generated from a checked-in schema file without compiling the Rust types.
+//! - [`compat`] — compares two registries and reports which changes break clients that use the
+//!   bincode or JSON encoding.
+//! - [`filter`] — prunes a registry to the types reachable from chosen roots and leaves types
+//!   out by name, namespace or predicate, so that each app can get a slim package.
 //!
 //! # Getting Started
 //!
@@ -165,9 +171,12 @@
 // or `use facet_generate as fg; #[facet(fg::bytes)]`
 pub use facet_generate_attrs::*;
 
+pub mod compat;
 pub mod error;
+pub mod filter;
 pub mod generation;
 pub mod reflection;
+pub mod schema;
 
 #[cfg(test)]
 mod tests;
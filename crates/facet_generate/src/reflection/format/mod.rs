@@ -32,7 +32,7 @@ use serde::{
 };
 use std::{
     cell::{Ref, RefCell, RefMut},
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     fmt,
     rc::Rc,
 };
@@ -198,6 +198,19 @@ pub enum Format {
     /// A reference to a named container type in the [`Registry`](crate::Registry).
     TypeName(QualifiedTypeName),
 
+    /// A reference to a generic container type instantiated with concrete arguments,
+    /// e.g. the format of a `Page<User>` field. The container itself is registered
+    /// once under `name`, with its type parameters listed in [`ContainerMetadata`].
+    #[serde(rename_all = "UPPERCASE")]
+    Generic {
+        name: QualifiedTypeName,
+        args: Vec<Self>,
+    },
+
+    /// A use of one of the enclosing container's type parameters, e.g. the format of
+    /// `items` in `struct Page<T> { items: Vec<T> }` is `Seq(TypeParameter("T"))`.
+    TypeParameter(String),
+
     // The formats of primitive types
     Unit,
     Bool,
@@ -223,6 +236,25 @@ pub enum Format {
     /// target language (e.g. `Foundation.UUID` in Swift, `java.util.UUID` in
     /// Kotlin, `System.Guid` in C#, branded `string` in TypeScript).
     Uuid,
+    /// An instant in time, e.g. the format of `chrono::DateTime<Utc>` or
+    /// `jiff::Timestamp`. On the wire this is an RFC 3339 string (e.g.
+    /// `2024-06-19T15:22:45.123Z`) for both bincode and JSON, matching the
+    /// `serde` implementations of those types. Foreign-language emitters map
+    /// this to the native instant type (`Foundation.Date` in Swift,
+    /// `java.time.Instant` in Kotlin, `DateTimeOffset` in C#, `Date` in
+    /// TypeScript).
+    Timestamp,
+    /// A calendar date without a time zone, e.g. the format of
+    /// `chrono::NaiveDate`. On the wire this is a `YYYY-MM-DD` string.
+    Date,
+    /// A time of day without a time zone, e.g. the format of
+    /// `chrono::NaiveTime`. On the wire this is an `HH:MM:SS[.fraction]`
+    /// string.
+    Time,
+    /// A span of time, i.e. the format of `std::time::Duration`. On the wire
+    /// this is a struct of whole seconds (`u64`) and nanoseconds (`u32`),
+    /// matching its `serde` implementation.
+    Duration,
 
     /// The format of `Option<T>`.
     Option(Box<Self>),
@@ -254,9 +286,10 @@ pub enum Format {
 /// Each variant holds the [`Format`] nodes that describe
 /// How an enum's variants are tagged during serialization.
 ///
-/// Extracted from `#[facet(tag = "...")]` / `#[facet(tag = "...", content = "...")]`
-/// on the Rust source type. Controls the discriminant field name and payload layout
-/// in the generated TypeScript discriminated union.
+/// Extracted from `#[facet(tag = "...")]` / `#[facet(tag = "...", content = "...")]` /
+/// `#[facet(untagged)]` on the Rust source type. Controls the discriminant field name and
+/// payload layout in the generated TypeScript discriminated union, and how the JSON plugins
+/// tell variants apart.
 #[derive(Serialize, Deserialize, Debug, Eq, Clone, PartialEq)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum EnumTagging {
@@ -269,6 +302,10 @@ pub enum EnumTagging {
     /// `#[facet(tag = "X", content = "Y")]` — tag and payload in separate fields.
     /// Variants render as `{ X: "VariantName"; Y: payload }`.
     Adjacent { tag: String, content: String },
+    /// `#[facet(untagged)]` — only the payload is serialized, and deserializers try the
+    /// variants in order. The TypeScript discriminant defaults to `kind`, like `External`.
+    /// Bincode can't encode these enums.
+    Untagged,
 }
 
 /// its fields or inner values, plus a [`Doc`] for documentation comments.
@@ -278,16 +315,440 @@ pub enum EnumTagging {
 #[serde(rename_all = "UPPERCASE")]
 pub enum ContainerFormat {
     /// An empty struct, e.g. `struct A`.
-    UnitStruct(Doc),
+    #[serde(with = "unit_struct")]
+    UnitStruct(Doc, ContainerMetadata),
     /// A struct with a single unnamed parameter, e.g. `struct A(u16)`
-    NewTypeStruct(Box<Format>, Doc),
+    NewTypeStruct(
+        Box<Format>,
+        Doc,
+        #[serde(default, skip_serializing_if = "ContainerMetadata::is_empty")] ContainerMetadata,
+    ),
     /// A struct with several unnamed parameters, e.g. `struct A(u16, u32)`
-    TupleStruct(Vec<Format>, Doc),
+    TupleStruct(
+        Vec<Format>,
+        Doc,
+        #[serde(default, skip_serializing_if = "ContainerMetadata::is_empty")] ContainerMetadata,
+    ),
     /// A struct with named parameters, e.g. `struct A { a: Foo }`.
-    Struct(Vec<Named<Format>>, Doc),
+    Struct(
+        Vec<Named<Format>>,
+        Doc,
+        #[serde(default, skip_serializing_if = "ContainerMetadata::is_empty")] ContainerMetadata,
+    ),
     /// An enum, that is, an enumeration of variants.
     /// Each variant has a unique name and index within the enum.
-    Enum(BTreeMap<u32, Named<VariantFormat>>, EnumTagging, Doc),
+    Enum(
+        BTreeMap<u32, Named<VariantFormat>>,
+        EnumTagging,
+        Doc,
+        #[serde(default, skip_serializing_if = "ContainerMetadata::is_empty")] ContainerMetadata,
+    ),
+}
+
+/// Properties of a [`ContainerFormat`] that describe the declaration rather than its shape.
+///
+/// Always the last element of each `ContainerFormat` variant. It is omitted from the
+/// serialized registry when empty, so registries of plain (non-generic) types look exactly
+/// as they did before it was introduced.
+#[derive(Serialize, Deserialize, Default, Debug, Eq, Clone, PartialEq)]
+pub struct ContainerMetadata {
+    /// The names of the container's generic type parameters, in declaration order
+    /// (e.g. `["T"]` for `struct Page<T>`). Fields refer to them with
+    /// [`Format::TypeParameter`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_params: Vec<String>,
+    /// Whether a [`ContainerFormat::NewTypeStruct`] is branded (`#[facet(fg::branded)]`):
+    /// emitted as a nominal type that can't be mixed up with its inner type, or with other
+    /// branded types wrapping the same inner type. Always `false` for other containers.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub branded: bool,
+    /// Whether a [`ContainerFormat::NewTypeStruct`] declares a serialization proxy
+    /// (`#[facet(fg::serialized_as = "...")]` on the type): its inner format is what the type
+    /// looks like on the wire, and it is emitted as an alias of that format. Uses of the type
+    /// elsewhere in the registry are replaced by the inner format when the registry is built.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub proxy: bool,
+    /// Whether every field of the container is read-only (`#[facet(fg::readonly)]` on the type).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub readonly: bool,
+    /// The container's explicit visibility (`#[facet(fg::public)]`), or `None` to use the
+    /// generator's [`default_visibility`](crate::generation::CodeGeneratorConfig::default_visibility).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+    /// The languages that the container is generated for (`#[facet(fg::skip(...))]` or
+    /// `#[facet(fg::only(...))]` on the type), or `None` for all of them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub targets: Option<BTreeSet<Language>>,
+    /// Extra protocols or interfaces that the container conforms to in each language
+    /// (`#[facet(fg::conforms(...))]`), added to those contributed by the emitter's plugins.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub conformances: BTreeMap<Language, Vec<String>>,
+    /// Extra annotations written before the container's declaration in each language
+    /// (`#[facet(fg::annotate(...))]`), added to those contributed by the emitter's plugins.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub annotations: BTreeMap<Language, Vec<String>>,
+    /// The container's deprecation notice (`#[facet(fg::deprecated(...))]`), if it is deprecated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<Deprecation>,
+}
+
+impl ContainerMetadata {
+    /// Whether no metadata is set, i.e. this is the [`Default`] value.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether the container is generated for `language`.
+    #[must_use]
+    pub fn targets(&self, language: Language) -> bool {
+        self.targets
+            .as_ref()
+            .is_none_or(|targets| targets.contains(&language))
+    }
+
+    /// The extra protocols or interfaces that the container conforms to in `language`.
+    #[must_use]
+    pub fn conformances(&self, language: Language) -> &[String] {
+        self.conformances.get(&language).map_or(&[], Vec::as_slice)
+    }
+
+    /// The extra annotations written before the container's declaration in `language`.
+    #[must_use]
+    pub fn annotations(&self, language: Language) -> &[String] {
+        self.annotations.get(&language).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Properties of a struct field that describe its declaration rather than its shape.
+///
+/// Carried by [`Named<Format>`] and [`Named<VariantFormat>`], although enum variants only ever
+/// have [`targets`](Self::targets), [`deprecated`](Self::deprecated) and
+/// [`other`](Self::other). Like [`ContainerMetadata`], it is omitted from the serialized
+/// registry when empty.
+#[derive(Serialize, Deserialize, Default, Debug, Eq, Clone, PartialEq)]
+pub struct FieldMetadata {
+    /// Whether the field is read-only (`#[facet(fg::readonly)]`): it can be set when the value
+    /// is created (or deserialized), but not mutated afterwards.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub readonly: bool,
+    /// The field's explicit visibility (`#[facet(fg::public)]`), or `None` to use its container's.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+    /// The languages that the field or variant is generated for (`#[facet(fg::skip(...))]` or
+    /// `#[facet(fg::only(...))]`), or `None` for all of them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub targets: Option<BTreeSet<Language>>,
+    /// Replacements for the field's type in particular languages
+    /// (`#[facet(fg::override(lang = "...", ty = "..."))]`).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub overrides: BTreeMap<Language, TypeOverride>,
+    /// The field's or variant's deprecation notice (`#[facet(fg::deprecated(...))]`), if it is
+    /// deprecated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<Deprecation>,
+    /// The value the field takes when it is missing (`#[facet(default)]` or
+    /// `#[facet(default = ...)]`), if it has one that generated code can express.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<DefaultValue>,
+    /// Whether the variant is its enum's catch-all (`#[facet(other)]`), which deserializers
+    /// return for variants they don't know, e.g. those added by a later version.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub other: bool,
+    /// Whether the field was inlined from the struct of a `#[facet(flatten)]` field, which
+    /// serde writes as a map of its container's fields rather than by position.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub flattened: bool,
+}
+
+impl FieldMetadata {
+    /// Whether no metadata is set, i.e. this is the [`Default`] value.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The metadata of a field declared in a container with the given metadata: the field is
+    /// read-only if either is, and takes the container's visibility unless it has its own.
+    #[must_use]
+    pub fn inherit(&self, container: &ContainerMetadata) -> Self {
+        Self {
+            readonly: self.readonly || container.readonly,
+            visibility: self.visibility.or(container.visibility),
+            targets: self.targets.clone(),
+            overrides: self.overrides.clone(),
+            deprecated: self.deprecated.clone(),
+            default: self.default.clone(),
+            other: self.other,
+            flattened: self.flattened,
+        }
+    }
+
+    /// The field's type override for `language`, if it has one.
+    #[must_use]
+    pub fn type_override(&self, language: Language) -> Option<&TypeOverride> {
+        self.overrides.get(&language)
+    }
+
+    /// Whether the field or variant is generated for `language`.
+    #[must_use]
+    pub fn targets(&self, language: Language) -> bool {
+        self.targets
+            .as_ref()
+            .is_none_or(|targets| targets.contains(&language))
+    }
+}
+
+/// The access level of a generated type or field.
+///
+/// Types and fields without an explicit visibility get the generator's
+/// [`default_visibility`](crate::generation::CodeGeneratorConfig::default_visibility).
+/// TypeScript has no equivalent of `internal` and always exports generated types.
+#[derive(Serialize, Deserialize, Default, Debug, Eq, Clone, Copy, PartialEq, Hash)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Visibility {
+    /// Visible outside the generated module: `public` in Swift, Kotlin and C#.
+    #[default]
+    Public,
+    /// Visible only inside the generated module: `internal` in Swift, Kotlin and C#.
+    Internal,
+}
+
+/// A language that code can be generated for, as named by `#[facet(fg::skip(...))]` and
+/// `#[facet(fg::only(...))]`.
+#[derive(Serialize, Deserialize, Debug, Eq, Clone, Copy, PartialEq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Language {
+    /// `fg::skip(cpp)` / `fg::only(cpp)`
+    Cpp,
+    /// `fg::skip(csharp)` / `fg::only(csharp)`
+    CSharp,
+    /// `fg::skip(dart)` / `fg::only(dart)`
+    Dart,
+    /// `fg::skip(go)` / `fg::only(go)`
+    Go,
+    /// `fg::skip(java)` / `fg::only(java)`
+    Java,
+    /// `fg::skip(kotlin)` / `fg::only(kotlin)`
+    Kotlin,
+    /// `fg::skip(python)` / `fg::only(python)`
+    Python,
+    /// `fg::skip(swift)` / `fg::only(swift)`
+    Swift,
+    /// `fg::skip(typescript)` / `fg::only(typescript)`
+    TypeScript,
+}
+
+impl Language {
+    /// Every language, in declaration order.
+    pub const ALL: [Self; 9] = [
+        Self::Cpp,
+        Self::CSharp,
+        Self::Dart,
+        Self::Go,
+        Self::Java,
+        Self::Kotlin,
+        Self::Python,
+        Self::Swift,
+        Self::TypeScript,
+    ];
+}
+
+impl std::str::FromStr for Language {
+    type Err = String;
+
+    /// Parses the name of a language as written in `fg` attributes, e.g. `"swift"`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "cpp" => Ok(Self::Cpp),
+            "csharp" => Ok(Self::CSharp),
+            "dart" => Ok(Self::Dart),
+            "go" => Ok(Self::Go),
+            "java" => Ok(Self::Java),
+            "kotlin" => Ok(Self::Kotlin),
+            "python" => Ok(Self::Python),
+            "swift" => Ok(Self::Swift),
+            "typescript" => Ok(Self::TypeScript),
+            _ => Err(format!(
+                "unknown language `{name}`: expected `cpp`, `csharp`, `dart`, `go`, `java`, \
+                 `kotlin`, `python`, `swift` or `typescript`"
+            )),
+        }
+    }
+}
+
+/// A replacement for a field's type in one language, from
+/// `#[facet(fg::override(lang = "...", ty = "...", serialize = "...", deserialize = "..."))]`.
+///
+/// The serialization plugins that write per-field code (Bincode in every language, JSON in
+/// Swift and TypeScript) use `serialize` and `deserialize` in place of the code they would
+/// derive from the field's [`Format`]; without them, the field is still (de)serialized as its
+/// Rust format.
+#[derive(Serialize, Deserialize, Debug, Eq, Clone, PartialEq)]
+pub struct TypeOverride {
+    /// The type expression emitted for the field, e.g. `CGFloat`.
+    pub ty: String,
+    /// A statement that serializes the field with the generated code's `serializer`, where
+    /// `{value}` stands for the field, e.g. `try serializer.serialize_f64(value: Double({value}))`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub serialize: Option<String>,
+    /// An expression that deserializes the field with the generated code's `deserializer`,
+    /// e.g. `CGFloat(try deserializer.deserialize_f64())`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deserialize: Option<String>,
+}
+
+impl TypeOverride {
+    /// The serialization statement for the field `value`, if one was given.
+    #[must_use]
+    pub fn serialize(&self, value: &str) -> Option<String> {
+        self.serialize
+            .as_ref()
+            .map(|serialize| serialize.replace("{value}", value))
+    }
+}
+
+/// A deprecation notice, from `#[facet(fg::deprecated(since = "...", note = "..."))]`.
+///
+/// Emitted as each language's deprecation attribute (`@available(*, deprecated)` in Swift,
+/// `@Deprecated` in Kotlin, `[Obsolete]` in C# and `@deprecated` in TypeScript), so that code using
+/// the type, field or variant gets compiler warnings.
+#[derive(Serialize, Deserialize, Default, Debug, Eq, Clone, PartialEq)]
+pub struct Deprecation {
+    /// The version that deprecated it, e.g. `0.3.0`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+    /// What to use instead, or why it is deprecated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl Deprecation {
+    /// The message shown with the compiler warning: the note followed by the version, or `None`
+    /// if neither was given.
+    #[must_use]
+    pub fn message(&self) -> Option<String> {
+        match (&self.note, &self.since) {
+            (Some(note), Some(since)) => Some(format!("{note} (since {since})")),
+            (Some(note), None) => Some(note.clone()),
+            (None, Some(since)) => Some(format!("Deprecated since {since}")),
+            (None, None) => None,
+        }
+    }
+}
+
+/// The default value of a field, from `#[facet(default)]` or `#[facet(default = ...)]`.
+///
+/// Emitted as the default value of the field's constructor parameter (or property initializer
+/// in C#), so that the field can be left out when constructing the type, and JSON deserializers
+/// that go through the constructor fill it in when it is missing from their input.
+#[derive(Serialize, Deserialize, Debug, Eq, Clone, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DefaultValue {
+    /// `None`, or an empty sequence, set, map or byte string.
+    Empty,
+    Bool(bool),
+    /// An integer, as its decimal digits (so that `i128` and `u128` values fit).
+    Integer(String),
+    /// A finite floating-point number, as Rust prints it (e.g. `0.5` or `1e100`).
+    Float(String),
+    Char(char),
+    Str(String),
+}
+
+/// (De)serializes [`ContainerFormat::UnitStruct`] as its bare [`Doc`] when the
+/// metadata is empty, and as a `[doc, metadata]` pair otherwise.
+mod unit_struct {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{ContainerMetadata, Doc};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+        Doc(Doc),
+        WithMetadata(Doc, ContainerMetadata),
+    }
+
+    pub fn serialize<S>(
+        doc: &Doc,
+        metadata: &ContainerMetadata,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if metadata.is_empty() {
+            doc.serialize(serializer)
+        } else {
+            (doc, metadata).serialize(serializer)
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<(Doc, ContainerMetadata), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Doc(doc) => (doc, ContainerMetadata::default()),
+            Repr::WithMetadata(doc, metadata) => (doc, metadata),
+        })
+    }
+}
+
+impl ContainerFormat {
+    /// The doc comments attached to this container.
+    #[must_use]
+    pub const fn doc(&self) -> &Doc {
+        match self {
+            Self::UnitStruct(doc, _)
+            | Self::NewTypeStruct(_, doc, _)
+            | Self::TupleStruct(_, doc, _)
+            | Self::Struct(_, doc, _)
+            | Self::Enum(_, _, doc, _) => doc,
+        }
+    }
+
+    /// The declaration metadata attached to this container.
+    #[must_use]
+    pub const fn metadata(&self) -> &ContainerMetadata {
+        match self {
+            Self::UnitStruct(_, metadata)
+            | Self::NewTypeStruct(_, _, metadata)
+            | Self::TupleStruct(_, _, metadata)
+            | Self::Struct(_, _, metadata)
+            | Self::Enum(_, _, _, metadata) => metadata,
+        }
+    }
+
+    /// Mutable access to the declaration metadata attached to this container.
+    pub const fn metadata_mut(&mut self) -> &mut ContainerMetadata {
+        match self {
+            Self::UnitStruct(_, metadata)
+            | Self::NewTypeStruct(_, _, metadata)
+            | Self::TupleStruct(_, _, metadata)
+            | Self::Struct(_, _, metadata)
+            | Self::Enum(_, _, _, metadata) => metadata,
+        }
+    }
+
+    /// The names of the container's generic type parameters (empty for non-generic types).
+    #[must_use]
+    pub fn type_params(&self) -> &[String] {
+        &self.metadata().type_params
+    }
+
+    /// Whether this is a branded newtype struct.
+    #[must_use]
+    pub const fn is_branded(&self) -> bool {
+        self.metadata().branded
+    }
+
+    /// Whether this is an alias declared by a serialization proxy.
+    #[must_use]
+    pub const fn is_proxy(&self) -> bool {
+        self.metadata().proxy
+    }
 }
 
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
@@ -297,6 +758,8 @@ pub struct Named<T> {
     pub name: String,
     pub doc: Doc,
     pub value: T,
+    /// Declaration properties of a struct field (empty for enum variants).
+    pub metadata: FieldMetadata,
 }
 
 impl<T> Named<T>
@@ -310,6 +773,7 @@ where
             name,
             doc: Doc::default(),
             value: value.clone(),
+            metadata: FieldMetadata::default(),
         }
     }
 
@@ -321,6 +785,19 @@ where
             ..self.clone()
         }
     }
+
+    /// Replaces the declaration metadata of a struct field.
+    #[must_use]
+    pub fn with_metadata(self, metadata: FieldMetadata) -> Self {
+        Self { metadata, ..self }
+    }
+
+    /// This field as declared in a container with the given metadata
+    /// (see [`FieldMetadata::inherit`]).
+    #[must_use]
+    pub fn inheriting(&self, container: &ContainerMetadata) -> Self {
+        self.clone().with_metadata(self.metadata.inherit(container))
+    }
 }
 
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
@@ -554,19 +1031,19 @@ where
 impl FormatHolder for ContainerFormat {
     fn visit<'a>(&'a self, f: &mut dyn FnMut(&'a Format) -> Result<()>) -> Result<()> {
         match self {
-            Self::UnitStruct(_doc) => (),
-            Self::NewTypeStruct(format, _doc) => format.visit(f)?,
-            Self::TupleStruct(formats, _doc) => {
+            Self::UnitStruct(..) => (),
+            Self::NewTypeStruct(format, ..) => format.visit(f)?,
+            Self::TupleStruct(formats, ..) => {
                 for format in formats {
                     format.visit(f)?;
                 }
             }
-            Self::Struct(named_formats, _doc) => {
+            Self::Struct(named_formats, ..) => {
                 for format in named_formats {
                     format.visit(f)?;
                 }
             }
-            Self::Enum(variants, _tagging, _doc) => {
+            Self::Enum(variants, ..) => {
                 for variant in variants {
                     variant.1.visit(f)?;
                 }
@@ -577,19 +1054,19 @@ impl FormatHolder for ContainerFormat {
 
     fn visit_mut(&mut self, f: &mut dyn FnMut(&mut Format) -> Result<()>) -> Result<()> {
         match self {
-            Self::UnitStruct(_doc) => (),
-            Self::NewTypeStruct(format, _doc) => format.visit_mut(f)?,
-            Self::TupleStruct(formats, _doc) => {
+            Self::UnitStruct(..) => (),
+            Self::NewTypeStruct(format, ..) => format.visit_mut(f)?,
+            Self::TupleStruct(formats, ..) => {
                 for format in formats {
                     format.visit_mut(f)?;
                 }
             }
-            Self::Struct(named_formats, _doc) => {
+            Self::Struct(named_formats, ..) => {
                 for format in named_formats {
                     format.visit_mut(f)?;
                 }
             }
-            Self::Enum(variants, _tagging, _doc) => {
+            Self::Enum(variants, ..) => {
                 for variant in variants {
                     variant.1.visit_mut(f)?;
                 }
@@ -608,6 +1085,7 @@ impl FormatHolder for Format {
         match self {
             Self::Variable(variable) => variable.visit(f)?,
             Self::TypeName(_)
+            | Self::TypeParameter(_)
             | Self::Unit
             | Self::Bool
             | Self::I8
@@ -625,7 +1103,11 @@ impl FormatHolder for Format {
             | Self::Char
             | Self::Str
             | Self::Bytes
-            | Self::Uuid => (),
+            | Self::Uuid
+            | Self::Timestamp
+            | Self::Date
+            | Self::Time
+            | Self::Duration => (),
 
             Self::Option(format)
             | Self::Seq(format)
@@ -641,7 +1123,7 @@ impl FormatHolder for Format {
                 value.visit(f)?;
             }
 
-            Self::Tuple(formats) => {
+            Self::Tuple(formats) | Self::Generic { args: formats, .. } => {
                 for format in formats {
                     format.visit(f)?;
                 }
@@ -661,6 +1143,7 @@ impl FormatHolder for Format {
                     .expect("variable is known");
             }
             Self::TypeName(_)
+            | Self::TypeParameter(_)
             | Self::Unit
             | Self::Bool
             | Self::I8
@@ -678,7 +1161,11 @@ impl FormatHolder for Format {
             | Self::Char
             | Self::Str
             | Self::Bytes
-            | Self::Uuid => (),
+            | Self::Uuid
+            | Self::Timestamp
+            | Self::Date
+            | Self::Time
+            | Self::Duration => (),
 
             Self::Option(format)
             | Self::Seq(format)
@@ -694,7 +1181,7 @@ impl FormatHolder for Format {
                 value.visit_mut(f)?;
             }
 
-            Self::Tuple(formats) => {
+            Self::Tuple(formats) | Self::Generic { args: formats, .. } => {
                 for format in formats {
                     format.visit_mut(f)?;
                 }
@@ -742,14 +1229,30 @@ impl Format {
                 | Self::Str
                 | Self::Bytes
                 | Self::Uuid
+                | Self::Timestamp
+                | Self::Date
+                | Self::Time
+                | Self::Duration
         )
     }
 
-    /// Whether this format is a leaf type — either a native primitive or a
-    /// named type reference.
+    /// Whether this format is a leaf type — either a native primitive, a
+    /// named type reference or a type parameter.
     #[must_use]
     pub(crate) const fn is_leaf(&self) -> bool {
-        self.is_native() || matches!(self, Self::TypeName(..))
+        self.is_native() || matches!(self, Self::TypeName(..) | Self::TypeParameter(..))
+    }
+
+    /// Whether the type parameter `name` is used anywhere in this format.
+    #[must_use]
+    pub fn uses_type_parameter(&self, name: &str) -> bool {
+        let mut used = false;
+        // visiting only fails on unresolved placeholders, which cannot hide a type parameter
+        let _ = self.visit(&mut |format| {
+            used |= matches!(format, Self::TypeParameter(param) if param == name);
+            Ok(())
+        });
+        used
     }
 }
 
@@ -759,6 +1262,21 @@ impl VariantFormat {
     pub fn unknown() -> Self {
         Self::Variable(Variable::new(None))
     }
+
+    /// Whether the type parameter `name` is used anywhere in this variant's payload.
+    #[must_use]
+    pub fn uses_type_parameter(&self, name: &str) -> bool {
+        match self {
+            Self::Variable(_) | Self::Unit => false,
+            Self::NewType(format) => format.uses_type_parameter(name),
+            Self::Tuple(formats) => formats
+                .iter()
+                .any(|format| format.uses_type_parameter(name)),
+            Self::Struct(fields) => fields
+                .iter()
+                .any(|field| field.value.uses_type_parameter(name)),
+        }
+    }
 }
 
 impl Default for Format {
@@ -774,7 +1292,8 @@ impl Default for VariantFormat {
 }
 
 // For better rendering in human readable formats, we wish to serialize
-// `Named { name: x, value: y, doc: z }` as a map `{ x: (y, z) }`.
+// `Named { name: x, value: y, doc: z, metadata: m }` as a map `{ x: (y, z) }`,
+// or `{ x: (y, z, m) }` when the metadata is not empty.
 impl<T> Serialize for Named<T>
 where
     T: Serialize,
@@ -785,18 +1304,33 @@ where
     {
         if serializer.is_human_readable() {
             let mut map = serializer.serialize_map(Some(1))?;
-            map.serialize_entry(&self.name, &(&self.value, &self.doc))?;
+            if self.metadata.is_empty() {
+                map.serialize_entry(&self.name, &(&self.value, &self.doc))?;
+            } else {
+                map.serialize_entry(&self.name, &(&self.value, &self.doc, &self.metadata))?;
+            }
             map.end()
         } else {
-            let mut inner = serializer.serialize_struct("Named", 3)?;
+            let mut inner = serializer.serialize_struct("Named", 4)?;
             inner.serialize_field("name", &self.name)?;
             inner.serialize_field("value", &self.value)?;
             inner.serialize_field("doc", &self.doc)?;
+            inner.serialize_field("metadata", &self.metadata)?;
             inner.end()
         }
     }
 }
 
+/// The value of a human-readable `Named` entry: `(value, doc)` or `(value, doc, metadata)` as
+/// written by `Serialize`, or a bare value.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NamedEntry<T> {
+    WithMetadata(T, Doc, FieldMetadata),
+    WithDoc(T, Doc),
+    Value(T),
+}
+
 struct NamedVisitor<T> {
     marker: std::marker::PhantomData<T>,
 }
@@ -823,17 +1357,25 @@ where
     where
         M: de::MapAccess<'de>,
     {
-        let named_value = match access.next_entry::<String, T>()? {
-            Some((name, value)) => Named {
-                name,
-                doc: Doc::new(),
-                value,
-            },
+        let named_value = match access.next_entry::<String, NamedEntry<T>>()? {
+            Some((name, entry)) => {
+                let (value, doc, metadata) = match entry {
+                    NamedEntry::WithMetadata(value, doc, metadata) => (value, doc, metadata),
+                    NamedEntry::WithDoc(value, doc) => (value, doc, FieldMetadata::default()),
+                    NamedEntry::Value(value) => (value, Doc::new(), FieldMetadata::default()),
+                };
+                Named {
+                    name,
+                    doc,
+                    value,
+                    metadata,
+                }
+            }
             _ => {
                 return Err(de::Error::custom("Missing entry"));
             }
         };
-        if access.next_entry::<String, T>()?.is_some() {
+        if access.next_entry::<String, NamedEntry<T>>()?.is_some() {
             return Err(de::Error::custom("Too many entries"));
         }
         Ok(named_value)
@@ -847,6 +1389,8 @@ struct NamedInternal<T> {
     name: String,
     doc: Doc,
     value: T,
+    #[serde(default)]
+    metadata: FieldMetadata,
 }
 
 impl<'de, T> Deserialize<'de> for Named<T>
@@ -860,8 +1404,18 @@ where
         if deserializer.is_human_readable() {
             deserializer.deserialize_map(NamedVisitor::new())
         } else {
-            let NamedInternal { name, doc, value } = NamedInternal::deserialize(deserializer)?;
-            Ok(Self { name, doc, value })
+            let NamedInternal {
+                name,
+                doc,
+                value,
+                metadata,
+            } = NamedInternal::deserialize(deserializer)?;
+            Ok(Self {
+                name,
+                doc,
+                value,
+                metadata,
+            })
         }
     }
 }
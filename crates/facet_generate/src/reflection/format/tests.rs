@@ -4,7 +4,8 @@
 use std::{collections::HashSet, string::ToString};
 
 use crate::reflection::format::{
-    ContainerFormat, Doc, EnumTagging, Format, FormatHolder, Named, VariantFormat,
+    ContainerFormat, ContainerMetadata, Doc, EnumTagging, FieldMetadata, Format, FormatHolder,
+    Named, VariantFormat,
 };
 
 #[test]
@@ -22,12 +23,14 @@ fn test_format_visiting() {
                     TypeName("bar".into()),
                     Seq(Box::new(TypeName("foo".into()))),
                 ]),
+                metadata: FieldMetadata::default(),
             },
         )]
         .into_iter()
         .collect(),
         EnumTagging::External,
         Doc::new(),
+        ContainerMetadata::default(),
     );
     let mut names = HashSet::new();
     format
@@ -24,22 +24,27 @@
 //! - Resolving generic types (`Option`, `Vec`, `HashMap`, `Arc`, `Box`, etc.) into their format equivalents
 //! - Handling transparent wrappers and newtypes
 //! - Propagating and resolving namespace annotations via a context stack
-//! - Detecting conflicts (e.g. a generic type used with different type parameters)
+//! - Reflecting user-defined generic types with several instantiations (e.g. `Page<T>`) as generic
+//!   containers, whose fields refer to their type parameters, and their uses as instantiations
+//!   such as `Page<User>`
 
 pub mod format;
+mod proxy;
 #[cfg(test)]
 pub mod regression_tests;
 
 use std::{
-    collections::{BTreeMap, HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    mem::MaybeUninit,
     string::ToString,
     sync::LazyLock,
 };
 
 use facet::{
-    ArrayDef, Def, EnumType, Facet, Field, FieldFlags, ListDef, MapDef, NumericType, OptionDef,
-    PointerDef, PointerType, PrimitiveType, SequenceType, SetDef, Shape, SliceDef, StructKind,
-    StructType, TextualType, Type, UserType, Variant,
+    ArrayDef, Attr, ConstTypeId, Def, DefaultSource, EnumType, Facet, Field, FieldFlags, ListDef,
+    MapDef, NumericType, OptionDef, PointerDef, PointerType, PrimitiveType, PtrUninit,
+    SequenceType, SetDef, Shape, SliceDef, StructKind, StructType, TextualType, Type, TypeParam,
+    UserType, Variant,
 };
 use regex::Regex;
 
@@ -47,14 +52,11 @@ use crate as fg;
 use crate::{Registry, error::Error};
 
 use format::{
-    ContainerFormat, EnumTagging, Format, FormatHolder, Named, Namespace, QualifiedTypeName,
-    VariantFormat,
+    ContainerFormat, ContainerMetadata, DefaultValue, Deprecation, EnumTagging, FieldMetadata,
+    Format, FormatHolder, Language, Named, Namespace, QualifiedTypeName, TypeOverride,
+    VariantFormat, Visibility,
 };
 
-const SUPPORTED_GENERIC_TYPES: [&str; 10] = [
-    "Arc", "Rc", "Box", "Option", "Vec", "HashMap", "HashSet", "BTreeMap", "BTreeSet", "DateTime",
-];
-
 /// A namespace context with its source information
 #[derive(Debug, Clone, PartialEq)]
 struct NamespaceContext {
@@ -136,18 +138,32 @@ impl NamespaceAction {
 ///
 /// Types are added with [`add_type`](Self::add_type), which recursively reflects the type and
 /// all types reachable from its fields and variants. The builder tracks which types have already
-/// been processed to avoid duplicates and detects conflicts (e.g. a generic type instantiated
-/// with different type parameters).
+/// been processed to avoid duplicates. A user-defined generic type is registered once: as a
+/// generic container if several instantiations of it are reachable, or else as its only
+/// instantiation.
+///
+/// A type that can't be reflected is reported as an [`Error::Unsupported`] carrying its path from
+/// the root type. By default the first error stops reflection; with
+/// [`collect_errors`](Self::collect_errors), [`build`](Self::build) reports all of them.
 #[derive(Debug, Default)]
 pub struct RegistryBuilder {
     pub registry: Registry,
     current: Vec<QualifiedTypeName>,
     processed: HashSet<QualifiedTypeName>,
     name_mappings: BTreeMap<QualifiedTypeName, QualifiedTypeName>,
-    generic_type_params: HashMap<String, String>,
+    instantiations: HashSet<ConstTypeId>,
+    /// The type arguments of every instantiation of each generic type.
+    type_arguments: HashMap<QualifiedTypeName, Vec<&'static [TypeParam]>>,
+    /// Each generic type as reflected from its first instantiation, with the type arguments in
+    /// place of the type parameters.
+    monomorphic: HashMap<QualifiedTypeName, ContainerFormat>,
     processing_nested: bool,
     namespace_context_stack: Vec<NamespaceContext>,
     type_namespace_sources: HashMap<QualifiedTypeName, bool>, // true = explicit, false = inherited
+    /// The fields, variants and elements leading from the root type to the one being reflected.
+    path: Vec<String>,
+    collect_errors: bool,
+    errors: Vec<Error>,
 }
 
 impl RegistryBuilder {
@@ -156,11 +172,26 @@ impl RegistryBuilder {
         Self::default()
     }
 
+    /// Carries on past fields and variants that can't be reflected, so that
+    /// [`build`](Self::build) returns every error (as [`Error::Multiple`] if there are several)
+    /// instead of [`add_type`](Self::add_type) returning the first.
+    #[must_use]
+    pub const fn collect_errors(mut self) -> Self {
+        self.collect_errors = true;
+        self
+    }
+
     /// Builds the registry from the current state.
     /// # Errors
     /// Will return an error with a suitable error message if the registry is invalid,
-    /// usually due to incomplete reflection.
-    pub fn build(self) -> Result<Registry, Error> {
+    /// usually due to incomplete reflection, or the errors collected while adding types.
+    pub fn build(mut self) -> Result<Registry, Error> {
+        match self.errors.len() {
+            0 => {}
+            1 => return Err(self.errors.remove(0)),
+            _ => return Err(Error::Multiple(self.errors)),
+        }
+
         for (type_name, format) in &self.registry {
             if let Err(err) = format.visit(&mut |_| Ok(())) {
                 return Err(Error::ReflectionError {
@@ -170,7 +201,75 @@ impl RegistryBuilder {
             }
         }
 
-        Ok(self.registry)
+        let mut registry = self.registry;
+
+        // `facet` only describes instantiations, so a field is only known to use a type parameter
+        // if its type follows the type argument across instantiations. A generic type with a
+        // single reachable instantiation is registered as that instantiation, and one whose
+        // instantiations agree on a type argument that its fields use is ambiguous.
+        let mut monomorphic = HashSet::new();
+        for (name, instantiations) in &self.type_arguments {
+            if let [_] = instantiations.as_slice() {
+                if let Some(container) = self.monomorphic.remove(name) {
+                    registry.insert(name.clone(), container);
+                    monomorphic.insert(name.clone());
+                }
+                continue;
+            }
+            let Some(container) = registry.get(name) else {
+                continue;
+            };
+            for (index, param) in instantiations[0].iter().enumerate() {
+                let varies = instantiations
+                    .iter()
+                    .any(|args| args.get(index).is_none_or(|arg| arg.shape != param.shape));
+                if !varies && uses_type_parameter(container, param.name) {
+                    return Err(Error::AmbiguousGenericType {
+                        type_name: name.to_string(),
+                        param: param.name.to_string(),
+                        argument: param.shape.to_string(),
+                    });
+                }
+            }
+        }
+        if !monomorphic.is_empty() {
+            for container in registry.values_mut() {
+                container.visit_mut(&mut |format| {
+                    if let Format::Generic { name, .. } = format
+                        && monomorphic.contains(name)
+                    {
+                        *format = Format::TypeName(name.clone());
+                    }
+                    Ok(())
+                })?;
+            }
+        }
+
+        // Types with a serialization proxy are replaced by the proxy's format wherever they are
+        // used, so that generated (de)serialization code matches the wire format.
+        let proxies = registry
+            .iter()
+            .filter_map(|(name, container)| match container {
+                ContainerFormat::NewTypeStruct(format, _, metadata) if metadata.proxy => {
+                    Some((name.clone(), (**format).clone()))
+                }
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>();
+        if !proxies.is_empty() {
+            for container in registry.values_mut() {
+                container.visit_mut(&mut |format| {
+                    if let Format::TypeName(name) = format
+                        && let Some(proxy) = proxies.get(name)
+                    {
+                        *format = proxy.clone();
+                    }
+                    Ok(())
+                })?;
+            }
+        }
+
+        Ok(registry)
     }
 
     /// Reflect a type into the registry.
@@ -178,17 +277,54 @@ impl RegistryBuilder {
     /// # Errors
     /// Will return an error if:
     /// * there is a problem building the registry,
-    /// * non-special generic types are used with different type parameters,
     /// * there is an unsupported layout,
     /// * namespaces are conflicting,
     /// * namespaces have invalid names, or
     /// * attributes are malformed.
     pub fn add_type<'a, T: Facet<'a>>(mut self) -> Result<Self, Error> {
-        self.format(T::SHAPE)?;
+        self.within(T::SHAPE.type_identifier, |builder| builder.format(T::SHAPE))?;
         Ok(self)
     }
 }
 
+impl RegistryBuilder {
+    /// Reflects the part of a type at `segment` of the path from the root type (a field, a
+    /// variant or a positional element), setting the path of any [`Error::Unsupported`] that
+    /// doesn't have one yet.
+    ///
+    /// When collecting errors, the error is recorded, the builder's state is restored and `None`
+    /// is returned, so that the caller can carry on without that part.
+    fn within<R>(
+        &mut self,
+        segment: impl ToString,
+        reflect: impl FnOnce(&mut Self) -> Result<R, Error>,
+    ) -> Result<Option<R>, Error> {
+        let current = self.current.len();
+        let namespaces = self.namespace_context_stack.len();
+        let processing_nested = self.processing_nested;
+
+        self.path.push(segment.to_string());
+        let result = reflect(self);
+        let path = self.path.join(".");
+        self.path.pop();
+
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(error) => {
+                let error = error.at(path);
+                if !self.collect_errors {
+                    return Err(error);
+                }
+                self.current.truncate(current);
+                self.namespace_context_stack.truncate(namespaces);
+                self.processing_nested = processing_nested;
+                self.errors.push(error);
+                Ok(None)
+            }
+        }
+    }
+}
+
 impl RegistryBuilder {
     fn push(&mut self, name: QualifiedTypeName, container: ContainerFormat) {
         self.registry.insert(name.clone(), container);
@@ -300,8 +436,8 @@ impl RegistryBuilder {
             shape = inner;
         }
 
-        if !self.is_supported_generic_type(shape) {
-            return Err(Error::UnsupportedGenericType(shape.to_string()));
+        if let Some(proxy) = serialized_as_format(shape.type_identifier, shape.attributes)? {
+            return self.format_proxy(shape, proxy);
         }
 
         // First check for special cases in the def system (like Option)
@@ -320,32 +456,6 @@ impl RegistryBuilder {
         Ok(())
     }
 
-    fn is_supported_generic_type(&mut self, shape: &Shape) -> bool {
-        // Return true immediately for non-generic or special cases
-        if shape.type_params.is_empty() {
-            return true;
-        }
-
-        // Skip lifetimes and arrays
-        if shape.type_identifier.starts_with('&') || shape.type_identifier.starts_with('[') {
-            return true;
-        }
-
-        // Accept known generic types unconditionally
-        if SUPPORTED_GENERIC_TYPES.contains(&shape.type_identifier) {
-            return true;
-        }
-
-        // For other generics, verify all type parameters are consistent with previous uses
-        let current_params = format!("{:?}", shape.type_params);
-        let previous_params = self
-            .generic_type_params
-            .entry(shape.type_identifier.to_string())
-            .or_insert_with(|| current_params.clone());
-
-        *previous_params == current_params
-    }
-
     fn try_format_from_type_system(&mut self, shape: &Shape) -> Result<bool, Error> {
         match &shape.ty {
             Type::User(UserType::Struct(struct_def)) => {
@@ -364,6 +474,48 @@ impl RegistryBuilder {
         }
     }
 
+    /// Registers a type with a serialization proxy (`#[facet(fg::serialized_as = "...")]`) as an
+    /// alias of the proxy's format. The type's own fields or variants are not reflected.
+    fn format_proxy(&mut self, shape: &Shape, proxy: Format) -> Result<(), Error> {
+        let name = self.get_name_with_mappings(shape)?;
+
+        if !self.processing_nested {
+            self.update_container_format(Format::TypeName(name.clone()), UpdateMode::IfUnknown)?;
+        }
+
+        if self.is_processed(&name) {
+            return Ok(());
+        }
+
+        if name.name != shape.type_identifier {
+            let original = QualifiedTypeName {
+                namespace: name.namespace.clone(),
+                name: shape.type_identifier.to_string(),
+            };
+            self.register_type_mapping(original, name.clone());
+        }
+
+        self.mark_processed(name.clone());
+
+        let ContainerMetadata {
+            visibility,
+            targets,
+            deprecated,
+            ..
+        } = container_metadata(shape)?;
+        let metadata = ContainerMetadata {
+            proxy: true,
+            visibility,
+            targets,
+            deprecated,
+            ..ContainerMetadata::default()
+        };
+        let container = ContainerFormat::NewTypeStruct(Box::new(proxy), shape.into(), metadata);
+        self.push_with_type_check(name, container, shape)?;
+        self.pop();
+        Ok(())
+    }
+
     fn handle_user_struct(&mut self, shape: &Shape, struct_def: &StructType) -> Result<(), Error> {
         let type_name = self.get_name_with_mappings(shape)?;
 
@@ -375,7 +527,7 @@ impl RegistryBuilder {
                 Format::TypeName(type_name)
             };
 
-            self.update_container_format(format, UpdateMode::IfUnknown);
+            self.update_container_format(format, UpdateMode::IfUnknown)?;
         }
 
         self.format_struct(struct_def, shape)?;
@@ -397,7 +549,7 @@ impl RegistryBuilder {
                     let target_shape = slice_type.t;
                     let inner_format = get_inner_format(target_shape)?;
                     let slice_format = Format::Seq(Box::new(inner_format));
-                    self.update_container_format(slice_format, UpdateMode::Force);
+                    self.update_container_format(slice_format, UpdateMode::Force)?;
                     self.process_nested_types(target_shape)?;
                 }
             }
@@ -410,7 +562,7 @@ impl RegistryBuilder {
                     let target_shape = array_type.t;
                     let inner_format = get_inner_format(target_shape)?;
                     let array_format = Format::Seq(Box::new(inner_format)); // Arrays are also sequences
-                    self.update_container_format(array_format, UpdateMode::Force);
+                    self.update_container_format(array_format, UpdateMode::Force)?;
                     self.process_nested_types(target_shape)?;
                 }
             }
@@ -434,12 +586,12 @@ impl RegistryBuilder {
                 self.handle_pointer(inner_shape)?;
             }
             Def::Pointer(PointerDef { pointee: None, .. }) => {
-                self.handle_opaque_pointee();
+                self.handle_opaque_pointee()?;
             }
             Def::Undefined => {
                 self.handle_undefined_def(shape)?;
             }
-            _ => (),
+            _ => return Err(def_unsupported(shape)),
         }
         Ok(())
     }
@@ -449,7 +601,7 @@ impl RegistryBuilder {
         let inner_format = get_format_for_shape(inner_shape)?;
 
         // Update the current container with the Pointer's inner format
-        self.update_container_format(inner_format, UpdateMode::IfUnknown);
+        self.update_container_format(inner_format, UpdateMode::IfUnknown)?;
 
         // Also process the inner type if it's a user-defined type
         self.process_nested_types(inner_shape)?;
@@ -464,23 +616,26 @@ impl RegistryBuilder {
             Type::Primitive(primitive) => match primitive {
                 PrimitiveType::Boolean => {
                     let format = Format::Bool;
-                    self.update_container_format(format, UpdateMode::Force);
+                    self.update_container_format(format, UpdateMode::Force)?;
                 }
                 PrimitiveType::Numeric(NumericType::Float) => {
                     let format = Format::F32; // or F64, but F32 is more common
-                    self.update_container_format(format, UpdateMode::Force);
+                    self.update_container_format(format, UpdateMode::Force)?;
                 }
                 PrimitiveType::Textual(TextualType::Str) => {
                     let format = Format::Str;
-                    self.update_container_format(format, UpdateMode::Force);
+                    self.update_container_format(format, UpdateMode::Force)?;
                 }
-                p => {
-                    unimplemented!("Unknown primitive type: {p:?}");
+                _ => {
+                    if let Some(format) = type_to_format(shape)? {
+                        self.update_container_format(format, UpdateMode::Force)?;
+                    }
                 }
             },
             Type::Pointer(PointerType::Reference(pt) | PointerType::Raw(pt)) => {
                 self.format(pt.target)?;
             }
+            Type::User(UserType::Union(_)) => return Err(union_unsupported(shape)),
             _ => {}
         }
 
@@ -489,7 +644,7 @@ impl RegistryBuilder {
 
     fn format_scalar(&mut self, shape: &Shape) -> Result<(), Error> {
         if let Some(format) = type_to_format(shape)? {
-            self.update_container_format(format, UpdateMode::Force);
+            self.update_container_format(format, UpdateMode::Force)?;
         }
         // If type_to_format returns None, we skip this field
         Ok(())
@@ -497,12 +652,13 @@ impl RegistryBuilder {
 
     fn format_struct(&mut self, struct_type: &StructType, shape: &Shape) -> Result<(), Error> {
         let struct_name = self.get_name_with_mappings(shape)?;
+        let previous = self.previous_instantiation(shape, &struct_name);
 
         // Check if already processed using the full namespaced name
-        if self.is_processed(&struct_name) {
+        if self.is_processed(&struct_name) && previous.is_none() {
             // This is a mutual recursion case - only update if there's an unknown format that needs updating
             let format = Format::TypeName(struct_name);
-            self.update_container_format(format, UpdateMode::MutualRecursion);
+            self.update_container_format(format, UpdateMode::MutualRecursion)?;
             return Ok(());
         }
 
@@ -526,8 +682,8 @@ impl RegistryBuilder {
         match struct_type.kind {
             StructKind::Unit => {
                 self.push_with_type_check(
-                    struct_name,
-                    ContainerFormat::UnitStruct(shape.into()),
+                    struct_name.clone(),
+                    ContainerFormat::UnitStruct(shape.into(), container_metadata(shape)?),
                     shape,
                 )?;
                 self.pop();
@@ -543,7 +699,7 @@ impl RegistryBuilder {
                     if is_transparent {
                         // For transparent structs, don't create a container - just process the inner type
                         // This will register the transparent struct with its inner type's format
-                        if !self.try_handle_bytes_attribute(&field) {
+                        if !self.try_handle_format_attribute(&field)? {
                             self.format(field_shape)?;
                         }
                         self.pop_namespace();
@@ -551,45 +707,70 @@ impl RegistryBuilder {
                     }
 
                     // Handle regular newtype struct
-                    let container = ContainerFormat::NewTypeStruct(Box::default(), shape.into());
-                    self.push_with_type_check(struct_name, container, shape)?;
+                    let container = ContainerFormat::NewTypeStruct(
+                        Box::default(),
+                        shape.into(),
+                        container_metadata(shape)?,
+                    );
+                    self.push_with_type_check(struct_name.clone(), container, shape)?;
 
                     // Process the inner field
-                    if !self.try_handle_bytes_attribute(&field) {
-                        self.format(field_shape)?;
-                    }
+                    self.within(field.name, |builder| {
+                        if !builder.try_handle_format_attribute(&field)? {
+                            builder.format(field_shape)?;
+                        }
+                        Ok(())
+                    })?;
                 } else {
                     // Handle tuple struct with multiple fields
-                    let container = ContainerFormat::TupleStruct(vec![], shape.into());
-                    self.push_with_type_check(struct_name, container, shape)?;
+                    let container = ContainerFormat::TupleStruct(
+                        vec![],
+                        shape.into(),
+                        container_metadata(shape)?,
+                    );
+                    self.push_with_type_check(struct_name.clone(), container, shape)?;
                     for field in struct_type.fields {
                         let skip = field.flags.contains(FieldFlags::SKIP);
                         if skip {
                             continue;
                         }
-                        if !self.try_handle_bytes_attribute(field) {
-                            self.format(field.shape())?;
-                        }
+                        self.within(field.name, |builder| {
+                            if !builder.try_handle_format_attribute(field)? {
+                                builder.format(field.shape())?;
+                            }
+                            Ok(())
+                        })?;
                     }
                 }
                 self.pop();
             }
             StructKind::Struct => {
-                let container = ContainerFormat::Struct(vec![], shape.into());
-                self.push_with_type_check(struct_name, container, shape)?;
+                let container =
+                    ContainerFormat::Struct(vec![], shape.into(), container_metadata(shape)?);
+                self.push_with_type_check(struct_name.clone(), container, shape)?;
                 for field in struct_type.fields {
                     let skip = field.flags.contains(FieldFlags::SKIP);
                     if skip {
                         continue;
                     }
-                    self.handle_struct_field(field)?;
+                    self.within(field.name, |builder| builder.handle_struct_field(field))?;
+                }
+
+                // Flattened fields share the struct's namespace of field names
+                if let Some(ContainerFormat::Struct(fields, _, _)) = self.get_mut()
+                    && let Some(field) = duplicate_field(fields)
+                {
+                    return Err(Error::DuplicateField {
+                        type_name: shape.type_identifier.to_string(),
+                        field,
+                    });
                 }
 
                 // If all fields were skipped, convert to UnitStruct to avoid empty data class issues
-                if let Some(ContainerFormat::Struct(fields, doc)) = self.get_mut()
+                if let Some(ContainerFormat::Struct(fields, doc, metadata)) = self.get_mut()
                     && fields.is_empty()
                 {
-                    let unit_container = ContainerFormat::UnitStruct(doc.clone());
+                    let unit_container = ContainerFormat::UnitStruct(doc.clone(), metadata.clone());
                     if let Some(current_name) = self.current.last() {
                         self.registry.insert(current_name.clone(), unit_container);
                     }
@@ -603,15 +784,39 @@ impl RegistryBuilder {
             }
         }
 
+        if let Some(mut container) = self.registry.remove(&struct_name) {
+            let mut result = Ok(());
+            if self.is_first_instantiation(shape, &struct_name) {
+                let mut monomorphic = container.clone();
+                result = self.resolve_struct_generics(struct_type, &[], &mut monomorphic, None);
+                monomorphic.metadata_mut().type_params.clear();
+                self.monomorphic.insert(struct_name.clone(), monomorphic);
+            }
+            let result = result.and_then(|()| {
+                self.resolve_struct_generics(
+                    struct_type,
+                    shape.type_params,
+                    &mut container,
+                    previous.as_ref(),
+                )
+            });
+            self.registry.insert(struct_name, container);
+            result?;
+        }
+
         self.pop_namespace();
         Ok(())
     }
 
     fn handle_struct_field(&mut self, field: &Field) -> Result<(), Error> {
+        if field.is_flattened() {
+            return self.handle_flattened_field(field);
+        }
+
         let field_shape = field.shape();
 
         // Check for field-level attributes first
-        if self.try_handle_bytes_attribute(field) {
+        if self.try_handle_format_attribute(field)? {
             return Ok(());
         }
 
@@ -652,35 +857,75 @@ impl RegistryBuilder {
 
         self.pop_namespace();
 
-        if let Some(ContainerFormat::Struct(named_formats, _doc)) = self.get_mut() {
+        if let Some(ContainerFormat::Struct(named_formats, _doc, _)) = self.get_mut() {
             let format = Named {
                 name: field_display_name(field),
                 doc: field.into(),
                 value: field_format,
+                metadata: field_metadata(field)?,
             };
             named_formats.push(format);
         }
         Ok(())
     }
 
-    fn try_handle_bytes_attribute(&mut self, field: &Field) -> bool {
-        let Some(value) = bytes_attribute_format(field) else {
-            return false;
+    /// Inlines the fields of a `#[facet(flatten)]` field's struct into the struct (or struct
+    /// variant) being reflected, which is how serde writes them, and marks them as
+    /// [`flattened`](FieldMetadata::flattened). The flattened struct itself isn't registered.
+    fn handle_flattened_field(&mut self, field: &Field) -> Result<(), Error> {
+        let shape = field.shape();
+        let Type::User(UserType::Struct(struct_type)) = shape.ty else {
+            return Err(unsupported(
+                shape,
+                "only structs with named fields can be flattened",
+            ));
+        };
+        if struct_type.kind != StructKind::Struct {
+            return Err(unsupported(
+                shape,
+                "only structs with named fields can be flattened",
+            ));
+        }
+
+        let inlined_from = match self.get_mut() {
+            Some(ContainerFormat::Struct(fields, ..)) => fields.len(),
+            _ => 0,
+        };
+        self.push_namespace(extract_namespace_from_shape(shape)?);
+        for field in struct_type.fields {
+            if field.flags.contains(FieldFlags::SKIP) {
+                continue;
+            }
+            self.within(field.name, |builder| builder.handle_struct_field(field))?;
+        }
+        self.pop_namespace();
+        if let Some(ContainerFormat::Struct(fields, ..)) = self.get_mut() {
+            for field in fields.iter_mut().skip(inlined_from) {
+                field.metadata.flattened = true;
+            }
+        }
+        Ok(())
+    }
+
+    fn try_handle_format_attribute(&mut self, field: &Field) -> Result<bool, Error> {
+        let Some(value) = field_attribute_format(field)? else {
+            return Ok(false);
         };
         let Some(container) = self.get_mut() else {
-            return false;
+            return Ok(false);
         };
         match container {
-            ContainerFormat::NewTypeStruct(format, _doc) => **format = value,
-            ContainerFormat::TupleStruct(formats, _doc) => formats.push(value),
-            ContainerFormat::Struct(nameds, _doc) => nameds.push(Named {
+            ContainerFormat::NewTypeStruct(format, _doc, _) => **format = value,
+            ContainerFormat::TupleStruct(formats, _doc, _) => formats.push(value),
+            ContainerFormat::Struct(nameds, _doc, _) => nameds.push(Named {
                 name: field_display_name(field),
                 doc: field.shape().into(),
                 value,
+                metadata: field_metadata(field)?,
             }),
-            _ => return false,
+            _ => return Ok(false),
         }
-        true
+        Ok(true)
     }
 
     fn try_handle_option_field(&mut self, field: &Field) -> Result<bool, Error> {
@@ -695,11 +940,12 @@ impl RegistryBuilder {
             let inner_format = get_format_for_shape(inner_shape)?;
             let option_format = Format::Option(Box::new(inner_format));
 
-            if let Some(ContainerFormat::Struct(named_formats, _doc)) = self.get_mut() {
+            if let Some(ContainerFormat::Struct(named_formats, _doc, _)) = self.get_mut() {
                 named_formats.push(Named {
                     name: field_display_name(field),
                     doc: field.into(),
                     value: option_format,
+                    metadata: field_metadata(field)?,
                 });
             }
 
@@ -725,7 +971,7 @@ impl RegistryBuilder {
                     tuple_formats.push(field_format);
                 }
 
-                if let Some(ContainerFormat::Struct(named_formats, _doc)) = self.get_mut() {
+                if let Some(ContainerFormat::Struct(named_formats, _doc, _)) = self.get_mut() {
                     let tuple_format = if tuple_formats.is_empty() {
                         Format::Unit
                     } else {
@@ -735,6 +981,7 @@ impl RegistryBuilder {
                         name: field_display_name(field),
                         doc: field.into(),
                         value: tuple_format,
+                        metadata: field_metadata(field)?,
                     });
                 }
                 return Ok(true);
@@ -763,11 +1010,12 @@ impl RegistryBuilder {
                     get_inner_format(inner_field_shape)?
                 };
 
-                if let Some(ContainerFormat::Struct(named_formats, _doc)) = self.get_mut() {
+                if let Some(ContainerFormat::Struct(named_formats, _doc, _)) = self.get_mut() {
                     named_formats.push(Named {
                         name: field_display_name(field),
                         doc: field.into(),
                         value: inner_format,
+                        metadata: field_metadata(field)?,
                     });
                 }
 
@@ -785,9 +1033,10 @@ impl RegistryBuilder {
 
     fn format_enum(&mut self, enum_type: &EnumType, shape: &Shape) -> Result<(), Error> {
         let enum_name = self.get_name_with_mappings(shape)?;
+        let previous = self.previous_instantiation(shape, &enum_name);
 
         // Check if already processed using the full namespaced name
-        if self.is_processed(&enum_name) {
+        if self.is_processed(&enum_name) && previous.is_none() {
             return Ok(());
         }
 
@@ -808,8 +1057,36 @@ impl RegistryBuilder {
         // Handle namespace context for the enum processing
         self.push_namespace(type_level_namespace);
 
-        let variants = self.process_enum_variants(enum_type, shape)?;
-        let container = ContainerFormat::Enum(variants, extract_enum_tagging(shape), shape.into());
+        let mut variants = self.process_enum_variants(enum_type, shape)?;
+        let previous_variants = match &previous {
+            Some(ContainerFormat::Enum(variants, ..)) => Some(variants),
+            _ => None,
+        };
+        if self.is_first_instantiation(shape, &enum_name) {
+            let mut monomorphic = variants.clone();
+            self.resolve_variant_generics(enum_type, &[], &mut monomorphic, None)?;
+            let mut metadata = container_metadata(shape)?;
+            metadata.type_params.clear();
+            let container = ContainerFormat::Enum(
+                monomorphic,
+                extract_enum_tagging(shape),
+                shape.into(),
+                metadata,
+            );
+            self.monomorphic.insert(enum_name.clone(), container);
+        }
+        self.resolve_variant_generics(
+            enum_type,
+            shape.type_params,
+            &mut variants,
+            previous_variants,
+        )?;
+        let container = ContainerFormat::Enum(
+            variants,
+            extract_enum_tagging(shape),
+            shape.into(),
+            container_metadata(shape)?,
+        );
         self.push_with_type_check(enum_name, container, shape)?;
         self.pop();
 
@@ -817,14 +1094,326 @@ impl RegistryBuilder {
         Ok(())
     }
 
+    /// Records `shape` as an instantiation of the type registered as `name`.
+    ///
+    /// Returns the registered definition when `shape` is a further instantiation of a generic
+    /// type that has already been reflected (e.g. `Page<User>` after `Page<Item>`). The type is
+    /// then reflected again, so that the new type arguments are registered and the fields can
+    /// refine the existing definition. Instantiations met while the type itself is still being
+    /// reflected (polymorphic recursion) are not reflected again.
+    fn previous_instantiation(
+        &mut self,
+        shape: &Shape,
+        name: &QualifiedTypeName,
+    ) -> Option<ContainerFormat> {
+        if !is_generic_shape(shape) || !self.instantiations.insert(shape.id) {
+            return None;
+        }
+        self.type_arguments
+            .entry(name.clone())
+            .or_default()
+            .push(shape.type_params);
+        if self.current.contains(name) {
+            return None;
+        }
+        self.registry.get(name).cloned()
+    }
+
+    /// Whether `shape` is the first instantiation of the generic type registered as `name` to
+    /// be reflected, whose definition is then also kept with its type arguments in place of
+    /// the type parameters, for [`build`](Self::build) to use if no other instantiation is
+    /// reachable.
+    fn is_first_instantiation(&self, shape: &Shape, name: &QualifiedTypeName) -> bool {
+        is_generic_shape(shape) && !self.monomorphic.contains_key(name)
+    }
+
+    fn resolve_struct_generics(
+        &mut self,
+        struct_type: &StructType,
+        params: &[TypeParam],
+        container: &mut ContainerFormat,
+        previous: Option<&ContainerFormat>,
+    ) -> Result<(), Error> {
+        match container {
+            ContainerFormat::NewTypeStruct(format, ..) => {
+                let previous = match previous {
+                    Some(ContainerFormat::NewTypeStruct(format, ..)) => Some(format.as_ref()),
+                    _ => None,
+                };
+                match struct_type.fields.first() {
+                    Some(field) => {
+                        self.resolve_format_generics(field.shape(), format, previous, params)
+                    }
+                    None => Ok(()),
+                }
+            }
+            ContainerFormat::TupleStruct(formats, ..) => {
+                let previous = match previous {
+                    Some(ContainerFormat::TupleStruct(formats, ..)) => Some(formats.as_slice()),
+                    _ => None,
+                };
+                self.resolve_positional_generics(struct_type.fields, formats, previous, params)
+            }
+            ContainerFormat::Struct(nameds, ..) => {
+                let previous = match previous {
+                    Some(ContainerFormat::Struct(nameds, ..)) => Some(nameds.as_slice()),
+                    _ => None,
+                };
+                self.resolve_named_generics(struct_type.fields, nameds, previous, params)
+            }
+            ContainerFormat::UnitStruct(..) | ContainerFormat::Enum(..) => Ok(()),
+        }
+    }
+
+    fn resolve_variant_generics(
+        &mut self,
+        enum_type: &EnumType,
+        params: &[TypeParam],
+        variants: &mut BTreeMap<u32, Named<VariantFormat>>,
+        previous: Option<&BTreeMap<u32, Named<VariantFormat>>>,
+    ) -> Result<(), Error> {
+        for variant in enum_type.variants {
+            let name = variant_display_name(variant);
+            let Some(named) = variants.values_mut().find(|named| named.name == name) else {
+                continue;
+            };
+            let previous = previous
+                .and_then(|variants| variants.values().find(|named| named.name == name))
+                .map(|named| &named.value);
+            let fields = variant.data.fields;
+            match &mut named.value {
+                VariantFormat::NewType(format) => {
+                    let previous = match previous {
+                        Some(VariantFormat::NewType(format)) => Some(format.as_ref()),
+                        _ => None,
+                    };
+                    if let Some(field) = fields.first() {
+                        self.resolve_format_generics(field.shape(), format, previous, params)?;
+                    }
+                }
+                VariantFormat::Tuple(formats) => {
+                    let previous = match previous {
+                        Some(VariantFormat::Tuple(formats)) => Some(formats.as_slice()),
+                        _ => None,
+                    };
+                    self.resolve_positional_generics(fields, formats, previous, params)?;
+                }
+                VariantFormat::Struct(nameds) => {
+                    let previous = match previous {
+                        Some(VariantFormat::Struct(nameds)) => Some(nameds.as_slice()),
+                        _ => None,
+                    };
+                    self.resolve_named_generics(fields, nameds, previous, params)?;
+                }
+                VariantFormat::Variable(_) | VariantFormat::Unit => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn resolve_named_generics(
+        &mut self,
+        fields: &[Field],
+        nameds: &mut [Named<Format>],
+        previous: Option<&[Named<Format>]>,
+        params: &[TypeParam],
+    ) -> Result<(), Error> {
+        for field in inlined_fields(fields) {
+            let name = field_display_name(field);
+            let Some(named) = nameds.iter_mut().find(|named| named.name == name) else {
+                continue;
+            };
+            let previous = previous
+                .and_then(|nameds| nameds.iter().find(|named| named.name == name))
+                .map(|named| &named.value);
+            self.resolve_format_generics(field.shape(), &mut named.value, previous, params)?;
+        }
+        Ok(())
+    }
+
+    fn resolve_positional_generics(
+        &mut self,
+        fields: &[Field],
+        formats: &mut [Format],
+        previous: Option<&[Format]>,
+        params: &[TypeParam],
+    ) -> Result<(), Error> {
+        let fields = fields
+            .iter()
+            .filter(|field| !field.flags.contains(FieldFlags::SKIP))
+            .collect::<Vec<_>>();
+        if fields.len() != formats.len() {
+            return Ok(());
+        }
+        for (index, (field, format)) in fields.into_iter().zip(formats).enumerate() {
+            let previous = previous.and_then(|formats| formats.get(index));
+            self.resolve_format_generics(field.shape(), format, previous, params)?;
+        }
+        Ok(())
+    }
+
+    /// Rewrites `format`, reflected from a value of `shape`, so that uses of the enclosing
+    /// container's type parameters become [`Format::TypeParameter`] and references to generic
+    /// containers become [`Format::Generic`].
+    ///
+    /// `facet` only describes the instantiated shape of a field, so a type parameter is
+    /// recognised by comparing the field's shape with the instantiation's type arguments.
+    /// `previous` is the same position in the definition reflected from earlier instantiations:
+    /// a position only stays a type parameter if every instantiation agrees, so a field that
+    /// merely happens to have the same type as a type argument (`total: u32` in `Page<u32>`)
+    /// is corrected by any other instantiation. [`build`](Self::build) deals with the types
+    /// that no other instantiation corrects.
+    fn resolve_format_generics(
+        &mut self,
+        mut shape: &Shape,
+        format: &mut Format,
+        previous: Option<&Format>,
+        params: &[TypeParam],
+    ) -> Result<(), Error> {
+        if matches!(format, Format::Bytes) {
+            return Ok(());
+        }
+
+        loop {
+            let mut candidates = params.iter().filter(|param| param.shape == shape);
+            if let Some(first) = candidates.next() {
+                match previous {
+                    None => {
+                        *format = Format::TypeParameter(first.name.to_string());
+                        return Ok(());
+                    }
+                    Some(Format::TypeParameter(name)) => {
+                        let param = std::iter::once(first)
+                            .chain(candidates)
+                            .find(|param| param.name == name)
+                            .unwrap_or(first);
+                        *format = Format::TypeParameter(param.name.to_string());
+                        return Ok(());
+                    }
+                    // an earlier instantiation shows that this is not a type parameter
+                    Some(_) => {}
+                }
+            }
+
+            // wrappers (`Box`, `Arc`, references, transparent structs) don't appear in formats
+            match unwrap_shape(shape) {
+                Some(inner) => shape = inner,
+                None => break,
+            }
+        }
+
+        let previous = previous.filter(|format| !matches!(format, Format::TypeParameter(_)));
+        match format {
+            Format::Option(inner) => {
+                if let Def::Option(def) = shape.def {
+                    let previous = match previous {
+                        Some(Format::Option(inner)) => Some(inner.as_ref()),
+                        _ => None,
+                    };
+                    self.resolve_format_generics(def.t(), inner, previous, params)?;
+                }
+            }
+            Format::Seq(inner) => {
+                let element = match shape.def {
+                    Def::List(def) => Some(def.t()),
+                    Def::Slice(def) => Some(def.t()),
+                    Def::Array(def) => Some(def.t()),
+                    _ => None,
+                };
+                if let Some(element) = element {
+                    let previous = match previous {
+                        Some(Format::Seq(inner)) => Some(inner.as_ref()),
+                        _ => None,
+                    };
+                    self.resolve_format_generics(element, inner, previous, params)?;
+                }
+            }
+            Format::Set(inner) => {
+                if let Def::Set(def) = shape.def {
+                    let previous = match previous {
+                        Some(Format::Set(inner)) => Some(inner.as_ref()),
+                        _ => None,
+                    };
+                    self.resolve_format_generics(def.t(), inner, previous, params)?;
+                }
+            }
+            Format::Map { key, value } => {
+                if let Def::Map(def) = shape.def {
+                    let (previous_key, previous_value) = match previous {
+                        Some(Format::Map { key, value }) => {
+                            (Some(key.as_ref()), Some(value.as_ref()))
+                        }
+                        _ => (None, None),
+                    };
+                    self.resolve_format_generics(def.k(), key, previous_key, params)?;
+                    self.resolve_format_generics(def.v(), value, previous_value, params)?;
+                }
+            }
+            Format::TupleArray { content, .. } => {
+                if let Def::Array(def) = shape.def {
+                    let previous = match previous {
+                        Some(Format::TupleArray { content, .. }) => Some(content.as_ref()),
+                        _ => None,
+                    };
+                    self.resolve_format_generics(def.t(), content, previous, params)?;
+                }
+            }
+            Format::Tuple(formats) => {
+                if let Type::User(UserType::Struct(struct_type)) = &shape.ty
+                    && struct_type.kind == StructKind::Tuple
+                {
+                    let previous = match previous {
+                        Some(Format::Tuple(formats)) => Some(formats.as_slice()),
+                        _ => None,
+                    };
+                    self.resolve_positional_generics(
+                        struct_type.fields,
+                        formats,
+                        previous,
+                        params,
+                    )?;
+                }
+            }
+            Format::TypeName(name) if is_generic_shape(shape) => {
+                let previous_args = match previous {
+                    Some(Format::Generic { args, .. }) => Some(args.as_slice()),
+                    _ => None,
+                };
+                let mut args = Vec::with_capacity(shape.type_params.len());
+                for (index, arg) in shape.type_params.iter().enumerate() {
+                    let mut format = self
+                        .get_user_type_format(arg.shape)?
+                        .unwrap_or(Format::Unit);
+                    let previous = previous_args.and_then(|args| args.get(index));
+                    self.resolve_format_generics(arg.shape, &mut format, previous, params)?;
+                    args.push(format);
+                }
+                *format = Format::Generic {
+                    name: name.clone(),
+                    args,
+                };
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
     fn process_enum_variants(
         &mut self,
         enum_type: &EnumType,
         shape: &Shape,
     ) -> Result<BTreeMap<u32, Named<VariantFormat>>, Error> {
-        let mut variants = BTreeMap::new();
+        let mut variants: BTreeMap<u32, Named<VariantFormat>> = BTreeMap::new();
         let mut variant_index = 0u32;
 
+        // Discriminants are only used as indices if some are written out, as they otherwise
+        // count every variant, including skipped ones.
+        let explicit_discriminants = enum_type
+            .variants
+            .iter()
+            .zip(0..)
+            .any(|(variant, position)| variant.discriminant.is_some_and(|d| d != position));
+
         for variant in enum_type.variants {
             let skip = variant
                 .attributes
@@ -834,17 +1423,66 @@ impl RegistryBuilder {
                 continue;
             }
 
-            let variant_format = self.process_single_variant(variant, shape)?;
+            let pinned = pinned_index(variant.attributes).or(variant
+                .discriminant
+                .filter(|_| explicit_discriminants)
+                .map(i128::from));
+            if let Some(index) = pinned {
+                variant_index = u32::try_from(index).map_err(|_| Error::InvalidVariantIndex {
+                    type_name: shape.type_identifier.to_string(),
+                    variant: variant.name.to_string(),
+                    index,
+                })?;
+            }
 
+            let Some(variant_format) = self.within(variant.name, |builder| {
+                builder.process_single_variant(variant, shape)
+            })?
+            else {
+                continue;
+            };
+
+            if let Some(existing) = variants.get(&variant_index) {
+                return Err(Error::DuplicateVariantIndex {
+                    type_name: shape.type_identifier.to_string(),
+                    index: variant_index,
+                    first: existing.name.clone(),
+                    second: variant_display_name(variant),
+                });
+            }
             variants.insert(
                 variant_index,
                 Named {
                     name: variant_display_name(variant),
                     doc: variant.into(),
                     value: variant_format,
+                    metadata: FieldMetadata {
+                        targets: targets(variant.attributes),
+                        deprecated: deprecation(variant.attributes),
+                        other: variant.is_other(),
+                        ..FieldMetadata::default()
+                    },
                 },
             );
-            variant_index += 1;
+            variant_index = variant_index.saturating_add(1);
+        }
+
+        // Deserializers can only make up a catch-all value without a payload.
+        let mut others = variants.values().filter(|variant| variant.metadata.other);
+        if let Some(other) = others.next() {
+            let message = if others.next().is_some() {
+                Some("only one variant can be marked `#[facet(other)]`")
+            } else if !matches!(other.value, VariantFormat::Unit) {
+                Some("the `#[facet(other)]` variant must be a unit variant")
+            } else {
+                None
+            };
+            if let Some(message) = message {
+                return Err(Error::ReflectionError {
+                    type_name: shape.type_identifier.to_string(),
+                    message: message.to_string(),
+                });
+            }
         }
 
         Ok(variants)
@@ -867,7 +1505,14 @@ impl RegistryBuilder {
             if is_struct_variant {
                 self.process_struct_variant(variant, shape)
             } else {
-                self.process_newtype_variant(variant, shape)
+                // When errors are collected, the registry is discarded, so the format of a
+                // payload that failed doesn't matter.
+                let field = variant.data.fields[0];
+                Ok(self
+                    .within(field.name, |builder| {
+                        builder.process_newtype_variant(variant, shape)
+                    })?
+                    .unwrap_or(VariantFormat::Unit))
             }
         } else {
             self.process_multi_field_variant(variant, shape)
@@ -880,7 +1525,7 @@ impl RegistryBuilder {
         shape: &Shape,
     ) -> Result<VariantFormat, Error> {
         let field = variant.data.fields[0];
-        if let Some(value) = bytes_attribute_format(&field) {
+        if let Some(value) = field_attribute_format(&field)? {
             return Ok(VariantFormat::NewType(Box::new(value)));
         }
         let field_shape = field.shape();
@@ -1001,7 +1646,7 @@ impl RegistryBuilder {
     ) -> Result<VariantFormat, Error> {
         let temp = self.push_temporary(
             variant_display_name(variant),
-            ContainerFormat::Struct(vec![], variant.into()),
+            ContainerFormat::Struct(vec![], variant.into(), ContainerMetadata::default()),
             Some(shape),
         );
 
@@ -1012,104 +1657,128 @@ impl RegistryBuilder {
             if skip {
                 continue;
             }
+            self.within(field.name, |builder| {
+                builder.process_struct_variant_field(field)
+            })?;
+        }
 
-            let field_shape = field.shape();
+        // Flattened fields share the variant's namespace of field names
+        if let Some(ContainerFormat::Struct(fields, _, _)) = self.registry.get(&temp)
+            && let Some(field) = duplicate_field(fields)
+        {
+            return Err(Error::DuplicateField {
+                type_name: format!("{}::{}", shape.type_identifier, variant.name),
+                field,
+            });
+        }
 
-            // Check for field-level attributes first
-            if let Some(value) = bytes_attribute_format(field) {
-                if let Some(ContainerFormat::Struct(named_formats, _doc)) = self.get_mut() {
-                    named_formats.push(Named {
-                        name: field_display_name(field),
-                        doc: field.into(),
-                        value,
-                    });
+        // Extract the formats from the temporary container
+        let variant_format = match self.registry.get(&temp) {
+            Some(ContainerFormat::Struct(named_formats, _doc, _)) => {
+                if named_formats.is_empty() {
+                    // If all fields were skipped, this should be a unit variant
+                    VariantFormat::Unit
+                } else {
+                    VariantFormat::Struct(named_formats.clone())
                 }
-                continue;
             }
+            _ => VariantFormat::Unit, // Handles missing entries
+        };
 
-            // Check for field-level namespace annotation
-            let field_namespace = extract_namespace_from_field_attributes(field)?;
+        // Clean up the temporary container
+        let _removed = self.registry.remove(&temp);
 
-            self.push_namespace(field_namespace.clone());
+        self.pop();
 
-            // Handle Option types specially (like handle_struct_field does)
-            if field_shape.type_identifier == "Option"
-                && let Def::Option(option_def) = field_shape.def
-            {
-                let inner_shape = option_def.t();
-                let inner_format =
-                    get_inner_format_with_context(inner_shape, self.current_namespace())?;
-                let option_format = Format::Option(Box::new(inner_format));
+        Ok(variant_format)
+    }
 
-                // Process any user-defined types in the nested structure
-                if !matches!(inner_shape.def, Def::Scalar) {
-                    self.format(inner_shape)?;
-                }
+    /// Adds a field of a struct variant to the temporary struct of its variant.
+    fn process_struct_variant_field(&mut self, field: &Field) -> Result<(), Error> {
+        if field.is_flattened() {
+            return self.handle_flattened_field(field);
+        }
 
-                self.pop_namespace();
+        let field_shape = field.shape();
 
-                if let Some(ContainerFormat::Struct(named_formats, _doc)) = self.get_mut() {
-                    named_formats.push(Named {
-                        name: field_display_name(field),
-                        doc: field.into(),
-                        value: option_format,
-                    });
-                }
-                continue;
+        // Check for field-level attributes first
+        if let Some(value) = field_attribute_format(field)? {
+            if let Some(ContainerFormat::Struct(named_formats, _doc, _)) = self.get_mut() {
+                named_formats.push(Named {
+                    name: field_display_name(field),
+                    doc: field.into(),
+                    value,
+                    metadata: field_metadata(field)?,
+                });
             }
+            return Ok(());
+        }
 
-            // Determine the proper format with the field-level context in place
-            let Some(value) = self.get_user_type_format(field_shape)? else {
-                // Skip this field if format couldn't be determined
-                self.pop_namespace();
-                continue;
-            };
+        // Check for field-level namespace annotation
+        let field_namespace = extract_namespace_from_field_attributes(field)?;
 
-            // Process the type under the field-level namespace context
-            if let NamespaceAction::SetContext(ctx) = &field_namespace {
-                if ctx.is_explicit() {
-                    if let Namespace::Named(name) = &ctx.namespace {
-                        self.format_with_namespace_override(field_shape, name)?;
-                    } else {
-                        self.format(field_shape)?;
-                    }
-                } else {
-                    self.format(field_shape)?;
-                }
-            } else {
-                self.format(field_shape)?;
+        self.push_namespace(field_namespace.clone());
+
+        // Handle Option types specially (like handle_struct_field does)
+        if field_shape.type_identifier == "Option"
+            && let Def::Option(option_def) = field_shape.def
+        {
+            let inner_shape = option_def.t();
+            let inner_format =
+                get_inner_format_with_context(inner_shape, self.current_namespace())?;
+            let option_format = Format::Option(Box::new(inner_format));
+
+            // Process any user-defined types in the nested structure
+            if !matches!(inner_shape.def, Def::Scalar) {
+                self.format(inner_shape)?;
             }
 
             self.pop_namespace();
 
-            if let Some(ContainerFormat::Struct(named_formats, _doc)) = self.get_mut() {
+            if let Some(ContainerFormat::Struct(named_formats, _doc, _)) = self.get_mut() {
                 named_formats.push(Named {
                     name: field_display_name(field),
                     doc: field.into(),
-                    value,
+                    value: option_format,
+                    metadata: field_metadata(field)?,
                 });
             }
+            return Ok(());
         }
 
-        // Extract the formats from the temporary container
-        let variant_format = match self.registry.get(&temp) {
-            Some(ContainerFormat::Struct(named_formats, _doc)) => {
-                if named_formats.is_empty() {
-                    // If all fields were skipped, this should be a unit variant
-                    VariantFormat::Unit
+        // Determine the proper format with the field-level context in place
+        let Some(value) = self.get_user_type_format(field_shape)? else {
+            // Skip this field if format couldn't be determined
+            self.pop_namespace();
+            return Ok(());
+        };
+
+        // Process the type under the field-level namespace context
+        if let NamespaceAction::SetContext(ctx) = &field_namespace {
+            if ctx.is_explicit() {
+                if let Namespace::Named(name) = &ctx.namespace {
+                    self.format_with_namespace_override(field_shape, name)?;
                 } else {
-                    VariantFormat::Struct(named_formats.clone())
+                    self.format(field_shape)?;
                 }
+            } else {
+                self.format(field_shape)?;
             }
-            _ => VariantFormat::Unit, // Handles missing entries
-        };
-
-        // Clean up the temporary container
-        let _removed = self.registry.remove(&temp);
+        } else {
+            self.format(field_shape)?;
+        }
 
-        self.pop();
+        self.pop_namespace();
 
-        Ok(variant_format)
+        if let Some(ContainerFormat::Struct(named_formats, _doc, _)) = self.get_mut() {
+            named_formats.push(Named {
+                name: field_display_name(field),
+                doc: field.into(),
+                value,
+                metadata: field_metadata(field)?,
+            });
+        }
+        Ok(())
     }
 
     fn process_tuple_variant(
@@ -1119,7 +1788,7 @@ impl RegistryBuilder {
     ) -> Result<VariantFormat, Error> {
         let temp = self.push_temporary(
             variant_display_name(variant),
-            ContainerFormat::TupleStruct(vec![], variant.into()),
+            ContainerFormat::TupleStruct(vec![], variant.into(), ContainerMetadata::default()),
             Some(shape),
         );
 
@@ -1131,27 +1800,32 @@ impl RegistryBuilder {
                 continue;
             }
 
-            if let Some(value) = bytes_attribute_format(field) {
-                if let Some(ContainerFormat::TupleStruct(formats, _doc)) = self.get_mut() {
-                    formats.push(value);
+            self.within(field.name, |builder| {
+                if let Some(value) = field_attribute_format(field)? {
+                    if let Some(ContainerFormat::TupleStruct(formats, _doc, _)) = builder.get_mut()
+                    {
+                        formats.push(value);
+                    }
+                    return Ok(());
                 }
-                continue;
-            }
-            // Use the namespace context of the current enum for its variant fields
-            let transparent_namespace = extract_namespace_from_shape(shape)?;
+                // Use the namespace context of the current enum for its variant fields
+                let transparent_namespace = extract_namespace_from_shape(shape)?;
 
-            self.push_namespace(transparent_namespace);
-            self.format(field.shape())?;
-            self.pop_namespace();
+                builder.push_namespace(transparent_namespace);
+                builder.format(field.shape())?;
+                builder.pop_namespace();
+                Ok(())
+            })?;
         }
 
         // Extract the formats from the temporary container
-        let variant_format =
-            if let Some(ContainerFormat::TupleStruct(formats, _doc)) = self.registry.get(&temp) {
-                VariantFormat::Tuple(formats.clone())
-            } else {
-                VariantFormat::Unit
-            };
+        let variant_format = if let Some(ContainerFormat::TupleStruct(formats, _doc, _)) =
+            self.registry.get(&temp)
+        {
+            VariantFormat::Tuple(formats.clone())
+        } else {
+            VariantFormat::Unit
+        };
 
         // Clean up the temporary container
         let _removed = self.registry.remove(&temp);
@@ -1170,7 +1844,7 @@ impl RegistryBuilder {
         let seq_format = Format::Seq(Box::new(inner_format));
 
         // Update the current container with the sequence format
-        self.update_container_format(seq_format, UpdateMode::Force);
+        self.update_container_format(seq_format, UpdateMode::Force)?;
 
         // Process any user-defined types in the nested structure
         self.process_nested_types(inner_shape)?;
@@ -1193,7 +1867,7 @@ impl RegistryBuilder {
         };
 
         // Update the current container with the map format
-        self.update_container_format(map_format, UpdateMode::Force);
+        self.update_container_format(map_format, UpdateMode::Force)?;
 
         // Process any user-defined types in the nested structure
         self.process_nested_types(key_shape)?;
@@ -1212,7 +1886,7 @@ impl RegistryBuilder {
         let slice_format = Format::Seq(Box::new(inner_format));
 
         // Update the current container with the slice format
-        self.update_container_format(slice_format, UpdateMode::Force);
+        self.update_container_format(slice_format, UpdateMode::Force)?;
 
         // Process any user-defined types in the nested structure
         self.process_nested_types(inner_shape)?;
@@ -1234,7 +1908,7 @@ impl RegistryBuilder {
         };
 
         // Update the current container with the array format
-        self.update_container_format(array_format, UpdateMode::Force);
+        self.update_container_format(array_format, UpdateMode::Force)?;
 
         // If the inner type is a user-defined type, we need to process it too
         if !matches!(inner_shape.def, Def::Scalar) {
@@ -1253,7 +1927,7 @@ impl RegistryBuilder {
         let option_format = Format::Option(Box::new(inner_format));
 
         // Update the current container with the option format
-        self.update_container_format(option_format, UpdateMode::Force);
+        self.update_container_format(option_format, UpdateMode::Force)?;
 
         // Process any user-defined types in the nested structure
         self.process_nested_types(inner_shape)?;
@@ -1272,7 +1946,7 @@ impl RegistryBuilder {
         let set_format = Format::Set(Box::new(element_format));
 
         // Update the current container with the set format
-        self.update_container_format(set_format, UpdateMode::Force);
+        self.update_container_format(set_format, UpdateMode::Force)?;
 
         // Process any user-defined types in the nested structure
         self.process_nested_types(element_shape)?;
@@ -1280,10 +1954,10 @@ impl RegistryBuilder {
         Ok(())
     }
 
-    fn handle_opaque_pointee(&mut self) {
+    fn handle_opaque_pointee(&mut self) -> Result<(), Error> {
         // For pointers that point to opaque types, treat as unit type for now
         let format = Format::Unit;
-        self.update_container_format(format, UpdateMode::Force);
+        self.update_container_format(format, UpdateMode::Force)
     }
 
     /// Push a namespace action onto the stack (always pushes something)
@@ -1346,8 +2020,10 @@ impl RegistryBuilder {
             }
             Type::Pointer(PointerType::Reference(pt) | PointerType::Raw(pt)) => {
                 let target_shape = pt.target;
-                get_inner_format_with_context(target_shape, self.current_namespace())
-                    .map_or(Ok(None), |format| Ok(Some(format)))
+                skip_unless_unsupported(get_inner_format_with_context(
+                    target_shape,
+                    self.current_namespace(),
+                ))
             }
             _ => {
                 // Check if this is an opaque type that should be skipped.
@@ -1355,18 +2031,21 @@ impl RegistryBuilder {
                 if matches!(field_shape.def, Def::Undefined) {
                     Ok(None)
                 } else {
-                    get_inner_format_with_context(field_shape, self.current_namespace())
-                        .map_or(Ok(None), |format| Ok(Some(format)))
+                    skip_unless_unsupported(get_inner_format_with_context(
+                        field_shape,
+                        self.current_namespace(),
+                    ))
                 }
             }
         }
     }
 
-    fn update_container_format(&mut self, format: Format, mode: UpdateMode) {
+    fn update_container_format(&mut self, format: Format, mode: UpdateMode) -> Result<(), Error> {
+        let name = self.current.last().cloned();
         if let Some(container_format) = self.get_mut() {
             match container_format {
-                ContainerFormat::UnitStruct(_doc) => {}
-                ContainerFormat::NewTypeStruct(inner_format, _doc) => match mode {
+                ContainerFormat::UnitStruct(_doc, _) => {}
+                ContainerFormat::NewTypeStruct(inner_format, _doc, _) => match mode {
                     UpdateMode::Force => {
                         **inner_format = format;
                     }
@@ -1376,7 +2055,7 @@ impl RegistryBuilder {
                         }
                     }
                 },
-                ContainerFormat::TupleStruct(formats, _doc) => {
+                ContainerFormat::TupleStruct(formats, _doc, _) => {
                     match mode {
                         UpdateMode::Force | UpdateMode::IfUnknown => {
                             formats.push(format);
@@ -1387,7 +2066,7 @@ impl RegistryBuilder {
                         }
                     }
                 }
-                ContainerFormat::Struct(fields, _doc) => {
+                ContainerFormat::Struct(fields, _doc, _) => {
                     if let Some(last_named) = fields.last_mut() {
                         match mode {
                             UpdateMode::Force => {
@@ -1406,13 +2085,18 @@ impl RegistryBuilder {
                         }
                     }
                 }
-                ContainerFormat::Enum(_, _, _doc) => {
+                ContainerFormat::Enum(_, _, _doc, _) => {
                     if matches!(mode, UpdateMode::Force) {
-                        todo!("Enum container format update not implemented");
+                        return Err(Error::Unsupported {
+                            path: String::new(),
+                            type_name: name.map(|name| name.to_string()).unwrap_or_default(),
+                            hint: "an enum can't be represented by a single format".to_string(),
+                        });
                     }
                 }
             }
         }
+        Ok(())
     }
 
     fn process_nested_types(&mut self, shape: &Shape) -> Result<(), Error> {
@@ -1598,7 +2282,12 @@ fn type_to_format(shape: &Shape) -> Result<Option<Format>, Error> {
                     match shape.type_identifier {
                         "f32" => Format::F32,
                         "f64" => Format::F64,
-                        _ => unimplemented!("Unsupported float type: {}", shape.type_identifier),
+                        _ => {
+                            return Err(unsupported(
+                                shape,
+                                "only `f32` and `f64` floating-point numbers are supported",
+                            ));
+                        }
                     }
                 }
                 NumericType::Integer { signed } => {
@@ -1621,9 +2310,12 @@ fn type_to_format(shape: &Shape) -> Result<Option<Format>, Error> {
                         (true, 32) => Format::I32,
                         (true, 64) => Format::I64,
                         (true, 128) => Format::I128,
-                        _ => unimplemented!(
-                            "Unsupported integer type: {size_bits} bits, signed: {signed}"
-                        ),
+                        _ => {
+                            return Err(unsupported(
+                                shape,
+                                "only 8, 16, 32, 64 and 128-bit integers are supported",
+                            ));
+                        }
                     }
                 }
             },
@@ -1632,13 +2324,26 @@ fn type_to_format(shape: &Shape) -> Result<Option<Format>, Error> {
                 TextualType::Char => Format::Char,
             },
             PrimitiveType::Never => {
-                unimplemented!("Never type not supported: {}", shape.type_identifier)
+                return Err(unsupported(
+                    shape,
+                    "the never type `!` has no values, so it can't be serialized",
+                ));
             }
         }),
-        Type::User(UserType::Opaque) => match shape.type_identifier {
-            "String" | "DateTime<Utc>" => Some(Format::Str),
-            "Uuid" => Some(Format::Uuid),
-            _ => None,
+        Type::User(UserType::Opaque) => match (shape.module_path, shape.type_identifier) {
+            (_, "String") => Some(Format::Str),
+            (_, "Uuid") => Some(Format::Uuid),
+            (Some("chrono"), "DateTime<Utc>" | "DateTime<FixedOffset>" | "DateTime<Local>")
+            | (Some("jiff"), "Timestamp") => Some(Format::Timestamp),
+            (Some("chrono"), "NaiveDate") | (Some("jiff"), "Date") => Some(Format::Date),
+            (Some("chrono"), "NaiveTime") | (Some("jiff"), "Time") => Some(Format::Time),
+            (Some("core::time"), "Duration") => Some(Format::Duration),
+            _ => {
+                return Err(unsupported(
+                    shape,
+                    "only the dates and times of chrono and jiff, `Duration`, `String` and `Uuid` are supported",
+                ));
+            }
         },
         // Handle () unit type which in facet 0.44.1 appears as User(Struct(Tuple, []))
         Type::User(UserType::Struct(st))
@@ -1646,16 +2351,79 @@ fn type_to_format(shape: &Shape) -> Result<Option<Format>, Error> {
         {
             Some(Format::Unit)
         }
-        _ => unimplemented!(
-            "Unsupported type for scalar format: {}: {:?}",
-            shape.type_identifier,
-            shape.ty
-        ),
+        Type::User(UserType::Union(_)) => return Err(union_unsupported(shape)),
+        _ => {
+            return Err(unsupported(
+                shape,
+                "only primitives, strings and well-known opaque types can be used as scalars",
+            ));
+        }
     };
 
     Ok(format)
 }
 
+/// The fields of a struct in the order of its format, with the fields of its
+/// `#[facet(flatten)]` fields inlined in their place.
+fn inlined_fields(fields: &[Field]) -> Vec<&Field> {
+    let mut inlined = vec![];
+    for field in fields {
+        if field.flags.contains(FieldFlags::SKIP) {
+            continue;
+        }
+        match field.shape().ty {
+            Type::User(UserType::Struct(struct_type)) if field.is_flattened() => {
+                inlined.extend(inlined_fields(struct_type.fields));
+            }
+            _ => inlined.push(field),
+        }
+    }
+    inlined
+}
+
+/// The first name given to more than one of a struct's fields, which can happen when fields are
+/// flattened into it.
+fn duplicate_field(fields: &[Named<Format>]) -> Option<String> {
+    let mut names = HashSet::new();
+    fields
+        .iter()
+        .find(|field| !names.insert(field.name.as_str()))
+        .map(|field| field.name.clone())
+}
+
+/// Skips a field whose format can't be determined, unless its type is unsupported.
+fn skip_unless_unsupported(format: Result<Format, Error>) -> Result<Option<Format>, Error> {
+    match format {
+        Ok(format) => Ok(Some(format)),
+        Err(error @ Error::Unsupported { .. }) => Err(error),
+        Err(_) => Ok(None),
+    }
+}
+
+/// An [`Error::Unsupported`] for `shape`, whose path is filled in by the [`RegistryBuilder`].
+fn unsupported(shape: &Shape, hint: impl Into<String>) -> Error {
+    Error::Unsupported {
+        path: String::new(),
+        type_name: shape.to_string(),
+        hint: hint.into(),
+    }
+}
+
+fn def_unsupported(shape: &Shape) -> Error {
+    let hint = match shape.def {
+        Def::Result(_) => "use an enum with `Ok` and `Err` variants instead",
+        _ => "this kind of type has no equivalent in the generated languages",
+    };
+    unsupported(shape, hint)
+}
+
+fn union_unsupported(shape: &Shape) -> Error {
+    unsupported(
+        shape,
+        "unions have no tag to tell their fields apart; use an enum instead",
+    )
+}
+
 /// Extract a rename value from shape attributes.
 ///
 /// In the latest facet, `#[facet(rename = "...")]` on a container is stored
@@ -1674,6 +2442,9 @@ fn extract_rename_from_shape_attributes(shape: &Shape) -> Option<&'static str> {
 }
 
 fn extract_enum_tagging(shape: &Shape) -> EnumTagging {
+    if shape.is_untagged() {
+        return EnumTagging::Untagged;
+    }
     match (shape.tag, shape.content) {
         (Some(tag), Some(content)) => EnumTagging::Adjacent {
             tag: tag.to_string(),
@@ -1751,6 +2522,357 @@ fn extract_namespace_from_attr(attr: &facet::Attr) -> Result<Option<NamespaceAct
     }
 }
 
+/// Whether `shape` is an instantiation of a user-defined generic struct or enum (e.g.
+/// `Page<User>`), as opposed to a generic standard library type such as `Vec<T>` or `Option<T>`.
+/// Whether any field or variant of `container` uses the type parameter `param`.
+fn uses_type_parameter(container: &ContainerFormat, param: &str) -> bool {
+    let mut used = false;
+    // The visitor never fails, so neither does the visit.
+    let _ = container.visit(&mut |format| {
+        used |= matches!(format, Format::TypeParameter(name) if name == param);
+        Ok(())
+    });
+    used
+}
+
+fn is_generic_shape(shape: &Shape) -> bool {
+    !shape.type_params.is_empty()
+        && matches!(shape.def, Def::Undefined)
+        && matches!(
+            shape.ty,
+            Type::User(UserType::Struct(_) | UserType::Enum(_))
+        )
+        && !is_transparent_shape(shape)
+}
+
+/// Whether `shape` is a struct with exactly one unnamed field, e.g. `struct A(u16)`.
+fn is_newtype_shape(shape: &Shape) -> bool {
+    matches!(
+        shape.ty,
+        Type::User(UserType::Struct(StructType {
+            kind: StructKind::TupleStruct,
+            fields,
+            ..
+        })) if fields.len() == 1
+    )
+}
+
+fn is_branded_shape(shape: &Shape) -> bool {
+    shape
+        .attributes
+        .iter()
+        .any(|attr| attr.ns == Some("fg") && attr.key == "branded")
+}
+
+fn container_metadata(shape: &Shape) -> Result<ContainerMetadata, Error> {
+    let type_params = if is_generic_shape(shape) {
+        shape
+            .type_params
+            .iter()
+            .map(|param| param.name.to_string())
+            .collect()
+    } else {
+        vec![]
+    };
+    let branded = is_branded_shape(shape);
+    if branded && !is_newtype_shape(shape) {
+        return Err(Error::ReflectionError {
+            type_name: shape.type_identifier.to_string(),
+            message: "`fg::branded` can only be applied to a newtype struct".to_string(),
+        });
+    }
+    Ok(ContainerMetadata {
+        type_params,
+        branded,
+        proxy: false,
+        readonly: has_fg_attribute(shape.attributes, "readonly"),
+        visibility: has_fg_attribute(shape.attributes, "public").then_some(Visibility::Public),
+        targets: targets(shape.attributes),
+        conformances: conformances(shape)?,
+        annotations: annotations(shape)?,
+        deprecated: deprecation(shape.attributes),
+    })
+}
+
+fn field_metadata(field: &Field) -> Result<FieldMetadata, Error> {
+    Ok(FieldMetadata {
+        readonly: has_fg_attribute(field.attributes, "readonly"),
+        visibility: has_fg_attribute(field.attributes, "public").then_some(Visibility::Public),
+        targets: targets(field.attributes),
+        overrides: type_overrides(field)?,
+        deprecated: deprecation(field.attributes),
+        default: default_value(field),
+        other: false,
+        flattened: false,
+    })
+}
+
+/// The field's default value, if it has one that generated code can express: the value of a
+/// primitive or string field, or the empty value of an option or collection that defaults to
+/// `Default::default()`.
+fn default_value(field: &Field) -> Option<DefaultValue> {
+    let source = field.default.as_ref()?;
+    macro_rules! integer {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = evaluate_default::<$ty>(field, source) {
+                    return Some(DefaultValue::Integer(value.to_string()));
+                }
+            )*
+        };
+    }
+    integer!(
+        i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+    );
+    if let Some(value) = evaluate_default::<bool>(field, source) {
+        return Some(DefaultValue::Bool(value));
+    }
+    if let Some(value) = evaluate_default::<f32>(field, source) {
+        return value
+            .is_finite()
+            .then(|| DefaultValue::Float(format!("{value:?}")));
+    }
+    if let Some(value) = evaluate_default::<f64>(field, source) {
+        return value
+            .is_finite()
+            .then(|| DefaultValue::Float(format!("{value:?}")));
+    }
+    if let Some(value) = evaluate_default::<char>(field, source) {
+        return Some(DefaultValue::Char(value));
+    }
+    if let Some(value) = evaluate_default::<String>(field, source) {
+        return Some(DefaultValue::Str(value));
+    }
+    match (source, field.shape().def) {
+        (DefaultSource::FromTrait, Def::Option(_) | Def::List(_) | Def::Map(_) | Def::Set(_)) => {
+            Some(DefaultValue::Empty)
+        }
+        _ => None,
+    }
+}
+
+/// Evaluates the default of a field of type `T`, or returns `None` if the field has another type.
+///
+/// A `#[facet(default = expr)]` function writes a value of the field's type to an untyped
+/// pointer, so it may only be called with room for a `T` once the field's shape is `T`'s.
+fn evaluate_default<T: Facet<'static> + Default>(
+    field: &Field,
+    source: &DefaultSource,
+) -> Option<T> {
+    if field.shape() != T::SHAPE {
+        return None;
+    }
+    match source {
+        DefaultSource::FromTrait => Some(T::default()),
+        DefaultSource::Custom(default_in_place) => {
+            let mut value = MaybeUninit::<T>::uninit();
+            // SAFETY: shapes are equal only for the same type, so the field's type is `T` and
+            // `default_in_place` initializes `value` with a `T`, as `facet` generates it for the
+            // field. `value` is aligned and sized for a `T` and isn't read before the call.
+            unsafe { default_in_place(PtrUninit::from_maybe_uninit(&mut value)) };
+            // SAFETY: `default_in_place` returned, so `value` is initialized.
+            Some(unsafe { value.assume_init() })
+        }
+    }
+}
+
+/// The field's `fg::override(...)` attributes, by language.
+fn type_overrides(field: &Field) -> Result<BTreeMap<Language, TypeOverride>, Error> {
+    let error = |message: String| Error::ReflectionError {
+        type_name: field.shape().type_identifier.to_string(),
+        message,
+    };
+    let mut overrides = BTreeMap::new();
+    for attr in field.attributes.iter().filter(|attr| attr.ns == Some("fg")) {
+        let Some(fg::Attr::Override(type_override)) = attr.get_as::<fg::Attr>() else {
+            continue;
+        };
+        let language = type_override.lang.parse::<Language>().map_err(error)?;
+        let previous = overrides.insert(
+            language,
+            TypeOverride {
+                ty: type_override.ty.to_string(),
+                serialize: type_override.serialize.map(ToString::to_string),
+                deserialize: type_override.deserialize.map(ToString::to_string),
+            },
+        );
+        if previous.is_some() {
+            return Err(error(format!(
+                "field `{}` has more than one `fg::override` for `{}`",
+                field.name, type_override.lang
+            )));
+        }
+    }
+    Ok(overrides)
+}
+
+/// The type's `fg::conforms(...)` attributes, by language, leaving out repeated conformances.
+fn conformances(shape: &Shape) -> Result<BTreeMap<Language, Vec<String>>, Error> {
+    let mut conformances = BTreeMap::<Language, Vec<String>>::new();
+    for attr in shape.attributes.iter().filter(|attr| attr.ns == Some("fg")) {
+        let Some(fg::Attr::Conforms(conforms)) = attr.get_as::<fg::Attr>() else {
+            continue;
+        };
+        let list = conformances
+            .entry(language(shape, conforms.lang)?)
+            .or_default();
+        for conformance in split_list(shape, "fg::conforms", conforms.to)? {
+            if !list.contains(&conformance) {
+                list.push(conformance);
+            }
+        }
+    }
+    Ok(conformances)
+}
+
+/// The type's `fg::annotate(...)` attributes, by language, in declaration order.
+fn annotations(shape: &Shape) -> Result<BTreeMap<Language, Vec<String>>, Error> {
+    let mut annotations = BTreeMap::<Language, Vec<String>>::new();
+    for attr in shape.attributes.iter().filter(|attr| attr.ns == Some("fg")) {
+        let Some(fg::Attr::Annotate(annotate)) = attr.get_as::<fg::Attr>() else {
+            continue;
+        };
+        annotations
+            .entry(language(shape, annotate.lang)?)
+            .or_default()
+            .extend(split_list(shape, "fg::annotate", annotate.with)?);
+    }
+    Ok(annotations)
+}
+
+fn language(shape: &Shape, name: &str) -> Result<Language, Error> {
+    name.parse().map_err(|message| Error::ReflectionError {
+        type_name: shape.type_identifier.to_string(),
+        message,
+    })
+}
+
+/// Splits the comma-separated list of an attribute, ignoring commas in string literals or nested
+/// in `<...>`, `(...)` or `[...]` (e.g. `Comparable<Self>` or `@SerialName("a, b")`).
+fn split_list(shape: &Shape, attribute: &str, list: &str) -> Result<Vec<String>, Error> {
+    let list = &unescape(list);
+    let mut items = vec![];
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut start = 0;
+    for (i, c) in list.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            _ if in_string => {}
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                items.push(list[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(list[start..].trim());
+    if items.iter().any(|item| item.is_empty()) {
+        return Err(Error::ReflectionError {
+            type_name: shape.type_identifier.to_string(),
+            message: format!("empty item in `{attribute}` list `{list}`"),
+        });
+    }
+    Ok(items.into_iter().map(ToString::to_string).collect())
+}
+
+/// The bincode index pinned by `fg::index` on an enum variant. The attribute grammar stores
+/// numeric attributes as a bare `usize` rather than an `fg::Attr`.
+fn pinned_index(attributes: &[Attr]) -> Option<i128> {
+    attributes
+        .iter()
+        .filter(|attr| attr.ns == Some("fg") && attr.key == "index")
+        .find_map(|attr| attr.get_as::<usize>())
+        .and_then(|index| i128::try_from(*index).ok())
+}
+
+/// The deprecation notice of an `fg::deprecated` attribute, if there is one.
+fn deprecation(attributes: &[Attr]) -> Option<Deprecation> {
+    attributes
+        .iter()
+        .filter(|attr| attr.ns == Some("fg"))
+        .find_map(|attr| match attr.get_as::<fg::Attr>() {
+            Some(fg::Attr::Deprecated(deprecated)) => Some(Deprecation {
+                since: deprecated.since.map(unescape),
+                note: deprecated.note.map(unescape),
+            }),
+            _ => None,
+        })
+}
+
+/// Resolves the `\"` and `\\` escapes of an attribute's string literal, which the attribute
+/// grammar passes on as written.
+fn unescape(literal: &str) -> String {
+    let mut unescaped = String::with_capacity(literal.len());
+    let mut chars = literal.chars().peekable();
+    while let Some(c) = chars.next() {
+        match chars.next_if(|next| c == '\\' && matches!(next, '"' | '\\')) {
+            Some(escaped) => unescaped.push(escaped),
+            None => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+/// The languages selected by `fg::skip(...)` and `fg::only(...)` attributes, or `None` for all
+/// of them. A language listed by both is skipped.
+fn targets(attributes: &[Attr]) -> Option<BTreeSet<Language>> {
+    let mut only: Option<BTreeSet<Language>> = None;
+    let mut skip: Option<BTreeSet<Language>> = None;
+    for attr in attributes.iter().filter(|attr| attr.ns == Some("fg")) {
+        let (set, languages) = match attr.get_as::<fg::Attr>() {
+            Some(fg::Attr::Only(languages)) => (&mut only, languages),
+            Some(fg::Attr::Skip(languages)) => (&mut skip, languages),
+            _ => continue,
+        };
+        let listed = [
+            (Language::Cpp, languages.cpp),
+            (Language::CSharp, languages.csharp),
+            (Language::Dart, languages.dart),
+            (Language::Go, languages.go),
+            (Language::Java, languages.java),
+            (Language::Kotlin, languages.kotlin),
+            (Language::Python, languages.python),
+            (Language::Swift, languages.swift),
+            (Language::TypeScript, languages.typescript),
+        ];
+        set.get_or_insert_default().extend(
+            listed
+                .into_iter()
+                .filter_map(|(language, listed)| listed.then_some(language)),
+        );
+    }
+    if only.is_none() && skip.is_none() {
+        return None;
+    }
+    let mut targets = only.unwrap_or_else(|| Language::ALL.into());
+    for language in skip.unwrap_or_default() {
+        targets.remove(&language);
+    }
+    Some(targets)
+}
+
+fn has_fg_attribute(attributes: &[Attr], key: &str) -> bool {
+    attributes
+        .iter()
+        .any(|attr| attr.ns == Some("fg") && attr.key == key)
+}
+
+/// The shape wrapped by a pointer, reference or transparent struct, which is reflected as the
+/// format of the wrapped shape.
+fn unwrap_shape(shape: &Shape) -> Option<&Shape> {
+    if is_transparent_shape(shape) {
+        return shape.inner;
+    }
+    match (shape.def, &shape.ty) {
+        (Def::Pointer(PointerDef { pointee, .. }), _) => pointee,
+        (_, Type::Pointer(PointerType::Reference(pt) | PointerType::Raw(pt))) => Some(pt.target),
+        _ => None,
+    }
+}
+
 fn is_transparent_shape(shape: &Shape) -> bool {
     shape
         .attributes
@@ -1772,7 +2894,36 @@ fn should_process_nested_type(shape: &Shape) -> bool {
     !matches!(shape.def, Def::Scalar) && shape.type_identifier != "()"
 }
 
-fn bytes_attribute_format(field: &Field) -> Option<Format> {
+/// The format imposed on a field by its attributes, if any: the format of its serialization
+/// proxy (`fg::serialized_as`), or `Bytes` for `fg::bytes`.
+fn field_attribute_format(field: &Field) -> Result<Option<Format>, Error> {
+    if let Some(proxy) = serialized_as_format(field.shape().type_identifier, field.attributes)? {
+        return Ok(Some(proxy));
+    }
+    bytes_attribute_format(field)
+}
+
+/// The format of the serialization proxy declared by a `fg::serialized_as` attribute, if any.
+fn serialized_as_format(type_name: &str, attributes: &[Attr]) -> Result<Option<Format>, Error> {
+    let Some(attr) = attributes
+        .iter()
+        .find(|attr| attr.ns == Some("fg") && attr.key == "serialized_as")
+    else {
+        return Ok(None);
+    };
+    let error = |message| Error::ReflectionError {
+        type_name: type_name.to_string(),
+        message,
+    };
+    match attr.get_as::<&str>() {
+        Some(proxy) => proxy::parse(proxy).map(Some).map_err(error),
+        None => Err(error(
+            r#"bad attribute format: use `#[facet(fg::serialized_as = "String")]`"#.to_string(),
+        )),
+    }
+}
+
+fn bytes_attribute_format(field: &Field) -> Result<Option<Format>, Error> {
     let mut shape = field.shape();
     let is_bytes_attr = |field: &Field| {
         field
@@ -1781,7 +2932,7 @@ fn bytes_attribute_format(field: &Field) -> Option<Format> {
             .any(|attr| attr.key == "bytes" && attr.ns == Some("fg"))
     };
     let mut is_transparent_bytes = || {
-        if is_transparent_shape(shape) {
+        let is_bytes = if is_transparent_shape(shape) {
             match shape.ty {
                 Type::User(ty) => match ty {
                     UserType::Struct(ty) => match ty.kind {
@@ -1792,18 +2943,23 @@ fn bytes_attribute_format(field: &Field) -> Option<Format> {
                         }
                         _ => false,
                     },
-                    UserType::Enum(_) => todo!(),
-                    UserType::Union(_) => todo!(),
+                    UserType::Enum(_) | UserType::Union(_) => {
+                        return Err(unsupported(
+                            shape,
+                            "only a newtype struct can be transparent over bytes",
+                        ));
+                    }
                     UserType::Opaque => false,
                 },
                 _ => false,
             }
         } else {
             false
-        }
+        };
+        Ok(is_bytes)
     };
-    if !is_bytes_attr(field) && !is_transparent_bytes() {
-        return None;
+    if !is_bytes_attr(field) && !is_transparent_bytes()? {
+        return Ok(None);
     }
 
     let (is_option, field_shape) = {
@@ -1829,20 +2985,20 @@ fn bytes_attribute_format(field: &Field) -> Option<Format> {
         if let Def::List(list_def) = field_shape.def {
             let inner_shape = list_def.t();
             if inner_shape.type_identifier == "u8" {
-                return Some(format());
+                return Ok(Some(format()));
             }
         }
     }
 
     if field_shape.type_identifier == "Bytes" {
-        return Some(format());
+        return Ok(Some(format()));
     }
 
     // Handle fixed byte arrays
     if let Def::Array(ArrayDef { t, .. }) = field_shape.def
         && t.type_identifier == "u8"
     {
-        return Some(format());
+        return Ok(Some(format()));
     }
 
     // Handle bytes attribute for &[u8] slices
@@ -1851,7 +3007,7 @@ fn bytes_attribute_format(field: &Field) -> Option<Format> {
         if let Def::Slice(slice_def) = target_shape.def {
             let element_shape = slice_def.t();
             if element_shape.type_identifier == "u8" {
-                return Some(format());
+                return Ok(Some(format()));
             }
         }
     }
@@ -1859,7 +3015,7 @@ fn bytes_attribute_format(field: &Field) -> Option<Format> {
         "{} is not a valid bytes attribute",
         field_shape.type_identifier
     );
-    None
+    Ok(None)
 }
 
 fn get_inner_format(shape: &Shape) -> Result<Format, Error> {
@@ -1994,7 +3150,7 @@ fn get_inner_format_with_context(
                 Format::Unit
             }
         }
-        _ => todo!(),
+        _ => return Err(def_unsupported(shape)),
     };
 
     Ok(format)
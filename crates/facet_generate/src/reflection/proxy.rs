@@ -0,0 +1,173 @@
+//! Parsing of the proxy types named by `#[facet(fg::serialized_as = "...")]`.
+//!
+//! A proxy is written in Rust type syntax, and can be built from primitives, `String`, `Uuid`,
+//! `()`, tuples, arrays and the standard collections — e.g. `"String"`, `"(u64, u32)"` or
+//! `"BTreeMap<String, Vec<u8>>"`. User-defined types can't be named, because an attribute string
+//! carries no shape that could be reflected into the registry.
+
+use super::format::Format;
+
+/// Parses a proxy type into the [`Format`] that it has on the wire.
+pub(super) fn parse(proxy: &str) -> Result<Format, String> {
+    let mut parser = Parser {
+        proxy,
+        rest: proxy.trim_start(),
+    };
+    let format = parser.ty()?;
+    if parser.rest.is_empty() {
+        Ok(format)
+    } else {
+        Err(parser.unexpected())
+    }
+}
+
+struct Parser<'a> {
+    proxy: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn ty(&mut self) -> Result<Format, String> {
+        if self.eat('&') {
+            if self.eat('\'') {
+                self.ident()?;
+            }
+            return self.ty();
+        }
+        if self.eat('(') {
+            let (mut formats, trailing_comma) = self.list(')')?;
+            return Ok(match formats.len() {
+                0 => Format::Unit,
+                1 if !trailing_comma => formats.remove(0),
+                _ => Format::Tuple(formats),
+            });
+        }
+        if self.eat('[') {
+            let content = Box::new(self.ty()?);
+            if self.eat(']') {
+                return Ok(Format::Seq(content));
+            }
+            self.expect(';')?;
+            let size = self
+                .ident()?
+                .parse()
+                .map_err(|_| format!("invalid array length in proxy `{}`", self.proxy))?;
+            self.expect(']')?;
+            return Ok(Format::TupleArray { content, size });
+        }
+
+        let path = self.ident()?;
+        let name = path.rsplit("::").next().unwrap_or(path);
+        let args = if self.eat('<') {
+            self.list('>')?.0
+        } else {
+            vec![]
+        };
+        self.named(name, args)
+    }
+
+    /// Parses comma-separated types up to `close`, returning them and whether the last one was
+    /// followed by a comma.
+    fn list(&mut self, close: char) -> Result<(Vec<Format>, bool), String> {
+        let mut formats = vec![];
+        let mut trailing_comma = false;
+        while !self.eat(close) {
+            formats.push(self.ty()?);
+            trailing_comma = self.eat(',');
+            if !trailing_comma {
+                self.expect(close)?;
+                break;
+            }
+        }
+        Ok((formats, trailing_comma))
+    }
+
+    fn named(&self, name: &str, args: Vec<Format>) -> Result<Format, String> {
+        let mut args = args.into_iter();
+        let format = match name {
+            "bool" => Format::Bool,
+            "i8" => Format::I8,
+            "i16" => Format::I16,
+            "i32" => Format::I32,
+            "i64" | "isize" => Format::I64,
+            "i128" => Format::I128,
+            "u8" => Format::U8,
+            "u16" => Format::U16,
+            "u32" => Format::U32,
+            "u64" | "usize" => Format::U64,
+            "u128" => Format::U128,
+            "f32" => Format::F32,
+            "f64" => Format::F64,
+            "char" => Format::Char,
+            "String" | "str" => Format::Str,
+            "Uuid" => Format::Uuid,
+            "Option" => Format::Option(Box::new(self.arg(name, args.next())?)),
+            "Vec" | "VecDeque" => Format::Seq(Box::new(self.arg(name, args.next())?)),
+            "HashSet" | "BTreeSet" => Format::Set(Box::new(self.arg(name, args.next())?)),
+            "HashMap" | "BTreeMap" => Format::Map {
+                key: Box::new(self.arg(name, args.next())?),
+                value: Box::new(self.arg(name, args.next())?),
+            },
+            "Box" | "Rc" | "Arc" => self.arg(name, args.next())?,
+            _ => {
+                return Err(format!(
+                    "unsupported type `{name}` in proxy `{}`: only built-in types can be named",
+                    self.proxy
+                ));
+            }
+        };
+        if args.next().is_some() {
+            return Err(format!(
+                "too many type arguments for `{name}` in proxy `{}`",
+                self.proxy
+            ));
+        }
+        Ok(format)
+    }
+
+    fn arg(&self, name: &str, arg: Option<Format>) -> Result<Format, String> {
+        arg.ok_or_else(|| {
+            format!(
+                "missing type argument for `{name}` in proxy `{}`",
+                self.proxy
+            )
+        })
+    }
+
+    fn ident(&mut self) -> Result<&'a str, String> {
+        let end = self
+            .rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'))
+            .unwrap_or(self.rest.len());
+        if end == 0 {
+            return Err(self.unexpected());
+        }
+        let (ident, rest) = self.rest.split_at(end);
+        self.rest = rest.trim_start();
+        Ok(ident)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if let Some(rest) = self.rest.strip_prefix(c) {
+            self.rest = rest.trim_start();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn unexpected(&self) -> String {
+        match self.rest.chars().next() {
+            Some(c) => format!("unexpected `{c}` in proxy `{}`", self.proxy),
+            None => format!("unexpected end of proxy `{}`", self.proxy),
+        }
+    }
+}
@@ -7,7 +7,9 @@
 //! Now they should all work correctly, demonstrating that the underlying issues have been resolved.
 
 use crate::reflection::RegistryBuilder;
-use crate::reflection::format::{ContainerFormat, EnumTagging, Format, VariantFormat};
+use crate::reflection::format::{
+    ContainerFormat, ContainerMetadata, EnumTagging, Format, VariantFormat,
+};
 
 #[cfg(test)]
 mod tests {
@@ -171,7 +173,11 @@ mod tests {
             name: "UnresolvedType".to_string(),
         };
 
-        let unresolved_container = ContainerFormat::NewTypeStruct(Box::default(), Doc::new());
+        let unresolved_container = ContainerFormat::NewTypeStruct(
+            Box::default(),
+            Doc::new(),
+            ContainerMetadata::default(),
+        );
         builder
             .registry
             .insert(type_name.clone(), unresolved_container);
@@ -198,10 +204,16 @@ mod tests {
                 name: "UnresolvedVariant".to_string(),
                 doc: Doc::new(),
                 value: VariantFormat::unknown(), // Unresolved Variable
+                metadata: crate::reflection::format::FieldMetadata::default(),
             },
         );
 
-        let enum_container = ContainerFormat::Enum(variants, EnumTagging::External, Doc::new());
+        let enum_container = ContainerFormat::Enum(
+            variants,
+            EnumTagging::External,
+            Doc::new(),
+            ContainerMetadata::default(),
+        );
         let type_name = QualifiedTypeName {
             namespace: Namespace::Named("test".to_string()),
             name: "EnumWithUnresolvedVariant".to_string(),
@@ -228,9 +240,11 @@ mod tests {
             name: "unresolved_field".to_string(),
             doc: Doc::new(),
             value: Format::unknown(), // Unresolved Variable
+            metadata: crate::reflection::format::FieldMetadata::default(),
         }];
 
-        let struct_container = ContainerFormat::Struct(fields, Doc::new());
+        let struct_container =
+            ContainerFormat::Struct(fields, Doc::new(), ContainerMetadata::default());
         let type_name = QualifiedTypeName {
             namespace: Namespace::Named("test".to_string()),
             name: "StructWithUnresolvedField".to_string(),
@@ -311,11 +325,90 @@ mod tests {
             Variant2 { result: MyResult<i32> },
         }
 
-        let err = reflect!(MyEnum).unwrap_err();
+        let registry = reflect!(MyEnum).unwrap();
 
-        insta::assert_snapshot!(
-            err.root_cause(),
-            @"failed to add type MyEnum: unsupported generic type: MyResult<i32>, the type may have already been used with different parameters"
-        );
+        insta::assert_yaml_snapshot!(registry, @"
+        ? namespace: ROOT
+          name: AnotherError
+        : UNITSTRUCT: []
+        ? namespace: ROOT
+          name: MyEnum
+        : ENUM:
+            - 0:
+                Variant1:
+                  - STRUCT:
+                      - result:
+                          - GENERIC:
+                              NAME:
+                                namespace: ROOT
+                                name: MyResult
+                              ARGS:
+                                - STR
+                          - []
+                  - []
+              1:
+                Variant2:
+                  - STRUCT:
+                      - result:
+                          - GENERIC:
+                              NAME:
+                                namespace: ROOT
+                                name: MyResult
+                              ARGS:
+                                - I32
+                          - []
+                  - []
+            - EXTERNAL
+            - []
+        ? namespace: ROOT
+          name: MyError
+        : ENUM:
+            - 0:
+                Disconnect:
+                  - NEWTYPE:
+                      TYPENAME:
+                        namespace: ROOT
+                        name: AnotherError
+                  - []
+              1:
+                Redaction:
+                  - NEWTYPE: STR
+                  - []
+              2:
+                InvalidHeader:
+                  - STRUCT:
+                      - expected:
+                          - STR
+                          - []
+                      - found:
+                          - STR
+                          - []
+                  - []
+              3:
+                Unknown:
+                  - UNIT
+                  - []
+            - EXTERNAL
+            - []
+        ? namespace: ROOT
+          name: MyResult
+        : ENUM:
+            - 0:
+                Ok:
+                  - NEWTYPE:
+                      TYPEPARAMETER: T
+                  - []
+              1:
+                Err:
+                  - NEWTYPE:
+                      TYPENAME:
+                        namespace: ROOT
+                        name: MyError
+                  - []
+            - EXTERNAL
+            - []
+            - type_params:
+                - T
+        ");
     }
 }
@@ -1,12 +1,13 @@
 use std::{
     collections::{BTreeSet, HashMap, HashSet},
     sync::Arc,
+    time::Duration,
 };
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, Utc};
 
 use super::*;
-use crate::reflect;
+use crate::{self as fg, reflect};
 
 #[test]
 fn unit_struct() {
@@ -1887,6 +1888,389 @@ fn enum_with_skip_serializing() {
     ");
 }
 
+#[test]
+fn enum_with_pinned_indices() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(dead_code)]
+    enum MyEnum {
+        Variant1,
+        #[facet(fg::index = 5)]
+        Variant2(u32),
+        Variant3,
+        #[facet(fg::index = 2)]
+        Variant4,
+    }
+
+    let registry = reflect!(MyEnum).unwrap();
+    let ContainerFormat::Enum(variants, ..) =
+        &registry[&QualifiedTypeName::root("MyEnum".to_string())]
+    else {
+        panic!("expected an enum");
+    };
+    assert_eq!(
+        variants
+            .iter()
+            .map(|(index, variant)| (*index, variant.name.as_str()))
+            .collect::<Vec<_>>(),
+        [
+            (0, "Variant1"),
+            (2, "Variant4"),
+            (5, "Variant2"),
+            (6, "Variant3")
+        ]
+    );
+}
+
+#[test]
+fn enum_with_explicit_discriminants() {
+    #[derive(Facet)]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum MyEnum {
+        Variant1 = 1,
+        Variant2 = 4,
+        #[facet(skip)]
+        Variant3,
+        Variant4,
+    }
+
+    insta::assert_yaml_snapshot!(reflect!(MyEnum).unwrap(), @"
+    ? namespace: ROOT
+      name: MyEnum
+    : ENUM:
+        - 1:
+            Variant1:
+              - UNIT
+              - []
+          4:
+            Variant2:
+              - UNIT
+              - []
+          6:
+            Variant4:
+              - UNIT
+              - []
+        - EXTERNAL
+        - []
+    ");
+}
+
+#[test]
+fn enum_with_duplicate_indices() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(dead_code)]
+    enum MyEnum {
+        Variant1,
+        Variant2,
+        #[facet(fg::index = 1)]
+        Variant3,
+    }
+
+    assert_eq!(
+        RegistryBuilder::new().add_type::<MyEnum>().err().unwrap(),
+        Error::DuplicateVariantIndex {
+            type_name: "MyEnum".to_string(),
+            index: 1,
+            first: "Variant2".to_string(),
+            second: "Variant3".to_string(),
+        }
+    );
+}
+
+#[test]
+fn enum_with_other_variant() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(dead_code)]
+    enum MyEnum {
+        Variant1(String),
+        #[facet(other)]
+        Unknown,
+    }
+
+    insta::assert_yaml_snapshot!(reflect!(MyEnum).unwrap(), @"
+    ? namespace: ROOT
+      name: MyEnum
+    : ENUM:
+        - 0:
+            Variant1:
+              - NEWTYPE: STR
+              - []
+          1:
+            Unknown:
+              - UNIT
+              - []
+              - other: true
+        - EXTERNAL
+        - []
+    ");
+}
+
+#[test]
+fn untagged_enum() {
+    #[derive(Facet)]
+    #[facet(untagged)]
+    #[repr(C)]
+    #[allow(dead_code)]
+    enum MyEnum {
+        Number(i32),
+        Text(String),
+    }
+
+    insta::assert_yaml_snapshot!(reflect!(MyEnum).unwrap(), @"
+    ? namespace: ROOT
+      name: MyEnum
+    : ENUM:
+        - 0:
+            Number:
+              - NEWTYPE: I32
+              - []
+          1:
+            Text:
+              - NEWTYPE: STR
+              - []
+        - UNTAGGED
+        - []
+    ");
+}
+
+#[test]
+fn flattened_struct_fields() {
+    #[derive(Facet)]
+    struct Pagination {
+        page: u32,
+        #[facet(flatten)]
+        cursor: Cursor,
+    }
+
+    #[derive(Facet)]
+    struct Cursor {
+        next: Option<String>,
+    }
+
+    #[derive(Facet)]
+    struct Users {
+        #[facet(flatten)]
+        pagination: Pagination,
+        users: Vec<String>,
+    }
+
+    insta::assert_yaml_snapshot!(reflect!(Users).unwrap(), @"
+    ? namespace: ROOT
+      name: Users
+    : STRUCT:
+        - - page:
+              - U32
+              - []
+              - flattened: true
+          - next:
+              - OPTION: STR
+              - []
+              - flattened: true
+          - users:
+              - SEQ: STR
+              - []
+        - []
+    ");
+}
+
+#[test]
+fn flattened_generic_struct_fields() {
+    #[derive(Facet)]
+    struct Meta<T> {
+        last: Option<T>,
+    }
+
+    #[derive(Facet)]
+    struct Page<T> {
+        #[facet(flatten)]
+        meta: Meta<T>,
+        items: Vec<T>,
+    }
+
+    #[derive(Facet)]
+    struct Root {
+        page: Page<u64>,
+        names: Page<String>,
+    }
+
+    insta::assert_yaml_snapshot!(reflect!(Root).unwrap(), @"
+    ? namespace: ROOT
+      name: Page
+    : STRUCT:
+        - - last:
+              - OPTION:
+                  TYPEPARAMETER: T
+              - []
+              - flattened: true
+          - items:
+              - SEQ:
+                  TYPEPARAMETER: T
+              - []
+        - []
+        - type_params:
+            - T
+    ? namespace: ROOT
+      name: Root
+    : STRUCT:
+        - - page:
+              - GENERIC:
+                  NAME:
+                    namespace: ROOT
+                    name: Page
+                  ARGS:
+                    - U64
+              - []
+          - names:
+              - GENERIC:
+                  NAME:
+                    namespace: ROOT
+                    name: Page
+                  ARGS:
+                    - STR
+              - []
+        - []
+    ");
+}
+
+#[test]
+fn flattened_field_with_duplicate_name() {
+    #[derive(Facet)]
+    struct Meta {
+        id: u32,
+    }
+
+    #[derive(Facet)]
+    struct User {
+        id: u32,
+        #[facet(flatten)]
+        meta: Meta,
+    }
+
+    assert_eq!(
+        RegistryBuilder::new().add_type::<User>().err(),
+        Some(Error::DuplicateField {
+            type_name: "User".to_string(),
+            field: "id".to_string(),
+        })
+    );
+}
+
+#[test]
+fn flattened_field_that_is_not_a_struct() {
+    #[derive(Facet)]
+    struct User {
+        id: u32,
+        #[facet(flatten)]
+        extra: std::collections::HashMap<String, String>,
+    }
+
+    let error = RegistryBuilder::new().add_type::<User>().err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "unsupported type `HashMap<String, String>` at `User.extra`: \
+         only structs with named fields can be flattened"
+    );
+}
+
+#[test]
+fn flattened_struct_variant_fields() {
+    #[derive(Facet)]
+    struct Cursor {
+        next: Option<String>,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Response {
+        Users {
+            #[facet(flatten)]
+            cursor: Cursor,
+            users: Vec<String>,
+        },
+    }
+
+    insta::assert_yaml_snapshot!(reflect!(Response).unwrap(), @"
+    ? namespace: ROOT
+      name: Response
+    : ENUM:
+        - 0:
+            Users:
+              - STRUCT:
+                  - next:
+                      - OPTION: STR
+                      - []
+                      - flattened: true
+                  - users:
+                      - SEQ: STR
+                      - []
+              - []
+        - EXTERNAL
+        - []
+    ");
+}
+
+#[test]
+fn flattened_struct_variant_field_with_duplicate_name() {
+    #[derive(Facet)]
+    struct Meta {
+        id: u32,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Event {
+        Created {
+            id: u32,
+            #[facet(flatten)]
+            meta: Meta,
+        },
+    }
+
+    assert_eq!(
+        RegistryBuilder::new().add_type::<Event>().err(),
+        Some(Error::DuplicateField {
+            type_name: "Event::Created".to_string(),
+            field: "id".to_string(),
+        })
+    );
+}
+
+#[test]
+fn enum_with_invalid_other_variants() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(dead_code)]
+    enum TwoOthers {
+        #[facet(other)]
+        Unknown,
+        #[facet(other)]
+        Other,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(dead_code)]
+    enum DataOther {
+        Variant1,
+        #[facet(other)]
+        Unknown(String),
+    }
+
+    insta::assert_snapshot!(
+        RegistryBuilder::new().add_type::<TwoOthers>().err().unwrap(),
+        @r#"problem reflecting type '"TwoOthers"': only one variant can be marked `#[facet(other)]`"#
+    );
+    insta::assert_snapshot!(
+        RegistryBuilder::new().add_type::<DataOther>().err().unwrap(),
+        @r#"problem reflecting type '"DataOther"': the `#[facet(other)]` variant must be a unit variant"#
+    );
+}
+
 #[test]
 fn transparent() {
     #[derive(Facet)]
@@ -2759,6 +3143,17 @@ fn self_referencing_type() {
             Doc(
                 [],
             ),
+            ContainerMetadata {
+                type_params: [],
+                branded: false,
+                proxy: false,
+                readonly: false,
+                visibility: None,
+                targets: None,
+                conformances: {},
+                annotations: {},
+                deprecated: None,
+            },
         ),
     }
     "#);
@@ -2786,6 +3181,16 @@ fn complex_self_referencing_type() {
                         [],
                     ),
                     value: I32,
+                    metadata: FieldMetadata {
+                        readonly: false,
+                        visibility: None,
+                        targets: None,
+                        overrides: {},
+                        deprecated: None,
+                        default: None,
+                        other: false,
+                        flattened: false,
+                    },
                 },
                 Named {
                     name: "children",
@@ -2802,11 +3207,32 @@ fn complex_self_referencing_type() {
                             ),
                         ),
                     ),
+                    metadata: FieldMetadata {
+                        readonly: false,
+                        visibility: None,
+                        targets: None,
+                        overrides: {},
+                        deprecated: None,
+                        default: None,
+                        other: false,
+                        flattened: false,
+                    },
                 },
             ],
             Doc(
                 [],
             ),
+            ContainerMetadata {
+                type_params: [],
+                branded: false,
+                proxy: false,
+                readonly: false,
+                visibility: None,
+                targets: None,
+                conformances: {},
+                annotations: {},
+                deprecated: None,
+            },
         ),
     }
     "#);
@@ -2847,12 +3273,33 @@ fn tree_struct_with_mutual_recursion() {
                             },
                         ),
                     ),
+                    metadata: FieldMetadata {
+                        readonly: false,
+                        visibility: None,
+                        targets: None,
+                        overrides: {},
+                        deprecated: None,
+                        default: None,
+                        other: false,
+                        flattened: false,
+                    },
                 },
             },
             External,
             Doc(
                 [],
             ),
+            ContainerMetadata {
+                type_params: [],
+                branded: false,
+                proxy: false,
+                readonly: false,
+                visibility: None,
+                targets: None,
+                conformances: {},
+                annotations: {},
+                deprecated: None,
+            },
         ),
         QualifiedTypeName {
             namespace: Root,
@@ -2870,6 +3317,16 @@ fn tree_struct_with_mutual_recursion() {
                             name: "Test",
                         },
                     ),
+                    metadata: FieldMetadata {
+                        readonly: false,
+                        visibility: None,
+                        targets: None,
+                        overrides: {},
+                        deprecated: None,
+                        default: None,
+                        other: false,
+                        flattened: false,
+                    },
                 },
                 Named {
                     name: "children",
@@ -2884,11 +3341,32 @@ fn tree_struct_with_mutual_recursion() {
                             },
                         ),
                     ),
+                    metadata: FieldMetadata {
+                        readonly: false,
+                        visibility: None,
+                        targets: None,
+                        overrides: {},
+                        deprecated: None,
+                        default: None,
+                        other: false,
+                        flattened: false,
+                    },
                 },
             ],
             Doc(
                 [],
             ),
+            ContainerMetadata {
+                type_params: [],
+                branded: false,
+                proxy: false,
+                readonly: false,
+                visibility: None,
+                targets: None,
+                conformances: {},
+                annotations: {},
+                deprecated: None,
+            },
         ),
     }
     "#);
@@ -2926,11 +3404,32 @@ fn tree_enum_with_mutual_recursion() {
                             name: "Tree",
                         },
                     ),
+                    metadata: FieldMetadata {
+                        readonly: false,
+                        visibility: None,
+                        targets: None,
+                        overrides: {},
+                        deprecated: None,
+                        default: None,
+                        other: false,
+                        flattened: false,
+                    },
                 },
             ],
             Doc(
                 [],
             ),
+            ContainerMetadata {
+                type_params: [],
+                branded: false,
+                proxy: false,
+                readonly: false,
+                visibility: None,
+                targets: None,
+                conformances: {},
+                annotations: {},
+                deprecated: None,
+            },
         ),
         QualifiedTypeName {
             namespace: Root,
@@ -2950,12 +3449,33 @@ fn tree_enum_with_mutual_recursion() {
                             },
                         ),
                     ),
+                    metadata: FieldMetadata {
+                        readonly: false,
+                        visibility: None,
+                        targets: None,
+                        overrides: {},
+                        deprecated: None,
+                        default: None,
+                        other: false,
+                        flattened: false,
+                    },
                 },
             },
             External,
             Doc(
                 [],
             ),
+            ContainerMetadata {
+                type_params: [],
+                branded: false,
+                proxy: false,
+                readonly: false,
+                visibility: None,
+                targets: None,
+                conformances: {},
+                annotations: {},
+                deprecated: None,
+            },
         ),
     }
     "#);
@@ -3528,13 +4048,13 @@ fn chrono_date_time() {
     : ENUM:
         - 0:
             Date:
-              - NEWTYPE: STR
+              - NEWTYPE: TIMESTAMP
               - []
           1:
             Date2:
               - STRUCT:
                   - date:
-                      - STR
+                      - TIMESTAMP
                       - []
               - []
         - EXTERNAL
@@ -3543,7 +4063,7 @@ fn chrono_date_time() {
       name: MyStruct
     : STRUCT:
         - - field1:
-              - STR
+              - TIMESTAMP
               - []
           - field2:
               - TYPENAME:
@@ -3555,15 +4075,13 @@ fn chrono_date_time() {
 }
 
 #[test]
-fn generics_supported_if_used_once() {
-    #[derive(Facet)]
-    struct SupportedGenerics<T> {
-        field: T,
-    }
-
+fn chrono_dates_times_and_durations() {
     #[derive(Facet)]
     struct MyStruct {
-        field1: SupportedGenerics<String>,
+        created_at: DateTime<FixedOffset>,
+        birthday: NaiveDate,
+        alarm: Option<NaiveTime>,
+        timeouts: Vec<Duration>,
     }
 
     let registry = reflect!(MyStruct).unwrap();
@@ -3571,37 +4089,1011 @@ fn generics_supported_if_used_once() {
     ? namespace: ROOT
       name: MyStruct
     : STRUCT:
-        - - field1:
-              - TYPENAME:
-                  namespace: ROOT
-                  name: SupportedGenerics
+        - - created_at:
+              - TIMESTAMP
               - []
-        - []
-    ? namespace: ROOT
-      name: SupportedGenerics
-    : STRUCT:
-        - - field:
-              - STR
+          - birthday:
+              - DATE
+              - []
+          - alarm:
+              - OPTION: TIME
+              - []
+          - timeouts:
+              - SEQ: DURATION
               - []
         - []
     ");
 }
 
-/// TODO: Eventually we should support generics used more than once
 #[test]
-fn generics_unsupported_if_used_twice() {
-    #[derive(Facet)]
-    struct UnsupportedGenerics<T> {
-        field: T,
-    }
-
+fn jiff_dates_and_times() {
     #[derive(Facet)]
     struct MyStruct {
-        field1: UnsupportedGenerics<String>,
-        field2: UnsupportedGenerics<u16>,
+        created_at: jiff::Timestamp,
+        birthday: jiff::civil::Date,
+        alarm: Option<jiff::civil::Time>,
     }
 
-    let err = reflect!(MyStruct).unwrap_err();
-
-    insta::assert_snapshot!(err.root_cause(), @"failed to add type MyStruct: unsupported generic type: UnsupportedGenerics<u16>, the type may have already been used with different parameters");
+    let registry = reflect!(MyStruct).unwrap();
+    insta::assert_yaml_snapshot!(registry, @"
+    ? namespace: ROOT
+      name: MyStruct
+    : STRUCT:
+        - - created_at:
+              - TIMESTAMP
+              - []
+          - birthday:
+              - DATE
+              - []
+          - alarm:
+              - OPTION: TIME
+              - []
+        - []
+    ");
+}
+
+#[test]
+fn time_date_times_are_rejected() {
+    #[derive(Facet)]
+    struct MyStruct {
+        created_at: time::OffsetDateTime,
+    }
+
+    let error = reflect!(MyStruct).unwrap_err();
+    insta::assert_snapshot!(error, @"failed to add type MyStruct: unsupported type `OffsetDateTime` at `MyStruct.created_at`: only the dates and times of chrono and jiff, `Duration`, `String` and `Uuid` are supported");
+}
+
+#[test]
+fn branded_newtype() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct UserId(String);
+
+    #[derive(Facet)]
+    struct MyStruct {
+        id: UserId,
+        manager: Option<UserId>,
+    }
+
+    let registry = reflect!(MyStruct).unwrap();
+    insta::assert_yaml_snapshot!(registry, @"
+    ? namespace: ROOT
+      name: MyStruct
+    : STRUCT:
+        - - id:
+              - TYPENAME:
+                  namespace: ROOT
+                  name: UserId
+              - []
+          - manager:
+              - OPTION:
+                  TYPENAME:
+                    namespace: ROOT
+                    name: UserId
+              - []
+        - []
+    ? namespace: ROOT
+      name: UserId
+    : NEWTYPESTRUCT:
+        - STR
+        - []
+        - branded: true
+    ");
+}
+
+#[test]
+fn branded_attribute_on_struct_is_rejected() {
+    #[derive(Facet)]
+    #[facet(fg::branded)]
+    struct NotANewType {
+        id: String,
+    }
+
+    assert_eq!(
+        RegistryBuilder::new().add_type::<NotANewType>().err(),
+        Some(Error::ReflectionError {
+            type_name: "NotANewType".to_string(),
+            message: "`fg::branded` can only be applied to a newtype struct".to_string(),
+        })
+    );
+}
+
+#[test]
+fn readonly_and_public_fields() {
+    #[derive(Facet)]
+    #[facet(fg::readonly)]
+    struct Point {
+        x: i32,
+    }
+
+    #[derive(Facet)]
+    #[facet(fg::public)]
+    struct MyStruct {
+        #[facet(fg::readonly)]
+        id: String,
+        #[facet(fg::public)]
+        point: Option<Point>,
+        name: String,
+    }
+
+    let registry = reflect!(MyStruct).unwrap();
+    insta::assert_yaml_snapshot!(registry, @"
+    ? namespace: ROOT
+      name: MyStruct
+    : STRUCT:
+        - - id:
+              - STR
+              - []
+              - readonly: true
+          - point:
+              - OPTION:
+                  TYPENAME:
+                    namespace: ROOT
+                    name: Point
+              - []
+              - visibility: PUBLIC
+          - name:
+              - STR
+              - []
+        - []
+        - visibility: PUBLIC
+    ? namespace: ROOT
+      name: Point
+    : STRUCT:
+        - - x:
+              - I32
+              - []
+        - []
+        - readonly: true
+    ");
+}
+
+#[test]
+fn serialized_as_proxies() {
+    #[derive(Facet)]
+    #[facet(fg::serialized_as = "String")]
+    struct ItemId {
+        inner: i64,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Money {
+        Cents(u64),
+    }
+
+    #[derive(Facet)]
+    struct Item {
+        id: ItemId,
+        parents: Vec<ItemId>,
+        #[facet(fg::serialized_as = "(std::string::String, Option<[u8; 3]>)")]
+        price: Money,
+    }
+
+    let registry = reflect!(Item).unwrap();
+    insta::assert_yaml_snapshot!(registry, @"
+    ? namespace: ROOT
+      name: Item
+    : STRUCT:
+        - - id:
+              - STR
+              - []
+          - parents:
+              - SEQ: STR
+              - []
+          - price:
+              - TUPLE:
+                  - STR
+                  - OPTION:
+                      TUPLEARRAY:
+                        CONTENT: U8
+                        SIZE: 3
+              - []
+        - []
+    ? namespace: ROOT
+      name: ItemId
+    : NEWTYPESTRUCT:
+        - STR
+        - []
+        - proxy: true
+    ");
+}
+
+#[test]
+fn serialized_as_rejects_user_types() {
+    #[derive(Facet)]
+    struct Item {
+        #[facet(fg::serialized_as = "Vec<ItemId>")]
+        id: String,
+    }
+
+    assert_eq!(
+        RegistryBuilder::new().add_type::<Item>().err(),
+        Some(Error::ReflectionError {
+            type_name: "String".to_string(),
+            message: "unsupported type `ItemId` in proxy `Vec<ItemId>`: only built-in types can \
+                      be named"
+                .to_string(),
+        })
+    );
+}
+
+#[test]
+fn language_targets() {
+    #[derive(Facet)]
+    #[facet(fg::skip(swift, kotlin))]
+    struct AdminOnly {
+        #[facet(fg::only(kotlin, swift), fg::skip(swift))]
+        mobile: String,
+        web: String,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Platform {
+        #[facet(fg::only(swift))]
+        Ios,
+        Web,
+    }
+
+    #[derive(Facet)]
+    struct Root {
+        admin: AdminOnly,
+        platform: Platform,
+    }
+
+    let registry = reflect!(Root).unwrap();
+    insta::assert_yaml_snapshot!(registry, @"
+    ? namespace: ROOT
+      name: AdminOnly
+    : STRUCT:
+        - - mobile:
+              - STR
+              - []
+              - targets:
+                  - KOTLIN
+          - web:
+              - STR
+              - []
+        - []
+        - targets:
+            - CPP
+            - CSHARP
+            - DART
+            - GO
+            - JAVA
+            - PYTHON
+            - TYPESCRIPT
+    ? namespace: ROOT
+      name: Platform
+    : ENUM:
+        - 0:
+            Ios:
+              - UNIT
+              - []
+              - targets:
+                  - SWIFT
+          1:
+            Web:
+              - UNIT
+              - []
+        - EXTERNAL
+        - []
+    ? namespace: ROOT
+      name: Root
+    : STRUCT:
+        - - admin:
+              - TYPENAME:
+                  namespace: ROOT
+                  name: AdminOnly
+              - []
+          - platform:
+              - TYPENAME:
+                  namespace: ROOT
+                  name: Platform
+              - []
+        - []
+    ");
+}
+
+#[test]
+fn type_overrides() {
+    #[derive(Facet)]
+    struct Size {
+        #[facet(
+            fg::override(lang = "swift", ty = "CGFloat"),
+            fg::override(
+                lang = "kotlin",
+                ty = "Float",
+                serialize = "serializer.serialize_f32({value})",
+                deserialize = "deserializer.deserialize_f32()"
+            )
+        )]
+        width: f64,
+    }
+
+    let registry = reflect!(Size).unwrap();
+    insta::assert_yaml_snapshot!(registry, @r#"
+    ? namespace: ROOT
+      name: Size
+    : STRUCT:
+        - - width:
+              - F64
+              - []
+              - overrides:
+                  KOTLIN:
+                    ty: Float
+                    serialize: "serializer.serialize_f32({value})"
+                    deserialize: deserializer.deserialize_f32()
+                  SWIFT:
+                    ty: CGFloat
+        - []
+    "#);
+}
+
+#[test]
+fn type_override_errors() {
+    #[derive(Facet)]
+    struct UnknownLanguage {
+        #[facet(fg::override(lang = "cobol", ty = "Long"))]
+        id: u64,
+    }
+
+    #[derive(Facet)]
+    struct Duplicate {
+        #[facet(
+            fg::override(lang = "swift", ty = "Int"),
+            fg::override(lang = "swift", ty = "Int64")
+        )]
+        id: u64,
+    }
+
+    assert_eq!(
+        RegistryBuilder::new().add_type::<UnknownLanguage>().err(),
+        Some(Error::ReflectionError {
+            type_name: "u64".to_string(),
+            message:
+                "unknown language `cobol`: expected `cpp`, `csharp`, `dart`, `go`, `java`, `kotlin`, `python`, `swift` or `typescript`"
+                    .to_string(),
+        })
+    );
+    assert_eq!(
+        RegistryBuilder::new().add_type::<Duplicate>().err(),
+        Some(Error::ReflectionError {
+            type_name: "u64".to_string(),
+            message: "field `id` has more than one `fg::override` for `swift`".to_string(),
+        })
+    );
+}
+
+#[test]
+fn conformances_and_annotations() {
+    #[derive(Facet)]
+    #[facet(
+        fg::conforms(lang = "swift", to = "Sendable, Identifiable"),
+        fg::conforms(lang = "typescript", to = "Comparable<Point, Point>"),
+        fg::annotate(lang = "kotlin", with = "@Parcelize, @SerialName(\"point, 2d\")"),
+        fg::conforms(lang = "kotlin", to = "Parcelable")
+    )]
+    #[facet(fg::conforms(lang = "swift", to = "Sendable"))]
+    #[expect(clippy::duplicated_attributes)]
+    struct Point {
+        id: u64,
+    }
+
+    let registry = reflect!(Point).unwrap();
+    insta::assert_yaml_snapshot!(registry, @r#"
+    ? namespace: ROOT
+      name: Point
+    : STRUCT:
+        - - id:
+              - U64
+              - []
+        - []
+        - conformances:
+            KOTLIN:
+              - Parcelable
+            SWIFT:
+              - Sendable
+              - Identifiable
+            TYPESCRIPT:
+              - "Comparable<Point, Point>"
+          annotations:
+            KOTLIN:
+              - "@Parcelize"
+              - "@SerialName(\"point, 2d\")"
+    "#);
+}
+
+#[test]
+fn conformance_errors() {
+    #[derive(Facet)]
+    #[facet(fg::annotate(lang = "cobol", with = "@Entity"))]
+    struct UnknownLanguage {
+        id: u64,
+    }
+
+    #[derive(Facet)]
+    #[facet(fg::conforms(lang = "swift", to = "Sendable,"))]
+    struct Empty {
+        id: u64,
+    }
+
+    assert_eq!(
+        RegistryBuilder::new().add_type::<UnknownLanguage>().err(),
+        Some(Error::ReflectionError {
+            type_name: "UnknownLanguage".to_string(),
+            message:
+                "unknown language `cobol`: expected `cpp`, `csharp`, `dart`, `go`, `java`, `kotlin`, `python`, `swift` or `typescript`"
+                    .to_string(),
+        })
+    );
+    assert_eq!(
+        RegistryBuilder::new().add_type::<Empty>().err(),
+        Some(Error::ReflectionError {
+            type_name: "Empty".to_string(),
+            message: "empty item in `fg::conforms` list `Sendable,`".to_string(),
+        })
+    );
+}
+
+#[test]
+fn deprecations() {
+    #[derive(Facet)]
+    #[facet(fg::deprecated(since = "0.3.0", note = "Use `Contact` instead"))]
+    struct Person {
+        #[facet(fg::deprecated)]
+        name: String,
+        kind: Kind,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum Kind {
+        Friend,
+        #[facet(fg::deprecated(note = "Use `Friend` instead"))]
+        Acquaintance,
+    }
+
+    let registry = reflect!(Person).unwrap();
+    insta::assert_yaml_snapshot!(registry, @r#"
+    ? namespace: ROOT
+      name: Kind
+    : ENUM:
+        - 0:
+            Friend:
+              - UNIT
+              - []
+          1:
+            Acquaintance:
+              - UNIT
+              - []
+              - deprecated:
+                  note: "Use `Friend` instead"
+        - EXTERNAL
+        - []
+    ? namespace: ROOT
+      name: Person
+    : STRUCT:
+        - - name:
+              - STR
+              - []
+              - deprecated: {}
+          - kind:
+              - TYPENAME:
+                  namespace: ROOT
+                  name: Kind
+              - []
+        - []
+        - deprecated:
+            since: 0.3.0
+            note: "Use `Contact` instead"
+    "#);
+}
+
+#[test]
+fn defaults() {
+    #[derive(Facet)]
+    struct Settings {
+        #[facet(default)]
+        enabled: bool,
+        #[facet(default = -7)]
+        offset: i64,
+        #[facet(default = u128::MAX)]
+        limit: u128,
+        #[facet(default = 0.25_f32)]
+        ratio: f32,
+        #[facet(default = 'x')]
+        marker: char,
+        #[facet(default = "a \"quoted\" name".to_string())]
+        name: String,
+        #[facet(default)]
+        tags: Vec<String>,
+        #[facet(default)]
+        counts: HashMap<String, u32>,
+        #[facet(default)]
+        nickname: Option<String>,
+        #[facet(default = Some(3))]
+        level: Option<u8>,
+        #[facet(default)]
+        inner: Inner,
+        required: u32,
+    }
+
+    #[derive(Facet, Default)]
+    struct Inner {
+        value: u32,
+    }
+
+    let registry = reflect!(Settings).unwrap();
+    insta::assert_yaml_snapshot!(registry, @r#"
+    ? namespace: ROOT
+      name: Inner
+    : STRUCT:
+        - - value:
+              - U32
+              - []
+        - []
+    ? namespace: ROOT
+      name: Settings
+    : STRUCT:
+        - - enabled:
+              - BOOL
+              - []
+              - default:
+                  BOOL: false
+          - offset:
+              - I64
+              - []
+              - default:
+                  INTEGER: "-7"
+          - limit:
+              - U128
+              - []
+              - default:
+                  INTEGER: "340282366920938463463374607431768211455"
+          - ratio:
+              - F32
+              - []
+              - default:
+                  FLOAT: "0.25"
+          - marker:
+              - CHAR
+              - []
+              - default:
+                  CHAR: x
+          - name:
+              - STR
+              - []
+              - default:
+                  STR: "a \"quoted\" name"
+          - tags:
+              - SEQ: STR
+              - []
+              - default: EMPTY
+          - counts:
+              - MAP:
+                  KEY: STR
+                  VALUE: U32
+              - []
+              - default: EMPTY
+          - nickname:
+              - OPTION: STR
+              - []
+              - default: EMPTY
+          - level:
+              - OPTION: U8
+              - []
+          - inner:
+              - TYPENAME:
+                  namespace: ROOT
+                  name: Inner
+              - []
+          - required:
+              - U32
+              - []
+        - []
+    "#);
+}
+
+#[test]
+fn generics_supported_if_used_once() {
+    #[derive(Facet)]
+    struct SupportedGenerics<T> {
+        field: T,
+    }
+
+    #[derive(Facet)]
+    struct MyStruct {
+        field1: SupportedGenerics<String>,
+    }
+
+    let registry = reflect!(MyStruct).unwrap();
+    insta::assert_yaml_snapshot!(registry, @"
+    ? namespace: ROOT
+      name: MyStruct
+    : STRUCT:
+        - - field1:
+              - TYPENAME:
+                  namespace: ROOT
+                  name: SupportedGenerics
+              - []
+        - []
+    ? namespace: ROOT
+      name: SupportedGenerics
+    : STRUCT:
+        - - field:
+              - STR
+              - []
+        - []
+    ");
+}
+
+#[test]
+fn generic_struct_used_twice() {
+    #[derive(Facet)]
+    struct Generic<T> {
+        field: T,
+    }
+
+    #[derive(Facet)]
+    struct MyStruct {
+        field1: Generic<String>,
+        field2: Generic<u16>,
+    }
+
+    let registry = reflect!(MyStruct).unwrap();
+    insta::assert_yaml_snapshot!(registry, @"
+    ? namespace: ROOT
+      name: Generic
+    : STRUCT:
+        - - field:
+              - TYPEPARAMETER: T
+              - []
+        - []
+        - type_params:
+            - T
+    ? namespace: ROOT
+      name: MyStruct
+    : STRUCT:
+        - - field1:
+              - GENERIC:
+                  NAME:
+                    namespace: ROOT
+                    name: Generic
+                  ARGS:
+                    - STR
+              - []
+          - field2:
+              - GENERIC:
+                  NAME:
+                    namespace: ROOT
+                    name: Generic
+                  ARGS:
+                    - U16
+              - []
+        - []
+    ");
+}
+
+#[test]
+fn generic_struct_with_nested_type_parameters() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct MyStruct {
+        names: Page<String>,
+        ids: Page<u32>,
+    }
+
+    let registry = reflect!(MyStruct).unwrap();
+    insta::assert_yaml_snapshot!(registry, @"
+    ? namespace: ROOT
+      name: MyStruct
+    : STRUCT:
+        - - names:
+              - GENERIC:
+                  NAME:
+                    namespace: ROOT
+                    name: Page
+                  ARGS:
+                    - STR
+              - []
+          - ids:
+              - GENERIC:
+                  NAME:
+                    namespace: ROOT
+                    name: Page
+                  ARGS:
+                    - U32
+              - []
+        - []
+    ? namespace: ROOT
+      name: Page
+    : STRUCT:
+        - - items:
+              - SEQ:
+                  TYPEPARAMETER: T
+              - []
+          - next:
+              - OPTION:
+                  TYPEPARAMETER: T
+              - []
+          - total:
+              - U32
+              - []
+        - []
+        - type_params:
+            - T
+    ");
+}
+
+#[test]
+fn generic_struct_used_once_with_a_field_of_its_type_argument() {
+    #[derive(Facet)]
+    struct Page<T> {
+        items: Vec<T>,
+        total: u32,
+    }
+
+    #[derive(Facet)]
+    struct Listing<T> {
+        pages: Vec<T>,
+    }
+
+    #[derive(Facet)]
+    struct MyStruct {
+        ids: Listing<Page<u32>>,
+        names: Listing<String>,
+    }
+
+    // `total` can't be told apart from a use of `T`, so the only instantiation is registered.
+    let registry = reflect!(MyStruct).unwrap();
+    insta::assert_yaml_snapshot!(registry, @"
+    ? namespace: ROOT
+      name: Listing
+    : STRUCT:
+        - - pages:
+              - SEQ:
+                  TYPEPARAMETER: T
+              - []
+        - []
+        - type_params:
+            - T
+    ? namespace: ROOT
+      name: MyStruct
+    : STRUCT:
+        - - ids:
+              - GENERIC:
+                  NAME:
+                    namespace: ROOT
+                    name: Listing
+                  ARGS:
+                    - TYPENAME:
+                        namespace: ROOT
+                        name: Page
+              - []
+          - names:
+              - GENERIC:
+                  NAME:
+                    namespace: ROOT
+                    name: Listing
+                  ARGS:
+                    - STR
+              - []
+        - []
+    ? namespace: ROOT
+      name: Page
+    : STRUCT:
+        - - items:
+              - SEQ: U32
+              - []
+          - total:
+              - U32
+              - []
+        - []
+    ");
+}
+
+#[test]
+fn generic_struct_with_a_type_argument_that_never_varies() {
+    #[derive(Facet)]
+    struct Pair<A, B> {
+        first: A,
+        second: B,
+        count: u32,
+    }
+
+    #[derive(Facet)]
+    struct MyStruct {
+        names: Pair<u32, String>,
+        flags: Pair<u32, bool>,
+    }
+
+    let error = reflect!(MyStruct).unwrap_err();
+    insta::assert_snapshot!(error, @"failed to build registry: type parameter `A` of `ROOT::Pair` is `u32` in every instantiation, so its uses can't be told apart from fields of type `u32`");
+}
+
+#[test]
+fn generic_enum_with_two_type_parameters() {
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(unused)]
+    enum MyResult<T, E> {
+        Ok(T),
+        Err(E),
+    }
+
+    #[derive(Facet)]
+    struct MyStruct {
+        first: MyResult<String, i32>,
+        second: MyResult<bool, String>,
+    }
+
+    let registry = reflect!(MyStruct).unwrap();
+    insta::assert_yaml_snapshot!(registry, @"
+    ? namespace: ROOT
+      name: MyResult
+    : ENUM:
+        - 0:
+            Ok:
+              - NEWTYPE:
+                  TYPEPARAMETER: T
+              - []
+          1:
+            Err:
+              - NEWTYPE:
+                  TYPEPARAMETER: E
+              - []
+        - EXTERNAL
+        - []
+        - type_params:
+            - T
+            - E
+    ? namespace: ROOT
+      name: MyStruct
+    : STRUCT:
+        - - first:
+              - GENERIC:
+                  NAME:
+                    namespace: ROOT
+                    name: MyResult
+                  ARGS:
+                    - STR
+                    - I32
+              - []
+          - second:
+              - GENERIC:
+                  NAME:
+                    namespace: ROOT
+                    name: MyResult
+                  ARGS:
+                    - BOOL
+                    - STR
+              - []
+        - []
+    ");
+}
+
+#[test]
+fn generic_struct_as_type_argument() {
+    #[derive(Facet)]
+    struct Wrapper<T> {
+        inner: T,
+    }
+
+    #[derive(Facet)]
+    struct MyStruct {
+        nested: Wrapper<Wrapper<String>>,
+    }
+
+    let registry = reflect!(MyStruct).unwrap();
+    insta::assert_yaml_snapshot!(registry, @"
+    ? namespace: ROOT
+      name: MyStruct
+    : STRUCT:
+        - - nested:
+              - GENERIC:
+                  NAME:
+                    namespace: ROOT
+                    name: Wrapper
+                  ARGS:
+                    - GENERIC:
+                        NAME:
+                          namespace: ROOT
+                          name: Wrapper
+                        ARGS:
+                          - STR
+              - []
+        - []
+    ? namespace: ROOT
+      name: Wrapper
+    : STRUCT:
+        - - inner:
+              - TYPEPARAMETER: T
+              - []
+        - []
+        - type_params:
+            - T
+    ");
+}
+
+#[test]
+fn unsupported_field_reports_path() {
+    #[derive(Facet)]
+    struct Job {
+        id: u32,
+        outcome: Result<u32, String>,
+    }
+
+    assert_eq!(
+        RegistryBuilder::new().add_type::<Job>().err(),
+        Some(Error::Unsupported {
+            path: "Job.outcome".to_string(),
+            type_name: "Result<u32, String>".to_string(),
+            hint: "use an enum with `Ok` and `Err` variants instead".to_string(),
+        })
+    );
+}
+
+#[test]
+fn unsupported_variant_field_reports_path() {
+    #[derive(Facet)]
+    struct Job {
+        status: Status,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(dead_code)]
+    enum Status {
+        Pending,
+        Done(u32, Result<u32, String>),
+    }
+
+    let error = RegistryBuilder::new().add_type::<Job>().err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "unsupported type `Result<u32, String>` at `Job.status.Done.1`: \
+         use an enum with `Ok` and `Err` variants instead"
+    );
+}
+
+#[test]
+fn collect_errors() {
+    #[derive(Facet)]
+    struct Job {
+        first: Result<u32, String>,
+        id: u32,
+        second: Result<u8, String>,
+    }
+
+    #[derive(Facet)]
+    #[repr(C)]
+    #[allow(dead_code)]
+    enum Status {
+        Done(Result<u32, String>),
+        Failed { reason: Result<String, u8> },
+    }
+
+    let result = RegistryBuilder::new()
+        .collect_errors()
+        .add_type::<Job>()
+        .and_then(|builder| builder.add_type::<Status>())
+        .and_then(RegistryBuilder::build);
+    insta::assert_snapshot!(result.unwrap_err(), @"
+    4 errors:
+      - unsupported type `Result<u32, String>` at `Job.first`: use an enum with `Ok` and `Err` variants instead
+      - unsupported type `Result<u8, String>` at `Job.second`: use an enum with `Ok` and `Err` variants instead
+      - unsupported type `Result<u32, String>` at `Status.Done.0`: use an enum with `Ok` and `Err` variants instead
+      - unsupported type `Result<String, u8>` at `Status.Failed.reason`: use an enum with `Ok` and `Err` variants instead
+    ");
 }
@@ -0,0 +1,298 @@
+//! A versioned document format for a [`Registry`], so that code can be generated from a
+//! checked-in schema file instead of from Rust types compiled into the generator.
+//!
+//! A [`Schema`] lists every type in the registry with its namespace and [`ContainerFormat`],
+//! including field and variant names, doc comments, enum tagging and metadata:
+//!
+//! ```yaml
+//! types:
+//! - format:
+//!     STRUCT:
+//!     - - id:
+//!         - U64
+//!         - - The user's unique id.
+//!     - []
+//!   name: User
+//!   namespace: api
+//! version: 1
+//! ```
+//!
+//! [`save`] and [`load`] write and read a schema file, as YAML if its extension is `.yaml` or
+//! `.yml` and as JSON otherwise:
+//!
+//! ```rust,ignore
+//! // in the Rust workspace
+//! let registry = RegistryBuilder::new().add_type::<User>()?.build()?;
+//! schema::save(&registry, "schema.json")?;
+//!
+//! // anywhere else
+//! let registry = schema::load("schema.json")?;
+//! typescript::Installer::new("api", &out_dir).generate(&registry)?;
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    Registry,
+    reflection::format::{ContainerFormat, Format, FormatHolder, Namespace, QualifiedTypeName},
+};
+
+/// The version of the schema document written by this version of the crate. Documents with a
+/// later version are rejected, as they may describe formats that this version can't generate.
+pub const VERSION: u32 = 1;
+
+/// Errors that can occur while reading or writing a schema document.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// An I/O error occurred while reading or writing a schema file.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    /// The document isn't valid JSON, or doesn't describe a registry.
+    #[error("invalid JSON schema: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// The document isn't valid YAML, or doesn't describe a registry.
+    #[error("invalid YAML schema: {0}")]
+    Yaml(#[from] serde_norway::Error),
+
+    /// The document was written by a later version of the crate.
+    #[error("unsupported schema version {found}: expected {VERSION} or earlier")]
+    UnsupportedVersion { found: u32 },
+
+    /// The document describes the same type twice.
+    #[error("type `{0}` is defined more than once")]
+    DuplicateType(QualifiedTypeName),
+
+    /// A type refers to a type that the document doesn't define.
+    #[error("type `{used_by}` refers to `{name}`, which isn't defined")]
+    UnknownType {
+        name: QualifiedTypeName,
+        used_by: QualifiedTypeName,
+    },
+
+    /// A type's format still has a placeholder that reflection didn't fill in.
+    #[error("the format of `{0}` is incomplete")]
+    UnknownFormat(QualifiedTypeName),
+}
+
+/// A registry document: the types of a [`Registry`], tagged with the [`VERSION`] of the
+/// document format.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Schema {
+    /// The version of the document format.
+    pub version: u32,
+    /// The types of the registry, in registry order.
+    pub types: Vec<TypeDefinition>,
+}
+
+/// A type in a [`Schema`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TypeDefinition {
+    /// The name of the type, without its namespace.
+    pub name: String,
+    /// The namespace of the type, or `None` for the root namespace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// The shape of the type.
+    pub format: ContainerFormat,
+}
+
+/// Only the version of a document, read before the rest so that documents written by a later
+/// version of the crate are reported as such rather than as malformed.
+#[derive(Deserialize)]
+struct Header {
+    version: u32,
+}
+
+impl TypeDefinition {
+    fn qualified_name(&self) -> QualifiedTypeName {
+        match &self.namespace {
+            Some(namespace) => QualifiedTypeName::namespaced(namespace.clone(), self.name.clone()),
+            None => QualifiedTypeName::root(self.name.clone()),
+        }
+    }
+}
+
+impl From<&Registry> for Schema {
+    fn from(registry: &Registry) -> Self {
+        let types = registry
+            .iter()
+            .map(|(name, format)| TypeDefinition {
+                name: name.name.clone(),
+                namespace: match &name.namespace {
+                    Namespace::Root => None,
+                    Namespace::Named(namespace) => Some(namespace.clone()),
+                },
+                format: format.clone(),
+            })
+            .collect();
+        Self {
+            version: VERSION,
+            types,
+        }
+    }
+}
+
+impl Schema {
+    /// Reads a schema from a JSON document.
+    ///
+    /// # Errors
+    /// Returns an error if the document is malformed or was written by a later version.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        check_version(serde_json::from_str::<Header>(json)?.version)?;
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Reads a schema from a YAML document.
+    ///
+    /// # Errors
+    /// Returns an error if the document is malformed or was written by a later version.
+    pub fn from_yaml(yaml: &str) -> Result<Self, Error> {
+        let value = yaml_to_json(serde_norway::from_str(yaml)?)?;
+        check_version(Header::deserialize(&value)?.version)?;
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Writes the schema as a pretty-printed JSON document.
+    ///
+    /// # Errors
+    /// Returns an error if a format can't be serialized.
+    pub fn to_json(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Writes the schema as a YAML document.
+    ///
+    /// # Errors
+    /// Returns an error if a format can't be serialized.
+    pub fn to_yaml(&self) -> Result<String, Error> {
+        Ok(serde_norway::to_string(&serde_json::to_value(self)?)?)
+    }
+
+    /// Converts the schema into a registry.
+    ///
+    /// # Errors
+    /// Returns an error if a type is defined more than once, or if a type refers to a type that
+    /// isn't defined. A registry filtered with
+    /// [`Replacement::External`](crate::filter::Replacement::External) refers to types it doesn't
+    /// contain, so save the whole registry and filter it after loading.
+    pub fn into_registry(self) -> Result<Registry, Error> {
+        let mut registry = Registry::new();
+        for definition in self.types {
+            let name = definition.qualified_name();
+            if registry.contains_key(&name) {
+                return Err(Error::DuplicateType(name));
+            }
+            registry.insert(name, definition.format);
+        }
+
+        for (used_by, format) in &registry {
+            let mut unknown = None;
+            format
+                .visit(&mut |format| {
+                    if let Format::TypeName(name) | Format::Generic { name, .. } = format
+                        && unknown.is_none()
+                        && !registry.contains_key(name)
+                    {
+                        unknown = Some(name.clone());
+                    }
+                    Ok(())
+                })
+                .map_err(|_| Error::UnknownFormat(used_by.clone()))?;
+            if let Some(name) = unknown {
+                return Err(Error::UnknownType {
+                    name,
+                    used_by: used_by.clone(),
+                });
+            }
+        }
+
+        Ok(registry)
+    }
+}
+
+fn check_version(found: u32) -> Result<(), Error> {
+    if found > VERSION {
+        return Err(Error::UnsupportedVersion { found });
+    }
+    Ok(())
+}
+
+/// Converts a YAML document to JSON, so that enums are read from single-entry maps, as in JSON,
+/// rather than from the YAML tags that `serde_norway` expects.
+fn yaml_to_json(value: serde_norway::Value) -> Result<serde_json::Value, Error> {
+    use serde_json::Value as Json;
+    use serde_norway::Value as Yaml;
+
+    Ok(match value {
+        Yaml::Null => Json::Null,
+        Yaml::Bool(value) => Json::Bool(value),
+        Yaml::Number(number) => serde_json::to_value(number)?,
+        Yaml::String(value) => Json::String(value),
+        Yaml::Sequence(values) => Json::Array(
+            values
+                .into_iter()
+                .map(yaml_to_json)
+                .collect::<Result<_, _>>()?,
+        ),
+        Yaml::Mapping(entries) => Json::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| {
+                    let key = match key {
+                        Yaml::String(key) => key,
+                        key => serde_norway::to_string(&key)?.trim_end().to_string(),
+                    };
+                    Ok((key, yaml_to_json(value)?))
+                })
+                .collect::<Result<_, Error>>()?,
+        ),
+        Yaml::Tagged(tagged) => yaml_to_json(tagged.value)?,
+    })
+}
+
+fn is_yaml(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "yaml" || extension == "yml")
+}
+
+/// Writes `registry` to a schema file at `path`, as YAML if its extension is `.yaml` or `.yml`
+/// and as JSON otherwise.
+///
+/// # Errors
+/// Returns an error if the file can't be written or a format can't be serialized.
+pub fn save(registry: &Registry, path: impl AsRef<Path>) -> Result<(), Error> {
+    let path = path.as_ref();
+    let schema = Schema::from(registry);
+    let document = if is_yaml(path) {
+        schema.to_yaml()?
+    } else {
+        schema.to_json()?
+    };
+    fs::write(path, document)?;
+    Ok(())
+}
+
+/// Reads a registry from a schema file at `path`, as YAML if its extension is `.yaml` or `.yml`
+/// and as JSON otherwise.
+///
+/// # Errors
+/// Returns an error if the file can't be read, is malformed, was written by a later version, or
+/// doesn't describe a consistent registry.
+pub fn load(path: impl AsRef<Path>) -> Result<Registry, Error> {
+    let path = path.as_ref();
+    let document = fs::read_to_string(path)?;
+    let schema = if is_yaml(path) {
+        Schema::from_yaml(&document)?
+    } else {
+        Schema::from_json(&document)?
+    };
+    schema.into_registry()
+}
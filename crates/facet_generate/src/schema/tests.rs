@@ -0,0 +1,190 @@
+#![allow(dead_code)]
+
+use std::collections::{BTreeMap, HashSet};
+
+use facet::Facet;
+
+use super::{Error, Schema, VERSION, load, save};
+use crate::{
+    self as fg, Registry, reflect,
+    reflection::format::{
+        ContainerFormat, ContainerMetadata, Doc, Format, Named, QualifiedTypeName,
+    },
+};
+
+/// A user of the service.
+#[derive(Facet)]
+#[facet(fg::namespace = "api", rename_all = "camelCase")]
+struct User {
+    /// The user's unique id.
+    id: u64,
+    display_name: String,
+    #[facet(fg::readonly)]
+    tags: HashSet<String>,
+    #[facet(default = 3)]
+    retries: u8,
+    scores: BTreeMap<String, f64>,
+    avatars: Vec<Avatar>,
+    #[facet(fg::bytes)]
+    thumbnail: Vec<u8>,
+}
+
+#[derive(Facet)]
+struct Avatar(String, u32);
+
+#[derive(Facet)]
+#[facet(tag = "type", content = "value")]
+#[repr(C)]
+enum Event {
+    Created(User),
+    Renamed {
+        from: String,
+        to: String,
+    },
+    #[facet(rename = "deleted")]
+    Deleted,
+}
+
+#[derive(Facet)]
+struct Page<T> {
+    items: Vec<T>,
+    next: Option<String>,
+}
+
+#[derive(Facet)]
+struct Feed {
+    events: Page<Event>,
+}
+
+fn registry() -> Registry {
+    reflect!(Feed).unwrap()
+}
+
+#[test]
+fn json_round_trip() {
+    let registry = registry();
+    let json = Schema::from(&registry).to_json().unwrap();
+    let schema = Schema::from_json(&json).unwrap();
+    assert_eq!(schema.version, VERSION);
+    assert_eq!(schema.into_registry().unwrap(), registry);
+}
+
+#[test]
+fn yaml_round_trip() {
+    let registry = registry();
+    let yaml = Schema::from(&registry).to_yaml().unwrap();
+    let schema = Schema::from_yaml(&yaml).unwrap();
+    assert_eq!(schema.into_registry().unwrap(), registry);
+}
+
+#[test]
+fn yaml_document() {
+    #[derive(Facet)]
+    #[facet(fg::namespace = "api")]
+    struct Point {
+        /// Horizontal position.
+        x: i32,
+        #[facet(fg::readonly)]
+        y: i32,
+    }
+
+    let registry = reflect!(Point).unwrap();
+    insta::assert_snapshot!(Schema::from(&registry).to_yaml().unwrap(), @"
+    types:
+    - format:
+        STRUCT:
+        - - x:
+            - I32
+            - - Horizontal position.
+          - y:
+            - I32
+            - []
+            - readonly: true
+        - []
+      name: Point
+      namespace: api
+    version: 1
+    ");
+}
+
+#[test]
+fn save_and_load() {
+    let registry = registry();
+    let dir = tempfile::tempdir().unwrap();
+    for file in ["schema.json", "schema.yaml"] {
+        let path = dir.path().join(file);
+        save(&registry, &path).unwrap();
+        assert_eq!(load(&path).unwrap(), registry);
+    }
+}
+
+#[test]
+fn later_version_is_rejected() {
+    let json = r#"{ "version": 99, "types": [{ "unknown": true }] }"#;
+    assert!(matches!(
+        Schema::from_json(json),
+        Err(Error::UnsupportedVersion { found: 99 })
+    ));
+}
+
+#[test]
+fn duplicate_type_is_rejected() {
+    let definition = ContainerFormat::Struct(
+        vec![Named {
+            name: "id".to_string(),
+            doc: Doc::new(),
+            value: Format::U32,
+            metadata: Default::default(),
+        }],
+        Doc::new(),
+        ContainerMetadata::default(),
+    );
+    let schema = Schema {
+        version: VERSION,
+        types: vec![
+            super::TypeDefinition {
+                name: "Id".to_string(),
+                namespace: None,
+                format: definition.clone(),
+            },
+            super::TypeDefinition {
+                name: "Id".to_string(),
+                namespace: None,
+                format: definition,
+            },
+        ],
+    };
+    assert!(matches!(
+        schema.into_registry(),
+        Err(Error::DuplicateType(name)) if name == QualifiedTypeName::root("Id".to_string())
+    ));
+}
+
+#[test]
+fn reference_to_undefined_type_is_rejected() {
+    let schema = Schema {
+        version: VERSION,
+        types: vec![super::TypeDefinition {
+            name: "Order".to_string(),
+            namespace: Some("api".to_string()),
+            format: ContainerFormat::Struct(
+                vec![Named {
+                    name: "customer".to_string(),
+                    doc: Doc::new(),
+                    value: Format::Option(Box::new(Format::TypeName(QualifiedTypeName::root(
+                        "Customer".to_string(),
+                    )))),
+                    metadata: Default::default(),
+                }],
+                Doc::new(),
+                ContainerMetadata::default(),
+            ),
+        }],
+    };
+    assert!(matches!(
+        schema.into_registry(),
+        Err(Error::UnknownType { name, used_by })
+            if name == QualifiedTypeName::root("Customer".to_string())
+                && used_by == QualifiedTypeName::namespaced("api".to_string(), "Order".to_string())
+    ));
+}
@@ -1,5 +1,7 @@
 #![expect(unused)]
 
+use crate as fg;
+
 use facet::Facet;
 
 #[derive(Facet)]
@@ -10,7 +12,7 @@ pub enum BestHockeyTeams {
     Lies(String),
 }
 #[derive(Facet)]
-// TODO: implement language-specific annotations: #[facet(swift = "Equatable")]
+#[facet(fg::conforms(lang = "swift", to = "Equatable"))]
 #[facet(tag = "type", content = "content")]
 #[repr(C)]
 pub enum BestHockeyTeams1 {
@@ -19,7 +21,7 @@ pub enum BestHockeyTeams1 {
 }
 
 #[derive(Facet)]
-// TODO: implement language-specific annotations: #[facet(swift = "Equatable, Codable, Comparable, Hashable")]
+#[facet(fg::conforms(lang = "swift", to = "Equatable, Codable, Comparable, Hashable"))]
 #[facet(tag = "type", content = "content")]
 #[repr(C)]
 pub enum BestHockeyTeams2 {
@@ -28,7 +30,7 @@ pub enum BestHockeyTeams2 {
 }
 
 #[derive(Facet)]
-// TODO: implement language-specific annotations: #[facet(kotlin = "idk")]
+#[facet(fg::conforms(lang = "kotlin", to = "Idk"))]
 #[facet(tag = "type", content = "content")]
 #[repr(C)]
 pub enum BestHockeyTeams3 {
@@ -37,10 +39,14 @@ pub enum BestHockeyTeams3 {
 }
 
 #[derive(Facet)]
-// TODO: implement language-specific annotations: #[facet(swift = "Equatable", swift = "Hashable")]
+#[facet(fg::conforms(lang = "swift", to = "Equatable"))]
+#[facet(fg::conforms(lang = "swift", to = "Hashable"))]
+#[expect(clippy::duplicated_attributes)]
 #[facet(tag = "type", content = "content")]
 #[repr(C)]
 pub enum BestHockeyTeams4 {
     PittsburghPenguins,
     Lies(String),
 }
+
+crate::test! { BestHockeyTeams, BestHockeyTeams1, BestHockeyTeams2, BestHockeyTeams3, BestHockeyTeams4 for kotlin, swift }
@@ -0,0 +1,41 @@
+package com.example
+
+sealed interface BestHockeyTeams {
+    data object PittsburghPenguins: BestHockeyTeams
+
+    data class Lies(
+        val value: String,
+    ) : BestHockeyTeams
+}
+
+sealed interface BestHockeyTeams1 {
+    data object PittsburghPenguins: BestHockeyTeams1
+
+    data class Lies(
+        val value: String,
+    ) : BestHockeyTeams1
+}
+
+sealed interface BestHockeyTeams2 {
+    data object PittsburghPenguins: BestHockeyTeams2
+
+    data class Lies(
+        val value: String,
+    ) : BestHockeyTeams2
+}
+
+sealed interface BestHockeyTeams3 : Idk {
+    data object PittsburghPenguins: BestHockeyTeams3
+
+    data class Lies(
+        val value: String,
+    ) : BestHockeyTeams3
+}
+
+sealed interface BestHockeyTeams4 {
+    data object PittsburghPenguins: BestHockeyTeams4
+
+    data class Lies(
+        val value: String,
+    ) : BestHockeyTeams4
+}
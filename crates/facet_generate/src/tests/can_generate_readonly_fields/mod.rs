@@ -7,8 +7,16 @@ pub struct SomeStruct {
     field_a: u32,
     #[facet(fg::readonly)]
     field_b: Vec<String>,
+    field_c: bool,
+}
+
+#[derive(Facet)]
+#[facet(fg::readonly)]
+pub struct ReadonlyStruct {
+    field_a: u32,
+    field_b: Option<String>,
 }
 
 crate::test! {
-    SomeStruct for typescript
+    SomeStruct, ReadonlyStruct for kotlin, swift, typescript, csharp
 }
@@ -0,0 +1,18 @@
+using CommunityToolkit.Mvvm.ComponentModel;
+using Facet.Runtime.Serde;
+using System.Collections.Generic;
+using System.Collections.ObjectModel;
+
+namespace Example;
+
+public partial class ReadonlyStruct : ObservableObject {
+    public uint FieldA { get; init; }
+    public string? FieldB { get; init; }
+}
+
+public partial class SomeStruct : ObservableObject {
+    public uint FieldA { get; init; }
+    public ObservableCollection<string> FieldB { get; init; }
+    [ObservableProperty]
+    private bool _fieldC;
+}
@@ -1,10 +1,12 @@
 package com.example
 
-import kotlinx.serialization.*
-import kotlinx.serialization.builtins.*
-import kotlinx.serialization.descriptors.*
-import kotlinx.serialization.encoding.*
-import kotlinx.serialization.json.*
-import kotlinx.serialization.modules.*
+data class ReadonlyStruct(
+    val fieldA: UInt,
+    val fieldB: String? = null,
+)
 
-@Serializable data class SomeStruct(val field_a: UInt, val field_b: List<String>)
+data class SomeStruct(
+    val fieldA: UInt,
+    val fieldB: List<String>,
+    val fieldC: Boolean,
+)
@@ -1,11 +1,22 @@
-import Foundation
 
-struct SomeStruct: Codable {
-    let field_a: UInt32
-    let field_b: [String]
+public struct ReadonlyStruct {
+    public let fieldA: UInt32
+    public let fieldB: String?
 
-    init(field_a: UInt32, field_b: [String]) {
-        self.field_a = field_a
-        self.field_b = field_b
+    public init(fieldA: UInt32, fieldB: String?) {
+        self.fieldA = fieldA
+        self.fieldB = fieldB
+    }
+}
+
+public struct SomeStruct {
+    public let fieldA: UInt32
+    public let fieldB: [String]
+    public var fieldC: Bool
+
+    public init(fieldA: UInt32, fieldB: [String], fieldC: Bool) {
+        self.fieldA = fieldA
+        self.fieldB = fieldB
+        self.fieldC = fieldC
     }
 }
@@ -1,8 +1,15 @@
+type bool = boolean;
+type Optional<T> = T | null;
 type Seq<T> = T[];
 type str = string;
 type uint32 = number;
 
+export class ReadonlyStruct {
+    constructor (public readonly field_a: uint32, public readonly field_b: Optional<str>) {
+    }
+}
+
 export class SomeStruct {
-    constructor (public field_a: uint32, public field_b: Seq<str>) {
+    constructor (public readonly field_a: uint32, public readonly field_b: Seq<str>, public field_c: bool) {
     }
 }
@@ -1,3 +1,5 @@
+use crate as fg;
+
 use facet::Facet;
 
 #[derive(Facet)]
@@ -5,13 +7,12 @@ pub struct MyStruct {
     a: i32,
     #[facet(skip)]
     b: i32,
-    // TODO: #[facet(swift(skip))]
+    #[facet(fg::skip(swift))]
     c: i32,
-    // TODO: #[facet(skip)]
+    #[facet(fg::skip(kotlin, swift, typescript, csharp))]
     d: i32,
 }
 
-// TODO: enable swift
 crate::test! {
-    MyStruct for kotlin, typescript
+    MyStruct for kotlin, swift, typescript, csharp
 }
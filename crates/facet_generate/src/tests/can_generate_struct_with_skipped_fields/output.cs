@@ -0,0 +1,13 @@
+using CommunityToolkit.Mvvm.ComponentModel;
+using Facet.Runtime.Serde;
+using System.Collections.Generic;
+using System.Collections.ObjectModel;
+
+namespace Example;
+
+public partial class MyStruct : ObservableObject {
+    [ObservableProperty]
+    private int _a;
+    [ObservableProperty]
+    private int _c;
+}
@@ -3,5 +3,4 @@ package com.example
 data class MyStruct(
     val a: Int,
     val c: Int,
-    val d: Int,
 )
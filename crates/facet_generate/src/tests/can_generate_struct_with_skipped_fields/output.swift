@@ -1,9 +1,8 @@
-import Foundation
 
-struct MyStruct: Codable {
-    var a: Int32
+public struct MyStruct {
+    public var a: Int32
 
-    init(a: Int32) {
+    public init(a: Int32) {
         self.a = a
     }
 }
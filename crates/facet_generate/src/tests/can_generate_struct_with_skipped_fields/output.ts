@@ -1,6 +1,6 @@
 type int32 = number;
 
 export class MyStruct {
-    constructor (public a: int32, public c: int32, public d: int32) {
+    constructor (public a: int32, public c: int32) {
     }
 }
@@ -1,17 +1,19 @@
 #![expect(unused)]
 #![expect(clippy::enum_variant_names)]
 
+use crate as fg;
+
 use facet::Facet;
 
 #[derive(Facet)]
 #[facet(rename_all = "camelCase")]
 struct OverrideStruct {
-    // TODO: re-implement language-specific type overrides
-    // #[facet(
-    //     swift(type = "Int"),
-    //     typescript(type = "any | undefined"),
-    //     kotlin(type = "Int")
-    // )]
+    #[facet(
+        fg::override(lang = "swift", ty = "Int"),
+        fg::override(lang = "typescript", ty = "any | undefined"),
+        fg::override(lang = "kotlin", ty = "Int"),
+        fg::override(lang = "csharp", ty = "int")
+    )]
     field_to_override: String,
 }
 
@@ -23,12 +25,16 @@ enum OverrideEnum {
     TupleVariant(String),
     #[facet(rename_all = "camelCase")]
     AnonymousStructVariant {
-        // TODO: re-implement language-specific type overrides
-        // #[facet(
-        //     swift(type = "Int"),
-        //     typescript(type = "any | undefined"),
-        //     kotlin(type = "Int")
-        // )]
+        #[facet(
+            fg::override(lang = "swift", ty = "Int"),
+            fg::override(lang = "typescript", ty = "any | undefined"),
+            fg::override(lang = "kotlin", ty = "Int"),
+            fg::override(lang = "csharp", ty = "int")
+        )]
         field_to_override: String,
     },
 }
+
+crate::test! {
+    OverrideStruct, OverrideEnum for kotlin, swift, typescript, csharp
+}
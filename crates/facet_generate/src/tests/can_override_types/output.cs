@@ -0,0 +1,20 @@
+using CommunityToolkit.Mvvm.ComponentModel;
+using Facet.Runtime.Serde;
+using System.Collections.Generic;
+using System.Collections.ObjectModel;
+
+namespace Example;
+
+public abstract record OverrideEnum {
+    public sealed record UnitVariant() : OverrideEnum;
+
+    public sealed record TupleVariant(string Value) : OverrideEnum;
+
+    public sealed record AnonymousStructVariant(int FieldToOverride) : OverrideEnum;
+
+}
+
+public partial class OverrideStruct : ObservableObject {
+    [ObservableProperty]
+    private int _fieldToOverride;
+}
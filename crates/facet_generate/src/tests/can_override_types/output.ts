@@ -1,11 +1,25 @@
-export interface OverrideStruct {
-    fieldToOverride: any | undefined;
-}
+type str = string;
 
-export type OverrideEnum = 
+export type OverrideEnum =
     | { type: "UnitVariant" }
-    | { type: "TupleVariant", content: string }
-    | { type: "AnonymousStructVariant", content: {
-    fieldToOverride: any | undefined;
-}};
+    | { type: "TupleVariant"; content: str }
+    | { type: "AnonymousStructVariant"; content: { fieldToOverride: any | undefined; } };
+
+export const overrideEnumUnitVariant = (): OverrideEnum => ({ type: "UnitVariant" });
+
+export const overrideEnumTupleVariant = (value: str): OverrideEnum => ({ type: "TupleVariant", content: value });
+
+export const overrideEnumAnonymousStructVariant = (fieldToOverride: any | undefined): OverrideEnum => ({ type: "AnonymousStructVariant", content: { fieldToOverride } });
 
+export function matchOverrideEnum<R>(value: OverrideEnum, cases: {
+    UnitVariant: (v: Extract<OverrideEnum, { type: "UnitVariant" }>) => R;
+    TupleVariant: (v: Extract<OverrideEnum, { type: "TupleVariant" }>) => R;
+    AnonymousStructVariant: (v: Extract<OverrideEnum, { type: "AnonymousStructVariant" }>) => R;
+}): R {
+    return cases[value.type as OverrideEnum["type"]](value as never);
+}
+
+export class OverrideStruct {
+    constructor (public fieldToOverride: any | undefined) {
+    }
+}
@@ -1,20 +1,36 @@
 #![expect(deprecated)]
 #![expect(unused)]
 
+use crate as fg;
+
 use facet::Facet;
 
 #[derive(Facet)]
 #[deprecated(since = "0.1.0", note = "Use `MySuperAwesomeStruct` instead")]
+#[facet(fg::deprecated(since = "0.1.0", note = "Use `MySuperAwesomeStruct` instead"))]
 pub struct MyLegacyStruct {
     field: String,
 }
 
+#[derive(Facet)]
+pub struct MyStruct {
+    field: String,
+    #[deprecated(note = "Use `field` instead")]
+    #[facet(fg::deprecated(note = "Use `field` instead"))]
+    legacy_field: String,
+    #[deprecated]
+    #[facet(fg::deprecated)]
+    unexplained_field: u32,
+}
+
 #[derive(Facet)]
 #[deprecated(note = "Use `MySuperAwesomeAlias` instead")]
+#[facet(fg::deprecated(note = "Use `MySuperAwesomeAlias` instead"))]
 pub struct MyLegacyAlias(pub u32);
 
 #[derive(Facet)]
 #[deprecated(note = "Use `MySuperAwesomeEnum` instead")]
+#[facet(fg::deprecated(note = "Use `MySuperAwesomeEnum` instead"))]
 #[repr(C)]
 pub enum MyLegacyEnum {
     VariantA,
@@ -29,6 +45,7 @@ pub enum MyUnitEnum {
     VariantB,
 
     #[deprecated(note = "Use `VariantB` instead")]
+    #[facet(fg::deprecated(note = "Use `VariantB` instead"))]
     LegacyVariant,
 }
 
@@ -44,6 +61,7 @@ pub enum MyInternallyTaggedEnum {
     },
 
     #[deprecated(note = "Use `VariantA` instead")]
+    #[facet(fg::deprecated(note = "Use `VariantA` instead"))]
     LegacyVariant {
         field: bool,
     },
@@ -56,9 +74,10 @@ pub enum MyExternallyTaggedEnum {
     VariantB(u32),
 
     #[deprecated(note = "Use `VariantB` instead")]
+    #[facet(fg::deprecated(note = "Use `VariantB` instead"))]
     LegacyVariant(bool),
 }
 
 crate::test! {
-    MyLegacyStruct, MyLegacyAlias, MyLegacyEnum, MyUnitEnum, MyInternallyTaggedEnum, MyExternallyTaggedEnum for typescript
+    MyLegacyStruct, MyStruct, MyLegacyAlias, MyLegacyEnum, MyUnitEnum, MyInternallyTaggedEnum, MyExternallyTaggedEnum for kotlin, swift, typescript, csharp
 }
@@ -0,0 +1,63 @@
+using CommunityToolkit.Mvvm.ComponentModel;
+using Facet.Runtime.Serde;
+using System.Collections.Generic;
+using System.Collections.ObjectModel;
+
+namespace Example;
+
+public abstract record MyExternallyTaggedEnum {
+    public sealed record VariantA(string Value) : MyExternallyTaggedEnum;
+
+    public sealed record VariantB(uint Value) : MyExternallyTaggedEnum;
+
+    [Obsolete("Use `VariantB` instead")]
+    public sealed record LegacyVariant(bool Value) : MyExternallyTaggedEnum;
+
+}
+
+public abstract record MyInternallyTaggedEnum {
+    public sealed record VariantA(string Field) : MyInternallyTaggedEnum;
+
+    public sealed record VariantB(uint Field) : MyInternallyTaggedEnum;
+
+    [Obsolete("Use `VariantA` instead")]
+    public sealed record LegacyVariant(bool Field) : MyInternallyTaggedEnum;
+
+}
+
+[Obsolete("Use `MySuperAwesomeAlias` instead")]
+public partial class MyLegacyAlias : ObservableObject {
+    [ObservableProperty]
+    private uint _value;
+}
+
+[Obsolete("Use `MySuperAwesomeEnum` instead")]
+public enum MyLegacyEnum {
+    VariantA,
+    VariantB,
+    VariantC
+}
+
+[Obsolete("Use `MySuperAwesomeStruct` instead (since 0.1.0)")]
+public partial class MyLegacyStruct : ObservableObject {
+    [ObservableProperty]
+    private string _field;
+}
+
+public partial class MyStruct : ObservableObject {
+    [ObservableProperty]
+    private string _field;
+    [Obsolete("Use `field` instead")]
+    [ObservableProperty]
+    private string _legacyField;
+    [Obsolete]
+    [ObservableProperty]
+    private uint _unexplainedField;
+}
+
+public enum MyUnitEnum {
+    VariantA,
+    VariantB,
+    [Obsolete("Use `VariantB` instead")]
+    LegacyVariant
+}
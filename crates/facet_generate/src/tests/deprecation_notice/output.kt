@@ -1,119 +1,62 @@
 package com.example
 
-import kotlinx.serialization.*
-import kotlinx.serialization.builtins.*
-import kotlinx.serialization.descriptors.*
-import kotlinx.serialization.encoding.*
-import kotlinx.serialization.json.*
-import kotlinx.serialization.modules.*
-
-@Deprecated(message = "Use `MySuperAwesomeAlias` instead")
-typealias MyLegacyAlias = UInt
-
-@Deprecated(message = "Use `MySuperAwesomeStruct` instead")
-@Serializable
-data class MyLegacyStruct(val field: String)
-
-@Deprecated(message = "Use `MySuperAwesomeEnum` instead")
-@Serializable
-enum class MyLegacyEnum {
-    @SerialName("VariantA") VARIANT_A,
-    @SerialName("VariantB") VARIANT_B,
-    @SerialName("VariantC") VARIANT_C;
-
-    val serialName: String
-        get() = javaClass.getDeclaredField(name).getAnnotation(SerialName::class.java)!!.value
-}
+sealed interface MyExternallyTaggedEnum {
+    data class VariantA(
+        val value: String,
+    ) : MyExternallyTaggedEnum
 
-@Serializable
-enum class MyUnitEnum {
-    @SerialName("VariantA") VARIANT_A,
-    @SerialName("VariantB") VARIANT_B,
-    @Deprecated(message = "Use `VariantB` instead") @SerialName("LegacyVariant") LEGACY_VARIANT;
+    data class VariantB(
+        val value: UInt,
+    ) : MyExternallyTaggedEnum
 
-    val serialName: String
-        get() = javaClass.getDeclaredField(name).getAnnotation(SerialName::class.java)!!.value
+    @Deprecated(message = "Use `VariantB` instead")
+    data class LegacyVariant(
+        val value: Boolean,
+    ) : MyExternallyTaggedEnum
 }
 
-@Serializable
-@JsonClassDiscriminator("type")
 sealed interface MyInternallyTaggedEnum {
-    @Serializable
-    @SerialName("VariantA")
-    data class VariantA(val field: String) : MyInternallyTaggedEnum
+    data class VariantA(
+        val field: String,
+    ) : MyInternallyTaggedEnum
 
-    @Serializable
-    @SerialName("VariantB")
-    data class VariantB(val field: UInt) : MyInternallyTaggedEnum
+    data class VariantB(
+        val field: UInt,
+    ) : MyInternallyTaggedEnum
 
     @Deprecated(message = "Use `VariantA` instead")
-    @Serializable
-    @SerialName("LegacyVariant")
-    data class LegacyVariant(val field: Boolean) : MyInternallyTaggedEnum
+    data class LegacyVariant(
+        val field: Boolean,
+    ) : MyInternallyTaggedEnum
 }
 
-@Serializable(with = MyExternallyTaggedEnum.Serializer::class)
-sealed interface MyExternallyTaggedEnum {
-    @Serializable data class VariantA(val value: String) : MyExternallyTaggedEnum
-
-    @Serializable data class VariantB(val value: UInt) : MyExternallyTaggedEnum
+@Deprecated(message = "Use `MySuperAwesomeAlias` instead")
+data class MyLegacyAlias(
+    val value: UInt,
+)
 
-    @Deprecated(message = "Use `VariantB` instead")
-    @Serializable
-    data class LegacyVariant(val value: Boolean) : MyExternallyTaggedEnum
+@Deprecated(message = "Use `MySuperAwesomeEnum` instead")
+enum class MyLegacyEnum {
+    VARIANT_A,
+    VARIANT_B,
+    VARIANT_C;
+}
 
-    object Serializer : KSerializer<MyExternallyTaggedEnum> {
-        override val descriptor =
-                buildClassSerialDescriptor("MyExternallyTaggedEnum") {
-                    element<VariantA>("VariantA", isOptional = true)
-                    element<VariantB>("VariantB", isOptional = true)
-                    element<LegacyVariant>("LegacyVariant", isOptional = true)
-                }
+@Deprecated(message = "Use `MySuperAwesomeStruct` instead (since 0.1.0)")
+data class MyLegacyStruct(
+    val field: String,
+)
 
-        override fun serialize(encoder: Encoder, value: MyExternallyTaggedEnum) {
-            encoder.encodeStructure(descriptor) {
-                when (value) {
-                    is VariantA ->
-                            encodeSerializableElement(
-                                    descriptor,
-                                    0,
-                                    String.serializer(),
-                                    value.value
-                            )
-                    is VariantB ->
-                            encodeSerializableElement(descriptor, 1, UInt.serializer(), value.value)
-                    is LegacyVariant ->
-                            encodeSerializableElement(
-                                    descriptor,
-                                    2,
-                                    Boolean.serializer(),
-                                    value.value
-                            )
-                }
-            }
-        }
+data class MyStruct(
+    val field: String,
+    @Deprecated(message = "Use `field` instead")
+    val legacyField: String,
+    @Deprecated(message = "")
+    val unexplainedField: UInt,
+)
 
-        override fun deserialize(decoder: Decoder): MyExternallyTaggedEnum {
-            return decoder.decodeStructure(descriptor) {
-                when (val index = decodeElementIndex(descriptor)) {
-                    0 -> {
-                        val value = decodeSerializableElement(descriptor, 0, String.serializer())
-                        return@decodeStructure VariantA(value)
-                    }
-                    1 -> {
-                        val value = decodeSerializableElement(descriptor, 1, UInt.serializer())
-                        return@decodeStructure VariantB(value)
-                    }
-                    2 -> {
-                        val value = decodeSerializableElement(descriptor, 2, Boolean.serializer())
-                        return@decodeStructure LegacyVariant(value)
-                    }
-                    else ->
-                            throw Exception(
-                                    "Unknown enum variant $index for MyExternallyTaggedEnum"
-                            )
-                }
-            }
-        }
-    }
+enum class MyUnitEnum {
+    VARIANT_A,
+    VARIANT_B,
+    @Deprecated(message = "Use `VariantB` instead") LEGACY_VARIANT;
 }
@@ -11,6 +11,7 @@ export const myExternallyTaggedEnumVariantA = (value: str): MyExternallyTaggedEn
 
 export const myExternallyTaggedEnumVariantB = (value: uint32): MyExternallyTaggedEnum => ({ kind: "VariantB", value });
 
+/** @deprecated Use `VariantB` instead */
 export const myExternallyTaggedEnumLegacyVariant = (value: bool): MyExternallyTaggedEnum => ({ kind: "LegacyVariant", value });
 
 export function matchMyExternallyTaggedEnum<R>(value: MyExternallyTaggedEnum, cases: {
@@ -30,6 +31,7 @@ export const myInternallyTaggedEnumVariantA = (field: str): MyInternallyTaggedEn
 
 export const myInternallyTaggedEnumVariantB = (field: uint32): MyInternallyTaggedEnum => ({ type: "VariantB", field });
 
+/** @deprecated Use `VariantA` instead */
 export const myInternallyTaggedEnumLegacyVariant = (field: bool): MyInternallyTaggedEnum => ({ type: "LegacyVariant", field });
 
 export function matchMyInternallyTaggedEnum<R>(value: MyInternallyTaggedEnum, cases: {
@@ -40,11 +42,13 @@ export function matchMyInternallyTaggedEnum<R>(value: MyInternallyTaggedEnum, ca
     return cases[value.type as MyInternallyTaggedEnum["type"]](value as never);
 }
 
+/** @deprecated Use `MySuperAwesomeAlias` instead */
 export class MyLegacyAlias {
     constructor (public value: uint32) {
     }
 }
 
+/** @deprecated Use `MySuperAwesomeEnum` instead */
 export type MyLegacyEnum =
     | { kind: "VariantA" }
     | { kind: "VariantB" }
@@ -64,11 +68,17 @@ export function matchMyLegacyEnum<R>(value: MyLegacyEnum, cases: {
     return cases[value.kind as MyLegacyEnum["kind"]](value as never);
 }
 
+/** @deprecated Use `MySuperAwesomeStruct` instead (since 0.1.0) */
 export class MyLegacyStruct {
     constructor (public field: str) {
     }
 }
 
+export class MyStruct {
+    constructor (public field: str, /** @deprecated Use `field` instead */ public legacy_field: str, /** @deprecated */ public unexplained_field: uint32) {
+    }
+}
+
 export type MyUnitEnum =
     | { kind: "VariantA" }
     | { kind: "VariantB" }
@@ -78,6 +88,7 @@ export const myUnitEnumVariantA = (): MyUnitEnum => ({ kind: "VariantA" });
 
 export const myUnitEnumVariantB = (): MyUnitEnum => ({ kind: "VariantB" });
 
+/** @deprecated Use `VariantB` instead */
 export const myUnitEnumLegacyVariant = (): MyUnitEnum => ({ kind: "LegacyVariant" });
 
 export function matchMyUnitEnum<R>(value: MyUnitEnum, cases: {
@@ -141,16 +141,24 @@ fn check<'a, L: CodeGenerator<'a>>(
     Ok(())
 }
 
-/// Register the given types and output code for the given languages.
+/// Register the given types and output code for the given languages, optionally
+/// with serialization plugins.
 /// e.g.
 /// ```rust
 /// test! {
 ///    UnitStruct, AnotherType for kotlin, swift, typescript
 /// }
+/// test! {
+///    UnitStruct for kotlin, swift with JsonPlugin
+/// }
 /// ```
 #[macro_export]
 macro_rules! test {
     ($($ty:ident),* for $($language:ident),*) => {
+        $crate::test!($($ty),* for $($language),* with);
+    };
+
+    ($($ty:ident),* for $($language:ident),* with $($plugin:ident),*) => {
         mod tests {
             use $crate::{tests::check, test};
             use super::*;
@@ -163,11 +171,11 @@ macro_rules! test {
             };
             use $crate::generation::{$($language),*};
 
-            test!(@generate_tests [$($ty),*] $($language),*);
+            test!(@generate_tests [$($ty),*] [$($plugin),*] $($language),*);
         }
     };
 
-    (@generate_tests [$($ty:ident),*] $language:ident $(, $rest:ident)*) => {
+    (@generate_tests [$($ty:ident),*] [$($plugin:ident),*] $language:ident $(, $rest:ident)*) => {
         #[test]
         fn $language() -> Result<()> {
             let registry = RegistryBuilder::new()
@@ -176,7 +184,9 @@ macro_rules! test {
                 .unwrap();
             let package_name = test!(@package $language).to_string();
             let cfg = CodeGeneratorConfig::new(package_name);
-            let generator = <test!(@gen $language) as CodeGenerator>::new(&cfg);
+            let generator = <test!(@gen $language) as CodeGenerator>::new(&cfg).with_plugins(vec![
+                $(std::sync::Arc::new(test!(@plugin $plugin)) as std::sync::Arc<dyn $crate::generation::plugin::EmitterPlugin<_>>),*
+            ]);
             let expect = expect_file!(test!(@out $language));
 
             check(&registry, generator, &expect)?;
@@ -185,10 +195,10 @@ macro_rules! test {
             Ok(())
         }
 
-        test!(@generate_tests [$($ty),*] $($rest),*);
+        test!(@generate_tests [$($ty),*] [$($plugin),*] $($rest),*);
     };
 
-    (@generate_tests [$($ty:ident),*]) => {};
+    (@generate_tests [$($ty:ident),*] [$($plugin:ident),*]) => {};
 
     (@package kotlin) => { "com.example" };
     (@package swift) => { "ExamplePackage" };
@@ -204,4 +214,7 @@ macro_rules! test {
     (@gen swift) => { swift::SwiftCodeGenerator };
     (@gen typescript) => { typescript::TypeScriptCodeGenerator };
     (@gen csharp) => { csharp::CSharpCodeGenerator };
+
+    (@plugin JsonPlugin) => { $crate::generation::json::JsonPlugin };
+    (@plugin BincodePlugin) => { $crate::generation::bincode::BincodePlugin };
 }
@@ -1,17 +1,25 @@
 #![expect(clippy::enum_variant_names)]
 #![expect(unused)]
 
+use crate as fg;
+
 use facet::Facet;
 
 #[derive(Facet)]
-// TODO: #[facet(kotlin = "Parcelable")]
+#[facet(
+    fg::annotate(lang = "kotlin", with = "@Parcelize"),
+    fg::conforms(lang = "kotlin", to = "Parcelable")
+)]
 pub struct Struct {
     field1: String,
     field2: u32,
 }
 
 #[derive(Facet)]
-// TODO: #[facet(kotlin = "Parcelable")]
+#[facet(
+    fg::annotate(lang = "kotlin", with = "@Parcelize"),
+    fg::conforms(lang = "kotlin", to = "Parcelable")
+)]
 #[repr(C)]
 pub enum UnitEnum {
     VariantA,
@@ -20,7 +28,10 @@ pub enum UnitEnum {
 }
 
 #[derive(Facet)]
-// TODO: #[facet(kotlin = "Parcelable")]
+#[facet(
+    fg::annotate(lang = "kotlin", with = "@Parcelize"),
+    fg::conforms(lang = "kotlin", to = "Parcelable")
+)]
 #[repr(C)]
 pub enum ExternallyTaggedEnum {
     TupleVariant(String),
@@ -28,7 +39,10 @@ pub enum ExternallyTaggedEnum {
 }
 
 #[derive(Facet)]
-// TODO: #[facet(kotlin = "Parcelable")]
+#[facet(
+    fg::annotate(lang = "kotlin", with = "@Parcelize"),
+    fg::conforms(lang = "kotlin", to = "Parcelable")
+)]
 #[facet(tag = "type")]
 #[repr(C)]
 pub enum InternallyTaggedEnum {
@@ -38,7 +52,10 @@ pub enum InternallyTaggedEnum {
 }
 
 #[derive(Facet)]
-// TODO: #[facet(kotlin = "Parcelable")]
+#[facet(
+    fg::annotate(lang = "kotlin", with = "@Parcelize"),
+    fg::conforms(lang = "kotlin", to = "Parcelable")
+)]
 #[facet(tag = "type", content = "content")]
 #[repr(C)]
 pub enum AdjacentlyTaggedEnum {
@@ -46,3 +63,5 @@ pub enum AdjacentlyTaggedEnum {
     TupleVariant(String),
     StructVariant { field: String },
 }
+
+crate::test! { Struct, UnitEnum, ExternallyTaggedEnum, InternallyTaggedEnum, AdjacentlyTaggedEnum for kotlin }
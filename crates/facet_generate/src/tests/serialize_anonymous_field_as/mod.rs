@@ -14,3 +14,7 @@ pub enum SomeEnum {
 
 #[derive(Facet, Serialize, Debug)]
 pub struct SomeOtherType;
+
+crate::test! {
+    SomeEnum for kotlin, swift, typescript, csharp
+}
@@ -0,0 +1,14 @@
+using CommunityToolkit.Mvvm.ComponentModel;
+using Facet.Runtime.Serde;
+using System.Collections.Generic;
+using System.Collections.ObjectModel;
+
+namespace Example;
+
+public abstract record SomeEnum {
+    /// The associated String contains some opaque context
+    public sealed record Context(string Value) : SomeEnum;
+
+    public sealed record Other(int Value) : SomeEnum;
+
+}
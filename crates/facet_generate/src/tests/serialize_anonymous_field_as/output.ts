@@ -1,5 +1,17 @@
-export type SomeEnum = 
-    /** The associated String contains some opaque context */
-    | { type: "Context", content: string }
-    | { type: "Other", content: number };
+type int32 = number;
+type str = string;
 
+export type SomeEnum =
+    | { type: "Context"; content: str }
+    | { type: "Other"; content: int32 };
+
+export const someEnumContext = (value: str): SomeEnum => ({ type: "Context", content: value });
+
+export const someEnumOther = (value: int32): SomeEnum => ({ type: "Other", content: value });
+
+export function matchSomeEnum<R>(value: SomeEnum, cases: {
+    Context: (v: Extract<SomeEnum, { type: "Context" }>) => R;
+    Other: (v: Extract<SomeEnum, { type: "Other" }>) => R;
+}): R {
+    return cases[value.type as SomeEnum["type"]](value as never);
+}
@@ -35,3 +35,7 @@ pub struct EditItemSaveValue;
 #[allow(clippy::unsafe_derive_deserialize)]
 #[derive(Facet, Serialize, Deserialize, Debug)]
 pub struct AutoFillItemActionRequest;
+
+crate::test! {
+    EditItemViewModelSaveRequest for kotlin, swift, typescript, csharp
+}
@@ -0,0 +1,19 @@
+using CommunityToolkit.Mvvm.ComponentModel;
+using Facet.Runtime.Serde;
+using System.Collections.Generic;
+using System.Collections.ObjectModel;
+
+namespace Example;
+
+public sealed record AutoFillItemActionRequest;
+
+public sealed record EditItemSaveValue;
+
+public partial class EditItemViewModelSaveRequest : ObservableObject {
+    [ObservableProperty]
+    private string _context;
+    [ObservableProperty]
+    private ObservableCollection<EditItemSaveValue> _values;
+    [ObservableProperty]
+    private AutoFillItemActionRequest? _fillAction;
+}
@@ -1,15 +1,11 @@
 package com.example
 
-import kotlinx.serialization.*
-import kotlinx.serialization.builtins.*
-import kotlinx.serialization.descriptors.*
-import kotlinx.serialization.encoding.*
-import kotlinx.serialization.json.*
-import kotlinx.serialization.modules.*
+data object AutoFillItemActionRequest
+
+data object EditItemSaveValue
 
-@Serializable
 data class EditItemViewModelSaveRequest(
-        val context: String,
-        val values: List<EditItemSaveValue>,
-        val fill_action: AutoFillItemActionRequest? = null
+    val context: String,
+    val values: List<com.example.EditItemSaveValue>,
+    val fillAction: com.example.AutoFillItemActionRequest? = null,
 )
@@ -1,13 +1,22 @@
-import Foundation
 
-struct EditItemViewModelSaveRequest: Codable {
-    var context: String
-    var values: [EditItemSaveValue]
-    var fill_action: AutoFillItemActionRequest?
+public struct AutoFillItemActionRequest {
+    public init() {
+    }
+}
+
+public struct EditItemSaveValue {
+    public init() {
+    }
+}
+
+public struct EditItemViewModelSaveRequest {
+    public var context: String
+    public var values: [EditItemSaveValue]
+    public var fillAction: AutoFillItemActionRequest?
 
-    init(context: String, values: [EditItemSaveValue], fill_action: AutoFillItemActionRequest?) {
+    public init(context: String, values: [EditItemSaveValue], fillAction: AutoFillItemActionRequest?) {
         self.context = context
         self.values = values
-        self.fill_action = fill_action
+        self.fillAction = fillAction
     }
 }
@@ -1,6 +1,18 @@
-export interface EditItemViewModelSaveRequest {
-    context: string;
-    values: EditItemSaveValue[];
-    fill_action?: AutoFillItemActionRequest | null;
+type Optional<T> = T | null;
+type Seq<T> = T[];
+type str = string;
+
+export class AutoFillItemActionRequest {
+    constructor () {
+    }
+}
+
+export class EditItemSaveValue {
+    constructor () {
+    }
 }
 
+export class EditItemViewModelSaveRequest {
+    constructor (public context: str, public values: Seq<EditItemSaveValue>, public fill_action: Optional<AutoFillItemActionRequest>) {
+    }
+}
@@ -14,3 +14,7 @@ struct Uuid(String);
 type AccountUuid = Uuid;
 
 type ItemUuid = Uuid;
+
+crate::test! {
+    Uuid for kotlin, swift, typescript, csharp
+}
@@ -1,10 +1,2 @@
-import Foundation
 
-typealias AlsoString = String
-
-typealias Uuid = String
-
-/// Unique identifier for an Account
-typealias AccountUuid = Uuid
-
-typealias ItemUuid = String
+public typealias Uuid = String
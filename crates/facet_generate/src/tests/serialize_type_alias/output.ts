@@ -1,9 +1,3 @@
-export type AlsoString = string;
-
-export type Uuid = string;
-
-/** Unique identifier for an Account */
-export type AccountUuid = Uuid;
-
-export type ItemUuid = string;
+type str = string;
 
+export type Uuid = str;
@@ -1,4 +1,6 @@
 #![expect(non_snake_case)]
+use crate as fg;
+
 use facet::Facet;
 
 #[derive(Facet)]
@@ -7,29 +9,33 @@ pub struct BestHockeyTeams {
     Lies: String,
 }
 #[derive(Facet)]
-// TODO: #[facet(swift = "Equatable")]
+#[facet(fg::conforms(lang = "swift", to = "Equatable"))]
 pub struct BestHockeyTeams1 {
     PittsburghPenguins: u32,
     Lies: String,
 }
 
 #[derive(Facet)]
-// TODO: #[facet(swift = "Equatable, Codable, Comparable, Hashable")]
+#[facet(fg::conforms(lang = "swift", to = "Equatable, Codable, Comparable, Hashable"))]
 pub struct BestHockeyTeams2 {
     PittsburghPenguins: u32,
     Lies: String,
 }
 
 #[derive(Facet)]
-// TODO: #[facet(kotlin = "idk")]
+#[facet(fg::conforms(lang = "kotlin", to = "Idk"))]
 pub struct BestHockeyTeams3 {
     PittsburghPenguins: u32,
     Lies: String,
 }
 
 #[derive(Facet)]
-// TODO: #[facet(swift = "Equatable", swift = "Hashable")]
+#[facet(fg::conforms(lang = "swift", to = "Equatable"))]
+#[facet(fg::conforms(lang = "swift", to = "Hashable"))]
+#[expect(clippy::duplicated_attributes)]
 pub struct BestHockeyTeams4 {
     PittsburghPenguins: u32,
     Lies: String,
 }
+
+crate::test! { BestHockeyTeams, BestHockeyTeams1, BestHockeyTeams2, BestHockeyTeams3, BestHockeyTeams4 for kotlin, swift }
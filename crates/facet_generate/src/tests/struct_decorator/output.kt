@@ -0,0 +1,26 @@
+package com.example
+
+data class BestHockeyTeams(
+    val pittsburghPenguins: UInt,
+    val lies: String,
+)
+
+data class BestHockeyTeams1(
+    val pittsburghPenguins: UInt,
+    val lies: String,
+)
+
+data class BestHockeyTeams2(
+    val pittsburghPenguins: UInt,
+    val lies: String,
+)
+
+data class BestHockeyTeams3(
+    val pittsburghPenguins: UInt,
+    val lies: String,
+) : Idk
+
+data class BestHockeyTeams4(
+    val pittsburghPenguins: UInt,
+    val lies: String,
+)
@@ -1,51 +1,50 @@
-import Foundation
 
-struct BestHockeyTeams: Codable {
-    var PittsburghPenguins: UInt32
-    var Lies: String
+public struct BestHockeyTeams {
+    public var pittsburghPenguins: UInt32
+    public var lies: String
 
-    init(PittsburghPenguins: UInt32, Lies: String) {
-        self.PittsburghPenguins = PittsburghPenguins
-        self.Lies = Lies
+    public init(pittsburghPenguins: UInt32, lies: String) {
+        self.pittsburghPenguins = pittsburghPenguins
+        self.lies = lies
     }
 }
 
-struct BestHockeyTeams1: Codable, Equatable {
-    var PittsburghPenguins: UInt32
-    var Lies: String
+public struct BestHockeyTeams1: Equatable {
+    public var pittsburghPenguins: UInt32
+    public var lies: String
 
-    init(PittsburghPenguins: UInt32, Lies: String) {
-        self.PittsburghPenguins = PittsburghPenguins
-        self.Lies = Lies
+    public init(pittsburghPenguins: UInt32, lies: String) {
+        self.pittsburghPenguins = pittsburghPenguins
+        self.lies = lies
     }
 }
 
-struct BestHockeyTeams2: Codable, Comparable, Equatable, Hashable {
-    var PittsburghPenguins: UInt32
-    var Lies: String
+public struct BestHockeyTeams2: Equatable, Codable, Comparable, Hashable {
+    public var pittsburghPenguins: UInt32
+    public var lies: String
 
-    init(PittsburghPenguins: UInt32, Lies: String) {
-        self.PittsburghPenguins = PittsburghPenguins
-        self.Lies = Lies
+    public init(pittsburghPenguins: UInt32, lies: String) {
+        self.pittsburghPenguins = pittsburghPenguins
+        self.lies = lies
     }
 }
 
-struct BestHockeyTeams3: Codable {
-    var PittsburghPenguins: UInt32
-    var Lies: String
+public struct BestHockeyTeams3 {
+    public var pittsburghPenguins: UInt32
+    public var lies: String
 
-    init(PittsburghPenguins: UInt32, Lies: String) {
-        self.PittsburghPenguins = PittsburghPenguins
-        self.Lies = Lies
+    public init(pittsburghPenguins: UInt32, lies: String) {
+        self.pittsburghPenguins = pittsburghPenguins
+        self.lies = lies
     }
 }
 
-struct BestHockeyTeams4: Codable, Equatable, Hashable {
-    var PittsburghPenguins: UInt32
-    var Lies: String
+public struct BestHockeyTeams4: Equatable, Hashable {
+    public var pittsburghPenguins: UInt32
+    public var lies: String
 
-    init(PittsburghPenguins: UInt32, Lies: String) {
-        self.PittsburghPenguins = PittsburghPenguins
-        self.Lies = Lies
+    public init(pittsburghPenguins: UInt32, lies: String) {
+        self.pittsburghPenguins = pittsburghPenguins
+        self.lies = lies
     }
 }
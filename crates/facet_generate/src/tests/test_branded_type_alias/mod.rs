@@ -19,7 +19,7 @@ pub struct BrandedOptionalStringAlias(Option<String>);
 
 #[derive(Facet)]
 #[facet(fg::branded)]
-// TODO: re-add language-specific annotation: #[facet(swift = "Equatable, Hashable")]
+#[facet(fg::conforms(lang = "swift", to = "Equatable, Hashable"))]
 pub struct BrandedU32Alias(u32);
 
 #[derive(Facet)]
@@ -31,3 +31,13 @@ struct MyStruct {
 #[derive(Facet)]
 #[facet(fg::branded)]
 pub struct BrandedStructAlias(MyStruct);
+
+crate::test! {
+    SimpleAlias1,
+    SimpleAlias2,
+    BrandedStringAlias,
+    BrandedOptionalStringAlias,
+    BrandedU32Alias,
+    BrandedStructAlias
+    for kotlin, swift, typescript, csharp
+}
@@ -0,0 +1,31 @@
+using CommunityToolkit.Mvvm.ComponentModel;
+using Facet.Runtime.Serde;
+using System.Collections.Generic;
+using System.Collections.ObjectModel;
+
+namespace Example;
+
+public readonly record struct BrandedOptionalStringAlias(string? Value);
+
+public readonly record struct BrandedStringAlias(string Value);
+
+public readonly record struct BrandedStructAlias(MyStruct Value);
+
+public readonly record struct BrandedU32Alias(uint Value);
+
+public partial class MyStruct : ObservableObject {
+    [ObservableProperty]
+    private uint _field;
+    [ObservableProperty]
+    private string _otherField;
+}
+
+public partial class SimpleAlias1 : ObservableObject {
+    [ObservableProperty]
+    private string _value;
+}
+
+public partial class SimpleAlias2 : ObservableObject {
+    [ObservableProperty]
+    private string _value;
+}
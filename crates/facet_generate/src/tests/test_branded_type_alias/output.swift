@@ -1,79 +1,58 @@
-import Foundation
 
-typealias SimpleAlias1 = String
+public struct BrandedOptionalStringAlias: RawRepresentable {
+    public var rawValue: String?
 
-typealias SimpleAlias2 = String
-
-struct BrandedStringAlias: Branded, Codable {
-    var value: String
-
-    init(_ value: String) {
-        self.value = value
-    }
-
-    func encode(to encoder: Encoder) throws {
-        try value.encode(to: encoder)
-    }
-
-    init(from decoder: Decoder) throws {
-        self.value = try String(from: decoder)
+    public init(rawValue: String?) {
+        self.rawValue = rawValue
     }
 }
 
-struct BrandedOptionalStringAlias: Branded, Codable {
-    var value: String?
-
-    init(_ value: String?) {
-        self.value = value
-    }
-
-    func encode(to encoder: Encoder) throws {
-        try value.encode(to: encoder)
-    }
+public struct BrandedStringAlias: RawRepresentable {
+    public var rawValue: String
 
-    init(from decoder: Decoder) throws {
-        self.value = try String?(from: decoder)
+    public init(rawValue: String) {
+        self.rawValue = rawValue
     }
 }
 
-struct BrandedU32Alias: Branded, Codable, Equatable, Hashable {
-    var value: UInt32
+public struct BrandedStructAlias: RawRepresentable {
+    public var rawValue: MyStruct
 
-    init(_ value: UInt32) {
-        self.value = value
+    public init(rawValue: MyStruct) {
+        self.rawValue = rawValue
     }
+}
 
-    func encode(to encoder: Encoder) throws {
-        try value.encode(to: encoder)
-    }
+public struct BrandedU32Alias: RawRepresentable, Equatable, Hashable {
+    public var rawValue: UInt32
 
-    init(from decoder: Decoder) throws {
-        self.value = try UInt32(from: decoder)
+    public init(rawValue: UInt32) {
+        self.rawValue = rawValue
     }
 }
 
-struct MyStruct: Codable {
-    var field: UInt32
-    var other_field: String
+public struct MyStruct {
+    public var field: UInt32
+    public var otherField: String
 
-    init(field: UInt32, other_field: String) {
+    public init(field: UInt32, otherField: String) {
         self.field = field
-        self.other_field = other_field
+        self.otherField = otherField
     }
 }
 
-struct BrandedStructAlias: Branded, Codable {
-    var value: MyStruct
+public struct SimpleAlias1 {
+    public var value: String
 
-    init(_ value: MyStruct) {
+    public init(value: String) {
         self.value = value
     }
+}
 
-    func encode(to encoder: Encoder) throws {
-        try value.encode(to: encoder)
-    }
+public struct SimpleAlias2 {
+    public var value: String
 
-    init(from decoder: Decoder) throws {
-        self.value = try MyStruct(from: decoder)
+    public init(value: String) {
+        self.value = value
     }
 }
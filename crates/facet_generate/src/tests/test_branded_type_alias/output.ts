@@ -1,17 +1,26 @@
-export type SimpleAlias1 = string;
+type Optional<T> = T | null;
+type str = string;
+type uint32 = number;
 
-export type SimpleAlias2 = string;
+export type BrandedOptionalStringAlias = Optional<str & { readonly __brand: "BrandedOptionalStringAlias" }>;
 
-export type BrandedStringAlias = (string & { __brand: "BrandedStringAlias" });
+export type BrandedStringAlias = str & { readonly __brand: "BrandedStringAlias" };
 
-export type BrandedOptionalStringAlias = (string & { __brand: "BrandedOptionalStringAlias" }) | undefined | null;
+export type BrandedStructAlias = MyStruct & { readonly __brand: "BrandedStructAlias" };
 
-export type BrandedU32Alias = (number & { __brand: "BrandedU32Alias" });
+export type BrandedU32Alias = uint32 & { readonly __brand: "BrandedU32Alias" };
 
-export interface MyStruct {
-    field: number;
-    other_field: string;
+export class MyStruct {
+    constructor (public field: uint32, public other_field: str) {
+    }
 }
 
-export type BrandedStructAlias = (MyStruct & { __brand: "BrandedStructAlias" });
+export class SimpleAlias1 {
+    constructor (public value: str) {
+    }
+}
 
+export class SimpleAlias2 {
+    constructor (public value: str) {
+    }
+}
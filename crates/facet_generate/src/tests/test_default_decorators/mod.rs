@@ -1,5 +1,9 @@
+use crate as fg;
+
 use facet::Facet;
 
 #[derive(Facet)]
-// TODO: #[facet(swift = "Equatable")]
+#[facet(fg::conforms(lang = "swift", to = "Equatable, Sendable"))]
 struct EmptyType {}
+
+crate::test! { EmptyType for swift }
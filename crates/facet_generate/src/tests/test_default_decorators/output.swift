@@ -1,5 +1,5 @@
-import Foundation
 
-struct EmptyType: Codable, Equatable, Identifiable, Sendable {
-    init() {}
+public struct EmptyType: Equatable, Sendable {
+    public init() {
+    }
 }
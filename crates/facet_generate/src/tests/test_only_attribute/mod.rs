@@ -2,47 +2,74 @@
 #![expect(clippy::struct_field_names)]
 #![expect(clippy::enum_variant_names)]
 
+use crate as fg;
+
 use facet::Facet;
 
 #[derive(Facet)]
-// TODO: #[facet(swift(only))]
+#[facet(fg::only(swift))]
 pub struct StructOnlyInSwift {
     field: String,
 }
 
 #[derive(Facet)]
-// TODO: #[facet(kotlin(only))]
+#[facet(fg::only(kotlin))]
 pub struct StructOnlyInKotlin {
     field: String,
 }
 
 #[derive(Facet)]
-// TODO: #[facet(typescript(only))]
+#[facet(fg::only(typescript))]
 pub struct StructOnlyInTypeScript {
     field: String,
 }
 
+#[derive(Facet)]
+#[facet(fg::only(csharp))]
+pub struct StructOnlyInCSharp {
+    field: String,
+}
+
 #[derive(Facet)]
 pub struct Struct {
-    // TODO: #[facet(swift(only))]
+    #[facet(fg::only(swift))]
     pub only_in_swift: String,
 
-    // TODO: #[facet(kotlin(only))]
+    #[facet(fg::only(kotlin))]
     pub only_in_kotlin: String,
 
-    // TODO: #[facet(typescript(only))]
+    #[facet(fg::only(typescript))]
     pub only_in_typescript: String,
+
+    #[facet(fg::only(csharp))]
+    pub only_in_csharp: String,
+
+    #[facet(fg::only(kotlin, swift))]
+    pub only_in_mobile: String,
 }
 
 #[derive(Facet)]
 #[repr(C)]
 pub enum Enum {
-    // TODO: #[facet(swift(only))]
+    #[facet(fg::only(swift))]
     OnlyInSwift(String),
 
-    // TODO: #[facet(kotlin(only))]
+    #[facet(fg::only(kotlin))]
     OnlyInKotlin(String),
 
-    // TODO: #[facet(typescript(only))]
+    #[facet(fg::only(typescript))]
     OnlyInTypeScript(String),
+
+    #[facet(fg::only(csharp))]
+    OnlyInCSharp(String),
+}
+
+crate::test! {
+    StructOnlyInSwift,
+    StructOnlyInKotlin,
+    StructOnlyInTypeScript,
+    StructOnlyInCSharp,
+    Struct,
+    Enum
+    for kotlin, swift, typescript, csharp
 }
@@ -0,0 +1,21 @@
+using CommunityToolkit.Mvvm.ComponentModel;
+using Facet.Runtime.Serde;
+using System.Collections.Generic;
+using System.Collections.ObjectModel;
+
+namespace Example;
+
+public abstract record Enum {
+    public sealed record OnlyInCSharp(string Value) : Enum;
+
+}
+
+public partial class Struct : ObservableObject {
+    [ObservableProperty]
+    private string _onlyInCsharp;
+}
+
+public partial class StructOnlyInCSharp : ObservableObject {
+    [ObservableProperty]
+    private string _field;
+}
@@ -1,11 +1,22 @@
-export interface StructOnlyInTypeScript {
-    field: string;
-}
+type str = string;
+
+export type Enum =
+    | { kind: "OnlyInTypeScript"; value: str };
+
+export const enumOnlyInTypeScript = (value: str): Enum => ({ kind: "OnlyInTypeScript", value });
 
-export interface Struct {
-    only_in_typescript: string;
+export function matchEnum<R>(value: Enum, cases: {
+    OnlyInTypeScript: (v: Extract<Enum, { kind: "OnlyInTypeScript" }>) => R;
+}): R {
+    return cases[value.kind as Enum["kind"]](value as never);
 }
 
-export type Enum = 
-    | { OnlyInTypeScript: string };
+export class Struct {
+    constructor (public only_in_typescript: str) {
+    }
+}
 
+export class StructOnlyInTypeScript {
+    constructor (public field: str) {
+    }
+}
@@ -5,4 +5,15 @@ use facet::Facet;
 pub struct Foo {
     #[facet(default)]
     pub bar: bool,
+    #[facet(default = 3)]
+    pub retries: u32,
+    #[facet(default = "guest".to_string())]
+    pub user_name: String,
+    #[facet(default)]
+    pub tags: Vec<String>,
+    #[facet(default)]
+    pub nickname: Option<String>,
+    pub id: u64,
 }
+
+crate::test! { Foo for kotlin, swift, typescript, csharp }
@@ -0,0 +1,21 @@
+using CommunityToolkit.Mvvm.ComponentModel;
+using Facet.Runtime.Serde;
+using System.Collections.Generic;
+using System.Collections.ObjectModel;
+
+namespace Example;
+
+public partial class Foo : ObservableObject {
+    [ObservableProperty]
+    private bool _bar = false;
+    [ObservableProperty]
+    private uint _retries = 3;
+    [ObservableProperty]
+    private string _userName = "guest";
+    [ObservableProperty]
+    private ObservableCollection<string> _tags = [];
+    [ObservableProperty]
+    private string? _nickname = null;
+    [ObservableProperty]
+    private ulong _id;
+}
@@ -1,10 +1,10 @@
 package com.example
 
-import kotlinx.serialization.*
-import kotlinx.serialization.builtins.*
-import kotlinx.serialization.descriptors.*
-import kotlinx.serialization.encoding.*
-import kotlinx.serialization.json.*
-import kotlinx.serialization.modules.*
-
-@Serializable data class Foo(val bar: Boolean)
+data class Foo(
+    val bar: Boolean = false,
+    val retries: UInt = 3u,
+    val userName: String = "guest",
+    val tags: List<String> = listOf(),
+    val nickname: String? = null,
+    val id: ULong,
+)
@@ -1,9 +1,18 @@
-import Foundation
 
-struct Foo: Codable {
-    var bar: Bool
+public struct Foo {
+    public var bar: Bool
+    public var retries: UInt32
+    public var userName: String
+    public var tags: [String]
+    public var nickname: String?
+    public var id: UInt64
 
-    init(bar: Bool) {
+    public init(bar: Bool = false, retries: UInt32 = 3, userName: String = "guest", tags: [String] = [], nickname: String? = nil, id: UInt64) {
         self.bar = bar
+        self.retries = retries
+        self.userName = userName
+        self.tags = tags
+        self.nickname = nickname
+        self.id = id
     }
 }
@@ -1,4 +1,11 @@
-export interface Foo {
-    bar: boolean;
-}
+type bool = boolean;
+type Optional<T> = T | null;
+type Seq<T> = T[];
+type str = string;
+type uint32 = number;
+type uint64 = bigint;
 
+export class Foo {
+    constructor (public id: uint64, public bar: bool = false, public retries: uint32 = 3, public userName: str = "guest", public tags: Seq<str> = [], public nickname: Optional<str> = null) {
+    }
+}
@@ -1,8 +1,13 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use facet::Facet;
 
 #[derive(Facet)]
 #[facet(rename_all = "camelCase")]
 pub struct Foo {
     pub time: DateTime<Utc>,
+    pub birthday: Option<NaiveDate>,
+    pub alarm: NaiveTime,
+    pub timeout: std::time::Duration,
 }
+
+crate::test! { Foo for kotlin, swift, typescript, csharp }
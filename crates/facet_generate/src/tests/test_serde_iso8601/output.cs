@@ -0,0 +1,17 @@
+using CommunityToolkit.Mvvm.ComponentModel;
+using Facet.Runtime.Serde;
+using System.Collections.Generic;
+using System.Collections.ObjectModel;
+
+namespace Example;
+
+public partial class Foo : ObservableObject {
+    [ObservableProperty]
+    private DateTimeOffset _time;
+    [ObservableProperty]
+    private DateOnly? _birthday;
+    [ObservableProperty]
+    private TimeOnly _alarm;
+    [ObservableProperty]
+    private TimeSpan _timeout;
+}
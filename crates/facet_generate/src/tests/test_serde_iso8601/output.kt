@@ -1,10 +1,8 @@
 package com.example
 
-import kotlinx.serialization.*
-import kotlinx.serialization.builtins.*
-import kotlinx.serialization.descriptors.*
-import kotlinx.serialization.encoding.*
-import kotlinx.serialization.json.*
-import kotlinx.serialization.modules.*
-
-@Serializable data class Foo(val time: String)
+data class Foo(
+    val time: Instant,
+    val birthday: LocalDate? = null,
+    val alarm: LocalTime,
+    val timeout: Duration,
+)
@@ -1,9 +1,15 @@
 import Foundation
 
-struct Foo: Codable {
-    var time: Date
+public struct Foo {
+    public var time: Date
+    public var birthday: DateComponents?
+    public var alarm: DateComponents
+    public var timeout: TimeInterval
 
-    init(time: Date) {
+    public init(time: Date, birthday: DateComponents?, alarm: DateComponents, timeout: TimeInterval) {
         self.time = time
+        self.birthday = birthday
+        self.alarm = alarm
+        self.timeout = timeout
     }
 }
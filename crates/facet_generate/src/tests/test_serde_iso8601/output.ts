@@ -1,4 +1,10 @@
-export interface Foo {
-    time: string;
-}
+type date = string;
+type duration = { secs: bigint; nanos: number };
+type Optional<T> = T | null;
+type time = string;
+type timestamp = Date;
 
+export class Foo {
+    constructor (public time: timestamp, public birthday: Optional<date>, public alarm: time, public timeout: duration) {
+    }
+}
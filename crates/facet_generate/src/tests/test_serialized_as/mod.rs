@@ -22,3 +22,14 @@ pub enum Options {
     /// Sends a string along
     Cool(String),
 }
+
+#[derive(Facet)]
+pub struct Item {
+    id: ItemId,
+    options: Vec<Options>,
+    parent: Option<ItemId>,
+}
+
+crate::test! {
+    Item for kotlin, swift, typescript, csharp
+}
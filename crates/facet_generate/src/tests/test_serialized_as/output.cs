@@ -0,0 +1,15 @@
+using CommunityToolkit.Mvvm.ComponentModel;
+using Facet.Runtime.Serde;
+using System.Collections.Generic;
+using System.Collections.ObjectModel;
+
+namespace Example;
+
+public partial class Item : ObservableObject {
+    [ObservableProperty]
+    private string _id;
+    [ObservableProperty]
+    private ObservableCollection<string> _options;
+    [ObservableProperty]
+    private string? _parent;
+}
@@ -1,11 +1,10 @@
 package com.example
 
-import kotlinx.serialization.*
-import kotlinx.serialization.builtins.*
-import kotlinx.serialization.descriptors.*
-import kotlinx.serialization.encoding.*
-import kotlinx.serialization.json.*
-import kotlinx.serialization.modules.*
+data class Item(
+    val id: String,
+    val options: List<String>,
+    val parent: String? = null,
+)
 
 typealias ItemId = String
 
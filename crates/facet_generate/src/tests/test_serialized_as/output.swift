@@ -1,6 +1,17 @@
-import Foundation
 
-typealias ItemId = String
+public struct Item {
+    public var id: String
+    public var options: [String]
+    public var parent: String?
+
+    public init(id: String, options: [String], parent: String?) {
+        self.id = id
+        self.options = options
+        self.parent = parent
+    }
+}
+
+public typealias ItemId = String
 
 /// Options that you could pick
-typealias Options = String
+public typealias Options = String
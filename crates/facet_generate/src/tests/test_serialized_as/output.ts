@@ -1,5 +1,13 @@
-export type ItemId = string;
+type Optional<T> = T | null;
+type Seq<T> = T[];
+type str = string;
 
-/** Options that you could pick */
-export type Options = string;
+export class Item {
+    constructor (public id: str, public options: Seq<str>, public parent: Optional<str>) {
+    }
+}
 
+export type ItemId = str;
+
+/// Options that you could pick
+export type Options = str;
@@ -5,3 +5,14 @@ use facet::Facet;
 #[derive(Facet)]
 #[facet(fg::serialized_as = "String")]
 pub struct ItemId(i64);
+
+#[derive(Facet)]
+#[facet(fg::serialized_as = "(u32, i64)")]
+pub struct ShardedId {
+    shard: u32,
+    id: ItemId,
+}
+
+crate::test! {
+    ItemId, ShardedId for kotlin, swift, typescript, csharp
+}
@@ -1,3 +1,4 @@
-import Foundation
 
-typealias ItemId = String
+public typealias ItemId = String
+
+public typealias ShardedId = (UInt32, Int64)
@@ -1,2 +1,8 @@
-export type ItemId = string;
+type int64 = bigint;
+type str = string;
+type Tuple<T extends any[]> = T;
+type uint32 = number;
 
+export type ItemId = str;
+
+export type ShardedId = Tuple<[uint32, int64]>;
@@ -1,38 +1,51 @@
 #![expect(clippy::enum_variant_names)]
 
+use crate as fg;
+
 use facet::Facet;
 
 #[derive(Facet)]
-// TODO: implement language-specific skip: #[facet(swift(skip))]
-#[facet(skip)]
+#[facet(fg::skip(swift))]
 pub struct NotVisibleInSwift {
     inner: u32,
 }
 
 #[derive(Facet)]
-// TODO: implement language-specific skip: #[facet(kotlin(skip))]
-#[facet(skip)]
+#[facet(fg::skip(kotlin))]
 pub struct NotVisibleInKotlin {
     inner: u32,
 }
 
 #[derive(Facet)]
-// TODO: implement language-specific skip: #[facet(typescript(skip))]
-#[facet(skip)]
+#[facet(fg::skip(typescript))]
 pub struct NotVisibleInTypescript {
     inner: u32,
 }
 
+#[derive(Facet)]
+#[facet(fg::skip(csharp))]
+pub struct NotVisibleInCSharp {
+    inner: u32,
+}
+
 #[derive(Facet)]
 #[repr(C)]
 pub enum EnumWithVariantsPerLanguage {
-    // TODO: implement language-specific skip: #[facet(swift(skip))]
-    #[facet(skip)]
+    #[facet(fg::skip(swift))]
+    NotVisibleInSwift,
+    #[facet(fg::skip(kotlin))]
+    NotVisibleInKotlin,
+    #[facet(fg::skip(typescript))]
+    NotVisibleInTypescript,
+    #[facet(fg::skip(csharp))]
+    NotVisibleInCSharp,
+}
+
+crate::test! {
     NotVisibleInSwift,
-    // TODO: implement language-specific skip: #[facet(kotlin(skip))]
-    #[facet(skip)]
     NotVisibleInKotlin,
-    // TODO: implement language-specific skip: #[facet(typescript(skip))]
-    #[facet(skip)]
     NotVisibleInTypescript,
+    NotVisibleInCSharp,
+    EnumWithVariantsPerLanguage
+    for kotlin, swift, typescript, csharp
 }
@@ -0,0 +1,27 @@
+using CommunityToolkit.Mvvm.ComponentModel;
+using Facet.Runtime.Serde;
+using System.Collections.Generic;
+using System.Collections.ObjectModel;
+
+namespace Example;
+
+public enum EnumWithVariantsPerLanguage {
+    NotVisibleInSwift,
+    NotVisibleInKotlin,
+    NotVisibleInTypescript
+}
+
+public partial class NotVisibleInKotlin : ObservableObject {
+    [ObservableProperty]
+    private uint _inner;
+}
+
+public partial class NotVisibleInSwift : ObservableObject {
+    [ObservableProperty]
+    private uint _inner;
+}
+
+public partial class NotVisibleInTypescript : ObservableObject {
+    [ObservableProperty]
+    private uint _inner;
+}
@@ -1,22 +1,30 @@
-import Foundation
 
-struct NotVisibleInKotlin: Codable {
-    var inner: UInt32
+indirect public enum EnumWithVariantsPerLanguage {
+    case notVisibleInKotlin
+    case notVisibleInTypescript
+    case notVisibleInCSharp
+}
+
+public struct NotVisibleInCSharp {
+    public var inner: UInt32
 
-    init(inner: UInt32) {
+    public init(inner: UInt32) {
         self.inner = inner
     }
 }
 
-struct NotVisibleInTypescript: Codable {
-    var inner: UInt32
+public struct NotVisibleInKotlin {
+    public var inner: UInt32
 
-    init(inner: UInt32) {
+    public init(inner: UInt32) {
         self.inner = inner
     }
 }
 
-enum EnumWithVariantsPerLanguage: String, Codable {
-    case notVisibleInKotlin = "NotVisibleInKotlin"
-    case notVisibleInTypescript = "NotVisibleInTypescript"
+public struct NotVisibleInTypescript {
+    public var inner: UInt32
+
+    public init(inner: UInt32) {
+        self.inner = inner
+    }
 }
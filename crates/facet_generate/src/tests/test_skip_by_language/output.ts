@@ -1,13 +1,35 @@
-export interface NotVisibleInSwift {
-    inner: number;
+type uint32 = number;
+
+export type EnumWithVariantsPerLanguage =
+    | { kind: "NotVisibleInSwift" }
+    | { kind: "NotVisibleInKotlin" }
+    | { kind: "NotVisibleInCSharp" };
+
+export const enumWithVariantsPerLanguageNotVisibleInSwift = (): EnumWithVariantsPerLanguage => ({ kind: "NotVisibleInSwift" });
+
+export const enumWithVariantsPerLanguageNotVisibleInKotlin = (): EnumWithVariantsPerLanguage => ({ kind: "NotVisibleInKotlin" });
+
+export const enumWithVariantsPerLanguageNotVisibleInCSharp = (): EnumWithVariantsPerLanguage => ({ kind: "NotVisibleInCSharp" });
+
+export function matchEnumWithVariantsPerLanguage<R>(value: EnumWithVariantsPerLanguage, cases: {
+    NotVisibleInSwift: (v: Extract<EnumWithVariantsPerLanguage, { kind: "NotVisibleInSwift" }>) => R;
+    NotVisibleInKotlin: (v: Extract<EnumWithVariantsPerLanguage, { kind: "NotVisibleInKotlin" }>) => R;
+    NotVisibleInCSharp: (v: Extract<EnumWithVariantsPerLanguage, { kind: "NotVisibleInCSharp" }>) => R;
+}): R {
+    return cases[value.kind as EnumWithVariantsPerLanguage["kind"]](value as never);
 }
 
-export interface NotVisibleInKotlin {
-    inner: number;
+export class NotVisibleInCSharp {
+    constructor (public inner: uint32) {
+    }
 }
 
-export enum EnumWithVariantsPerLanguage {
-    NotVisibleInSwift = "NotVisibleInSwift",
-    NotVisibleInKotlin = "NotVisibleInKotlin",
+export class NotVisibleInKotlin {
+    constructor (public inner: uint32) {
+    }
 }
 
+export class NotVisibleInSwift {
+    constructor (public inner: uint32) {
+    }
+}
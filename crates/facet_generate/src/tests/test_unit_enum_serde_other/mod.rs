@@ -10,3 +10,5 @@ pub enum Source {
     #[facet(other)]
     Unknown,
 }
+
+crate::test! { Source for kotlin, swift, typescript, csharp with JsonPlugin }
@@ -0,0 +1,36 @@
+using CommunityToolkit.Mvvm.ComponentModel;
+using Facet.Runtime.Serde;
+using System.Collections.Generic;
+using System.Collections.ObjectModel;
+using Facet.Runtime.Json;
+using System.Text.Json.Serialization;
+
+namespace Example;
+
+/// This is a comment.
+[JsonConverter(typeof(SourceJsonConverter))]
+public enum Source {
+    Embedded,
+    GoogleFont,
+    Custom,
+    Unknown
+}
+
+/// <summary>
+/// JSON converter for <see cref="Source"/> that reads unknown variants as <see cref="Source.Unknown"/>.
+/// </summary>
+public sealed class SourceJsonConverter : JsonConverter<Source> {
+    public override Source Read(ref System.Text.Json.Utf8JsonReader reader, System.Type typeToConvert, System.Text.Json.JsonSerializerOptions options) {
+        return reader.GetString() switch {
+            "Embedded" => Source.Embedded,
+            "GoogleFont" => Source.GoogleFont,
+            "Custom" => Source.Custom,
+            "Unknown" => Source.Unknown,
+            _ => Source.Unknown,
+        };
+    }
+
+    public override void Write(System.Text.Json.Utf8JsonWriter writer, Source value, System.Text.Json.JsonSerializerOptions options) {
+        writer.WriteStringValue(value.ToString());
+    }
+}
@@ -1,14 +1,11 @@
 package com.example
 
-import kotlinx.serialization.*
-import kotlinx.serialization.builtins.*
-import kotlinx.serialization.descriptors.*
-import kotlinx.serialization.encoding.*
-import kotlinx.serialization.json.*
-import kotlinx.serialization.modules.*
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
 
 /// This is a comment.
 @Serializable(with = Source.Serializer::class)
+@SerialName("Source")
 enum class Source {
     @SerialName("Embedded") EMBEDDED,
     @SerialName("GoogleFont") GOOGLE_FONT,
@@ -18,18 +15,17 @@ enum class Source {
     val serialName: String
         get() = javaClass.getDeclaredField(name).getAnnotation(SerialName::class.java)!!.value
 
-    object Serializer : KSerializer<Source> {
-        override val descriptor: SerialDescriptor =
-                PrimitiveSerialDescriptor("Source", PrimitiveKind.STRING)
+    object Serializer : kotlinx.serialization.KSerializer<Source> {
+        override val descriptor: kotlinx.serialization.descriptors.SerialDescriptor =
+            kotlinx.serialization.descriptors.PrimitiveSerialDescriptor("Source", kotlinx.serialization.descriptors.PrimitiveKind.STRING)
 
-        override fun serialize(encoder: Encoder, value: Source) {
+        override fun serialize(encoder: kotlinx.serialization.encoding.Encoder, value: Source) {
             encoder.encodeString(value.serialName)
         }
 
-        override fun deserialize(decoder: Decoder): Source {
-            return decoder.decodeString().let { value ->
-                Source.entries.firstOrNull { it.serialName == value } ?: UNKNOWN
-            }
+        override fun deserialize(decoder: kotlinx.serialization.encoding.Decoder): Source {
+            val value = decoder.decodeString()
+            return Source.entries.firstOrNull { it.serialName == value } ?: Source.UNKNOWN
         }
     }
 }
@@ -1,13 +1,61 @@
-import Foundation
+import Serde
 
 /// This is a comment.
-enum Source: String, Codable {
-    case embedded = "Embedded"
-    case googleFont = "GoogleFont"
-    case custom = "Custom"
-    case unknown = "Unknown"
-
-    init(from decoder: Decoder) throws {
-        self = try Source(rawValue: decoder.singleValueContainer().decode(RawValue.self)) ?? .unknown
+indirect public enum Source: Hashable, Equatable {
+    case embedded
+    case googleFont
+    case custom
+    case unknown
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        switch self {
+        case .embedded:
+            try serializer.serialize_variant_index(value: 0)
+        case .googleFont:
+            try serializer.serialize_variant_index(value: 1)
+        case .custom:
+            try serializer.serialize_variant_index(value: 2)
+        case .unknown:
+            try serializer.serialize_variant_index(value: 3)
+        }
+        try serializer.decrease_container_depth()
+    }
+
+    public func jsonSerialize() throws -> [UInt8] {
+        let serializer = JsonSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> Source {
+        let index = try deserializer.deserialize_variant_index()
+        try deserializer.increase_container_depth()
+        switch index {
+        case 0:
+            try deserializer.decrease_container_depth()
+            return .embedded
+        case 1:
+            try deserializer.decrease_container_depth()
+            return .googleFont
+        case 2:
+            try deserializer.decrease_container_depth()
+            return .custom
+        case 3:
+            try deserializer.decrease_container_depth()
+            return .unknown
+        default:
+            try deserializer.decrease_container_depth()
+            return .unknown
+        }
+    }
+
+    public static func jsonDeserialize(input: [UInt8]) throws -> Source {
+        let deserializer = JsonDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
     }
 }
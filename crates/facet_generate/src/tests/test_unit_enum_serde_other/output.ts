@@ -0,0 +1,68 @@
+import { Serializer, Deserializer } from "./serde";
+
+/// This is a comment.
+export type Source =
+    | { kind: "Embedded" }
+    | { kind: "GoogleFont" }
+    | { kind: "Custom" }
+    | { kind: "Unknown" };
+
+export const sourceEmbedded = (): Source => ({ kind: "Embedded" });
+
+export const sourceGoogleFont = (): Source => ({ kind: "GoogleFont" });
+
+export const sourceCustom = (): Source => ({ kind: "Custom" });
+
+export const sourceUnknown = (): Source => ({ kind: "Unknown" });
+
+export function matchSource<R>(value: Source, cases: {
+    Embedded: (v: Extract<Source, { kind: "Embedded" }>) => R;
+    GoogleFont: (v: Extract<Source, { kind: "GoogleFont" }>) => R;
+    Custom: (v: Extract<Source, { kind: "Custom" }>) => R;
+    Unknown: (v: Extract<Source, { kind: "Unknown" }>) => R;
+}): R {
+    return cases[value.kind as Source["kind"]](value as never);
+}
+
+export function serializeSource(value: Source, serializer: Serializer): void {
+    switch (value.kind) {
+        case "Embedded": {
+            serializer.serializeVariantIndex(0);
+            break;
+        }
+        case "GoogleFont": {
+            serializer.serializeVariantIndex(1);
+            break;
+        }
+        case "Custom": {
+            serializer.serializeVariantIndex(2);
+            break;
+        }
+        case "Unknown": {
+            serializer.serializeVariantIndex(3);
+            break;
+        }
+        default: throw new Error("Unknown variant: " + (value as any).kind);
+    }
+}
+
+export function deserializeSource(deserializer: Deserializer): Source {
+    const index = deserializer.deserializeVariantIndex();
+    switch (index) {
+        case 0: {
+            return { kind: "Embedded" };
+        }
+        case 1: {
+            return { kind: "GoogleFont" };
+        }
+        case 2: {
+            return { kind: "Custom" };
+        }
+        case 3: {
+            return { kind: "Unknown" };
+        }
+        default: {
+            return { kind: "Unknown" };
+        }
+    }
+}
@@ -19,3 +19,59 @@ pub enum Baz {
     Bar,
     Foo,
 }
+
+#[derive(Facet)]
+pub struct Internal {
+    #[facet(fg::public)]
+    exposed: Foo,
+    hidden: Baz,
+}
+
+/// Generated with `Visibility::Internal` as the default, so that only the types and fields
+/// marked `fg::public` are public.
+mod tests {
+    use super::*;
+    use crate::tests::check;
+
+    use anyhow::Result;
+    use expect_test::expect_file;
+
+    use crate::{
+        generation::{CodeGeneratorConfig, csharp, kotlin, swift},
+        reflection::{RegistryBuilder, format::Visibility},
+    };
+
+    fn registry() -> crate::Registry {
+        RegistryBuilder::new()
+            .add_type::<Internal>()
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    fn config(package_name: &str) -> CodeGeneratorConfig {
+        CodeGeneratorConfig::new(package_name.to_string())
+            .with_default_visibility(Visibility::Internal)
+    }
+
+    #[test]
+    fn kotlin() -> Result<()> {
+        let cfg = config("com.example");
+        let generator = kotlin::KotlinCodeGenerator::new(&cfg);
+        check(&registry(), generator, &expect_file!("output.kt"))
+    }
+
+    #[test]
+    fn swift() -> Result<()> {
+        let cfg = config("ExamplePackage");
+        let generator = swift::SwiftCodeGenerator::new(&cfg);
+        check(&registry(), generator, &expect_file!("output.swift"))
+    }
+
+    #[test]
+    fn csharp() -> Result<()> {
+        let cfg = config("Example");
+        let generator = csharp::CSharpCodeGenerator::new(&cfg);
+        check(&registry(), generator, &expect_file!("output.cs"))
+    }
+}
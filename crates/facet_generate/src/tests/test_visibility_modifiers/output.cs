@@ -0,0 +1,28 @@
+using CommunityToolkit.Mvvm.ComponentModel;
+using Facet.Runtime.Serde;
+using System.Collections.Generic;
+using System.Collections.ObjectModel;
+
+namespace Example;
+
+public partial class Bar : ObservableObject {
+    [ObservableProperty]
+    private string _value;
+}
+
+public enum Baz {
+    Bar,
+    Foo
+}
+
+public partial class Foo : ObservableObject {
+    [ObservableProperty]
+    private Bar _bar;
+}
+
+internal partial class Internal : ObservableObject {
+    [ObservableProperty]
+    private Foo _exposed;
+    [ObservableProperty]
+    private Baz _hidden;
+}
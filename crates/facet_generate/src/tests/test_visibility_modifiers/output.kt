@@ -0,0 +1,19 @@
+package com.example
+
+data class Bar(
+    val value: String,
+)
+
+enum class Baz {
+    BAR,
+    FOO;
+}
+
+data class Foo(
+    val bar: com.example.Bar,
+)
+
+internal data class Internal(
+    val exposed: com.example.Foo,
+    val hidden: com.example.Baz,
+)
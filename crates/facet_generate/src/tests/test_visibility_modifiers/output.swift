@@ -1,8 +1,18 @@
-import Foundation
 
-public typealias Bar = String
+public struct Bar {
+    public var value: String
 
-public struct Foo: Codable {
+    public init(value: String) {
+        self.value = value
+    }
+}
+
+indirect public enum Baz {
+    case bar
+    case foo
+}
+
+public struct Foo {
     public var bar: Bar
 
     public init(bar: Bar) {
@@ -10,7 +20,12 @@ public struct Foo: Codable {
     }
 }
 
-public enum Baz: String, Codable {
-    case bar = "Bar"
-    case foo = "Foo"
+struct Internal {
+    public var exposed: Foo
+    var hidden: Baz
+
+    public init(exposed: Foo, hidden: Baz) {
+        self.exposed = exposed
+        self.hidden = hidden
+    }
 }
@@ -1,3 +1,5 @@
+use crate as fg;
+
 use facet::Facet;
 
 #[derive(Facet)]
@@ -7,29 +9,33 @@ pub enum BestHockeyTeams {
 }
 
 #[derive(Facet)]
-// TODO: #[facet(swift = "Equatable")]
+#[facet(fg::conforms(lang = "swift", to = "Equatable"))]
 #[repr(C)]
 pub enum BestHockeyTeams1 {
     PittsburghPenguins,
 }
 
 #[derive(Facet)]
-// TODO: #[facet(swift = "Equatable, Comparable, Hashable")]
+#[facet(fg::conforms(lang = "swift", to = "Equatable, Comparable, Hashable"))]
 #[repr(C)]
 pub enum BestHockeyTeams2 {
     PittsburghPenguins,
 }
 
 #[derive(Facet)]
-// TODO: #[facet(kotlin = "idk")]
+#[facet(fg::conforms(lang = "kotlin", to = "Idk"))]
 #[repr(C)]
 pub enum BestHockeyTeams3 {
     PittsburghPenguins,
 }
 
 #[derive(Facet)]
-// TODO: #[facet(swift = "Equatable", swift = "Hashable")]
+#[facet(fg::conforms(lang = "swift", to = "Equatable"))]
+#[facet(fg::conforms(lang = "swift", to = "Hashable"))]
+#[expect(clippy::duplicated_attributes)]
 #[repr(C)]
 pub enum BestHockeyTeams4 {
     PittsburghPenguins,
 }
+
+crate::test! { BestHockeyTeams, BestHockeyTeams1, BestHockeyTeams2, BestHockeyTeams3, BestHockeyTeams4 for kotlin, swift }
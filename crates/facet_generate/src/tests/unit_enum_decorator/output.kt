@@ -0,0 +1,21 @@
+package com.example
+
+enum class BestHockeyTeams {
+    PITTSBURGH_PENGUINS;
+}
+
+enum class BestHockeyTeams1 {
+    PITTSBURGH_PENGUINS;
+}
+
+enum class BestHockeyTeams2 {
+    PITTSBURGH_PENGUINS;
+}
+
+enum class BestHockeyTeams3 : Idk {
+    PITTSBURGH_PENGUINS;
+}
+
+enum class BestHockeyTeams4 {
+    PITTSBURGH_PENGUINS;
+}
@@ -1,21 +1,20 @@
-import Foundation
 
-enum BestHockeyTeams: String, Codable {
-    case pittsburghPenguins = "PittsburghPenguins"
+indirect public enum BestHockeyTeams {
+    case pittsburghPenguins
 }
 
-enum BestHockeyTeams1: String, Codable, Equatable {
-    case pittsburghPenguins = "PittsburghPenguins"
+indirect public enum BestHockeyTeams1: Equatable {
+    case pittsburghPenguins
 }
 
-enum BestHockeyTeams2: String, Codable, Comparable, Equatable, Hashable {
-    case pittsburghPenguins = "PittsburghPenguins"
+indirect public enum BestHockeyTeams2: Equatable, Comparable, Hashable {
+    case pittsburghPenguins
 }
 
-enum BestHockeyTeams3: String, Codable {
-    case pittsburghPenguins = "PittsburghPenguins"
+indirect public enum BestHockeyTeams3 {
+    case pittsburghPenguins
 }
 
-enum BestHockeyTeams4: String, Codable, Equatable, Hashable {
-    case pittsburghPenguins = "PittsburghPenguins"
+indirect public enum BestHockeyTeams4: Equatable, Hashable {
+    case pittsburghPenguins
 }
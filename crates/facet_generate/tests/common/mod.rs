@@ -615,3 +615,82 @@ pub fn get_swift_positive_samples() -> Vec<Vec<u8>> {
         .map(|v| bincode::serialize(v).unwrap())
         .collect()
 }
+
+// ---------------------------------------------------------------------------
+// Tagged enum JSON round-trip fixtures
+// ---------------------------------------------------------------------------
+
+/// The payload of [`Shape::Circle`], which Swift encodes with its `Codable` conformance.
+#[derive(Facet, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[facet(fg::conforms(lang = "swift", to = "Codable"))]
+pub struct Disc {
+    pub radius: u32,
+}
+
+/// An internally tagged enum.
+#[derive(Facet, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[facet(tag = "type")]
+#[serde(tag = "type")]
+#[repr(C)]
+pub enum Shape {
+    Circle(Disc),
+    Rectangle { width: u32, height: u32 },
+    Empty,
+}
+
+/// An adjacently tagged enum.
+#[derive(Facet, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[facet(tag = "t", content = "c")]
+#[serde(tag = "t", content = "c")]
+#[repr(C)]
+pub enum Message {
+    Text(String),
+    Move(i32, i32),
+    Resize { width: u32, height: u32 },
+    Quit,
+}
+
+/// Returns a registry containing [`Shape`] and [`Message`].
+pub fn get_tagged_registry() -> Registry {
+    reflect!(Shape, Message).unwrap()
+}
+
+/// Serialize one value of each variant of [`Shape`] with `serde_json`.
+pub fn get_shape_reference_json() -> Vec<String> {
+    [
+        Shape::Circle(Disc { radius: 3 }),
+        Shape::Rectangle {
+            width: 4,
+            height: 5,
+        },
+        Shape::Empty,
+    ]
+    .iter()
+    .map(|value| serde_json::to_string(value).unwrap())
+    .collect()
+}
+
+/// Serialize one value of each variant of [`Message`] with `serde_json`.
+pub fn get_message_reference_json() -> Vec<String> {
+    [
+        Message::Text("hello".to_string()),
+        Message::Move(-1, 2),
+        Message::Resize {
+            width: 4,
+            height: 5,
+        },
+        Message::Quit,
+    ]
+    .iter()
+    .map(|value| serde_json::to_string(value).unwrap())
+    .collect()
+}
+
+/// Asserts that the lines of `output` are the same JSON documents as `reference`, in
+/// the same order, whatever the order of the keys in their objects.
+pub fn assert_same_json(reference: &[String], output: &str) {
+    let parse = |document: &str| serde_json::from_str::<serde_json::Value>(document).unwrap();
+    let expected: Vec<_> = reference.iter().map(|document| parse(document)).collect();
+    let actual: Vec<_> = output.lines().map(parse).collect();
+    assert_eq!(actual, expected);
+}
@@ -88,7 +88,7 @@ fn test_get_registry() {
             D:
               - UNIT
               - []
-          4:
+          10:
             E:
               - UNIT
               - []
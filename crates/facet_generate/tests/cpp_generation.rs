@@ -0,0 +1,92 @@
+//! Compilation test for C++ code generation.
+//!
+//! This integration test verifies that the C++ code we generate is
+//! **syntactically and type-correct** by compiling a translation unit that
+//! includes every generated header with `g++`. It does *not* run the generated
+//! code — see `cpp_runtime.rs` for end-to-end round-trip tests.
+//!
+//! # How it works
+//!
+//! 1. Reflects [`SerdeData`](common::SerdeData) into a [`Registry`](facet_generate::Registry).
+//! 2. Runs the full [`Installer`](facet_generate::generation::cpp::Installer)
+//!    pipeline with the bincode plugin into a temporary directory, producing
+//!    the library's headers, the runtime and a `CMakeLists.txt` manifest.
+//! 3. Invokes `g++ -std=c++17 -fsyntax-only` over a `main.cpp` that includes
+//!    every header, and asserts a zero exit code.
+//!
+//! The test is gated on `#[cfg(feature = "cpp")]`, and fails when `g++` is not
+//! on `PATH`.
+
+#![cfg(feature = "cpp")]
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use facet_generate::generation::{bincode::BincodePlugin, cpp};
+use tempfile::tempdir;
+
+pub mod common;
+
+/// Collects the `.hpp` files under `dir`, recursively.
+fn collect_headers(dir: &Path) -> Vec<PathBuf> {
+    let mut files = vec![];
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            files.extend(collect_headers(&path));
+        } else if path.extension().is_some_and(|extension| extension == "hpp") {
+            files.push(path);
+        }
+    }
+    files
+}
+
+#[test]
+fn test_that_cpp_code_compiles() {
+    let registry = common::get_registry();
+    let dir = tempdir().unwrap();
+
+    cpp::Installer::new("example", dir.path())
+        .plugin(BincodePlugin)
+        .generate(&registry)
+        .unwrap();
+    assert!(dir.path().join("CMakeLists.txt").exists());
+
+    let include = dir.path().join("include");
+    let mut headers = collect_headers(&include);
+    assert!(!headers.is_empty(), "no headers were generated");
+    headers.sort();
+
+    let includes: String = headers
+        .iter()
+        .map(|header| {
+            let header = header.strip_prefix(&include).unwrap().display();
+            format!("#include \"{header}\"\n")
+        })
+        .collect();
+    let main = dir.path().join("main.cpp");
+    fs::write(&main, format!("{includes}\nint main() {{ return 0; }}\n")).unwrap();
+
+    let output = Command::new("g++")
+        .args([
+            "-std=c++17",
+            "-fsyntax-only",
+            "-Wall",
+            "-Wextra",
+            "-Werror",
+            "-pedantic",
+            "-I",
+        ])
+        .arg(&include)
+        .arg(&main)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "g++ failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,178 @@
+#![cfg(feature = "cpp")]
+//! Runtime tests for C++ bincode serialization.
+//!
+//! These tests generate a C++ library, serialize data in Rust with bincode,
+//! then run the generated C++ code to deserialize and re-serialize it —
+//! checking that the bytes round-trip correctly.
+//!
+//! # Toolchain requirement
+//!
+//! `g++` (with C++17 support) must be on `PATH`, or the tests fail; build
+//! without the `cpp` feature to leave them out. Each test writes a `main.cpp` next to the generated `include`
+//! directory and compiles it directly, without CMake.
+
+use std::{fs, path::Path, process::Command};
+
+use facet_generate::generation::{bincode::BincodePlugin, cpp};
+use tempfile::tempdir;
+
+pub mod common;
+
+/// The root namespace of the generated library.
+const NAMESPACE: &str = "example";
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Format a `&[u8]` as a C++ braced list of bytes.
+fn quote_bytes_cpp(bytes: &[u8]) -> String {
+    let bytes: Vec<String> = bytes.iter().map(|byte| format!("0x{byte:02x}")).collect();
+    format!("std::vector<std::uint8_t>{{{}}}", bytes.join(", "))
+}
+
+/// Format the bytes of a UUID as the initializer of a `serde::uuid`.
+fn uuid_bytes_cpp(uuid: &uuid::Uuid) -> String {
+    let bytes: Vec<String> = uuid
+        .as_bytes()
+        .iter()
+        .map(|byte| format!("0x{byte:02x}"))
+        .collect();
+    bytes.join(", ")
+}
+
+/// Writes `main.cpp` next to the headers in `dir`, compiles it and runs it.
+fn run_main(dir: &Path, main: &str) {
+    let source = dir.join("main.cpp");
+    fs::write(&source, main).unwrap();
+
+    let binary = dir.join("main");
+    let output = Command::new("g++")
+        .args(["-std=c++17", "-I"])
+        .arg(dir.join("include"))
+        .arg(&source)
+        .arg("-o")
+        .arg(&binary)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "g++ failed:\nstdout: {}\nstderr: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr),
+    );
+
+    let output = Command::new(&binary).output().unwrap();
+    assert!(
+        output.status.success(),
+        "C++ round-trip test failed:\nstdout: {}\nstderr: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr),
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_cpp_bincode_runtime_on_simple_data() {
+    let registry = common::get_registry();
+    let dir = tempdir().unwrap();
+
+    cpp::Installer::new(NAMESPACE, dir.path())
+        .plugin(BincodePlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let samples = common::get_positive_samples()
+        .iter()
+        .map(|sample| quote_bytes_cpp(sample))
+        .collect::<Vec<_>>();
+
+    let main = format!(
+        r#"#include <cstdio>
+#include <cstdlib>
+
+#include "{NAMESPACE}.hpp"
+
+static void print_hex(const char *label, const std::vector<std::uint8_t> &bytes) {{
+    std::fprintf(stderr, "  %s = ", label);
+    for (std::uint8_t byte : bytes) {{
+        std::fprintf(stderr, "%02x", byte);
+    }}
+    std::fprintf(stderr, "\n");
+}}
+
+int main() {{
+    std::vector<std::vector<std::uint8_t>> samples = {{
+        {samples},
+    }};
+    for (const auto &sample : samples) {{
+        auto value = {NAMESPACE}::SerdeData::bincodeDeserialize(sample);
+        auto output = value.bincodeSerialize();
+        if (output != sample) {{
+            std::fprintf(stderr, "roundtrip failed:\n");
+            print_hex("input ", sample);
+            print_hex("output", output);
+            return EXIT_FAILURE;
+        }}
+        if ({NAMESPACE}::SerdeData::bincodeDeserialize(output) != value) {{
+            std::fprintf(stderr, "values differ after a roundtrip\n");
+            return EXIT_FAILURE;
+        }}
+    }}
+    std::printf("bincode roundtrip: PASSED\n");
+    return EXIT_SUCCESS;
+}}
+"#,
+        samples = samples.join(",\n        "),
+    );
+    run_main(dir.path(), &main);
+}
+
+#[test]
+fn test_cpp_bincode_runtime_on_uuid_data() {
+    let registry = common::get_uuid_registry();
+    let dir = tempdir().unwrap();
+
+    cpp::Installer::new(NAMESPACE, dir.path())
+        .plugin(BincodePlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let reference = common::get_uuid_reference_bytes();
+
+    let main = format!(
+        r#"#include <cstdio>
+#include <cstdlib>
+
+#include "{NAMESPACE}.hpp"
+
+int main() {{
+    std::vector<std::uint8_t> data = {bytes};
+    auto value = {NAMESPACE}::UuidData::bincodeDeserialize(data);
+    serde::uuid id{{{{{id}}}}};
+    serde::uuid parent_id{{{{{parent_id}}}}};
+    if (value.id != id) {{
+        std::fprintf(stderr, "id mismatch\n");
+        return EXIT_FAILURE;
+    }}
+    if (value.parent_id != std::optional<serde::uuid>(parent_id)) {{
+        std::fprintf(stderr, "parent_id mismatch\n");
+        return EXIT_FAILURE;
+    }}
+    if (value.bincodeSerialize() != data) {{
+        std::fprintf(stderr, "roundtrip failed\n");
+        return EXIT_FAILURE;
+    }}
+    std::printf("UUID roundtrip: PASSED\n");
+    return EXIT_SUCCESS;
+}}
+"#,
+        bytes = quote_bytes_cpp(&reference),
+        id = uuid_bytes_cpp(&common::UUID_ID),
+        parent_id = uuid_bytes_cpp(&common::UUID_PARENT_ID),
+    );
+    run_main(dir.path(), &main);
+}
@@ -3,13 +3,14 @@
 //!
 //! These tests generate C# code, serialize data in Rust with bincode, then
 //! run the generated C# code to deserialize, verify, and re-serialize —
-//! checking that the bytes roundtrip correctly.
+//! checking that the bytes roundtrip correctly. The JSON tests do the same
+//! with `serde_json` and the generated converters of tagged enums.
 
 use std::{fs, io::Write as _, process::Command};
 
 use facet::Facet;
 use facet_generate::{
-    generation::{bincode::BincodePlugin, csharp},
+    generation::{bincode::BincodePlugin, csharp, json::JsonPlugin},
     reflect,
 };
 use serde::Serialize;
@@ -47,6 +48,15 @@ fn dotnet_run(dir: &std::path::Path) {
     );
 }
 
+/// Format JSON documents as a C# array of verbatim string literals.
+fn quote_json(documents: &[String]) -> String {
+    let literals: Vec<String> = documents
+        .iter()
+        .map(|document| format!("@\"{}\"", document.replace('"', "\"\"")))
+        .collect();
+    format!("new string[] {{ {} }}", literals.join(", "))
+}
+
 fn quote_bytes(bytes: &[u8]) -> String {
     format!(
         "new byte[] {{ {} }}",
@@ -336,3 +346,65 @@ Console.WriteLine($"Supported types roundtrip + mutation: {{passed}}/{{positiveI
 
     dotnet_run(&dir);
 }
+
+#[test]
+fn test_csharp_json_runtime_on_tagged_enums() {
+    let registry = common::get_tagged_registry();
+    let dir = tempdir().unwrap();
+    let dir = dir.path().to_path_buf().join("testing");
+
+    csharp::Installer::new("Example.Testing", &dir)
+        .plugin(JsonPlugin)
+        .generate(&registry)
+        .unwrap();
+
+    make_executable(&dir, "Example.Testing");
+
+    let program_path = dir.join("Program.cs");
+    let mut program = fs::File::create(program_path).unwrap();
+    writeln!(
+        program,
+        r#"using System;
+using Example.Testing;
+using Facet.Runtime.Json;
+
+static void Assert(bool condition, string message)
+{{
+    if (!condition) throw new Exception("Assertion failed: " + message);
+}}
+
+var shapes = {shapes};
+foreach (var input in shapes)
+{{
+    var output = JsonSerde.Serialize(JsonSerde.Deserialize<Shape>(input));
+    Assert(input == output, $"Shape roundtrip failed: {{input}} != {{output}}");
+}}
+
+foreach (var input in {messages})
+{{
+    var output = JsonSerde.Serialize(JsonSerde.Deserialize<Message>(input));
+    Assert(input == output, $"Message roundtrip failed: {{input}} != {{output}}");
+}}
+
+var rectangle = (Shape.Rectangle)JsonSerde.Deserialize<Shape>(shapes[1]);
+Assert(rectangle.Width == 4 && rectangle.Height == 5, "Rectangle should be 4 x 5");
+
+try
+{{
+    JsonSerde.Deserialize<Shape>(@"{{""type"":""Triangle""}}");
+    Assert(false, "Should have thrown on an unknown variant");
+}}
+catch (System.Text.Json.JsonException)
+{{
+    // expected
+}}
+
+Console.WriteLine("Tagged enums roundtrip: PASSED");
+"#,
+        shapes = quote_json(&common::get_shape_reference_json()),
+        messages = quote_json(&common::get_message_reference_json()),
+    )
+    .unwrap();
+
+    dotnet_run(&dir);
+}
@@ -0,0 +1,54 @@
+//! Compilation test for Dart code generation.
+//!
+//! This integration test verifies that the Dart code we generate is
+//! **syntactically and type-correct** by running `dart analyze` over it. It
+//! does *not* run the generated code — see `dart_runtime.rs` for end-to-end
+//! round-trip tests.
+//!
+//! # How it works
+//!
+//! 1. Reflects [`SerdeData`](common::SerdeData) into a [`Registry`](facet_generate::Registry).
+//! 2. Runs the full [`Installer`](facet_generate::generation::dart::Installer)
+//!    pipeline with the bincode and JSON plugins into a temporary directory,
+//!    producing the `lib/` libraries and a `pubspec.yaml` manifest.
+//! 3. Invokes `dart pub get` to resolve the (dependency-free) package.
+//! 4. Invokes `dart analyze` and asserts a zero exit code.
+//!
+//! The test is gated on `#[cfg(feature = "dart")]`, and fails when `dart` is not
+//! on `PATH`.
+
+#![cfg(feature = "dart")]
+
+use std::process::Command;
+
+use facet_generate::generation::{bincode::BincodePlugin, dart, json::JsonPlugin};
+use tempfile::tempdir;
+
+pub mod common;
+
+#[test]
+fn test_that_dart_code_analyzes() {
+    let registry = common::get_registry();
+    let dir = tempdir().unwrap();
+    let dir = dir.path().to_path_buf().join("testing");
+
+    dart::Installer::new("testing", &dir)
+        .plugin(BincodePlugin)
+        .plugin(JsonPlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let status = Command::new("dart")
+        .args(["pub", "get", "--offline"])
+        .current_dir(&dir)
+        .status()
+        .unwrap();
+    assert!(status.success(), "dart pub get failed");
+
+    let status = Command::new("dart")
+        .args(["analyze", "--fatal-infos"])
+        .current_dir(&dir)
+        .status()
+        .unwrap();
+    assert!(status.success(), "dart analyze failed");
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "dart")]
+//! Runtime tests for Dart bincode serialization.
+//!
+//! These tests generate Dart code, serialize data in Rust with bincode, then
+//! run the generated Dart code to deserialize and re-serialize it — checking
+//! that the bytes round-trip correctly.
+//!
+//! # Toolchain requirement
+//!
+//! `dart` must be on `PATH`, or the tests fail; build without the `dart` feature
+//! to leave them out. The generated package gets a `bin/main.dart` entry-point,
+//! which is run with `dart run`.
+
+use std::{fs, io::Write as _, path::Path, process::Command};
+
+use facet_generate::generation::{bincode::BincodePlugin, dart};
+use tempfile::tempdir;
+
+pub mod common;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Format a `&[u8]` as a Dart list literal.
+fn quote_bytes_dart(bytes: &[u8]) -> String {
+    let elems: Vec<String> = bytes.iter().map(ToString::to_string).collect();
+    format!("[{}]", elems.join(", "))
+}
+
+/// Writes `bin/main.dart` into the package at `dir` and runs it.
+fn run_main(dir: &Path, main: &str) {
+    fs::create_dir_all(dir.join("bin")).unwrap();
+    let mut main_file = fs::File::create(dir.join("bin").join("main.dart")).unwrap();
+    main_file.write_all(main.as_bytes()).unwrap();
+
+    let status = Command::new("dart")
+        .args(["pub", "get", "--offline"])
+        .current_dir(dir)
+        .status()
+        .unwrap();
+    assert!(status.success(), "dart pub get failed");
+
+    let status = Command::new("dart")
+        .args(["run", "bin/main.dart"])
+        .current_dir(dir)
+        .status()
+        .unwrap();
+    assert!(status.success(), "Dart round-trip test failed");
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_dart_bincode_runtime_on_simple_data() {
+    let registry = common::get_registry();
+    let dir = tempdir().unwrap();
+    let dir = dir.path().to_path_buf().join("testing");
+
+    dart::Installer::new("testing", &dir)
+        .plugin(BincodePlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let samples = common::get_positive_samples()
+        .iter()
+        .map(|sample| quote_bytes_dart(sample))
+        .collect::<Vec<_>>();
+
+    let main = format!(
+        r"import 'dart:typed_data';
+
+import 'package:testing/testing.dart';
+
+void main() {{
+  final samples = <List<int>>[
+    {samples},
+  ];
+  for (final sample in samples) {{
+    final input = Uint8List.fromList(sample);
+    final value = SerdeData.bincodeDeserialize(input);
+    final output = value.bincodeSerialize();
+    if (output.length != input.length ||
+        !Iterable<int>.generate(input.length).every((i) => input[i] == output[i])) {{
+      throw StateError('roundtrip failed:\n  input  = $input\n  output = $output');
+    }}
+  }}
+  print('bincode roundtrip: PASSED');
+}}
+",
+        samples = samples.join(",\n    "),
+    );
+    run_main(&dir, &main);
+}
+
+#[test]
+fn test_dart_bincode_runtime_on_uuid_data() {
+    let registry = common::get_uuid_registry();
+    let dir = tempdir().unwrap();
+    let dir = dir.path().to_path_buf().join("testing");
+
+    dart::Installer::new("testing", &dir)
+        .plugin(BincodePlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let reference = common::get_uuid_reference_bytes();
+
+    let main = format!(
+        r"import 'dart:typed_data';
+
+import 'package:testing/testing.dart';
+
+void main() {{
+  final input = Uint8List.fromList({bytes});
+  final value = UuidData.bincodeDeserialize(input);
+  if (value.id != '{id}') {{
+    throw StateError('id mismatch: ${{value.id}}');
+  }}
+  if (value.parentId != '{parent_id}') {{
+    throw StateError('parentId mismatch: ${{value.parentId}}');
+  }}
+  final output = value.bincodeSerialize();
+  if (output.length != input.length ||
+      !Iterable<int>.generate(input.length).every((i) => input[i] == output[i])) {{
+    throw StateError('roundtrip failed:\n  input  = $input\n  output = $output');
+  }}
+  print('UUID roundtrip: PASSED');
+}}
+",
+        bytes = quote_bytes_dart(&reference),
+        id = common::UUID_ID,
+        parent_id = common::UUID_PARENT_ID,
+    );
+    run_main(&dir, &main);
+}
@@ -0,0 +1,45 @@
+//! Compilation test for Go code generation.
+//!
+//! This integration test verifies that the Go code we generate is
+//! **syntactically and type-correct** by running `go vet` over the module,
+//! which compiles every package. It does *not* run the generated code — see
+//! `go_runtime.rs` for end-to-end round-trip tests.
+//!
+//! # How it works
+//!
+//! 1. Reflects [`SerdeData`](common::SerdeData) into a [`Registry`](facet_generate::Registry).
+//! 2. Runs the full [`Installer`](facet_generate::generation::go::Installer)
+//!    pipeline with the bincode and JSON plugins into a temporary directory,
+//!    producing the module's packages and a `go.mod` manifest.
+//! 3. Invokes `go vet ./...` and asserts a zero exit code.
+//!
+//! The test is gated on `#[cfg(feature = "go")]`, and fails when `go` is not on
+//! `PATH`.
+
+#![cfg(feature = "go")]
+
+use std::process::Command;
+
+use facet_generate::generation::{bincode::BincodePlugin, go, json::JsonPlugin};
+use tempfile::tempdir;
+
+pub mod common;
+
+#[test]
+fn test_that_go_code_vets() {
+    let registry = common::get_registry();
+    let dir = tempdir().unwrap();
+
+    go::Installer::new("example.com/testing", dir.path())
+        .plugin(BincodePlugin)
+        .plugin(JsonPlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let status = Command::new("go")
+        .args(["vet", "./..."])
+        .current_dir(dir.path())
+        .status()
+        .unwrap();
+    assert!(status.success(), "go vet failed");
+}
@@ -0,0 +1,237 @@
+#![cfg(feature = "go")]
+//! Runtime tests for Go bincode and JSON serialization.
+//!
+//! These tests generate a Go module, serialize data in Rust with bincode,
+//! then run the generated Go code to deserialize and re-serialize it —
+//! checking that the bytes round-trip correctly. The JSON test does the same
+//! with `serde_json` and the generated functions of tagged enums.
+//!
+//! # Toolchain requirement
+//!
+//! `go` (1.21 or later) must be on `PATH`, or the tests fail; build without the
+//! `go` feature to leave them out.
+//! Each test writes a `main` package into the generated module, which needs
+//! no dependencies.
+
+use std::{fs, path::Path, process::Command};
+
+use facet_generate::generation::{bincode::BincodePlugin, go, json::JsonPlugin};
+use tempfile::tempdir;
+
+pub mod common;
+
+/// The path of the generated module, whose root package is `testing`.
+const MODULE_PATH: &str = "example.com/testing";
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Format a `&[u8]` as a Go byte slice of a string literal.
+fn quote_bytes_go(bytes: &[u8]) -> String {
+    let escaped: String = bytes.iter().map(|byte| format!("\\x{byte:02x}")).collect();
+    format!("[]byte(\"{escaped}\")")
+}
+
+/// Format JSON documents as a Go slice of raw string literals.
+fn quote_json_go(documents: &[String]) -> String {
+    let literals: Vec<String> = documents
+        .iter()
+        .map(|document| format!("`{document}`"))
+        .collect();
+    format!("[]string{{{}}}", literals.join(", "))
+}
+
+/// Writes `cmd/main.go` into the module in `dir` and runs it.
+fn run_main(dir: &Path, main: &str) {
+    fs::create_dir_all(dir.join("cmd")).unwrap();
+    fs::write(dir.join("cmd/main.go"), main).unwrap();
+
+    let output = Command::new("go")
+        .args(["run", "./cmd"])
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "Go round-trip test failed:\nstdout: {}\nstderr: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr),
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_go_bincode_runtime_on_simple_data() {
+    let registry = common::get_registry();
+    let dir = tempdir().unwrap();
+
+    go::Installer::new(MODULE_PATH, dir.path())
+        .plugin(BincodePlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let samples = common::get_positive_samples()
+        .iter()
+        .map(|sample| quote_bytes_go(sample))
+        .collect::<Vec<_>>();
+
+    let main = format!(
+        r#"package main
+
+import (
+	"bytes"
+	"fmt"
+
+	testing "{MODULE_PATH}"
+)
+
+func main() {{
+	samples := [][]byte{{
+		{samples},
+	}}
+	for _, sample := range samples {{
+		value, err := testing.BincodeDeserializeSerdeData(sample)
+		if err != nil {{
+			panic(err)
+		}}
+		output, err := value.BincodeSerialize()
+		if err != nil {{
+			panic(err)
+		}}
+		if !bytes.Equal(output, sample) {{
+			panic(fmt.Sprintf("roundtrip failed:\n  input  = %x\n  output = %x", sample, output))
+		}}
+	}}
+	fmt.Println("bincode roundtrip: PASSED")
+}}
+"#,
+        samples = samples.join(",\n\t\t"),
+    );
+    run_main(dir.path(), &main);
+}
+
+#[test]
+fn test_go_bincode_runtime_on_uuid_data() {
+    let registry = common::get_uuid_registry();
+    let dir = tempdir().unwrap();
+
+    go::Installer::new(MODULE_PATH, dir.path())
+        .plugin(BincodePlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let reference = common::get_uuid_reference_bytes();
+
+    let main = format!(
+        r#"package main
+
+import (
+	"bytes"
+	"fmt"
+
+	testing "{MODULE_PATH}"
+)
+
+func main() {{
+	data := {bytes}
+	value, err := testing.BincodeDeserializeUuidData(data)
+	if err != nil {{
+		panic(err)
+	}}
+	if value.Id.String() != "{id}" {{
+		panic(fmt.Sprintf("id mismatch: %s", value.Id))
+	}}
+	if value.ParentId == nil || value.ParentId.String() != "{parent_id}" {{
+		panic(fmt.Sprintf("parent_id mismatch: %v", value.ParentId))
+	}}
+	output, err := value.BincodeSerialize()
+	if err != nil {{
+		panic(err)
+	}}
+	if !bytes.Equal(output, data) {{
+		panic(fmt.Sprintf("roundtrip failed:\n  input  = %x\n  output = %x", data, output))
+	}}
+	fmt.Println("UUID roundtrip: PASSED")
+}}
+"#,
+        bytes = quote_bytes_go(&reference),
+        id = common::UUID_ID,
+        parent_id = common::UUID_PARENT_ID,
+    );
+    run_main(dir.path(), &main);
+}
+
+#[test]
+fn test_go_json_runtime_on_tagged_enums() {
+    let registry = common::get_tagged_registry();
+    let dir = tempdir().unwrap();
+
+    go::Installer::new(MODULE_PATH, dir.path())
+        .plugin(JsonPlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let main = format!(
+        r#"package main
+
+import (
+	"encoding/json"
+	"fmt"
+
+	testing "{MODULE_PATH}"
+)
+
+func main() {{
+	shapes := {shapes}
+	for _, document := range shapes {{
+		value, err := testing.ShapeFromJSON([]byte(document))
+		if err != nil {{
+			panic(err)
+		}}
+		output, err := json.Marshal(value)
+		if err != nil {{
+			panic(err)
+		}}
+		if string(output) != document {{
+			panic(fmt.Sprintf("Shape roundtrip failed: %s != %s", document, output))
+		}}
+	}}
+
+	for _, document := range {messages} {{
+		value, err := testing.MessageFromJSON([]byte(document))
+		if err != nil {{
+			panic(err)
+		}}
+		output, err := json.Marshal(value)
+		if err != nil {{
+			panic(err)
+		}}
+		if string(output) != document {{
+			panic(fmt.Sprintf("Message roundtrip failed: %s != %s", document, output))
+		}}
+	}}
+
+	shape, err := testing.ShapeFromJSON([]byte(shapes[1]))
+	if err != nil {{
+		panic(err)
+	}}
+	if rectangle, ok := shape.(*testing.ShapeRectangle); !ok || rectangle.Width != 4 || rectangle.Height != 5 {{
+		panic(fmt.Sprintf("Rectangle should be 4 x 5: %#v", shape))
+	}}
+
+	if _, err := testing.ShapeFromJSON([]byte(`{{"type":"Triangle"}}`)); err == nil {{
+		panic("Should have failed on an unknown variant")
+	}}
+
+	fmt.Println("Tagged enums roundtrip: PASSED")
+}}
+"#,
+        shapes = quote_json_go(&common::get_shape_reference_json()),
+        messages = quote_json_go(&common::get_message_reference_json()),
+    );
+    run_main(dir.path(), &main);
+}
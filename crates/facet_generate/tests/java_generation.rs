@@ -0,0 +1,79 @@
+//! Compilation test for Java code generation.
+//!
+//! This integration test verifies that the Java code we generate is
+//! **syntactically and type-correct** by compiling the project's sources with
+//! `javac`. It does *not* run the generated code — see `java_runtime.rs` for
+//! end-to-end round-trip tests.
+//!
+//! # How it works
+//!
+//! 1. Reflects [`SerdeData`](common::SerdeData) into a [`Registry`](facet_generate::Registry).
+//! 2. Runs the full [`Installer`](facet_generate::generation::java::Installer)
+//!    pipeline with the bincode and JSON plugins into a temporary directory,
+//!    producing the project's sources, the runtime and a `pom.xml` manifest.
+//! 3. Invokes `javac --release 17` over every source and asserts a zero exit
+//!    code.
+//!
+//! The test is gated on `#[cfg(feature = "java")]`, and fails when `javac` is
+//! not on `PATH`.
+
+#![cfg(feature = "java")]
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use facet_generate::generation::{bincode::BincodePlugin, java, json::JsonPlugin};
+use tempfile::tempdir;
+
+pub mod common;
+
+/// Collects the `.java` files under `dir`, recursively.
+fn collect_java_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = vec![];
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            files.extend(collect_java_files(&path));
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension == "java")
+        {
+            files.push(path);
+        }
+    }
+    files
+}
+
+#[test]
+fn test_that_java_code_compiles() {
+    let registry = common::get_registry();
+    let dir = tempdir().unwrap();
+
+    java::Installer::new("com.example.testing", dir.path())
+        .plugin(BincodePlugin)
+        .plugin(JsonPlugin)
+        .generate(&registry)
+        .unwrap();
+    assert!(dir.path().join("pom.xml").exists());
+
+    let sources = collect_java_files(&dir.path().join("src/main/java"));
+    assert!(!sources.is_empty(), "no sources were generated");
+    let status = Command::new("javac")
+        .args([
+            "--release",
+            "17",
+            "-encoding",
+            "UTF-8",
+            "-Xlint:all",
+            "-Werror",
+            "-d",
+        ])
+        .arg(dir.path().join("classes"))
+        .args(&sources)
+        .status()
+        .unwrap();
+    assert!(status.success(), "javac failed");
+}
@@ -0,0 +1,241 @@
+#![cfg(feature = "java")]
+//! Runtime tests for Java bincode and JSON serialization.
+//!
+//! These tests generate a Java project, serialize data in Rust with bincode,
+//! then run the generated Java code to deserialize and re-serialize it —
+//! checking that the bytes round-trip correctly. The JSON test does the same
+//! with `serde_json` and the generated converters of tagged enums.
+//!
+//! # Toolchain requirement
+//!
+//! `javac` and `java` (17 or later) must be on `PATH`, or the tests fail;
+//! build without the `java` feature to leave them out. Each test writes a `Main` class next to the generated sources
+//! and compiles them together, without Maven.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use facet_generate::generation::{bincode::BincodePlugin, java, json::JsonPlugin};
+use tempfile::tempdir;
+
+pub mod common;
+
+/// The root package of the generated project.
+const PACKAGE: &str = "com.example.testing";
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Collects the `.java` files under `dir`, recursively.
+fn collect_java_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = vec![];
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            files.extend(collect_java_files(&path));
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension == "java")
+        {
+            files.push(path);
+        }
+    }
+    files
+}
+
+/// Format a `&[u8]` as a Java string literal of its hex digits, for
+/// `java.util.HexFormat`.
+fn quote_bytes_java(bytes: &[u8]) -> String {
+    let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+    format!("\"{hex}\"")
+}
+
+/// Format JSON documents as a Java array of string literals.
+fn quote_json_java(documents: &[String]) -> String {
+    let literals: Vec<String> = documents
+        .iter()
+        .map(|document| {
+            format!(
+                "\"{}\"",
+                document.replace('\\', "\\\\").replace('"', "\\\"")
+            )
+        })
+        .collect();
+    format!("new String[] {{{}}}", literals.join(", "))
+}
+
+/// Writes `Main.java` next to the sources in `dir`, compiles them together
+/// and runs it.
+fn run_main(dir: &Path, main: &str) {
+    let sources = dir.join("src/main/java");
+    fs::write(sources.join("Main.java"), main).unwrap();
+
+    let classes = dir.join("classes");
+    let output = Command::new("javac")
+        .args(["--release", "17", "-encoding", "UTF-8", "-d"])
+        .arg(&classes)
+        .args(collect_java_files(&sources))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "javac failed:\nstdout: {}\nstderr: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr),
+    );
+
+    let output = Command::new("java")
+        .arg("-cp")
+        .arg(&classes)
+        .arg("Main")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "Java round-trip test failed:\nstdout: {}\nstderr: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr),
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_java_bincode_runtime_on_simple_data() {
+    let registry = common::get_registry();
+    let dir = tempdir().unwrap();
+
+    java::Installer::new(PACKAGE, dir.path())
+        .plugin(BincodePlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let samples = common::get_positive_samples()
+        .iter()
+        .map(|sample| quote_bytes_java(sample))
+        .collect::<Vec<_>>();
+
+    let main = format!(
+        r#"import {PACKAGE}.SerdeData;
+
+public class Main {{
+    public static void main(String[] args) throws Exception {{
+        String[] samples = {{
+            {samples},
+        }};
+        for (String hex : samples) {{
+            byte[] sample = java.util.HexFormat.of().parseHex(hex);
+            SerdeData value = SerdeData.bincodeDeserialize(sample);
+            byte[] output = value.bincodeSerialize();
+            if (!java.util.Arrays.equals(output, sample)) {{
+                throw new AssertionError("roundtrip failed:\n  input  = " + hex
+                    + "\n  output = " + java.util.HexFormat.of().formatHex(output));
+            }}
+        }}
+        System.out.println("bincode roundtrip: PASSED");
+    }}
+}}
+"#,
+        samples = samples.join(",\n            "),
+    );
+    run_main(dir.path(), &main);
+}
+
+#[test]
+fn test_java_bincode_runtime_on_uuid_data() {
+    let registry = common::get_uuid_registry();
+    let dir = tempdir().unwrap();
+
+    java::Installer::new(PACKAGE, dir.path())
+        .plugin(BincodePlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let reference = common::get_uuid_reference_bytes();
+
+    let main = format!(
+        r#"import {PACKAGE}.UuidData;
+
+public class Main {{
+    public static void main(String[] args) throws Exception {{
+        byte[] data = java.util.HexFormat.of().parseHex({bytes});
+        UuidData value = UuidData.bincodeDeserialize(data);
+        if (!value.id().toString().equals("{id}")) {{
+            throw new AssertionError("id mismatch: " + value.id());
+        }}
+        if (!value.parentId().map(java.util.UUID::toString).equals(java.util.Optional.of("{parent_id}"))) {{
+            throw new AssertionError("parent_id mismatch: " + value.parentId());
+        }}
+        byte[] output = value.bincodeSerialize();
+        if (!java.util.Arrays.equals(output, data)) {{
+            throw new AssertionError("roundtrip failed: " + java.util.HexFormat.of().formatHex(output));
+        }}
+        System.out.println("UUID roundtrip: PASSED");
+    }}
+}}
+"#,
+        bytes = quote_bytes_java(&reference),
+        id = common::UUID_ID,
+        parent_id = common::UUID_PARENT_ID,
+    );
+    run_main(dir.path(), &main);
+}
+
+#[test]
+fn test_java_json_runtime_on_tagged_enums() {
+    let registry = common::get_tagged_registry();
+    let dir = tempdir().unwrap();
+
+    java::Installer::new(PACKAGE, dir.path())
+        .plugin(JsonPlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let main = format!(
+        r#"import {PACKAGE}.Message;
+import {PACKAGE}.Shape;
+
+public class Main {{
+    public static void main(String[] args) throws Exception {{
+        String[] shapes = {shapes};
+        for (String document : shapes) {{
+            String output = Shape.jsonDeserialize(document).jsonSerialize();
+            if (!output.equals(document)) {{
+                throw new AssertionError("Shape roundtrip failed: " + document + " != " + output);
+            }}
+        }}
+
+        for (String document : {messages}) {{
+            String output = Message.jsonDeserialize(document).jsonSerialize();
+            if (!output.equals(document)) {{
+                throw new AssertionError("Message roundtrip failed: " + document + " != " + output);
+            }}
+        }}
+
+        if (!(Shape.jsonDeserialize(shapes[1]) instanceof Shape.Rectangle rectangle)
+                || rectangle.width() != 4 || rectangle.height() != 5) {{
+            throw new AssertionError("Rectangle should be 4 x 5: " + Shape.jsonDeserialize(shapes[1]));
+        }}
+
+        try {{
+            Shape.jsonDeserialize("{{\"type\":\"Triangle\"}}");
+            throw new AssertionError("Should have failed on an unknown variant");
+        }} catch (com.novi.serde.DeserializationError e) {{
+            // Expected.
+        }}
+
+        System.out.println("Tagged enums roundtrip: PASSED");
+    }}
+}}
+"#,
+        shapes = quote_json_java(&common::get_shape_reference_json()),
+        messages = quote_json_java(&common::get_message_reference_json()),
+    );
+    run_main(dir.path(), &main);
+}
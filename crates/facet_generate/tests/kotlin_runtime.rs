@@ -1,17 +1,21 @@
 #![cfg(feature = "kotlin")]
-//! Runtime tests for Kotlin bincode serialization.
+//! Runtime tests for Kotlin bincode and JSON serialization.
 //!
-//! These tests generate Kotlin code, serialize data in Rust with bincode, then
-//! compile and run the generated Kotlin code to deserialize, verify field
-//! values, and re-serialize — checking that the bytes round-trip correctly.
+//! These tests generate Kotlin code, serialize data in Rust with bincode or
+//! `serde_json`, then compile and run the generated Kotlin code to deserialize,
+//! verify field values, and re-serialize — checking that the data round-trips
+//! correctly.
 //!
 //! # Toolchain requirement
 //!
-//! `kotlinc` and `java` must be on `PATH`. The test compiles all generated
-//! `.kt` sources (including the serde runtime) into a single JAR with
+//! `kotlinc` and `java` must be on `PATH` for the bincode test. It compiles all
+//! generated `.kt` sources (including the serde runtime) into a single JAR with
 //! `kotlinc -include-runtime`, then runs the JVM entry-point with
 //! `java -classpath`.
 //!
+//! The JSON test needs `gradle` instead, which brings in the kotlinx.serialization
+//! compiler plugin and library that the generated code depends on.
+//!
 //! Unlike the compilation-only test in `kotlin_generation.rs`, this test
 //! actually *executes* the generated serialization logic and verifies the
 //! bytes produced by the Kotlin code match what Rust's `bincode` produces
@@ -24,7 +28,7 @@ use std::{
     process::Command,
 };
 
-use facet_generate::generation::{bincode::BincodePlugin, kotlin};
+use facet_generate::generation::{bincode::BincodePlugin, json::JsonPlugin, kotlin};
 use tempfile::tempdir;
 
 pub mod common;
@@ -58,6 +62,15 @@ fn quote_bytes_kotlin(bytes: &[u8]) -> String {
     format!("byteArrayOf({})", elems.join(", "))
 }
 
+/// Format JSON documents as a Kotlin `listOf(…)` of raw string literals.
+fn quote_json_kotlin(documents: &[String]) -> String {
+    let literals: Vec<String> = documents
+        .iter()
+        .map(|document| format!(r#""""{document}""""#))
+        .collect();
+    format!("listOf({})", literals.join(", "))
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -148,3 +161,85 @@ fun main() {{
         .unwrap();
     assert!(status.success(), "UUID round-trip test failed");
 }
+
+#[test]
+fn test_kotlin_json_runtime_on_tagged_enums() {
+    let registry = common::get_tagged_registry();
+    let dir = tempdir().unwrap();
+    let dir = dir.path().to_path_buf().join("testing");
+    let sources = dir.join("src/main/kotlin");
+
+    let package_name = "com.example.testing";
+    let installer = kotlin::Installer::new(package_name, &sources).plugin(JsonPlugin);
+    let manifest = installer.make_manifest(package_name);
+    installer.generate(&registry).unwrap();
+
+    // Gradle builds the project from its root, where the sources are under
+    // `src/main/kotlin`, so the manifest is moved there.
+    fs::remove_file(sources.join("build.gradle.kts")).unwrap();
+    fs::write(
+        dir.join("build.gradle.kts"),
+        format!(
+            r#"{manifest}
+tasks.register<JavaExec>("roundtrip") {{
+    classpath = sourceSets["main"].runtimeClasspath
+    mainClass.set("MainKt")
+}}
+"#
+        ),
+    )
+    .unwrap();
+    fs::write(
+        dir.join("settings.gradle.kts"),
+        "rootProject.name = \"testing\"\n",
+    )
+    .unwrap();
+
+    let shapes = common::get_shape_reference_json();
+    let messages = common::get_message_reference_json();
+
+    let mut main_file = fs::File::create(sources.join("Main.kt")).unwrap();
+    writeln!(
+        main_file,
+        r#"import com.example.testing.Message
+import com.example.testing.Shape
+import java.io.File
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.json.Json
+
+fun main() {{
+    val shapes = {shapes}
+    val messages = {messages}
+
+    val output = shapes.map {{ Json.encodeToString(Shape.Serializer, Json.decodeFromString(Shape.Serializer, it)) }} +
+        messages.map {{ Json.encodeToString(Message.Serializer, Json.decodeFromString(Message.Serializer, it)) }}
+    File("output.jsonl").writeText(output.joinToString("\n"))
+
+    val rectangle = Json.decodeFromString(Shape.Serializer, shapes[1])
+    check(rectangle == Shape.Rectangle(4u, 5u)) {{ "Rectangle should be 4 x 5: $rectangle" }}
+
+    try {{
+        Json.decodeFromString(Shape.Serializer, """{{"type":"Triangle"}}""")
+        error("Should have thrown on an unknown variant")
+    }} catch (e: SerializationException) {{
+        // expected
+    }}
+
+    println("Tagged enums roundtrip: PASSED")
+}}
+"#,
+        shapes = quote_json_kotlin(&shapes),
+        messages = quote_json_kotlin(&messages),
+    )
+    .unwrap();
+
+    let status = Command::new("gradle")
+        .arg("roundtrip")
+        .current_dir(&dir)
+        .status()
+        .unwrap();
+    assert!(status.success(), "tagged enums round-trip test failed");
+
+    let output = fs::read_to_string(dir.join("output.jsonl")).unwrap();
+    common::assert_same_json(&[shapes, messages].concat(), &output);
+}
@@ -0,0 +1,54 @@
+//! Compilation test for Python code generation.
+//!
+//! This integration test verifies that the Python code we generate is
+//! **syntactically correct and importable**: it byte-compiles the package and
+//! imports it, which defines every class and function. It does *not* run the
+//! generated (de)serializers — see `python_runtime.rs` for end-to-end
+//! round-trip tests.
+//!
+//! # How it works
+//!
+//! 1. Reflects [`SerdeData`](common::SerdeData) into a [`Registry`](facet_generate::Registry).
+//! 2. Runs the full [`Installer`](facet_generate::generation::python::Installer)
+//!    pipeline with the bincode and JSON plugins into a temporary directory,
+//!    producing the package's modules and a `pyproject.toml` manifest.
+//! 3. Invokes `python3 -m compileall` over the package.
+//! 4. Imports the package and asserts a zero exit code.
+//!
+//! The test is gated on `#[cfg(feature = "python")]`, and fails when `python3`
+//! is not on `PATH`.
+
+#![cfg(feature = "python")]
+
+use std::process::Command;
+
+use facet_generate::generation::{bincode::BincodePlugin, json::JsonPlugin, python};
+use tempfile::tempdir;
+
+pub mod common;
+
+#[test]
+fn test_that_python_code_imports() {
+    let registry = common::get_registry();
+    let dir = tempdir().unwrap();
+
+    python::Installer::new("testing", dir.path())
+        .plugin(BincodePlugin)
+        .plugin(JsonPlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let status = Command::new("python3")
+        .args(["-m", "compileall", "-q", "testing"])
+        .current_dir(dir.path())
+        .status()
+        .unwrap();
+    assert!(status.success(), "python3 -m compileall failed");
+
+    let status = Command::new("python3")
+        .args(["-c", "import testing"])
+        .current_dir(dir.path())
+        .status()
+        .unwrap();
+    assert!(status.success(), "importing the generated package failed");
+}
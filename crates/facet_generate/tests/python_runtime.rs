@@ -0,0 +1,178 @@
+#![cfg(feature = "python")]
+//! Runtime tests for Python bincode and JSON serialization.
+//!
+//! These tests generate a Python package, serialize data in Rust with bincode,
+//! then run the generated Python code to deserialize and re-serialize it —
+//! checking that the bytes round-trip correctly. The JSON test does the same
+//! with `serde_json` and the generated converters of tagged enums.
+//!
+//! # Toolchain requirement
+//!
+//! `python3` (3.10 or later) must be on `PATH`, or the tests fail; build
+//! without the `python` feature to leave them out. Each test writes a `main.py` script next to the generated
+//! package, which needs no installation.
+
+use std::{fs, path::Path, process::Command};
+
+use facet_generate::generation::{bincode::BincodePlugin, json::JsonPlugin, python};
+use tempfile::tempdir;
+
+pub mod common;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Format a `&[u8]` as a Python bytes literal.
+fn quote_bytes_python(bytes: &[u8]) -> String {
+    let escaped: String = bytes.iter().map(|byte| format!("\\x{byte:02x}")).collect();
+    format!("b\"{escaped}\"")
+}
+
+/// Format JSON documents as a Python list of string literals.
+fn quote_json_python(documents: &[String]) -> String {
+    let literals: Vec<String> = documents
+        .iter()
+        .map(|document| format!("r'{document}'"))
+        .collect();
+    format!("[{}]", literals.join(", "))
+}
+
+/// Writes `main.py` next to the package in `dir` and runs it.
+fn run_main(dir: &Path, main: &str) {
+    fs::write(dir.join("main.py"), main).unwrap();
+
+    let output = Command::new("python3")
+        .arg("main.py")
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "Python round-trip test failed:\nstdout: {}\nstderr: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr),
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_python_bincode_runtime_on_simple_data() {
+    let registry = common::get_registry();
+    let dir = tempdir().unwrap();
+
+    python::Installer::new("testing", dir.path())
+        .plugin(BincodePlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let samples = common::get_positive_samples()
+        .iter()
+        .map(|sample| quote_bytes_python(sample))
+        .collect::<Vec<_>>();
+
+    let main = format!(
+        r#"from testing import bincode_deserialize_serde_data
+
+samples = [
+    {samples},
+]
+for sample in samples:
+    value = bincode_deserialize_serde_data(sample)
+    output = value.bincode_serialize()
+    if output != sample:
+        raise AssertionError(f"roundtrip failed:\n  input  = {{sample!r}}\n  output = {{output!r}}")
+print("bincode roundtrip: PASSED")
+"#,
+        samples = samples.join(",\n    "),
+    );
+    run_main(dir.path(), &main);
+}
+
+#[test]
+fn test_python_bincode_runtime_on_uuid_data() {
+    let registry = common::get_uuid_registry();
+    let dir = tempdir().unwrap();
+
+    python::Installer::new("testing", dir.path())
+        .plugin(BincodePlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let reference = common::get_uuid_reference_bytes();
+
+    let main = format!(
+        r#"from uuid import UUID
+
+from testing import UuidData
+
+data = {bytes}
+value = UuidData.bincode_deserialize(data)
+if value.id != UUID("{id}"):
+    raise AssertionError(f"id mismatch: {{value.id}}")
+if value.parent_id != UUID("{parent_id}"):
+    raise AssertionError(f"parent_id mismatch: {{value.parent_id}}")
+output = value.bincode_serialize()
+if output != data:
+    raise AssertionError(f"roundtrip failed:\n  input  = {{data!r}}\n  output = {{output!r}}")
+print("UUID roundtrip: PASSED")
+"#,
+        bytes = quote_bytes_python(&reference),
+        id = common::UUID_ID,
+        parent_id = common::UUID_PARENT_ID,
+    );
+    run_main(dir.path(), &main);
+}
+
+#[test]
+fn test_python_json_runtime_on_tagged_enums() {
+    let registry = common::get_tagged_registry();
+    let dir = tempdir().unwrap();
+
+    python::Installer::new("testing", dir.path())
+        .plugin(JsonPlugin)
+        .generate(&registry)
+        .unwrap();
+
+    let main = format!(
+        r#"import json
+
+from testing import ShapeRectangle, from_json_message, from_json_shape
+from testing.serde import DeserializationError
+
+
+def dumps(value):
+    return json.dumps(value, separators=(",", ":"))
+
+
+shapes = {shapes}
+for document in shapes:
+    output = dumps(from_json_shape(json.loads(document)).to_json())
+    if output != document:
+        raise AssertionError(f"Shape roundtrip failed: {{document}} != {{output}}")
+
+for document in {messages}:
+    output = dumps(from_json_message(json.loads(document)).to_json())
+    if output != document:
+        raise AssertionError(f"Message roundtrip failed: {{document}} != {{output}}")
+
+rectangle = from_json_shape(json.loads(shapes[1]))
+if rectangle != ShapeRectangle(width=4, height=5):
+    raise AssertionError(f"Rectangle should be 4 x 5: {{rectangle}}")
+
+try:
+    from_json_shape({{"type": "Triangle"}})
+    raise AssertionError("Should have raised on an unknown variant")
+except DeserializationError:
+    pass
+
+print("Tagged enums roundtrip: PASSED")
+"#,
+        shapes = quote_json_python(&common::get_shape_reference_json()),
+        messages = quote_json_python(&common::get_message_reference_json()),
+    );
+    run_main(dir.path(), &main);
+}
@@ -25,7 +25,12 @@
     feature = "swift",
     feature = "kotlin",
     feature = "typescript",
-    feature = "csharp"
+    feature = "csharp",
+    feature = "dart",
+    feature = "python",
+    feature = "go",
+    feature = "java",
+    feature = "cpp"
 ))]
 
 use std::{
@@ -36,7 +41,9 @@ use std::{
 use facet::Facet;
 use facet_generate::{
     Registry,
-    generation::{bincode::BincodePlugin, csharp, kotlin, swift, typescript},
+    generation::{
+        bincode::BincodePlugin, cpp, csharp, dart, go, java, kotlin, python, swift, typescript,
+    },
     reflection::RegistryBuilder,
 };
 
@@ -101,6 +108,31 @@ fn readme_examples_are_up_to_date() {
             fence: "csharp",
             code: generate_csharp(&registry),
         },
+        Block {
+            marker: "dart",
+            fence: "dart",
+            code: generate_dart(&registry),
+        },
+        Block {
+            marker: "python",
+            fence: "python",
+            code: generate_python(&registry),
+        },
+        Block {
+            marker: "go",
+            fence: "go",
+            code: generate_go(&registry),
+        },
+        Block {
+            marker: "java",
+            fence: "java",
+            code: generate_java(&registry),
+        },
+        Block {
+            marker: "cpp",
+            fence: "cpp",
+            code: generate_cpp(&registry),
+        },
     ];
 
     let readme_path = readme_path();
@@ -175,8 +207,69 @@ fn generate_csharp(registry: &Registry) -> String {
     strip_preamble(&source)
 }
 
-/// Strips language preamble (import / package / using lines and blank lines
-/// between them) from the start of a generated source file.
+fn generate_dart(registry: &Registry) -> String {
+    let dir = tempfile::tempdir().unwrap();
+    dart::Installer::new("example", dir.path())
+        .plugin(BincodePlugin)
+        .generate(registry)
+        .unwrap();
+    let source = find_source_with_token(dir.path(), "Point").expect("Dart module not found");
+    strip_preamble(&source)
+}
+
+fn generate_python(registry: &Registry) -> String {
+    let dir = tempfile::tempdir().unwrap();
+    python::Installer::new("example", dir.path())
+        .plugin(BincodePlugin)
+        .generate(registry)
+        .unwrap();
+    let source = find_source_with_token(dir.path(), "Point").expect("Python module not found");
+    strip_preamble(&source)
+}
+
+fn generate_go(registry: &Registry) -> String {
+    let dir = tempfile::tempdir().unwrap();
+    go::Installer::new("github.com/example/types", dir.path())
+        .plugin(BincodePlugin)
+        .generate(registry)
+        .unwrap();
+    let source = find_source_with_token(dir.path(), "Point").expect("Go package not found");
+    strip_preamble(&source)
+}
+
+fn generate_java(registry: &Registry) -> String {
+    let dir = tempfile::tempdir().unwrap();
+    java::Installer::new("com.example", dir.path())
+        .plugin(BincodePlugin)
+        .generate(registry)
+        .unwrap();
+    // Java writes each type to a file of its own.
+    let package = dir.path().join("src/main/java/com/example");
+    ["Point", "Shape"]
+        .iter()
+        .map(|name| {
+            let source = fs::read_to_string(package.join(format!("{name}.java")))
+                .expect("Java source not found");
+            strip_preamble(&source)
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn generate_cpp(registry: &Registry) -> String {
+    let dir = tempfile::tempdir().unwrap();
+    cpp::Installer::new("example", dir.path())
+        .plugin(BincodePlugin)
+        .generate(registry)
+        .unwrap();
+    let source =
+        fs::read_to_string(dir.path().join("include/example.hpp")).expect("C++ header not found");
+    strip_preamble(&source)
+}
+
+/// Strips language preamble (import / package / using lines, the paths of a Go
+/// import block, C++ `#pragma` / `#include` lines and blank lines between them)
+/// from the start of a generated source file.
 fn strip_preamble(source: &str) -> String {
     source
         .lines()
@@ -184,8 +277,13 @@ fn strip_preamble(source: &str) -> String {
             let t = line.trim();
             t.is_empty()
                 || t.starts_with("import ")
+                || t.starts_with("from ")
                 || t.starts_with("package ")
                 || t.starts_with("using ")
+                || t.starts_with('"')
+                || t == ")"
+                || t.starts_with("#pragma ")
+                || t.starts_with("#include ")
         })
         .collect::<Vec<_>>()
         .join("\n")
@@ -6,7 +6,7 @@ pub mod common;
 
 use common::{Choice, Test};
 use facet_generate::generation::{
-    CodeGeneratorConfig, SourceInstaller, bincode::BincodePlugin, swift,
+    CodeGeneratorConfig, SourceInstaller, bincode::BincodePlugin, json::JsonPlugin, swift,
 };
 use std::{fs::File, io::Write as _, path::Path, process::Command};
 
@@ -284,6 +284,103 @@ let package = Package(
     assert!(status.success());
 }
 
+#[test]
+fn test_swift_json_runtime_on_tagged_enums() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = CodeGeneratorConfig::new("Testing".to_string());
+    let registry = common::get_tagged_registry();
+    let mut installer = swift::Installer::new(&config.module_name, dir.path()).plugin(JsonPlugin);
+    installer.install_module(&config, &registry).unwrap();
+    installer.install_serde_runtime().unwrap();
+
+    let shapes = common::get_shape_reference_json();
+    let messages = common::get_message_reference_json();
+
+    std::fs::create_dir_all(dir.path().join("Sources/main")).unwrap();
+    let main_path = dir.path().join("Sources/main/main.swift");
+    let mut main = File::create(main_path).unwrap();
+    writeln!(
+        main,
+        r##"
+import Foundation
+import Serde
+import Testing
+
+let shapes = {shapes}
+let messages = {messages}
+
+var output: [String] = []
+for input in shapes {{
+    let value = try Shape.jsonDeserialize(input: Array(input.utf8))
+    output.append(String(decoding: try value.jsonSerialize(), as: UTF8.self))
+}}
+for input in messages {{
+    let value = try Message.jsonDeserialize(input: Array(input.utf8))
+    output.append(String(decoding: try value.jsonSerialize(), as: UTF8.self))
+}}
+try output.joined(separator: "\n").write(toFile: "output.jsonl", atomically: true, encoding: .utf8)
+
+let rectangle = try Shape.jsonDeserialize(input: Array(shapes[1].utf8))
+assert(rectangle == .rectangle(width: 4, height: 5), "Rectangle should be 4 x 5")
+
+do {{
+    let _ = try Shape.jsonDeserialize(input: Array(#"{{"type":"Triangle"}}"#.utf8))
+    assertionFailure("Was expecting an error")
+}}
+catch {{}}
+"##,
+        shapes = quote_json(&shapes),
+        messages = quote_json(&messages),
+    )
+    .unwrap();
+
+    let mut file = File::create(dir.path().join("Package.swift")).unwrap();
+    write!(
+        file,
+        r#"// swift-tools-version:6.0
+
+import PackageDescription
+
+let package = Package(
+    name: "Testing",
+    platforms: [.macOS(.v15)],
+    targets: [
+        .target(
+            name: "Serde",
+            dependencies: []),
+        .target(
+            name: "Testing",
+            dependencies: ["Serde"]),
+        .target(
+            name: "main",
+            dependencies: ["Serde", "Testing"]
+        ),
+    ]
+)
+"#
+    )
+    .unwrap();
+
+    let status = Command::new("swift")
+        .current_dir(dir.path())
+        .arg("run")
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    // `JSONEncoder` doesn't keep the keys in the order serde writes them.
+    let output = std::fs::read_to_string(dir.path().join("output.jsonl")).unwrap();
+    common::assert_same_json(&[shapes, messages].concat(), &output);
+}
+
+fn quote_json(documents: &[String]) -> String {
+    let literals: Vec<String> = documents
+        .iter()
+        .map(|document| format!(r##"#"{document}"#"##))
+        .collect();
+    format!("[{}]", literals.join(", "))
+}
+
 fn quote_bytes(bytes: &[u8]) -> String {
     format!(
         "[{}]",